@@ -61,6 +61,20 @@ fn maximize_bench(c: &mut Criterion) {
     });
 }
 
+fn minimize_bench(c: &mut Criterion) {
+    let langids: Vec<LanguageIdentifier> = STRINGS
+        .iter()
+        .map(|s| -> LanguageIdentifier { s.parse().unwrap() })
+        .collect();
+    c.bench_function("minimize", move |b| {
+        b.iter(|| {
+            for mut s in langids.clone().into_iter() {
+                s.minimize();
+            }
+        })
+    });
+}
+
 fn extract_input(
     s: &str,
 ) -> (
@@ -84,5 +98,23 @@ fn raw_maximize_bench(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, maximize_bench, raw_maximize_bench,);
+fn raw_minimize_bench(c: &mut Criterion) {
+    let entries: Vec<_> = STRINGS.iter().map(|s| extract_input(s)).collect();
+
+    c.bench_function("raw_minimize", move |b| {
+        b.iter(|| {
+            for (lang, script, region) in entries.clone().into_iter() {
+                let _ = unic_langid_impl::likelysubtags::minimize(lang, script, region);
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    maximize_bench,
+    minimize_bench,
+    raw_maximize_bench,
+    raw_minimize_bench,
+);
 criterion_main!(benches);
@@ -0,0 +1,183 @@
+//! Regenerates the likely subtags tables with a subset of their entries
+//! when any of the following environment variables is set, each to a
+//! comma separated list of subtags to keep:
+//!
+//! * `UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES`
+//! * `UNIC_LANGID_LIKELYSUBTAGS_SCRIPTS`
+//! * `UNIC_LANGID_LIKELYSUBTAGS_REGIONS`
+//!
+//! See the documentation of the `likelysubtags` module.
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+#[path = "build/likelysubtags.rs"]
+mod likelysubtags;
+#[path = "build/output.rs"]
+mod output;
+#[allow(dead_code)]
+#[path = "src/likelysubtags/tables.rs"]
+mod tables;
+
+use likelysubtags::{Entries, Subset, Subtags, KEY_SHIFT, LANGUAGE_SHIFT, SCRIPT_SHIFT, UND};
+
+const LANGUAGES: &str = "UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES";
+const SCRIPTS: &str = "UNIC_LANGID_LIKELYSUBTAGS_SCRIPTS";
+const REGIONS: &str = "UNIC_LANGID_LIKELYSUBTAGS_REGIONS";
+
+/// Parses a list of subtags into the integers of their `tinystr` bytes,
+/// normalizing their case with `normalize`.
+fn parse_list<const N: usize>(
+    name: &str,
+    valid: impl Fn(&str) -> bool,
+    normalize: impl Fn(&str) -> String,
+) -> Option<BTreeSet<[u8; N]>> {
+    let value = env::var(name).ok()?;
+    let result = value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|subtag| {
+            if !valid(subtag) {
+                panic!("{} holds an invalid subtag: {:?}", name, subtag);
+            }
+            let mut bytes = [0; N];
+            bytes[..subtag.len()].copy_from_slice(normalize(subtag).as_bytes());
+            bytes
+        })
+        .collect();
+    Some(result)
+}
+
+fn subset() -> Subset {
+    let alpha = |s: &str| s.bytes().all(|b| b.is_ascii_alphabetic());
+    let digit = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    let languages = parse_list::<8>(
+        LANGUAGES,
+        |s| alpha(s) && (2..=8).contains(&s.len()) && s.len() != 4,
+        |s| s.to_ascii_lowercase(),
+    );
+    let scripts = parse_list::<4>(
+        SCRIPTS,
+        |s| alpha(s) && s.len() == 4,
+        |s| s[..1].to_ascii_uppercase() + &s[1..].to_ascii_lowercase(),
+    );
+    let regions = parse_list::<4>(
+        REGIONS,
+        |s| alpha(s) && s.len() == 2 || digit(s) && s.len() == 3,
+        |s| s.to_ascii_uppercase(),
+    );
+
+    Subset {
+        languages: merge(
+            languages.map(|set| set.into_iter().map(u64::from_le_bytes).collect()),
+            tables::SUBSET_LANGUAGES,
+        ),
+        scripts: merge(
+            scripts.map(|set| set.into_iter().map(u32::from_le_bytes).collect()),
+            tables::SUBSET_SCRIPTS,
+        ),
+        regions: merge(
+            regions.map(|set| set.into_iter().map(u32::from_le_bytes).collect()),
+            tables::SUBSET_REGIONS,
+        ),
+    }
+}
+
+/// Restricts a subset to the one the baked tables were generated with, if any.
+fn merge<T: Ord + Copy>(subset: Option<BTreeSet<T>>, baked: Option<&[T]>) -> Option<BTreeSet<T>> {
+    match (subset, baked) {
+        (Some(subset), Some(baked)) => {
+            Some(subset.into_iter().filter(|v| baked.contains(v)).collect())
+        }
+        (subset, baked) => subset.or_else(|| baked.map(|baked| baked.iter().copied().collect())),
+    }
+}
+
+/// Reads all the entries back from the baked tables.
+fn decode() -> Entries {
+    let language = |idx: usize| match tables::LANGUAGES[idx] {
+        UND => 0,
+        language => language,
+    };
+    let script = |idx: usize| tables::SCRIPTS[idx];
+    let region = |idx: usize| tables::REGIONS[idx];
+    let value = |input: u32| -> Subtags {
+        let script_idx =
+            (input >> SCRIPT_SHIFT & ((1 << (LANGUAGE_SHIFT - SCRIPT_SHIFT)) - 1)) as usize;
+        let region_idx = (input & ((1 << SCRIPT_SHIFT) - 1)) as usize;
+        (
+            language((input >> LANGUAGE_SHIFT) as usize - 1),
+            script_idx.checked_sub(1).map(script),
+            region_idx.checked_sub(1).map(region),
+        )
+    };
+    let first = |key: u32| (key >> KEY_SHIFT) as usize;
+    let second = |key: u32| (key & ((1 << KEY_SHIFT) - 1)) as usize;
+
+    let mut result = Entries::new();
+    for (idx, input) in tables::LANG_ONLY.iter().enumerate() {
+        if *input != 0 {
+            result.insert((language(idx), None, None), value(*input));
+        }
+    }
+    for (key, input) in tables::LANG_REGION {
+        result.insert(
+            (language(first(key)), None, Some(region(second(key)))),
+            value(input),
+        );
+    }
+    for (key, input) in tables::LANG_SCRIPT {
+        result.insert(
+            (language(first(key)), Some(script(second(key))), None),
+            value(input),
+        );
+    }
+    for (key, input) in tables::SCRIPT_REGION {
+        let key = (0, Some(script(first(key))), Some(region(second(key))));
+        result.insert(key, value(input));
+    }
+    for (idx, input) in tables::SCRIPT_ONLY.iter().enumerate() {
+        if *input != 0 {
+            result.insert((0, Some(script(idx)), None), value(*input));
+        }
+    }
+    for (idx, input) in tables::REGION_ONLY.iter().enumerate() {
+        if *input != 0 {
+            result.insert((0, None, Some(region(idx))), value(*input));
+        }
+    }
+    result
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed=src/likelysubtags/tables.rs");
+    for name in [LANGUAGES, SCRIPTS, REGIONS] {
+        println!("cargo:rerun-if-env-changed={}", name);
+    }
+    println!("cargo:rustc-check-cfg=cfg(unic_langid_likelysubtags_subset)");
+
+    if env::var_os("CARGO_FEATURE_LIKELYSUBTAGS").is_none() {
+        return;
+    }
+    if [LANGUAGES, SCRIPTS, REGIONS]
+        .iter()
+        .all(|name| env::var_os(name).is_none())
+    {
+        return;
+    }
+    let subset = subset();
+
+    let mut entries = decode();
+    subset.apply(&mut entries);
+    let source = likelysubtags::generate(&entries, tables::CLDR_VERSION, &subset)
+        .expect("Failed to generate the likely subtags subset.");
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("likelysubtags_tables.rs");
+    fs::write(out, source).expect("Failed to write the likely subtags subset.");
+    println!("cargo:rustc-cfg=unic_langid_likelysubtags_subset");
+}
@@ -0,0 +1,304 @@
+//! Generating the likely subtags tables of `src/likelysubtags/tables.rs`.
+//!
+//! Shared by `unic-datagen`, which generates them from CLDR, and the build
+//! script, which regenerates a subset of them. Subtags are handled in the
+//! raw `tinystr` form used by the tables, since the build script can't
+//! depend on the crate.
+use crate::output::{Output, HEADER};
+use std::collections::{BTreeMap, BTreeSet};
+
+// Must match the layout expected by `likelysubtags::lang_from_parts`.
+pub const SCRIPT_SHIFT: u32 = 9;
+pub const LANGUAGE_SHIFT: u32 = 17;
+pub const KEY_SHIFT: u32 = 16;
+
+/// The `und` key is stored in the `LANG_ONLY` table like a language.
+pub const UND: u64 = u64::from_le_bytes(*b"und\0\0\0\0\0");
+
+/// A language, with `0` standing for `und`, and an optional script and
+/// region, each as the integer of its `tinystr` bytes.
+pub type Subtags = (u64, Option<u32>, Option<u32>);
+
+/// Likely subtags entries, by key.
+pub type Entries = BTreeMap<Subtags, Subtags>;
+
+/// The languages, scripts and regions to keep, `None` keeping all.
+#[derive(Default)]
+pub struct Subset {
+    pub languages: Option<BTreeSet<u64>>,
+    pub scripts: Option<BTreeSet<u32>>,
+    pub regions: Option<BTreeSet<u32>>,
+}
+
+impl Subset {
+    /// Returns whether every subtag of `subtags` is kept. `und` always is.
+    pub fn contains(&self, (language, script, region): &Subtags) -> bool {
+        fn keeps<T: Ord>(set: &Option<BTreeSet<T>>, value: Option<&T>) -> bool {
+            match (set, value) {
+                (Some(set), Some(value)) => set.contains(value),
+                _ => true,
+            }
+        }
+        keeps(&self.languages, Some(language).filter(|l| **l != 0))
+            && keeps(&self.scripts, script.as_ref())
+            && keeps(&self.regions, region.as_ref())
+    }
+
+    /// Keeps the entries whose key only uses kept subtags. Their values
+    /// may use any subtag, so that they stay the same as in CLDR.
+    pub fn apply(&self, entries: &mut Entries) {
+        entries.retain(|key, _| self.contains(key));
+    }
+}
+
+fn to_str(bytes: &[u8]) -> &str {
+    let length = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    std::str::from_utf8(&bytes[..length]).unwrap()
+}
+
+/// Formats subtags like a language identifier.
+pub fn to_string((language, script, region): &Subtags) -> String {
+    let language = if *language == 0 { UND } else { *language };
+    let mut result = to_str(&language.to_le_bytes()).to_string();
+    for subtag in [script, region].into_iter().flatten() {
+        result.push('-');
+        result.push_str(to_str(&subtag.to_le_bytes()));
+    }
+    result
+}
+
+fn language(value: &Subtags) -> u64 {
+    if value.0 == 0 {
+        UND
+    } else {
+        value.0
+    }
+}
+
+struct Pools {
+    languages: Vec<u64>,
+    scripts: Vec<u32>,
+    regions: Vec<u32>,
+}
+
+impl Pools {
+    fn new(entries: &Entries) -> Result<Self, String> {
+        let mut languages = BTreeSet::new();
+        let mut scripts = BTreeSet::new();
+        let mut regions = BTreeSet::new();
+        for entry in entries.iter().flat_map(|(k, v)| [k, v]) {
+            if entry.0 != 0 || entry.1.is_none() && entry.2.is_none() {
+                languages.insert(language(entry));
+            }
+            scripts.extend(entry.1);
+            regions.extend(entry.2);
+        }
+
+        // Indices are stored plus one, so that `0` means a missing subtag.
+        if languages.len() >= 1 << (32 - LANGUAGE_SHIFT) {
+            return Err(format!("too many languages: {}", languages.len()));
+        }
+        if scripts.len() >= 1 << (LANGUAGE_SHIFT - SCRIPT_SHIFT) {
+            return Err(format!("too many scripts: {}", scripts.len()));
+        }
+        if regions.len() >= 1 << SCRIPT_SHIFT {
+            return Err(format!("too many regions: {}", regions.len()));
+        }
+
+        Ok(Self {
+            languages: languages.into_iter().collect(),
+            scripts: scripts.into_iter().collect(),
+            regions: regions.into_iter().collect(),
+        })
+    }
+
+    fn language(&self, value: &Subtags) -> u32 {
+        self.languages.binary_search(&language(value)).unwrap() as u32
+    }
+
+    fn script(&self, value: &Subtags) -> u32 {
+        self.scripts.binary_search(&value.1.unwrap()).unwrap() as u32
+    }
+
+    fn region(&self, value: &Subtags) -> u32 {
+        self.regions.binary_search(&value.2.unwrap()).unwrap() as u32
+    }
+
+    fn pack(&self, value: &Subtags) -> u32 {
+        let language = self.language(value) + 1;
+        let script = value.1.map_or(0, |_| self.script(value) + 1);
+        let region = value.2.map_or(0, |_| self.region(value) + 1);
+        language << LANGUAGE_SHIFT | script << SCRIPT_SHIFT | region
+    }
+}
+
+type Table<'a> = Vec<(u32, &'a Subtags, &'a Subtags)>;
+
+/// Tables with a single subtag as their key hold an entry for every
+/// subtag of the pool, `0` if there is no value.
+fn dense<T>(out: &mut Output, name: &str, pool: &[T], entries: &Table, pools: &Pools) {
+    let mut values = vec![None; pool.len()];
+    for (idx, key, value) in entries {
+        values[*idx as usize] = Some((key, value));
+    }
+    out.array_start(name, "u32", values.len());
+    for value in values {
+        match value {
+            Some((key, value)) => out.entry(
+                pools.pack(value),
+                format!("{}: {}", to_string(key), to_string(value)),
+            ),
+            None => out.entry(0, ""),
+        }
+    }
+    out.array_end();
+}
+
+/// Tables with two subtags as their key are sorted by key.
+fn sparse(out: &mut Output, name: &str, mut entries: Table, pools: &Pools) {
+    entries.sort_by_key(|(key, _, _)| *key);
+    out.array_start(name, "(u32, u32)", entries.len());
+    for (packed_key, key, value) in entries {
+        out.entry(
+            format!("({}, {})", packed_key, pools.pack(value)),
+            format!("{}: {}", to_string(key), to_string(value)),
+        );
+    }
+    out.array_end();
+}
+
+/// Lists the subtags kept by a subset, sorted for binary search.
+fn subset_list<T: std::fmt::Display>(
+    out: &mut Output,
+    name: &str,
+    ty: &str,
+    set: Option<Vec<T>>,
+    comment: impl Fn(&T) -> String,
+) {
+    out.line("");
+    match set {
+        None => out.line(&format!("pub static {}: Option<&[{}]> = None;", name, ty)),
+        Some(set) => {
+            out.line("#[rustfmt::skip]");
+            out.line(&format!("pub static {}: Option<&[{}]> = Some(&[", name, ty));
+            for value in &set {
+                out.entry(value, comment(value));
+            }
+            out.line("]);");
+        }
+    }
+}
+
+/// Generates the source of the tables holding `entries`, which have been
+/// restricted to `subset`.
+pub fn generate(entries: &Entries, version: &str, subset: &Subset) -> Result<String, String> {
+    let pools = Pools::new(entries)?;
+
+    let mut lang_only = vec![];
+    let mut lang_region = vec![];
+    let mut lang_script = vec![];
+    let mut script_region = vec![];
+    let mut script_only = vec![];
+    let mut region_only = vec![];
+    for (key, value) in entries {
+        if value.0 == 0 {
+            return Err(format!("value of {} has no language", to_string(key)));
+        }
+        let pair = |first: u32, second: u32| first << KEY_SHIFT | second;
+        match (key.0 == 0, key.1, key.2) {
+            (_, None, None) => lang_only.push((pools.language(key), key, value)),
+            (false, None, Some(_)) => {
+                lang_region.push((pair(pools.language(key), pools.region(key)), key, value))
+            }
+            (false, Some(_), None) => {
+                lang_script.push((pair(pools.language(key), pools.script(key)), key, value))
+            }
+            (true, Some(_), Some(_)) => {
+                script_region.push((pair(pools.script(key), pools.region(key)), key, value))
+            }
+            (true, Some(_), None) => script_only.push((pools.script(key), key, value)),
+            (true, None, Some(_)) => region_only.push((pools.region(key), key, value)),
+            (false, Some(_), Some(_)) => {
+                return Err(format!(
+                    "unsupported likely subtags key: {}",
+                    to_string(key)
+                ))
+            }
+        }
+    }
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    subset_list(
+        &mut out,
+        "SUBSET_LANGUAGES",
+        "u64",
+        subset
+            .languages
+            .as_ref()
+            .map(|set| set.iter().copied().collect()),
+        |value: &_| to_str(&value.to_le_bytes()).to_string(),
+    );
+    subset_list(
+        &mut out,
+        "SUBSET_SCRIPTS",
+        "u32",
+        subset
+            .scripts
+            .as_ref()
+            .map(|set| set.iter().copied().collect()),
+        |value: &_| to_str(&value.to_le_bytes()).to_string(),
+    );
+    subset_list(
+        &mut out,
+        "SUBSET_REGIONS",
+        "u32",
+        subset
+            .regions
+            .as_ref()
+            .map(|set| set.iter().copied().collect()),
+        |value: &_| to_str(&value.to_le_bytes()).to_string(),
+    );
+
+    out.array_start("LANGUAGES", "u64", pools.languages.len());
+    for value in &pools.languages {
+        out.entry(value, to_str(&value.to_le_bytes()));
+    }
+    out.array_end();
+
+    out.array_start("SCRIPTS", "u32", pools.scripts.len());
+    for value in &pools.scripts {
+        out.entry(value, to_str(&value.to_le_bytes()));
+    }
+    out.array_end();
+
+    out.array_start("REGIONS", "u32", pools.regions.len());
+    for value in &pools.regions {
+        out.entry(value, to_str(&value.to_le_bytes()));
+    }
+    out.array_end();
+
+    dense(&mut out, "LANG_ONLY", &pools.languages, &lang_only, &pools);
+    sparse(&mut out, "LANG_REGION", lang_region, &pools);
+    sparse(&mut out, "LANG_SCRIPT", lang_script, &pools);
+    sparse(&mut out, "SCRIPT_REGION", script_region, &pools);
+    dense(
+        &mut out,
+        "SCRIPT_ONLY",
+        &pools.scripts,
+        &script_only,
+        &pools,
+    );
+    dense(
+        &mut out,
+        "REGION_ONLY",
+        &pools.regions,
+        &region_only,
+        &pools,
+    );
+
+    Ok(out.finish())
+}
@@ -0,0 +1,44 @@
+//! Writing generated Rust sources.
+//!
+//! Every table is marked `#[rustfmt::skip]` and lists one entry per line,
+//! annotated with the subtags it stands for, so that the output is stable
+//! under `rustfmt` and regenerating it produces a readable diff.
+use std::fmt::{Display, Write};
+
+pub const HEADER: &str = "// This file is generated by `unic-datagen`. Do not edit it by hand.";
+
+pub struct Output(String);
+
+impl Output {
+    pub fn new() -> Self {
+        Self(String::new())
+    }
+
+    pub fn line(&mut self, line: &str) {
+        self.0.push_str(line);
+        self.0.push('\n');
+    }
+
+    pub fn array_start(&mut self, name: &str, ty: &str, len: usize) {
+        self.line("");
+        self.line("#[rustfmt::skip]");
+        writeln!(self.0, "pub static {}: [{}; {}] = [", name, ty, len).unwrap();
+    }
+
+    pub fn entry(&mut self, value: impl Display, comment: impl AsRef<str>) {
+        let comment = comment.as_ref();
+        if comment.is_empty() {
+            writeln!(self.0, "    {},", value).unwrap();
+        } else {
+            writeln!(self.0, "    {}, // {}", value, comment).unwrap();
+        }
+    }
+
+    pub fn array_end(&mut self) {
+        self.line("];");
+    }
+
+    pub fn finish(self) -> String {
+        self.0
+    }
+}
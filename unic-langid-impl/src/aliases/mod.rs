@@ -0,0 +1,204 @@
+//! Replacement of deprecated and legacy subtags, from the CLDR alias data.
+//!
+//! [`LanguageIdentifier::replace_aliases`] applies the language, variant,
+//! script and territory aliases of CLDR the way the canonicalization of
+//! [UTS #35] does, turning `iw` into `he`, `sh-YU` into `sr-Latn-RS` or
+//! `sgn-BR` into `bzs`. [`keyword_type_alias`] returns the preferred types
+//! of deprecated unicode extension types, like `islamic-civil` for the
+//! `islamicc` calendar.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let mut li: LanguageIdentifier = "sh-YU".parse().expect("Parsing failed.");
+//! assert!(li.replace_aliases());
+//! assert_eq!(li, "sr-Latn-RS");
+//!
+//! let mut li: LanguageIdentifier = "hy-arevela".parse().expect("Parsing failed.");
+//! assert!(li.replace_aliases());
+//! assert_eq!(li, "hy");
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the `cldr-core` and
+//! `cldr-bcp47` packages, and can be limited to some languages, scripts and
+//! regions with its `--locales`, `--scripts` and `--regions` options, which
+//! keep the aliases replaced by kept subtags. Aliases which aren't language identifiers,
+//! like `i-klingon`, or which are replaced by private use subtags, like
+//! `zh-min`, are left out.
+//!
+//! [UTS #35]: https://unicode.org/reports/tr35/#LocaleId_Canonicalization
+#[allow(clippy::type_complexity)]
+mod tables;
+
+use crate::subtags::{Language, Region, Script, Variant};
+use crate::LanguageIdentifier;
+use alloc::vec::Vec;
+
+pub use tables::CLDR_VERSION;
+
+// The language, script, region and variants of an alias rule, as raw
+// subtags with `0` for `und` and missing subtags.
+type Subtags = (u64, u32, u32, &'static [u64]);
+
+fn language(raw: u64) -> Language {
+    // safe because all table entries are well formed.
+    match raw {
+        0 => Language::default(),
+        raw => unsafe { Language::from_raw_unchecked(raw) },
+    }
+}
+
+fn script(raw: u32) -> Option<Script> {
+    (raw != 0).then(|| unsafe { Script::from_raw_unchecked(raw) })
+}
+
+fn region(raw: u32) -> Option<Region> {
+    (raw != 0).then(|| unsafe { Region::from_raw_unchecked(raw) })
+}
+
+fn variant(raw: u64) -> Variant {
+    unsafe { Variant::from_raw_unchecked(raw) }
+}
+
+/// Returns the preferred type of the deprecated type `value` of the unicode
+/// extension key `key`, if it has one.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::aliases::keyword_type_alias;
+///
+/// assert_eq!(keyword_type_alias("ca", "islamicc"), Some("islamic-civil"));
+/// assert_eq!(keyword_type_alias("ca", "islamic-civil"), None);
+/// ```
+pub fn keyword_type_alias(key: &str, value: &str) -> Option<&'static str> {
+    tables::KEYWORD_TYPE_ALIASES
+        .binary_search_by_key(&(key, value), |(key, value, _)| (*key, *value))
+        .ok()
+        .map(|idx| tables::KEYWORD_TYPE_ALIASES[idx].2)
+}
+
+impl LanguageIdentifier {
+    /// Replaces the deprecated and legacy subtags of the
+    /// `LanguageIdentifier` using CLDR alias data, returning whether it
+    /// changed.
+    ///
+    /// A region replaced by several ones, like `SU`, becomes the likely
+    /// region of the language among them, or else the first of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "hy-SU".parse().expect("Parsing failed.");
+    /// assert!(li.replace_aliases());
+    /// assert_eq!(li, "hy-AM");
+    ///
+    /// let mut li: LanguageIdentifier = "de-CH".parse().expect("Parsing failed.");
+    /// assert!(!li.replace_aliases());
+    /// ```
+    pub fn replace_aliases(&mut self) -> bool {
+        let mut changed = false;
+        while self.replace_language_alias()
+            || self.replace_variant_alias()
+            || self.replace_script_alias()
+            || self.replace_region_alias()
+        {
+            changed = true;
+        }
+        changed
+    }
+
+    fn alias_matches(&self, &(language, script, region, variants): &Subtags) -> bool {
+        (language == 0 || Option::<u64>::from(self.language) == Some(language))
+            && (script == 0 || self.script.map(u32::from) == Some(script))
+            && (region == 0 || self.region.map(u32::from) == Some(region))
+            && variants.iter().all(|v| self.has_variant(variant(*v)))
+    }
+
+    fn replace_language_alias(&mut self) -> bool {
+        let (key, replacement) = match tables::LANGUAGE_ALIASES
+            .iter()
+            .find(|(key, _)| self.alias_matches(key))
+        {
+            Some(rule) => rule,
+            None => return false,
+        };
+
+        // Matched subtags are replaced, and missing ones are filled in.
+        if key.0 != 0 {
+            self.language = language(replacement.0);
+        }
+        if key.1 != 0 || self.script.is_none() {
+            self.script = script(replacement.1);
+        }
+        if key.2 != 0 || self.region.is_none() {
+            self.region = region(replacement.2);
+        }
+        let mut variants: Vec<Variant> = self
+            .variants()
+            .copied()
+            .filter(|v| !key.3.contains(&u64::from(v)))
+            .collect();
+        variants.extend(replacement.3.iter().copied().map(variant));
+        self.set_variants(&variants);
+        true
+    }
+
+    fn replace_variant_alias(&mut self) -> bool {
+        let mut changed = false;
+        let variants: Vec<Variant> = self
+            .variants()
+            .map(|v| {
+                match tables::VARIANT_ALIASES.binary_search_by_key(&u64::from(v), |(k, _)| *k) {
+                    Ok(idx) => {
+                        changed = true;
+                        variant(tables::VARIANT_ALIASES[idx].1)
+                    }
+                    Err(_) => *v,
+                }
+            })
+            .collect();
+        if changed {
+            self.set_variants(&variants);
+        }
+        changed
+    }
+
+    fn replace_script_alias(&mut self) -> bool {
+        let raw = match self.script {
+            Some(script) => u32::from(script),
+            None => return false,
+        };
+        match tables::SCRIPT_ALIASES.binary_search_by_key(&raw, |(k, _)| *k) {
+            Ok(idx) => {
+                self.script = script(tables::SCRIPT_ALIASES[idx].1);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn replace_region_alias(&mut self) -> bool {
+        let raw = match self.region {
+            Some(region) => u32::from(region),
+            None => return false,
+        };
+        let replacements = match tables::REGION_ALIASES.binary_search_by_key(&raw, |(k, _)| *k) {
+            Ok(idx) => tables::REGION_ALIASES[idx].1,
+            Err(_) => return false,
+        };
+
+        let likely = crate::likelysubtags::maximize(self.language, self.script, None)
+            .and_then(|(_, _, region)| region)
+            .map(u32::from)
+            .filter(|likely| replacements.contains(likely));
+        self.region = region(likely.unwrap_or(replacements[0]));
+        true
+    }
+}
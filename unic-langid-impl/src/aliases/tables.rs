@@ -0,0 +1,830 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static LANGUAGE_ALIASES: [((u64, u32, u32, &[u64]), (u64, u32, u32, &[u64])); 461] = [
+    ((0, 0, 0, &[31088095926445416, 109330211825000]), (0, 0, 0, &[15544222901955681])), // und-hepburn-heploc -> und-alalc97
+    ((24929, 0, 0, &[478492582259]), (7959411, 0, 0, &[])), // aa-saaho -> ssy
+    ((7631457, 0, 0, &[121364542025580]), (7299690, 0, 0, &[])), // art-lojban -> jbo
+    ((7103843, 0, 0, &[29400294206300519]), (6780024, 0, 0, &[])), // cel-gaulish -> xtg
+    ((31080, 0, 0, &[27413494041506401]), (7829864, 0, 0, &[])), // hy-arevmda -> hyw
+    ((28526, 0, 0, &[119165703384930]), (25198, 0, 0, &[])), // no-bokmal -> nb
+    ((28526, 0, 0, &[30244757841017198]), (28270, 0, 0, &[])), // no-nynorsk -> nn
+    ((26746, 0, 0, &[504548521319]), (26746, 0, 0, &[])), // zh-guoyu -> zh
+    ((26746, 0, 0, &[418414027112]), (7037288, 0, 0, &[])), // zh-hakka -> hak
+    ((26746, 0, 0, &[444233509240]), (7238504, 0, 0, &[])), // zh-xiang -> hsn
+    ((0, 0, 0, &[110425243672929]), (0, 0, 22593, &[])), // und-aaland -> und-AX
+    ((0, 0, 0, &[27422255774790241]), (0, 0, 0, &[])), // und-arevela -> und
+    ((0, 0, 0, &[27413494041506401]), (0, 0, 0, &[])), // und-arevmda -> und
+    ((0, 0, 0, &[119165703384930]), (0, 0, 0, &[])), // und-bokmal -> und
+    ((0, 0, 0, &[418414027112]), (0, 0, 0, &[])), // und-hakka -> und
+    ((0, 0, 0, &[121364542025580]), (0, 0, 0, &[])), // und-lojban -> und
+    ((0, 0, 0, &[30244757841017198]), (0, 0, 0, &[])), // und-nynorsk -> und
+    ((0, 0, 0, &[478492582259]), (0, 0, 0, &[])), // und-saaho -> und
+    ((0, 0, 0, &[444233509240]), (0, 0, 0, &[])), // und-xiang -> und
+    ((7235443, 0, 21058, &[]), (7567970, 0, 0, &[])), // sgn-BR -> bzs
+    ((7235443, 0, 20291, &[]), (7238499, 0, 0, &[])), // sgn-CO -> csn
+    ((7235443, 0, 17732, &[]), (6779751, 0, 0, &[])), // sgn-DE -> gsg
+    ((7235443, 0, 19268, &[]), (7107428, 0, 0, &[])), // sgn-DK -> dsl
+    ((7235443, 0, 21317, &[]), (7369587, 0, 0, &[])), // sgn-ES -> ssp
+    ((7235443, 0, 21062, &[]), (7107430, 0, 0, &[])), // sgn-FR -> fsl
+    ((7235443, 0, 16967, &[]), (6907490, 0, 0, &[])), // sgn-GB -> bfi
+    ((7235443, 0, 21063, &[]), (7566183, 0, 0, &[])), // sgn-GR -> gss
+    ((7235443, 0, 17737, &[]), (6779753, 0, 0, &[])), // sgn-IE -> isg
+    ((7235443, 0, 21577, &[]), (6648681, 0, 0, &[])), // sgn-IT -> ise
+    ((7235443, 0, 20554, &[]), (7107434, 0, 0, &[])), // sgn-JP -> jsl
+    ((7235443, 0, 22605, &[]), (7562861, 0, 0, &[])), // sgn-MX -> mfs
+    ((7235443, 0, 18766, &[]), (7562094, 0, 0, &[])), // sgn-NI -> ncs
+    ((7235443, 0, 19534, &[]), (6648676, 0, 0, &[])), // sgn-NL -> dse
+    ((7235443, 0, 20302, &[]), (6910830, 0, 0, &[])), // sgn-NO -> nsi
+    ((7235443, 0, 21584, &[]), (7500656, 0, 0, &[])), // sgn-PT -> psr
+    ((7235443, 0, 17747, &[]), (7108467, 0, 0, &[])), // sgn-SE -> swl
+    ((7235443, 0, 21333, &[]), (6648673, 0, 0, &[])), // sgn-US -> ase
+    ((7235443, 0, 16730, &[]), (7562867, 0, 0, &[])), // sgn-ZA -> sfs
+    ((7168353, 0, 0, &[]), (7561569, 0, 0, &[])), // aam -> aas
+    ((7496033, 0, 0, &[]), (24929, 0, 0, &[])), // aar -> aa
+    ((7037537, 0, 0, &[]), (25185, 0, 0, &[])), // abk -> ab
+    ((7365729, 0, 0, &[]), (31332, 0, 0, &[])), // adp -> dz
+    ((7497313, 0, 0, &[]), (26209, 0, 0, &[])), // afr -> af
+    ((7366497, 0, 0, &[]), (6713441, 0, 0, &[])), // agp -> apf
+    ((7563617, 0, 0, &[]), (6909281, 0, 0, &[])), // ais -> ami
+    ((7629409, 0, 0, &[]), (6448481, 0, 0, &[])), // ajt -> aeb
+    ((7694945, 0, 0, &[]), (6451818, 0, 0, &[])), // aju -> jrb
+    ((6384481, 0, 0, &[]), (27489, 0, 0, &[])), // aka -> ak
+    ((6450273, 0, 0, &[]), (29043, 0, 0, &[])), // alb -> sq
+    ((7564385, 0, 0, &[]), (29043, 0, 0, &[])), // als -> sq
+    ((6843745, 0, 0, &[]), (28001, 0, 0, &[])), // amh -> am
+    ((6386273, 0, 0, &[]), (29281, 0, 0, &[])), // ara -> ar
+    ((6451809, 0, 0, &[]), (29281, 0, 0, &[])), // arb -> ar
+    ((6779489, 0, 0, &[]), (28257, 0, 0, &[])), // arg -> an
+    ((7172705, 0, 0, &[]), (31080, 0, 0, &[])), // arm -> hy
+    ((6583137, 0, 0, &[]), (8023667, 0, 0, &[])), // asd -> snz
+    ((7172961, 0, 0, &[]), (29537, 0, 0, &[])), // asm -> as
+    ((6649185, 0, 0, &[]), (8025195, 0, 0, &[])), // aue -> ktz
+    ((6387297, 0, 0, &[]), (30305, 0, 0, &[])), // ava -> av
+    ((6649441, 0, 0, &[]), (25953, 0, 0, &[])), // ave -> ae
+    ((7174497, 0, 0, &[]), (31073, 0, 0, &[])), // aym -> ay
+    ((7502177, 0, 0, &[]), (31073, 0, 0, &[])), // ayr -> ay
+    ((7895393, 0, 0, &[]), (7239022, 0, 0, &[])), // ayx -> nun
+    ((6650465, 0, 0, &[]), (31329, 0, 0, &[])), // aze -> az
+    ((6978145, 0, 0, &[]), (31329, 0, 0, &[])), // azj -> az
+    ((7037282, 0, 0, &[]), (24930, 0, 0, &[])), // bak -> ba
+    ((7168354, 0, 0, &[]), (28002, 0, 0, &[])), // bam -> bm
+    ((7430498, 0, 0, &[]), (30053, 0, 0, &[])), // baq -> eu
+    ((8020322, 0, 0, &[]), (7304814, 0, 0, &[])), // baz -> nvo
+    ((6513506, 0, 0, &[]), (7102818, 0, 0, &[])), // bcc -> bal
+    ((7103330, 0, 0, &[]), (7039330, 0, 0, &[])), // bcl -> bik
+    ((7103842, 0, 0, &[]), (25954, 0, 0, &[])), // bel -> be
+    ((7234914, 0, 0, &[]), (28258, 0, 0, &[])), // ben -> bn
+    ((7169890, 0, 0, &[]), (6775650, 0, 0, &[])), // bgm -> bcg
+    ((26722, 0, 0, &[]), (7301218, 0, 0, &[])), // bh -> bho
+    ((7039074, 0, 0, &[]), (7103078, 0, 0, &[])), // bhk -> fbl
+    ((6515042, 0, 0, &[]), (7498082, 0, 0, &[])), // bic -> bir
+    ((6842722, 0, 0, &[]), (7301218, 0, 0, &[])), // bih -> bho
+    ((7563618, 0, 0, &[]), (26978, 0, 0, &[])), // bis -> bi
+    ((6580834, 0, 0, &[]), (7107172, 0, 0, &[])), // bjd -> drl
+    ((7432802, 0, 0, &[]), (6519394, 0, 0, &[])), // bjq -> bzc
+    ((6450018, 0, 0, &[]), (7037541, 0, 0, &[])), // bkb -> ebk
+    ((6777954, 0, 0, &[]), (6382185, 0, 0, &[])), // blg -> iba
+    ((6582114, 0, 0, &[]), (28514, 0, 0, &[])), // bod -> bo
+    ((7565154, 0, 0, &[]), (29538, 0, 0, &[])), // bos -> bs
+    ((6648418, 0, 0, &[]), (29282, 0, 0, &[])), // bre -> br
+    ((6452322, 0, 0, &[]), (6448482, 0, 0, &[])), // btb -> beb
+    ((7107938, 0, 0, &[]), (26466, 0, 0, &[])), // bul -> bg
+    ((7501154, 0, 0, &[]), (31085, 0, 0, &[])), // bur -> my
+    ((7043170, 0, 0, &[]), (7959916, 0, 0, &[])), // bxk -> luy
+    ((7501922, 0, 0, &[]), (6387042, 0, 0, &[])), // bxr -> bua
+    ((7627107, 0, 0, &[]), (24931, 0, 0, &[])), // cat -> ca
+    ((7431011, 0, 0, &[]), (6908786, 0, 0, &[])), // ccq -> rki
+    ((7562595, 0, 0, &[]), (29539, 0, 0, &[])), // ces -> cs
+    ((6383715, 0, 0, &[]), (26723, 0, 0, &[])), // cha -> ch
+    ((6645859, 0, 0, &[]), (25955, 0, 0, &[])), // che -> ce
+    ((6908003, 0, 0, &[]), (26746, 0, 0, &[])), // chi -> zh
+    ((7694435, 0, 0, &[]), (30051, 0, 0, &[])), // chu -> cu
+    ((7759971, 0, 0, &[]), (30307, 0, 0, &[])), // chv -> cv
+    ((7498339, 0, 0, &[]), (7171949, 0, 0, &[])), // cjr -> mom
+    ((6384483, 0, 0, &[]), (7499107, 0, 0, &[])), // cka -> cmr
+    ((6581347, 0, 0, &[]), (7502195, 0, 0, &[])), // cld -> syr
+    ((7040355, 0, 0, &[]), (6841208, 0, 0, &[])), // cmk -> xch
+    ((7236963, 0, 0, &[]), (26746, 0, 0, &[])), // cmn -> zh
+    ((7499363, 0, 0, &[]), (29299, 0, 17741, &[])), // cnr -> sr-ME
+    ((7499619, 0, 0, &[]), (30571, 0, 0, &[])), // cor -> kw
+    ((7565155, 0, 0, &[]), (28515, 0, 0, &[])), // cos -> co
+    ((7958371, 0, 0, &[]), (6973808, 0, 0, &[])), // coy -> pij
+    ((7696739, 0, 0, &[]), (6845809, 0, 0, &[])), // cqu -> quh
+    ((6648419, 0, 0, &[]), (29283, 0, 0, &[])), // cre -> cr
+    ((6584163, 0, 0, &[]), (29283, 0, 0, &[])), // cwd -> cr
+    ((7174499, 0, 0, &[]), (31075, 0, 0, &[])), // cym -> cy
+    ((6650467, 0, 0, &[]), (29539, 0, 0, &[])), // cze -> cs
+    ((6709604, 0, 0, &[]), (6975076, 0, 0, &[])), // daf -> dnj
+    ((7233892, 0, 0, &[]), (24932, 0, 0, &[])), // dan -> da
+    ((7364964, 0, 0, &[]), (8022638, 0, 0, &[])), // dap -> njz
+    ((7693668, 0, 0, &[]), (25956, 0, 0, &[])), // deu -> de
+    ((7300964, 0, 0, &[]), (6909796, 0, 0, &[])), // dgo -> doi
+    ((6580324, 0, 0, &[]), (7501677, 0, 0, &[])), // dhd -> mwr
+    ((7039332, 0, 0, &[]), (7235940, 0, 0, &[])), // dik -> din
+    ((7432548, 0, 0, &[]), (6388346, 0, 0, &[])), // diq -> zza
+    ((7629156, 0, 0, &[]), (6711652, 0, 0, &[])), // dit -> dif
+    ((7760228, 0, 0, &[]), (30308, 0, 0, &[])), // div -> dv
+    ((7105124, 0, 0, &[]), (6650468, 0, 0, &[])), // djl -> dze
+    ((7105380, 0, 0, &[]), (6582625, 0, 0, &[])), // dkl -> aqd
+    ((6845028, 0, 0, &[]), (28269, 0, 0, &[])), // drh -> mn
+    ((7500388, 0, 0, &[]), (7043691, 0, 0, &[])), // drr -> kzk
+    ((7828068, 0, 0, &[]), (24934, 0, 17985, &[])), // drw -> fa-AF
+    ((6583652, 0, 0, &[]), (6845557, 0, 0, &[])), // dud -> uth
+    ((6976868, 0, 0, &[]), (7698276, 0, 0, &[])), // duj -> dwu
+    ((7632228, 0, 0, &[]), (27758, 0, 0, &[])), // dut -> nl
+    ((7108452, 0, 0, &[]), (7627364, 0, 0, &[])), // dwl -> dbt
+    ((7305828, 0, 0, &[]), (31332, 0, 0, &[])), // dzo -> dz
+    ((7039845, 0, 0, &[]), (29797, 0, 0, &[])), // ekk -> et
+    ((7105637, 0, 0, &[]), (27749, 0, 0, &[])), // ell -> el
+    ((7367781, 0, 0, &[]), (7433569, 0, 0, &[])), // elp -> amq
+    ((7040357, 0, 0, &[]), (7233901, 0, 0, &[])), // emk -> man
+    ((6778469, 0, 0, &[]), (28261, 0, 0, &[])), // eng -> en
+    ((7303269, 0, 0, &[]), (28517, 0, 0, &[])), // epo -> eo
+    ((7041893, 0, 0, &[]), (27497, 0, 0, &[])), // esk -> ik
+    ((7631717, 0, 0, &[]), (29797, 0, 0, &[])), // est -> et
+    ((7566693, 0, 0, &[]), (30053, 0, 0, &[])), // eus -> eu
+    ((6649701, 0, 0, &[]), (25957, 0, 0, &[])), // ewe -> ee
+    ((7299430, 0, 0, &[]), (28518, 0, 0, &[])), // fao -> fo
+    ((7561574, 0, 0, &[]), (24934, 0, 0, &[])), // fas -> fa
+    ((7627110, 0, 0, &[]), (27489, 0, 0, &[])), // fat -> ak
+    ((6973798, 0, 0, &[]), (27238, 0, 0, &[])), // fij -> fj
+    ((7235942, 0, 0, &[]), (26982, 0, 0, &[])), // fin -> fi
+    ((6386278, 0, 0, &[]), (29286, 0, 0, &[])), // fra -> fr
+    ((6648422, 0, 0, &[]), (29286, 0, 0, &[])), // fre -> fr
+    ((7959142, 0, 0, &[]), (31078, 0, 0, &[])), // fry -> fy
+    ((6518118, 0, 0, &[]), (26214, 0, 0, &[])), // fuc -> ff
+    ((7107942, 0, 0, &[]), (26214, 0, 0, &[])), // ful -> ff
+    ((7758183, 0, 0, &[]), (7759204, 0, 0, &[])), // gav -> dev
+    ((8020327, 0, 0, &[]), (28015, 0, 0, &[])), // gaz -> om
+    ((6513255, 0, 0, &[]), (7958135, 0, 0, &[])), // gbc -> wny
+    ((7299687, 0, 0, &[]), (6451815, 0, 0, &[])), // gbo -> grb
+    ((7300455, 0, 0, &[]), (24939, 0, 0, &[])), // geo -> ka
+    ((7497063, 0, 0, &[]), (25956, 0, 0, &[])), // ger -> de
+    ((7890535, 0, 0, &[]), (6971766, 0, 0, &[])), // gfx -> vaj
+    ((7235431, 0, 0, &[]), (7501415, 0, 0, &[])), // ggn -> gvr
+    ((7300967, 0, 0, &[]), (6779749, 0, 0, &[])), // ggo -> esg
+    ((7497575, 0, 0, &[]), (7697511, 0, 0, &[])), // ggr -> gtu
+    ((7301479, 0, 0, &[]), (7696225, 0, 0, &[])), // gio -> aou
+    ((6384743, 0, 0, &[]), (25703, 0, 0, &[])), // gla -> gd
+    ((6646887, 0, 0, &[]), (24935, 0, 0, &[])), // gle -> ga
+    ((6777959, 0, 0, &[]), (27751, 0, 0, &[])), // glg -> gl
+    ((6909031, 0, 0, &[]), (7043691, 0, 0, &[])), // gli -> kzk
+    ((7760999, 0, 0, &[]), (30311, 0, 0, &[])), // glv -> gv
+    ((7302759, 0, 0, &[]), (7237479, 0, 0, &[])), // gno -> gon
+    ((6648423, 0, 0, &[]), (27749, 0, 0, &[])), // gre -> el
+    ((7238247, 0, 0, &[]), (28263, 0, 0, &[])), // grn -> gn
+    ((6911079, 0, 0, &[]), (6519150, 0, 0, &[])), // gti -> nyc
+    ((6780263, 0, 0, &[]), (28263, 0, 0, &[])), // gug -> gn
+    ((6976871, 0, 0, &[]), (30055, 0, 0, &[])), // guj -> gu
+    ((7763303, 0, 0, &[]), (8025444, 0, 0, &[])), // guv -> duz
+    ((6388071, 0, 0, &[]), (6382183, 0, 0, &[])), // gya -> gba
+    ((7627112, 0, 0, &[]), (29800, 0, 0, &[])), // hat -> ht
+    ((7692648, 0, 0, &[]), (24936, 0, 0, &[])), // hau -> ha
+    ((7561832, 0, 0, &[]), (29299, 1853120844, 0, &[])), // hbs -> sr-Latn
+    ((7234664, 0, 0, &[]), (6906216, 0, 0, &[])), // hdn -> hai
+    ((6382952, 0, 0, &[]), (7236968, 0, 0, &[])), // hea -> hmn
+    ((6448488, 0, 0, &[]), (25960, 0, 0, &[])), // heb -> he
+    ((7497064, 0, 0, &[]), (31336, 0, 0, &[])), // her -> hz
+    ((7170408, 0, 0, &[]), (7893619, 0, 0, &[])), // him -> srx
+    ((7235944, 0, 0, &[]), (26984, 0, 0, &[])), // hin -> hi
+    ((7302504, 0, 0, &[]), (28520, 0, 0, &[])), // hmo -> ho
+    ((7500392, 0, 0, &[]), (7102826, 0, 0, &[])), // hrr -> jal
+    ((7762536, 0, 0, &[]), (29288, 0, 0, &[])), // hrv -> hr
+    ((7239016, 0, 0, &[]), (30056, 0, 0, &[])), // hun -> hu
+    ((6650216, 0, 0, &[]), (31080, 0, 0, &[])), // hye -> hy
+    ((6906473, 0, 0, &[]), (6385775, 0, 0, &[])), // ibi -> opa
+    ((7299689, 0, 0, &[]), (26473, 0, 0, &[])), // ibo -> ig
+    ((6644585, 0, 0, &[]), (29545, 0, 0, &[])), // ice -> is
+    ((7300201, 0, 0, &[]), (28521, 0, 0, &[])), // ido -> io
+    ((6908265, 0, 0, &[]), (26985, 0, 0, &[])), // iii -> ii
+    ((6646633, 0, 0, &[]), (30057, 0, 0, &[])), // ike -> iu
+    ((7695209, 0, 0, &[]), (30057, 0, 0, &[])), // iku -> iu
+    ((6646889, 0, 0, &[]), (25961, 0, 0, &[])), // ile -> ie
+    ((7105641, 0, 0, &[]), (7171177, 0, 0, &[])), // ill -> ilm
+    ((7826537, 0, 0, &[]), (7102823, 0, 0, &[])), // ilw -> gal
+    ((28265, 0, 0, &[]), (25705, 0, 0, &[])), // in -> id
+    ((6385257, 0, 0, &[]), (24937, 0, 0, &[])), // ina -> ia
+    ((6581865, 0, 0, &[]), (25705, 0, 0, &[])), // ind -> id
+    ((7041129, 0, 0, &[]), (27497, 0, 0, &[])), // ipk -> ik
+    ((7107433, 0, 0, &[]), (29545, 0, 0, &[])), // isl -> is
+    ((6386793, 0, 0, &[]), (29801, 0, 0, &[])), // ita -> it
+    ((30569, 0, 0, &[]), (25960, 0, 0, &[])), // iw -> he
+    ((6912617, 0, 0, &[]), (6388325, 0, 0, &[])), // izi -> eza
+    ((7496042, 0, 0, &[]), (7038826, 0, 0, &[])), // jar -> jgk
+    ((7758186, 0, 0, &[]), (30314, 0, 0, &[])), // jav -> jv
+    ((6776170, 0, 0, &[]), (6453615, 0, 0, &[])), // jeg -> oyb
+    ((26986, 0, 0, &[]), (27001, 0, 0, &[])), // ji -> yi
+    ((7237738, 0, 0, &[]), (24938, 0, 0, &[])), // jpn -> ja
+    ((30570, 0, 0, &[]), (30314, 0, 0, &[])), // jw -> jv
+    ((7102827, 0, 0, &[]), (27755, 0, 0, &[])), // kal -> kl
+    ((7233899, 0, 0, &[]), (28267, 0, 0, &[])), // kan -> kn
+    ((7561579, 0, 0, &[]), (29547, 0, 0, &[])), // kas -> ks
+    ((7627115, 0, 0, &[]), (24939, 0, 0, &[])), // kat -> ka
+    ((7692651, 0, 0, &[]), (29291, 0, 0, &[])), // kau -> kr
+    ((8020331, 0, 0, &[]), (27499, 0, 0, &[])), // kaz -> kk
+    ((7758955, 0, 0, &[]), (6581114, 0, 0, &[])), // kdv -> zkd
+    ((6514539, 0, 0, &[]), (6710388, 0, 0, &[])), // kgc -> tdf
+    ((6580075, 0, 0, &[]), (7431022, 0, 0, &[])), // kgd -> ncq
+    ((6842219, 0, 0, &[]), (7105899, 0, 0, &[])), // kgh -> kml
+    ((7039083, 0, 0, &[]), (28269, 0, 0, &[])), // khk -> mn
+    ((7170155, 0, 0, &[]), (28011, 0, 0, &[])), // khm -> km
+    ((7039339, 0, 0, &[]), (26987, 0, 0, &[])), // kik -> ki
+    ((7235947, 0, 0, &[]), (30578, 0, 0, &[])), // kin -> rw
+    ((7498091, 0, 0, &[]), (31083, 0, 0, &[])), // kir -> ky
+    ((7499115, 0, 0, &[]), (30059, 0, 0, &[])), // kmr -> ku
+    ((6516331, 0, 0, &[]), (29291, 0, 0, &[])), // knc -> kr
+    ((6778475, 0, 0, &[]), (26475, 0, 0, &[])), // kng -> kg
+    ((7237227, 0, 0, &[]), (7040875, 0, 0, &[])), // knn -> kok
+    ((6975339, 0, 0, &[]), (7763819, 0, 0, &[])), // koj -> kwv
+    ((7171947, 0, 0, &[]), (30315, 0, 0, &[])), // kom -> kv
+    ((7237483, 0, 0, &[]), (26475, 0, 0, &[])), // kon -> kg
+    ((7499627, 0, 0, &[]), (28523, 0, 0, &[])), // kor -> ko
+    ((7368811, 0, 0, &[]), (7170922, 0, 0, &[])), // kpp -> jkm
+    ((7762027, 0, 0, &[]), (30315, 0, 0, &[])), // kpv -> kv
+    ((7172715, 0, 0, &[]), (6712674, 0, 0, &[])), // krm -> bmf
+    ((7500907, 0, 0, &[]), (7369828, 0, 0, &[])), // ktr -> dtp
+    ((6387051, 0, 0, &[]), (27243, 0, 0, &[])), // kua -> kj
+    ((7501163, 0, 0, &[]), (30059, 0, 0, &[])), // kur -> ku
+    ((7566955, 0, 0, &[]), (6972519, 0, 0, &[])), // kvs -> gdj
+    ((7436139, 0, 0, &[]), (7168377, 0, 0, &[])), // kwq -> yam
+    ((6649963, 0, 0, &[]), (6583924, 0, 0, &[])), // kxe -> tvd
+    ((7108715, 0, 0, &[]), (7697003, 0, 0, &[])), // kxl -> kru
+    ((6847083, 0, 0, &[]), (7104356, 0, 0, &[])), // kzh -> dgl
+    ((6978155, 0, 0, &[]), (7369828, 0, 0, &[])), // kzj -> dtp
+    ((7633515, 0, 0, &[]), (7369828, 0, 0, &[])), // kzt -> dtp
+    ((7037292, 0, 0, &[]), (7369579, 0, 0, &[])), // lak -> ksp
+    ((7299436, 0, 0, &[]), (28524, 0, 0, &[])), // lao -> lo
+    ((7627116, 0, 0, &[]), (24940, 0, 0, &[])), // lat -> la
+    ((7758188, 0, 0, &[]), (30316, 0, 0, &[])), // lav -> lv
+    ((7037548, 0, 0, &[]), (6516322, 0, 0, &[])), // lbk -> bnc
+    ((6776172, 0, 0, &[]), (7106149, 0, 0, &[])), // leg -> enl
+    ((6908268, 0, 0, &[]), (7430514, 0, 0, &[])), // lii -> raq
+    ((7170412, 0, 0, &[]), (26988, 0, 0, &[])), // lim -> li
+    ((7235948, 0, 0, &[]), (28268, 0, 0, &[])), // lin -> ln
+    ((7629164, 0, 0, &[]), (29804, 0, 0, &[])), // lit -> lt
+    ((7302252, 0, 0, &[]), (7628654, 0, 0, &[])), // llo -> ngt
+    ((7171436, 0, 0, &[]), (7892338, 0, 0, &[])), // lmm -> rmx
+    ((8025196, 0, 0, &[]), (25196, 0, 0, &[])), // ltz -> lb
+    ((6452588, 0, 0, &[]), (30060, 0, 0, &[])), // lub -> lu
+    ((6780268, 0, 0, &[]), (26476, 0, 0, &[])), // lug -> lg
+    ((7566956, 0, 0, &[]), (30316, 0, 0, &[])), // lvs -> lv
+    ((6513005, 0, 0, &[]), (27501, 0, 0, &[])), // mac -> mk
+    ((6840685, 0, 0, &[]), (26733, 0, 0, &[])), // mah -> mh
+    ((7102829, 0, 0, &[]), (27757, 0, 0, &[])), // mal -> ml
+    ((7299437, 0, 0, &[]), (26989, 0, 0, &[])), // mao -> mi
+    ((7496045, 0, 0, &[]), (29293, 0, 0, &[])), // mar -> mr
+    ((7954797, 0, 0, &[]), (29549, 0, 0, &[])), // may -> ms
+    ((6776173, 0, 0, &[]), (7498083, 0, 0, &[])), // meg -> cir
+    ((7890797, 0, 0, &[]), (7037546, 0, 0, &[])), // mgx -> jbk
+    ((7497837, 0, 0, &[]), (7170147, 0, 0, &[])), // mhr -> chm
+    ((6581101, 0, 0, &[]), (27501, 0, 0, &[])), // mkd -> mk
+    ((6777965, 0, 0, &[]), (26477, 0, 0, &[])), // mlg -> mg
+    ((7629933, 0, 0, &[]), (29805, 0, 0, &[])), // mlt -> mt
+    ((7040621, 0, 0, &[]), (7233901, 0, 0, &[])), // mnk -> man
+    ((7630445, 0, 0, &[]), (7237239, 0, 0, &[])), // mnt -> wnn
+    ((28525, 0, 0, &[]), (28530, 0, 0, &[])), // mo -> ro
+    ((6713197, 0, 0, &[]), (7630456, 0, 0, &[])), // mof -> xnt
+    ((7106413, 0, 0, &[]), (28530, 0, 0, &[])), // mol -> ro
+    ((7237485, 0, 0, &[]), (28269, 0, 0, &[])), // mon -> mn
+    ((6910573, 0, 0, &[]), (26989, 0, 0, &[])), // mri -> mi
+    ((6386541, 0, 0, &[]), (29549, 0, 0, &[])), // msa -> ms
+    ((7631725, 0, 0, &[]), (7959149, 0, 0, &[])), // mst -> mry
+    ((7370093, 0, 0, &[]), (6971762, 0, 0, &[])), // mup -> raj
+    ((6584173, 0, 0, &[]), (7826788, 0, 0, &[])), // mwd -> dmw
+    ((6977389, 0, 0, &[]), (6971766, 0, 0, &[])), // mwj -> vaj
+    ((6388077, 0, 0, &[]), (31085, 0, 0, &[])), // mya -> my
+    ((6584685, 0, 0, &[]), (6778721, 0, 0, &[])), // myd -> aog
+    ((7633261, 0, 0, &[]), (7959149, 0, 0, &[])), // myt -> mry
+    ((6578542, 0, 0, &[]), (7958136, 0, 0, &[])), // nad -> xny
+    ((7692654, 0, 0, &[]), (24942, 0, 0, &[])), // nau -> na
+    ((7758190, 0, 0, &[]), (30318, 0, 0, &[])), // nav -> nv
+    ((6709870, 0, 0, &[]), (7697006, 0, 0, &[])), // nbf -> nru
+    ((7103086, 0, 0, &[]), (29294, 0, 0, &[])), // nbl -> nr
+    ((7889518, 0, 0, &[]), (6515557, 0, 0, &[])), // nbx -> ekc
+    ((7365486, 0, 0, &[]), (8021099, 0, 0, &[])), // ncp -> kdz
+    ((6644846, 0, 0, &[]), (25710, 0, 0, &[])), // nde -> nd
+    ((7300206, 0, 0, &[]), (26478, 0, 0, &[])), // ndo -> ng
+    ((7365998, 0, 0, &[]), (25966, 0, 0, &[])), // nep -> ne
+    ((6581358, 0, 0, &[]), (27758, 0, 0, &[])), // nld -> nl
+    ((7236718, 0, 0, &[]), (6584929, 0, 0, &[])), // nln -> azd
+    ((7498862, 0, 0, &[]), (7041646, 0, 0, &[])), // nlr -> nrk
+    ((7302766, 0, 0, &[]), (28270, 0, 0, &[])), // nno -> nn
+    ((7564910, 0, 0, &[]), (7496302, 0, 0, &[])), // nns -> nbr
+    ((7892590, 0, 0, &[]), (7759726, 0, 0, &[])), // nnx -> ngv
+    ((6451054, 0, 0, &[]), (25198, 0, 0, &[])), // nob -> nb
+    ((7303022, 0, 0, &[]), (6583396, 0, 0, &[])), // noo -> dtd
+    ((7499630, 0, 0, &[]), (28526, 0, 0, &[])), // nor -> no
+    ((6910062, 0, 0, &[]), (25966, 0, 0, &[])), // npi -> ne
+    ((7566446, 0, 0, &[]), (6973808, 0, 0, &[])), // nts -> pij
+    ((7698542, 0, 0, &[]), (7368802, 0, 0, &[])), // nxu -> bpp
+    ((6388078, 0, 0, &[]), (31086, 0, 0, &[])), // nya -> ny
+    ((6906735, 0, 0, &[]), (25455, 0, 0, &[])), // oci -> oc
+    ((6777455, 0, 0, &[]), (27247, 0, 0, &[])), // ojg -> oj
+    ((6908527, 0, 0, &[]), (27247, 0, 0, &[])), // oji -> oj
+    ((6910575, 0, 0, &[]), (29295, 0, 0, &[])), // ori -> or
+    ((7172719, 0, 0, &[]), (28015, 0, 0, &[])), // orm -> om
+    ((7959151, 0, 0, &[]), (29295, 0, 0, &[])), // ory -> or
+    ((7566191, 0, 0, &[]), (29551, 0, 0, &[])), // oss -> os
+    ((7239023, 0, 0, &[]), (6971766, 0, 0, &[])), // oun -> vaj
+    ((7233904, 0, 0, &[]), (24944, 0, 0, &[])), // pan -> pa
+    ((7627120, 0, 0, &[]), (7501931, 0, 0, &[])), // pat -> kxr
+    ((7692912, 0, 0, &[]), (29552, 0, 0, &[])), // pbu -> ps
+    ((7496560, 0, 0, &[]), (7890017, 0, 0, &[])), // pcr -> adx
+    ((7497072, 0, 0, &[]), (24934, 0, 0, &[])), // per -> fa
+    ((7562608, 0, 0, &[]), (24934, 0, 0, &[])), // pes -> fa
+    ((6909040, 0, 0, &[]), (26992, 0, 0, &[])), // pli -> pi
+    ((7629936, 0, 0, &[]), (26477, 0, 0, &[])), // plt -> mg
+    ((6516080, 0, 0, &[]), (7828840, 0, 0, &[])), // pmc -> huw
+    ((7695728, 0, 0, &[]), (7497840, 0, 0, &[])), // pmu -> phr
+    ((6450800, 0, 0, &[]), (6840684, 0, 0, &[])), // pnb -> lah
+    ((7106416, 0, 0, &[]), (27760, 0, 0, &[])), // pol -> pl
+    ((7499632, 0, 0, &[]), (29808, 0, 0, &[])), // por -> pt
+    ((6385776, 0, 0, &[]), (7956066, 0, 0, &[])), // ppa -> bfy
+    ((7499888, 0, 0, &[]), (7431020, 0, 0, &[])), // ppr -> lcq
+    ((7565936, 0, 0, &[]), (24934, 0, 17985, &[])), // prs -> fa-AF
+    ((7959152, 0, 0, &[]), (7631472, 0, 0, &[])), // pry -> prt
+    ((7566704, 0, 0, &[]), (29552, 0, 0, &[])), // pus -> ps
+    ((8025456, 0, 0, &[]), (6452592, 0, 0, &[])), // puz -> pub
+    ((6649201, 0, 0, &[]), (30065, 0, 0, &[])), // que -> qu
+    ((8025457, 0, 0, &[]), (30065, 0, 0, &[])), // quz -> qu
+    ((7499122, 0, 0, &[]), (7892325, 0, 0, &[])), // rmr -> emx
+    ((7957874, 0, 0, &[]), (7171954, 0, 0, &[])), // rmy -> rom
+    ((6844274, 0, 0, &[]), (28018, 0, 0, &[])), // roh -> rm
+    ((7237490, 0, 0, &[]), (28530, 0, 0, &[])), // ron -> ro
+    ((7173490, 0, 0, &[]), (28530, 0, 0, &[])), // rum -> ro
+    ((7239026, 0, 0, &[]), (28274, 0, 0, &[])), // run -> rn
+    ((7566706, 0, 0, &[]), (30066, 0, 0, &[])), // rus -> ru
+    ((6775155, 0, 0, &[]), (26483, 0, 0, &[])), // sag -> sg
+    ((7233907, 0, 0, &[]), (24947, 0, 0, &[])), // san -> sa
+    ((7364979, 0, 0, &[]), (7631201, 0, 0, &[])), // sap -> aqt
+    ((6382451, 0, 0, &[]), (6646888, 0, 0, &[])), // sca -> hle
+    ((6513523, 0, 0, &[]), (29299, 0, 0, &[])), // scc -> sr
+    ((7496563, 0, 0, &[]), (29288, 0, 0, &[])), // scr -> hr
+    ((7104371, 0, 0, &[]), (7041897, 0, 0, &[])), // sgl -> isk
+    ((26739, 0, 0, &[]), (29299, 1853120844, 0, &[])), // sh -> sr-Latn
+    ((7235955, 0, 0, &[]), (26995, 0, 0, &[])), // sin -> si
+    ((7039859, 0, 0, &[]), (6453615, 0, 0, &[])), // skk -> oyb
+    ((7040115, 0, 0, &[]), (27507, 0, 0, &[])), // slk -> sk
+    ((7302259, 0, 0, &[]), (27507, 0, 0, &[])), // slo -> sk
+    ((7761011, 0, 0, &[]), (27763, 0, 0, &[])), // slv -> sl
+    ((6581619, 0, 0, &[]), (6450539, 0, 0, &[])), // smd -> kmb
+    ((6647155, 0, 0, &[]), (25971, 0, 0, &[])), // sme -> se
+    ((7302515, 0, 0, &[]), (28019, 0, 0, &[])), // smo -> sm
+    ((6385267, 0, 0, &[]), (28275, 0, 0, &[])), // sna -> sn
+    ((6450803, 0, 0, &[]), (6382185, 0, 0, &[])), // snb -> iba
+    ((6581875, 0, 0, &[]), (25715, 0, 0, &[])), // snd -> sd
+    ((7171955, 0, 0, &[]), (28531, 0, 0, &[])), // som -> so
+    ((7630707, 0, 0, &[]), (29811, 0, 0, &[])), // sot -> st
+    ((6385779, 0, 0, &[]), (29541, 0, 0, &[])), // spa -> es
+    ((7958643, 0, 0, &[]), (7236715, 0, 0, &[])), // spy -> kln
+    ((6910323, 0, 0, &[]), (29043, 0, 0, &[])), // sqi -> sq
+    ((6517363, 0, 0, &[]), (25459, 0, 0, &[])), // src -> sc
+    ((6582899, 0, 0, &[]), (25459, 0, 0, &[])), // srd -> sc
+    ((7369331, 0, 0, &[]), (29299, 0, 0, &[])), // srp -> sr
+    ((7828339, 0, 0, &[]), (29555, 0, 0, &[])), // ssw -> ss
+    ((7107955, 0, 0, &[]), (6580083, 0, 0, &[])), // sul -> sgd
+    ((7173491, 0, 0, &[]), (7826549, 0, 0, &[])), // sum -> ulw
+    ((7239027, 0, 0, &[]), (30067, 0, 0, &[])), // sun -> su
+    ((6387571, 0, 0, &[]), (30579, 0, 0, &[])), // swa -> sw
+    ((6518643, 0, 0, &[]), (30579, 0, 17475, &[])), // swc -> sw-CD
+    ((6649715, 0, 0, &[]), (30323, 0, 0, &[])), // swe -> sv
+    ((6846323, 0, 0, &[]), (30579, 0, 0, &[])), // swh -> sw
+    ((6840692, 0, 0, &[]), (31092, 0, 0, &[])), // tah -> ty
+    ((7168372, 0, 0, &[]), (24948, 0, 0, &[])), // tam -> ta
+    ((7627124, 0, 0, &[]), (29812, 0, 0, &[])), // tat -> tt
+    ((7693428, 0, 0, &[]), (7369828, 0, 0, &[])), // tdu -> dtp
+    ((7103860, 0, 0, &[]), (25972, 0, 0, &[])), // tel -> te
+    ((6776692, 0, 0, &[]), (7367266, 0, 0, &[])), // tgg -> bjp
+    ((7038836, 0, 0, &[]), (26484, 0, 0, &[])), // tgk -> tg
+    ((7104372, 0, 0, &[]), (7104870, 0, 0, &[])), // tgl -> fil
+    ((6383732, 0, 0, &[]), (26740, 0, 0, &[])), // tha -> th
+    ((6514804, 0, 0, &[]), (7303284, 0, 0, &[])), // thc -> tpo
+    ((7825524, 0, 0, &[]), (6384751, 0, 0, &[])), // thw -> ola
+    ((7891060, 0, 0, &[]), (6453615, 0, 0, &[])), // thx -> oyb
+    ((6449524, 0, 0, &[]), (28514, 0, 0, &[])), // tib -> bo
+    ((6580596, 0, 0, &[]), (6583401, 0, 0, &[])), // tid -> itd
+    ((6646132, 0, 0, &[]), (7561586, 0, 0, &[])), // tie -> ras
+    ((7498100, 0, 0, &[]), (26996, 0, 0, &[])), // tir -> ti
+    ((7039860, 0, 0, &[]), (7174004, 0, 0, &[])), // tkk -> twm
+    ((27764, 0, 0, &[]), (7104870, 0, 0, &[])), // tl -> fil
+    ((7826548, 0, 0, &[]), (7300471, 0, 0, &[])), // tlw -> weo
+    ((7368052, 0, 0, &[]), (6977908, 0, 0, &[])), // tmp -> tyj
+    ((6647412, 0, 0, &[]), (7037291, 0, 0, &[])), // tne -> kak
+    ((6712948, 0, 0, &[]), (24934, 0, 17985, &[])), // tnf -> fa-AF
+    ((7237492, 0, 0, &[]), (28532, 0, 0, &[])), // ton -> to
+    ((6714228, 0, 0, &[]), (6971764, 0, 0, &[])), // tsf -> taj
+    ((7238516, 0, 0, &[]), (28276, 0, 0, &[])), // tsn -> tn
+    ((7304052, 0, 0, &[]), (29556, 0, 0, &[])), // tso -> ts
+    ((7435380, 0, 0, &[]), (6843764, 0, 0, &[])), // ttq -> tmh
+    ((7042420, 0, 0, &[]), (27508, 0, 0, &[])), // tuk -> tk
+    ((7501172, 0, 0, &[]), (29300, 0, 0, &[])), // tur -> tr
+    ((30580, 0, 0, &[]), (27489, 0, 0, &[])), // tw -> ak
+    ((6911860, 0, 0, &[]), (27489, 0, 0, &[])), // twi -> ak
+    ((6777205, 0, 0, &[]), (26485, 0, 0, &[])), // uig -> ug
+    ((7498613, 0, 0, &[]), (27509, 0, 0, &[])), // ukr -> uk
+    ((7695733, 0, 0, &[]), (7103844, 0, 0, &[])), // umu -> del
+    ((7368309, 0, 0, &[]), (7303799, 0, 0, &[])), // unp -> wro
+    ((7040885, 0, 0, &[]), (6384997, 0, 0, &[])), // uok -> ema
+    ((6582901, 0, 0, &[]), (29301, 0, 0, &[])), // urd -> ur
+    ((6453877, 0, 0, &[]), (31349, 0, 0, &[])), // uzb -> uz
+    ((7240309, 0, 0, &[]), (31349, 0, 0, &[])), // uzn -> uz
+    ((7234934, 0, 0, &[]), (25974, 0, 0, &[])), // ven -> ve
+    ((6646134, 0, 0, &[]), (26998, 0, 0, &[])), // vie -> vi
+    ((7106422, 0, 0, &[]), (28534, 0, 0, &[])), // vol -> vo
+    ((7103863, 0, 0, &[]), (31075, 0, 0, &[])), // wel -> cy
+    ((7825271, 0, 0, &[]), (6449015, 0, 0, &[])), // wgw -> wgb
+    ((7629175, 0, 0, &[]), (7106414, 0, 0, &[])), // wit -> nol
+    ((7825783, 0, 0, &[]), (7305070, 0, 0, &[])), // wiw -> nwo
+    ((7236727, 0, 0, &[]), (24951, 0, 0, &[])), // wln -> wa
+    ((7106423, 0, 0, &[]), (28535, 0, 0, &[])), // wol -> wo
+    ((6382200, 0, 0, &[]), (7889251, 0, 0, &[])), // xba -> cax
+    ((7301240, 0, 0, &[]), (26744, 0, 0, &[])), // xho -> xh
+    ((6383992, 0, 0, &[]), (7234401, 0, 0, &[])), // xia -> acn
+    ((6843256, 0, 0, &[]), (7823735, 0, 0, &[])), // xkh -> waw
+    ((6647928, 0, 0, &[]), (6647915, 0, 0, &[])), // xpe -> kpe
+    ((7434872, 0, 0, &[]), (7826788, 0, 0, &[])), // xrq -> dmw
+    ((6976376, 0, 0, &[]), (6976883, 0, 0, &[])), // xsj -> suj
+    ((7107448, 0, 0, &[]), (7234916, 0, 0, &[])), // xsl -> den
+    ((6578809, 0, 0, &[]), (6908786, 0, 0, &[])), // ybd -> rki
+    ((6579321, 0, 0, &[]), (27001, 0, 0, &[])), // ydd -> yi
+    ((7234937, 0, 0, &[]), (7433849, 0, 0, &[])), // yen -> ynq
+    ((6580601, 0, 0, &[]), (27001, 0, 0, &[])), // yid -> yi
+    ((7956857, 0, 0, &[]), (7172729, 0, 0, &[])), // yiy -> yrm
+    ((6385017, 0, 0, &[]), (7500396, 0, 0, &[])), // yma -> lrr
+    ((7630201, 0, 0, &[]), (7173229, 0, 0, &[])), // ymt -> mtm
+    ((7499641, 0, 0, &[]), (28537, 0, 0, &[])), // yor -> yo
+    ((7565177, 0, 0, &[]), (7171962, 0, 0, &[])), // yos -> zom
+    ((7697785, 0, 0, &[]), (6780281, 0, 0, &[])), // yuu -> yug
+    ((6906234, 0, 0, &[]), (7364986, 0, 0, &[])), // zai -> zap
+    ((6383738, 0, 0, &[]), (24954, 0, 0, &[])), // zha -> za
+    ((7301242, 0, 0, &[]), (26746, 0, 0, &[])), // zho -> zh
+    ((7498106, 0, 0, &[]), (7758707, 0, 0, &[])), // zir -> scv
+    ((7172986, 0, 0, &[]), (29549, 0, 0, &[])), // zsm -> ms
+    ((7107962, 0, 0, &[]), (30074, 0, 0, &[])), // zul -> zu
+    ((6453626, 0, 0, &[]), (24954, 0, 0, &[])), // zyb -> za
+];
+
+#[rustfmt::skip]
+pub static SCRIPT_ALIASES: [(u32, u32); 1] = [
+    (1767989585, 1752066394), // Qaai -> Zinh
+];
+
+#[rustfmt::skip]
+pub static REGION_ALIASES: [(u32, &[u32]); 335] = [
+    (17232, &[19782, 18509, 20557, 22352]), // PC -> FM MH MP PW
+    (17476, &[17732]), // DD -> DE
+    (17494, &[20054]), // VD -> VN
+    (17497, &[17753]), // YD -> YE
+    (18510, &[21846]), // NH -> VU
+    (18514, &[22362]), // RH -> ZW
+    (18765, &[19797]), // MI -> UM
+    (19285, &[16967]), // UK -> GB
+    (19287, &[19797]), // WK -> UM
+    (20033, &[22339, 22611, 20802]), // AN -> CW SX BQ
+    (20564, &[19540]), // TP -> TL
+    (20806, &[20801, 18004]), // FQ -> AQ TF
+    (20814, &[20801]), // NQ -> AQ
+    (21082, &[17475]), // ZR -> CD
+    (21315, &[21330, 17741]), // CS -> RS ME
+    (21571, &[18763]), // CT -> KI
+    (21578, &[19797]), // JT -> UM
+    (21582, &[16723, 20809]), // NT -> SA IQ
+    (21826, &[19789]), // BU -> MM
+    (21840, &[19797]), // PU -> UM
+    (21841, &[21829]), // QU -> EU
+    (21843, &[21842, 19777, 23105, 22850, 17733, 17735, 23115, 18251, 22092, 21580, 17485, 19028, 19796, 16725, 23125]), // SU -> RU AM AZ BY EE GE KZ KG LV LT MD TJ TM UA UZ
+    (21849, &[21330, 17741]), // YU -> RS ME
+    (22088, &[17986]), // HV -> BF
+    (22598, &[21062]), // FX -> FR
+    (22852, &[19010]), // DY -> BJ
+    (23120, &[16720]), // PZ -> PA
+    (3158065, &[18242]), // 100 -> BG
+    (3158066, &[23107, 19283]), // 200 -> CZ SK
+    (3158067, &[21063]), // 300 -> GR
+    (3158068, &[20298]), // 400 -> JO
+    (3158069, &[21325]), // 500 -> MS
+    (3158070, &[22864]), // 600 -> PY
+    (3158072, &[18261]), // 800 -> UG
+    (3158320, &[20801]), // 010 -> AQ
+    (3158324, &[21067]), // 410 -> KR
+    (3158327, &[16730]), // 710 -> ZA
+    (3158328, &[21842, 19777, 23105, 22850, 17733, 17735, 23115, 18251, 22092, 21580, 17485, 19028, 19796, 16725, 23125]), // 810 -> RU AM AZ BY EE GE KZ KG LV LT MD TJ TM UA UZ
+    (3158576, &[17473]), // 020 -> AD
+    (3158577, &[19779]), // 120 -> CM
+    (3158579, &[21575]), // 320 -> GT
+    (3158581, &[21070]), // 520 -> NR
+    (3158582, &[21584]), // 620 -> PT
+    (3158583, &[17753]), // 720 -> YE
+    (3158834, &[21573]), // 230 -> ET
+    (3158836, &[21068]), // 430 -> LR
+    (3158837, &[22339, 22611, 20802]), // 530 -> CW SX BQ
+    (3158838, &[21072]), // 630 -> PR
+    (3158840, &[17738, 18247]), // 830 -> JE GG
+    (3159088, &[21569]), // 040 -> AT
+    (3159089, &[17987]), // 140 -> CF
+    (3159091, &[20040]), // 340 -> HN
+    (3159092, &[21580]), // 440 -> LT
+    (3159093, &[17230]), // 540 -> NC
+    (3159095, &[21075]), // 740 -> SR
+    (3159096, &[21333]), // 840 -> US
+    (3159344, &[17474]), // 050 -> BD
+    (3159346, &[21062]), // 250 -> FR
+    (3159348, &[18253]), // 450 -> MG
+    (3159352, &[18774]), // 850 -> VI
+    (3159600, &[19778]), // 060 -> BM
+    (3159602, &[18004]), // 260 -> TF
+    (3159603, &[17481]), // 360 -> ID
+    (3159606, &[18753]), // 660 -> AI
+    (3159607, &[22867]), // 760 -> SY
+    (3159608, &[23125]), // 860 -> UZ
+    (3159609, &[20049]), // 960 -> QN
+    (3159856, &[16706]), // 070 -> BA
+    (3159857, &[20291]), // 170 -> CO
+    (3159858, &[19783]), // 270 -> GM
+    (3159860, &[21581]), // 470 -> MT
+    (3159861, &[21838]), // 570 -> NU
+    (3159862, &[17238]), // 670 -> VC
+    (3159865, &[22609]), // 970 -> QX
+    (3160113, &[17475]), // 180 -> CD
+    (3160114, &[17732]), // 280 -> DE
+    (3160115, &[21577]), // 380 -> IT
+    (3160116, &[21837]), // 480 -> MU
+    (3160117, &[20557]), // 580 -> MP
+    (3160119, &[21588]), // 780 -> TT
+    (3160121, &[18520]), // 980 -> XH
+    (3160368, &[16979]), // 090 -> SB
+    (3160374, &[17235]), // 690 -> SC
+    (3160376, &[21330, 17741, 18771, 21064, 19277, 16706]), // 890 -> RS ME SI HR MK BA
+    (3160377, &[21080]), // 990 -> XR
+    (3224368, &[23105]), // 031 -> AZ
+    (3224370, &[21573]), // 231 -> ET
+    (3224373, &[22339]), // 531 -> CW
+    (3224376, &[18247]), // 831 -> GG
+    (3224880, &[19777]), // 051 -> AM
+    (3225401, &[22865]), // 971 -> QY
+    (3225653, &[19797]), // 581 -> UM
+    (3225657, &[18776]), // 981 -> XI
+    (3225905, &[21064]), // 191 -> HR
+    (3225909, &[16720]), // 591 -> PA
+    (3225912, &[21330, 17741]), // 891 -> RS ME
+    (3225913, &[21336]), // 991 -> XS
+    (3289143, &[18259]), // 702 -> SG
+    (3289392, &[23108]), // 012 -> DZ
+    (3289393, &[22850]), // 112 -> BY
+    (3289394, &[19780]), // 212 -> DM
+    (3289395, &[20551]), // 312 -> GP
+    (3289397, &[19791]), // 512 -> OM
+    (3289398, &[20048]), // 612 -> PN
+    (3289650, &[22099]), // 222 -> SV
+    (3289652, &[16972]), // 422 -> LB
+    (3289904, &[21057]), // 032 -> AR
+    (3289905, &[22083]), // 132 -> CV
+    (3289906, &[21061]), // 232 -> ER
+    (3289907, &[21576]), // 332 -> HT
+    (3289909, &[22339, 22611, 20802]), // 532 -> CW SX BQ
+    (3289911, &[18501]), // 732 -> EH
+    (3289912, &[17738]), // 832 -> JE
+    (3290162, &[19014]), // 242 -> FJ
+    (3290164, &[21836]), // 442 -> LU
+    (3290166, &[20306]), // 642 -> RO
+    (3290416, &[16962]), // 052 -> BB
+    (3290417, &[19523]), // 152 -> CL
+    (3290419, &[21321]), // 352 -> IS
+    (3290422, &[19522]), // 652 -> BL
+    (3290423, &[17747]), // 752 -> SE
+    (3290672, &[3420976, 3355697]), // 062 -> 034 143
+    (3290673, &[22595]), // 162 -> CX
+    (3290674, &[19012]), // 262 -> DJ
+    (3290676, &[22093]), // 462 -> MV
+    (3290677, &[17742]), // 562 -> NE
+    (3290678, &[17228]), // 662 -> LC
+    (3290679, &[19028]), // 762 -> TJ
+    (3290680, &[17750]), // 862 -> VE
+    (3290681, &[20561]), // 962 -> QP
+    (3290928, &[22338]), // 072 -> BW
+    (3290929, &[21842, 19777, 23105, 22850, 17735, 18251, 23115, 17485, 19028, 19796, 16725, 23125]), // 172 -> RU AM AZ BY GE KG KZ MD TJ TM UA UZ
+    (3290931, &[17737]), // 372 -> IE
+    (3290935, &[19284]), // 772 -> TK
+    (3290937, &[23121]), // 972 -> QZ
+    (3291189, &[19782, 18509, 20557, 22352]), // 582 -> FM MH MP PW
+    (3291190, &[16723]), // 682 -> SA
+    (3291192, &[21335]), // 882 -> WS
+    (3291193, &[19032]), // 982 -> XJ
+    (3291440, &[18262]), // 092 -> VG
+    (3291441, &[21827]), // 192 -> CU
+    (3291442, &[18759]), // 292 -> GI
+    (3291443, &[20554]), // 392 -> JP
+    (3291444, &[17229]), // 492 -> MC
+    (3291447, &[21076]), // 792 -> TR
+    (3291449, &[21592]), // 992 -> XT
+    (3354674, &[23107]), // 203 -> CZ
+    (3354679, &[19283]), // 703 -> SK
+    (3355442, &[17733]), // 233 -> EE
+    (3355445, &[22337]), // 533 -> AW
+    (3355448, &[19785]), // 833 -> IM
+    (3355702, &[21842]), // 643 -> RU
+    (3356214, &[17997]), // 663 -> MF
+    (3356217, &[20817]), // 963 -> QQ
+    (3356473, &[16728]), // 973 -> XA
+    (3356725, &[19782]), // 583 -> FM
+    (3356729, &[19288]), // 983 -> XK
+    (3356985, &[21848]), // 993 -> XU
+    (3420208, &[17985]), // 004 -> AF
+    (3420209, &[19789]), // 104 -> MM
+    (3420210, &[19010]), // 204 -> BJ
+    (3420211, &[19527]), // 304 -> GL
+    (3420212, &[17739]), // 404 -> KE
+    (3420213, &[16717]), // 504 -> MA
+    (3420214, &[17744]), // 604 -> PE
+    (3420215, &[20054]), // 704 -> VN
+    (3420216, &[16725]), // 804 -> UA
+    (3420466, &[20292]), // 214 -> DO
+    (3420468, &[22347]), // 414 -> KW
+    (3420720, &[20289]), // 024 -> AO
+    (3420721, &[16707]), // 124 -> CA
+    (3420723, &[20039]), // 324 -> GN
+    (3420725, &[20558]), // 524 -> NP
+    (3420726, &[22343]), // 624 -> GW
+    (3420727, &[21317]), // 724 -> ES
+    (3420978, &[20294]), // 234 -> FO
+    (3420979, &[19784]), // 334 -> HM
+    (3420980, &[22860]), // 434 -> LY
+    (3420981, &[22611]), // 534 -> SX
+    (3420982, &[16721]), // 634 -> QA
+    (3420984, &[23124]), // 834 -> TZ
+    (3421232, &[21314]), // 044 -> BS
+    (3421233, &[19276]), // 144 -> LK
+    (3421235, &[19272]), // 344 -> HK
+    (3421239, &[19027]), // 744 -> SJ
+    (3421490, &[17991]), // 254 -> GF
+    (3421492, &[22349]), // 454 -> MW
+    (3421493, &[23118]), // 554 -> NZ
+    (3421494, &[18515]), // 654 -> SH
+    (3421496, &[17986]), // 854 -> BF
+    (3421744, &[21570]), // 064 -> BT
+    (3421747, &[21065]), // 364 -> IR
+    (3421751, &[18516]), // 764 -> TH
+    (3421753, &[21073]), // 964 -> QR
+    (3422000, &[22082]), // 074 -> BV
+    (3422001, &[19787]), // 174 -> KM
+    (3422004, &[20813]), // 474 -> MQ
+    (3422005, &[17998]), // 574 -> NF
+    (3422006, &[19795]), // 674 -> SM
+    (3422009, &[16984]), // 974 -> XB
+    (3422256, &[23106]), // 084 -> BZ
+    (3422257, &[19267]), // 184 -> CK
+    (3422259, &[18755]), // 384 -> CI
+    (3422260, &[22605]), // 484 -> MX
+    (3422261, &[18509]), // 584 -> MH
+    (3422263, &[17729]), // 784 -> AE
+    (3422265, &[19544]), // 984 -> XL
+    (3422518, &[19539]), // 694 -> SL
+    (3422520, &[19802]), // 894 -> ZM
+    (3422521, &[22104]), // 994 -> XV
+    (3485751, &[18771]), // 705 -> SI
+    (3486517, &[20802]), // 535 -> BQ
+    (3487289, &[21329]), // 965 -> QS
+    (3487537, &[21593]), // 175 -> YT
+    (3487538, &[21328]), // 275 -> PS
+    (3487545, &[17240]), // 975 -> XC
+    (3487797, &[22352]), // 585 -> PW
+    (3487801, &[19800]), // 985 -> XM
+    (3488055, &[19796]), // 795 -> TM
+    (3488057, &[22360]), // 995 -> XW
+    (3551287, &[20307]), // 706 -> SO
+    (3551536, &[21313]), // 016 -> AS
+    (3551537, &[18507]), // 116 -> KH
+    (3551539, &[21831]), // 316 -> GU
+    (3551541, &[16718]), // 516 -> NA
+    (3551542, &[19536]), // 616 -> PL
+    (3551543, &[22362]), // 716 -> ZW
+    (3551794, &[20807]), // 226 -> GQ
+    (3551796, &[21324]), // 426 -> LS
+    (3551798, &[19540]), // 626 -> TL
+    (3551800, &[16967]), // 826 -> GB
+    (3552048, &[21825]), // 036 -> AU
+    (3552049, &[22859]), // 136 -> KY
+    (3552051, &[16726]), // 336 -> VA
+    (3552053, &[16723, 20809]), // 536 -> SA IQ
+    (3552055, &[17491]), // 736 -> SD
+    (3552306, &[18758]), // 246 -> FI
+    (3552308, &[20301]), // 446 -> MO
+    (3552310, &[22354]), // 646 -> RW
+    (3552560, &[17730]), // 056 -> BE
+    (3552561, &[20035]), // 156 -> CN
+    (3552563, &[20041]), // 356 -> IN
+    (3552567, &[18499]), // 756 -> CH
+    (3552817, &[17219]), // 166 -> CC
+    (3552818, &[16711]), // 266 -> GA
+    (3552820, &[19533]), // 466 -> ML
+    (3552821, &[18254]), // 566 -> NG
+    (3552822, &[19792]), // 666 -> PM
+    (3552825, &[21585]), // 966 -> QT
+    (3553072, &[21058]), // 076 -> BR
+    (3553074, &[17732]), // 276 -> DE
+    (3553075, &[19529]), // 376 -> IL
+    (3553079, &[20308]), // 776 -> TO
+    (3553080, &[18007]), // 876 -> WF
+    (3553081, &[17496]), // 976 -> XD
+    (3553328, &[20297]), // 086 -> IO
+    (3553333, &[19280]), // 586 -> PK
+    (3553334, &[20051]), // 686 -> SN
+    (3553336, &[17753]), // 886 -> YE
+    (3553337, &[20056]), // 986 -> XN
+    (3553584, &[20034]), // 096 -> BN
+    (3553585, &[22851]), // 196 -> CY
+    (3553586, &[18763]), // 296 -> KI
+    (3553588, &[20045]), // 496 -> MN
+    (3553591, &[17236]), // 796 -> TC
+    (3553593, &[22616]), // 996 -> XX
+    (3616824, &[19277]), // 807 -> MK
+    (3617076, &[18251]), // 417 -> KG
+    (3618361, &[21829]), // 967 -> EU
+    (3618617, &[17752]), // 977 -> XE
+    (3618872, &[17753]), // 887 -> YE
+    (3618873, &[20312]), // 987 -> XO
+    (3619129, &[22872]), // 997 -> XY
+    (3682352, &[19521]), // 008 -> AL
+    (3682353, &[18754]), // 108 -> BI
+    (3682354, &[19268]), // 208 -> DK
+    (3682355, &[17479]), // 308 -> GD
+    (3682356, &[20555]), // 408 -> KP
+    (3682357, &[23117]), // 508 -> MZ
+    (3682358, &[18512]), // 608 -> PH
+    (3682610, &[17221]), // 218 -> EC
+    (3682612, &[16716]), // 418 -> LA
+    (3682616, &[18245]), // 818 -> EG
+    (3682864, &[18241]), // 028 -> AG
+    (3682867, &[22855]), // 328 -> GY
+    (3682868, &[22092]), // 428 -> LV
+    (3682869, &[19534]), // 528 -> NL
+    (3682871, &[21331]), // 728 -> SS
+    (3683122, &[19270]), // 238 -> FK
+    (3683124, &[18764]), // 438 -> LI
+    (3683126, &[17746]), // 638 -> RE
+    (3683376, &[18498]), // 048 -> BH
+    (3683377, &[17492]), // 148 -> TD
+    (3683378, &[22593]), // 248 -> AX
+    (3683379, &[21832]), // 348 -> HU
+    (3683381, &[21846]), // 548 -> VU
+    (3683383, &[23123]), // 748 -> SZ
+    (3683633, &[22356]), // 158 -> TW
+    (3683634, &[18000]), // 258 -> PF
+    (3683636, &[22861]), // 458 -> MY
+    (3683637, &[18766]), // 558 -> NI
+    (3683640, &[22869]), // 858 -> UY
+    (3683641, &[16705]), // 958 -> AA
+    (3683888, &[20290]), // 068 -> BO
+    (3683890, &[17735]), // 268 -> GE
+    (3683891, &[20809]), // 368 -> IQ
+    (3683895, &[18260]), // 768 -> TG
+    (3683897, &[22097]), // 968 -> QV
+    (3684145, &[18243]), // 178 -> CG
+    (3684146, &[17732]), // 278 -> DE
+    (3684148, &[21069]), // 478 -> MR
+    (3684149, &[20302]), // 578 -> NO
+    (3684150, &[21587]), // 678 -> ST
+    (3684153, &[18008]), // 978 -> XF
+    (3684401, &[21059]), // 188 -> CR
+    (3684402, &[18503]), // 288 -> GH
+    (3684403, &[19786]), // 388 -> JM
+    (3684406, &[21330]), // 688 -> RS
+    (3684407, &[20052]), // 788 -> TN
+    (3684409, &[20568]), // 988 -> XP
+    (3684659, &[23115]), // 398 -> KZ
+    (3684660, &[17485]), // 498 -> MD
+    (3684661, &[18256]), // 598 -> PG
+    (3684663, &[22100]), // 798 -> TV
+    (3684665, &[23128]), // 998 -> XZ
+    (3748407, &[17491]), // 729 -> SD
+    (3748658, &[21319]), // 239 -> GS
+    (3748914, &[21062]), // 249 -> FR
+    (3749174, &[20043]), // 659 -> KN
+    (3749177, &[19793]), // 959 -> QM
+    (3749433, &[22353]), // 969 -> QW
+    (3749689, &[18264]), // 979 -> XG
+    (3749945, &[20824]), // 989 -> XQ
+    (3750196, &[17741]), // 499 -> ME
+    (3750201, &[23130]), // 999 -> ZZ
+];
+
+#[rustfmt::skip]
+pub static VARIANT_ALIASES: [(u64, u64); 2] = [
+    (109330211825000, 15544222901955681), // heploc -> alalc97
+    (7597132167464644464, 31084793482211184), // polytoni -> polyton
+];
+
+#[rustfmt::skip]
+pub static KEYWORD_TYPE_ALIASES: [(&str, &str, &str); 8] = [
+    ("ca", "islamicc", "islamic-civil"),
+    ("tz", "aqams", "nzakl"),
+    ("tz", "camtr", "cator"),
+    ("tz", "cnckg", "cnsha"),
+    ("tz", "cnhrb", "cnsha"),
+    ("tz", "cnkhg", "cnurc"),
+    ("tz", "gaza", "gazastrp"),
+    ("tz", "usnavajo", "usden"),
+];
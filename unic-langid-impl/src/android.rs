@@ -0,0 +1,287 @@
+//! Conversion between `LanguageIdentifier` and Android resource qualifiers.
+//!
+//! Android selects resources by language using one of two qualifier forms:
+//!
+//!  * the classic form, `language[-rREGION]`, for example `zh-rTW`,
+//!  * the BCP 47 form, `b+` followed by subtags separated with `+`,
+//!    for example `b+sr+Latn+RS`.
+//!
+//! The classic form uses the legacy ISO 639 codes `in`, `iw` and `ji`
+//! for Indonesian, Hebrew and Yiddish, which are mapped to `id`, `he` and `yi`.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::android::{from_android_dir, to_android_dir};
+//!
+//! let langid = from_android_dir("values-b+sr+Latn+RS").expect("Conversion failed.");
+//! assert_eq!(langid.to_string(), "sr-Latn-RS");
+//!
+//! let langid = from_android_dir("values-in").expect("Conversion failed.");
+//! assert_eq!(langid.to_string(), "id");
+//!
+//! assert_eq!(to_android_dir("values", &langid), "values-in");
+//! ```
+use crate::parser::{ParserError, SubtagKind};
+use crate::{subtags, LanguageIdentifier, LanguageIdentifierError};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Legacy language codes of the classic form and their current equivalents.
+static LEGACY_LANGUAGES: &[(&str, &str)] = &[("in", "id"), ("iw", "he"), ("ji", "yi")];
+
+/// Qualifiers with a fixed name which may follow the language qualifier,
+/// sorted. `car` looks like a language and is special cased by Android too.
+static NON_LOCALE_QUALIFIERS: &[&str] = &[
+    "12key",
+    "anydpi",
+    "appliance",
+    "car",
+    "desk",
+    "dpad",
+    "finger",
+    "hdpi",
+    "highdr",
+    "keysexposed",
+    "keyshidden",
+    "keyssoft",
+    "land",
+    "large",
+    "ldltr",
+    "ldpi",
+    "ldrtl",
+    "long",
+    "lowdr",
+    "mdpi",
+    "navexposed",
+    "navhidden",
+    "night",
+    "nodpi",
+    "nokeys",
+    "nonav",
+    "normal",
+    "notlong",
+    "notnight",
+    "notouch",
+    "notround",
+    "nowidecg",
+    "port",
+    "qwerty",
+    "round",
+    "small",
+    "square",
+    "stylus",
+    "television",
+    "trackball",
+    "tvdpi",
+    "vrheadset",
+    "watch",
+    "wheel",
+    "widecg",
+    "xhdpi",
+    "xlarge",
+    "xxhdpi",
+    "xxxhdpi",
+];
+
+fn is_language(part: &str) -> bool {
+    (2..=3).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_region(part: &str) -> bool {
+    part.len() == 3
+        && (part.starts_with('r') || part.starts_with('R'))
+        && part[1..].bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_non_locale_qualifier(part: &str) -> bool {
+    NON_LOCALE_QUALIFIERS
+        .binary_search(&part.to_ascii_lowercase().as_str())
+        .is_ok()
+}
+
+fn is_network_code(part: &str) -> bool {
+    (part.starts_with("mcc") || part.starts_with("mnc"))
+        && part.len() > 3
+        && part[3..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Converts an Android language qualifier into a `LanguageIdentifier`.
+///
+/// An empty qualifier, which selects the default resources, is mapped to `und`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::android::from_android_qualifier;
+///
+/// let langid = from_android_qualifier("zh-rTW").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "zh-TW");
+///
+/// let langid = from_android_qualifier("b+es+419").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "es-419");
+///
+/// assert!(from_android_qualifier("zh-TW").is_err());
+/// ```
+pub fn from_android_qualifier(
+    qualifier: &str,
+) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+    let mut langid = if let Some(tag) = qualifier
+        .strip_prefix("b+")
+        .or_else(|| qualifier.strip_prefix("B+"))
+    {
+        LanguageIdentifier::from_bytes(tag.replace('+', "-").as_bytes())?
+    } else if qualifier.is_empty() {
+        LanguageIdentifier::default()
+    } else {
+        let mut parts = qualifier.split('-');
+        let mut offset = 0;
+        let language = parts.next().unwrap_or_default();
+        if !is_language(language) {
+            return Err(ParserError::MalformedSubtag {
+                expected: SubtagKind::Language,
+                offset,
+                length: language.len(),
+            }
+            .into());
+        }
+        let mut langid = LanguageIdentifier::from_bytes(language.as_bytes())?;
+
+        offset += language.len() + 1;
+        if let Some(region) = parts.next() {
+            if !is_region(region) {
+                return Err(ParserError::MalformedSubtag {
+                    expected: SubtagKind::Region,
+                    offset,
+                    length: region.len(),
+                }
+                .into());
+            }
+            langid.region = Some(region[1..].parse()?);
+            offset += region.len() + 1;
+        }
+        if let Some(part) = parts.next() {
+            return Err(ParserError::MalformedSubtag {
+                expected: SubtagKind::Variant,
+                offset,
+                length: part.len(),
+            }
+            .into());
+        }
+        langid
+    };
+
+    if let Some((_, current)) = LEGACY_LANGUAGES
+        .iter()
+        .find(|(legacy, _)| langid.language == *legacy)
+    {
+        langid.language = current.parse()?;
+    }
+    Ok(langid)
+}
+
+/// Converts a `LanguageIdentifier` into an Android language qualifier.
+///
+/// The classic form, with legacy language codes, is used whenever it can
+/// express the identifier, since it is supported by all Android versions.
+/// Otherwise the BCP 47 form is used. `und` is mapped to an empty qualifier.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::android::to_android_qualifier;
+///
+/// let langid: LanguageIdentifier = "he-IL".parse().expect("Parsing failed.");
+/// assert_eq!(to_android_qualifier(&langid), "iw-rIL");
+///
+/// let langid: LanguageIdentifier = "zh-Hant-TW".parse().expect("Parsing failed.");
+/// assert_eq!(to_android_qualifier(&langid), "b+zh+Hant+TW");
+/// ```
+pub fn to_android_qualifier(langid: &LanguageIdentifier) -> String {
+    if *langid == LanguageIdentifier::default() {
+        return String::new();
+    }
+
+    let classic = !langid.language.is_empty()
+        && langid.script.is_none()
+        && langid.variants().len() == 0
+        && langid.region.is_none_or(is_alpha_region);
+
+    if !classic {
+        return format!("b+{}", langid.to_string().replace('-', "+"));
+    }
+
+    let language = langid.language.as_str();
+    let mut result = LEGACY_LANGUAGES
+        .iter()
+        .find(|(_, current)| *current == language)
+        .map_or(language, |(legacy, _)| legacy)
+        .to_string();
+    if let Some(region) = langid.region {
+        result.push_str("-r");
+        result.push_str(region.as_str());
+    }
+    result
+}
+
+fn is_alpha_region(region: subtags::Region) -> bool {
+    region.as_str().bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Converts the name of an Android resource directory, such as
+/// `values-zh-rTW` or `values-mcc310-en-land`, into a `LanguageIdentifier`.
+///
+/// Following the order of Android qualifiers, the language qualifier is the
+/// first one after any mobile country and network codes. Directories without
+/// a language qualifier, like `values-car` or `values-port`, hold the default
+/// resources and are mapped to `und`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::android::from_android_dir;
+///
+/// let langid = from_android_dir("values-mcc310-en-rUS-land").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "en-US");
+///
+/// let langid = from_android_dir("drawable-night").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "und");
+/// ```
+pub fn from_android_dir(dir: &str) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+    let mut parts = dir.split('-').skip(1).skip_while(|p| is_network_code(p));
+    match parts.next() {
+        Some(part) if part.starts_with("b+") || part.starts_with("B+") => {
+            from_android_qualifier(part)
+        }
+        Some(part) if is_language(part) && !is_non_locale_qualifier(part) => match parts.next() {
+            Some(region) if is_region(region) && !is_non_locale_qualifier(region) => {
+                from_android_qualifier(&format!("{}-{}", part, region))
+            }
+            _ => from_android_qualifier(part),
+        },
+        _ => Ok(LanguageIdentifier::default()),
+    }
+}
+
+/// Converts a resource type, such as `values`, and a `LanguageIdentifier`
+/// into the name of an Android resource directory.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::android::to_android_dir;
+///
+/// let langid: LanguageIdentifier = "pt-BR".parse().expect("Parsing failed.");
+/// assert_eq!(to_android_dir("values", &langid), "values-pt-rBR");
+/// assert_eq!(to_android_dir("values", &LanguageIdentifier::default()), "values");
+/// ```
+pub fn to_android_dir(resource_type: &str, langid: &LanguageIdentifier) -> String {
+    let qualifier = to_android_qualifier(langid);
+    if qualifier.is_empty() {
+        resource_type.to_string()
+    } else {
+        format!("{}-{}", resource_type, qualifier)
+    }
+}
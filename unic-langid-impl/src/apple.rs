@@ -0,0 +1,92 @@
+//! Conversion between `LanguageIdentifier` and Apple `.lproj` directory names.
+//!
+//! Apple bundles store localized resources in directories such as
+//! `zh-Hans.lproj`, `en_GB.lproj` or `pt-BR.lproj`. Both `_` and `-` are
+//! accepted as separators, `Base.lproj` holds the base internationalization
+//! resources and older bundles may use English language names such as
+//! `English.lproj`.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::apple::{from_lproj, to_lproj};
+//!
+//! let langid = from_lproj("en_GB.lproj").expect("Conversion failed.");
+//! assert_eq!(langid.to_string(), "en-GB");
+//! assert_eq!(to_lproj(&langid), "en-GB.lproj");
+//! ```
+use crate::{LanguageIdentifier, LanguageIdentifierError};
+use alloc::format;
+use alloc::string::String;
+
+const LPROJ_SUFFIX: &str = ".lproj";
+
+/// Name of the directory with base internationalization resources.
+const BASE_NAME: &str = "Base";
+
+/// Language names used by older bundles.
+static LEGACY_NAMES: &[(&str, &str)] = &[
+    ("Dutch", "nl"),
+    ("English", "en"),
+    ("French", "fr"),
+    ("German", "de"),
+    ("Italian", "it"),
+    ("Japanese", "ja"),
+    ("Spanish", "es"),
+];
+
+/// Converts an `.lproj` directory name into a `LanguageIdentifier`.
+///
+/// The `.lproj` suffix is optional. `Base` is mapped to `und`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::apple::from_lproj;
+///
+/// let langid = from_lproj("zh-Hans.lproj").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "zh-Hans");
+///
+/// let langid = from_lproj("Base.lproj").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "und");
+///
+/// let langid = from_lproj("English").expect("Conversion failed.");
+/// assert_eq!(langid.to_string(), "en");
+/// ```
+pub fn from_lproj(name: &str) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+    let name = name.strip_suffix(LPROJ_SUFFIX).unwrap_or(name);
+
+    if name == BASE_NAME {
+        return Ok(LanguageIdentifier::default());
+    }
+
+    let name = LEGACY_NAMES
+        .iter()
+        .find(|(legacy, _)| *legacy == name)
+        .map_or(name, |(_, language)| language);
+
+    LanguageIdentifier::from_bytes(name.as_bytes())
+}
+
+/// Converts a `LanguageIdentifier` into an `.lproj` directory name.
+///
+/// Subtags are separated with `-`, as in bundles created by current
+/// versions of Xcode. `und` is mapped to `Base.lproj`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::apple::to_lproj;
+///
+/// let langid: LanguageIdentifier = "zh-Hant-HK".parse().expect("Parsing failed.");
+/// assert_eq!(to_lproj(&langid), "zh-Hant-HK.lproj");
+///
+/// assert_eq!(to_lproj(&LanguageIdentifier::default()), "Base.lproj");
+/// ```
+pub fn to_lproj(langid: &LanguageIdentifier) -> String {
+    if *langid == LanguageIdentifier::default() {
+        return format!("{}{}", BASE_NAME, LPROJ_SUFFIX);
+    }
+    format!("{}{}", langid, LPROJ_SUFFIX)
+}
@@ -0,0 +1,113 @@
+//! Generating `src/aliases/tables.rs`.
+use crate::cldr::Aliases;
+use crate::output::{Output, HEADER};
+use std::cmp::Reverse;
+use unic_langid_impl::LanguageIdentifier;
+
+fn subtags(langid: &LanguageIdentifier) -> String {
+    let variants: Vec<String> = langid
+        .variants()
+        .map(|v| u64::from(v).to_string())
+        .collect();
+    format!(
+        "({}, {}, {}, &[{}])",
+        Option::<u64>::from(langid.language).unwrap_or(0),
+        langid.script.map_or(0, u32::from),
+        langid.region.map_or(0, u32::from),
+        variants.join(", ")
+    )
+}
+
+pub fn generate(data: &Aliases, version: &str) -> Result<String, String> {
+    // The first matching rule is applied, so rules matching more subtags
+    // come first.
+    let mut languages: Vec<_> = data.languages.iter().collect();
+    languages.sort_by_key(|(key, _)| {
+        (
+            Reverse(key.variants().len()),
+            Reverse(key.region.is_some()),
+            Reverse(key.script.is_some()),
+            Reverse(!key.language.is_empty()),
+        )
+    });
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    out.array_start(
+        "LANGUAGE_ALIASES",
+        "((u64, u32, u32, &[u64]), (u64, u32, u32, &[u64]))",
+        languages.len(),
+    );
+    for (key, replacement) in &languages {
+        out.entry(
+            format!("({}, {})", subtags(key), subtags(replacement)),
+            format!("{} -> {}", key, replacement),
+        );
+    }
+    out.array_end();
+
+    let mut scripts: Vec<_> = data
+        .scripts
+        .iter()
+        .map(|(k, v)| (u32::from(*k), k, v))
+        .collect();
+    scripts.sort_by_key(|(raw, ..)| *raw);
+    out.array_start("SCRIPT_ALIASES", "(u32, u32)", scripts.len());
+    for (raw, key, replacement) in &scripts {
+        out.entry(
+            format!("({}, {})", raw, u32::from(**replacement)),
+            format!("{} -> {}", key, replacement),
+        );
+    }
+    out.array_end();
+
+    let mut regions: Vec<_> = data
+        .regions
+        .iter()
+        .map(|(k, v)| (u32::from(*k), k, v))
+        .collect();
+    regions.sort_by_key(|(raw, ..)| *raw);
+    out.array_start("REGION_ALIASES", "(u32, &[u32])", regions.len());
+    for (raw, key, replacements) in &regions {
+        let raws: Vec<String> = replacements
+            .iter()
+            .map(|r| u32::from(*r).to_string())
+            .collect();
+        let names: Vec<&str> = replacements.iter().map(|r| r.as_str()).collect();
+        out.entry(
+            format!("({}, &[{}])", raw, raws.join(", ")),
+            format!("{} -> {}", key, names.join(" ")),
+        );
+    }
+    out.array_end();
+
+    let mut variants: Vec<_> = data
+        .variants
+        .iter()
+        .map(|(k, v)| (u64::from(k), k, v))
+        .collect();
+    variants.sort_by_key(|(raw, ..)| *raw);
+    out.array_start("VARIANT_ALIASES", "(u64, u64)", variants.len());
+    for (raw, key, replacement) in &variants {
+        out.entry(
+            format!("({}, {})", raw, u64::from(*replacement)),
+            format!("{} -> {}", key, replacement),
+        );
+    }
+    out.array_end();
+
+    out.array_start(
+        "KEYWORD_TYPE_ALIASES",
+        "(&str, &str, &str)",
+        data.keyword_types.len(),
+    );
+    for ((key, name), preferred) in &data.keyword_types {
+        out.entry(format!("({:?}, {:?}, {:?})", key, name, preferred), "");
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
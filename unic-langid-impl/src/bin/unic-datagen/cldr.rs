@@ -0,0 +1,1150 @@
+//! Reading CLDR JSON data.
+//!
+//! The input directory follows the layout of the `cldr-json` repository,
+//! with one directory per package:
+//!
+//! * `cldr-bcp47/bcp47/*.json`
+//! * `cldr-core/supplemental/*.json`
+//! * `cldr-localenames-full/main/<locale>/*.json`
+//! * `cldr-misc-full/main/<locale>/layout.json`
+//!
+//! A directory holding CLDR XML instead is converted by [`crate::xml`].
+use crate::likelysubtags::{Entries, Subset, Subtags};
+use crate::xml;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use unic_langid_impl::subtags::{Language, Region, Script, Variant};
+use unic_langid_impl::{CharacterDirection, LanguageIdentifier, PackedLanguageIdentifier};
+
+pub type Layout = BTreeMap<LanguageIdentifier, CharacterDirection>;
+
+/// Converts subtags to the raw form used by the likely subtags tables.
+pub fn raw(language: Language, script: Option<Script>, region: Option<Region>) -> Subtags {
+    (
+        Option::<u64>::from(language).unwrap_or(0),
+        script.map(u32::from),
+        region.map(u32::from),
+    )
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+pub fn check_version(
+    path: &Path,
+    expected: Option<&str>,
+    found: Option<&str>,
+) -> Result<(), String> {
+    match expected {
+        Some(expected) if found != Some(expected) => Err(format!(
+            "{}: expected CLDR version {}, found {}",
+            path.display(),
+            expected,
+            found.unwrap_or("none")
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reads `cldr-core/supplemental/<name>.json`, or the same data from CLDR
+/// XML, checking that it comes from `version` if given.
+fn supplemental(dir: &Path, version: Option<&str>, name: &str) -> Result<(PathBuf, Value), String> {
+    let (path, supplemental) = if xml::is_xml(dir) {
+        xml::supplemental(dir, name)?
+    } else {
+        let path = dir.join(format!("cldr-core/supplemental/{}.json", name));
+        let mut root = read_json(&path)?;
+        let supplemental = root["supplemental"].take();
+        (path, supplemental)
+    };
+    check_version(
+        &path,
+        version,
+        supplemental["version"]["_cldrVersion"].as_str(),
+    )?;
+    Ok((path, supplemental))
+}
+
+/// Checks that `package` comes from `version` if given. The version of
+/// CLDR XML applies to all of its data.
+fn check_package_version(dir: &Path, package: &str, version: Option<&str>) -> Result<(), String> {
+    let (path, found) = if xml::is_xml(dir) {
+        xml::version(dir)?
+    } else {
+        let path = dir.join(package).join("package.json");
+        let found = read_json(&path)?["version"].as_str().map(String::from);
+        (path, found)
+    };
+    // CLDR JSON packages append a patch number to the CLDR version.
+    let found = found.as_deref().and_then(|v| v.split('.').next());
+    check_version(&path, version, found)
+}
+
+/// Reads the locales of `package`, with the content of the given files of
+/// each, merged, and the path of their data.
+fn main_locales(
+    dir: &Path,
+    package: &str,
+    files: &[&str],
+) -> Result<Vec<(String, PathBuf, Value)>, String> {
+    if xml::is_xml(dir) {
+        return xml::main_locales(dir);
+    }
+
+    let main = dir.join(package).join("main");
+    let entries = fs::read_dir(&main).map_err(|e| format!("{}: {}", main.display(), e))?;
+    let mut result = vec![];
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("{}: {}", main.display(), e))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let mut data = Value::Object(Default::default());
+        for file in files {
+            let path = entry.path().join(file);
+            let mut root = read_json(&path)?;
+            let locale = root["main"]
+                .as_object_mut()
+                .and_then(|main| main.values_mut().next())
+                .ok_or_else(|| format!("{}: missing main", path.display()))?;
+            merge(&mut data, locale.take());
+        }
+        result.push((name, entry.path(), data));
+    }
+    Ok(result)
+}
+
+/// Adds the fields of `from` to `into`, recursively.
+fn merge(into: &mut Value, from: Value) {
+    match (into, from) {
+        (Value::Object(into), Value::Object(from)) => {
+            for (key, value) in from {
+                match into.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        into.insert(key, value);
+                    }
+                }
+            }
+        }
+        (into, from) => *into = from,
+    }
+}
+
+/// Loads `likelySubtags.json`, checking that it comes from `version`
+/// if given.
+///
+/// A `ZZ` region in the values is dropped, as the region is unknown.
+pub fn load_likely_subtags(dir: &Path, version: Option<&str>) -> Result<Entries, String> {
+    let (path, supplemental) = supplemental(dir, version, "likelySubtags")?;
+    let entries = supplemental["likelySubtags"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing supplemental.likelySubtags", path.display()))?;
+
+    let zz: Region = "ZZ".parse().unwrap();
+    let mut result = BTreeMap::new();
+    for (key, value) in entries {
+        let parse = |input: &str| -> Result<PackedLanguageIdentifier, String> {
+            input
+                .parse()
+                .map_err(|e| format!("{}: invalid entry {:?}: {}", path.display(), input, e))
+        };
+        let value = value
+            .as_str()
+            .ok_or_else(|| format!("{}: value of {:?} is not a string", path.display(), key))?;
+        let value = parse(value)?;
+        if value.language().is_empty() {
+            return Err(format!(
+                "{}: value of {:?} has no language",
+                path.display(),
+                key
+            ));
+        }
+        let region = value.region().filter(|r| *r != zz);
+        let key = parse(key)?;
+        result.insert(
+            raw(key.language(), key.script(), key.region()),
+            raw(value.language(), value.script(), region),
+        );
+    }
+    Ok(result)
+}
+
+/// Loads the character direction of every locale in `cldr-misc-full`,
+/// checking that the package comes from `version` if given.
+pub fn load_layout(dir: &Path, version: Option<&str>) -> Result<Layout, String> {
+    check_package_version(dir, "cldr-misc-full", version)?;
+
+    let mut result = BTreeMap::new();
+    for (key, path, locale) in main_locales(dir, "cldr-misc-full", &["layout.json"])? {
+        if key == "root" {
+            continue;
+        }
+        let langid: LanguageIdentifier = key
+            .parse()
+            .map_err(|e| format!("{}: invalid locale {:?}: {}", path.display(), key, e))?;
+
+        let order = locale["layout"]["orientation"]["characterOrder"].as_str();
+        let direction = match order {
+            Some("right-to-left") => CharacterDirection::RTL,
+            Some("left-to-right") => CharacterDirection::LTR,
+            Some("top-to-bottom") => CharacterDirection::TTB,
+            _ => {
+                return Err(format!(
+                    "{}: unknown characterOrder {:?}",
+                    path.display(),
+                    order
+                ))
+            }
+        };
+        result.insert(langid, direction);
+    }
+    Ok(result)
+}
+
+/// Keeps the locales which only use kept subtags.
+pub fn subset_layout(data: &mut Layout, subset: &Subset) {
+    data.retain(|langid, _| subset.contains(&raw(langid.language, langid.script, langid.region)));
+}
+
+/// Alternate forms of display names, ordered like
+/// `unic_langid_impl::display_names::Alternate`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Alternate {
+    Default,
+    Short,
+    Variant,
+}
+
+/// The display names of a single display locale.
+#[derive(Default, Clone, PartialEq)]
+pub struct LocaleNames {
+    pub locale_pattern: Option<String>,
+    pub locale_separator: Option<String>,
+    pub key_type_pattern: Option<String>,
+    pub languages: BTreeMap<(String, Alternate), String>,
+    pub scripts: BTreeMap<(String, Alternate), String>,
+    pub regions: BTreeMap<(String, Alternate), String>,
+    pub variants: BTreeMap<String, String>,
+    pub keys: BTreeMap<String, String>,
+    pub types: BTreeMap<(String, String), String>,
+}
+
+pub type DisplayNames = BTreeMap<LanguageIdentifier, LocaleNames>;
+
+// Unicode extension keys whose CLDR name differs from the BCP 47 one.
+const KEY_ALIASES: &[(&str, &str)] = &[
+    ("calendar", "ca"),
+    ("colAlternate", "ka"),
+    ("colBackwards", "kb"),
+    ("colCaseFirst", "kf"),
+    ("colCaseLevel", "kc"),
+    ("colHiraganaQuaternary", "kh"),
+    ("colNormalization", "kk"),
+    ("colNumeric", "kn"),
+    ("colReorder", "kr"),
+    ("colStrength", "ks"),
+    ("collation", "co"),
+    ("currency", "cu"),
+    ("numbers", "nu"),
+    ("timezone", "tz"),
+    ("variableTop", "vt"),
+];
+
+// Unicode extension types whose CLDR name differs from the BCP 47 one.
+const TYPE_ALIASES: &[(&str, &str, &str)] = &[
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "gregorian", "gregory"),
+    ("co", "dictionary", "dict"),
+    ("co", "gb2312han", "gb2312"),
+    ("co", "phonebook", "phonebk"),
+    ("co", "traditional", "trad"),
+];
+
+fn bcp47_key(key: &str) -> Option<String> {
+    if let Some((_, alias)) = KEY_ALIASES.iter().find(|(name, _)| *name == key) {
+        return Some(alias.to_string());
+    }
+    let bytes = key.as_bytes();
+    (bytes.len() == 2 && bytes[0].is_ascii_alphanumeric() && bytes[1].is_ascii_alphabetic())
+        .then(|| key.to_ascii_lowercase())
+}
+
+fn bcp47_type(key: &str, value: &str) -> Option<String> {
+    if let Some((_, _, alias)) = TYPE_ALIASES
+        .iter()
+        .find(|(k, name, _)| *k == key && *name == value)
+    {
+        return Some(alias.to_string());
+    }
+    // Boolean keys name their values `yes` and `no`.
+    match value {
+        "yes" => return Some("true".to_string()),
+        "no" => return Some("false".to_string()),
+        _ => {}
+    }
+    value
+        .split('-')
+        .all(|part| {
+            (3..=8).contains(&part.len()) && part.bytes().all(|b| b.is_ascii_alphanumeric())
+        })
+        .then(|| value.to_ascii_lowercase())
+}
+
+/// Splits the `-alt-` suffix off a key, returning `None` for the
+/// alternate forms other than `short` and `variant`.
+fn split_alternate(key: &str) -> Option<(&str, Alternate)> {
+    match key.split_once("-alt-") {
+        None => Some((key, Alternate::Default)),
+        Some((key, "short")) => Some((key, Alternate::Short)),
+        Some((key, "variant")) => Some((key, Alternate::Variant)),
+        Some(_) => None,
+    }
+}
+
+fn read_names(path: &Path, names: &Value, field: &str) -> Result<Vec<(String, String)>, String> {
+    let names = names[field]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing localeDisplayNames.{}", path.display(), field))?;
+    names
+        .iter()
+        .map(|(key, value)| {
+            value
+                .as_str()
+                .map(|value| (key.clone(), value.to_string()))
+                .ok_or_else(|| format!("{}: value of {:?} is not a string", path.display(), key))
+        })
+        .collect()
+}
+
+fn subtag_names<T: ToString>(
+    path: &Path,
+    names: &Value,
+    field: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<BTreeMap<(String, Alternate), String>, String> {
+    let mut result = BTreeMap::new();
+    for (key, value) in read_names(path, names, field)? {
+        // Keys CLDR doesn't name in BCP 47 form are skipped.
+        if let Some((key, alternate)) = split_alternate(&key) {
+            if let Some(key) = parse(key) {
+                result.insert((key.to_string(), alternate), value);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Reads the display names of a locale from its `localeDisplayNames`.
+fn locale_names(path: &Path, names: &Value) -> Result<LocaleNames, String> {
+    let mut result = LocaleNames {
+        languages: subtag_names(path, names, "languages", |key| {
+            key.parse::<LanguageIdentifier>().ok()
+        })?,
+        scripts: subtag_names(path, names, "scripts", |key| key.parse::<Script>().ok())?,
+        regions: subtag_names(path, names, "territories", |key| key.parse::<Region>().ok())?,
+        ..Default::default()
+    };
+    for (key, value) in read_names(path, names, "variants")? {
+        if let Ok(variant) = key.parse::<Variant>() {
+            result.variants.insert(variant.to_string(), value);
+        }
+    }
+
+    let pattern = &names["localeDisplayPattern"];
+    result.locale_pattern = pattern["localePattern"].as_str().map(String::from);
+    result.locale_separator = pattern["localeSeparator"].as_str().map(String::from);
+    result.key_type_pattern = pattern["localeKeyTypePattern"].as_str().map(String::from);
+
+    for (key, value) in names["keys"].as_object().into_iter().flatten() {
+        if let (Some(key), Some(value)) = (bcp47_key(key), value.as_str()) {
+            result.keys.insert(key, value.to_string());
+        }
+    }
+    for (key, values) in names["types"].as_object().into_iter().flatten() {
+        let key = match bcp47_key(key) {
+            Some(key) => key,
+            None => continue,
+        };
+        for (value, name) in values.as_object().into_iter().flatten() {
+            if let (Some(value), Some(name)) = (bcp47_type(&key, value), name.as_str()) {
+                result.types.insert((key.clone(), value), name.to_string());
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Returns the locales `langid` inherits from, closest first.
+pub fn parents(langid: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+    let mut result = vec![];
+    if langid.script.is_some() && langid.region.is_some() {
+        result.push(LanguageIdentifier::from_parts(
+            langid.language,
+            langid.script,
+            None,
+            &[],
+        ));
+    }
+    if langid.script.is_some() || langid.region.is_some() {
+        result.push(LanguageIdentifier::from_parts(
+            langid.language,
+            None,
+            None,
+            &[],
+        ));
+    }
+    result
+}
+
+/// Loads the display names of every locale in `cldr-localenames-full`,
+/// checking that the package comes from `version` if given.
+///
+/// Names equal to the ones of a parent locale are dropped.
+pub fn load_display_names(dir: &Path, version: Option<&str>) -> Result<DisplayNames, String> {
+    check_package_version(dir, "cldr-localenames-full", version)?;
+
+    let files = [
+        "languages.json",
+        "scripts.json",
+        "territories.json",
+        "variants.json",
+        "localeDisplayNames.json",
+    ];
+    let mut all = BTreeMap::new();
+    for (name, path, locale) in main_locales(dir, "cldr-localenames-full", &files)? {
+        if name == "root" {
+            continue;
+        }
+        let langid: LanguageIdentifier = name
+            .parse()
+            .map_err(|e| format!("{}: invalid locale {:?}: {}", path.display(), name, e))?;
+        // Locales with variants can't be selected as display locales.
+        if langid.variants().len() != 0 {
+            continue;
+        }
+        all.insert(langid, locale_names(&path, &locale["localeDisplayNames"])?);
+    }
+
+    let mut result = all.clone();
+    for (langid, names) in result.iter_mut() {
+        let parents: Vec<&LocaleNames> = parents(langid)
+            .iter()
+            .filter_map(|parent| all.get(parent))
+            .collect();
+        dedup(&mut names.languages, &parents, |p| &p.languages);
+        dedup(&mut names.scripts, &parents, |p| &p.scripts);
+        dedup(&mut names.regions, &parents, |p| &p.regions);
+        dedup(&mut names.variants, &parents, |p| &p.variants);
+        dedup(&mut names.keys, &parents, |p| &p.keys);
+        dedup(&mut names.types, &parents, |p| &p.types);
+        let inherited = |pattern: &Option<String>, get: fn(&LocaleNames) -> &Option<String>| {
+            pattern.is_some() && parents.iter().find_map(|p| get(p).as_ref()) == pattern.as_ref()
+        };
+        if inherited(&names.locale_pattern, |p| &p.locale_pattern) {
+            names.locale_pattern = None;
+        }
+        if inherited(&names.locale_separator, |p| &p.locale_separator) {
+            names.locale_separator = None;
+        }
+        if inherited(&names.key_type_pattern, |p| &p.key_type_pattern) {
+            names.key_type_pattern = None;
+        }
+    }
+    Ok(result)
+}
+
+/// Drops the entries of `map` which the closest parent having them
+/// already has.
+fn dedup<K: Ord>(
+    map: &mut BTreeMap<K, String>,
+    parents: &[&LocaleNames],
+    get: impl Fn(&LocaleNames) -> &BTreeMap<K, String>,
+) {
+    map.retain(|key, value| parents.iter().find_map(|p| get(p).get(key)) != Some(value));
+}
+
+/// Keeps the display locales and the named languages, scripts and regions
+/// which only use kept subtags.
+pub fn subset_display_names(data: &mut DisplayNames, subset: &Subset) {
+    let keeps = |langid: &LanguageIdentifier| {
+        subset.contains(&raw(langid.language, langid.script, langid.region))
+    };
+    data.retain(|langid, _| keeps(langid));
+    for names in data.values_mut() {
+        names
+            .languages
+            .retain(|(key, _), _| key.parse().is_ok_and(|langid| keeps(&langid)));
+        names.scripts.retain(|(key, _), _| {
+            key.parse()
+                .is_ok_and(|script| subset.contains(&raw(Language::default(), Some(script), None)))
+        });
+        names.regions.retain(|(key, _), _| {
+            key.parse()
+                .is_ok_and(|region| subset.contains(&raw(Language::default(), None, Some(region))))
+        });
+    }
+}
+
+/// Official status of a language in a territory, ordered like
+/// `unic_langid_impl::territory_info::OfficialStatus`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OfficialStatus {
+    Official,
+    DeFactoOfficial,
+    OfficialRegional,
+    OfficialMinority,
+}
+
+/// A language spoken in a territory, with its script resolved.
+#[derive(Clone, PartialEq)]
+pub struct LanguagePopulation {
+    pub language: Language,
+    pub script: Script,
+    pub percent: f32,
+    pub status: Option<OfficialStatus>,
+}
+
+/// The population of a territory and the languages spoken in it, the most
+/// spoken first.
+#[derive(Clone, PartialEq)]
+pub struct Territory {
+    pub population: u64,
+    pub languages: Vec<LanguagePopulation>,
+}
+
+pub type TerritoryInfo = BTreeMap<Region, Territory>;
+
+fn parse_number<T: std::str::FromStr>(path: &Path, value: &Value, what: &str) -> Result<T, String> {
+    value
+        .as_str()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{}: invalid {}: {}", path.display(), what, value))
+}
+
+/// Loads the primary scripts of each language from `languageData.json`.
+fn load_language_scripts(
+    dir: &Path,
+    version: Option<&str>,
+) -> Result<BTreeMap<Language, Vec<Script>>, String> {
+    let (path, supplemental) = supplemental(dir, version, "languageData")?;
+
+    let entries = supplemental["languageData"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing supplemental.languageData", path.display()))?;
+    let mut result = BTreeMap::new();
+    for (key, value) in entries {
+        // Secondary scripts and territories are listed under `-alt-secondary`.
+        if key.contains("-alt-") {
+            continue;
+        }
+        let language: Language = key
+            .parse()
+            .map_err(|e| format!("{}: invalid language {:?}: {}", path.display(), key, e))?;
+        let scripts = value["_scripts"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|script| {
+                script
+                    .as_str()
+                    .and_then(|script| script.parse().ok())
+                    .ok_or_else(|| format!("{}: invalid script of {:?}", path.display(), key))
+            })
+            .collect::<Result<_, _>>()?;
+        result.insert(language, scripts);
+    }
+    Ok(result)
+}
+
+/// Loads `territoryInfo.json`, checking that it comes from `version` if
+/// given.
+///
+/// The script of a language without one is its only primary script in
+/// `languageData.json`, or else its likely script in the territory. The
+/// version of `likelySubtags.json` isn't checked, as it only fills in
+/// these scripts and may come from another CLDR version.
+pub fn load_territory_info(dir: &Path, version: Option<&str>) -> Result<TerritoryInfo, String> {
+    let likely_subtags = load_likely_subtags(dir, None)?;
+    let language_scripts = load_language_scripts(dir, version)?;
+
+    let (path, supplemental) = supplemental(dir, version, "territoryInfo")?;
+
+    let entries = supplemental["territoryInfo"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing supplemental.territoryInfo", path.display()))?;
+    let mut result = BTreeMap::new();
+    for (key, value) in entries {
+        let region: Region = key
+            .parse()
+            .map_err(|e| format!("{}: invalid territory {:?}: {}", path.display(), key, e))?;
+        let population = parse_number(&path, &value["_population"], "population")?;
+
+        let mut languages = vec![];
+        for (key, value) in value["languagePopulation"]
+            .as_object()
+            .into_iter()
+            .flatten()
+        {
+            // Languages are keyed with an underscore before the script.
+            let langid: LanguageIdentifier = key
+                .replace('_', "-")
+                .parse()
+                .map_err(|e| format!("{}: invalid language {:?}: {}", path.display(), key, e))?;
+            // The unknown language, given for territories like AQ, is left out.
+            if langid.language.is_empty() {
+                continue;
+            }
+            let script = match langid.script {
+                Some(script) => Some(script),
+                None => match language_scripts.get(&langid.language).map(Vec::as_slice) {
+                    Some([script]) => Some(*script),
+                    _ => [Some(region), None].into_iter().find_map(|region| {
+                        likely_subtags
+                            .get(&raw(langid.language, None, region))
+                            .and_then(|(_, script, _)| *script)
+                            .map(|script| unsafe { Script::from_raw_unchecked(script) })
+                    }),
+                },
+            };
+            let script = script.ok_or_else(|| {
+                format!("{}: no script for {:?} in {}", path.display(), key, region)
+            })?;
+            let status = match value["_officialStatus"].as_str() {
+                None => None,
+                Some("official") => Some(OfficialStatus::Official),
+                Some("de_facto_official") => Some(OfficialStatus::DeFactoOfficial),
+                Some("official_regional") => Some(OfficialStatus::OfficialRegional),
+                Some("official_minority") => Some(OfficialStatus::OfficialMinority),
+                Some(status) => {
+                    return Err(format!(
+                        "{}: unknown _officialStatus {:?}",
+                        path.display(),
+                        status
+                    ))
+                }
+            };
+            languages.push(LanguagePopulation {
+                language: langid.language,
+                script,
+                percent: parse_number(&path, &value["_populationPercent"], "population percent")?,
+                status,
+            });
+        }
+        languages.sort_by(|a, b| {
+            b.percent
+                .total_cmp(&a.percent)
+                .then(a.status.is_none().cmp(&b.status.is_none()))
+                .then(a.status.cmp(&b.status))
+                .then(a.language.cmp(&b.language))
+                .then(a.script.cmp(&b.script))
+        });
+        // A language listed both with and without its script, like `zh`
+        // and `zh_Hant` in HK, keeps the entry with the largest share.
+        let mut seen = BTreeSet::new();
+        languages.retain(|language| seen.insert((language.language, language.script)));
+        result.insert(
+            region,
+            Territory {
+                population,
+                languages,
+            },
+        );
+    }
+    Ok(result)
+}
+
+/// Keeps the territories and the languages spoken in them which only use
+/// kept subtags.
+pub fn subset_territory_info(data: &mut TerritoryInfo, subset: &Subset) {
+    data.retain(|region, _| subset.contains(&raw(Language::default(), None, Some(*region))));
+    for (region, territory) in data.iter_mut() {
+        territory.languages.retain(|language| {
+            subset.contains(&raw(
+                language.language,
+                Some(language.script),
+                Some(*region),
+            ))
+        });
+    }
+}
+
+/// The first day, weekend and minimal days in the first week of a region,
+/// with days numbered from 1 for Monday to 7 for Sunday.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeekData {
+    pub first_day: u8,
+    pub weekend_start: u8,
+    pub weekend_end: u8,
+    pub minimal_days: u8,
+}
+
+/// Regional preferences, by region, or for hour cycles by region or
+/// locale, like `ca-ES`.
+#[derive(Default, Clone, PartialEq)]
+pub struct LocaleInfo {
+    pub calendars: BTreeMap<String, Vec<String>>,
+    pub hour_cycles: BTreeMap<String, String>,
+    pub week_data: BTreeMap<String, WeekData>,
+}
+
+fn region_key(path: &Path, key: &str) -> Result<String, String> {
+    key.parse::<Region>()
+        .map(|region| region.to_string())
+        .map_err(|e| format!("{}: invalid region {:?}: {}", path.display(), key, e))
+}
+
+fn weekday(path: &Path, value: &Value) -> Result<u8, String> {
+    let days = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+    value
+        .as_str()
+        .and_then(|day| days.iter().position(|d| *d == day))
+        .map(|idx| idx as u8 + 1)
+        .ok_or_else(|| format!("{}: invalid day {}", path.display(), value))
+}
+
+/// Loads `calendarPreferenceData.json`, `timeData.json` and
+/// `weekData.json`, checking that they come from `version` if given.
+///
+/// Calendars are converted to their BCP 47 types and hour cycles to the
+/// `hc` type of the preferred hour format. Week data missing for a region
+/// is filled in from `001`.
+pub fn load_locale_info(dir: &Path, version: Option<&str>) -> Result<LocaleInfo, String> {
+    let mut result = LocaleInfo::default();
+
+    let (path, data) = supplemental(dir, version, "calendarPreferenceData")?;
+    let entries = data["calendarPreferenceData"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing calendarPreferenceData", path.display()))?;
+    for (key, value) in entries {
+        let calendars = value
+            .as_str()
+            .ok_or_else(|| format!("{}: value of {:?} is not a string", path.display(), key))?
+            .split_whitespace()
+            .map(|calendar| {
+                bcp47_type("ca", calendar)
+                    .ok_or_else(|| format!("{}: invalid calendar {:?}", path.display(), calendar))
+            })
+            .collect::<Result<_, _>>()?;
+        result.calendars.insert(region_key(&path, key)?, calendars);
+    }
+
+    let (path, data) = supplemental(dir, version, "timeData")?;
+    let entries = data["timeData"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing timeData", path.display()))?;
+    for (key, value) in entries {
+        // Keys are regions or locales, like `ca_ES`.
+        let key = match key.split_once('_') {
+            Some(_) => key
+                .replace('_', "-")
+                .parse::<LanguageIdentifier>()
+                .map(|langid| langid.to_string())
+                .map_err(|e| format!("{}: invalid locale {:?}: {}", path.display(), key, e))?,
+            None => region_key(&path, key)?,
+        };
+        let hour_cycle = match value["_preferred"].as_str() {
+            Some("K") => "h11",
+            Some("h") => "h12",
+            Some("H") => "h23",
+            Some("k") => "h24",
+            preferred => {
+                return Err(format!(
+                    "{}: unknown _preferred {:?} of {}",
+                    path.display(),
+                    preferred,
+                    key
+                ))
+            }
+        };
+        result.hour_cycles.insert(key, hour_cycle.to_string());
+    }
+
+    let (path, data) = supplemental(dir, version, "weekData")?;
+    let data = &data["weekData"];
+    let mut fields: [BTreeMap<String, u8>; 4] = Default::default();
+    for (field, name) in
+        fields
+            .iter_mut()
+            .zip(["firstDay", "weekendStart", "weekendEnd", "minDays"])
+    {
+        let entries = data[name]
+            .as_object()
+            .ok_or_else(|| format!("{}: missing weekData.{}", path.display(), name))?;
+        for (key, value) in entries {
+            let value = match name {
+                "minDays" => parse_number(&path, value, "minDays")?,
+                _ => weekday(&path, value)?,
+            };
+            field.insert(region_key(&path, key)?, value);
+        }
+    }
+    let regions: std::collections::BTreeSet<&String> =
+        fields.iter().flat_map(|field| field.keys()).collect();
+    for region in regions {
+        let get = |field: &BTreeMap<String, u8>| {
+            field
+                .get(region)
+                .or_else(|| field.get("001"))
+                .copied()
+                .ok_or_else(|| format!("{}: missing week data for 001", path.display()))
+        };
+        let week_data = WeekData {
+            first_day: get(&fields[0])?,
+            weekend_start: get(&fields[1])?,
+            weekend_end: get(&fields[2])?,
+            minimal_days: get(&fields[3])?,
+        };
+        result.week_data.insert(region.clone(), week_data);
+    }
+    Ok(result)
+}
+
+/// Keeps the regions, and locales, which only use kept subtags.
+pub fn subset_locale_info(data: &mut LocaleInfo, subset: &Subset) {
+    let keeps = |key: &String| {
+        let langid: LanguageIdentifier = match key.parse::<Region>() {
+            Ok(region) => {
+                LanguageIdentifier::from_parts(Language::default(), None, Some(region), &[])
+            }
+            Err(_) => key.parse().unwrap(),
+        };
+        subset.contains(&raw(langid.language, langid.script, langid.region))
+    };
+    data.calendars.retain(|key, _| keeps(key));
+    data.hour_cycles.retain(|key, _| keeps(key));
+    data.week_data.retain(|key, _| keeps(key));
+}
+
+/// Loads every file of `cldr-bcp47/bcp47`, or `common/bcp47` for CLDR
+/// XML, checking that they come from `version` if given.
+fn bcp47(dir: &Path, version: Option<&str>) -> Result<Vec<(PathBuf, Value)>, String> {
+    check_package_version(dir, "cldr-bcp47", version)?;
+
+    if xml::is_xml(dir) {
+        return xml::bcp47(dir);
+    }
+    let bcp47 = dir.join("cldr-bcp47/bcp47");
+    let mut paths = fs::read_dir(&bcp47)
+        .map_err(|e| format!("{}: {}", bcp47.display(), e))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("{}: {}", bcp47.display(), e))?;
+    paths.retain(|p| p.extension().is_some_and(|e| e == "json"));
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| read_json(&path).map(|root| (path, root)))
+        .collect()
+}
+
+/// The legacy names of the unicode extension keys and types, and of
+/// regions.
+#[derive(Default)]
+pub struct KeywordAliases {
+    /// Lowercase legacy key names and their keys.
+    pub keys: BTreeMap<String, String>,
+    /// Keys, legacy type names and their types. The preferred legacy
+    /// name of a type comes first.
+    pub types: Vec<(String, String, String)>,
+    /// ISO 3166 alpha-3 region codes and their alpha-2 regions.
+    pub regions: BTreeMap<String, String>,
+}
+
+/// Loads the `_alias` names of the `-u-` keys and types of every file in
+/// `cldr-bcp47/bcp47`, or `common/bcp47` for CLDR XML, and the alpha-3
+/// codes of `codeMappings.json`, checking that they come from `version`
+/// if given.
+pub fn load_keyword_aliases(dir: &Path, version: Option<&str>) -> Result<KeywordAliases, String> {
+    let files = bcp47(dir, version)?;
+
+    let mut keys = BTreeMap::new();
+    for (path, root) in &files {
+        for (key, types) in root["keyword"]["u"].as_object().into_iter().flatten() {
+            keys.insert(key.clone(), (path.clone(), types.clone()));
+        }
+    }
+
+    let mut result = KeywordAliases::default();
+    for (key, (path, types)) in &keys {
+        if bcp47_key(key).as_deref() != Some(key.as_str()) {
+            return Err(format!("{}: invalid key {:?}", path.display(), key));
+        }
+        for alias in types["_alias"]
+            .as_str()
+            .into_iter()
+            .flat_map(str::split_whitespace)
+        {
+            result.keys.insert(alias.to_ascii_lowercase(), key.clone());
+        }
+        for (name, value) in types.as_object().into_iter().flatten() {
+            if name.starts_with('_') {
+                continue;
+            }
+            for alias in value["_alias"]
+                .as_str()
+                .into_iter()
+                .flat_map(str::split_whitespace)
+            {
+                result
+                    .types
+                    .push((key.clone(), alias.to_string(), name.clone()));
+            }
+        }
+    }
+
+    let (path, data) = supplemental(dir, version, "codeMappings")?;
+    let entries = data["codeMappings"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing codeMappings", path.display()))?;
+    // Currencies have no `_alpha3`. A deprecated region sharing the
+    // alpha-3 code of its replacement, like QU and EU, comes second.
+    for (region, value) in entries {
+        if let Some(alpha3) = value["_alpha3"].as_str() {
+            result
+                .regions
+                .entry(alpha3.to_string())
+                .or_insert(region_key(&path, region)?);
+        }
+    }
+    Ok(result)
+}
+
+/// The replacements of deprecated and legacy subtags.
+#[derive(Default, Clone, PartialEq)]
+pub struct Aliases {
+    pub languages: BTreeMap<LanguageIdentifier, LanguageIdentifier>,
+    pub scripts: BTreeMap<Script, Script>,
+    pub regions: BTreeMap<Region, Vec<Region>>,
+    pub variants: BTreeMap<Variant, Variant>,
+    /// Unicode extension keys and deprecated types, and their preferred
+    /// types.
+    pub keyword_types: BTreeMap<(String, String), String>,
+}
+
+fn parse_locale(key: &str) -> Option<LanguageIdentifier> {
+    match key {
+        "root" => Some(LanguageIdentifier::default()),
+        _ => key.replace('_', "-").parse().ok(),
+    }
+}
+
+/// Loads the language, script, territory and variant aliases of
+/// `aliases.json`, and the `_preferred` types of the deprecated `-u-` types
+/// of the `bcp47` data, checking that they come from `version` if given.
+///
+/// Aliases which aren't language identifiers, like `i_klingon`, or which
+/// are replaced by private use subtags, like `zh_min`, are left out.
+pub fn load_aliases(dir: &Path, version: Option<&str>) -> Result<Aliases, String> {
+    let (path, data) = supplemental(dir, version, "aliases")?;
+    let alias = &data["metadata"]["alias"];
+    let entries = |field: &str| -> Result<Vec<(&String, &str)>, String> {
+        alias[field]
+            .as_object()
+            .ok_or_else(|| format!("{}: missing metadata.alias.{}", path.display(), field))?
+            .iter()
+            .map(|(key, value)| {
+                value["_replacement"]
+                    .as_str()
+                    .map(|replacement| (key, replacement))
+                    .ok_or_else(|| format!("{}: missing _replacement of {:?}", path.display(), key))
+            })
+            .collect()
+    };
+
+    let mut result = Aliases::default();
+    for (key, replacement) in entries("languageAlias")? {
+        if let (Some(key), Some(replacement)) = (parse_locale(key), parse_locale(replacement)) {
+            if key != replacement {
+                result.languages.insert(key, replacement);
+            }
+        }
+    }
+    for (key, replacement) in entries("scriptAlias")? {
+        if let (Ok(key), Ok(replacement)) = (key.parse(), replacement.parse()) {
+            result.scripts.insert(key, replacement);
+        }
+    }
+    for (key, replacement) in entries("territoryAlias")? {
+        let replacements: Option<Vec<Region>> = replacement
+            .split_whitespace()
+            .map(|region| region.parse().ok())
+            .collect();
+        match (key.parse(), replacements) {
+            (Ok(key), Some(replacements)) if !replacements.is_empty() => {
+                result.regions.insert(key, replacements);
+            }
+            _ => {}
+        }
+    }
+    for (key, replacement) in entries("variantAlias")? {
+        if let (Ok(key), Ok(replacement)) = (key.parse(), replacement.parse()) {
+            result.variants.insert(key, replacement);
+        }
+    }
+    for (_, root) in bcp47(dir, version)? {
+        for (key, types) in root["keyword"]["u"].as_object().into_iter().flatten() {
+            for (name, value) in types.as_object().into_iter().flatten() {
+                if let Some(preferred) = value["_preferred"].as_str() {
+                    result
+                        .keyword_types
+                        .insert((key.clone(), name.clone()), preferred.to_string());
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Loads the language aliases of `aliases.json` replacing an individual
+/// language with its macrolanguage, like `cmn` with `zh`, checking that it
+/// comes from `version` if given.
+pub fn load_macrolanguage_aliases(
+    dir: &Path,
+    version: Option<&str>,
+) -> Result<BTreeMap<String, String>, String> {
+    let (path, data) = supplemental(dir, version, "aliases")?;
+    let entries = data["metadata"]["alias"]["languageAlias"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing metadata.alias.languageAlias", path.display()))?;
+    let mut result = BTreeMap::new();
+    for (key, value) in entries {
+        if value["_reason"].as_str() != Some("macrolanguage") {
+            continue;
+        }
+        let replacement = value["_replacement"]
+            .as_str()
+            .ok_or_else(|| format!("{}: missing _replacement of {:?}", path.display(), key))?;
+        if key.parse::<Language>().is_ok() && replacement.parse::<Language>().is_ok() {
+            result.insert(key.clone(), replacement.to_string());
+        }
+    }
+    Ok(result)
+}
+
+/// Keeps the aliases replaced by kept subtags.
+pub fn subset_aliases(data: &mut Aliases, subset: &Subset) {
+    data.languages.retain(|_, replacement| {
+        subset.contains(&raw(
+            replacement.language,
+            replacement.script,
+            replacement.region,
+        ))
+    });
+    data.scripts
+        .retain(|_, script| subset.contains(&raw(Language::default(), Some(*script), None)));
+    for replacements in data.regions.values_mut() {
+        replacements
+            .retain(|region| subset.contains(&raw(Language::default(), None, Some(*region))));
+    }
+    data.regions
+        .retain(|_, replacements| !replacements.is_empty());
+}
+
+pub type ParentLocales = BTreeMap<LanguageIdentifier, LanguageIdentifier>;
+
+/// Loads `parentLocales.json`, checking that it comes from `version` if
+/// given. The `root` locale becomes `und`.
+pub fn load_parent_locales(dir: &Path, version: Option<&str>) -> Result<ParentLocales, String> {
+    let (path, data) = supplemental(dir, version, "parentLocales")?;
+    let entries = data["parentLocales"]["parentLocale"]
+        .as_object()
+        .ok_or_else(|| format!("{}: missing parentLocales.parentLocale", path.display()))?;
+
+    let mut result = BTreeMap::new();
+    for (key, value) in entries {
+        let parse = |input: &str| {
+            parse_locale(input)
+                .ok_or_else(|| format!("{}: invalid locale {:?}", path.display(), input))
+        };
+        let value = value
+            .as_str()
+            .ok_or_else(|| format!("{}: value of {:?} is not a string", path.display(), key))?;
+        result.insert(parse(key)?, parse(value)?);
+    }
+    Ok(result)
+}
+
+/// Keeps the locales which only use kept subtags.
+pub fn subset_parent_locales(data: &mut ParentLocales, subset: &Subset) {
+    data.retain(|langid, _| subset.contains(&raw(langid.language, langid.script, langid.region)));
+}
+
+/// The status of a subtag in the validity data, ordered like
+/// `unic_langid_impl::validity::IdStatus`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IdStatus {
+    Regular,
+    Special,
+    Macroregion,
+    Deprecated,
+    PrivateUse,
+    Reserved,
+    Unknown,
+}
+
+/// The status of every language, script, region and variant.
+#[derive(Default, Clone, PartialEq)]
+pub struct Validity {
+    pub languages: BTreeMap<Language, IdStatus>,
+    pub scripts: BTreeMap<Script, IdStatus>,
+    pub regions: BTreeMap<Region, IdStatus>,
+    pub variants: BTreeMap<Variant, IdStatus>,
+}
+
+impl Validity {
+    /// Parses the subtags of the validity data read from `path`.
+    pub fn from_ids(
+        path: &Path,
+        languages: BTreeMap<String, IdStatus>,
+        scripts: BTreeMap<String, IdStatus>,
+        regions: BTreeMap<String, IdStatus>,
+        variants: BTreeMap<String, IdStatus>,
+    ) -> Result<Self, String> {
+        fn parse<T: std::str::FromStr + Ord>(
+            path: &Path,
+            ids: BTreeMap<String, IdStatus>,
+        ) -> Result<BTreeMap<T, IdStatus>, String>
+        where
+            T::Err: std::fmt::Display,
+        {
+            ids.into_iter()
+                .map(|(id, status)| {
+                    id.parse()
+                        .map(|id| (id, status))
+                        .map_err(|e| format!("{}: invalid subtag {:?}: {}", path.display(), id, e))
+                })
+                .collect()
+        }
+        Ok(Self {
+            languages: parse(path, languages)?,
+            scripts: parse(path, scripts)?,
+            regions: parse(path, regions)?,
+            variants: parse(path, variants)?,
+        })
+    }
+}
+
+/// Loads the validity data, which only comes with CLDR XML, checking that
+/// it comes from `version` if given.
+pub fn load_validity(dir: &Path, version: Option<&str>) -> Result<Validity, String> {
+    if !xml::is_xml(dir) {
+        return Err(format!(
+            "{}: CLDR JSON has no validity data, use the CLDR XML release instead",
+            dir.display()
+        ));
+    }
+    xml::load_validity(dir, version)
+}
+
+/// Keeps the languages, scripts and regions which are kept.
+pub fn subset_validity(data: &mut Validity, subset: &Subset) {
+    data.languages
+        .retain(|language, _| subset.contains(&raw(*language, None, None)));
+    data.scripts
+        .retain(|script, _| subset.contains(&raw(Language::default(), Some(*script), None)));
+    data.regions
+        .retain(|region, _| subset.contains(&raw(Language::default(), None, Some(*region))));
+}
@@ -0,0 +1,100 @@
+//! Generating `src/display_names/tables.rs`.
+use crate::cldr::{Alternate, DisplayNames, LocaleNames};
+use crate::output::{Output, HEADER};
+use std::collections::BTreeMap;
+
+fn names(out: &mut Output, name: &str, entries: &BTreeMap<(String, Alternate), String>) {
+    out.array_start(name, "(&str, Alternate, &str)", entries.len());
+    for ((key, alternate), value) in entries {
+        out.entry(
+            format!("({:?}, Alternate::{:?}, {:?})", key, alternate, value),
+            "",
+        );
+    }
+    out.array_end();
+}
+
+fn pairs(out: &mut Output, name: &str, entries: &BTreeMap<String, String>) {
+    out.array_start(name, "(&str, &str)", entries.len());
+    for (key, value) in entries {
+        out.entry(format!("({:?}, {:?})", key, value), "");
+    }
+    out.array_end();
+}
+
+fn types(out: &mut Output, name: &str, entries: &BTreeMap<(String, String), String>) {
+    out.array_start(name, "(&str, &str, &str)", entries.len());
+    for ((key, value), name) in entries {
+        out.entry(format!("({:?}, {:?}, {:?})", key, value, name), "");
+    }
+    out.array_end();
+}
+
+fn prefix(locale: &str) -> String {
+    locale.replace('-', "_").to_ascii_uppercase()
+}
+
+fn locale(out: &mut Output, locale: &str, data: &LocaleNames) {
+    let prefix = prefix(locale);
+    names(out, &format!("{}_LANGUAGES", prefix), &data.languages);
+    names(out, &format!("{}_SCRIPTS", prefix), &data.scripts);
+    names(out, &format!("{}_REGIONS", prefix), &data.regions);
+    pairs(out, &format!("{}_VARIANTS", prefix), &data.variants);
+    pairs(out, &format!("{}_KEYS", prefix), &data.keys);
+    types(out, &format!("{}_TYPES", prefix), &data.types);
+}
+
+pub fn generate(data: &DisplayNames, version: &str) -> Result<String, String> {
+    let locales: BTreeMap<String, &LocaleNames> = data
+        .iter()
+        .map(|(langid, names)| (langid.to_string(), names))
+        .collect();
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line("use super::{Alternate, LocaleData};");
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    for (name, names) in &locales {
+        locale(&mut out, name, names);
+    }
+
+    out.array_start("LOCALES", "(&str, LocaleData)", locales.len());
+    for (name, names) in &locales {
+        let prefix = prefix(name);
+        out.line(&format!("    ({:?}, LocaleData {{", name));
+        out.line(&format!(
+            "        locale_pattern: {:?},",
+            names.locale_pattern
+        ));
+        out.line(&format!(
+            "        locale_separator: {:?},",
+            names.locale_separator
+        ));
+        out.line(&format!(
+            "        key_type_pattern: {:?},",
+            names.key_type_pattern
+        ));
+        for field in [
+            "languages",
+            "scripts",
+            "regions",
+            "variants",
+            "keys",
+            "types",
+        ] {
+            out.line(&format!(
+                "        {}: &{}_{},",
+                field,
+                prefix,
+                field.to_ascii_uppercase()
+            ));
+        }
+        out.line("    }),");
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
@@ -0,0 +1,224 @@
+//! Reading the IANA Language Subtag Registry and generating
+//! `src/iana_registry/tables.rs`.
+//!
+//! The registry uses the record-jar format of RFC 5646, section 3.1.1:
+//! records are separated by `%%` lines, and hold `Field-Name: value`
+//! lines, continued on the following lines starting with whitespace.
+use crate::cldr::raw;
+use crate::likelysubtags::Subset;
+use crate::output::{Output, HEADER};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use unic_langid_impl::subtags::{Language, Region, Script};
+use unic_langid_impl::LanguageIdentifier;
+
+/// The fields of a record, in order.
+pub type Fields = Vec<(String, String)>;
+
+/// Parses the records of a registry file.
+pub fn parse(input: &str) -> Result<Vec<Fields>, String> {
+    let mut records = vec![];
+    let mut fields: Fields = vec![];
+    for (idx, line) in input.lines().enumerate() {
+        if line == "%%" {
+            records.push(std::mem::take(&mut fields));
+        } else if line.starts_with([' ', '\t']) {
+            let (_, value) = fields
+                .last_mut()
+                .ok_or_else(|| format!("line {}: continuation without a field", idx + 1))?;
+            value.push(' ');
+            value.push_str(line.trim());
+        } else if !line.trim().is_empty() {
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line {}: invalid field {:?}", idx + 1, line))?;
+            fields.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    records.push(fields);
+    Ok(records)
+}
+
+/// A registry record of a subtag.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Record {
+    pub descriptions: Vec<String>,
+    pub added: String,
+    pub deprecated: Option<String>,
+    pub preferred_value: Option<String>,
+    pub suppress_script: Option<String>,
+    pub prefixes: Vec<String>,
+}
+
+/// The subtag records of the registry, by type and subtag.
+#[derive(Default)]
+pub struct Registry {
+    pub file_date: String,
+    pub languages: BTreeMap<String, Record>,
+    pub scripts: BTreeMap<String, Record>,
+    pub regions: BTreeMap<String, Record>,
+    pub variants: BTreeMap<String, Record>,
+}
+
+/// Loads `iana/language-subtag-registry`.
+///
+/// Ranges of private use subtags, and grandfathered and redundant tags,
+/// are left out.
+pub fn load(dir: &Path) -> Result<Registry, String> {
+    let path = dir.join("iana/language-subtag-registry");
+    let contents = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let records = parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let mut result = Registry::default();
+    let mut records = records.into_iter();
+    result.file_date = records
+        .next()
+        .and_then(|fields| fields.into_iter().find(|(name, _)| name == "File-Date"))
+        .map(|(_, value)| value)
+        .ok_or_else(|| format!("{}: missing File-Date", path.display()))?;
+
+    for fields in records {
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.clone())
+        };
+        let values = |name: &str| -> Vec<String> {
+            fields
+                .iter()
+                .filter(|(n, _)| n == name)
+                .map(|(_, value)| value.clone())
+                .collect()
+        };
+        let kind = field("Type").ok_or_else(|| format!("{}: missing Type", path.display()))?;
+        let map = match kind.as_str() {
+            "language" => &mut result.languages,
+            "script" => &mut result.scripts,
+            "region" => &mut result.regions,
+            "variant" => &mut result.variants,
+            "grandfathered" | "redundant" | "extlang" => continue,
+            _ => return Err(format!("{}: unknown Type {:?}", path.display(), kind)),
+        };
+        let subtag =
+            field("Subtag").ok_or_else(|| format!("{}: missing Subtag", path.display()))?;
+        if subtag.contains("..") {
+            continue;
+        }
+        let record = Record {
+            descriptions: values("Description"),
+            added: field("Added")
+                .ok_or_else(|| format!("{}: missing Added for {}", path.display(), subtag))?,
+            deprecated: field("Deprecated"),
+            preferred_value: field("Preferred-Value"),
+            suppress_script: field("Suppress-Script"),
+            prefixes: values("Prefix"),
+        };
+        map.insert(subtag, record);
+    }
+    Ok(result)
+}
+
+/// Keeps the languages, scripts and regions which are kept.
+pub fn subset(data: &mut Registry, subset: &Subset) {
+    data.languages.retain(|subtag, _| {
+        subtag
+            .parse::<Language>()
+            .is_ok_and(|language| subset.contains(&raw(language, None, None)))
+    });
+    data.scripts.retain(|subtag, _| {
+        subtag
+            .parse::<Script>()
+            .is_ok_and(|script| subset.contains(&raw(Language::default(), Some(script), None)))
+    });
+    data.regions.retain(|subtag, _| {
+        subtag
+            .parse::<Region>()
+            .is_ok_and(|region| subset.contains(&raw(Language::default(), None, Some(region))))
+    });
+}
+
+fn option(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".to_string(),
+    }
+}
+
+fn record(record: &Record) -> String {
+    format!(
+        "Record {{ descriptions: &{:?}, added: {:?}, deprecated: {}, preferred_value: {} }}",
+        record.descriptions,
+        record.added,
+        option(&record.deprecated),
+        option(&record.preferred_value)
+    )
+}
+
+fn records(out: &mut Output, name: &str, records: &BTreeMap<String, Record>) {
+    out.array_start(name, "(&str, Record)", records.len());
+    for (subtag, data) in records {
+        out.entry(format!("({:?}, {})", subtag, record(data)), "");
+    }
+    out.array_end();
+}
+
+pub fn generate(data: &Registry) -> Result<String, String> {
+    for (subtag, record) in &data.languages {
+        if let Some(script) = &record.suppress_script {
+            script
+                .parse::<Script>()
+                .map_err(|e| format!("invalid Suppress-Script of {}: {}", subtag, e))?;
+        }
+    }
+    for (subtag, record) in &data.variants {
+        for prefix in &record.prefixes {
+            prefix
+                .parse::<LanguageIdentifier>()
+                .map_err(|e| format!("invalid Prefix of {}: {}", subtag, e))?;
+        }
+    }
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line("use super::Record;");
+    out.line("");
+    out.line(&format!(
+        "pub static FILE_DATE: &str = {:?};",
+        data.file_date
+    ));
+
+    out.array_start(
+        "LANGUAGES",
+        "(&str, Record, Option<&str>)",
+        data.languages.len(),
+    );
+    for (subtag, data) in &data.languages {
+        out.entry(
+            format!(
+                "({:?}, {}, {})",
+                subtag,
+                record(data),
+                option(&data.suppress_script)
+            ),
+            "",
+        );
+    }
+    out.array_end();
+
+    records(&mut out, "SCRIPTS", &data.scripts);
+    records(&mut out, "REGIONS", &data.regions);
+
+    out.array_start("VARIANTS", "(&str, Record, &[&str])", data.variants.len());
+    for (subtag, data) in &data.variants {
+        out.entry(
+            format!("({:?}, {}, &{:?})", subtag, record(data), data.prefixes),
+            "",
+        );
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
@@ -0,0 +1,202 @@
+//! Reading the SIL ISO 639-3 code tables and generating
+//! `src/iso639/tables.rs`.
+use crate::likelysubtags::Subset;
+use crate::output::{Output, HEADER};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+use unic_langid_impl::subtags::Language;
+
+/// The codes of a language in each part of ISO 639.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Codes {
+    pub part1: Option<String>,
+    pub part2t: Option<String>,
+    pub part2b: Option<String>,
+    pub part3: String,
+}
+
+impl Codes {
+    /// The Unicode canonical code, which is the ISO 639-1 code if there
+    /// is one and the ISO 639-3 one otherwise.
+    pub fn canonical(&self) -> &str {
+        self.part1.as_deref().unwrap_or(&self.part3)
+    }
+}
+
+/// The ISO 639 data, keyed by canonical code.
+#[derive(Default)]
+pub struct Iso639 {
+    pub codes: BTreeMap<String, Codes>,
+    /// The canonical codes whose scope is macrolanguage.
+    pub macrolanguage_codes: BTreeSet<String>,
+    /// The macrolanguage of each individual language encompassed by one.
+    pub macrolanguages: BTreeMap<String, String>,
+}
+
+/// Reads the fields of each line of a tab separated table, checking its
+/// header and that lines have at least `len` fields.
+fn read_table(path: &Path, header: &str, len: usize) -> Result<Vec<Vec<String>>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut lines = contents.lines();
+    let found = lines.next().unwrap_or_default();
+    if !found.starts_with(header) {
+        return Err(format!("{}: unexpected header {:?}", path.display(), found));
+    }
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<String> = line.split('\t').map(|f| f.trim().to_string()).collect();
+            if fields.len() < len {
+                return Err(format!("{}: invalid line {:?}", path.display(), line));
+            }
+            Ok(fields)
+        })
+        .collect()
+}
+
+/// Loads `iso-639-3/iso-639-3.tab` and
+/// `iso-639-3/iso-639-3-macrolanguages.tab`.
+///
+/// Retired individual languages are left out of the macrolanguages.
+pub fn load(dir: &Path) -> Result<Iso639, String> {
+    let path = dir.join("iso-639-3/iso-639-3.tab");
+    let mut result = Iso639::default();
+    for fields in read_table(&path, "Id\tPart2b\tPart2t\tPart1\tScope\t", 5)? {
+        let code = |field: &String| -> Result<Option<String>, String> {
+            if field.is_empty() {
+                return Ok(None);
+            }
+            field
+                .parse::<Language>()
+                .map(|_| Some(field.clone()))
+                .map_err(|e| format!("{}: invalid code {:?}: {}", path.display(), field, e))
+        };
+        let codes = Codes {
+            part1: code(&fields[3])?,
+            part2t: code(&fields[2])?,
+            part2b: code(&fields[1])?,
+            part3: code(&fields[0])?
+                .ok_or_else(|| format!("{}: missing Id in {:?}", path.display(), fields))?,
+        };
+        if fields[4] == "M" {
+            result
+                .macrolanguage_codes
+                .insert(codes.canonical().to_string());
+        }
+        result.codes.insert(codes.canonical().to_string(), codes);
+    }
+
+    // The mappings use ISO 639-3 codes.
+    let canonical: BTreeMap<&str, &str> = result
+        .codes
+        .iter()
+        .map(|(canonical, codes)| (codes.part3.as_str(), canonical.as_str()))
+        .collect();
+    let path = dir.join("iso-639-3/iso-639-3-macrolanguages.tab");
+    let mut macrolanguages = BTreeMap::new();
+    for fields in read_table(&path, "M_Id\tI_Id\tI_Status", 3)? {
+        if fields[2] == "R" {
+            continue;
+        }
+        let code = |code: &String| {
+            canonical
+                .get(code.as_str())
+                .map(|code| code.to_string())
+                .ok_or_else(|| format!("{}: unknown code {:?}", path.display(), code))
+        };
+        macrolanguages.insert(code(&fields[1])?, code(&fields[0])?);
+    }
+    result.macrolanguages = macrolanguages;
+    Ok(result)
+}
+
+/// Adds the individual languages of `aliases` replaced by the
+/// macrolanguage encompassing them, which the bundled SIL macrolanguage
+/// table leaves out, like `swh` and `sw`.
+pub fn add_macrolanguages(data: &mut Iso639, aliases: &BTreeMap<String, String>) {
+    for (language, macrolanguage) in aliases {
+        if data.codes.contains_key(language)
+            && !data.macrolanguage_codes.contains(language)
+            && data.macrolanguage_codes.contains(macrolanguage)
+        {
+            data.macrolanguages
+                .entry(language.clone())
+                .or_insert_with(|| macrolanguage.clone());
+        }
+    }
+}
+
+/// Keeps the languages whose canonical code is kept.
+pub fn subset(data: &mut Iso639, subset: &Subset) {
+    let keeps = |canonical: &String| {
+        let language: Language = canonical.parse().unwrap();
+        subset.contains(&crate::cldr::raw(language, None, None))
+    };
+    data.codes.retain(|canonical, _| keeps(canonical));
+    data.macrolanguages
+        .retain(|language, macrolanguage| keeps(language) && keeps(macrolanguage));
+}
+
+fn option(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".to_string(),
+    }
+}
+
+pub fn generate(data: &Iso639) -> Result<String, String> {
+    let mut aliases = BTreeMap::new();
+    for (canonical, codes) in &data.codes {
+        let others = [&codes.part2t, &codes.part2b, &Some(codes.part3.clone())];
+        for alias in others.into_iter().flatten() {
+            if alias != canonical {
+                if let Some(other) = aliases.insert(alias.clone(), canonical.clone()) {
+                    if &other != canonical {
+                        return Err(format!(
+                            "{} is a code of both {} and {}",
+                            alias, other, canonical
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut out = Output::new();
+    out.line(HEADER);
+
+    out.array_start(
+        "CODES",
+        "(&str, Option<&str>, Option<&str>, Option<&str>, &str)",
+        data.codes.len(),
+    );
+    for (canonical, codes) in &data.codes {
+        out.entry(
+            format!(
+                "({:?}, {}, {}, {}, {:?})",
+                canonical,
+                option(&codes.part1),
+                option(&codes.part2t),
+                option(&codes.part2b),
+                codes.part3
+            ),
+            "",
+        );
+    }
+    out.array_end();
+
+    out.array_start("ALIASES", "(&str, &str)", aliases.len());
+    for (alias, canonical) in &aliases {
+        out.entry(format!("({:?}, {:?})", alias, canonical), "");
+    }
+    out.array_end();
+
+    out.array_start("MACROLANGUAGES", "(&str, &str)", data.macrolanguages.len());
+    for (language, macrolanguage) in &data.macrolanguages {
+        out.entry(format!("({:?}, {:?})", language, macrolanguage), "");
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
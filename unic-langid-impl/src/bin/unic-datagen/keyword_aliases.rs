@@ -0,0 +1,30 @@
+//! Generating `unic-locale-impl/src/icu/tables.rs`.
+use crate::cldr::KeywordAliases;
+use crate::output::{Output, HEADER};
+
+pub fn generate(data: &KeywordAliases, version: &str) -> Result<String, String> {
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    out.array_start("KEY_ALIASES", "(&str, &str)", data.keys.len());
+    for (alias, key) in &data.keys {
+        out.entry(format!("({:?}, {:?})", alias, key), "");
+    }
+    out.array_end();
+
+    out.array_start("TYPE_ALIASES", "(&str, &str, &str)", data.types.len());
+    for (key, alias, name) in &data.types {
+        out.entry(format!("({:?}, {:?}, {:?})", key, alias, name), "");
+    }
+    out.array_end();
+
+    out.array_start("REGION_ALPHA3", "(&str, &str)", data.regions.len());
+    for (alpha3, region) in &data.regions {
+        out.entry(format!("({:?}, {:?})", alpha3, region), "");
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
@@ -0,0 +1,79 @@
+//! Generating `src/layout_table.rs`.
+use crate::cldr::Layout;
+use crate::output::{Output, HEADER};
+use std::collections::{BTreeMap, BTreeSet};
+use unic_langid_impl::subtags::{Language, Script};
+use unic_langid_impl::CharacterDirection;
+
+fn scripts(
+    out: &mut Output,
+    name: &str,
+    scripts: &BTreeMap<Script, CharacterDirection>,
+    direction: CharacterDirection,
+) {
+    let values: BTreeSet<(u32, Script)> = scripts
+        .iter()
+        .filter(|(_, d)| **d == direction)
+        .map(|(s, _)| (u32::from(*s), *s))
+        .collect();
+    out.array_start(name, "u32", values.len());
+    for (value, script) in values {
+        out.entry(value, script.as_str());
+    }
+    out.array_end();
+}
+
+pub fn generate(data: &Layout, version: &str) -> Result<String, String> {
+    let mut script_directions: BTreeMap<Script, CharacterDirection> = BTreeMap::new();
+    let mut rtl_languages: BTreeSet<(u64, Language)> = BTreeSet::new();
+
+    for (langid, direction) in data {
+        if let Some(script) = langid.script {
+            match script_directions.insert(script, *direction) {
+                Some(previous) if previous != *direction => {
+                    return Err(format!(
+                        "script {} is both {:?} and {:?}, last in {}",
+                        script, previous, direction, langid
+                    ))
+                }
+                _ => {}
+            }
+        }
+        if *direction == CharacterDirection::RTL {
+            let value = Option::<u64>::from(langid.language)
+                .ok_or_else(|| format!("right-to-left locale without a language: {}", langid))?;
+            rtl_languages.insert((value, langid.language));
+        }
+    }
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+    scripts(
+        &mut out,
+        "SCRIPTS_CHARACTER_DIRECTION_LTR",
+        &script_directions,
+        CharacterDirection::LTR,
+    );
+    scripts(
+        &mut out,
+        "SCRIPTS_CHARACTER_DIRECTION_RTL",
+        &script_directions,
+        CharacterDirection::RTL,
+    );
+    scripts(
+        &mut out,
+        "SCRIPTS_CHARACTER_DIRECTION_TTB",
+        &script_directions,
+        CharacterDirection::TTB,
+    );
+
+    out.array_start("LANGS_CHARACTER_DIRECTION_RTL", "u64", rtl_languages.len());
+    for (value, language) in rtl_languages {
+        out.entry(value, language.as_str());
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
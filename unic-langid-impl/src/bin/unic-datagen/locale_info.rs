@@ -0,0 +1,45 @@
+//! Generating `src/locale_info/tables.rs`.
+use crate::cldr::LocaleInfo;
+use crate::output::{Output, HEADER};
+
+pub fn generate(data: &LocaleInfo, version: &str) -> Result<String, String> {
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line("use super::HourCycle;");
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    out.array_start("CALENDARS", "(&str, &[&str])", data.calendars.len());
+    for (region, calendars) in &data.calendars {
+        out.entry(format!("({:?}, &{:?})", region, calendars), "");
+    }
+    out.array_end();
+
+    out.array_start("HOUR_CYCLES", "(&str, HourCycle)", data.hour_cycles.len());
+    for (key, hour_cycle) in &data.hour_cycles {
+        out.entry(
+            format!(
+                "({:?}, HourCycle::{})",
+                key,
+                hour_cycle.to_ascii_uppercase()
+            ),
+            "",
+        );
+    }
+    out.array_end();
+
+    out.array_start("WEEK_DATA", "(&str, u8, u8, u8, u8)", data.week_data.len());
+    for (region, week) in &data.week_data {
+        out.entry(
+            format!(
+                "({:?}, {}, {}, {}, {})",
+                region, week.first_day, week.weekend_start, week.weekend_end, week.minimal_days
+            ),
+            "",
+        );
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
@@ -0,0 +1,505 @@
+//! Regenerates the CLDR tables baked into `unic-langid-impl` and
+//! `unic-locale-impl`.
+//!
+//! Run from the crate directory with:
+//!
+//! ```text
+//! cargo run --features binary --bin unic-datagen -- --cldr data --cldr-version 44 \
+//!     --keys likelysubtags,layout,iana-registry
+//! cargo run --features binary --bin unic-datagen -- --cldr data --cldr-version 43 \
+//!     --keys display-names,territory-info,keyword-aliases,aliases,parent-locales,locale-info,iso639
+//! cargo run --features binary --bin unic-datagen -- --cldr data/cldr-xml --cldr-version 43 \
+//!     --keys validity
+//! ```
+//!
+//! The files in `data/cldr-bcp47` and `data/cldr-localenames-full`, the
+//! supplemental files of `data/cldr-core` other than `likelySubtags.json`
+//! and the CLDR XML files in `data/cldr-xml` come from CLDR 43, see their
+//! READMEs. `--cldr` also accepts a CLDR XML release,
+//! which is the only one holding the validity data.
+mod aliases;
+mod cldr;
+mod display_names;
+mod iana;
+mod iso639;
+mod keyword_aliases;
+mod layout;
+#[path = "../../../build/likelysubtags.rs"]
+mod likelysubtags;
+mod locale_info;
+#[path = "../../../build/output.rs"]
+mod output;
+mod parent_locales;
+mod territory_info;
+mod validity;
+mod xml;
+
+use likelysubtags::Subset;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use unic_langid_impl::subtags::{Region, Script};
+use unic_langid_impl::LanguageIdentifier;
+
+const USAGE: &str = "\
+Usage: unic-datagen --cldr <DIR> --cldr-version <VERSION> [OPTIONS]
+
+Regenerates the CLDR tables baked into unic-langid-impl and unic-locale-impl.
+
+Options:
+  --cldr <DIR>              CLDR JSON directory, laid out like the cldr-json repository,
+                            or CLDR XML release directory, holding common/,
+                            also holding the SIL code tables in iso-639-3/
+                            and the IANA subtag registry in iana/
+  --cldr-version <VERSION>  CLDR version the data is expected to come from
+  --out <DIR>               Source directory to write the tables to [default: src]
+  --locale-out <DIR>        Source directory of unic-locale-impl to write its tables to
+                            [default: ../unic-locale-impl/src]
+  --keys <KEYS>             Comma separated tables to generate [default: likelysubtags,layout,display-names,territory-info,iso639,iana-registry,
+                            locale-info,keyword-aliases,aliases,parent-locales,validity]
+  --locales <LOCALES>       Comma separated locales whose languages to keep [default: all]
+  --scripts <SCRIPTS>       Comma separated scripts to keep [default: all]
+  --regions <REGIONS>       Comma separated regions to keep [default: all]
+  --previous <DIR>          CLDR JSON or XML directory of the previous version to report changes from
+  --help                    Print this message";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    LikelySubtags,
+    Layout,
+    DisplayNames,
+    TerritoryInfo,
+    Iso639,
+    IanaRegistry,
+    LocaleInfo,
+    KeywordAliases,
+    Aliases,
+    ParentLocales,
+    Validity,
+}
+
+impl Key {
+    const ALL: [Key; 11] = [
+        Key::LikelySubtags,
+        Key::Layout,
+        Key::DisplayNames,
+        Key::TerritoryInfo,
+        Key::Iso639,
+        Key::IanaRegistry,
+        Key::LocaleInfo,
+        Key::KeywordAliases,
+        Key::Aliases,
+        Key::ParentLocales,
+        Key::Validity,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Key::LikelySubtags => "likelysubtags",
+            Key::Layout => "layout",
+            Key::DisplayNames => "display-names",
+            Key::TerritoryInfo => "territory-info",
+            Key::Iso639 => "iso639",
+            Key::IanaRegistry => "iana-registry",
+            Key::LocaleInfo => "locale-info",
+            Key::KeywordAliases => "keyword-aliases",
+            Key::Aliases => "aliases",
+            Key::ParentLocales => "parent-locales",
+            Key::Validity => "validity",
+        }
+    }
+
+    fn path(self) -> &'static str {
+        match self {
+            Key::LikelySubtags => "likelysubtags/tables.rs",
+            Key::Layout => "layout_table.rs",
+            Key::DisplayNames => "display_names/tables.rs",
+            Key::TerritoryInfo => "territory_info/tables.rs",
+            Key::Iso639 => "iso639/tables.rs",
+            Key::IanaRegistry => "iana_registry/tables.rs",
+            Key::LocaleInfo => "locale_info/tables.rs",
+            Key::KeywordAliases => "icu/tables.rs",
+            Key::Aliases => "aliases/tables.rs",
+            Key::ParentLocales => "parent_locales/tables.rs",
+            Key::Validity => "validity/tables.rs",
+        }
+    }
+
+    /// Returns the source directory of the crate the table is baked into.
+    fn out(self, options: &Options) -> &Path {
+        match self {
+            Key::KeywordAliases => &options.locale_out,
+            _ => &options.out,
+        }
+    }
+}
+
+struct Options {
+    cldr: PathBuf,
+    cldr_version: String,
+    out: PathBuf,
+    locale_out: PathBuf,
+    keys: BTreeSet<Key>,
+    subset: Subset,
+    previous: Option<PathBuf>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut cldr = None;
+    let mut cldr_version = None;
+    let mut out = PathBuf::from("src");
+    let mut locale_out = PathBuf::from("../unic-locale-impl/src");
+    let mut keys = BTreeSet::from(Key::ALL);
+    let mut subset = Subset::default();
+    let mut previous = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--help" {
+            println!("{}", USAGE);
+            process::exit(0);
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--cldr" => cldr = Some(PathBuf::from(value)),
+            "--cldr-version" => cldr_version = Some(value),
+            "--out" => out = PathBuf::from(value),
+            "--locale-out" => locale_out = PathBuf::from(value),
+            "--keys" => {
+                keys = value
+                    .split(',')
+                    .map(|name| {
+                        Key::ALL
+                            .into_iter()
+                            .find(|key| key.name() == name)
+                            .ok_or_else(|| format!("unknown key: {}", name))
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "--locales" => {
+                subset.languages = Some(parse_list(&value, |locale| {
+                    locale
+                        .parse::<LanguageIdentifier>()
+                        .map(|langid| cldr::raw(langid.language, None, None).0)
+                })?);
+            }
+            "--scripts" => {
+                subset.scripts = Some(parse_list(&value, |script| {
+                    script.parse::<Script>().map(u32::from)
+                })?);
+            }
+            "--regions" => {
+                subset.regions = Some(parse_list(&value, |region| {
+                    region.parse::<Region>().map(u32::from)
+                })?);
+            }
+            "--previous" => previous = Some(PathBuf::from(value)),
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+
+    Ok(Options {
+        cldr: cldr.ok_or("missing --cldr")?,
+        cldr_version: cldr_version.ok_or("missing --cldr-version")?,
+        out,
+        locale_out,
+        keys,
+        subset,
+        previous,
+    })
+}
+
+fn parse_list<T: Ord, E: std::fmt::Display>(
+    value: &str,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<BTreeSet<T>, String> {
+    value
+        .split(',')
+        .map(|item| parse(item).map_err(|e| format!("invalid subtag {:?}: {}", item, e)))
+        .collect()
+}
+
+/// Prints the entries added, removed and changed between two versions
+/// of a table.
+fn report(key: Key, previous: &BTreeMap<String, String>, current: &BTreeMap<String, String>) {
+    let mut lines = vec![];
+    let (mut added, mut removed, mut changed) = (0, 0, 0);
+    for (k, v) in current {
+        match previous.get(k) {
+            None => {
+                added += 1;
+                lines.push(format!("  + {}: {}", k, v));
+            }
+            Some(old) if old != v => {
+                changed += 1;
+                lines.push(format!("  ~ {}: {} -> {}", k, old, v));
+            }
+            _ => {}
+        }
+    }
+    for (k, v) in previous {
+        if !current.contains_key(k) {
+            removed += 1;
+            lines.push(format!("  - {}: {}", k, v));
+        }
+    }
+    lines.sort_by(|a, b| a[4..].cmp(&b[4..]));
+
+    println!(
+        "{}: {} added, {} removed, {} changed",
+        key.name(),
+        added,
+        removed,
+        changed
+    );
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+/// Loads the table for `key` from `dir`, returning it both as generated
+/// source and as readable entries for reporting changes.
+fn load(
+    key: Key,
+    dir: &Path,
+    version: Option<&str>,
+    options: &Options,
+) -> Result<(BTreeMap<String, String>, Option<String>), String> {
+    let generate = |source: Result<String, String>| match version {
+        Some(_) => source.map(Some),
+        None => Ok(None),
+    };
+    match key {
+        Key::LikelySubtags => {
+            let mut data = cldr::load_likely_subtags(dir, version)?;
+            options.subset.apply(&mut data);
+            let entries = data
+                .iter()
+                .map(|(k, v)| (likelysubtags::to_string(k), likelysubtags::to_string(v)))
+                .collect();
+            let source = generate(likelysubtags::generate(
+                &data,
+                &options.cldr_version,
+                &options.subset,
+            ))?;
+            Ok((entries, source))
+        }
+        Key::Layout => {
+            let mut data = cldr::load_layout(dir, version)?;
+            cldr::subset_layout(&mut data, &options.subset);
+            let entries = data
+                .iter()
+                .map(|(k, v)| (k.to_string(), format!("{:?}", v)))
+                .collect();
+            let source = generate(layout::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::DisplayNames => {
+            let mut data = cldr::load_display_names(dir, version)?;
+            cldr::subset_display_names(&mut data, &options.subset);
+            let mut entries = BTreeMap::new();
+            for (langid, names) in &data {
+                let mut add = |kind: &str, key: String, value: &str| {
+                    entries.insert(format!("{} {} {}", langid, kind, key), value.to_string());
+                };
+                for (kind, pattern) in [
+                    ("pattern", &names.locale_pattern),
+                    ("separator", &names.locale_separator),
+                    ("key-type-pattern", &names.key_type_pattern),
+                ] {
+                    if let Some(pattern) = pattern {
+                        add(kind, String::new(), pattern);
+                    }
+                }
+                for (kind, map) in [
+                    ("language", &names.languages),
+                    ("script", &names.scripts),
+                    ("region", &names.regions),
+                ] {
+                    for ((key, alternate), value) in map {
+                        add(kind, format!("{} {:?}", key, alternate), value);
+                    }
+                }
+                for (key, value) in &names.variants {
+                    add("variant", key.clone(), value);
+                }
+                for (key, value) in &names.keys {
+                    add("key", key.clone(), value);
+                }
+                for ((key, value), name) in &names.types {
+                    add("type", format!("{}-{}", key, value), name);
+                }
+            }
+            let source = generate(display_names::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::TerritoryInfo => {
+            let mut data = cldr::load_territory_info(dir, version)?;
+            cldr::subset_territory_info(&mut data, &options.subset);
+            let mut entries = BTreeMap::new();
+            for (region, territory) in &data {
+                entries.insert(region.to_string(), territory.population.to_string());
+                for language in &territory.languages {
+                    let mut value = format!("{}%", language.percent);
+                    if let Some(status) = language.status {
+                        value.push_str(&format!(" {:?}", status));
+                    }
+                    entries.insert(
+                        format!("{} {}-{}", region, language.language, language.script),
+                        value,
+                    );
+                }
+            }
+            let source = generate(territory_info::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::Iso639 => {
+            let mut data = iso639::load(dir)?;
+            let aliases = cldr::load_macrolanguage_aliases(dir, version)?;
+            iso639::add_macrolanguages(&mut data, &aliases);
+            iso639::subset(&mut data, &options.subset);
+            let mut entries: BTreeMap<String, String> = data
+                .codes
+                .iter()
+                .map(|(canonical, codes)| (canonical.clone(), format!("{:?}", codes)))
+                .collect();
+            for (language, macrolanguage) in &data.macrolanguages {
+                entries.insert(format!("{} macrolanguage", language), macrolanguage.clone());
+            }
+            let source = generate(iso639::generate(&data))?;
+            Ok((entries, source))
+        }
+        Key::IanaRegistry => {
+            let mut data = iana::load(dir)?;
+            iana::subset(&mut data, &options.subset);
+            let mut entries = BTreeMap::new();
+            for (kind, records) in [
+                ("language", &data.languages),
+                ("script", &data.scripts),
+                ("region", &data.regions),
+                ("variant", &data.variants),
+            ] {
+                for (subtag, record) in records {
+                    entries.insert(format!("{} {}", kind, subtag), format!("{:?}", record));
+                }
+            }
+            let source = generate(iana::generate(&data))?;
+            Ok((entries, source))
+        }
+        Key::LocaleInfo => {
+            let mut data = cldr::load_locale_info(dir, version)?;
+            cldr::subset_locale_info(&mut data, &options.subset);
+            let mut entries = BTreeMap::new();
+            for (region, calendars) in &data.calendars {
+                entries.insert(format!("calendars {}", region), calendars.join(" "));
+            }
+            for (key, hour_cycle) in &data.hour_cycles {
+                entries.insert(format!("hour-cycle {}", key), hour_cycle.clone());
+            }
+            for (region, week_data) in &data.week_data {
+                entries.insert(format!("week {}", region), format!("{:?}", week_data));
+            }
+            let source = generate(locale_info::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::KeywordAliases => {
+            let data = cldr::load_keyword_aliases(dir, version)?;
+            let mut entries = BTreeMap::new();
+            for (alias, key) in &data.keys {
+                entries.insert(format!("key {}", alias), key.clone());
+            }
+            for (key, alias, name) in &data.types {
+                entries.insert(format!("type {} {}", key, alias), name.clone());
+            }
+            for (alpha3, region) in &data.regions {
+                entries.insert(format!("region {}", alpha3), region.clone());
+            }
+            let source = generate(keyword_aliases::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::Aliases => {
+            let mut data = cldr::load_aliases(dir, version)?;
+            cldr::subset_aliases(&mut data, &options.subset);
+            let mut entries: BTreeMap<String, String> = data
+                .languages
+                .iter()
+                .map(|(k, v)| (format!("language {}", k), v.to_string()))
+                .collect();
+            for (k, v) in &data.scripts {
+                entries.insert(format!("script {}", k), v.to_string());
+            }
+            for (k, v) in &data.regions {
+                let regions: Vec<&str> = v.iter().map(|r| r.as_str()).collect();
+                entries.insert(format!("region {}", k), regions.join(" "));
+            }
+            for (k, v) in &data.variants {
+                entries.insert(format!("variant {}", k), v.to_string());
+            }
+            for ((key, name), preferred) in &data.keyword_types {
+                entries.insert(format!("keyword {} {}", key, name), preferred.clone());
+            }
+            let source = generate(aliases::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::ParentLocales => {
+            let mut data = cldr::load_parent_locales(dir, version)?;
+            cldr::subset_parent_locales(&mut data, &options.subset);
+            let entries = data
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            let source = generate(parent_locales::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+        Key::Validity => {
+            let mut data = cldr::load_validity(dir, version)?;
+            cldr::subset_validity(&mut data, &options.subset);
+            let mut entries = BTreeMap::new();
+            for (k, v) in &data.languages {
+                entries.insert(format!("language {}", k), format!("{:?}", v));
+            }
+            for (k, v) in &data.scripts {
+                entries.insert(format!("script {}", k), format!("{:?}", v));
+            }
+            for (k, v) in &data.regions {
+                entries.insert(format!("region {}", k), format!("{:?}", v));
+            }
+            for (k, v) in &data.variants {
+                entries.insert(format!("variant {}", k), format!("{:?}", v));
+            }
+            let source = generate(validity::generate(&data, &options.cldr_version))?;
+            Ok((entries, source))
+        }
+    }
+}
+
+fn run(options: Options) -> Result<(), String> {
+    for key in &options.keys {
+        let (entries, source) = load(*key, &options.cldr, Some(&options.cldr_version), &options)?;
+        let source = source.unwrap();
+
+        if let Some(previous) = &options.previous {
+            let (previous, _) = load(*key, previous, None, &options)?;
+            report(*key, &previous, &entries);
+        }
+
+        let path = key.out(&options).join(key.path());
+        if fs::read_to_string(&path).ok().as_ref() == Some(&source) {
+            println!("{} is up to date", path.display());
+        } else {
+            fs::write(&path, source).map_err(|e| format!("{}: {}", path.display(), e))?;
+            println!("wrote {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let result = parse_args(std::env::args().skip(1)).and_then(run);
+    if let Err(error) = result {
+        eprintln!("error: {}\n\n{}", error, USAGE);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,41 @@
+//! Generating `src/parent_locales/tables.rs`.
+use crate::cldr::ParentLocales;
+use crate::output::{Output, HEADER};
+use unic_langid_impl::LanguageIdentifier;
+
+fn raw(langid: &LanguageIdentifier) -> (u64, u32, u32) {
+    (
+        Option::<u64>::from(langid.language).unwrap_or(0),
+        langid.script.map_or(0, u32::from),
+        langid.region.map_or(0, u32::from),
+    )
+}
+
+pub fn generate(data: &ParentLocales, version: &str) -> Result<String, String> {
+    let mut parents: Vec<_> = data.iter().map(|(k, v)| (raw(k), k, v)).collect();
+    parents.sort_by_key(|(raw, ..)| *raw);
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    out.array_start(
+        "PARENTS",
+        "((u64, u32, u32), (u64, u32, u32))",
+        parents.len(),
+    );
+    for ((language, script, region), child, parent) in &parents {
+        let (p_language, p_script, p_region) = raw(parent);
+        out.entry(
+            format!(
+                "(({}, {}, {}), ({}, {}, {}))",
+                language, script, region, p_language, p_script, p_region
+            ),
+            format!("{} -> {}", child, parent),
+        );
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
@@ -0,0 +1,53 @@
+//! Generating `src/territory_info/tables.rs`.
+use crate::cldr::TerritoryInfo;
+use crate::output::{Output, HEADER};
+
+pub fn generate(data: &TerritoryInfo, version: &str) -> Result<String, String> {
+    let mut regions: Vec<_> = data.iter().map(|(r, t)| (u32::from(*r), r, t)).collect();
+    regions.sort_by_key(|(raw, ..)| *raw);
+
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line("use super::OfficialStatus;");
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    out.array_start("POPULATIONS", "(u32, u64)", regions.len());
+    for (raw, region, territory) in &regions {
+        out.entry(
+            format!("({}, {})", raw, territory.population),
+            region.as_str(),
+        );
+    }
+    out.array_end();
+
+    let len = regions.iter().map(|(.., t)| t.languages.len()).sum();
+    out.array_start(
+        "LANGUAGES",
+        "(u32, u64, u32, f32, Option<OfficialStatus>)",
+        len,
+    );
+    for (raw, region, territory) in &regions {
+        for language in &territory.languages {
+            let status = match language.status {
+                Some(status) => format!("Some(OfficialStatus::{:?})", status),
+                None => "None".to_string(),
+            };
+            out.entry(
+                format!(
+                    "({}, {}, {}, {:?}, {})",
+                    raw,
+                    Option::<u64>::from(language.language).unwrap_or(0),
+                    u32::from(language.script),
+                    language.percent,
+                    status
+                ),
+                format!("{}: {}-{}", region, language.language, language.script),
+            );
+        }
+    }
+    out.array_end();
+
+    Ok(out.finish())
+}
@@ -0,0 +1,52 @@
+//! Generating `src/validity/tables.rs`.
+use crate::cldr::Validity;
+use crate::output::{Output, HEADER};
+
+// The key of `und` in the `LANGUAGES` table.
+const UND: u64 = u64::from_le_bytes(*b"und\0\0\0\0\0");
+
+pub fn generate(data: &Validity, version: &str) -> Result<String, String> {
+    let mut out = Output::new();
+    out.line(HEADER);
+    out.line("");
+    out.line("use super::IdStatus;");
+    out.line("");
+    out.line(&format!("pub static CLDR_VERSION: &str = {:?};", version));
+
+    let mut languages: Vec<_> = data
+        .languages
+        .iter()
+        .map(|(l, s)| (Option::<u64>::from(*l).unwrap_or(UND), l.as_str(), s))
+        .collect();
+    let mut scripts: Vec<_> = data
+        .scripts
+        .iter()
+        .map(|(k, s)| (u64::from(u32::from(*k)), k.as_str(), s))
+        .collect();
+    let mut regions: Vec<_> = data
+        .regions
+        .iter()
+        .map(|(k, s)| (u64::from(u32::from(*k)), k.as_str(), s))
+        .collect();
+    let mut variants: Vec<_> = data
+        .variants
+        .iter()
+        .map(|(k, s)| (u64::from(k), k.as_str(), s))
+        .collect();
+
+    for (name, ty, entries) in [
+        ("LANGUAGES", "(u64, IdStatus)", &mut languages),
+        ("SCRIPTS", "(u32, IdStatus)", &mut scripts),
+        ("REGIONS", "(u32, IdStatus)", &mut regions),
+        ("VARIANTS", "(u64, IdStatus)", &mut variants),
+    ] {
+        entries.sort_by_key(|(raw, ..)| *raw);
+        out.array_start(name, ty, entries.len());
+        for (raw, subtag, status) in entries.iter() {
+            out.entry(format!("({}, IdStatus::{:?})", raw, status), subtag);
+        }
+        out.array_end();
+    }
+
+    Ok(out.finish())
+}
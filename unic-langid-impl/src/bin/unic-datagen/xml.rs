@@ -0,0 +1,524 @@
+//! Reading CLDR XML data.
+//!
+//! The input directory is the root of a CLDR release, holding `common/`.
+//! Its files are converted to the structure of the matching CLDR JSON
+//! files, which [`crate::cldr`] then reads the same way. Keys keep their
+//! underscores, like `en_150`, while locales in `common/main` are named
+//! like in CLDR JSON, like `en-150`.
+//!
+//! The CLDR version is read from `common/dtd/ldmlSupplemental.dtd`.
+use crate::cldr::{IdStatus, Validity};
+use roxmltree::{Document, Node, ParsingOptions};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns whether `dir` holds CLDR XML rather than CLDR JSON.
+pub fn is_xml(dir: &Path) -> bool {
+    dir.join("common").join("dtd").is_dir()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn parse<'a>(path: &Path, contents: &'a str) -> Result<Document<'a>, String> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    Document::parse_with_options(contents, options)
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Returns the CLDR version declared by the DTD of the supplemental data.
+pub fn version(dir: &Path) -> Result<(PathBuf, Option<String>), String> {
+    let path = dir.join("common/dtd/ldmlSupplemental.dtd");
+    let contents = read(&path)?;
+    let version = contents.lines().find_map(|line| {
+        let rest = line.split("cldrVersion CDATA #FIXED").nth(1)?;
+        Some(
+            rest.trim()
+                .trim_end_matches('>')
+                .trim()
+                .trim_matches('"')
+                .to_string(),
+        )
+    });
+    Ok((path, version))
+}
+
+fn attribute<'a>(path: &Path, node: Node<'a, '_>, name: &str) -> Result<&'a str, String> {
+    node.attribute(name).ok_or_else(|| {
+        format!(
+            "{}: missing {} attribute of <{}>",
+            path.display(),
+            name,
+            node.tag_name().name()
+        )
+    })
+}
+
+fn elements<'a, 'input>(
+    document: &'a Document<'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    document
+        .descendants()
+        .filter(move |node| node.has_tag_name(name))
+}
+
+/// Reads the supplemental data CLDR JSON has in
+/// `cldr-core/supplemental/<name>.json` from `common/supplemental`.
+pub fn supplemental(dir: &Path, name: &str) -> Result<(PathBuf, Value), String> {
+    let file = match name {
+        "likelySubtags" => "likelySubtags.xml",
+        "aliases" => "supplementalMetadata.xml",
+        _ => "supplementalData.xml",
+    };
+    let path = dir.join("common/supplemental").join(file);
+    let contents = read(&path)?;
+    let document = parse(&path, &contents)?;
+
+    let mut data = Map::new();
+    let mut insert = |field: &str, key: &str, value: Value| {
+        data.entry(field)
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .unwrap()
+            .insert(key.to_string(), value);
+    };
+    match name {
+        "likelySubtags" => {
+            for node in elements(&document, "likelySubtag") {
+                let from = attribute(&path, node, "from")?.replace('_', "-");
+                let to = attribute(&path, node, "to")?.replace('_', "-");
+                insert("likelySubtags", &from, json!(to));
+            }
+        }
+        "languageData" => {
+            for node in elements(&document, "language").filter(|node| {
+                node.parent()
+                    .is_some_and(|parent| parent.has_tag_name("languageData"))
+            }) {
+                let mut key = attribute(&path, node, "type")?.to_string();
+                if let Some(alt) = node.attribute("alt") {
+                    key = format!("{}-alt-{}", key, alt);
+                }
+                let mut value = Map::new();
+                for (attribute, field) in [("scripts", "_scripts"), ("territories", "_territories")]
+                {
+                    if let Some(list) = node.attribute(attribute) {
+                        value.insert(field.to_string(), json!(split(list)));
+                    }
+                }
+                insert("languageData", &key, Value::Object(value));
+            }
+        }
+        "territoryInfo" => {
+            for node in elements(&document, "territory").filter(|node| {
+                node.parent()
+                    .is_some_and(|parent| parent.has_tag_name("territoryInfo"))
+            }) {
+                let mut languages = Map::new();
+                for language in node
+                    .children()
+                    .filter(|n| n.has_tag_name("languagePopulation"))
+                {
+                    let mut value = Map::new();
+                    value.insert(
+                        "_populationPercent".to_string(),
+                        json!(attribute(&path, language, "populationPercent")?),
+                    );
+                    if let Some(status) = language.attribute("officialStatus") {
+                        value.insert("_officialStatus".to_string(), json!(status));
+                    }
+                    languages.insert(
+                        attribute(&path, language, "type")?.to_string(),
+                        Value::Object(value),
+                    );
+                }
+                insert(
+                    "territoryInfo",
+                    attribute(&path, node, "type")?,
+                    json!({
+                        "_population": attribute(&path, node, "population")?,
+                        "languagePopulation": languages,
+                    }),
+                );
+            }
+        }
+        "calendarPreferenceData" => {
+            for node in elements(&document, "calendarPreference") {
+                let ordering = attribute(&path, node, "ordering")?;
+                for territory in split(attribute(&path, node, "territories")?) {
+                    insert("calendarPreferenceData", territory, json!(ordering));
+                }
+            }
+        }
+        "timeData" => {
+            for node in elements(&document, "hours") {
+                let value = json!({
+                    "_allowed": attribute(&path, node, "allowed")?,
+                    "_preferred": attribute(&path, node, "preferred")?,
+                });
+                for region in split(attribute(&path, node, "regions")?) {
+                    insert("timeData", region, value.clone());
+                }
+            }
+        }
+        "weekData" => {
+            let mut week_data = Map::new();
+            for (element, attribute_name) in [
+                ("minDays", "count"),
+                ("firstDay", "day"),
+                ("weekendStart", "day"),
+                ("weekendEnd", "day"),
+            ] {
+                let mut field = Map::new();
+                // Alternate data, like `alt="variant"`, is left out.
+                for node in elements(&document, element).filter(|n| n.attribute("alt").is_none()) {
+                    let value = attribute(&path, node, attribute_name)?;
+                    for territory in split(attribute(&path, node, "territories")?) {
+                        field.insert(territory.to_string(), json!(value));
+                    }
+                }
+                week_data.insert(element.to_string(), Value::Object(field));
+            }
+            data.insert("weekData".to_string(), Value::Object(week_data));
+        }
+        "parentLocales" => {
+            // Parents of other components, like segmentations, are left out.
+            for node in elements(&document, "parentLocale").filter(|node| {
+                node.parent()
+                    .is_some_and(|parent| parent.attribute("component").is_none())
+            }) {
+                let parent = attribute(&path, node, "parent")?;
+                for locale in split(attribute(&path, node, "locales")?) {
+                    insert("parentLocale", locale, json!(parent));
+                }
+            }
+            let parents = data.remove("parentLocale").unwrap_or_else(|| json!({}));
+            data.insert(
+                "parentLocales".to_string(),
+                json!({ "parentLocale": parents }),
+            );
+        }
+        "codeMappings" => {
+            for node in elements(&document, "territoryCodes") {
+                let mut value = Map::new();
+                for (attribute, field) in [("numeric", "_numeric"), ("alpha3", "_alpha3")] {
+                    if let Some(code) = node.attribute(attribute) {
+                        value.insert(field.to_string(), json!(code));
+                    }
+                }
+                insert(
+                    "codeMappings",
+                    attribute(&path, node, "type")?,
+                    Value::Object(value),
+                );
+            }
+        }
+        "aliases" => {
+            let mut aliases = Map::new();
+            for field in [
+                "languageAlias",
+                "scriptAlias",
+                "territoryAlias",
+                "variantAlias",
+            ] {
+                let mut entries = Map::new();
+                for node in elements(&document, field) {
+                    entries.insert(
+                        attribute(&path, node, "type")?.to_string(),
+                        json!({
+                            "_reason": attribute(&path, node, "reason")?,
+                            "_replacement": attribute(&path, node, "replacement")?,
+                        }),
+                    );
+                }
+                aliases.insert(field.to_string(), Value::Object(entries));
+            }
+            data.insert("metadata".to_string(), json!({ "alias": aliases }));
+        }
+        _ => return Err(format!("{}: no supplemental data {}", path.display(), name)),
+    }
+
+    let (_, version) = version(dir)?;
+    data.insert("version".to_string(), json!({ "_cldrVersion": version }));
+    Ok((path, Value::Object(data)))
+}
+
+fn split(list: &str) -> Vec<&str> {
+    list.split_whitespace().collect()
+}
+
+/// Converts the parts of a `common/main` file read by `unic-datagen` to
+/// the structure of CLDR JSON, leaving out unconfirmed and inherited
+/// values.
+fn locale_data(path: &Path) -> Result<Value, String> {
+    let contents = read(path)?;
+    let document = parse(path, &contents)?;
+
+    let values = |parent: &'static str| {
+        elements(&document, parent)
+            .flat_map(|node| node.children())
+            .filter(|node| node.is_element())
+            .filter(|node| {
+                !matches!(
+                    node.attribute("draft"),
+                    Some("unconfirmed") | Some("provisional")
+                )
+            })
+            .filter_map(|node| Some((node, node.text().filter(|text| *text != "↑↑↑")?)))
+            .collect::<Vec<_>>()
+    };
+
+    let mut names = Map::new();
+    for (field, element) in [
+        ("languages", "languages"),
+        ("scripts", "scripts"),
+        ("territories", "territories"),
+        ("variants", "variants"),
+        ("keys", "keys"),
+    ] {
+        let mut entries = Map::new();
+        for (node, text) in values(element) {
+            let mut key = attribute(path, node, "type")?.replace('_', "-");
+            if let Some(alt) = node.attribute("alt") {
+                key = format!("{}-alt-{}", key, alt);
+            }
+            entries.insert(key, json!(text));
+        }
+        if !entries.is_empty() {
+            names.insert(field.to_string(), Value::Object(entries));
+        }
+    }
+
+    let mut types = Map::new();
+    for (node, text) in values("types") {
+        if node.attribute("alt").is_some() {
+            continue;
+        }
+        types
+            .entry(attribute(path, node, "key")?)
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .unwrap()
+            .insert(attribute(path, node, "type")?.to_string(), json!(text));
+    }
+    if !types.is_empty() {
+        names.insert("types".to_string(), Value::Object(types));
+    }
+
+    let mut pattern = Map::new();
+    for (node, text) in values("localeDisplayPattern") {
+        pattern.insert(node.tag_name().name().to_string(), json!(text));
+    }
+    if !pattern.is_empty() {
+        names.insert("localeDisplayPattern".to_string(), Value::Object(pattern));
+    }
+
+    let mut result = Map::new();
+    if !names.is_empty() {
+        result.insert("localeDisplayNames".to_string(), Value::Object(names));
+    }
+    if let Some((_, order)) = values("orientation")
+        .into_iter()
+        .find(|(node, _)| node.has_tag_name("characterOrder"))
+    {
+        result.insert(
+            "layout".to_string(),
+            json!({ "orientation": { "characterOrder": order } }),
+        );
+    }
+    Ok(Value::Object(result))
+}
+
+/// Copies the fields of `from` missing in `into`, recursively.
+fn inherit(into: &mut Value, from: &Value) {
+    if let (Some(into), Some(from)) = (into.as_object_mut(), from.as_object()) {
+        for (key, value) in from {
+            match into.get_mut(key) {
+                Some(existing) => inherit(existing, value),
+                None => {
+                    into.insert(key.clone(), value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Reads the locales of `common/main`, with the data they inherit from
+/// their parent locales filled in like in CLDR JSON, and the path of their
+/// file.
+pub fn main_locales(dir: &Path) -> Result<Vec<(String, PathBuf, Value)>, String> {
+    let main = dir.join("common/main");
+    let mut locales = BTreeMap::new();
+    for entry in fs::read_dir(&main).map_err(|e| format!("{}: {}", main.display(), e))? {
+        let path = entry
+            .map_err(|e| format!("{}: {}", main.display(), e))?
+            .path();
+        if path.extension().is_some_and(|e| e == "xml") {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let data = locale_data(&path)?;
+            locales.insert(name, (path, data));
+        }
+    }
+
+    let (_, supplemental) = supplemental(dir, "parentLocales")?;
+    let parents = &supplemental["parentLocales"]["parentLocale"];
+    let parent = |name: &str| -> Option<String> {
+        match parents[name].as_str() {
+            Some(parent) => Some(parent.to_string()),
+            None if name == "root" => None,
+            None => Some(
+                name.rsplit_once('_')
+                    .map_or("root", |(parent, _)| parent)
+                    .to_string(),
+            ),
+        }
+    };
+
+    let mut result = vec![];
+    for (name, (path, data)) in &locales {
+        let mut resolved = data.clone();
+        let mut ancestor = parent(name);
+        while let Some(name) = ancestor {
+            if let Some((_, data)) = locales.get(&name) {
+                inherit(&mut resolved, data);
+            }
+            ancestor = parent(&name);
+        }
+        result.push((name.replace('_', "-"), path.clone(), resolved));
+    }
+    Ok(result)
+}
+
+/// Reads the `-u-` keys of every file in `common/bcp47`, as the content of
+/// the matching CLDR JSON file, with the path of the file.
+pub fn bcp47(dir: &Path) -> Result<Vec<(PathBuf, Value)>, String> {
+    let bcp47 = dir.join("common/bcp47");
+    let mut paths = fs::read_dir(&bcp47)
+        .map_err(|e| format!("{}: {}", bcp47.display(), e))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("{}: {}", bcp47.display(), e))?;
+    paths.retain(|p| p.extension().is_some_and(|e| e == "xml"));
+    paths.sort();
+
+    let mut result = vec![];
+    for path in paths {
+        let contents = read(&path)?;
+        let document = parse(&path, &contents)?;
+        let mut keys = Map::new();
+        for key in elements(&document, "key")
+            .filter(|key| matches!(key.attribute("extension"), None | Some("u")))
+        {
+            let mut value = Map::new();
+            if let Some(alias) = key.attribute("alias") {
+                value.insert("_alias".to_string(), json!(alias));
+            }
+            for node in key.children().filter(|n| n.has_tag_name("type")) {
+                let mut entry = Map::new();
+                if let Some(alias) = node.attribute("alias") {
+                    entry.insert("_alias".to_string(), json!(alias));
+                }
+                if let Some(preferred) = node.attribute("preferred") {
+                    entry.insert("_preferred".to_string(), json!(preferred));
+                }
+                value.insert(
+                    attribute(&path, node, "name")?.to_string(),
+                    Value::Object(entry),
+                );
+            }
+            keys.insert(
+                attribute(&path, key, "name")?.to_string(),
+                Value::Object(value),
+            );
+        }
+        result.push((path, json!({ "keyword": { "u": keys } })));
+    }
+    Ok(result)
+}
+
+/// Expands a range like `aaa~c` into `aaa`, `aab` and `aac`. The end of
+/// the range replaces as many characters at the end of its start, each of
+/// which goes through its own range.
+fn expand(path: &Path, item: &str) -> Result<Vec<String>, String> {
+    let (start, end) = match item.split_once('~') {
+        Some(range) => range,
+        None => return Ok(vec![item.to_string()]),
+    };
+    let prefix_len = start
+        .len()
+        .checked_sub(end.len())
+        .filter(|_| start.is_ascii() && end.is_ascii())
+        .ok_or_else(|| format!("{}: invalid range {:?}", path.display(), item))?;
+    let (prefix, first) = start.split_at(prefix_len);
+
+    let mut result = vec![prefix.to_string()];
+    for (from, to) in first.bytes().zip(end.bytes()) {
+        if from > to {
+            return Err(format!("{}: invalid range {:?}", path.display(), item));
+        }
+        result = result
+            .iter()
+            .flat_map(|prefix| (from..=to).map(move |b| format!("{}{}", prefix, b as char)))
+            .collect();
+    }
+    Ok(result)
+}
+
+/// Loads the languages, scripts, regions and variants of
+/// `common/validity`, checking that they come from `version` if given.
+pub fn load_validity(dir: &Path, version: Option<&str>) -> Result<Validity, String> {
+    let (path, found) = self::version(dir)?;
+    crate::cldr::check_version(&path, version, found.as_deref())?;
+
+    let mut ids: [BTreeMap<String, IdStatus>; 4] = Default::default();
+    for (ids, kind) in ids
+        .iter_mut()
+        .zip(["language", "script", "region", "variant"])
+    {
+        let path = dir.join(format!("common/validity/{}.xml", kind));
+        let contents = read(&path)?;
+        let document = parse(&path, &contents)?;
+        for node in elements(&document, "id") {
+            if attribute(&path, node, "type")? != kind {
+                continue;
+            }
+            let status = match attribute(&path, node, "idStatus")? {
+                "regular" => IdStatus::Regular,
+                "special" => IdStatus::Special,
+                "macroregion" => IdStatus::Macroregion,
+                "deprecated" => IdStatus::Deprecated,
+                "private_use" => IdStatus::PrivateUse,
+                "reserved" => IdStatus::Reserved,
+                "unknown" => IdStatus::Unknown,
+                status => return Err(format!("{}: unknown idStatus {:?}", path.display(), status)),
+            };
+            // The list may be split by comments, like the item counts.
+            for text in node
+                .children()
+                .filter(|child| child.is_text())
+                .filter_map(|child| child.text())
+            {
+                for item in text.split_whitespace() {
+                    for id in expand(&path, item)? {
+                        ids.insert(id, status);
+                    }
+                }
+            }
+        }
+    }
+    let [languages, scripts, regions, variants] = ids;
+    Validity::from_ids(
+        &dir.join("common/validity"),
+        languages,
+        scripts,
+        regions,
+        variants,
+    )
+}
@@ -0,0 +1,388 @@
+//! Localized display names of language identifiers and their subtags.
+//!
+//! [`DisplayNames`] renders the name of a [`LanguageIdentifier`] in a
+//! display locale using the CLDR `localeDisplayNames` data baked into the
+//! crate, following the [`Locale Display Name Algorithm`].
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::display_names::{DisplayNames, DisplayNamesOptions, LanguageDisplay};
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let langid: LanguageIdentifier = "de-CH".parse().expect("Parsing failed.");
+//!
+//! let options = DisplayNamesOptions {
+//!     language_display: LanguageDisplay::Standard,
+//!     ..Default::default()
+//! };
+//! let de = DisplayNames::new(&"de".parse().expect("Parsing failed."), options)
+//!     .expect("No data for de.");
+//! let en = DisplayNames::new(&"en".parse().expect("Parsing failed."), options)
+//!     .expect("No data for en.");
+//!
+//! assert_eq!(de.language_identifier(&langid), "Deutsch (Schweiz)");
+//! assert_eq!(en.language_identifier(&langid), "German (Switzerland)");
+//!
+//! let en = DisplayNames::new(&"en".parse().expect("Parsing failed."), Default::default())
+//!     .expect("No data for en.");
+//! assert_eq!(en.language_identifier(&langid), "Swiss High German");
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the `cldr-localenames-full`
+//! package, and can be limited to some display locales and named subtags
+//! with its `--locales`, `--scripts` and `--regions` options. Names missing
+//! from the tables fall back to the subtag itself.
+//!
+//! The baked tables hold all of the names of CLDR 43 for the `de`, `en` and
+//! `fr` display locales and their regional sublocales, like `en-AU`. Other
+//! display languages aren't included, and [`DisplayNames::new`] returns
+//! `None` for them.
+//!
+//! [`Locale Display Name Algorithm`]: https://unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm
+#[allow(clippy::type_complexity)]
+mod tables;
+
+use crate::subtags::{Language, Region, Script, Variant};
+use crate::LanguageIdentifier;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+pub use tables::CLDR_VERSION;
+
+const LOCALE_PATTERN: &str = "{0} ({1})";
+const LOCALE_SEPARATOR: &str = "{0}, {1}";
+const KEY_TYPE_PATTERN: &str = "{0}: {1}";
+
+/// How the language of an identifier is named.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LanguageDisplay {
+    /// Use dedicated names for combinations of the language with its
+    /// script or region, like "Swiss High German" for `de-CH`.
+    #[default]
+    Dialect,
+    /// Always name the language alone, like "German (Switzerland)"
+    /// for `de-CH`.
+    Standard,
+}
+
+/// Which of the alternate forms CLDR provides for some names to prefer.
+///
+/// Names without the requested form use the default one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Alternate {
+    /// The default form, like "United Kingdom".
+    #[default]
+    Default,
+    /// A shorter form, like "UK".
+    Short,
+    /// A variant form, like "Ivory Coast" for "Côte d’Ivoire".
+    Variant,
+}
+
+/// Options of [`DisplayNames`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DisplayNamesOptions {
+    pub language_display: LanguageDisplay,
+    pub alternate: Alternate,
+}
+
+type Names = &'static [(&'static str, Alternate, &'static str)];
+
+/// The display names of a single display locale. Entries equal to the
+/// ones of the parent locale are left out.
+pub(crate) struct LocaleData {
+    locale_pattern: Option<&'static str>,
+    locale_separator: Option<&'static str>,
+    key_type_pattern: Option<&'static str>,
+    languages: Names,
+    scripts: Names,
+    regions: Names,
+    variants: &'static [(&'static str, &'static str)],
+    keys: &'static [(&'static str, &'static str)],
+    types: &'static [(&'static str, &'static str, &'static str)],
+}
+
+/// Formats display names in a display locale.
+///
+/// See the [module documentation](self) for an example.
+pub struct DisplayNames {
+    locale: LanguageIdentifier,
+    // The data of the display locale and its parents, most specific first.
+    data: Vec<&'static LocaleData>,
+    options: DisplayNamesOptions,
+}
+
+fn find_locale(locale: &str) -> Option<&'static LocaleData> {
+    tables::LOCALES
+        .binary_search_by(|(key, _)| (*key).cmp(locale))
+        .ok()
+        .map(|idx| &tables::LOCALES[idx].1)
+}
+
+fn find(names: Names, key: &str, alternate: Alternate) -> Option<&'static str> {
+    names
+        .binary_search_by(|(k, alt, _)| (*k, *alt).cmp(&(key, alternate)))
+        .ok()
+        .map(|idx| names[idx].2)
+}
+
+fn apply(pattern: &str, first: &str, second: &str) -> String {
+    pattern.replace("{0}", first).replace("{1}", second)
+}
+
+impl DisplayNames {
+    /// Creates display names for `locale`, using the data of its closest
+    /// available parent.
+    ///
+    /// Returns `None` if there is no data for the language of `locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::display_names::DisplayNames;
+    ///
+    /// let names = DisplayNames::new(&"fr-LU".parse().expect("Parsing failed."), Default::default())
+    ///     .expect("No data for fr.");
+    /// assert_eq!(names.locale().to_string(), "fr");
+    ///
+    /// assert!(DisplayNames::new(&"xx".parse().expect("Parsing failed."), Default::default()).is_none());
+    /// ```
+    pub fn new(locale: &LanguageIdentifier, options: DisplayNamesOptions) -> Option<Self> {
+        let mut candidates = Vec::with_capacity(3);
+        let language = locale.language;
+        candidates.push(LanguageIdentifier::from_parts(
+            language,
+            locale.script,
+            locale.region,
+            &[],
+        ));
+        if locale.script.is_some() && locale.region.is_some() {
+            candidates.push(LanguageIdentifier::from_parts(
+                language,
+                locale.script,
+                None,
+                &[],
+            ));
+        }
+        if locale.script.is_some() || locale.region.is_some() {
+            candidates.push(LanguageIdentifier::from_parts(language, None, None, &[]));
+        }
+
+        let mut resolved = None;
+        let mut data = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            if let Some(locale_data) = find_locale(&candidate.to_string()) {
+                resolved.get_or_insert(candidate);
+                data.push(locale_data);
+            }
+        }
+        Some(Self {
+            locale: resolved?,
+            data,
+            options,
+        })
+    }
+
+    /// Returns the locale whose data is used.
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.locale
+    }
+
+    fn name(&self, names: impl Fn(&LocaleData) -> Names, key: &str) -> Option<&'static str> {
+        let lookup = |alternate| {
+            self.data
+                .iter()
+                .find_map(|data| find(names(data), key, alternate))
+        };
+        match self.options.alternate {
+            Alternate::Default => lookup(Alternate::Default),
+            alternate => lookup(alternate).or_else(|| lookup(Alternate::Default)),
+        }
+    }
+
+    fn pattern(&self, pattern: impl Fn(&LocaleData) -> Option<&'static str>) -> Option<&str> {
+        self.data.iter().find_map(|data| pattern(data))
+    }
+
+    /// Returns the name of `language`, or of `und` for the empty language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::display_names::DisplayNames;
+    ///
+    /// let names = DisplayNames::new(&"fr".parse().expect("Parsing failed."), Default::default())
+    ///     .expect("No data for fr.");
+    /// assert_eq!(names.language("de".parse().expect("Parsing failed.")), Some("allemand"));
+    /// ```
+    pub fn language(&self, language: Language) -> Option<&'static str> {
+        self.name(|data| data.languages, language.as_str())
+    }
+
+    /// Returns the name of `script`.
+    pub fn script(&self, script: Script) -> Option<&'static str> {
+        self.name(|data| data.scripts, script.as_str())
+    }
+
+    /// Returns the name of `region`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::display_names::{Alternate, DisplayNames, DisplayNamesOptions};
+    ///
+    /// let options = DisplayNamesOptions {
+    ///     alternate: Alternate::Short,
+    ///     ..Default::default()
+    /// };
+    /// let names = DisplayNames::new(&"en".parse().expect("Parsing failed."), options)
+    ///     .expect("No data for en.");
+    /// assert_eq!(names.region("GB".parse().expect("Parsing failed.")), Some("UK"));
+    /// assert_eq!(names.region("FR".parse().expect("Parsing failed.")), Some("France"));
+    /// ```
+    pub fn region(&self, region: Region) -> Option<&'static str> {
+        self.name(|data| data.regions, region.as_str())
+    }
+
+    /// Returns the name of `variant`.
+    pub fn variant(&self, variant: Variant) -> Option<&'static str> {
+        self.data.iter().find_map(|data| {
+            data.variants
+                .binary_search_by(|(key, _)| (*key).cmp(variant.as_str()))
+                .ok()
+                .map(|idx| data.variants[idx].1)
+        })
+    }
+
+    /// Returns the name of the Unicode extension `key`, like "Calendar"
+    /// for `ca`.
+    pub fn key(&self, key: &str) -> Option<&'static str> {
+        self.data.iter().find_map(|data| {
+            data.keys
+                .binary_search_by(|(k, _)| (*k).cmp(key))
+                .ok()
+                .map(|idx| data.keys[idx].1)
+        })
+    }
+
+    /// Returns the name of the `value` of the Unicode extension `key`,
+    /// like "Buddhist Calendar" for `ca-buddhist`.
+    pub fn key_type(&self, key: &str, value: &str) -> Option<&'static str> {
+        self.data.iter().find_map(|data| {
+            data.types
+                .binary_search_by(|(k, v, _)| (*k, *v).cmp(&(key, value)))
+                .ok()
+                .map(|idx| data.types[idx].2)
+        })
+    }
+
+    /// Formats the Unicode extension keyword `key` with `value`, using the
+    /// name of the value if there is one, and the name of the key followed
+    /// by the value otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::display_names::DisplayNames;
+    ///
+    /// let names = DisplayNames::new(&"en".parse().expect("Parsing failed."), Default::default())
+    ///     .expect("No data for en.");
+    /// assert_eq!(names.keyword("ca", "buddhist"), "Buddhist Calendar");
+    /// assert_eq!(names.keyword("ca", "abcde"), "Calendar: abcde");
+    /// ```
+    pub fn keyword(&self, key: &str, value: &str) -> String {
+        match self.key_type(key, value) {
+            Some(name) => name.to_string(),
+            None => apply(
+                self.pattern(|data| data.key_type_pattern)
+                    .unwrap_or(KEY_TYPE_PATTERN),
+                self.key(key).unwrap_or(key),
+                value,
+            ),
+        }
+    }
+
+    /// Formats the name of `langid`.
+    ///
+    /// The language is named first, followed by the names of the subtags
+    /// its name doesn't cover, if any. Subtags without a name are shown
+    /// as they are.
+    pub fn language_identifier(&self, langid: &LanguageIdentifier) -> String {
+        self.locale_with_keywords(langid, core::iter::empty())
+    }
+
+    /// Formats the name of `langid` followed by the names of Unicode
+    /// extension `keywords`, as pairs of a key and its value.
+    pub fn locale_with_keywords<'a>(
+        &self,
+        langid: &LanguageIdentifier,
+        keywords: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> String {
+        let language = langid.language.as_str();
+        let mut script = langid.script;
+        let mut region = langid.region;
+
+        let mut name = None;
+        if self.options.language_display == LanguageDisplay::Dialect {
+            let mut candidates = Vec::with_capacity(3);
+            if let (Some(s), Some(r)) = (script, region) {
+                candidates.push((Some(s), Some(r)));
+            }
+            if let Some(r) = region {
+                candidates.push((None, Some(r)));
+            }
+            if let Some(s) = script {
+                candidates.push((Some(s), None));
+            }
+            for (s, r) in candidates {
+                let key = LanguageIdentifier::from_parts(langid.language, s, r, &[]).to_string();
+                if let Some(found) = self.name(|data| data.languages, &key) {
+                    name = Some(found);
+                    script = script.filter(|_| s.is_none());
+                    region = region.filter(|_| r.is_none());
+                    break;
+                }
+            }
+        }
+        let name = name
+            .or_else(|| self.language(langid.language))
+            .unwrap_or(language);
+
+        let mut qualifiers: Vec<String> = Vec::new();
+        if let Some(script) = script {
+            qualifiers.push(self.script(script).unwrap_or(script.as_str()).to_string());
+        }
+        if let Some(region) = region {
+            qualifiers.push(self.region(region).unwrap_or(region.as_str()).to_string());
+        }
+        for variant in langid.variants() {
+            qualifiers.push(
+                self.variant(*variant)
+                    .unwrap_or(variant.as_str())
+                    .to_string(),
+            );
+        }
+        for (key, value) in keywords {
+            qualifiers.push(self.keyword(key, value));
+        }
+
+        let mut qualifiers = qualifiers.into_iter();
+        let first = match qualifiers.next() {
+            Some(first) => first,
+            None => return name.to_string(),
+        };
+        let separator = self
+            .pattern(|data| data.locale_separator)
+            .unwrap_or(LOCALE_SEPARATOR);
+        let qualifiers = qualifiers.fold(first, |result, next| apply(separator, &result, &next));
+        apply(
+            self.pattern(|data| data.locale_pattern)
+                .unwrap_or(LOCALE_PATTERN),
+            name,
+            &qualifiers,
+        )
+    }
+}
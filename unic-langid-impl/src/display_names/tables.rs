@@ -0,0 +1,9952 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+use super::{Alternate, LocaleData};
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static DE_LANGUAGES: [(&str, Alternate, &str); 641] = [
+    ("aa", Alternate::Default, "Afar"),
+    ("ab", Alternate::Default, "Abchasisch"),
+    ("ace", Alternate::Default, "Aceh"),
+    ("ach", Alternate::Default, "Acholi"),
+    ("ada", Alternate::Default, "Adangme"),
+    ("ady", Alternate::Default, "Adygeisch"),
+    ("ae", Alternate::Default, "Avestisch"),
+    ("aeb", Alternate::Default, "Tunesisches Arabisch"),
+    ("af", Alternate::Default, "Afrikaans"),
+    ("afh", Alternate::Default, "Afrihili"),
+    ("agq", Alternate::Default, "Aghem"),
+    ("ain", Alternate::Default, "Ainu"),
+    ("ak", Alternate::Default, "Akan"),
+    ("akk", Alternate::Default, "Akkadisch"),
+    ("akz", Alternate::Default, "Alabama"),
+    ("ale", Alternate::Default, "Aleutisch"),
+    ("aln", Alternate::Default, "Gegisch"),
+    ("alt", Alternate::Default, "Süd-Altaisch"),
+    ("am", Alternate::Default, "Amharisch"),
+    ("an", Alternate::Default, "Aragonesisch"),
+    ("ang", Alternate::Default, "Altenglisch"),
+    ("ann", Alternate::Default, "Obolo"),
+    ("anp", Alternate::Default, "Angika"),
+    ("ar", Alternate::Default, "Arabisch"),
+    ("ar-001", Alternate::Default, "Modernes Hocharabisch"),
+    ("arc", Alternate::Default, "Aramäisch"),
+    ("arn", Alternate::Default, "Mapudungun"),
+    ("aro", Alternate::Default, "Araona"),
+    ("arp", Alternate::Default, "Arapaho"),
+    ("arq", Alternate::Default, "Algerisches Arabisch"),
+    ("ars", Alternate::Default, "Arabisch (Nadschd)"),
+    ("arw", Alternate::Default, "Arawak"),
+    ("ary", Alternate::Default, "Marokkanisches Arabisch"),
+    ("arz", Alternate::Default, "Ägyptisches Arabisch"),
+    ("as", Alternate::Default, "Assamesisch"),
+    ("asa", Alternate::Default, "Asu"),
+    ("ase", Alternate::Default, "Amerikanische Gebärdensprache"),
+    ("ast", Alternate::Default, "Asturisch"),
+    ("atj", Alternate::Default, "Atikamekw"),
+    ("av", Alternate::Default, "Awarisch"),
+    ("avk", Alternate::Default, "Kotava"),
+    ("awa", Alternate::Default, "Awadhi"),
+    ("ay", Alternate::Default, "Aymara"),
+    ("az", Alternate::Default, "Aserbaidschanisch"),
+    ("ba", Alternate::Default, "Baschkirisch"),
+    ("bal", Alternate::Default, "Belutschisch"),
+    ("ban", Alternate::Default, "Balinesisch"),
+    ("bar", Alternate::Default, "Bairisch"),
+    ("bas", Alternate::Default, "Bassa"),
+    ("bax", Alternate::Default, "Bamun"),
+    ("bbc", Alternate::Default, "Batak Toba"),
+    ("bbj", Alternate::Default, "Ghomala"),
+    ("be", Alternate::Default, "Belarussisch"),
+    ("bej", Alternate::Default, "Bedauye"),
+    ("bem", Alternate::Default, "Bemba"),
+    ("bew", Alternate::Default, "Betawi"),
+    ("bez", Alternate::Default, "Bena"),
+    ("bfd", Alternate::Default, "Bafut"),
+    ("bfq", Alternate::Default, "Badaga"),
+    ("bg", Alternate::Default, "Bulgarisch"),
+    ("bgn", Alternate::Default, "Westliches Belutschi"),
+    ("bho", Alternate::Default, "Bhodschpuri"),
+    ("bi", Alternate::Default, "Bislama"),
+    ("bik", Alternate::Default, "Bikol"),
+    ("bin", Alternate::Default, "Bini"),
+    ("bjn", Alternate::Default, "Banjaresisch"),
+    ("bkm", Alternate::Default, "Kom"),
+    ("bla", Alternate::Default, "Blackfoot"),
+    ("bm", Alternate::Default, "Bambara"),
+    ("bn", Alternate::Default, "Bengalisch"),
+    ("bo", Alternate::Default, "Tibetisch"),
+    ("bpy", Alternate::Default, "Bishnupriya"),
+    ("bqi", Alternate::Default, "Bachtiarisch"),
+    ("br", Alternate::Default, "Bretonisch"),
+    ("bra", Alternate::Default, "Braj-Bhakha"),
+    ("brh", Alternate::Default, "Brahui"),
+    ("brx", Alternate::Default, "Bodo"),
+    ("bs", Alternate::Default, "Bosnisch"),
+    ("bss", Alternate::Default, "Akoose"),
+    ("bua", Alternate::Default, "Burjatisch"),
+    ("bug", Alternate::Default, "Buginesisch"),
+    ("bum", Alternate::Default, "Bulu"),
+    ("byn", Alternate::Default, "Blin"),
+    ("byv", Alternate::Default, "Medumba"),
+    ("ca", Alternate::Default, "Katalanisch"),
+    ("cad", Alternate::Default, "Caddo"),
+    ("car", Alternate::Default, "Karibisch"),
+    ("cay", Alternate::Default, "Cayuga"),
+    ("cch", Alternate::Default, "Atsam"),
+    ("ccp", Alternate::Default, "Chakma"),
+    ("ce", Alternate::Default, "Tschetschenisch"),
+    ("ceb", Alternate::Default, "Cebuano"),
+    ("cgg", Alternate::Default, "Rukiga"),
+    ("ch", Alternate::Default, "Chamorro"),
+    ("chb", Alternate::Default, "Chibcha"),
+    ("chg", Alternate::Default, "Tschagataisch"),
+    ("chk", Alternate::Default, "Chuukesisch"),
+    ("chm", Alternate::Default, "Mari"),
+    ("chn", Alternate::Default, "Chinook"),
+    ("cho", Alternate::Default, "Choctaw"),
+    ("chp", Alternate::Default, "Chipewyan"),
+    ("chr", Alternate::Default, "Cherokee"),
+    ("chy", Alternate::Default, "Cheyenne"),
+    ("ckb", Alternate::Default, "Zentralkurdisch"),
+    ("clc", Alternate::Default, "Chilcotin"),
+    ("co", Alternate::Default, "Korsisch"),
+    ("cop", Alternate::Default, "Koptisch"),
+    ("cps", Alternate::Default, "Capiznon"),
+    ("cr", Alternate::Default, "Cree"),
+    ("crg", Alternate::Default, "Michif"),
+    ("crh", Alternate::Default, "Krimtatarisch"),
+    ("crj", Alternate::Default, "Südost-Cree"),
+    ("crk", Alternate::Default, "Plains-Cree"),
+    ("crl", Alternate::Default, "Northern East Cree"),
+    ("crm", Alternate::Default, "Moose Cree"),
+    ("crr", Alternate::Default, "Carolina-Algonkin"),
+    ("crs", Alternate::Default, "Seychellenkreol"),
+    ("cs", Alternate::Default, "Tschechisch"),
+    ("csb", Alternate::Default, "Kaschubisch"),
+    ("csw", Alternate::Default, "Swampy Cree"),
+    ("cu", Alternate::Default, "Kirchenslawisch"),
+    ("cv", Alternate::Default, "Tschuwaschisch"),
+    ("cy", Alternate::Default, "Walisisch"),
+    ("da", Alternate::Default, "Dänisch"),
+    ("dak", Alternate::Default, "Dakota"),
+    ("dar", Alternate::Default, "Darginisch"),
+    ("dav", Alternate::Default, "Taita"),
+    ("de", Alternate::Default, "Deutsch"),
+    ("de-AT", Alternate::Default, "Österreichisches Deutsch"),
+    ("de-CH", Alternate::Default, "Schweizer Hochdeutsch"),
+    ("del", Alternate::Default, "Delaware"),
+    ("den", Alternate::Default, "Slave"),
+    ("dgr", Alternate::Default, "Dogrib"),
+    ("din", Alternate::Default, "Dinka"),
+    ("dje", Alternate::Default, "Zarma"),
+    ("doi", Alternate::Default, "Dogri"),
+    ("dsb", Alternate::Default, "Niedersorbisch"),
+    ("dtp", Alternate::Default, "Zentral-Dusun"),
+    ("dua", Alternate::Default, "Duala"),
+    ("dum", Alternate::Default, "Mittelniederländisch"),
+    ("dv", Alternate::Default, "Dhivehi"),
+    ("dyo", Alternate::Default, "Diola"),
+    ("dyu", Alternate::Default, "Dyula"),
+    ("dz", Alternate::Default, "Dzongkha"),
+    ("dzg", Alternate::Default, "Dazaga"),
+    ("ebu", Alternate::Default, "Embu"),
+    ("ee", Alternate::Default, "Ewe"),
+    ("efi", Alternate::Default, "Efik"),
+    ("egl", Alternate::Default, "Emilianisch"),
+    ("egy", Alternate::Default, "Ägyptisch"),
+    ("eka", Alternate::Default, "Ekajuk"),
+    ("el", Alternate::Default, "Griechisch"),
+    ("elx", Alternate::Default, "Elamisch"),
+    ("en", Alternate::Default, "Englisch"),
+    ("en-GB", Alternate::Short, "Englisch (GB)"),
+    ("en-US", Alternate::Short, "Englisch (USA)"),
+    ("enm", Alternate::Default, "Mittelenglisch"),
+    ("eo", Alternate::Default, "Esperanto"),
+    ("es", Alternate::Default, "Spanisch"),
+    ("esu", Alternate::Default, "Zentral-Alaska-Yupik"),
+    ("et", Alternate::Default, "Estnisch"),
+    ("eu", Alternate::Default, "Baskisch"),
+    ("ewo", Alternate::Default, "Ewondo"),
+    ("ext", Alternate::Default, "Extremadurisch"),
+    ("fa", Alternate::Default, "Persisch"),
+    ("fa-AF", Alternate::Default, "Dari"),
+    ("fan", Alternate::Default, "Pangwe"),
+    ("fat", Alternate::Default, "Fanti"),
+    ("ff", Alternate::Default, "Ful"),
+    ("fi", Alternate::Default, "Finnisch"),
+    ("fil", Alternate::Default, "Filipino"),
+    ("fit", Alternate::Default, "Meänkieli"),
+    ("fj", Alternate::Default, "Fidschi"),
+    ("fo", Alternate::Default, "Färöisch"),
+    ("fon", Alternate::Default, "Fon"),
+    ("fr", Alternate::Default, "Französisch"),
+    ("frc", Alternate::Default, "Cajun"),
+    ("frm", Alternate::Default, "Mittelfranzösisch"),
+    ("fro", Alternate::Default, "Altfranzösisch"),
+    ("frp", Alternate::Default, "Frankoprovenzalisch"),
+    ("frr", Alternate::Default, "Nordfriesisch"),
+    ("frs", Alternate::Default, "Ostfriesisch"),
+    ("fur", Alternate::Default, "Friaulisch"),
+    ("fy", Alternate::Default, "Westfriesisch"),
+    ("ga", Alternate::Default, "Irisch"),
+    ("gaa", Alternate::Default, "Ga"),
+    ("gag", Alternate::Default, "Gagausisch"),
+    ("gan", Alternate::Default, "Gan"),
+    ("gay", Alternate::Default, "Gayo"),
+    ("gba", Alternate::Default, "Gbaya"),
+    ("gbz", Alternate::Default, "Gabri"),
+    ("gd", Alternate::Default, "Gälisch (Schottland)"),
+    ("gez", Alternate::Default, "Geez"),
+    ("gil", Alternate::Default, "Kiribatisch"),
+    ("gl", Alternate::Default, "Galicisch"),
+    ("glk", Alternate::Default, "Gilaki"),
+    ("gmh", Alternate::Default, "Mittelhochdeutsch"),
+    ("gn", Alternate::Default, "Guaraní"),
+    ("goh", Alternate::Default, "Althochdeutsch"),
+    ("gom", Alternate::Default, "Goa-Konkani"),
+    ("gon", Alternate::Default, "Gondi"),
+    ("gor", Alternate::Default, "Mongondou"),
+    ("got", Alternate::Default, "Gotisch"),
+    ("grb", Alternate::Default, "Grebo"),
+    ("grc", Alternate::Default, "Altgriechisch"),
+    ("gsw", Alternate::Default, "Schweizerdeutsch"),
+    ("gu", Alternate::Default, "Gujarati"),
+    ("guc", Alternate::Default, "Wayúu"),
+    ("gur", Alternate::Default, "Farefare"),
+    ("guz", Alternate::Default, "Gusii"),
+    ("gv", Alternate::Default, "Manx"),
+    ("gwi", Alternate::Default, "Kutchin"),
+    ("ha", Alternate::Default, "Haussa"),
+    ("hai", Alternate::Default, "Haida"),
+    ("hak", Alternate::Default, "Hakka"),
+    ("haw", Alternate::Default, "Hawaiisch"),
+    ("hax", Alternate::Default, "Süd-Haida"),
+    ("he", Alternate::Default, "Hebräisch"),
+    ("hi", Alternate::Default, "Hindi"),
+    ("hi-Latn", Alternate::Variant, "Hinglish"),
+    ("hif", Alternate::Default, "Fidschi-Hindi"),
+    ("hil", Alternate::Default, "Hiligaynon"),
+    ("hit", Alternate::Default, "Hethitisch"),
+    ("hmn", Alternate::Default, "Miao"),
+    ("ho", Alternate::Default, "Hiri-Motu"),
+    ("hr", Alternate::Default, "Kroatisch"),
+    ("hsb", Alternate::Default, "Obersorbisch"),
+    ("hsn", Alternate::Default, "Xiang"),
+    ("ht", Alternate::Default, "Haiti-Kreolisch"),
+    ("hu", Alternate::Default, "Ungarisch"),
+    ("hup", Alternate::Default, "Hupa"),
+    ("hur", Alternate::Default, "Halkomelem"),
+    ("hy", Alternate::Default, "Armenisch"),
+    ("hz", Alternate::Default, "Herero"),
+    ("ia", Alternate::Default, "Interlingua"),
+    ("iba", Alternate::Default, "Iban"),
+    ("ibb", Alternate::Default, "Ibibio"),
+    ("id", Alternate::Default, "Indonesisch"),
+    ("ie", Alternate::Default, "Interlingue"),
+    ("ig", Alternate::Default, "Igbo"),
+    ("ii", Alternate::Default, "Yi"),
+    ("ik", Alternate::Default, "Inupiak"),
+    ("ikt", Alternate::Default, "Westkanadisches Inuktitut"),
+    ("ilo", Alternate::Default, "Ilokano"),
+    ("inh", Alternate::Default, "Inguschisch"),
+    ("io", Alternate::Default, "Ido"),
+    ("is", Alternate::Default, "Isländisch"),
+    ("it", Alternate::Default, "Italienisch"),
+    ("iu", Alternate::Default, "Inuktitut"),
+    ("izh", Alternate::Default, "Ischorisch"),
+    ("ja", Alternate::Default, "Japanisch"),
+    ("jam", Alternate::Default, "Jamaikanisch-Kreolisch"),
+    ("jbo", Alternate::Default, "Lojban"),
+    ("jgo", Alternate::Default, "Ngomba"),
+    ("jmc", Alternate::Default, "Machame"),
+    ("jpr", Alternate::Default, "Jüdisch-Persisch"),
+    ("jrb", Alternate::Default, "Jüdisch-Arabisch"),
+    ("jut", Alternate::Default, "Jütisch"),
+    ("jv", Alternate::Default, "Javanisch"),
+    ("ka", Alternate::Default, "Georgisch"),
+    ("kaa", Alternate::Default, "Karakalpakisch"),
+    ("kab", Alternate::Default, "Kabylisch"),
+    ("kac", Alternate::Default, "Kachin"),
+    ("kaj", Alternate::Default, "Jju"),
+    ("kam", Alternate::Default, "Kamba"),
+    ("kaw", Alternate::Default, "Kawi"),
+    ("kbd", Alternate::Default, "Kabardinisch"),
+    ("kbl", Alternate::Default, "Kanembu"),
+    ("kcg", Alternate::Default, "Tyap"),
+    ("kde", Alternate::Default, "Makonde"),
+    ("kea", Alternate::Default, "Kabuverdianu"),
+    ("ken", Alternate::Default, "Kenyang"),
+    ("kfo", Alternate::Default, "Koro"),
+    ("kg", Alternate::Default, "Kongolesisch"),
+    ("kgp", Alternate::Default, "Kaingang"),
+    ("kha", Alternate::Default, "Khasi"),
+    ("kho", Alternate::Default, "Sakisch"),
+    ("khq", Alternate::Default, "Koyra Chiini"),
+    ("khw", Alternate::Default, "Khowar"),
+    ("ki", Alternate::Default, "Kikuyu"),
+    ("kiu", Alternate::Default, "Kirmanjki"),
+    ("kj", Alternate::Default, "Kwanyama"),
+    ("kk", Alternate::Default, "Kasachisch"),
+    ("kkj", Alternate::Default, "Kako"),
+    ("kl", Alternate::Default, "Grönländisch"),
+    ("kln", Alternate::Default, "Kalenjin"),
+    ("km", Alternate::Default, "Khmer"),
+    ("kmb", Alternate::Default, "Kimbundu"),
+    ("kn", Alternate::Default, "Kannada"),
+    ("ko", Alternate::Default, "Koreanisch"),
+    ("koi", Alternate::Default, "Komi-Permjakisch"),
+    ("kok", Alternate::Default, "Konkani"),
+    ("kos", Alternate::Default, "Kosraeanisch"),
+    ("kpe", Alternate::Default, "Kpelle"),
+    ("kr", Alternate::Default, "Kanuri"),
+    ("krc", Alternate::Default, "Karatschaiisch-Balkarisch"),
+    ("kri", Alternate::Default, "Krio"),
+    ("krj", Alternate::Default, "Kinaray-a"),
+    ("krl", Alternate::Default, "Karelisch"),
+    ("kru", Alternate::Default, "Oraon"),
+    ("ks", Alternate::Default, "Kaschmiri"),
+    ("ksb", Alternate::Default, "Shambala"),
+    ("ksf", Alternate::Default, "Bafia"),
+    ("ksh", Alternate::Default, "Kölsch"),
+    ("ku", Alternate::Default, "Kurdisch"),
+    ("kum", Alternate::Default, "Kumükisch"),
+    ("kut", Alternate::Default, "Kutenai"),
+    ("kv", Alternate::Default, "Komi"),
+    ("kw", Alternate::Default, "Kornisch"),
+    ("kwk", Alternate::Default, "Kwakʼwala"),
+    ("ky", Alternate::Default, "Kirgisisch"),
+    ("la", Alternate::Default, "Latein"),
+    ("lad", Alternate::Default, "Ladino"),
+    ("lag", Alternate::Default, "Langi"),
+    ("lah", Alternate::Default, "Lahnda"),
+    ("lam", Alternate::Default, "Lamba"),
+    ("lb", Alternate::Default, "Luxemburgisch"),
+    ("lez", Alternate::Default, "Lesgisch"),
+    ("lfn", Alternate::Default, "Lingua Franca Nova"),
+    ("lg", Alternate::Default, "Ganda"),
+    ("li", Alternate::Default, "Limburgisch"),
+    ("lij", Alternate::Default, "Ligurisch"),
+    ("lil", Alternate::Default, "Lillooet"),
+    ("liv", Alternate::Default, "Livisch"),
+    ("lkt", Alternate::Default, "Lakota"),
+    ("lmo", Alternate::Default, "Lombardisch"),
+    ("ln", Alternate::Default, "Lingala"),
+    ("lo", Alternate::Default, "Laotisch"),
+    ("lol", Alternate::Default, "Mongo"),
+    ("lou", Alternate::Default, "Kreol (Louisiana)"),
+    ("loz", Alternate::Default, "Lozi"),
+    ("lrc", Alternate::Default, "Nördliches Luri"),
+    ("lsm", Alternate::Default, "Saamia"),
+    ("lt", Alternate::Default, "Litauisch"),
+    ("ltg", Alternate::Default, "Lettgallisch"),
+    ("lu", Alternate::Default, "Luba-Katanga"),
+    ("lua", Alternate::Default, "Luba-Lulua"),
+    ("lui", Alternate::Default, "Luiseno"),
+    ("lun", Alternate::Default, "Lunda"),
+    ("luo", Alternate::Default, "Luo"),
+    ("lus", Alternate::Default, "Lushai"),
+    ("luy", Alternate::Default, "Luhya"),
+    ("lv", Alternate::Default, "Lettisch"),
+    ("lzh", Alternate::Default, "Klassisches Chinesisch"),
+    ("lzz", Alternate::Default, "Lasisch"),
+    ("mad", Alternate::Default, "Maduresisch"),
+    ("maf", Alternate::Default, "Mafa"),
+    ("mag", Alternate::Default, "Khotta"),
+    ("mai", Alternate::Default, "Maithili"),
+    ("mak", Alternate::Default, "Makassarisch"),
+    ("man", Alternate::Default, "Malinke"),
+    ("mas", Alternate::Default, "Massai"),
+    ("mde", Alternate::Default, "Maba"),
+    ("mdf", Alternate::Default, "Mokschanisch"),
+    ("mdr", Alternate::Default, "Mandaresisch"),
+    ("men", Alternate::Default, "Mende"),
+    ("mer", Alternate::Default, "Meru"),
+    ("mfe", Alternate::Default, "Morisyen"),
+    ("mg", Alternate::Default, "Malagasy"),
+    ("mga", Alternate::Default, "Mittelirisch"),
+    ("mgh", Alternate::Default, "Makhuwa-Meetto"),
+    ("mgo", Alternate::Default, "Meta’"),
+    ("mh", Alternate::Default, "Marschallesisch"),
+    ("mi", Alternate::Default, "Māori"),
+    ("mic", Alternate::Default, "Micmac"),
+    ("min", Alternate::Default, "Minangkabau"),
+    ("mk", Alternate::Default, "Mazedonisch"),
+    ("ml", Alternate::Default, "Malayalam"),
+    ("mn", Alternate::Default, "Mongolisch"),
+    ("mnc", Alternate::Default, "Mandschurisch"),
+    ("mni", Alternate::Default, "Meithei"),
+    ("moe", Alternate::Default, "Innu-Aimun"),
+    ("moh", Alternate::Default, "Mohawk"),
+    ("mos", Alternate::Default, "Mossi"),
+    ("mr", Alternate::Default, "Marathi"),
+    ("mrj", Alternate::Default, "Bergmari"),
+    ("ms", Alternate::Default, "Malaiisch"),
+    ("mt", Alternate::Default, "Maltesisch"),
+    ("mua", Alternate::Default, "Mundang"),
+    ("mul", Alternate::Default, "Mehrsprachig"),
+    ("mus", Alternate::Default, "Muskogee"),
+    ("mwl", Alternate::Default, "Mirandesisch"),
+    ("mwr", Alternate::Default, "Marwari"),
+    ("mwv", Alternate::Default, "Mentawai"),
+    ("my", Alternate::Default, "Birmanisch"),
+    ("mye", Alternate::Default, "Myene"),
+    ("myv", Alternate::Default, "Ersja-Mordwinisch"),
+    ("mzn", Alternate::Default, "Masanderanisch"),
+    ("na", Alternate::Default, "Nauruisch"),
+    ("nan", Alternate::Default, "Min Nan"),
+    ("nap", Alternate::Default, "Neapolitanisch"),
+    ("naq", Alternate::Default, "Nama"),
+    ("nb", Alternate::Default, "Norwegisch (Bokmål)"),
+    ("nd", Alternate::Default, "Nord-Ndebele"),
+    ("nds", Alternate::Default, "Niederdeutsch"),
+    ("nds-NL", Alternate::Default, "Niedersächsisch"),
+    ("ne", Alternate::Default, "Nepalesisch"),
+    ("new", Alternate::Default, "Newari"),
+    ("ng", Alternate::Default, "Ndonga"),
+    ("nia", Alternate::Default, "Nias"),
+    ("niu", Alternate::Default, "Niue"),
+    ("njo", Alternate::Default, "Ao-Naga"),
+    ("nl", Alternate::Default, "Niederländisch"),
+    ("nl-BE", Alternate::Default, "Flämisch"),
+    ("nmg", Alternate::Default, "Kwasio"),
+    ("nn", Alternate::Default, "Norwegisch (Nynorsk)"),
+    ("nnh", Alternate::Default, "Ngiemboon"),
+    ("no", Alternate::Default, "Norwegisch"),
+    ("nog", Alternate::Default, "Nogai"),
+    ("non", Alternate::Default, "Altnordisch"),
+    ("nov", Alternate::Default, "Novial"),
+    ("nqo", Alternate::Default, "N’Ko"),
+    ("nr", Alternate::Default, "Süd-Ndebele"),
+    ("nso", Alternate::Default, "Nord-Sotho"),
+    ("nus", Alternate::Default, "Nuer"),
+    ("nv", Alternate::Default, "Navajo"),
+    ("nwc", Alternate::Default, "Alt-Newari"),
+    ("ny", Alternate::Default, "Nyanja"),
+    ("nym", Alternate::Default, "Nyamwezi"),
+    ("nyn", Alternate::Default, "Nyankole"),
+    ("nyo", Alternate::Default, "Nyoro"),
+    ("nzi", Alternate::Default, "Nzima"),
+    ("oc", Alternate::Default, "Okzitanisch"),
+    ("oj", Alternate::Default, "Ojibwa"),
+    ("ojb", Alternate::Default, "Nordwest-Ojibwe"),
+    ("ojc", Alternate::Default, "Zentral-Ojibwe"),
+    ("ojs", Alternate::Default, "Oji-Cree"),
+    ("ojw", Alternate::Default, "West-Ojibwe"),
+    ("oka", Alternate::Default, "Okanagan"),
+    ("om", Alternate::Default, "Oromo"),
+    ("or", Alternate::Default, "Oriya"),
+    ("os", Alternate::Default, "Ossetisch"),
+    ("osa", Alternate::Default, "Osage"),
+    ("ota", Alternate::Default, "Osmanisch"),
+    ("pa", Alternate::Default, "Punjabi"),
+    ("pag", Alternate::Default, "Pangasinan"),
+    ("pal", Alternate::Default, "Mittelpersisch"),
+    ("pam", Alternate::Default, "Pampanggan"),
+    ("pap", Alternate::Default, "Papiamento"),
+    ("pau", Alternate::Default, "Palau"),
+    ("pcd", Alternate::Default, "Picardisch"),
+    ("pcm", Alternate::Default, "Nigerianisches Pidgin"),
+    ("pdc", Alternate::Default, "Pennsylvaniadeutsch"),
+    ("pdt", Alternate::Default, "Plautdietsch"),
+    ("peo", Alternate::Default, "Altpersisch"),
+    ("pfl", Alternate::Default, "Pfälzisch"),
+    ("phn", Alternate::Default, "Phönizisch"),
+    ("pi", Alternate::Default, "Pali"),
+    ("pis", Alternate::Default, "Pijin"),
+    ("pl", Alternate::Default, "Polnisch"),
+    ("pms", Alternate::Default, "Piemontesisch"),
+    ("pnt", Alternate::Default, "Pontisch"),
+    ("pon", Alternate::Default, "Ponapeanisch"),
+    ("pqm", Alternate::Default, "Maliseet-Passamaquoddy"),
+    ("prg", Alternate::Default, "Altpreußisch"),
+    ("pro", Alternate::Default, "Altprovenzalisch"),
+    ("ps", Alternate::Default, "Paschtu"),
+    ("pt", Alternate::Default, "Portugiesisch"),
+    ("qu", Alternate::Default, "Quechua"),
+    ("quc", Alternate::Default, "K’iche’"),
+    ("qug", Alternate::Default, "Chimborazo Hochland-Quechua"),
+    ("raj", Alternate::Default, "Rajasthani"),
+    ("rap", Alternate::Default, "Rapanui"),
+    ("rar", Alternate::Default, "Rarotonganisch"),
+    ("rgn", Alternate::Default, "Romagnol"),
+    ("rhg", Alternate::Default, "Rohingyalisch"),
+    ("rif", Alternate::Default, "Tarifit"),
+    ("rm", Alternate::Default, "Rätoromanisch"),
+    ("rn", Alternate::Default, "Rundi"),
+    ("ro", Alternate::Default, "Rumänisch"),
+    ("ro-MD", Alternate::Default, "Moldauisch"),
+    ("rof", Alternate::Default, "Rombo"),
+    ("rom", Alternate::Default, "Romani"),
+    ("rtm", Alternate::Default, "Rotumanisch"),
+    ("ru", Alternate::Default, "Russisch"),
+    ("rue", Alternate::Default, "Russinisch"),
+    ("rug", Alternate::Default, "Roviana"),
+    ("rup", Alternate::Default, "Aromunisch"),
+    ("rw", Alternate::Default, "Kinyarwanda"),
+    ("rwk", Alternate::Default, "Rwa"),
+    ("sa", Alternate::Default, "Sanskrit"),
+    ("sad", Alternate::Default, "Sandawe"),
+    ("sah", Alternate::Default, "Jakutisch"),
+    ("sam", Alternate::Default, "Samaritanisch"),
+    ("saq", Alternate::Default, "Samburu"),
+    ("sas", Alternate::Default, "Sasak"),
+    ("sat", Alternate::Default, "Santali"),
+    ("saz", Alternate::Default, "Saurashtra"),
+    ("sba", Alternate::Default, "Ngambay"),
+    ("sbp", Alternate::Default, "Sangu"),
+    ("sc", Alternate::Default, "Sardisch"),
+    ("scn", Alternate::Default, "Sizilianisch"),
+    ("sco", Alternate::Default, "Schottisch"),
+    ("sd", Alternate::Default, "Sindhi"),
+    ("sdc", Alternate::Default, "Sassarisch"),
+    ("sdh", Alternate::Default, "Südkurdisch"),
+    ("se", Alternate::Default, "Nordsamisch"),
+    ("see", Alternate::Default, "Seneca"),
+    ("seh", Alternate::Default, "Sena"),
+    ("sei", Alternate::Default, "Seri"),
+    ("sel", Alternate::Default, "Selkupisch"),
+    ("ses", Alternate::Default, "Koyra Senni"),
+    ("sg", Alternate::Default, "Sango"),
+    ("sga", Alternate::Default, "Altirisch"),
+    ("sgs", Alternate::Default, "Samogitisch"),
+    ("sh", Alternate::Default, "Serbo-Kroatisch"),
+    ("shi", Alternate::Default, "Taschelhit"),
+    ("shn", Alternate::Default, "Schan"),
+    ("shu", Alternate::Default, "Tschadisch-Arabisch"),
+    ("si", Alternate::Default, "Singhalesisch"),
+    ("sid", Alternate::Default, "Sidamo"),
+    ("sk", Alternate::Default, "Slowakisch"),
+    ("sl", Alternate::Default, "Slowenisch"),
+    ("slh", Alternate::Default, "Süd-Lushootseed"),
+    ("sli", Alternate::Default, "Schlesisch (Niederschlesisch)"),
+    ("sly", Alternate::Default, "Selayar"),
+    ("sm", Alternate::Default, "Samoanisch"),
+    ("sma", Alternate::Default, "Südsamisch"),
+    ("smj", Alternate::Default, "Lule-Samisch"),
+    ("smn", Alternate::Default, "Inari-Samisch"),
+    ("sms", Alternate::Default, "Skolt-Samisch"),
+    ("sn", Alternate::Default, "Shona"),
+    ("snk", Alternate::Default, "Soninke"),
+    ("so", Alternate::Default, "Somali"),
+    ("sog", Alternate::Default, "Sogdisch"),
+    ("sq", Alternate::Default, "Albanisch"),
+    ("sr", Alternate::Default, "Serbisch"),
+    ("srn", Alternate::Default, "Srananisch"),
+    ("srr", Alternate::Default, "Serer"),
+    ("ss", Alternate::Default, "Swazi"),
+    ("ssy", Alternate::Default, "Saho"),
+    ("st", Alternate::Default, "Süd-Sotho"),
+    ("stq", Alternate::Default, "Saterfriesisch"),
+    ("str", Alternate::Default, "Straits Salish"),
+    ("su", Alternate::Default, "Sundanesisch"),
+    ("suk", Alternate::Default, "Sukuma"),
+    ("sus", Alternate::Default, "Susu"),
+    ("sux", Alternate::Default, "Sumerisch"),
+    ("sv", Alternate::Default, "Schwedisch"),
+    ("sw", Alternate::Default, "Suaheli"),
+    ("sw-CD", Alternate::Default, "Kongo-Swahili"),
+    ("swb", Alternate::Default, "Komorisch"),
+    ("syc", Alternate::Default, "Altsyrisch"),
+    ("syr", Alternate::Default, "Syrisch"),
+    ("szl", Alternate::Default, "Schlesisch (Wasserpolnisch)"),
+    ("ta", Alternate::Default, "Tamil"),
+    ("tce", Alternate::Default, "Südliches Tutchone"),
+    ("tcy", Alternate::Default, "Tulu"),
+    ("te", Alternate::Default, "Telugu"),
+    ("tem", Alternate::Default, "Temne"),
+    ("teo", Alternate::Default, "Teso"),
+    ("ter", Alternate::Default, "Tereno"),
+    ("tet", Alternate::Default, "Tetum"),
+    ("tg", Alternate::Default, "Tadschikisch"),
+    ("tgx", Alternate::Default, "Tagish"),
+    ("th", Alternate::Default, "Thailändisch"),
+    ("tht", Alternate::Default, "Tahltan"),
+    ("ti", Alternate::Default, "Tigrinya"),
+    ("tig", Alternate::Default, "Tigre"),
+    ("tiv", Alternate::Default, "Tiv"),
+    ("tk", Alternate::Default, "Turkmenisch"),
+    ("tkl", Alternate::Default, "Tokelauanisch"),
+    ("tkr", Alternate::Default, "Tsachurisch"),
+    ("tl", Alternate::Default, "Tagalog"),
+    ("tlh", Alternate::Default, "Klingonisch"),
+    ("tli", Alternate::Default, "Tlingit"),
+    ("tly", Alternate::Default, "Talisch"),
+    ("tmh", Alternate::Default, "Tamaseq"),
+    ("tn", Alternate::Default, "Tswana"),
+    ("to", Alternate::Default, "Tongaisch"),
+    ("tog", Alternate::Default, "Nyasa Tonga"),
+    ("tok", Alternate::Default, "Toki Pona"),
+    ("tpi", Alternate::Default, "Neumelanesisch"),
+    ("tr", Alternate::Default, "Türkisch"),
+    ("tru", Alternate::Default, "Turoyo"),
+    ("trv", Alternate::Default, "Taroko"),
+    ("ts", Alternate::Default, "Tsonga"),
+    ("tsd", Alternate::Default, "Tsakonisch"),
+    ("tsi", Alternate::Default, "Tsimshian"),
+    ("tt", Alternate::Default, "Tatarisch"),
+    ("ttm", Alternate::Default, "Nördliches Tutchone"),
+    ("ttt", Alternate::Default, "Tatisch"),
+    ("tum", Alternate::Default, "Tumbuka"),
+    ("tvl", Alternate::Default, "Tuvaluisch"),
+    ("tw", Alternate::Default, "Twi"),
+    ("twq", Alternate::Default, "Tasawaq"),
+    ("ty", Alternate::Default, "Tahitisch"),
+    ("tyv", Alternate::Default, "Tuwinisch"),
+    ("tzm", Alternate::Default, "Zentralatlas-Tamazight"),
+    ("udm", Alternate::Default, "Udmurtisch"),
+    ("ug", Alternate::Default, "Uigurisch"),
+    ("uga", Alternate::Default, "Ugaritisch"),
+    ("uk", Alternate::Default, "Ukrainisch"),
+    ("umb", Alternate::Default, "Umbundu"),
+    ("und", Alternate::Default, "Unbekannte Sprache"),
+    ("ur", Alternate::Default, "Urdu"),
+    ("uz", Alternate::Default, "Usbekisch"),
+    ("vai", Alternate::Default, "Vai"),
+    ("ve", Alternate::Default, "Venda"),
+    ("vec", Alternate::Default, "Venetisch"),
+    ("vep", Alternate::Default, "Wepsisch"),
+    ("vi", Alternate::Default, "Vietnamesisch"),
+    ("vls", Alternate::Default, "Westflämisch"),
+    ("vmf", Alternate::Default, "Mainfränkisch"),
+    ("vo", Alternate::Default, "Volapük"),
+    ("vot", Alternate::Default, "Wotisch"),
+    ("vro", Alternate::Default, "Võro"),
+    ("vun", Alternate::Default, "Vunjo"),
+    ("wa", Alternate::Default, "Wallonisch"),
+    ("wae", Alternate::Default, "Walliserdeutsch"),
+    ("wal", Alternate::Default, "Walamo"),
+    ("war", Alternate::Default, "Waray"),
+    ("was", Alternate::Default, "Washo"),
+    ("wbp", Alternate::Default, "Warlpiri"),
+    ("wo", Alternate::Default, "Wolof"),
+    ("wuu", Alternate::Default, "Wu"),
+    ("xal", Alternate::Default, "Kalmückisch"),
+    ("xh", Alternate::Default, "Xhosa"),
+    ("xmf", Alternate::Default, "Mingrelisch"),
+    ("xog", Alternate::Default, "Soga"),
+    ("yao", Alternate::Default, "Yao"),
+    ("yap", Alternate::Default, "Yapesisch"),
+    ("yav", Alternate::Default, "Yangben"),
+    ("ybb", Alternate::Default, "Yemba"),
+    ("yi", Alternate::Default, "Jiddisch"),
+    ("yo", Alternate::Default, "Yoruba"),
+    ("yrl", Alternate::Default, "Nheengatu"),
+    ("yue", Alternate::Default, "Kantonesisch"),
+    ("za", Alternate::Default, "Zhuang"),
+    ("zap", Alternate::Default, "Zapotekisch"),
+    ("zbl", Alternate::Default, "Bliss-Symbole"),
+    ("zea", Alternate::Default, "Seeländisch"),
+    ("zen", Alternate::Default, "Zenaga"),
+    ("zgh", Alternate::Default, "Tamazight"),
+    ("zh", Alternate::Default, "Chinesisch"),
+    ("zh-Hans", Alternate::Default, "Chinesisch (vereinfacht)"),
+    ("zh-Hant", Alternate::Default, "Chinesisch (traditionell)"),
+    ("zu", Alternate::Default, "Zulu"),
+    ("zun", Alternate::Default, "Zuni"),
+    ("zxx", Alternate::Default, "Keine Sprachinhalte"),
+    ("zza", Alternate::Default, "Zaza"),
+];
+
+#[rustfmt::skip]
+pub static DE_SCRIPTS: [(&str, Alternate, &str); 169] = [
+    ("Adlm", Alternate::Default, "Adlam"),
+    ("Afak", Alternate::Default, "Afaka"),
+    ("Aghb", Alternate::Default, "Kaukasisch-Albanisch"),
+    ("Arab", Alternate::Default, "Arabisch"),
+    ("Arab", Alternate::Variant, "Persisch"),
+    ("Aran", Alternate::Default, "Nastaliq"),
+    ("Armn", Alternate::Default, "Armenisch"),
+    ("Avst", Alternate::Default, "Avestisch"),
+    ("Bali", Alternate::Default, "Balinesisch"),
+    ("Bamu", Alternate::Default, "Bamun"),
+    ("Bass", Alternate::Default, "Bassa"),
+    ("Batk", Alternate::Default, "Battakisch"),
+    ("Beng", Alternate::Default, "Bengalisch"),
+    ("Blis", Alternate::Default, "Bliss-Symbole"),
+    ("Bopo", Alternate::Default, "Bopomofo"),
+    ("Brah", Alternate::Default, "Brahmi"),
+    ("Brai", Alternate::Default, "Braille"),
+    ("Bugi", Alternate::Default, "Buginesisch"),
+    ("Buhd", Alternate::Default, "Buhid"),
+    ("Cakm", Alternate::Default, "Chakma"),
+    ("Cans", Alternate::Default, "UCAS"),
+    ("Cari", Alternate::Default, "Karisch"),
+    ("Cher", Alternate::Default, "Cherokee"),
+    ("Cirt", Alternate::Default, "Cirth"),
+    ("Copt", Alternate::Default, "Koptisch"),
+    ("Cprt", Alternate::Default, "Zypriotisch"),
+    ("Cyrl", Alternate::Default, "Kyrillisch"),
+    ("Cyrs", Alternate::Default, "Altkirchenslawisch"),
+    ("Deva", Alternate::Default, "Devanagari"),
+    ("Dsrt", Alternate::Default, "Deseret"),
+    ("Dupl", Alternate::Default, "Duployanisch"),
+    ("Egyd", Alternate::Default, "Ägyptisch - Demotisch"),
+    ("Egyh", Alternate::Default, "Ägyptisch - Hieratisch"),
+    ("Egyp", Alternate::Default, "Ägyptische Hieroglyphen"),
+    ("Elba", Alternate::Default, "Elbasanisch"),
+    ("Ethi", Alternate::Default, "Äthiopisch"),
+    ("Geok", Alternate::Default, "Khutsuri"),
+    ("Geor", Alternate::Default, "Georgisch"),
+    ("Glag", Alternate::Default, "Glagolitisch"),
+    ("Goth", Alternate::Default, "Gotisch"),
+    ("Gran", Alternate::Default, "Grantha"),
+    ("Grek", Alternate::Default, "Griechisch"),
+    ("Gujr", Alternate::Default, "Gujarati"),
+    ("Guru", Alternate::Default, "Gurmukhi"),
+    ("Hanb", Alternate::Default, "Han mit Bopomofo"),
+    ("Hang", Alternate::Default, "Hangul"),
+    ("Hani", Alternate::Default, "Chinesisch"),
+    ("Hano", Alternate::Default, "Hanunoo"),
+    ("Hans", Alternate::Default, "Vereinfacht"),
+    ("Hant", Alternate::Default, "Traditionell"),
+    ("Hebr", Alternate::Default, "Hebräisch"),
+    ("Hira", Alternate::Default, "Hiragana"),
+    ("Hluw", Alternate::Default, "Hieroglyphen-Luwisch"),
+    ("Hmng", Alternate::Default, "Pahawh Hmong"),
+    ("Hrkt", Alternate::Default, "Japanische Silbenschrift"),
+    ("Hung", Alternate::Default, "Altungarisch"),
+    ("Inds", Alternate::Default, "Indus-Schrift"),
+    ("Ital", Alternate::Default, "Altitalisch"),
+    ("Jamo", Alternate::Default, "Jamo"),
+    ("Java", Alternate::Default, "Javanesisch"),
+    ("Jpan", Alternate::Default, "Japanisch"),
+    ("Jurc", Alternate::Default, "Jurchen"),
+    ("Kali", Alternate::Default, "Kayah Li"),
+    ("Kana", Alternate::Default, "Katakana"),
+    ("Khar", Alternate::Default, "Kharoshthi"),
+    ("Khmr", Alternate::Default, "Khmer"),
+    ("Khoj", Alternate::Default, "Khojki"),
+    ("Knda", Alternate::Default, "Kannada"),
+    ("Kore", Alternate::Default, "Koreanisch"),
+    ("Kpel", Alternate::Default, "Kpelle"),
+    ("Kthi", Alternate::Default, "Kaithi"),
+    ("Lana", Alternate::Default, "Lanna"),
+    ("Laoo", Alternate::Default, "Laotisch"),
+    ("Latf", Alternate::Default, "Lateinisch - Fraktur-Variante"),
+    ("Latg", Alternate::Default, "Lateinisch - Gälische Variante"),
+    ("Latn", Alternate::Default, "Lateinisch"),
+    ("Lepc", Alternate::Default, "Lepcha"),
+    ("Limb", Alternate::Default, "Limbu"),
+    ("Lina", Alternate::Default, "Linear A"),
+    ("Linb", Alternate::Default, "Linear B"),
+    ("Lisu", Alternate::Default, "Fraser"),
+    ("Loma", Alternate::Default, "Loma"),
+    ("Lyci", Alternate::Default, "Lykisch"),
+    ("Lydi", Alternate::Default, "Lydisch"),
+    ("Mahj", Alternate::Default, "Mahajani"),
+    ("Mand", Alternate::Default, "Mandäisch"),
+    ("Mani", Alternate::Default, "Manichäisch"),
+    ("Maya", Alternate::Default, "Maya-Hieroglyphen"),
+    ("Mend", Alternate::Default, "Mende"),
+    ("Merc", Alternate::Default, "Meroitisch kursiv"),
+    ("Mero", Alternate::Default, "Meroitisch"),
+    ("Mlym", Alternate::Default, "Malayalam"),
+    ("Mong", Alternate::Default, "Mongolisch"),
+    ("Moon", Alternate::Default, "Moon"),
+    ("Mroo", Alternate::Default, "Mro"),
+    ("Mtei", Alternate::Default, "Meitei-Mayek"),
+    ("Mymr", Alternate::Default, "Birmanisch"),
+    ("Narb", Alternate::Default, "Altnordarabisch"),
+    ("Nbat", Alternate::Default, "Nabatäisch"),
+    ("Nkgb", Alternate::Default, "Geba"),
+    ("Nkoo", Alternate::Default, "N’Ko"),
+    ("Nshu", Alternate::Default, "Frauenschrift"),
+    ("Ogam", Alternate::Default, "Ogham"),
+    ("Olck", Alternate::Default, "Ol Chiki"),
+    ("Orkh", Alternate::Default, "Orchon-Runen"),
+    ("Orya", Alternate::Default, "Oriya"),
+    ("Osma", Alternate::Default, "Osmanisch"),
+    ("Palm", Alternate::Default, "Palmyrenisch"),
+    ("Pauc", Alternate::Default, "Pau Cin Hau"),
+    ("Perm", Alternate::Default, "Altpermisch"),
+    ("Phag", Alternate::Default, "Phags-pa"),
+    ("Phli", Alternate::Default, "Buch-Pahlavi"),
+    ("Phlp", Alternate::Default, "Psalter-Pahlavi"),
+    ("Phlv", Alternate::Default, "Pahlavi"),
+    ("Phnx", Alternate::Default, "Phönizisch"),
+    ("Plrd", Alternate::Default, "Pollard Phonetisch"),
+    ("Prti", Alternate::Default, "Parthisch"),
+    ("Qaag", Alternate::Default, "Zawgyi"),
+    ("Rjng", Alternate::Default, "Rejang"),
+    ("Rohg", Alternate::Default, "Hanifi Rohingya"),
+    ("Roro", Alternate::Default, "Rongorongo"),
+    ("Runr", Alternate::Default, "Runenschrift"),
+    ("Samr", Alternate::Default, "Samaritanisch"),
+    ("Sara", Alternate::Default, "Sarati"),
+    ("Sarb", Alternate::Default, "Altsüdarabisch"),
+    ("Saur", Alternate::Default, "Saurashtra"),
+    ("Sgnw", Alternate::Default, "Gebärdensprache"),
+    ("Shaw", Alternate::Default, "Shaw-Alphabet"),
+    ("Shrd", Alternate::Default, "Sharada"),
+    ("Sidd", Alternate::Default, "Siddham"),
+    ("Sind", Alternate::Default, "Khudawadi"),
+    ("Sinh", Alternate::Default, "Singhalesisch"),
+    ("Sora", Alternate::Default, "Sora Sompeng"),
+    ("Sund", Alternate::Default, "Sundanesisch"),
+    ("Sylo", Alternate::Default, "Syloti Nagri"),
+    ("Syrc", Alternate::Default, "Syrisch"),
+    ("Syre", Alternate::Default, "Syrisch - Estrangelo-Variante"),
+    ("Syrj", Alternate::Default, "Westsyrisch"),
+    ("Syrn", Alternate::Default, "Ostsyrisch"),
+    ("Tagb", Alternate::Default, "Tagbanwa"),
+    ("Takr", Alternate::Default, "Takri"),
+    ("Tale", Alternate::Default, "Tai Le"),
+    ("Talu", Alternate::Default, "Tai Lue"),
+    ("Taml", Alternate::Default, "Tamilisch"),
+    ("Tang", Alternate::Default, "Xixia"),
+    ("Tavt", Alternate::Default, "Tai-Viet"),
+    ("Telu", Alternate::Default, "Telugu"),
+    ("Teng", Alternate::Default, "Tengwar"),
+    ("Tfng", Alternate::Default, "Tifinagh"),
+    ("Tglg", Alternate::Default, "Tagalog"),
+    ("Thaa", Alternate::Default, "Thaana"),
+    ("Thai", Alternate::Default, "Thai"),
+    ("Tibt", Alternate::Default, "Tibetisch"),
+    ("Tirh", Alternate::Default, "Tirhuta"),
+    ("Ugar", Alternate::Default, "Ugaritisch"),
+    ("Vaii", Alternate::Default, "Vai"),
+    ("Visp", Alternate::Default, "Sichtbare Sprache"),
+    ("Wara", Alternate::Default, "Varang Kshiti"),
+    ("Wole", Alternate::Default, "Woleaianisch"),
+    ("Xpeo", Alternate::Default, "Altpersisch"),
+    ("Xsux", Alternate::Default, "Sumerisch-akkadische Keilschrift"),
+    ("Yiii", Alternate::Default, "Yi"),
+    ("Zinh", Alternate::Default, "Geerbter Schriftwert"),
+    ("Zmth", Alternate::Default, "Mathematische Notation"),
+    ("Zsye", Alternate::Default, "Emoji"),
+    ("Zsym", Alternate::Default, "Symbole"),
+    ("Zxxx", Alternate::Default, "Schriftlos"),
+    ("Zyyy", Alternate::Default, "Verbreitet"),
+    ("Zzzz", Alternate::Default, "Unbekannte Schrift"),
+];
+
+#[rustfmt::skip]
+pub static DE_REGIONS: [(&str, Alternate, &str); 308] = [
+    ("001", Alternate::Default, "Welt"),
+    ("002", Alternate::Default, "Afrika"),
+    ("003", Alternate::Default, "Nordamerika"),
+    ("005", Alternate::Default, "Südamerika"),
+    ("009", Alternate::Default, "Ozeanien"),
+    ("011", Alternate::Default, "Westafrika"),
+    ("013", Alternate::Default, "Mittelamerika"),
+    ("014", Alternate::Default, "Ostafrika"),
+    ("015", Alternate::Default, "Nordafrika"),
+    ("017", Alternate::Default, "Zentralafrika"),
+    ("018", Alternate::Default, "Südliches Afrika"),
+    ("019", Alternate::Default, "Amerika"),
+    ("021", Alternate::Default, "Nördliches Amerika"),
+    ("029", Alternate::Default, "Karibik"),
+    ("030", Alternate::Default, "Ostasien"),
+    ("034", Alternate::Default, "Südasien"),
+    ("035", Alternate::Default, "Südostasien"),
+    ("039", Alternate::Default, "Südeuropa"),
+    ("053", Alternate::Default, "Australasien"),
+    ("054", Alternate::Default, "Melanesien"),
+    ("057", Alternate::Default, "Mikronesisches Inselgebiet"),
+    ("061", Alternate::Default, "Polynesien"),
+    ("142", Alternate::Default, "Asien"),
+    ("143", Alternate::Default, "Zentralasien"),
+    ("145", Alternate::Default, "Westasien"),
+    ("150", Alternate::Default, "Europa"),
+    ("151", Alternate::Default, "Osteuropa"),
+    ("154", Alternate::Default, "Nordeuropa"),
+    ("155", Alternate::Default, "Westeuropa"),
+    ("202", Alternate::Default, "Subsahara-Afrika"),
+    ("419", Alternate::Default, "Lateinamerika"),
+    ("AC", Alternate::Default, "Ascension"),
+    ("AD", Alternate::Default, "Andorra"),
+    ("AE", Alternate::Default, "Vereinigte Arabische Emirate"),
+    ("AF", Alternate::Default, "Afghanistan"),
+    ("AG", Alternate::Default, "Antigua und Barbuda"),
+    ("AI", Alternate::Default, "Anguilla"),
+    ("AL", Alternate::Default, "Albanien"),
+    ("AM", Alternate::Default, "Armenien"),
+    ("AO", Alternate::Default, "Angola"),
+    ("AQ", Alternate::Default, "Antarktis"),
+    ("AR", Alternate::Default, "Argentinien"),
+    ("AS", Alternate::Default, "Amerikanisch-Samoa"),
+    ("AT", Alternate::Default, "Österreich"),
+    ("AU", Alternate::Default, "Australien"),
+    ("AW", Alternate::Default, "Aruba"),
+    ("AX", Alternate::Default, "Ålandinseln"),
+    ("AZ", Alternate::Default, "Aserbaidschan"),
+    ("BA", Alternate::Default, "Bosnien und Herzegowina"),
+    ("BB", Alternate::Default, "Barbados"),
+    ("BD", Alternate::Default, "Bangladesch"),
+    ("BE", Alternate::Default, "Belgien"),
+    ("BF", Alternate::Default, "Burkina Faso"),
+    ("BG", Alternate::Default, "Bulgarien"),
+    ("BH", Alternate::Default, "Bahrain"),
+    ("BI", Alternate::Default, "Burundi"),
+    ("BJ", Alternate::Default, "Benin"),
+    ("BL", Alternate::Default, "St. Barthélemy"),
+    ("BM", Alternate::Default, "Bermuda"),
+    ("BN", Alternate::Default, "Brunei Darussalam"),
+    ("BO", Alternate::Default, "Bolivien"),
+    ("BQ", Alternate::Default, "Karibische Niederlande"),
+    ("BR", Alternate::Default, "Brasilien"),
+    ("BS", Alternate::Default, "Bahamas"),
+    ("BT", Alternate::Default, "Bhutan"),
+    ("BV", Alternate::Default, "Bouvetinsel"),
+    ("BW", Alternate::Default, "Botsuana"),
+    ("BY", Alternate::Default, "Belarus"),
+    ("BZ", Alternate::Default, "Belize"),
+    ("CA", Alternate::Default, "Kanada"),
+    ("CC", Alternate::Default, "Kokosinseln"),
+    ("CD", Alternate::Default, "Kongo-Kinshasa"),
+    ("CD", Alternate::Variant, "Kongo (Demokratische Republik)"),
+    ("CF", Alternate::Default, "Zentralafrikanische Republik"),
+    ("CG", Alternate::Default, "Kongo-Brazzaville"),
+    ("CG", Alternate::Variant, "Kongo (Republik)"),
+    ("CH", Alternate::Default, "Schweiz"),
+    ("CI", Alternate::Default, "Côte d’Ivoire"),
+    ("CI", Alternate::Variant, "Elfenbeinküste"),
+    ("CK", Alternate::Default, "Cookinseln"),
+    ("CL", Alternate::Default, "Chile"),
+    ("CM", Alternate::Default, "Kamerun"),
+    ("CN", Alternate::Default, "China"),
+    ("CO", Alternate::Default, "Kolumbien"),
+    ("CP", Alternate::Default, "Clipperton-Insel"),
+    ("CR", Alternate::Default, "Costa Rica"),
+    ("CU", Alternate::Default, "Kuba"),
+    ("CV", Alternate::Default, "Cabo Verde"),
+    ("CW", Alternate::Default, "Curaçao"),
+    ("CX", Alternate::Default, "Weihnachtsinsel"),
+    ("CY", Alternate::Default, "Zypern"),
+    ("CZ", Alternate::Default, "Tschechien"),
+    ("CZ", Alternate::Variant, "Tschechische Republik"),
+    ("DE", Alternate::Default, "Deutschland"),
+    ("DG", Alternate::Default, "Diego Garcia"),
+    ("DJ", Alternate::Default, "Dschibuti"),
+    ("DK", Alternate::Default, "Dänemark"),
+    ("DM", Alternate::Default, "Dominica"),
+    ("DO", Alternate::Default, "Dominikanische Republik"),
+    ("DZ", Alternate::Default, "Algerien"),
+    ("EA", Alternate::Default, "Ceuta und Melilla"),
+    ("EC", Alternate::Default, "Ecuador"),
+    ("EE", Alternate::Default, "Estland"),
+    ("EG", Alternate::Default, "Ägypten"),
+    ("EH", Alternate::Default, "Westsahara"),
+    ("ER", Alternate::Default, "Eritrea"),
+    ("ES", Alternate::Default, "Spanien"),
+    ("ET", Alternate::Default, "Äthiopien"),
+    ("EU", Alternate::Default, "Europäische Union"),
+    ("EZ", Alternate::Default, "Eurozone"),
+    ("FI", Alternate::Default, "Finnland"),
+    ("FJ", Alternate::Default, "Fidschi"),
+    ("FK", Alternate::Default, "Falklandinseln"),
+    ("FK", Alternate::Variant, "Falklandinseln (Malwinen)"),
+    ("FM", Alternate::Default, "Mikronesien"),
+    ("FO", Alternate::Default, "Färöer"),
+    ("FR", Alternate::Default, "Frankreich"),
+    ("GA", Alternate::Default, "Gabun"),
+    ("GB", Alternate::Default, "Vereinigtes Königreich"),
+    ("GB", Alternate::Short, "UK"),
+    ("GD", Alternate::Default, "Grenada"),
+    ("GE", Alternate::Default, "Georgien"),
+    ("GF", Alternate::Default, "Französisch-Guayana"),
+    ("GG", Alternate::Default, "Guernsey"),
+    ("GH", Alternate::Default, "Ghana"),
+    ("GI", Alternate::Default, "Gibraltar"),
+    ("GL", Alternate::Default, "Grönland"),
+    ("GM", Alternate::Default, "Gambia"),
+    ("GN", Alternate::Default, "Guinea"),
+    ("GP", Alternate::Default, "Guadeloupe"),
+    ("GQ", Alternate::Default, "Äquatorialguinea"),
+    ("GR", Alternate::Default, "Griechenland"),
+    ("GS", Alternate::Default, "Südgeorgien und die Südlichen Sandwichinseln"),
+    ("GT", Alternate::Default, "Guatemala"),
+    ("GU", Alternate::Default, "Guam"),
+    ("GW", Alternate::Default, "Guinea-Bissau"),
+    ("GY", Alternate::Default, "Guyana"),
+    ("HK", Alternate::Default, "Sonderverwaltungsregion Hongkong"),
+    ("HK", Alternate::Short, "Hongkong"),
+    ("HM", Alternate::Default, "Heard und McDonaldinseln"),
+    ("HN", Alternate::Default, "Honduras"),
+    ("HR", Alternate::Default, "Kroatien"),
+    ("HT", Alternate::Default, "Haiti"),
+    ("HU", Alternate::Default, "Ungarn"),
+    ("IC", Alternate::Default, "Kanarische Inseln"),
+    ("ID", Alternate::Default, "Indonesien"),
+    ("IE", Alternate::Default, "Irland"),
+    ("IL", Alternate::Default, "Israel"),
+    ("IM", Alternate::Default, "Isle of Man"),
+    ("IN", Alternate::Default, "Indien"),
+    ("IO", Alternate::Default, "Britisches Territorium im Indischen Ozean"),
+    ("IQ", Alternate::Default, "Irak"),
+    ("IR", Alternate::Default, "Iran"),
+    ("IS", Alternate::Default, "Island"),
+    ("IT", Alternate::Default, "Italien"),
+    ("JE", Alternate::Default, "Jersey"),
+    ("JM", Alternate::Default, "Jamaika"),
+    ("JO", Alternate::Default, "Jordanien"),
+    ("JP", Alternate::Default, "Japan"),
+    ("KE", Alternate::Default, "Kenia"),
+    ("KG", Alternate::Default, "Kirgisistan"),
+    ("KH", Alternate::Default, "Kambodscha"),
+    ("KI", Alternate::Default, "Kiribati"),
+    ("KM", Alternate::Default, "Komoren"),
+    ("KN", Alternate::Default, "St. Kitts und Nevis"),
+    ("KP", Alternate::Default, "Nordkorea"),
+    ("KR", Alternate::Default, "Südkorea"),
+    ("KW", Alternate::Default, "Kuwait"),
+    ("KY", Alternate::Default, "Kaimaninseln"),
+    ("KZ", Alternate::Default, "Kasachstan"),
+    ("LA", Alternate::Default, "Laos"),
+    ("LB", Alternate::Default, "Libanon"),
+    ("LC", Alternate::Default, "St. Lucia"),
+    ("LI", Alternate::Default, "Liechtenstein"),
+    ("LK", Alternate::Default, "Sri Lanka"),
+    ("LR", Alternate::Default, "Liberia"),
+    ("LS", Alternate::Default, "Lesotho"),
+    ("LT", Alternate::Default, "Litauen"),
+    ("LU", Alternate::Default, "Luxemburg"),
+    ("LV", Alternate::Default, "Lettland"),
+    ("LY", Alternate::Default, "Libyen"),
+    ("MA", Alternate::Default, "Marokko"),
+    ("MC", Alternate::Default, "Monaco"),
+    ("MD", Alternate::Default, "Republik Moldau"),
+    ("ME", Alternate::Default, "Montenegro"),
+    ("MF", Alternate::Default, "St. Martin"),
+    ("MG", Alternate::Default, "Madagaskar"),
+    ("MH", Alternate::Default, "Marshallinseln"),
+    ("MK", Alternate::Default, "Nordmazedonien"),
+    ("ML", Alternate::Default, "Mali"),
+    ("MM", Alternate::Default, "Myanmar"),
+    ("MN", Alternate::Default, "Mongolei"),
+    ("MO", Alternate::Default, "Sonderverwaltungsregion Macau"),
+    ("MO", Alternate::Short, "Macau"),
+    ("MP", Alternate::Default, "Nördliche Marianen"),
+    ("MQ", Alternate::Default, "Martinique"),
+    ("MR", Alternate::Default, "Mauretanien"),
+    ("MS", Alternate::Default, "Montserrat"),
+    ("MT", Alternate::Default, "Malta"),
+    ("MU", Alternate::Default, "Mauritius"),
+    ("MV", Alternate::Default, "Malediven"),
+    ("MW", Alternate::Default, "Malawi"),
+    ("MX", Alternate::Default, "Mexiko"),
+    ("MY", Alternate::Default, "Malaysia"),
+    ("MZ", Alternate::Default, "Mosambik"),
+    ("NA", Alternate::Default, "Namibia"),
+    ("NC", Alternate::Default, "Neukaledonien"),
+    ("NE", Alternate::Default, "Niger"),
+    ("NF", Alternate::Default, "Norfolkinsel"),
+    ("NG", Alternate::Default, "Nigeria"),
+    ("NI", Alternate::Default, "Nicaragua"),
+    ("NL", Alternate::Default, "Niederlande"),
+    ("NO", Alternate::Default, "Norwegen"),
+    ("NP", Alternate::Default, "Nepal"),
+    ("NR", Alternate::Default, "Nauru"),
+    ("NU", Alternate::Default, "Niue"),
+    ("NZ", Alternate::Default, "Neuseeland"),
+    ("NZ", Alternate::Variant, "Aotearoa (Neuseeland)"),
+    ("OM", Alternate::Default, "Oman"),
+    ("PA", Alternate::Default, "Panama"),
+    ("PE", Alternate::Default, "Peru"),
+    ("PF", Alternate::Default, "Französisch-Polynesien"),
+    ("PG", Alternate::Default, "Papua-Neuguinea"),
+    ("PH", Alternate::Default, "Philippinen"),
+    ("PK", Alternate::Default, "Pakistan"),
+    ("PL", Alternate::Default, "Polen"),
+    ("PM", Alternate::Default, "St. Pierre und Miquelon"),
+    ("PN", Alternate::Default, "Pitcairninseln"),
+    ("PR", Alternate::Default, "Puerto Rico"),
+    ("PS", Alternate::Default, "Palästinensische Autonomiegebiete"),
+    ("PS", Alternate::Short, "Palästina"),
+    ("PT", Alternate::Default, "Portugal"),
+    ("PW", Alternate::Default, "Palau"),
+    ("PY", Alternate::Default, "Paraguay"),
+    ("QA", Alternate::Default, "Katar"),
+    ("QO", Alternate::Default, "Äußeres Ozeanien"),
+    ("RE", Alternate::Default, "Réunion"),
+    ("RO", Alternate::Default, "Rumänien"),
+    ("RS", Alternate::Default, "Serbien"),
+    ("RU", Alternate::Default, "Russland"),
+    ("RW", Alternate::Default, "Ruanda"),
+    ("SA", Alternate::Default, "Saudi-Arabien"),
+    ("SB", Alternate::Default, "Salomonen"),
+    ("SC", Alternate::Default, "Seychellen"),
+    ("SD", Alternate::Default, "Sudan"),
+    ("SE", Alternate::Default, "Schweden"),
+    ("SG", Alternate::Default, "Singapur"),
+    ("SH", Alternate::Default, "St. Helena"),
+    ("SI", Alternate::Default, "Slowenien"),
+    ("SJ", Alternate::Default, "Spitzbergen und Jan Mayen"),
+    ("SK", Alternate::Default, "Slowakei"),
+    ("SL", Alternate::Default, "Sierra Leone"),
+    ("SM", Alternate::Default, "San Marino"),
+    ("SN", Alternate::Default, "Senegal"),
+    ("SO", Alternate::Default, "Somalia"),
+    ("SR", Alternate::Default, "Suriname"),
+    ("SS", Alternate::Default, "Südsudan"),
+    ("ST", Alternate::Default, "São Tomé und Príncipe"),
+    ("SV", Alternate::Default, "El Salvador"),
+    ("SX", Alternate::Default, "Sint Maarten"),
+    ("SY", Alternate::Default, "Syrien"),
+    ("SZ", Alternate::Default, "Eswatini"),
+    ("SZ", Alternate::Variant, "Swasiland"),
+    ("TA", Alternate::Default, "Tristan da Cunha"),
+    ("TC", Alternate::Default, "Turks- und Caicosinseln"),
+    ("TD", Alternate::Default, "Tschad"),
+    ("TF", Alternate::Default, "Französische Süd- und Antarktisgebiete"),
+    ("TG", Alternate::Default, "Togo"),
+    ("TH", Alternate::Default, "Thailand"),
+    ("TJ", Alternate::Default, "Tadschikistan"),
+    ("TK", Alternate::Default, "Tokelau"),
+    ("TL", Alternate::Default, "Timor-Leste"),
+    ("TL", Alternate::Variant, "Osttimor"),
+    ("TM", Alternate::Default, "Turkmenistan"),
+    ("TN", Alternate::Default, "Tunesien"),
+    ("TO", Alternate::Default, "Tonga"),
+    ("TR", Alternate::Default, "Türkei"),
+    ("TT", Alternate::Default, "Trinidad und Tobago"),
+    ("TV", Alternate::Default, "Tuvalu"),
+    ("TW", Alternate::Default, "Taiwan"),
+    ("TZ", Alternate::Default, "Tansania"),
+    ("UA", Alternate::Default, "Ukraine"),
+    ("UG", Alternate::Default, "Uganda"),
+    ("UM", Alternate::Default, "Amerikanische Überseeinseln"),
+    ("UN", Alternate::Default, "Vereinte Nationen"),
+    ("UN", Alternate::Short, "UN"),
+    ("US", Alternate::Default, "Vereinigte Staaten"),
+    ("US", Alternate::Short, "USA"),
+    ("UY", Alternate::Default, "Uruguay"),
+    ("UZ", Alternate::Default, "Usbekistan"),
+    ("VA", Alternate::Default, "Vatikanstadt"),
+    ("VC", Alternate::Default, "St. Vincent und die Grenadinen"),
+    ("VE", Alternate::Default, "Venezuela"),
+    ("VG", Alternate::Default, "Britische Jungferninseln"),
+    ("VI", Alternate::Default, "Amerikanische Jungferninseln"),
+    ("VN", Alternate::Default, "Vietnam"),
+    ("VU", Alternate::Default, "Vanuatu"),
+    ("WF", Alternate::Default, "Wallis und Futuna"),
+    ("WS", Alternate::Default, "Samoa"),
+    ("XA", Alternate::Default, "Pseudo-Akzente"),
+    ("XB", Alternate::Default, "Pseudo-Bidi"),
+    ("XK", Alternate::Default, "Kosovo"),
+    ("YE", Alternate::Default, "Jemen"),
+    ("YT", Alternate::Default, "Mayotte"),
+    ("ZA", Alternate::Default, "Südafrika"),
+    ("ZM", Alternate::Default, "Sambia"),
+    ("ZW", Alternate::Default, "Simbabwe"),
+    ("ZZ", Alternate::Default, "Unbekannte Region"),
+];
+
+#[rustfmt::skip]
+pub static DE_VARIANTS: [(&str, &str); 33] = [
+    ("1606nict", "Spätes Mittelfranzösisch"),
+    ("1694acad", "Klassisches Französisch"),
+    ("1901", "Alte deutsche Rechtschreibung"),
+    ("1959acad", "Akademisch"),
+    ("1994", "Standardisierte Resianische Rechtschreibung"),
+    ("1996", "Neue deutsche Rechtschreibung"),
+    ("arevela", "Ostarmenisch"),
+    ("arevmda", "Westarmenisch"),
+    ("baku1926", "Einheitliches Türkisches Alphabet"),
+    ("biske", "Bela-Dialekt"),
+    ("boont", "Boontling"),
+    ("fonipa", "IPA Phonetisch"),
+    ("fonupa", "Phonetisch (UPA)"),
+    ("kkcor", "Allgemeine Rechtschreibung"),
+    ("lipaw", "Lipovaz-Dialekt"),
+    ("monoton", "Monotonisch"),
+    ("nedis", "Natisone-Dialekt"),
+    ("njiva", "Njiva-Dialekt"),
+    ("osojs", "Osojane-Dialekt"),
+    ("pinyin", "Pinyin"),
+    ("polyton", "Polytonisch"),
+    ("posix", "Posix"),
+    ("revised", "Revidierte Rechtschreibung"),
+    ("rozaj", "Resianisch"),
+    ("saaho", "Saho"),
+    ("scotland", "Schottisches Standardenglisch"),
+    ("scouse", "Scouse-Dialekt"),
+    ("solba", "Solbica-Dialekt"),
+    ("tarask", "Taraskievica-Orthographie"),
+    ("uccor", "Vereinheitlichte Rechtschreibung"),
+    ("ucrcor", "Vereinheitlichte überarbeitete Rechtschreibung"),
+    ("valencia", "Valencianisch"),
+    ("wadegile", "Wade-Giles"),
+];
+
+#[rustfmt::skip]
+pub static DE_KEYS: [(&str, &str); 17] = [
+    ("ca", "Kalender"),
+    ("cf", "Währungsformat"),
+    ("co", "Sortierung"),
+    ("cu", "Währung"),
+    ("hc", "Stundenformat (12h/24h)"),
+    ("ka", "Sortierung ohne Symbole"),
+    ("kb", "Umgekehrte Sortierung von Akzenten"),
+    ("kc", "Sortierung nach Groß- oder Kleinschreibung"),
+    ("kf", "Sortierung nach Groß- bzw. Kleinbuchstaben"),
+    ("kk", "Normierte Sortierung"),
+    ("kn", "Sortierung nach Zahlen"),
+    ("ks", "Sortierstärke"),
+    ("lb", "Zeilenumbruchstil"),
+    ("ms", "Maßsystem"),
+    ("nu", "Zahlen"),
+    ("tz", "Zeitzone"),
+    ("va", "Lokale Variante"),
+];
+
+#[rustfmt::skip]
+pub static DE_TYPES: [(&str, &str, &str); 120] = [
+    ("ca", "buddhist", "Buddhistischer Kalender"),
+    ("ca", "chinese", "Chinesischer Kalender"),
+    ("ca", "coptic", "Koptischer Kalender"),
+    ("ca", "dangi", "Dangi-Kalender"),
+    ("ca", "ethioaa", "Äthiopischer Amätä-Aläm-Kalender"),
+    ("ca", "ethiopic", "Äthiopischer Kalender"),
+    ("ca", "gregory", "Gregorianischer Kalender"),
+    ("ca", "hebrew", "Hebräischer Kalender"),
+    ("ca", "indian", "Indischer Nationalkalender"),
+    ("ca", "islamic", "Islamischer Kalender"),
+    ("ca", "islamic-civil", "Bürgerlicher islamischer Kalender (tabellarisch)"),
+    ("ca", "islamic-rgsa", "Islamischer Kalender (Saudi-Arabien, Beobachtung)"),
+    ("ca", "islamic-tbla", "Islamischer Kalender (tabellarisch, astronomische Epoche)"),
+    ("ca", "islamic-umalqura", "Islamischer Umm-al-Qura-Kalender"),
+    ("ca", "iso8601", "ISO-8601-Kalender"),
+    ("ca", "japanese", "Japanischer Kalender"),
+    ("ca", "persian", "Persischer Kalender"),
+    ("ca", "roc", "Kalender der Republik China"),
+    ("cf", "account", "Währungsformat (Buchhaltung)"),
+    ("cf", "standard", "Währungsformat (Standard)"),
+    ("co", "big5han", "Traditionelle chinesische Sortierung (Big5)"),
+    ("co", "compat", "Vorherige Sortierung, Kompatibilität"),
+    ("co", "dict", "Lexikografische Sortierung"),
+    ("co", "ducet", "Unicode-Sortierung"),
+    ("co", "eor", "Europäische Sortierregeln"),
+    ("co", "gb2312", "Vereinfachte chinesische Sortierung (GB2312)"),
+    ("co", "phonebk", "Telefonbuch-Sortierung"),
+    ("co", "phonetic", "Phonetische Sortierung"),
+    ("co", "pinyin", "Pinyin-Sortierung"),
+    ("co", "reformed", "Reformierte Sortierreihenfolge"),
+    ("co", "search", "Allgemeine Suche"),
+    ("co", "searchjl", "Suche nach Anfangsbuchstaben des koreanischen Alphabets"),
+    ("co", "standard", "Standard-Sortierung"),
+    ("co", "stroke", "Strichfolge"),
+    ("co", "trad", "Traditionelle Sortierung"),
+    ("co", "unihan", "Radikal-und-Strich-Sortierung"),
+    ("co", "zhuyin", "Zhuyin-Sortierung"),
+    ("hc", "h11", "12-Stunden-Format (0-11)"),
+    ("hc", "h12", "12-Stunden-Format (1-12)"),
+    ("hc", "h23", "24-Stunden-Format (0-23)"),
+    ("hc", "h24", "24-Stunden-Format (1-24)"),
+    ("ka", "shifted", "Symbole sortieren ignorieren"),
+    ("kb", "false", "Akzente normal sortieren"),
+    ("kb", "true", "Akzente umgekehrt sortieren"),
+    ("kc", "false", "Ohne Groß-/Kleinschreibung sortieren"),
+    ("kc", "true", "Nach Groß-/Kleinschreibung sortieren"),
+    ("kf", "false", "Normal sortieren"),
+    ("kf", "lower", "Kleinbuchstaben zuerst aufführen"),
+    ("kf", "upper", "Großbuchstaben zuerst aufführen"),
+    ("kk", "false", "Ohne Normierung sortieren"),
+    ("kk", "true", "Nach Unicode sortieren"),
+    ("kn", "false", "Ziffern einzeln sortieren"),
+    ("kn", "true", "Ziffern numerisch sortieren"),
+    ("ks", "primary", "Nur Basisbuchstaben sortieren"),
+    ("ks", "tertiary", "Akzente/Fall/Breite sortieren"),
+    ("lb", "loose", "Lockerer Zeilenumbruch"),
+    ("lb", "normal", "Normaler Zeilenumbruch"),
+    ("lb", "strict", "Fester Zeilenumbruch"),
+    ("ms", "metric", "Metrisches System"),
+    ("ms", "uksystem", "Britisches Maßsystem"),
+    ("ms", "ussystem", "US-Maßsystem"),
+    ("nu", "arab", "Arabisch-indische Ziffern"),
+    ("nu", "arabext", "Erweiterte arabisch-indische Ziffern"),
+    ("nu", "armn", "Armenische Ziffern"),
+    ("nu", "armnlow", "Armenische Ziffern in Kleinschrift"),
+    ("nu", "bali", "Balinesische Ziffern"),
+    ("nu", "beng", "Bengalische Ziffern"),
+    ("nu", "brah", "Brahmi-Ziffern"),
+    ("nu", "cakm", "Chakma-Ziffern"),
+    ("nu", "cham", "Cham-Ziffern"),
+    ("nu", "deva", "Devanagari-Ziffern"),
+    ("nu", "ethi", "Äthiopische Ziffern"),
+    ("nu", "finance", "Finanzzahlen"),
+    ("nu", "fullwide", "Vollbreite Ziffern"),
+    ("nu", "geor", "Georgische Ziffern"),
+    ("nu", "grek", "Griechische Ziffern"),
+    ("nu", "greklow", "Griechische Ziffern in Kleinschrift"),
+    ("nu", "gujr", "Gujarati-Ziffern"),
+    ("nu", "guru", "Gurmukhi-Ziffern"),
+    ("nu", "hanidec", "Chinesische Dezimalzahlen"),
+    ("nu", "hans", "Vereinfacht-chinesische Ziffern"),
+    ("nu", "hansfin", "Vereinfacht-chinesische Finanzziffern"),
+    ("nu", "hant", "Traditionell-chinesische Ziffern"),
+    ("nu", "hantfin", "Traditionell-chinesische Finanzziffern"),
+    ("nu", "hebr", "Hebräische Ziffern"),
+    ("nu", "java", "Javanesische Ziffern"),
+    ("nu", "jpan", "Japanische Ziffern"),
+    ("nu", "jpanfin", "Japanische Finanzziffern"),
+    ("nu", "kali", "Kayah-Li-Ziffern"),
+    ("nu", "khmr", "Khmer-Ziffern"),
+    ("nu", "knda", "Kannada-Ziffern"),
+    ("nu", "lana", "Lanna-Ziffern (säkular)"),
+    ("nu", "lanatham", "Lanna-Ziffern (sakral)"),
+    ("nu", "laoo", "Laotische Ziffern"),
+    ("nu", "latn", "Westliche Ziffern"),
+    ("nu", "lepc", "Lepcha-Ziffern"),
+    ("nu", "limb", "Limbu-Ziffern"),
+    ("nu", "mlym", "Malayalam-Ziffern"),
+    ("nu", "mong", "Mongolische Ziffern"),
+    ("nu", "mtei", "Meitei-Mayek-Ziffern"),
+    ("nu", "mymr", "Myanmar-Ziffern"),
+    ("nu", "mymrshan", "Myanmarische Shan-Ziffern"),
+    ("nu", "native", "Native Ziffern"),
+    ("nu", "nkoo", "N’Ko-Ziffern"),
+    ("nu", "olck", "Ol-Chiki-Ziffern"),
+    ("nu", "orya", "Oriya-Ziffern"),
+    ("nu", "roman", "Römische Ziffern"),
+    ("nu", "romanlow", "Römische Ziffern in Kleinschrift"),
+    ("nu", "saur", "Saurashtra-Ziffern"),
+    ("nu", "shrd", "Sharada-Ziffern"),
+    ("nu", "sora", "Sora-Sompeng-Ziffern"),
+    ("nu", "sund", "Sundanesische Ziffern"),
+    ("nu", "takr", "Takri-Ziffern"),
+    ("nu", "talu", "Neue Tai-Lü-Ziffern"),
+    ("nu", "taml", "Tamilische Ziffern"),
+    ("nu", "tamldec", "Tamil-Ziffern"),
+    ("nu", "telu", "Telugu-Ziffern"),
+    ("nu", "thai", "Thai-Ziffern"),
+    ("nu", "tibt", "Tibetische Ziffern"),
+    ("nu", "vaii", "Vai-Ziffern"),
+];
+
+#[rustfmt::skip]
+pub static DE_AT_LANGUAGES: [(&str, Alternate, &str); 13] = [
+    ("ar-001", Alternate::Default, "modernes Hocharabisch"),
+    ("car", Alternate::Default, "karibische Sprache"),
+    ("chb", Alternate::Default, "Chibcha-Sprache"),
+    ("del", Alternate::Default, "Delawarisch"),
+    ("fur", Alternate::Default, "Friulanisch"),
+    ("ha", Alternate::Default, "Hausa"),
+    ("haw", Alternate::Default, "Hawaiianisch"),
+    ("hmn", Alternate::Default, "Miao-Sprache"),
+    ("mus", Alternate::Default, "Muskogee-Sprache"),
+    ("niu", Alternate::Default, "Niueanisch"),
+    ("pag", Alternate::Default, "Pangasinensisch"),
+    ("sh", Alternate::Default, "Serbokroatisch"),
+    ("szl", Alternate::Default, "Schlesisch"),
+];
+
+#[rustfmt::skip]
+pub static DE_AT_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static DE_AT_REGIONS: [(&str, Alternate, &str); 1] = [
+    ("SJ", Alternate::Default, "Svalbard und Jan Mayen"),
+];
+
+#[rustfmt::skip]
+pub static DE_AT_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static DE_AT_KEYS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static DE_AT_TYPES: [(&str, &str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static DE_CH_LANGUAGES: [(&str, Alternate, &str); 9] = [
+    ("ach", Alternate::Default, "Acholi-Sprache"),
+    ("bik", Alternate::Default, "Bikol-Sprache"),
+    ("chb", Alternate::Default, "Chibcha-Sprache"),
+    ("din", Alternate::Default, "Dinka-Sprache"),
+    ("fan", Alternate::Default, "Pangwe-Sprache"),
+    ("gba", Alternate::Default, "Gbaya-Sprache"),
+    ("prg", Alternate::Default, "Altpreussisch"),
+    ("rhg", Alternate::Default, "Rohingya"),
+    ("tgx", Alternate::Default, "Tagisch"),
+];
+
+#[rustfmt::skip]
+pub static DE_CH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static DE_CH_REGIONS: [(&str, Alternate, &str); 8] = [
+    ("BN", Alternate::Default, "Brunei"),
+    ("BW", Alternate::Default, "Botswana"),
+    ("CV", Alternate::Default, "Kapverden"),
+    ("QO", Alternate::Default, "Äusseres Ozeanien"),
+    ("SB", Alternate::Default, "Salomon-Inseln"),
+    ("TL", Alternate::Default, "Osttimor"),
+    ("TR", Alternate::Variant, "Türkiye"),
+    ("ZW", Alternate::Default, "Zimbabwe"),
+];
+
+#[rustfmt::skip]
+pub static DE_CH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static DE_CH_KEYS: [(&str, &str); 3] = [
+    ("kc", "Sortierung nach Gross- oder Kleinschreibung"),
+    ("kf", "Sortierung nach Gross- bzw. Kleinbuchstaben"),
+    ("ms", "Mass-System"),
+];
+
+#[rustfmt::skip]
+pub static DE_CH_TYPES: [(&str, &str, &str); 5] = [
+    ("kc", "false", "Ohne Gross-/Kleinschreibung sortieren"),
+    ("kc", "true", "Nach Gross-/Kleinschreibung sortieren"),
+    ("kf", "upper", "Grossbuchstaben zuerst aufführen"),
+    ("ms", "uksystem", "britisches Mass-System"),
+    ("ms", "ussystem", "US Mass-System"),
+];
+
+#[rustfmt::skip]
+pub static EN_LANGUAGES: [(&str, Alternate, &str); 669] = [
+    ("aa", Alternate::Default, "Afar"),
+    ("ab", Alternate::Default, "Abkhazian"),
+    ("ace", Alternate::Default, "Achinese"),
+    ("ach", Alternate::Default, "Acoli"),
+    ("ada", Alternate::Default, "Adangme"),
+    ("ady", Alternate::Default, "Adyghe"),
+    ("ae", Alternate::Default, "Avestan"),
+    ("aeb", Alternate::Default, "Tunisian Arabic"),
+    ("af", Alternate::Default, "Afrikaans"),
+    ("afh", Alternate::Default, "Afrihili"),
+    ("agq", Alternate::Default, "Aghem"),
+    ("ain", Alternate::Default, "Ainu"),
+    ("ak", Alternate::Default, "Akan"),
+    ("akk", Alternate::Default, "Akkadian"),
+    ("akz", Alternate::Default, "Alabama"),
+    ("ale", Alternate::Default, "Aleut"),
+    ("aln", Alternate::Default, "Gheg Albanian"),
+    ("alt", Alternate::Default, "Southern Altai"),
+    ("am", Alternate::Default, "Amharic"),
+    ("an", Alternate::Default, "Aragonese"),
+    ("ang", Alternate::Default, "Old English"),
+    ("ann", Alternate::Default, "Obolo"),
+    ("anp", Alternate::Default, "Angika"),
+    ("ar", Alternate::Default, "Arabic"),
+    ("ar-001", Alternate::Default, "Modern Standard Arabic"),
+    ("arc", Alternate::Default, "Aramaic"),
+    ("arn", Alternate::Default, "Mapuche"),
+    ("aro", Alternate::Default, "Araona"),
+    ("arp", Alternate::Default, "Arapaho"),
+    ("arq", Alternate::Default, "Algerian Arabic"),
+    ("ars", Alternate::Default, "Najdi Arabic"),
+    ("arw", Alternate::Default, "Arawak"),
+    ("ary", Alternate::Default, "Moroccan Arabic"),
+    ("arz", Alternate::Default, "Egyptian Arabic"),
+    ("as", Alternate::Default, "Assamese"),
+    ("asa", Alternate::Default, "Asu"),
+    ("ase", Alternate::Default, "American Sign Language"),
+    ("ast", Alternate::Default, "Asturian"),
+    ("atj", Alternate::Default, "Atikamekw"),
+    ("av", Alternate::Default, "Avaric"),
+    ("avk", Alternate::Default, "Kotava"),
+    ("awa", Alternate::Default, "Awadhi"),
+    ("ay", Alternate::Default, "Aymara"),
+    ("az", Alternate::Default, "Azerbaijani"),
+    ("az", Alternate::Short, "Azeri"),
+    ("ba", Alternate::Default, "Bashkir"),
+    ("bal", Alternate::Default, "Baluchi"),
+    ("ban", Alternate::Default, "Balinese"),
+    ("bar", Alternate::Default, "Bavarian"),
+    ("bas", Alternate::Default, "Basaa"),
+    ("bax", Alternate::Default, "Bamun"),
+    ("bbc", Alternate::Default, "Batak Toba"),
+    ("bbj", Alternate::Default, "Ghomala"),
+    ("be", Alternate::Default, "Belarusian"),
+    ("bej", Alternate::Default, "Beja"),
+    ("bem", Alternate::Default, "Bemba"),
+    ("bew", Alternate::Default, "Betawi"),
+    ("bez", Alternate::Default, "Bena"),
+    ("bfd", Alternate::Default, "Bafut"),
+    ("bfq", Alternate::Default, "Badaga"),
+    ("bg", Alternate::Default, "Bulgarian"),
+    ("bgc", Alternate::Default, "Haryanvi"),
+    ("bgn", Alternate::Default, "Western Balochi"),
+    ("bho", Alternate::Default, "Bhojpuri"),
+    ("bi", Alternate::Default, "Bislama"),
+    ("bik", Alternate::Default, "Bikol"),
+    ("bin", Alternate::Default, "Bini"),
+    ("bjn", Alternate::Default, "Banjar"),
+    ("bkm", Alternate::Default, "Kom"),
+    ("bla", Alternate::Default, "Siksiká"),
+    ("blt", Alternate::Default, "Tai Dam"),
+    ("bm", Alternate::Default, "Bambara"),
+    ("bn", Alternate::Default, "Bangla"),
+    ("bo", Alternate::Default, "Tibetan"),
+    ("bpy", Alternate::Default, "Bishnupriya"),
+    ("bqi", Alternate::Default, "Bakhtiari"),
+    ("br", Alternate::Default, "Breton"),
+    ("bra", Alternate::Default, "Braj"),
+    ("brh", Alternate::Default, "Brahui"),
+    ("brx", Alternate::Default, "Bodo"),
+    ("bs", Alternate::Default, "Bosnian"),
+    ("bss", Alternate::Default, "Akoose"),
+    ("bua", Alternate::Default, "Buriat"),
+    ("bug", Alternate::Default, "Buginese"),
+    ("bum", Alternate::Default, "Bulu"),
+    ("byn", Alternate::Default, "Blin"),
+    ("byv", Alternate::Default, "Medumba"),
+    ("ca", Alternate::Default, "Catalan"),
+    ("cad", Alternate::Default, "Caddo"),
+    ("car", Alternate::Default, "Carib"),
+    ("cay", Alternate::Default, "Cayuga"),
+    ("cch", Alternate::Default, "Atsam"),
+    ("ccp", Alternate::Default, "Chakma"),
+    ("ce", Alternate::Default, "Chechen"),
+    ("ceb", Alternate::Default, "Cebuano"),
+    ("cgg", Alternate::Default, "Chiga"),
+    ("ch", Alternate::Default, "Chamorro"),
+    ("chb", Alternate::Default, "Chibcha"),
+    ("chg", Alternate::Default, "Chagatai"),
+    ("chk", Alternate::Default, "Chuukese"),
+    ("chm", Alternate::Default, "Mari"),
+    ("chn", Alternate::Default, "Chinook Jargon"),
+    ("cho", Alternate::Default, "Choctaw"),
+    ("chp", Alternate::Default, "Chipewyan"),
+    ("chr", Alternate::Default, "Cherokee"),
+    ("chy", Alternate::Default, "Cheyenne"),
+    ("cic", Alternate::Default, "Chickasaw"),
+    ("ckb", Alternate::Default, "Central Kurdish"),
+    ("ckb", Alternate::Variant, "Kurdish, Sorani"),
+    ("clc", Alternate::Default, "Chilcotin"),
+    ("co", Alternate::Default, "Corsican"),
+    ("cop", Alternate::Default, "Coptic"),
+    ("cps", Alternate::Default, "Capiznon"),
+    ("cr", Alternate::Default, "Cree"),
+    ("crg", Alternate::Default, "Michif"),
+    ("crh", Alternate::Default, "Crimean Tatar"),
+    ("crj", Alternate::Default, "Southern East Cree"),
+    ("crk", Alternate::Default, "Plains Cree"),
+    ("crl", Alternate::Default, "Northern East Cree"),
+    ("crm", Alternate::Default, "Moose Cree"),
+    ("crr", Alternate::Default, "Carolina Algonquian"),
+    ("crs", Alternate::Default, "Seselwa Creole French"),
+    ("cs", Alternate::Default, "Czech"),
+    ("csb", Alternate::Default, "Kashubian"),
+    ("csw", Alternate::Default, "Swampy Cree"),
+    ("cu", Alternate::Default, "Church Slavic"),
+    ("cv", Alternate::Default, "Chuvash"),
+    ("cwd", Alternate::Default, "Woods Cree"),
+    ("cy", Alternate::Default, "Welsh"),
+    ("da", Alternate::Default, "Danish"),
+    ("dak", Alternate::Default, "Dakota"),
+    ("dar", Alternate::Default, "Dargwa"),
+    ("dav", Alternate::Default, "Taita"),
+    ("de", Alternate::Default, "German"),
+    ("de-AT", Alternate::Default, "Austrian German"),
+    ("de-CH", Alternate::Default, "Swiss High German"),
+    ("del", Alternate::Default, "Delaware"),
+    ("den", Alternate::Default, "Slave"),
+    ("dgr", Alternate::Default, "Dogrib"),
+    ("din", Alternate::Default, "Dinka"),
+    ("dje", Alternate::Default, "Zarma"),
+    ("doi", Alternate::Default, "Dogri"),
+    ("dsb", Alternate::Default, "Lower Sorbian"),
+    ("dtp", Alternate::Default, "Central Dusun"),
+    ("dua", Alternate::Default, "Duala"),
+    ("dum", Alternate::Default, "Middle Dutch"),
+    ("dv", Alternate::Default, "Divehi"),
+    ("dyo", Alternate::Default, "Jola-Fonyi"),
+    ("dyu", Alternate::Default, "Dyula"),
+    ("dz", Alternate::Default, "Dzongkha"),
+    ("dzg", Alternate::Default, "Dazaga"),
+    ("ebu", Alternate::Default, "Embu"),
+    ("ee", Alternate::Default, "Ewe"),
+    ("efi", Alternate::Default, "Efik"),
+    ("egl", Alternate::Default, "Emilian"),
+    ("egy", Alternate::Default, "Ancient Egyptian"),
+    ("eka", Alternate::Default, "Ekajuk"),
+    ("el", Alternate::Default, "Greek"),
+    ("elx", Alternate::Default, "Elamite"),
+    ("en", Alternate::Default, "English"),
+    ("en-AU", Alternate::Default, "Australian English"),
+    ("en-CA", Alternate::Default, "Canadian English"),
+    ("en-GB", Alternate::Default, "British English"),
+    ("en-GB", Alternate::Short, "UK English"),
+    ("en-US", Alternate::Default, "American English"),
+    ("en-US", Alternate::Short, "US English"),
+    ("enm", Alternate::Default, "Middle English"),
+    ("eo", Alternate::Default, "Esperanto"),
+    ("es", Alternate::Default, "Spanish"),
+    ("es-419", Alternate::Default, "Latin American Spanish"),
+    ("es-ES", Alternate::Default, "European Spanish"),
+    ("es-MX", Alternate::Default, "Mexican Spanish"),
+    ("esu", Alternate::Default, "Central Yupik"),
+    ("et", Alternate::Default, "Estonian"),
+    ("eu", Alternate::Default, "Basque"),
+    ("ewo", Alternate::Default, "Ewondo"),
+    ("ext", Alternate::Default, "Extremaduran"),
+    ("fa", Alternate::Default, "Persian"),
+    ("fa-AF", Alternate::Default, "Dari"),
+    ("fan", Alternate::Default, "Fang"),
+    ("fat", Alternate::Default, "Fanti"),
+    ("ff", Alternate::Default, "Fula"),
+    ("fi", Alternate::Default, "Finnish"),
+    ("fil", Alternate::Default, "Filipino"),
+    ("fit", Alternate::Default, "Tornedalen Finnish"),
+    ("fj", Alternate::Default, "Fijian"),
+    ("fo", Alternate::Default, "Faroese"),
+    ("fon", Alternate::Default, "Fon"),
+    ("fr", Alternate::Default, "French"),
+    ("fr-CA", Alternate::Default, "Canadian French"),
+    ("fr-CH", Alternate::Default, "Swiss French"),
+    ("frc", Alternate::Default, "Cajun French"),
+    ("frm", Alternate::Default, "Middle French"),
+    ("fro", Alternate::Default, "Old French"),
+    ("frp", Alternate::Default, "Arpitan"),
+    ("frr", Alternate::Default, "Northern Frisian"),
+    ("frs", Alternate::Default, "Eastern Frisian"),
+    ("fur", Alternate::Default, "Friulian"),
+    ("fy", Alternate::Default, "Western Frisian"),
+    ("ga", Alternate::Default, "Irish"),
+    ("gaa", Alternate::Default, "Ga"),
+    ("gag", Alternate::Default, "Gagauz"),
+    ("gan", Alternate::Default, "Gan Chinese"),
+    ("gay", Alternate::Default, "Gayo"),
+    ("gba", Alternate::Default, "Gbaya"),
+    ("gbz", Alternate::Default, "Zoroastrian Dari"),
+    ("gd", Alternate::Default, "Scottish Gaelic"),
+    ("gez", Alternate::Default, "Geez"),
+    ("gil", Alternate::Default, "Gilbertese"),
+    ("gl", Alternate::Default, "Galician"),
+    ("glk", Alternate::Default, "Gilaki"),
+    ("gmh", Alternate::Default, "Middle High German"),
+    ("gn", Alternate::Default, "Guarani"),
+    ("goh", Alternate::Default, "Old High German"),
+    ("gom", Alternate::Default, "Goan Konkani"),
+    ("gon", Alternate::Default, "Gondi"),
+    ("gor", Alternate::Default, "Gorontalo"),
+    ("got", Alternate::Default, "Gothic"),
+    ("grb", Alternate::Default, "Grebo"),
+    ("grc", Alternate::Default, "Ancient Greek"),
+    ("gsw", Alternate::Default, "Swiss German"),
+    ("gu", Alternate::Default, "Gujarati"),
+    ("guc", Alternate::Default, "Wayuu"),
+    ("gur", Alternate::Default, "Frafra"),
+    ("guz", Alternate::Default, "Gusii"),
+    ("gv", Alternate::Default, "Manx"),
+    ("gwi", Alternate::Default, "Gwichʼin"),
+    ("ha", Alternate::Default, "Hausa"),
+    ("hai", Alternate::Default, "Haida"),
+    ("hak", Alternate::Default, "Hakka Chinese"),
+    ("haw", Alternate::Default, "Hawaiian"),
+    ("hax", Alternate::Default, "Southern Haida"),
+    ("hdn", Alternate::Default, "Northern Haida"),
+    ("he", Alternate::Default, "Hebrew"),
+    ("hi", Alternate::Default, "Hindi"),
+    ("hi-Latn", Alternate::Default, "Hindi (Latin)"),
+    ("hi-Latn", Alternate::Variant, "Hinglish"),
+    ("hif", Alternate::Default, "Fiji Hindi"),
+    ("hil", Alternate::Default, "Hiligaynon"),
+    ("hit", Alternate::Default, "Hittite"),
+    ("hmn", Alternate::Default, "Hmong"),
+    ("hnj", Alternate::Default, "Hmong Njua"),
+    ("ho", Alternate::Default, "Hiri Motu"),
+    ("hr", Alternate::Default, "Croatian"),
+    ("hsb", Alternate::Default, "Upper Sorbian"),
+    ("hsn", Alternate::Default, "Xiang Chinese"),
+    ("ht", Alternate::Default, "Haitian Creole"),
+    ("hu", Alternate::Default, "Hungarian"),
+    ("hup", Alternate::Default, "Hupa"),
+    ("hur", Alternate::Default, "Halkomelem"),
+    ("hy", Alternate::Default, "Armenian"),
+    ("hz", Alternate::Default, "Herero"),
+    ("ia", Alternate::Default, "Interlingua"),
+    ("iba", Alternate::Default, "Iban"),
+    ("ibb", Alternate::Default, "Ibibio"),
+    ("id", Alternate::Default, "Indonesian"),
+    ("ie", Alternate::Default, "Interlingue"),
+    ("ig", Alternate::Default, "Igbo"),
+    ("ii", Alternate::Default, "Sichuan Yi"),
+    ("ik", Alternate::Default, "Inupiaq"),
+    ("ike", Alternate::Default, "Eastern Canadian Inuktitut"),
+    ("ikt", Alternate::Default, "Western Canadian Inuktitut"),
+    ("ilo", Alternate::Default, "Iloko"),
+    ("inh", Alternate::Default, "Ingush"),
+    ("io", Alternate::Default, "Ido"),
+    ("is", Alternate::Default, "Icelandic"),
+    ("it", Alternate::Default, "Italian"),
+    ("iu", Alternate::Default, "Inuktitut"),
+    ("izh", Alternate::Default, "Ingrian"),
+    ("ja", Alternate::Default, "Japanese"),
+    ("jam", Alternate::Default, "Jamaican Creole English"),
+    ("jbo", Alternate::Default, "Lojban"),
+    ("jgo", Alternate::Default, "Ngomba"),
+    ("jmc", Alternate::Default, "Machame"),
+    ("jpr", Alternate::Default, "Judeo-Persian"),
+    ("jrb", Alternate::Default, "Judeo-Arabic"),
+    ("jut", Alternate::Default, "Jutish"),
+    ("jv", Alternate::Default, "Javanese"),
+    ("ka", Alternate::Default, "Georgian"),
+    ("kaa", Alternate::Default, "Kara-Kalpak"),
+    ("kab", Alternate::Default, "Kabyle"),
+    ("kac", Alternate::Default, "Kachin"),
+    ("kaj", Alternate::Default, "Jju"),
+    ("kam", Alternate::Default, "Kamba"),
+    ("kaw", Alternate::Default, "Kawi"),
+    ("kbd", Alternate::Default, "Kabardian"),
+    ("kbl", Alternate::Default, "Kanembu"),
+    ("kcg", Alternate::Default, "Tyap"),
+    ("kde", Alternate::Default, "Makonde"),
+    ("kea", Alternate::Default, "Kabuverdianu"),
+    ("ken", Alternate::Default, "Kenyang"),
+    ("kfo", Alternate::Default, "Koro"),
+    ("kg", Alternate::Default, "Kongo"),
+    ("kgp", Alternate::Default, "Kaingang"),
+    ("kha", Alternate::Default, "Khasi"),
+    ("kho", Alternate::Default, "Khotanese"),
+    ("khq", Alternate::Default, "Koyra Chiini"),
+    ("khw", Alternate::Default, "Khowar"),
+    ("ki", Alternate::Default, "Kikuyu"),
+    ("kiu", Alternate::Default, "Kirmanjki"),
+    ("kj", Alternate::Default, "Kuanyama"),
+    ("kk", Alternate::Default, "Kazakh"),
+    ("kkj", Alternate::Default, "Kako"),
+    ("kl", Alternate::Default, "Kalaallisut"),
+    ("kln", Alternate::Default, "Kalenjin"),
+    ("km", Alternate::Default, "Khmer"),
+    ("kmb", Alternate::Default, "Kimbundu"),
+    ("kn", Alternate::Default, "Kannada"),
+    ("ko", Alternate::Default, "Korean"),
+    ("koi", Alternate::Default, "Komi-Permyak"),
+    ("kok", Alternate::Default, "Konkani"),
+    ("kos", Alternate::Default, "Kosraean"),
+    ("kpe", Alternate::Default, "Kpelle"),
+    ("kr", Alternate::Default, "Kanuri"),
+    ("krc", Alternate::Default, "Karachay-Balkar"),
+    ("kri", Alternate::Default, "Krio"),
+    ("krj", Alternate::Default, "Kinaray-a"),
+    ("krl", Alternate::Default, "Karelian"),
+    ("kru", Alternate::Default, "Kurukh"),
+    ("ks", Alternate::Default, "Kashmiri"),
+    ("ksb", Alternate::Default, "Shambala"),
+    ("ksf", Alternate::Default, "Bafia"),
+    ("ksh", Alternate::Default, "Colognian"),
+    ("ku", Alternate::Default, "Kurdish"),
+    ("kum", Alternate::Default, "Kumyk"),
+    ("kut", Alternate::Default, "Kutenai"),
+    ("kv", Alternate::Default, "Komi"),
+    ("kw", Alternate::Default, "Cornish"),
+    ("kwk", Alternate::Default, "Kwakʼwala"),
+    ("ky", Alternate::Default, "Kyrgyz"),
+    ("ky", Alternate::Variant, "Kirghiz"),
+    ("la", Alternate::Default, "Latin"),
+    ("lad", Alternate::Default, "Ladino"),
+    ("lag", Alternate::Default, "Langi"),
+    ("lah", Alternate::Default, "Western Panjabi"),
+    ("lam", Alternate::Default, "Lamba"),
+    ("lb", Alternate::Default, "Luxembourgish"),
+    ("lez", Alternate::Default, "Lezghian"),
+    ("lfn", Alternate::Default, "Lingua Franca Nova"),
+    ("lg", Alternate::Default, "Ganda"),
+    ("li", Alternate::Default, "Limburgish"),
+    ("lij", Alternate::Default, "Ligurian"),
+    ("lil", Alternate::Default, "Lillooet"),
+    ("liv", Alternate::Default, "Livonian"),
+    ("lkt", Alternate::Default, "Lakota"),
+    ("lmo", Alternate::Default, "Lombard"),
+    ("ln", Alternate::Default, "Lingala"),
+    ("lo", Alternate::Default, "Lao"),
+    ("lol", Alternate::Default, "Mongo"),
+    ("lou", Alternate::Default, "Louisiana Creole"),
+    ("loz", Alternate::Default, "Lozi"),
+    ("lrc", Alternate::Default, "Northern Luri"),
+    ("lsm", Alternate::Default, "Saamia"),
+    ("lt", Alternate::Default, "Lithuanian"),
+    ("ltg", Alternate::Default, "Latgalian"),
+    ("lu", Alternate::Default, "Luba-Katanga"),
+    ("lua", Alternate::Default, "Luba-Lulua"),
+    ("lui", Alternate::Default, "Luiseno"),
+    ("lun", Alternate::Default, "Lunda"),
+    ("luo", Alternate::Default, "Luo"),
+    ("lus", Alternate::Default, "Mizo"),
+    ("luy", Alternate::Default, "Luyia"),
+    ("lv", Alternate::Default, "Latvian"),
+    ("lzh", Alternate::Default, "Literary Chinese"),
+    ("lzz", Alternate::Default, "Laz"),
+    ("mad", Alternate::Default, "Madurese"),
+    ("maf", Alternate::Default, "Mafa"),
+    ("mag", Alternate::Default, "Magahi"),
+    ("mai", Alternate::Default, "Maithili"),
+    ("mak", Alternate::Default, "Makasar"),
+    ("man", Alternate::Default, "Mandingo"),
+    ("mas", Alternate::Default, "Masai"),
+    ("mde", Alternate::Default, "Maba"),
+    ("mdf", Alternate::Default, "Moksha"),
+    ("mdr", Alternate::Default, "Mandar"),
+    ("men", Alternate::Default, "Mende"),
+    ("mer", Alternate::Default, "Meru"),
+    ("mfe", Alternate::Default, "Morisyen"),
+    ("mg", Alternate::Default, "Malagasy"),
+    ("mga", Alternate::Default, "Middle Irish"),
+    ("mgh", Alternate::Default, "Makhuwa-Meetto"),
+    ("mgo", Alternate::Default, "Metaʼ"),
+    ("mh", Alternate::Default, "Marshallese"),
+    ("mi", Alternate::Default, "Māori"),
+    ("mic", Alternate::Default, "Mi'kmaq"),
+    ("min", Alternate::Default, "Minangkabau"),
+    ("mk", Alternate::Default, "Macedonian"),
+    ("ml", Alternate::Default, "Malayalam"),
+    ("mn", Alternate::Default, "Mongolian"),
+    ("mnc", Alternate::Default, "Manchu"),
+    ("mni", Alternate::Default, "Manipuri"),
+    ("moe", Alternate::Default, "Innu-aimun"),
+    ("moh", Alternate::Default, "Mohawk"),
+    ("mos", Alternate::Default, "Mossi"),
+    ("mr", Alternate::Default, "Marathi"),
+    ("mrj", Alternate::Default, "Western Mari"),
+    ("ms", Alternate::Default, "Malay"),
+    ("mt", Alternate::Default, "Maltese"),
+    ("mua", Alternate::Default, "Mundang"),
+    ("mul", Alternate::Default, "Multiple languages"),
+    ("mus", Alternate::Default, "Muscogee"),
+    ("mwl", Alternate::Default, "Mirandese"),
+    ("mwr", Alternate::Default, "Marwari"),
+    ("mwv", Alternate::Default, "Mentawai"),
+    ("my", Alternate::Default, "Burmese"),
+    ("my", Alternate::Variant, "Myanmar Language"),
+    ("mye", Alternate::Default, "Myene"),
+    ("myv", Alternate::Default, "Erzya"),
+    ("mzn", Alternate::Default, "Mazanderani"),
+    ("na", Alternate::Default, "Nauru"),
+    ("nan", Alternate::Default, "Min Nan Chinese"),
+    ("nap", Alternate::Default, "Neapolitan"),
+    ("naq", Alternate::Default, "Nama"),
+    ("nb", Alternate::Default, "Norwegian Bokmål"),
+    ("nd", Alternate::Default, "North Ndebele"),
+    ("nds", Alternate::Default, "Low German"),
+    ("nds-NL", Alternate::Default, "Low Saxon"),
+    ("ne", Alternate::Default, "Nepali"),
+    ("new", Alternate::Default, "Newari"),
+    ("ng", Alternate::Default, "Ndonga"),
+    ("nia", Alternate::Default, "Nias"),
+    ("niu", Alternate::Default, "Niuean"),
+    ("njo", Alternate::Default, "Ao Naga"),
+    ("nl", Alternate::Default, "Dutch"),
+    ("nl-BE", Alternate::Default, "Flemish"),
+    ("nmg", Alternate::Default, "Kwasio"),
+    ("nn", Alternate::Default, "Norwegian Nynorsk"),
+    ("nnh", Alternate::Default, "Ngiemboon"),
+    ("no", Alternate::Default, "Norwegian"),
+    ("nog", Alternate::Default, "Nogai"),
+    ("non", Alternate::Default, "Old Norse"),
+    ("nov", Alternate::Default, "Novial"),
+    ("nqo", Alternate::Default, "N’Ko"),
+    ("nr", Alternate::Default, "South Ndebele"),
+    ("nso", Alternate::Default, "Northern Sotho"),
+    ("nus", Alternate::Default, "Nuer"),
+    ("nv", Alternate::Default, "Navajo"),
+    ("nwc", Alternate::Default, "Classical Newari"),
+    ("ny", Alternate::Default, "Nyanja"),
+    ("nym", Alternate::Default, "Nyamwezi"),
+    ("nyn", Alternate::Default, "Nyankole"),
+    ("nyo", Alternate::Default, "Nyoro"),
+    ("nzi", Alternate::Default, "Nzima"),
+    ("oc", Alternate::Default, "Occitan"),
+    ("oj", Alternate::Default, "Ojibwa"),
+    ("ojb", Alternate::Default, "Northwestern Ojibwa"),
+    ("ojc", Alternate::Default, "Central Ojibwa"),
+    ("ojg", Alternate::Default, "Eastern Ojibwa"),
+    ("ojs", Alternate::Default, "Oji-Cree"),
+    ("ojw", Alternate::Default, "Western Ojibwa"),
+    ("oka", Alternate::Default, "Okanagan"),
+    ("om", Alternate::Default, "Oromo"),
+    ("or", Alternate::Default, "Odia"),
+    ("os", Alternate::Default, "Ossetic"),
+    ("osa", Alternate::Default, "Osage"),
+    ("ota", Alternate::Default, "Ottoman Turkish"),
+    ("pa", Alternate::Default, "Punjabi"),
+    ("pag", Alternate::Default, "Pangasinan"),
+    ("pal", Alternate::Default, "Pahlavi"),
+    ("pam", Alternate::Default, "Pampanga"),
+    ("pap", Alternate::Default, "Papiamento"),
+    ("pau", Alternate::Default, "Palauan"),
+    ("pcd", Alternate::Default, "Picard"),
+    ("pcm", Alternate::Default, "Nigerian Pidgin"),
+    ("pdc", Alternate::Default, "Pennsylvania German"),
+    ("pdt", Alternate::Default, "Plautdietsch"),
+    ("peo", Alternate::Default, "Old Persian"),
+    ("pfl", Alternate::Default, "Palatine German"),
+    ("phn", Alternate::Default, "Phoenician"),
+    ("pi", Alternate::Default, "Pali"),
+    ("pis", Alternate::Default, "Pijin"),
+    ("pl", Alternate::Default, "Polish"),
+    ("pms", Alternate::Default, "Piedmontese"),
+    ("pnt", Alternate::Default, "Pontic"),
+    ("pon", Alternate::Default, "Pohnpeian"),
+    ("pqm", Alternate::Default, "Maliseet-Passamaquoddy"),
+    ("prg", Alternate::Default, "Prussian"),
+    ("pro", Alternate::Default, "Old Provençal"),
+    ("ps", Alternate::Default, "Pashto"),
+    ("ps", Alternate::Variant, "Pushto"),
+    ("pt", Alternate::Default, "Portuguese"),
+    ("pt-BR", Alternate::Default, "Brazilian Portuguese"),
+    ("pt-PT", Alternate::Default, "European Portuguese"),
+    ("qu", Alternate::Default, "Quechua"),
+    ("quc", Alternate::Default, "Kʼicheʼ"),
+    ("qug", Alternate::Default, "Chimborazo Highland Quichua"),
+    ("raj", Alternate::Default, "Rajasthani"),
+    ("rap", Alternate::Default, "Rapanui"),
+    ("rar", Alternate::Default, "Rarotongan"),
+    ("rgn", Alternate::Default, "Romagnol"),
+    ("rhg", Alternate::Default, "Rohingya"),
+    ("rif", Alternate::Default, "Riffian"),
+    ("rm", Alternate::Default, "Romansh"),
+    ("rn", Alternate::Default, "Rundi"),
+    ("ro", Alternate::Default, "Romanian"),
+    ("ro-MD", Alternate::Default, "Moldavian"),
+    ("rof", Alternate::Default, "Rombo"),
+    ("rom", Alternate::Default, "Romany"),
+    ("rtm", Alternate::Default, "Rotuman"),
+    ("ru", Alternate::Default, "Russian"),
+    ("rue", Alternate::Default, "Rusyn"),
+    ("rug", Alternate::Default, "Roviana"),
+    ("rup", Alternate::Default, "Aromanian"),
+    ("rw", Alternate::Default, "Kinyarwanda"),
+    ("rwk", Alternate::Default, "Rwa"),
+    ("sa", Alternate::Default, "Sanskrit"),
+    ("sad", Alternate::Default, "Sandawe"),
+    ("sah", Alternate::Default, "Yakut"),
+    ("sam", Alternate::Default, "Samaritan Aramaic"),
+    ("saq", Alternate::Default, "Samburu"),
+    ("sas", Alternate::Default, "Sasak"),
+    ("sat", Alternate::Default, "Santali"),
+    ("saz", Alternate::Default, "Saurashtra"),
+    ("sba", Alternate::Default, "Ngambay"),
+    ("sbp", Alternate::Default, "Sangu"),
+    ("sc", Alternate::Default, "Sardinian"),
+    ("scn", Alternate::Default, "Sicilian"),
+    ("sco", Alternate::Default, "Scots"),
+    ("sd", Alternate::Default, "Sindhi"),
+    ("sdc", Alternate::Default, "Sassarese Sardinian"),
+    ("sdh", Alternate::Default, "Southern Kurdish"),
+    ("se", Alternate::Default, "Northern Sami"),
+    ("see", Alternate::Default, "Seneca"),
+    ("seh", Alternate::Default, "Sena"),
+    ("sei", Alternate::Default, "Seri"),
+    ("sel", Alternate::Default, "Selkup"),
+    ("ses", Alternate::Default, "Koyraboro Senni"),
+    ("sg", Alternate::Default, "Sango"),
+    ("sga", Alternate::Default, "Old Irish"),
+    ("sgs", Alternate::Default, "Samogitian"),
+    ("sh", Alternate::Default, "Serbo-Croatian"),
+    ("shi", Alternate::Default, "Tachelhit"),
+    ("shn", Alternate::Default, "Shan"),
+    ("shu", Alternate::Default, "Chadian Arabic"),
+    ("si", Alternate::Default, "Sinhala"),
+    ("sid", Alternate::Default, "Sidamo"),
+    ("sk", Alternate::Default, "Slovak"),
+    ("sl", Alternate::Default, "Slovenian"),
+    ("slh", Alternate::Default, "Southern Lushootseed"),
+    ("sli", Alternate::Default, "Lower Silesian"),
+    ("sly", Alternate::Default, "Selayar"),
+    ("sm", Alternate::Default, "Samoan"),
+    ("sma", Alternate::Default, "Southern Sami"),
+    ("smj", Alternate::Default, "Lule Sami"),
+    ("smn", Alternate::Default, "Inari Sami"),
+    ("sms", Alternate::Default, "Skolt Sami"),
+    ("sn", Alternate::Default, "Shona"),
+    ("snk", Alternate::Default, "Soninke"),
+    ("so", Alternate::Default, "Somali"),
+    ("sog", Alternate::Default, "Sogdien"),
+    ("sq", Alternate::Default, "Albanian"),
+    ("sr", Alternate::Default, "Serbian"),
+    ("sr-ME", Alternate::Default, "Montenegrin"),
+    ("srn", Alternate::Default, "Sranan Tongo"),
+    ("srr", Alternate::Default, "Serer"),
+    ("ss", Alternate::Default, "Swati"),
+    ("ssy", Alternate::Default, "Saho"),
+    ("st", Alternate::Default, "Southern Sotho"),
+    ("stq", Alternate::Default, "Saterland Frisian"),
+    ("str", Alternate::Default, "Straits Salish"),
+    ("su", Alternate::Default, "Sundanese"),
+    ("suk", Alternate::Default, "Sukuma"),
+    ("sus", Alternate::Default, "Susu"),
+    ("sux", Alternate::Default, "Sumerian"),
+    ("sv", Alternate::Default, "Swedish"),
+    ("sw", Alternate::Default, "Swahili"),
+    ("sw-CD", Alternate::Default, "Congo Swahili"),
+    ("swb", Alternate::Default, "Comorian"),
+    ("syc", Alternate::Default, "Classical Syriac"),
+    ("syr", Alternate::Default, "Syriac"),
+    ("szl", Alternate::Default, "Silesian"),
+    ("ta", Alternate::Default, "Tamil"),
+    ("tce", Alternate::Default, "Southern Tutchone"),
+    ("tcy", Alternate::Default, "Tulu"),
+    ("te", Alternate::Default, "Telugu"),
+    ("tem", Alternate::Default, "Timne"),
+    ("teo", Alternate::Default, "Teso"),
+    ("ter", Alternate::Default, "Tereno"),
+    ("tet", Alternate::Default, "Tetum"),
+    ("tg", Alternate::Default, "Tajik"),
+    ("tgx", Alternate::Default, "Tagish"),
+    ("th", Alternate::Default, "Thai"),
+    ("tht", Alternate::Default, "Tahltan"),
+    ("ti", Alternate::Default, "Tigrinya"),
+    ("tig", Alternate::Default, "Tigre"),
+    ("tiv", Alternate::Default, "Tiv"),
+    ("tk", Alternate::Default, "Turkmen"),
+    ("tkl", Alternate::Default, "Tokelau"),
+    ("tkr", Alternate::Default, "Tsakhur"),
+    ("tl", Alternate::Default, "Tagalog"),
+    ("tlh", Alternate::Default, "Klingon"),
+    ("tli", Alternate::Default, "Tlingit"),
+    ("tly", Alternate::Default, "Talysh"),
+    ("tmh", Alternate::Default, "Tamashek"),
+    ("tn", Alternate::Default, "Tswana"),
+    ("to", Alternate::Default, "Tongan"),
+    ("tog", Alternate::Default, "Nyasa Tonga"),
+    ("tok", Alternate::Default, "Toki Pona"),
+    ("tpi", Alternate::Default, "Tok Pisin"),
+    ("tr", Alternate::Default, "Turkish"),
+    ("tru", Alternate::Default, "Turoyo"),
+    ("trv", Alternate::Default, "Taroko"),
+    ("trw", Alternate::Default, "Torwali"),
+    ("ts", Alternate::Default, "Tsonga"),
+    ("tsd", Alternate::Default, "Tsakonian"),
+    ("tsi", Alternate::Default, "Tsimshian"),
+    ("tt", Alternate::Default, "Tatar"),
+    ("ttm", Alternate::Default, "Northern Tutchone"),
+    ("ttt", Alternate::Default, "Muslim Tat"),
+    ("tum", Alternate::Default, "Tumbuka"),
+    ("tvl", Alternate::Default, "Tuvalu"),
+    ("tw", Alternate::Default, "Twi"),
+    ("twq", Alternate::Default, "Tasawaq"),
+    ("ty", Alternate::Default, "Tahitian"),
+    ("tyv", Alternate::Default, "Tuvinian"),
+    ("tzm", Alternate::Default, "Central Atlas Tamazight"),
+    ("udm", Alternate::Default, "Udmurt"),
+    ("ug", Alternate::Default, "Uyghur"),
+    ("ug", Alternate::Variant, "Uighur"),
+    ("uga", Alternate::Default, "Ugaritic"),
+    ("uk", Alternate::Default, "Ukrainian"),
+    ("umb", Alternate::Default, "Umbundu"),
+    ("und", Alternate::Default, "Unknown language"),
+    ("ur", Alternate::Default, "Urdu"),
+    ("uz", Alternate::Default, "Uzbek"),
+    ("vai", Alternate::Default, "Vai"),
+    ("ve", Alternate::Default, "Venda"),
+    ("vec", Alternate::Default, "Venetian"),
+    ("vep", Alternate::Default, "Veps"),
+    ("vi", Alternate::Default, "Vietnamese"),
+    ("vls", Alternate::Default, "West Flemish"),
+    ("vmf", Alternate::Default, "Main-Franconian"),
+    ("vo", Alternate::Default, "Volapük"),
+    ("vot", Alternate::Default, "Votic"),
+    ("vro", Alternate::Default, "Võro"),
+    ("vun", Alternate::Default, "Vunjo"),
+    ("wa", Alternate::Default, "Walloon"),
+    ("wae", Alternate::Default, "Walser"),
+    ("wal", Alternate::Default, "Wolaytta"),
+    ("war", Alternate::Default, "Waray"),
+    ("was", Alternate::Default, "Washo"),
+    ("wbp", Alternate::Default, "Warlpiri"),
+    ("wo", Alternate::Default, "Wolof"),
+    ("wuu", Alternate::Default, "Wu Chinese"),
+    ("xal", Alternate::Default, "Kalmyk"),
+    ("xh", Alternate::Default, "Xhosa"),
+    ("xmf", Alternate::Default, "Mingrelian"),
+    ("xog", Alternate::Default, "Soga"),
+    ("yao", Alternate::Default, "Yao"),
+    ("yap", Alternate::Default, "Yapese"),
+    ("yav", Alternate::Default, "Yangben"),
+    ("ybb", Alternate::Default, "Yemba"),
+    ("yi", Alternate::Default, "Yiddish"),
+    ("yo", Alternate::Default, "Yoruba"),
+    ("yrl", Alternate::Default, "Nheengatu"),
+    ("yue", Alternate::Default, "Cantonese"),
+    ("za", Alternate::Default, "Zhuang"),
+    ("zap", Alternate::Default, "Zapotec"),
+    ("zbl", Alternate::Default, "Blissymbols"),
+    ("zea", Alternate::Default, "Zeelandic"),
+    ("zen", Alternate::Default, "Zenaga"),
+    ("zgh", Alternate::Default, "Standard Moroccan Tamazight"),
+    ("zh", Alternate::Default, "Chinese"),
+    ("zh-Hans", Alternate::Default, "Simplified Chinese"),
+    ("zh-Hant", Alternate::Default, "Traditional Chinese"),
+    ("zu", Alternate::Default, "Zulu"),
+    ("zun", Alternate::Default, "Zuni"),
+    ("zxx", Alternate::Default, "No linguistic content"),
+    ("zza", Alternate::Default, "Zaza"),
+];
+
+#[rustfmt::skip]
+pub static EN_SCRIPTS: [(&str, Alternate, &str); 205] = [
+    ("Adlm", Alternate::Default, "Adlam"),
+    ("Afak", Alternate::Default, "Afaka"),
+    ("Aghb", Alternate::Default, "Caucasian Albanian"),
+    ("Ahom", Alternate::Default, "Ahom"),
+    ("Arab", Alternate::Default, "Arabic"),
+    ("Arab", Alternate::Variant, "Perso-Arabic"),
+    ("Aran", Alternate::Default, "Nastaliq"),
+    ("Armi", Alternate::Default, "Imperial Aramaic"),
+    ("Armn", Alternate::Default, "Armenian"),
+    ("Avst", Alternate::Default, "Avestan"),
+    ("Bali", Alternate::Default, "Balinese"),
+    ("Bamu", Alternate::Default, "Bamum"),
+    ("Bass", Alternate::Default, "Bassa Vah"),
+    ("Batk", Alternate::Default, "Batak"),
+    ("Beng", Alternate::Default, "Bangla"),
+    ("Bhks", Alternate::Default, "Bhaiksuki"),
+    ("Blis", Alternate::Default, "Blissymbols"),
+    ("Bopo", Alternate::Default, "Bopomofo"),
+    ("Brah", Alternate::Default, "Brahmi"),
+    ("Brai", Alternate::Default, "Braille"),
+    ("Bugi", Alternate::Default, "Buginese"),
+    ("Buhd", Alternate::Default, "Buhid"),
+    ("Cakm", Alternate::Default, "Chakma"),
+    ("Cans", Alternate::Default, "Unified Canadian Aboriginal Syllabics"),
+    ("Cans", Alternate::Short, "UCAS"),
+    ("Cari", Alternate::Default, "Carian"),
+    ("Cham", Alternate::Default, "Cham"),
+    ("Cher", Alternate::Default, "Cherokee"),
+    ("Chrs", Alternate::Default, "Chorasmian"),
+    ("Cirt", Alternate::Default, "Cirth"),
+    ("Copt", Alternate::Default, "Coptic"),
+    ("Cpmn", Alternate::Default, "Cypro-Minoan"),
+    ("Cprt", Alternate::Default, "Cypriot"),
+    ("Cyrl", Alternate::Default, "Cyrillic"),
+    ("Cyrs", Alternate::Default, "Old Church Slavonic Cyrillic"),
+    ("Deva", Alternate::Default, "Devanagari"),
+    ("Diak", Alternate::Default, "Dives Akuru"),
+    ("Dogr", Alternate::Default, "Dogra"),
+    ("Dsrt", Alternate::Default, "Deseret"),
+    ("Dupl", Alternate::Default, "Duployan shorthand"),
+    ("Egyd", Alternate::Default, "Egyptian demotic"),
+    ("Egyh", Alternate::Default, "Egyptian hieratic"),
+    ("Egyp", Alternate::Default, "Egyptian hieroglyphs"),
+    ("Elba", Alternate::Default, "Elbasan"),
+    ("Elym", Alternate::Default, "Elymaic"),
+    ("Ethi", Alternate::Default, "Ethiopic"),
+    ("Geok", Alternate::Default, "Georgian Khutsuri"),
+    ("Geor", Alternate::Default, "Georgian"),
+    ("Glag", Alternate::Default, "Glagolitic"),
+    ("Gong", Alternate::Default, "Gunjala Gondi"),
+    ("Gonm", Alternate::Default, "Masaram Gondi"),
+    ("Goth", Alternate::Default, "Gothic"),
+    ("Gran", Alternate::Default, "Grantha"),
+    ("Grek", Alternate::Default, "Greek"),
+    ("Gujr", Alternate::Default, "Gujarati"),
+    ("Guru", Alternate::Default, "Gurmukhi"),
+    ("Hanb", Alternate::Default, "Han with Bopomofo"),
+    ("Hang", Alternate::Default, "Hangul"),
+    ("Hani", Alternate::Default, "Han"),
+    ("Hano", Alternate::Default, "Hanunoo"),
+    ("Hans", Alternate::Default, "Simplified"),
+    ("Hant", Alternate::Default, "Traditional"),
+    ("Hatr", Alternate::Default, "Hatran"),
+    ("Hebr", Alternate::Default, "Hebrew"),
+    ("Hira", Alternate::Default, "Hiragana"),
+    ("Hluw", Alternate::Default, "Anatolian Hieroglyphs"),
+    ("Hmng", Alternate::Default, "Pahawh Hmong"),
+    ("Hmnp", Alternate::Default, "Nyiakeng Puachue Hmong"),
+    ("Hrkt", Alternate::Default, "Japanese syllabaries"),
+    ("Hung", Alternate::Default, "Old Hungarian"),
+    ("Inds", Alternate::Default, "Indus"),
+    ("Ital", Alternate::Default, "Old Italic"),
+    ("Jamo", Alternate::Default, "Jamo"),
+    ("Java", Alternate::Default, "Javanese"),
+    ("Jpan", Alternate::Default, "Japanese"),
+    ("Jurc", Alternate::Default, "Jurchen"),
+    ("Kali", Alternate::Default, "Kayah Li"),
+    ("Kana", Alternate::Default, "Katakana"),
+    ("Kawi", Alternate::Default, "Kawi"),
+    ("Khar", Alternate::Default, "Kharoshthi"),
+    ("Khmr", Alternate::Default, "Khmer"),
+    ("Khoj", Alternate::Default, "Khojki"),
+    ("Kits", Alternate::Default, "Khitan small script"),
+    ("Knda", Alternate::Default, "Kannada"),
+    ("Kore", Alternate::Default, "Korean"),
+    ("Kpel", Alternate::Default, "Kpelle"),
+    ("Kthi", Alternate::Default, "Kaithi"),
+    ("Lana", Alternate::Default, "Lanna"),
+    ("Laoo", Alternate::Default, "Lao"),
+    ("Latf", Alternate::Default, "Fraktur Latin"),
+    ("Latg", Alternate::Default, "Gaelic Latin"),
+    ("Latn", Alternate::Default, "Latin"),
+    ("Lepc", Alternate::Default, "Lepcha"),
+    ("Limb", Alternate::Default, "Limbu"),
+    ("Lina", Alternate::Default, "Linear A"),
+    ("Linb", Alternate::Default, "Linear B"),
+    ("Lisu", Alternate::Default, "Fraser"),
+    ("Loma", Alternate::Default, "Loma"),
+    ("Lyci", Alternate::Default, "Lycian"),
+    ("Lydi", Alternate::Default, "Lydian"),
+    ("Mahj", Alternate::Default, "Mahajani"),
+    ("Maka", Alternate::Default, "Makasar"),
+    ("Mand", Alternate::Default, "Mandaean"),
+    ("Mani", Alternate::Default, "Manichaean"),
+    ("Marc", Alternate::Default, "Marchen"),
+    ("Maya", Alternate::Default, "Mayan hieroglyphs"),
+    ("Medf", Alternate::Default, "Medefaidrin"),
+    ("Mend", Alternate::Default, "Mende"),
+    ("Merc", Alternate::Default, "Meroitic Cursive"),
+    ("Mero", Alternate::Default, "Meroitic"),
+    ("Mlym", Alternate::Default, "Malayalam"),
+    ("Modi", Alternate::Default, "Modi"),
+    ("Mong", Alternate::Default, "Mongolian"),
+    ("Moon", Alternate::Default, "Moon"),
+    ("Mroo", Alternate::Default, "Mro"),
+    ("Mtei", Alternate::Default, "Meitei Mayek"),
+    ("Mult", Alternate::Default, "Multani"),
+    ("Mymr", Alternate::Default, "Myanmar"),
+    ("Nagm", Alternate::Default, "Nag Mundari"),
+    ("Nand", Alternate::Default, "Nandinagari"),
+    ("Narb", Alternate::Default, "Old North Arabian"),
+    ("Nbat", Alternate::Default, "Nabataean"),
+    ("Newa", Alternate::Default, "Newa"),
+    ("Nkgb", Alternate::Default, "Naxi Geba"),
+    ("Nkoo", Alternate::Default, "N’Ko"),
+    ("Nshu", Alternate::Default, "Nüshu"),
+    ("Ogam", Alternate::Default, "Ogham"),
+    ("Olck", Alternate::Default, "Ol Chiki"),
+    ("Orkh", Alternate::Default, "Orkhon"),
+    ("Orya", Alternate::Default, "Odia"),
+    ("Osge", Alternate::Default, "Osage"),
+    ("Osma", Alternate::Default, "Osmanya"),
+    ("Ougr", Alternate::Default, "Old Uyghur"),
+    ("Palm", Alternate::Default, "Palmyrene"),
+    ("Pauc", Alternate::Default, "Pau Cin Hau"),
+    ("Perm", Alternate::Default, "Old Permic"),
+    ("Phag", Alternate::Default, "Phags-pa"),
+    ("Phli", Alternate::Default, "Inscriptional Pahlavi"),
+    ("Phlp", Alternate::Default, "Psalter Pahlavi"),
+    ("Phlv", Alternate::Default, "Book Pahlavi"),
+    ("Phnx", Alternate::Default, "Phoenician"),
+    ("Plrd", Alternate::Default, "Pollard Phonetic"),
+    ("Prti", Alternate::Default, "Inscriptional Parthian"),
+    ("Qaag", Alternate::Default, "Zawgyi"),
+    ("Rjng", Alternate::Default, "Rejang"),
+    ("Rohg", Alternate::Default, "Hanifi"),
+    ("Roro", Alternate::Default, "Rongorongo"),
+    ("Runr", Alternate::Default, "Runic"),
+    ("Samr", Alternate::Default, "Samaritan"),
+    ("Sara", Alternate::Default, "Sarati"),
+    ("Sarb", Alternate::Default, "Old South Arabian"),
+    ("Saur", Alternate::Default, "Saurashtra"),
+    ("Sgnw", Alternate::Default, "SignWriting"),
+    ("Shaw", Alternate::Default, "Shavian"),
+    ("Shrd", Alternate::Default, "Sharada"),
+    ("Sidd", Alternate::Default, "Siddham"),
+    ("Sind", Alternate::Default, "Khudawadi"),
+    ("Sinh", Alternate::Default, "Sinhala"),
+    ("Sogd", Alternate::Default, "Sogdian"),
+    ("Sogo", Alternate::Default, "Old Sogdian"),
+    ("Sora", Alternate::Default, "Sora Sompeng"),
+    ("Soyo", Alternate::Default, "Soyombo"),
+    ("Sund", Alternate::Default, "Sundanese"),
+    ("Sylo", Alternate::Default, "Syloti Nagri"),
+    ("Syrc", Alternate::Default, "Syriac"),
+    ("Syre", Alternate::Default, "Estrangelo Syriac"),
+    ("Syrj", Alternate::Default, "Western Syriac"),
+    ("Syrn", Alternate::Default, "Eastern Syriac"),
+    ("Tagb", Alternate::Default, "Tagbanwa"),
+    ("Takr", Alternate::Default, "Takri"),
+    ("Tale", Alternate::Default, "Tai Le"),
+    ("Talu", Alternate::Default, "New Tai Lue"),
+    ("Taml", Alternate::Default, "Tamil"),
+    ("Tang", Alternate::Default, "Tangut"),
+    ("Tavt", Alternate::Default, "Tai Viet"),
+    ("Telu", Alternate::Default, "Telugu"),
+    ("Teng", Alternate::Default, "Tengwar"),
+    ("Tfng", Alternate::Default, "Tifinagh"),
+    ("Tglg", Alternate::Default, "Tagalog"),
+    ("Thaa", Alternate::Default, "Thaana"),
+    ("Thai", Alternate::Default, "Thai"),
+    ("Tibt", Alternate::Default, "Tibetan"),
+    ("Tirh", Alternate::Default, "Tirhuta"),
+    ("Tnsa", Alternate::Default, "Tangsa"),
+    ("Toto", Alternate::Default, "Toto"),
+    ("Ugar", Alternate::Default, "Ugaritic"),
+    ("Vaii", Alternate::Default, "Vai"),
+    ("Visp", Alternate::Default, "Visible Speech"),
+    ("Vith", Alternate::Default, "Vithkuqi"),
+    ("Wara", Alternate::Default, "Varang Kshiti"),
+    ("Wcho", Alternate::Default, "Wancho"),
+    ("Wole", Alternate::Default, "Woleai"),
+    ("Xpeo", Alternate::Default, "Old Persian"),
+    ("Xsux", Alternate::Default, "Sumero-Akkadian Cuneiform"),
+    ("Xsux", Alternate::Short, "S-A Cuneiform"),
+    ("Yezi", Alternate::Default, "Yezidi"),
+    ("Yiii", Alternate::Default, "Yi"),
+    ("Zanb", Alternate::Default, "Zanabazar Square"),
+    ("Zinh", Alternate::Default, "Inherited"),
+    ("Zmth", Alternate::Default, "Mathematical Notation"),
+    ("Zsye", Alternate::Default, "Emoji"),
+    ("Zsym", Alternate::Default, "Symbols"),
+    ("Zxxx", Alternate::Default, "Unwritten"),
+    ("Zyyy", Alternate::Default, "Common"),
+    ("Zzzz", Alternate::Default, "Unknown Script"),
+];
+
+#[rustfmt::skip]
+pub static EN_REGIONS: [(&str, Alternate, &str); 313] = [
+    ("001", Alternate::Default, "world"),
+    ("002", Alternate::Default, "Africa"),
+    ("003", Alternate::Default, "North America"),
+    ("005", Alternate::Default, "South America"),
+    ("009", Alternate::Default, "Oceania"),
+    ("011", Alternate::Default, "Western Africa"),
+    ("013", Alternate::Default, "Central America"),
+    ("014", Alternate::Default, "Eastern Africa"),
+    ("015", Alternate::Default, "Northern Africa"),
+    ("017", Alternate::Default, "Middle Africa"),
+    ("018", Alternate::Default, "Southern Africa"),
+    ("019", Alternate::Default, "Americas"),
+    ("021", Alternate::Default, "Northern America"),
+    ("029", Alternate::Default, "Caribbean"),
+    ("030", Alternate::Default, "Eastern Asia"),
+    ("034", Alternate::Default, "Southern Asia"),
+    ("035", Alternate::Default, "Southeast Asia"),
+    ("039", Alternate::Default, "Southern Europe"),
+    ("053", Alternate::Default, "Australasia"),
+    ("054", Alternate::Default, "Melanesia"),
+    ("057", Alternate::Default, "Micronesian Region"),
+    ("061", Alternate::Default, "Polynesia"),
+    ("142", Alternate::Default, "Asia"),
+    ("143", Alternate::Default, "Central Asia"),
+    ("145", Alternate::Default, "Western Asia"),
+    ("150", Alternate::Default, "Europe"),
+    ("151", Alternate::Default, "Eastern Europe"),
+    ("154", Alternate::Default, "Northern Europe"),
+    ("155", Alternate::Default, "Western Europe"),
+    ("202", Alternate::Default, "Sub-Saharan Africa"),
+    ("419", Alternate::Default, "Latin America"),
+    ("AC", Alternate::Default, "Ascension Island"),
+    ("AD", Alternate::Default, "Andorra"),
+    ("AE", Alternate::Default, "United Arab Emirates"),
+    ("AF", Alternate::Default, "Afghanistan"),
+    ("AG", Alternate::Default, "Antigua & Barbuda"),
+    ("AI", Alternate::Default, "Anguilla"),
+    ("AL", Alternate::Default, "Albania"),
+    ("AM", Alternate::Default, "Armenia"),
+    ("AO", Alternate::Default, "Angola"),
+    ("AQ", Alternate::Default, "Antarctica"),
+    ("AR", Alternate::Default, "Argentina"),
+    ("AS", Alternate::Default, "American Samoa"),
+    ("AT", Alternate::Default, "Austria"),
+    ("AU", Alternate::Default, "Australia"),
+    ("AW", Alternate::Default, "Aruba"),
+    ("AX", Alternate::Default, "Åland Islands"),
+    ("AZ", Alternate::Default, "Azerbaijan"),
+    ("BA", Alternate::Default, "Bosnia & Herzegovina"),
+    ("BA", Alternate::Short, "Bosnia"),
+    ("BB", Alternate::Default, "Barbados"),
+    ("BD", Alternate::Default, "Bangladesh"),
+    ("BE", Alternate::Default, "Belgium"),
+    ("BF", Alternate::Default, "Burkina Faso"),
+    ("BG", Alternate::Default, "Bulgaria"),
+    ("BH", Alternate::Default, "Bahrain"),
+    ("BI", Alternate::Default, "Burundi"),
+    ("BJ", Alternate::Default, "Benin"),
+    ("BL", Alternate::Default, "St. Barthélemy"),
+    ("BM", Alternate::Default, "Bermuda"),
+    ("BN", Alternate::Default, "Brunei"),
+    ("BO", Alternate::Default, "Bolivia"),
+    ("BQ", Alternate::Default, "Caribbean Netherlands"),
+    ("BR", Alternate::Default, "Brazil"),
+    ("BS", Alternate::Default, "Bahamas"),
+    ("BT", Alternate::Default, "Bhutan"),
+    ("BV", Alternate::Default, "Bouvet Island"),
+    ("BW", Alternate::Default, "Botswana"),
+    ("BY", Alternate::Default, "Belarus"),
+    ("BZ", Alternate::Default, "Belize"),
+    ("CA", Alternate::Default, "Canada"),
+    ("CC", Alternate::Default, "Cocos (Keeling) Islands"),
+    ("CD", Alternate::Default, "Congo - Kinshasa"),
+    ("CD", Alternate::Variant, "Congo (DRC)"),
+    ("CF", Alternate::Default, "Central African Republic"),
+    ("CG", Alternate::Default, "Congo - Brazzaville"),
+    ("CG", Alternate::Variant, "Congo (Republic)"),
+    ("CH", Alternate::Default, "Switzerland"),
+    ("CI", Alternate::Default, "Côte d’Ivoire"),
+    ("CI", Alternate::Variant, "Ivory Coast"),
+    ("CK", Alternate::Default, "Cook Islands"),
+    ("CL", Alternate::Default, "Chile"),
+    ("CM", Alternate::Default, "Cameroon"),
+    ("CN", Alternate::Default, "China"),
+    ("CO", Alternate::Default, "Colombia"),
+    ("CP", Alternate::Default, "Clipperton Island"),
+    ("CQ", Alternate::Default, "Sark"),
+    ("CR", Alternate::Default, "Costa Rica"),
+    ("CU", Alternate::Default, "Cuba"),
+    ("CV", Alternate::Default, "Cape Verde"),
+    ("CV", Alternate::Variant, "Cabo Verde"),
+    ("CW", Alternate::Default, "Curaçao"),
+    ("CX", Alternate::Default, "Christmas Island"),
+    ("CY", Alternate::Default, "Cyprus"),
+    ("CZ", Alternate::Default, "Czechia"),
+    ("CZ", Alternate::Variant, "Czech Republic"),
+    ("DE", Alternate::Default, "Germany"),
+    ("DG", Alternate::Default, "Diego Garcia"),
+    ("DJ", Alternate::Default, "Djibouti"),
+    ("DK", Alternate::Default, "Denmark"),
+    ("DM", Alternate::Default, "Dominica"),
+    ("DO", Alternate::Default, "Dominican Republic"),
+    ("DZ", Alternate::Default, "Algeria"),
+    ("EA", Alternate::Default, "Ceuta & Melilla"),
+    ("EC", Alternate::Default, "Ecuador"),
+    ("EE", Alternate::Default, "Estonia"),
+    ("EG", Alternate::Default, "Egypt"),
+    ("EH", Alternate::Default, "Western Sahara"),
+    ("ER", Alternate::Default, "Eritrea"),
+    ("ES", Alternate::Default, "Spain"),
+    ("ET", Alternate::Default, "Ethiopia"),
+    ("EU", Alternate::Default, "European Union"),
+    ("EZ", Alternate::Default, "Eurozone"),
+    ("FI", Alternate::Default, "Finland"),
+    ("FJ", Alternate::Default, "Fiji"),
+    ("FK", Alternate::Default, "Falkland Islands"),
+    ("FK", Alternate::Variant, "Falkland Islands (Islas Malvinas)"),
+    ("FM", Alternate::Default, "Micronesia"),
+    ("FO", Alternate::Default, "Faroe Islands"),
+    ("FR", Alternate::Default, "France"),
+    ("GA", Alternate::Default, "Gabon"),
+    ("GB", Alternate::Default, "United Kingdom"),
+    ("GB", Alternate::Short, "UK"),
+    ("GD", Alternate::Default, "Grenada"),
+    ("GE", Alternate::Default, "Georgia"),
+    ("GF", Alternate::Default, "French Guiana"),
+    ("GG", Alternate::Default, "Guernsey"),
+    ("GH", Alternate::Default, "Ghana"),
+    ("GI", Alternate::Default, "Gibraltar"),
+    ("GL", Alternate::Default, "Greenland"),
+    ("GM", Alternate::Default, "Gambia"),
+    ("GN", Alternate::Default, "Guinea"),
+    ("GP", Alternate::Default, "Guadeloupe"),
+    ("GQ", Alternate::Default, "Equatorial Guinea"),
+    ("GR", Alternate::Default, "Greece"),
+    ("GS", Alternate::Default, "South Georgia & South Sandwich Islands"),
+    ("GT", Alternate::Default, "Guatemala"),
+    ("GU", Alternate::Default, "Guam"),
+    ("GW", Alternate::Default, "Guinea-Bissau"),
+    ("GY", Alternate::Default, "Guyana"),
+    ("HK", Alternate::Default, "Hong Kong SAR China"),
+    ("HK", Alternate::Short, "Hong Kong"),
+    ("HM", Alternate::Default, "Heard & McDonald Islands"),
+    ("HN", Alternate::Default, "Honduras"),
+    ("HR", Alternate::Default, "Croatia"),
+    ("HT", Alternate::Default, "Haiti"),
+    ("HU", Alternate::Default, "Hungary"),
+    ("IC", Alternate::Default, "Canary Islands"),
+    ("ID", Alternate::Default, "Indonesia"),
+    ("IE", Alternate::Default, "Ireland"),
+    ("IL", Alternate::Default, "Israel"),
+    ("IM", Alternate::Default, "Isle of Man"),
+    ("IN", Alternate::Default, "India"),
+    ("IO", Alternate::Default, "British Indian Ocean Territory"),
+    ("IQ", Alternate::Default, "Iraq"),
+    ("IR", Alternate::Default, "Iran"),
+    ("IS", Alternate::Default, "Iceland"),
+    ("IT", Alternate::Default, "Italy"),
+    ("JE", Alternate::Default, "Jersey"),
+    ("JM", Alternate::Default, "Jamaica"),
+    ("JO", Alternate::Default, "Jordan"),
+    ("JP", Alternate::Default, "Japan"),
+    ("KE", Alternate::Default, "Kenya"),
+    ("KG", Alternate::Default, "Kyrgyzstan"),
+    ("KH", Alternate::Default, "Cambodia"),
+    ("KI", Alternate::Default, "Kiribati"),
+    ("KM", Alternate::Default, "Comoros"),
+    ("KN", Alternate::Default, "St. Kitts & Nevis"),
+    ("KP", Alternate::Default, "North Korea"),
+    ("KR", Alternate::Default, "South Korea"),
+    ("KW", Alternate::Default, "Kuwait"),
+    ("KY", Alternate::Default, "Cayman Islands"),
+    ("KZ", Alternate::Default, "Kazakhstan"),
+    ("LA", Alternate::Default, "Laos"),
+    ("LB", Alternate::Default, "Lebanon"),
+    ("LC", Alternate::Default, "St. Lucia"),
+    ("LI", Alternate::Default, "Liechtenstein"),
+    ("LK", Alternate::Default, "Sri Lanka"),
+    ("LR", Alternate::Default, "Liberia"),
+    ("LS", Alternate::Default, "Lesotho"),
+    ("LT", Alternate::Default, "Lithuania"),
+    ("LU", Alternate::Default, "Luxembourg"),
+    ("LV", Alternate::Default, "Latvia"),
+    ("LY", Alternate::Default, "Libya"),
+    ("MA", Alternate::Default, "Morocco"),
+    ("MC", Alternate::Default, "Monaco"),
+    ("MD", Alternate::Default, "Moldova"),
+    ("ME", Alternate::Default, "Montenegro"),
+    ("MF", Alternate::Default, "St. Martin"),
+    ("MG", Alternate::Default, "Madagascar"),
+    ("MH", Alternate::Default, "Marshall Islands"),
+    ("MK", Alternate::Default, "North Macedonia"),
+    ("ML", Alternate::Default, "Mali"),
+    ("MM", Alternate::Default, "Myanmar (Burma)"),
+    ("MM", Alternate::Short, "Myanmar"),
+    ("MN", Alternate::Default, "Mongolia"),
+    ("MO", Alternate::Default, "Macao SAR China"),
+    ("MO", Alternate::Short, "Macao"),
+    ("MP", Alternate::Default, "Northern Mariana Islands"),
+    ("MQ", Alternate::Default, "Martinique"),
+    ("MR", Alternate::Default, "Mauritania"),
+    ("MS", Alternate::Default, "Montserrat"),
+    ("MT", Alternate::Default, "Malta"),
+    ("MU", Alternate::Default, "Mauritius"),
+    ("MV", Alternate::Default, "Maldives"),
+    ("MW", Alternate::Default, "Malawi"),
+    ("MX", Alternate::Default, "Mexico"),
+    ("MY", Alternate::Default, "Malaysia"),
+    ("MZ", Alternate::Default, "Mozambique"),
+    ("NA", Alternate::Default, "Namibia"),
+    ("NC", Alternate::Default, "New Caledonia"),
+    ("NE", Alternate::Default, "Niger"),
+    ("NF", Alternate::Default, "Norfolk Island"),
+    ("NG", Alternate::Default, "Nigeria"),
+    ("NI", Alternate::Default, "Nicaragua"),
+    ("NL", Alternate::Default, "Netherlands"),
+    ("NO", Alternate::Default, "Norway"),
+    ("NP", Alternate::Default, "Nepal"),
+    ("NR", Alternate::Default, "Nauru"),
+    ("NU", Alternate::Default, "Niue"),
+    ("NZ", Alternate::Default, "New Zealand"),
+    ("NZ", Alternate::Variant, "Aotearoa New Zealand"),
+    ("OM", Alternate::Default, "Oman"),
+    ("PA", Alternate::Default, "Panama"),
+    ("PE", Alternate::Default, "Peru"),
+    ("PF", Alternate::Default, "French Polynesia"),
+    ("PG", Alternate::Default, "Papua New Guinea"),
+    ("PH", Alternate::Default, "Philippines"),
+    ("PK", Alternate::Default, "Pakistan"),
+    ("PL", Alternate::Default, "Poland"),
+    ("PM", Alternate::Default, "St. Pierre & Miquelon"),
+    ("PN", Alternate::Default, "Pitcairn Islands"),
+    ("PR", Alternate::Default, "Puerto Rico"),
+    ("PS", Alternate::Default, "Palestinian Territories"),
+    ("PS", Alternate::Short, "Palestine"),
+    ("PT", Alternate::Default, "Portugal"),
+    ("PW", Alternate::Default, "Palau"),
+    ("PY", Alternate::Default, "Paraguay"),
+    ("QA", Alternate::Default, "Qatar"),
+    ("QO", Alternate::Default, "Outlying Oceania"),
+    ("RE", Alternate::Default, "Réunion"),
+    ("RO", Alternate::Default, "Romania"),
+    ("RS", Alternate::Default, "Serbia"),
+    ("RU", Alternate::Default, "Russia"),
+    ("RW", Alternate::Default, "Rwanda"),
+    ("SA", Alternate::Default, "Saudi Arabia"),
+    ("SB", Alternate::Default, "Solomon Islands"),
+    ("SC", Alternate::Default, "Seychelles"),
+    ("SD", Alternate::Default, "Sudan"),
+    ("SE", Alternate::Default, "Sweden"),
+    ("SG", Alternate::Default, "Singapore"),
+    ("SH", Alternate::Default, "St. Helena"),
+    ("SI", Alternate::Default, "Slovenia"),
+    ("SJ", Alternate::Default, "Svalbard & Jan Mayen"),
+    ("SK", Alternate::Default, "Slovakia"),
+    ("SL", Alternate::Default, "Sierra Leone"),
+    ("SM", Alternate::Default, "San Marino"),
+    ("SN", Alternate::Default, "Senegal"),
+    ("SO", Alternate::Default, "Somalia"),
+    ("SR", Alternate::Default, "Suriname"),
+    ("SS", Alternate::Default, "South Sudan"),
+    ("ST", Alternate::Default, "São Tomé & Príncipe"),
+    ("SV", Alternate::Default, "El Salvador"),
+    ("SX", Alternate::Default, "Sint Maarten"),
+    ("SY", Alternate::Default, "Syria"),
+    ("SZ", Alternate::Default, "Eswatini"),
+    ("SZ", Alternate::Variant, "Swaziland"),
+    ("TA", Alternate::Default, "Tristan da Cunha"),
+    ("TC", Alternate::Default, "Turks & Caicos Islands"),
+    ("TD", Alternate::Default, "Chad"),
+    ("TF", Alternate::Default, "French Southern Territories"),
+    ("TG", Alternate::Default, "Togo"),
+    ("TH", Alternate::Default, "Thailand"),
+    ("TJ", Alternate::Default, "Tajikistan"),
+    ("TK", Alternate::Default, "Tokelau"),
+    ("TL", Alternate::Default, "Timor-Leste"),
+    ("TL", Alternate::Variant, "East Timor"),
+    ("TM", Alternate::Default, "Turkmenistan"),
+    ("TN", Alternate::Default, "Tunisia"),
+    ("TO", Alternate::Default, "Tonga"),
+    ("TR", Alternate::Default, "Türkiye"),
+    ("TR", Alternate::Variant, "Turkey"),
+    ("TT", Alternate::Default, "Trinidad & Tobago"),
+    ("TV", Alternate::Default, "Tuvalu"),
+    ("TW", Alternate::Default, "Taiwan"),
+    ("TZ", Alternate::Default, "Tanzania"),
+    ("UA", Alternate::Default, "Ukraine"),
+    ("UG", Alternate::Default, "Uganda"),
+    ("UM", Alternate::Default, "U.S. Outlying Islands"),
+    ("UN", Alternate::Default, "United Nations"),
+    ("UN", Alternate::Short, "UN"),
+    ("US", Alternate::Default, "United States"),
+    ("US", Alternate::Short, "US"),
+    ("UY", Alternate::Default, "Uruguay"),
+    ("UZ", Alternate::Default, "Uzbekistan"),
+    ("VA", Alternate::Default, "Vatican City"),
+    ("VC", Alternate::Default, "St. Vincent & Grenadines"),
+    ("VE", Alternate::Default, "Venezuela"),
+    ("VG", Alternate::Default, "British Virgin Islands"),
+    ("VI", Alternate::Default, "U.S. Virgin Islands"),
+    ("VN", Alternate::Default, "Vietnam"),
+    ("VU", Alternate::Default, "Vanuatu"),
+    ("WF", Alternate::Default, "Wallis & Futuna"),
+    ("WS", Alternate::Default, "Samoa"),
+    ("XA", Alternate::Default, "Pseudo-Accents"),
+    ("XB", Alternate::Default, "Pseudo-Bidi"),
+    ("XK", Alternate::Default, "Kosovo"),
+    ("YE", Alternate::Default, "Yemen"),
+    ("YT", Alternate::Default, "Mayotte"),
+    ("ZA", Alternate::Default, "South Africa"),
+    ("ZM", Alternate::Default, "Zambia"),
+    ("ZW", Alternate::Default, "Zimbabwe"),
+    ("ZZ", Alternate::Default, "Unknown Region"),
+];
+
+#[rustfmt::skip]
+pub static EN_VARIANTS: [(&str, &str); 55] = [
+    ("1606nict", "Late Middle French to 1606"),
+    ("1694acad", "Early Modern French"),
+    ("1901", "Traditional German orthography"),
+    ("1959acad", "Academic"),
+    ("1994", "Standardized Resian orthography"),
+    ("1996", "German orthography of 1996"),
+    ("abl1943", "Orthographic formulation of 1943"),
+    ("alalc97", "ALA-LC Romanization, 1997 edition"),
+    ("aluku", "Aluku dialect"),
+    ("ao1990", "Portuguese Language Orthographic Agreement of 1990"),
+    ("arevela", "Eastern Armenian"),
+    ("arevmda", "Western Armenian"),
+    ("baku1926", "Unified Turkic Latin Alphabet"),
+    ("balanka", "Balanka dialect of Anii"),
+    ("barla", "Barlavento dialect group of Kabuverdianu"),
+    ("biske", "San Giorgio/Bila dialect"),
+    ("bohoric", "Bohorič alphabet"),
+    ("boont", "Boontling"),
+    ("colb1945", "Portuguese-Brazilian Orthographic Convention of 1945"),
+    ("dajnko", "Dajnko alphabet"),
+    ("ekavsk", "Serbian with Ekavian pronunciation"),
+    ("emodeng", "Early Modern English"),
+    ("fonipa", "IPA Phonetics"),
+    ("fonupa", "UPA Phonetics"),
+    ("hepburn", "Hepburn romanization"),
+    ("ijekavsk", "Serbian with Ijekavian pronunciation"),
+    ("kkcor", "Common Orthography"),
+    ("kscor", "Standard Orthography"),
+    ("lipaw", "The Lipovaz dialect of Resian"),
+    ("metelko", "Metelko alphabet"),
+    ("monoton", "Monotonic"),
+    ("ndyuka", "Ndyuka dialect"),
+    ("nedis", "Natisone dialect"),
+    ("njiva", "Gniva/Njiva dialect"),
+    ("nulik", "Modern Volapük"),
+    ("osojs", "Oseacco/Osojane dialect"),
+    ("oxendict", "Oxford English Dictionary spelling"),
+    ("pamaka", "Pamaka dialect"),
+    ("pinyin", "Pinyin Romanization"),
+    ("polyton", "Polytonic"),
+    ("posix", "Computer"),
+    ("revised", "Revised Orthography"),
+    ("rigik", "Classic Volapük"),
+    ("rozaj", "Resian"),
+    ("saaho", "Saho"),
+    ("scotland", "Scottish Standard English"),
+    ("scouse", "Scouse"),
+    ("solba", "Stolvizza/Solbica dialect"),
+    ("sotav", "Sotavento dialect group of Kabuverdianu"),
+    ("tarask", "Taraskievica orthography"),
+    ("uccor", "Unified Orthography"),
+    ("ucrcor", "Unified Revised Orthography"),
+    ("unifon", "Unifon phonetic alphabet"),
+    ("valencia", "Valencian"),
+    ("wadegile", "Wade-Giles Romanization"),
+];
+
+#[rustfmt::skip]
+pub static EN_KEYS: [(&str, &str); 27] = [
+    ("ca", "Calendar"),
+    ("cf", "Currency Format"),
+    ("co", "Sort Order"),
+    ("cu", "Currency"),
+    ("dx", "Dictionary Break Exclusions"),
+    ("em", "Emoji Presentation Style"),
+    ("fw", "First day of week"),
+    ("hc", "Hour Cycle (12 vs 24)"),
+    ("ka", "Ignore Symbols Sorting"),
+    ("kb", "Reversed Accent Sorting"),
+    ("kc", "Case Sensitive Sorting"),
+    ("kf", "Uppercase/Lowercase Ordering"),
+    ("kk", "Normalized Sorting"),
+    ("kn", "Numeric Sorting"),
+    ("kr", "Script/Block Reordering"),
+    ("ks", "Sorting Strength"),
+    ("kv", "Highest Ignored"),
+    ("lb", "Line Break Style"),
+    ("lw", "Line Breaks In Words Setting"),
+    ("ms", "Measurement System"),
+    ("mu", "Measurement Unit"),
+    ("nu", "Numbers"),
+    ("rg", "Region For Supplemental Data"),
+    ("sd", "Region Subdivision"),
+    ("ss", "Sentence Break Suppressions Type"),
+    ("tz", "Time Zone"),
+    ("va", "Locale Variant"),
+];
+
+#[rustfmt::skip]
+pub static EN_TYPES: [(&str, &str, &str); 181] = [
+    ("ca", "buddhist", "Buddhist Calendar"),
+    ("ca", "chinese", "Chinese Calendar"),
+    ("ca", "coptic", "Coptic Calendar"),
+    ("ca", "dangi", "Dangi Calendar"),
+    ("ca", "ethioaa", "Ethiopic Amete Alem Calendar"),
+    ("ca", "ethiopic", "Ethiopic Calendar"),
+    ("ca", "gregory", "Gregorian Calendar"),
+    ("ca", "hebrew", "Hebrew Calendar"),
+    ("ca", "indian", "Indian National Calendar"),
+    ("ca", "islamic", "Islamic Calendar"),
+    ("ca", "islamic-civil", "Islamic Calendar (tabular, civil epoch)"),
+    ("ca", "islamic-rgsa", "Islamic Calendar (Saudi Arabia, sighting)"),
+    ("ca", "islamic-tbla", "Islamic Calendar (tabular, astronomical epoch)"),
+    ("ca", "islamic-umalqura", "Islamic Calendar (Umm al-Qura)"),
+    ("ca", "iso8601", "ISO-8601 Calendar"),
+    ("ca", "japanese", "Japanese Calendar"),
+    ("ca", "persian", "Persian Calendar"),
+    ("ca", "roc", "Minguo Calendar"),
+    ("cf", "account", "Accounting Currency Format"),
+    ("cf", "standard", "Standard Currency Format"),
+    ("co", "big5han", "Traditional Chinese Sort Order - Big5"),
+    ("co", "compat", "Previous Sort Order, for compatibility"),
+    ("co", "dict", "Dictionary Sort Order"),
+    ("co", "ducet", "Default Unicode Sort Order"),
+    ("co", "emoji", "Emoji Sort Order"),
+    ("co", "eor", "European Ordering Rules"),
+    ("co", "gb2312", "Simplified Chinese Sort Order - GB2312"),
+    ("co", "phonebk", "Phonebook Sort Order"),
+    ("co", "phonetic", "Phonetic Sort Order"),
+    ("co", "pinyin", "Pinyin Sort Order"),
+    ("co", "reformed", "Reformed Sort Order"),
+    ("co", "search", "General-Purpose Search"),
+    ("co", "searchjl", "Search By Hangul Initial Consonant"),
+    ("co", "standard", "Standard Sort Order"),
+    ("co", "stroke", "Stroke Sort Order"),
+    ("co", "trad", "Traditional Sort Order"),
+    ("co", "unihan", "Radical-Stroke Sort Order"),
+    ("co", "zhuyin", "Zhuyin Sort Order"),
+    ("em", "default", "Use Default Presentation For Emoji Characters"),
+    ("em", "emoji", "Prefer Emoji Presentation For Emoji Characters"),
+    ("em", "text", "Prefer Text Presentation For Emoji Characters"),
+    ("fw", "fri", "First Day of Week Is Friday"),
+    ("fw", "mon", "First Day of Week Is Monday"),
+    ("fw", "sat", "First Day of Week Is Saturday"),
+    ("fw", "sun", "First Day of Week Is Sunday"),
+    ("fw", "thu", "First Day of Week Is Thursday"),
+    ("fw", "tue", "First Day of Week Is Tuesday"),
+    ("fw", "wed", "First Day of Week Is Wednesday"),
+    ("hc", "h11", "12 Hour System (0–11)"),
+    ("hc", "h12", "12 Hour System (1–12)"),
+    ("hc", "h23", "24 Hour System (0–23)"),
+    ("hc", "h24", "24 Hour System (1–24)"),
+    ("ka", "shifted", "Sort Ignoring Symbols"),
+    ("kb", "false", "Sort Accents Normally"),
+    ("kb", "true", "Sort Accents Reversed"),
+    ("kc", "false", "Sort Case Insensitive"),
+    ("kc", "true", "Sort Case Sensitive"),
+    ("kf", "false", "Sort Normal Case Order"),
+    ("kf", "lower", "Sort Lowercase First"),
+    ("kf", "upper", "Sort Uppercase First"),
+    ("kk", "false", "Sort Without Normalization"),
+    ("kk", "true", "Sort Unicode Normalized"),
+    ("kn", "false", "Sort Digits Individually"),
+    ("kn", "true", "Sort Digits Numerically"),
+    ("kr", "currency", "Currency"),
+    ("kr", "digit", "Digits"),
+    ("kr", "punct", "Punctuation"),
+    ("kr", "space", "Whitespace"),
+    ("kr", "symbol", "Symbol"),
+    ("ks", "primary", "Sort Base Letters Only"),
+    ("ks", "tertiary", "Sort Accents/Case/Width"),
+    ("kv", "currency", "Ignore Symbols affects spaces, punctuation, all symbols"),
+    ("kv", "punct", "Ignore Symbols affects spaces and punctuation only"),
+    ("kv", "space", "Ignore Symbols affects spaces only"),
+    ("kv", "symbol", "Ignore Symbols affects spaces, punctuation, non-currency symbols"),
+    ("lb", "loose", "Loose Line Break Style"),
+    ("lb", "normal", "Normal Line Break Style"),
+    ("lb", "strict", "Strict Line Break Style"),
+    ("lw", "breakall", "Allow Line Breaks In All Words"),
+    ("lw", "keepall", "Prevent Line Breaks In All Words"),
+    ("lw", "normal", "Normal Line Breaks For Words"),
+    ("lw", "phrase", "Prevent Line Breaks In Phrases"),
+    ("ms", "metric", "Metric System"),
+    ("ms", "uksystem", "Imperial Measurement System"),
+    ("ms", "ussystem", "US Measurement System"),
+    ("mu", "celsius", "Celsius"),
+    ("mu", "fahrenhe", "Fahrenheit"),
+    ("mu", "kelvin", "Kelvin"),
+    ("nu", "adlm", "Adlam Digits"),
+    ("nu", "ahom", "Ahom Digits"),
+    ("nu", "arab", "Arabic-Indic Digits"),
+    ("nu", "arabext", "Extended Arabic-Indic Digits"),
+    ("nu", "armn", "Armenian Numerals"),
+    ("nu", "armnlow", "Armenian Lowercase Numerals"),
+    ("nu", "bali", "Balinese Digits"),
+    ("nu", "beng", "Bangla Digits"),
+    ("nu", "bhks", "Bhaiksuki Digits"),
+    ("nu", "brah", "Brahmi Digits"),
+    ("nu", "cakm", "Chakma Digits"),
+    ("nu", "cham", "Cham Digits"),
+    ("nu", "cyrl", "Cyrillic Numerals"),
+    ("nu", "deva", "Devanagari Digits"),
+    ("nu", "diak", "Dives Akuru Digits"),
+    ("nu", "ethi", "Ethiopic Numerals"),
+    ("nu", "finance", "Financial Numerals"),
+    ("nu", "fullwide", "Full-Width Digits"),
+    ("nu", "geor", "Georgian Numerals"),
+    ("nu", "gong", "Gunjala Gondi digits"),
+    ("nu", "gonm", "Masaram Gondi digits"),
+    ("nu", "grek", "Greek Numerals"),
+    ("nu", "greklow", "Greek Lowercase Numerals"),
+    ("nu", "gujr", "Gujarati Digits"),
+    ("nu", "guru", "Gurmukhi Digits"),
+    ("nu", "hanidays", "Chinese Calendar Day-of-Month Numerals"),
+    ("nu", "hanidec", "Chinese Decimal Numerals"),
+    ("nu", "hans", "Simplified Chinese Numerals"),
+    ("nu", "hansfin", "Simplified Chinese Financial Numerals"),
+    ("nu", "hant", "Traditional Chinese Numerals"),
+    ("nu", "hantfin", "Traditional Chinese Financial Numerals"),
+    ("nu", "hebr", "Hebrew Numerals"),
+    ("nu", "hmng", "Pahawh Hmong Digits"),
+    ("nu", "hmnp", "Nyiakeng Puachue Hmong Digits"),
+    ("nu", "java", "Javanese Digits"),
+    ("nu", "jpan", "Japanese Numerals"),
+    ("nu", "jpanfin", "Japanese Financial Numerals"),
+    ("nu", "jpanyear", "Japanese Calendar Gannen Year Numerals"),
+    ("nu", "kali", "Kayah Li Digits"),
+    ("nu", "kawi", "Kawi Digits"),
+    ("nu", "khmr", "Khmer Digits"),
+    ("nu", "knda", "Kannada Digits"),
+    ("nu", "lana", "Tai Tham Hora Digits"),
+    ("nu", "lanatham", "Tai Tham Tham Digits"),
+    ("nu", "laoo", "Lao Digits"),
+    ("nu", "latn", "Western Digits"),
+    ("nu", "lepc", "Lepcha Digits"),
+    ("nu", "limb", "Limbu Digits"),
+    ("nu", "mathbold", "Mathematical Bold Digits"),
+    ("nu", "mathdbl", "Mathematical Double-Struck Digits"),
+    ("nu", "mathmono", "Mathematical Monospace Digits"),
+    ("nu", "mathsanb", "Mathematical Sans-Serif Bold Digits"),
+    ("nu", "mathsans", "Mathematical Sans-Serif Digits"),
+    ("nu", "mlym", "Malayalam Digits"),
+    ("nu", "modi", "Modi Digits"),
+    ("nu", "mong", "Mongolian Digits"),
+    ("nu", "mroo", "Mro Digits"),
+    ("nu", "mtei", "Meetei Mayek Digits"),
+    ("nu", "mymr", "Myanmar Digits"),
+    ("nu", "mymrshan", "Myanmar Shan Digits"),
+    ("nu", "mymrtlng", "Myanmar Tai Laing Digits"),
+    ("nu", "nagm", "Nag Mundari Digits"),
+    ("nu", "native", "Native Digits"),
+    ("nu", "newa", "Newa Digits"),
+    ("nu", "nkoo", "N’Ko Digits"),
+    ("nu", "olck", "Ol Chiki Digits"),
+    ("nu", "orya", "Odia Digits"),
+    ("nu", "osma", "Osmanya Digits"),
+    ("nu", "rohg", "Hanifi Rohingya digits"),
+    ("nu", "roman", "Roman Numerals"),
+    ("nu", "romanlow", "Roman Lowercase Numerals"),
+    ("nu", "saur", "Saurashtra Digits"),
+    ("nu", "segment", "Segmented Digits"),
+    ("nu", "shrd", "Sharada Digits"),
+    ("nu", "sind", "Khudawadi Digits"),
+    ("nu", "sinh", "Sinhala Lith Digits"),
+    ("nu", "sora", "Sora Sompeng Digits"),
+    ("nu", "sund", "Sundanese Digits"),
+    ("nu", "takr", "Takri Digits"),
+    ("nu", "talu", "New Tai Lue Digits"),
+    ("nu", "taml", "Traditional Tamil Numerals"),
+    ("nu", "tamldec", "Tamil Digits"),
+    ("nu", "telu", "Telugu Digits"),
+    ("nu", "thai", "Thai Digits"),
+    ("nu", "tibt", "Tibetan Digits"),
+    ("nu", "tirh", "Tirhuta Digits"),
+    ("nu", "tnsa", "Tangsa Digits"),
+    ("nu", "vaii", "Vai Digits"),
+    ("nu", "wara", "Warang Citi Digits"),
+    ("nu", "wcho", "Wancho Digits"),
+    ("ss", "none", "Sentence Breaks Without Abbreviation Handling"),
+    ("ss", "standard", "Suppress Sentence Breaks After Standard Abbreviations"),
+    ("va", "posix", "POSIX Compliant Locale"),
+];
+
+#[rustfmt::skip]
+pub static EN_001_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_001_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_001_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_001_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_001_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_001_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_150_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_150_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_150_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_150_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_150_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_150_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_AG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_AG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_AG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_AG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_AG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_AG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_AI_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_AI_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_AI_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_AI_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_AI_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_AI_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_AT_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_AT_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_AT_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_AT_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_AT_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_AT_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_AU_LANGUAGES: [(&str, Alternate, &str); 7] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("bn", Alternate::Default, "Bengali"),
+    ("ckb", Alternate::Variant, "Kurdish (Sorani)"),
+    ("en-US", Alternate::Default, "United States English"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+    ("ro-MD", Alternate::Default, "Moldovan"),
+];
+
+#[rustfmt::skip]
+pub static EN_AU_SCRIPTS: [(&str, Alternate, &str); 1] = [
+    ("Beng", Alternate::Default, "Bengali"),
+];
+
+#[rustfmt::skip]
+pub static EN_AU_REGIONS: [(&str, Alternate, &str); 5] = [
+    ("001", Alternate::Default, "World"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_AU_VARIANTS: [(&str, &str); 1] = [
+    ("simple", "SIMPLE"),
+];
+
+#[rustfmt::skip]
+pub static EN_AU_KEYS: [(&str, &str); 3] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kf", "Upper case / Lower case Ordering"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_AU_TYPES: [(&str, &str, &str); 8] = [
+    ("ca", "dangi", "Korean Calendar"),
+    ("ca", "ethiopic", "Ethiopian Calendar"),
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_BB_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_BB_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BB_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_BB_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BB_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_BB_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_BE_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_BE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BE_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_BE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BE_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_BE_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_BM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_BM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_BM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_BM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_BS_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_BS_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BS_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_BS_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BS_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_BS_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_BW_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_BW_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BW_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_BW_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BW_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_BW_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_BZ_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_BZ_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BZ_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_BZ_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_BZ_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_BZ_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_CA_LANGUAGES: [(&str, Alternate, &str); 10] = [
+    ("ar-001", Alternate::Default, "Arabic (Modern Standard)"),
+    ("bn", Alternate::Default, "Bengali"),
+    ("mfe", Alternate::Default, "Mauritian Creole"),
+    ("mul", Alternate::Default, "multiple languages"),
+    ("my", Alternate::Variant, "Myanmar language"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+    ("ro-MD", Alternate::Default, "Moldovan"),
+    ("und", Alternate::Default, "unknown language"),
+    ("zh-Hans", Alternate::Default, "simplified Chinese"),
+    ("zh-Hant", Alternate::Default, "traditional Chinese"),
+];
+
+#[rustfmt::skip]
+pub static EN_CA_SCRIPTS: [(&str, Alternate, &str); 5] = [
+    ("Zmth", Alternate::Default, "mathematical notation"),
+    ("Zsye", Alternate::Default, "emoji"),
+    ("Zsym", Alternate::Default, "symbols"),
+    ("Zxxx", Alternate::Default, "unwritten"),
+    ("Zzzz", Alternate::Default, "unknown script"),
+];
+
+#[rustfmt::skip]
+pub static EN_CA_REGIONS: [(&str, Alternate, &str); 25] = [
+    ("057", Alternate::Default, "Micronesian region"),
+    ("AG", Alternate::Default, "Antigua and Barbuda"),
+    ("BA", Alternate::Default, "Bosnia and Herzegovina"),
+    ("BL", Alternate::Default, "Saint-Barthélemy"),
+    ("EA", Alternate::Default, "Ceuta and Melilla"),
+    ("GS", Alternate::Default, "South Georgia and South Sandwich Islands"),
+    ("HM", Alternate::Default, "Heard and McDonald Islands"),
+    ("KN", Alternate::Default, "Saint Kitts and Nevis"),
+    ("LC", Alternate::Default, "Saint Lucia"),
+    ("MF", Alternate::Default, "Saint Martin"),
+    ("MO", Alternate::Short, "Macau"),
+    ("PM", Alternate::Default, "Saint-Pierre-et-Miquelon"),
+    ("PS", Alternate::Default, "Palestinian territories"),
+    ("SH", Alternate::Default, "Saint Helena"),
+    ("SJ", Alternate::Default, "Svalbard and Jan Mayen"),
+    ("ST", Alternate::Default, "São Tomé and Príncipe"),
+    ("TC", Alternate::Default, "Turks and Caicos Islands"),
+    ("TT", Alternate::Default, "Trinidad and Tobago"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "Saint Vincent and the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+    ("WF", Alternate::Default, "Wallis and Futuna"),
+    ("XA", Alternate::Default, "pseudo-accents"),
+    ("XB", Alternate::Default, "pseudo-bidi"),
+    ("ZZ", Alternate::Default, "unknown region"),
+];
+
+#[rustfmt::skip]
+pub static EN_CA_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CA_KEYS: [(&str, &str); 27] = [
+    ("ca", "calendar"),
+    ("cf", "currency format"),
+    ("co", "sort order"),
+    ("cu", "currency"),
+    ("dx", "dictionary break exclusions"),
+    ("em", "emoji presentation style"),
+    ("fw", "first day of week"),
+    ("hc", "hour cycle (12 vs 24)"),
+    ("ka", "ignore-symbols sorting"),
+    ("kb", "reversed-accent sorting"),
+    ("kc", "case-sensitive sorting"),
+    ("kf", "uppercase/lowercase ordering"),
+    ("kk", "normalized sorting"),
+    ("kn", "numeric sorting"),
+    ("kr", "script/block reordering"),
+    ("ks", "sorting strength"),
+    ("kv", "highest ignored"),
+    ("lb", "line break style"),
+    ("lw", "line breaks in words setting"),
+    ("ms", "measurement system"),
+    ("mu", "measurement unit"),
+    ("nu", "numbers"),
+    ("rg", "region for supplemental data"),
+    ("sd", "region subdivision"),
+    ("ss", "sentence break suppressions type"),
+    ("tz", "time zone"),
+    ("va", "locale variant"),
+];
+
+#[rustfmt::skip]
+pub static EN_CA_TYPES: [(&str, &str, &str); 104] = [
+    ("ca", "buddhist", "Buddhist calendar"),
+    ("ca", "chinese", "Chinese calendar"),
+    ("ca", "coptic", "Coptic calendar"),
+    ("ca", "dangi", "Dangi calendar"),
+    ("ca", "ethioaa", "Ethiopic Amete Alem calendar"),
+    ("ca", "ethiopic", "Ethiopic calendar"),
+    ("ca", "gregory", "Gregorian calendar"),
+    ("ca", "hebrew", "Hebrew calendar"),
+    ("ca", "indian", "Indian national calendar"),
+    ("ca", "islamic", "Islamic calendar"),
+    ("ca", "islamic-civil", "Islamic calendar (tabular, civil epoch)"),
+    ("ca", "islamic-tbla", "Islamic calendar (tabular, astronomical epoch)"),
+    ("ca", "islamic-umalqura", "Islamic calendar (Umm al-Qura)"),
+    ("ca", "iso8601", "ISO-8601 calendar"),
+    ("ca", "japanese", "Japanese calendar"),
+    ("ca", "persian", "Persian calendar"),
+    ("ca", "roc", "Minguo calendar"),
+    ("cf", "account", "accounting currency format"),
+    ("cf", "standard", "standard currency format"),
+    ("co", "big5han", "traditional Chinese sort order - Big5"),
+    ("co", "compat", "previous sort order, for compatibility"),
+    ("co", "dict", "dictionary sort order"),
+    ("co", "ducet", "default Unicode sort order"),
+    ("co", "gb2312", "simplified Chinese sort order - GB2312"),
+    ("co", "phonebk", "phonebook sort order"),
+    ("co", "phonetic", "phonetic sort order"),
+    ("co", "pinyin", "Pinyin sort order"),
+    ("co", "reformed", "reformed sort order"),
+    ("co", "search", "general-purpose search"),
+    ("co", "standard", "standard sort order"),
+    ("co", "stroke", "stroke sort order"),
+    ("co", "trad", "traditional sort order"),
+    ("co", "unihan", "radical-stroke sort order"),
+    ("co", "zhuyin", "Zhuyin sort order"),
+    ("em", "default", "use default presentation for emoji characters"),
+    ("em", "emoji", "prefer emoji presentation for emoji characters"),
+    ("em", "text", "prefer text presentation for emoji characters"),
+    ("fw", "fri", "first day of week is Friday"),
+    ("fw", "mon", "first day of week is Monday"),
+    ("fw", "sat", "first day of week is Saturday"),
+    ("fw", "sun", "first day of week is Sunday"),
+    ("fw", "thu", "first day of week is Thursday"),
+    ("fw", "tue", "first day of week is Tuesday"),
+    ("fw", "wed", "first day of week is Wednesday"),
+    ("hc", "h11", "12-hour system (0–11)"),
+    ("hc", "h12", "12-hour system (1–12)"),
+    ("hc", "h23", "24-hour system (0–23)"),
+    ("hc", "h24", "24-hour system (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+    ("lb", "loose", "loose line break style"),
+    ("lb", "normal", "normal line break style"),
+    ("lb", "strict", "strict line break style"),
+    ("lw", "breakall", "allow line breaks in all words"),
+    ("lw", "keepall", "prevent line breaks in all words"),
+    ("lw", "normal", "normal line breaks for words"),
+    ("lw", "phrase", "prevent line breaks in phrases"),
+    ("ms", "metric", "metric system"),
+    ("ms", "uksystem", "imperial measurement system"),
+    ("ms", "ussystem", "US measurement system"),
+    ("nu", "adlm", "Adlam digits"),
+    ("nu", "arab", "Arabic-Indic digits"),
+    ("nu", "arabext", "extended Arabic-Indic digits"),
+    ("nu", "armn", "Armenian numerals"),
+    ("nu", "armnlow", "Armenian lowercase numerals"),
+    ("nu", "beng", "Bangla digits"),
+    ("nu", "cakm", "Chakma digits"),
+    ("nu", "deva", "Devanagari digits"),
+    ("nu", "ethi", "Ethiopic numerals"),
+    ("nu", "fullwide", "full-width digits"),
+    ("nu", "geor", "Georgian numerals"),
+    ("nu", "grek", "Greek numerals"),
+    ("nu", "greklow", "Greek lowercase numerals"),
+    ("nu", "gujr", "Gujarati digits"),
+    ("nu", "guru", "Gurmukhi digits"),
+    ("nu", "hanidec", "Chinese decimal numerals"),
+    ("nu", "hans", "simplified Chinese numerals"),
+    ("nu", "hansfin", "simplified Chinese financial numerals"),
+    ("nu", "hant", "traditional Chinese numerals"),
+    ("nu", "hantfin", "traditional Chinese financial numerals"),
+    ("nu", "hebr", "Hebrew numerals"),
+    ("nu", "java", "Javanese digits"),
+    ("nu", "jpan", "Japanese numerals"),
+    ("nu", "jpanfin", "Japanese financial numerals"),
+    ("nu", "khmr", "Khmer digits"),
+    ("nu", "knda", "Kannada digits"),
+    ("nu", "laoo", "Lao digits"),
+    ("nu", "latn", "Western digits"),
+    ("nu", "mlym", "Malayalam digits"),
+    ("nu", "mtei", "Meetei Mayek digits"),
+    ("nu", "mymr", "Myanmar digits"),
+    ("nu", "native", "Native digits"),
+    ("nu", "olck", "Ol Chiki digits"),
+    ("nu", "orya", "Odia digits"),
+    ("nu", "roman", "Roman numerals"),
+    ("nu", "romanlow", "Roman lowercase numerals"),
+    ("nu", "taml", "traditional Tamil numerals"),
+    ("nu", "tamldec", "Tamil digits"),
+    ("nu", "telu", "Telugu digits"),
+    ("nu", "thai", "Thai digits"),
+    ("nu", "tibt", "Tibetan digits"),
+    ("nu", "vaii", "Vai digits"),
+    ("ss", "none", "sentence breaks without abbreviation handling"),
+    ("ss", "standard", "suppress sentence breaks after standard abbreviations"),
+];
+
+#[rustfmt::skip]
+pub static EN_CC_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_CC_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CC_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_CC_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CC_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_CC_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_CH_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_CH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CH_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_CH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CH_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_CH_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_CK_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_CK_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CK_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_CK_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CK_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_CK_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_CM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_CM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_CM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_CM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_CX_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_CX_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CX_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_CX_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CX_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_CX_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_CY_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_CY_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CY_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_CY_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_CY_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_CY_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_DE_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_DE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DE_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_DE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DE_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_DE_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_DG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_DG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_DG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_DG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_DK_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_DK_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DK_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_DK_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DK_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_DK_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_DM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_DM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_DM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_DM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_DM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_ER_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_ER_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ER_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_ER_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ER_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_ER_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_FI_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_FI_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FI_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_FI_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FI_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_FI_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_FJ_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_FJ_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FJ_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_FJ_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FJ_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_FJ_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_FK_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_FK_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FK_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_FK_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FK_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_FK_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_FM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_FM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_FM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_FM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_FM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GB_LANGUAGES: [(&str, Alternate, &str); 4] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("ff", Alternate::Default, "Fulah"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GB_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GB_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GB_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GB_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GB_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GD_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GD_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GD_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GD_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GD_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GD_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GH_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GH_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GH_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GH_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GI_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GI_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GI_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GI_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GI_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GI_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_GY_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_GY_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GY_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_GY_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_GY_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_GY_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_HK_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_HK_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_HK_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_HK_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_HK_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_HK_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_IE_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_IE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IE_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_IE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IE_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_IE_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_IL_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_IL_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IL_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_IL_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IL_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_IL_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_IM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_IM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_IM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_IM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_IN_LANGUAGES: [(&str, Alternate, &str); 4] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("bn", Alternate::Default, "Bengali"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_IN_SCRIPTS: [(&str, Alternate, &str); 2] = [
+    ("Beng", Alternate::Default, "Bengali"),
+    ("Orya", Alternate::Default, "Oriya"),
+];
+
+#[rustfmt::skip]
+pub static EN_IN_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_IN_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IN_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_IN_TYPES: [(&str, &str, &str); 8] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+    ("nu", "beng", "Bengali Digits"),
+    ("nu", "orya", "Oriya Digits"),
+];
+
+#[rustfmt::skip]
+pub static EN_IO_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_IO_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IO_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_IO_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_IO_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_IO_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_JE_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_JE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_JE_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_JE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_JE_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_JE_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_JM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_JM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_JM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_JM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_JM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_JM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_KE_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_KE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KE_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_KE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KE_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_KE_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_KI_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_KI_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KI_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_KI_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KI_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_KI_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_KN_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_KN_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KN_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_KN_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KN_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_KN_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_KY_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_KY_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KY_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_KY_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_KY_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_KY_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_LC_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_LC_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_LC_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_LC_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_LC_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_LC_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_LR_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_LR_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_LR_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_LR_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_LR_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_LR_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_LS_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_LS_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_LS_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_LS_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_LS_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_LS_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MO_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MO_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MO_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MO_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MO_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MO_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MS_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MS_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MS_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MS_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MS_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MS_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MT_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MT_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MT_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MT_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MT_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MT_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MU_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MU_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MU_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MU_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MU_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MU_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MV_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MV_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MV_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MV_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MV_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MV_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MW_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MW_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MW_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MW_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MW_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MW_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_MY_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_MY_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MY_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_MY_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_MY_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_MY_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NA_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NA_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NA_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NA_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NA_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NA_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NF_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NF_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NF_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NF_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NF_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NF_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NH_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NH_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NH_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NH_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NL_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NL_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NL_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NL_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NL_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NL_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NR_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NR_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NR_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NR_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NR_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NR_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NU_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NU_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NU_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NU_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NU_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NU_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_NZ_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_NZ_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NZ_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_NZ_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_NZ_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_NZ_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_PG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_PG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_PG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_PG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_PK_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_PK_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PK_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_PK_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PK_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_PK_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_PN_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_PN_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PN_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_PN_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PN_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_PN_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_PW_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_PW_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PW_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_PW_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_PW_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_PW_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_RH_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_RH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_RH_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_RH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_RH_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_RH_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_RW_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_RW_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_RW_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_RW_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_RW_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_RW_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SB_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SB_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SB_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SB_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SB_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SB_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SC_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SC_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SC_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SC_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SC_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SC_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SD_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SD_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SD_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SD_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SD_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SD_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SE_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SE_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SE_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SE_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SH_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SH_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SH_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SH_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SI_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SI_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SI_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SI_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SI_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SI_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SL_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SL_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SL_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SL_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SL_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SL_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SS_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SS_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SS_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SS_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SS_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SS_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SX_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SX_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SX_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SX_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SX_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SX_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_SZ_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_SZ_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SZ_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_SZ_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_SZ_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_SZ_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_TC_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_TC_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TC_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_TC_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TC_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_TC_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_TK_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_TK_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TK_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_TK_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TK_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_TK_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_TO_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_TO_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TO_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_TO_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TO_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_TO_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_TT_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_TT_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TT_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_TT_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TT_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_TT_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_TV_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_TV_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TV_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_TV_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TV_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_TV_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_TZ_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_TZ_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TZ_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_TZ_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_TZ_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_TZ_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_UG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_UG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_UG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_UG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_UG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_UG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_VC_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_VC_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_VC_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_VC_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_VC_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_VC_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_VG_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_VG_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_VG_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_VG_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_VG_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_VG_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_VU_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_VU_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_VU_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_VU_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_VU_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_VU_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_WS_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_WS_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_WS_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_WS_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_WS_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_WS_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZA_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZA_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ZA_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZA_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ZA_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZA_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZM_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZM_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ZM_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZM_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ZM_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZM_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZW_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("bla", Alternate::Default, "Siksika"),
+    ("mus", Alternate::Default, "Creek"),
+    ("nds-NL", Alternate::Default, "West Low German"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZW_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ZW_REGIONS: [(&str, Alternate, &str); 9] = [
+    ("BL", Alternate::Default, "St Barthélemy"),
+    ("KN", Alternate::Default, "St Kitts & Nevis"),
+    ("LC", Alternate::Default, "St Lucia"),
+    ("MF", Alternate::Default, "St Martin"),
+    ("PM", Alternate::Default, "St Pierre & Miquelon"),
+    ("SH", Alternate::Default, "St Helena"),
+    ("UM", Alternate::Default, "US Outlying Islands"),
+    ("VC", Alternate::Default, "St Vincent & the Grenadines"),
+    ("VI", Alternate::Default, "US Virgin Islands"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZW_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static EN_ZW_KEYS: [(&str, &str); 2] = [
+    ("kc", "Case-Sensitive Sorting"),
+    ("kk", "Normalised Sorting"),
+];
+
+#[rustfmt::skip]
+pub static EN_ZW_TYPES: [(&str, &str, &str); 6] = [
+    ("hc", "h11", "12-Hour System (0–11)"),
+    ("hc", "h12", "12-Hour System (1–12)"),
+    ("hc", "h23", "24-Hour System (0–23)"),
+    ("hc", "h24", "24-Hour System (1–24)"),
+    ("kk", "false", "Sort Without Normalisation"),
+    ("kk", "true", "Sort Unicode Normalised"),
+];
+
+#[rustfmt::skip]
+pub static FR_LANGUAGES: [(&str, Alternate, &str); 653] = [
+    ("aa", Alternate::Default, "afar"),
+    ("ab", Alternate::Default, "abkhaze"),
+    ("ace", Alternate::Default, "aceh"),
+    ("ach", Alternate::Default, "acoli"),
+    ("ada", Alternate::Default, "adangme"),
+    ("ady", Alternate::Default, "adyguéen"),
+    ("ae", Alternate::Default, "avestique"),
+    ("aeb", Alternate::Default, "arabe tunisien"),
+    ("af", Alternate::Default, "afrikaans"),
+    ("afh", Alternate::Default, "afrihili"),
+    ("agq", Alternate::Default, "aghem"),
+    ("ain", Alternate::Default, "aïnou"),
+    ("ak", Alternate::Default, "akan"),
+    ("akk", Alternate::Default, "akkadien"),
+    ("akz", Alternate::Default, "alabama"),
+    ("ale", Alternate::Default, "aléoute"),
+    ("aln", Alternate::Default, "guègue"),
+    ("alt", Alternate::Default, "altaï du Sud"),
+    ("am", Alternate::Default, "amharique"),
+    ("an", Alternate::Default, "aragonais"),
+    ("ang", Alternate::Default, "ancien anglais"),
+    ("ann", Alternate::Default, "obolo"),
+    ("anp", Alternate::Default, "angika"),
+    ("ar", Alternate::Default, "arabe"),
+    ("ar-001", Alternate::Default, "arabe standard moderne"),
+    ("arc", Alternate::Default, "araméen"),
+    ("arn", Alternate::Default, "mapuche"),
+    ("aro", Alternate::Default, "araona"),
+    ("arp", Alternate::Default, "arapaho"),
+    ("arq", Alternate::Default, "arabe algérien"),
+    ("ars", Alternate::Default, "arabe najdi"),
+    ("arw", Alternate::Default, "arawak"),
+    ("ary", Alternate::Default, "arabe marocain"),
+    ("arz", Alternate::Default, "arabe égyptien"),
+    ("as", Alternate::Default, "assamais"),
+    ("asa", Alternate::Default, "asu"),
+    ("ase", Alternate::Default, "langue des signes américaine"),
+    ("ast", Alternate::Default, "asturien"),
+    ("atj", Alternate::Default, "atikamekw"),
+    ("av", Alternate::Default, "avar"),
+    ("avk", Alternate::Default, "kotava"),
+    ("awa", Alternate::Default, "awadhi"),
+    ("ay", Alternate::Default, "aymara"),
+    ("az", Alternate::Default, "azerbaïdjanais"),
+    ("az", Alternate::Short, "azéri"),
+    ("ba", Alternate::Default, "bachkir"),
+    ("bal", Alternate::Default, "baloutchi"),
+    ("ban", Alternate::Default, "balinais"),
+    ("bar", Alternate::Default, "bavarois"),
+    ("bas", Alternate::Default, "bassa"),
+    ("bax", Alternate::Default, "bamoun"),
+    ("bbc", Alternate::Default, "batak toba"),
+    ("bbj", Alternate::Default, "ghomalaʼ"),
+    ("be", Alternate::Default, "biélorusse"),
+    ("bej", Alternate::Default, "bedja"),
+    ("bem", Alternate::Default, "bemba"),
+    ("bew", Alternate::Default, "betawi"),
+    ("bez", Alternate::Default, "bena"),
+    ("bfd", Alternate::Default, "bafut"),
+    ("bfq", Alternate::Default, "badaga"),
+    ("bg", Alternate::Default, "bulgare"),
+    ("bgn", Alternate::Default, "baloutchi occidental"),
+    ("bho", Alternate::Default, "bhodjpouri"),
+    ("bi", Alternate::Default, "bichelamar"),
+    ("bik", Alternate::Default, "bikol"),
+    ("bin", Alternate::Default, "bini"),
+    ("bjn", Alternate::Default, "banjar"),
+    ("bkm", Alternate::Default, "kom"),
+    ("bla", Alternate::Default, "siksika"),
+    ("bm", Alternate::Default, "bambara"),
+    ("bn", Alternate::Default, "bengali"),
+    ("bo", Alternate::Default, "tibétain"),
+    ("bpy", Alternate::Default, "bishnupriya"),
+    ("bqi", Alternate::Default, "bakhtiari"),
+    ("br", Alternate::Default, "breton"),
+    ("bra", Alternate::Default, "braj"),
+    ("brh", Alternate::Default, "brahoui"),
+    ("brx", Alternate::Default, "bodo"),
+    ("bs", Alternate::Default, "bosniaque"),
+    ("bss", Alternate::Default, "akoose"),
+    ("bua", Alternate::Default, "bouriate"),
+    ("bug", Alternate::Default, "bugi"),
+    ("bum", Alternate::Default, "boulou"),
+    ("byn", Alternate::Default, "blin"),
+    ("byv", Alternate::Default, "médumba"),
+    ("ca", Alternate::Default, "catalan"),
+    ("cad", Alternate::Default, "caddo"),
+    ("car", Alternate::Default, "caribe"),
+    ("cay", Alternate::Default, "cayuga"),
+    ("cch", Alternate::Default, "atsam"),
+    ("ccp", Alternate::Default, "changma kodha"),
+    ("ce", Alternate::Default, "tchétchène"),
+    ("ceb", Alternate::Default, "cebuano"),
+    ("cgg", Alternate::Default, "kiga"),
+    ("ch", Alternate::Default, "chamorro"),
+    ("chb", Alternate::Default, "chibcha"),
+    ("chg", Alternate::Default, "tchaghataï"),
+    ("chk", Alternate::Default, "chuuk"),
+    ("chm", Alternate::Default, "mari"),
+    ("chn", Alternate::Default, "jargon chinook"),
+    ("cho", Alternate::Default, "choctaw"),
+    ("chp", Alternate::Default, "chipewyan"),
+    ("chr", Alternate::Default, "cherokee"),
+    ("chy", Alternate::Default, "cheyenne"),
+    ("ckb", Alternate::Default, "sorani"),
+    ("clc", Alternate::Default, "chilcotin"),
+    ("co", Alternate::Default, "corse"),
+    ("cop", Alternate::Default, "copte"),
+    ("cps", Alternate::Default, "capiznon"),
+    ("cr", Alternate::Default, "cree"),
+    ("crg", Alternate::Default, "mitchif"),
+    ("crh", Alternate::Default, "tatar de Crimée"),
+    ("crj", Alternate::Default, "cri de l’Est (dialecte du Sud)"),
+    ("crk", Alternate::Default, "cri des plaines"),
+    ("crl", Alternate::Default, "cri de l’Est (dialecte du Nord)"),
+    ("crm", Alternate::Default, "cri de Moose"),
+    ("crr", Alternate::Default, "algonquin de Caroline"),
+    ("crs", Alternate::Default, "créole seychellois"),
+    ("cs", Alternate::Default, "tchèque"),
+    ("csb", Alternate::Default, "kachoube"),
+    ("csw", Alternate::Default, "cri des marais"),
+    ("cu", Alternate::Default, "slavon d’église"),
+    ("cv", Alternate::Default, "tchouvache"),
+    ("cy", Alternate::Default, "gallois"),
+    ("da", Alternate::Default, "danois"),
+    ("dak", Alternate::Default, "dakota"),
+    ("dar", Alternate::Default, "dargwa"),
+    ("dav", Alternate::Default, "taita"),
+    ("de", Alternate::Default, "allemand"),
+    ("de-AT", Alternate::Default, "allemand autrichien"),
+    ("de-CH", Alternate::Default, "allemand suisse"),
+    ("del", Alternate::Default, "delaware"),
+    ("den", Alternate::Default, "esclave"),
+    ("dgr", Alternate::Default, "dogrib"),
+    ("din", Alternate::Default, "dinka"),
+    ("dje", Alternate::Default, "zarma"),
+    ("doi", Alternate::Default, "dogri"),
+    ("dsb", Alternate::Default, "bas-sorabe"),
+    ("dtp", Alternate::Default, "dusun central"),
+    ("dua", Alternate::Default, "douala"),
+    ("dum", Alternate::Default, "moyen néerlandais"),
+    ("dv", Alternate::Default, "maldivien"),
+    ("dyo", Alternate::Default, "diola-fogny"),
+    ("dyu", Alternate::Default, "dioula"),
+    ("dz", Alternate::Default, "dzongkha"),
+    ("dzg", Alternate::Default, "dazaga"),
+    ("ebu", Alternate::Default, "embu"),
+    ("ee", Alternate::Default, "éwé"),
+    ("efi", Alternate::Default, "éfik"),
+    ("egl", Alternate::Default, "émilien"),
+    ("egy", Alternate::Default, "égyptien ancien"),
+    ("eka", Alternate::Default, "ékadjouk"),
+    ("el", Alternate::Default, "grec"),
+    ("elx", Alternate::Default, "élamite"),
+    ("en", Alternate::Default, "anglais"),
+    ("en-AU", Alternate::Default, "anglais australien"),
+    ("en-CA", Alternate::Default, "anglais canadien"),
+    ("en-GB", Alternate::Default, "anglais britannique"),
+    ("en-US", Alternate::Default, "anglais américain"),
+    ("enm", Alternate::Default, "moyen anglais"),
+    ("eo", Alternate::Default, "espéranto"),
+    ("es", Alternate::Default, "espagnol"),
+    ("es-419", Alternate::Default, "espagnol d’Amérique latine"),
+    ("es-ES", Alternate::Default, "espagnol d’Espagne"),
+    ("es-MX", Alternate::Default, "espagnol du Mexique"),
+    ("esu", Alternate::Default, "youpik central"),
+    ("et", Alternate::Default, "estonien"),
+    ("eu", Alternate::Default, "basque"),
+    ("ewo", Alternate::Default, "éwondo"),
+    ("ext", Alternate::Default, "estrémègne"),
+    ("fa", Alternate::Default, "persan"),
+    ("fa-AF", Alternate::Default, "dari"),
+    ("fan", Alternate::Default, "fang"),
+    ("fat", Alternate::Default, "fanti"),
+    ("ff", Alternate::Default, "peul"),
+    ("fi", Alternate::Default, "finnois"),
+    ("fil", Alternate::Default, "filipino"),
+    ("fit", Alternate::Default, "finnois tornédalien"),
+    ("fj", Alternate::Default, "fidjien"),
+    ("fo", Alternate::Default, "féroïen"),
+    ("fon", Alternate::Default, "fon"),
+    ("fr", Alternate::Default, "français"),
+    ("fr-CA", Alternate::Default, "français canadien"),
+    ("fr-CH", Alternate::Default, "français suisse"),
+    ("frc", Alternate::Default, "français cadien"),
+    ("frm", Alternate::Default, "moyen français"),
+    ("fro", Alternate::Default, "ancien français"),
+    ("frp", Alternate::Default, "francoprovençal"),
+    ("frr", Alternate::Default, "frison septentrional"),
+    ("frs", Alternate::Default, "frison oriental"),
+    ("fur", Alternate::Default, "frioulan"),
+    ("fy", Alternate::Default, "frison occidental"),
+    ("ga", Alternate::Default, "irlandais"),
+    ("gaa", Alternate::Default, "ga"),
+    ("gag", Alternate::Default, "gagaouze"),
+    ("gan", Alternate::Default, "gan"),
+    ("gay", Alternate::Default, "gayo"),
+    ("gba", Alternate::Default, "gbaya"),
+    ("gbz", Alternate::Default, "dari zoroastrien"),
+    ("gd", Alternate::Default, "gaélique écossais"),
+    ("gez", Alternate::Default, "guèze"),
+    ("gil", Alternate::Default, "gilbertin"),
+    ("gl", Alternate::Default, "galicien"),
+    ("glk", Alternate::Default, "gilaki"),
+    ("gmh", Alternate::Default, "moyen haut-allemand"),
+    ("gn", Alternate::Default, "guarani"),
+    ("goh", Alternate::Default, "ancien haut allemand"),
+    ("gom", Alternate::Default, "konkani de Goa"),
+    ("gon", Alternate::Default, "gondi"),
+    ("gor", Alternate::Default, "gorontalo"),
+    ("got", Alternate::Default, "gotique"),
+    ("grb", Alternate::Default, "grebo"),
+    ("grc", Alternate::Default, "grec ancien"),
+    ("gsw", Alternate::Default, "suisse allemand"),
+    ("gu", Alternate::Default, "goudjarati"),
+    ("guc", Alternate::Default, "wayuu"),
+    ("gur", Alternate::Default, "gurenne"),
+    ("guz", Alternate::Default, "gusii"),
+    ("gv", Alternate::Default, "mannois"),
+    ("gwi", Alternate::Default, "gwichʼin"),
+    ("ha", Alternate::Default, "haoussa"),
+    ("hai", Alternate::Default, "haïda"),
+    ("hak", Alternate::Default, "hakka"),
+    ("haw", Alternate::Default, "hawaïen"),
+    ("hax", Alternate::Default, "haïda du Sud"),
+    ("he", Alternate::Default, "hébreu"),
+    ("hi", Alternate::Default, "hindi"),
+    ("hi-Latn", Alternate::Variant, "hinglish"),
+    ("hif", Alternate::Default, "hindi fidjien"),
+    ("hil", Alternate::Default, "hiligaynon"),
+    ("hit", Alternate::Default, "hittite"),
+    ("hmn", Alternate::Default, "hmong"),
+    ("ho", Alternate::Default, "hiri motu"),
+    ("hr", Alternate::Default, "croate"),
+    ("hsb", Alternate::Default, "haut-sorabe"),
+    ("hsn", Alternate::Default, "xiang"),
+    ("ht", Alternate::Default, "créole haïtien"),
+    ("hu", Alternate::Default, "hongrois"),
+    ("hup", Alternate::Default, "hupa"),
+    ("hur", Alternate::Default, "halkomelem"),
+    ("hy", Alternate::Default, "arménien"),
+    ("hz", Alternate::Default, "héréro"),
+    ("ia", Alternate::Default, "interlingua"),
+    ("iba", Alternate::Default, "iban"),
+    ("ibb", Alternate::Default, "ibibio"),
+    ("id", Alternate::Default, "indonésien"),
+    ("ie", Alternate::Default, "interlingue"),
+    ("ig", Alternate::Default, "igbo"),
+    ("ii", Alternate::Default, "yi du Sichuan"),
+    ("ik", Alternate::Default, "inupiaq"),
+    ("ikt", Alternate::Default, "inuktitut de l’Ouest canadien"),
+    ("ilo", Alternate::Default, "ilocano"),
+    ("inh", Alternate::Default, "ingouche"),
+    ("io", Alternate::Default, "ido"),
+    ("is", Alternate::Default, "islandais"),
+    ("it", Alternate::Default, "italien"),
+    ("iu", Alternate::Default, "inuktitut"),
+    ("izh", Alternate::Default, "ingrien"),
+    ("ja", Alternate::Default, "japonais"),
+    ("jam", Alternate::Default, "créole jamaïcain"),
+    ("jbo", Alternate::Default, "lojban"),
+    ("jgo", Alternate::Default, "ngomba"),
+    ("jmc", Alternate::Default, "matchamé"),
+    ("jpr", Alternate::Default, "judéo-persan"),
+    ("jrb", Alternate::Default, "judéo-arabe"),
+    ("jut", Alternate::Default, "jute"),
+    ("jv", Alternate::Default, "javanais"),
+    ("ka", Alternate::Default, "géorgien"),
+    ("kaa", Alternate::Default, "karakalpak"),
+    ("kab", Alternate::Default, "kabyle"),
+    ("kac", Alternate::Default, "kachin"),
+    ("kaj", Alternate::Default, "jju"),
+    ("kam", Alternate::Default, "kamba"),
+    ("kaw", Alternate::Default, "kawi"),
+    ("kbd", Alternate::Default, "kabarde"),
+    ("kbl", Alternate::Default, "kanembou"),
+    ("kcg", Alternate::Default, "tyap"),
+    ("kde", Alternate::Default, "makondé"),
+    ("kea", Alternate::Default, "capverdien"),
+    ("ken", Alternate::Default, "kényang"),
+    ("kfo", Alternate::Default, "koro"),
+    ("kg", Alternate::Default, "kikongo"),
+    ("kgp", Alternate::Default, "caingangue"),
+    ("kha", Alternate::Default, "khasi"),
+    ("kho", Alternate::Default, "khotanais"),
+    ("khq", Alternate::Default, "koyra chiini"),
+    ("khw", Alternate::Default, "khowar"),
+    ("ki", Alternate::Default, "kikuyu"),
+    ("kiu", Alternate::Default, "kirmanjki"),
+    ("kj", Alternate::Default, "kuanyama"),
+    ("kk", Alternate::Default, "kazakh"),
+    ("kkj", Alternate::Default, "kako"),
+    ("kl", Alternate::Default, "groenlandais"),
+    ("kln", Alternate::Default, "kalendjin"),
+    ("km", Alternate::Default, "khmer"),
+    ("kmb", Alternate::Default, "kimboundou"),
+    ("kn", Alternate::Default, "kannada"),
+    ("ko", Alternate::Default, "coréen"),
+    ("koi", Alternate::Default, "komi-permiak"),
+    ("kok", Alternate::Default, "konkani"),
+    ("kos", Alternate::Default, "kosraéen"),
+    ("kpe", Alternate::Default, "kpellé"),
+    ("kr", Alternate::Default, "kanouri"),
+    ("krc", Alternate::Default, "karatchaï balkar"),
+    ("kri", Alternate::Default, "krio"),
+    ("krj", Alternate::Default, "kinaray-a"),
+    ("krl", Alternate::Default, "carélien"),
+    ("kru", Alternate::Default, "kouroukh"),
+    ("ks", Alternate::Default, "cachemiri"),
+    ("ksb", Alternate::Default, "shambala"),
+    ("ksf", Alternate::Default, "bafia"),
+    ("ksh", Alternate::Default, "kölsch"),
+    ("ku", Alternate::Default, "kurde"),
+    ("kum", Alternate::Default, "koumyk"),
+    ("kut", Alternate::Default, "kutenai"),
+    ("kv", Alternate::Default, "komi"),
+    ("kw", Alternate::Default, "cornique"),
+    ("kwk", Alternate::Default, "kwak’wala"),
+    ("ky", Alternate::Default, "kirghize"),
+    ("la", Alternate::Default, "latin"),
+    ("lad", Alternate::Default, "ladino"),
+    ("lag", Alternate::Default, "langi"),
+    ("lah", Alternate::Default, "lahnda"),
+    ("lam", Alternate::Default, "lamba"),
+    ("lb", Alternate::Default, "luxembourgeois"),
+    ("lez", Alternate::Default, "lezghien"),
+    ("lfn", Alternate::Default, "lingua franca nova"),
+    ("lg", Alternate::Default, "ganda"),
+    ("li", Alternate::Default, "limbourgeois"),
+    ("lij", Alternate::Default, "ligure"),
+    ("lil", Alternate::Default, "lillooet"),
+    ("liv", Alternate::Default, "livonien"),
+    ("lkt", Alternate::Default, "lakota"),
+    ("lmo", Alternate::Default, "lombard"),
+    ("ln", Alternate::Default, "lingala"),
+    ("lo", Alternate::Default, "lao"),
+    ("lol", Alternate::Default, "mongo"),
+    ("lou", Alternate::Default, "créole louisianais"),
+    ("loz", Alternate::Default, "lozi"),
+    ("lrc", Alternate::Default, "lori du Nord"),
+    ("lsm", Alternate::Default, "samia"),
+    ("lt", Alternate::Default, "lituanien"),
+    ("ltg", Alternate::Default, "latgalien"),
+    ("lu", Alternate::Default, "luba-katanga (kiluba)"),
+    ("lua", Alternate::Default, "luba-kasaï (ciluba)"),
+    ("lui", Alternate::Default, "luiseño"),
+    ("lun", Alternate::Default, "lunda"),
+    ("luo", Alternate::Default, "luo"),
+    ("lus", Alternate::Default, "lushaï"),
+    ("luy", Alternate::Default, "luyia"),
+    ("lv", Alternate::Default, "letton"),
+    ("lzh", Alternate::Default, "chinois littéraire"),
+    ("lzz", Alternate::Default, "laze"),
+    ("mad", Alternate::Default, "madurais"),
+    ("maf", Alternate::Default, "mafa"),
+    ("mag", Alternate::Default, "magahi"),
+    ("mai", Alternate::Default, "maïthili"),
+    ("mak", Alternate::Default, "makassar"),
+    ("man", Alternate::Default, "mandingue"),
+    ("mas", Alternate::Default, "maasaï"),
+    ("mde", Alternate::Default, "maba"),
+    ("mdf", Alternate::Default, "mokcha"),
+    ("mdr", Alternate::Default, "mandar"),
+    ("men", Alternate::Default, "mendé"),
+    ("mer", Alternate::Default, "meru"),
+    ("mfe", Alternate::Default, "créole mauricien"),
+    ("mg", Alternate::Default, "malgache"),
+    ("mga", Alternate::Default, "moyen irlandais"),
+    ("mgh", Alternate::Default, "makua"),
+    ("mgo", Alternate::Default, "metaʼ"),
+    ("mh", Alternate::Default, "marshallais"),
+    ("mi", Alternate::Default, "maori"),
+    ("mic", Alternate::Default, "micmac"),
+    ("min", Alternate::Default, "minangkabau"),
+    ("mk", Alternate::Default, "macédonien"),
+    ("ml", Alternate::Default, "malayalam"),
+    ("mn", Alternate::Default, "mongol"),
+    ("mnc", Alternate::Default, "mandchou"),
+    ("mni", Alternate::Default, "manipuri"),
+    ("moe", Alternate::Default, "innu-aimun"),
+    ("moh", Alternate::Default, "mohawk"),
+    ("mos", Alternate::Default, "moré"),
+    ("mr", Alternate::Default, "marathi"),
+    ("mrj", Alternate::Default, "mari occidental"),
+    ("ms", Alternate::Default, "malais"),
+    ("mt", Alternate::Default, "maltais"),
+    ("mua", Alternate::Default, "moundang"),
+    ("mul", Alternate::Default, "multilingue"),
+    ("mus", Alternate::Default, "creek"),
+    ("mwl", Alternate::Default, "mirandais"),
+    ("mwr", Alternate::Default, "marwarî"),
+    ("mwv", Alternate::Default, "mentawaï"),
+    ("my", Alternate::Default, "birman"),
+    ("mye", Alternate::Default, "myènè"),
+    ("myv", Alternate::Default, "erzya"),
+    ("mzn", Alternate::Default, "mazandérani"),
+    ("na", Alternate::Default, "nauruan"),
+    ("nan", Alternate::Default, "minnan"),
+    ("nap", Alternate::Default, "napolitain"),
+    ("naq", Alternate::Default, "nama"),
+    ("nb", Alternate::Default, "norvégien bokmål"),
+    ("nd", Alternate::Default, "ndébélé du Nord"),
+    ("nds", Alternate::Default, "bas-allemand"),
+    ("nds-NL", Alternate::Default, "bas-saxon néerlandais"),
+    ("ne", Alternate::Default, "népalais"),
+    ("new", Alternate::Default, "newari"),
+    ("ng", Alternate::Default, "ndonga"),
+    ("nia", Alternate::Default, "niha"),
+    ("niu", Alternate::Default, "niuéen"),
+    ("njo", Alternate::Default, "Ao"),
+    ("nl", Alternate::Default, "néerlandais"),
+    ("nl-BE", Alternate::Default, "flamand"),
+    ("nmg", Alternate::Default, "ngoumba"),
+    ("nn", Alternate::Default, "norvégien nynorsk"),
+    ("nnh", Alternate::Default, "ngiemboon"),
+    ("no", Alternate::Default, "norvégien"),
+    ("nog", Alternate::Default, "nogaï"),
+    ("non", Alternate::Default, "vieux norrois"),
+    ("nov", Alternate::Default, "novial"),
+    ("nqo", Alternate::Default, "n’ko"),
+    ("nr", Alternate::Default, "ndébélé du Sud"),
+    ("nso", Alternate::Default, "sotho du Nord"),
+    ("nus", Alternate::Default, "nuer"),
+    ("nv", Alternate::Default, "navajo"),
+    ("nwc", Alternate::Default, "newarî classique"),
+    ("ny", Alternate::Default, "chewa"),
+    ("nym", Alternate::Default, "nyamwezi"),
+    ("nyn", Alternate::Default, "nyankolé"),
+    ("nyo", Alternate::Default, "nyoro"),
+    ("nzi", Alternate::Default, "nzema"),
+    ("oc", Alternate::Default, "occitan"),
+    ("oj", Alternate::Default, "ojibwa"),
+    ("ojb", Alternate::Default, "ojibwé du Nord-Ouest"),
+    ("ojc", Alternate::Default, "ojibwé central"),
+    ("ojs", Alternate::Default, "oji-cri"),
+    ("ojw", Alternate::Default, "ojibwé occidental"),
+    ("oka", Alternate::Default, "colville-okanagan"),
+    ("om", Alternate::Default, "oromo"),
+    ("or", Alternate::Default, "odia"),
+    ("os", Alternate::Default, "ossète"),
+    ("osa", Alternate::Default, "osage"),
+    ("ota", Alternate::Default, "turc ottoman"),
+    ("pa", Alternate::Default, "pendjabi"),
+    ("pag", Alternate::Default, "pangasinan"),
+    ("pal", Alternate::Default, "pahlavi"),
+    ("pam", Alternate::Default, "pampangan"),
+    ("pap", Alternate::Default, "papiamento"),
+    ("pau", Alternate::Default, "palau"),
+    ("pcd", Alternate::Default, "picard"),
+    ("pcm", Alternate::Default, "pidgin nigérian"),
+    ("pdc", Alternate::Default, "pennsilfaanisch"),
+    ("pdt", Alternate::Default, "bas-prussien"),
+    ("peo", Alternate::Default, "persan ancien"),
+    ("pfl", Alternate::Default, "allemand palatin"),
+    ("phn", Alternate::Default, "phénicien"),
+    ("pi", Alternate::Default, "pali"),
+    ("pis", Alternate::Default, "pijin"),
+    ("pl", Alternate::Default, "polonais"),
+    ("pms", Alternate::Default, "piémontais"),
+    ("pnt", Alternate::Default, "pontique"),
+    ("pon", Alternate::Default, "pohnpei"),
+    ("pqm", Alternate::Default, "malécite-passamaquoddy"),
+    ("prg", Alternate::Default, "prussien"),
+    ("pro", Alternate::Default, "provençal ancien"),
+    ("ps", Alternate::Default, "pachto"),
+    ("ps", Alternate::Variant, "pashto"),
+    ("pt", Alternate::Default, "portugais"),
+    ("pt-BR", Alternate::Default, "portugais brésilien"),
+    ("pt-PT", Alternate::Default, "portugais européen"),
+    ("qu", Alternate::Default, "quechua"),
+    ("quc", Alternate::Default, "quiché"),
+    ("qug", Alternate::Default, "quichua du Haut-Chimborazo"),
+    ("raj", Alternate::Default, "rajasthani"),
+    ("rap", Alternate::Default, "rapanui"),
+    ("rar", Alternate::Default, "rarotongien"),
+    ("rgn", Alternate::Default, "romagnol"),
+    ("rhg", Alternate::Default, "rohingya"),
+    ("rif", Alternate::Default, "rifain"),
+    ("rm", Alternate::Default, "romanche"),
+    ("rn", Alternate::Default, "roundi"),
+    ("ro", Alternate::Default, "roumain"),
+    ("ro-MD", Alternate::Default, "moldave"),
+    ("rof", Alternate::Default, "rombo"),
+    ("rom", Alternate::Default, "romani"),
+    ("rtm", Alternate::Default, "rotuman"),
+    ("ru", Alternate::Default, "russe"),
+    ("rue", Alternate::Default, "ruthène"),
+    ("rug", Alternate::Default, "roviana"),
+    ("rup", Alternate::Default, "aroumain"),
+    ("rw", Alternate::Default, "kinyarwanda"),
+    ("rwk", Alternate::Default, "rwa"),
+    ("sa", Alternate::Default, "sanskrit"),
+    ("sad", Alternate::Default, "sandawe"),
+    ("sah", Alternate::Default, "iakoute"),
+    ("sam", Alternate::Default, "araméen samaritain"),
+    ("saq", Alternate::Default, "samburu"),
+    ("sas", Alternate::Default, "sasak"),
+    ("sat", Alternate::Default, "santali"),
+    ("saz", Alternate::Default, "saurashtra"),
+    ("sba", Alternate::Default, "ngambay"),
+    ("sbp", Alternate::Default, "isangu"),
+    ("sc", Alternate::Default, "sarde"),
+    ("scn", Alternate::Default, "sicilien"),
+    ("sco", Alternate::Default, "écossais"),
+    ("sd", Alternate::Default, "sindhi"),
+    ("sdc", Alternate::Default, "sarde sassarais"),
+    ("sdh", Alternate::Default, "kurde du Sud"),
+    ("se", Alternate::Default, "same du Nord"),
+    ("see", Alternate::Default, "seneca"),
+    ("seh", Alternate::Default, "cisena"),
+    ("sei", Alternate::Default, "séri"),
+    ("sel", Alternate::Default, "selkoupe"),
+    ("ses", Alternate::Default, "koyraboro senni"),
+    ("sg", Alternate::Default, "sango"),
+    ("sga", Alternate::Default, "ancien irlandais"),
+    ("sgs", Alternate::Default, "samogitien"),
+    ("sh", Alternate::Default, "serbo-croate"),
+    ("shi", Alternate::Default, "chleuh"),
+    ("shn", Alternate::Default, "shan"),
+    ("shu", Alternate::Default, "arabe tchadien"),
+    ("si", Alternate::Default, "cingalais"),
+    ("sid", Alternate::Default, "sidamo"),
+    ("sk", Alternate::Default, "slovaque"),
+    ("sl", Alternate::Default, "slovène"),
+    ("slh", Alternate::Default, "lushootseed du Sud"),
+    ("sli", Alternate::Default, "bas-silésien"),
+    ("sly", Alternate::Default, "sélayar"),
+    ("sm", Alternate::Default, "samoan"),
+    ("sma", Alternate::Default, "same du Sud"),
+    ("smj", Alternate::Default, "same de Lule"),
+    ("smn", Alternate::Default, "same d’Inari"),
+    ("sms", Alternate::Default, "same skolt"),
+    ("sn", Alternate::Default, "shona"),
+    ("snk", Alternate::Default, "soninké"),
+    ("so", Alternate::Default, "somali"),
+    ("sog", Alternate::Default, "sogdien"),
+    ("sq", Alternate::Default, "albanais"),
+    ("sr", Alternate::Default, "serbe"),
+    ("srn", Alternate::Default, "sranan tongo"),
+    ("srr", Alternate::Default, "sérère"),
+    ("ss", Alternate::Default, "swati"),
+    ("ssy", Alternate::Default, "saho"),
+    ("st", Alternate::Default, "sotho du Sud"),
+    ("stq", Alternate::Default, "saterlandais"),
+    ("str", Alternate::Default, "salish des détroits"),
+    ("su", Alternate::Default, "soundanais"),
+    ("suk", Alternate::Default, "soukouma"),
+    ("sus", Alternate::Default, "soussou"),
+    ("sux", Alternate::Default, "sumérien"),
+    ("sv", Alternate::Default, "suédois"),
+    ("sw", Alternate::Default, "swahili"),
+    ("sw-CD", Alternate::Default, "swahili du Congo"),
+    ("swb", Alternate::Default, "comorien"),
+    ("syc", Alternate::Default, "syriaque classique"),
+    ("syr", Alternate::Default, "syriaque"),
+    ("szl", Alternate::Default, "silésien"),
+    ("ta", Alternate::Default, "tamoul"),
+    ("tce", Alternate::Default, "tutchone du Sud"),
+    ("tcy", Alternate::Default, "toulou"),
+    ("te", Alternate::Default, "télougou"),
+    ("tem", Alternate::Default, "timné"),
+    ("teo", Alternate::Default, "teso"),
+    ("ter", Alternate::Default, "tereno"),
+    ("tet", Alternate::Default, "tétoum"),
+    ("tg", Alternate::Default, "tadjik"),
+    ("tgx", Alternate::Default, "tagish"),
+    ("th", Alternate::Default, "thaï"),
+    ("tht", Alternate::Default, "tahltan"),
+    ("ti", Alternate::Default, "tigrigna"),
+    ("tig", Alternate::Default, "tigré"),
+    ("tiv", Alternate::Default, "tiv"),
+    ("tk", Alternate::Default, "turkmène"),
+    ("tkl", Alternate::Default, "tokelau"),
+    ("tkr", Alternate::Default, "tsakhour"),
+    ("tl", Alternate::Default, "tagalog"),
+    ("tlh", Alternate::Default, "klingon"),
+    ("tli", Alternate::Default, "tlingit"),
+    ("tly", Alternate::Default, "talysh"),
+    ("tmh", Alternate::Default, "tamacheq"),
+    ("tn", Alternate::Default, "tswana"),
+    ("to", Alternate::Default, "tongien"),
+    ("tog", Alternate::Default, "tonga nyasa"),
+    ("tok", Alternate::Default, "toki pona"),
+    ("tpi", Alternate::Default, "tok pisin"),
+    ("tr", Alternate::Default, "turc"),
+    ("tru", Alternate::Default, "touroyo"),
+    ("trv", Alternate::Default, "taroko"),
+    ("ts", Alternate::Default, "tsonga"),
+    ("tsd", Alternate::Default, "tsakonien"),
+    ("tsi", Alternate::Default, "tsimshian"),
+    ("tt", Alternate::Default, "tatar"),
+    ("ttm", Alternate::Default, "tutchone du Nord"),
+    ("ttt", Alternate::Default, "tati caucasien"),
+    ("tum", Alternate::Default, "tumbuka"),
+    ("tvl", Alternate::Default, "tuvalu"),
+    ("tw", Alternate::Default, "twi"),
+    ("twq", Alternate::Default, "tasawaq"),
+    ("ty", Alternate::Default, "tahitien"),
+    ("tyv", Alternate::Default, "touvain"),
+    ("tzm", Alternate::Default, "amazighe de l’Atlas central"),
+    ("udm", Alternate::Default, "oudmourte"),
+    ("ug", Alternate::Default, "ouïghour"),
+    ("ug", Alternate::Variant, "ouïgour"),
+    ("uga", Alternate::Default, "ougaritique"),
+    ("uk", Alternate::Default, "ukrainien"),
+    ("umb", Alternate::Default, "umbundu"),
+    ("und", Alternate::Default, "langue indéterminée"),
+    ("ur", Alternate::Default, "ourdou"),
+    ("uz", Alternate::Default, "ouzbek"),
+    ("vai", Alternate::Default, "vaï"),
+    ("ve", Alternate::Default, "venda"),
+    ("vec", Alternate::Default, "vénitien"),
+    ("vep", Alternate::Default, "vepse"),
+    ("vi", Alternate::Default, "vietnamien"),
+    ("vls", Alternate::Default, "flamand occidental"),
+    ("vmf", Alternate::Default, "franconien du Main"),
+    ("vo", Alternate::Default, "volapük"),
+    ("vot", Alternate::Default, "vote"),
+    ("vro", Alternate::Default, "võro"),
+    ("vun", Alternate::Default, "vunjo"),
+    ("wa", Alternate::Default, "wallon"),
+    ("wae", Alternate::Default, "walser"),
+    ("wal", Alternate::Default, "walamo"),
+    ("war", Alternate::Default, "waray"),
+    ("was", Alternate::Default, "washo"),
+    ("wbp", Alternate::Default, "warlpiri"),
+    ("wo", Alternate::Default, "wolof"),
+    ("wuu", Alternate::Default, "wu"),
+    ("xal", Alternate::Default, "kalmouk"),
+    ("xh", Alternate::Default, "xhosa"),
+    ("xmf", Alternate::Default, "mingrélien"),
+    ("xog", Alternate::Default, "soga"),
+    ("yao", Alternate::Default, "yao"),
+    ("yap", Alternate::Default, "yapois"),
+    ("yav", Alternate::Default, "yangben"),
+    ("ybb", Alternate::Default, "yemba"),
+    ("yi", Alternate::Default, "yiddish"),
+    ("yo", Alternate::Default, "yoruba"),
+    ("yrl", Alternate::Default, "nheengatou"),
+    ("yue", Alternate::Default, "cantonais"),
+    ("za", Alternate::Default, "zhuang"),
+    ("zap", Alternate::Default, "zapotèque"),
+    ("zbl", Alternate::Default, "symboles Bliss"),
+    ("zea", Alternate::Default, "zélandais"),
+    ("zen", Alternate::Default, "zenaga"),
+    ("zgh", Alternate::Default, "amazighe standard marocain"),
+    ("zh", Alternate::Default, "chinois"),
+    ("zh-Hans", Alternate::Default, "chinois simplifié"),
+    ("zh-Hant", Alternate::Default, "chinois traditionnel"),
+    ("zu", Alternate::Default, "zoulou"),
+    ("zun", Alternate::Default, "zuñi"),
+    ("zxx", Alternate::Default, "sans contenu linguistique"),
+    ("zza", Alternate::Default, "zazaki"),
+];
+
+#[rustfmt::skip]
+pub static FR_SCRIPTS: [(&str, Alternate, &str); 142] = [
+    ("Adlm", Alternate::Default, "adlam"),
+    ("Arab", Alternate::Default, "arabe"),
+    ("Arab", Alternate::Variant, "arabo-persan"),
+    ("Aran", Alternate::Default, "nastaliq"),
+    ("Armi", Alternate::Default, "araméen impérial"),
+    ("Armn", Alternate::Default, "arménien"),
+    ("Avst", Alternate::Default, "avestique"),
+    ("Bali", Alternate::Default, "balinais"),
+    ("Batk", Alternate::Default, "batak"),
+    ("Beng", Alternate::Default, "bengali"),
+    ("Blis", Alternate::Default, "symboles Bliss"),
+    ("Bopo", Alternate::Default, "bopomofo"),
+    ("Brah", Alternate::Default, "brâhmî"),
+    ("Brai", Alternate::Default, "braille"),
+    ("Bugi", Alternate::Default, "bouguis"),
+    ("Buhd", Alternate::Default, "bouhide"),
+    ("Cakm", Alternate::Default, "chakma"),
+    ("Cans", Alternate::Default, "syllabaire autochtone canadien unifié"),
+    ("Cari", Alternate::Default, "carien"),
+    ("Cham", Alternate::Default, "cham"),
+    ("Cher", Alternate::Default, "cherokee"),
+    ("Cirt", Alternate::Default, "cirth"),
+    ("Copt", Alternate::Default, "copte"),
+    ("Cprt", Alternate::Default, "syllabaire chypriote"),
+    ("Cyrl", Alternate::Default, "cyrillique"),
+    ("Cyrs", Alternate::Default, "cyrillique (variante slavonne)"),
+    ("Deva", Alternate::Default, "dévanagari"),
+    ("Dsrt", Alternate::Default, "déséret"),
+    ("Egyd", Alternate::Default, "démotique égyptien"),
+    ("Egyh", Alternate::Default, "hiératique égyptien"),
+    ("Egyp", Alternate::Default, "hiéroglyphes égyptiens"),
+    ("Elym", Alternate::Default, "élymaïque"),
+    ("Ethi", Alternate::Default, "éthiopique"),
+    ("Geok", Alternate::Default, "géorgien khoutsouri"),
+    ("Geor", Alternate::Default, "géorgien"),
+    ("Glag", Alternate::Default, "glagolitique"),
+    ("Goth", Alternate::Default, "gotique"),
+    ("Grek", Alternate::Default, "grec"),
+    ("Gujr", Alternate::Default, "goudjarâtî"),
+    ("Guru", Alternate::Default, "gourmoukhî"),
+    ("Hanb", Alternate::Default, "han avec bopomofo"),
+    ("Hang", Alternate::Default, "hangûl"),
+    ("Hani", Alternate::Default, "sinogrammes"),
+    ("Hano", Alternate::Default, "hanounóo"),
+    ("Hans", Alternate::Default, "simplifié"),
+    ("Hant", Alternate::Default, "traditionnel"),
+    ("Hebr", Alternate::Default, "hébreu"),
+    ("Hira", Alternate::Default, "hiragana"),
+    ("Hmng", Alternate::Default, "pahawh hmong"),
+    ("Hmnp", Alternate::Default, "nyiakeng puachue hmong"),
+    ("Hrkt", Alternate::Default, "katakana ou hiragana"),
+    ("Hung", Alternate::Default, "ancien hongrois"),
+    ("Inds", Alternate::Default, "indus"),
+    ("Ital", Alternate::Default, "ancien italique"),
+    ("Jamo", Alternate::Default, "jamo"),
+    ("Java", Alternate::Default, "javanais"),
+    ("Jpan", Alternate::Default, "japonais"),
+    ("Kali", Alternate::Default, "kayah li"),
+    ("Kana", Alternate::Default, "katakana"),
+    ("Khar", Alternate::Default, "kharochthî"),
+    ("Khmr", Alternate::Default, "khmer"),
+    ("Knda", Alternate::Default, "kannara"),
+    ("Kore", Alternate::Default, "coréen"),
+    ("Kthi", Alternate::Default, "kaithî"),
+    ("Lana", Alternate::Default, "lanna"),
+    ("Laoo", Alternate::Default, "lao"),
+    ("Latf", Alternate::Default, "latin (variante brisée)"),
+    ("Latg", Alternate::Default, "latin (variante gaélique)"),
+    ("Latn", Alternate::Default, "latin"),
+    ("Lepc", Alternate::Default, "lepcha"),
+    ("Limb", Alternate::Default, "limbou"),
+    ("Lina", Alternate::Default, "linéaire A"),
+    ("Linb", Alternate::Default, "linéaire B"),
+    ("Lyci", Alternate::Default, "lycien"),
+    ("Lydi", Alternate::Default, "lydien"),
+    ("Mand", Alternate::Default, "mandéen"),
+    ("Mani", Alternate::Default, "manichéen"),
+    ("Maya", Alternate::Default, "hiéroglyphes mayas"),
+    ("Mero", Alternate::Default, "méroïtique"),
+    ("Mlym", Alternate::Default, "malayalam"),
+    ("Mong", Alternate::Default, "mongol"),
+    ("Moon", Alternate::Default, "moon"),
+    ("Mtei", Alternate::Default, "meitei mayek"),
+    ("Mymr", Alternate::Default, "birman"),
+    ("Nand", Alternate::Default, "nandinagari"),
+    ("Nkoo", Alternate::Default, "n’ko"),
+    ("Ogam", Alternate::Default, "ogam"),
+    ("Olck", Alternate::Default, "ol-chiki"),
+    ("Orkh", Alternate::Default, "orkhon"),
+    ("Orya", Alternate::Default, "odia"),
+    ("Osma", Alternate::Default, "osmanais"),
+    ("Perm", Alternate::Default, "ancien permien"),
+    ("Phag", Alternate::Default, "phags pa"),
+    ("Phli", Alternate::Default, "pehlevi des inscriptions"),
+    ("Phlp", Alternate::Default, "pehlevi des psautiers"),
+    ("Phlv", Alternate::Default, "pehlevi des livres"),
+    ("Phnx", Alternate::Default, "phénicien"),
+    ("Plrd", Alternate::Default, "phonétique de Pollard"),
+    ("Prti", Alternate::Default, "parthe des inscriptions"),
+    ("Qaag", Alternate::Default, "zawgyi"),
+    ("Rjng", Alternate::Default, "rejang"),
+    ("Rohg", Alternate::Default, "hanifi"),
+    ("Roro", Alternate::Default, "rongorongo"),
+    ("Runr", Alternate::Default, "runique"),
+    ("Samr", Alternate::Default, "samaritain"),
+    ("Sara", Alternate::Default, "sarati"),
+    ("Saur", Alternate::Default, "saurashtra"),
+    ("Sgnw", Alternate::Default, "écriture des signes"),
+    ("Shaw", Alternate::Default, "shavien"),
+    ("Sinh", Alternate::Default, "cingalais"),
+    ("Sund", Alternate::Default, "sundanais"),
+    ("Sylo", Alternate::Default, "sylotî nâgrî"),
+    ("Syrc", Alternate::Default, "syriaque"),
+    ("Syre", Alternate::Default, "syriaque estranghélo"),
+    ("Syrj", Alternate::Default, "syriaque occidental"),
+    ("Syrn", Alternate::Default, "syriaque oriental"),
+    ("Tagb", Alternate::Default, "tagbanoua"),
+    ("Tale", Alternate::Default, "taï-le"),
+    ("Talu", Alternate::Default, "nouveau taï-lue"),
+    ("Taml", Alternate::Default, "tamoul"),
+    ("Tavt", Alternate::Default, "taï viêt"),
+    ("Telu", Alternate::Default, "télougou"),
+    ("Teng", Alternate::Default, "tengwar"),
+    ("Tfng", Alternate::Default, "tifinagh"),
+    ("Tglg", Alternate::Default, "tagal"),
+    ("Thaa", Alternate::Default, "thâna"),
+    ("Thai", Alternate::Default, "thaï"),
+    ("Tibt", Alternate::Default, "tibétain"),
+    ("Ugar", Alternate::Default, "ougaritique"),
+    ("Vaii", Alternate::Default, "vaï"),
+    ("Visp", Alternate::Default, "parole visible"),
+    ("Wcho", Alternate::Default, "wantcho"),
+    ("Xpeo", Alternate::Default, "cunéiforme persépolitain"),
+    ("Xsux", Alternate::Default, "cunéiforme suméro-akkadien"),
+    ("Yiii", Alternate::Default, "yi"),
+    ("Zinh", Alternate::Default, "hérité"),
+    ("Zmth", Alternate::Default, "notation mathématique"),
+    ("Zsye", Alternate::Default, "emoji"),
+    ("Zsym", Alternate::Default, "symboles"),
+    ("Zxxx", Alternate::Default, "non écrit"),
+    ("Zyyy", Alternate::Default, "commun"),
+    ("Zzzz", Alternate::Default, "écriture inconnue"),
+];
+
+#[rustfmt::skip]
+pub static FR_REGIONS: [(&str, Alternate, &str); 309] = [
+    ("001", Alternate::Default, "Monde"),
+    ("002", Alternate::Default, "Afrique"),
+    ("003", Alternate::Default, "Amérique du Nord"),
+    ("005", Alternate::Default, "Amérique du Sud"),
+    ("009", Alternate::Default, "Océanie"),
+    ("011", Alternate::Default, "Afrique occidentale"),
+    ("013", Alternate::Default, "Amérique centrale"),
+    ("014", Alternate::Default, "Afrique orientale"),
+    ("015", Alternate::Default, "Afrique septentrionale"),
+    ("017", Alternate::Default, "Afrique centrale"),
+    ("018", Alternate::Default, "Afrique australe"),
+    ("019", Alternate::Default, "Amériques"),
+    ("021", Alternate::Default, "Amérique septentrionale"),
+    ("029", Alternate::Default, "Caraïbes"),
+    ("030", Alternate::Default, "Asie de l’Est"),
+    ("034", Alternate::Default, "Asie du Sud"),
+    ("035", Alternate::Default, "Asie du Sud-Est"),
+    ("039", Alternate::Default, "Europe du Sud"),
+    ("053", Alternate::Default, "Australasie"),
+    ("054", Alternate::Default, "Mélanésie"),
+    ("057", Alternate::Default, "région micronésienne"),
+    ("061", Alternate::Default, "Polynésie"),
+    ("142", Alternate::Default, "Asie"),
+    ("143", Alternate::Default, "Asie centrale"),
+    ("145", Alternate::Default, "Asie de l’Ouest"),
+    ("150", Alternate::Default, "Europe"),
+    ("151", Alternate::Default, "Europe de l’Est"),
+    ("154", Alternate::Default, "Europe du Nord"),
+    ("155", Alternate::Default, "Europe de l’Ouest"),
+    ("202", Alternate::Default, "Afrique subsaharienne"),
+    ("419", Alternate::Default, "Amérique latine"),
+    ("AC", Alternate::Default, "Île de l’Ascension"),
+    ("AD", Alternate::Default, "Andorre"),
+    ("AE", Alternate::Default, "Émirats arabes unis"),
+    ("AF", Alternate::Default, "Afghanistan"),
+    ("AG", Alternate::Default, "Antigua-et-Barbuda"),
+    ("AI", Alternate::Default, "Anguilla"),
+    ("AL", Alternate::Default, "Albanie"),
+    ("AM", Alternate::Default, "Arménie"),
+    ("AO", Alternate::Default, "Angola"),
+    ("AQ", Alternate::Default, "Antarctique"),
+    ("AR", Alternate::Default, "Argentine"),
+    ("AS", Alternate::Default, "Samoa américaines"),
+    ("AT", Alternate::Default, "Autriche"),
+    ("AU", Alternate::Default, "Australie"),
+    ("AW", Alternate::Default, "Aruba"),
+    ("AX", Alternate::Default, "Îles Åland"),
+    ("AZ", Alternate::Default, "Azerbaïdjan"),
+    ("BA", Alternate::Default, "Bosnie-Herzégovine"),
+    ("BB", Alternate::Default, "Barbade"),
+    ("BD", Alternate::Default, "Bangladesh"),
+    ("BE", Alternate::Default, "Belgique"),
+    ("BF", Alternate::Default, "Burkina Faso"),
+    ("BG", Alternate::Default, "Bulgarie"),
+    ("BH", Alternate::Default, "Bahreïn"),
+    ("BI", Alternate::Default, "Burundi"),
+    ("BJ", Alternate::Default, "Bénin"),
+    ("BL", Alternate::Default, "Saint-Barthélemy"),
+    ("BM", Alternate::Default, "Bermudes"),
+    ("BN", Alternate::Default, "Brunei"),
+    ("BO", Alternate::Default, "Bolivie"),
+    ("BQ", Alternate::Default, "Pays-Bas caribéens"),
+    ("BR", Alternate::Default, "Brésil"),
+    ("BS", Alternate::Default, "Bahamas"),
+    ("BT", Alternate::Default, "Bhoutan"),
+    ("BV", Alternate::Default, "Île Bouvet"),
+    ("BW", Alternate::Default, "Botswana"),
+    ("BY", Alternate::Default, "Biélorussie"),
+    ("BZ", Alternate::Default, "Belize"),
+    ("CA", Alternate::Default, "Canada"),
+    ("CC", Alternate::Default, "Îles Cocos"),
+    ("CD", Alternate::Default, "Congo-Kinshasa"),
+    ("CD", Alternate::Variant, "Congo (RDC)"),
+    ("CF", Alternate::Default, "République centrafricaine"),
+    ("CG", Alternate::Default, "Congo-Brazzaville"),
+    ("CG", Alternate::Variant, "République du Congo"),
+    ("CH", Alternate::Default, "Suisse"),
+    ("CI", Alternate::Default, "Côte d’Ivoire"),
+    ("CI", Alternate::Variant, "République de Côte d’Ivoire"),
+    ("CK", Alternate::Default, "Îles Cook"),
+    ("CL", Alternate::Default, "Chili"),
+    ("CM", Alternate::Default, "Cameroun"),
+    ("CN", Alternate::Default, "Chine"),
+    ("CO", Alternate::Default, "Colombie"),
+    ("CP", Alternate::Default, "Île Clipperton"),
+    ("CR", Alternate::Default, "Costa Rica"),
+    ("CU", Alternate::Default, "Cuba"),
+    ("CV", Alternate::Default, "Cap-Vert"),
+    ("CW", Alternate::Default, "Curaçao"),
+    ("CX", Alternate::Default, "Île Christmas"),
+    ("CY", Alternate::Default, "Chypre"),
+    ("CZ", Alternate::Default, "Tchéquie"),
+    ("CZ", Alternate::Variant, "République tchèque"),
+    ("DE", Alternate::Default, "Allemagne"),
+    ("DG", Alternate::Default, "Diego Garcia"),
+    ("DJ", Alternate::Default, "Djibouti"),
+    ("DK", Alternate::Default, "Danemark"),
+    ("DM", Alternate::Default, "Dominique"),
+    ("DO", Alternate::Default, "République dominicaine"),
+    ("DZ", Alternate::Default, "Algérie"),
+    ("EA", Alternate::Default, "Ceuta et Melilla"),
+    ("EC", Alternate::Default, "Équateur"),
+    ("EE", Alternate::Default, "Estonie"),
+    ("EG", Alternate::Default, "Égypte"),
+    ("EH", Alternate::Default, "Sahara occidental"),
+    ("ER", Alternate::Default, "Érythrée"),
+    ("ES", Alternate::Default, "Espagne"),
+    ("ET", Alternate::Default, "Éthiopie"),
+    ("EU", Alternate::Default, "Union européenne"),
+    ("EZ", Alternate::Default, "zone euro"),
+    ("FI", Alternate::Default, "Finlande"),
+    ("FJ", Alternate::Default, "Fidji"),
+    ("FK", Alternate::Default, "Îles Malouines"),
+    ("FK", Alternate::Variant, "Îles Malouines (Îles Falkland)"),
+    ("FM", Alternate::Default, "Micronésie"),
+    ("FO", Alternate::Default, "Îles Féroé"),
+    ("FR", Alternate::Default, "France"),
+    ("GA", Alternate::Default, "Gabon"),
+    ("GB", Alternate::Default, "Royaume-Uni"),
+    ("GB", Alternate::Short, "R.-U."),
+    ("GD", Alternate::Default, "Grenade"),
+    ("GE", Alternate::Default, "Géorgie"),
+    ("GF", Alternate::Default, "Guyane française"),
+    ("GG", Alternate::Default, "Guernesey"),
+    ("GH", Alternate::Default, "Ghana"),
+    ("GI", Alternate::Default, "Gibraltar"),
+    ("GL", Alternate::Default, "Groenland"),
+    ("GM", Alternate::Default, "Gambie"),
+    ("GN", Alternate::Default, "Guinée"),
+    ("GP", Alternate::Default, "Guadeloupe"),
+    ("GQ", Alternate::Default, "Guinée équatoriale"),
+    ("GR", Alternate::Default, "Grèce"),
+    ("GS", Alternate::Default, "Géorgie du Sud-et-les Îles Sandwich du Sud"),
+    ("GT", Alternate::Default, "Guatemala"),
+    ("GU", Alternate::Default, "Guam"),
+    ("GW", Alternate::Default, "Guinée-Bissau"),
+    ("GY", Alternate::Default, "Guyana"),
+    ("HK", Alternate::Default, "R.A.S. chinoise de Hong Kong"),
+    ("HK", Alternate::Short, "Hong Kong"),
+    ("HM", Alternate::Default, "Îles Heard-et-MacDonald"),
+    ("HN", Alternate::Default, "Honduras"),
+    ("HR", Alternate::Default, "Croatie"),
+    ("HT", Alternate::Default, "Haïti"),
+    ("HU", Alternate::Default, "Hongrie"),
+    ("IC", Alternate::Default, "Îles Canaries"),
+    ("ID", Alternate::Default, "Indonésie"),
+    ("IE", Alternate::Default, "Irlande"),
+    ("IL", Alternate::Default, "Israël"),
+    ("IM", Alternate::Default, "Île de Man"),
+    ("IN", Alternate::Default, "Inde"),
+    ("IO", Alternate::Default, "Territoire britannique de l’océan Indien"),
+    ("IQ", Alternate::Default, "Irak"),
+    ("IR", Alternate::Default, "Iran"),
+    ("IS", Alternate::Default, "Islande"),
+    ("IT", Alternate::Default, "Italie"),
+    ("JE", Alternate::Default, "Jersey"),
+    ("JM", Alternate::Default, "Jamaïque"),
+    ("JO", Alternate::Default, "Jordanie"),
+    ("JP", Alternate::Default, "Japon"),
+    ("KE", Alternate::Default, "Kenya"),
+    ("KG", Alternate::Default, "Kirghizstan"),
+    ("KH", Alternate::Default, "Cambodge"),
+    ("KI", Alternate::Default, "Kiribati"),
+    ("KM", Alternate::Default, "Comores"),
+    ("KN", Alternate::Default, "Saint-Christophe-et-Niévès"),
+    ("KP", Alternate::Default, "Corée du Nord"),
+    ("KR", Alternate::Default, "Corée du Sud"),
+    ("KW", Alternate::Default, "Koweït"),
+    ("KY", Alternate::Default, "Îles Caïmans"),
+    ("KZ", Alternate::Default, "Kazakhstan"),
+    ("LA", Alternate::Default, "Laos"),
+    ("LB", Alternate::Default, "Liban"),
+    ("LC", Alternate::Default, "Sainte-Lucie"),
+    ("LI", Alternate::Default, "Liechtenstein"),
+    ("LK", Alternate::Default, "Sri Lanka"),
+    ("LR", Alternate::Default, "Liberia"),
+    ("LS", Alternate::Default, "Lesotho"),
+    ("LT", Alternate::Default, "Lituanie"),
+    ("LU", Alternate::Default, "Luxembourg"),
+    ("LV", Alternate::Default, "Lettonie"),
+    ("LY", Alternate::Default, "Libye"),
+    ("MA", Alternate::Default, "Maroc"),
+    ("MC", Alternate::Default, "Monaco"),
+    ("MD", Alternate::Default, "Moldavie"),
+    ("ME", Alternate::Default, "Monténégro"),
+    ("MF", Alternate::Default, "Saint-Martin"),
+    ("MG", Alternate::Default, "Madagascar"),
+    ("MH", Alternate::Default, "Îles Marshall"),
+    ("MK", Alternate::Default, "Macédoine du Nord"),
+    ("ML", Alternate::Default, "Mali"),
+    ("MM", Alternate::Default, "Myanmar (Birmanie)"),
+    ("MN", Alternate::Default, "Mongolie"),
+    ("MO", Alternate::Default, "R.A.S. chinoise de Macao"),
+    ("MO", Alternate::Short, "Macao"),
+    ("MP", Alternate::Default, "Îles Mariannes du Nord"),
+    ("MQ", Alternate::Default, "Martinique"),
+    ("MR", Alternate::Default, "Mauritanie"),
+    ("MS", Alternate::Default, "Montserrat"),
+    ("MT", Alternate::Default, "Malte"),
+    ("MU", Alternate::Default, "Maurice"),
+    ("MV", Alternate::Default, "Maldives"),
+    ("MW", Alternate::Default, "Malawi"),
+    ("MX", Alternate::Default, "Mexique"),
+    ("MY", Alternate::Default, "Malaisie"),
+    ("MZ", Alternate::Default, "Mozambique"),
+    ("NA", Alternate::Default, "Namibie"),
+    ("NC", Alternate::Default, "Nouvelle-Calédonie"),
+    ("NE", Alternate::Default, "Niger"),
+    ("NF", Alternate::Default, "Île Norfolk"),
+    ("NG", Alternate::Default, "Nigeria"),
+    ("NI", Alternate::Default, "Nicaragua"),
+    ("NL", Alternate::Default, "Pays-Bas"),
+    ("NO", Alternate::Default, "Norvège"),
+    ("NP", Alternate::Default, "Népal"),
+    ("NR", Alternate::Default, "Nauru"),
+    ("NU", Alternate::Default, "Niue"),
+    ("NZ", Alternate::Default, "Nouvelle-Zélande"),
+    ("NZ", Alternate::Variant, "Aotearoa (Nouvelle-Zélande)"),
+    ("OM", Alternate::Default, "Oman"),
+    ("PA", Alternate::Default, "Panama"),
+    ("PE", Alternate::Default, "Pérou"),
+    ("PF", Alternate::Default, "Polynésie française"),
+    ("PG", Alternate::Default, "Papouasie-Nouvelle-Guinée"),
+    ("PH", Alternate::Default, "Philippines"),
+    ("PK", Alternate::Default, "Pakistan"),
+    ("PL", Alternate::Default, "Pologne"),
+    ("PM", Alternate::Default, "Saint-Pierre-et-Miquelon"),
+    ("PN", Alternate::Default, "Îles Pitcairn"),
+    ("PR", Alternate::Default, "Porto Rico"),
+    ("PS", Alternate::Default, "Territoires palestiniens"),
+    ("PS", Alternate::Short, "Palestine"),
+    ("PT", Alternate::Default, "Portugal"),
+    ("PW", Alternate::Default, "Palaos"),
+    ("PY", Alternate::Default, "Paraguay"),
+    ("QA", Alternate::Default, "Qatar"),
+    ("QO", Alternate::Default, "régions éloignées de l’Océanie"),
+    ("RE", Alternate::Default, "La Réunion"),
+    ("RO", Alternate::Default, "Roumanie"),
+    ("RS", Alternate::Default, "Serbie"),
+    ("RU", Alternate::Default, "Russie"),
+    ("RW", Alternate::Default, "Rwanda"),
+    ("SA", Alternate::Default, "Arabie saoudite"),
+    ("SB", Alternate::Default, "Îles Salomon"),
+    ("SC", Alternate::Default, "Seychelles"),
+    ("SD", Alternate::Default, "Soudan"),
+    ("SE", Alternate::Default, "Suède"),
+    ("SG", Alternate::Default, "Singapour"),
+    ("SH", Alternate::Default, "Sainte-Hélène"),
+    ("SI", Alternate::Default, "Slovénie"),
+    ("SJ", Alternate::Default, "Svalbard et Jan Mayen"),
+    ("SK", Alternate::Default, "Slovaquie"),
+    ("SL", Alternate::Default, "Sierra Leone"),
+    ("SM", Alternate::Default, "Saint-Marin"),
+    ("SN", Alternate::Default, "Sénégal"),
+    ("SO", Alternate::Default, "Somalie"),
+    ("SR", Alternate::Default, "Suriname"),
+    ("SS", Alternate::Default, "Soudan du Sud"),
+    ("ST", Alternate::Default, "Sao Tomé-et-Principe"),
+    ("SV", Alternate::Default, "Salvador"),
+    ("SX", Alternate::Default, "Saint-Martin (partie néerlandaise)"),
+    ("SY", Alternate::Default, "Syrie"),
+    ("SZ", Alternate::Default, "Eswatini"),
+    ("SZ", Alternate::Variant, "Swaziland"),
+    ("TA", Alternate::Default, "Tristan da Cunha"),
+    ("TC", Alternate::Default, "Îles Turques-et-Caïques"),
+    ("TD", Alternate::Default, "Tchad"),
+    ("TF", Alternate::Default, "Terres australes françaises"),
+    ("TG", Alternate::Default, "Togo"),
+    ("TH", Alternate::Default, "Thaïlande"),
+    ("TJ", Alternate::Default, "Tadjikistan"),
+    ("TK", Alternate::Default, "Tokelau"),
+    ("TL", Alternate::Default, "Timor oriental"),
+    ("TL", Alternate::Variant, "Timor-Oriental"),
+    ("TM", Alternate::Default, "Turkménistan"),
+    ("TN", Alternate::Default, "Tunisie"),
+    ("TO", Alternate::Default, "Tonga"),
+    ("TR", Alternate::Default, "Turquie"),
+    ("TR", Alternate::Variant, "Türkiye"),
+    ("TT", Alternate::Default, "Trinité-et-Tobago"),
+    ("TV", Alternate::Default, "Tuvalu"),
+    ("TW", Alternate::Default, "Taïwan"),
+    ("TZ", Alternate::Default, "Tanzanie"),
+    ("UA", Alternate::Default, "Ukraine"),
+    ("UG", Alternate::Default, "Ouganda"),
+    ("UM", Alternate::Default, "Îles mineures éloignées des États-Unis"),
+    ("UN", Alternate::Default, "Nations Unies"),
+    ("UN", Alternate::Short, "ONU"),
+    ("US", Alternate::Default, "États-Unis"),
+    ("US", Alternate::Short, "É.-U."),
+    ("UY", Alternate::Default, "Uruguay"),
+    ("UZ", Alternate::Default, "Ouzbékistan"),
+    ("VA", Alternate::Default, "État de la Cité du Vatican"),
+    ("VC", Alternate::Default, "Saint-Vincent-et-les Grenadines"),
+    ("VE", Alternate::Default, "Venezuela"),
+    ("VG", Alternate::Default, "Îles Vierges britanniques"),
+    ("VI", Alternate::Default, "Îles Vierges des États-Unis"),
+    ("VN", Alternate::Default, "Viêt Nam"),
+    ("VU", Alternate::Default, "Vanuatu"),
+    ("WF", Alternate::Default, "Wallis-et-Futuna"),
+    ("WS", Alternate::Default, "Samoa"),
+    ("XA", Alternate::Default, "pseudo-accents"),
+    ("XB", Alternate::Default, "pseudo-bidi"),
+    ("XK", Alternate::Default, "Kosovo"),
+    ("YE", Alternate::Default, "Yémen"),
+    ("YT", Alternate::Default, "Mayotte"),
+    ("ZA", Alternate::Default, "Afrique du Sud"),
+    ("ZM", Alternate::Default, "Zambie"),
+    ("ZW", Alternate::Default, "Zimbabwe"),
+    ("ZZ", Alternate::Default, "région indéterminée"),
+];
+
+#[rustfmt::skip]
+pub static FR_VARIANTS: [(&str, &str); 29] = [
+    ("1606nict", "françoys de 1606"),
+    ("1694acad", "françois académique de 1694"),
+    ("1901", "orthographe allemande traditionnelle"),
+    ("1994", "orthographe normalisée de Resia"),
+    ("1996", "orthographe allemande de 1996"),
+    ("arevela", "arménien oriental"),
+    ("arevmda", "arménien occidental"),
+    ("baku1926", "alphabet latin altaïque unifié"),
+    ("biske", "dialecte de San Giorgio / Bila"),
+    ("boont", "dialecte boontling"),
+    ("fonipa", "alphabet phonétique international"),
+    ("fonupa", "alphabet phonétique ouralique"),
+    ("lipaw", "dialecte lipovaz de Resia"),
+    ("monoton", "monotonique"),
+    ("nedis", "dialecte de Natisone"),
+    ("njiva", "dialecte de Gniva / Njiva"),
+    ("osojs", "dialecte d’Oseacco / Osojane"),
+    ("pinyin", "pinyin"),
+    ("polyton", "polytonique"),
+    ("posix", "informatique"),
+    ("revised", "orthographe révisée"),
+    ("rozaj", "dialecte de Resia"),
+    ("saaho", "dialecte saho"),
+    ("scotland", "anglais standard écossais"),
+    ("scouse", "dialecte scouse"),
+    ("solba", "dialecte de Stolvizza / Solbica"),
+    ("tarask", "orthographe taraskievica"),
+    ("valencia", "valencien"),
+    ("wadegile", "Wade-Giles"),
+];
+
+#[rustfmt::skip]
+pub static FR_KEYS: [(&str, &str); 17] = [
+    ("ca", "calendrier"),
+    ("cf", "format de devise"),
+    ("co", "ordre de tri"),
+    ("cu", "devise"),
+    ("hc", "système horaire (12 ou 24 heures)"),
+    ("ka", "tri ne tenant pas compte des symboles"),
+    ("kb", "tri inversé des caractères accentués"),
+    ("kc", "tri sensible à la casse"),
+    ("kf", "classement basé sur les majuscules et les minuscules"),
+    ("kk", "tri normalisé"),
+    ("kn", "tri numérique"),
+    ("ks", "priorité du tri"),
+    ("lb", "style de saut de ligne"),
+    ("ms", "système de mesure"),
+    ("nu", "nombres"),
+    ("tz", "fuseau horaire"),
+    ("va", "variante locale"),
+];
+
+#[rustfmt::skip]
+pub static FR_TYPES: [(&str, &str, &str); 105] = [
+    ("ca", "buddhist", "calendrier bouddhiste"),
+    ("ca", "chinese", "calendrier chinois"),
+    ("ca", "coptic", "calendrier copte"),
+    ("ca", "dangi", "calendrier dangi"),
+    ("ca", "ethioaa", "calendrier éthiopien Amete Alem"),
+    ("ca", "ethiopic", "calendrier éthiopien"),
+    ("ca", "gregory", "calendrier grégorien"),
+    ("ca", "hebrew", "calendrier hébraïque"),
+    ("ca", "indian", "calendrier indien"),
+    ("ca", "islamic", "calendrier musulman"),
+    ("ca", "islamic-civil", "calendrier musulman (tabulaire, époque civile)"),
+    ("ca", "islamic-tbla", "calendrier musulman (tabulaire, époque astronomique)"),
+    ("ca", "islamic-umalqura", "calendrier musulman (Umm al Qura)"),
+    ("ca", "iso8601", "calendrier ISO 8601"),
+    ("ca", "japanese", "calendrier japonais"),
+    ("ca", "persian", "calendrier persan"),
+    ("ca", "roc", "calendrier républicain chinois"),
+    ("cf", "account", "format de devise comptable"),
+    ("cf", "standard", "format de devise standard"),
+    ("co", "big5han", "ordre chinois traditionnel - Big5"),
+    ("co", "compat", "ancien ordre de tri pour compatibilité"),
+    ("co", "dict", "ordre du dictionnaire"),
+    ("co", "ducet", "ordre de tri Unicode par défaut"),
+    ("co", "eor", "règles de classement européen"),
+    ("co", "gb2312", "ordre chinois simplifié - GB2312"),
+    ("co", "phonebk", "ordre de l’annuaire"),
+    ("co", "phonetic", "ordre de tri phonétique"),
+    ("co", "pinyin", "ordre pinyin"),
+    ("co", "reformed", "ordre réformé"),
+    ("co", "search", "recherche générique"),
+    ("co", "searchjl", "rechercher par consonne initiale en hangeul"),
+    ("co", "standard", "ordre de tri standard"),
+    ("co", "stroke", "ordre des traits"),
+    ("co", "trad", "ordre traditionnel"),
+    ("co", "unihan", "ordre de tri radical-traits"),
+    ("co", "zhuyin", "ordre zhuyin"),
+    ("hc", "h11", "système horaire de 12 heures (0–11)"),
+    ("hc", "h12", "système horaire de 12 heures (1–12)"),
+    ("hc", "h23", "système horaire de 24 heures (0–23)"),
+    ("hc", "h24", "système horaire de 24 heures (1–24)"),
+    ("ka", "shifted", "Trier en ignorant les symboles"),
+    ("kb", "false", "Trier les caractères accentués normalement"),
+    ("kb", "true", "Trier les caractères accentués dans l’ordre inverse"),
+    ("kc", "false", "Trier sans tenir compte de la casse"),
+    ("kc", "true", "Trier en tenant compte de la casse"),
+    ("kf", "false", "Trier sans ordre lié à la casse"),
+    ("kf", "lower", "Trier avec les minuscules d’abord"),
+    ("kf", "upper", "Trier avec les majuscules d’abord"),
+    ("kk", "false", "Trier sans normalisation"),
+    ("kk", "true", "Trier avec normalisation Unicode"),
+    ("kn", "false", "Trier les chiffres individuellement"),
+    ("kn", "true", "Trier les chiffres par ordre numérique"),
+    ("ks", "primary", "Ne trier que les lettres de base"),
+    ("ks", "tertiary", "Trier en tenant compte des caractères accentués, de la casse et de la largeur"),
+    ("lb", "loose", "style de saut de ligne permissif"),
+    ("lb", "normal", "style de saut de ligne normal"),
+    ("lb", "strict", "style de saut de ligne strict"),
+    ("ms", "metric", "système métrique"),
+    ("ms", "uksystem", "système impérial"),
+    ("ms", "ussystem", "système américain"),
+    ("nu", "arab", "chiffres arabes"),
+    ("nu", "arabext", "chiffres arabes étendus"),
+    ("nu", "armn", "chiffres arméniens"),
+    ("nu", "armnlow", "chiffres arméniens minuscules"),
+    ("nu", "beng", "chiffres bengalis"),
+    ("nu", "cakm", "chiffres chakmas"),
+    ("nu", "deva", "chiffres dévanagaris"),
+    ("nu", "ethi", "chiffres éthiopiens"),
+    ("nu", "finance", "Chiffres financiers"),
+    ("nu", "fullwide", "chiffres pleine chasse"),
+    ("nu", "geor", "chiffres géorgiens"),
+    ("nu", "grek", "chiffres grecs"),
+    ("nu", "greklow", "chiffres grecs minuscules"),
+    ("nu", "gujr", "chiffres goudjarâtîs"),
+    ("nu", "guru", "chiffres gourmoukhîs"),
+    ("nu", "hanidec", "nombres décimaux chinois"),
+    ("nu", "hans", "chiffres en chinois simplifié"),
+    ("nu", "hansfin", "chiffres financiers en chinois simplifié"),
+    ("nu", "hant", "chiffres en chinois traditionnel"),
+    ("nu", "hantfin", "chiffres financiers en chinois traditionnel"),
+    ("nu", "hebr", "chiffres hébreux"),
+    ("nu", "hmnp", "chiffres nyiakeng puachue hmong"),
+    ("nu", "java", "chiffres javanais"),
+    ("nu", "jpan", "chiffres japonais"),
+    ("nu", "jpanfin", "chiffres japonais financiers"),
+    ("nu", "khmr", "chiffres khmers"),
+    ("nu", "knda", "chiffres en kannada"),
+    ("nu", "laoo", "chiffres laotiens"),
+    ("nu", "latn", "chiffres occidentaux"),
+    ("nu", "mlym", "chiffres malayâlams"),
+    ("nu", "mong", "chiffres mongols"),
+    ("nu", "mtei", "chiffres meitei-mayeks"),
+    ("nu", "mymr", "chiffres birmans"),
+    ("nu", "native", "chiffres natifs"),
+    ("nu", "olck", "chiffres ol-chikis"),
+    ("nu", "orya", "chiffres oriyas"),
+    ("nu", "roman", "chiffres romains"),
+    ("nu", "romanlow", "chiffres romains minuscules"),
+    ("nu", "taml", "chiffres tamouls traditionnels"),
+    ("nu", "tamldec", "chiffres tamouls"),
+    ("nu", "telu", "chiffres télougous"),
+    ("nu", "thai", "chiffres thaïs"),
+    ("nu", "tibt", "chiffres tibétains"),
+    ("nu", "vaii", "chiffres en vaï"),
+    ("nu", "wcho", "chiffres wantcho"),
+];
+
+#[rustfmt::skip]
+pub static FR_BE_LANGUAGES: [(&str, Alternate, &str); 4] = [
+    ("frp", Alternate::Default, "franco-provençal"),
+    ("goh", Alternate::Default, "ancien haut-allemand"),
+    ("gu", Alternate::Default, "gujarati"),
+    ("njo", Alternate::Default, "ao"),
+];
+
+#[rustfmt::skip]
+pub static FR_BE_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_BE_REGIONS: [(&str, Alternate, &str); 1] = [
+    ("GS", Alternate::Default, "Îles Géorgie du Sud et Sandwich du Sud"),
+];
+
+#[rustfmt::skip]
+pub static FR_BE_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_BE_KEYS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_BE_TYPES: [(&str, &str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CA_LANGUAGES: [(&str, Alternate, &str); 66] = [
+    ("ady", Alternate::Default, "adygué"),
+    ("ang", Alternate::Default, "vieil anglais"),
+    ("asa", Alternate::Default, "asou"),
+    ("bbj", Alternate::Default, "ghomala"),
+    ("bik", Alternate::Default, "bicol"),
+    ("byn", Alternate::Default, "bilen"),
+    ("byv", Alternate::Default, "medumba"),
+    ("chg", Alternate::Default, "tchagatay"),
+    ("chn", Alternate::Default, "chinook"),
+    ("ckb", Alternate::Default, "kurde central"),
+    ("ckb", Alternate::Variant, "kurde central"),
+    ("cr", Alternate::Default, "cri"),
+    ("crg", Alternate::Default, "michif"),
+    ("crl", Alternate::Default, "cri du Nord-Est"),
+    ("crr", Alternate::Default, "algonquin de la Caroline"),
+    ("den", Alternate::Default, "slave"),
+    ("dgr", Alternate::Default, "tlicho"),
+    ("ebu", Alternate::Default, "embou"),
+    ("en-US", Alternate::Short, "anglais (É.-U.)"),
+    ("esu", Alternate::Default, "yupik central"),
+    ("ewo", Alternate::Default, "ewondo"),
+    ("frc", Alternate::Default, "cajun"),
+    ("frp", Alternate::Default, "franco-provençal"),
+    ("goh", Alternate::Default, "vieux haut-allemand"),
+    ("gu", Alternate::Default, "gujarati"),
+    ("ii", Alternate::Default, "yi de Sichuan"),
+    ("ken", Alternate::Default, "kenyang"),
+    ("kg", Alternate::Default, "kongo"),
+    ("kl", Alternate::Default, "kalaallisut"),
+    ("ks", Alternate::Default, "kashmiri"),
+    ("ksb", Alternate::Default, "chambala"),
+    ("liv", Alternate::Default, "live"),
+    ("lu", Alternate::Default, "luba-katanga"),
+    ("lzh", Alternate::Default, "chinois classique"),
+    ("mgh", Alternate::Default, "makhuwa-meetto"),
+    ("mgo", Alternate::Default, "meta’"),
+    ("mr", Alternate::Default, "marathe"),
+    ("mwr", Alternate::Default, "marwari"),
+    ("mwv", Alternate::Default, "mentawai"),
+    ("nds-NL", Alternate::Default, "bas saxon"),
+    ("njo", Alternate::Default, "ao naga"),
+    ("nmg", Alternate::Default, "kwasio"),
+    ("nwc", Alternate::Default, "newari classique"),
+    ("nyn", Alternate::Default, "nkole"),
+    ("oka", Alternate::Default, "okanagan"),
+    ("pau", Alternate::Default, "palauan"),
+    ("pdc", Alternate::Default, "allemand de Pennsylvanie"),
+    ("pdt", Alternate::Default, "bas allemand mennonite"),
+    ("peo", Alternate::Default, "vieux perse"),
+    ("pfl", Alternate::Default, "palatin"),
+    ("pis", Alternate::Default, "pidgin"),
+    ("pro", Alternate::Default, "ancien occitan"),
+    ("quc", Alternate::Default, "k’iche’"),
+    ("rar", Alternate::Default, "rarotonga"),
+    ("sbp", Alternate::Default, "sangu"),
+    ("sdh", Alternate::Default, "kurde méridional"),
+    ("sei", Alternate::Default, "seri"),
+    ("sga", Alternate::Default, "vieil irlandais"),
+    ("sly", Alternate::Default, "selayar"),
+    ("smn", Alternate::Default, "sami d’Inari"),
+    ("stq", Alternate::Default, "frison de Saterland"),
+    ("sus", Alternate::Default, "sosso"),
+    ("sw-CD", Alternate::Default, "swahili congolais"),
+    ("tru", Alternate::Default, "turoyo"),
+    ("tzm", Alternate::Default, "tamazight"),
+    ("ug", Alternate::Variant, "ouïghour"),
+];
+
+#[rustfmt::skip]
+pub static FR_CA_SCRIPTS: [(&str, Alternate, &str); 8] = [
+    ("Deva", Alternate::Default, "devanagari"),
+    ("Gujr", Alternate::Default, "gujarati"),
+    ("Hanb", Alternate::Default, "hanb"),
+    ("Hans", Alternate::Default, "idéogrammes han simplifiés"),
+    ("Hant", Alternate::Default, "idéogrammes han traditionnels"),
+    ("Hrkt", Alternate::Default, "syllabaires japonais"),
+    ("Olck", Alternate::Default, "ol chiki"),
+    ("Zsye", Alternate::Default, "émoji"),
+];
+
+#[rustfmt::skip]
+pub static FR_CA_REGIONS: [(&str, Alternate, &str); 38] = [
+    ("030", Alternate::Default, "Asie orientale"),
+    ("039", Alternate::Default, "Europe méridionale"),
+    ("145", Alternate::Default, "Asie occidentale"),
+    ("151", Alternate::Default, "Europe orientale"),
+    ("154", Alternate::Default, "Europe septentrionale"),
+    ("155", Alternate::Default, "Europe occidentale"),
+    ("AC", Alternate::Default, "île de l’Ascension"),
+    ("AX", Alternate::Default, "îles d’Åland"),
+    ("BV", Alternate::Default, "île Bouvet"),
+    ("BY", Alternate::Default, "Bélarus"),
+    ("CC", Alternate::Default, "îles Cocos (Keeling)"),
+    ("CK", Alternate::Default, "îles Cook"),
+    ("CX", Alternate::Default, "île Christmas"),
+    ("FK", Alternate::Default, "îles Malouines"),
+    ("FK", Alternate::Variant, "îles Falkland (Malouines)"),
+    ("FO", Alternate::Default, "îles Féroé"),
+    ("HM", Alternate::Default, "îles Heard et McDonald"),
+    ("IC", Alternate::Default, "îles Canaries"),
+    ("IM", Alternate::Default, "île de Man"),
+    ("IO", Alternate::Default, "territoire britannique de l’océan Indien"),
+    ("KG", Alternate::Default, "Kirghizistan"),
+    ("LR", Alternate::Default, "Libéria"),
+    ("MF", Alternate::Default, "Saint-Martin (France)"),
+    ("MM", Alternate::Default, "Myanmar"),
+    ("MP", Alternate::Default, "Mariannes du Nord"),
+    ("NF", Alternate::Default, "île Norfolk"),
+    ("NG", Alternate::Default, "Nigéria"),
+    ("PN", Alternate::Default, "îles Pitcairn"),
+    ("QO", Alternate::Default, "Océanie lointaine"),
+    ("RE", Alternate::Default, "la Réunion"),
+    ("SX", Alternate::Default, "Saint-Martin (Pays-Bas)"),
+    ("TL", Alternate::Default, "Timor-Leste"),
+    ("TL", Alternate::Variant, "Timor oriental"),
+    ("UM", Alternate::Default, "îles mineures éloignées des États-Unis"),
+    ("VA", Alternate::Default, "Cité du Vatican"),
+    ("VG", Alternate::Default, "îles Vierges britanniques"),
+    ("VI", Alternate::Default, "îles Vierges américaines"),
+    ("VN", Alternate::Default, "Vietnam"),
+];
+
+#[rustfmt::skip]
+pub static FR_CA_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CA_KEYS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CA_TYPES: [(&str, &str, &str); 14] = [
+    ("ca", "islamic-umalqura", "calendrier musulman (calculé, Umm al-Qura)"),
+    ("co", "big5han", "ordre de tri chinois traditionnel - Big5"),
+    ("co", "dict", "ordre de tri du dictionnaire"),
+    ("co", "eor", "ordre multilingue européen"),
+    ("co", "gb2312", "ordre de tri chinois simplifié - GB2312"),
+    ("co", "phonebk", "ordre de tri de l’annuaire"),
+    ("co", "pinyin", "ordre de tri pinyin"),
+    ("co", "reformed", "ordre de tri réformé"),
+    ("co", "searchjl", "Rechercher par consonne initiale en hangeul"),
+    ("co", "stroke", "ordre de tri des traits"),
+    ("co", "trad", "ordre de tri traditionnel"),
+    ("co", "zhuyin", "ordre de tri zhuyin"),
+    ("nu", "gujr", "chiffres gujaratis"),
+    ("nu", "mong", "Chiffres mongols"),
+];
+
+#[rustfmt::skip]
+pub static FR_CH_LANGUAGES: [(&str, Alternate, &str); 3] = [
+    ("gu", Alternate::Default, "goudjrati"),
+    ("pdc", Alternate::Default, "allemand de Pennsylvanie"),
+    ("sdh", Alternate::Default, "kurde méridional"),
+];
+
+#[rustfmt::skip]
+pub static FR_CH_SCRIPTS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CH_REGIONS: [(&str, Alternate, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CH_VARIANTS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CH_KEYS: [(&str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static FR_CH_TYPES: [(&str, &str, &str); 0] = [
+];
+
+#[rustfmt::skip]
+pub static LOCALES: [(&str, LocaleData); 105] = [
+    ("de", LocaleData {
+        locale_pattern: Some("{0} ({1})"),
+        locale_separator: Some("{0}, {1}"),
+        key_type_pattern: Some("{0}: {1}"),
+        languages: &DE_LANGUAGES,
+        scripts: &DE_SCRIPTS,
+        regions: &DE_REGIONS,
+        variants: &DE_VARIANTS,
+        keys: &DE_KEYS,
+        types: &DE_TYPES,
+    }),
+    ("de-AT", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &DE_AT_LANGUAGES,
+        scripts: &DE_AT_SCRIPTS,
+        regions: &DE_AT_REGIONS,
+        variants: &DE_AT_VARIANTS,
+        keys: &DE_AT_KEYS,
+        types: &DE_AT_TYPES,
+    }),
+    ("de-CH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &DE_CH_LANGUAGES,
+        scripts: &DE_CH_SCRIPTS,
+        regions: &DE_CH_REGIONS,
+        variants: &DE_CH_VARIANTS,
+        keys: &DE_CH_KEYS,
+        types: &DE_CH_TYPES,
+    }),
+    ("en", LocaleData {
+        locale_pattern: Some("{0} ({1})"),
+        locale_separator: Some("{0}, {1}"),
+        key_type_pattern: Some("{0}: {1}"),
+        languages: &EN_LANGUAGES,
+        scripts: &EN_SCRIPTS,
+        regions: &EN_REGIONS,
+        variants: &EN_VARIANTS,
+        keys: &EN_KEYS,
+        types: &EN_TYPES,
+    }),
+    ("en-001", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_001_LANGUAGES,
+        scripts: &EN_001_SCRIPTS,
+        regions: &EN_001_REGIONS,
+        variants: &EN_001_VARIANTS,
+        keys: &EN_001_KEYS,
+        types: &EN_001_TYPES,
+    }),
+    ("en-150", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_150_LANGUAGES,
+        scripts: &EN_150_SCRIPTS,
+        regions: &EN_150_REGIONS,
+        variants: &EN_150_VARIANTS,
+        keys: &EN_150_KEYS,
+        types: &EN_150_TYPES,
+    }),
+    ("en-AG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_AG_LANGUAGES,
+        scripts: &EN_AG_SCRIPTS,
+        regions: &EN_AG_REGIONS,
+        variants: &EN_AG_VARIANTS,
+        keys: &EN_AG_KEYS,
+        types: &EN_AG_TYPES,
+    }),
+    ("en-AI", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_AI_LANGUAGES,
+        scripts: &EN_AI_SCRIPTS,
+        regions: &EN_AI_REGIONS,
+        variants: &EN_AI_VARIANTS,
+        keys: &EN_AI_KEYS,
+        types: &EN_AI_TYPES,
+    }),
+    ("en-AT", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_AT_LANGUAGES,
+        scripts: &EN_AT_SCRIPTS,
+        regions: &EN_AT_REGIONS,
+        variants: &EN_AT_VARIANTS,
+        keys: &EN_AT_KEYS,
+        types: &EN_AT_TYPES,
+    }),
+    ("en-AU", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_AU_LANGUAGES,
+        scripts: &EN_AU_SCRIPTS,
+        regions: &EN_AU_REGIONS,
+        variants: &EN_AU_VARIANTS,
+        keys: &EN_AU_KEYS,
+        types: &EN_AU_TYPES,
+    }),
+    ("en-BB", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_BB_LANGUAGES,
+        scripts: &EN_BB_SCRIPTS,
+        regions: &EN_BB_REGIONS,
+        variants: &EN_BB_VARIANTS,
+        keys: &EN_BB_KEYS,
+        types: &EN_BB_TYPES,
+    }),
+    ("en-BE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_BE_LANGUAGES,
+        scripts: &EN_BE_SCRIPTS,
+        regions: &EN_BE_REGIONS,
+        variants: &EN_BE_VARIANTS,
+        keys: &EN_BE_KEYS,
+        types: &EN_BE_TYPES,
+    }),
+    ("en-BM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_BM_LANGUAGES,
+        scripts: &EN_BM_SCRIPTS,
+        regions: &EN_BM_REGIONS,
+        variants: &EN_BM_VARIANTS,
+        keys: &EN_BM_KEYS,
+        types: &EN_BM_TYPES,
+    }),
+    ("en-BS", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_BS_LANGUAGES,
+        scripts: &EN_BS_SCRIPTS,
+        regions: &EN_BS_REGIONS,
+        variants: &EN_BS_VARIANTS,
+        keys: &EN_BS_KEYS,
+        types: &EN_BS_TYPES,
+    }),
+    ("en-BW", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_BW_LANGUAGES,
+        scripts: &EN_BW_SCRIPTS,
+        regions: &EN_BW_REGIONS,
+        variants: &EN_BW_VARIANTS,
+        keys: &EN_BW_KEYS,
+        types: &EN_BW_TYPES,
+    }),
+    ("en-BZ", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_BZ_LANGUAGES,
+        scripts: &EN_BZ_SCRIPTS,
+        regions: &EN_BZ_REGIONS,
+        variants: &EN_BZ_VARIANTS,
+        keys: &EN_BZ_KEYS,
+        types: &EN_BZ_TYPES,
+    }),
+    ("en-CA", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CA_LANGUAGES,
+        scripts: &EN_CA_SCRIPTS,
+        regions: &EN_CA_REGIONS,
+        variants: &EN_CA_VARIANTS,
+        keys: &EN_CA_KEYS,
+        types: &EN_CA_TYPES,
+    }),
+    ("en-CC", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CC_LANGUAGES,
+        scripts: &EN_CC_SCRIPTS,
+        regions: &EN_CC_REGIONS,
+        variants: &EN_CC_VARIANTS,
+        keys: &EN_CC_KEYS,
+        types: &EN_CC_TYPES,
+    }),
+    ("en-CH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CH_LANGUAGES,
+        scripts: &EN_CH_SCRIPTS,
+        regions: &EN_CH_REGIONS,
+        variants: &EN_CH_VARIANTS,
+        keys: &EN_CH_KEYS,
+        types: &EN_CH_TYPES,
+    }),
+    ("en-CK", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CK_LANGUAGES,
+        scripts: &EN_CK_SCRIPTS,
+        regions: &EN_CK_REGIONS,
+        variants: &EN_CK_VARIANTS,
+        keys: &EN_CK_KEYS,
+        types: &EN_CK_TYPES,
+    }),
+    ("en-CM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CM_LANGUAGES,
+        scripts: &EN_CM_SCRIPTS,
+        regions: &EN_CM_REGIONS,
+        variants: &EN_CM_VARIANTS,
+        keys: &EN_CM_KEYS,
+        types: &EN_CM_TYPES,
+    }),
+    ("en-CX", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CX_LANGUAGES,
+        scripts: &EN_CX_SCRIPTS,
+        regions: &EN_CX_REGIONS,
+        variants: &EN_CX_VARIANTS,
+        keys: &EN_CX_KEYS,
+        types: &EN_CX_TYPES,
+    }),
+    ("en-CY", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_CY_LANGUAGES,
+        scripts: &EN_CY_SCRIPTS,
+        regions: &EN_CY_REGIONS,
+        variants: &EN_CY_VARIANTS,
+        keys: &EN_CY_KEYS,
+        types: &EN_CY_TYPES,
+    }),
+    ("en-DE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_DE_LANGUAGES,
+        scripts: &EN_DE_SCRIPTS,
+        regions: &EN_DE_REGIONS,
+        variants: &EN_DE_VARIANTS,
+        keys: &EN_DE_KEYS,
+        types: &EN_DE_TYPES,
+    }),
+    ("en-DG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_DG_LANGUAGES,
+        scripts: &EN_DG_SCRIPTS,
+        regions: &EN_DG_REGIONS,
+        variants: &EN_DG_VARIANTS,
+        keys: &EN_DG_KEYS,
+        types: &EN_DG_TYPES,
+    }),
+    ("en-DK", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_DK_LANGUAGES,
+        scripts: &EN_DK_SCRIPTS,
+        regions: &EN_DK_REGIONS,
+        variants: &EN_DK_VARIANTS,
+        keys: &EN_DK_KEYS,
+        types: &EN_DK_TYPES,
+    }),
+    ("en-DM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_DM_LANGUAGES,
+        scripts: &EN_DM_SCRIPTS,
+        regions: &EN_DM_REGIONS,
+        variants: &EN_DM_VARIANTS,
+        keys: &EN_DM_KEYS,
+        types: &EN_DM_TYPES,
+    }),
+    ("en-ER", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_ER_LANGUAGES,
+        scripts: &EN_ER_SCRIPTS,
+        regions: &EN_ER_REGIONS,
+        variants: &EN_ER_VARIANTS,
+        keys: &EN_ER_KEYS,
+        types: &EN_ER_TYPES,
+    }),
+    ("en-FI", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_FI_LANGUAGES,
+        scripts: &EN_FI_SCRIPTS,
+        regions: &EN_FI_REGIONS,
+        variants: &EN_FI_VARIANTS,
+        keys: &EN_FI_KEYS,
+        types: &EN_FI_TYPES,
+    }),
+    ("en-FJ", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_FJ_LANGUAGES,
+        scripts: &EN_FJ_SCRIPTS,
+        regions: &EN_FJ_REGIONS,
+        variants: &EN_FJ_VARIANTS,
+        keys: &EN_FJ_KEYS,
+        types: &EN_FJ_TYPES,
+    }),
+    ("en-FK", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_FK_LANGUAGES,
+        scripts: &EN_FK_SCRIPTS,
+        regions: &EN_FK_REGIONS,
+        variants: &EN_FK_VARIANTS,
+        keys: &EN_FK_KEYS,
+        types: &EN_FK_TYPES,
+    }),
+    ("en-FM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_FM_LANGUAGES,
+        scripts: &EN_FM_SCRIPTS,
+        regions: &EN_FM_REGIONS,
+        variants: &EN_FM_VARIANTS,
+        keys: &EN_FM_KEYS,
+        types: &EN_FM_TYPES,
+    }),
+    ("en-GB", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GB_LANGUAGES,
+        scripts: &EN_GB_SCRIPTS,
+        regions: &EN_GB_REGIONS,
+        variants: &EN_GB_VARIANTS,
+        keys: &EN_GB_KEYS,
+        types: &EN_GB_TYPES,
+    }),
+    ("en-GD", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GD_LANGUAGES,
+        scripts: &EN_GD_SCRIPTS,
+        regions: &EN_GD_REGIONS,
+        variants: &EN_GD_VARIANTS,
+        keys: &EN_GD_KEYS,
+        types: &EN_GD_TYPES,
+    }),
+    ("en-GG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GG_LANGUAGES,
+        scripts: &EN_GG_SCRIPTS,
+        regions: &EN_GG_REGIONS,
+        variants: &EN_GG_VARIANTS,
+        keys: &EN_GG_KEYS,
+        types: &EN_GG_TYPES,
+    }),
+    ("en-GH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GH_LANGUAGES,
+        scripts: &EN_GH_SCRIPTS,
+        regions: &EN_GH_REGIONS,
+        variants: &EN_GH_VARIANTS,
+        keys: &EN_GH_KEYS,
+        types: &EN_GH_TYPES,
+    }),
+    ("en-GI", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GI_LANGUAGES,
+        scripts: &EN_GI_SCRIPTS,
+        regions: &EN_GI_REGIONS,
+        variants: &EN_GI_VARIANTS,
+        keys: &EN_GI_KEYS,
+        types: &EN_GI_TYPES,
+    }),
+    ("en-GM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GM_LANGUAGES,
+        scripts: &EN_GM_SCRIPTS,
+        regions: &EN_GM_REGIONS,
+        variants: &EN_GM_VARIANTS,
+        keys: &EN_GM_KEYS,
+        types: &EN_GM_TYPES,
+    }),
+    ("en-GY", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_GY_LANGUAGES,
+        scripts: &EN_GY_SCRIPTS,
+        regions: &EN_GY_REGIONS,
+        variants: &EN_GY_VARIANTS,
+        keys: &EN_GY_KEYS,
+        types: &EN_GY_TYPES,
+    }),
+    ("en-HK", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_HK_LANGUAGES,
+        scripts: &EN_HK_SCRIPTS,
+        regions: &EN_HK_REGIONS,
+        variants: &EN_HK_VARIANTS,
+        keys: &EN_HK_KEYS,
+        types: &EN_HK_TYPES,
+    }),
+    ("en-IE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_IE_LANGUAGES,
+        scripts: &EN_IE_SCRIPTS,
+        regions: &EN_IE_REGIONS,
+        variants: &EN_IE_VARIANTS,
+        keys: &EN_IE_KEYS,
+        types: &EN_IE_TYPES,
+    }),
+    ("en-IL", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_IL_LANGUAGES,
+        scripts: &EN_IL_SCRIPTS,
+        regions: &EN_IL_REGIONS,
+        variants: &EN_IL_VARIANTS,
+        keys: &EN_IL_KEYS,
+        types: &EN_IL_TYPES,
+    }),
+    ("en-IM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_IM_LANGUAGES,
+        scripts: &EN_IM_SCRIPTS,
+        regions: &EN_IM_REGIONS,
+        variants: &EN_IM_VARIANTS,
+        keys: &EN_IM_KEYS,
+        types: &EN_IM_TYPES,
+    }),
+    ("en-IN", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_IN_LANGUAGES,
+        scripts: &EN_IN_SCRIPTS,
+        regions: &EN_IN_REGIONS,
+        variants: &EN_IN_VARIANTS,
+        keys: &EN_IN_KEYS,
+        types: &EN_IN_TYPES,
+    }),
+    ("en-IO", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_IO_LANGUAGES,
+        scripts: &EN_IO_SCRIPTS,
+        regions: &EN_IO_REGIONS,
+        variants: &EN_IO_VARIANTS,
+        keys: &EN_IO_KEYS,
+        types: &EN_IO_TYPES,
+    }),
+    ("en-JE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_JE_LANGUAGES,
+        scripts: &EN_JE_SCRIPTS,
+        regions: &EN_JE_REGIONS,
+        variants: &EN_JE_VARIANTS,
+        keys: &EN_JE_KEYS,
+        types: &EN_JE_TYPES,
+    }),
+    ("en-JM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_JM_LANGUAGES,
+        scripts: &EN_JM_SCRIPTS,
+        regions: &EN_JM_REGIONS,
+        variants: &EN_JM_VARIANTS,
+        keys: &EN_JM_KEYS,
+        types: &EN_JM_TYPES,
+    }),
+    ("en-KE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_KE_LANGUAGES,
+        scripts: &EN_KE_SCRIPTS,
+        regions: &EN_KE_REGIONS,
+        variants: &EN_KE_VARIANTS,
+        keys: &EN_KE_KEYS,
+        types: &EN_KE_TYPES,
+    }),
+    ("en-KI", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_KI_LANGUAGES,
+        scripts: &EN_KI_SCRIPTS,
+        regions: &EN_KI_REGIONS,
+        variants: &EN_KI_VARIANTS,
+        keys: &EN_KI_KEYS,
+        types: &EN_KI_TYPES,
+    }),
+    ("en-KN", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_KN_LANGUAGES,
+        scripts: &EN_KN_SCRIPTS,
+        regions: &EN_KN_REGIONS,
+        variants: &EN_KN_VARIANTS,
+        keys: &EN_KN_KEYS,
+        types: &EN_KN_TYPES,
+    }),
+    ("en-KY", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_KY_LANGUAGES,
+        scripts: &EN_KY_SCRIPTS,
+        regions: &EN_KY_REGIONS,
+        variants: &EN_KY_VARIANTS,
+        keys: &EN_KY_KEYS,
+        types: &EN_KY_TYPES,
+    }),
+    ("en-LC", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_LC_LANGUAGES,
+        scripts: &EN_LC_SCRIPTS,
+        regions: &EN_LC_REGIONS,
+        variants: &EN_LC_VARIANTS,
+        keys: &EN_LC_KEYS,
+        types: &EN_LC_TYPES,
+    }),
+    ("en-LR", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_LR_LANGUAGES,
+        scripts: &EN_LR_SCRIPTS,
+        regions: &EN_LR_REGIONS,
+        variants: &EN_LR_VARIANTS,
+        keys: &EN_LR_KEYS,
+        types: &EN_LR_TYPES,
+    }),
+    ("en-LS", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_LS_LANGUAGES,
+        scripts: &EN_LS_SCRIPTS,
+        regions: &EN_LS_REGIONS,
+        variants: &EN_LS_VARIANTS,
+        keys: &EN_LS_KEYS,
+        types: &EN_LS_TYPES,
+    }),
+    ("en-MG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MG_LANGUAGES,
+        scripts: &EN_MG_SCRIPTS,
+        regions: &EN_MG_REGIONS,
+        variants: &EN_MG_VARIANTS,
+        keys: &EN_MG_KEYS,
+        types: &EN_MG_TYPES,
+    }),
+    ("en-MO", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MO_LANGUAGES,
+        scripts: &EN_MO_SCRIPTS,
+        regions: &EN_MO_REGIONS,
+        variants: &EN_MO_VARIANTS,
+        keys: &EN_MO_KEYS,
+        types: &EN_MO_TYPES,
+    }),
+    ("en-MS", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MS_LANGUAGES,
+        scripts: &EN_MS_SCRIPTS,
+        regions: &EN_MS_REGIONS,
+        variants: &EN_MS_VARIANTS,
+        keys: &EN_MS_KEYS,
+        types: &EN_MS_TYPES,
+    }),
+    ("en-MT", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MT_LANGUAGES,
+        scripts: &EN_MT_SCRIPTS,
+        regions: &EN_MT_REGIONS,
+        variants: &EN_MT_VARIANTS,
+        keys: &EN_MT_KEYS,
+        types: &EN_MT_TYPES,
+    }),
+    ("en-MU", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MU_LANGUAGES,
+        scripts: &EN_MU_SCRIPTS,
+        regions: &EN_MU_REGIONS,
+        variants: &EN_MU_VARIANTS,
+        keys: &EN_MU_KEYS,
+        types: &EN_MU_TYPES,
+    }),
+    ("en-MV", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MV_LANGUAGES,
+        scripts: &EN_MV_SCRIPTS,
+        regions: &EN_MV_REGIONS,
+        variants: &EN_MV_VARIANTS,
+        keys: &EN_MV_KEYS,
+        types: &EN_MV_TYPES,
+    }),
+    ("en-MW", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MW_LANGUAGES,
+        scripts: &EN_MW_SCRIPTS,
+        regions: &EN_MW_REGIONS,
+        variants: &EN_MW_VARIANTS,
+        keys: &EN_MW_KEYS,
+        types: &EN_MW_TYPES,
+    }),
+    ("en-MY", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_MY_LANGUAGES,
+        scripts: &EN_MY_SCRIPTS,
+        regions: &EN_MY_REGIONS,
+        variants: &EN_MY_VARIANTS,
+        keys: &EN_MY_KEYS,
+        types: &EN_MY_TYPES,
+    }),
+    ("en-NA", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NA_LANGUAGES,
+        scripts: &EN_NA_SCRIPTS,
+        regions: &EN_NA_REGIONS,
+        variants: &EN_NA_VARIANTS,
+        keys: &EN_NA_KEYS,
+        types: &EN_NA_TYPES,
+    }),
+    ("en-NF", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NF_LANGUAGES,
+        scripts: &EN_NF_SCRIPTS,
+        regions: &EN_NF_REGIONS,
+        variants: &EN_NF_VARIANTS,
+        keys: &EN_NF_KEYS,
+        types: &EN_NF_TYPES,
+    }),
+    ("en-NG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NG_LANGUAGES,
+        scripts: &EN_NG_SCRIPTS,
+        regions: &EN_NG_REGIONS,
+        variants: &EN_NG_VARIANTS,
+        keys: &EN_NG_KEYS,
+        types: &EN_NG_TYPES,
+    }),
+    ("en-NH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NH_LANGUAGES,
+        scripts: &EN_NH_SCRIPTS,
+        regions: &EN_NH_REGIONS,
+        variants: &EN_NH_VARIANTS,
+        keys: &EN_NH_KEYS,
+        types: &EN_NH_TYPES,
+    }),
+    ("en-NL", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NL_LANGUAGES,
+        scripts: &EN_NL_SCRIPTS,
+        regions: &EN_NL_REGIONS,
+        variants: &EN_NL_VARIANTS,
+        keys: &EN_NL_KEYS,
+        types: &EN_NL_TYPES,
+    }),
+    ("en-NR", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NR_LANGUAGES,
+        scripts: &EN_NR_SCRIPTS,
+        regions: &EN_NR_REGIONS,
+        variants: &EN_NR_VARIANTS,
+        keys: &EN_NR_KEYS,
+        types: &EN_NR_TYPES,
+    }),
+    ("en-NU", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NU_LANGUAGES,
+        scripts: &EN_NU_SCRIPTS,
+        regions: &EN_NU_REGIONS,
+        variants: &EN_NU_VARIANTS,
+        keys: &EN_NU_KEYS,
+        types: &EN_NU_TYPES,
+    }),
+    ("en-NZ", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_NZ_LANGUAGES,
+        scripts: &EN_NZ_SCRIPTS,
+        regions: &EN_NZ_REGIONS,
+        variants: &EN_NZ_VARIANTS,
+        keys: &EN_NZ_KEYS,
+        types: &EN_NZ_TYPES,
+    }),
+    ("en-PG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_PG_LANGUAGES,
+        scripts: &EN_PG_SCRIPTS,
+        regions: &EN_PG_REGIONS,
+        variants: &EN_PG_VARIANTS,
+        keys: &EN_PG_KEYS,
+        types: &EN_PG_TYPES,
+    }),
+    ("en-PK", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_PK_LANGUAGES,
+        scripts: &EN_PK_SCRIPTS,
+        regions: &EN_PK_REGIONS,
+        variants: &EN_PK_VARIANTS,
+        keys: &EN_PK_KEYS,
+        types: &EN_PK_TYPES,
+    }),
+    ("en-PN", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_PN_LANGUAGES,
+        scripts: &EN_PN_SCRIPTS,
+        regions: &EN_PN_REGIONS,
+        variants: &EN_PN_VARIANTS,
+        keys: &EN_PN_KEYS,
+        types: &EN_PN_TYPES,
+    }),
+    ("en-PW", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_PW_LANGUAGES,
+        scripts: &EN_PW_SCRIPTS,
+        regions: &EN_PW_REGIONS,
+        variants: &EN_PW_VARIANTS,
+        keys: &EN_PW_KEYS,
+        types: &EN_PW_TYPES,
+    }),
+    ("en-RH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_RH_LANGUAGES,
+        scripts: &EN_RH_SCRIPTS,
+        regions: &EN_RH_REGIONS,
+        variants: &EN_RH_VARIANTS,
+        keys: &EN_RH_KEYS,
+        types: &EN_RH_TYPES,
+    }),
+    ("en-RW", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_RW_LANGUAGES,
+        scripts: &EN_RW_SCRIPTS,
+        regions: &EN_RW_REGIONS,
+        variants: &EN_RW_VARIANTS,
+        keys: &EN_RW_KEYS,
+        types: &EN_RW_TYPES,
+    }),
+    ("en-SB", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SB_LANGUAGES,
+        scripts: &EN_SB_SCRIPTS,
+        regions: &EN_SB_REGIONS,
+        variants: &EN_SB_VARIANTS,
+        keys: &EN_SB_KEYS,
+        types: &EN_SB_TYPES,
+    }),
+    ("en-SC", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SC_LANGUAGES,
+        scripts: &EN_SC_SCRIPTS,
+        regions: &EN_SC_REGIONS,
+        variants: &EN_SC_VARIANTS,
+        keys: &EN_SC_KEYS,
+        types: &EN_SC_TYPES,
+    }),
+    ("en-SD", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SD_LANGUAGES,
+        scripts: &EN_SD_SCRIPTS,
+        regions: &EN_SD_REGIONS,
+        variants: &EN_SD_VARIANTS,
+        keys: &EN_SD_KEYS,
+        types: &EN_SD_TYPES,
+    }),
+    ("en-SE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SE_LANGUAGES,
+        scripts: &EN_SE_SCRIPTS,
+        regions: &EN_SE_REGIONS,
+        variants: &EN_SE_VARIANTS,
+        keys: &EN_SE_KEYS,
+        types: &EN_SE_TYPES,
+    }),
+    ("en-SG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SG_LANGUAGES,
+        scripts: &EN_SG_SCRIPTS,
+        regions: &EN_SG_REGIONS,
+        variants: &EN_SG_VARIANTS,
+        keys: &EN_SG_KEYS,
+        types: &EN_SG_TYPES,
+    }),
+    ("en-SH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SH_LANGUAGES,
+        scripts: &EN_SH_SCRIPTS,
+        regions: &EN_SH_REGIONS,
+        variants: &EN_SH_VARIANTS,
+        keys: &EN_SH_KEYS,
+        types: &EN_SH_TYPES,
+    }),
+    ("en-SI", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SI_LANGUAGES,
+        scripts: &EN_SI_SCRIPTS,
+        regions: &EN_SI_REGIONS,
+        variants: &EN_SI_VARIANTS,
+        keys: &EN_SI_KEYS,
+        types: &EN_SI_TYPES,
+    }),
+    ("en-SL", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SL_LANGUAGES,
+        scripts: &EN_SL_SCRIPTS,
+        regions: &EN_SL_REGIONS,
+        variants: &EN_SL_VARIANTS,
+        keys: &EN_SL_KEYS,
+        types: &EN_SL_TYPES,
+    }),
+    ("en-SS", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SS_LANGUAGES,
+        scripts: &EN_SS_SCRIPTS,
+        regions: &EN_SS_REGIONS,
+        variants: &EN_SS_VARIANTS,
+        keys: &EN_SS_KEYS,
+        types: &EN_SS_TYPES,
+    }),
+    ("en-SX", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SX_LANGUAGES,
+        scripts: &EN_SX_SCRIPTS,
+        regions: &EN_SX_REGIONS,
+        variants: &EN_SX_VARIANTS,
+        keys: &EN_SX_KEYS,
+        types: &EN_SX_TYPES,
+    }),
+    ("en-SZ", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_SZ_LANGUAGES,
+        scripts: &EN_SZ_SCRIPTS,
+        regions: &EN_SZ_REGIONS,
+        variants: &EN_SZ_VARIANTS,
+        keys: &EN_SZ_KEYS,
+        types: &EN_SZ_TYPES,
+    }),
+    ("en-TC", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_TC_LANGUAGES,
+        scripts: &EN_TC_SCRIPTS,
+        regions: &EN_TC_REGIONS,
+        variants: &EN_TC_VARIANTS,
+        keys: &EN_TC_KEYS,
+        types: &EN_TC_TYPES,
+    }),
+    ("en-TK", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_TK_LANGUAGES,
+        scripts: &EN_TK_SCRIPTS,
+        regions: &EN_TK_REGIONS,
+        variants: &EN_TK_VARIANTS,
+        keys: &EN_TK_KEYS,
+        types: &EN_TK_TYPES,
+    }),
+    ("en-TO", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_TO_LANGUAGES,
+        scripts: &EN_TO_SCRIPTS,
+        regions: &EN_TO_REGIONS,
+        variants: &EN_TO_VARIANTS,
+        keys: &EN_TO_KEYS,
+        types: &EN_TO_TYPES,
+    }),
+    ("en-TT", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_TT_LANGUAGES,
+        scripts: &EN_TT_SCRIPTS,
+        regions: &EN_TT_REGIONS,
+        variants: &EN_TT_VARIANTS,
+        keys: &EN_TT_KEYS,
+        types: &EN_TT_TYPES,
+    }),
+    ("en-TV", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_TV_LANGUAGES,
+        scripts: &EN_TV_SCRIPTS,
+        regions: &EN_TV_REGIONS,
+        variants: &EN_TV_VARIANTS,
+        keys: &EN_TV_KEYS,
+        types: &EN_TV_TYPES,
+    }),
+    ("en-TZ", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_TZ_LANGUAGES,
+        scripts: &EN_TZ_SCRIPTS,
+        regions: &EN_TZ_REGIONS,
+        variants: &EN_TZ_VARIANTS,
+        keys: &EN_TZ_KEYS,
+        types: &EN_TZ_TYPES,
+    }),
+    ("en-UG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_UG_LANGUAGES,
+        scripts: &EN_UG_SCRIPTS,
+        regions: &EN_UG_REGIONS,
+        variants: &EN_UG_VARIANTS,
+        keys: &EN_UG_KEYS,
+        types: &EN_UG_TYPES,
+    }),
+    ("en-VC", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_VC_LANGUAGES,
+        scripts: &EN_VC_SCRIPTS,
+        regions: &EN_VC_REGIONS,
+        variants: &EN_VC_VARIANTS,
+        keys: &EN_VC_KEYS,
+        types: &EN_VC_TYPES,
+    }),
+    ("en-VG", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_VG_LANGUAGES,
+        scripts: &EN_VG_SCRIPTS,
+        regions: &EN_VG_REGIONS,
+        variants: &EN_VG_VARIANTS,
+        keys: &EN_VG_KEYS,
+        types: &EN_VG_TYPES,
+    }),
+    ("en-VU", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_VU_LANGUAGES,
+        scripts: &EN_VU_SCRIPTS,
+        regions: &EN_VU_REGIONS,
+        variants: &EN_VU_VARIANTS,
+        keys: &EN_VU_KEYS,
+        types: &EN_VU_TYPES,
+    }),
+    ("en-WS", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_WS_LANGUAGES,
+        scripts: &EN_WS_SCRIPTS,
+        regions: &EN_WS_REGIONS,
+        variants: &EN_WS_VARIANTS,
+        keys: &EN_WS_KEYS,
+        types: &EN_WS_TYPES,
+    }),
+    ("en-ZA", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_ZA_LANGUAGES,
+        scripts: &EN_ZA_SCRIPTS,
+        regions: &EN_ZA_REGIONS,
+        variants: &EN_ZA_VARIANTS,
+        keys: &EN_ZA_KEYS,
+        types: &EN_ZA_TYPES,
+    }),
+    ("en-ZM", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_ZM_LANGUAGES,
+        scripts: &EN_ZM_SCRIPTS,
+        regions: &EN_ZM_REGIONS,
+        variants: &EN_ZM_VARIANTS,
+        keys: &EN_ZM_KEYS,
+        types: &EN_ZM_TYPES,
+    }),
+    ("en-ZW", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &EN_ZW_LANGUAGES,
+        scripts: &EN_ZW_SCRIPTS,
+        regions: &EN_ZW_REGIONS,
+        variants: &EN_ZW_VARIANTS,
+        keys: &EN_ZW_KEYS,
+        types: &EN_ZW_TYPES,
+    }),
+    ("fr", LocaleData {
+        locale_pattern: Some("{0} ({1})"),
+        locale_separator: Some("{0}, {1}"),
+        key_type_pattern: Some("{0}\u{202f}: {1}"),
+        languages: &FR_LANGUAGES,
+        scripts: &FR_SCRIPTS,
+        regions: &FR_REGIONS,
+        variants: &FR_VARIANTS,
+        keys: &FR_KEYS,
+        types: &FR_TYPES,
+    }),
+    ("fr-BE", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &FR_BE_LANGUAGES,
+        scripts: &FR_BE_SCRIPTS,
+        regions: &FR_BE_REGIONS,
+        variants: &FR_BE_VARIANTS,
+        keys: &FR_BE_KEYS,
+        types: &FR_BE_TYPES,
+    }),
+    ("fr-CA", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: Some("{0} : {1}"),
+        languages: &FR_CA_LANGUAGES,
+        scripts: &FR_CA_SCRIPTS,
+        regions: &FR_CA_REGIONS,
+        variants: &FR_CA_VARIANTS,
+        keys: &FR_CA_KEYS,
+        types: &FR_CA_TYPES,
+    }),
+    ("fr-CH", LocaleData {
+        locale_pattern: None,
+        locale_separator: None,
+        key_type_pattern: None,
+        languages: &FR_CH_LANGUAGES,
+        scripts: &FR_CH_SCRIPTS,
+        regions: &FR_CH_REGIONS,
+        variants: &FR_CH_VARIANTS,
+        keys: &FR_CH_KEYS,
+        types: &FR_CH_TYPES,
+    }),
+];
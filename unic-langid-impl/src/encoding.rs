@@ -0,0 +1,341 @@
+//! Compact binary encoding of `LanguageIdentifier`.
+//!
+//! The encoding is meant for storage and IPC where the string form is too
+//! large or too slow to parse. It is stable: an encoded identifier always
+//! starts with a version byte, and data written with a given version will
+//! be decoded by all later releases.
+//!
+//! # Format
+//!
+//! Version 1 lays out an identifier as:
+//!
+//! | Bytes    | Content                                                        |
+//! |----------|----------------------------------------------------------------|
+//! | 1        | Version, currently `1`.                                        |
+//! | 1        | Header: bits 0-3 hold the length of the language subtag (`0` for `und`), bit 4 is set if there is a script, bits 5-6 hold the length of the region subtag (`0` if there is none). Bit 7 is reserved and must be `0`. |
+//! | 0, 2-8   | Language subtag.                                               |
+//! | 0, 4     | Script subtag.                                                 |
+//! | 0, 2-3   | Region subtag.                                                 |
+//! | 1+       | Number of variants, as a LEB128 varint.                        |
+//! | 1 + 4-8  | Each variant, prefixed with its length as a single byte.       |
+//!
+//! Subtags are stored as ASCII in their canonical case and variants in their
+//! canonical order, so every identifier has exactly one encoding. Decoding
+//! validates the input and rejects anything which isn't that encoding.
+//!
+//! `en-US` takes 7 bytes, and `sl-rozaj-biske` takes 17.
+//!
+//! # Packed `u64`
+//!
+//! Identifiers without variants and with a language subtag of at most
+//! 7 letters can also be packed into a single `u64`, which is handy as a
+//! fixed-width database column:
+//!
+//! | Bits  | Content                                                           |
+//! |-------|-------------------------------------------------------------------|
+//! | 30-63 | Language, letters `a`-`z` as digits `1`-`26` of a base 27 number, `0` for `und`. |
+//! | 11-29 | Script, `0` if there is none, otherwise `1` plus the letters as a base 26 number. |
+//! | 0-10  | Region, `0` if there is none, `1`-`676` for alphabetic regions and `677`-`1676` for numeric ones. |
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let langid: LanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+//!
+//! let bytes = langid.to_bytes();
+//! assert_eq!(bytes, &[1, 0x42, b'e', b'n', b'U', b'S', 0]);
+//! assert_eq!(LanguageIdentifier::from_bytes_encoded(&bytes), Ok(langid.clone()));
+//!
+//! let packed = langid.to_u64().expect("Packing failed.");
+//! assert_eq!(LanguageIdentifier::from_u64(packed), Ok(langid));
+//! ```
+use crate::parser::ParserError;
+use crate::{subtags, LanguageIdentifier};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Current version of the encoding.
+pub const VERSION: u8 = 1;
+
+const SCRIPT_FLAG: u8 = 0b0001_0000;
+const RESERVED_FLAG: u8 = 0b1000_0000;
+
+const LANGUAGE_SHIFT: u32 = 30;
+const SCRIPT_SHIFT: u32 = 11;
+const SCRIPT_MASK: u64 = (1 << 19) - 1;
+const REGION_MASK: u64 = (1 << 11) - 1;
+const MAX_PACKED_LANGUAGE_LENGTH: usize = 7;
+const ALPHA_REGIONS: u64 = 26 * 26;
+
+/// Appends a LEB128 encoded `value` to `output`.
+#[doc(hidden)]
+pub fn write_varint(mut value: usize, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Reads a LEB128 encoded value starting at `offset`, returning it with
+/// the offset past its end.
+///
+/// Values which don't fit in a `u32` or use more bytes than needed are rejected.
+#[doc(hidden)]
+pub fn read_varint(input: &[u8], mut offset: usize) -> Result<(usize, usize), ParserError> {
+    let start = offset;
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *input
+            .get(offset)
+            .ok_or(ParserError::InvalidEncoding { offset })?;
+        value |= u64::from(byte & 0x7F) << shift;
+        offset += 1;
+        if byte & 0x80 == 0 {
+            if (byte == 0 && offset - start > 1) || value > u64::from(u32::MAX) {
+                return Err(ParserError::InvalidEncoding { offset: start });
+            }
+            return Ok((value as usize, offset));
+        }
+        shift += 7;
+        if shift > 28 {
+            return Err(ParserError::InvalidEncoding { offset: start });
+        }
+    }
+}
+
+fn read_slice(input: &[u8], offset: usize, length: usize) -> Result<&[u8], ParserError> {
+    input
+        .get(offset..offset + length)
+        .ok_or(ParserError::InvalidEncoding { offset })
+}
+
+/// Appends the encoding of `langid` to `output`, without the version byte.
+#[doc(hidden)]
+pub fn write_language_identifier(langid: &LanguageIdentifier, output: &mut Vec<u8>) {
+    let language = if langid.language.is_empty() {
+        ""
+    } else {
+        langid.language.as_str()
+    };
+    let region = langid.region.as_ref().map_or("", |r| r.as_str());
+
+    let mut header = language.len() as u8 | (region.len() as u8) << 5;
+    if langid.script.is_some() {
+        header |= SCRIPT_FLAG;
+    }
+    output.push(header);
+    output.extend_from_slice(language.as_bytes());
+    if let Some(script) = &langid.script {
+        output.extend_from_slice(script.as_str().as_bytes());
+    }
+    output.extend_from_slice(region.as_bytes());
+
+    write_varint(langid.variants().len(), output);
+    for variant in langid.variants() {
+        output.push(variant.as_str().len() as u8);
+        output.extend_from_slice(variant.as_str().as_bytes());
+    }
+}
+
+/// Reads an identifier encoded without the version byte starting at `offset`,
+/// returning it with the offset past its end.
+#[doc(hidden)]
+pub fn read_language_identifier(
+    input: &[u8],
+    offset: usize,
+) -> Result<(LanguageIdentifier, usize), ParserError> {
+    let start = offset;
+    let invalid = |offset| move |_| ParserError::InvalidEncoding { offset };
+
+    let header = *input
+        .get(offset)
+        .ok_or(ParserError::InvalidEncoding { offset })?;
+    if header & RESERVED_FLAG != 0 {
+        return Err(ParserError::InvalidEncoding { offset });
+    }
+    let mut offset = offset + 1;
+
+    let language_length = usize::from(header & 0x0F);
+    let language = if language_length == 0 {
+        subtags::Language::default()
+    } else {
+        let bytes = read_slice(input, offset, language_length)?;
+        let language = subtags::Language::from_bytes(bytes).map_err(invalid(offset))?;
+        offset += language_length;
+        language
+    };
+
+    let script = if header & SCRIPT_FLAG != 0 {
+        let bytes = read_slice(input, offset, 4)?;
+        let script = subtags::Script::from_bytes(bytes).map_err(invalid(offset))?;
+        offset += 4;
+        Some(script)
+    } else {
+        None
+    };
+
+    let region_length = usize::from(header >> 5 & 0b11);
+    let region = if region_length == 0 {
+        None
+    } else {
+        let bytes = read_slice(input, offset, region_length)?;
+        let region = subtags::Region::from_bytes(bytes).map_err(invalid(offset))?;
+        offset += region_length;
+        Some(region)
+    };
+
+    let (count, mut offset) = read_varint(input, offset)?;
+    let mut variants = Vec::with_capacity(count.min(input.len()));
+    for _ in 0..count {
+        let length = usize::from(
+            *input
+                .get(offset)
+                .ok_or(ParserError::InvalidEncoding { offset })?,
+        );
+        let bytes = read_slice(input, offset + 1, length)?;
+        variants.push(subtags::Variant::from_bytes(bytes).map_err(invalid(offset))?);
+        offset += 1 + length;
+    }
+
+    let langid = LanguageIdentifier::from_parts(language, script, region, &variants);
+
+    // Reject any input which isn't the canonical encoding, such as
+    // subtags in the wrong case or unordered variants.
+    let mut canonical = Vec::with_capacity(offset - start);
+    write_language_identifier(&langid, &mut canonical);
+    if canonical != input[start..offset] {
+        return Err(ParserError::InvalidEncoding { offset: start });
+    }
+
+    Ok((langid, offset))
+}
+
+/// Encodes `langid` into a new buffer, starting with the version byte.
+pub fn encode(langid: &LanguageIdentifier) -> Vec<u8> {
+    let mut result = vec![VERSION];
+    write_language_identifier(langid, &mut result);
+    result
+}
+
+/// Decodes an identifier from `input`, which must hold exactly one
+/// encoded identifier starting with the version byte.
+pub fn decode(input: &[u8]) -> Result<LanguageIdentifier, ParserError> {
+    match input.first() {
+        Some(&VERSION) => {}
+        _ => return Err(ParserError::InvalidEncoding { offset: 0 }),
+    }
+    let (langid, offset) = read_language_identifier(input, 1)?;
+    if offset != input.len() {
+        return Err(ParserError::InvalidEncoding { offset });
+    }
+    Ok(langid)
+}
+
+/// Packs `langid` into a `u64`.
+///
+/// Returns `None` if the identifier has variants or a language subtag
+/// longer than 7 letters.
+pub fn pack(langid: &LanguageIdentifier) -> Option<u64> {
+    if langid.variants().len() != 0 {
+        return None;
+    }
+
+    let language = if langid.language.is_empty() {
+        0
+    } else {
+        let language = langid.language.as_str();
+        if language.len() > MAX_PACKED_LANGUAGE_LENGTH {
+            return None;
+        }
+        language
+            .bytes()
+            .fold(0, |acc, b| acc * 27 + u64::from(b - b'a' + 1))
+    };
+
+    let script = langid.script.as_ref().map_or(0, |s| {
+        1 + s.as_str().bytes().fold(0, |acc, b| {
+            acc * 26 + u64::from(b.to_ascii_lowercase() - b'a')
+        })
+    });
+
+    let region = langid.region.as_ref().map_or(0, |r| {
+        let region = r.as_str();
+        if region.len() == 2 {
+            1 + region
+                .bytes()
+                .fold(0, |acc, b| acc * 26 + u64::from(b - b'A'))
+        } else {
+            // Numeric regions always have three digits.
+            1 + ALPHA_REGIONS + region.parse::<u64>().unwrap()
+        }
+    });
+
+    Some(language << LANGUAGE_SHIFT | script << SCRIPT_SHIFT | region)
+}
+
+/// Unpacks an identifier packed with [`pack`].
+pub fn unpack(value: u64) -> Result<LanguageIdentifier, ParserError> {
+    let invalid = |_| ParserError::InvalidEncoding { offset: 0 };
+
+    let mut packed_language = value >> LANGUAGE_SHIFT;
+    let language = if packed_language == 0 {
+        subtags::Language::default()
+    } else {
+        let mut bytes = vec![];
+        while packed_language != 0 {
+            let digit = (packed_language % 27) as u8;
+            if digit == 0 {
+                return Err(ParserError::InvalidEncoding { offset: 0 });
+            }
+            bytes.push(b'a' + digit - 1);
+            packed_language /= 27;
+        }
+        bytes.reverse();
+        subtags::Language::from_bytes(&bytes).map_err(invalid)?
+    };
+
+    let script = match value >> SCRIPT_SHIFT & SCRIPT_MASK {
+        0 => None,
+        packed => {
+            let mut packed = packed - 1;
+            let mut bytes = [0; 4];
+            for byte in bytes.iter_mut().rev() {
+                *byte = b'a' + (packed % 26) as u8;
+                packed /= 26;
+            }
+            if packed != 0 {
+                return Err(ParserError::InvalidEncoding { offset: 0 });
+            }
+            Some(subtags::Script::from_bytes(&bytes).map_err(invalid)?)
+        }
+    };
+
+    let region = match value & REGION_MASK {
+        0 => None,
+        packed if packed <= ALPHA_REGIONS => {
+            let packed = packed - 1;
+            let bytes = [b'A' + (packed / 26) as u8, b'A' + (packed % 26) as u8];
+            Some(subtags::Region::from_bytes(&bytes).map_err(invalid)?)
+        }
+        packed if packed <= ALPHA_REGIONS + 1000 => {
+            let bytes = format!("{:03}", packed - ALPHA_REGIONS - 1);
+            Some(subtags::Region::from_bytes(bytes.as_bytes()).map_err(invalid)?)
+        }
+        _ => return Err(ParserError::InvalidEncoding { offset: 0 }),
+    };
+
+    let langid = LanguageIdentifier::from_parts(language, script, region, &[]);
+
+    // Reject values which aren't the canonical packing, such as `und`
+    // spelled out as a language subtag.
+    if pack(&langid) != Some(value) {
+        return Err(ParserError::InvalidEncoding { offset: 0 });
+    }
+
+    Ok(langid)
+}
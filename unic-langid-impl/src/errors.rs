@@ -1,6 +1,6 @@
+use super::packed::PackError;
 use super::parser::ParserError;
-use std::error::Error;
-use std::fmt::{self, Display};
+use core::fmt::{self, Display};
 
 /// Enum with errors that can be returned by LanguageIdentifier.
 #[derive(Debug, PartialEq)]
@@ -9,6 +9,8 @@ pub enum LanguageIdentifierError {
     Unknown,
     /// A parser error.
     ParserError(ParserError),
+    /// The identifier can't be packed into a `PackedLanguageIdentifier`.
+    PackError(PackError),
 }
 
 impl From<ParserError> for LanguageIdentifierError {
@@ -17,13 +19,21 @@ impl From<ParserError> for LanguageIdentifierError {
     }
 }
 
-impl Error for LanguageIdentifierError {}
+impl From<PackError> for LanguageIdentifierError {
+    fn from(error: PackError) -> LanguageIdentifierError {
+        LanguageIdentifierError::PackError(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LanguageIdentifierError {}
 
 impl Display for LanguageIdentifierError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             LanguageIdentifierError::Unknown => write!(f, "Unknown error"),
             LanguageIdentifierError::ParserError(p) => write!(f, "Parser error: {}", p),
+            LanguageIdentifierError::PackError(p) => write!(f, "Pack error: {}", p),
         }
     }
 }
@@ -0,0 +1,237 @@
+//! Helpers for locating gettext message catalogs.
+//!
+//! gettext and glibc name locales as `language[_territory][.codeset][@modifier]`
+//! and look up a catalog by trying every combination of those parts, from
+//! the most to the least specific one. Scripts and some variants are
+//! expressed as modifiers, for example `sr-Latn-RS` is `sr_RS@latin`.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::LanguageIdentifier;
+//! use unic_langid_impl::gettext::candidates;
+//!
+//! let langid: LanguageIdentifier = "sr-Latn-RS".parse().expect("Parsing failed.");
+//!
+//! assert_eq!(
+//!     candidates(&langid, None),
+//!     &["sr_RS@latin", "sr@latin", "sr_RS", "sr"]
+//! );
+//! ```
+use crate::parser::{ParserError, SubtagKind};
+use crate::{subtags, LanguageIdentifier, LanguageIdentifierError};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Modifiers which select a script.
+///
+/// When converting a script into a modifier the first one is used.
+static SCRIPT_MODIFIERS: &[(&str, &str)] = &[
+    ("arabic", "Arab"),
+    ("cyrillic", "Cyrl"),
+    ("devanagari", "Deva"),
+    ("hebrew", "Hebr"),
+    ("latin", "Latn"),
+    ("iqtelif", "Latn"),
+    ("shaw", "Shaw"),
+];
+
+/// Modifiers which select a variant.
+static VARIANT_MODIFIERS: &[(&str, &str)] = &[
+    ("ijekavian", "ijekavsk"),
+    ("saaho", "saaho"),
+    ("valencia", "valencia"),
+];
+
+/// Modifier which selects both the `Latn` script and the `ijekavsk` variant.
+pub const IJEKAVIAN_LATIN_MODIFIER: &str = "ijekavianlatin";
+
+const HEADER_NAME: &str = "Language:";
+
+/// Returns the modifier which expresses a script, such as `latin` for `Latn`.
+pub fn script_modifier(script: subtags::Script) -> Option<&'static str> {
+    SCRIPT_MODIFIERS
+        .iter()
+        .find(|(_, s)| script == *s)
+        .map(|(m, _)| *m)
+}
+
+/// Returns the script selected by a modifier, such as `Cyrl` for `cyrillic`.
+pub fn modifier_script(modifier: &str) -> Option<subtags::Script> {
+    SCRIPT_MODIFIERS
+        .iter()
+        .find(|(m, _)| m.eq_ignore_ascii_case(modifier))
+        // Table values are well-formed, so parsing them can't fail.
+        .map(|(_, s)| s.parse().unwrap())
+}
+
+/// Returns the modifier which expresses a variant, such as `ijekavian`
+/// for `ijekavsk`.
+pub fn variant_modifier(variant: subtags::Variant) -> Option<&'static str> {
+    VARIANT_MODIFIERS
+        .iter()
+        .find(|(_, v)| variant == *v)
+        .map(|(m, _)| *m)
+}
+
+/// Returns the variant selected by a modifier, such as `ijekavsk`
+/// for `ijekavian`.
+pub fn modifier_variant(modifier: &str) -> Option<subtags::Variant> {
+    VARIANT_MODIFIERS
+        .iter()
+        .find(|(m, _)| m.eq_ignore_ascii_case(modifier))
+        .map(|(_, v)| v.parse().unwrap())
+}
+
+/// Normalizes a codeset the way glibc does, so that `UTF-8` becomes `utf8`
+/// and `8859-1` becomes `iso88591`.
+fn normalize_codeset(codeset: &str) -> String {
+    let normalized = codeset
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<String>();
+    if normalized.chars().all(|c| c.is_ascii_digit()) {
+        format!("iso{}", normalized)
+    } else {
+        normalized
+    }
+}
+
+/// Returns the gettext catalog names to look up for a `LanguageIdentifier`,
+/// from the most to the least specific one.
+///
+/// The order follows glibc, which drops the codeset before the territory
+/// and the territory before the modifier. With a `codeset`, both its given
+/// and its normalized form are tried.
+///
+/// The script, or failing that the first variant which has a modifier,
+/// is expressed as the modifier. Other scripts and variants are dropped.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::gettext::candidates;
+///
+/// let langid: LanguageIdentifier = "de-AT".parse().expect("Parsing failed.");
+///
+/// assert_eq!(
+///     candidates(&langid, Some("UTF-8")),
+///     &["de_AT.UTF-8", "de_AT.utf8", "de_AT", "de.UTF-8", "de.utf8", "de"]
+/// );
+/// ```
+pub fn candidates(langid: &LanguageIdentifier, codeset: Option<&str>) -> Vec<String> {
+    let territory = langid.region.map(|r| format!("_{}", r));
+    let ijekavian_latin =
+        langid.script.is_some_and(|s| s == "Latn") && langid.variants().any(|v| *v == "ijekavsk");
+    let modifier = if ijekavian_latin {
+        Some(IJEKAVIAN_LATIN_MODIFIER)
+    } else {
+        langid
+            .script
+            .and_then(script_modifier)
+            .or_else(|| langid.variants().find_map(|v| variant_modifier(*v)))
+    };
+    let modifier = modifier.map(|m| format!("@{}", m));
+
+    let mut codesets = vec![];
+    if let Some(codeset) = codeset {
+        codesets.push(format!(".{}", codeset));
+        let normalized = format!(".{}", normalize_codeset(codeset));
+        if normalized != codesets[0] {
+            codesets.push(normalized);
+        }
+    }
+
+    let mut result = vec![];
+    for modifier in [modifier.as_deref(), None] {
+        for territory in [territory.as_deref(), None] {
+            let codesets = codesets.iter().map(|c| Some(c.as_str())).chain([None]);
+            for codeset in codesets {
+                let candidate = format!(
+                    "{}{}{}{}",
+                    langid.language,
+                    territory.unwrap_or_default(),
+                    codeset.unwrap_or_default(),
+                    modifier.unwrap_or_default()
+                );
+                if !result.contains(&candidate) {
+                    result.push(candidate);
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Parses the value of a gettext `Language:` header into a `LanguageIdentifier`.
+///
+/// The whole header line is accepted as well. An empty value, as found
+/// in catalog templates, is mapped to `und`. A codeset is ignored and
+/// a modifier is mapped to a script or a variant.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::gettext::parse_language_header;
+///
+/// let langid = parse_language_header("sr_RS@latin").expect("Parsing failed.");
+/// assert_eq!(langid.to_string(), "sr-Latn-RS");
+///
+/// let langid = parse_language_header("Language: pt_BR\n").expect("Parsing failed.");
+/// assert_eq!(langid.to_string(), "pt-BR");
+/// ```
+pub fn parse_language_header(header: &str) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+    // The offset of `value` in `header`, for error spans.
+    let mut offset = header.len() - header.trim_start().len();
+    let mut value = header.trim();
+    if let Some(name) = value.get(..HEADER_NAME.len()) {
+        if name.eq_ignore_ascii_case(HEADER_NAME) {
+            let rest = &value[HEADER_NAME.len()..];
+            offset += HEADER_NAME.len() + rest.len() - rest.trim_start().len();
+            value = rest.trim();
+        }
+    }
+
+    if value.is_empty() {
+        return Ok(LanguageIdentifier::default());
+    }
+
+    let (name, modifier) = match value.split_once('@') {
+        Some((name, modifier)) => (name, Some(modifier)),
+        None => (value, None),
+    };
+    let base = name.split('.').next().unwrap_or(name);
+
+    let mut langid = LanguageIdentifier::from_bytes(base.as_bytes())?;
+
+    if let Some(modifier) = modifier {
+        let variant = if modifier.eq_ignore_ascii_case(IJEKAVIAN_LATIN_MODIFIER) {
+            langid.script = modifier_script("latin");
+            modifier_variant("ijekavian")
+        } else if let Some(script) = modifier_script(modifier) {
+            langid.script = Some(script);
+            None
+        } else {
+            match modifier_variant(modifier) {
+                Some(variant) => Some(variant),
+                None => Some(modifier.parse().map_err(|_| ParserError::MalformedSubtag {
+                    expected: SubtagKind::Variant,
+                    // The modifier follows the name and the `@`.
+                    offset: offset + name.len() + 1,
+                    length: modifier.len(),
+                })?),
+            }
+        };
+        if let Some(variant) = variant {
+            let mut variants = langid.variants().copied().collect::<Vec<_>>();
+            variants.push(variant);
+            langid.set_variants(&variants);
+        }
+    }
+
+    Ok(langid)
+}
@@ -0,0 +1,222 @@
+//! Subtag data from the IANA Language Subtag Registry.
+//!
+//! The registry records information CLDR doesn't lead with: the English
+//! descriptions of subtags, the dates they were added or deprecated and
+//! their preferred values, the script each language suppresses, and the
+//! prefixes each variant requires.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let mut li: LanguageIdentifier = "en-Latn-US".parse().expect("Parsing failed.");
+//! assert!(li.has_suppressed_script());
+//! assert!(li.remove_suppressed_script());
+//! assert_eq!(li, "en-US");
+//!
+//! let li: LanguageIdentifier = "sl-rozaj".parse().expect("Parsing failed.");
+//! assert!(li.has_valid_variant_prefixes());
+//!
+//! let li: LanguageIdentifier = "de-rozaj".parse().expect("Parsing failed.");
+//! assert!(!li.has_valid_variant_prefixes());
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the registry file, and
+//! can be limited to some languages, scripts and regions with its
+//! `--locales`, `--scripts` and `--regions` options. Private use ranges
+//! and grandfathered and redundant tags are left out.
+//!
+//! The bundled registry is a partial excerpt with 51 of its records,
+//! covering a few dozen subtags like `en`, `Latn`, `US` and `rozaj`, and
+//! not the full registry. Other subtags have no record, no suppressed
+//! script and no prefixes, so their variants always have a valid prefix.
+//! See `data/iana/README.md` for the subtags it covers.
+#[allow(clippy::type_complexity)]
+mod tables;
+
+use crate::subtags::{Language, Region, Script, Variant};
+use crate::LanguageIdentifier;
+
+pub use tables::FILE_DATE;
+
+/// The registry record of a subtag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Record {
+    /// The English descriptions of the subtag, the primary one first.
+    pub descriptions: &'static [&'static str],
+    /// The date the subtag was added, like `2005-10-16`.
+    pub added: &'static str,
+    /// The date the subtag was deprecated, if it was.
+    pub deprecated: Option<&'static str>,
+    /// The subtag to use instead, if any.
+    pub preferred_value: Option<&'static str>,
+}
+
+fn find<T>(table: &'static [(&'static str, T)], subtag: &str) -> Option<&'static T> {
+    table
+        .binary_search_by_key(&subtag, |(s, _)| s)
+        .ok()
+        .map(|idx| &table[idx].1)
+}
+
+fn language(subtag: &str) -> Option<(&'static Record, Option<&'static str>)> {
+    tables::LANGUAGES
+        .binary_search_by_key(&subtag, |(s, ..)| s)
+        .ok()
+        .map(|idx| {
+            let (_, record, suppress_script) = &tables::LANGUAGES[idx];
+            (record, *suppress_script)
+        })
+}
+
+fn variant(subtag: &str) -> Option<&'static (&'static str, Record, &'static [&'static str])> {
+    tables::VARIANTS
+        .binary_search_by_key(&subtag, |(s, ..)| s)
+        .ok()
+        .map(|idx| &tables::VARIANTS[idx])
+}
+
+/// Returns whether `langid` starts with the subtags of `prefix`, in the
+/// sense of RFC 5646: its language, script and region if present, and
+/// its variants in any order.
+fn has_prefix(langid: &LanguageIdentifier, prefix: &str) -> bool {
+    let prefix: LanguageIdentifier = match prefix.parse() {
+        Ok(prefix) => prefix,
+        Err(_) => return false,
+    };
+    prefix.language == langid.language
+        && prefix
+            .script
+            .is_none_or(|script| langid.script == Some(script))
+        && prefix
+            .region
+            .is_none_or(|region| langid.region == Some(region))
+        && prefix
+            .variants()
+            .all(|variant| langid.variants().any(|v| v == variant))
+}
+
+impl Language {
+    /// Returns the registry record of the language, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "iw".parse().expect("Parsing failed.");
+    /// let record = language.registry_record().expect("No record for iw.");
+    /// assert_eq!(record.descriptions, &["Hebrew"]);
+    /// assert_eq!(record.deprecated, Some("1989-01-01"));
+    /// assert_eq!(record.preferred_value, Some("he"));
+    /// ```
+    pub fn registry_record(&self) -> Option<&'static Record> {
+        language(self.as_str()).map(|(record, _)| record)
+    }
+
+    /// Returns the script the language suppresses, which is the script
+    /// nearly all of its content is written in, and should not be added
+    /// to identifiers with the language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "en".parse().expect("Parsing failed.");
+    /// assert_eq!(language.suppress_script(), Some("Latn".parse().unwrap()));
+    ///
+    /// let language: Language = "sr".parse().expect("Parsing failed.");
+    /// assert_eq!(language.suppress_script(), None);
+    /// ```
+    pub fn suppress_script(&self) -> Option<Script> {
+        language(self.as_str())
+            .and_then(|(_, script)| script)
+            .and_then(|script| script.parse().ok())
+    }
+}
+
+impl Script {
+    /// Returns the registry record of the script, if any.
+    pub fn registry_record(&self) -> Option<&'static Record> {
+        find(&tables::SCRIPTS, self.as_str())
+    }
+}
+
+impl Region {
+    /// Returns the registry record of the region, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// let region: Region = "BU".parse().expect("Parsing failed.");
+    /// let record = region.registry_record().expect("No record for BU.");
+    /// assert_eq!(record.preferred_value, Some("MM"));
+    /// ```
+    pub fn registry_record(&self) -> Option<&'static Record> {
+        find(&tables::REGIONS, self.as_str())
+    }
+}
+
+impl Variant {
+    /// Returns the registry record of the variant, if any.
+    pub fn registry_record(&self) -> Option<&'static Record> {
+        variant(self.as_str()).map(|(_, record, _)| record)
+    }
+
+    /// Returns the prefixes the variant is suitable for, like `sl` for
+    /// `rozaj`. Variants without prefixes, or unknown, return none.
+    pub fn prefixes(&self) -> &'static [&'static str] {
+        variant(self.as_str()).map_or(&[], |(.., prefixes)| prefixes)
+    }
+
+    /// Returns whether `langid` starts with one of the prefixes of the
+    /// variant, which is always the case for variants without prefixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Variant;
+    ///
+    /// let variant: Variant = "1901".parse().expect("Parsing failed.");
+    /// assert!(variant.has_valid_prefix(&"de-CH".parse().expect("Parsing failed.")));
+    /// assert!(!variant.has_valid_prefix(&"fr".parse().expect("Parsing failed.")));
+    /// ```
+    pub fn has_valid_prefix(&self, langid: &LanguageIdentifier) -> bool {
+        let prefixes = self.prefixes();
+        prefixes.is_empty() || prefixes.iter().any(|prefix| has_prefix(langid, prefix))
+    }
+}
+
+impl LanguageIdentifier {
+    /// Returns whether every variant of the `LanguageIdentifier` has one
+    /// of its prefixes, as the registry requires.
+    pub fn has_valid_variant_prefixes(&self) -> bool {
+        self.variants()
+            .all(|variant| variant.has_valid_prefix(self))
+    }
+
+    /// Returns whether the script of the `LanguageIdentifier` is the one
+    /// its language suppresses, which should then be removed, like in
+    /// `en-Latn`.
+    pub fn has_suppressed_script(&self) -> bool {
+        self.script.is_some() && self.script == self.language.suppress_script()
+    }
+
+    /// Removes the script of the `LanguageIdentifier` if its language
+    /// suppresses it.
+    ///
+    /// Returns whether the `LanguageIdentifier` was modified.
+    pub fn remove_suppressed_script(&mut self) -> bool {
+        let suppressed = self.has_suppressed_script();
+        if suppressed {
+            self.script = None;
+        }
+        suppressed
+    }
+}
@@ -0,0 +1,67 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+use super::Record;
+
+pub static FILE_DATE: &str = "2024-03-07";
+
+#[rustfmt::skip]
+pub static LANGUAGES: [(&str, Record, Option<&str>); 19] = [
+    ("ar", Record { descriptions: &["Arabic"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Arab")),
+    ("ca", Record { descriptions: &["Catalan", "Valencian"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("cmn", Record { descriptions: &["Mandarin Chinese"], added: "2009-07-29", deprecated: None, preferred_value: None }, None),
+    ("de", Record { descriptions: &["German"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("en", Record { descriptions: &["English"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("fr", Record { descriptions: &["French"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("he", Record { descriptions: &["Hebrew"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Hebr")),
+    ("hy", Record { descriptions: &["Armenian"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Armn")),
+    ("id", Record { descriptions: &["Indonesian"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("in", Record { descriptions: &["Indonesian"], added: "2005-10-16", deprecated: Some("1989-01-01"), preferred_value: Some("id") }, Some("Latn")),
+    ("iw", Record { descriptions: &["Hebrew"], added: "2005-10-16", deprecated: Some("1989-01-01"), preferred_value: Some("he") }, Some("Hebr")),
+    ("ja", Record { descriptions: &["Japanese"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Jpan")),
+    ("mo", Record { descriptions: &["Moldavian", "Moldovan"], added: "2005-10-16", deprecated: Some("2008-11-22"), preferred_value: Some("ro") }, Some("Latn")),
+    ("ro", Record { descriptions: &["Romanian", "Moldavian", "Moldovan"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("ru", Record { descriptions: &["Russian"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Cyrl")),
+    ("sl", Record { descriptions: &["Slovenian"], added: "2005-10-16", deprecated: None, preferred_value: None }, Some("Latn")),
+    ("sr", Record { descriptions: &["Serbian"], added: "2005-10-16", deprecated: None, preferred_value: None }, None),
+    ("und", Record { descriptions: &["Undetermined"], added: "2005-10-16", deprecated: None, preferred_value: None }, None),
+    ("zh", Record { descriptions: &["Chinese"], added: "2005-10-16", deprecated: None, preferred_value: None }, None),
+];
+
+#[rustfmt::skip]
+pub static SCRIPTS: [(&str, Record); 8] = [
+    ("Arab", Record { descriptions: &["Arabic"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("Armn", Record { descriptions: &["Armenian"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("Cyrl", Record { descriptions: &["Cyrillic"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("Hans", Record { descriptions: &["Han (Simplified variant)"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("Hant", Record { descriptions: &["Han (Traditional variant)"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("Hebr", Record { descriptions: &["Hebrew"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("Jpan", Record { descriptions: &["Japanese (alias for Han + Hiragana + Katakana)"], added: "2006-10-17", deprecated: None, preferred_value: None }),
+    ("Latn", Record { descriptions: &["Latin"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+];
+
+#[rustfmt::skip]
+pub static REGIONS: [(&str, Record); 9] = [
+    ("419", Record { descriptions: &["Latin America and the Caribbean"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("AT", Record { descriptions: &["Austria"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("BU", Record { descriptions: &["Burma"], added: "2005-10-16", deprecated: Some("1989-12-05"), preferred_value: Some("MM") }),
+    ("CH", Record { descriptions: &["Switzerland"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("DE", Record { descriptions: &["Germany"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("FR", Record { descriptions: &["France"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("IT", Record { descriptions: &["Italy"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("MM", Record { descriptions: &["Myanmar"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+    ("US", Record { descriptions: &["United States"], added: "2005-10-16", deprecated: None, preferred_value: None }),
+];
+
+#[rustfmt::skip]
+pub static VARIANTS: [(&str, Record, &[&str]); 10] = [
+    ("1901", Record { descriptions: &["Traditional German orthography"], added: "2005-10-16", deprecated: None, preferred_value: None }, &["de"]),
+    ("1996", Record { descriptions: &["German orthography of 1996"], added: "2005-10-16", deprecated: None, preferred_value: None }, &["de"]),
+    ("alalc97", Record { descriptions: &["ALA-LC Romanization, 1997 edition"], added: "2009-12-09", deprecated: None, preferred_value: None }, &[]),
+    ("arevela", Record { descriptions: &["Eastern Armenian"], added: "2006-09-18", deprecated: Some("2018-03-24"), preferred_value: None }, &["hy"]),
+    ("biske", Record { descriptions: &["The San Giorgio dialect of Resian", "The Bila dialect of Resian"], added: "2005-10-16", deprecated: None, preferred_value: None }, &["sl-rozaj"]),
+    ("fonipa", Record { descriptions: &["International Phonetic Alphabet"], added: "2006-12-11", deprecated: None, preferred_value: None }, &[]),
+    ("hepburn", Record { descriptions: &["Hepburn romanization"], added: "2009-10-01", deprecated: None, preferred_value: None }, &["ja-Latn"]),
+    ("heploc", Record { descriptions: &["Hepburn romanization, Library of Congress method"], added: "2009-10-01", deprecated: Some("2010-02-07"), preferred_value: Some("alalc97") }, &["ja-Latn-hepburn"]),
+    ("rozaj", Record { descriptions: &["Resian", "Resianic", "Rezijan"], added: "2005-10-16", deprecated: None, preferred_value: None }, &["sl"]),
+    ("valencia", Record { descriptions: &["Valencian"], added: "2007-03-06", deprecated: None, preferred_value: None }, &["ca"]),
+];
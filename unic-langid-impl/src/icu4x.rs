@@ -0,0 +1,121 @@
+//! Conversions between `LanguageIdentifier` and the ICU4X
+//! `icu_locale_core::LanguageIdentifier`.
+//!
+//! Subtags of both types are backed by `tinystr`, so they are copied
+//! in their raw form without a round-trip through a string.
+//!
+//! ICU4X only supports language subtags of two or three letters, so
+//! converting an identifier with a longer language subtag fails.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let langid: LanguageIdentifier = "sr-Latn-RS".parse().expect("Parsing failed.");
+//!
+//! let icu_langid = icu_locale_core::LanguageIdentifier::try_from(&langid)
+//!     .expect("Conversion failed.");
+//! assert_eq!(icu_langid.to_string(), "sr-Latn-RS");
+//!
+//! assert_eq!(LanguageIdentifier::from(icu_langid), langid);
+//! ```
+use crate::parser::ParserError;
+use crate::{subtags, LanguageIdentifier, LanguageIdentifierError};
+use alloc::vec::Vec;
+use icu_locale_core as icu;
+
+fn pad<const N: usize, const M: usize>(raw: [u8; N]) -> [u8; M] {
+    let mut result = [0; M];
+    result[..N].copy_from_slice(&raw);
+    result
+}
+
+fn truncate<const M: usize>(raw: &[u8]) -> Option<[u8; M]> {
+    if raw[M..].iter().any(|b| *b != 0) {
+        return None;
+    }
+    let mut result = [0; M];
+    result.copy_from_slice(&raw[..M]);
+    Some(result)
+}
+
+// ICU4X subtags are normalized the same way as ours, so their raw
+// form is a valid raw form of the matching subtag.
+impl From<&icu::LanguageIdentifier> for LanguageIdentifier {
+    fn from(input: &icu::LanguageIdentifier) -> Self {
+        let language = if input.language == icu::subtags::Language::UNKNOWN {
+            subtags::Language::default()
+        } else {
+            let raw: [u8; 8] = pad(input.language.into_raw());
+            unsafe { subtags::Language::from_raw_unchecked(u64::from_le_bytes(raw)) }
+        };
+        let script = input.script.map(|s| unsafe {
+            subtags::Script::from_raw_unchecked(u32::from_le_bytes(s.into_raw()))
+        });
+        let region = input.region.map(|r| unsafe {
+            subtags::Region::from_raw_unchecked(u32::from_le_bytes(pad(r.into_raw())))
+        });
+        let variants = input
+            .variants
+            .iter()
+            .map(|v| unsafe {
+                subtags::Variant::from_raw_unchecked(u64::from_le_bytes(v.into_raw()))
+            })
+            .collect::<Vec<_>>();
+
+        Self::from_parts(language, script, region, &variants)
+    }
+}
+
+impl From<icu::LanguageIdentifier> for LanguageIdentifier {
+    fn from(input: icu::LanguageIdentifier) -> Self {
+        Self::from(&input)
+    }
+}
+
+impl TryFrom<&LanguageIdentifier> for icu::LanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(input: &LanguageIdentifier) -> Result<Self, Self::Error> {
+        let language = match Option::<u64>::from(input.language) {
+            Some(raw) => truncate::<3>(&raw.to_le_bytes())
+                .and_then(|raw| icu::subtags::Language::try_from_raw(raw).ok())
+                .ok_or(ParserError::InvalidLanguage)?,
+            None => icu::subtags::Language::UNKNOWN,
+        };
+        let script = input
+            .script
+            .map(|s| icu::subtags::Script::try_from_raw(u32::from(s).to_le_bytes()))
+            .transpose()
+            .map_err(|_| ParserError::InvalidSubtag)?;
+        let region = input
+            .region
+            .map(|r| {
+                truncate::<3>(&u32::from(r).to_le_bytes())
+                    .and_then(|raw| icu::subtags::Region::try_from_raw(raw).ok())
+            })
+            .map(|r| r.ok_or(ParserError::InvalidSubtag))
+            .transpose()?;
+        let variants = input
+            .variants()
+            .map(|v| icu::subtags::Variant::try_from_raw(u64::from(v).to_le_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParserError::InvalidSubtag)?;
+
+        Ok(icu::LanguageIdentifier {
+            language,
+            script,
+            region,
+            variants: icu::subtags::Variants::from_vec_unchecked(variants),
+        })
+    }
+}
+
+impl TryFrom<LanguageIdentifier> for icu::LanguageIdentifier {
+    type Error = LanguageIdentifierError;
+
+    fn try_from(input: LanguageIdentifier) -> Result<Self, Self::Error> {
+        Self::try_from(&input)
+    }
+}
@@ -0,0 +1,194 @@
+//! Conversions between the codes of a language in the parts of ISO 639.
+//!
+//! [`Language`] holds the Unicode canonical code of a language, which is
+//! its ISO 639-1 code if it has one and its ISO 639-3 code otherwise.
+//! This module adds conversions to the other codes of the language, such
+//! as the ISO 639-2/B codes used by library catalogs, and
+//! [`Language::from_iso639`] to parse any of them.
+//!
+//! It also relates macrolanguages, like `zh`, to the individual languages
+//! they encompass, like `cmn` and `yue`, which
+//! [`MatchOptions::macrolanguages`](crate::MatchOptions::macrolanguages)
+//! uses to match them.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::subtags::Language;
+//!
+//! let language = Language::from_iso639("ger").expect("Parsing failed.");
+//! assert_eq!(language, "de");
+//! assert_eq!(language.to_iso639_1(), Some("de"));
+//! assert_eq!(language.to_iso639_2t(), Some("deu"));
+//! assert_eq!(language.to_iso639_2b(), Some("ger"));
+//! assert_eq!(language.to_iso639_3(), Some("deu"));
+//!
+//! let language: Language = "cmn".parse().expect("Parsing failed.");
+//! assert_eq!(language.macrolanguage(), Some("zh".parse().unwrap()));
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the ISO 639-3 code
+//! tables published by SIL, which also list the ISO 639-1 and ISO 639-2
+//! codes maintained by the Library of Congress, and can be limited to some
+//! languages with its `--locales` option. Codes missing from the tables
+//! are left as they are.
+//!
+//! The bundled code table lists all of the ISO 639-3 codes. The
+//! macrolanguage table combines the SIL mappings bundled for the `ar`,
+//! `fa`, `ms`, `no`, `sq` and `zh` macrolanguages with the individual
+//! languages CLDR aliases to their macrolanguage, like `swh` to `sw`, so
+//! the other macrolanguages are only related to some of their individual
+//! languages.
+#[allow(clippy::type_complexity)]
+mod tables;
+
+use crate::parser::errors::ParserError;
+use crate::subtags::Language;
+use alloc::vec::Vec;
+
+type Codes = (
+    &'static str,
+    Option<&'static str>,
+    Option<&'static str>,
+    Option<&'static str>,
+    &'static str,
+);
+
+fn canonical(code: &str) -> &str {
+    tables::ALIASES
+        .binary_search_by_key(&code, |(alias, _)| alias)
+        .map_or(code, |idx| tables::ALIASES[idx].1)
+}
+
+fn language(code: &str) -> Language {
+    Language::from_bytes(code.as_bytes()).expect("Tables only hold valid codes.")
+}
+
+/// Returns whether one of the languages is the macrolanguage of the
+/// other.
+pub(crate) fn encompasses(language1: &Language, language2: &Language) -> bool {
+    language1.macrolanguage().as_ref() == Some(language2)
+        || language2.macrolanguage().as_ref() == Some(language1)
+}
+
+fn codes(language: &Language) -> Option<&'static Codes> {
+    let code = canonical(language.as_str());
+    tables::CODES
+        .binary_search_by_key(&code, |(canonical, ..)| canonical)
+        .ok()
+        .map(|idx| &tables::CODES[idx])
+}
+
+impl Language {
+    /// Parses an ISO 639-1, ISO 639-2/T, ISO 639-2/B or ISO 639-3 code,
+    /// normalizing it to the Unicode canonical code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// for code in ["fr", "fra", "fre", "FRE"] {
+    ///     assert_eq!(Language::from_iso639(code), Ok("fr".parse().unwrap()));
+    /// }
+    /// assert_eq!(Language::from_iso639("cmn"), Ok("cmn".parse().unwrap()));
+    /// assert!(Language::from_iso639("x").is_err());
+    /// ```
+    pub fn from_iso639(code: &str) -> Result<Self, ParserError> {
+        let language = Self::from_bytes(code.as_bytes())?;
+        match codes(&language) {
+            Some((canonical, ..)) => Self::from_bytes(canonical.as_bytes()),
+            None => Ok(language),
+        }
+    }
+
+    /// Returns the ISO 639-1 code of the language, if it has one.
+    pub fn to_iso639_1(&self) -> Option<&'static str> {
+        codes(self).and_then(|codes| codes.1)
+    }
+
+    /// Returns the ISO 639-2/T code of the language, if it has one.
+    pub fn to_iso639_2t(&self) -> Option<&'static str> {
+        codes(self).and_then(|codes| codes.2)
+    }
+
+    /// Returns the ISO 639-2/B code of the language, if it has one.
+    ///
+    /// It differs from the ISO 639-2/T code for some twenty languages,
+    /// such as `ger` for German.
+    pub fn to_iso639_2b(&self) -> Option<&'static str> {
+        codes(self).and_then(|codes| codes.3)
+    }
+
+    /// Returns the ISO 639-3 code of the language, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "zh".parse().expect("Parsing failed.");
+    /// assert_eq!(language.to_iso639_3(), Some("zho"));
+    ///
+    /// let language: Language = "cmn".parse().expect("Parsing failed.");
+    /// assert_eq!(language.to_iso639_1(), None);
+    /// assert_eq!(language.to_iso639_3(), Some("cmn"));
+    /// ```
+    pub fn to_iso639_3(&self) -> Option<&'static str> {
+        codes(self).map(|codes| codes.4)
+    }
+
+    /// Returns the macrolanguage encompassing the language, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "arb".parse().expect("Parsing failed.");
+    /// assert_eq!(language.macrolanguage(), Some("ar".parse().unwrap()));
+    ///
+    /// let language: Language = "ar".parse().expect("Parsing failed.");
+    /// assert_eq!(language.macrolanguage(), None);
+    /// ```
+    pub fn macrolanguage(&self) -> Option<Language> {
+        let code = canonical(self.as_str());
+        tables::MACROLANGUAGES
+            .binary_search_by_key(&code, |(language, _)| language)
+            .ok()
+            .map(|idx| language(tables::MACROLANGUAGES[idx].1))
+    }
+
+    /// Returns the individual languages the macrolanguage encompasses,
+    /// which is empty for other languages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "no".parse().expect("Parsing failed.");
+    /// assert_eq!(
+    ///     language.encompassed_languages(),
+    ///     vec!["nb".parse::<Language>().unwrap(), "nn".parse().unwrap()]
+    /// );
+    /// ```
+    pub fn encompassed_languages(&self) -> Vec<Language> {
+        let code = canonical(self.as_str());
+        tables::MACROLANGUAGES
+            .iter()
+            .filter(|(_, macrolanguage)| *macrolanguage == code)
+            .map(|(code, _)| language(code))
+            .collect()
+    }
+
+    /// Returns whether the language is a macrolanguage, like `zh`.
+    pub fn is_macrolanguage(&self) -> bool {
+        let code = canonical(self.as_str());
+        tables::MACROLANGUAGES
+            .iter()
+            .any(|(_, macrolanguage)| *macrolanguage == code)
+    }
+}
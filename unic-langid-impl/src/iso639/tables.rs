@@ -0,0 +1,8208 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+#[rustfmt::skip]
+pub static CODES: [(&str, Option<&str>, Option<&str>, Option<&str>, &str); 7910] = [
+    ("aa", Some("aa"), Some("aar"), Some("aar"), "aar"),
+    ("aaa", None, None, None, "aaa"),
+    ("aab", None, None, None, "aab"),
+    ("aac", None, None, None, "aac"),
+    ("aad", None, None, None, "aad"),
+    ("aae", None, None, None, "aae"),
+    ("aaf", None, None, None, "aaf"),
+    ("aag", None, None, None, "aag"),
+    ("aah", None, None, None, "aah"),
+    ("aai", None, None, None, "aai"),
+    ("aak", None, None, None, "aak"),
+    ("aal", None, None, None, "aal"),
+    ("aan", None, None, None, "aan"),
+    ("aao", None, None, None, "aao"),
+    ("aap", None, None, None, "aap"),
+    ("aaq", None, None, None, "aaq"),
+    ("aas", None, None, None, "aas"),
+    ("aat", None, None, None, "aat"),
+    ("aau", None, None, None, "aau"),
+    ("aaw", None, None, None, "aaw"),
+    ("aax", None, None, None, "aax"),
+    ("aaz", None, None, None, "aaz"),
+    ("ab", Some("ab"), Some("abk"), Some("abk"), "abk"),
+    ("aba", None, None, None, "aba"),
+    ("abb", None, None, None, "abb"),
+    ("abc", None, None, None, "abc"),
+    ("abd", None, None, None, "abd"),
+    ("abe", None, None, None, "abe"),
+    ("abf", None, None, None, "abf"),
+    ("abg", None, None, None, "abg"),
+    ("abh", None, None, None, "abh"),
+    ("abi", None, None, None, "abi"),
+    ("abj", None, None, None, "abj"),
+    ("abl", None, None, None, "abl"),
+    ("abm", None, None, None, "abm"),
+    ("abn", None, None, None, "abn"),
+    ("abo", None, None, None, "abo"),
+    ("abp", None, None, None, "abp"),
+    ("abq", None, None, None, "abq"),
+    ("abr", None, None, None, "abr"),
+    ("abs", None, None, None, "abs"),
+    ("abt", None, None, None, "abt"),
+    ("abu", None, None, None, "abu"),
+    ("abv", None, None, None, "abv"),
+    ("abw", None, None, None, "abw"),
+    ("abx", None, None, None, "abx"),
+    ("aby", None, None, None, "aby"),
+    ("abz", None, None, None, "abz"),
+    ("aca", None, None, None, "aca"),
+    ("acb", None, None, None, "acb"),
+    ("acd", None, None, None, "acd"),
+    ("ace", None, Some("ace"), Some("ace"), "ace"),
+    ("acf", None, None, None, "acf"),
+    ("ach", None, Some("ach"), Some("ach"), "ach"),
+    ("aci", None, None, None, "aci"),
+    ("ack", None, None, None, "ack"),
+    ("acl", None, None, None, "acl"),
+    ("acm", None, None, None, "acm"),
+    ("acn", None, None, None, "acn"),
+    ("acp", None, None, None, "acp"),
+    ("acq", None, None, None, "acq"),
+    ("acr", None, None, None, "acr"),
+    ("acs", None, None, None, "acs"),
+    ("act", None, None, None, "act"),
+    ("acu", None, None, None, "acu"),
+    ("acv", None, None, None, "acv"),
+    ("acw", None, None, None, "acw"),
+    ("acx", None, None, None, "acx"),
+    ("acy", None, None, None, "acy"),
+    ("acz", None, None, None, "acz"),
+    ("ada", None, Some("ada"), Some("ada"), "ada"),
+    ("adb", None, None, None, "adb"),
+    ("add", None, None, None, "add"),
+    ("ade", None, None, None, "ade"),
+    ("adf", None, None, None, "adf"),
+    ("adg", None, None, None, "adg"),
+    ("adh", None, None, None, "adh"),
+    ("adi", None, None, None, "adi"),
+    ("adj", None, None, None, "adj"),
+    ("adl", None, None, None, "adl"),
+    ("adn", None, None, None, "adn"),
+    ("ado", None, None, None, "ado"),
+    ("adq", None, None, None, "adq"),
+    ("adr", None, None, None, "adr"),
+    ("ads", None, None, None, "ads"),
+    ("adt", None, None, None, "adt"),
+    ("adu", None, None, None, "adu"),
+    ("adw", None, None, None, "adw"),
+    ("adx", None, None, None, "adx"),
+    ("ady", None, Some("ady"), Some("ady"), "ady"),
+    ("adz", None, None, None, "adz"),
+    ("ae", Some("ae"), Some("ave"), Some("ave"), "ave"),
+    ("aea", None, None, None, "aea"),
+    ("aeb", None, None, None, "aeb"),
+    ("aec", None, None, None, "aec"),
+    ("aed", None, None, None, "aed"),
+    ("aee", None, None, None, "aee"),
+    ("aek", None, None, None, "aek"),
+    ("ael", None, None, None, "ael"),
+    ("aem", None, None, None, "aem"),
+    ("aen", None, None, None, "aen"),
+    ("aeq", None, None, None, "aeq"),
+    ("aer", None, None, None, "aer"),
+    ("aes", None, None, None, "aes"),
+    ("aeu", None, None, None, "aeu"),
+    ("aew", None, None, None, "aew"),
+    ("aey", None, None, None, "aey"),
+    ("aez", None, None, None, "aez"),
+    ("af", Some("af"), Some("afr"), Some("afr"), "afr"),
+    ("afb", None, None, None, "afb"),
+    ("afd", None, None, None, "afd"),
+    ("afe", None, None, None, "afe"),
+    ("afg", None, None, None, "afg"),
+    ("afh", None, Some("afh"), Some("afh"), "afh"),
+    ("afi", None, None, None, "afi"),
+    ("afk", None, None, None, "afk"),
+    ("afn", None, None, None, "afn"),
+    ("afo", None, None, None, "afo"),
+    ("afp", None, None, None, "afp"),
+    ("afs", None, None, None, "afs"),
+    ("aft", None, None, None, "aft"),
+    ("afu", None, None, None, "afu"),
+    ("afz", None, None, None, "afz"),
+    ("aga", None, None, None, "aga"),
+    ("agb", None, None, None, "agb"),
+    ("agc", None, None, None, "agc"),
+    ("agd", None, None, None, "agd"),
+    ("age", None, None, None, "age"),
+    ("agf", None, None, None, "agf"),
+    ("agg", None, None, None, "agg"),
+    ("agh", None, None, None, "agh"),
+    ("agi", None, None, None, "agi"),
+    ("agj", None, None, None, "agj"),
+    ("agk", None, None, None, "agk"),
+    ("agl", None, None, None, "agl"),
+    ("agm", None, None, None, "agm"),
+    ("agn", None, None, None, "agn"),
+    ("ago", None, None, None, "ago"),
+    ("agq", None, None, None, "agq"),
+    ("agr", None, None, None, "agr"),
+    ("ags", None, None, None, "ags"),
+    ("agt", None, None, None, "agt"),
+    ("agu", None, None, None, "agu"),
+    ("agv", None, None, None, "agv"),
+    ("agw", None, None, None, "agw"),
+    ("agx", None, None, None, "agx"),
+    ("agy", None, None, None, "agy"),
+    ("agz", None, None, None, "agz"),
+    ("aha", None, None, None, "aha"),
+    ("ahb", None, None, None, "ahb"),
+    ("ahg", None, None, None, "ahg"),
+    ("ahh", None, None, None, "ahh"),
+    ("ahi", None, None, None, "ahi"),
+    ("ahk", None, None, None, "ahk"),
+    ("ahl", None, None, None, "ahl"),
+    ("ahm", None, None, None, "ahm"),
+    ("ahn", None, None, None, "ahn"),
+    ("aho", None, None, None, "aho"),
+    ("ahp", None, None, None, "ahp"),
+    ("ahr", None, None, None, "ahr"),
+    ("ahs", None, None, None, "ahs"),
+    ("aht", None, None, None, "aht"),
+    ("aia", None, None, None, "aia"),
+    ("aib", None, None, None, "aib"),
+    ("aic", None, None, None, "aic"),
+    ("aid", None, None, None, "aid"),
+    ("aie", None, None, None, "aie"),
+    ("aif", None, None, None, "aif"),
+    ("aig", None, None, None, "aig"),
+    ("aih", None, None, None, "aih"),
+    ("aii", None, None, None, "aii"),
+    ("aij", None, None, None, "aij"),
+    ("aik", None, None, None, "aik"),
+    ("ail", None, None, None, "ail"),
+    ("aim", None, None, None, "aim"),
+    ("ain", None, Some("ain"), Some("ain"), "ain"),
+    ("aio", None, None, None, "aio"),
+    ("aip", None, None, None, "aip"),
+    ("aiq", None, None, None, "aiq"),
+    ("air", None, None, None, "air"),
+    ("ait", None, None, None, "ait"),
+    ("aiw", None, None, None, "aiw"),
+    ("aix", None, None, None, "aix"),
+    ("aiy", None, None, None, "aiy"),
+    ("aja", None, None, None, "aja"),
+    ("ajg", None, None, None, "ajg"),
+    ("aji", None, None, None, "aji"),
+    ("ajn", None, None, None, "ajn"),
+    ("ajp", None, None, None, "ajp"),
+    ("ajs", None, None, None, "ajs"),
+    ("aju", None, None, None, "aju"),
+    ("ajw", None, None, None, "ajw"),
+    ("ajz", None, None, None, "ajz"),
+    ("ak", Some("ak"), Some("aka"), Some("aka"), "aka"),
+    ("akb", None, None, None, "akb"),
+    ("akc", None, None, None, "akc"),
+    ("akd", None, None, None, "akd"),
+    ("ake", None, None, None, "ake"),
+    ("akf", None, None, None, "akf"),
+    ("akg", None, None, None, "akg"),
+    ("akh", None, None, None, "akh"),
+    ("aki", None, None, None, "aki"),
+    ("akj", None, None, None, "akj"),
+    ("akk", None, Some("akk"), Some("akk"), "akk"),
+    ("akl", None, None, None, "akl"),
+    ("akm", None, None, None, "akm"),
+    ("ako", None, None, None, "ako"),
+    ("akp", None, None, None, "akp"),
+    ("akq", None, None, None, "akq"),
+    ("akr", None, None, None, "akr"),
+    ("aks", None, None, None, "aks"),
+    ("akt", None, None, None, "akt"),
+    ("aku", None, None, None, "aku"),
+    ("akv", None, None, None, "akv"),
+    ("akw", None, None, None, "akw"),
+    ("akx", None, None, None, "akx"),
+    ("aky", None, None, None, "aky"),
+    ("akz", None, None, None, "akz"),
+    ("ala", None, None, None, "ala"),
+    ("alc", None, None, None, "alc"),
+    ("ald", None, None, None, "ald"),
+    ("ale", None, Some("ale"), Some("ale"), "ale"),
+    ("alf", None, None, None, "alf"),
+    ("alh", None, None, None, "alh"),
+    ("ali", None, None, None, "ali"),
+    ("alj", None, None, None, "alj"),
+    ("alk", None, None, None, "alk"),
+    ("all", None, None, None, "all"),
+    ("alm", None, None, None, "alm"),
+    ("aln", None, None, None, "aln"),
+    ("alo", None, None, None, "alo"),
+    ("alp", None, None, None, "alp"),
+    ("alq", None, None, None, "alq"),
+    ("alr", None, None, None, "alr"),
+    ("als", None, None, None, "als"),
+    ("alt", None, Some("alt"), Some("alt"), "alt"),
+    ("alu", None, None, None, "alu"),
+    ("alw", None, None, None, "alw"),
+    ("alx", None, None, None, "alx"),
+    ("aly", None, None, None, "aly"),
+    ("alz", None, None, None, "alz"),
+    ("am", Some("am"), Some("amh"), Some("amh"), "amh"),
+    ("ama", None, None, None, "ama"),
+    ("amb", None, None, None, "amb"),
+    ("amc", None, None, None, "amc"),
+    ("ame", None, None, None, "ame"),
+    ("amf", None, None, None, "amf"),
+    ("amg", None, None, None, "amg"),
+    ("ami", None, None, None, "ami"),
+    ("amj", None, None, None, "amj"),
+    ("amk", None, None, None, "amk"),
+    ("aml", None, None, None, "aml"),
+    ("amm", None, None, None, "amm"),
+    ("amn", None, None, None, "amn"),
+    ("amo", None, None, None, "amo"),
+    ("amp", None, None, None, "amp"),
+    ("amq", None, None, None, "amq"),
+    ("amr", None, None, None, "amr"),
+    ("ams", None, None, None, "ams"),
+    ("amt", None, None, None, "amt"),
+    ("amu", None, None, None, "amu"),
+    ("amv", None, None, None, "amv"),
+    ("amw", None, None, None, "amw"),
+    ("amx", None, None, None, "amx"),
+    ("amy", None, None, None, "amy"),
+    ("amz", None, None, None, "amz"),
+    ("an", Some("an"), Some("arg"), Some("arg"), "arg"),
+    ("ana", None, None, None, "ana"),
+    ("anb", None, None, None, "anb"),
+    ("anc", None, None, None, "anc"),
+    ("and", None, None, None, "and"),
+    ("ane", None, None, None, "ane"),
+    ("anf", None, None, None, "anf"),
+    ("ang", None, Some("ang"), Some("ang"), "ang"),
+    ("anh", None, None, None, "anh"),
+    ("ani", None, None, None, "ani"),
+    ("anj", None, None, None, "anj"),
+    ("ank", None, None, None, "ank"),
+    ("anl", None, None, None, "anl"),
+    ("anm", None, None, None, "anm"),
+    ("ann", None, None, None, "ann"),
+    ("ano", None, None, None, "ano"),
+    ("anp", None, Some("anp"), Some("anp"), "anp"),
+    ("anq", None, None, None, "anq"),
+    ("anr", None, None, None, "anr"),
+    ("ans", None, None, None, "ans"),
+    ("ant", None, None, None, "ant"),
+    ("anu", None, None, None, "anu"),
+    ("anv", None, None, None, "anv"),
+    ("anw", None, None, None, "anw"),
+    ("anx", None, None, None, "anx"),
+    ("any", None, None, None, "any"),
+    ("anz", None, None, None, "anz"),
+    ("aoa", None, None, None, "aoa"),
+    ("aob", None, None, None, "aob"),
+    ("aoc", None, None, None, "aoc"),
+    ("aod", None, None, None, "aod"),
+    ("aoe", None, None, None, "aoe"),
+    ("aof", None, None, None, "aof"),
+    ("aog", None, None, None, "aog"),
+    ("aoi", None, None, None, "aoi"),
+    ("aoj", None, None, None, "aoj"),
+    ("aok", None, None, None, "aok"),
+    ("aol", None, None, None, "aol"),
+    ("aom", None, None, None, "aom"),
+    ("aon", None, None, None, "aon"),
+    ("aor", None, None, None, "aor"),
+    ("aos", None, None, None, "aos"),
+    ("aot", None, None, None, "aot"),
+    ("aou", None, None, None, "aou"),
+    ("aox", None, None, None, "aox"),
+    ("aoz", None, None, None, "aoz"),
+    ("apb", None, None, None, "apb"),
+    ("apc", None, None, None, "apc"),
+    ("apd", None, None, None, "apd"),
+    ("ape", None, None, None, "ape"),
+    ("apf", None, None, None, "apf"),
+    ("apg", None, None, None, "apg"),
+    ("aph", None, None, None, "aph"),
+    ("api", None, None, None, "api"),
+    ("apj", None, None, None, "apj"),
+    ("apk", None, None, None, "apk"),
+    ("apl", None, None, None, "apl"),
+    ("apm", None, None, None, "apm"),
+    ("apn", None, None, None, "apn"),
+    ("apo", None, None, None, "apo"),
+    ("app", None, None, None, "app"),
+    ("apq", None, None, None, "apq"),
+    ("apr", None, None, None, "apr"),
+    ("aps", None, None, None, "aps"),
+    ("apt", None, None, None, "apt"),
+    ("apu", None, None, None, "apu"),
+    ("apv", None, None, None, "apv"),
+    ("apw", None, None, None, "apw"),
+    ("apx", None, None, None, "apx"),
+    ("apy", None, None, None, "apy"),
+    ("apz", None, None, None, "apz"),
+    ("aqc", None, None, None, "aqc"),
+    ("aqd", None, None, None, "aqd"),
+    ("aqg", None, None, None, "aqg"),
+    ("aqk", None, None, None, "aqk"),
+    ("aqm", None, None, None, "aqm"),
+    ("aqn", None, None, None, "aqn"),
+    ("aqp", None, None, None, "aqp"),
+    ("aqr", None, None, None, "aqr"),
+    ("aqt", None, None, None, "aqt"),
+    ("aqz", None, None, None, "aqz"),
+    ("ar", Some("ar"), Some("ara"), Some("ara"), "ara"),
+    ("arb", None, None, None, "arb"),
+    ("arc", None, Some("arc"), Some("arc"), "arc"),
+    ("ard", None, None, None, "ard"),
+    ("are", None, None, None, "are"),
+    ("arh", None, None, None, "arh"),
+    ("ari", None, None, None, "ari"),
+    ("arj", None, None, None, "arj"),
+    ("ark", None, None, None, "ark"),
+    ("arl", None, None, None, "arl"),
+    ("arn", None, Some("arn"), Some("arn"), "arn"),
+    ("aro", None, None, None, "aro"),
+    ("arp", None, Some("arp"), Some("arp"), "arp"),
+    ("arq", None, None, None, "arq"),
+    ("arr", None, None, None, "arr"),
+    ("ars", None, None, None, "ars"),
+    ("aru", None, None, None, "aru"),
+    ("arv", None, None, None, "arv"),
+    ("arw", None, Some("arw"), Some("arw"), "arw"),
+    ("arx", None, None, None, "arx"),
+    ("ary", None, None, None, "ary"),
+    ("arz", None, None, None, "arz"),
+    ("as", Some("as"), Some("asm"), Some("asm"), "asm"),
+    ("asa", None, None, None, "asa"),
+    ("asb", None, None, None, "asb"),
+    ("asc", None, None, None, "asc"),
+    ("ase", None, None, None, "ase"),
+    ("asf", None, None, None, "asf"),
+    ("asg", None, None, None, "asg"),
+    ("ash", None, None, None, "ash"),
+    ("asi", None, None, None, "asi"),
+    ("asj", None, None, None, "asj"),
+    ("ask", None, None, None, "ask"),
+    ("asl", None, None, None, "asl"),
+    ("asn", None, None, None, "asn"),
+    ("aso", None, None, None, "aso"),
+    ("asp", None, None, None, "asp"),
+    ("asq", None, None, None, "asq"),
+    ("asr", None, None, None, "asr"),
+    ("ass", None, None, None, "ass"),
+    ("ast", None, Some("ast"), Some("ast"), "ast"),
+    ("asu", None, None, None, "asu"),
+    ("asv", None, None, None, "asv"),
+    ("asw", None, None, None, "asw"),
+    ("asx", None, None, None, "asx"),
+    ("asy", None, None, None, "asy"),
+    ("asz", None, None, None, "asz"),
+    ("ata", None, None, None, "ata"),
+    ("atb", None, None, None, "atb"),
+    ("atc", None, None, None, "atc"),
+    ("atd", None, None, None, "atd"),
+    ("ate", None, None, None, "ate"),
+    ("atg", None, None, None, "atg"),
+    ("ati", None, None, None, "ati"),
+    ("atj", None, None, None, "atj"),
+    ("atk", None, None, None, "atk"),
+    ("atl", None, None, None, "atl"),
+    ("atm", None, None, None, "atm"),
+    ("atn", None, None, None, "atn"),
+    ("ato", None, None, None, "ato"),
+    ("atp", None, None, None, "atp"),
+    ("atq", None, None, None, "atq"),
+    ("atr", None, None, None, "atr"),
+    ("ats", None, None, None, "ats"),
+    ("att", None, None, None, "att"),
+    ("atu", None, None, None, "atu"),
+    ("atv", None, None, None, "atv"),
+    ("atw", None, None, None, "atw"),
+    ("atx", None, None, None, "atx"),
+    ("aty", None, None, None, "aty"),
+    ("atz", None, None, None, "atz"),
+    ("aua", None, None, None, "aua"),
+    ("aub", None, None, None, "aub"),
+    ("auc", None, None, None, "auc"),
+    ("aud", None, None, None, "aud"),
+    ("aug", None, None, None, "aug"),
+    ("auh", None, None, None, "auh"),
+    ("aui", None, None, None, "aui"),
+    ("auj", None, None, None, "auj"),
+    ("auk", None, None, None, "auk"),
+    ("aul", None, None, None, "aul"),
+    ("aum", None, None, None, "aum"),
+    ("aun", None, None, None, "aun"),
+    ("auo", None, None, None, "auo"),
+    ("aup", None, None, None, "aup"),
+    ("auq", None, None, None, "auq"),
+    ("aur", None, None, None, "aur"),
+    ("aut", None, None, None, "aut"),
+    ("auu", None, None, None, "auu"),
+    ("auw", None, None, None, "auw"),
+    ("aux", None, None, None, "aux"),
+    ("auy", None, None, None, "auy"),
+    ("auz", None, None, None, "auz"),
+    ("av", Some("av"), Some("ava"), Some("ava"), "ava"),
+    ("avb", None, None, None, "avb"),
+    ("avd", None, None, None, "avd"),
+    ("avi", None, None, None, "avi"),
+    ("avk", None, None, None, "avk"),
+    ("avl", None, None, None, "avl"),
+    ("avm", None, None, None, "avm"),
+    ("avn", None, None, None, "avn"),
+    ("avo", None, None, None, "avo"),
+    ("avs", None, None, None, "avs"),
+    ("avt", None, None, None, "avt"),
+    ("avu", None, None, None, "avu"),
+    ("avv", None, None, None, "avv"),
+    ("awa", None, Some("awa"), Some("awa"), "awa"),
+    ("awb", None, None, None, "awb"),
+    ("awc", None, None, None, "awc"),
+    ("awe", None, None, None, "awe"),
+    ("awg", None, None, None, "awg"),
+    ("awh", None, None, None, "awh"),
+    ("awi", None, None, None, "awi"),
+    ("awk", None, None, None, "awk"),
+    ("awm", None, None, None, "awm"),
+    ("awn", None, None, None, "awn"),
+    ("awo", None, None, None, "awo"),
+    ("awr", None, None, None, "awr"),
+    ("aws", None, None, None, "aws"),
+    ("awt", None, None, None, "awt"),
+    ("awu", None, None, None, "awu"),
+    ("awv", None, None, None, "awv"),
+    ("aww", None, None, None, "aww"),
+    ("awx", None, None, None, "awx"),
+    ("awy", None, None, None, "awy"),
+    ("axb", None, None, None, "axb"),
+    ("axe", None, None, None, "axe"),
+    ("axg", None, None, None, "axg"),
+    ("axk", None, None, None, "axk"),
+    ("axl", None, None, None, "axl"),
+    ("axm", None, None, None, "axm"),
+    ("axx", None, None, None, "axx"),
+    ("ay", Some("ay"), Some("aym"), Some("aym"), "aym"),
+    ("aya", None, None, None, "aya"),
+    ("ayb", None, None, None, "ayb"),
+    ("ayc", None, None, None, "ayc"),
+    ("ayd", None, None, None, "ayd"),
+    ("aye", None, None, None, "aye"),
+    ("ayg", None, None, None, "ayg"),
+    ("ayh", None, None, None, "ayh"),
+    ("ayi", None, None, None, "ayi"),
+    ("ayk", None, None, None, "ayk"),
+    ("ayl", None, None, None, "ayl"),
+    ("ayn", None, None, None, "ayn"),
+    ("ayo", None, None, None, "ayo"),
+    ("ayp", None, None, None, "ayp"),
+    ("ayq", None, None, None, "ayq"),
+    ("ayr", None, None, None, "ayr"),
+    ("ays", None, None, None, "ays"),
+    ("ayt", None, None, None, "ayt"),
+    ("ayu", None, None, None, "ayu"),
+    ("ayz", None, None, None, "ayz"),
+    ("az", Some("az"), Some("aze"), Some("aze"), "aze"),
+    ("aza", None, None, None, "aza"),
+    ("azb", None, None, None, "azb"),
+    ("azd", None, None, None, "azd"),
+    ("azg", None, None, None, "azg"),
+    ("azj", None, None, None, "azj"),
+    ("azm", None, None, None, "azm"),
+    ("azn", None, None, None, "azn"),
+    ("azo", None, None, None, "azo"),
+    ("azt", None, None, None, "azt"),
+    ("azz", None, None, None, "azz"),
+    ("ba", Some("ba"), Some("bak"), Some("bak"), "bak"),
+    ("baa", None, None, None, "baa"),
+    ("bab", None, None, None, "bab"),
+    ("bac", None, None, None, "bac"),
+    ("bae", None, None, None, "bae"),
+    ("baf", None, None, None, "baf"),
+    ("bag", None, None, None, "bag"),
+    ("bah", None, None, None, "bah"),
+    ("baj", None, None, None, "baj"),
+    ("bal", None, Some("bal"), Some("bal"), "bal"),
+    ("ban", None, Some("ban"), Some("ban"), "ban"),
+    ("bao", None, None, None, "bao"),
+    ("bap", None, None, None, "bap"),
+    ("bar", None, None, None, "bar"),
+    ("bas", None, Some("bas"), Some("bas"), "bas"),
+    ("bau", None, None, None, "bau"),
+    ("bav", None, None, None, "bav"),
+    ("baw", None, None, None, "baw"),
+    ("bax", None, None, None, "bax"),
+    ("bay", None, None, None, "bay"),
+    ("bba", None, None, None, "bba"),
+    ("bbb", None, None, None, "bbb"),
+    ("bbc", None, None, None, "bbc"),
+    ("bbd", None, None, None, "bbd"),
+    ("bbe", None, None, None, "bbe"),
+    ("bbf", None, None, None, "bbf"),
+    ("bbg", None, None, None, "bbg"),
+    ("bbh", None, None, None, "bbh"),
+    ("bbi", None, None, None, "bbi"),
+    ("bbj", None, None, None, "bbj"),
+    ("bbk", None, None, None, "bbk"),
+    ("bbl", None, None, None, "bbl"),
+    ("bbm", None, None, None, "bbm"),
+    ("bbn", None, None, None, "bbn"),
+    ("bbo", None, None, None, "bbo"),
+    ("bbp", None, None, None, "bbp"),
+    ("bbq", None, None, None, "bbq"),
+    ("bbr", None, None, None, "bbr"),
+    ("bbs", None, None, None, "bbs"),
+    ("bbt", None, None, None, "bbt"),
+    ("bbu", None, None, None, "bbu"),
+    ("bbv", None, None, None, "bbv"),
+    ("bbw", None, None, None, "bbw"),
+    ("bbx", None, None, None, "bbx"),
+    ("bby", None, None, None, "bby"),
+    ("bca", None, None, None, "bca"),
+    ("bcb", None, None, None, "bcb"),
+    ("bcc", None, None, None, "bcc"),
+    ("bcd", None, None, None, "bcd"),
+    ("bce", None, None, None, "bce"),
+    ("bcf", None, None, None, "bcf"),
+    ("bcg", None, None, None, "bcg"),
+    ("bch", None, None, None, "bch"),
+    ("bci", None, None, None, "bci"),
+    ("bcj", None, None, None, "bcj"),
+    ("bck", None, None, None, "bck"),
+    ("bcl", None, None, None, "bcl"),
+    ("bcm", None, None, None, "bcm"),
+    ("bcn", None, None, None, "bcn"),
+    ("bco", None, None, None, "bco"),
+    ("bcp", None, None, None, "bcp"),
+    ("bcq", None, None, None, "bcq"),
+    ("bcr", None, None, None, "bcr"),
+    ("bcs", None, None, None, "bcs"),
+    ("bct", None, None, None, "bct"),
+    ("bcu", None, None, None, "bcu"),
+    ("bcv", None, None, None, "bcv"),
+    ("bcw", None, None, None, "bcw"),
+    ("bcy", None, None, None, "bcy"),
+    ("bcz", None, None, None, "bcz"),
+    ("bda", None, None, None, "bda"),
+    ("bdb", None, None, None, "bdb"),
+    ("bdc", None, None, None, "bdc"),
+    ("bdd", None, None, None, "bdd"),
+    ("bde", None, None, None, "bde"),
+    ("bdf", None, None, None, "bdf"),
+    ("bdg", None, None, None, "bdg"),
+    ("bdh", None, None, None, "bdh"),
+    ("bdi", None, None, None, "bdi"),
+    ("bdj", None, None, None, "bdj"),
+    ("bdk", None, None, None, "bdk"),
+    ("bdl", None, None, None, "bdl"),
+    ("bdm", None, None, None, "bdm"),
+    ("bdn", None, None, None, "bdn"),
+    ("bdo", None, None, None, "bdo"),
+    ("bdp", None, None, None, "bdp"),
+    ("bdq", None, None, None, "bdq"),
+    ("bdr", None, None, None, "bdr"),
+    ("bds", None, None, None, "bds"),
+    ("bdt", None, None, None, "bdt"),
+    ("bdu", None, None, None, "bdu"),
+    ("bdv", None, None, None, "bdv"),
+    ("bdw", None, None, None, "bdw"),
+    ("bdx", None, None, None, "bdx"),
+    ("bdy", None, None, None, "bdy"),
+    ("bdz", None, None, None, "bdz"),
+    ("be", Some("be"), Some("bel"), Some("bel"), "bel"),
+    ("bea", None, None, None, "bea"),
+    ("beb", None, None, None, "beb"),
+    ("bec", None, None, None, "bec"),
+    ("bed", None, None, None, "bed"),
+    ("bee", None, None, None, "bee"),
+    ("bef", None, None, None, "bef"),
+    ("beg", None, None, None, "beg"),
+    ("beh", None, None, None, "beh"),
+    ("bei", None, None, None, "bei"),
+    ("bej", None, Some("bej"), Some("bej"), "bej"),
+    ("bek", None, None, None, "bek"),
+    ("bem", None, Some("bem"), Some("bem"), "bem"),
+    ("beo", None, None, None, "beo"),
+    ("bep", None, None, None, "bep"),
+    ("beq", None, None, None, "beq"),
+    ("bes", None, None, None, "bes"),
+    ("bet", None, None, None, "bet"),
+    ("beu", None, None, None, "beu"),
+    ("bev", None, None, None, "bev"),
+    ("bew", None, None, None, "bew"),
+    ("bex", None, None, None, "bex"),
+    ("bey", None, None, None, "bey"),
+    ("bez", None, None, None, "bez"),
+    ("bfa", None, None, None, "bfa"),
+    ("bfb", None, None, None, "bfb"),
+    ("bfc", None, None, None, "bfc"),
+    ("bfd", None, None, None, "bfd"),
+    ("bfe", None, None, None, "bfe"),
+    ("bff", None, None, None, "bff"),
+    ("bfg", None, None, None, "bfg"),
+    ("bfh", None, None, None, "bfh"),
+    ("bfi", None, None, None, "bfi"),
+    ("bfj", None, None, None, "bfj"),
+    ("bfk", None, None, None, "bfk"),
+    ("bfl", None, None, None, "bfl"),
+    ("bfm", None, None, None, "bfm"),
+    ("bfn", None, None, None, "bfn"),
+    ("bfo", None, None, None, "bfo"),
+    ("bfp", None, None, None, "bfp"),
+    ("bfq", None, None, None, "bfq"),
+    ("bfr", None, None, None, "bfr"),
+    ("bfs", None, None, None, "bfs"),
+    ("bft", None, None, None, "bft"),
+    ("bfu", None, None, None, "bfu"),
+    ("bfw", None, None, None, "bfw"),
+    ("bfx", None, None, None, "bfx"),
+    ("bfy", None, None, None, "bfy"),
+    ("bfz", None, None, None, "bfz"),
+    ("bg", Some("bg"), Some("bul"), Some("bul"), "bul"),
+    ("bga", None, None, None, "bga"),
+    ("bgb", None, None, None, "bgb"),
+    ("bgc", None, None, None, "bgc"),
+    ("bgd", None, None, None, "bgd"),
+    ("bge", None, None, None, "bge"),
+    ("bgf", None, None, None, "bgf"),
+    ("bgg", None, None, None, "bgg"),
+    ("bgi", None, None, None, "bgi"),
+    ("bgj", None, None, None, "bgj"),
+    ("bgk", None, None, None, "bgk"),
+    ("bgl", None, None, None, "bgl"),
+    ("bgn", None, None, None, "bgn"),
+    ("bgo", None, None, None, "bgo"),
+    ("bgp", None, None, None, "bgp"),
+    ("bgq", None, None, None, "bgq"),
+    ("bgr", None, None, None, "bgr"),
+    ("bgs", None, None, None, "bgs"),
+    ("bgt", None, None, None, "bgt"),
+    ("bgu", None, None, None, "bgu"),
+    ("bgv", None, None, None, "bgv"),
+    ("bgw", None, None, None, "bgw"),
+    ("bgx", None, None, None, "bgx"),
+    ("bgy", None, None, None, "bgy"),
+    ("bgz", None, None, None, "bgz"),
+    ("bha", None, None, None, "bha"),
+    ("bhb", None, None, None, "bhb"),
+    ("bhc", None, None, None, "bhc"),
+    ("bhd", None, None, None, "bhd"),
+    ("bhe", None, None, None, "bhe"),
+    ("bhf", None, None, None, "bhf"),
+    ("bhg", None, None, None, "bhg"),
+    ("bhh", None, None, None, "bhh"),
+    ("bhi", None, None, None, "bhi"),
+    ("bhj", None, None, None, "bhj"),
+    ("bhl", None, None, None, "bhl"),
+    ("bhm", None, None, None, "bhm"),
+    ("bhn", None, None, None, "bhn"),
+    ("bho", None, Some("bho"), Some("bho"), "bho"),
+    ("bhp", None, None, None, "bhp"),
+    ("bhq", None, None, None, "bhq"),
+    ("bhr", None, None, None, "bhr"),
+    ("bhs", None, None, None, "bhs"),
+    ("bht", None, None, None, "bht"),
+    ("bhu", None, None, None, "bhu"),
+    ("bhv", None, None, None, "bhv"),
+    ("bhw", None, None, None, "bhw"),
+    ("bhx", None, None, None, "bhx"),
+    ("bhy", None, None, None, "bhy"),
+    ("bhz", None, None, None, "bhz"),
+    ("bi", Some("bi"), Some("bis"), Some("bis"), "bis"),
+    ("bia", None, None, None, "bia"),
+    ("bib", None, None, None, "bib"),
+    ("bid", None, None, None, "bid"),
+    ("bie", None, None, None, "bie"),
+    ("bif", None, None, None, "bif"),
+    ("big", None, None, None, "big"),
+    ("bik", None, Some("bik"), Some("bik"), "bik"),
+    ("bil", None, None, None, "bil"),
+    ("bim", None, None, None, "bim"),
+    ("bin", None, Some("bin"), Some("bin"), "bin"),
+    ("bio", None, None, None, "bio"),
+    ("bip", None, None, None, "bip"),
+    ("biq", None, None, None, "biq"),
+    ("bir", None, None, None, "bir"),
+    ("bit", None, None, None, "bit"),
+    ("biu", None, None, None, "biu"),
+    ("biv", None, None, None, "biv"),
+    ("biw", None, None, None, "biw"),
+    ("bix", None, None, None, "bix"),
+    ("biy", None, None, None, "biy"),
+    ("biz", None, None, None, "biz"),
+    ("bja", None, None, None, "bja"),
+    ("bjb", None, None, None, "bjb"),
+    ("bjc", None, None, None, "bjc"),
+    ("bje", None, None, None, "bje"),
+    ("bjf", None, None, None, "bjf"),
+    ("bjg", None, None, None, "bjg"),
+    ("bjh", None, None, None, "bjh"),
+    ("bji", None, None, None, "bji"),
+    ("bjj", None, None, None, "bjj"),
+    ("bjk", None, None, None, "bjk"),
+    ("bjl", None, None, None, "bjl"),
+    ("bjm", None, None, None, "bjm"),
+    ("bjn", None, None, None, "bjn"),
+    ("bjo", None, None, None, "bjo"),
+    ("bjp", None, None, None, "bjp"),
+    ("bjr", None, None, None, "bjr"),
+    ("bjs", None, None, None, "bjs"),
+    ("bjt", None, None, None, "bjt"),
+    ("bju", None, None, None, "bju"),
+    ("bjv", None, None, None, "bjv"),
+    ("bjw", None, None, None, "bjw"),
+    ("bjx", None, None, None, "bjx"),
+    ("bjy", None, None, None, "bjy"),
+    ("bjz", None, None, None, "bjz"),
+    ("bka", None, None, None, "bka"),
+    ("bkc", None, None, None, "bkc"),
+    ("bkd", None, None, None, "bkd"),
+    ("bkf", None, None, None, "bkf"),
+    ("bkg", None, None, None, "bkg"),
+    ("bkh", None, None, None, "bkh"),
+    ("bki", None, None, None, "bki"),
+    ("bkj", None, None, None, "bkj"),
+    ("bkk", None, None, None, "bkk"),
+    ("bkl", None, None, None, "bkl"),
+    ("bkm", None, None, None, "bkm"),
+    ("bkn", None, None, None, "bkn"),
+    ("bko", None, None, None, "bko"),
+    ("bkp", None, None, None, "bkp"),
+    ("bkq", None, None, None, "bkq"),
+    ("bkr", None, None, None, "bkr"),
+    ("bks", None, None, None, "bks"),
+    ("bkt", None, None, None, "bkt"),
+    ("bku", None, None, None, "bku"),
+    ("bkv", None, None, None, "bkv"),
+    ("bkw", None, None, None, "bkw"),
+    ("bkx", None, None, None, "bkx"),
+    ("bky", None, None, None, "bky"),
+    ("bkz", None, None, None, "bkz"),
+    ("bla", None, Some("bla"), Some("bla"), "bla"),
+    ("blb", None, None, None, "blb"),
+    ("blc", None, None, None, "blc"),
+    ("bld", None, None, None, "bld"),
+    ("ble", None, None, None, "ble"),
+    ("blf", None, None, None, "blf"),
+    ("blh", None, None, None, "blh"),
+    ("bli", None, None, None, "bli"),
+    ("blj", None, None, None, "blj"),
+    ("blk", None, None, None, "blk"),
+    ("bll", None, None, None, "bll"),
+    ("blm", None, None, None, "blm"),
+    ("bln", None, None, None, "bln"),
+    ("blo", None, None, None, "blo"),
+    ("blp", None, None, None, "blp"),
+    ("blq", None, None, None, "blq"),
+    ("blr", None, None, None, "blr"),
+    ("bls", None, None, None, "bls"),
+    ("blt", None, None, None, "blt"),
+    ("blv", None, None, None, "blv"),
+    ("blw", None, None, None, "blw"),
+    ("blx", None, None, None, "blx"),
+    ("bly", None, None, None, "bly"),
+    ("blz", None, None, None, "blz"),
+    ("bm", Some("bm"), Some("bam"), Some("bam"), "bam"),
+    ("bma", None, None, None, "bma"),
+    ("bmb", None, None, None, "bmb"),
+    ("bmc", None, None, None, "bmc"),
+    ("bmd", None, None, None, "bmd"),
+    ("bme", None, None, None, "bme"),
+    ("bmf", None, None, None, "bmf"),
+    ("bmg", None, None, None, "bmg"),
+    ("bmh", None, None, None, "bmh"),
+    ("bmi", None, None, None, "bmi"),
+    ("bmj", None, None, None, "bmj"),
+    ("bmk", None, None, None, "bmk"),
+    ("bml", None, None, None, "bml"),
+    ("bmm", None, None, None, "bmm"),
+    ("bmn", None, None, None, "bmn"),
+    ("bmo", None, None, None, "bmo"),
+    ("bmp", None, None, None, "bmp"),
+    ("bmq", None, None, None, "bmq"),
+    ("bmr", None, None, None, "bmr"),
+    ("bms", None, None, None, "bms"),
+    ("bmt", None, None, None, "bmt"),
+    ("bmu", None, None, None, "bmu"),
+    ("bmv", None, None, None, "bmv"),
+    ("bmw", None, None, None, "bmw"),
+    ("bmx", None, None, None, "bmx"),
+    ("bmz", None, None, None, "bmz"),
+    ("bn", Some("bn"), Some("ben"), Some("ben"), "ben"),
+    ("bna", None, None, None, "bna"),
+    ("bnb", None, None, None, "bnb"),
+    ("bnc", None, None, None, "bnc"),
+    ("bnd", None, None, None, "bnd"),
+    ("bne", None, None, None, "bne"),
+    ("bnf", None, None, None, "bnf"),
+    ("bng", None, None, None, "bng"),
+    ("bni", None, None, None, "bni"),
+    ("bnj", None, None, None, "bnj"),
+    ("bnk", None, None, None, "bnk"),
+    ("bnl", None, None, None, "bnl"),
+    ("bnm", None, None, None, "bnm"),
+    ("bnn", None, None, None, "bnn"),
+    ("bno", None, None, None, "bno"),
+    ("bnp", None, None, None, "bnp"),
+    ("bnq", None, None, None, "bnq"),
+    ("bnr", None, None, None, "bnr"),
+    ("bns", None, None, None, "bns"),
+    ("bnu", None, None, None, "bnu"),
+    ("bnv", None, None, None, "bnv"),
+    ("bnw", None, None, None, "bnw"),
+    ("bnx", None, None, None, "bnx"),
+    ("bny", None, None, None, "bny"),
+    ("bnz", None, None, None, "bnz"),
+    ("bo", Some("bo"), Some("bod"), Some("tib"), "bod"),
+    ("boa", None, None, None, "boa"),
+    ("bob", None, None, None, "bob"),
+    ("boe", None, None, None, "boe"),
+    ("bof", None, None, None, "bof"),
+    ("bog", None, None, None, "bog"),
+    ("boh", None, None, None, "boh"),
+    ("boi", None, None, None, "boi"),
+    ("boj", None, None, None, "boj"),
+    ("bok", None, None, None, "bok"),
+    ("bol", None, None, None, "bol"),
+    ("bom", None, None, None, "bom"),
+    ("bon", None, None, None, "bon"),
+    ("boo", None, None, None, "boo"),
+    ("bop", None, None, None, "bop"),
+    ("boq", None, None, None, "boq"),
+    ("bor", None, None, None, "bor"),
+    ("bot", None, None, None, "bot"),
+    ("bou", None, None, None, "bou"),
+    ("bov", None, None, None, "bov"),
+    ("bow", None, None, None, "bow"),
+    ("box", None, None, None, "box"),
+    ("boy", None, None, None, "boy"),
+    ("boz", None, None, None, "boz"),
+    ("bpa", None, None, None, "bpa"),
+    ("bpc", None, None, None, "bpc"),
+    ("bpd", None, None, None, "bpd"),
+    ("bpe", None, None, None, "bpe"),
+    ("bpg", None, None, None, "bpg"),
+    ("bph", None, None, None, "bph"),
+    ("bpi", None, None, None, "bpi"),
+    ("bpj", None, None, None, "bpj"),
+    ("bpk", None, None, None, "bpk"),
+    ("bpl", None, None, None, "bpl"),
+    ("bpm", None, None, None, "bpm"),
+    ("bpn", None, None, None, "bpn"),
+    ("bpo", None, None, None, "bpo"),
+    ("bpp", None, None, None, "bpp"),
+    ("bpq", None, None, None, "bpq"),
+    ("bpr", None, None, None, "bpr"),
+    ("bps", None, None, None, "bps"),
+    ("bpt", None, None, None, "bpt"),
+    ("bpu", None, None, None, "bpu"),
+    ("bpv", None, None, None, "bpv"),
+    ("bpw", None, None, None, "bpw"),
+    ("bpx", None, None, None, "bpx"),
+    ("bpy", None, None, None, "bpy"),
+    ("bpz", None, None, None, "bpz"),
+    ("bqa", None, None, None, "bqa"),
+    ("bqb", None, None, None, "bqb"),
+    ("bqc", None, None, None, "bqc"),
+    ("bqd", None, None, None, "bqd"),
+    ("bqf", None, None, None, "bqf"),
+    ("bqg", None, None, None, "bqg"),
+    ("bqh", None, None, None, "bqh"),
+    ("bqi", None, None, None, "bqi"),
+    ("bqj", None, None, None, "bqj"),
+    ("bqk", None, None, None, "bqk"),
+    ("bql", None, None, None, "bql"),
+    ("bqm", None, None, None, "bqm"),
+    ("bqn", None, None, None, "bqn"),
+    ("bqo", None, None, None, "bqo"),
+    ("bqp", None, None, None, "bqp"),
+    ("bqq", None, None, None, "bqq"),
+    ("bqr", None, None, None, "bqr"),
+    ("bqs", None, None, None, "bqs"),
+    ("bqt", None, None, None, "bqt"),
+    ("bqu", None, None, None, "bqu"),
+    ("bqv", None, None, None, "bqv"),
+    ("bqw", None, None, None, "bqw"),
+    ("bqx", None, None, None, "bqx"),
+    ("bqy", None, None, None, "bqy"),
+    ("bqz", None, None, None, "bqz"),
+    ("br", Some("br"), Some("bre"), Some("bre"), "bre"),
+    ("bra", None, Some("bra"), Some("bra"), "bra"),
+    ("brb", None, None, None, "brb"),
+    ("brc", None, None, None, "brc"),
+    ("brd", None, None, None, "brd"),
+    ("brf", None, None, None, "brf"),
+    ("brg", None, None, None, "brg"),
+    ("brh", None, None, None, "brh"),
+    ("bri", None, None, None, "bri"),
+    ("brj", None, None, None, "brj"),
+    ("brk", None, None, None, "brk"),
+    ("brl", None, None, None, "brl"),
+    ("brm", None, None, None, "brm"),
+    ("brn", None, None, None, "brn"),
+    ("bro", None, None, None, "bro"),
+    ("brp", None, None, None, "brp"),
+    ("brq", None, None, None, "brq"),
+    ("brr", None, None, None, "brr"),
+    ("brs", None, None, None, "brs"),
+    ("brt", None, None, None, "brt"),
+    ("bru", None, None, None, "bru"),
+    ("brv", None, None, None, "brv"),
+    ("brw", None, None, None, "brw"),
+    ("brx", None, None, None, "brx"),
+    ("bry", None, None, None, "bry"),
+    ("brz", None, None, None, "brz"),
+    ("bs", Some("bs"), Some("bos"), Some("bos"), "bos"),
+    ("bsa", None, None, None, "bsa"),
+    ("bsb", None, None, None, "bsb"),
+    ("bsc", None, None, None, "bsc"),
+    ("bse", None, None, None, "bse"),
+    ("bsf", None, None, None, "bsf"),
+    ("bsg", None, None, None, "bsg"),
+    ("bsh", None, None, None, "bsh"),
+    ("bsi", None, None, None, "bsi"),
+    ("bsj", None, None, None, "bsj"),
+    ("bsk", None, None, None, "bsk"),
+    ("bsl", None, None, None, "bsl"),
+    ("bsm", None, None, None, "bsm"),
+    ("bsn", None, None, None, "bsn"),
+    ("bso", None, None, None, "bso"),
+    ("bsp", None, None, None, "bsp"),
+    ("bsq", None, None, None, "bsq"),
+    ("bsr", None, None, None, "bsr"),
+    ("bss", None, None, None, "bss"),
+    ("bst", None, None, None, "bst"),
+    ("bsu", None, None, None, "bsu"),
+    ("bsv", None, None, None, "bsv"),
+    ("bsw", None, None, None, "bsw"),
+    ("bsx", None, None, None, "bsx"),
+    ("bsy", None, None, None, "bsy"),
+    ("bta", None, None, None, "bta"),
+    ("btc", None, None, None, "btc"),
+    ("btd", None, None, None, "btd"),
+    ("bte", None, None, None, "bte"),
+    ("btf", None, None, None, "btf"),
+    ("btg", None, None, None, "btg"),
+    ("bth", None, None, None, "bth"),
+    ("bti", None, None, None, "bti"),
+    ("btj", None, None, None, "btj"),
+    ("btm", None, None, None, "btm"),
+    ("btn", None, None, None, "btn"),
+    ("bto", None, None, None, "bto"),
+    ("btp", None, None, None, "btp"),
+    ("btq", None, None, None, "btq"),
+    ("btr", None, None, None, "btr"),
+    ("bts", None, None, None, "bts"),
+    ("btt", None, None, None, "btt"),
+    ("btu", None, None, None, "btu"),
+    ("btv", None, None, None, "btv"),
+    ("btw", None, None, None, "btw"),
+    ("btx", None, None, None, "btx"),
+    ("bty", None, None, None, "bty"),
+    ("btz", None, None, None, "btz"),
+    ("bua", None, Some("bua"), Some("bua"), "bua"),
+    ("bub", None, None, None, "bub"),
+    ("buc", None, None, None, "buc"),
+    ("bud", None, None, None, "bud"),
+    ("bue", None, None, None, "bue"),
+    ("buf", None, None, None, "buf"),
+    ("bug", None, Some("bug"), Some("bug"), "bug"),
+    ("buh", None, None, None, "buh"),
+    ("bui", None, None, None, "bui"),
+    ("buj", None, None, None, "buj"),
+    ("buk", None, None, None, "buk"),
+    ("bum", None, None, None, "bum"),
+    ("bun", None, None, None, "bun"),
+    ("buo", None, None, None, "buo"),
+    ("bup", None, None, None, "bup"),
+    ("buq", None, None, None, "buq"),
+    ("bus", None, None, None, "bus"),
+    ("but", None, None, None, "but"),
+    ("buu", None, None, None, "buu"),
+    ("buv", None, None, None, "buv"),
+    ("buw", None, None, None, "buw"),
+    ("bux", None, None, None, "bux"),
+    ("buy", None, None, None, "buy"),
+    ("buz", None, None, None, "buz"),
+    ("bva", None, None, None, "bva"),
+    ("bvb", None, None, None, "bvb"),
+    ("bvc", None, None, None, "bvc"),
+    ("bvd", None, None, None, "bvd"),
+    ("bve", None, None, None, "bve"),
+    ("bvf", None, None, None, "bvf"),
+    ("bvg", None, None, None, "bvg"),
+    ("bvh", None, None, None, "bvh"),
+    ("bvi", None, None, None, "bvi"),
+    ("bvj", None, None, None, "bvj"),
+    ("bvk", None, None, None, "bvk"),
+    ("bvl", None, None, None, "bvl"),
+    ("bvm", None, None, None, "bvm"),
+    ("bvn", None, None, None, "bvn"),
+    ("bvo", None, None, None, "bvo"),
+    ("bvp", None, None, None, "bvp"),
+    ("bvq", None, None, None, "bvq"),
+    ("bvr", None, None, None, "bvr"),
+    ("bvt", None, None, None, "bvt"),
+    ("bvu", None, None, None, "bvu"),
+    ("bvv", None, None, None, "bvv"),
+    ("bvw", None, None, None, "bvw"),
+    ("bvx", None, None, None, "bvx"),
+    ("bvy", None, None, None, "bvy"),
+    ("bvz", None, None, None, "bvz"),
+    ("bwa", None, None, None, "bwa"),
+    ("bwb", None, None, None, "bwb"),
+    ("bwc", None, None, None, "bwc"),
+    ("bwd", None, None, None, "bwd"),
+    ("bwe", None, None, None, "bwe"),
+    ("bwf", None, None, None, "bwf"),
+    ("bwg", None, None, None, "bwg"),
+    ("bwh", None, None, None, "bwh"),
+    ("bwi", None, None, None, "bwi"),
+    ("bwj", None, None, None, "bwj"),
+    ("bwk", None, None, None, "bwk"),
+    ("bwl", None, None, None, "bwl"),
+    ("bwm", None, None, None, "bwm"),
+    ("bwn", None, None, None, "bwn"),
+    ("bwo", None, None, None, "bwo"),
+    ("bwp", None, None, None, "bwp"),
+    ("bwq", None, None, None, "bwq"),
+    ("bwr", None, None, None, "bwr"),
+    ("bws", None, None, None, "bws"),
+    ("bwt", None, None, None, "bwt"),
+    ("bwu", None, None, None, "bwu"),
+    ("bww", None, None, None, "bww"),
+    ("bwx", None, None, None, "bwx"),
+    ("bwy", None, None, None, "bwy"),
+    ("bwz", None, None, None, "bwz"),
+    ("bxa", None, None, None, "bxa"),
+    ("bxb", None, None, None, "bxb"),
+    ("bxc", None, None, None, "bxc"),
+    ("bxd", None, None, None, "bxd"),
+    ("bxe", None, None, None, "bxe"),
+    ("bxf", None, None, None, "bxf"),
+    ("bxg", None, None, None, "bxg"),
+    ("bxh", None, None, None, "bxh"),
+    ("bxi", None, None, None, "bxi"),
+    ("bxj", None, None, None, "bxj"),
+    ("bxk", None, None, None, "bxk"),
+    ("bxl", None, None, None, "bxl"),
+    ("bxm", None, None, None, "bxm"),
+    ("bxn", None, None, None, "bxn"),
+    ("bxo", None, None, None, "bxo"),
+    ("bxp", None, None, None, "bxp"),
+    ("bxq", None, None, None, "bxq"),
+    ("bxr", None, None, None, "bxr"),
+    ("bxs", None, None, None, "bxs"),
+    ("bxu", None, None, None, "bxu"),
+    ("bxv", None, None, None, "bxv"),
+    ("bxw", None, None, None, "bxw"),
+    ("bxz", None, None, None, "bxz"),
+    ("bya", None, None, None, "bya"),
+    ("byb", None, None, None, "byb"),
+    ("byc", None, None, None, "byc"),
+    ("byd", None, None, None, "byd"),
+    ("bye", None, None, None, "bye"),
+    ("byf", None, None, None, "byf"),
+    ("byg", None, None, None, "byg"),
+    ("byh", None, None, None, "byh"),
+    ("byi", None, None, None, "byi"),
+    ("byj", None, None, None, "byj"),
+    ("byk", None, None, None, "byk"),
+    ("byl", None, None, None, "byl"),
+    ("bym", None, None, None, "bym"),
+    ("byn", None, Some("byn"), Some("byn"), "byn"),
+    ("byo", None, None, None, "byo"),
+    ("byp", None, None, None, "byp"),
+    ("byq", None, None, None, "byq"),
+    ("byr", None, None, None, "byr"),
+    ("bys", None, None, None, "bys"),
+    ("byt", None, None, None, "byt"),
+    ("byv", None, None, None, "byv"),
+    ("byw", None, None, None, "byw"),
+    ("byx", None, None, None, "byx"),
+    ("byz", None, None, None, "byz"),
+    ("bza", None, None, None, "bza"),
+    ("bzb", None, None, None, "bzb"),
+    ("bzc", None, None, None, "bzc"),
+    ("bzd", None, None, None, "bzd"),
+    ("bze", None, None, None, "bze"),
+    ("bzf", None, None, None, "bzf"),
+    ("bzg", None, None, None, "bzg"),
+    ("bzh", None, None, None, "bzh"),
+    ("bzi", None, None, None, "bzi"),
+    ("bzj", None, None, None, "bzj"),
+    ("bzk", None, None, None, "bzk"),
+    ("bzl", None, None, None, "bzl"),
+    ("bzm", None, None, None, "bzm"),
+    ("bzn", None, None, None, "bzn"),
+    ("bzo", None, None, None, "bzo"),
+    ("bzp", None, None, None, "bzp"),
+    ("bzq", None, None, None, "bzq"),
+    ("bzr", None, None, None, "bzr"),
+    ("bzs", None, None, None, "bzs"),
+    ("bzt", None, None, None, "bzt"),
+    ("bzu", None, None, None, "bzu"),
+    ("bzv", None, None, None, "bzv"),
+    ("bzw", None, None, None, "bzw"),
+    ("bzx", None, None, None, "bzx"),
+    ("bzy", None, None, None, "bzy"),
+    ("bzz", None, None, None, "bzz"),
+    ("ca", Some("ca"), Some("cat"), Some("cat"), "cat"),
+    ("caa", None, None, None, "caa"),
+    ("cab", None, None, None, "cab"),
+    ("cac", None, None, None, "cac"),
+    ("cad", None, Some("cad"), Some("cad"), "cad"),
+    ("cae", None, None, None, "cae"),
+    ("caf", None, None, None, "caf"),
+    ("cag", None, None, None, "cag"),
+    ("cah", None, None, None, "cah"),
+    ("caj", None, None, None, "caj"),
+    ("cak", None, None, None, "cak"),
+    ("cal", None, None, None, "cal"),
+    ("cam", None, None, None, "cam"),
+    ("can", None, None, None, "can"),
+    ("cao", None, None, None, "cao"),
+    ("cap", None, None, None, "cap"),
+    ("caq", None, None, None, "caq"),
+    ("car", None, Some("car"), Some("car"), "car"),
+    ("cas", None, None, None, "cas"),
+    ("cav", None, None, None, "cav"),
+    ("caw", None, None, None, "caw"),
+    ("cax", None, None, None, "cax"),
+    ("cay", None, None, None, "cay"),
+    ("caz", None, None, None, "caz"),
+    ("cbb", None, None, None, "cbb"),
+    ("cbc", None, None, None, "cbc"),
+    ("cbd", None, None, None, "cbd"),
+    ("cbg", None, None, None, "cbg"),
+    ("cbi", None, None, None, "cbi"),
+    ("cbj", None, None, None, "cbj"),
+    ("cbk", None, None, None, "cbk"),
+    ("cbl", None, None, None, "cbl"),
+    ("cbn", None, None, None, "cbn"),
+    ("cbo", None, None, None, "cbo"),
+    ("cbq", None, None, None, "cbq"),
+    ("cbr", None, None, None, "cbr"),
+    ("cbs", None, None, None, "cbs"),
+    ("cbt", None, None, None, "cbt"),
+    ("cbu", None, None, None, "cbu"),
+    ("cbv", None, None, None, "cbv"),
+    ("cbw", None, None, None, "cbw"),
+    ("cby", None, None, None, "cby"),
+    ("ccc", None, None, None, "ccc"),
+    ("ccd", None, None, None, "ccd"),
+    ("cce", None, None, None, "cce"),
+    ("ccg", None, None, None, "ccg"),
+    ("cch", None, None, None, "cch"),
+    ("ccj", None, None, None, "ccj"),
+    ("ccl", None, None, None, "ccl"),
+    ("ccm", None, None, None, "ccm"),
+    ("cco", None, None, None, "cco"),
+    ("ccp", None, None, None, "ccp"),
+    ("ccr", None, None, None, "ccr"),
+    ("cda", None, None, None, "cda"),
+    ("cde", None, None, None, "cde"),
+    ("cdf", None, None, None, "cdf"),
+    ("cdh", None, None, None, "cdh"),
+    ("cdi", None, None, None, "cdi"),
+    ("cdj", None, None, None, "cdj"),
+    ("cdm", None, None, None, "cdm"),
+    ("cdn", None, None, None, "cdn"),
+    ("cdo", None, None, None, "cdo"),
+    ("cdr", None, None, None, "cdr"),
+    ("cds", None, None, None, "cds"),
+    ("cdy", None, None, None, "cdy"),
+    ("cdz", None, None, None, "cdz"),
+    ("ce", Some("ce"), Some("che"), Some("che"), "che"),
+    ("cea", None, None, None, "cea"),
+    ("ceb", None, Some("ceb"), Some("ceb"), "ceb"),
+    ("ceg", None, None, None, "ceg"),
+    ("cek", None, None, None, "cek"),
+    ("cen", None, None, None, "cen"),
+    ("cet", None, None, None, "cet"),
+    ("cey", None, None, None, "cey"),
+    ("cfa", None, None, None, "cfa"),
+    ("cfd", None, None, None, "cfd"),
+    ("cfg", None, None, None, "cfg"),
+    ("cfm", None, None, None, "cfm"),
+    ("cga", None, None, None, "cga"),
+    ("cgc", None, None, None, "cgc"),
+    ("cgg", None, None, None, "cgg"),
+    ("cgk", None, None, None, "cgk"),
+    ("ch", Some("ch"), Some("cha"), Some("cha"), "cha"),
+    ("chb", None, Some("chb"), Some("chb"), "chb"),
+    ("chc", None, None, None, "chc"),
+    ("chd", None, None, None, "chd"),
+    ("chf", None, None, None, "chf"),
+    ("chg", None, Some("chg"), Some("chg"), "chg"),
+    ("chh", None, None, None, "chh"),
+    ("chj", None, None, None, "chj"),
+    ("chk", None, Some("chk"), Some("chk"), "chk"),
+    ("chl", None, None, None, "chl"),
+    ("chm", None, Some("chm"), Some("chm"), "chm"),
+    ("chn", None, Some("chn"), Some("chn"), "chn"),
+    ("cho", None, Some("cho"), Some("cho"), "cho"),
+    ("chp", None, Some("chp"), Some("chp"), "chp"),
+    ("chq", None, None, None, "chq"),
+    ("chr", None, Some("chr"), Some("chr"), "chr"),
+    ("cht", None, None, None, "cht"),
+    ("chw", None, None, None, "chw"),
+    ("chx", None, None, None, "chx"),
+    ("chy", None, Some("chy"), Some("chy"), "chy"),
+    ("chz", None, None, None, "chz"),
+    ("cia", None, None, None, "cia"),
+    ("cib", None, None, None, "cib"),
+    ("cic", None, None, None, "cic"),
+    ("cid", None, None, None, "cid"),
+    ("cie", None, None, None, "cie"),
+    ("cih", None, None, None, "cih"),
+    ("cik", None, None, None, "cik"),
+    ("cim", None, None, None, "cim"),
+    ("cin", None, None, None, "cin"),
+    ("cip", None, None, None, "cip"),
+    ("cir", None, None, None, "cir"),
+    ("ciw", None, None, None, "ciw"),
+    ("ciy", None, None, None, "ciy"),
+    ("cja", None, None, None, "cja"),
+    ("cje", None, None, None, "cje"),
+    ("cjh", None, None, None, "cjh"),
+    ("cji", None, None, None, "cji"),
+    ("cjk", None, None, None, "cjk"),
+    ("cjm", None, None, None, "cjm"),
+    ("cjn", None, None, None, "cjn"),
+    ("cjo", None, None, None, "cjo"),
+    ("cjp", None, None, None, "cjp"),
+    ("cjs", None, None, None, "cjs"),
+    ("cjv", None, None, None, "cjv"),
+    ("cjy", None, None, None, "cjy"),
+    ("ckb", None, None, None, "ckb"),
+    ("ckh", None, None, None, "ckh"),
+    ("ckl", None, None, None, "ckl"),
+    ("ckm", None, None, None, "ckm"),
+    ("ckn", None, None, None, "ckn"),
+    ("cko", None, None, None, "cko"),
+    ("ckq", None, None, None, "ckq"),
+    ("ckr", None, None, None, "ckr"),
+    ("cks", None, None, None, "cks"),
+    ("ckt", None, None, None, "ckt"),
+    ("cku", None, None, None, "cku"),
+    ("ckv", None, None, None, "ckv"),
+    ("ckx", None, None, None, "ckx"),
+    ("cky", None, None, None, "cky"),
+    ("ckz", None, None, None, "ckz"),
+    ("cla", None, None, None, "cla"),
+    ("clc", None, None, None, "clc"),
+    ("cld", None, None, None, "cld"),
+    ("cle", None, None, None, "cle"),
+    ("clh", None, None, None, "clh"),
+    ("cli", None, None, None, "cli"),
+    ("clj", None, None, None, "clj"),
+    ("clk", None, None, None, "clk"),
+    ("cll", None, None, None, "cll"),
+    ("clm", None, None, None, "clm"),
+    ("clo", None, None, None, "clo"),
+    ("clt", None, None, None, "clt"),
+    ("clu", None, None, None, "clu"),
+    ("clw", None, None, None, "clw"),
+    ("cly", None, None, None, "cly"),
+    ("cma", None, None, None, "cma"),
+    ("cme", None, None, None, "cme"),
+    ("cmg", None, None, None, "cmg"),
+    ("cmi", None, None, None, "cmi"),
+    ("cml", None, None, None, "cml"),
+    ("cmm", None, None, None, "cmm"),
+    ("cmn", None, None, None, "cmn"),
+    ("cmo", None, None, None, "cmo"),
+    ("cmr", None, None, None, "cmr"),
+    ("cms", None, None, None, "cms"),
+    ("cmt", None, None, None, "cmt"),
+    ("cna", None, None, None, "cna"),
+    ("cnb", None, None, None, "cnb"),
+    ("cnc", None, None, None, "cnc"),
+    ("cng", None, None, None, "cng"),
+    ("cnh", None, None, None, "cnh"),
+    ("cni", None, None, None, "cni"),
+    ("cnk", None, None, None, "cnk"),
+    ("cnl", None, None, None, "cnl"),
+    ("cno", None, None, None, "cno"),
+    ("cnp", None, None, None, "cnp"),
+    ("cnq", None, None, None, "cnq"),
+    ("cnr", None, Some("cnr"), Some("cnr"), "cnr"),
+    ("cns", None, None, None, "cns"),
+    ("cnt", None, None, None, "cnt"),
+    ("cnu", None, None, None, "cnu"),
+    ("cnw", None, None, None, "cnw"),
+    ("cnx", None, None, None, "cnx"),
+    ("co", Some("co"), Some("cos"), Some("cos"), "cos"),
+    ("coa", None, None, None, "coa"),
+    ("cob", None, None, None, "cob"),
+    ("coc", None, None, None, "coc"),
+    ("cod", None, None, None, "cod"),
+    ("coe", None, None, None, "coe"),
+    ("cof", None, None, None, "cof"),
+    ("cog", None, None, None, "cog"),
+    ("coh", None, None, None, "coh"),
+    ("coj", None, None, None, "coj"),
+    ("cok", None, None, None, "cok"),
+    ("col", None, None, None, "col"),
+    ("com", None, None, None, "com"),
+    ("con", None, None, None, "con"),
+    ("coo", None, None, None, "coo"),
+    ("cop", None, Some("cop"), Some("cop"), "cop"),
+    ("coq", None, None, None, "coq"),
+    ("cot", None, None, None, "cot"),
+    ("cou", None, None, None, "cou"),
+    ("cov", None, None, None, "cov"),
+    ("cow", None, None, None, "cow"),
+    ("cox", None, None, None, "cox"),
+    ("coz", None, None, None, "coz"),
+    ("cpa", None, None, None, "cpa"),
+    ("cpb", None, None, None, "cpb"),
+    ("cpc", None, None, None, "cpc"),
+    ("cpg", None, None, None, "cpg"),
+    ("cpi", None, None, None, "cpi"),
+    ("cpn", None, None, None, "cpn"),
+    ("cpo", None, None, None, "cpo"),
+    ("cps", None, None, None, "cps"),
+    ("cpu", None, None, None, "cpu"),
+    ("cpx", None, None, None, "cpx"),
+    ("cpy", None, None, None, "cpy"),
+    ("cqd", None, None, None, "cqd"),
+    ("cr", Some("cr"), Some("cre"), Some("cre"), "cre"),
+    ("cra", None, None, None, "cra"),
+    ("crb", None, None, None, "crb"),
+    ("crc", None, None, None, "crc"),
+    ("crd", None, None, None, "crd"),
+    ("crf", None, None, None, "crf"),
+    ("crg", None, None, None, "crg"),
+    ("crh", None, Some("crh"), Some("crh"), "crh"),
+    ("cri", None, None, None, "cri"),
+    ("crj", None, None, None, "crj"),
+    ("crk", None, None, None, "crk"),
+    ("crl", None, None, None, "crl"),
+    ("crm", None, None, None, "crm"),
+    ("crn", None, None, None, "crn"),
+    ("cro", None, None, None, "cro"),
+    ("crq", None, None, None, "crq"),
+    ("crr", None, None, None, "crr"),
+    ("crs", None, None, None, "crs"),
+    ("crt", None, None, None, "crt"),
+    ("crv", None, None, None, "crv"),
+    ("crw", None, None, None, "crw"),
+    ("crx", None, None, None, "crx"),
+    ("cry", None, None, None, "cry"),
+    ("crz", None, None, None, "crz"),
+    ("cs", Some("cs"), Some("ces"), Some("cze"), "ces"),
+    ("csa", None, None, None, "csa"),
+    ("csb", None, Some("csb"), Some("csb"), "csb"),
+    ("csc", None, None, None, "csc"),
+    ("csd", None, None, None, "csd"),
+    ("cse", None, None, None, "cse"),
+    ("csf", None, None, None, "csf"),
+    ("csg", None, None, None, "csg"),
+    ("csh", None, None, None, "csh"),
+    ("csi", None, None, None, "csi"),
+    ("csj", None, None, None, "csj"),
+    ("csk", None, None, None, "csk"),
+    ("csl", None, None, None, "csl"),
+    ("csm", None, None, None, "csm"),
+    ("csn", None, None, None, "csn"),
+    ("cso", None, None, None, "cso"),
+    ("csp", None, None, None, "csp"),
+    ("csq", None, None, None, "csq"),
+    ("csr", None, None, None, "csr"),
+    ("css", None, None, None, "css"),
+    ("cst", None, None, None, "cst"),
+    ("csv", None, None, None, "csv"),
+    ("csw", None, None, None, "csw"),
+    ("csx", None, None, None, "csx"),
+    ("csy", None, None, None, "csy"),
+    ("csz", None, None, None, "csz"),
+    ("cta", None, None, None, "cta"),
+    ("ctc", None, None, None, "ctc"),
+    ("ctd", None, None, None, "ctd"),
+    ("cte", None, None, None, "cte"),
+    ("ctg", None, None, None, "ctg"),
+    ("cth", None, None, None, "cth"),
+    ("ctl", None, None, None, "ctl"),
+    ("ctm", None, None, None, "ctm"),
+    ("ctn", None, None, None, "ctn"),
+    ("cto", None, None, None, "cto"),
+    ("ctp", None, None, None, "ctp"),
+    ("cts", None, None, None, "cts"),
+    ("ctt", None, None, None, "ctt"),
+    ("ctu", None, None, None, "ctu"),
+    ("cty", None, None, None, "cty"),
+    ("ctz", None, None, None, "ctz"),
+    ("cu", Some("cu"), Some("chu"), Some("chu"), "chu"),
+    ("cua", None, None, None, "cua"),
+    ("cub", None, None, None, "cub"),
+    ("cuc", None, None, None, "cuc"),
+    ("cuh", None, None, None, "cuh"),
+    ("cui", None, None, None, "cui"),
+    ("cuj", None, None, None, "cuj"),
+    ("cuk", None, None, None, "cuk"),
+    ("cul", None, None, None, "cul"),
+    ("cuo", None, None, None, "cuo"),
+    ("cup", None, None, None, "cup"),
+    ("cuq", None, None, None, "cuq"),
+    ("cur", None, None, None, "cur"),
+    ("cut", None, None, None, "cut"),
+    ("cuu", None, None, None, "cuu"),
+    ("cuv", None, None, None, "cuv"),
+    ("cuw", None, None, None, "cuw"),
+    ("cux", None, None, None, "cux"),
+    ("cuy", None, None, None, "cuy"),
+    ("cv", Some("cv"), Some("chv"), Some("chv"), "chv"),
+    ("cvg", None, None, None, "cvg"),
+    ("cvn", None, None, None, "cvn"),
+    ("cwa", None, None, None, "cwa"),
+    ("cwb", None, None, None, "cwb"),
+    ("cwd", None, None, None, "cwd"),
+    ("cwe", None, None, None, "cwe"),
+    ("cwg", None, None, None, "cwg"),
+    ("cwt", None, None, None, "cwt"),
+    ("cy", Some("cy"), Some("cym"), Some("wel"), "cym"),
+    ("cya", None, None, None, "cya"),
+    ("cyb", None, None, None, "cyb"),
+    ("cyo", None, None, None, "cyo"),
+    ("czh", None, None, None, "czh"),
+    ("czk", None, None, None, "czk"),
+    ("czn", None, None, None, "czn"),
+    ("czo", None, None, None, "czo"),
+    ("czt", None, None, None, "czt"),
+    ("da", Some("da"), Some("dan"), Some("dan"), "dan"),
+    ("daa", None, None, None, "daa"),
+    ("dac", None, None, None, "dac"),
+    ("dad", None, None, None, "dad"),
+    ("dae", None, None, None, "dae"),
+    ("dag", None, None, None, "dag"),
+    ("dah", None, None, None, "dah"),
+    ("dai", None, None, None, "dai"),
+    ("daj", None, None, None, "daj"),
+    ("dak", None, Some("dak"), Some("dak"), "dak"),
+    ("dal", None, None, None, "dal"),
+    ("dam", None, None, None, "dam"),
+    ("dao", None, None, None, "dao"),
+    ("daq", None, None, None, "daq"),
+    ("dar", None, Some("dar"), Some("dar"), "dar"),
+    ("das", None, None, None, "das"),
+    ("dau", None, None, None, "dau"),
+    ("dav", None, None, None, "dav"),
+    ("daw", None, None, None, "daw"),
+    ("dax", None, None, None, "dax"),
+    ("daz", None, None, None, "daz"),
+    ("dba", None, None, None, "dba"),
+    ("dbb", None, None, None, "dbb"),
+    ("dbd", None, None, None, "dbd"),
+    ("dbe", None, None, None, "dbe"),
+    ("dbf", None, None, None, "dbf"),
+    ("dbg", None, None, None, "dbg"),
+    ("dbi", None, None, None, "dbi"),
+    ("dbj", None, None, None, "dbj"),
+    ("dbl", None, None, None, "dbl"),
+    ("dbm", None, None, None, "dbm"),
+    ("dbn", None, None, None, "dbn"),
+    ("dbo", None, None, None, "dbo"),
+    ("dbp", None, None, None, "dbp"),
+    ("dbq", None, None, None, "dbq"),
+    ("dbr", None, None, None, "dbr"),
+    ("dbt", None, None, None, "dbt"),
+    ("dbu", None, None, None, "dbu"),
+    ("dbv", None, None, None, "dbv"),
+    ("dbw", None, None, None, "dbw"),
+    ("dby", None, None, None, "dby"),
+    ("dcc", None, None, None, "dcc"),
+    ("dcr", None, None, None, "dcr"),
+    ("dda", None, None, None, "dda"),
+    ("ddd", None, None, None, "ddd"),
+    ("dde", None, None, None, "dde"),
+    ("ddg", None, None, None, "ddg"),
+    ("ddi", None, None, None, "ddi"),
+    ("ddj", None, None, None, "ddj"),
+    ("ddn", None, None, None, "ddn"),
+    ("ddo", None, None, None, "ddo"),
+    ("ddr", None, None, None, "ddr"),
+    ("dds", None, None, None, "dds"),
+    ("ddw", None, None, None, "ddw"),
+    ("de", Some("de"), Some("deu"), Some("ger"), "deu"),
+    ("dec", None, None, None, "dec"),
+    ("ded", None, None, None, "ded"),
+    ("dee", None, None, None, "dee"),
+    ("def", None, None, None, "def"),
+    ("deg", None, None, None, "deg"),
+    ("deh", None, None, None, "deh"),
+    ("dei", None, None, None, "dei"),
+    ("dek", None, None, None, "dek"),
+    ("del", None, Some("del"), Some("del"), "del"),
+    ("dem", None, None, None, "dem"),
+    ("den", None, Some("den"), Some("den"), "den"),
+    ("dep", None, None, None, "dep"),
+    ("deq", None, None, None, "deq"),
+    ("der", None, None, None, "der"),
+    ("des", None, None, None, "des"),
+    ("dev", None, None, None, "dev"),
+    ("dez", None, None, None, "dez"),
+    ("dga", None, None, None, "dga"),
+    ("dgb", None, None, None, "dgb"),
+    ("dgc", None, None, None, "dgc"),
+    ("dgd", None, None, None, "dgd"),
+    ("dge", None, None, None, "dge"),
+    ("dgg", None, None, None, "dgg"),
+    ("dgh", None, None, None, "dgh"),
+    ("dgi", None, None, None, "dgi"),
+    ("dgk", None, None, None, "dgk"),
+    ("dgl", None, None, None, "dgl"),
+    ("dgn", None, None, None, "dgn"),
+    ("dgo", None, None, None, "dgo"),
+    ("dgr", None, Some("dgr"), Some("dgr"), "dgr"),
+    ("dgs", None, None, None, "dgs"),
+    ("dgt", None, None, None, "dgt"),
+    ("dgw", None, None, None, "dgw"),
+    ("dgx", None, None, None, "dgx"),
+    ("dgz", None, None, None, "dgz"),
+    ("dhd", None, None, None, "dhd"),
+    ("dhg", None, None, None, "dhg"),
+    ("dhi", None, None, None, "dhi"),
+    ("dhl", None, None, None, "dhl"),
+    ("dhm", None, None, None, "dhm"),
+    ("dhn", None, None, None, "dhn"),
+    ("dho", None, None, None, "dho"),
+    ("dhr", None, None, None, "dhr"),
+    ("dhs", None, None, None, "dhs"),
+    ("dhu", None, None, None, "dhu"),
+    ("dhv", None, None, None, "dhv"),
+    ("dhw", None, None, None, "dhw"),
+    ("dhx", None, None, None, "dhx"),
+    ("dia", None, None, None, "dia"),
+    ("dib", None, None, None, "dib"),
+    ("dic", None, None, None, "dic"),
+    ("did", None, None, None, "did"),
+    ("dif", None, None, None, "dif"),
+    ("dig", None, None, None, "dig"),
+    ("dih", None, None, None, "dih"),
+    ("dii", None, None, None, "dii"),
+    ("dij", None, None, None, "dij"),
+    ("dik", None, None, None, "dik"),
+    ("dil", None, None, None, "dil"),
+    ("dim", None, None, None, "dim"),
+    ("din", None, Some("din"), Some("din"), "din"),
+    ("dio", None, None, None, "dio"),
+    ("dip", None, None, None, "dip"),
+    ("diq", None, None, None, "diq"),
+    ("dir", None, None, None, "dir"),
+    ("dis", None, None, None, "dis"),
+    ("diu", None, None, None, "diu"),
+    ("diw", None, None, None, "diw"),
+    ("dix", None, None, None, "dix"),
+    ("diy", None, None, None, "diy"),
+    ("diz", None, None, None, "diz"),
+    ("dja", None, None, None, "dja"),
+    ("djb", None, None, None, "djb"),
+    ("djc", None, None, None, "djc"),
+    ("djd", None, None, None, "djd"),
+    ("dje", None, None, None, "dje"),
+    ("djf", None, None, None, "djf"),
+    ("dji", None, None, None, "dji"),
+    ("djj", None, None, None, "djj"),
+    ("djk", None, None, None, "djk"),
+    ("djm", None, None, None, "djm"),
+    ("djn", None, None, None, "djn"),
+    ("djo", None, None, None, "djo"),
+    ("djr", None, None, None, "djr"),
+    ("dju", None, None, None, "dju"),
+    ("djw", None, None, None, "djw"),
+    ("dka", None, None, None, "dka"),
+    ("dkg", None, None, None, "dkg"),
+    ("dkk", None, None, None, "dkk"),
+    ("dkr", None, None, None, "dkr"),
+    ("dks", None, None, None, "dks"),
+    ("dkx", None, None, None, "dkx"),
+    ("dlg", None, None, None, "dlg"),
+    ("dlk", None, None, None, "dlk"),
+    ("dlm", None, None, None, "dlm"),
+    ("dln", None, None, None, "dln"),
+    ("dma", None, None, None, "dma"),
+    ("dmb", None, None, None, "dmb"),
+    ("dmc", None, None, None, "dmc"),
+    ("dmd", None, None, None, "dmd"),
+    ("dme", None, None, None, "dme"),
+    ("dmf", None, None, None, "dmf"),
+    ("dmg", None, None, None, "dmg"),
+    ("dmk", None, None, None, "dmk"),
+    ("dml", None, None, None, "dml"),
+    ("dmm", None, None, None, "dmm"),
+    ("dmo", None, None, None, "dmo"),
+    ("dmr", None, None, None, "dmr"),
+    ("dms", None, None, None, "dms"),
+    ("dmu", None, None, None, "dmu"),
+    ("dmv", None, None, None, "dmv"),
+    ("dmw", None, None, None, "dmw"),
+    ("dmx", None, None, None, "dmx"),
+    ("dmy", None, None, None, "dmy"),
+    ("dna", None, None, None, "dna"),
+    ("dnd", None, None, None, "dnd"),
+    ("dne", None, None, None, "dne"),
+    ("dng", None, None, None, "dng"),
+    ("dni", None, None, None, "dni"),
+    ("dnj", None, None, None, "dnj"),
+    ("dnk", None, None, None, "dnk"),
+    ("dnn", None, None, None, "dnn"),
+    ("dno", None, None, None, "dno"),
+    ("dnr", None, None, None, "dnr"),
+    ("dnt", None, None, None, "dnt"),
+    ("dnu", None, None, None, "dnu"),
+    ("dnv", None, None, None, "dnv"),
+    ("dnw", None, None, None, "dnw"),
+    ("dny", None, None, None, "dny"),
+    ("doa", None, None, None, "doa"),
+    ("dob", None, None, None, "dob"),
+    ("doc", None, None, None, "doc"),
+    ("doe", None, None, None, "doe"),
+    ("dof", None, None, None, "dof"),
+    ("doh", None, None, None, "doh"),
+    ("doi", None, Some("doi"), Some("doi"), "doi"),
+    ("dok", None, None, None, "dok"),
+    ("dol", None, None, None, "dol"),
+    ("don", None, None, None, "don"),
+    ("doo", None, None, None, "doo"),
+    ("dop", None, None, None, "dop"),
+    ("doq", None, None, None, "doq"),
+    ("dor", None, None, None, "dor"),
+    ("dos", None, None, None, "dos"),
+    ("dot", None, None, None, "dot"),
+    ("dov", None, None, None, "dov"),
+    ("dow", None, None, None, "dow"),
+    ("dox", None, None, None, "dox"),
+    ("doy", None, None, None, "doy"),
+    ("doz", None, None, None, "doz"),
+    ("dpp", None, None, None, "dpp"),
+    ("drb", None, None, None, "drb"),
+    ("drc", None, None, None, "drc"),
+    ("drd", None, None, None, "drd"),
+    ("dre", None, None, None, "dre"),
+    ("drg", None, None, None, "drg"),
+    ("dri", None, None, None, "dri"),
+    ("drl", None, None, None, "drl"),
+    ("drn", None, None, None, "drn"),
+    ("dro", None, None, None, "dro"),
+    ("drq", None, None, None, "drq"),
+    ("drs", None, None, None, "drs"),
+    ("drt", None, None, None, "drt"),
+    ("dru", None, None, None, "dru"),
+    ("dry", None, None, None, "dry"),
+    ("dsb", None, Some("dsb"), Some("dsb"), "dsb"),
+    ("dse", None, None, None, "dse"),
+    ("dsh", None, None, None, "dsh"),
+    ("dsi", None, None, None, "dsi"),
+    ("dsl", None, None, None, "dsl"),
+    ("dsn", None, None, None, "dsn"),
+    ("dso", None, None, None, "dso"),
+    ("dsq", None, None, None, "dsq"),
+    ("dsz", None, None, None, "dsz"),
+    ("dta", None, None, None, "dta"),
+    ("dtb", None, None, None, "dtb"),
+    ("dtd", None, None, None, "dtd"),
+    ("dth", None, None, None, "dth"),
+    ("dti", None, None, None, "dti"),
+    ("dtk", None, None, None, "dtk"),
+    ("dtm", None, None, None, "dtm"),
+    ("dtn", None, None, None, "dtn"),
+    ("dto", None, None, None, "dto"),
+    ("dtp", None, None, None, "dtp"),
+    ("dtr", None, None, None, "dtr"),
+    ("dts", None, None, None, "dts"),
+    ("dtt", None, None, None, "dtt"),
+    ("dtu", None, None, None, "dtu"),
+    ("dty", None, None, None, "dty"),
+    ("dua", None, Some("dua"), Some("dua"), "dua"),
+    ("dub", None, None, None, "dub"),
+    ("duc", None, None, None, "duc"),
+    ("due", None, None, None, "due"),
+    ("duf", None, None, None, "duf"),
+    ("dug", None, None, None, "dug"),
+    ("duh", None, None, None, "duh"),
+    ("dui", None, None, None, "dui"),
+    ("duk", None, None, None, "duk"),
+    ("dul", None, None, None, "dul"),
+    ("dum", None, Some("dum"), Some("dum"), "dum"),
+    ("dun", None, None, None, "dun"),
+    ("duo", None, None, None, "duo"),
+    ("dup", None, None, None, "dup"),
+    ("duq", None, None, None, "duq"),
+    ("dur", None, None, None, "dur"),
+    ("dus", None, None, None, "dus"),
+    ("duu", None, None, None, "duu"),
+    ("duv", None, None, None, "duv"),
+    ("duw", None, None, None, "duw"),
+    ("dux", None, None, None, "dux"),
+    ("duy", None, None, None, "duy"),
+    ("duz", None, None, None, "duz"),
+    ("dv", Some("dv"), Some("div"), Some("div"), "div"),
+    ("dva", None, None, None, "dva"),
+    ("dwa", None, None, None, "dwa"),
+    ("dwk", None, None, None, "dwk"),
+    ("dwr", None, None, None, "dwr"),
+    ("dws", None, None, None, "dws"),
+    ("dwu", None, None, None, "dwu"),
+    ("dww", None, None, None, "dww"),
+    ("dwy", None, None, None, "dwy"),
+    ("dwz", None, None, None, "dwz"),
+    ("dya", None, None, None, "dya"),
+    ("dyb", None, None, None, "dyb"),
+    ("dyd", None, None, None, "dyd"),
+    ("dyg", None, None, None, "dyg"),
+    ("dyi", None, None, None, "dyi"),
+    ("dym", None, None, None, "dym"),
+    ("dyn", None, None, None, "dyn"),
+    ("dyo", None, None, None, "dyo"),
+    ("dyu", None, Some("dyu"), Some("dyu"), "dyu"),
+    ("dyy", None, None, None, "dyy"),
+    ("dz", Some("dz"), Some("dzo"), Some("dzo"), "dzo"),
+    ("dza", None, None, None, "dza"),
+    ("dze", None, None, None, "dze"),
+    ("dzg", None, None, None, "dzg"),
+    ("dzl", None, None, None, "dzl"),
+    ("dzn", None, None, None, "dzn"),
+    ("eaa", None, None, None, "eaa"),
+    ("ebc", None, None, None, "ebc"),
+    ("ebg", None, None, None, "ebg"),
+    ("ebk", None, None, None, "ebk"),
+    ("ebo", None, None, None, "ebo"),
+    ("ebr", None, None, None, "ebr"),
+    ("ebu", None, None, None, "ebu"),
+    ("ecr", None, None, None, "ecr"),
+    ("ecs", None, None, None, "ecs"),
+    ("ecy", None, None, None, "ecy"),
+    ("ee", Some("ee"), Some("ewe"), Some("ewe"), "ewe"),
+    ("eee", None, None, None, "eee"),
+    ("efa", None, None, None, "efa"),
+    ("efe", None, None, None, "efe"),
+    ("efi", None, Some("efi"), Some("efi"), "efi"),
+    ("ega", None, None, None, "ega"),
+    ("egl", None, None, None, "egl"),
+    ("egm", None, None, None, "egm"),
+    ("ego", None, None, None, "ego"),
+    ("egy", None, Some("egy"), Some("egy"), "egy"),
+    ("ehs", None, None, None, "ehs"),
+    ("ehu", None, None, None, "ehu"),
+    ("eip", None, None, None, "eip"),
+    ("eit", None, None, None, "eit"),
+    ("eiv", None, None, None, "eiv"),
+    ("eja", None, None, None, "eja"),
+    ("eka", None, Some("eka"), Some("eka"), "eka"),
+    ("eke", None, None, None, "eke"),
+    ("ekg", None, None, None, "ekg"),
+    ("eki", None, None, None, "eki"),
+    ("ekk", None, None, None, "ekk"),
+    ("ekl", None, None, None, "ekl"),
+    ("ekm", None, None, None, "ekm"),
+    ("eko", None, None, None, "eko"),
+    ("ekp", None, None, None, "ekp"),
+    ("ekr", None, None, None, "ekr"),
+    ("eky", None, None, None, "eky"),
+    ("el", Some("el"), Some("ell"), Some("gre"), "ell"),
+    ("ele", None, None, None, "ele"),
+    ("elh", None, None, None, "elh"),
+    ("eli", None, None, None, "eli"),
+    ("elk", None, None, None, "elk"),
+    ("elm", None, None, None, "elm"),
+    ("elo", None, None, None, "elo"),
+    ("elu", None, None, None, "elu"),
+    ("elx", None, Some("elx"), Some("elx"), "elx"),
+    ("ema", None, None, None, "ema"),
+    ("emb", None, None, None, "emb"),
+    ("eme", None, None, None, "eme"),
+    ("emg", None, None, None, "emg"),
+    ("emi", None, None, None, "emi"),
+    ("emk", None, None, None, "emk"),
+    ("emm", None, None, None, "emm"),
+    ("emn", None, None, None, "emn"),
+    ("emp", None, None, None, "emp"),
+    ("emq", None, None, None, "emq"),
+    ("ems", None, None, None, "ems"),
+    ("emu", None, None, None, "emu"),
+    ("emw", None, None, None, "emw"),
+    ("emx", None, None, None, "emx"),
+    ("emy", None, None, None, "emy"),
+    ("emz", None, None, None, "emz"),
+    ("en", Some("en"), Some("eng"), Some("eng"), "eng"),
+    ("ena", None, None, None, "ena"),
+    ("enb", None, None, None, "enb"),
+    ("enc", None, None, None, "enc"),
+    ("end", None, None, None, "end"),
+    ("enf", None, None, None, "enf"),
+    ("enh", None, None, None, "enh"),
+    ("enl", None, None, None, "enl"),
+    ("enm", None, Some("enm"), Some("enm"), "enm"),
+    ("enn", None, None, None, "enn"),
+    ("eno", None, None, None, "eno"),
+    ("enq", None, None, None, "enq"),
+    ("enr", None, None, None, "enr"),
+    ("enu", None, None, None, "enu"),
+    ("env", None, None, None, "env"),
+    ("enw", None, None, None, "enw"),
+    ("enx", None, None, None, "enx"),
+    ("eo", Some("eo"), Some("epo"), Some("epo"), "epo"),
+    ("eot", None, None, None, "eot"),
+    ("epi", None, None, None, "epi"),
+    ("era", None, None, None, "era"),
+    ("erg", None, None, None, "erg"),
+    ("erh", None, None, None, "erh"),
+    ("eri", None, None, None, "eri"),
+    ("erk", None, None, None, "erk"),
+    ("ero", None, None, None, "ero"),
+    ("err", None, None, None, "err"),
+    ("ers", None, None, None, "ers"),
+    ("ert", None, None, None, "ert"),
+    ("erw", None, None, None, "erw"),
+    ("es", Some("es"), Some("spa"), Some("spa"), "spa"),
+    ("ese", None, None, None, "ese"),
+    ("esg", None, None, None, "esg"),
+    ("esh", None, None, None, "esh"),
+    ("esi", None, None, None, "esi"),
+    ("esk", None, None, None, "esk"),
+    ("esl", None, None, None, "esl"),
+    ("esm", None, None, None, "esm"),
+    ("esn", None, None, None, "esn"),
+    ("eso", None, None, None, "eso"),
+    ("esq", None, None, None, "esq"),
+    ("ess", None, None, None, "ess"),
+    ("esu", None, None, None, "esu"),
+    ("esy", None, None, None, "esy"),
+    ("et", Some("et"), Some("est"), Some("est"), "est"),
+    ("etb", None, None, None, "etb"),
+    ("etc", None, None, None, "etc"),
+    ("eth", None, None, None, "eth"),
+    ("etn", None, None, None, "etn"),
+    ("eto", None, None, None, "eto"),
+    ("etr", None, None, None, "etr"),
+    ("ets", None, None, None, "ets"),
+    ("ett", None, None, None, "ett"),
+    ("etu", None, None, None, "etu"),
+    ("etx", None, None, None, "etx"),
+    ("etz", None, None, None, "etz"),
+    ("eu", Some("eu"), Some("eus"), Some("baq"), "eus"),
+    ("eve", None, None, None, "eve"),
+    ("evh", None, None, None, "evh"),
+    ("evn", None, None, None, "evn"),
+    ("ewo", None, Some("ewo"), Some("ewo"), "ewo"),
+    ("ext", None, None, None, "ext"),
+    ("eya", None, None, None, "eya"),
+    ("eyo", None, None, None, "eyo"),
+    ("eza", None, None, None, "eza"),
+    ("eze", None, None, None, "eze"),
+    ("fa", Some("fa"), Some("fas"), Some("per"), "fas"),
+    ("faa", None, None, None, "faa"),
+    ("fab", None, None, None, "fab"),
+    ("fad", None, None, None, "fad"),
+    ("faf", None, None, None, "faf"),
+    ("fag", None, None, None, "fag"),
+    ("fah", None, None, None, "fah"),
+    ("fai", None, None, None, "fai"),
+    ("faj", None, None, None, "faj"),
+    ("fak", None, None, None, "fak"),
+    ("fal", None, None, None, "fal"),
+    ("fam", None, None, None, "fam"),
+    ("fan", None, Some("fan"), Some("fan"), "fan"),
+    ("fap", None, None, None, "fap"),
+    ("far", None, None, None, "far"),
+    ("fat", None, Some("fat"), Some("fat"), "fat"),
+    ("fau", None, None, None, "fau"),
+    ("fax", None, None, None, "fax"),
+    ("fay", None, None, None, "fay"),
+    ("faz", None, None, None, "faz"),
+    ("fbl", None, None, None, "fbl"),
+    ("fcs", None, None, None, "fcs"),
+    ("fer", None, None, None, "fer"),
+    ("ff", Some("ff"), Some("ful"), Some("ful"), "ful"),
+    ("ffi", None, None, None, "ffi"),
+    ("ffm", None, None, None, "ffm"),
+    ("fgr", None, None, None, "fgr"),
+    ("fi", Some("fi"), Some("fin"), Some("fin"), "fin"),
+    ("fia", None, None, None, "fia"),
+    ("fie", None, None, None, "fie"),
+    ("fif", None, None, None, "fif"),
+    ("fil", None, Some("fil"), Some("fil"), "fil"),
+    ("fip", None, None, None, "fip"),
+    ("fir", None, None, None, "fir"),
+    ("fit", None, None, None, "fit"),
+    ("fiw", None, None, None, "fiw"),
+    ("fj", Some("fj"), Some("fij"), Some("fij"), "fij"),
+    ("fkk", None, None, None, "fkk"),
+    ("fkv", None, None, None, "fkv"),
+    ("fla", None, None, None, "fla"),
+    ("flh", None, None, None, "flh"),
+    ("fli", None, None, None, "fli"),
+    ("fll", None, None, None, "fll"),
+    ("fln", None, None, None, "fln"),
+    ("flr", None, None, None, "flr"),
+    ("fly", None, None, None, "fly"),
+    ("fmp", None, None, None, "fmp"),
+    ("fmu", None, None, None, "fmu"),
+    ("fnb", None, None, None, "fnb"),
+    ("fng", None, None, None, "fng"),
+    ("fni", None, None, None, "fni"),
+    ("fo", Some("fo"), Some("fao"), Some("fao"), "fao"),
+    ("fod", None, None, None, "fod"),
+    ("foi", None, None, None, "foi"),
+    ("fom", None, None, None, "fom"),
+    ("fon", None, Some("fon"), Some("fon"), "fon"),
+    ("for", None, None, None, "for"),
+    ("fos", None, None, None, "fos"),
+    ("fpe", None, None, None, "fpe"),
+    ("fqs", None, None, None, "fqs"),
+    ("fr", Some("fr"), Some("fra"), Some("fre"), "fra"),
+    ("frc", None, None, None, "frc"),
+    ("frd", None, None, None, "frd"),
+    ("frk", None, None, None, "frk"),
+    ("frm", None, Some("frm"), Some("frm"), "frm"),
+    ("fro", None, Some("fro"), Some("fro"), "fro"),
+    ("frp", None, None, None, "frp"),
+    ("frq", None, None, None, "frq"),
+    ("frr", None, Some("frr"), Some("frr"), "frr"),
+    ("frs", None, Some("frs"), Some("frs"), "frs"),
+    ("frt", None, None, None, "frt"),
+    ("fse", None, None, None, "fse"),
+    ("fsl", None, None, None, "fsl"),
+    ("fss", None, None, None, "fss"),
+    ("fub", None, None, None, "fub"),
+    ("fuc", None, None, None, "fuc"),
+    ("fud", None, None, None, "fud"),
+    ("fue", None, None, None, "fue"),
+    ("fuf", None, None, None, "fuf"),
+    ("fuh", None, None, None, "fuh"),
+    ("fui", None, None, None, "fui"),
+    ("fuj", None, None, None, "fuj"),
+    ("fum", None, None, None, "fum"),
+    ("fun", None, None, None, "fun"),
+    ("fuq", None, None, None, "fuq"),
+    ("fur", None, Some("fur"), Some("fur"), "fur"),
+    ("fut", None, None, None, "fut"),
+    ("fuu", None, None, None, "fuu"),
+    ("fuv", None, None, None, "fuv"),
+    ("fuy", None, None, None, "fuy"),
+    ("fvr", None, None, None, "fvr"),
+    ("fwa", None, None, None, "fwa"),
+    ("fwe", None, None, None, "fwe"),
+    ("fy", Some("fy"), Some("fry"), Some("fry"), "fry"),
+    ("ga", Some("ga"), Some("gle"), Some("gle"), "gle"),
+    ("gaa", None, Some("gaa"), Some("gaa"), "gaa"),
+    ("gab", None, None, None, "gab"),
+    ("gac", None, None, None, "gac"),
+    ("gad", None, None, None, "gad"),
+    ("gae", None, None, None, "gae"),
+    ("gaf", None, None, None, "gaf"),
+    ("gag", None, None, None, "gag"),
+    ("gah", None, None, None, "gah"),
+    ("gai", None, None, None, "gai"),
+    ("gaj", None, None, None, "gaj"),
+    ("gak", None, None, None, "gak"),
+    ("gal", None, None, None, "gal"),
+    ("gam", None, None, None, "gam"),
+    ("gan", None, None, None, "gan"),
+    ("gao", None, None, None, "gao"),
+    ("gap", None, None, None, "gap"),
+    ("gaq", None, None, None, "gaq"),
+    ("gar", None, None, None, "gar"),
+    ("gas", None, None, None, "gas"),
+    ("gat", None, None, None, "gat"),
+    ("gau", None, None, None, "gau"),
+    ("gaw", None, None, None, "gaw"),
+    ("gax", None, None, None, "gax"),
+    ("gay", None, Some("gay"), Some("gay"), "gay"),
+    ("gaz", None, None, None, "gaz"),
+    ("gba", None, Some("gba"), Some("gba"), "gba"),
+    ("gbb", None, None, None, "gbb"),
+    ("gbd", None, None, None, "gbd"),
+    ("gbe", None, None, None, "gbe"),
+    ("gbf", None, None, None, "gbf"),
+    ("gbg", None, None, None, "gbg"),
+    ("gbh", None, None, None, "gbh"),
+    ("gbi", None, None, None, "gbi"),
+    ("gbj", None, None, None, "gbj"),
+    ("gbk", None, None, None, "gbk"),
+    ("gbl", None, None, None, "gbl"),
+    ("gbm", None, None, None, "gbm"),
+    ("gbn", None, None, None, "gbn"),
+    ("gbo", None, None, None, "gbo"),
+    ("gbp", None, None, None, "gbp"),
+    ("gbq", None, None, None, "gbq"),
+    ("gbr", None, None, None, "gbr"),
+    ("gbs", None, None, None, "gbs"),
+    ("gbu", None, None, None, "gbu"),
+    ("gbv", None, None, None, "gbv"),
+    ("gbw", None, None, None, "gbw"),
+    ("gbx", None, None, None, "gbx"),
+    ("gby", None, None, None, "gby"),
+    ("gbz", None, None, None, "gbz"),
+    ("gcc", None, None, None, "gcc"),
+    ("gcd", None, None, None, "gcd"),
+    ("gce", None, None, None, "gce"),
+    ("gcf", None, None, None, "gcf"),
+    ("gcl", None, None, None, "gcl"),
+    ("gcn", None, None, None, "gcn"),
+    ("gcr", None, None, None, "gcr"),
+    ("gct", None, None, None, "gct"),
+    ("gd", Some("gd"), Some("gla"), Some("gla"), "gla"),
+    ("gda", None, None, None, "gda"),
+    ("gdb", None, None, None, "gdb"),
+    ("gdc", None, None, None, "gdc"),
+    ("gdd", None, None, None, "gdd"),
+    ("gde", None, None, None, "gde"),
+    ("gdf", None, None, None, "gdf"),
+    ("gdg", None, None, None, "gdg"),
+    ("gdh", None, None, None, "gdh"),
+    ("gdi", None, None, None, "gdi"),
+    ("gdj", None, None, None, "gdj"),
+    ("gdk", None, None, None, "gdk"),
+    ("gdl", None, None, None, "gdl"),
+    ("gdm", None, None, None, "gdm"),
+    ("gdn", None, None, None, "gdn"),
+    ("gdo", None, None, None, "gdo"),
+    ("gdq", None, None, None, "gdq"),
+    ("gdr", None, None, None, "gdr"),
+    ("gds", None, None, None, "gds"),
+    ("gdt", None, None, None, "gdt"),
+    ("gdu", None, None, None, "gdu"),
+    ("gdx", None, None, None, "gdx"),
+    ("gea", None, None, None, "gea"),
+    ("geb", None, None, None, "geb"),
+    ("gec", None, None, None, "gec"),
+    ("ged", None, None, None, "ged"),
+    ("gef", None, None, None, "gef"),
+    ("geg", None, None, None, "geg"),
+    ("geh", None, None, None, "geh"),
+    ("gei", None, None, None, "gei"),
+    ("gej", None, None, None, "gej"),
+    ("gek", None, None, None, "gek"),
+    ("gel", None, None, None, "gel"),
+    ("geq", None, None, None, "geq"),
+    ("ges", None, None, None, "ges"),
+    ("gev", None, None, None, "gev"),
+    ("gew", None, None, None, "gew"),
+    ("gex", None, None, None, "gex"),
+    ("gey", None, None, None, "gey"),
+    ("gez", None, Some("gez"), Some("gez"), "gez"),
+    ("gfk", None, None, None, "gfk"),
+    ("gft", None, None, None, "gft"),
+    ("gga", None, None, None, "gga"),
+    ("ggb", None, None, None, "ggb"),
+    ("ggd", None, None, None, "ggd"),
+    ("gge", None, None, None, "gge"),
+    ("ggg", None, None, None, "ggg"),
+    ("ggk", None, None, None, "ggk"),
+    ("ggl", None, None, None, "ggl"),
+    ("ggt", None, None, None, "ggt"),
+    ("ggu", None, None, None, "ggu"),
+    ("ggw", None, None, None, "ggw"),
+    ("gha", None, None, None, "gha"),
+    ("ghc", None, None, None, "ghc"),
+    ("ghe", None, None, None, "ghe"),
+    ("ghh", None, None, None, "ghh"),
+    ("ghk", None, None, None, "ghk"),
+    ("ghl", None, None, None, "ghl"),
+    ("ghn", None, None, None, "ghn"),
+    ("gho", None, None, None, "gho"),
+    ("ghr", None, None, None, "ghr"),
+    ("ghs", None, None, None, "ghs"),
+    ("ght", None, None, None, "ght"),
+    ("gia", None, None, None, "gia"),
+    ("gib", None, None, None, "gib"),
+    ("gic", None, None, None, "gic"),
+    ("gid", None, None, None, "gid"),
+    ("gie", None, None, None, "gie"),
+    ("gig", None, None, None, "gig"),
+    ("gih", None, None, None, "gih"),
+    ("gii", None, None, None, "gii"),
+    ("gil", None, Some("gil"), Some("gil"), "gil"),
+    ("gim", None, None, None, "gim"),
+    ("gin", None, None, None, "gin"),
+    ("gip", None, None, None, "gip"),
+    ("giq", None, None, None, "giq"),
+    ("gir", None, None, None, "gir"),
+    ("gis", None, None, None, "gis"),
+    ("git", None, None, None, "git"),
+    ("giu", None, None, None, "giu"),
+    ("giw", None, None, None, "giw"),
+    ("gix", None, None, None, "gix"),
+    ("giy", None, None, None, "giy"),
+    ("giz", None, None, None, "giz"),
+    ("gjk", None, None, None, "gjk"),
+    ("gjm", None, None, None, "gjm"),
+    ("gjn", None, None, None, "gjn"),
+    ("gjr", None, None, None, "gjr"),
+    ("gju", None, None, None, "gju"),
+    ("gka", None, None, None, "gka"),
+    ("gkd", None, None, None, "gkd"),
+    ("gke", None, None, None, "gke"),
+    ("gkn", None, None, None, "gkn"),
+    ("gko", None, None, None, "gko"),
+    ("gkp", None, None, None, "gkp"),
+    ("gku", None, None, None, "gku"),
+    ("gl", Some("gl"), Some("glg"), Some("glg"), "glg"),
+    ("glb", None, None, None, "glb"),
+    ("glc", None, None, None, "glc"),
+    ("gld", None, None, None, "gld"),
+    ("glh", None, None, None, "glh"),
+    ("glj", None, None, None, "glj"),
+    ("glk", None, None, None, "glk"),
+    ("gll", None, None, None, "gll"),
+    ("glo", None, None, None, "glo"),
+    ("glr", None, None, None, "glr"),
+    ("glu", None, None, None, "glu"),
+    ("glw", None, None, None, "glw"),
+    ("gly", None, None, None, "gly"),
+    ("gma", None, None, None, "gma"),
+    ("gmb", None, None, None, "gmb"),
+    ("gmd", None, None, None, "gmd"),
+    ("gmg", None, None, None, "gmg"),
+    ("gmh", None, Some("gmh"), Some("gmh"), "gmh"),
+    ("gml", None, None, None, "gml"),
+    ("gmm", None, None, None, "gmm"),
+    ("gmn", None, None, None, "gmn"),
+    ("gmr", None, None, None, "gmr"),
+    ("gmu", None, None, None, "gmu"),
+    ("gmv", None, None, None, "gmv"),
+    ("gmx", None, None, None, "gmx"),
+    ("gmy", None, None, None, "gmy"),
+    ("gmz", None, None, None, "gmz"),
+    ("gn", Some("gn"), Some("grn"), Some("grn"), "grn"),
+    ("gna", None, None, None, "gna"),
+    ("gnb", None, None, None, "gnb"),
+    ("gnc", None, None, None, "gnc"),
+    ("gnd", None, None, None, "gnd"),
+    ("gne", None, None, None, "gne"),
+    ("gng", None, None, None, "gng"),
+    ("gnh", None, None, None, "gnh"),
+    ("gni", None, None, None, "gni"),
+    ("gnj", None, None, None, "gnj"),
+    ("gnk", None, None, None, "gnk"),
+    ("gnl", None, None, None, "gnl"),
+    ("gnm", None, None, None, "gnm"),
+    ("gnn", None, None, None, "gnn"),
+    ("gno", None, None, None, "gno"),
+    ("gnq", None, None, None, "gnq"),
+    ("gnr", None, None, None, "gnr"),
+    ("gnt", None, None, None, "gnt"),
+    ("gnu", None, None, None, "gnu"),
+    ("gnw", None, None, None, "gnw"),
+    ("gnz", None, None, None, "gnz"),
+    ("goa", None, None, None, "goa"),
+    ("gob", None, None, None, "gob"),
+    ("goc", None, None, None, "goc"),
+    ("god", None, None, None, "god"),
+    ("goe", None, None, None, "goe"),
+    ("gof", None, None, None, "gof"),
+    ("gog", None, None, None, "gog"),
+    ("goh", None, Some("goh"), Some("goh"), "goh"),
+    ("goi", None, None, None, "goi"),
+    ("goj", None, None, None, "goj"),
+    ("gok", None, None, None, "gok"),
+    ("gol", None, None, None, "gol"),
+    ("gom", None, None, None, "gom"),
+    ("gon", None, Some("gon"), Some("gon"), "gon"),
+    ("goo", None, None, None, "goo"),
+    ("gop", None, None, None, "gop"),
+    ("goq", None, None, None, "goq"),
+    ("gor", None, Some("gor"), Some("gor"), "gor"),
+    ("gos", None, None, None, "gos"),
+    ("got", None, Some("got"), Some("got"), "got"),
+    ("gou", None, None, None, "gou"),
+    ("gov", None, None, None, "gov"),
+    ("gow", None, None, None, "gow"),
+    ("gox", None, None, None, "gox"),
+    ("goy", None, None, None, "goy"),
+    ("goz", None, None, None, "goz"),
+    ("gpa", None, None, None, "gpa"),
+    ("gpe", None, None, None, "gpe"),
+    ("gpn", None, None, None, "gpn"),
+    ("gqa", None, None, None, "gqa"),
+    ("gqi", None, None, None, "gqi"),
+    ("gqn", None, None, None, "gqn"),
+    ("gqr", None, None, None, "gqr"),
+    ("gqu", None, None, None, "gqu"),
+    ("gra", None, None, None, "gra"),
+    ("grb", None, Some("grb"), Some("grb"), "grb"),
+    ("grc", None, Some("grc"), Some("grc"), "grc"),
+    ("grd", None, None, None, "grd"),
+    ("grg", None, None, None, "grg"),
+    ("grh", None, None, None, "grh"),
+    ("gri", None, None, None, "gri"),
+    ("grj", None, None, None, "grj"),
+    ("grm", None, None, None, "grm"),
+    ("gro", None, None, None, "gro"),
+    ("grq", None, None, None, "grq"),
+    ("grr", None, None, None, "grr"),
+    ("grs", None, None, None, "grs"),
+    ("grt", None, None, None, "grt"),
+    ("gru", None, None, None, "gru"),
+    ("grv", None, None, None, "grv"),
+    ("grw", None, None, None, "grw"),
+    ("grx", None, None, None, "grx"),
+    ("gry", None, None, None, "gry"),
+    ("grz", None, None, None, "grz"),
+    ("gse", None, None, None, "gse"),
+    ("gsg", None, None, None, "gsg"),
+    ("gsl", None, None, None, "gsl"),
+    ("gsm", None, None, None, "gsm"),
+    ("gsn", None, None, None, "gsn"),
+    ("gso", None, None, None, "gso"),
+    ("gsp", None, None, None, "gsp"),
+    ("gss", None, None, None, "gss"),
+    ("gsw", None, Some("gsw"), Some("gsw"), "gsw"),
+    ("gta", None, None, None, "gta"),
+    ("gtu", None, None, None, "gtu"),
+    ("gu", Some("gu"), Some("guj"), Some("guj"), "guj"),
+    ("gua", None, None, None, "gua"),
+    ("gub", None, None, None, "gub"),
+    ("guc", None, None, None, "guc"),
+    ("gud", None, None, None, "gud"),
+    ("gue", None, None, None, "gue"),
+    ("guf", None, None, None, "guf"),
+    ("gug", None, None, None, "gug"),
+    ("guh", None, None, None, "guh"),
+    ("gui", None, None, None, "gui"),
+    ("guk", None, None, None, "guk"),
+    ("gul", None, None, None, "gul"),
+    ("gum", None, None, None, "gum"),
+    ("gun", None, None, None, "gun"),
+    ("guo", None, None, None, "guo"),
+    ("gup", None, None, None, "gup"),
+    ("guq", None, None, None, "guq"),
+    ("gur", None, None, None, "gur"),
+    ("gus", None, None, None, "gus"),
+    ("gut", None, None, None, "gut"),
+    ("guu", None, None, None, "guu"),
+    ("guw", None, None, None, "guw"),
+    ("gux", None, None, None, "gux"),
+    ("guz", None, None, None, "guz"),
+    ("gv", Some("gv"), Some("glv"), Some("glv"), "glv"),
+    ("gva", None, None, None, "gva"),
+    ("gvc", None, None, None, "gvc"),
+    ("gve", None, None, None, "gve"),
+    ("gvf", None, None, None, "gvf"),
+    ("gvj", None, None, None, "gvj"),
+    ("gvl", None, None, None, "gvl"),
+    ("gvm", None, None, None, "gvm"),
+    ("gvn", None, None, None, "gvn"),
+    ("gvo", None, None, None, "gvo"),
+    ("gvp", None, None, None, "gvp"),
+    ("gvr", None, None, None, "gvr"),
+    ("gvs", None, None, None, "gvs"),
+    ("gvy", None, None, None, "gvy"),
+    ("gwa", None, None, None, "gwa"),
+    ("gwb", None, None, None, "gwb"),
+    ("gwc", None, None, None, "gwc"),
+    ("gwd", None, None, None, "gwd"),
+    ("gwe", None, None, None, "gwe"),
+    ("gwf", None, None, None, "gwf"),
+    ("gwg", None, None, None, "gwg"),
+    ("gwi", None, Some("gwi"), Some("gwi"), "gwi"),
+    ("gwj", None, None, None, "gwj"),
+    ("gwm", None, None, None, "gwm"),
+    ("gwn", None, None, None, "gwn"),
+    ("gwr", None, None, None, "gwr"),
+    ("gwt", None, None, None, "gwt"),
+    ("gwu", None, None, None, "gwu"),
+    ("gww", None, None, None, "gww"),
+    ("gwx", None, None, None, "gwx"),
+    ("gxx", None, None, None, "gxx"),
+    ("gya", None, None, None, "gya"),
+    ("gyb", None, None, None, "gyb"),
+    ("gyd", None, None, None, "gyd"),
+    ("gye", None, None, None, "gye"),
+    ("gyf", None, None, None, "gyf"),
+    ("gyg", None, None, None, "gyg"),
+    ("gyi", None, None, None, "gyi"),
+    ("gyl", None, None, None, "gyl"),
+    ("gym", None, None, None, "gym"),
+    ("gyn", None, None, None, "gyn"),
+    ("gyo", None, None, None, "gyo"),
+    ("gyr", None, None, None, "gyr"),
+    ("gyy", None, None, None, "gyy"),
+    ("gyz", None, None, None, "gyz"),
+    ("gza", None, None, None, "gza"),
+    ("gzi", None, None, None, "gzi"),
+    ("gzn", None, None, None, "gzn"),
+    ("ha", Some("ha"), Some("hau"), Some("hau"), "hau"),
+    ("haa", None, None, None, "haa"),
+    ("hab", None, None, None, "hab"),
+    ("hac", None, None, None, "hac"),
+    ("had", None, None, None, "had"),
+    ("hae", None, None, None, "hae"),
+    ("haf", None, None, None, "haf"),
+    ("hag", None, None, None, "hag"),
+    ("hah", None, None, None, "hah"),
+    ("hai", None, Some("hai"), Some("hai"), "hai"),
+    ("haj", None, None, None, "haj"),
+    ("hak", None, None, None, "hak"),
+    ("hal", None, None, None, "hal"),
+    ("ham", None, None, None, "ham"),
+    ("han", None, None, None, "han"),
+    ("hao", None, None, None, "hao"),
+    ("hap", None, None, None, "hap"),
+    ("haq", None, None, None, "haq"),
+    ("har", None, None, None, "har"),
+    ("has", None, None, None, "has"),
+    ("hav", None, None, None, "hav"),
+    ("haw", None, Some("haw"), Some("haw"), "haw"),
+    ("hax", None, None, None, "hax"),
+    ("hay", None, None, None, "hay"),
+    ("haz", None, None, None, "haz"),
+    ("hba", None, None, None, "hba"),
+    ("hbb", None, None, None, "hbb"),
+    ("hbn", None, None, None, "hbn"),
+    ("hbo", None, None, None, "hbo"),
+    ("hbu", None, None, None, "hbu"),
+    ("hca", None, None, None, "hca"),
+    ("hch", None, None, None, "hch"),
+    ("hdn", None, None, None, "hdn"),
+    ("hds", None, None, None, "hds"),
+    ("hdy", None, None, None, "hdy"),
+    ("he", Some("he"), Some("heb"), Some("heb"), "heb"),
+    ("hea", None, None, None, "hea"),
+    ("hed", None, None, None, "hed"),
+    ("heg", None, None, None, "heg"),
+    ("heh", None, None, None, "heh"),
+    ("hei", None, None, None, "hei"),
+    ("hem", None, None, None, "hem"),
+    ("hgm", None, None, None, "hgm"),
+    ("hgw", None, None, None, "hgw"),
+    ("hhi", None, None, None, "hhi"),
+    ("hhr", None, None, None, "hhr"),
+    ("hhy", None, None, None, "hhy"),
+    ("hi", Some("hi"), Some("hin"), Some("hin"), "hin"),
+    ("hia", None, None, None, "hia"),
+    ("hib", None, None, None, "hib"),
+    ("hid", None, None, None, "hid"),
+    ("hif", None, None, None, "hif"),
+    ("hig", None, None, None, "hig"),
+    ("hih", None, None, None, "hih"),
+    ("hii", None, None, None, "hii"),
+    ("hij", None, None, None, "hij"),
+    ("hik", None, None, None, "hik"),
+    ("hil", None, Some("hil"), Some("hil"), "hil"),
+    ("hio", None, None, None, "hio"),
+    ("hir", None, None, None, "hir"),
+    ("hit", None, Some("hit"), Some("hit"), "hit"),
+    ("hiw", None, None, None, "hiw"),
+    ("hix", None, None, None, "hix"),
+    ("hji", None, None, None, "hji"),
+    ("hka", None, None, None, "hka"),
+    ("hke", None, None, None, "hke"),
+    ("hkh", None, None, None, "hkh"),
+    ("hkk", None, None, None, "hkk"),
+    ("hkn", None, None, None, "hkn"),
+    ("hks", None, None, None, "hks"),
+    ("hla", None, None, None, "hla"),
+    ("hlb", None, None, None, "hlb"),
+    ("hld", None, None, None, "hld"),
+    ("hle", None, None, None, "hle"),
+    ("hlt", None, None, None, "hlt"),
+    ("hlu", None, None, None, "hlu"),
+    ("hma", None, None, None, "hma"),
+    ("hmb", None, None, None, "hmb"),
+    ("hmc", None, None, None, "hmc"),
+    ("hmd", None, None, None, "hmd"),
+    ("hme", None, None, None, "hme"),
+    ("hmf", None, None, None, "hmf"),
+    ("hmg", None, None, None, "hmg"),
+    ("hmh", None, None, None, "hmh"),
+    ("hmi", None, None, None, "hmi"),
+    ("hmj", None, None, None, "hmj"),
+    ("hmk", None, None, None, "hmk"),
+    ("hml", None, None, None, "hml"),
+    ("hmm", None, None, None, "hmm"),
+    ("hmn", None, Some("hmn"), Some("hmn"), "hmn"),
+    ("hmp", None, None, None, "hmp"),
+    ("hmq", None, None, None, "hmq"),
+    ("hmr", None, None, None, "hmr"),
+    ("hms", None, None, None, "hms"),
+    ("hmt", None, None, None, "hmt"),
+    ("hmu", None, None, None, "hmu"),
+    ("hmv", None, None, None, "hmv"),
+    ("hmw", None, None, None, "hmw"),
+    ("hmy", None, None, None, "hmy"),
+    ("hmz", None, None, None, "hmz"),
+    ("hna", None, None, None, "hna"),
+    ("hnd", None, None, None, "hnd"),
+    ("hne", None, None, None, "hne"),
+    ("hng", None, None, None, "hng"),
+    ("hnh", None, None, None, "hnh"),
+    ("hni", None, None, None, "hni"),
+    ("hnj", None, None, None, "hnj"),
+    ("hnn", None, None, None, "hnn"),
+    ("hno", None, None, None, "hno"),
+    ("hns", None, None, None, "hns"),
+    ("hnu", None, None, None, "hnu"),
+    ("ho", Some("ho"), Some("hmo"), Some("hmo"), "hmo"),
+    ("hoa", None, None, None, "hoa"),
+    ("hob", None, None, None, "hob"),
+    ("hoc", None, None, None, "hoc"),
+    ("hod", None, None, None, "hod"),
+    ("hoe", None, None, None, "hoe"),
+    ("hoh", None, None, None, "hoh"),
+    ("hoi", None, None, None, "hoi"),
+    ("hoj", None, None, None, "hoj"),
+    ("hol", None, None, None, "hol"),
+    ("hom", None, None, None, "hom"),
+    ("hoo", None, None, None, "hoo"),
+    ("hop", None, None, None, "hop"),
+    ("hor", None, None, None, "hor"),
+    ("hos", None, None, None, "hos"),
+    ("hot", None, None, None, "hot"),
+    ("hov", None, None, None, "hov"),
+    ("how", None, None, None, "how"),
+    ("hoy", None, None, None, "hoy"),
+    ("hoz", None, None, None, "hoz"),
+    ("hpo", None, None, None, "hpo"),
+    ("hps", None, None, None, "hps"),
+    ("hr", Some("hr"), Some("hrv"), Some("hrv"), "hrv"),
+    ("hra", None, None, None, "hra"),
+    ("hrc", None, None, None, "hrc"),
+    ("hre", None, None, None, "hre"),
+    ("hrk", None, None, None, "hrk"),
+    ("hrm", None, None, None, "hrm"),
+    ("hro", None, None, None, "hro"),
+    ("hrp", None, None, None, "hrp"),
+    ("hrt", None, None, None, "hrt"),
+    ("hru", None, None, None, "hru"),
+    ("hrw", None, None, None, "hrw"),
+    ("hrx", None, None, None, "hrx"),
+    ("hrz", None, None, None, "hrz"),
+    ("hsb", None, Some("hsb"), Some("hsb"), "hsb"),
+    ("hsh", None, None, None, "hsh"),
+    ("hsl", None, None, None, "hsl"),
+    ("hsn", None, None, None, "hsn"),
+    ("hss", None, None, None, "hss"),
+    ("ht", Some("ht"), Some("hat"), Some("hat"), "hat"),
+    ("hti", None, None, None, "hti"),
+    ("hto", None, None, None, "hto"),
+    ("hts", None, None, None, "hts"),
+    ("htu", None, None, None, "htu"),
+    ("htx", None, None, None, "htx"),
+    ("hu", Some("hu"), Some("hun"), Some("hun"), "hun"),
+    ("hub", None, None, None, "hub"),
+    ("huc", None, None, None, "huc"),
+    ("hud", None, None, None, "hud"),
+    ("hue", None, None, None, "hue"),
+    ("huf", None, None, None, "huf"),
+    ("hug", None, None, None, "hug"),
+    ("huh", None, None, None, "huh"),
+    ("hui", None, None, None, "hui"),
+    ("huj", None, None, None, "huj"),
+    ("huk", None, None, None, "huk"),
+    ("hul", None, None, None, "hul"),
+    ("hum", None, None, None, "hum"),
+    ("huo", None, None, None, "huo"),
+    ("hup", None, Some("hup"), Some("hup"), "hup"),
+    ("huq", None, None, None, "huq"),
+    ("hur", None, None, None, "hur"),
+    ("hus", None, None, None, "hus"),
+    ("hut", None, None, None, "hut"),
+    ("huu", None, None, None, "huu"),
+    ("huv", None, None, None, "huv"),
+    ("huw", None, None, None, "huw"),
+    ("hux", None, None, None, "hux"),
+    ("huy", None, None, None, "huy"),
+    ("huz", None, None, None, "huz"),
+    ("hvc", None, None, None, "hvc"),
+    ("hve", None, None, None, "hve"),
+    ("hvk", None, None, None, "hvk"),
+    ("hvn", None, None, None, "hvn"),
+    ("hvv", None, None, None, "hvv"),
+    ("hwa", None, None, None, "hwa"),
+    ("hwc", None, None, None, "hwc"),
+    ("hwo", None, None, None, "hwo"),
+    ("hy", Some("hy"), Some("hye"), Some("arm"), "hye"),
+    ("hya", None, None, None, "hya"),
+    ("hyw", None, None, None, "hyw"),
+    ("hz", Some("hz"), Some("her"), Some("her"), "her"),
+    ("ia", Some("ia"), Some("ina"), Some("ina"), "ina"),
+    ("iai", None, None, None, "iai"),
+    ("ian", None, None, None, "ian"),
+    ("iar", None, None, None, "iar"),
+    ("iba", None, Some("iba"), Some("iba"), "iba"),
+    ("ibb", None, None, None, "ibb"),
+    ("ibd", None, None, None, "ibd"),
+    ("ibe", None, None, None, "ibe"),
+    ("ibg", None, None, None, "ibg"),
+    ("ibh", None, None, None, "ibh"),
+    ("ibl", None, None, None, "ibl"),
+    ("ibm", None, None, None, "ibm"),
+    ("ibn", None, None, None, "ibn"),
+    ("ibr", None, None, None, "ibr"),
+    ("ibu", None, None, None, "ibu"),
+    ("iby", None, None, None, "iby"),
+    ("ica", None, None, None, "ica"),
+    ("ich", None, None, None, "ich"),
+    ("icl", None, None, None, "icl"),
+    ("icr", None, None, None, "icr"),
+    ("id", Some("id"), Some("ind"), Some("ind"), "ind"),
+    ("ida", None, None, None, "ida"),
+    ("idb", None, None, None, "idb"),
+    ("idc", None, None, None, "idc"),
+    ("idd", None, None, None, "idd"),
+    ("ide", None, None, None, "ide"),
+    ("idi", None, None, None, "idi"),
+    ("idr", None, None, None, "idr"),
+    ("ids", None, None, None, "ids"),
+    ("idt", None, None, None, "idt"),
+    ("idu", None, None, None, "idu"),
+    ("ie", Some("ie"), Some("ile"), Some("ile"), "ile"),
+    ("ifa", None, None, None, "ifa"),
+    ("ifb", None, None, None, "ifb"),
+    ("ife", None, None, None, "ife"),
+    ("iff", None, None, None, "iff"),
+    ("ifk", None, None, None, "ifk"),
+    ("ifm", None, None, None, "ifm"),
+    ("ifu", None, None, None, "ifu"),
+    ("ify", None, None, None, "ify"),
+    ("ig", Some("ig"), Some("ibo"), Some("ibo"), "ibo"),
+    ("igb", None, None, None, "igb"),
+    ("ige", None, None, None, "ige"),
+    ("igg", None, None, None, "igg"),
+    ("igl", None, None, None, "igl"),
+    ("igm", None, None, None, "igm"),
+    ("ign", None, None, None, "ign"),
+    ("igo", None, None, None, "igo"),
+    ("igs", None, None, None, "igs"),
+    ("igw", None, None, None, "igw"),
+    ("ihb", None, None, None, "ihb"),
+    ("ihi", None, None, None, "ihi"),
+    ("ihp", None, None, None, "ihp"),
+    ("ihw", None, None, None, "ihw"),
+    ("ii", Some("ii"), Some("iii"), Some("iii"), "iii"),
+    ("iin", None, None, None, "iin"),
+    ("ijc", None, None, None, "ijc"),
+    ("ije", None, None, None, "ije"),
+    ("ijj", None, None, None, "ijj"),
+    ("ijn", None, None, None, "ijn"),
+    ("ijs", None, None, None, "ijs"),
+    ("ik", Some("ik"), Some("ipk"), Some("ipk"), "ipk"),
+    ("ike", None, None, None, "ike"),
+    ("iki", None, None, None, "iki"),
+    ("ikk", None, None, None, "ikk"),
+    ("ikl", None, None, None, "ikl"),
+    ("iko", None, None, None, "iko"),
+    ("ikp", None, None, None, "ikp"),
+    ("ikr", None, None, None, "ikr"),
+    ("iks", None, None, None, "iks"),
+    ("ikt", None, None, None, "ikt"),
+    ("ikv", None, None, None, "ikv"),
+    ("ikw", None, None, None, "ikw"),
+    ("ikx", None, None, None, "ikx"),
+    ("ikz", None, None, None, "ikz"),
+    ("ila", None, None, None, "ila"),
+    ("ilb", None, None, None, "ilb"),
+    ("ilg", None, None, None, "ilg"),
+    ("ili", None, None, None, "ili"),
+    ("ilk", None, None, None, "ilk"),
+    ("ilm", None, None, None, "ilm"),
+    ("ilo", None, Some("ilo"), Some("ilo"), "ilo"),
+    ("ilp", None, None, None, "ilp"),
+    ("ils", None, None, None, "ils"),
+    ("ilu", None, None, None, "ilu"),
+    ("ilv", None, None, None, "ilv"),
+    ("ima", None, None, None, "ima"),
+    ("imi", None, None, None, "imi"),
+    ("iml", None, None, None, "iml"),
+    ("imn", None, None, None, "imn"),
+    ("imo", None, None, None, "imo"),
+    ("imr", None, None, None, "imr"),
+    ("ims", None, None, None, "ims"),
+    ("imt", None, None, None, "imt"),
+    ("imy", None, None, None, "imy"),
+    ("inb", None, None, None, "inb"),
+    ("ing", None, None, None, "ing"),
+    ("inh", None, Some("inh"), Some("inh"), "inh"),
+    ("inj", None, None, None, "inj"),
+    ("inl", None, None, None, "inl"),
+    ("inm", None, None, None, "inm"),
+    ("inn", None, None, None, "inn"),
+    ("ino", None, None, None, "ino"),
+    ("inp", None, None, None, "inp"),
+    ("ins", None, None, None, "ins"),
+    ("int", None, None, None, "int"),
+    ("inz", None, None, None, "inz"),
+    ("io", Some("io"), Some("ido"), Some("ido"), "ido"),
+    ("ior", None, None, None, "ior"),
+    ("iou", None, None, None, "iou"),
+    ("iow", None, None, None, "iow"),
+    ("ipi", None, None, None, "ipi"),
+    ("ipo", None, None, None, "ipo"),
+    ("iqu", None, None, None, "iqu"),
+    ("iqw", None, None, None, "iqw"),
+    ("ire", None, None, None, "ire"),
+    ("irh", None, None, None, "irh"),
+    ("iri", None, None, None, "iri"),
+    ("irk", None, None, None, "irk"),
+    ("irn", None, None, None, "irn"),
+    ("irr", None, None, None, "irr"),
+    ("iru", None, None, None, "iru"),
+    ("irx", None, None, None, "irx"),
+    ("iry", None, None, None, "iry"),
+    ("is", Some("is"), Some("isl"), Some("ice"), "isl"),
+    ("isa", None, None, None, "isa"),
+    ("isc", None, None, None, "isc"),
+    ("isd", None, None, None, "isd"),
+    ("ise", None, None, None, "ise"),
+    ("isg", None, None, None, "isg"),
+    ("ish", None, None, None, "ish"),
+    ("isi", None, None, None, "isi"),
+    ("isk", None, None, None, "isk"),
+    ("ism", None, None, None, "ism"),
+    ("isn", None, None, None, "isn"),
+    ("iso", None, None, None, "iso"),
+    ("isr", None, None, None, "isr"),
+    ("ist", None, None, None, "ist"),
+    ("isu", None, None, None, "isu"),
+    ("it", Some("it"), Some("ita"), Some("ita"), "ita"),
+    ("itb", None, None, None, "itb"),
+    ("itd", None, None, None, "itd"),
+    ("ite", None, None, None, "ite"),
+    ("iti", None, None, None, "iti"),
+    ("itk", None, None, None, "itk"),
+    ("itl", None, None, None, "itl"),
+    ("itm", None, None, None, "itm"),
+    ("ito", None, None, None, "ito"),
+    ("itr", None, None, None, "itr"),
+    ("its", None, None, None, "its"),
+    ("itt", None, None, None, "itt"),
+    ("itv", None, None, None, "itv"),
+    ("itw", None, None, None, "itw"),
+    ("itx", None, None, None, "itx"),
+    ("ity", None, None, None, "ity"),
+    ("itz", None, None, None, "itz"),
+    ("iu", Some("iu"), Some("iku"), Some("iku"), "iku"),
+    ("ium", None, None, None, "ium"),
+    ("ivb", None, None, None, "ivb"),
+    ("ivv", None, None, None, "ivv"),
+    ("iwk", None, None, None, "iwk"),
+    ("iwm", None, None, None, "iwm"),
+    ("iwo", None, None, None, "iwo"),
+    ("iws", None, None, None, "iws"),
+    ("ixc", None, None, None, "ixc"),
+    ("ixl", None, None, None, "ixl"),
+    ("iya", None, None, None, "iya"),
+    ("iyo", None, None, None, "iyo"),
+    ("iyx", None, None, None, "iyx"),
+    ("izh", None, None, None, "izh"),
+    ("izr", None, None, None, "izr"),
+    ("izz", None, None, None, "izz"),
+    ("ja", Some("ja"), Some("jpn"), Some("jpn"), "jpn"),
+    ("jaa", None, None, None, "jaa"),
+    ("jab", None, None, None, "jab"),
+    ("jac", None, None, None, "jac"),
+    ("jad", None, None, None, "jad"),
+    ("jae", None, None, None, "jae"),
+    ("jaf", None, None, None, "jaf"),
+    ("jah", None, None, None, "jah"),
+    ("jaj", None, None, None, "jaj"),
+    ("jak", None, None, None, "jak"),
+    ("jal", None, None, None, "jal"),
+    ("jam", None, None, None, "jam"),
+    ("jan", None, None, None, "jan"),
+    ("jao", None, None, None, "jao"),
+    ("jaq", None, None, None, "jaq"),
+    ("jas", None, None, None, "jas"),
+    ("jat", None, None, None, "jat"),
+    ("jau", None, None, None, "jau"),
+    ("jax", None, None, None, "jax"),
+    ("jay", None, None, None, "jay"),
+    ("jaz", None, None, None, "jaz"),
+    ("jbe", None, None, None, "jbe"),
+    ("jbi", None, None, None, "jbi"),
+    ("jbj", None, None, None, "jbj"),
+    ("jbk", None, None, None, "jbk"),
+    ("jbm", None, None, None, "jbm"),
+    ("jbn", None, None, None, "jbn"),
+    ("jbo", None, Some("jbo"), Some("jbo"), "jbo"),
+    ("jbr", None, None, None, "jbr"),
+    ("jbt", None, None, None, "jbt"),
+    ("jbu", None, None, None, "jbu"),
+    ("jbw", None, None, None, "jbw"),
+    ("jcs", None, None, None, "jcs"),
+    ("jct", None, None, None, "jct"),
+    ("jda", None, None, None, "jda"),
+    ("jdg", None, None, None, "jdg"),
+    ("jdt", None, None, None, "jdt"),
+    ("jeb", None, None, None, "jeb"),
+    ("jee", None, None, None, "jee"),
+    ("jeh", None, None, None, "jeh"),
+    ("jei", None, None, None, "jei"),
+    ("jek", None, None, None, "jek"),
+    ("jel", None, None, None, "jel"),
+    ("jen", None, None, None, "jen"),
+    ("jer", None, None, None, "jer"),
+    ("jet", None, None, None, "jet"),
+    ("jeu", None, None, None, "jeu"),
+    ("jgb", None, None, None, "jgb"),
+    ("jge", None, None, None, "jge"),
+    ("jgk", None, None, None, "jgk"),
+    ("jgo", None, None, None, "jgo"),
+    ("jhi", None, None, None, "jhi"),
+    ("jhs", None, None, None, "jhs"),
+    ("jia", None, None, None, "jia"),
+    ("jib", None, None, None, "jib"),
+    ("jic", None, None, None, "jic"),
+    ("jid", None, None, None, "jid"),
+    ("jie", None, None, None, "jie"),
+    ("jig", None, None, None, "jig"),
+    ("jih", None, None, None, "jih"),
+    ("jii", None, None, None, "jii"),
+    ("jil", None, None, None, "jil"),
+    ("jim", None, None, None, "jim"),
+    ("jio", None, None, None, "jio"),
+    ("jiq", None, None, None, "jiq"),
+    ("jit", None, None, None, "jit"),
+    ("jiu", None, None, None, "jiu"),
+    ("jiv", None, None, None, "jiv"),
+    ("jiy", None, None, None, "jiy"),
+    ("jje", None, None, None, "jje"),
+    ("jjr", None, None, None, "jjr"),
+    ("jka", None, None, None, "jka"),
+    ("jkm", None, None, None, "jkm"),
+    ("jko", None, None, None, "jko"),
+    ("jkp", None, None, None, "jkp"),
+    ("jkr", None, None, None, "jkr"),
+    ("jks", None, None, None, "jks"),
+    ("jku", None, None, None, "jku"),
+    ("jle", None, None, None, "jle"),
+    ("jls", None, None, None, "jls"),
+    ("jma", None, None, None, "jma"),
+    ("jmb", None, None, None, "jmb"),
+    ("jmc", None, None, None, "jmc"),
+    ("jmd", None, None, None, "jmd"),
+    ("jmi", None, None, None, "jmi"),
+    ("jml", None, None, None, "jml"),
+    ("jmn", None, None, None, "jmn"),
+    ("jmr", None, None, None, "jmr"),
+    ("jms", None, None, None, "jms"),
+    ("jmw", None, None, None, "jmw"),
+    ("jmx", None, None, None, "jmx"),
+    ("jna", None, None, None, "jna"),
+    ("jnd", None, None, None, "jnd"),
+    ("jng", None, None, None, "jng"),
+    ("jni", None, None, None, "jni"),
+    ("jnj", None, None, None, "jnj"),
+    ("jnl", None, None, None, "jnl"),
+    ("jns", None, None, None, "jns"),
+    ("job", None, None, None, "job"),
+    ("jod", None, None, None, "jod"),
+    ("jog", None, None, None, "jog"),
+    ("jor", None, None, None, "jor"),
+    ("jos", None, None, None, "jos"),
+    ("jow", None, None, None, "jow"),
+    ("jpa", None, None, None, "jpa"),
+    ("jpr", None, Some("jpr"), Some("jpr"), "jpr"),
+    ("jqr", None, None, None, "jqr"),
+    ("jra", None, None, None, "jra"),
+    ("jrb", None, Some("jrb"), Some("jrb"), "jrb"),
+    ("jrr", None, None, None, "jrr"),
+    ("jrt", None, None, None, "jrt"),
+    ("jru", None, None, None, "jru"),
+    ("jsl", None, None, None, "jsl"),
+    ("jua", None, None, None, "jua"),
+    ("jub", None, None, None, "jub"),
+    ("juc", None, None, None, "juc"),
+    ("jud", None, None, None, "jud"),
+    ("juh", None, None, None, "juh"),
+    ("jui", None, None, None, "jui"),
+    ("juk", None, None, None, "juk"),
+    ("jul", None, None, None, "jul"),
+    ("jum", None, None, None, "jum"),
+    ("jun", None, None, None, "jun"),
+    ("juo", None, None, None, "juo"),
+    ("jup", None, None, None, "jup"),
+    ("jur", None, None, None, "jur"),
+    ("jus", None, None, None, "jus"),
+    ("jut", None, None, None, "jut"),
+    ("juu", None, None, None, "juu"),
+    ("juw", None, None, None, "juw"),
+    ("juy", None, None, None, "juy"),
+    ("jv", Some("jv"), Some("jav"), Some("jav"), "jav"),
+    ("jvd", None, None, None, "jvd"),
+    ("jvn", None, None, None, "jvn"),
+    ("jwi", None, None, None, "jwi"),
+    ("jya", None, None, None, "jya"),
+    ("jye", None, None, None, "jye"),
+    ("jyy", None, None, None, "jyy"),
+    ("ka", Some("ka"), Some("kat"), Some("geo"), "kat"),
+    ("kaa", None, Some("kaa"), Some("kaa"), "kaa"),
+    ("kab", None, Some("kab"), Some("kab"), "kab"),
+    ("kac", None, Some("kac"), Some("kac"), "kac"),
+    ("kad", None, None, None, "kad"),
+    ("kae", None, None, None, "kae"),
+    ("kaf", None, None, None, "kaf"),
+    ("kag", None, None, None, "kag"),
+    ("kah", None, None, None, "kah"),
+    ("kai", None, None, None, "kai"),
+    ("kaj", None, None, None, "kaj"),
+    ("kak", None, None, None, "kak"),
+    ("kam", None, Some("kam"), Some("kam"), "kam"),
+    ("kao", None, None, None, "kao"),
+    ("kap", None, None, None, "kap"),
+    ("kaq", None, None, None, "kaq"),
+    ("kav", None, None, None, "kav"),
+    ("kaw", None, Some("kaw"), Some("kaw"), "kaw"),
+    ("kax", None, None, None, "kax"),
+    ("kay", None, None, None, "kay"),
+    ("kba", None, None, None, "kba"),
+    ("kbb", None, None, None, "kbb"),
+    ("kbc", None, None, None, "kbc"),
+    ("kbd", None, Some("kbd"), Some("kbd"), "kbd"),
+    ("kbe", None, None, None, "kbe"),
+    ("kbg", None, None, None, "kbg"),
+    ("kbh", None, None, None, "kbh"),
+    ("kbi", None, None, None, "kbi"),
+    ("kbj", None, None, None, "kbj"),
+    ("kbk", None, None, None, "kbk"),
+    ("kbl", None, None, None, "kbl"),
+    ("kbm", None, None, None, "kbm"),
+    ("kbn", None, None, None, "kbn"),
+    ("kbo", None, None, None, "kbo"),
+    ("kbp", None, None, None, "kbp"),
+    ("kbq", None, None, None, "kbq"),
+    ("kbr", None, None, None, "kbr"),
+    ("kbs", None, None, None, "kbs"),
+    ("kbt", None, None, None, "kbt"),
+    ("kbu", None, None, None, "kbu"),
+    ("kbv", None, None, None, "kbv"),
+    ("kbw", None, None, None, "kbw"),
+    ("kbx", None, None, None, "kbx"),
+    ("kby", None, None, None, "kby"),
+    ("kbz", None, None, None, "kbz"),
+    ("kca", None, None, None, "kca"),
+    ("kcb", None, None, None, "kcb"),
+    ("kcc", None, None, None, "kcc"),
+    ("kcd", None, None, None, "kcd"),
+    ("kce", None, None, None, "kce"),
+    ("kcf", None, None, None, "kcf"),
+    ("kcg", None, None, None, "kcg"),
+    ("kch", None, None, None, "kch"),
+    ("kci", None, None, None, "kci"),
+    ("kcj", None, None, None, "kcj"),
+    ("kck", None, None, None, "kck"),
+    ("kcl", None, None, None, "kcl"),
+    ("kcm", None, None, None, "kcm"),
+    ("kcn", None, None, None, "kcn"),
+    ("kco", None, None, None, "kco"),
+    ("kcp", None, None, None, "kcp"),
+    ("kcq", None, None, None, "kcq"),
+    ("kcr", None, None, None, "kcr"),
+    ("kcs", None, None, None, "kcs"),
+    ("kct", None, None, None, "kct"),
+    ("kcu", None, None, None, "kcu"),
+    ("kcv", None, None, None, "kcv"),
+    ("kcw", None, None, None, "kcw"),
+    ("kcx", None, None, None, "kcx"),
+    ("kcy", None, None, None, "kcy"),
+    ("kcz", None, None, None, "kcz"),
+    ("kda", None, None, None, "kda"),
+    ("kdc", None, None, None, "kdc"),
+    ("kdd", None, None, None, "kdd"),
+    ("kde", None, None, None, "kde"),
+    ("kdf", None, None, None, "kdf"),
+    ("kdg", None, None, None, "kdg"),
+    ("kdh", None, None, None, "kdh"),
+    ("kdi", None, None, None, "kdi"),
+    ("kdj", None, None, None, "kdj"),
+    ("kdk", None, None, None, "kdk"),
+    ("kdl", None, None, None, "kdl"),
+    ("kdm", None, None, None, "kdm"),
+    ("kdn", None, None, None, "kdn"),
+    ("kdp", None, None, None, "kdp"),
+    ("kdq", None, None, None, "kdq"),
+    ("kdr", None, None, None, "kdr"),
+    ("kdt", None, None, None, "kdt"),
+    ("kdu", None, None, None, "kdu"),
+    ("kdw", None, None, None, "kdw"),
+    ("kdx", None, None, None, "kdx"),
+    ("kdy", None, None, None, "kdy"),
+    ("kdz", None, None, None, "kdz"),
+    ("kea", None, None, None, "kea"),
+    ("keb", None, None, None, "keb"),
+    ("kec", None, None, None, "kec"),
+    ("ked", None, None, None, "ked"),
+    ("kee", None, None, None, "kee"),
+    ("kef", None, None, None, "kef"),
+    ("keg", None, None, None, "keg"),
+    ("keh", None, None, None, "keh"),
+    ("kei", None, None, None, "kei"),
+    ("kej", None, None, None, "kej"),
+    ("kek", None, None, None, "kek"),
+    ("kel", None, None, None, "kel"),
+    ("kem", None, None, None, "kem"),
+    ("ken", None, None, None, "ken"),
+    ("keo", None, None, None, "keo"),
+    ("kep", None, None, None, "kep"),
+    ("keq", None, None, None, "keq"),
+    ("ker", None, None, None, "ker"),
+    ("kes", None, None, None, "kes"),
+    ("ket", None, None, None, "ket"),
+    ("keu", None, None, None, "keu"),
+    ("kev", None, None, None, "kev"),
+    ("kew", None, None, None, "kew"),
+    ("kex", None, None, None, "kex"),
+    ("key", None, None, None, "key"),
+    ("kez", None, None, None, "kez"),
+    ("kfa", None, None, None, "kfa"),
+    ("kfb", None, None, None, "kfb"),
+    ("kfc", None, None, None, "kfc"),
+    ("kfd", None, None, None, "kfd"),
+    ("kfe", None, None, None, "kfe"),
+    ("kff", None, None, None, "kff"),
+    ("kfg", None, None, None, "kfg"),
+    ("kfh", None, None, None, "kfh"),
+    ("kfi", None, None, None, "kfi"),
+    ("kfj", None, None, None, "kfj"),
+    ("kfk", None, None, None, "kfk"),
+    ("kfl", None, None, None, "kfl"),
+    ("kfm", None, None, None, "kfm"),
+    ("kfn", None, None, None, "kfn"),
+    ("kfo", None, None, None, "kfo"),
+    ("kfp", None, None, None, "kfp"),
+    ("kfq", None, None, None, "kfq"),
+    ("kfr", None, None, None, "kfr"),
+    ("kfs", None, None, None, "kfs"),
+    ("kft", None, None, None, "kft"),
+    ("kfu", None, None, None, "kfu"),
+    ("kfv", None, None, None, "kfv"),
+    ("kfw", None, None, None, "kfw"),
+    ("kfx", None, None, None, "kfx"),
+    ("kfy", None, None, None, "kfy"),
+    ("kfz", None, None, None, "kfz"),
+    ("kg", Some("kg"), Some("kon"), Some("kon"), "kon"),
+    ("kga", None, None, None, "kga"),
+    ("kgb", None, None, None, "kgb"),
+    ("kge", None, None, None, "kge"),
+    ("kgf", None, None, None, "kgf"),
+    ("kgg", None, None, None, "kgg"),
+    ("kgi", None, None, None, "kgi"),
+    ("kgj", None, None, None, "kgj"),
+    ("kgk", None, None, None, "kgk"),
+    ("kgl", None, None, None, "kgl"),
+    ("kgm", None, None, None, "kgm"),
+    ("kgn", None, None, None, "kgn"),
+    ("kgo", None, None, None, "kgo"),
+    ("kgp", None, None, None, "kgp"),
+    ("kgq", None, None, None, "kgq"),
+    ("kgr", None, None, None, "kgr"),
+    ("kgs", None, None, None, "kgs"),
+    ("kgt", None, None, None, "kgt"),
+    ("kgu", None, None, None, "kgu"),
+    ("kgv", None, None, None, "kgv"),
+    ("kgw", None, None, None, "kgw"),
+    ("kgx", None, None, None, "kgx"),
+    ("kgy", None, None, None, "kgy"),
+    ("kha", None, Some("kha"), Some("kha"), "kha"),
+    ("khb", None, None, None, "khb"),
+    ("khc", None, None, None, "khc"),
+    ("khd", None, None, None, "khd"),
+    ("khe", None, None, None, "khe"),
+    ("khf", None, None, None, "khf"),
+    ("khg", None, None, None, "khg"),
+    ("khh", None, None, None, "khh"),
+    ("khj", None, None, None, "khj"),
+    ("khk", None, None, None, "khk"),
+    ("khl", None, None, None, "khl"),
+    ("khn", None, None, None, "khn"),
+    ("kho", None, Some("kho"), Some("kho"), "kho"),
+    ("khp", None, None, None, "khp"),
+    ("khq", None, None, None, "khq"),
+    ("khr", None, None, None, "khr"),
+    ("khs", None, None, None, "khs"),
+    ("kht", None, None, None, "kht"),
+    ("khu", None, None, None, "khu"),
+    ("khv", None, None, None, "khv"),
+    ("khw", None, None, None, "khw"),
+    ("khx", None, None, None, "khx"),
+    ("khy", None, None, None, "khy"),
+    ("khz", None, None, None, "khz"),
+    ("ki", Some("ki"), Some("kik"), Some("kik"), "kik"),
+    ("kia", None, None, None, "kia"),
+    ("kib", None, None, None, "kib"),
+    ("kic", None, None, None, "kic"),
+    ("kid", None, None, None, "kid"),
+    ("kie", None, None, None, "kie"),
+    ("kif", None, None, None, "kif"),
+    ("kig", None, None, None, "kig"),
+    ("kih", None, None, None, "kih"),
+    ("kii", None, None, None, "kii"),
+    ("kij", None, None, None, "kij"),
+    ("kil", None, None, None, "kil"),
+    ("kim", None, None, None, "kim"),
+    ("kio", None, None, None, "kio"),
+    ("kip", None, None, None, "kip"),
+    ("kiq", None, None, None, "kiq"),
+    ("kis", None, None, None, "kis"),
+    ("kit", None, None, None, "kit"),
+    ("kiu", None, None, None, "kiu"),
+    ("kiv", None, None, None, "kiv"),
+    ("kiw", None, None, None, "kiw"),
+    ("kix", None, None, None, "kix"),
+    ("kiy", None, None, None, "kiy"),
+    ("kiz", None, None, None, "kiz"),
+    ("kj", Some("kj"), Some("kua"), Some("kua"), "kua"),
+    ("kja", None, None, None, "kja"),
+    ("kjb", None, None, None, "kjb"),
+    ("kjc", None, None, None, "kjc"),
+    ("kjd", None, None, None, "kjd"),
+    ("kje", None, None, None, "kje"),
+    ("kjg", None, None, None, "kjg"),
+    ("kjh", None, None, None, "kjh"),
+    ("kji", None, None, None, "kji"),
+    ("kjj", None, None, None, "kjj"),
+    ("kjk", None, None, None, "kjk"),
+    ("kjl", None, None, None, "kjl"),
+    ("kjm", None, None, None, "kjm"),
+    ("kjn", None, None, None, "kjn"),
+    ("kjo", None, None, None, "kjo"),
+    ("kjp", None, None, None, "kjp"),
+    ("kjq", None, None, None, "kjq"),
+    ("kjr", None, None, None, "kjr"),
+    ("kjs", None, None, None, "kjs"),
+    ("kjt", None, None, None, "kjt"),
+    ("kju", None, None, None, "kju"),
+    ("kjv", None, None, None, "kjv"),
+    ("kjx", None, None, None, "kjx"),
+    ("kjy", None, None, None, "kjy"),
+    ("kjz", None, None, None, "kjz"),
+    ("kk", Some("kk"), Some("kaz"), Some("kaz"), "kaz"),
+    ("kka", None, None, None, "kka"),
+    ("kkb", None, None, None, "kkb"),
+    ("kkc", None, None, None, "kkc"),
+    ("kkd", None, None, None, "kkd"),
+    ("kke", None, None, None, "kke"),
+    ("kkf", None, None, None, "kkf"),
+    ("kkg", None, None, None, "kkg"),
+    ("kkh", None, None, None, "kkh"),
+    ("kki", None, None, None, "kki"),
+    ("kkj", None, None, None, "kkj"),
+    ("kkk", None, None, None, "kkk"),
+    ("kkl", None, None, None, "kkl"),
+    ("kkm", None, None, None, "kkm"),
+    ("kkn", None, None, None, "kkn"),
+    ("kko", None, None, None, "kko"),
+    ("kkp", None, None, None, "kkp"),
+    ("kkq", None, None, None, "kkq"),
+    ("kkr", None, None, None, "kkr"),
+    ("kks", None, None, None, "kks"),
+    ("kkt", None, None, None, "kkt"),
+    ("kku", None, None, None, "kku"),
+    ("kkv", None, None, None, "kkv"),
+    ("kkw", None, None, None, "kkw"),
+    ("kkx", None, None, None, "kkx"),
+    ("kky", None, None, None, "kky"),
+    ("kkz", None, None, None, "kkz"),
+    ("kl", Some("kl"), Some("kal"), Some("kal"), "kal"),
+    ("kla", None, None, None, "kla"),
+    ("klb", None, None, None, "klb"),
+    ("klc", None, None, None, "klc"),
+    ("kld", None, None, None, "kld"),
+    ("kle", None, None, None, "kle"),
+    ("klf", None, None, None, "klf"),
+    ("klg", None, None, None, "klg"),
+    ("klh", None, None, None, "klh"),
+    ("kli", None, None, None, "kli"),
+    ("klj", None, None, None, "klj"),
+    ("klk", None, None, None, "klk"),
+    ("kll", None, None, None, "kll"),
+    ("klm", None, None, None, "klm"),
+    ("kln", None, None, None, "kln"),
+    ("klo", None, None, None, "klo"),
+    ("klp", None, None, None, "klp"),
+    ("klq", None, None, None, "klq"),
+    ("klr", None, None, None, "klr"),
+    ("kls", None, None, None, "kls"),
+    ("klt", None, None, None, "klt"),
+    ("klu", None, None, None, "klu"),
+    ("klv", None, None, None, "klv"),
+    ("klw", None, None, None, "klw"),
+    ("klx", None, None, None, "klx"),
+    ("kly", None, None, None, "kly"),
+    ("klz", None, None, None, "klz"),
+    ("km", Some("km"), Some("khm"), Some("khm"), "khm"),
+    ("kma", None, None, None, "kma"),
+    ("kmb", None, Some("kmb"), Some("kmb"), "kmb"),
+    ("kmc", None, None, None, "kmc"),
+    ("kmd", None, None, None, "kmd"),
+    ("kme", None, None, None, "kme"),
+    ("kmf", None, None, None, "kmf"),
+    ("kmg", None, None, None, "kmg"),
+    ("kmh", None, None, None, "kmh"),
+    ("kmi", None, None, None, "kmi"),
+    ("kmj", None, None, None, "kmj"),
+    ("kmk", None, None, None, "kmk"),
+    ("kml", None, None, None, "kml"),
+    ("kmm", None, None, None, "kmm"),
+    ("kmn", None, None, None, "kmn"),
+    ("kmo", None, None, None, "kmo"),
+    ("kmp", None, None, None, "kmp"),
+    ("kmq", None, None, None, "kmq"),
+    ("kmr", None, None, None, "kmr"),
+    ("kms", None, None, None, "kms"),
+    ("kmt", None, None, None, "kmt"),
+    ("kmu", None, None, None, "kmu"),
+    ("kmv", None, None, None, "kmv"),
+    ("kmw", None, None, None, "kmw"),
+    ("kmx", None, None, None, "kmx"),
+    ("kmy", None, None, None, "kmy"),
+    ("kmz", None, None, None, "kmz"),
+    ("kn", Some("kn"), Some("kan"), Some("kan"), "kan"),
+    ("kna", None, None, None, "kna"),
+    ("knb", None, None, None, "knb"),
+    ("knc", None, None, None, "knc"),
+    ("knd", None, None, None, "knd"),
+    ("kne", None, None, None, "kne"),
+    ("knf", None, None, None, "knf"),
+    ("kng", None, None, None, "kng"),
+    ("kni", None, None, None, "kni"),
+    ("knj", None, None, None, "knj"),
+    ("knk", None, None, None, "knk"),
+    ("knl", None, None, None, "knl"),
+    ("knm", None, None, None, "knm"),
+    ("knn", None, None, None, "knn"),
+    ("kno", None, None, None, "kno"),
+    ("knp", None, None, None, "knp"),
+    ("knq", None, None, None, "knq"),
+    ("knr", None, None, None, "knr"),
+    ("kns", None, None, None, "kns"),
+    ("knt", None, None, None, "knt"),
+    ("knu", None, None, None, "knu"),
+    ("knv", None, None, None, "knv"),
+    ("knw", None, None, None, "knw"),
+    ("knx", None, None, None, "knx"),
+    ("kny", None, None, None, "kny"),
+    ("knz", None, None, None, "knz"),
+    ("ko", Some("ko"), Some("kor"), Some("kor"), "kor"),
+    ("koa", None, None, None, "koa"),
+    ("koc", None, None, None, "koc"),
+    ("kod", None, None, None, "kod"),
+    ("koe", None, None, None, "koe"),
+    ("kof", None, None, None, "kof"),
+    ("kog", None, None, None, "kog"),
+    ("koh", None, None, None, "koh"),
+    ("koi", None, None, None, "koi"),
+    ("kok", None, Some("kok"), Some("kok"), "kok"),
+    ("kol", None, None, None, "kol"),
+    ("koo", None, None, None, "koo"),
+    ("kop", None, None, None, "kop"),
+    ("koq", None, None, None, "koq"),
+    ("kos", None, Some("kos"), Some("kos"), "kos"),
+    ("kot", None, None, None, "kot"),
+    ("kou", None, None, None, "kou"),
+    ("kov", None, None, None, "kov"),
+    ("kow", None, None, None, "kow"),
+    ("koy", None, None, None, "koy"),
+    ("koz", None, None, None, "koz"),
+    ("kpa", None, None, None, "kpa"),
+    ("kpb", None, None, None, "kpb"),
+    ("kpc", None, None, None, "kpc"),
+    ("kpd", None, None, None, "kpd"),
+    ("kpe", None, Some("kpe"), Some("kpe"), "kpe"),
+    ("kpf", None, None, None, "kpf"),
+    ("kpg", None, None, None, "kpg"),
+    ("kph", None, None, None, "kph"),
+    ("kpi", None, None, None, "kpi"),
+    ("kpj", None, None, None, "kpj"),
+    ("kpk", None, None, None, "kpk"),
+    ("kpl", None, None, None, "kpl"),
+    ("kpm", None, None, None, "kpm"),
+    ("kpn", None, None, None, "kpn"),
+    ("kpo", None, None, None, "kpo"),
+    ("kpq", None, None, None, "kpq"),
+    ("kpr", None, None, None, "kpr"),
+    ("kps", None, None, None, "kps"),
+    ("kpt", None, None, None, "kpt"),
+    ("kpu", None, None, None, "kpu"),
+    ("kpv", None, None, None, "kpv"),
+    ("kpw", None, None, None, "kpw"),
+    ("kpx", None, None, None, "kpx"),
+    ("kpy", None, None, None, "kpy"),
+    ("kpz", None, None, None, "kpz"),
+    ("kqa", None, None, None, "kqa"),
+    ("kqb", None, None, None, "kqb"),
+    ("kqc", None, None, None, "kqc"),
+    ("kqd", None, None, None, "kqd"),
+    ("kqe", None, None, None, "kqe"),
+    ("kqf", None, None, None, "kqf"),
+    ("kqg", None, None, None, "kqg"),
+    ("kqh", None, None, None, "kqh"),
+    ("kqi", None, None, None, "kqi"),
+    ("kqj", None, None, None, "kqj"),
+    ("kqk", None, None, None, "kqk"),
+    ("kql", None, None, None, "kql"),
+    ("kqm", None, None, None, "kqm"),
+    ("kqn", None, None, None, "kqn"),
+    ("kqo", None, None, None, "kqo"),
+    ("kqp", None, None, None, "kqp"),
+    ("kqq", None, None, None, "kqq"),
+    ("kqr", None, None, None, "kqr"),
+    ("kqs", None, None, None, "kqs"),
+    ("kqt", None, None, None, "kqt"),
+    ("kqu", None, None, None, "kqu"),
+    ("kqv", None, None, None, "kqv"),
+    ("kqw", None, None, None, "kqw"),
+    ("kqx", None, None, None, "kqx"),
+    ("kqy", None, None, None, "kqy"),
+    ("kqz", None, None, None, "kqz"),
+    ("kr", Some("kr"), Some("kau"), Some("kau"), "kau"),
+    ("kra", None, None, None, "kra"),
+    ("krb", None, None, None, "krb"),
+    ("krc", None, Some("krc"), Some("krc"), "krc"),
+    ("krd", None, None, None, "krd"),
+    ("kre", None, None, None, "kre"),
+    ("krf", None, None, None, "krf"),
+    ("krh", None, None, None, "krh"),
+    ("kri", None, None, None, "kri"),
+    ("krj", None, None, None, "krj"),
+    ("krk", None, None, None, "krk"),
+    ("krl", None, Some("krl"), Some("krl"), "krl"),
+    ("krn", None, None, None, "krn"),
+    ("krp", None, None, None, "krp"),
+    ("krr", None, None, None, "krr"),
+    ("krs", None, None, None, "krs"),
+    ("krt", None, None, None, "krt"),
+    ("kru", None, Some("kru"), Some("kru"), "kru"),
+    ("krv", None, None, None, "krv"),
+    ("krw", None, None, None, "krw"),
+    ("krx", None, None, None, "krx"),
+    ("kry", None, None, None, "kry"),
+    ("krz", None, None, None, "krz"),
+    ("ks", Some("ks"), Some("kas"), Some("kas"), "kas"),
+    ("ksa", None, None, None, "ksa"),
+    ("ksb", None, None, None, "ksb"),
+    ("ksc", None, None, None, "ksc"),
+    ("ksd", None, None, None, "ksd"),
+    ("kse", None, None, None, "kse"),
+    ("ksf", None, None, None, "ksf"),
+    ("ksg", None, None, None, "ksg"),
+    ("ksh", None, None, None, "ksh"),
+    ("ksi", None, None, None, "ksi"),
+    ("ksj", None, None, None, "ksj"),
+    ("ksk", None, None, None, "ksk"),
+    ("ksl", None, None, None, "ksl"),
+    ("ksm", None, None, None, "ksm"),
+    ("ksn", None, None, None, "ksn"),
+    ("kso", None, None, None, "kso"),
+    ("ksp", None, None, None, "ksp"),
+    ("ksq", None, None, None, "ksq"),
+    ("ksr", None, None, None, "ksr"),
+    ("kss", None, None, None, "kss"),
+    ("kst", None, None, None, "kst"),
+    ("ksu", None, None, None, "ksu"),
+    ("ksv", None, None, None, "ksv"),
+    ("ksw", None, None, None, "ksw"),
+    ("ksx", None, None, None, "ksx"),
+    ("ksy", None, None, None, "ksy"),
+    ("ksz", None, None, None, "ksz"),
+    ("kta", None, None, None, "kta"),
+    ("ktb", None, None, None, "ktb"),
+    ("ktc", None, None, None, "ktc"),
+    ("ktd", None, None, None, "ktd"),
+    ("kte", None, None, None, "kte"),
+    ("ktf", None, None, None, "ktf"),
+    ("ktg", None, None, None, "ktg"),
+    ("kth", None, None, None, "kth"),
+    ("kti", None, None, None, "kti"),
+    ("ktj", None, None, None, "ktj"),
+    ("ktk", None, None, None, "ktk"),
+    ("ktl", None, None, None, "ktl"),
+    ("ktm", None, None, None, "ktm"),
+    ("ktn", None, None, None, "ktn"),
+    ("kto", None, None, None, "kto"),
+    ("ktp", None, None, None, "ktp"),
+    ("ktq", None, None, None, "ktq"),
+    ("kts", None, None, None, "kts"),
+    ("ktt", None, None, None, "ktt"),
+    ("ktu", None, None, None, "ktu"),
+    ("ktv", None, None, None, "ktv"),
+    ("ktw", None, None, None, "ktw"),
+    ("ktx", None, None, None, "ktx"),
+    ("kty", None, None, None, "kty"),
+    ("ktz", None, None, None, "ktz"),
+    ("ku", Some("ku"), Some("kur"), Some("kur"), "kur"),
+    ("kub", None, None, None, "kub"),
+    ("kuc", None, None, None, "kuc"),
+    ("kud", None, None, None, "kud"),
+    ("kue", None, None, None, "kue"),
+    ("kuf", None, None, None, "kuf"),
+    ("kug", None, None, None, "kug"),
+    ("kuh", None, None, None, "kuh"),
+    ("kui", None, None, None, "kui"),
+    ("kuj", None, None, None, "kuj"),
+    ("kuk", None, None, None, "kuk"),
+    ("kul", None, None, None, "kul"),
+    ("kum", None, Some("kum"), Some("kum"), "kum"),
+    ("kun", None, None, None, "kun"),
+    ("kuo", None, None, None, "kuo"),
+    ("kup", None, None, None, "kup"),
+    ("kuq", None, None, None, "kuq"),
+    ("kus", None, None, None, "kus"),
+    ("kut", None, Some("kut"), Some("kut"), "kut"),
+    ("kuu", None, None, None, "kuu"),
+    ("kuv", None, None, None, "kuv"),
+    ("kuw", None, None, None, "kuw"),
+    ("kux", None, None, None, "kux"),
+    ("kuy", None, None, None, "kuy"),
+    ("kuz", None, None, None, "kuz"),
+    ("kv", Some("kv"), Some("kom"), Some("kom"), "kom"),
+    ("kva", None, None, None, "kva"),
+    ("kvb", None, None, None, "kvb"),
+    ("kvc", None, None, None, "kvc"),
+    ("kvd", None, None, None, "kvd"),
+    ("kve", None, None, None, "kve"),
+    ("kvf", None, None, None, "kvf"),
+    ("kvg", None, None, None, "kvg"),
+    ("kvh", None, None, None, "kvh"),
+    ("kvi", None, None, None, "kvi"),
+    ("kvj", None, None, None, "kvj"),
+    ("kvk", None, None, None, "kvk"),
+    ("kvl", None, None, None, "kvl"),
+    ("kvm", None, None, None, "kvm"),
+    ("kvn", None, None, None, "kvn"),
+    ("kvo", None, None, None, "kvo"),
+    ("kvp", None, None, None, "kvp"),
+    ("kvq", None, None, None, "kvq"),
+    ("kvr", None, None, None, "kvr"),
+    ("kvt", None, None, None, "kvt"),
+    ("kvu", None, None, None, "kvu"),
+    ("kvv", None, None, None, "kvv"),
+    ("kvw", None, None, None, "kvw"),
+    ("kvx", None, None, None, "kvx"),
+    ("kvy", None, None, None, "kvy"),
+    ("kvz", None, None, None, "kvz"),
+    ("kw", Some("kw"), Some("cor"), Some("cor"), "cor"),
+    ("kwa", None, None, None, "kwa"),
+    ("kwb", None, None, None, "kwb"),
+    ("kwc", None, None, None, "kwc"),
+    ("kwd", None, None, None, "kwd"),
+    ("kwe", None, None, None, "kwe"),
+    ("kwf", None, None, None, "kwf"),
+    ("kwg", None, None, None, "kwg"),
+    ("kwh", None, None, None, "kwh"),
+    ("kwi", None, None, None, "kwi"),
+    ("kwj", None, None, None, "kwj"),
+    ("kwk", None, None, None, "kwk"),
+    ("kwl", None, None, None, "kwl"),
+    ("kwm", None, None, None, "kwm"),
+    ("kwn", None, None, None, "kwn"),
+    ("kwo", None, None, None, "kwo"),
+    ("kwp", None, None, None, "kwp"),
+    ("kwr", None, None, None, "kwr"),
+    ("kws", None, None, None, "kws"),
+    ("kwt", None, None, None, "kwt"),
+    ("kwu", None, None, None, "kwu"),
+    ("kwv", None, None, None, "kwv"),
+    ("kww", None, None, None, "kww"),
+    ("kwx", None, None, None, "kwx"),
+    ("kwy", None, None, None, "kwy"),
+    ("kwz", None, None, None, "kwz"),
+    ("kxa", None, None, None, "kxa"),
+    ("kxb", None, None, None, "kxb"),
+    ("kxc", None, None, None, "kxc"),
+    ("kxd", None, None, None, "kxd"),
+    ("kxf", None, None, None, "kxf"),
+    ("kxh", None, None, None, "kxh"),
+    ("kxi", None, None, None, "kxi"),
+    ("kxj", None, None, None, "kxj"),
+    ("kxk", None, None, None, "kxk"),
+    ("kxm", None, None, None, "kxm"),
+    ("kxn", None, None, None, "kxn"),
+    ("kxo", None, None, None, "kxo"),
+    ("kxp", None, None, None, "kxp"),
+    ("kxq", None, None, None, "kxq"),
+    ("kxr", None, None, None, "kxr"),
+    ("kxs", None, None, None, "kxs"),
+    ("kxt", None, None, None, "kxt"),
+    ("kxv", None, None, None, "kxv"),
+    ("kxw", None, None, None, "kxw"),
+    ("kxx", None, None, None, "kxx"),
+    ("kxy", None, None, None, "kxy"),
+    ("kxz", None, None, None, "kxz"),
+    ("ky", Some("ky"), Some("kir"), Some("kir"), "kir"),
+    ("kya", None, None, None, "kya"),
+    ("kyb", None, None, None, "kyb"),
+    ("kyc", None, None, None, "kyc"),
+    ("kyd", None, None, None, "kyd"),
+    ("kye", None, None, None, "kye"),
+    ("kyf", None, None, None, "kyf"),
+    ("kyg", None, None, None, "kyg"),
+    ("kyh", None, None, None, "kyh"),
+    ("kyi", None, None, None, "kyi"),
+    ("kyj", None, None, None, "kyj"),
+    ("kyk", None, None, None, "kyk"),
+    ("kyl", None, None, None, "kyl"),
+    ("kym", None, None, None, "kym"),
+    ("kyn", None, None, None, "kyn"),
+    ("kyo", None, None, None, "kyo"),
+    ("kyp", None, None, None, "kyp"),
+    ("kyq", None, None, None, "kyq"),
+    ("kyr", None, None, None, "kyr"),
+    ("kys", None, None, None, "kys"),
+    ("kyt", None, None, None, "kyt"),
+    ("kyu", None, None, None, "kyu"),
+    ("kyv", None, None, None, "kyv"),
+    ("kyw", None, None, None, "kyw"),
+    ("kyx", None, None, None, "kyx"),
+    ("kyy", None, None, None, "kyy"),
+    ("kyz", None, None, None, "kyz"),
+    ("kza", None, None, None, "kza"),
+    ("kzb", None, None, None, "kzb"),
+    ("kzc", None, None, None, "kzc"),
+    ("kzd", None, None, None, "kzd"),
+    ("kze", None, None, None, "kze"),
+    ("kzf", None, None, None, "kzf"),
+    ("kzg", None, None, None, "kzg"),
+    ("kzi", None, None, None, "kzi"),
+    ("kzk", None, None, None, "kzk"),
+    ("kzl", None, None, None, "kzl"),
+    ("kzm", None, None, None, "kzm"),
+    ("kzn", None, None, None, "kzn"),
+    ("kzo", None, None, None, "kzo"),
+    ("kzp", None, None, None, "kzp"),
+    ("kzq", None, None, None, "kzq"),
+    ("kzr", None, None, None, "kzr"),
+    ("kzs", None, None, None, "kzs"),
+    ("kzu", None, None, None, "kzu"),
+    ("kzv", None, None, None, "kzv"),
+    ("kzw", None, None, None, "kzw"),
+    ("kzx", None, None, None, "kzx"),
+    ("kzy", None, None, None, "kzy"),
+    ("kzz", None, None, None, "kzz"),
+    ("la", Some("la"), Some("lat"), Some("lat"), "lat"),
+    ("laa", None, None, None, "laa"),
+    ("lab", None, None, None, "lab"),
+    ("lac", None, None, None, "lac"),
+    ("lad", None, Some("lad"), Some("lad"), "lad"),
+    ("lae", None, None, None, "lae"),
+    ("laf", None, None, None, "laf"),
+    ("lag", None, None, None, "lag"),
+    ("lah", None, Some("lah"), Some("lah"), "lah"),
+    ("lai", None, None, None, "lai"),
+    ("laj", None, None, None, "laj"),
+    ("lal", None, None, None, "lal"),
+    ("lam", None, Some("lam"), Some("lam"), "lam"),
+    ("lan", None, None, None, "lan"),
+    ("lap", None, None, None, "lap"),
+    ("laq", None, None, None, "laq"),
+    ("lar", None, None, None, "lar"),
+    ("las", None, None, None, "las"),
+    ("lau", None, None, None, "lau"),
+    ("law", None, None, None, "law"),
+    ("lax", None, None, None, "lax"),
+    ("lay", None, None, None, "lay"),
+    ("laz", None, None, None, "laz"),
+    ("lb", Some("lb"), Some("ltz"), Some("ltz"), "ltz"),
+    ("lbb", None, None, None, "lbb"),
+    ("lbc", None, None, None, "lbc"),
+    ("lbe", None, None, None, "lbe"),
+    ("lbf", None, None, None, "lbf"),
+    ("lbg", None, None, None, "lbg"),
+    ("lbi", None, None, None, "lbi"),
+    ("lbj", None, None, None, "lbj"),
+    ("lbk", None, None, None, "lbk"),
+    ("lbl", None, None, None, "lbl"),
+    ("lbm", None, None, None, "lbm"),
+    ("lbn", None, None, None, "lbn"),
+    ("lbo", None, None, None, "lbo"),
+    ("lbq", None, None, None, "lbq"),
+    ("lbr", None, None, None, "lbr"),
+    ("lbs", None, None, None, "lbs"),
+    ("lbt", None, None, None, "lbt"),
+    ("lbu", None, None, None, "lbu"),
+    ("lbv", None, None, None, "lbv"),
+    ("lbw", None, None, None, "lbw"),
+    ("lbx", None, None, None, "lbx"),
+    ("lby", None, None, None, "lby"),
+    ("lbz", None, None, None, "lbz"),
+    ("lcc", None, None, None, "lcc"),
+    ("lcd", None, None, None, "lcd"),
+    ("lce", None, None, None, "lce"),
+    ("lcf", None, None, None, "lcf"),
+    ("lch", None, None, None, "lch"),
+    ("lcl", None, None, None, "lcl"),
+    ("lcm", None, None, None, "lcm"),
+    ("lcp", None, None, None, "lcp"),
+    ("lcq", None, None, None, "lcq"),
+    ("lcs", None, None, None, "lcs"),
+    ("lda", None, None, None, "lda"),
+    ("ldb", None, None, None, "ldb"),
+    ("ldd", None, None, None, "ldd"),
+    ("ldg", None, None, None, "ldg"),
+    ("ldh", None, None, None, "ldh"),
+    ("ldi", None, None, None, "ldi"),
+    ("ldj", None, None, None, "ldj"),
+    ("ldk", None, None, None, "ldk"),
+    ("ldl", None, None, None, "ldl"),
+    ("ldm", None, None, None, "ldm"),
+    ("ldn", None, None, None, "ldn"),
+    ("ldo", None, None, None, "ldo"),
+    ("ldp", None, None, None, "ldp"),
+    ("ldq", None, None, None, "ldq"),
+    ("lea", None, None, None, "lea"),
+    ("leb", None, None, None, "leb"),
+    ("lec", None, None, None, "lec"),
+    ("led", None, None, None, "led"),
+    ("lee", None, None, None, "lee"),
+    ("lef", None, None, None, "lef"),
+    ("leh", None, None, None, "leh"),
+    ("lei", None, None, None, "lei"),
+    ("lej", None, None, None, "lej"),
+    ("lek", None, None, None, "lek"),
+    ("lel", None, None, None, "lel"),
+    ("lem", None, None, None, "lem"),
+    ("len", None, None, None, "len"),
+    ("leo", None, None, None, "leo"),
+    ("lep", None, None, None, "lep"),
+    ("leq", None, None, None, "leq"),
+    ("ler", None, None, None, "ler"),
+    ("les", None, None, None, "les"),
+    ("let", None, None, None, "let"),
+    ("leu", None, None, None, "leu"),
+    ("lev", None, None, None, "lev"),
+    ("lew", None, None, None, "lew"),
+    ("lex", None, None, None, "lex"),
+    ("ley", None, None, None, "ley"),
+    ("lez", None, Some("lez"), Some("lez"), "lez"),
+    ("lfa", None, None, None, "lfa"),
+    ("lfn", None, None, None, "lfn"),
+    ("lg", Some("lg"), Some("lug"), Some("lug"), "lug"),
+    ("lga", None, None, None, "lga"),
+    ("lgb", None, None, None, "lgb"),
+    ("lgg", None, None, None, "lgg"),
+    ("lgh", None, None, None, "lgh"),
+    ("lgi", None, None, None, "lgi"),
+    ("lgk", None, None, None, "lgk"),
+    ("lgl", None, None, None, "lgl"),
+    ("lgm", None, None, None, "lgm"),
+    ("lgn", None, None, None, "lgn"),
+    ("lgo", None, None, None, "lgo"),
+    ("lgq", None, None, None, "lgq"),
+    ("lgr", None, None, None, "lgr"),
+    ("lgt", None, None, None, "lgt"),
+    ("lgu", None, None, None, "lgu"),
+    ("lgz", None, None, None, "lgz"),
+    ("lha", None, None, None, "lha"),
+    ("lhh", None, None, None, "lhh"),
+    ("lhi", None, None, None, "lhi"),
+    ("lhl", None, None, None, "lhl"),
+    ("lhm", None, None, None, "lhm"),
+    ("lhn", None, None, None, "lhn"),
+    ("lhp", None, None, None, "lhp"),
+    ("lhs", None, None, None, "lhs"),
+    ("lht", None, None, None, "lht"),
+    ("lhu", None, None, None, "lhu"),
+    ("li", Some("li"), Some("lim"), Some("lim"), "lim"),
+    ("lia", None, None, None, "lia"),
+    ("lib", None, None, None, "lib"),
+    ("lic", None, None, None, "lic"),
+    ("lid", None, None, None, "lid"),
+    ("lie", None, None, None, "lie"),
+    ("lif", None, None, None, "lif"),
+    ("lig", None, None, None, "lig"),
+    ("lih", None, None, None, "lih"),
+    ("lij", None, None, None, "lij"),
+    ("lik", None, None, None, "lik"),
+    ("lil", None, None, None, "lil"),
+    ("lio", None, None, None, "lio"),
+    ("lip", None, None, None, "lip"),
+    ("liq", None, None, None, "liq"),
+    ("lir", None, None, None, "lir"),
+    ("lis", None, None, None, "lis"),
+    ("liu", None, None, None, "liu"),
+    ("liv", None, None, None, "liv"),
+    ("liw", None, None, None, "liw"),
+    ("lix", None, None, None, "lix"),
+    ("liy", None, None, None, "liy"),
+    ("liz", None, None, None, "liz"),
+    ("lja", None, None, None, "lja"),
+    ("lje", None, None, None, "lje"),
+    ("lji", None, None, None, "lji"),
+    ("ljl", None, None, None, "ljl"),
+    ("ljp", None, None, None, "ljp"),
+    ("ljw", None, None, None, "ljw"),
+    ("ljx", None, None, None, "ljx"),
+    ("lka", None, None, None, "lka"),
+    ("lkb", None, None, None, "lkb"),
+    ("lkc", None, None, None, "lkc"),
+    ("lkd", None, None, None, "lkd"),
+    ("lke", None, None, None, "lke"),
+    ("lkh", None, None, None, "lkh"),
+    ("lki", None, None, None, "lki"),
+    ("lkj", None, None, None, "lkj"),
+    ("lkl", None, None, None, "lkl"),
+    ("lkm", None, None, None, "lkm"),
+    ("lkn", None, None, None, "lkn"),
+    ("lko", None, None, None, "lko"),
+    ("lkr", None, None, None, "lkr"),
+    ("lks", None, None, None, "lks"),
+    ("lkt", None, None, None, "lkt"),
+    ("lku", None, None, None, "lku"),
+    ("lky", None, None, None, "lky"),
+    ("lla", None, None, None, "lla"),
+    ("llb", None, None, None, "llb"),
+    ("llc", None, None, None, "llc"),
+    ("lld", None, None, None, "lld"),
+    ("lle", None, None, None, "lle"),
+    ("llf", None, None, None, "llf"),
+    ("llg", None, None, None, "llg"),
+    ("llh", None, None, None, "llh"),
+    ("lli", None, None, None, "lli"),
+    ("llj", None, None, None, "llj"),
+    ("llk", None, None, None, "llk"),
+    ("lll", None, None, None, "lll"),
+    ("llm", None, None, None, "llm"),
+    ("lln", None, None, None, "lln"),
+    ("llp", None, None, None, "llp"),
+    ("llq", None, None, None, "llq"),
+    ("lls", None, None, None, "lls"),
+    ("llu", None, None, None, "llu"),
+    ("llx", None, None, None, "llx"),
+    ("lma", None, None, None, "lma"),
+    ("lmb", None, None, None, "lmb"),
+    ("lmc", None, None, None, "lmc"),
+    ("lmd", None, None, None, "lmd"),
+    ("lme", None, None, None, "lme"),
+    ("lmf", None, None, None, "lmf"),
+    ("lmg", None, None, None, "lmg"),
+    ("lmh", None, None, None, "lmh"),
+    ("lmi", None, None, None, "lmi"),
+    ("lmj", None, None, None, "lmj"),
+    ("lmk", None, None, None, "lmk"),
+    ("lml", None, None, None, "lml"),
+    ("lmn", None, None, None, "lmn"),
+    ("lmo", None, None, None, "lmo"),
+    ("lmp", None, None, None, "lmp"),
+    ("lmq", None, None, None, "lmq"),
+    ("lmr", None, None, None, "lmr"),
+    ("lmu", None, None, None, "lmu"),
+    ("lmv", None, None, None, "lmv"),
+    ("lmw", None, None, None, "lmw"),
+    ("lmx", None, None, None, "lmx"),
+    ("lmy", None, None, None, "lmy"),
+    ("ln", Some("ln"), Some("lin"), Some("lin"), "lin"),
+    ("lna", None, None, None, "lna"),
+    ("lnb", None, None, None, "lnb"),
+    ("lnd", None, None, None, "lnd"),
+    ("lng", None, None, None, "lng"),
+    ("lnh", None, None, None, "lnh"),
+    ("lni", None, None, None, "lni"),
+    ("lnj", None, None, None, "lnj"),
+    ("lnl", None, None, None, "lnl"),
+    ("lnm", None, None, None, "lnm"),
+    ("lnn", None, None, None, "lnn"),
+    ("lns", None, None, None, "lns"),
+    ("lnu", None, None, None, "lnu"),
+    ("lnw", None, None, None, "lnw"),
+    ("lnz", None, None, None, "lnz"),
+    ("lo", Some("lo"), Some("lao"), Some("lao"), "lao"),
+    ("loa", None, None, None, "loa"),
+    ("lob", None, None, None, "lob"),
+    ("loc", None, None, None, "loc"),
+    ("loe", None, None, None, "loe"),
+    ("lof", None, None, None, "lof"),
+    ("log", None, None, None, "log"),
+    ("loh", None, None, None, "loh"),
+    ("loi", None, None, None, "loi"),
+    ("loj", None, None, None, "loj"),
+    ("lok", None, None, None, "lok"),
+    ("lol", None, Some("lol"), Some("lol"), "lol"),
+    ("lom", None, None, None, "lom"),
+    ("lon", None, None, None, "lon"),
+    ("loo", None, None, None, "loo"),
+    ("lop", None, None, None, "lop"),
+    ("loq", None, None, None, "loq"),
+    ("lor", None, None, None, "lor"),
+    ("los", None, None, None, "los"),
+    ("lot", None, None, None, "lot"),
+    ("lou", None, None, None, "lou"),
+    ("lov", None, None, None, "lov"),
+    ("low", None, None, None, "low"),
+    ("lox", None, None, None, "lox"),
+    ("loy", None, None, None, "loy"),
+    ("loz", None, Some("loz"), Some("loz"), "loz"),
+    ("lpa", None, None, None, "lpa"),
+    ("lpe", None, None, None, "lpe"),
+    ("lpn", None, None, None, "lpn"),
+    ("lpo", None, None, None, "lpo"),
+    ("lpx", None, None, None, "lpx"),
+    ("lqr", None, None, None, "lqr"),
+    ("lra", None, None, None, "lra"),
+    ("lrc", None, None, None, "lrc"),
+    ("lre", None, None, None, "lre"),
+    ("lrg", None, None, None, "lrg"),
+    ("lri", None, None, None, "lri"),
+    ("lrk", None, None, None, "lrk"),
+    ("lrl", None, None, None, "lrl"),
+    ("lrm", None, None, None, "lrm"),
+    ("lrn", None, None, None, "lrn"),
+    ("lro", None, None, None, "lro"),
+    ("lrr", None, None, None, "lrr"),
+    ("lrt", None, None, None, "lrt"),
+    ("lrv", None, None, None, "lrv"),
+    ("lrz", None, None, None, "lrz"),
+    ("lsa", None, None, None, "lsa"),
+    ("lsb", None, None, None, "lsb"),
+    ("lsc", None, None, None, "lsc"),
+    ("lsd", None, None, None, "lsd"),
+    ("lse", None, None, None, "lse"),
+    ("lsh", None, None, None, "lsh"),
+    ("lsi", None, None, None, "lsi"),
+    ("lsl", None, None, None, "lsl"),
+    ("lsm", None, None, None, "lsm"),
+    ("lsn", None, None, None, "lsn"),
+    ("lso", None, None, None, "lso"),
+    ("lsp", None, None, None, "lsp"),
+    ("lsr", None, None, None, "lsr"),
+    ("lss", None, None, None, "lss"),
+    ("lst", None, None, None, "lst"),
+    ("lsv", None, None, None, "lsv"),
+    ("lsw", None, None, None, "lsw"),
+    ("lsy", None, None, None, "lsy"),
+    ("lt", Some("lt"), Some("lit"), Some("lit"), "lit"),
+    ("ltc", None, None, None, "ltc"),
+    ("ltg", None, None, None, "ltg"),
+    ("lth", None, None, None, "lth"),
+    ("lti", None, None, None, "lti"),
+    ("ltn", None, None, None, "ltn"),
+    ("lto", None, None, None, "lto"),
+    ("lts", None, None, None, "lts"),
+    ("ltu", None, None, None, "ltu"),
+    ("lu", Some("lu"), Some("lub"), Some("lub"), "lub"),
+    ("lua", None, Some("lua"), Some("lua"), "lua"),
+    ("luc", None, None, None, "luc"),
+    ("lud", None, None, None, "lud"),
+    ("lue", None, None, None, "lue"),
+    ("luf", None, None, None, "luf"),
+    ("lui", None, Some("lui"), Some("lui"), "lui"),
+    ("luj", None, None, None, "luj"),
+    ("luk", None, None, None, "luk"),
+    ("lul", None, None, None, "lul"),
+    ("lum", None, None, None, "lum"),
+    ("lun", None, Some("lun"), Some("lun"), "lun"),
+    ("luo", None, Some("luo"), Some("luo"), "luo"),
+    ("lup", None, None, None, "lup"),
+    ("luq", None, None, None, "luq"),
+    ("lur", None, None, None, "lur"),
+    ("lus", None, Some("lus"), Some("lus"), "lus"),
+    ("lut", None, None, None, "lut"),
+    ("luu", None, None, None, "luu"),
+    ("luv", None, None, None, "luv"),
+    ("luw", None, None, None, "luw"),
+    ("luy", None, None, None, "luy"),
+    ("luz", None, None, None, "luz"),
+    ("lv", Some("lv"), Some("lav"), Some("lav"), "lav"),
+    ("lva", None, None, None, "lva"),
+    ("lvi", None, None, None, "lvi"),
+    ("lvk", None, None, None, "lvk"),
+    ("lvs", None, None, None, "lvs"),
+    ("lvu", None, None, None, "lvu"),
+    ("lwa", None, None, None, "lwa"),
+    ("lwe", None, None, None, "lwe"),
+    ("lwg", None, None, None, "lwg"),
+    ("lwh", None, None, None, "lwh"),
+    ("lwl", None, None, None, "lwl"),
+    ("lwm", None, None, None, "lwm"),
+    ("lwo", None, None, None, "lwo"),
+    ("lws", None, None, None, "lws"),
+    ("lwt", None, None, None, "lwt"),
+    ("lwu", None, None, None, "lwu"),
+    ("lww", None, None, None, "lww"),
+    ("lxm", None, None, None, "lxm"),
+    ("lya", None, None, None, "lya"),
+    ("lyg", None, None, None, "lyg"),
+    ("lyn", None, None, None, "lyn"),
+    ("lzh", None, None, None, "lzh"),
+    ("lzl", None, None, None, "lzl"),
+    ("lzn", None, None, None, "lzn"),
+    ("lzz", None, None, None, "lzz"),
+    ("maa", None, None, None, "maa"),
+    ("mab", None, None, None, "mab"),
+    ("mad", None, Some("mad"), Some("mad"), "mad"),
+    ("mae", None, None, None, "mae"),
+    ("maf", None, None, None, "maf"),
+    ("mag", None, Some("mag"), Some("mag"), "mag"),
+    ("mai", None, Some("mai"), Some("mai"), "mai"),
+    ("maj", None, None, None, "maj"),
+    ("mak", None, Some("mak"), Some("mak"), "mak"),
+    ("mam", None, None, None, "mam"),
+    ("man", None, Some("man"), Some("man"), "man"),
+    ("maq", None, None, None, "maq"),
+    ("mas", None, Some("mas"), Some("mas"), "mas"),
+    ("mat", None, None, None, "mat"),
+    ("mau", None, None, None, "mau"),
+    ("mav", None, None, None, "mav"),
+    ("maw", None, None, None, "maw"),
+    ("max", None, None, None, "max"),
+    ("maz", None, None, None, "maz"),
+    ("mba", None, None, None, "mba"),
+    ("mbb", None, None, None, "mbb"),
+    ("mbc", None, None, None, "mbc"),
+    ("mbd", None, None, None, "mbd"),
+    ("mbe", None, None, None, "mbe"),
+    ("mbf", None, None, None, "mbf"),
+    ("mbh", None, None, None, "mbh"),
+    ("mbi", None, None, None, "mbi"),
+    ("mbj", None, None, None, "mbj"),
+    ("mbk", None, None, None, "mbk"),
+    ("mbl", None, None, None, "mbl"),
+    ("mbm", None, None, None, "mbm"),
+    ("mbn", None, None, None, "mbn"),
+    ("mbo", None, None, None, "mbo"),
+    ("mbp", None, None, None, "mbp"),
+    ("mbq", None, None, None, "mbq"),
+    ("mbr", None, None, None, "mbr"),
+    ("mbs", None, None, None, "mbs"),
+    ("mbt", None, None, None, "mbt"),
+    ("mbu", None, None, None, "mbu"),
+    ("mbv", None, None, None, "mbv"),
+    ("mbw", None, None, None, "mbw"),
+    ("mbx", None, None, None, "mbx"),
+    ("mby", None, None, None, "mby"),
+    ("mbz", None, None, None, "mbz"),
+    ("mca", None, None, None, "mca"),
+    ("mcb", None, None, None, "mcb"),
+    ("mcc", None, None, None, "mcc"),
+    ("mcd", None, None, None, "mcd"),
+    ("mce", None, None, None, "mce"),
+    ("mcf", None, None, None, "mcf"),
+    ("mcg", None, None, None, "mcg"),
+    ("mch", None, None, None, "mch"),
+    ("mci", None, None, None, "mci"),
+    ("mcj", None, None, None, "mcj"),
+    ("mck", None, None, None, "mck"),
+    ("mcl", None, None, None, "mcl"),
+    ("mcm", None, None, None, "mcm"),
+    ("mcn", None, None, None, "mcn"),
+    ("mco", None, None, None, "mco"),
+    ("mcp", None, None, None, "mcp"),
+    ("mcq", None, None, None, "mcq"),
+    ("mcr", None, None, None, "mcr"),
+    ("mcs", None, None, None, "mcs"),
+    ("mct", None, None, None, "mct"),
+    ("mcu", None, None, None, "mcu"),
+    ("mcv", None, None, None, "mcv"),
+    ("mcw", None, None, None, "mcw"),
+    ("mcx", None, None, None, "mcx"),
+    ("mcy", None, None, None, "mcy"),
+    ("mcz", None, None, None, "mcz"),
+    ("mda", None, None, None, "mda"),
+    ("mdb", None, None, None, "mdb"),
+    ("mdc", None, None, None, "mdc"),
+    ("mdd", None, None, None, "mdd"),
+    ("mde", None, None, None, "mde"),
+    ("mdf", None, Some("mdf"), Some("mdf"), "mdf"),
+    ("mdg", None, None, None, "mdg"),
+    ("mdh", None, None, None, "mdh"),
+    ("mdi", None, None, None, "mdi"),
+    ("mdj", None, None, None, "mdj"),
+    ("mdk", None, None, None, "mdk"),
+    ("mdl", None, None, None, "mdl"),
+    ("mdm", None, None, None, "mdm"),
+    ("mdn", None, None, None, "mdn"),
+    ("mdp", None, None, None, "mdp"),
+    ("mdq", None, None, None, "mdq"),
+    ("mdr", None, Some("mdr"), Some("mdr"), "mdr"),
+    ("mds", None, None, None, "mds"),
+    ("mdt", None, None, None, "mdt"),
+    ("mdu", None, None, None, "mdu"),
+    ("mdv", None, None, None, "mdv"),
+    ("mdw", None, None, None, "mdw"),
+    ("mdx", None, None, None, "mdx"),
+    ("mdy", None, None, None, "mdy"),
+    ("mdz", None, None, None, "mdz"),
+    ("mea", None, None, None, "mea"),
+    ("meb", None, None, None, "meb"),
+    ("mec", None, None, None, "mec"),
+    ("med", None, None, None, "med"),
+    ("mee", None, None, None, "mee"),
+    ("mef", None, None, None, "mef"),
+    ("meh", None, None, None, "meh"),
+    ("mei", None, None, None, "mei"),
+    ("mej", None, None, None, "mej"),
+    ("mek", None, None, None, "mek"),
+    ("mel", None, None, None, "mel"),
+    ("mem", None, None, None, "mem"),
+    ("men", None, Some("men"), Some("men"), "men"),
+    ("meo", None, None, None, "meo"),
+    ("mep", None, None, None, "mep"),
+    ("meq", None, None, None, "meq"),
+    ("mer", None, None, None, "mer"),
+    ("mes", None, None, None, "mes"),
+    ("met", None, None, None, "met"),
+    ("meu", None, None, None, "meu"),
+    ("mev", None, None, None, "mev"),
+    ("mew", None, None, None, "mew"),
+    ("mey", None, None, None, "mey"),
+    ("mez", None, None, None, "mez"),
+    ("mfa", None, None, None, "mfa"),
+    ("mfb", None, None, None, "mfb"),
+    ("mfc", None, None, None, "mfc"),
+    ("mfd", None, None, None, "mfd"),
+    ("mfe", None, None, None, "mfe"),
+    ("mff", None, None, None, "mff"),
+    ("mfg", None, None, None, "mfg"),
+    ("mfh", None, None, None, "mfh"),
+    ("mfi", None, None, None, "mfi"),
+    ("mfj", None, None, None, "mfj"),
+    ("mfk", None, None, None, "mfk"),
+    ("mfl", None, None, None, "mfl"),
+    ("mfm", None, None, None, "mfm"),
+    ("mfn", None, None, None, "mfn"),
+    ("mfo", None, None, None, "mfo"),
+    ("mfp", None, None, None, "mfp"),
+    ("mfq", None, None, None, "mfq"),
+    ("mfr", None, None, None, "mfr"),
+    ("mfs", None, None, None, "mfs"),
+    ("mft", None, None, None, "mft"),
+    ("mfu", None, None, None, "mfu"),
+    ("mfv", None, None, None, "mfv"),
+    ("mfw", None, None, None, "mfw"),
+    ("mfx", None, None, None, "mfx"),
+    ("mfy", None, None, None, "mfy"),
+    ("mfz", None, None, None, "mfz"),
+    ("mg", Some("mg"), Some("mlg"), Some("mlg"), "mlg"),
+    ("mga", None, Some("mga"), Some("mga"), "mga"),
+    ("mgb", None, None, None, "mgb"),
+    ("mgc", None, None, None, "mgc"),
+    ("mgd", None, None, None, "mgd"),
+    ("mge", None, None, None, "mge"),
+    ("mgf", None, None, None, "mgf"),
+    ("mgg", None, None, None, "mgg"),
+    ("mgh", None, None, None, "mgh"),
+    ("mgi", None, None, None, "mgi"),
+    ("mgj", None, None, None, "mgj"),
+    ("mgk", None, None, None, "mgk"),
+    ("mgl", None, None, None, "mgl"),
+    ("mgm", None, None, None, "mgm"),
+    ("mgn", None, None, None, "mgn"),
+    ("mgo", None, None, None, "mgo"),
+    ("mgp", None, None, None, "mgp"),
+    ("mgq", None, None, None, "mgq"),
+    ("mgr", None, None, None, "mgr"),
+    ("mgs", None, None, None, "mgs"),
+    ("mgt", None, None, None, "mgt"),
+    ("mgu", None, None, None, "mgu"),
+    ("mgv", None, None, None, "mgv"),
+    ("mgw", None, None, None, "mgw"),
+    ("mgy", None, None, None, "mgy"),
+    ("mgz", None, None, None, "mgz"),
+    ("mh", Some("mh"), Some("mah"), Some("mah"), "mah"),
+    ("mha", None, None, None, "mha"),
+    ("mhb", None, None, None, "mhb"),
+    ("mhc", None, None, None, "mhc"),
+    ("mhd", None, None, None, "mhd"),
+    ("mhe", None, None, None, "mhe"),
+    ("mhf", None, None, None, "mhf"),
+    ("mhg", None, None, None, "mhg"),
+    ("mhi", None, None, None, "mhi"),
+    ("mhj", None, None, None, "mhj"),
+    ("mhk", None, None, None, "mhk"),
+    ("mhl", None, None, None, "mhl"),
+    ("mhm", None, None, None, "mhm"),
+    ("mhn", None, None, None, "mhn"),
+    ("mho", None, None, None, "mho"),
+    ("mhp", None, None, None, "mhp"),
+    ("mhq", None, None, None, "mhq"),
+    ("mhr", None, None, None, "mhr"),
+    ("mhs", None, None, None, "mhs"),
+    ("mht", None, None, None, "mht"),
+    ("mhu", None, None, None, "mhu"),
+    ("mhw", None, None, None, "mhw"),
+    ("mhx", None, None, None, "mhx"),
+    ("mhy", None, None, None, "mhy"),
+    ("mhz", None, None, None, "mhz"),
+    ("mi", Some("mi"), Some("mri"), Some("mao"), "mri"),
+    ("mia", None, None, None, "mia"),
+    ("mib", None, None, None, "mib"),
+    ("mic", None, Some("mic"), Some("mic"), "mic"),
+    ("mid", None, None, None, "mid"),
+    ("mie", None, None, None, "mie"),
+    ("mif", None, None, None, "mif"),
+    ("mig", None, None, None, "mig"),
+    ("mih", None, None, None, "mih"),
+    ("mii", None, None, None, "mii"),
+    ("mij", None, None, None, "mij"),
+    ("mik", None, None, None, "mik"),
+    ("mil", None, None, None, "mil"),
+    ("mim", None, None, None, "mim"),
+    ("min", None, Some("min"), Some("min"), "min"),
+    ("mio", None, None, None, "mio"),
+    ("mip", None, None, None, "mip"),
+    ("miq", None, None, None, "miq"),
+    ("mir", None, None, None, "mir"),
+    ("mis", None, Some("mis"), Some("mis"), "mis"),
+    ("mit", None, None, None, "mit"),
+    ("miu", None, None, None, "miu"),
+    ("miw", None, None, None, "miw"),
+    ("mix", None, None, None, "mix"),
+    ("miy", None, None, None, "miy"),
+    ("miz", None, None, None, "miz"),
+    ("mjb", None, None, None, "mjb"),
+    ("mjc", None, None, None, "mjc"),
+    ("mjd", None, None, None, "mjd"),
+    ("mje", None, None, None, "mje"),
+    ("mjg", None, None, None, "mjg"),
+    ("mjh", None, None, None, "mjh"),
+    ("mji", None, None, None, "mji"),
+    ("mjj", None, None, None, "mjj"),
+    ("mjk", None, None, None, "mjk"),
+    ("mjl", None, None, None, "mjl"),
+    ("mjm", None, None, None, "mjm"),
+    ("mjn", None, None, None, "mjn"),
+    ("mjo", None, None, None, "mjo"),
+    ("mjp", None, None, None, "mjp"),
+    ("mjq", None, None, None, "mjq"),
+    ("mjr", None, None, None, "mjr"),
+    ("mjs", None, None, None, "mjs"),
+    ("mjt", None, None, None, "mjt"),
+    ("mju", None, None, None, "mju"),
+    ("mjv", None, None, None, "mjv"),
+    ("mjw", None, None, None, "mjw"),
+    ("mjx", None, None, None, "mjx"),
+    ("mjy", None, None, None, "mjy"),
+    ("mjz", None, None, None, "mjz"),
+    ("mk", Some("mk"), Some("mkd"), Some("mac"), "mkd"),
+    ("mka", None, None, None, "mka"),
+    ("mkb", None, None, None, "mkb"),
+    ("mkc", None, None, None, "mkc"),
+    ("mke", None, None, None, "mke"),
+    ("mkf", None, None, None, "mkf"),
+    ("mkg", None, None, None, "mkg"),
+    ("mki", None, None, None, "mki"),
+    ("mkj", None, None, None, "mkj"),
+    ("mkk", None, None, None, "mkk"),
+    ("mkl", None, None, None, "mkl"),
+    ("mkm", None, None, None, "mkm"),
+    ("mkn", None, None, None, "mkn"),
+    ("mko", None, None, None, "mko"),
+    ("mkp", None, None, None, "mkp"),
+    ("mkq", None, None, None, "mkq"),
+    ("mkr", None, None, None, "mkr"),
+    ("mks", None, None, None, "mks"),
+    ("mkt", None, None, None, "mkt"),
+    ("mku", None, None, None, "mku"),
+    ("mkv", None, None, None, "mkv"),
+    ("mkw", None, None, None, "mkw"),
+    ("mkx", None, None, None, "mkx"),
+    ("mky", None, None, None, "mky"),
+    ("mkz", None, None, None, "mkz"),
+    ("ml", Some("ml"), Some("mal"), Some("mal"), "mal"),
+    ("mla", None, None, None, "mla"),
+    ("mlb", None, None, None, "mlb"),
+    ("mlc", None, None, None, "mlc"),
+    ("mle", None, None, None, "mle"),
+    ("mlf", None, None, None, "mlf"),
+    ("mlh", None, None, None, "mlh"),
+    ("mli", None, None, None, "mli"),
+    ("mlj", None, None, None, "mlj"),
+    ("mlk", None, None, None, "mlk"),
+    ("mll", None, None, None, "mll"),
+    ("mlm", None, None, None, "mlm"),
+    ("mln", None, None, None, "mln"),
+    ("mlo", None, None, None, "mlo"),
+    ("mlp", None, None, None, "mlp"),
+    ("mlq", None, None, None, "mlq"),
+    ("mlr", None, None, None, "mlr"),
+    ("mls", None, None, None, "mls"),
+    ("mlu", None, None, None, "mlu"),
+    ("mlv", None, None, None, "mlv"),
+    ("mlw", None, None, None, "mlw"),
+    ("mlx", None, None, None, "mlx"),
+    ("mlz", None, None, None, "mlz"),
+    ("mma", None, None, None, "mma"),
+    ("mmb", None, None, None, "mmb"),
+    ("mmc", None, None, None, "mmc"),
+    ("mmd", None, None, None, "mmd"),
+    ("mme", None, None, None, "mme"),
+    ("mmf", None, None, None, "mmf"),
+    ("mmg", None, None, None, "mmg"),
+    ("mmh", None, None, None, "mmh"),
+    ("mmi", None, None, None, "mmi"),
+    ("mmj", None, None, None, "mmj"),
+    ("mmk", None, None, None, "mmk"),
+    ("mml", None, None, None, "mml"),
+    ("mmm", None, None, None, "mmm"),
+    ("mmn", None, None, None, "mmn"),
+    ("mmo", None, None, None, "mmo"),
+    ("mmp", None, None, None, "mmp"),
+    ("mmq", None, None, None, "mmq"),
+    ("mmr", None, None, None, "mmr"),
+    ("mmt", None, None, None, "mmt"),
+    ("mmu", None, None, None, "mmu"),
+    ("mmv", None, None, None, "mmv"),
+    ("mmw", None, None, None, "mmw"),
+    ("mmx", None, None, None, "mmx"),
+    ("mmy", None, None, None, "mmy"),
+    ("mmz", None, None, None, "mmz"),
+    ("mn", Some("mn"), Some("mon"), Some("mon"), "mon"),
+    ("mna", None, None, None, "mna"),
+    ("mnb", None, None, None, "mnb"),
+    ("mnc", None, Some("mnc"), Some("mnc"), "mnc"),
+    ("mnd", None, None, None, "mnd"),
+    ("mne", None, None, None, "mne"),
+    ("mnf", None, None, None, "mnf"),
+    ("mng", None, None, None, "mng"),
+    ("mnh", None, None, None, "mnh"),
+    ("mni", None, Some("mni"), Some("mni"), "mni"),
+    ("mnj", None, None, None, "mnj"),
+    ("mnk", None, None, None, "mnk"),
+    ("mnl", None, None, None, "mnl"),
+    ("mnm", None, None, None, "mnm"),
+    ("mnn", None, None, None, "mnn"),
+    ("mnp", None, None, None, "mnp"),
+    ("mnq", None, None, None, "mnq"),
+    ("mnr", None, None, None, "mnr"),
+    ("mns", None, None, None, "mns"),
+    ("mnu", None, None, None, "mnu"),
+    ("mnv", None, None, None, "mnv"),
+    ("mnw", None, None, None, "mnw"),
+    ("mnx", None, None, None, "mnx"),
+    ("mny", None, None, None, "mny"),
+    ("mnz", None, None, None, "mnz"),
+    ("moa", None, None, None, "moa"),
+    ("moc", None, None, None, "moc"),
+    ("mod", None, None, None, "mod"),
+    ("moe", None, None, None, "moe"),
+    ("mog", None, None, None, "mog"),
+    ("moh", None, Some("moh"), Some("moh"), "moh"),
+    ("moi", None, None, None, "moi"),
+    ("moj", None, None, None, "moj"),
+    ("mok", None, None, None, "mok"),
+    ("mom", None, None, None, "mom"),
+    ("moo", None, None, None, "moo"),
+    ("mop", None, None, None, "mop"),
+    ("moq", None, None, None, "moq"),
+    ("mor", None, None, None, "mor"),
+    ("mos", None, Some("mos"), Some("mos"), "mos"),
+    ("mot", None, None, None, "mot"),
+    ("mou", None, None, None, "mou"),
+    ("mov", None, None, None, "mov"),
+    ("mow", None, None, None, "mow"),
+    ("mox", None, None, None, "mox"),
+    ("moy", None, None, None, "moy"),
+    ("moz", None, None, None, "moz"),
+    ("mpa", None, None, None, "mpa"),
+    ("mpb", None, None, None, "mpb"),
+    ("mpc", None, None, None, "mpc"),
+    ("mpd", None, None, None, "mpd"),
+    ("mpe", None, None, None, "mpe"),
+    ("mpg", None, None, None, "mpg"),
+    ("mph", None, None, None, "mph"),
+    ("mpi", None, None, None, "mpi"),
+    ("mpj", None, None, None, "mpj"),
+    ("mpk", None, None, None, "mpk"),
+    ("mpl", None, None, None, "mpl"),
+    ("mpm", None, None, None, "mpm"),
+    ("mpn", None, None, None, "mpn"),
+    ("mpo", None, None, None, "mpo"),
+    ("mpp", None, None, None, "mpp"),
+    ("mpq", None, None, None, "mpq"),
+    ("mpr", None, None, None, "mpr"),
+    ("mps", None, None, None, "mps"),
+    ("mpt", None, None, None, "mpt"),
+    ("mpu", None, None, None, "mpu"),
+    ("mpv", None, None, None, "mpv"),
+    ("mpw", None, None, None, "mpw"),
+    ("mpx", None, None, None, "mpx"),
+    ("mpy", None, None, None, "mpy"),
+    ("mpz", None, None, None, "mpz"),
+    ("mqa", None, None, None, "mqa"),
+    ("mqb", None, None, None, "mqb"),
+    ("mqc", None, None, None, "mqc"),
+    ("mqe", None, None, None, "mqe"),
+    ("mqf", None, None, None, "mqf"),
+    ("mqg", None, None, None, "mqg"),
+    ("mqh", None, None, None, "mqh"),
+    ("mqi", None, None, None, "mqi"),
+    ("mqj", None, None, None, "mqj"),
+    ("mqk", None, None, None, "mqk"),
+    ("mql", None, None, None, "mql"),
+    ("mqm", None, None, None, "mqm"),
+    ("mqn", None, None, None, "mqn"),
+    ("mqo", None, None, None, "mqo"),
+    ("mqp", None, None, None, "mqp"),
+    ("mqq", None, None, None, "mqq"),
+    ("mqr", None, None, None, "mqr"),
+    ("mqs", None, None, None, "mqs"),
+    ("mqt", None, None, None, "mqt"),
+    ("mqu", None, None, None, "mqu"),
+    ("mqv", None, None, None, "mqv"),
+    ("mqw", None, None, None, "mqw"),
+    ("mqx", None, None, None, "mqx"),
+    ("mqy", None, None, None, "mqy"),
+    ("mqz", None, None, None, "mqz"),
+    ("mr", Some("mr"), Some("mar"), Some("mar"), "mar"),
+    ("mra", None, None, None, "mra"),
+    ("mrb", None, None, None, "mrb"),
+    ("mrc", None, None, None, "mrc"),
+    ("mrd", None, None, None, "mrd"),
+    ("mre", None, None, None, "mre"),
+    ("mrf", None, None, None, "mrf"),
+    ("mrg", None, None, None, "mrg"),
+    ("mrh", None, None, None, "mrh"),
+    ("mrj", None, None, None, "mrj"),
+    ("mrk", None, None, None, "mrk"),
+    ("mrl", None, None, None, "mrl"),
+    ("mrm", None, None, None, "mrm"),
+    ("mrn", None, None, None, "mrn"),
+    ("mro", None, None, None, "mro"),
+    ("mrp", None, None, None, "mrp"),
+    ("mrq", None, None, None, "mrq"),
+    ("mrr", None, None, None, "mrr"),
+    ("mrs", None, None, None, "mrs"),
+    ("mrt", None, None, None, "mrt"),
+    ("mru", None, None, None, "mru"),
+    ("mrv", None, None, None, "mrv"),
+    ("mrw", None, None, None, "mrw"),
+    ("mrx", None, None, None, "mrx"),
+    ("mry", None, None, None, "mry"),
+    ("mrz", None, None, None, "mrz"),
+    ("ms", Some("ms"), Some("msa"), Some("may"), "msa"),
+    ("msb", None, None, None, "msb"),
+    ("msc", None, None, None, "msc"),
+    ("msd", None, None, None, "msd"),
+    ("mse", None, None, None, "mse"),
+    ("msf", None, None, None, "msf"),
+    ("msg", None, None, None, "msg"),
+    ("msh", None, None, None, "msh"),
+    ("msi", None, None, None, "msi"),
+    ("msj", None, None, None, "msj"),
+    ("msk", None, None, None, "msk"),
+    ("msl", None, None, None, "msl"),
+    ("msm", None, None, None, "msm"),
+    ("msn", None, None, None, "msn"),
+    ("mso", None, None, None, "mso"),
+    ("msp", None, None, None, "msp"),
+    ("msq", None, None, None, "msq"),
+    ("msr", None, None, None, "msr"),
+    ("mss", None, None, None, "mss"),
+    ("msu", None, None, None, "msu"),
+    ("msv", None, None, None, "msv"),
+    ("msw", None, None, None, "msw"),
+    ("msx", None, None, None, "msx"),
+    ("msy", None, None, None, "msy"),
+    ("msz", None, None, None, "msz"),
+    ("mt", Some("mt"), Some("mlt"), Some("mlt"), "mlt"),
+    ("mta", None, None, None, "mta"),
+    ("mtb", None, None, None, "mtb"),
+    ("mtc", None, None, None, "mtc"),
+    ("mtd", None, None, None, "mtd"),
+    ("mte", None, None, None, "mte"),
+    ("mtf", None, None, None, "mtf"),
+    ("mtg", None, None, None, "mtg"),
+    ("mth", None, None, None, "mth"),
+    ("mti", None, None, None, "mti"),
+    ("mtj", None, None, None, "mtj"),
+    ("mtk", None, None, None, "mtk"),
+    ("mtl", None, None, None, "mtl"),
+    ("mtm", None, None, None, "mtm"),
+    ("mtn", None, None, None, "mtn"),
+    ("mto", None, None, None, "mto"),
+    ("mtp", None, None, None, "mtp"),
+    ("mtq", None, None, None, "mtq"),
+    ("mtr", None, None, None, "mtr"),
+    ("mts", None, None, None, "mts"),
+    ("mtt", None, None, None, "mtt"),
+    ("mtu", None, None, None, "mtu"),
+    ("mtv", None, None, None, "mtv"),
+    ("mtw", None, None, None, "mtw"),
+    ("mtx", None, None, None, "mtx"),
+    ("mty", None, None, None, "mty"),
+    ("mua", None, None, None, "mua"),
+    ("mub", None, None, None, "mub"),
+    ("muc", None, None, None, "muc"),
+    ("mud", None, None, None, "mud"),
+    ("mue", None, None, None, "mue"),
+    ("mug", None, None, None, "mug"),
+    ("muh", None, None, None, "muh"),
+    ("mui", None, None, None, "mui"),
+    ("muj", None, None, None, "muj"),
+    ("muk", None, None, None, "muk"),
+    ("mul", None, Some("mul"), Some("mul"), "mul"),
+    ("mum", None, None, None, "mum"),
+    ("muo", None, None, None, "muo"),
+    ("mup", None, None, None, "mup"),
+    ("muq", None, None, None, "muq"),
+    ("mur", None, None, None, "mur"),
+    ("mus", None, Some("mus"), Some("mus"), "mus"),
+    ("mut", None, None, None, "mut"),
+    ("muu", None, None, None, "muu"),
+    ("muv", None, None, None, "muv"),
+    ("mux", None, None, None, "mux"),
+    ("muy", None, None, None, "muy"),
+    ("muz", None, None, None, "muz"),
+    ("mva", None, None, None, "mva"),
+    ("mvb", None, None, None, "mvb"),
+    ("mvd", None, None, None, "mvd"),
+    ("mve", None, None, None, "mve"),
+    ("mvf", None, None, None, "mvf"),
+    ("mvg", None, None, None, "mvg"),
+    ("mvh", None, None, None, "mvh"),
+    ("mvi", None, None, None, "mvi"),
+    ("mvk", None, None, None, "mvk"),
+    ("mvl", None, None, None, "mvl"),
+    ("mvn", None, None, None, "mvn"),
+    ("mvo", None, None, None, "mvo"),
+    ("mvp", None, None, None, "mvp"),
+    ("mvq", None, None, None, "mvq"),
+    ("mvr", None, None, None, "mvr"),
+    ("mvs", None, None, None, "mvs"),
+    ("mvt", None, None, None, "mvt"),
+    ("mvu", None, None, None, "mvu"),
+    ("mvv", None, None, None, "mvv"),
+    ("mvw", None, None, None, "mvw"),
+    ("mvx", None, None, None, "mvx"),
+    ("mvy", None, None, None, "mvy"),
+    ("mvz", None, None, None, "mvz"),
+    ("mwa", None, None, None, "mwa"),
+    ("mwb", None, None, None, "mwb"),
+    ("mwc", None, None, None, "mwc"),
+    ("mwe", None, None, None, "mwe"),
+    ("mwf", None, None, None, "mwf"),
+    ("mwg", None, None, None, "mwg"),
+    ("mwh", None, None, None, "mwh"),
+    ("mwi", None, None, None, "mwi"),
+    ("mwk", None, None, None, "mwk"),
+    ("mwl", None, Some("mwl"), Some("mwl"), "mwl"),
+    ("mwm", None, None, None, "mwm"),
+    ("mwn", None, None, None, "mwn"),
+    ("mwo", None, None, None, "mwo"),
+    ("mwp", None, None, None, "mwp"),
+    ("mwq", None, None, None, "mwq"),
+    ("mwr", None, Some("mwr"), Some("mwr"), "mwr"),
+    ("mws", None, None, None, "mws"),
+    ("mwt", None, None, None, "mwt"),
+    ("mwu", None, None, None, "mwu"),
+    ("mwv", None, None, None, "mwv"),
+    ("mww", None, None, None, "mww"),
+    ("mwz", None, None, None, "mwz"),
+    ("mxa", None, None, None, "mxa"),
+    ("mxb", None, None, None, "mxb"),
+    ("mxc", None, None, None, "mxc"),
+    ("mxd", None, None, None, "mxd"),
+    ("mxe", None, None, None, "mxe"),
+    ("mxf", None, None, None, "mxf"),
+    ("mxg", None, None, None, "mxg"),
+    ("mxh", None, None, None, "mxh"),
+    ("mxi", None, None, None, "mxi"),
+    ("mxj", None, None, None, "mxj"),
+    ("mxk", None, None, None, "mxk"),
+    ("mxl", None, None, None, "mxl"),
+    ("mxm", None, None, None, "mxm"),
+    ("mxn", None, None, None, "mxn"),
+    ("mxo", None, None, None, "mxo"),
+    ("mxp", None, None, None, "mxp"),
+    ("mxq", None, None, None, "mxq"),
+    ("mxr", None, None, None, "mxr"),
+    ("mxs", None, None, None, "mxs"),
+    ("mxt", None, None, None, "mxt"),
+    ("mxu", None, None, None, "mxu"),
+    ("mxv", None, None, None, "mxv"),
+    ("mxw", None, None, None, "mxw"),
+    ("mxx", None, None, None, "mxx"),
+    ("mxy", None, None, None, "mxy"),
+    ("mxz", None, None, None, "mxz"),
+    ("my", Some("my"), Some("mya"), Some("bur"), "mya"),
+    ("myb", None, None, None, "myb"),
+    ("myc", None, None, None, "myc"),
+    ("mye", None, None, None, "mye"),
+    ("myf", None, None, None, "myf"),
+    ("myg", None, None, None, "myg"),
+    ("myh", None, None, None, "myh"),
+    ("myj", None, None, None, "myj"),
+    ("myk", None, None, None, "myk"),
+    ("myl", None, None, None, "myl"),
+    ("mym", None, None, None, "mym"),
+    ("myo", None, None, None, "myo"),
+    ("myp", None, None, None, "myp"),
+    ("myr", None, None, None, "myr"),
+    ("mys", None, None, None, "mys"),
+    ("myu", None, None, None, "myu"),
+    ("myv", None, Some("myv"), Some("myv"), "myv"),
+    ("myw", None, None, None, "myw"),
+    ("myx", None, None, None, "myx"),
+    ("myy", None, None, None, "myy"),
+    ("myz", None, None, None, "myz"),
+    ("mza", None, None, None, "mza"),
+    ("mzb", None, None, None, "mzb"),
+    ("mzc", None, None, None, "mzc"),
+    ("mzd", None, None, None, "mzd"),
+    ("mze", None, None, None, "mze"),
+    ("mzg", None, None, None, "mzg"),
+    ("mzh", None, None, None, "mzh"),
+    ("mzi", None, None, None, "mzi"),
+    ("mzj", None, None, None, "mzj"),
+    ("mzk", None, None, None, "mzk"),
+    ("mzl", None, None, None, "mzl"),
+    ("mzm", None, None, None, "mzm"),
+    ("mzn", None, None, None, "mzn"),
+    ("mzo", None, None, None, "mzo"),
+    ("mzp", None, None, None, "mzp"),
+    ("mzq", None, None, None, "mzq"),
+    ("mzr", None, None, None, "mzr"),
+    ("mzs", None, None, None, "mzs"),
+    ("mzt", None, None, None, "mzt"),
+    ("mzu", None, None, None, "mzu"),
+    ("mzv", None, None, None, "mzv"),
+    ("mzw", None, None, None, "mzw"),
+    ("mzx", None, None, None, "mzx"),
+    ("mzy", None, None, None, "mzy"),
+    ("mzz", None, None, None, "mzz"),
+    ("na", Some("na"), Some("nau"), Some("nau"), "nau"),
+    ("naa", None, None, None, "naa"),
+    ("nab", None, None, None, "nab"),
+    ("nac", None, None, None, "nac"),
+    ("nae", None, None, None, "nae"),
+    ("naf", None, None, None, "naf"),
+    ("nag", None, None, None, "nag"),
+    ("naj", None, None, None, "naj"),
+    ("nak", None, None, None, "nak"),
+    ("nal", None, None, None, "nal"),
+    ("nam", None, None, None, "nam"),
+    ("nan", None, None, None, "nan"),
+    ("nao", None, None, None, "nao"),
+    ("nap", None, Some("nap"), Some("nap"), "nap"),
+    ("naq", None, None, None, "naq"),
+    ("nar", None, None, None, "nar"),
+    ("nas", None, None, None, "nas"),
+    ("nat", None, None, None, "nat"),
+    ("naw", None, None, None, "naw"),
+    ("nax", None, None, None, "nax"),
+    ("nay", None, None, None, "nay"),
+    ("naz", None, None, None, "naz"),
+    ("nb", Some("nb"), Some("nob"), Some("nob"), "nob"),
+    ("nba", None, None, None, "nba"),
+    ("nbb", None, None, None, "nbb"),
+    ("nbc", None, None, None, "nbc"),
+    ("nbd", None, None, None, "nbd"),
+    ("nbe", None, None, None, "nbe"),
+    ("nbg", None, None, None, "nbg"),
+    ("nbh", None, None, None, "nbh"),
+    ("nbi", None, None, None, "nbi"),
+    ("nbj", None, None, None, "nbj"),
+    ("nbk", None, None, None, "nbk"),
+    ("nbm", None, None, None, "nbm"),
+    ("nbn", None, None, None, "nbn"),
+    ("nbo", None, None, None, "nbo"),
+    ("nbp", None, None, None, "nbp"),
+    ("nbq", None, None, None, "nbq"),
+    ("nbr", None, None, None, "nbr"),
+    ("nbs", None, None, None, "nbs"),
+    ("nbt", None, None, None, "nbt"),
+    ("nbu", None, None, None, "nbu"),
+    ("nbv", None, None, None, "nbv"),
+    ("nbw", None, None, None, "nbw"),
+    ("nby", None, None, None, "nby"),
+    ("nca", None, None, None, "nca"),
+    ("ncb", None, None, None, "ncb"),
+    ("ncc", None, None, None, "ncc"),
+    ("ncd", None, None, None, "ncd"),
+    ("nce", None, None, None, "nce"),
+    ("ncf", None, None, None, "ncf"),
+    ("ncg", None, None, None, "ncg"),
+    ("nch", None, None, None, "nch"),
+    ("nci", None, None, None, "nci"),
+    ("ncj", None, None, None, "ncj"),
+    ("nck", None, None, None, "nck"),
+    ("ncl", None, None, None, "ncl"),
+    ("ncm", None, None, None, "ncm"),
+    ("ncn", None, None, None, "ncn"),
+    ("nco", None, None, None, "nco"),
+    ("ncq", None, None, None, "ncq"),
+    ("ncr", None, None, None, "ncr"),
+    ("ncs", None, None, None, "ncs"),
+    ("nct", None, None, None, "nct"),
+    ("ncu", None, None, None, "ncu"),
+    ("ncx", None, None, None, "ncx"),
+    ("ncz", None, None, None, "ncz"),
+    ("nd", Some("nd"), Some("nde"), Some("nde"), "nde"),
+    ("nda", None, None, None, "nda"),
+    ("ndb", None, None, None, "ndb"),
+    ("ndc", None, None, None, "ndc"),
+    ("ndd", None, None, None, "ndd"),
+    ("ndf", None, None, None, "ndf"),
+    ("ndg", None, None, None, "ndg"),
+    ("ndh", None, None, None, "ndh"),
+    ("ndi", None, None, None, "ndi"),
+    ("ndj", None, None, None, "ndj"),
+    ("ndk", None, None, None, "ndk"),
+    ("ndl", None, None, None, "ndl"),
+    ("ndm", None, None, None, "ndm"),
+    ("ndn", None, None, None, "ndn"),
+    ("ndp", None, None, None, "ndp"),
+    ("ndq", None, None, None, "ndq"),
+    ("ndr", None, None, None, "ndr"),
+    ("nds", None, Some("nds"), Some("nds"), "nds"),
+    ("ndt", None, None, None, "ndt"),
+    ("ndu", None, None, None, "ndu"),
+    ("ndv", None, None, None, "ndv"),
+    ("ndw", None, None, None, "ndw"),
+    ("ndx", None, None, None, "ndx"),
+    ("ndy", None, None, None, "ndy"),
+    ("ndz", None, None, None, "ndz"),
+    ("ne", Some("ne"), Some("nep"), Some("nep"), "nep"),
+    ("nea", None, None, None, "nea"),
+    ("neb", None, None, None, "neb"),
+    ("nec", None, None, None, "nec"),
+    ("ned", None, None, None, "ned"),
+    ("nee", None, None, None, "nee"),
+    ("nef", None, None, None, "nef"),
+    ("neg", None, None, None, "neg"),
+    ("neh", None, None, None, "neh"),
+    ("nei", None, None, None, "nei"),
+    ("nej", None, None, None, "nej"),
+    ("nek", None, None, None, "nek"),
+    ("nem", None, None, None, "nem"),
+    ("nen", None, None, None, "nen"),
+    ("neo", None, None, None, "neo"),
+    ("neq", None, None, None, "neq"),
+    ("ner", None, None, None, "ner"),
+    ("nes", None, None, None, "nes"),
+    ("net", None, None, None, "net"),
+    ("neu", None, None, None, "neu"),
+    ("nev", None, None, None, "nev"),
+    ("new", None, Some("new"), Some("new"), "new"),
+    ("nex", None, None, None, "nex"),
+    ("ney", None, None, None, "ney"),
+    ("nez", None, None, None, "nez"),
+    ("nfa", None, None, None, "nfa"),
+    ("nfd", None, None, None, "nfd"),
+    ("nfl", None, None, None, "nfl"),
+    ("nfr", None, None, None, "nfr"),
+    ("nfu", None, None, None, "nfu"),
+    ("ng", Some("ng"), Some("ndo"), Some("ndo"), "ndo"),
+    ("nga", None, None, None, "nga"),
+    ("ngb", None, None, None, "ngb"),
+    ("ngc", None, None, None, "ngc"),
+    ("ngd", None, None, None, "ngd"),
+    ("nge", None, None, None, "nge"),
+    ("ngg", None, None, None, "ngg"),
+    ("ngh", None, None, None, "ngh"),
+    ("ngi", None, None, None, "ngi"),
+    ("ngj", None, None, None, "ngj"),
+    ("ngk", None, None, None, "ngk"),
+    ("ngl", None, None, None, "ngl"),
+    ("ngm", None, None, None, "ngm"),
+    ("ngn", None, None, None, "ngn"),
+    ("ngp", None, None, None, "ngp"),
+    ("ngq", None, None, None, "ngq"),
+    ("ngr", None, None, None, "ngr"),
+    ("ngs", None, None, None, "ngs"),
+    ("ngt", None, None, None, "ngt"),
+    ("ngu", None, None, None, "ngu"),
+    ("ngv", None, None, None, "ngv"),
+    ("ngw", None, None, None, "ngw"),
+    ("ngx", None, None, None, "ngx"),
+    ("ngy", None, None, None, "ngy"),
+    ("ngz", None, None, None, "ngz"),
+    ("nha", None, None, None, "nha"),
+    ("nhb", None, None, None, "nhb"),
+    ("nhc", None, None, None, "nhc"),
+    ("nhd", None, None, None, "nhd"),
+    ("nhe", None, None, None, "nhe"),
+    ("nhf", None, None, None, "nhf"),
+    ("nhg", None, None, None, "nhg"),
+    ("nhh", None, None, None, "nhh"),
+    ("nhi", None, None, None, "nhi"),
+    ("nhk", None, None, None, "nhk"),
+    ("nhm", None, None, None, "nhm"),
+    ("nhn", None, None, None, "nhn"),
+    ("nho", None, None, None, "nho"),
+    ("nhp", None, None, None, "nhp"),
+    ("nhq", None, None, None, "nhq"),
+    ("nhr", None, None, None, "nhr"),
+    ("nht", None, None, None, "nht"),
+    ("nhu", None, None, None, "nhu"),
+    ("nhv", None, None, None, "nhv"),
+    ("nhw", None, None, None, "nhw"),
+    ("nhx", None, None, None, "nhx"),
+    ("nhy", None, None, None, "nhy"),
+    ("nhz", None, None, None, "nhz"),
+    ("nia", None, Some("nia"), Some("nia"), "nia"),
+    ("nib", None, None, None, "nib"),
+    ("nid", None, None, None, "nid"),
+    ("nie", None, None, None, "nie"),
+    ("nif", None, None, None, "nif"),
+    ("nig", None, None, None, "nig"),
+    ("nih", None, None, None, "nih"),
+    ("nii", None, None, None, "nii"),
+    ("nij", None, None, None, "nij"),
+    ("nik", None, None, None, "nik"),
+    ("nil", None, None, None, "nil"),
+    ("nim", None, None, None, "nim"),
+    ("nin", None, None, None, "nin"),
+    ("nio", None, None, None, "nio"),
+    ("niq", None, None, None, "niq"),
+    ("nir", None, None, None, "nir"),
+    ("nis", None, None, None, "nis"),
+    ("nit", None, None, None, "nit"),
+    ("niu", None, Some("niu"), Some("niu"), "niu"),
+    ("niv", None, None, None, "niv"),
+    ("niw", None, None, None, "niw"),
+    ("nix", None, None, None, "nix"),
+    ("niy", None, None, None, "niy"),
+    ("niz", None, None, None, "niz"),
+    ("nja", None, None, None, "nja"),
+    ("njb", None, None, None, "njb"),
+    ("njd", None, None, None, "njd"),
+    ("njh", None, None, None, "njh"),
+    ("nji", None, None, None, "nji"),
+    ("njj", None, None, None, "njj"),
+    ("njl", None, None, None, "njl"),
+    ("njm", None, None, None, "njm"),
+    ("njn", None, None, None, "njn"),
+    ("njo", None, None, None, "njo"),
+    ("njr", None, None, None, "njr"),
+    ("njs", None, None, None, "njs"),
+    ("njt", None, None, None, "njt"),
+    ("nju", None, None, None, "nju"),
+    ("njx", None, None, None, "njx"),
+    ("njy", None, None, None, "njy"),
+    ("njz", None, None, None, "njz"),
+    ("nka", None, None, None, "nka"),
+    ("nkb", None, None, None, "nkb"),
+    ("nkc", None, None, None, "nkc"),
+    ("nkd", None, None, None, "nkd"),
+    ("nke", None, None, None, "nke"),
+    ("nkf", None, None, None, "nkf"),
+    ("nkg", None, None, None, "nkg"),
+    ("nkh", None, None, None, "nkh"),
+    ("nki", None, None, None, "nki"),
+    ("nkj", None, None, None, "nkj"),
+    ("nkk", None, None, None, "nkk"),
+    ("nkm", None, None, None, "nkm"),
+    ("nkn", None, None, None, "nkn"),
+    ("nko", None, None, None, "nko"),
+    ("nkp", None, None, None, "nkp"),
+    ("nkq", None, None, None, "nkq"),
+    ("nkr", None, None, None, "nkr"),
+    ("nks", None, None, None, "nks"),
+    ("nkt", None, None, None, "nkt"),
+    ("nku", None, None, None, "nku"),
+    ("nkv", None, None, None, "nkv"),
+    ("nkw", None, None, None, "nkw"),
+    ("nkx", None, None, None, "nkx"),
+    ("nkz", None, None, None, "nkz"),
+    ("nl", Some("nl"), Some("nld"), Some("dut"), "nld"),
+    ("nla", None, None, None, "nla"),
+    ("nlc", None, None, None, "nlc"),
+    ("nle", None, None, None, "nle"),
+    ("nlg", None, None, None, "nlg"),
+    ("nli", None, None, None, "nli"),
+    ("nlj", None, None, None, "nlj"),
+    ("nlk", None, None, None, "nlk"),
+    ("nll", None, None, None, "nll"),
+    ("nlm", None, None, None, "nlm"),
+    ("nlo", None, None, None, "nlo"),
+    ("nlq", None, None, None, "nlq"),
+    ("nlu", None, None, None, "nlu"),
+    ("nlv", None, None, None, "nlv"),
+    ("nlw", None, None, None, "nlw"),
+    ("nlx", None, None, None, "nlx"),
+    ("nly", None, None, None, "nly"),
+    ("nlz", None, None, None, "nlz"),
+    ("nma", None, None, None, "nma"),
+    ("nmb", None, None, None, "nmb"),
+    ("nmc", None, None, None, "nmc"),
+    ("nmd", None, None, None, "nmd"),
+    ("nme", None, None, None, "nme"),
+    ("nmf", None, None, None, "nmf"),
+    ("nmg", None, None, None, "nmg"),
+    ("nmh", None, None, None, "nmh"),
+    ("nmi", None, None, None, "nmi"),
+    ("nmj", None, None, None, "nmj"),
+    ("nmk", None, None, None, "nmk"),
+    ("nml", None, None, None, "nml"),
+    ("nmm", None, None, None, "nmm"),
+    ("nmn", None, None, None, "nmn"),
+    ("nmo", None, None, None, "nmo"),
+    ("nmp", None, None, None, "nmp"),
+    ("nmq", None, None, None, "nmq"),
+    ("nmr", None, None, None, "nmr"),
+    ("nms", None, None, None, "nms"),
+    ("nmt", None, None, None, "nmt"),
+    ("nmu", None, None, None, "nmu"),
+    ("nmv", None, None, None, "nmv"),
+    ("nmw", None, None, None, "nmw"),
+    ("nmx", None, None, None, "nmx"),
+    ("nmy", None, None, None, "nmy"),
+    ("nmz", None, None, None, "nmz"),
+    ("nn", Some("nn"), Some("nno"), Some("nno"), "nno"),
+    ("nna", None, None, None, "nna"),
+    ("nnb", None, None, None, "nnb"),
+    ("nnc", None, None, None, "nnc"),
+    ("nnd", None, None, None, "nnd"),
+    ("nne", None, None, None, "nne"),
+    ("nnf", None, None, None, "nnf"),
+    ("nng", None, None, None, "nng"),
+    ("nnh", None, None, None, "nnh"),
+    ("nni", None, None, None, "nni"),
+    ("nnj", None, None, None, "nnj"),
+    ("nnk", None, None, None, "nnk"),
+    ("nnl", None, None, None, "nnl"),
+    ("nnm", None, None, None, "nnm"),
+    ("nnn", None, None, None, "nnn"),
+    ("nnp", None, None, None, "nnp"),
+    ("nnq", None, None, None, "nnq"),
+    ("nnr", None, None, None, "nnr"),
+    ("nnt", None, None, None, "nnt"),
+    ("nnu", None, None, None, "nnu"),
+    ("nnv", None, None, None, "nnv"),
+    ("nnw", None, None, None, "nnw"),
+    ("nny", None, None, None, "nny"),
+    ("nnz", None, None, None, "nnz"),
+    ("no", Some("no"), Some("nor"), Some("nor"), "nor"),
+    ("noa", None, None, None, "noa"),
+    ("noc", None, None, None, "noc"),
+    ("nod", None, None, None, "nod"),
+    ("noe", None, None, None, "noe"),
+    ("nof", None, None, None, "nof"),
+    ("nog", None, Some("nog"), Some("nog"), "nog"),
+    ("noh", None, None, None, "noh"),
+    ("noi", None, None, None, "noi"),
+    ("noj", None, None, None, "noj"),
+    ("nok", None, None, None, "nok"),
+    ("nol", None, None, None, "nol"),
+    ("nom", None, None, None, "nom"),
+    ("non", None, Some("non"), Some("non"), "non"),
+    ("nop", None, None, None, "nop"),
+    ("noq", None, None, None, "noq"),
+    ("nos", None, None, None, "nos"),
+    ("not", None, None, None, "not"),
+    ("nou", None, None, None, "nou"),
+    ("nov", None, None, None, "nov"),
+    ("now", None, None, None, "now"),
+    ("noy", None, None, None, "noy"),
+    ("noz", None, None, None, "noz"),
+    ("npa", None, None, None, "npa"),
+    ("npb", None, None, None, "npb"),
+    ("npg", None, None, None, "npg"),
+    ("nph", None, None, None, "nph"),
+    ("npi", None, None, None, "npi"),
+    ("npl", None, None, None, "npl"),
+    ("npn", None, None, None, "npn"),
+    ("npo", None, None, None, "npo"),
+    ("nps", None, None, None, "nps"),
+    ("npu", None, None, None, "npu"),
+    ("npx", None, None, None, "npx"),
+    ("npy", None, None, None, "npy"),
+    ("nqg", None, None, None, "nqg"),
+    ("nqk", None, None, None, "nqk"),
+    ("nql", None, None, None, "nql"),
+    ("nqm", None, None, None, "nqm"),
+    ("nqn", None, None, None, "nqn"),
+    ("nqo", None, Some("nqo"), Some("nqo"), "nqo"),
+    ("nqq", None, None, None, "nqq"),
+    ("nqt", None, None, None, "nqt"),
+    ("nqy", None, None, None, "nqy"),
+    ("nr", Some("nr"), Some("nbl"), Some("nbl"), "nbl"),
+    ("nra", None, None, None, "nra"),
+    ("nrb", None, None, None, "nrb"),
+    ("nrc", None, None, None, "nrc"),
+    ("nre", None, None, None, "nre"),
+    ("nrf", None, None, None, "nrf"),
+    ("nrg", None, None, None, "nrg"),
+    ("nri", None, None, None, "nri"),
+    ("nrk", None, None, None, "nrk"),
+    ("nrl", None, None, None, "nrl"),
+    ("nrm", None, None, None, "nrm"),
+    ("nrn", None, None, None, "nrn"),
+    ("nrp", None, None, None, "nrp"),
+    ("nrr", None, None, None, "nrr"),
+    ("nrt", None, None, None, "nrt"),
+    ("nru", None, None, None, "nru"),
+    ("nrx", None, None, None, "nrx"),
+    ("nrz", None, None, None, "nrz"),
+    ("nsa", None, None, None, "nsa"),
+    ("nsb", None, None, None, "nsb"),
+    ("nsc", None, None, None, "nsc"),
+    ("nsd", None, None, None, "nsd"),
+    ("nse", None, None, None, "nse"),
+    ("nsf", None, None, None, "nsf"),
+    ("nsg", None, None, None, "nsg"),
+    ("nsh", None, None, None, "nsh"),
+    ("nsi", None, None, None, "nsi"),
+    ("nsk", None, None, None, "nsk"),
+    ("nsl", None, None, None, "nsl"),
+    ("nsm", None, None, None, "nsm"),
+    ("nsn", None, None, None, "nsn"),
+    ("nso", None, Some("nso"), Some("nso"), "nso"),
+    ("nsp", None, None, None, "nsp"),
+    ("nsq", None, None, None, "nsq"),
+    ("nsr", None, None, None, "nsr"),
+    ("nss", None, None, None, "nss"),
+    ("nst", None, None, None, "nst"),
+    ("nsu", None, None, None, "nsu"),
+    ("nsv", None, None, None, "nsv"),
+    ("nsw", None, None, None, "nsw"),
+    ("nsx", None, None, None, "nsx"),
+    ("nsy", None, None, None, "nsy"),
+    ("nsz", None, None, None, "nsz"),
+    ("ntd", None, None, None, "ntd"),
+    ("nte", None, None, None, "nte"),
+    ("ntg", None, None, None, "ntg"),
+    ("nti", None, None, None, "nti"),
+    ("ntj", None, None, None, "ntj"),
+    ("ntk", None, None, None, "ntk"),
+    ("ntm", None, None, None, "ntm"),
+    ("nto", None, None, None, "nto"),
+    ("ntp", None, None, None, "ntp"),
+    ("ntr", None, None, None, "ntr"),
+    ("ntu", None, None, None, "ntu"),
+    ("ntw", None, None, None, "ntw"),
+    ("ntx", None, None, None, "ntx"),
+    ("nty", None, None, None, "nty"),
+    ("ntz", None, None, None, "ntz"),
+    ("nua", None, None, None, "nua"),
+    ("nuc", None, None, None, "nuc"),
+    ("nud", None, None, None, "nud"),
+    ("nue", None, None, None, "nue"),
+    ("nuf", None, None, None, "nuf"),
+    ("nug", None, None, None, "nug"),
+    ("nuh", None, None, None, "nuh"),
+    ("nui", None, None, None, "nui"),
+    ("nuj", None, None, None, "nuj"),
+    ("nuk", None, None, None, "nuk"),
+    ("nul", None, None, None, "nul"),
+    ("num", None, None, None, "num"),
+    ("nun", None, None, None, "nun"),
+    ("nuo", None, None, None, "nuo"),
+    ("nup", None, None, None, "nup"),
+    ("nuq", None, None, None, "nuq"),
+    ("nur", None, None, None, "nur"),
+    ("nus", None, None, None, "nus"),
+    ("nut", None, None, None, "nut"),
+    ("nuu", None, None, None, "nuu"),
+    ("nuv", None, None, None, "nuv"),
+    ("nuw", None, None, None, "nuw"),
+    ("nux", None, None, None, "nux"),
+    ("nuy", None, None, None, "nuy"),
+    ("nuz", None, None, None, "nuz"),
+    ("nv", Some("nv"), Some("nav"), Some("nav"), "nav"),
+    ("nvh", None, None, None, "nvh"),
+    ("nvm", None, None, None, "nvm"),
+    ("nvo", None, None, None, "nvo"),
+    ("nwa", None, None, None, "nwa"),
+    ("nwb", None, None, None, "nwb"),
+    ("nwc", None, Some("nwc"), Some("nwc"), "nwc"),
+    ("nwe", None, None, None, "nwe"),
+    ("nwg", None, None, None, "nwg"),
+    ("nwi", None, None, None, "nwi"),
+    ("nwm", None, None, None, "nwm"),
+    ("nwo", None, None, None, "nwo"),
+    ("nwr", None, None, None, "nwr"),
+    ("nww", None, None, None, "nww"),
+    ("nwx", None, None, None, "nwx"),
+    ("nwy", None, None, None, "nwy"),
+    ("nxa", None, None, None, "nxa"),
+    ("nxd", None, None, None, "nxd"),
+    ("nxe", None, None, None, "nxe"),
+    ("nxg", None, None, None, "nxg"),
+    ("nxi", None, None, None, "nxi"),
+    ("nxk", None, None, None, "nxk"),
+    ("nxl", None, None, None, "nxl"),
+    ("nxm", None, None, None, "nxm"),
+    ("nxn", None, None, None, "nxn"),
+    ("nxo", None, None, None, "nxo"),
+    ("nxq", None, None, None, "nxq"),
+    ("nxr", None, None, None, "nxr"),
+    ("nxx", None, None, None, "nxx"),
+    ("ny", Some("ny"), Some("nya"), Some("nya"), "nya"),
+    ("nyb", None, None, None, "nyb"),
+    ("nyc", None, None, None, "nyc"),
+    ("nyd", None, None, None, "nyd"),
+    ("nye", None, None, None, "nye"),
+    ("nyf", None, None, None, "nyf"),
+    ("nyg", None, None, None, "nyg"),
+    ("nyh", None, None, None, "nyh"),
+    ("nyi", None, None, None, "nyi"),
+    ("nyj", None, None, None, "nyj"),
+    ("nyk", None, None, None, "nyk"),
+    ("nyl", None, None, None, "nyl"),
+    ("nym", None, Some("nym"), Some("nym"), "nym"),
+    ("nyn", None, Some("nyn"), Some("nyn"), "nyn"),
+    ("nyo", None, Some("nyo"), Some("nyo"), "nyo"),
+    ("nyp", None, None, None, "nyp"),
+    ("nyq", None, None, None, "nyq"),
+    ("nyr", None, None, None, "nyr"),
+    ("nys", None, None, None, "nys"),
+    ("nyt", None, None, None, "nyt"),
+    ("nyu", None, None, None, "nyu"),
+    ("nyv", None, None, None, "nyv"),
+    ("nyw", None, None, None, "nyw"),
+    ("nyx", None, None, None, "nyx"),
+    ("nyy", None, None, None, "nyy"),
+    ("nza", None, None, None, "nza"),
+    ("nzb", None, None, None, "nzb"),
+    ("nzd", None, None, None, "nzd"),
+    ("nzi", None, Some("nzi"), Some("nzi"), "nzi"),
+    ("nzk", None, None, None, "nzk"),
+    ("nzm", None, None, None, "nzm"),
+    ("nzs", None, None, None, "nzs"),
+    ("nzu", None, None, None, "nzu"),
+    ("nzy", None, None, None, "nzy"),
+    ("nzz", None, None, None, "nzz"),
+    ("oaa", None, None, None, "oaa"),
+    ("oac", None, None, None, "oac"),
+    ("oar", None, None, None, "oar"),
+    ("oav", None, None, None, "oav"),
+    ("obi", None, None, None, "obi"),
+    ("obk", None, None, None, "obk"),
+    ("obl", None, None, None, "obl"),
+    ("obm", None, None, None, "obm"),
+    ("obo", None, None, None, "obo"),
+    ("obr", None, None, None, "obr"),
+    ("obt", None, None, None, "obt"),
+    ("obu", None, None, None, "obu"),
+    ("oc", Some("oc"), Some("oci"), Some("oci"), "oci"),
+    ("oca", None, None, None, "oca"),
+    ("och", None, None, None, "och"),
+    ("ocm", None, None, None, "ocm"),
+    ("oco", None, None, None, "oco"),
+    ("ocu", None, None, None, "ocu"),
+    ("oda", None, None, None, "oda"),
+    ("odk", None, None, None, "odk"),
+    ("odt", None, None, None, "odt"),
+    ("odu", None, None, None, "odu"),
+    ("ofo", None, None, None, "ofo"),
+    ("ofs", None, None, None, "ofs"),
+    ("ofu", None, None, None, "ofu"),
+    ("ogb", None, None, None, "ogb"),
+    ("ogc", None, None, None, "ogc"),
+    ("oge", None, None, None, "oge"),
+    ("ogg", None, None, None, "ogg"),
+    ("ogo", None, None, None, "ogo"),
+    ("ogu", None, None, None, "ogu"),
+    ("oht", None, None, None, "oht"),
+    ("ohu", None, None, None, "ohu"),
+    ("oia", None, None, None, "oia"),
+    ("oie", None, None, None, "oie"),
+    ("oin", None, None, None, "oin"),
+    ("oj", Some("oj"), Some("oji"), Some("oji"), "oji"),
+    ("ojb", None, None, None, "ojb"),
+    ("ojc", None, None, None, "ojc"),
+    ("ojg", None, None, None, "ojg"),
+    ("ojp", None, None, None, "ojp"),
+    ("ojs", None, None, None, "ojs"),
+    ("ojv", None, None, None, "ojv"),
+    ("ojw", None, None, None, "ojw"),
+    ("oka", None, None, None, "oka"),
+    ("okb", None, None, None, "okb"),
+    ("okc", None, None, None, "okc"),
+    ("okd", None, None, None, "okd"),
+    ("oke", None, None, None, "oke"),
+    ("okg", None, None, None, "okg"),
+    ("okh", None, None, None, "okh"),
+    ("oki", None, None, None, "oki"),
+    ("okj", None, None, None, "okj"),
+    ("okk", None, None, None, "okk"),
+    ("okl", None, None, None, "okl"),
+    ("okm", None, None, None, "okm"),
+    ("okn", None, None, None, "okn"),
+    ("oko", None, None, None, "oko"),
+    ("okr", None, None, None, "okr"),
+    ("oks", None, None, None, "oks"),
+    ("oku", None, None, None, "oku"),
+    ("okv", None, None, None, "okv"),
+    ("okx", None, None, None, "okx"),
+    ("okz", None, None, None, "okz"),
+    ("ola", None, None, None, "ola"),
+    ("old", None, None, None, "old"),
+    ("ole", None, None, None, "ole"),
+    ("olk", None, None, None, "olk"),
+    ("olm", None, None, None, "olm"),
+    ("olo", None, None, None, "olo"),
+    ("olr", None, None, None, "olr"),
+    ("olt", None, None, None, "olt"),
+    ("olu", None, None, None, "olu"),
+    ("om", Some("om"), Some("orm"), Some("orm"), "orm"),
+    ("oma", None, None, None, "oma"),
+    ("omb", None, None, None, "omb"),
+    ("omc", None, None, None, "omc"),
+    ("omg", None, None, None, "omg"),
+    ("omi", None, None, None, "omi"),
+    ("omk", None, None, None, "omk"),
+    ("oml", None, None, None, "oml"),
+    ("omn", None, None, None, "omn"),
+    ("omo", None, None, None, "omo"),
+    ("omp", None, None, None, "omp"),
+    ("omr", None, None, None, "omr"),
+    ("omt", None, None, None, "omt"),
+    ("omu", None, None, None, "omu"),
+    ("omw", None, None, None, "omw"),
+    ("omx", None, None, None, "omx"),
+    ("omy", None, None, None, "omy"),
+    ("ona", None, None, None, "ona"),
+    ("onb", None, None, None, "onb"),
+    ("one", None, None, None, "one"),
+    ("ong", None, None, None, "ong"),
+    ("oni", None, None, None, "oni"),
+    ("onj", None, None, None, "onj"),
+    ("onk", None, None, None, "onk"),
+    ("onn", None, None, None, "onn"),
+    ("ono", None, None, None, "ono"),
+    ("onp", None, None, None, "onp"),
+    ("onr", None, None, None, "onr"),
+    ("ons", None, None, None, "ons"),
+    ("ont", None, None, None, "ont"),
+    ("onu", None, None, None, "onu"),
+    ("onw", None, None, None, "onw"),
+    ("onx", None, None, None, "onx"),
+    ("ood", None, None, None, "ood"),
+    ("oog", None, None, None, "oog"),
+    ("oon", None, None, None, "oon"),
+    ("oor", None, None, None, "oor"),
+    ("oos", None, None, None, "oos"),
+    ("opa", None, None, None, "opa"),
+    ("opk", None, None, None, "opk"),
+    ("opm", None, None, None, "opm"),
+    ("opo", None, None, None, "opo"),
+    ("opt", None, None, None, "opt"),
+    ("opy", None, None, None, "opy"),
+    ("or", Some("or"), Some("ori"), Some("ori"), "ori"),
+    ("ora", None, None, None, "ora"),
+    ("orc", None, None, None, "orc"),
+    ("ore", None, None, None, "ore"),
+    ("org", None, None, None, "org"),
+    ("orh", None, None, None, "orh"),
+    ("orn", None, None, None, "orn"),
+    ("oro", None, None, None, "oro"),
+    ("orr", None, None, None, "orr"),
+    ("ors", None, None, None, "ors"),
+    ("ort", None, None, None, "ort"),
+    ("oru", None, None, None, "oru"),
+    ("orv", None, None, None, "orv"),
+    ("orw", None, None, None, "orw"),
+    ("orx", None, None, None, "orx"),
+    ("ory", None, None, None, "ory"),
+    ("orz", None, None, None, "orz"),
+    ("os", Some("os"), Some("oss"), Some("oss"), "oss"),
+    ("osa", None, Some("osa"), Some("osa"), "osa"),
+    ("osc", None, None, None, "osc"),
+    ("osi", None, None, None, "osi"),
+    ("osn", None, None, None, "osn"),
+    ("oso", None, None, None, "oso"),
+    ("osp", None, None, None, "osp"),
+    ("ost", None, None, None, "ost"),
+    ("osu", None, None, None, "osu"),
+    ("osx", None, None, None, "osx"),
+    ("ota", None, Some("ota"), Some("ota"), "ota"),
+    ("otb", None, None, None, "otb"),
+    ("otd", None, None, None, "otd"),
+    ("ote", None, None, None, "ote"),
+    ("oti", None, None, None, "oti"),
+    ("otk", None, None, None, "otk"),
+    ("otl", None, None, None, "otl"),
+    ("otm", None, None, None, "otm"),
+    ("otn", None, None, None, "otn"),
+    ("otq", None, None, None, "otq"),
+    ("otr", None, None, None, "otr"),
+    ("ots", None, None, None, "ots"),
+    ("ott", None, None, None, "ott"),
+    ("otu", None, None, None, "otu"),
+    ("otw", None, None, None, "otw"),
+    ("otx", None, None, None, "otx"),
+    ("oty", None, None, None, "oty"),
+    ("otz", None, None, None, "otz"),
+    ("oua", None, None, None, "oua"),
+    ("oub", None, None, None, "oub"),
+    ("oue", None, None, None, "oue"),
+    ("oui", None, None, None, "oui"),
+    ("oum", None, None, None, "oum"),
+    ("ovd", None, None, None, "ovd"),
+    ("owi", None, None, None, "owi"),
+    ("owl", None, None, None, "owl"),
+    ("oyb", None, None, None, "oyb"),
+    ("oyd", None, None, None, "oyd"),
+    ("oym", None, None, None, "oym"),
+    ("oyy", None, None, None, "oyy"),
+    ("ozm", None, None, None, "ozm"),
+    ("pa", Some("pa"), Some("pan"), Some("pan"), "pan"),
+    ("pab", None, None, None, "pab"),
+    ("pac", None, None, None, "pac"),
+    ("pad", None, None, None, "pad"),
+    ("pae", None, None, None, "pae"),
+    ("paf", None, None, None, "paf"),
+    ("pag", None, Some("pag"), Some("pag"), "pag"),
+    ("pah", None, None, None, "pah"),
+    ("pai", None, None, None, "pai"),
+    ("pak", None, None, None, "pak"),
+    ("pal", None, Some("pal"), Some("pal"), "pal"),
+    ("pam", None, Some("pam"), Some("pam"), "pam"),
+    ("pao", None, None, None, "pao"),
+    ("pap", None, Some("pap"), Some("pap"), "pap"),
+    ("paq", None, None, None, "paq"),
+    ("par", None, None, None, "par"),
+    ("pas", None, None, None, "pas"),
+    ("pau", None, Some("pau"), Some("pau"), "pau"),
+    ("pav", None, None, None, "pav"),
+    ("paw", None, None, None, "paw"),
+    ("pax", None, None, None, "pax"),
+    ("pay", None, None, None, "pay"),
+    ("paz", None, None, None, "paz"),
+    ("pbb", None, None, None, "pbb"),
+    ("pbc", None, None, None, "pbc"),
+    ("pbe", None, None, None, "pbe"),
+    ("pbf", None, None, None, "pbf"),
+    ("pbg", None, None, None, "pbg"),
+    ("pbh", None, None, None, "pbh"),
+    ("pbi", None, None, None, "pbi"),
+    ("pbl", None, None, None, "pbl"),
+    ("pbm", None, None, None, "pbm"),
+    ("pbn", None, None, None, "pbn"),
+    ("pbo", None, None, None, "pbo"),
+    ("pbp", None, None, None, "pbp"),
+    ("pbr", None, None, None, "pbr"),
+    ("pbs", None, None, None, "pbs"),
+    ("pbt", None, None, None, "pbt"),
+    ("pbu", None, None, None, "pbu"),
+    ("pbv", None, None, None, "pbv"),
+    ("pby", None, None, None, "pby"),
+    ("pca", None, None, None, "pca"),
+    ("pcb", None, None, None, "pcb"),
+    ("pcc", None, None, None, "pcc"),
+    ("pcd", None, None, None, "pcd"),
+    ("pce", None, None, None, "pce"),
+    ("pcf", None, None, None, "pcf"),
+    ("pcg", None, None, None, "pcg"),
+    ("pch", None, None, None, "pch"),
+    ("pci", None, None, None, "pci"),
+    ("pcj", None, None, None, "pcj"),
+    ("pck", None, None, None, "pck"),
+    ("pcl", None, None, None, "pcl"),
+    ("pcm", None, None, None, "pcm"),
+    ("pcn", None, None, None, "pcn"),
+    ("pcp", None, None, None, "pcp"),
+    ("pcw", None, None, None, "pcw"),
+    ("pda", None, None, None, "pda"),
+    ("pdc", None, None, None, "pdc"),
+    ("pdi", None, None, None, "pdi"),
+    ("pdn", None, None, None, "pdn"),
+    ("pdo", None, None, None, "pdo"),
+    ("pdt", None, None, None, "pdt"),
+    ("pdu", None, None, None, "pdu"),
+    ("pea", None, None, None, "pea"),
+    ("peb", None, None, None, "peb"),
+    ("ped", None, None, None, "ped"),
+    ("pee", None, None, None, "pee"),
+    ("pef", None, None, None, "pef"),
+    ("peg", None, None, None, "peg"),
+    ("peh", None, None, None, "peh"),
+    ("pei", None, None, None, "pei"),
+    ("pej", None, None, None, "pej"),
+    ("pek", None, None, None, "pek"),
+    ("pel", None, None, None, "pel"),
+    ("pem", None, None, None, "pem"),
+    ("peo", None, Some("peo"), Some("peo"), "peo"),
+    ("pep", None, None, None, "pep"),
+    ("peq", None, None, None, "peq"),
+    ("pes", None, None, None, "pes"),
+    ("pev", None, None, None, "pev"),
+    ("pex", None, None, None, "pex"),
+    ("pey", None, None, None, "pey"),
+    ("pez", None, None, None, "pez"),
+    ("pfa", None, None, None, "pfa"),
+    ("pfe", None, None, None, "pfe"),
+    ("pfl", None, None, None, "pfl"),
+    ("pga", None, None, None, "pga"),
+    ("pgd", None, None, None, "pgd"),
+    ("pgg", None, None, None, "pgg"),
+    ("pgi", None, None, None, "pgi"),
+    ("pgk", None, None, None, "pgk"),
+    ("pgl", None, None, None, "pgl"),
+    ("pgn", None, None, None, "pgn"),
+    ("pgs", None, None, None, "pgs"),
+    ("pgu", None, None, None, "pgu"),
+    ("pgz", None, None, None, "pgz"),
+    ("pha", None, None, None, "pha"),
+    ("phd", None, None, None, "phd"),
+    ("phg", None, None, None, "phg"),
+    ("phh", None, None, None, "phh"),
+    ("phj", None, None, None, "phj"),
+    ("phk", None, None, None, "phk"),
+    ("phl", None, None, None, "phl"),
+    ("phm", None, None, None, "phm"),
+    ("phn", None, Some("phn"), Some("phn"), "phn"),
+    ("pho", None, None, None, "pho"),
+    ("phq", None, None, None, "phq"),
+    ("phr", None, None, None, "phr"),
+    ("pht", None, None, None, "pht"),
+    ("phu", None, None, None, "phu"),
+    ("phv", None, None, None, "phv"),
+    ("phw", None, None, None, "phw"),
+    ("pi", Some("pi"), Some("pli"), Some("pli"), "pli"),
+    ("pia", None, None, None, "pia"),
+    ("pib", None, None, None, "pib"),
+    ("pic", None, None, None, "pic"),
+    ("pid", None, None, None, "pid"),
+    ("pie", None, None, None, "pie"),
+    ("pif", None, None, None, "pif"),
+    ("pig", None, None, None, "pig"),
+    ("pih", None, None, None, "pih"),
+    ("pij", None, None, None, "pij"),
+    ("pil", None, None, None, "pil"),
+    ("pim", None, None, None, "pim"),
+    ("pin", None, None, None, "pin"),
+    ("pio", None, None, None, "pio"),
+    ("pip", None, None, None, "pip"),
+    ("pir", None, None, None, "pir"),
+    ("pis", None, None, None, "pis"),
+    ("pit", None, None, None, "pit"),
+    ("piu", None, None, None, "piu"),
+    ("piv", None, None, None, "piv"),
+    ("piw", None, None, None, "piw"),
+    ("pix", None, None, None, "pix"),
+    ("piy", None, None, None, "piy"),
+    ("piz", None, None, None, "piz"),
+    ("pjt", None, None, None, "pjt"),
+    ("pka", None, None, None, "pka"),
+    ("pkb", None, None, None, "pkb"),
+    ("pkc", None, None, None, "pkc"),
+    ("pkg", None, None, None, "pkg"),
+    ("pkh", None, None, None, "pkh"),
+    ("pkn", None, None, None, "pkn"),
+    ("pko", None, None, None, "pko"),
+    ("pkp", None, None, None, "pkp"),
+    ("pkr", None, None, None, "pkr"),
+    ("pks", None, None, None, "pks"),
+    ("pkt", None, None, None, "pkt"),
+    ("pku", None, None, None, "pku"),
+    ("pl", Some("pl"), Some("pol"), Some("pol"), "pol"),
+    ("pla", None, None, None, "pla"),
+    ("plb", None, None, None, "plb"),
+    ("plc", None, None, None, "plc"),
+    ("pld", None, None, None, "pld"),
+    ("ple", None, None, None, "ple"),
+    ("plg", None, None, None, "plg"),
+    ("plh", None, None, None, "plh"),
+    ("plj", None, None, None, "plj"),
+    ("plk", None, None, None, "plk"),
+    ("pll", None, None, None, "pll"),
+    ("pln", None, None, None, "pln"),
+    ("plo", None, None, None, "plo"),
+    ("plq", None, None, None, "plq"),
+    ("plr", None, None, None, "plr"),
+    ("pls", None, None, None, "pls"),
+    ("plt", None, None, None, "plt"),
+    ("plu", None, None, None, "plu"),
+    ("plv", None, None, None, "plv"),
+    ("plw", None, None, None, "plw"),
+    ("ply", None, None, None, "ply"),
+    ("plz", None, None, None, "plz"),
+    ("pma", None, None, None, "pma"),
+    ("pmb", None, None, None, "pmb"),
+    ("pmd", None, None, None, "pmd"),
+    ("pme", None, None, None, "pme"),
+    ("pmf", None, None, None, "pmf"),
+    ("pmh", None, None, None, "pmh"),
+    ("pmi", None, None, None, "pmi"),
+    ("pmj", None, None, None, "pmj"),
+    ("pmk", None, None, None, "pmk"),
+    ("pml", None, None, None, "pml"),
+    ("pmm", None, None, None, "pmm"),
+    ("pmn", None, None, None, "pmn"),
+    ("pmo", None, None, None, "pmo"),
+    ("pmq", None, None, None, "pmq"),
+    ("pmr", None, None, None, "pmr"),
+    ("pms", None, None, None, "pms"),
+    ("pmt", None, None, None, "pmt"),
+    ("pmw", None, None, None, "pmw"),
+    ("pmx", None, None, None, "pmx"),
+    ("pmy", None, None, None, "pmy"),
+    ("pmz", None, None, None, "pmz"),
+    ("pna", None, None, None, "pna"),
+    ("pnb", None, None, None, "pnb"),
+    ("pnc", None, None, None, "pnc"),
+    ("pnd", None, None, None, "pnd"),
+    ("pne", None, None, None, "pne"),
+    ("png", None, None, None, "png"),
+    ("pnh", None, None, None, "pnh"),
+    ("pni", None, None, None, "pni"),
+    ("pnj", None, None, None, "pnj"),
+    ("pnk", None, None, None, "pnk"),
+    ("pnl", None, None, None, "pnl"),
+    ("pnm", None, None, None, "pnm"),
+    ("pnn", None, None, None, "pnn"),
+    ("pno", None, None, None, "pno"),
+    ("pnp", None, None, None, "pnp"),
+    ("pnq", None, None, None, "pnq"),
+    ("pnr", None, None, None, "pnr"),
+    ("pns", None, None, None, "pns"),
+    ("pnt", None, None, None, "pnt"),
+    ("pnu", None, None, None, "pnu"),
+    ("pnv", None, None, None, "pnv"),
+    ("pnw", None, None, None, "pnw"),
+    ("pnx", None, None, None, "pnx"),
+    ("pny", None, None, None, "pny"),
+    ("pnz", None, None, None, "pnz"),
+    ("poc", None, None, None, "poc"),
+    ("poe", None, None, None, "poe"),
+    ("pof", None, None, None, "pof"),
+    ("pog", None, None, None, "pog"),
+    ("poh", None, None, None, "poh"),
+    ("poi", None, None, None, "poi"),
+    ("pok", None, None, None, "pok"),
+    ("pom", None, None, None, "pom"),
+    ("pon", None, Some("pon"), Some("pon"), "pon"),
+    ("poo", None, None, None, "poo"),
+    ("pop", None, None, None, "pop"),
+    ("poq", None, None, None, "poq"),
+    ("pos", None, None, None, "pos"),
+    ("pot", None, None, None, "pot"),
+    ("pov", None, None, None, "pov"),
+    ("pow", None, None, None, "pow"),
+    ("pox", None, None, None, "pox"),
+    ("poy", None, None, None, "poy"),
+    ("ppe", None, None, None, "ppe"),
+    ("ppi", None, None, None, "ppi"),
+    ("ppk", None, None, None, "ppk"),
+    ("ppl", None, None, None, "ppl"),
+    ("ppm", None, None, None, "ppm"),
+    ("ppn", None, None, None, "ppn"),
+    ("ppo", None, None, None, "ppo"),
+    ("ppp", None, None, None, "ppp"),
+    ("ppq", None, None, None, "ppq"),
+    ("pps", None, None, None, "pps"),
+    ("ppt", None, None, None, "ppt"),
+    ("ppu", None, None, None, "ppu"),
+    ("pqa", None, None, None, "pqa"),
+    ("pqm", None, None, None, "pqm"),
+    ("prc", None, None, None, "prc"),
+    ("prd", None, None, None, "prd"),
+    ("pre", None, None, None, "pre"),
+    ("prf", None, None, None, "prf"),
+    ("prg", None, None, None, "prg"),
+    ("prh", None, None, None, "prh"),
+    ("pri", None, None, None, "pri"),
+    ("prk", None, None, None, "prk"),
+    ("prl", None, None, None, "prl"),
+    ("prm", None, None, None, "prm"),
+    ("prn", None, None, None, "prn"),
+    ("pro", None, Some("pro"), Some("pro"), "pro"),
+    ("prp", None, None, None, "prp"),
+    ("prq", None, None, None, "prq"),
+    ("prr", None, None, None, "prr"),
+    ("prs", None, None, None, "prs"),
+    ("prt", None, None, None, "prt"),
+    ("pru", None, None, None, "pru"),
+    ("prw", None, None, None, "prw"),
+    ("prx", None, None, None, "prx"),
+    ("prz", None, None, None, "prz"),
+    ("ps", Some("ps"), Some("pus"), Some("pus"), "pus"),
+    ("psa", None, None, None, "psa"),
+    ("psc", None, None, None, "psc"),
+    ("psd", None, None, None, "psd"),
+    ("pse", None, None, None, "pse"),
+    ("psg", None, None, None, "psg"),
+    ("psh", None, None, None, "psh"),
+    ("psi", None, None, None, "psi"),
+    ("psl", None, None, None, "psl"),
+    ("psm", None, None, None, "psm"),
+    ("psn", None, None, None, "psn"),
+    ("pso", None, None, None, "pso"),
+    ("psp", None, None, None, "psp"),
+    ("psq", None, None, None, "psq"),
+    ("psr", None, None, None, "psr"),
+    ("pss", None, None, None, "pss"),
+    ("pst", None, None, None, "pst"),
+    ("psu", None, None, None, "psu"),
+    ("psw", None, None, None, "psw"),
+    ("psy", None, None, None, "psy"),
+    ("pt", Some("pt"), Some("por"), Some("por"), "por"),
+    ("pta", None, None, None, "pta"),
+    ("pth", None, None, None, "pth"),
+    ("pti", None, None, None, "pti"),
+    ("ptn", None, None, None, "ptn"),
+    ("pto", None, None, None, "pto"),
+    ("ptp", None, None, None, "ptp"),
+    ("ptq", None, None, None, "ptq"),
+    ("ptr", None, None, None, "ptr"),
+    ("ptt", None, None, None, "ptt"),
+    ("ptu", None, None, None, "ptu"),
+    ("ptv", None, None, None, "ptv"),
+    ("ptw", None, None, None, "ptw"),
+    ("pty", None, None, None, "pty"),
+    ("pua", None, None, None, "pua"),
+    ("pub", None, None, None, "pub"),
+    ("puc", None, None, None, "puc"),
+    ("pud", None, None, None, "pud"),
+    ("pue", None, None, None, "pue"),
+    ("puf", None, None, None, "puf"),
+    ("pug", None, None, None, "pug"),
+    ("pui", None, None, None, "pui"),
+    ("puj", None, None, None, "puj"),
+    ("pum", None, None, None, "pum"),
+    ("puo", None, None, None, "puo"),
+    ("pup", None, None, None, "pup"),
+    ("puq", None, None, None, "puq"),
+    ("pur", None, None, None, "pur"),
+    ("put", None, None, None, "put"),
+    ("puu", None, None, None, "puu"),
+    ("puw", None, None, None, "puw"),
+    ("pux", None, None, None, "pux"),
+    ("puy", None, None, None, "puy"),
+    ("pwa", None, None, None, "pwa"),
+    ("pwb", None, None, None, "pwb"),
+    ("pwg", None, None, None, "pwg"),
+    ("pwi", None, None, None, "pwi"),
+    ("pwm", None, None, None, "pwm"),
+    ("pwn", None, None, None, "pwn"),
+    ("pwo", None, None, None, "pwo"),
+    ("pwr", None, None, None, "pwr"),
+    ("pww", None, None, None, "pww"),
+    ("pxm", None, None, None, "pxm"),
+    ("pye", None, None, None, "pye"),
+    ("pym", None, None, None, "pym"),
+    ("pyn", None, None, None, "pyn"),
+    ("pys", None, None, None, "pys"),
+    ("pyu", None, None, None, "pyu"),
+    ("pyx", None, None, None, "pyx"),
+    ("pyy", None, None, None, "pyy"),
+    ("pzh", None, None, None, "pzh"),
+    ("pzn", None, None, None, "pzn"),
+    ("qu", Some("qu"), Some("que"), Some("que"), "que"),
+    ("qua", None, None, None, "qua"),
+    ("qub", None, None, None, "qub"),
+    ("quc", None, None, None, "quc"),
+    ("qud", None, None, None, "qud"),
+    ("quf", None, None, None, "quf"),
+    ("qug", None, None, None, "qug"),
+    ("quh", None, None, None, "quh"),
+    ("qui", None, None, None, "qui"),
+    ("quk", None, None, None, "quk"),
+    ("qul", None, None, None, "qul"),
+    ("qum", None, None, None, "qum"),
+    ("qun", None, None, None, "qun"),
+    ("qup", None, None, None, "qup"),
+    ("quq", None, None, None, "quq"),
+    ("qur", None, None, None, "qur"),
+    ("qus", None, None, None, "qus"),
+    ("quv", None, None, None, "quv"),
+    ("quw", None, None, None, "quw"),
+    ("qux", None, None, None, "qux"),
+    ("quy", None, None, None, "quy"),
+    ("quz", None, None, None, "quz"),
+    ("qva", None, None, None, "qva"),
+    ("qvc", None, None, None, "qvc"),
+    ("qve", None, None, None, "qve"),
+    ("qvh", None, None, None, "qvh"),
+    ("qvi", None, None, None, "qvi"),
+    ("qvj", None, None, None, "qvj"),
+    ("qvl", None, None, None, "qvl"),
+    ("qvm", None, None, None, "qvm"),
+    ("qvn", None, None, None, "qvn"),
+    ("qvo", None, None, None, "qvo"),
+    ("qvp", None, None, None, "qvp"),
+    ("qvs", None, None, None, "qvs"),
+    ("qvw", None, None, None, "qvw"),
+    ("qvy", None, None, None, "qvy"),
+    ("qvz", None, None, None, "qvz"),
+    ("qwa", None, None, None, "qwa"),
+    ("qwc", None, None, None, "qwc"),
+    ("qwh", None, None, None, "qwh"),
+    ("qwm", None, None, None, "qwm"),
+    ("qws", None, None, None, "qws"),
+    ("qwt", None, None, None, "qwt"),
+    ("qxa", None, None, None, "qxa"),
+    ("qxc", None, None, None, "qxc"),
+    ("qxh", None, None, None, "qxh"),
+    ("qxl", None, None, None, "qxl"),
+    ("qxn", None, None, None, "qxn"),
+    ("qxo", None, None, None, "qxo"),
+    ("qxp", None, None, None, "qxp"),
+    ("qxq", None, None, None, "qxq"),
+    ("qxr", None, None, None, "qxr"),
+    ("qxs", None, None, None, "qxs"),
+    ("qxt", None, None, None, "qxt"),
+    ("qxu", None, None, None, "qxu"),
+    ("qxw", None, None, None, "qxw"),
+    ("qya", None, None, None, "qya"),
+    ("qyp", None, None, None, "qyp"),
+    ("raa", None, None, None, "raa"),
+    ("rab", None, None, None, "rab"),
+    ("rac", None, None, None, "rac"),
+    ("rad", None, None, None, "rad"),
+    ("raf", None, None, None, "raf"),
+    ("rag", None, None, None, "rag"),
+    ("rah", None, None, None, "rah"),
+    ("rai", None, None, None, "rai"),
+    ("raj", None, Some("raj"), Some("raj"), "raj"),
+    ("rak", None, None, None, "rak"),
+    ("ral", None, None, None, "ral"),
+    ("ram", None, None, None, "ram"),
+    ("ran", None, None, None, "ran"),
+    ("rao", None, None, None, "rao"),
+    ("rap", None, Some("rap"), Some("rap"), "rap"),
+    ("raq", None, None, None, "raq"),
+    ("rar", None, Some("rar"), Some("rar"), "rar"),
+    ("ras", None, None, None, "ras"),
+    ("rat", None, None, None, "rat"),
+    ("rau", None, None, None, "rau"),
+    ("rav", None, None, None, "rav"),
+    ("raw", None, None, None, "raw"),
+    ("rax", None, None, None, "rax"),
+    ("ray", None, None, None, "ray"),
+    ("raz", None, None, None, "raz"),
+    ("rbb", None, None, None, "rbb"),
+    ("rbk", None, None, None, "rbk"),
+    ("rbl", None, None, None, "rbl"),
+    ("rbp", None, None, None, "rbp"),
+    ("rcf", None, None, None, "rcf"),
+    ("rdb", None, None, None, "rdb"),
+    ("rea", None, None, None, "rea"),
+    ("reb", None, None, None, "reb"),
+    ("ree", None, None, None, "ree"),
+    ("reg", None, None, None, "reg"),
+    ("rei", None, None, None, "rei"),
+    ("rej", None, None, None, "rej"),
+    ("rel", None, None, None, "rel"),
+    ("rem", None, None, None, "rem"),
+    ("ren", None, None, None, "ren"),
+    ("rer", None, None, None, "rer"),
+    ("res", None, None, None, "res"),
+    ("ret", None, None, None, "ret"),
+    ("rey", None, None, None, "rey"),
+    ("rga", None, None, None, "rga"),
+    ("rge", None, None, None, "rge"),
+    ("rgk", None, None, None, "rgk"),
+    ("rgn", None, None, None, "rgn"),
+    ("rgr", None, None, None, "rgr"),
+    ("rgs", None, None, None, "rgs"),
+    ("rgu", None, None, None, "rgu"),
+    ("rhg", None, None, None, "rhg"),
+    ("rhp", None, None, None, "rhp"),
+    ("ria", None, None, None, "ria"),
+    ("rib", None, None, None, "rib"),
+    ("rif", None, None, None, "rif"),
+    ("ril", None, None, None, "ril"),
+    ("rim", None, None, None, "rim"),
+    ("rin", None, None, None, "rin"),
+    ("rir", None, None, None, "rir"),
+    ("rit", None, None, None, "rit"),
+    ("riu", None, None, None, "riu"),
+    ("rjg", None, None, None, "rjg"),
+    ("rji", None, None, None, "rji"),
+    ("rjs", None, None, None, "rjs"),
+    ("rka", None, None, None, "rka"),
+    ("rkb", None, None, None, "rkb"),
+    ("rkh", None, None, None, "rkh"),
+    ("rki", None, None, None, "rki"),
+    ("rkm", None, None, None, "rkm"),
+    ("rkt", None, None, None, "rkt"),
+    ("rkw", None, None, None, "rkw"),
+    ("rm", Some("rm"), Some("roh"), Some("roh"), "roh"),
+    ("rma", None, None, None, "rma"),
+    ("rmb", None, None, None, "rmb"),
+    ("rmc", None, None, None, "rmc"),
+    ("rmd", None, None, None, "rmd"),
+    ("rme", None, None, None, "rme"),
+    ("rmf", None, None, None, "rmf"),
+    ("rmg", None, None, None, "rmg"),
+    ("rmh", None, None, None, "rmh"),
+    ("rmi", None, None, None, "rmi"),
+    ("rmk", None, None, None, "rmk"),
+    ("rml", None, None, None, "rml"),
+    ("rmm", None, None, None, "rmm"),
+    ("rmn", None, None, None, "rmn"),
+    ("rmo", None, None, None, "rmo"),
+    ("rmp", None, None, None, "rmp"),
+    ("rmq", None, None, None, "rmq"),
+    ("rms", None, None, None, "rms"),
+    ("rmt", None, None, None, "rmt"),
+    ("rmu", None, None, None, "rmu"),
+    ("rmv", None, None, None, "rmv"),
+    ("rmw", None, None, None, "rmw"),
+    ("rmx", None, None, None, "rmx"),
+    ("rmy", None, None, None, "rmy"),
+    ("rmz", None, None, None, "rmz"),
+    ("rn", Some("rn"), Some("run"), Some("run"), "run"),
+    ("rnb", None, None, None, "rnb"),
+    ("rnd", None, None, None, "rnd"),
+    ("rng", None, None, None, "rng"),
+    ("rnl", None, None, None, "rnl"),
+    ("rnn", None, None, None, "rnn"),
+    ("rnp", None, None, None, "rnp"),
+    ("rnr", None, None, None, "rnr"),
+    ("rnw", None, None, None, "rnw"),
+    ("ro", Some("ro"), Some("ron"), Some("rum"), "ron"),
+    ("rob", None, None, None, "rob"),
+    ("roc", None, None, None, "roc"),
+    ("rod", None, None, None, "rod"),
+    ("roe", None, None, None, "roe"),
+    ("rof", None, None, None, "rof"),
+    ("rog", None, None, None, "rog"),
+    ("rol", None, None, None, "rol"),
+    ("rom", None, Some("rom"), Some("rom"), "rom"),
+    ("roo", None, None, None, "roo"),
+    ("rop", None, None, None, "rop"),
+    ("ror", None, None, None, "ror"),
+    ("rou", None, None, None, "rou"),
+    ("row", None, None, None, "row"),
+    ("rpn", None, None, None, "rpn"),
+    ("rpt", None, None, None, "rpt"),
+    ("rri", None, None, None, "rri"),
+    ("rro", None, None, None, "rro"),
+    ("rrt", None, None, None, "rrt"),
+    ("rsb", None, None, None, "rsb"),
+    ("rsk", None, None, None, "rsk"),
+    ("rsl", None, None, None, "rsl"),
+    ("rsm", None, None, None, "rsm"),
+    ("rsn", None, None, None, "rsn"),
+    ("rtc", None, None, None, "rtc"),
+    ("rth", None, None, None, "rth"),
+    ("rtm", None, None, None, "rtm"),
+    ("rts", None, None, None, "rts"),
+    ("rtw", None, None, None, "rtw"),
+    ("ru", Some("ru"), Some("rus"), Some("rus"), "rus"),
+    ("rub", None, None, None, "rub"),
+    ("ruc", None, None, None, "ruc"),
+    ("rue", None, None, None, "rue"),
+    ("ruf", None, None, None, "ruf"),
+    ("rug", None, None, None, "rug"),
+    ("ruh", None, None, None, "ruh"),
+    ("rui", None, None, None, "rui"),
+    ("ruk", None, None, None, "ruk"),
+    ("ruo", None, None, None, "ruo"),
+    ("rup", None, Some("rup"), Some("rup"), "rup"),
+    ("ruq", None, None, None, "ruq"),
+    ("rut", None, None, None, "rut"),
+    ("ruu", None, None, None, "ruu"),
+    ("ruy", None, None, None, "ruy"),
+    ("ruz", None, None, None, "ruz"),
+    ("rw", Some("rw"), Some("kin"), Some("kin"), "kin"),
+    ("rwa", None, None, None, "rwa"),
+    ("rwk", None, None, None, "rwk"),
+    ("rwl", None, None, None, "rwl"),
+    ("rwm", None, None, None, "rwm"),
+    ("rwo", None, None, None, "rwo"),
+    ("rwr", None, None, None, "rwr"),
+    ("rxd", None, None, None, "rxd"),
+    ("rxw", None, None, None, "rxw"),
+    ("ryn", None, None, None, "ryn"),
+    ("rys", None, None, None, "rys"),
+    ("ryu", None, None, None, "ryu"),
+    ("rzh", None, None, None, "rzh"),
+    ("sa", Some("sa"), Some("san"), Some("san"), "san"),
+    ("saa", None, None, None, "saa"),
+    ("sab", None, None, None, "sab"),
+    ("sac", None, None, None, "sac"),
+    ("sad", None, Some("sad"), Some("sad"), "sad"),
+    ("sae", None, None, None, "sae"),
+    ("saf", None, None, None, "saf"),
+    ("sah", None, Some("sah"), Some("sah"), "sah"),
+    ("saj", None, None, None, "saj"),
+    ("sak", None, None, None, "sak"),
+    ("sam", None, Some("sam"), Some("sam"), "sam"),
+    ("sao", None, None, None, "sao"),
+    ("saq", None, None, None, "saq"),
+    ("sar", None, None, None, "sar"),
+    ("sas", None, Some("sas"), Some("sas"), "sas"),
+    ("sat", None, Some("sat"), Some("sat"), "sat"),
+    ("sau", None, None, None, "sau"),
+    ("sav", None, None, None, "sav"),
+    ("saw", None, None, None, "saw"),
+    ("sax", None, None, None, "sax"),
+    ("say", None, None, None, "say"),
+    ("saz", None, None, None, "saz"),
+    ("sba", None, None, None, "sba"),
+    ("sbb", None, None, None, "sbb"),
+    ("sbc", None, None, None, "sbc"),
+    ("sbd", None, None, None, "sbd"),
+    ("sbe", None, None, None, "sbe"),
+    ("sbf", None, None, None, "sbf"),
+    ("sbg", None, None, None, "sbg"),
+    ("sbh", None, None, None, "sbh"),
+    ("sbi", None, None, None, "sbi"),
+    ("sbj", None, None, None, "sbj"),
+    ("sbk", None, None, None, "sbk"),
+    ("sbl", None, None, None, "sbl"),
+    ("sbm", None, None, None, "sbm"),
+    ("sbn", None, None, None, "sbn"),
+    ("sbo", None, None, None, "sbo"),
+    ("sbp", None, None, None, "sbp"),
+    ("sbq", None, None, None, "sbq"),
+    ("sbr", None, None, None, "sbr"),
+    ("sbs", None, None, None, "sbs"),
+    ("sbt", None, None, None, "sbt"),
+    ("sbu", None, None, None, "sbu"),
+    ("sbv", None, None, None, "sbv"),
+    ("sbw", None, None, None, "sbw"),
+    ("sbx", None, None, None, "sbx"),
+    ("sby", None, None, None, "sby"),
+    ("sbz", None, None, None, "sbz"),
+    ("sc", Some("sc"), Some("srd"), Some("srd"), "srd"),
+    ("scb", None, None, None, "scb"),
+    ("sce", None, None, None, "sce"),
+    ("scf", None, None, None, "scf"),
+    ("scg", None, None, None, "scg"),
+    ("sch", None, None, None, "sch"),
+    ("sci", None, None, None, "sci"),
+    ("sck", None, None, None, "sck"),
+    ("scl", None, None, None, "scl"),
+    ("scn", None, Some("scn"), Some("scn"), "scn"),
+    ("sco", None, Some("sco"), Some("sco"), "sco"),
+    ("scp", None, None, None, "scp"),
+    ("scq", None, None, None, "scq"),
+    ("scs", None, None, None, "scs"),
+    ("sct", None, None, None, "sct"),
+    ("scu", None, None, None, "scu"),
+    ("scv", None, None, None, "scv"),
+    ("scw", None, None, None, "scw"),
+    ("scx", None, None, None, "scx"),
+    ("sd", Some("sd"), Some("snd"), Some("snd"), "snd"),
+    ("sda", None, None, None, "sda"),
+    ("sdb", None, None, None, "sdb"),
+    ("sdc", None, None, None, "sdc"),
+    ("sde", None, None, None, "sde"),
+    ("sdf", None, None, None, "sdf"),
+    ("sdg", None, None, None, "sdg"),
+    ("sdh", None, None, None, "sdh"),
+    ("sdj", None, None, None, "sdj"),
+    ("sdk", None, None, None, "sdk"),
+    ("sdl", None, None, None, "sdl"),
+    ("sdn", None, None, None, "sdn"),
+    ("sdo", None, None, None, "sdo"),
+    ("sdp", None, None, None, "sdp"),
+    ("sdq", None, None, None, "sdq"),
+    ("sdr", None, None, None, "sdr"),
+    ("sds", None, None, None, "sds"),
+    ("sdt", None, None, None, "sdt"),
+    ("sdu", None, None, None, "sdu"),
+    ("sdx", None, None, None, "sdx"),
+    ("sdz", None, None, None, "sdz"),
+    ("se", Some("se"), Some("sme"), Some("sme"), "sme"),
+    ("sea", None, None, None, "sea"),
+    ("seb", None, None, None, "seb"),
+    ("sec", None, None, None, "sec"),
+    ("sed", None, None, None, "sed"),
+    ("see", None, None, None, "see"),
+    ("sef", None, None, None, "sef"),
+    ("seg", None, None, None, "seg"),
+    ("seh", None, None, None, "seh"),
+    ("sei", None, None, None, "sei"),
+    ("sej", None, None, None, "sej"),
+    ("sek", None, None, None, "sek"),
+    ("sel", None, Some("sel"), Some("sel"), "sel"),
+    ("sen", None, None, None, "sen"),
+    ("seo", None, None, None, "seo"),
+    ("sep", None, None, None, "sep"),
+    ("seq", None, None, None, "seq"),
+    ("ser", None, None, None, "ser"),
+    ("ses", None, None, None, "ses"),
+    ("set", None, None, None, "set"),
+    ("seu", None, None, None, "seu"),
+    ("sev", None, None, None, "sev"),
+    ("sew", None, None, None, "sew"),
+    ("sey", None, None, None, "sey"),
+    ("sez", None, None, None, "sez"),
+    ("sfb", None, None, None, "sfb"),
+    ("sfe", None, None, None, "sfe"),
+    ("sfm", None, None, None, "sfm"),
+    ("sfs", None, None, None, "sfs"),
+    ("sfw", None, None, None, "sfw"),
+    ("sg", Some("sg"), Some("sag"), Some("sag"), "sag"),
+    ("sga", None, Some("sga"), Some("sga"), "sga"),
+    ("sgb", None, None, None, "sgb"),
+    ("sgc", None, None, None, "sgc"),
+    ("sgd", None, None, None, "sgd"),
+    ("sge", None, None, None, "sge"),
+    ("sgg", None, None, None, "sgg"),
+    ("sgh", None, None, None, "sgh"),
+    ("sgi", None, None, None, "sgi"),
+    ("sgj", None, None, None, "sgj"),
+    ("sgk", None, None, None, "sgk"),
+    ("sgm", None, None, None, "sgm"),
+    ("sgp", None, None, None, "sgp"),
+    ("sgr", None, None, None, "sgr"),
+    ("sgs", None, None, None, "sgs"),
+    ("sgt", None, None, None, "sgt"),
+    ("sgu", None, None, None, "sgu"),
+    ("sgw", None, None, None, "sgw"),
+    ("sgx", None, None, None, "sgx"),
+    ("sgy", None, None, None, "sgy"),
+    ("sgz", None, None, None, "sgz"),
+    ("sh", Some("sh"), None, None, "hbs"),
+    ("sha", None, None, None, "sha"),
+    ("shb", None, None, None, "shb"),
+    ("shc", None, None, None, "shc"),
+    ("shd", None, None, None, "shd"),
+    ("she", None, None, None, "she"),
+    ("shg", None, None, None, "shg"),
+    ("shh", None, None, None, "shh"),
+    ("shi", None, None, None, "shi"),
+    ("shj", None, None, None, "shj"),
+    ("shk", None, None, None, "shk"),
+    ("shl", None, None, None, "shl"),
+    ("shm", None, None, None, "shm"),
+    ("shn", None, Some("shn"), Some("shn"), "shn"),
+    ("sho", None, None, None, "sho"),
+    ("shp", None, None, None, "shp"),
+    ("shq", None, None, None, "shq"),
+    ("shr", None, None, None, "shr"),
+    ("shs", None, None, None, "shs"),
+    ("sht", None, None, None, "sht"),
+    ("shu", None, None, None, "shu"),
+    ("shv", None, None, None, "shv"),
+    ("shw", None, None, None, "shw"),
+    ("shx", None, None, None, "shx"),
+    ("shy", None, None, None, "shy"),
+    ("shz", None, None, None, "shz"),
+    ("si", Some("si"), Some("sin"), Some("sin"), "sin"),
+    ("sia", None, None, None, "sia"),
+    ("sib", None, None, None, "sib"),
+    ("sid", None, Some("sid"), Some("sid"), "sid"),
+    ("sie", None, None, None, "sie"),
+    ("sif", None, None, None, "sif"),
+    ("sig", None, None, None, "sig"),
+    ("sih", None, None, None, "sih"),
+    ("sii", None, None, None, "sii"),
+    ("sij", None, None, None, "sij"),
+    ("sik", None, None, None, "sik"),
+    ("sil", None, None, None, "sil"),
+    ("sim", None, None, None, "sim"),
+    ("sip", None, None, None, "sip"),
+    ("siq", None, None, None, "siq"),
+    ("sir", None, None, None, "sir"),
+    ("sis", None, None, None, "sis"),
+    ("siu", None, None, None, "siu"),
+    ("siv", None, None, None, "siv"),
+    ("siw", None, None, None, "siw"),
+    ("six", None, None, None, "six"),
+    ("siy", None, None, None, "siy"),
+    ("siz", None, None, None, "siz"),
+    ("sja", None, None, None, "sja"),
+    ("sjb", None, None, None, "sjb"),
+    ("sjd", None, None, None, "sjd"),
+    ("sje", None, None, None, "sje"),
+    ("sjg", None, None, None, "sjg"),
+    ("sjk", None, None, None, "sjk"),
+    ("sjl", None, None, None, "sjl"),
+    ("sjm", None, None, None, "sjm"),
+    ("sjn", None, None, None, "sjn"),
+    ("sjo", None, None, None, "sjo"),
+    ("sjp", None, None, None, "sjp"),
+    ("sjr", None, None, None, "sjr"),
+    ("sjs", None, None, None, "sjs"),
+    ("sjt", None, None, None, "sjt"),
+    ("sju", None, None, None, "sju"),
+    ("sjw", None, None, None, "sjw"),
+    ("sk", Some("sk"), Some("slk"), Some("slo"), "slk"),
+    ("ska", None, None, None, "ska"),
+    ("skb", None, None, None, "skb"),
+    ("skc", None, None, None, "skc"),
+    ("skd", None, None, None, "skd"),
+    ("ske", None, None, None, "ske"),
+    ("skf", None, None, None, "skf"),
+    ("skg", None, None, None, "skg"),
+    ("skh", None, None, None, "skh"),
+    ("ski", None, None, None, "ski"),
+    ("skj", None, None, None, "skj"),
+    ("skm", None, None, None, "skm"),
+    ("skn", None, None, None, "skn"),
+    ("sko", None, None, None, "sko"),
+    ("skp", None, None, None, "skp"),
+    ("skq", None, None, None, "skq"),
+    ("skr", None, None, None, "skr"),
+    ("sks", None, None, None, "sks"),
+    ("skt", None, None, None, "skt"),
+    ("sku", None, None, None, "sku"),
+    ("skv", None, None, None, "skv"),
+    ("skw", None, None, None, "skw"),
+    ("skx", None, None, None, "skx"),
+    ("sky", None, None, None, "sky"),
+    ("skz", None, None, None, "skz"),
+    ("sl", Some("sl"), Some("slv"), Some("slv"), "slv"),
+    ("slc", None, None, None, "slc"),
+    ("sld", None, None, None, "sld"),
+    ("sle", None, None, None, "sle"),
+    ("slf", None, None, None, "slf"),
+    ("slg", None, None, None, "slg"),
+    ("slh", None, None, None, "slh"),
+    ("sli", None, None, None, "sli"),
+    ("slj", None, None, None, "slj"),
+    ("sll", None, None, None, "sll"),
+    ("slm", None, None, None, "slm"),
+    ("sln", None, None, None, "sln"),
+    ("slp", None, None, None, "slp"),
+    ("slq", None, None, None, "slq"),
+    ("slr", None, None, None, "slr"),
+    ("sls", None, None, None, "sls"),
+    ("slt", None, None, None, "slt"),
+    ("slu", None, None, None, "slu"),
+    ("slw", None, None, None, "slw"),
+    ("slx", None, None, None, "slx"),
+    ("sly", None, None, None, "sly"),
+    ("slz", None, None, None, "slz"),
+    ("sm", Some("sm"), Some("smo"), Some("smo"), "smo"),
+    ("sma", None, Some("sma"), Some("sma"), "sma"),
+    ("smb", None, None, None, "smb"),
+    ("smc", None, None, None, "smc"),
+    ("smf", None, None, None, "smf"),
+    ("smg", None, None, None, "smg"),
+    ("smh", None, None, None, "smh"),
+    ("smj", None, Some("smj"), Some("smj"), "smj"),
+    ("smk", None, None, None, "smk"),
+    ("sml", None, None, None, "sml"),
+    ("smm", None, None, None, "smm"),
+    ("smn", None, Some("smn"), Some("smn"), "smn"),
+    ("smp", None, None, None, "smp"),
+    ("smq", None, None, None, "smq"),
+    ("smr", None, None, None, "smr"),
+    ("sms", None, Some("sms"), Some("sms"), "sms"),
+    ("smt", None, None, None, "smt"),
+    ("smu", None, None, None, "smu"),
+    ("smv", None, None, None, "smv"),
+    ("smw", None, None, None, "smw"),
+    ("smx", None, None, None, "smx"),
+    ("smy", None, None, None, "smy"),
+    ("smz", None, None, None, "smz"),
+    ("sn", Some("sn"), Some("sna"), Some("sna"), "sna"),
+    ("snc", None, None, None, "snc"),
+    ("sne", None, None, None, "sne"),
+    ("snf", None, None, None, "snf"),
+    ("sng", None, None, None, "sng"),
+    ("sni", None, None, None, "sni"),
+    ("snj", None, None, None, "snj"),
+    ("snk", None, Some("snk"), Some("snk"), "snk"),
+    ("snl", None, None, None, "snl"),
+    ("snm", None, None, None, "snm"),
+    ("snn", None, None, None, "snn"),
+    ("sno", None, None, None, "sno"),
+    ("snp", None, None, None, "snp"),
+    ("snq", None, None, None, "snq"),
+    ("snr", None, None, None, "snr"),
+    ("sns", None, None, None, "sns"),
+    ("snu", None, None, None, "snu"),
+    ("snv", None, None, None, "snv"),
+    ("snw", None, None, None, "snw"),
+    ("snx", None, None, None, "snx"),
+    ("sny", None, None, None, "sny"),
+    ("snz", None, None, None, "snz"),
+    ("so", Some("so"), Some("som"), Some("som"), "som"),
+    ("soa", None, None, None, "soa"),
+    ("sob", None, None, None, "sob"),
+    ("soc", None, None, None, "soc"),
+    ("sod", None, None, None, "sod"),
+    ("soe", None, None, None, "soe"),
+    ("sog", None, Some("sog"), Some("sog"), "sog"),
+    ("soh", None, None, None, "soh"),
+    ("soi", None, None, None, "soi"),
+    ("soj", None, None, None, "soj"),
+    ("sok", None, None, None, "sok"),
+    ("sol", None, None, None, "sol"),
+    ("soo", None, None, None, "soo"),
+    ("sop", None, None, None, "sop"),
+    ("soq", None, None, None, "soq"),
+    ("sor", None, None, None, "sor"),
+    ("sos", None, None, None, "sos"),
+    ("sou", None, None, None, "sou"),
+    ("sov", None, None, None, "sov"),
+    ("sow", None, None, None, "sow"),
+    ("sox", None, None, None, "sox"),
+    ("soy", None, None, None, "soy"),
+    ("soz", None, None, None, "soz"),
+    ("spb", None, None, None, "spb"),
+    ("spc", None, None, None, "spc"),
+    ("spd", None, None, None, "spd"),
+    ("spe", None, None, None, "spe"),
+    ("spg", None, None, None, "spg"),
+    ("spi", None, None, None, "spi"),
+    ("spk", None, None, None, "spk"),
+    ("spl", None, None, None, "spl"),
+    ("spm", None, None, None, "spm"),
+    ("spn", None, None, None, "spn"),
+    ("spo", None, None, None, "spo"),
+    ("spp", None, None, None, "spp"),
+    ("spq", None, None, None, "spq"),
+    ("spr", None, None, None, "spr"),
+    ("sps", None, None, None, "sps"),
+    ("spt", None, None, None, "spt"),
+    ("spu", None, None, None, "spu"),
+    ("spv", None, None, None, "spv"),
+    ("spx", None, None, None, "spx"),
+    ("spy", None, None, None, "spy"),
+    ("sq", Some("sq"), Some("sqi"), Some("alb"), "sqi"),
+    ("sqa", None, None, None, "sqa"),
+    ("sqh", None, None, None, "sqh"),
+    ("sqk", None, None, None, "sqk"),
+    ("sqm", None, None, None, "sqm"),
+    ("sqn", None, None, None, "sqn"),
+    ("sqo", None, None, None, "sqo"),
+    ("sqq", None, None, None, "sqq"),
+    ("sqr", None, None, None, "sqr"),
+    ("sqs", None, None, None, "sqs"),
+    ("sqt", None, None, None, "sqt"),
+    ("squ", None, None, None, "squ"),
+    ("sqx", None, None, None, "sqx"),
+    ("sr", Some("sr"), Some("srp"), Some("srp"), "srp"),
+    ("sra", None, None, None, "sra"),
+    ("srb", None, None, None, "srb"),
+    ("src", None, None, None, "src"),
+    ("sre", None, None, None, "sre"),
+    ("srf", None, None, None, "srf"),
+    ("srg", None, None, None, "srg"),
+    ("srh", None, None, None, "srh"),
+    ("sri", None, None, None, "sri"),
+    ("srk", None, None, None, "srk"),
+    ("srl", None, None, None, "srl"),
+    ("srm", None, None, None, "srm"),
+    ("srn", None, Some("srn"), Some("srn"), "srn"),
+    ("sro", None, None, None, "sro"),
+    ("srq", None, None, None, "srq"),
+    ("srr", None, Some("srr"), Some("srr"), "srr"),
+    ("srs", None, None, None, "srs"),
+    ("srt", None, None, None, "srt"),
+    ("sru", None, None, None, "sru"),
+    ("srv", None, None, None, "srv"),
+    ("srw", None, None, None, "srw"),
+    ("srx", None, None, None, "srx"),
+    ("sry", None, None, None, "sry"),
+    ("srz", None, None, None, "srz"),
+    ("ss", Some("ss"), Some("ssw"), Some("ssw"), "ssw"),
+    ("ssb", None, None, None, "ssb"),
+    ("ssc", None, None, None, "ssc"),
+    ("ssd", None, None, None, "ssd"),
+    ("sse", None, None, None, "sse"),
+    ("ssf", None, None, None, "ssf"),
+    ("ssg", None, None, None, "ssg"),
+    ("ssh", None, None, None, "ssh"),
+    ("ssi", None, None, None, "ssi"),
+    ("ssj", None, None, None, "ssj"),
+    ("ssk", None, None, None, "ssk"),
+    ("ssl", None, None, None, "ssl"),
+    ("ssm", None, None, None, "ssm"),
+    ("ssn", None, None, None, "ssn"),
+    ("sso", None, None, None, "sso"),
+    ("ssp", None, None, None, "ssp"),
+    ("ssq", None, None, None, "ssq"),
+    ("ssr", None, None, None, "ssr"),
+    ("sss", None, None, None, "sss"),
+    ("sst", None, None, None, "sst"),
+    ("ssu", None, None, None, "ssu"),
+    ("ssv", None, None, None, "ssv"),
+    ("ssx", None, None, None, "ssx"),
+    ("ssy", None, None, None, "ssy"),
+    ("ssz", None, None, None, "ssz"),
+    ("st", Some("st"), Some("sot"), Some("sot"), "sot"),
+    ("sta", None, None, None, "sta"),
+    ("stb", None, None, None, "stb"),
+    ("std", None, None, None, "std"),
+    ("ste", None, None, None, "ste"),
+    ("stf", None, None, None, "stf"),
+    ("stg", None, None, None, "stg"),
+    ("sth", None, None, None, "sth"),
+    ("sti", None, None, None, "sti"),
+    ("stj", None, None, None, "stj"),
+    ("stk", None, None, None, "stk"),
+    ("stl", None, None, None, "stl"),
+    ("stm", None, None, None, "stm"),
+    ("stn", None, None, None, "stn"),
+    ("sto", None, None, None, "sto"),
+    ("stp", None, None, None, "stp"),
+    ("stq", None, None, None, "stq"),
+    ("str", None, None, None, "str"),
+    ("sts", None, None, None, "sts"),
+    ("stt", None, None, None, "stt"),
+    ("stu", None, None, None, "stu"),
+    ("stv", None, None, None, "stv"),
+    ("stw", None, None, None, "stw"),
+    ("sty", None, None, None, "sty"),
+    ("su", Some("su"), Some("sun"), Some("sun"), "sun"),
+    ("sua", None, None, None, "sua"),
+    ("sub", None, None, None, "sub"),
+    ("suc", None, None, None, "suc"),
+    ("sue", None, None, None, "sue"),
+    ("sug", None, None, None, "sug"),
+    ("sui", None, None, None, "sui"),
+    ("suj", None, None, None, "suj"),
+    ("suk", None, Some("suk"), Some("suk"), "suk"),
+    ("suo", None, None, None, "suo"),
+    ("suq", None, None, None, "suq"),
+    ("sur", None, None, None, "sur"),
+    ("sus", None, Some("sus"), Some("sus"), "sus"),
+    ("sut", None, None, None, "sut"),
+    ("suv", None, None, None, "suv"),
+    ("suw", None, None, None, "suw"),
+    ("sux", None, Some("sux"), Some("sux"), "sux"),
+    ("suy", None, None, None, "suy"),
+    ("suz", None, None, None, "suz"),
+    ("sv", Some("sv"), Some("swe"), Some("swe"), "swe"),
+    ("sva", None, None, None, "sva"),
+    ("svb", None, None, None, "svb"),
+    ("svc", None, None, None, "svc"),
+    ("sve", None, None, None, "sve"),
+    ("svk", None, None, None, "svk"),
+    ("svm", None, None, None, "svm"),
+    ("svs", None, None, None, "svs"),
+    ("svx", None, None, None, "svx"),
+    ("sw", Some("sw"), Some("swa"), Some("swa"), "swa"),
+    ("swb", None, None, None, "swb"),
+    ("swc", None, None, None, "swc"),
+    ("swf", None, None, None, "swf"),
+    ("swg", None, None, None, "swg"),
+    ("swh", None, None, None, "swh"),
+    ("swi", None, None, None, "swi"),
+    ("swj", None, None, None, "swj"),
+    ("swk", None, None, None, "swk"),
+    ("swl", None, None, None, "swl"),
+    ("swm", None, None, None, "swm"),
+    ("swn", None, None, None, "swn"),
+    ("swo", None, None, None, "swo"),
+    ("swp", None, None, None, "swp"),
+    ("swq", None, None, None, "swq"),
+    ("swr", None, None, None, "swr"),
+    ("sws", None, None, None, "sws"),
+    ("swt", None, None, None, "swt"),
+    ("swu", None, None, None, "swu"),
+    ("swv", None, None, None, "swv"),
+    ("sww", None, None, None, "sww"),
+    ("swx", None, None, None, "swx"),
+    ("swy", None, None, None, "swy"),
+    ("sxb", None, None, None, "sxb"),
+    ("sxc", None, None, None, "sxc"),
+    ("sxe", None, None, None, "sxe"),
+    ("sxg", None, None, None, "sxg"),
+    ("sxk", None, None, None, "sxk"),
+    ("sxl", None, None, None, "sxl"),
+    ("sxm", None, None, None, "sxm"),
+    ("sxn", None, None, None, "sxn"),
+    ("sxo", None, None, None, "sxo"),
+    ("sxr", None, None, None, "sxr"),
+    ("sxs", None, None, None, "sxs"),
+    ("sxu", None, None, None, "sxu"),
+    ("sxw", None, None, None, "sxw"),
+    ("sya", None, None, None, "sya"),
+    ("syb", None, None, None, "syb"),
+    ("syc", None, Some("syc"), Some("syc"), "syc"),
+    ("syi", None, None, None, "syi"),
+    ("syk", None, None, None, "syk"),
+    ("syl", None, None, None, "syl"),
+    ("sym", None, None, None, "sym"),
+    ("syn", None, None, None, "syn"),
+    ("syo", None, None, None, "syo"),
+    ("syr", None, Some("syr"), Some("syr"), "syr"),
+    ("sys", None, None, None, "sys"),
+    ("syw", None, None, None, "syw"),
+    ("syx", None, None, None, "syx"),
+    ("syy", None, None, None, "syy"),
+    ("sza", None, None, None, "sza"),
+    ("szb", None, None, None, "szb"),
+    ("szc", None, None, None, "szc"),
+    ("szd", None, None, None, "szd"),
+    ("sze", None, None, None, "sze"),
+    ("szg", None, None, None, "szg"),
+    ("szl", None, None, None, "szl"),
+    ("szn", None, None, None, "szn"),
+    ("szp", None, None, None, "szp"),
+    ("szs", None, None, None, "szs"),
+    ("szv", None, None, None, "szv"),
+    ("szw", None, None, None, "szw"),
+    ("szy", None, None, None, "szy"),
+    ("ta", Some("ta"), Some("tam"), Some("tam"), "tam"),
+    ("taa", None, None, None, "taa"),
+    ("tab", None, None, None, "tab"),
+    ("tac", None, None, None, "tac"),
+    ("tad", None, None, None, "tad"),
+    ("tae", None, None, None, "tae"),
+    ("taf", None, None, None, "taf"),
+    ("tag", None, None, None, "tag"),
+    ("taj", None, None, None, "taj"),
+    ("tak", None, None, None, "tak"),
+    ("tal", None, None, None, "tal"),
+    ("tan", None, None, None, "tan"),
+    ("tao", None, None, None, "tao"),
+    ("tap", None, None, None, "tap"),
+    ("taq", None, None, None, "taq"),
+    ("tar", None, None, None, "tar"),
+    ("tas", None, None, None, "tas"),
+    ("tau", None, None, None, "tau"),
+    ("tav", None, None, None, "tav"),
+    ("taw", None, None, None, "taw"),
+    ("tax", None, None, None, "tax"),
+    ("tay", None, None, None, "tay"),
+    ("taz", None, None, None, "taz"),
+    ("tba", None, None, None, "tba"),
+    ("tbc", None, None, None, "tbc"),
+    ("tbd", None, None, None, "tbd"),
+    ("tbe", None, None, None, "tbe"),
+    ("tbf", None, None, None, "tbf"),
+    ("tbg", None, None, None, "tbg"),
+    ("tbh", None, None, None, "tbh"),
+    ("tbi", None, None, None, "tbi"),
+    ("tbj", None, None, None, "tbj"),
+    ("tbk", None, None, None, "tbk"),
+    ("tbl", None, None, None, "tbl"),
+    ("tbm", None, None, None, "tbm"),
+    ("tbn", None, None, None, "tbn"),
+    ("tbo", None, None, None, "tbo"),
+    ("tbp", None, None, None, "tbp"),
+    ("tbr", None, None, None, "tbr"),
+    ("tbs", None, None, None, "tbs"),
+    ("tbt", None, None, None, "tbt"),
+    ("tbu", None, None, None, "tbu"),
+    ("tbv", None, None, None, "tbv"),
+    ("tbw", None, None, None, "tbw"),
+    ("tbx", None, None, None, "tbx"),
+    ("tby", None, None, None, "tby"),
+    ("tbz", None, None, None, "tbz"),
+    ("tca", None, None, None, "tca"),
+    ("tcb", None, None, None, "tcb"),
+    ("tcc", None, None, None, "tcc"),
+    ("tcd", None, None, None, "tcd"),
+    ("tce", None, None, None, "tce"),
+    ("tcf", None, None, None, "tcf"),
+    ("tcg", None, None, None, "tcg"),
+    ("tch", None, None, None, "tch"),
+    ("tci", None, None, None, "tci"),
+    ("tck", None, None, None, "tck"),
+    ("tcl", None, None, None, "tcl"),
+    ("tcm", None, None, None, "tcm"),
+    ("tcn", None, None, None, "tcn"),
+    ("tco", None, None, None, "tco"),
+    ("tcp", None, None, None, "tcp"),
+    ("tcq", None, None, None, "tcq"),
+    ("tcs", None, None, None, "tcs"),
+    ("tct", None, None, None, "tct"),
+    ("tcu", None, None, None, "tcu"),
+    ("tcw", None, None, None, "tcw"),
+    ("tcx", None, None, None, "tcx"),
+    ("tcy", None, None, None, "tcy"),
+    ("tcz", None, None, None, "tcz"),
+    ("tda", None, None, None, "tda"),
+    ("tdb", None, None, None, "tdb"),
+    ("tdc", None, None, None, "tdc"),
+    ("tdd", None, None, None, "tdd"),
+    ("tde", None, None, None, "tde"),
+    ("tdf", None, None, None, "tdf"),
+    ("tdg", None, None, None, "tdg"),
+    ("tdh", None, None, None, "tdh"),
+    ("tdi", None, None, None, "tdi"),
+    ("tdj", None, None, None, "tdj"),
+    ("tdk", None, None, None, "tdk"),
+    ("tdl", None, None, None, "tdl"),
+    ("tdm", None, None, None, "tdm"),
+    ("tdn", None, None, None, "tdn"),
+    ("tdo", None, None, None, "tdo"),
+    ("tdq", None, None, None, "tdq"),
+    ("tdr", None, None, None, "tdr"),
+    ("tds", None, None, None, "tds"),
+    ("tdt", None, None, None, "tdt"),
+    ("tdv", None, None, None, "tdv"),
+    ("tdx", None, None, None, "tdx"),
+    ("tdy", None, None, None, "tdy"),
+    ("te", Some("te"), Some("tel"), Some("tel"), "tel"),
+    ("tea", None, None, None, "tea"),
+    ("teb", None, None, None, "teb"),
+    ("tec", None, None, None, "tec"),
+    ("ted", None, None, None, "ted"),
+    ("tee", None, None, None, "tee"),
+    ("tef", None, None, None, "tef"),
+    ("teg", None, None, None, "teg"),
+    ("teh", None, None, None, "teh"),
+    ("tei", None, None, None, "tei"),
+    ("tek", None, None, None, "tek"),
+    ("tem", None, Some("tem"), Some("tem"), "tem"),
+    ("ten", None, None, None, "ten"),
+    ("teo", None, None, None, "teo"),
+    ("tep", None, None, None, "tep"),
+    ("teq", None, None, None, "teq"),
+    ("ter", None, Some("ter"), Some("ter"), "ter"),
+    ("tes", None, None, None, "tes"),
+    ("tet", None, Some("tet"), Some("tet"), "tet"),
+    ("teu", None, None, None, "teu"),
+    ("tev", None, None, None, "tev"),
+    ("tew", None, None, None, "tew"),
+    ("tex", None, None, None, "tex"),
+    ("tey", None, None, None, "tey"),
+    ("tez", None, None, None, "tez"),
+    ("tfi", None, None, None, "tfi"),
+    ("tfn", None, None, None, "tfn"),
+    ("tfo", None, None, None, "tfo"),
+    ("tfr", None, None, None, "tfr"),
+    ("tft", None, None, None, "tft"),
+    ("tg", Some("tg"), Some("tgk"), Some("tgk"), "tgk"),
+    ("tga", None, None, None, "tga"),
+    ("tgb", None, None, None, "tgb"),
+    ("tgc", None, None, None, "tgc"),
+    ("tgd", None, None, None, "tgd"),
+    ("tge", None, None, None, "tge"),
+    ("tgf", None, None, None, "tgf"),
+    ("tgh", None, None, None, "tgh"),
+    ("tgi", None, None, None, "tgi"),
+    ("tgj", None, None, None, "tgj"),
+    ("tgn", None, None, None, "tgn"),
+    ("tgo", None, None, None, "tgo"),
+    ("tgp", None, None, None, "tgp"),
+    ("tgq", None, None, None, "tgq"),
+    ("tgr", None, None, None, "tgr"),
+    ("tgs", None, None, None, "tgs"),
+    ("tgt", None, None, None, "tgt"),
+    ("tgu", None, None, None, "tgu"),
+    ("tgv", None, None, None, "tgv"),
+    ("tgw", None, None, None, "tgw"),
+    ("tgx", None, None, None, "tgx"),
+    ("tgy", None, None, None, "tgy"),
+    ("tgz", None, None, None, "tgz"),
+    ("th", Some("th"), Some("tha"), Some("tha"), "tha"),
+    ("thd", None, None, None, "thd"),
+    ("the", None, None, None, "the"),
+    ("thf", None, None, None, "thf"),
+    ("thh", None, None, None, "thh"),
+    ("thi", None, None, None, "thi"),
+    ("thk", None, None, None, "thk"),
+    ("thl", None, None, None, "thl"),
+    ("thm", None, None, None, "thm"),
+    ("thn", None, None, None, "thn"),
+    ("thp", None, None, None, "thp"),
+    ("thq", None, None, None, "thq"),
+    ("thr", None, None, None, "thr"),
+    ("ths", None, None, None, "ths"),
+    ("tht", None, None, None, "tht"),
+    ("thu", None, None, None, "thu"),
+    ("thv", None, None, None, "thv"),
+    ("thy", None, None, None, "thy"),
+    ("thz", None, None, None, "thz"),
+    ("ti", Some("ti"), Some("tir"), Some("tir"), "tir"),
+    ("tia", None, None, None, "tia"),
+    ("tic", None, None, None, "tic"),
+    ("tif", None, None, None, "tif"),
+    ("tig", None, Some("tig"), Some("tig"), "tig"),
+    ("tih", None, None, None, "tih"),
+    ("tii", None, None, None, "tii"),
+    ("tij", None, None, None, "tij"),
+    ("tik", None, None, None, "tik"),
+    ("til", None, None, None, "til"),
+    ("tim", None, None, None, "tim"),
+    ("tin", None, None, None, "tin"),
+    ("tio", None, None, None, "tio"),
+    ("tip", None, None, None, "tip"),
+    ("tiq", None, None, None, "tiq"),
+    ("tis", None, None, None, "tis"),
+    ("tit", None, None, None, "tit"),
+    ("tiu", None, None, None, "tiu"),
+    ("tiv", None, Some("tiv"), Some("tiv"), "tiv"),
+    ("tiw", None, None, None, "tiw"),
+    ("tix", None, None, None, "tix"),
+    ("tiy", None, None, None, "tiy"),
+    ("tiz", None, None, None, "tiz"),
+    ("tja", None, None, None, "tja"),
+    ("tjg", None, None, None, "tjg"),
+    ("tji", None, None, None, "tji"),
+    ("tjj", None, None, None, "tjj"),
+    ("tjl", None, None, None, "tjl"),
+    ("tjm", None, None, None, "tjm"),
+    ("tjn", None, None, None, "tjn"),
+    ("tjo", None, None, None, "tjo"),
+    ("tjp", None, None, None, "tjp"),
+    ("tjs", None, None, None, "tjs"),
+    ("tju", None, None, None, "tju"),
+    ("tjw", None, None, None, "tjw"),
+    ("tk", Some("tk"), Some("tuk"), Some("tuk"), "tuk"),
+    ("tka", None, None, None, "tka"),
+    ("tkb", None, None, None, "tkb"),
+    ("tkd", None, None, None, "tkd"),
+    ("tke", None, None, None, "tke"),
+    ("tkf", None, None, None, "tkf"),
+    ("tkg", None, None, None, "tkg"),
+    ("tkl", None, Some("tkl"), Some("tkl"), "tkl"),
+    ("tkm", None, None, None, "tkm"),
+    ("tkn", None, None, None, "tkn"),
+    ("tkp", None, None, None, "tkp"),
+    ("tkq", None, None, None, "tkq"),
+    ("tkr", None, None, None, "tkr"),
+    ("tks", None, None, None, "tks"),
+    ("tkt", None, None, None, "tkt"),
+    ("tku", None, None, None, "tku"),
+    ("tkv", None, None, None, "tkv"),
+    ("tkw", None, None, None, "tkw"),
+    ("tkx", None, None, None, "tkx"),
+    ("tkz", None, None, None, "tkz"),
+    ("tl", Some("tl"), Some("tgl"), Some("tgl"), "tgl"),
+    ("tla", None, None, None, "tla"),
+    ("tlb", None, None, None, "tlb"),
+    ("tlc", None, None, None, "tlc"),
+    ("tld", None, None, None, "tld"),
+    ("tlf", None, None, None, "tlf"),
+    ("tlg", None, None, None, "tlg"),
+    ("tlh", None, Some("tlh"), Some("tlh"), "tlh"),
+    ("tli", None, Some("tli"), Some("tli"), "tli"),
+    ("tlj", None, None, None, "tlj"),
+    ("tlk", None, None, None, "tlk"),
+    ("tll", None, None, None, "tll"),
+    ("tlm", None, None, None, "tlm"),
+    ("tln", None, None, None, "tln"),
+    ("tlo", None, None, None, "tlo"),
+    ("tlp", None, None, None, "tlp"),
+    ("tlq", None, None, None, "tlq"),
+    ("tlr", None, None, None, "tlr"),
+    ("tls", None, None, None, "tls"),
+    ("tlt", None, None, None, "tlt"),
+    ("tlu", None, None, None, "tlu"),
+    ("tlv", None, None, None, "tlv"),
+    ("tlx", None, None, None, "tlx"),
+    ("tly", None, None, None, "tly"),
+    ("tma", None, None, None, "tma"),
+    ("tmb", None, None, None, "tmb"),
+    ("tmc", None, None, None, "tmc"),
+    ("tmd", None, None, None, "tmd"),
+    ("tme", None, None, None, "tme"),
+    ("tmf", None, None, None, "tmf"),
+    ("tmg", None, None, None, "tmg"),
+    ("tmh", None, Some("tmh"), Some("tmh"), "tmh"),
+    ("tmi", None, None, None, "tmi"),
+    ("tmj", None, None, None, "tmj"),
+    ("tmk", None, None, None, "tmk"),
+    ("tml", None, None, None, "tml"),
+    ("tmm", None, None, None, "tmm"),
+    ("tmn", None, None, None, "tmn"),
+    ("tmo", None, None, None, "tmo"),
+    ("tmq", None, None, None, "tmq"),
+    ("tmr", None, None, None, "tmr"),
+    ("tms", None, None, None, "tms"),
+    ("tmt", None, None, None, "tmt"),
+    ("tmu", None, None, None, "tmu"),
+    ("tmv", None, None, None, "tmv"),
+    ("tmw", None, None, None, "tmw"),
+    ("tmy", None, None, None, "tmy"),
+    ("tmz", None, None, None, "tmz"),
+    ("tn", Some("tn"), Some("tsn"), Some("tsn"), "tsn"),
+    ("tna", None, None, None, "tna"),
+    ("tnb", None, None, None, "tnb"),
+    ("tnc", None, None, None, "tnc"),
+    ("tnd", None, None, None, "tnd"),
+    ("tng", None, None, None, "tng"),
+    ("tnh", None, None, None, "tnh"),
+    ("tni", None, None, None, "tni"),
+    ("tnk", None, None, None, "tnk"),
+    ("tnl", None, None, None, "tnl"),
+    ("tnm", None, None, None, "tnm"),
+    ("tnn", None, None, None, "tnn"),
+    ("tno", None, None, None, "tno"),
+    ("tnp", None, None, None, "tnp"),
+    ("tnq", None, None, None, "tnq"),
+    ("tnr", None, None, None, "tnr"),
+    ("tns", None, None, None, "tns"),
+    ("tnt", None, None, None, "tnt"),
+    ("tnu", None, None, None, "tnu"),
+    ("tnv", None, None, None, "tnv"),
+    ("tnw", None, None, None, "tnw"),
+    ("tnx", None, None, None, "tnx"),
+    ("tny", None, None, None, "tny"),
+    ("tnz", None, None, None, "tnz"),
+    ("to", Some("to"), Some("ton"), Some("ton"), "ton"),
+    ("tob", None, None, None, "tob"),
+    ("toc", None, None, None, "toc"),
+    ("tod", None, None, None, "tod"),
+    ("tof", None, None, None, "tof"),
+    ("tog", None, Some("tog"), Some("tog"), "tog"),
+    ("toh", None, None, None, "toh"),
+    ("toi", None, None, None, "toi"),
+    ("toj", None, None, None, "toj"),
+    ("tok", None, None, None, "tok"),
+    ("tol", None, None, None, "tol"),
+    ("tom", None, None, None, "tom"),
+    ("too", None, None, None, "too"),
+    ("top", None, None, None, "top"),
+    ("toq", None, None, None, "toq"),
+    ("tor", None, None, None, "tor"),
+    ("tos", None, None, None, "tos"),
+    ("tou", None, None, None, "tou"),
+    ("tov", None, None, None, "tov"),
+    ("tow", None, None, None, "tow"),
+    ("tox", None, None, None, "tox"),
+    ("toy", None, None, None, "toy"),
+    ("toz", None, None, None, "toz"),
+    ("tpa", None, None, None, "tpa"),
+    ("tpc", None, None, None, "tpc"),
+    ("tpe", None, None, None, "tpe"),
+    ("tpf", None, None, None, "tpf"),
+    ("tpg", None, None, None, "tpg"),
+    ("tpi", None, Some("tpi"), Some("tpi"), "tpi"),
+    ("tpj", None, None, None, "tpj"),
+    ("tpk", None, None, None, "tpk"),
+    ("tpl", None, None, None, "tpl"),
+    ("tpm", None, None, None, "tpm"),
+    ("tpn", None, None, None, "tpn"),
+    ("tpo", None, None, None, "tpo"),
+    ("tpp", None, None, None, "tpp"),
+    ("tpq", None, None, None, "tpq"),
+    ("tpr", None, None, None, "tpr"),
+    ("tpt", None, None, None, "tpt"),
+    ("tpu", None, None, None, "tpu"),
+    ("tpv", None, None, None, "tpv"),
+    ("tpw", None, None, None, "tpw"),
+    ("tpx", None, None, None, "tpx"),
+    ("tpy", None, None, None, "tpy"),
+    ("tpz", None, None, None, "tpz"),
+    ("tqb", None, None, None, "tqb"),
+    ("tql", None, None, None, "tql"),
+    ("tqm", None, None, None, "tqm"),
+    ("tqn", None, None, None, "tqn"),
+    ("tqo", None, None, None, "tqo"),
+    ("tqp", None, None, None, "tqp"),
+    ("tqq", None, None, None, "tqq"),
+    ("tqr", None, None, None, "tqr"),
+    ("tqt", None, None, None, "tqt"),
+    ("tqu", None, None, None, "tqu"),
+    ("tqw", None, None, None, "tqw"),
+    ("tr", Some("tr"), Some("tur"), Some("tur"), "tur"),
+    ("tra", None, None, None, "tra"),
+    ("trb", None, None, None, "trb"),
+    ("trc", None, None, None, "trc"),
+    ("trd", None, None, None, "trd"),
+    ("tre", None, None, None, "tre"),
+    ("trf", None, None, None, "trf"),
+    ("trg", None, None, None, "trg"),
+    ("trh", None, None, None, "trh"),
+    ("tri", None, None, None, "tri"),
+    ("trj", None, None, None, "trj"),
+    ("trl", None, None, None, "trl"),
+    ("trm", None, None, None, "trm"),
+    ("trn", None, None, None, "trn"),
+    ("tro", None, None, None, "tro"),
+    ("trp", None, None, None, "trp"),
+    ("trq", None, None, None, "trq"),
+    ("trr", None, None, None, "trr"),
+    ("trs", None, None, None, "trs"),
+    ("trt", None, None, None, "trt"),
+    ("tru", None, None, None, "tru"),
+    ("trv", None, None, None, "trv"),
+    ("trw", None, None, None, "trw"),
+    ("trx", None, None, None, "trx"),
+    ("try", None, None, None, "try"),
+    ("trz", None, None, None, "trz"),
+    ("ts", Some("ts"), Some("tso"), Some("tso"), "tso"),
+    ("tsa", None, None, None, "tsa"),
+    ("tsb", None, None, None, "tsb"),
+    ("tsc", None, None, None, "tsc"),
+    ("tsd", None, None, None, "tsd"),
+    ("tse", None, None, None, "tse"),
+    ("tsg", None, None, None, "tsg"),
+    ("tsh", None, None, None, "tsh"),
+    ("tsi", None, Some("tsi"), Some("tsi"), "tsi"),
+    ("tsj", None, None, None, "tsj"),
+    ("tsk", None, None, None, "tsk"),
+    ("tsl", None, None, None, "tsl"),
+    ("tsm", None, None, None, "tsm"),
+    ("tsp", None, None, None, "tsp"),
+    ("tsq", None, None, None, "tsq"),
+    ("tsr", None, None, None, "tsr"),
+    ("tss", None, None, None, "tss"),
+    ("tst", None, None, None, "tst"),
+    ("tsu", None, None, None, "tsu"),
+    ("tsv", None, None, None, "tsv"),
+    ("tsw", None, None, None, "tsw"),
+    ("tsx", None, None, None, "tsx"),
+    ("tsy", None, None, None, "tsy"),
+    ("tsz", None, None, None, "tsz"),
+    ("tt", Some("tt"), Some("tat"), Some("tat"), "tat"),
+    ("tta", None, None, None, "tta"),
+    ("ttb", None, None, None, "ttb"),
+    ("ttc", None, None, None, "ttc"),
+    ("ttd", None, None, None, "ttd"),
+    ("tte", None, None, None, "tte"),
+    ("ttf", None, None, None, "ttf"),
+    ("ttg", None, None, None, "ttg"),
+    ("tth", None, None, None, "tth"),
+    ("tti", None, None, None, "tti"),
+    ("ttj", None, None, None, "ttj"),
+    ("ttk", None, None, None, "ttk"),
+    ("ttl", None, None, None, "ttl"),
+    ("ttm", None, None, None, "ttm"),
+    ("ttn", None, None, None, "ttn"),
+    ("tto", None, None, None, "tto"),
+    ("ttp", None, None, None, "ttp"),
+    ("ttq", None, None, None, "ttq"),
+    ("ttr", None, None, None, "ttr"),
+    ("tts", None, None, None, "tts"),
+    ("ttt", None, None, None, "ttt"),
+    ("ttu", None, None, None, "ttu"),
+    ("ttv", None, None, None, "ttv"),
+    ("ttw", None, None, None, "ttw"),
+    ("tty", None, None, None, "tty"),
+    ("ttz", None, None, None, "ttz"),
+    ("tua", None, None, None, "tua"),
+    ("tub", None, None, None, "tub"),
+    ("tuc", None, None, None, "tuc"),
+    ("tud", None, None, None, "tud"),
+    ("tue", None, None, None, "tue"),
+    ("tuf", None, None, None, "tuf"),
+    ("tug", None, None, None, "tug"),
+    ("tuh", None, None, None, "tuh"),
+    ("tui", None, None, None, "tui"),
+    ("tuj", None, None, None, "tuj"),
+    ("tul", None, None, None, "tul"),
+    ("tum", None, Some("tum"), Some("tum"), "tum"),
+    ("tun", None, None, None, "tun"),
+    ("tuo", None, None, None, "tuo"),
+    ("tuq", None, None, None, "tuq"),
+    ("tus", None, None, None, "tus"),
+    ("tuu", None, None, None, "tuu"),
+    ("tuv", None, None, None, "tuv"),
+    ("tux", None, None, None, "tux"),
+    ("tuy", None, None, None, "tuy"),
+    ("tuz", None, None, None, "tuz"),
+    ("tva", None, None, None, "tva"),
+    ("tvd", None, None, None, "tvd"),
+    ("tve", None, None, None, "tve"),
+    ("tvk", None, None, None, "tvk"),
+    ("tvl", None, Some("tvl"), Some("tvl"), "tvl"),
+    ("tvm", None, None, None, "tvm"),
+    ("tvn", None, None, None, "tvn"),
+    ("tvo", None, None, None, "tvo"),
+    ("tvs", None, None, None, "tvs"),
+    ("tvt", None, None, None, "tvt"),
+    ("tvu", None, None, None, "tvu"),
+    ("tvw", None, None, None, "tvw"),
+    ("tvx", None, None, None, "tvx"),
+    ("tvy", None, None, None, "tvy"),
+    ("tw", Some("tw"), Some("twi"), Some("twi"), "twi"),
+    ("twa", None, None, None, "twa"),
+    ("twb", None, None, None, "twb"),
+    ("twc", None, None, None, "twc"),
+    ("twd", None, None, None, "twd"),
+    ("twe", None, None, None, "twe"),
+    ("twf", None, None, None, "twf"),
+    ("twg", None, None, None, "twg"),
+    ("twh", None, None, None, "twh"),
+    ("twl", None, None, None, "twl"),
+    ("twm", None, None, None, "twm"),
+    ("twn", None, None, None, "twn"),
+    ("two", None, None, None, "two"),
+    ("twp", None, None, None, "twp"),
+    ("twq", None, None, None, "twq"),
+    ("twr", None, None, None, "twr"),
+    ("twt", None, None, None, "twt"),
+    ("twu", None, None, None, "twu"),
+    ("tww", None, None, None, "tww"),
+    ("twx", None, None, None, "twx"),
+    ("twy", None, None, None, "twy"),
+    ("txa", None, None, None, "txa"),
+    ("txb", None, None, None, "txb"),
+    ("txc", None, None, None, "txc"),
+    ("txe", None, None, None, "txe"),
+    ("txg", None, None, None, "txg"),
+    ("txh", None, None, None, "txh"),
+    ("txi", None, None, None, "txi"),
+    ("txj", None, None, None, "txj"),
+    ("txm", None, None, None, "txm"),
+    ("txn", None, None, None, "txn"),
+    ("txo", None, None, None, "txo"),
+    ("txq", None, None, None, "txq"),
+    ("txr", None, None, None, "txr"),
+    ("txs", None, None, None, "txs"),
+    ("txt", None, None, None, "txt"),
+    ("txu", None, None, None, "txu"),
+    ("txx", None, None, None, "txx"),
+    ("txy", None, None, None, "txy"),
+    ("ty", Some("ty"), Some("tah"), Some("tah"), "tah"),
+    ("tya", None, None, None, "tya"),
+    ("tye", None, None, None, "tye"),
+    ("tyh", None, None, None, "tyh"),
+    ("tyi", None, None, None, "tyi"),
+    ("tyj", None, None, None, "tyj"),
+    ("tyl", None, None, None, "tyl"),
+    ("tyn", None, None, None, "tyn"),
+    ("typ", None, None, None, "typ"),
+    ("tyr", None, None, None, "tyr"),
+    ("tys", None, None, None, "tys"),
+    ("tyt", None, None, None, "tyt"),
+    ("tyu", None, None, None, "tyu"),
+    ("tyv", None, Some("tyv"), Some("tyv"), "tyv"),
+    ("tyx", None, None, None, "tyx"),
+    ("tyy", None, None, None, "tyy"),
+    ("tyz", None, None, None, "tyz"),
+    ("tza", None, None, None, "tza"),
+    ("tzh", None, None, None, "tzh"),
+    ("tzj", None, None, None, "tzj"),
+    ("tzl", None, None, None, "tzl"),
+    ("tzm", None, None, None, "tzm"),
+    ("tzn", None, None, None, "tzn"),
+    ("tzo", None, None, None, "tzo"),
+    ("tzx", None, None, None, "tzx"),
+    ("uam", None, None, None, "uam"),
+    ("uan", None, None, None, "uan"),
+    ("uar", None, None, None, "uar"),
+    ("uba", None, None, None, "uba"),
+    ("ubi", None, None, None, "ubi"),
+    ("ubl", None, None, None, "ubl"),
+    ("ubr", None, None, None, "ubr"),
+    ("ubu", None, None, None, "ubu"),
+    ("uby", None, None, None, "uby"),
+    ("uda", None, None, None, "uda"),
+    ("ude", None, None, None, "ude"),
+    ("udg", None, None, None, "udg"),
+    ("udi", None, None, None, "udi"),
+    ("udj", None, None, None, "udj"),
+    ("udl", None, None, None, "udl"),
+    ("udm", None, Some("udm"), Some("udm"), "udm"),
+    ("udu", None, None, None, "udu"),
+    ("ues", None, None, None, "ues"),
+    ("ufi", None, None, None, "ufi"),
+    ("ug", Some("ug"), Some("uig"), Some("uig"), "uig"),
+    ("uga", None, Some("uga"), Some("uga"), "uga"),
+    ("ugb", None, None, None, "ugb"),
+    ("uge", None, None, None, "uge"),
+    ("ugh", None, None, None, "ugh"),
+    ("ugn", None, None, None, "ugn"),
+    ("ugo", None, None, None, "ugo"),
+    ("ugy", None, None, None, "ugy"),
+    ("uha", None, None, None, "uha"),
+    ("uhn", None, None, None, "uhn"),
+    ("uis", None, None, None, "uis"),
+    ("uiv", None, None, None, "uiv"),
+    ("uji", None, None, None, "uji"),
+    ("uk", Some("uk"), Some("ukr"), Some("ukr"), "ukr"),
+    ("uka", None, None, None, "uka"),
+    ("ukg", None, None, None, "ukg"),
+    ("ukh", None, None, None, "ukh"),
+    ("uki", None, None, None, "uki"),
+    ("ukk", None, None, None, "ukk"),
+    ("ukl", None, None, None, "ukl"),
+    ("ukp", None, None, None, "ukp"),
+    ("ukq", None, None, None, "ukq"),
+    ("uks", None, None, None, "uks"),
+    ("uku", None, None, None, "uku"),
+    ("ukv", None, None, None, "ukv"),
+    ("ukw", None, None, None, "ukw"),
+    ("uky", None, None, None, "uky"),
+    ("ula", None, None, None, "ula"),
+    ("ulb", None, None, None, "ulb"),
+    ("ulc", None, None, None, "ulc"),
+    ("ule", None, None, None, "ule"),
+    ("ulf", None, None, None, "ulf"),
+    ("uli", None, None, None, "uli"),
+    ("ulk", None, None, None, "ulk"),
+    ("ull", None, None, None, "ull"),
+    ("ulm", None, None, None, "ulm"),
+    ("uln", None, None, None, "uln"),
+    ("ulu", None, None, None, "ulu"),
+    ("ulw", None, None, None, "ulw"),
+    ("uma", None, None, None, "uma"),
+    ("umb", None, Some("umb"), Some("umb"), "umb"),
+    ("umc", None, None, None, "umc"),
+    ("umd", None, None, None, "umd"),
+    ("umg", None, None, None, "umg"),
+    ("umi", None, None, None, "umi"),
+    ("umm", None, None, None, "umm"),
+    ("umn", None, None, None, "umn"),
+    ("umo", None, None, None, "umo"),
+    ("ump", None, None, None, "ump"),
+    ("umr", None, None, None, "umr"),
+    ("ums", None, None, None, "ums"),
+    ("umu", None, None, None, "umu"),
+    ("una", None, None, None, "una"),
+    ("und", None, Some("und"), Some("und"), "und"),
+    ("une", None, None, None, "une"),
+    ("ung", None, None, None, "ung"),
+    ("uni", None, None, None, "uni"),
+    ("unk", None, None, None, "unk"),
+    ("unm", None, None, None, "unm"),
+    ("unn", None, None, None, "unn"),
+    ("unr", None, None, None, "unr"),
+    ("unu", None, None, None, "unu"),
+    ("unx", None, None, None, "unx"),
+    ("unz", None, None, None, "unz"),
+    ("uon", None, None, None, "uon"),
+    ("upi", None, None, None, "upi"),
+    ("upv", None, None, None, "upv"),
+    ("ur", Some("ur"), Some("urd"), Some("urd"), "urd"),
+    ("ura", None, None, None, "ura"),
+    ("urb", None, None, None, "urb"),
+    ("urc", None, None, None, "urc"),
+    ("ure", None, None, None, "ure"),
+    ("urf", None, None, None, "urf"),
+    ("urg", None, None, None, "urg"),
+    ("urh", None, None, None, "urh"),
+    ("uri", None, None, None, "uri"),
+    ("urk", None, None, None, "urk"),
+    ("url", None, None, None, "url"),
+    ("urm", None, None, None, "urm"),
+    ("urn", None, None, None, "urn"),
+    ("uro", None, None, None, "uro"),
+    ("urp", None, None, None, "urp"),
+    ("urr", None, None, None, "urr"),
+    ("urt", None, None, None, "urt"),
+    ("uru", None, None, None, "uru"),
+    ("urv", None, None, None, "urv"),
+    ("urw", None, None, None, "urw"),
+    ("urx", None, None, None, "urx"),
+    ("ury", None, None, None, "ury"),
+    ("urz", None, None, None, "urz"),
+    ("usa", None, None, None, "usa"),
+    ("ush", None, None, None, "ush"),
+    ("usi", None, None, None, "usi"),
+    ("usk", None, None, None, "usk"),
+    ("usp", None, None, None, "usp"),
+    ("uss", None, None, None, "uss"),
+    ("usu", None, None, None, "usu"),
+    ("uta", None, None, None, "uta"),
+    ("ute", None, None, None, "ute"),
+    ("uth", None, None, None, "uth"),
+    ("utp", None, None, None, "utp"),
+    ("utr", None, None, None, "utr"),
+    ("utu", None, None, None, "utu"),
+    ("uum", None, None, None, "uum"),
+    ("uur", None, None, None, "uur"),
+    ("uuu", None, None, None, "uuu"),
+    ("uve", None, None, None, "uve"),
+    ("uvh", None, None, None, "uvh"),
+    ("uvl", None, None, None, "uvl"),
+    ("uwa", None, None, None, "uwa"),
+    ("uya", None, None, None, "uya"),
+    ("uz", Some("uz"), Some("uzb"), Some("uzb"), "uzb"),
+    ("uzn", None, None, None, "uzn"),
+    ("uzs", None, None, None, "uzs"),
+    ("vaa", None, None, None, "vaa"),
+    ("vae", None, None, None, "vae"),
+    ("vaf", None, None, None, "vaf"),
+    ("vag", None, None, None, "vag"),
+    ("vah", None, None, None, "vah"),
+    ("vai", None, Some("vai"), Some("vai"), "vai"),
+    ("vaj", None, None, None, "vaj"),
+    ("val", None, None, None, "val"),
+    ("vam", None, None, None, "vam"),
+    ("van", None, None, None, "van"),
+    ("vao", None, None, None, "vao"),
+    ("vap", None, None, None, "vap"),
+    ("var", None, None, None, "var"),
+    ("vas", None, None, None, "vas"),
+    ("vau", None, None, None, "vau"),
+    ("vav", None, None, None, "vav"),
+    ("vay", None, None, None, "vay"),
+    ("vbb", None, None, None, "vbb"),
+    ("vbk", None, None, None, "vbk"),
+    ("ve", Some("ve"), Some("ven"), Some("ven"), "ven"),
+    ("vec", None, None, None, "vec"),
+    ("ved", None, None, None, "ved"),
+    ("vel", None, None, None, "vel"),
+    ("vem", None, None, None, "vem"),
+    ("veo", None, None, None, "veo"),
+    ("vep", None, None, None, "vep"),
+    ("ver", None, None, None, "ver"),
+    ("vgr", None, None, None, "vgr"),
+    ("vgt", None, None, None, "vgt"),
+    ("vi", Some("vi"), Some("vie"), Some("vie"), "vie"),
+    ("vic", None, None, None, "vic"),
+    ("vid", None, None, None, "vid"),
+    ("vif", None, None, None, "vif"),
+    ("vig", None, None, None, "vig"),
+    ("vil", None, None, None, "vil"),
+    ("vin", None, None, None, "vin"),
+    ("vis", None, None, None, "vis"),
+    ("vit", None, None, None, "vit"),
+    ("viv", None, None, None, "viv"),
+    ("vka", None, None, None, "vka"),
+    ("vkj", None, None, None, "vkj"),
+    ("vkk", None, None, None, "vkk"),
+    ("vkl", None, None, None, "vkl"),
+    ("vkm", None, None, None, "vkm"),
+    ("vkn", None, None, None, "vkn"),
+    ("vko", None, None, None, "vko"),
+    ("vkp", None, None, None, "vkp"),
+    ("vkt", None, None, None, "vkt"),
+    ("vku", None, None, None, "vku"),
+    ("vkz", None, None, None, "vkz"),
+    ("vlp", None, None, None, "vlp"),
+    ("vls", None, None, None, "vls"),
+    ("vma", None, None, None, "vma"),
+    ("vmb", None, None, None, "vmb"),
+    ("vmc", None, None, None, "vmc"),
+    ("vmd", None, None, None, "vmd"),
+    ("vme", None, None, None, "vme"),
+    ("vmf", None, None, None, "vmf"),
+    ("vmg", None, None, None, "vmg"),
+    ("vmh", None, None, None, "vmh"),
+    ("vmi", None, None, None, "vmi"),
+    ("vmj", None, None, None, "vmj"),
+    ("vmk", None, None, None, "vmk"),
+    ("vml", None, None, None, "vml"),
+    ("vmm", None, None, None, "vmm"),
+    ("vmp", None, None, None, "vmp"),
+    ("vmq", None, None, None, "vmq"),
+    ("vmr", None, None, None, "vmr"),
+    ("vms", None, None, None, "vms"),
+    ("vmu", None, None, None, "vmu"),
+    ("vmv", None, None, None, "vmv"),
+    ("vmw", None, None, None, "vmw"),
+    ("vmx", None, None, None, "vmx"),
+    ("vmy", None, None, None, "vmy"),
+    ("vmz", None, None, None, "vmz"),
+    ("vnk", None, None, None, "vnk"),
+    ("vnm", None, None, None, "vnm"),
+    ("vnp", None, None, None, "vnp"),
+    ("vo", Some("vo"), Some("vol"), Some("vol"), "vol"),
+    ("vor", None, None, None, "vor"),
+    ("vot", None, Some("vot"), Some("vot"), "vot"),
+    ("vra", None, None, None, "vra"),
+    ("vro", None, None, None, "vro"),
+    ("vrs", None, None, None, "vrs"),
+    ("vrt", None, None, None, "vrt"),
+    ("vsi", None, None, None, "vsi"),
+    ("vsl", None, None, None, "vsl"),
+    ("vsv", None, None, None, "vsv"),
+    ("vto", None, None, None, "vto"),
+    ("vum", None, None, None, "vum"),
+    ("vun", None, None, None, "vun"),
+    ("vut", None, None, None, "vut"),
+    ("vwa", None, None, None, "vwa"),
+    ("wa", Some("wa"), Some("wln"), Some("wln"), "wln"),
+    ("waa", None, None, None, "waa"),
+    ("wab", None, None, None, "wab"),
+    ("wac", None, None, None, "wac"),
+    ("wad", None, None, None, "wad"),
+    ("wae", None, None, None, "wae"),
+    ("waf", None, None, None, "waf"),
+    ("wag", None, None, None, "wag"),
+    ("wah", None, None, None, "wah"),
+    ("wai", None, None, None, "wai"),
+    ("waj", None, None, None, "waj"),
+    ("wal", None, Some("wal"), Some("wal"), "wal"),
+    ("wam", None, None, None, "wam"),
+    ("wan", None, None, None, "wan"),
+    ("wao", None, None, None, "wao"),
+    ("wap", None, None, None, "wap"),
+    ("waq", None, None, None, "waq"),
+    ("war", None, Some("war"), Some("war"), "war"),
+    ("was", None, Some("was"), Some("was"), "was"),
+    ("wat", None, None, None, "wat"),
+    ("wau", None, None, None, "wau"),
+    ("wav", None, None, None, "wav"),
+    ("waw", None, None, None, "waw"),
+    ("wax", None, None, None, "wax"),
+    ("way", None, None, None, "way"),
+    ("waz", None, None, None, "waz"),
+    ("wba", None, None, None, "wba"),
+    ("wbb", None, None, None, "wbb"),
+    ("wbe", None, None, None, "wbe"),
+    ("wbf", None, None, None, "wbf"),
+    ("wbh", None, None, None, "wbh"),
+    ("wbi", None, None, None, "wbi"),
+    ("wbj", None, None, None, "wbj"),
+    ("wbk", None, None, None, "wbk"),
+    ("wbl", None, None, None, "wbl"),
+    ("wbm", None, None, None, "wbm"),
+    ("wbp", None, None, None, "wbp"),
+    ("wbq", None, None, None, "wbq"),
+    ("wbr", None, None, None, "wbr"),
+    ("wbs", None, None, None, "wbs"),
+    ("wbt", None, None, None, "wbt"),
+    ("wbv", None, None, None, "wbv"),
+    ("wbw", None, None, None, "wbw"),
+    ("wca", None, None, None, "wca"),
+    ("wci", None, None, None, "wci"),
+    ("wdd", None, None, None, "wdd"),
+    ("wdg", None, None, None, "wdg"),
+    ("wdj", None, None, None, "wdj"),
+    ("wdk", None, None, None, "wdk"),
+    ("wdt", None, None, None, "wdt"),
+    ("wdu", None, None, None, "wdu"),
+    ("wdy", None, None, None, "wdy"),
+    ("wea", None, None, None, "wea"),
+    ("wec", None, None, None, "wec"),
+    ("wed", None, None, None, "wed"),
+    ("weg", None, None, None, "weg"),
+    ("weh", None, None, None, "weh"),
+    ("wei", None, None, None, "wei"),
+    ("wem", None, None, None, "wem"),
+    ("weo", None, None, None, "weo"),
+    ("wep", None, None, None, "wep"),
+    ("wer", None, None, None, "wer"),
+    ("wes", None, None, None, "wes"),
+    ("wet", None, None, None, "wet"),
+    ("weu", None, None, None, "weu"),
+    ("wew", None, None, None, "wew"),
+    ("wfg", None, None, None, "wfg"),
+    ("wga", None, None, None, "wga"),
+    ("wgb", None, None, None, "wgb"),
+    ("wgg", None, None, None, "wgg"),
+    ("wgi", None, None, None, "wgi"),
+    ("wgo", None, None, None, "wgo"),
+    ("wgu", None, None, None, "wgu"),
+    ("wgy", None, None, None, "wgy"),
+    ("wha", None, None, None, "wha"),
+    ("whg", None, None, None, "whg"),
+    ("whk", None, None, None, "whk"),
+    ("whu", None, None, None, "whu"),
+    ("wib", None, None, None, "wib"),
+    ("wic", None, None, None, "wic"),
+    ("wie", None, None, None, "wie"),
+    ("wif", None, None, None, "wif"),
+    ("wig", None, None, None, "wig"),
+    ("wih", None, None, None, "wih"),
+    ("wii", None, None, None, "wii"),
+    ("wij", None, None, None, "wij"),
+    ("wik", None, None, None, "wik"),
+    ("wil", None, None, None, "wil"),
+    ("wim", None, None, None, "wim"),
+    ("win", None, None, None, "win"),
+    ("wir", None, None, None, "wir"),
+    ("wiu", None, None, None, "wiu"),
+    ("wiv", None, None, None, "wiv"),
+    ("wiy", None, None, None, "wiy"),
+    ("wja", None, None, None, "wja"),
+    ("wji", None, None, None, "wji"),
+    ("wka", None, None, None, "wka"),
+    ("wkb", None, None, None, "wkb"),
+    ("wkd", None, None, None, "wkd"),
+    ("wkl", None, None, None, "wkl"),
+    ("wkr", None, None, None, "wkr"),
+    ("wku", None, None, None, "wku"),
+    ("wkw", None, None, None, "wkw"),
+    ("wky", None, None, None, "wky"),
+    ("wla", None, None, None, "wla"),
+    ("wlc", None, None, None, "wlc"),
+    ("wle", None, None, None, "wle"),
+    ("wlg", None, None, None, "wlg"),
+    ("wlh", None, None, None, "wlh"),
+    ("wli", None, None, None, "wli"),
+    ("wlk", None, None, None, "wlk"),
+    ("wll", None, None, None, "wll"),
+    ("wlm", None, None, None, "wlm"),
+    ("wlo", None, None, None, "wlo"),
+    ("wlr", None, None, None, "wlr"),
+    ("wls", None, None, None, "wls"),
+    ("wlu", None, None, None, "wlu"),
+    ("wlv", None, None, None, "wlv"),
+    ("wlw", None, None, None, "wlw"),
+    ("wlx", None, None, None, "wlx"),
+    ("wly", None, None, None, "wly"),
+    ("wma", None, None, None, "wma"),
+    ("wmb", None, None, None, "wmb"),
+    ("wmc", None, None, None, "wmc"),
+    ("wmd", None, None, None, "wmd"),
+    ("wme", None, None, None, "wme"),
+    ("wmg", None, None, None, "wmg"),
+    ("wmh", None, None, None, "wmh"),
+    ("wmi", None, None, None, "wmi"),
+    ("wmm", None, None, None, "wmm"),
+    ("wmn", None, None, None, "wmn"),
+    ("wmo", None, None, None, "wmo"),
+    ("wms", None, None, None, "wms"),
+    ("wmt", None, None, None, "wmt"),
+    ("wmw", None, None, None, "wmw"),
+    ("wmx", None, None, None, "wmx"),
+    ("wnb", None, None, None, "wnb"),
+    ("wnc", None, None, None, "wnc"),
+    ("wnd", None, None, None, "wnd"),
+    ("wne", None, None, None, "wne"),
+    ("wng", None, None, None, "wng"),
+    ("wni", None, None, None, "wni"),
+    ("wnk", None, None, None, "wnk"),
+    ("wnm", None, None, None, "wnm"),
+    ("wnn", None, None, None, "wnn"),
+    ("wno", None, None, None, "wno"),
+    ("wnp", None, None, None, "wnp"),
+    ("wnu", None, None, None, "wnu"),
+    ("wnw", None, None, None, "wnw"),
+    ("wny", None, None, None, "wny"),
+    ("wo", Some("wo"), Some("wol"), Some("wol"), "wol"),
+    ("woa", None, None, None, "woa"),
+    ("wob", None, None, None, "wob"),
+    ("woc", None, None, None, "woc"),
+    ("wod", None, None, None, "wod"),
+    ("woe", None, None, None, "woe"),
+    ("wof", None, None, None, "wof"),
+    ("wog", None, None, None, "wog"),
+    ("woi", None, None, None, "woi"),
+    ("wok", None, None, None, "wok"),
+    ("wom", None, None, None, "wom"),
+    ("won", None, None, None, "won"),
+    ("woo", None, None, None, "woo"),
+    ("wor", None, None, None, "wor"),
+    ("wos", None, None, None, "wos"),
+    ("wow", None, None, None, "wow"),
+    ("woy", None, None, None, "woy"),
+    ("wpc", None, None, None, "wpc"),
+    ("wrb", None, None, None, "wrb"),
+    ("wrg", None, None, None, "wrg"),
+    ("wrh", None, None, None, "wrh"),
+    ("wri", None, None, None, "wri"),
+    ("wrk", None, None, None, "wrk"),
+    ("wrl", None, None, None, "wrl"),
+    ("wrm", None, None, None, "wrm"),
+    ("wrn", None, None, None, "wrn"),
+    ("wro", None, None, None, "wro"),
+    ("wrp", None, None, None, "wrp"),
+    ("wrr", None, None, None, "wrr"),
+    ("wrs", None, None, None, "wrs"),
+    ("wru", None, None, None, "wru"),
+    ("wrv", None, None, None, "wrv"),
+    ("wrw", None, None, None, "wrw"),
+    ("wrx", None, None, None, "wrx"),
+    ("wry", None, None, None, "wry"),
+    ("wrz", None, None, None, "wrz"),
+    ("wsa", None, None, None, "wsa"),
+    ("wsg", None, None, None, "wsg"),
+    ("wsi", None, None, None, "wsi"),
+    ("wsk", None, None, None, "wsk"),
+    ("wsr", None, None, None, "wsr"),
+    ("wss", None, None, None, "wss"),
+    ("wsu", None, None, None, "wsu"),
+    ("wsv", None, None, None, "wsv"),
+    ("wtf", None, None, None, "wtf"),
+    ("wth", None, None, None, "wth"),
+    ("wti", None, None, None, "wti"),
+    ("wtk", None, None, None, "wtk"),
+    ("wtm", None, None, None, "wtm"),
+    ("wtw", None, None, None, "wtw"),
+    ("wua", None, None, None, "wua"),
+    ("wub", None, None, None, "wub"),
+    ("wud", None, None, None, "wud"),
+    ("wuh", None, None, None, "wuh"),
+    ("wul", None, None, None, "wul"),
+    ("wum", None, None, None, "wum"),
+    ("wun", None, None, None, "wun"),
+    ("wur", None, None, None, "wur"),
+    ("wut", None, None, None, "wut"),
+    ("wuu", None, None, None, "wuu"),
+    ("wuv", None, None, None, "wuv"),
+    ("wux", None, None, None, "wux"),
+    ("wuy", None, None, None, "wuy"),
+    ("wwa", None, None, None, "wwa"),
+    ("wwb", None, None, None, "wwb"),
+    ("wwo", None, None, None, "wwo"),
+    ("wwr", None, None, None, "wwr"),
+    ("www", None, None, None, "www"),
+    ("wxa", None, None, None, "wxa"),
+    ("wxw", None, None, None, "wxw"),
+    ("wyb", None, None, None, "wyb"),
+    ("wyi", None, None, None, "wyi"),
+    ("wym", None, None, None, "wym"),
+    ("wyn", None, None, None, "wyn"),
+    ("wyr", None, None, None, "wyr"),
+    ("wyy", None, None, None, "wyy"),
+    ("xaa", None, None, None, "xaa"),
+    ("xab", None, None, None, "xab"),
+    ("xac", None, None, None, "xac"),
+    ("xad", None, None, None, "xad"),
+    ("xae", None, None, None, "xae"),
+    ("xag", None, None, None, "xag"),
+    ("xai", None, None, None, "xai"),
+    ("xaj", None, None, None, "xaj"),
+    ("xak", None, None, None, "xak"),
+    ("xal", None, Some("xal"), Some("xal"), "xal"),
+    ("xam", None, None, None, "xam"),
+    ("xan", None, None, None, "xan"),
+    ("xao", None, None, None, "xao"),
+    ("xap", None, None, None, "xap"),
+    ("xaq", None, None, None, "xaq"),
+    ("xar", None, None, None, "xar"),
+    ("xas", None, None, None, "xas"),
+    ("xat", None, None, None, "xat"),
+    ("xau", None, None, None, "xau"),
+    ("xav", None, None, None, "xav"),
+    ("xaw", None, None, None, "xaw"),
+    ("xay", None, None, None, "xay"),
+    ("xbb", None, None, None, "xbb"),
+    ("xbc", None, None, None, "xbc"),
+    ("xbd", None, None, None, "xbd"),
+    ("xbe", None, None, None, "xbe"),
+    ("xbg", None, None, None, "xbg"),
+    ("xbi", None, None, None, "xbi"),
+    ("xbj", None, None, None, "xbj"),
+    ("xbm", None, None, None, "xbm"),
+    ("xbn", None, None, None, "xbn"),
+    ("xbo", None, None, None, "xbo"),
+    ("xbp", None, None, None, "xbp"),
+    ("xbr", None, None, None, "xbr"),
+    ("xbw", None, None, None, "xbw"),
+    ("xby", None, None, None, "xby"),
+    ("xcb", None, None, None, "xcb"),
+    ("xcc", None, None, None, "xcc"),
+    ("xce", None, None, None, "xce"),
+    ("xcg", None, None, None, "xcg"),
+    ("xch", None, None, None, "xch"),
+    ("xcl", None, None, None, "xcl"),
+    ("xcm", None, None, None, "xcm"),
+    ("xcn", None, None, None, "xcn"),
+    ("xco", None, None, None, "xco"),
+    ("xcr", None, None, None, "xcr"),
+    ("xct", None, None, None, "xct"),
+    ("xcu", None, None, None, "xcu"),
+    ("xcv", None, None, None, "xcv"),
+    ("xcw", None, None, None, "xcw"),
+    ("xcy", None, None, None, "xcy"),
+    ("xda", None, None, None, "xda"),
+    ("xdc", None, None, None, "xdc"),
+    ("xdk", None, None, None, "xdk"),
+    ("xdm", None, None, None, "xdm"),
+    ("xdo", None, None, None, "xdo"),
+    ("xdq", None, None, None, "xdq"),
+    ("xdy", None, None, None, "xdy"),
+    ("xeb", None, None, None, "xeb"),
+    ("xed", None, None, None, "xed"),
+    ("xeg", None, None, None, "xeg"),
+    ("xel", None, None, None, "xel"),
+    ("xem", None, None, None, "xem"),
+    ("xep", None, None, None, "xep"),
+    ("xer", None, None, None, "xer"),
+    ("xes", None, None, None, "xes"),
+    ("xet", None, None, None, "xet"),
+    ("xeu", None, None, None, "xeu"),
+    ("xfa", None, None, None, "xfa"),
+    ("xga", None, None, None, "xga"),
+    ("xgb", None, None, None, "xgb"),
+    ("xgd", None, None, None, "xgd"),
+    ("xgf", None, None, None, "xgf"),
+    ("xgg", None, None, None, "xgg"),
+    ("xgi", None, None, None, "xgi"),
+    ("xgl", None, None, None, "xgl"),
+    ("xgm", None, None, None, "xgm"),
+    ("xgr", None, None, None, "xgr"),
+    ("xgu", None, None, None, "xgu"),
+    ("xgw", None, None, None, "xgw"),
+    ("xh", Some("xh"), Some("xho"), Some("xho"), "xho"),
+    ("xha", None, None, None, "xha"),
+    ("xhc", None, None, None, "xhc"),
+    ("xhd", None, None, None, "xhd"),
+    ("xhe", None, None, None, "xhe"),
+    ("xhm", None, None, None, "xhm"),
+    ("xhr", None, None, None, "xhr"),
+    ("xht", None, None, None, "xht"),
+    ("xhu", None, None, None, "xhu"),
+    ("xhv", None, None, None, "xhv"),
+    ("xib", None, None, None, "xib"),
+    ("xii", None, None, None, "xii"),
+    ("xil", None, None, None, "xil"),
+    ("xin", None, None, None, "xin"),
+    ("xir", None, None, None, "xir"),
+    ("xis", None, None, None, "xis"),
+    ("xiv", None, None, None, "xiv"),
+    ("xiy", None, None, None, "xiy"),
+    ("xjb", None, None, None, "xjb"),
+    ("xjt", None, None, None, "xjt"),
+    ("xka", None, None, None, "xka"),
+    ("xkb", None, None, None, "xkb"),
+    ("xkc", None, None, None, "xkc"),
+    ("xkd", None, None, None, "xkd"),
+    ("xke", None, None, None, "xke"),
+    ("xkf", None, None, None, "xkf"),
+    ("xkg", None, None, None, "xkg"),
+    ("xki", None, None, None, "xki"),
+    ("xkj", None, None, None, "xkj"),
+    ("xkk", None, None, None, "xkk"),
+    ("xkl", None, None, None, "xkl"),
+    ("xkn", None, None, None, "xkn"),
+    ("xko", None, None, None, "xko"),
+    ("xkp", None, None, None, "xkp"),
+    ("xkq", None, None, None, "xkq"),
+    ("xkr", None, None, None, "xkr"),
+    ("xks", None, None, None, "xks"),
+    ("xkt", None, None, None, "xkt"),
+    ("xku", None, None, None, "xku"),
+    ("xkv", None, None, None, "xkv"),
+    ("xkw", None, None, None, "xkw"),
+    ("xkx", None, None, None, "xkx"),
+    ("xky", None, None, None, "xky"),
+    ("xkz", None, None, None, "xkz"),
+    ("xla", None, None, None, "xla"),
+    ("xlb", None, None, None, "xlb"),
+    ("xlc", None, None, None, "xlc"),
+    ("xld", None, None, None, "xld"),
+    ("xle", None, None, None, "xle"),
+    ("xlg", None, None, None, "xlg"),
+    ("xli", None, None, None, "xli"),
+    ("xln", None, None, None, "xln"),
+    ("xlo", None, None, None, "xlo"),
+    ("xlp", None, None, None, "xlp"),
+    ("xls", None, None, None, "xls"),
+    ("xlu", None, None, None, "xlu"),
+    ("xly", None, None, None, "xly"),
+    ("xma", None, None, None, "xma"),
+    ("xmb", None, None, None, "xmb"),
+    ("xmc", None, None, None, "xmc"),
+    ("xmd", None, None, None, "xmd"),
+    ("xme", None, None, None, "xme"),
+    ("xmf", None, None, None, "xmf"),
+    ("xmg", None, None, None, "xmg"),
+    ("xmh", None, None, None, "xmh"),
+    ("xmj", None, None, None, "xmj"),
+    ("xmk", None, None, None, "xmk"),
+    ("xml", None, None, None, "xml"),
+    ("xmm", None, None, None, "xmm"),
+    ("xmn", None, None, None, "xmn"),
+    ("xmo", None, None, None, "xmo"),
+    ("xmp", None, None, None, "xmp"),
+    ("xmq", None, None, None, "xmq"),
+    ("xmr", None, None, None, "xmr"),
+    ("xms", None, None, None, "xms"),
+    ("xmt", None, None, None, "xmt"),
+    ("xmu", None, None, None, "xmu"),
+    ("xmv", None, None, None, "xmv"),
+    ("xmw", None, None, None, "xmw"),
+    ("xmx", None, None, None, "xmx"),
+    ("xmy", None, None, None, "xmy"),
+    ("xmz", None, None, None, "xmz"),
+    ("xna", None, None, None, "xna"),
+    ("xnb", None, None, None, "xnb"),
+    ("xng", None, None, None, "xng"),
+    ("xnh", None, None, None, "xnh"),
+    ("xni", None, None, None, "xni"),
+    ("xnj", None, None, None, "xnj"),
+    ("xnk", None, None, None, "xnk"),
+    ("xnm", None, None, None, "xnm"),
+    ("xnn", None, None, None, "xnn"),
+    ("xno", None, None, None, "xno"),
+    ("xnq", None, None, None, "xnq"),
+    ("xnr", None, None, None, "xnr"),
+    ("xns", None, None, None, "xns"),
+    ("xnt", None, None, None, "xnt"),
+    ("xnu", None, None, None, "xnu"),
+    ("xny", None, None, None, "xny"),
+    ("xnz", None, None, None, "xnz"),
+    ("xoc", None, None, None, "xoc"),
+    ("xod", None, None, None, "xod"),
+    ("xog", None, None, None, "xog"),
+    ("xoi", None, None, None, "xoi"),
+    ("xok", None, None, None, "xok"),
+    ("xom", None, None, None, "xom"),
+    ("xon", None, None, None, "xon"),
+    ("xoo", None, None, None, "xoo"),
+    ("xop", None, None, None, "xop"),
+    ("xor", None, None, None, "xor"),
+    ("xow", None, None, None, "xow"),
+    ("xpa", None, None, None, "xpa"),
+    ("xpb", None, None, None, "xpb"),
+    ("xpc", None, None, None, "xpc"),
+    ("xpd", None, None, None, "xpd"),
+    ("xpe", None, None, None, "xpe"),
+    ("xpf", None, None, None, "xpf"),
+    ("xpg", None, None, None, "xpg"),
+    ("xph", None, None, None, "xph"),
+    ("xpi", None, None, None, "xpi"),
+    ("xpj", None, None, None, "xpj"),
+    ("xpk", None, None, None, "xpk"),
+    ("xpl", None, None, None, "xpl"),
+    ("xpm", None, None, None, "xpm"),
+    ("xpn", None, None, None, "xpn"),
+    ("xpo", None, None, None, "xpo"),
+    ("xpp", None, None, None, "xpp"),
+    ("xpq", None, None, None, "xpq"),
+    ("xpr", None, None, None, "xpr"),
+    ("xps", None, None, None, "xps"),
+    ("xpt", None, None, None, "xpt"),
+    ("xpu", None, None, None, "xpu"),
+    ("xpv", None, None, None, "xpv"),
+    ("xpw", None, None, None, "xpw"),
+    ("xpx", None, None, None, "xpx"),
+    ("xpy", None, None, None, "xpy"),
+    ("xpz", None, None, None, "xpz"),
+    ("xqa", None, None, None, "xqa"),
+    ("xqt", None, None, None, "xqt"),
+    ("xra", None, None, None, "xra"),
+    ("xrb", None, None, None, "xrb"),
+    ("xrd", None, None, None, "xrd"),
+    ("xre", None, None, None, "xre"),
+    ("xrg", None, None, None, "xrg"),
+    ("xri", None, None, None, "xri"),
+    ("xrm", None, None, None, "xrm"),
+    ("xrn", None, None, None, "xrn"),
+    ("xrr", None, None, None, "xrr"),
+    ("xrt", None, None, None, "xrt"),
+    ("xru", None, None, None, "xru"),
+    ("xrw", None, None, None, "xrw"),
+    ("xsa", None, None, None, "xsa"),
+    ("xsb", None, None, None, "xsb"),
+    ("xsc", None, None, None, "xsc"),
+    ("xsd", None, None, None, "xsd"),
+    ("xse", None, None, None, "xse"),
+    ("xsh", None, None, None, "xsh"),
+    ("xsi", None, None, None, "xsi"),
+    ("xsj", None, None, None, "xsj"),
+    ("xsl", None, None, None, "xsl"),
+    ("xsm", None, None, None, "xsm"),
+    ("xsn", None, None, None, "xsn"),
+    ("xso", None, None, None, "xso"),
+    ("xsp", None, None, None, "xsp"),
+    ("xsq", None, None, None, "xsq"),
+    ("xsr", None, None, None, "xsr"),
+    ("xss", None, None, None, "xss"),
+    ("xsu", None, None, None, "xsu"),
+    ("xsv", None, None, None, "xsv"),
+    ("xsy", None, None, None, "xsy"),
+    ("xta", None, None, None, "xta"),
+    ("xtb", None, None, None, "xtb"),
+    ("xtc", None, None, None, "xtc"),
+    ("xtd", None, None, None, "xtd"),
+    ("xte", None, None, None, "xte"),
+    ("xtg", None, None, None, "xtg"),
+    ("xth", None, None, None, "xth"),
+    ("xti", None, None, None, "xti"),
+    ("xtj", None, None, None, "xtj"),
+    ("xtl", None, None, None, "xtl"),
+    ("xtm", None, None, None, "xtm"),
+    ("xtn", None, None, None, "xtn"),
+    ("xto", None, None, None, "xto"),
+    ("xtp", None, None, None, "xtp"),
+    ("xtq", None, None, None, "xtq"),
+    ("xtr", None, None, None, "xtr"),
+    ("xts", None, None, None, "xts"),
+    ("xtt", None, None, None, "xtt"),
+    ("xtu", None, None, None, "xtu"),
+    ("xtv", None, None, None, "xtv"),
+    ("xtw", None, None, None, "xtw"),
+    ("xty", None, None, None, "xty"),
+    ("xua", None, None, None, "xua"),
+    ("xub", None, None, None, "xub"),
+    ("xud", None, None, None, "xud"),
+    ("xug", None, None, None, "xug"),
+    ("xuj", None, None, None, "xuj"),
+    ("xul", None, None, None, "xul"),
+    ("xum", None, None, None, "xum"),
+    ("xun", None, None, None, "xun"),
+    ("xuo", None, None, None, "xuo"),
+    ("xup", None, None, None, "xup"),
+    ("xur", None, None, None, "xur"),
+    ("xut", None, None, None, "xut"),
+    ("xuu", None, None, None, "xuu"),
+    ("xve", None, None, None, "xve"),
+    ("xvi", None, None, None, "xvi"),
+    ("xvn", None, None, None, "xvn"),
+    ("xvo", None, None, None, "xvo"),
+    ("xvs", None, None, None, "xvs"),
+    ("xwa", None, None, None, "xwa"),
+    ("xwc", None, None, None, "xwc"),
+    ("xwd", None, None, None, "xwd"),
+    ("xwe", None, None, None, "xwe"),
+    ("xwg", None, None, None, "xwg"),
+    ("xwj", None, None, None, "xwj"),
+    ("xwk", None, None, None, "xwk"),
+    ("xwl", None, None, None, "xwl"),
+    ("xwo", None, None, None, "xwo"),
+    ("xwr", None, None, None, "xwr"),
+    ("xwt", None, None, None, "xwt"),
+    ("xww", None, None, None, "xww"),
+    ("xxb", None, None, None, "xxb"),
+    ("xxk", None, None, None, "xxk"),
+    ("xxm", None, None, None, "xxm"),
+    ("xxr", None, None, None, "xxr"),
+    ("xxt", None, None, None, "xxt"),
+    ("xya", None, None, None, "xya"),
+    ("xyb", None, None, None, "xyb"),
+    ("xyj", None, None, None, "xyj"),
+    ("xyk", None, None, None, "xyk"),
+    ("xyl", None, None, None, "xyl"),
+    ("xyt", None, None, None, "xyt"),
+    ("xyy", None, None, None, "xyy"),
+    ("xzh", None, None, None, "xzh"),
+    ("xzm", None, None, None, "xzm"),
+    ("xzp", None, None, None, "xzp"),
+    ("yaa", None, None, None, "yaa"),
+    ("yab", None, None, None, "yab"),
+    ("yac", None, None, None, "yac"),
+    ("yad", None, None, None, "yad"),
+    ("yae", None, None, None, "yae"),
+    ("yaf", None, None, None, "yaf"),
+    ("yag", None, None, None, "yag"),
+    ("yah", None, None, None, "yah"),
+    ("yai", None, None, None, "yai"),
+    ("yaj", None, None, None, "yaj"),
+    ("yak", None, None, None, "yak"),
+    ("yal", None, None, None, "yal"),
+    ("yam", None, None, None, "yam"),
+    ("yan", None, None, None, "yan"),
+    ("yao", None, Some("yao"), Some("yao"), "yao"),
+    ("yap", None, Some("yap"), Some("yap"), "yap"),
+    ("yaq", None, None, None, "yaq"),
+    ("yar", None, None, None, "yar"),
+    ("yas", None, None, None, "yas"),
+    ("yat", None, None, None, "yat"),
+    ("yau", None, None, None, "yau"),
+    ("yav", None, None, None, "yav"),
+    ("yaw", None, None, None, "yaw"),
+    ("yax", None, None, None, "yax"),
+    ("yay", None, None, None, "yay"),
+    ("yaz", None, None, None, "yaz"),
+    ("yba", None, None, None, "yba"),
+    ("ybb", None, None, None, "ybb"),
+    ("ybe", None, None, None, "ybe"),
+    ("ybh", None, None, None, "ybh"),
+    ("ybi", None, None, None, "ybi"),
+    ("ybj", None, None, None, "ybj"),
+    ("ybk", None, None, None, "ybk"),
+    ("ybl", None, None, None, "ybl"),
+    ("ybm", None, None, None, "ybm"),
+    ("ybn", None, None, None, "ybn"),
+    ("ybo", None, None, None, "ybo"),
+    ("ybx", None, None, None, "ybx"),
+    ("yby", None, None, None, "yby"),
+    ("ych", None, None, None, "ych"),
+    ("ycl", None, None, None, "ycl"),
+    ("ycn", None, None, None, "ycn"),
+    ("ycp", None, None, None, "ycp"),
+    ("yda", None, None, None, "yda"),
+    ("ydd", None, None, None, "ydd"),
+    ("yde", None, None, None, "yde"),
+    ("ydg", None, None, None, "ydg"),
+    ("ydk", None, None, None, "ydk"),
+    ("yea", None, None, None, "yea"),
+    ("yec", None, None, None, "yec"),
+    ("yee", None, None, None, "yee"),
+    ("yei", None, None, None, "yei"),
+    ("yej", None, None, None, "yej"),
+    ("yel", None, None, None, "yel"),
+    ("yer", None, None, None, "yer"),
+    ("yes", None, None, None, "yes"),
+    ("yet", None, None, None, "yet"),
+    ("yeu", None, None, None, "yeu"),
+    ("yev", None, None, None, "yev"),
+    ("yey", None, None, None, "yey"),
+    ("yga", None, None, None, "yga"),
+    ("ygi", None, None, None, "ygi"),
+    ("ygl", None, None, None, "ygl"),
+    ("ygm", None, None, None, "ygm"),
+    ("ygp", None, None, None, "ygp"),
+    ("ygr", None, None, None, "ygr"),
+    ("ygs", None, None, None, "ygs"),
+    ("ygu", None, None, None, "ygu"),
+    ("ygw", None, None, None, "ygw"),
+    ("yha", None, None, None, "yha"),
+    ("yhd", None, None, None, "yhd"),
+    ("yhl", None, None, None, "yhl"),
+    ("yhs", None, None, None, "yhs"),
+    ("yi", Some("yi"), Some("yid"), Some("yid"), "yid"),
+    ("yia", None, None, None, "yia"),
+    ("yif", None, None, None, "yif"),
+    ("yig", None, None, None, "yig"),
+    ("yih", None, None, None, "yih"),
+    ("yii", None, None, None, "yii"),
+    ("yij", None, None, None, "yij"),
+    ("yik", None, None, None, "yik"),
+    ("yil", None, None, None, "yil"),
+    ("yim", None, None, None, "yim"),
+    ("yin", None, None, None, "yin"),
+    ("yip", None, None, None, "yip"),
+    ("yiq", None, None, None, "yiq"),
+    ("yir", None, None, None, "yir"),
+    ("yis", None, None, None, "yis"),
+    ("yit", None, None, None, "yit"),
+    ("yiu", None, None, None, "yiu"),
+    ("yiv", None, None, None, "yiv"),
+    ("yix", None, None, None, "yix"),
+    ("yiz", None, None, None, "yiz"),
+    ("yka", None, None, None, "yka"),
+    ("ykg", None, None, None, "ykg"),
+    ("yki", None, None, None, "yki"),
+    ("ykk", None, None, None, "ykk"),
+    ("ykl", None, None, None, "ykl"),
+    ("ykm", None, None, None, "ykm"),
+    ("ykn", None, None, None, "ykn"),
+    ("yko", None, None, None, "yko"),
+    ("ykr", None, None, None, "ykr"),
+    ("ykt", None, None, None, "ykt"),
+    ("yku", None, None, None, "yku"),
+    ("yky", None, None, None, "yky"),
+    ("yla", None, None, None, "yla"),
+    ("ylb", None, None, None, "ylb"),
+    ("yle", None, None, None, "yle"),
+    ("ylg", None, None, None, "ylg"),
+    ("yli", None, None, None, "yli"),
+    ("yll", None, None, None, "yll"),
+    ("ylm", None, None, None, "ylm"),
+    ("yln", None, None, None, "yln"),
+    ("ylo", None, None, None, "ylo"),
+    ("ylr", None, None, None, "ylr"),
+    ("ylu", None, None, None, "ylu"),
+    ("yly", None, None, None, "yly"),
+    ("ymb", None, None, None, "ymb"),
+    ("ymc", None, None, None, "ymc"),
+    ("ymd", None, None, None, "ymd"),
+    ("yme", None, None, None, "yme"),
+    ("ymg", None, None, None, "ymg"),
+    ("ymh", None, None, None, "ymh"),
+    ("ymi", None, None, None, "ymi"),
+    ("ymk", None, None, None, "ymk"),
+    ("yml", None, None, None, "yml"),
+    ("ymm", None, None, None, "ymm"),
+    ("ymn", None, None, None, "ymn"),
+    ("ymo", None, None, None, "ymo"),
+    ("ymp", None, None, None, "ymp"),
+    ("ymq", None, None, None, "ymq"),
+    ("ymr", None, None, None, "ymr"),
+    ("yms", None, None, None, "yms"),
+    ("ymx", None, None, None, "ymx"),
+    ("ymz", None, None, None, "ymz"),
+    ("yna", None, None, None, "yna"),
+    ("ynd", None, None, None, "ynd"),
+    ("yne", None, None, None, "yne"),
+    ("yng", None, None, None, "yng"),
+    ("ynk", None, None, None, "ynk"),
+    ("ynl", None, None, None, "ynl"),
+    ("ynn", None, None, None, "ynn"),
+    ("yno", None, None, None, "yno"),
+    ("ynq", None, None, None, "ynq"),
+    ("yns", None, None, None, "yns"),
+    ("ynu", None, None, None, "ynu"),
+    ("yo", Some("yo"), Some("yor"), Some("yor"), "yor"),
+    ("yob", None, None, None, "yob"),
+    ("yog", None, None, None, "yog"),
+    ("yoi", None, None, None, "yoi"),
+    ("yok", None, None, None, "yok"),
+    ("yol", None, None, None, "yol"),
+    ("yom", None, None, None, "yom"),
+    ("yon", None, None, None, "yon"),
+    ("yot", None, None, None, "yot"),
+    ("yox", None, None, None, "yox"),
+    ("yoy", None, None, None, "yoy"),
+    ("ypa", None, None, None, "ypa"),
+    ("ypb", None, None, None, "ypb"),
+    ("ypg", None, None, None, "ypg"),
+    ("yph", None, None, None, "yph"),
+    ("ypm", None, None, None, "ypm"),
+    ("ypn", None, None, None, "ypn"),
+    ("ypo", None, None, None, "ypo"),
+    ("ypp", None, None, None, "ypp"),
+    ("ypz", None, None, None, "ypz"),
+    ("yra", None, None, None, "yra"),
+    ("yrb", None, None, None, "yrb"),
+    ("yre", None, None, None, "yre"),
+    ("yrk", None, None, None, "yrk"),
+    ("yrl", None, None, None, "yrl"),
+    ("yrm", None, None, None, "yrm"),
+    ("yrn", None, None, None, "yrn"),
+    ("yro", None, None, None, "yro"),
+    ("yrs", None, None, None, "yrs"),
+    ("yrw", None, None, None, "yrw"),
+    ("yry", None, None, None, "yry"),
+    ("ysc", None, None, None, "ysc"),
+    ("ysd", None, None, None, "ysd"),
+    ("ysg", None, None, None, "ysg"),
+    ("ysl", None, None, None, "ysl"),
+    ("ysm", None, None, None, "ysm"),
+    ("ysn", None, None, None, "ysn"),
+    ("yso", None, None, None, "yso"),
+    ("ysp", None, None, None, "ysp"),
+    ("ysr", None, None, None, "ysr"),
+    ("yss", None, None, None, "yss"),
+    ("ysy", None, None, None, "ysy"),
+    ("yta", None, None, None, "yta"),
+    ("ytl", None, None, None, "ytl"),
+    ("ytp", None, None, None, "ytp"),
+    ("ytw", None, None, None, "ytw"),
+    ("yty", None, None, None, "yty"),
+    ("yua", None, None, None, "yua"),
+    ("yub", None, None, None, "yub"),
+    ("yuc", None, None, None, "yuc"),
+    ("yud", None, None, None, "yud"),
+    ("yue", None, None, None, "yue"),
+    ("yuf", None, None, None, "yuf"),
+    ("yug", None, None, None, "yug"),
+    ("yui", None, None, None, "yui"),
+    ("yuj", None, None, None, "yuj"),
+    ("yuk", None, None, None, "yuk"),
+    ("yul", None, None, None, "yul"),
+    ("yum", None, None, None, "yum"),
+    ("yun", None, None, None, "yun"),
+    ("yup", None, None, None, "yup"),
+    ("yuq", None, None, None, "yuq"),
+    ("yur", None, None, None, "yur"),
+    ("yut", None, None, None, "yut"),
+    ("yuw", None, None, None, "yuw"),
+    ("yux", None, None, None, "yux"),
+    ("yuy", None, None, None, "yuy"),
+    ("yuz", None, None, None, "yuz"),
+    ("yva", None, None, None, "yva"),
+    ("yvt", None, None, None, "yvt"),
+    ("ywa", None, None, None, "ywa"),
+    ("ywg", None, None, None, "ywg"),
+    ("ywl", None, None, None, "ywl"),
+    ("ywn", None, None, None, "ywn"),
+    ("ywq", None, None, None, "ywq"),
+    ("ywr", None, None, None, "ywr"),
+    ("ywt", None, None, None, "ywt"),
+    ("ywu", None, None, None, "ywu"),
+    ("yww", None, None, None, "yww"),
+    ("yxa", None, None, None, "yxa"),
+    ("yxg", None, None, None, "yxg"),
+    ("yxl", None, None, None, "yxl"),
+    ("yxm", None, None, None, "yxm"),
+    ("yxu", None, None, None, "yxu"),
+    ("yxy", None, None, None, "yxy"),
+    ("yyr", None, None, None, "yyr"),
+    ("yyu", None, None, None, "yyu"),
+    ("yyz", None, None, None, "yyz"),
+    ("yzg", None, None, None, "yzg"),
+    ("yzk", None, None, None, "yzk"),
+    ("za", Some("za"), Some("zha"), Some("zha"), "zha"),
+    ("zaa", None, None, None, "zaa"),
+    ("zab", None, None, None, "zab"),
+    ("zac", None, None, None, "zac"),
+    ("zad", None, None, None, "zad"),
+    ("zae", None, None, None, "zae"),
+    ("zaf", None, None, None, "zaf"),
+    ("zag", None, None, None, "zag"),
+    ("zah", None, None, None, "zah"),
+    ("zai", None, None, None, "zai"),
+    ("zaj", None, None, None, "zaj"),
+    ("zak", None, None, None, "zak"),
+    ("zal", None, None, None, "zal"),
+    ("zam", None, None, None, "zam"),
+    ("zao", None, None, None, "zao"),
+    ("zap", None, Some("zap"), Some("zap"), "zap"),
+    ("zaq", None, None, None, "zaq"),
+    ("zar", None, None, None, "zar"),
+    ("zas", None, None, None, "zas"),
+    ("zat", None, None, None, "zat"),
+    ("zau", None, None, None, "zau"),
+    ("zav", None, None, None, "zav"),
+    ("zaw", None, None, None, "zaw"),
+    ("zax", None, None, None, "zax"),
+    ("zay", None, None, None, "zay"),
+    ("zaz", None, None, None, "zaz"),
+    ("zba", None, None, None, "zba"),
+    ("zbc", None, None, None, "zbc"),
+    ("zbe", None, None, None, "zbe"),
+    ("zbl", None, Some("zbl"), Some("zbl"), "zbl"),
+    ("zbt", None, None, None, "zbt"),
+    ("zbu", None, None, None, "zbu"),
+    ("zbw", None, None, None, "zbw"),
+    ("zca", None, None, None, "zca"),
+    ("zcd", None, None, None, "zcd"),
+    ("zch", None, None, None, "zch"),
+    ("zdj", None, None, None, "zdj"),
+    ("zea", None, None, None, "zea"),
+    ("zeg", None, None, None, "zeg"),
+    ("zeh", None, None, None, "zeh"),
+    ("zen", None, Some("zen"), Some("zen"), "zen"),
+    ("zga", None, None, None, "zga"),
+    ("zgb", None, None, None, "zgb"),
+    ("zgh", None, Some("zgh"), Some("zgh"), "zgh"),
+    ("zgm", None, None, None, "zgm"),
+    ("zgn", None, None, None, "zgn"),
+    ("zgr", None, None, None, "zgr"),
+    ("zh", Some("zh"), Some("zho"), Some("chi"), "zho"),
+    ("zhb", None, None, None, "zhb"),
+    ("zhd", None, None, None, "zhd"),
+    ("zhi", None, None, None, "zhi"),
+    ("zhn", None, None, None, "zhn"),
+    ("zhw", None, None, None, "zhw"),
+    ("zia", None, None, None, "zia"),
+    ("zib", None, None, None, "zib"),
+    ("zik", None, None, None, "zik"),
+    ("zil", None, None, None, "zil"),
+    ("zim", None, None, None, "zim"),
+    ("zin", None, None, None, "zin"),
+    ("ziw", None, None, None, "ziw"),
+    ("ziz", None, None, None, "ziz"),
+    ("zka", None, None, None, "zka"),
+    ("zkb", None, None, None, "zkb"),
+    ("zkd", None, None, None, "zkd"),
+    ("zkg", None, None, None, "zkg"),
+    ("zkh", None, None, None, "zkh"),
+    ("zkk", None, None, None, "zkk"),
+    ("zkn", None, None, None, "zkn"),
+    ("zko", None, None, None, "zko"),
+    ("zkp", None, None, None, "zkp"),
+    ("zkr", None, None, None, "zkr"),
+    ("zkt", None, None, None, "zkt"),
+    ("zku", None, None, None, "zku"),
+    ("zkv", None, None, None, "zkv"),
+    ("zkz", None, None, None, "zkz"),
+    ("zla", None, None, None, "zla"),
+    ("zlj", None, None, None, "zlj"),
+    ("zlm", None, None, None, "zlm"),
+    ("zln", None, None, None, "zln"),
+    ("zlq", None, None, None, "zlq"),
+    ("zma", None, None, None, "zma"),
+    ("zmb", None, None, None, "zmb"),
+    ("zmc", None, None, None, "zmc"),
+    ("zmd", None, None, None, "zmd"),
+    ("zme", None, None, None, "zme"),
+    ("zmf", None, None, None, "zmf"),
+    ("zmg", None, None, None, "zmg"),
+    ("zmh", None, None, None, "zmh"),
+    ("zmi", None, None, None, "zmi"),
+    ("zmj", None, None, None, "zmj"),
+    ("zmk", None, None, None, "zmk"),
+    ("zml", None, None, None, "zml"),
+    ("zmm", None, None, None, "zmm"),
+    ("zmn", None, None, None, "zmn"),
+    ("zmo", None, None, None, "zmo"),
+    ("zmp", None, None, None, "zmp"),
+    ("zmq", None, None, None, "zmq"),
+    ("zmr", None, None, None, "zmr"),
+    ("zms", None, None, None, "zms"),
+    ("zmt", None, None, None, "zmt"),
+    ("zmu", None, None, None, "zmu"),
+    ("zmv", None, None, None, "zmv"),
+    ("zmw", None, None, None, "zmw"),
+    ("zmx", None, None, None, "zmx"),
+    ("zmy", None, None, None, "zmy"),
+    ("zmz", None, None, None, "zmz"),
+    ("zna", None, None, None, "zna"),
+    ("zne", None, None, None, "zne"),
+    ("zng", None, None, None, "zng"),
+    ("znk", None, None, None, "znk"),
+    ("zns", None, None, None, "zns"),
+    ("zoc", None, None, None, "zoc"),
+    ("zoh", None, None, None, "zoh"),
+    ("zom", None, None, None, "zom"),
+    ("zoo", None, None, None, "zoo"),
+    ("zoq", None, None, None, "zoq"),
+    ("zor", None, None, None, "zor"),
+    ("zos", None, None, None, "zos"),
+    ("zpa", None, None, None, "zpa"),
+    ("zpb", None, None, None, "zpb"),
+    ("zpc", None, None, None, "zpc"),
+    ("zpd", None, None, None, "zpd"),
+    ("zpe", None, None, None, "zpe"),
+    ("zpf", None, None, None, "zpf"),
+    ("zpg", None, None, None, "zpg"),
+    ("zph", None, None, None, "zph"),
+    ("zpi", None, None, None, "zpi"),
+    ("zpj", None, None, None, "zpj"),
+    ("zpk", None, None, None, "zpk"),
+    ("zpl", None, None, None, "zpl"),
+    ("zpm", None, None, None, "zpm"),
+    ("zpn", None, None, None, "zpn"),
+    ("zpo", None, None, None, "zpo"),
+    ("zpp", None, None, None, "zpp"),
+    ("zpq", None, None, None, "zpq"),
+    ("zpr", None, None, None, "zpr"),
+    ("zps", None, None, None, "zps"),
+    ("zpt", None, None, None, "zpt"),
+    ("zpu", None, None, None, "zpu"),
+    ("zpv", None, None, None, "zpv"),
+    ("zpw", None, None, None, "zpw"),
+    ("zpx", None, None, None, "zpx"),
+    ("zpy", None, None, None, "zpy"),
+    ("zpz", None, None, None, "zpz"),
+    ("zqe", None, None, None, "zqe"),
+    ("zra", None, None, None, "zra"),
+    ("zrg", None, None, None, "zrg"),
+    ("zrn", None, None, None, "zrn"),
+    ("zro", None, None, None, "zro"),
+    ("zrp", None, None, None, "zrp"),
+    ("zrs", None, None, None, "zrs"),
+    ("zsa", None, None, None, "zsa"),
+    ("zsk", None, None, None, "zsk"),
+    ("zsl", None, None, None, "zsl"),
+    ("zsm", None, None, None, "zsm"),
+    ("zsr", None, None, None, "zsr"),
+    ("zsu", None, None, None, "zsu"),
+    ("zte", None, None, None, "zte"),
+    ("ztg", None, None, None, "ztg"),
+    ("ztl", None, None, None, "ztl"),
+    ("ztm", None, None, None, "ztm"),
+    ("ztn", None, None, None, "ztn"),
+    ("ztp", None, None, None, "ztp"),
+    ("ztq", None, None, None, "ztq"),
+    ("zts", None, None, None, "zts"),
+    ("ztt", None, None, None, "ztt"),
+    ("ztu", None, None, None, "ztu"),
+    ("ztx", None, None, None, "ztx"),
+    ("zty", None, None, None, "zty"),
+    ("zu", Some("zu"), Some("zul"), Some("zul"), "zul"),
+    ("zua", None, None, None, "zua"),
+    ("zuh", None, None, None, "zuh"),
+    ("zum", None, None, None, "zum"),
+    ("zun", None, Some("zun"), Some("zun"), "zun"),
+    ("zuy", None, None, None, "zuy"),
+    ("zwa", None, None, None, "zwa"),
+    ("zxx", None, Some("zxx"), Some("zxx"), "zxx"),
+    ("zyb", None, None, None, "zyb"),
+    ("zyg", None, None, None, "zyg"),
+    ("zyj", None, None, None, "zyj"),
+    ("zyn", None, None, None, "zyn"),
+    ("zyp", None, None, None, "zyp"),
+    ("zza", None, Some("zza"), Some("zza"), "zza"),
+    ("zzj", None, None, None, "zzj"),
+];
+
+#[rustfmt::skip]
+pub static ALIASES: [(&str, &str); 204] = [
+    ("aar", "aa"),
+    ("abk", "ab"),
+    ("afr", "af"),
+    ("aka", "ak"),
+    ("alb", "sq"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("arg", "an"),
+    ("arm", "hy"),
+    ("asm", "as"),
+    ("ava", "av"),
+    ("ave", "ae"),
+    ("aym", "ay"),
+    ("aze", "az"),
+    ("bak", "ba"),
+    ("bam", "bm"),
+    ("baq", "eu"),
+    ("bel", "be"),
+    ("ben", "bn"),
+    ("bis", "bi"),
+    ("bod", "bo"),
+    ("bos", "bs"),
+    ("bre", "br"),
+    ("bul", "bg"),
+    ("bur", "my"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cha", "ch"),
+    ("che", "ce"),
+    ("chi", "zh"),
+    ("chu", "cu"),
+    ("chv", "cv"),
+    ("cor", "kw"),
+    ("cos", "co"),
+    ("cre", "cr"),
+    ("cym", "cy"),
+    ("cze", "cs"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("div", "dv"),
+    ("dut", "nl"),
+    ("dzo", "dz"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("eus", "eu"),
+    ("ewe", "ee"),
+    ("fao", "fo"),
+    ("fas", "fa"),
+    ("fij", "fj"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("fre", "fr"),
+    ("fry", "fy"),
+    ("ful", "ff"),
+    ("geo", "ka"),
+    ("ger", "de"),
+    ("gla", "gd"),
+    ("gle", "ga"),
+    ("glg", "gl"),
+    ("glv", "gv"),
+    ("gre", "el"),
+    ("grn", "gn"),
+    ("guj", "gu"),
+    ("hat", "ht"),
+    ("hau", "ha"),
+    ("hbs", "sh"),
+    ("heb", "he"),
+    ("her", "hz"),
+    ("hin", "hi"),
+    ("hmo", "ho"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ibo", "ig"),
+    ("ice", "is"),
+    ("ido", "io"),
+    ("iii", "ii"),
+    ("iku", "iu"),
+    ("ile", "ie"),
+    ("ina", "ia"),
+    ("ind", "id"),
+    ("ipk", "ik"),
+    ("isl", "is"),
+    ("ita", "it"),
+    ("jav", "jv"),
+    ("jpn", "ja"),
+    ("kal", "kl"),
+    ("kan", "kn"),
+    ("kas", "ks"),
+    ("kat", "ka"),
+    ("kau", "kr"),
+    ("kaz", "kk"),
+    ("khm", "km"),
+    ("kik", "ki"),
+    ("kin", "rw"),
+    ("kir", "ky"),
+    ("kom", "kv"),
+    ("kon", "kg"),
+    ("kor", "ko"),
+    ("kua", "kj"),
+    ("kur", "ku"),
+    ("lao", "lo"),
+    ("lat", "la"),
+    ("lav", "lv"),
+    ("lim", "li"),
+    ("lin", "ln"),
+    ("lit", "lt"),
+    ("ltz", "lb"),
+    ("lub", "lu"),
+    ("lug", "lg"),
+    ("mac", "mk"),
+    ("mah", "mh"),
+    ("mal", "ml"),
+    ("mao", "mi"),
+    ("mar", "mr"),
+    ("may", "ms"),
+    ("mkd", "mk"),
+    ("mlg", "mg"),
+    ("mlt", "mt"),
+    ("mon", "mn"),
+    ("mri", "mi"),
+    ("msa", "ms"),
+    ("mya", "my"),
+    ("nau", "na"),
+    ("nav", "nv"),
+    ("nbl", "nr"),
+    ("nde", "nd"),
+    ("ndo", "ng"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nno", "nn"),
+    ("nob", "nb"),
+    ("nor", "no"),
+    ("nya", "ny"),
+    ("oci", "oc"),
+    ("oji", "oj"),
+    ("ori", "or"),
+    ("orm", "om"),
+    ("oss", "os"),
+    ("pan", "pa"),
+    ("per", "fa"),
+    ("pli", "pi"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("pus", "ps"),
+    ("que", "qu"),
+    ("roh", "rm"),
+    ("ron", "ro"),
+    ("rum", "ro"),
+    ("run", "rn"),
+    ("rus", "ru"),
+    ("sag", "sg"),
+    ("san", "sa"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slo", "sk"),
+    ("slv", "sl"),
+    ("sme", "se"),
+    ("smo", "sm"),
+    ("sna", "sn"),
+    ("snd", "sd"),
+    ("som", "so"),
+    ("sot", "st"),
+    ("spa", "es"),
+    ("sqi", "sq"),
+    ("srd", "sc"),
+    ("srp", "sr"),
+    ("ssw", "ss"),
+    ("sun", "su"),
+    ("swa", "sw"),
+    ("swe", "sv"),
+    ("tah", "ty"),
+    ("tam", "ta"),
+    ("tat", "tt"),
+    ("tel", "te"),
+    ("tgk", "tg"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("tib", "bo"),
+    ("tir", "ti"),
+    ("ton", "to"),
+    ("tsn", "tn"),
+    ("tso", "ts"),
+    ("tuk", "tk"),
+    ("tur", "tr"),
+    ("twi", "tw"),
+    ("uig", "ug"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("ven", "ve"),
+    ("vie", "vi"),
+    ("vol", "vo"),
+    ("wel", "cy"),
+    ("wln", "wa"),
+    ("wol", "wo"),
+    ("xho", "xh"),
+    ("yid", "yi"),
+    ("yor", "yo"),
+    ("zha", "za"),
+    ("zho", "zh"),
+    ("zul", "zu"),
+];
+
+#[rustfmt::skip]
+pub static MACROLANGUAGES: [(&str, &str); 81] = [
+    ("aae", "sq"),
+    ("aat", "sq"),
+    ("acm", "ar"),
+    ("aju", "jrb"),
+    ("aln", "sq"),
+    ("als", "sq"),
+    ("arb", "ar"),
+    ("ary", "ar"),
+    ("arz", "ar"),
+    ("ayr", "ay"),
+    ("azj", "az"),
+    ("bcc", "bal"),
+    ("bcl", "bik"),
+    ("bxk", "luy"),
+    ("bxr", "bua"),
+    ("cjy", "zh"),
+    ("cld", "syr"),
+    ("cmn", "zh"),
+    ("cwd", "cr"),
+    ("dgo", "doi"),
+    ("dhd", "mwr"),
+    ("dik", "din"),
+    ("diq", "zza"),
+    ("ekk", "et"),
+    ("emk", "man"),
+    ("esk", "ik"),
+    ("fat", "ak"),
+    ("fuc", "ff"),
+    ("gan", "zh"),
+    ("gaz", "om"),
+    ("gbo", "grb"),
+    ("gno", "gon"),
+    ("gug", "gn"),
+    ("gya", "gba"),
+    ("hak", "zh"),
+    ("hdn", "hai"),
+    ("hea", "hmn"),
+    ("hsn", "zh"),
+    ("id", "ms"),
+    ("ike", "iu"),
+    ("khk", "mn"),
+    ("kmr", "ku"),
+    ("knc", "kr"),
+    ("kng", "kg"),
+    ("knn", "kok"),
+    ("kpv", "kv"),
+    ("lbk", "bnc"),
+    ("lvs", "lv"),
+    ("mhr", "chm"),
+    ("min", "ms"),
+    ("mnk", "man"),
+    ("mup", "raj"),
+    ("nan", "zh"),
+    ("nb", "no"),
+    ("nn", "no"),
+    ("npi", "ne"),
+    ("ojg", "oj"),
+    ("ory", "or"),
+    ("pbu", "ps"),
+    ("pes", "fa"),
+    ("plt", "mg"),
+    ("pnb", "lah"),
+    ("prs", "fa"),
+    ("quz", "qu"),
+    ("rmy", "rom"),
+    ("spy", "kln"),
+    ("src", "sc"),
+    ("swh", "sw"),
+    ("ttq", "tmh"),
+    ("tw", "ak"),
+    ("umu", "del"),
+    ("uzn", "uz"),
+    ("wuu", "zh"),
+    ("xpe", "kpe"),
+    ("xsl", "den"),
+    ("ydd", "yi"),
+    ("yue", "zh"),
+    ("zai", "zap"),
+    ("zlm", "ms"),
+    ("zsm", "ms"),
+    ("zyb", "za"),
+];
@@ -1,12 +1,69 @@
-pub const SCRIPTS_CHARACTER_DIRECTION_LTR: [u32; 16] = [
-    1635149124, 1635349071, 1735288130, 1735288404, 1768256589, 1768513878, 1801677903, 1819441475,
-    1853120844, 1886285128, 1936613704, 1953390920, 1953657668, 1970038100, 1970435399, 2002872403,
-];
-pub const SCRIPTS_CHARACTER_DIRECTION_RTL: [u32; 4] =
-    [1650553409, 1734897490, 1835820097, 1869572942];
-pub const SCRIPTS_CHARACTER_DIRECTION_TTB: [u32; 1] = [1735290701];
-pub const LANGS_CHARACTER_DIRECTION_RTL: [u64; 29] = [
-    24934, 24936, 24944, 25715, 25960, 26214, 26485, 27001, 28002, 29281, 29301, 29547, 29549,
-    29552, 30308, 31329, 31349, 6450019, 6516833, 6517356, 6776946, 6841459, 7102818, 7235426,
-    7240301, 7303534, 7498611, 7502195, 7828084,
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+pub static CLDR_VERSION: &str = "44";
+
+#[rustfmt::skip]
+pub static SCRIPTS_CHARACTER_DIRECTION_LTR: [u32; 16] = [
+    1635149124, // Deva
+    1635349071, // Orya
+    1735288130, // Beng
+    1735288404, // Tfng
+    1768256589, // Mtei
+    1768513878, // Vaii
+    1801677903, // Olck
+    1819441475, // Cyrl
+    1853120844, // Latn
+    1886285128, // Hmnp
+    1936613704, // Hans
+    1953390920, // Hant
+    1953657668, // Dsrt
+    1970038100, // Telu
+    1970435399, // Guru
+    2002872403, // Shaw
+];
+
+#[rustfmt::skip]
+pub static SCRIPTS_CHARACTER_DIRECTION_RTL: [u32; 4] = [
+    1650553409, // Arab
+    1734897490, // Rohg
+    1835820097, // Adlm
+    1869572942, // Nkoo
+];
+
+#[rustfmt::skip]
+pub static SCRIPTS_CHARACTER_DIRECTION_TTB: [u32; 1] = [
+    1735290701, // Mong
+];
+
+#[rustfmt::skip]
+pub static LANGS_CHARACTER_DIRECTION_RTL: [u64; 29] = [
+    24934, // fa
+    24936, // ha
+    24944, // pa
+    25715, // sd
+    25960, // he
+    26214, // ff
+    26485, // ug
+    27001, // yi
+    28002, // bm
+    29281, // ar
+    29301, // ur
+    29547, // ks
+    29549, // ms
+    29552, // ps
+    30308, // dv
+    31329, // az
+    31349, // uz
+    6450019, // ckb
+    6516833, // apc
+    6517356, // lrc
+    6776946, // rhg
+    6841459, // sdh
+    7102818, // bal
+    7235426, // bgn
+    7240301, // mzn
+    7303534, // nqo
+    7498611, // skr
+    7502195, // syr
+    7828084, // trw
 ];
@@ -1,17 +1,65 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "aliases")]
+pub mod aliases;
+#[cfg(feature = "android")]
+pub mod android;
+#[cfg(feature = "apple")]
+pub mod apple;
+#[cfg(feature = "display-names")]
+pub mod display_names;
+pub mod encoding;
 mod errors;
+#[cfg(feature = "gettext")]
+pub mod gettext;
+#[cfg(feature = "iana-registry")]
+pub mod iana_registry;
+#[cfg(feature = "icu4x")]
+mod icu4x;
+#[cfg(feature = "iso639")]
+pub mod iso639;
 mod layout_table;
 #[cfg(feature = "likelysubtags")]
 pub mod likelysubtags;
+#[cfg(feature = "locale-info")]
+pub mod locale_info;
+mod packed;
+#[cfg(feature = "parent-locales")]
+pub mod parent_locales;
 #[doc(hidden)]
 pub mod parser;
+pub mod provider;
 #[cfg(feature = "serde")]
 mod serde;
 pub mod subtags;
+#[cfg(feature = "territory-info")]
+pub mod territory_info;
+#[cfg(feature = "validity")]
+pub mod validity;
 
 pub use crate::errors::LanguageIdentifierError;
-use std::fmt::Write;
-use std::iter::Peekable;
-use std::str::FromStr;
+pub use crate::packed::{PackError, PackedLanguageIdentifier};
+use crate::provider::{BakedDataProvider, DataProvider};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::str::FromStr;
+
+/// Options of [`LanguageIdentifier::matches_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MatchOptions {
+    /// Treat the missing fields of `self` as wildcards.
+    pub self_as_range: bool,
+    /// Treat the missing fields of the other identifier as wildcards.
+    pub other_as_range: bool,
+    /// Treat an individual language as matching the macrolanguage
+    /// encompassing it, like `cmn` and `zh`.
+    #[cfg(feature = "iso639")]
+    pub macrolanguages: bool,
+}
 
 /// Enum representing available character direction orientations.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -106,6 +154,36 @@ impl LanguageIdentifier {
         Ok(parser::parse_language_identifier(v)?)
     }
 
+    /// A constructor which takes a utf8 slice, parses it leniently and
+    /// produces a well-formed `LanguageIdentifier` together with a list
+    /// of subtags which were dropped.
+    ///
+    /// The longest well-formed prefix of the input is kept, and any later
+    /// subtag which doesn't fit into the identifier is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let input = "en-US-posix-xx_YY";
+    /// let (li, errors) = LanguageIdentifier::from_bytes_lenient(input.as_bytes());
+    ///
+    /// assert_eq!(li.to_string(), "en-US-posix");
+    /// assert_eq!(
+    ///     errors.iter().filter_map(|e| e.span()).map(|s| &input[s]).collect::<Vec<_>>(),
+    ///     &["xx", "YY"]
+    /// );
+    ///
+    /// let (li, errors) = LanguageIdentifier::from_bytes_lenient(b"en_US.UTF-8");
+    ///
+    /// assert_eq!(li.to_string(), "en-US");
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn from_bytes_lenient(v: &[u8]) -> (Self, Vec<parser::ParserError>) {
+        parser::parse_language_identifier_lenient(v)
+    }
+
     /// A constructor which takes optional subtags as `AsRef<[u8]>`, parses them and
     /// produces a well-formed `LanguageIdentifier`.
     ///
@@ -169,8 +247,8 @@ impl LanguageIdentifier {
     /// subtag iterator.
     ///
     /// Not stable.
-    pub fn try_from_iter<'a>(
-        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    pub fn try_from_iter(
+        iter: &mut parser::SubtagIterator,
         allow_extension: bool,
     ) -> Result<LanguageIdentifier, LanguageIdentifierError> {
         Ok(parser::parse_language_identifier_from_iter(
@@ -214,6 +292,86 @@ impl LanguageIdentifier {
         )
     }
 
+    /// Encodes the `LanguageIdentifier` into the compact binary form
+    /// described in [`encoding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sr-Cyrl-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_bytes().len(), 11);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encoding::encode(self)
+    }
+
+    /// A constructor which takes the compact binary form produced by
+    /// [`to_bytes`](Self::to_bytes) and decodes it into a `LanguageIdentifier`.
+    ///
+    /// Malformed and non-canonical input is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sl-rozaj-biske".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let bytes = li.to_bytes();
+    /// assert_eq!(LanguageIdentifier::from_bytes_encoded(&bytes), Ok(li));
+    ///
+    /// assert!(LanguageIdentifier::from_bytes_encoded(&bytes[..5]).is_err());
+    /// ```
+    pub fn from_bytes_encoded(v: &[u8]) -> Result<Self, LanguageIdentifierError> {
+        Ok(encoding::decode(v)?)
+    }
+
+    /// Packs the `LanguageIdentifier` into a single `u64` as described
+    /// in [`encoding`].
+    ///
+    /// Returns `None` if there are variants or the language subtag is
+    /// longer than 7 letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "zh-Hant-TW".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(li.to_u64().is_some());
+    ///
+    /// let li: LanguageIdentifier = "de-CH-1996".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.to_u64(), None);
+    /// ```
+    pub fn to_u64(&self) -> Option<u64> {
+        encoding::pack(self)
+    }
+
+    /// A constructor which takes a `u64` produced by [`to_u64`](Self::to_u64)
+    /// and unpacks it into a `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "es-419".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let packed = li.to_u64().expect("Packing failed.");
+    /// assert_eq!(LanguageIdentifier::from_u64(packed), Ok(li));
+    /// ```
+    pub fn from_u64(v: u64) -> Result<Self, LanguageIdentifierError> {
+        Ok(encoding::unpack(v)?)
+    }
+
     /// Compares a `LanguageIdentifier` to another `AsRef<LanguageIdentifier`
     /// allowing for either side to use the missing fields as wildcards.
     ///
@@ -244,9 +402,55 @@ impl LanguageIdentifier {
         self_as_range: bool,
         other_as_range: bool,
     ) -> bool {
+        let options = MatchOptions {
+            self_as_range,
+            other_as_range,
+            #[cfg(feature = "iso639")]
+            macrolanguages: false,
+        };
+        self.matches_with_options(other, options)
+    }
+
+    /// Compares a `LanguageIdentifier` to another `AsRef<LanguageIdentifier>`
+    /// like [`matches`](Self::matches), with more options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, MatchOptions};
+    ///
+    /// let requested: LanguageIdentifier = "cmn-Hans-CN".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let available: LanguageIdentifier = "zh-Hans".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let mut options = MatchOptions {
+    ///     self_as_range: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(available.matches_with_options(&requested, options), false);
+    ///
+    /// # #[cfg(feature = "iso639")]
+    /// # {
+    /// options.macrolanguages = true;
+    /// assert_eq!(available.matches_with_options(&requested, options), true);
+    /// # }
+    /// ```
+    pub fn matches_with_options<O: AsRef<Self>>(&self, other: &O, options: MatchOptions) -> bool {
         let other = other.as_ref();
-        self.language
-            .matches(other.language, self_as_range, other_as_range)
+        let MatchOptions {
+            self_as_range,
+            other_as_range,
+            ..
+        } = options;
+        let language_matches = self
+            .language
+            .matches(other.language, self_as_range, other_as_range);
+        #[cfg(feature = "iso639")]
+        let language_matches = language_matches
+            || options.macrolanguages && iso639::encompasses(&self.language, &other.language);
+        language_matches
             && subtag_matches(&self.script, &other.script, self_as_range, other_as_range)
             && subtag_matches(&self.region, &other.region, self_as_range, other_as_range)
             && subtags_match(
@@ -400,6 +604,36 @@ impl LanguageIdentifier {
         }
     }
 
+    /// Extends the `LanguageIdentifier` removing likely subtags like
+    /// [`minimize`](Self::minimize), but keeps the script rather than the
+    /// region when either would do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "zh-Hant-TW".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.clone().minimize(), true);
+    /// assert_eq!(li.minimize_favor_script(), true);
+    /// assert_eq!(li.to_string(), "zh-Hant");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize_favor_script(&mut self) -> bool {
+        if let Some(new_li) =
+            likelysubtags::minimize_favor_script(self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Returns character direction of the `LanguageIdentifier`.
     ///
     /// # Examples
@@ -416,36 +650,166 @@ impl LanguageIdentifier {
     /// assert_eq!(li2.character_direction(), CharacterDirection::RTL);
     /// ```
     pub fn character_direction(&self) -> CharacterDirection {
-        match (self.language.into(), self.script) {
-            (_, Some(script))
-                if layout_table::SCRIPTS_CHARACTER_DIRECTION_LTR.contains(&script.into()) =>
-            {
-                CharacterDirection::LTR
-            }
-            (_, Some(script))
-                if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script.into()) =>
-            {
-                CharacterDirection::RTL
-            }
-            (_, Some(script))
-                if layout_table::SCRIPTS_CHARACTER_DIRECTION_TTB.contains(&script.into()) =>
-            {
-                CharacterDirection::TTB
-            }
-            (Some(lang), _) if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&lang) => {
-                #[cfg(feature = "likelysubtags")]
-                if let Some((_, Some(script), _)) =
-                    likelysubtags::maximize(self.language, None, self.region)
-                {
-                    if layout_table::SCRIPTS_CHARACTER_DIRECTION_LTR.contains(&script.into()) {
-                        return CharacterDirection::LTR;
-                    }
-                }
-                CharacterDirection::RTL
-            }
-            _ => CharacterDirection::LTR,
+        self.character_direction_with(&BakedDataProvider)
+    }
+
+    /// Extends the `LanguageIdentifier` adding likely subtags based
+    /// on data from `provider`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::provider::RuntimeDataProvider;
+    ///
+    /// let mut provider = RuntimeDataProvider::new("test");
+    /// provider.insert_likely_subtags(
+    ///     "en".parse().expect("Parsing failed."),
+    ///     "en-Latn-US".parse().expect("Parsing failed."),
+    /// );
+    ///
+    /// let mut li: LanguageIdentifier = "en-GB".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.maximize_with(&provider), true);
+    /// assert_eq!(li.to_string(), "en-Latn-GB");
+    /// ```
+    pub fn maximize_with<P: DataProvider + ?Sized>(&mut self, provider: &P) -> bool {
+        if let Some(new_li) = provider::maximize(provider, self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
         }
     }
+
+    /// Extends the `LanguageIdentifier` removing likely subtags based
+    /// on data from `provider`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::provider::RuntimeDataProvider;
+    ///
+    /// let mut provider = RuntimeDataProvider::new("test");
+    /// provider.insert_likely_subtags(
+    ///     "en".parse().expect("Parsing failed."),
+    ///     "en-Latn-US".parse().expect("Parsing failed."),
+    /// );
+    ///
+    /// let mut li: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimize_with(&provider), true);
+    /// assert_eq!(li.to_string(), "en");
+    /// ```
+    pub fn minimize_with<P: DataProvider + ?Sized>(&mut self, provider: &P) -> bool {
+        if let Some(new_li) = provider::minimize(provider, self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Extends the `LanguageIdentifier` removing likely subtags based
+    /// on data from `provider`, like
+    /// [`minimize_favor_script`](Self::minimize_favor_script).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::provider::RuntimeDataProvider;
+    ///
+    /// let mut provider = RuntimeDataProvider::new("test");
+    /// provider.insert_likely_subtags(
+    ///     "zh".parse().expect("Parsing failed."),
+    ///     "zh-Hans-CN".parse().expect("Parsing failed."),
+    /// );
+    /// provider.insert_likely_subtags(
+    ///     "zh-TW".parse().expect("Parsing failed."),
+    ///     "zh-Hant-TW".parse().expect("Parsing failed."),
+    /// );
+    /// provider.insert_likely_subtags(
+    ///     "zh-Hant".parse().expect("Parsing failed."),
+    ///     "zh-Hant-TW".parse().expect("Parsing failed."),
+    /// );
+    ///
+    /// let mut li: LanguageIdentifier = "zh-Hant-TW".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimize_favor_script_with(&provider), true);
+    /// assert_eq!(li.to_string(), "zh-Hant");
+    /// ```
+    pub fn minimize_favor_script_with<P: DataProvider + ?Sized>(&mut self, provider: &P) -> bool {
+        if let Some(new_li) =
+            provider::minimize_favor_script(provider, self.language, self.script, self.region)
+        {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns character direction of the `LanguageIdentifier` based
+    /// on data from `provider`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, CharacterDirection};
+    /// use unic_langid_impl::provider::RuntimeDataProvider;
+    ///
+    /// let mut provider = RuntimeDataProvider::new("test");
+    /// provider.insert_language_direction(
+    ///     "he".parse().expect("Parsing failed."),
+    ///     CharacterDirection::RTL,
+    /// );
+    ///
+    /// let li: LanguageIdentifier = "he-IL".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.character_direction_with(&provider), CharacterDirection::RTL);
+    /// ```
+    pub fn character_direction_with<P: DataProvider + ?Sized>(
+        &self,
+        provider: &P,
+    ) -> CharacterDirection {
+        provider::character_direction(provider, self.language, self.script, self.region)
+    }
+
+    /// Returns the name of the `LanguageIdentifier` in the display locale
+    /// of `names`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::display_names::DisplayNames;
+    ///
+    /// let names = DisplayNames::new(&"fr".parse().expect("Parsing failed."), Default::default())
+    ///     .expect("No data for fr.");
+    ///
+    /// let li: LanguageIdentifier = "de-CH".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.display_name(&names), "allemand suisse");
+    /// ```
+    #[cfg(feature = "display-names")]
+    pub fn display_name(&self, names: &display_names::DisplayNames) -> String {
+        names.language_identifier(self)
+    }
 }
 
 impl FromStr for LanguageIdentifier {
@@ -463,8 +827,8 @@ impl AsRef<LanguageIdentifier> for LanguageIdentifier {
     }
 }
 
-impl std::fmt::Display for LanguageIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for LanguageIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.language.fmt(f)?;
         if let Some(ref script) = self.script {
             f.write_char('-')?;
@@ -1,28 +1,135 @@
+//! Likely subtags data from CLDR, used by `maximize` and `minimize`.
+//!
+//! # Subsetting
+//!
+//! The tables cover every language known to CLDR. Builds which only need
+//! some of them can keep a subset of the entries by setting any of the
+//! following environment variables at build time, for example in the
+//! `[env]` section of `.cargo/config.toml`, to a comma separated list of
+//! subtags:
+//!
+//! * `UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES`, such as `en,fr,ar`
+//! * `UNIC_LANGID_LIKELYSUBTAGS_SCRIPTS`, such as `Latn,Arab`
+//! * `UNIC_LANGID_LIKELYSUBTAGS_REGIONS`, such as `US,FR,EG`
+//!
+//! Only the entries whose key uses nothing but listed subtags are kept,
+//! so identifiers made of listed subtags maximize exactly as with the full
+//! data, and minimize exactly as with it when their maximized form is made
+//! of listed subtags too. Otherwise they may be left unchanged, like
+//! `und-Cyrl`, maximized to `ru-Cyrl-RU`, when `ru` isn't listed. Any other
+//! identifier is left unchanged, as if CLDR had no data for it, which
+//! [`covers`] tells apart. [`IS_SUBSET`] tells
+//! whether the tables were subset.
+#[allow(clippy::unreadable_literal)]
+#[cfg(not(unic_langid_likelysubtags_subset))]
 mod tables;
+#[allow(clippy::unreadable_literal)]
+#[cfg(unic_langid_likelysubtags_subset)]
+mod tables {
+    include!(concat!(env!("OUT_DIR"), "/likelysubtags_tables.rs"));
+}
 
 pub use tables::CLDR_VERSION;
 
+/// Whether the tables only hold a subset of the CLDR data, as configured
+/// at build time.
+pub const IS_SUBSET: bool = cfg!(unic_langid_likelysubtags_subset);
+
+use crate::provider::{self, BakedDataProvider};
 use crate::subtags;
 
-unsafe fn lang_from_parts(
-    input: (Option<u64>, Option<u32>, Option<u32>),
-    lang: Option<subtags::Language>,
+// Entries in the tables pack the indices of their subtags in the
+// `LANGUAGES`, `SCRIPTS` and `REGIONS` pools, plus one, into a `u32`:
+// the language above `LANGUAGE_SHIFT`, the script above `SCRIPT_SHIFT`
+// and the region below it. A missing subtag, or entry, is `0`.
+const SCRIPT_SHIFT: u32 = 9;
+const LANGUAGE_SHIFT: u32 = 17;
+const SCRIPT_MASK: u32 = (1 << (LANGUAGE_SHIFT - SCRIPT_SHIFT)) - 1;
+const REGION_MASK: u32 = (1 << SCRIPT_SHIFT) - 1;
+
+// Keys of tables indexed by two subtags pack the index of the first
+// one above `KEY_SHIFT` and of the second one below it.
+const KEY_SHIFT: u32 = 16;
+
+fn language_index(lang: subtags::Language) -> Option<u32> {
+    let lang: u64 = Option::<u64>::from(lang)?;
+    tables::LANGUAGES
+        .binary_search(&lang)
+        .ok()
+        .map(|idx| idx as u32)
+}
+
+fn script_index(script: subtags::Script) -> Option<u32> {
+    tables::SCRIPTS
+        .binary_search(&script.into())
+        .ok()
+        .map(|idx| idx as u32)
+}
+
+fn region_index(region: subtags::Region) -> Option<u32> {
+    tables::REGIONS
+        .binary_search(&region.into())
+        .ok()
+        .map(|idx| idx as u32)
+}
+
+/// Returns whether the tables cover all of the given subtags, which is
+/// always the case unless they were built with a subset of the data.
+pub fn covers(
+    lang: subtags::Language,
     script: Option<subtags::Script>,
     region: Option<subtags::Region>,
+) -> bool {
+    fn contains<T: Ord>(set: Option<&[T]>, value: Option<T>) -> bool {
+        match (set, value) {
+            (Some(set), Some(value)) => set.binary_search(&value).is_ok(),
+            _ => true,
+        }
+    }
+    contains(tables::SUBSET_LANGUAGES, lang.into())
+        && contains(tables::SUBSET_SCRIPTS, script.map(Into::into))
+        && contains(tables::SUBSET_REGIONS, region.map(Into::into))
+}
+
+fn find(table: &[(u32, u32)], first: u32, second: u32) -> Option<u32> {
+    let key = first << KEY_SHIFT | second;
+    table
+        .binary_search_by_key(&key, |(k, _)| *k)
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+fn lang_from_parts(
+    input: u32,
 ) -> Option<(
     subtags::Language,
     Option<subtags::Script>,
     Option<subtags::Region>,
 )> {
-    let lang = lang
-        .or_else(|| input.0.map(|s| subtags::Language::from_raw_unchecked(s)))
-        .unwrap();
-    let script = script.or_else(|| input.1.map(|s| subtags::Script::from_raw_unchecked(s)));
-    let region = region.or_else(|| input.2.map(|r| subtags::Region::from_raw_unchecked(r)));
+    if input == 0 {
+        return None;
+    }
+    let lang_idx = (input >> LANGUAGE_SHIFT) as usize - 1;
+    let script_idx = (input >> SCRIPT_SHIFT & SCRIPT_MASK) as usize;
+    let region_idx = (input & REGION_MASK) as usize;
+
+    // safe because all pool entries are well formed.
+    let lang = unsafe { subtags::Language::from_raw_unchecked(tables::LANGUAGES[lang_idx]) };
+    let script = script_idx
+        .checked_sub(1)
+        .map(|idx| unsafe { subtags::Script::from_raw_unchecked(tables::SCRIPTS[idx]) });
+    let region = region_idx
+        .checked_sub(1)
+        .map(|idx| unsafe { subtags::Region::from_raw_unchecked(tables::REGIONS[idx]) });
     Some((lang, script, region))
 }
 
-pub fn maximize(
+// The key of the `und` entry in the `LANG_ONLY` table.
+const UND: u64 = u64::from_le_bytes(*b"und\0\0\0\0\0");
+
+/// Returns the likely subtags listed by CLDR for exactly the given
+/// subtags, without falling back to any other entry.
+pub(crate) fn lookup(
     lang: subtags::Language,
     script: Option<subtags::Script>,
     region: Option<subtags::Region>,
@@ -31,69 +138,50 @@ pub fn maximize(
     Option<subtags::Script>,
     Option<subtags::Region>,
 )> {
-    if !lang.is_empty() && script.is_some() && region.is_some() {
-        return None;
-    }
-
-    if let Some(l) = Into::<Option<u64>>::into(lang) {
-        if let Some(r) = region {
-            let result = tables::LANG_REGION
-                .binary_search_by_key(&(&l, &r.into()), |(key_l, key_r, _)| (key_l, key_r))
-                .ok();
-            if let Some(r) = result {
-                // safe because all table entries are well formed.
-                return unsafe { lang_from_parts(tables::LANG_REGION[r].2, None, None, None) };
-            }
-        }
-
-        if let Some(s) = script {
-            let result = tables::LANG_SCRIPT
-                .binary_search_by_key(&(&l, &s.into()), |(key_l, key_s, _)| (key_l, key_s))
-                .ok();
-            if let Some(r) = result {
-                // safe because all table entries are well formed.
-                return unsafe { lang_from_parts(tables::LANG_SCRIPT[r].2, None, None, None) };
-            }
-        }
-
-        let result = tables::LANG_ONLY
-            .binary_search_by_key(&(&l), |(key_l, _)| key_l)
-            .ok();
-        if let Some(r) = result {
-            // safe because all table entries are well formed.
-            return unsafe { lang_from_parts(tables::LANG_ONLY[r].1, None, script, region) };
-        }
-    } else if let Some(s) = script {
-        if let Some(r) = region {
-            let result = tables::SCRIPT_REGION
-                .binary_search_by_key(&(&s.into(), &r.into()), |(key_s, key_r, _)| (key_s, key_r))
-                .ok();
-            if let Some(r) = result {
-                // safe because all table entries are well formed.
-                return unsafe { lang_from_parts(tables::SCRIPT_REGION[r].2, None, None, None) };
-            }
-        }
-
-        let result = tables::SCRIPT_ONLY
-            .binary_search_by_key(&(&s.into()), |(key_s, _)| key_s)
-            .ok();
-        if let Some(r) = result {
-            // safe because all table entries are well formed.
-            return unsafe { lang_from_parts(tables::SCRIPT_ONLY[r].1, None, None, region) };
+    let result = match (lang.is_empty(), script, region) {
+        (true, None, None) => {
+            let l = tables::LANGUAGES.binary_search(&UND).ok()?;
+            tables::LANG_ONLY[l]
         }
-    } else if let Some(r) = region {
-        let result = tables::REGION_ONLY
-            .binary_search_by_key(&(&r.into()), |(key_r, _)| key_r)
-            .ok();
-        if let Some(r) = result {
-            // safe because all table entries are well formed.
-            return unsafe { lang_from_parts(tables::REGION_ONLY[r].1, None, None, None) };
+        (false, None, None) => tables::LANG_ONLY[language_index(lang)? as usize],
+        (false, None, Some(r)) => find(
+            &tables::LANG_REGION,
+            language_index(lang)?,
+            region_index(r)?,
+        )?,
+        (false, Some(s), None) => find(
+            &tables::LANG_SCRIPT,
+            language_index(lang)?,
+            script_index(s)?,
+        )?,
+        (true, Some(s), Some(r)) => {
+            find(&tables::SCRIPT_REGION, script_index(s)?, region_index(r)?)?
         }
-    }
+        (true, Some(s), None) => tables::SCRIPT_ONLY[script_index(s)? as usize],
+        (true, None, Some(r)) => tables::REGION_ONLY[region_index(r)? as usize],
+        (false, Some(_), Some(_)) => return None,
+    };
+    lang_from_parts(result)
+}
 
-    None
+/// Extends the subtags with likely subtags from the baked tables.
+///
+/// This is [`provider::maximize`] with the [`BakedDataProvider`].
+pub fn maximize(
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+)> {
+    provider::maximize(&BakedDataProvider, lang, script, region)
 }
 
+/// Removes likely subtags using the baked tables.
+///
+/// This is [`provider::minimize`] with the [`BakedDataProvider`].
 pub fn minimize(
     lang: subtags::Language,
     script: Option<subtags::Script>,
@@ -103,34 +191,19 @@ pub fn minimize(
     Option<subtags::Script>,
     Option<subtags::Region>,
 )> {
-    // maximize returns None when all 3 components are
-    // already filled so don't call it in that case.
-    let max_langid = if !lang.is_empty() && script.is_some() && region.is_some() {
-        (lang, script, region)
-    } else {
-        maximize(lang, script, region)?
-    };
-
-    if let Some(trial) = maximize(max_langid.0, None, None) {
-        if trial == max_langid {
-            return Some((max_langid.0, None, None));
-        }
-    }
-
-    if max_langid.2.is_some() {
-        if let Some(trial) = maximize(max_langid.0, None, max_langid.2) {
-            if trial == max_langid {
-                return Some((max_langid.0, None, max_langid.2));
-            }
-        }
-    }
+    provider::minimize(&BakedDataProvider, lang, script, region)
+}
 
-    if max_langid.1.is_some() {
-        if let Some(trial) = maximize(max_langid.0, max_langid.1, None) {
-            if trial == max_langid {
-                return Some((max_langid.0, max_langid.1, None));
-            }
-        }
-    }
-    None
+/// Like [`minimize`], but keeps the script rather than the region when
+/// either would do, like `zh-Hant` rather than `zh-TW` for `zh-Hant-TW`.
+pub fn minimize_favor_script(
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+)> {
+    provider::minimize_favor_script(&BakedDataProvider, lang, script, region)
 }
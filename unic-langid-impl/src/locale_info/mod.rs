@@ -0,0 +1,160 @@
+//! Regional preferences for calendars, hour cycles and weeks.
+//!
+//! These back the `getCalendars`, `getHourCycles` and `getWeekInfo`
+//! methods of ECMA-402 `Intl.Locale`, using the CLDR
+//! `calendarPreferenceData`, `timeData` and `weekData` baked into the
+//! crate. Preferences are looked up by the region of the
+//! `LanguageIdentifier`, or its likely region if it has none, falling
+//! back to the world, `001`.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::locale_info::{self, HourCycle};
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let li: LanguageIdentifier = "th".parse().expect("Parsing failed.");
+//! assert_eq!(locale_info::calendars(&li), &["buddhist", "gregory"]);
+//!
+//! let li: LanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+//! assert_eq!(locale_info::hour_cycle(&li), HourCycle::H12);
+//!
+//! let week = locale_info::week_info(&li);
+//! assert_eq!(week.first_day, 7);
+//! assert_eq!(week.weekend, vec![6, 7]);
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the `cldr-core` package,
+//! and can be limited to some languages and regions with its `--locales`
+//! and `--regions` options.
+mod tables;
+
+use crate::subtags::Region;
+use crate::LanguageIdentifier;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub use tables::CLDR_VERSION;
+
+/// The hour cycle of a time format, as the `hc` Unicode extension keyword
+/// names it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HourCycle {
+    /// Hours 0 to 11, like `0:30 PM`.
+    H11,
+    /// Hours 1 to 12, like `12:30 PM`.
+    H12,
+    /// Hours 0 to 23, like `0:30`.
+    H23,
+    /// Hours 1 to 24, like `24:30`.
+    H24,
+}
+
+impl HourCycle {
+    /// Returns the `hc` keyword type of the hour cycle, like `h12`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::H11 => "h11",
+            Self::H12 => "h12",
+            Self::H23 => "h23",
+            Self::H24 => "h24",
+        }
+    }
+}
+
+impl core::str::FromStr for HourCycle {
+    type Err = crate::parser::errors::ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(Self::Err::InvalidSubtag),
+        }
+    }
+}
+
+/// The week of a region, with days numbered from 1 for Monday to 7 for
+/// Sunday.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WeekInfo {
+    /// The first day of the week.
+    pub first_day: u8,
+    /// The days of the weekend, in order.
+    pub weekend: Vec<u8>,
+    /// The minimal number of days of the first week of a month or year.
+    pub minimal_days: u8,
+}
+
+fn find<T>(table: &'static [(&'static str, T)], key: &str) -> Option<&'static T> {
+    table
+        .binary_search_by_key(&key, |(k, _)| k)
+        .ok()
+        .map(|idx| &table[idx].1)
+}
+
+/// Returns the region of `langid`, or its likely region.
+fn region(langid: &LanguageIdentifier) -> Option<Region> {
+    langid.region.or_else(|| {
+        let mut langid = langid.clone();
+        langid.maximize();
+        langid.region
+    })
+}
+
+/// Returns the calendars used in the region of `langid`, the preferred
+/// one first, as `ca` keyword types like `gregory`.
+pub fn calendars(langid: &LanguageIdentifier) -> &'static [&'static str] {
+    region(langid)
+        .and_then(|region| find(&tables::CALENDARS, region.as_str()))
+        .or_else(|| find(&tables::CALENDARS, "001"))
+        .copied()
+        .unwrap_or(&["gregory"])
+}
+
+/// Returns the preferred hour cycle of `langid`, which may depend on its
+/// language as well as on its region, like for `ca-ES`.
+pub fn hour_cycle(langid: &LanguageIdentifier) -> HourCycle {
+    let region = region(langid);
+    region
+        .and_then(|region| {
+            let key = LanguageIdentifier::from_parts(langid.language, None, Some(region), &[]);
+            find(&tables::HOUR_CYCLES, &key.to_string())
+                .or_else(|| find(&tables::HOUR_CYCLES, region.as_str()))
+        })
+        .or_else(|| find(&tables::HOUR_CYCLES, "001"))
+        .copied()
+        .unwrap_or(HourCycle::H23)
+}
+
+/// Returns the week of the region of `langid`.
+pub fn week_info(langid: &LanguageIdentifier) -> WeekInfo {
+    let find = |key: &str| {
+        tables::WEEK_DATA
+            .binary_search_by_key(&key, |(k, ..)| k)
+            .ok()
+            .map(|idx| &tables::WEEK_DATA[idx])
+    };
+    let (_, first_day, weekend_start, weekend_end, minimal_days) = region(langid)
+        .and_then(|region| find(region.as_str()))
+        .or_else(|| find("001"))
+        .copied()
+        .unwrap_or(("001", 1, 6, 7, 1));
+
+    let mut weekend = vec![weekend_start];
+    let mut day = weekend_start;
+    while day != weekend_end {
+        day = day % 7 + 1;
+        weekend.push(day);
+    }
+    WeekInfo {
+        first_day,
+        weekend,
+        minimal_days,
+    }
+}
@@ -0,0 +1,458 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+use super::HourCycle;
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static CALENDARS: [(&str, &[&str]); 52] = [
+    ("001", &["gregory"]),
+    ("AE", &["gregory", "islamic-umalqura", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("AF", &["persian", "gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("AL", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("AZ", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("BD", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("BH", &["gregory", "islamic-umalqura", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("CN", &["gregory", "chinese"]),
+    ("CX", &["gregory", "chinese"]),
+    ("DJ", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("DZ", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("EG", &["gregory", "coptic", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("EH", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("ER", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("ET", &["gregory", "ethiopic"]),
+    ("HK", &["gregory", "chinese"]),
+    ("ID", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("IL", &["gregory", "hebrew", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("IN", &["gregory", "indian"]),
+    ("IQ", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("IR", &["persian", "gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("JO", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("JP", &["gregory", "japanese"]),
+    ("KM", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("KR", &["gregory", "dangi"]),
+    ("KW", &["gregory", "islamic-umalqura", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("LB", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("LY", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("MA", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("MO", &["gregory", "chinese"]),
+    ("MR", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("MV", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("MY", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("NE", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("OM", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("PK", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("PS", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("QA", &["gregory", "islamic-umalqura", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("SA", &["islamic-umalqura", "gregory", "islamic", "islamic-rgsa"]),
+    ("SD", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("SG", &["gregory", "chinese"]),
+    ("SY", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("TD", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("TH", &["buddhist", "gregory"]),
+    ("TJ", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("TM", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("TN", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+    ("TR", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("TW", &["gregory", "roc", "chinese"]),
+    ("UZ", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("XK", &["gregory", "islamic-civil", "islamic-tbla"]),
+    ("YE", &["gregory", "islamic", "islamic-civil", "islamic-tbla"]),
+];
+
+#[rustfmt::skip]
+pub static HOUR_CYCLES: [(&str, HourCycle); 273] = [
+    ("001", HourCycle::H23),
+    ("AC", HourCycle::H23),
+    ("AD", HourCycle::H23),
+    ("AE", HourCycle::H12),
+    ("AF", HourCycle::H23),
+    ("AG", HourCycle::H12),
+    ("AI", HourCycle::H23),
+    ("AL", HourCycle::H12),
+    ("AM", HourCycle::H23),
+    ("AO", HourCycle::H23),
+    ("AR", HourCycle::H23),
+    ("AS", HourCycle::H12),
+    ("AT", HourCycle::H23),
+    ("AU", HourCycle::H12),
+    ("AW", HourCycle::H23),
+    ("AX", HourCycle::H23),
+    ("AZ", HourCycle::H23),
+    ("BA", HourCycle::H23),
+    ("BB", HourCycle::H12),
+    ("BD", HourCycle::H12),
+    ("BE", HourCycle::H23),
+    ("BF", HourCycle::H23),
+    ("BG", HourCycle::H23),
+    ("BH", HourCycle::H12),
+    ("BI", HourCycle::H23),
+    ("BJ", HourCycle::H23),
+    ("BL", HourCycle::H23),
+    ("BM", HourCycle::H12),
+    ("BN", HourCycle::H12),
+    ("BO", HourCycle::H23),
+    ("BQ", HourCycle::H23),
+    ("BR", HourCycle::H23),
+    ("BS", HourCycle::H12),
+    ("BT", HourCycle::H12),
+    ("BW", HourCycle::H23),
+    ("BY", HourCycle::H23),
+    ("BZ", HourCycle::H23),
+    ("CA", HourCycle::H12),
+    ("CC", HourCycle::H23),
+    ("CD", HourCycle::H23),
+    ("CF", HourCycle::H23),
+    ("CG", HourCycle::H23),
+    ("CH", HourCycle::H23),
+    ("CI", HourCycle::H23),
+    ("CK", HourCycle::H23),
+    ("CL", HourCycle::H23),
+    ("CM", HourCycle::H23),
+    ("CN", HourCycle::H23),
+    ("CO", HourCycle::H12),
+    ("CP", HourCycle::H23),
+    ("CR", HourCycle::H23),
+    ("CU", HourCycle::H23),
+    ("CV", HourCycle::H23),
+    ("CW", HourCycle::H23),
+    ("CX", HourCycle::H23),
+    ("CY", HourCycle::H12),
+    ("CZ", HourCycle::H23),
+    ("DE", HourCycle::H23),
+    ("DG", HourCycle::H23),
+    ("DJ", HourCycle::H12),
+    ("DK", HourCycle::H23),
+    ("DM", HourCycle::H12),
+    ("DO", HourCycle::H12),
+    ("DZ", HourCycle::H12),
+    ("EA", HourCycle::H23),
+    ("EC", HourCycle::H23),
+    ("EE", HourCycle::H23),
+    ("EG", HourCycle::H12),
+    ("EH", HourCycle::H12),
+    ("ER", HourCycle::H12),
+    ("ES", HourCycle::H23),
+    ("ET", HourCycle::H12),
+    ("FI", HourCycle::H23),
+    ("FJ", HourCycle::H12),
+    ("FK", HourCycle::H23),
+    ("FM", HourCycle::H12),
+    ("FO", HourCycle::H23),
+    ("FR", HourCycle::H23),
+    ("GA", HourCycle::H23),
+    ("GB", HourCycle::H23),
+    ("GD", HourCycle::H12),
+    ("GE", HourCycle::H23),
+    ("GF", HourCycle::H23),
+    ("GG", HourCycle::H23),
+    ("GH", HourCycle::H12),
+    ("GI", HourCycle::H23),
+    ("GL", HourCycle::H23),
+    ("GM", HourCycle::H12),
+    ("GN", HourCycle::H23),
+    ("GP", HourCycle::H23),
+    ("GQ", HourCycle::H23),
+    ("GR", HourCycle::H12),
+    ("GT", HourCycle::H23),
+    ("GU", HourCycle::H12),
+    ("GW", HourCycle::H23),
+    ("GY", HourCycle::H12),
+    ("HK", HourCycle::H12),
+    ("HN", HourCycle::H23),
+    ("HR", HourCycle::H23),
+    ("HU", HourCycle::H23),
+    ("IC", HourCycle::H23),
+    ("ID", HourCycle::H23),
+    ("IE", HourCycle::H23),
+    ("IL", HourCycle::H23),
+    ("IM", HourCycle::H23),
+    ("IN", HourCycle::H12),
+    ("IO", HourCycle::H23),
+    ("IQ", HourCycle::H12),
+    ("IR", HourCycle::H23),
+    ("IS", HourCycle::H23),
+    ("IT", HourCycle::H23),
+    ("JE", HourCycle::H23),
+    ("JM", HourCycle::H12),
+    ("JO", HourCycle::H12),
+    ("JP", HourCycle::H23),
+    ("KE", HourCycle::H23),
+    ("KG", HourCycle::H23),
+    ("KH", HourCycle::H12),
+    ("KI", HourCycle::H12),
+    ("KM", HourCycle::H23),
+    ("KN", HourCycle::H12),
+    ("KP", HourCycle::H12),
+    ("KR", HourCycle::H12),
+    ("KW", HourCycle::H12),
+    ("KY", HourCycle::H12),
+    ("KZ", HourCycle::H23),
+    ("LA", HourCycle::H23),
+    ("LB", HourCycle::H12),
+    ("LC", HourCycle::H12),
+    ("LI", HourCycle::H23),
+    ("LK", HourCycle::H23),
+    ("LR", HourCycle::H12),
+    ("LS", HourCycle::H12),
+    ("LT", HourCycle::H23),
+    ("LU", HourCycle::H23),
+    ("LV", HourCycle::H23),
+    ("LY", HourCycle::H12),
+    ("MA", HourCycle::H23),
+    ("MC", HourCycle::H23),
+    ("MD", HourCycle::H23),
+    ("ME", HourCycle::H23),
+    ("MF", HourCycle::H23),
+    ("MG", HourCycle::H23),
+    ("MH", HourCycle::H12),
+    ("MK", HourCycle::H23),
+    ("ML", HourCycle::H23),
+    ("MM", HourCycle::H23),
+    ("MN", HourCycle::H23),
+    ("MO", HourCycle::H12),
+    ("MP", HourCycle::H12),
+    ("MQ", HourCycle::H23),
+    ("MR", HourCycle::H12),
+    ("MS", HourCycle::H23),
+    ("MT", HourCycle::H23),
+    ("MU", HourCycle::H23),
+    ("MV", HourCycle::H23),
+    ("MW", HourCycle::H12),
+    ("MX", HourCycle::H23),
+    ("MY", HourCycle::H12),
+    ("MZ", HourCycle::H23),
+    ("NA", HourCycle::H12),
+    ("NC", HourCycle::H23),
+    ("NE", HourCycle::H23),
+    ("NF", HourCycle::H23),
+    ("NG", HourCycle::H23),
+    ("NI", HourCycle::H23),
+    ("NL", HourCycle::H23),
+    ("NO", HourCycle::H23),
+    ("NP", HourCycle::H23),
+    ("NR", HourCycle::H23),
+    ("NU", HourCycle::H23),
+    ("NZ", HourCycle::H12),
+    ("OM", HourCycle::H12),
+    ("PA", HourCycle::H12),
+    ("PE", HourCycle::H23),
+    ("PF", HourCycle::H23),
+    ("PG", HourCycle::H12),
+    ("PH", HourCycle::H12),
+    ("PK", HourCycle::H12),
+    ("PL", HourCycle::H23),
+    ("PM", HourCycle::H23),
+    ("PN", HourCycle::H23),
+    ("PR", HourCycle::H12),
+    ("PS", HourCycle::H12),
+    ("PT", HourCycle::H23),
+    ("PW", HourCycle::H12),
+    ("PY", HourCycle::H23),
+    ("QA", HourCycle::H12),
+    ("RE", HourCycle::H23),
+    ("RO", HourCycle::H23),
+    ("RS", HourCycle::H23),
+    ("RU", HourCycle::H23),
+    ("RW", HourCycle::H23),
+    ("SA", HourCycle::H12),
+    ("SB", HourCycle::H12),
+    ("SC", HourCycle::H23),
+    ("SD", HourCycle::H12),
+    ("SE", HourCycle::H23),
+    ("SG", HourCycle::H12),
+    ("SH", HourCycle::H23),
+    ("SI", HourCycle::H23),
+    ("SJ", HourCycle::H23),
+    ("SK", HourCycle::H23),
+    ("SL", HourCycle::H12),
+    ("SM", HourCycle::H23),
+    ("SN", HourCycle::H23),
+    ("SO", HourCycle::H12),
+    ("SR", HourCycle::H23),
+    ("SS", HourCycle::H12),
+    ("ST", HourCycle::H23),
+    ("SV", HourCycle::H23),
+    ("SX", HourCycle::H23),
+    ("SY", HourCycle::H12),
+    ("SZ", HourCycle::H12),
+    ("TA", HourCycle::H23),
+    ("TC", HourCycle::H12),
+    ("TD", HourCycle::H12),
+    ("TF", HourCycle::H23),
+    ("TG", HourCycle::H23),
+    ("TH", HourCycle::H23),
+    ("TJ", HourCycle::H23),
+    ("TL", HourCycle::H23),
+    ("TM", HourCycle::H23),
+    ("TN", HourCycle::H12),
+    ("TO", HourCycle::H12),
+    ("TR", HourCycle::H23),
+    ("TT", HourCycle::H12),
+    ("TW", HourCycle::H12),
+    ("TZ", HourCycle::H23),
+    ("UA", HourCycle::H23),
+    ("UG", HourCycle::H23),
+    ("UM", HourCycle::H12),
+    ("US", HourCycle::H12),
+    ("UY", HourCycle::H23),
+    ("UZ", HourCycle::H23),
+    ("VA", HourCycle::H23),
+    ("VC", HourCycle::H12),
+    ("VE", HourCycle::H12),
+    ("VG", HourCycle::H12),
+    ("VI", HourCycle::H12),
+    ("VN", HourCycle::H23),
+    ("VU", HourCycle::H12),
+    ("WF", HourCycle::H23),
+    ("WS", HourCycle::H12),
+    ("XK", HourCycle::H23),
+    ("YE", HourCycle::H12),
+    ("YT", HourCycle::H23),
+    ("ZA", HourCycle::H23),
+    ("ZM", HourCycle::H12),
+    ("ZW", HourCycle::H23),
+    ("af-ZA", HourCycle::H23),
+    ("ar-001", HourCycle::H12),
+    ("ca-ES", HourCycle::H23),
+    ("en-001", HourCycle::H12),
+    ("es-BO", HourCycle::H23),
+    ("es-BR", HourCycle::H23),
+    ("es-EC", HourCycle::H23),
+    ("es-ES", HourCycle::H23),
+    ("es-GQ", HourCycle::H23),
+    ("es-PE", HourCycle::H23),
+    ("fr-CA", HourCycle::H23),
+    ("gl-ES", HourCycle::H23),
+    ("gu-IN", HourCycle::H12),
+    ("hi-IN", HourCycle::H12),
+    ("it-CH", HourCycle::H23),
+    ("it-IT", HourCycle::H23),
+    ("kn-IN", HourCycle::H12),
+    ("ml-IN", HourCycle::H12),
+    ("mr-IN", HourCycle::H12),
+    ("pa-IN", HourCycle::H12),
+    ("ta-IN", HourCycle::H12),
+    ("te-IN", HourCycle::H12),
+    ("zu-ZA", HourCycle::H23),
+];
+
+#[rustfmt::skip]
+pub static WEEK_DATA: [(&str, u8, u8, u8, u8); 116] = [
+    ("001", 1, 6, 7, 1),
+    ("AD", 1, 6, 7, 4),
+    ("AE", 6, 6, 7, 1),
+    ("AF", 6, 4, 5, 1),
+    ("AG", 7, 6, 7, 1),
+    ("AN", 1, 6, 7, 4),
+    ("AS", 7, 6, 7, 1),
+    ("AT", 1, 6, 7, 4),
+    ("AX", 1, 6, 7, 4),
+    ("BD", 7, 6, 7, 1),
+    ("BE", 1, 6, 7, 4),
+    ("BG", 1, 6, 7, 4),
+    ("BH", 6, 5, 6, 1),
+    ("BR", 7, 6, 7, 1),
+    ("BS", 7, 6, 7, 1),
+    ("BT", 7, 6, 7, 1),
+    ("BW", 7, 6, 7, 1),
+    ("BZ", 7, 6, 7, 1),
+    ("CA", 7, 6, 7, 1),
+    ("CH", 1, 6, 7, 4),
+    ("CO", 7, 6, 7, 1),
+    ("CZ", 1, 6, 7, 4),
+    ("DE", 1, 6, 7, 4),
+    ("DJ", 6, 6, 7, 1),
+    ("DK", 1, 6, 7, 4),
+    ("DM", 7, 6, 7, 1),
+    ("DO", 7, 6, 7, 1),
+    ("DZ", 6, 5, 6, 1),
+    ("EE", 1, 6, 7, 4),
+    ("EG", 6, 5, 6, 1),
+    ("ES", 1, 6, 7, 4),
+    ("ET", 7, 6, 7, 1),
+    ("FI", 1, 6, 7, 4),
+    ("FJ", 1, 6, 7, 4),
+    ("FO", 1, 6, 7, 4),
+    ("FR", 1, 6, 7, 4),
+    ("GB", 1, 6, 7, 4),
+    ("GF", 1, 6, 7, 4),
+    ("GG", 1, 6, 7, 4),
+    ("GI", 1, 6, 7, 4),
+    ("GP", 1, 6, 7, 4),
+    ("GR", 1, 6, 7, 4),
+    ("GT", 7, 6, 7, 1),
+    ("GU", 7, 6, 7, 1),
+    ("HK", 7, 6, 7, 1),
+    ("HN", 7, 6, 7, 1),
+    ("HU", 1, 6, 7, 4),
+    ("ID", 7, 6, 7, 1),
+    ("IE", 1, 6, 7, 4),
+    ("IL", 7, 5, 6, 1),
+    ("IM", 1, 6, 7, 4),
+    ("IN", 7, 7, 7, 1),
+    ("IQ", 6, 5, 6, 1),
+    ("IR", 6, 5, 5, 1),
+    ("IS", 1, 6, 7, 4),
+    ("IT", 1, 6, 7, 4),
+    ("JE", 1, 6, 7, 4),
+    ("JM", 7, 6, 7, 1),
+    ("JO", 6, 5, 6, 1),
+    ("JP", 7, 6, 7, 1),
+    ("KE", 7, 6, 7, 1),
+    ("KH", 7, 6, 7, 1),
+    ("KR", 7, 6, 7, 1),
+    ("KW", 6, 5, 6, 1),
+    ("LA", 7, 6, 7, 1),
+    ("LI", 1, 6, 7, 4),
+    ("LT", 1, 6, 7, 4),
+    ("LU", 1, 6, 7, 4),
+    ("LY", 6, 5, 6, 1),
+    ("MC", 1, 6, 7, 4),
+    ("MH", 7, 6, 7, 1),
+    ("MM", 7, 6, 7, 1),
+    ("MO", 7, 6, 7, 1),
+    ("MQ", 1, 6, 7, 4),
+    ("MT", 7, 6, 7, 1),
+    ("MV", 5, 6, 7, 1),
+    ("MX", 7, 6, 7, 1),
+    ("MZ", 7, 6, 7, 1),
+    ("NI", 7, 6, 7, 1),
+    ("NL", 1, 6, 7, 4),
+    ("NO", 1, 6, 7, 4),
+    ("NP", 7, 6, 7, 1),
+    ("OM", 6, 5, 6, 1),
+    ("PA", 7, 6, 7, 1),
+    ("PE", 7, 6, 7, 1),
+    ("PH", 7, 6, 7, 1),
+    ("PK", 7, 6, 7, 1),
+    ("PL", 1, 6, 7, 4),
+    ("PR", 7, 6, 7, 1),
+    ("PT", 7, 6, 7, 4),
+    ("PY", 7, 6, 7, 1),
+    ("QA", 6, 5, 6, 1),
+    ("RE", 1, 6, 7, 4),
+    ("RU", 1, 6, 7, 4),
+    ("SA", 7, 5, 6, 1),
+    ("SD", 6, 5, 6, 1),
+    ("SE", 1, 6, 7, 4),
+    ("SG", 7, 6, 7, 1),
+    ("SJ", 1, 6, 7, 4),
+    ("SK", 1, 6, 7, 4),
+    ("SM", 1, 6, 7, 4),
+    ("SV", 7, 6, 7, 1),
+    ("SY", 6, 5, 6, 1),
+    ("TH", 7, 6, 7, 1),
+    ("TT", 7, 6, 7, 1),
+    ("TW", 7, 6, 7, 1),
+    ("UG", 1, 7, 7, 1),
+    ("UM", 7, 6, 7, 1),
+    ("US", 7, 6, 7, 1),
+    ("VA", 1, 6, 7, 4),
+    ("VE", 7, 6, 7, 1),
+    ("VI", 7, 6, 7, 1),
+    ("WS", 7, 6, 7, 1),
+    ("YE", 7, 5, 6, 1),
+    ("ZA", 7, 6, 7, 1),
+    ("ZW", 7, 6, 7, 1),
+];
@@ -0,0 +1,292 @@
+use crate::parser::ParserError;
+use crate::{subtags, LanguageIdentifier};
+use alloc::string::ToString;
+use core::fmt::{self, Display, Write};
+
+const LANGUAGE_SHIFT: u32 = 64;
+const SCRIPT_SHIFT: u32 = 32;
+
+/// `PackedLanguageIdentifier` is a `LanguageIdentifier` without variants
+/// packed into a single `u128`.
+///
+/// Unlike `LanguageIdentifier` it doesn't hold a heap allocation, so it is
+/// `Copy` and cheap to hash and compare, which makes it a good key for
+/// maps and caches.
+///
+/// The language, script and region subtags are stored in the top 64, the
+/// next 32 and the bottom 32 bits, each as its ASCII bytes in big-endian
+/// order padded with zeros, and a missing subtag as `0`. Ordering packed
+/// identifiers is therefore the same as ordering their `LanguageIdentifier`s.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{LanguageIdentifier, PackedLanguageIdentifier};
+/// use std::collections::HashMap;
+///
+/// let langid: LanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+/// let packed = PackedLanguageIdentifier::try_from(langid.clone())
+///     .expect("Packing failed.");
+///
+/// let mut cache = HashMap::new();
+/// cache.insert(packed, "Hello");
+///
+/// assert_eq!(cache.get(&packed), Some(&"Hello"));
+/// assert_eq!(LanguageIdentifier::from(packed), langid);
+/// ```
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct PackedLanguageIdentifier(u128);
+
+/// Error returned when a `LanguageIdentifier` can't be packed into a
+/// `PackedLanguageIdentifier`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::{LanguageIdentifier, PackError, PackedLanguageIdentifier};
+///
+/// let langid: LanguageIdentifier = "ca-ES-valencia".parse().expect("Parsing failed.");
+///
+/// assert_eq!(
+///     PackedLanguageIdentifier::try_from(&langid),
+///     Err(PackError::Variant("valencia".parse().expect("Parsing failed.")))
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PackError {
+    /// The identifier has variants, this is the first of them.
+    Variant(subtags::Variant),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PackError {}
+
+impl Display for PackError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PackError::Variant(variant) => {
+                write!(f, "The variant subtag {} can't be packed", variant)
+            }
+        }
+    }
+}
+
+impl PackedLanguageIdentifier {
+    /// A constructor which takes the subtags of a `LanguageIdentifier`
+    /// without variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::PackedLanguageIdentifier;
+    ///
+    /// let packed = PackedLanguageIdentifier::from_parts(
+    ///     "fr".parse().expect("Parsing failed."),
+    ///     None,
+    ///     Some("CA".parse().expect("Parsing failed.")),
+    /// );
+    ///
+    /// assert_eq!(packed.to_string(), "fr-CA");
+    /// ```
+    pub fn from_parts(
+        language: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Self {
+        let language = Option::<u64>::from(language).map_or(0, u64::swap_bytes);
+        let script = script.map_or(0, |s| u32::from(s).swap_bytes());
+        let region = region.map_or(0, |r| u32::from(r).swap_bytes());
+        Self(
+            u128::from(language) << LANGUAGE_SHIFT
+                | u128::from(script) << SCRIPT_SHIFT
+                | u128::from(region),
+        )
+    }
+
+    /// A constructor which takes a `u128` produced by
+    /// [`into_raw`](Self::into_raw) and validates it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::PackedLanguageIdentifier;
+    ///
+    /// let packed: PackedLanguageIdentifier = "sr-Latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let raw = packed.into_raw();
+    /// assert_eq!(PackedLanguageIdentifier::try_from_raw(raw), Ok(packed));
+    ///
+    /// assert!(PackedLanguageIdentifier::try_from_raw(raw + 1).is_err());
+    /// ```
+    pub fn try_from_raw(v: u128) -> Result<Self, ParserError> {
+        fn subtag<T>(
+            bytes: &[u8],
+            parse: impl Fn(&[u8]) -> Result<T, ParserError>,
+        ) -> Result<Option<T>, ParserError> {
+            let length = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            if length == 0 {
+                Ok(None)
+            } else {
+                parse(&bytes[..length]).map(Some)
+            }
+        }
+
+        let language = subtag(
+            &((v >> LANGUAGE_SHIFT) as u64).to_be_bytes(),
+            subtags::Language::from_bytes,
+        )?;
+        let script = subtag(
+            &((v >> SCRIPT_SHIFT) as u32).to_be_bytes(),
+            subtags::Script::from_bytes,
+        )?;
+        let region = subtag(&(v as u32).to_be_bytes(), subtags::Region::from_bytes)?;
+
+        let result = Self::from_parts(language.unwrap_or_default(), script, region);
+        // Reject values which aren't canonical, such as subtags in
+        // the wrong case or followed by garbage.
+        if result.0 != v {
+            return Err(ParserError::InvalidSubtag);
+        }
+        Ok(result)
+    }
+
+    /// Returns the `u128` representation of the `PackedLanguageIdentifier`.
+    pub const fn into_raw(self) -> u128 {
+        self.0
+    }
+
+    /// Returns the language subtag.
+    pub fn language(self) -> subtags::Language {
+        match (self.0 >> LANGUAGE_SHIFT) as u64 {
+            0 => subtags::Language::default(),
+            // safe because only valid subtags get packed.
+            raw => unsafe { subtags::Language::from_raw_unchecked(raw.swap_bytes()) },
+        }
+    }
+
+    /// Returns the script subtag, if there is one.
+    pub fn script(self) -> Option<subtags::Script> {
+        match (self.0 >> SCRIPT_SHIFT) as u32 {
+            0 => None,
+            // safe because only valid subtags get packed.
+            raw => Some(unsafe { subtags::Script::from_raw_unchecked(raw.swap_bytes()) }),
+        }
+    }
+
+    /// Returns the region subtag, if there is one.
+    pub fn region(self) -> Option<subtags::Region> {
+        match self.0 as u32 {
+            0 => None,
+            // safe because only valid subtags get packed.
+            raw => Some(unsafe { subtags::Region::from_raw_unchecked(raw.swap_bytes()) }),
+        }
+    }
+
+    /// Extends the `PackedLanguageIdentifier` with likely subtags, the same
+    /// way as [`LanguageIdentifier::maximize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::PackedLanguageIdentifier;
+    ///
+    /// let mut packed: PackedLanguageIdentifier = "en".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(packed.maximize(), true);
+    /// assert_eq!(packed.to_string(), "en-Latn-US");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn maximize(&mut self) -> bool {
+        if let Some(new_li) =
+            crate::likelysubtags::maximize(self.language(), self.script(), self.region())
+        {
+            *self = Self::from_parts(new_li.0, new_li.1, new_li.2);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes likely subtags from the `PackedLanguageIdentifier`, the same
+    /// way as [`LanguageIdentifier::minimize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::PackedLanguageIdentifier;
+    ///
+    /// let mut packed: PackedLanguageIdentifier = "zh-Hant-TW".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(packed.minimize(), true);
+    /// assert_eq!(packed.to_string(), "zh-TW");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize(&mut self) -> bool {
+        if let Some(new_li) =
+            crate::likelysubtags::minimize(self.language(), self.script(), self.region())
+        {
+            *self = Self::from_parts(new_li.0, new_li.1, new_li.2);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl TryFrom<LanguageIdentifier> for PackedLanguageIdentifier {
+    type Error = PackError;
+
+    fn try_from(input: LanguageIdentifier) -> Result<Self, Self::Error> {
+        Self::try_from(&input)
+    }
+}
+
+impl TryFrom<&LanguageIdentifier> for PackedLanguageIdentifier {
+    type Error = PackError;
+
+    fn try_from(input: &LanguageIdentifier) -> Result<Self, Self::Error> {
+        if let Some(variant) = input.variants().next() {
+            return Err(PackError::Variant(*variant));
+        }
+        Ok(Self::from_parts(input.language, input.script, input.region))
+    }
+}
+
+impl From<PackedLanguageIdentifier> for LanguageIdentifier {
+    fn from(input: PackedLanguageIdentifier) -> Self {
+        LanguageIdentifier::from_parts(input.language(), input.script(), input.region(), &[])
+    }
+}
+
+impl core::str::FromStr for PackedLanguageIdentifier {
+    type Err = crate::LanguageIdentifierError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        let langid: LanguageIdentifier = source.parse()?;
+        Ok(Self::try_from(&langid)?)
+    }
+}
+
+impl core::fmt::Display for PackedLanguageIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.language().fmt(f)?;
+        if let Some(script) = self.script() {
+            f.write_char('-')?;
+            script.fmt(f)?;
+        }
+        if let Some(region) = self.region() {
+            f.write_char('-')?;
+            region.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq<&str> for PackedLanguageIdentifier {
+    fn eq(&self, other: &&str) -> bool {
+        self.to_string().as_str() == *other
+    }
+}
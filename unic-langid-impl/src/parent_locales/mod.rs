@@ -0,0 +1,113 @@
+//! Parent locales, from the CLDR `parentLocales` data.
+//!
+//! Locale data is inherited from a parent locale, usually found by
+//! removing the last subtag, like `de` for `de-CH`. CLDR lists the
+//! exceptions, like `en-001` for `en-AU` or `und` for `az-Arab`, which
+//! [`LanguageIdentifier::parent`] takes into account.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let li: LanguageIdentifier = "en-AU".parse().expect("Parsing failed.");
+//! let parent = li.parent().expect("Missing parent.");
+//! assert_eq!(parent, "en-001");
+//! assert_eq!(parent.parent().expect("Missing parent."), "en");
+//!
+//! let li: LanguageIdentifier = "az-Arab-IR".parse().expect("Parsing failed.");
+//! let parent = li.parent().expect("Missing parent.");
+//! assert_eq!(parent, "az-Arab");
+//! assert_eq!(parent.parent().expect("Missing parent."), "und");
+//! ```
+//!
+//! # Data
+//!
+//! The table is generated by `unic-datagen` from the `cldr-core` package,
+//! and can be limited to some languages, scripts and regions with its
+//! `--locales`, `--scripts` and `--regions` options. Locales left out of a
+//! subset fall back by removing their last subtag.
+#[allow(clippy::type_complexity)]
+mod tables;
+
+use crate::subtags::{Language, Region, Script};
+use crate::LanguageIdentifier;
+
+pub use tables::CLDR_VERSION;
+
+fn raw(language: Language, script: Option<Script>, region: Option<Region>) -> (u64, u32, u32) {
+    (
+        Option::<u64>::from(language).unwrap_or(0),
+        script.map_or(0, u32::from),
+        region.map_or(0, u32::from),
+    )
+}
+
+fn from_raw((language, script, region): (u64, u32, u32)) -> LanguageIdentifier {
+    // safe because all table entries are well formed.
+    let language = match language {
+        0 => Language::default(),
+        language => unsafe { Language::from_raw_unchecked(language) },
+    };
+    let script = (script != 0).then(|| unsafe { Script::from_raw_unchecked(script) });
+    let region = (region != 0).then(|| unsafe { Region::from_raw_unchecked(region) });
+    LanguageIdentifier::from_parts(language, script, region, &[])
+}
+
+impl LanguageIdentifier {
+    /// Returns the locale the `LanguageIdentifier` inherits its locale data
+    /// from, or `None` for `und`.
+    ///
+    /// The last variant is removed first, then the parent listed by CLDR
+    /// is returned if there is one, or else the region, the script and the
+    /// language are removed in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "es-AR".parse().expect("Parsing failed.");
+    /// assert_eq!(li.parent().expect("Missing parent."), "es-419");
+    ///
+    /// let li: LanguageIdentifier = "ca-ES-valencia".parse().expect("Parsing failed.");
+    /// assert_eq!(li.parent().expect("Missing parent."), "ca-ES");
+    ///
+    /// assert_eq!(LanguageIdentifier::default().parent(), None);
+    /// ```
+    pub fn parent(&self) -> Option<LanguageIdentifier> {
+        if let Some((_, rest)) = self.variants.as_deref().and_then(<[_]>::split_last) {
+            return Some(LanguageIdentifier::from_parts(
+                self.language,
+                self.script,
+                self.region,
+                rest,
+            ));
+        }
+
+        let key = raw(self.language, self.script, self.region);
+        if let Ok(idx) = tables::PARENTS.binary_search_by_key(&key, |(k, _)| *k) {
+            return Some(from_raw(tables::PARENTS[idx].1));
+        }
+
+        if self.region.is_some() {
+            Some(LanguageIdentifier::from_parts(
+                self.language,
+                self.script,
+                None,
+                &[],
+            ))
+        } else if self.script.is_some() {
+            Some(LanguageIdentifier::from_parts(
+                self.language,
+                None,
+                None,
+                &[],
+            ))
+        } else if !self.language.is_empty() {
+            Some(LanguageIdentifier::default())
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static PARENTS: [((u64, u32, u32), (u64, u32, u32)); 175] = [
+    ((24936, 1650553409, 0), (0, 0, 0)), // ha-Arab -> und
+    ((24944, 1650553409, 0), (0, 0, 0)), // pa-Arab -> und
+    ((25198, 0, 0), (28526, 0, 0)), // nb -> no
+    ((25715, 1635149124, 0), (0, 0, 0)), // sd-Deva -> und
+    ((25715, 1684957523, 0), (0, 0, 0)), // sd-Sind -> und
+    ((25715, 1785686091, 0), (0, 0, 0)), // sd-Khoj -> und
+    ((26214, 1650553409, 0), (0, 0, 0)), // ff-Arab -> und
+    ((26214, 1835820097, 0), (0, 0, 0)), // ff-Adlm -> und
+    ((26484, 1650553409, 0), (0, 0, 0)), // tg-Arab -> und
+    ((26485, 1819441475, 0), (0, 0, 0)), // ug-Cyrl -> und
+    ((26746, 1953390920, 0), (0, 0, 0)), // zh-Hant -> und
+    ((26746, 1953390920, 20301), (26746, 1953390920, 19272)), // zh-Hant-MO -> zh-Hant-HK
+    ((26984, 1853120844, 0), (28261, 0, 20041)), // hi-Latn -> en-IN
+    ((27499, 1650553409, 0), (0, 0, 0)), // kk-Arab -> und
+    ((27757, 1650553409, 0), (0, 0, 0)), // ml-Arab -> und
+    ((28002, 1869572942, 0), (0, 0, 0)), // bm-Nkoo -> und
+    ((28261, 0, 16718), (28261, 0, 3223600)), // en-NA -> en-001
+    ((28261, 0, 16730), (28261, 0, 3223600)), // en-ZA -> en-001
+    ((28261, 0, 16962), (28261, 0, 3223600)), // en-BB -> en-001
+    ((28261, 0, 16967), (28261, 0, 3223600)), // en-GB -> en-001
+    ((28261, 0, 16979), (28261, 0, 3223600)), // en-SB -> en-001
+    ((28261, 0, 17219), (28261, 0, 3223600)), // en-CC -> en-001
+    ((28261, 0, 17228), (28261, 0, 3223600)), // en-LC -> en-001
+    ((28261, 0, 17235), (28261, 0, 3223600)), // en-SC -> en-001
+    ((28261, 0, 17236), (28261, 0, 3223600)), // en-TC -> en-001
+    ((28261, 0, 17238), (28261, 0, 3223600)), // en-VC -> en-001
+    ((28261, 0, 17479), (28261, 0, 3223600)), // en-GD -> en-001
+    ((28261, 0, 17491), (28261, 0, 3223600)), // en-SD -> en-001
+    ((28261, 0, 17730), (28261, 0, 3159345)), // en-BE -> en-150
+    ((28261, 0, 17732), (28261, 0, 3159345)), // en-DE -> en-150
+    ((28261, 0, 17737), (28261, 0, 3223600)), // en-IE -> en-001
+    ((28261, 0, 17738), (28261, 0, 3223600)), // en-JE -> en-001
+    ((28261, 0, 17739), (28261, 0, 3223600)), // en-KE -> en-001
+    ((28261, 0, 17747), (28261, 0, 3159345)), // en-SE -> en-150
+    ((28261, 0, 17998), (28261, 0, 3223600)), // en-NF -> en-001
+    ((28261, 0, 18241), (28261, 0, 3223600)), // en-AG -> en-001
+    ((28261, 0, 18244), (28261, 0, 3223600)), // en-DG -> en-001
+    ((28261, 0, 18247), (28261, 0, 3223600)), // en-GG -> en-001
+    ((28261, 0, 18253), (28261, 0, 3223600)), // en-MG -> en-001
+    ((28261, 0, 18254), (28261, 0, 3223600)), // en-NG -> en-001
+    ((28261, 0, 18256), (28261, 0, 3223600)), // en-PG -> en-001
+    ((28261, 0, 18259), (28261, 0, 3223600)), // en-SG -> en-001
+    ((28261, 0, 18261), (28261, 0, 3223600)), // en-UG -> en-001
+    ((28261, 0, 18262), (28261, 0, 3223600)), // en-VG -> en-001
+    ((28261, 0, 18499), (28261, 0, 3159345)), // en-CH -> en-150
+    ((28261, 0, 18503), (28261, 0, 3223600)), // en-GH -> en-001
+    ((28261, 0, 18515), (28261, 0, 3223600)), // en-SH -> en-001
+    ((28261, 0, 18753), (28261, 0, 3223600)), // en-AI -> en-001
+    ((28261, 0, 18758), (28261, 0, 3159345)), // en-FI -> en-150
+    ((28261, 0, 18759), (28261, 0, 3223600)), // en-GI -> en-001
+    ((28261, 0, 18763), (28261, 0, 3223600)), // en-KI -> en-001
+    ((28261, 0, 18771), (28261, 0, 3159345)), // en-SI -> en-150
+    ((28261, 0, 19014), (28261, 0, 3223600)), // en-FJ -> en-001
+    ((28261, 0, 19267), (28261, 0, 3223600)), // en-CK -> en-001
+    ((28261, 0, 19268), (28261, 0, 3159345)), // en-DK -> en-150
+    ((28261, 0, 19270), (28261, 0, 3223600)), // en-FK -> en-001
+    ((28261, 0, 19272), (28261, 0, 3223600)), // en-HK -> en-001
+    ((28261, 0, 19280), (28261, 0, 3223600)), // en-PK -> en-001
+    ((28261, 0, 19284), (28261, 0, 3223600)), // en-TK -> en-001
+    ((28261, 0, 19529), (28261, 0, 3223600)), // en-IL -> en-001
+    ((28261, 0, 19534), (28261, 0, 3159345)), // en-NL -> en-150
+    ((28261, 0, 19539), (28261, 0, 3223600)), // en-SL -> en-001
+    ((28261, 0, 19778), (28261, 0, 3223600)), // en-BM -> en-001
+    ((28261, 0, 19779), (28261, 0, 3223600)), // en-CM -> en-001
+    ((28261, 0, 19780), (28261, 0, 3223600)), // en-DM -> en-001
+    ((28261, 0, 19782), (28261, 0, 3223600)), // en-FM -> en-001
+    ((28261, 0, 19783), (28261, 0, 3223600)), // en-GM -> en-001
+    ((28261, 0, 19785), (28261, 0, 3223600)), // en-IM -> en-001
+    ((28261, 0, 19786), (28261, 0, 3223600)), // en-JM -> en-001
+    ((28261, 0, 19802), (28261, 0, 3223600)), // en-ZM -> en-001
+    ((28261, 0, 20041), (28261, 0, 3223600)), // en-IN -> en-001
+    ((28261, 0, 20043), (28261, 0, 3223600)), // en-KN -> en-001
+    ((28261, 0, 20048), (28261, 0, 3223600)), // en-PN -> en-001
+    ((28261, 0, 20297), (28261, 0, 3223600)), // en-IO -> en-001
+    ((28261, 0, 20301), (28261, 0, 3223600)), // en-MO -> en-001
+    ((28261, 0, 20308), (28261, 0, 3223600)), // en-TO -> en-001
+    ((28261, 0, 21061), (28261, 0, 3223600)), // en-ER -> en-001
+    ((28261, 0, 21068), (28261, 0, 3223600)), // en-LR -> en-001
+    ((28261, 0, 21070), (28261, 0, 3223600)), // en-NR -> en-001
+    ((28261, 0, 21314), (28261, 0, 3223600)), // en-BS -> en-001
+    ((28261, 0, 21324), (28261, 0, 3223600)), // en-LS -> en-001
+    ((28261, 0, 21325), (28261, 0, 3223600)), // en-MS -> en-001
+    ((28261, 0, 21331), (28261, 0, 3223600)), // en-SS -> en-001
+    ((28261, 0, 21335), (28261, 0, 3223600)), // en-WS -> en-001
+    ((28261, 0, 21569), (28261, 0, 3159345)), // en-AT -> en-150
+    ((28261, 0, 21581), (28261, 0, 3223600)), // en-MT -> en-001
+    ((28261, 0, 21588), (28261, 0, 3223600)), // en-TT -> en-001
+    ((28261, 0, 21825), (28261, 0, 3223600)), // en-AU -> en-001
+    ((28261, 0, 21837), (28261, 0, 3223600)), // en-MU -> en-001
+    ((28261, 0, 21838), (28261, 0, 3223600)), // en-NU -> en-001
+    ((28261, 0, 21846), (28261, 0, 3223600)), // en-VU -> en-001
+    ((28261, 0, 22093), (28261, 0, 3223600)), // en-MV -> en-001
+    ((28261, 0, 22100), (28261, 0, 3223600)), // en-TV -> en-001
+    ((28261, 0, 22338), (28261, 0, 3223600)), // en-BW -> en-001
+    ((28261, 0, 22349), (28261, 0, 3223600)), // en-MW -> en-001
+    ((28261, 0, 22352), (28261, 0, 3223600)), // en-PW -> en-001
+    ((28261, 0, 22354), (28261, 0, 3223600)), // en-RW -> en-001
+    ((28261, 0, 22362), (28261, 0, 3223600)), // en-ZW -> en-001
+    ((28261, 0, 22595), (28261, 0, 3223600)), // en-CX -> en-001
+    ((28261, 0, 22611), (28261, 0, 3223600)), // en-SX -> en-001
+    ((28261, 0, 22851), (28261, 0, 3223600)), // en-CY -> en-001
+    ((28261, 0, 22855), (28261, 0, 3223600)), // en-GY -> en-001
+    ((28261, 0, 22859), (28261, 0, 3223600)), // en-KY -> en-001
+    ((28261, 0, 22861), (28261, 0, 3223600)), // en-MY -> en-001
+    ((28261, 0, 23106), (28261, 0, 3223600)), // en-BZ -> en-001
+    ((28261, 0, 23118), (28261, 0, 3223600)), // en-NZ -> en-001
+    ((28261, 0, 23123), (28261, 0, 3223600)), // en-SZ -> en-001
+    ((28261, 0, 23124), (28261, 0, 3223600)), // en-TZ -> en-001
+    ((28261, 0, 3159345), (28261, 0, 3223600)), // en-150 -> en-001
+    ((28261, 1953657668, 0), (0, 0, 0)), // en-Dsrt -> und
+    ((28261, 2002872403, 0), (0, 0, 0)), // en-Shaw -> und
+    ((28269, 1735290701, 0), (0, 0, 0)), // mn-Mong -> und
+    ((28270, 0, 0), (28526, 0, 0)), // nn -> no
+    ((28526, 0, 20302), (28526, 0, 0)), // no-NO -> no
+    ((28531, 1650553409, 0), (0, 0, 0)), // so-Arab -> und
+    ((28535, 1650553409, 0), (0, 0, 0)), // wo-Arab -> und
+    ((28537, 1650553409, 0), (0, 0, 0)), // yo-Arab -> und
+    ((29299, 1853120844, 0), (0, 0, 0)), // sr-Latn -> und
+    ((29538, 1819441475, 0), (0, 0, 0)), // bs-Cyrl -> und
+    ((29541, 0, 16720), (29541, 0, 3748148)), // es-PA -> es-419
+    ((29541, 0, 17221), (29541, 0, 3748148)), // es-EC -> es-419
+    ((29541, 0, 17744), (29541, 0, 3748148)), // es-PE -> es-419
+    ((29541, 0, 17750), (29541, 0, 3748148)), // es-VE -> es-419
+    ((29541, 0, 18766), (29541, 0, 3748148)), // es-NI -> es-419
+    ((29541, 0, 19523), (29541, 0, 3748148)), // es-CL -> es-419
+    ((29541, 0, 20040), (29541, 0, 3748148)), // es-HN -> es-419
+    ((29541, 0, 20290), (29541, 0, 3748148)), // es-BO -> es-419
+    ((29541, 0, 20291), (29541, 0, 3748148)), // es-CO -> es-419
+    ((29541, 0, 20292), (29541, 0, 3748148)), // es-DO -> es-419
+    ((29541, 0, 21057), (29541, 0, 3748148)), // es-AR -> es-419
+    ((29541, 0, 21058), (29541, 0, 3748148)), // es-BR -> es-419
+    ((29541, 0, 21059), (29541, 0, 3748148)), // es-CR -> es-419
+    ((29541, 0, 21072), (29541, 0, 3748148)), // es-PR -> es-419
+    ((29541, 0, 21333), (29541, 0, 3748148)), // es-US -> es-419
+    ((29541, 0, 21575), (29541, 0, 3748148)), // es-GT -> es-419
+    ((29541, 0, 21827), (29541, 0, 3748148)), // es-CU -> es-419
+    ((29541, 0, 22099), (29541, 0, 3748148)), // es-SV -> es-419
+    ((29541, 0, 22605), (29541, 0, 3748148)), // es-MX -> es-419
+    ((29541, 0, 22864), (29541, 0, 3748148)), // es-PY -> es-419
+    ((29541, 0, 22869), (29541, 0, 3748148)), // es-UY -> es-419
+    ((29541, 0, 23106), (29541, 0, 3748148)), // es-BZ -> es-419
+    ((29547, 1635149124, 0), (0, 0, 0)), // ks-Deva -> und
+    ((29549, 1650553409, 0), (0, 0, 0)), // ms-Arab -> und
+    ((29800, 0, 0), (29286, 0, 21576)), // ht -> fr-HT
+    ((29808, 0, 18499), (29808, 0, 21584)), // pt-CH -> pt-PT
+    ((29808, 0, 19540), (29808, 0, 21584)), // pt-TL -> pt-PT
+    ((29808, 0, 20289), (29808, 0, 21584)), // pt-AO -> pt-PT
+    ((29808, 0, 20301), (29808, 0, 21584)), // pt-MO -> pt-PT
+    ((29808, 0, 20807), (29808, 0, 21584)), // pt-GQ -> pt-PT
+    ((29808, 0, 21062), (29808, 0, 21584)), // pt-FR -> pt-PT
+    ((29808, 0, 21587), (29808, 0, 21584)), // pt-ST -> pt-PT
+    ((29808, 0, 21836), (29808, 0, 21584)), // pt-LU -> pt-PT
+    ((29808, 0, 22083), (29808, 0, 21584)), // pt-CV -> pt-PT
+    ((29808, 0, 22343), (29808, 0, 21584)), // pt-GW -> pt-PT
+    ((29808, 0, 23117), (29808, 0, 21584)), // pt-MZ -> pt-PT
+    ((30051, 1734437959, 0), (0, 0, 0)), // cu-Glag -> und
+    ((30057, 1853120844, 0), (0, 0, 0)), // iu-Latn -> und
+    ((30059, 1650553409, 0), (0, 0, 0)), // ku-Arab -> und
+    ((30579, 1650553409, 0), (0, 0, 0)), // sw-Arab -> und
+    ((31083, 1650553409, 0), (0, 0, 0)), // ky-Arab -> und
+    ((31083, 1853120844, 0), (0, 0, 0)), // ky-Latn -> und
+    ((31329, 1650553409, 0), (0, 0, 0)), // az-Arab -> und
+    ((31329, 1819441475, 0), (0, 0, 0)), // az-Cyrl -> und
+    ((31349, 1650553409, 0), (0, 0, 0)), // uz-Arab -> und
+    ((31349, 1819441475, 0), (0, 0, 0)), // uz-Cyrl -> und
+    ((6646372, 1650553409, 0), (0, 0, 0)), // dje-Arab -> und
+    ((6649209, 1936613704, 0), (0, 0, 0)), // yue-Hans -> und
+    ((6906230, 1853120844, 0), (0, 0, 0)), // vai-Latn -> und
+    ((6908019, 1853120844, 0), (0, 0, 0)), // shi-Latn -> und
+    ((6909549, 1768256589, 0), (0, 0, 0)), // mni-Mtei -> und
+    ((7102818, 1853120844, 0), (0, 0, 0)), // bal-Latn -> und
+    ((7240034, 1853120844, 0), (0, 0, 0)), // byn-Latn -> und
+    ((7305572, 1650553409, 0), (0, 0, 0)), // dyo-Arab -> und
+    ((7627123, 1635149124, 0), (0, 0, 0)), // sat-Deva -> und
+    ((7629922, 1853120844, 0), (0, 0, 0)), // blt-Latn -> und
+];
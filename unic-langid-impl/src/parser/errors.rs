@@ -1,20 +1,120 @@
-use std::error::Error;
-use std::fmt::{self, Display};
+use core::fmt::{self, Display};
+use core::ops::Range;
+
+/// Kind of subtag the parser expected at a given position of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubtagKind {
+    Language,
+    Script,
+    Region,
+    Variant,
+    ExtensionSingleton,
+    UnicodeKey,
+    UnicodeType,
+    UnicodeAttribute,
+    TransformKey,
+    TransformValue,
+    Private,
+}
+
+impl Display for SubtagKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            SubtagKind::Language => "language",
+            SubtagKind::Script => "script",
+            SubtagKind::Region => "region",
+            SubtagKind::Variant => "variant",
+            SubtagKind::ExtensionSingleton => "extension singleton",
+            SubtagKind::UnicodeKey => "unicode extension key",
+            SubtagKind::UnicodeType => "unicode extension type",
+            SubtagKind::UnicodeAttribute => "unicode extension attribute",
+            SubtagKind::TransformKey => "transform extension key",
+            SubtagKind::TransformValue => "transform extension value",
+            SubtagKind::Private => "private use",
+        };
+        f.write_str(value)
+    }
+}
 
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParserError {
     InvalidLanguage,
     InvalidSubtag,
+    /// The subtag at `offset..offset + length` is not a well-formed
+    /// subtag of the `expected` kind.
+    MalformedSubtag {
+        expected: SubtagKind,
+        offset: usize,
+        length: usize,
+    },
+    /// The variant at `offset..offset + length` has already been
+    /// used earlier in the identifier.
+    DuplicateVariant {
+        offset: usize,
+        length: usize,
+    },
+    /// The binary encoded input is malformed at `offset`.
+    InvalidEncoding {
+        offset: usize,
+    },
+}
+
+impl ParserError {
+    /// Returns the byte range of the input the error points at, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::parser::parse_language_identifier;
+    ///
+    /// let input = "en-Latn-USA";
+    /// let err = parse_language_identifier(input.as_bytes())
+    ///     .expect_err("Parsing should fail.");
+    ///
+    /// assert_eq!(err.span(), Some(8..11));
+    /// assert_eq!(&input[err.span().unwrap()], "USA");
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            ParserError::InvalidLanguage
+            | ParserError::InvalidSubtag
+            | ParserError::InvalidEncoding { .. } => None,
+            ParserError::MalformedSubtag { offset, length, .. }
+            | ParserError::DuplicateVariant { offset, length } => Some(*offset..*offset + *length),
+        }
+    }
 }
 
-impl Error for ParserError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let value = match self {
-            ParserError::InvalidLanguage => "The given language subtag is invalid",
-            ParserError::InvalidSubtag => "Invalid subtag",
-        };
-        f.write_str(value)
+        match self {
+            ParserError::InvalidLanguage => f.write_str("The given language subtag is invalid"),
+            ParserError::InvalidSubtag => f.write_str("Invalid subtag"),
+            ParserError::MalformedSubtag {
+                expected,
+                offset,
+                length,
+            } => write!(
+                f,
+                "Invalid subtag at {}..{}, expected {}",
+                offset,
+                offset + length,
+                expected
+            ),
+            ParserError::DuplicateVariant { offset, length } => write!(
+                f,
+                "Duplicate variant subtag at {}..{}",
+                offset,
+                offset + length
+            ),
+            ParserError::InvalidEncoding { offset } => {
+                write!(f, "Invalid binary encoding at byte {}", offset)
+            }
+        }
     }
 }
@@ -1,17 +1,142 @@
 pub mod errors;
 
-use std::iter::Peekable;
-
-pub use self::errors::ParserError;
+pub use self::errors::{ParserError, SubtagKind};
 use crate::subtags;
 use crate::LanguageIdentifier;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An iterator over `-` or `_` separated subtags of a byte slice which
+/// keeps track of the byte offset of each subtag.
+///
+/// The offsets are used to report the position of the failing subtag
+/// in `ParserError`.
+#[derive(Debug, Clone)]
+pub struct SubtagIterator<'a> {
+    slice: &'a [u8],
+    ignored: &'a [usize],
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> SubtagIterator<'a> {
+    pub fn new(slice: &'a [u8]) -> Self {
+        Self::with_ignored(slice, &[])
+    }
+
+    /// Creates an iterator which skips over subtags starting at
+    /// any of the `ignored` byte offsets.
+    pub fn with_ignored(slice: &'a [u8], ignored: &'a [usize]) -> Self {
+        let mut iter = Self {
+            slice,
+            ignored,
+            offset: 0,
+            done: false,
+        };
+        iter.skip_ignored();
+        iter
+    }
+
+    /// Returns the byte offset of the subtag which will be returned
+    /// by the next call to `next` or `peek`.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn peek(&self) -> Option<&'a [u8]> {
+        if self.done {
+            None
+        } else {
+            Some(&self.slice[self.offset..self.subtag_end()])
+        }
+    }
+
+    fn subtag_end(&self) -> usize {
+        self.slice[self.offset..]
+            .iter()
+            .position(|c| *c == b'-' || *c == b'_')
+            .map_or(self.slice.len(), |idx| self.offset + idx)
+    }
+
+    fn advance(&mut self) -> Option<&'a [u8]> {
+        if self.done {
+            return None;
+        }
+        let end = self.subtag_end();
+        let subtag = &self.slice[self.offset..end];
+        if end == self.slice.len() {
+            self.done = true;
+        } else {
+            self.offset = end + 1;
+        }
+        Some(subtag)
+    }
+
+    fn skip_ignored(&mut self) {
+        while !self.done && self.ignored.contains(&self.offset) {
+            self.advance();
+        }
+    }
+}
+
+impl<'a> Iterator for SubtagIterator<'a> {
+    type Item = &'a [u8];
 
-pub fn parse_language_identifier_from_iter<'a>(
-    iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    fn next(&mut self) -> Option<Self::Item> {
+        let subtag = self.advance();
+        self.skip_ignored();
+        subtag
+    }
+}
+
+/// Splits the input at the first byte which can't be a part of any subtag
+/// or separator, such as the codeset in `en_US.UTF-8` or the modifier
+/// in `sr-Latn-RS-@latin`.
+///
+/// Returns the leading part without a trailing separator and the offset
+/// at which the rest of the input starts.
+pub fn split_trailing_junk(t: &[u8]) -> (&[u8], usize) {
+    match t
+        .iter()
+        .position(|c| !c.is_ascii_alphanumeric() && *c != b'-' && *c != b'_')
+    {
+        Some(idx) => {
+            let head = &t[..idx];
+            let head = match head.last() {
+                Some(b'-') | Some(b'_') => &head[..head.len() - 1],
+                _ => head,
+            };
+            (head, idx)
+        }
+        None => (t, t.len()),
+    }
+}
+
+/// Returns the kind of subtag which may follow the given `LanguageIdentifier`.
+///
+/// Used to report a subtag which follows a complete identifier and
+/// doesn't fit into it.
+pub fn expected_subtag_kind(id: &LanguageIdentifier) -> SubtagKind {
+    if id.region.is_some() || id.variants().len() > 0 {
+        SubtagKind::Variant
+    } else if id.script.is_some() {
+        SubtagKind::Region
+    } else {
+        SubtagKind::Script
+    }
+}
+
+pub fn parse_language_identifier_from_iter(
+    iter: &mut SubtagIterator,
     allow_extension: bool,
 ) -> Result<LanguageIdentifier, ParserError> {
+    let offset = iter.offset();
     let language = if let Some(subtag) = iter.next() {
-        subtags::Language::from_bytes(subtag)?
+        subtags::Language::from_bytes(subtag).map_err(|_| ParserError::MalformedSubtag {
+            expected: SubtagKind::Language,
+            offset,
+            length: subtag.len(),
+        })?
     } else {
         subtags::Language::default()
     };
@@ -49,6 +174,12 @@ pub fn parse_language_identifier_from_iter<'a>(
         } else {
             // Variants
             if let Ok(v) = subtags::Variant::from_bytes(subtag) {
+                if variants.contains(&v) {
+                    return Err(ParserError::DuplicateVariant {
+                        offset: iter.offset(),
+                        length: subtag.len(),
+                    });
+                }
                 variants.push(v);
             } else {
                 break;
@@ -57,15 +188,25 @@ pub fn parse_language_identifier_from_iter<'a>(
         iter.next();
     }
 
-    if !allow_extension && iter.peek().is_some() {
-        return Err(ParserError::InvalidSubtag);
+    if !allow_extension {
+        if let Some(subtag) = iter.peek() {
+            let expected = match position {
+                1 => SubtagKind::Script,
+                2 => SubtagKind::Region,
+                _ => SubtagKind::Variant,
+            };
+            return Err(ParserError::MalformedSubtag {
+                expected,
+                offset: iter.offset(),
+                length: subtag.len(),
+            });
+        }
     }
 
     let variants = if variants.is_empty() {
         None
     } else {
         variants.sort_unstable();
-        variants.dedup();
         Some(variants.into_boxed_slice())
     };
 
@@ -78,6 +219,70 @@ pub fn parse_language_identifier_from_iter<'a>(
 }
 
 pub fn parse_language_identifier(t: &[u8]) -> Result<LanguageIdentifier, ParserError> {
-    let mut iter = t.split(|c| *c == b'-' || *c == b'_').peekable();
+    let mut iter = SubtagIterator::new(t);
     parse_language_identifier_from_iter(&mut iter, false)
 }
+
+/// Parses a `LanguageIdentifier` recovering from malformed subtags.
+///
+/// The longest well-formed prefix of the input is kept and any subtag
+/// which doesn't fit into the identifier is dropped and reported.
+/// Extensions and trailing junk such as a POSIX codeset are dropped
+/// as a whole.
+pub fn parse_language_identifier_lenient(t: &[u8]) -> (LanguageIdentifier, Vec<ParserError>) {
+    let (mut input, junk) = split_trailing_junk(t);
+    let mut errors = vec![];
+    let mut ignored = vec![];
+
+    let langid = loop {
+        let mut iter = SubtagIterator::with_ignored(input, &ignored);
+        let err = match parse_language_identifier_from_iter(&mut iter, false) {
+            Ok(langid) => break langid,
+            Err(err) => err,
+        };
+        match err {
+            // Without a language there is no prefix to keep.
+            ParserError::MalformedSubtag {
+                expected: SubtagKind::Language,
+                ..
+            } => {
+                errors.push(err);
+                break LanguageIdentifier::default();
+            }
+            // An extension singleton. Drop the rest of the input.
+            ParserError::MalformedSubtag {
+                expected,
+                offset,
+                length: 1,
+            } => {
+                errors.push(ParserError::MalformedSubtag {
+                    expected,
+                    offset,
+                    length: input.len() - offset,
+                });
+                input = &input[..offset - 1];
+            }
+            ParserError::MalformedSubtag { offset, .. }
+            | ParserError::DuplicateVariant { offset, .. } => {
+                errors.push(err);
+                ignored.push(offset);
+            }
+            ParserError::InvalidLanguage
+            | ParserError::InvalidSubtag
+            | ParserError::InvalidEncoding { .. } => {
+                errors.push(err);
+                break LanguageIdentifier::default();
+            }
+        }
+    };
+
+    if junk < t.len() {
+        errors.push(ParserError::MalformedSubtag {
+            expected: expected_subtag_kind(&langid),
+            offset: junk,
+            length: t.len() - junk,
+        });
+    }
+
+    (langid, errors)
+}
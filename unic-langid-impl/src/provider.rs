@@ -0,0 +1,655 @@
+//! Locale data used by `maximize`, `minimize` and `character_direction`.
+//!
+//! Those methods of `LanguageIdentifier` use data baked into the crate at
+//! build time. Their `*_with` counterparts take any [`DataProvider`]
+//! instead, which allows updating the data without recompiling, or
+//! injecting small fixtures in tests.
+//!
+//! Two providers are available:
+//!
+//! * [`BakedDataProvider`] serves the tables compiled into the crate.
+//!   Likely subtags are only available with the `likelysubtags` feature.
+//! * [`RuntimeDataProvider`] is filled at runtime, either entry by entry,
+//!   from CLDR JSON files with the `cldr-json` feature, or from a compact
+//!   binary blob produced by [`RuntimeDataProvider::to_bytes`].
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::provider::RuntimeDataProvider;
+//! use unic_langid_impl::{CharacterDirection, LanguageIdentifier};
+//!
+//! let mut provider = RuntimeDataProvider::new("test");
+//! provider.insert_likely_subtags(
+//!     "ar".parse().expect("Parsing failed."),
+//!     "ar-Arab-EG".parse().expect("Parsing failed."),
+//! );
+//! provider.insert_script_direction(
+//!     "Arab".parse().expect("Parsing failed."),
+//!     CharacterDirection::RTL,
+//! );
+//!
+//! let mut li: LanguageIdentifier = "ar".parse().expect("Parsing failed.");
+//! assert_eq!(li.maximize_with(&provider), true);
+//! assert_eq!(li.to_string(), "ar-Arab-EG");
+//! assert_eq!(li.character_direction_with(&provider), CharacterDirection::RTL);
+//! ```
+//!
+//! # Binary format
+//!
+//! | Bytes | Content                                                           |
+//! |-------|-------------------------------------------------------------------|
+//! | 1     | Version, currently `1`.                                           |
+//! | 1+    | Length of the CLDR version, as a LEB128 varint, and its bytes.    |
+//! | 1+    | Number of likely subtags entries, as a LEB128 varint.             |
+//! | 4+    | Each entry, as its key and value identifiers.                     |
+//! | 1+    | Number of script directions, as a LEB128 varint.                  |
+//! | 6+    | Each entry, as an `und-Script` identifier and a direction byte.   |
+//! | 1+    | Number of language directions, as a LEB128 varint.                |
+//! | 4+    | Each entry, as a language-only identifier and a direction byte.   |
+//!
+//! Identifiers are encoded as described in [`crate::encoding`], without the
+//! version byte, and directions as `0` for LTR, `1` for RTL and `2` for TTB.
+//! Entries of each table are sorted and unique.
+use crate::encoding::{
+    read_language_identifier, read_varint, write_language_identifier, write_varint,
+};
+use crate::parser::ParserError;
+use crate::{layout_table, subtags, CharacterDirection, PackedLanguageIdentifier};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// The version of the binary format written by [`RuntimeDataProvider::to_bytes`].
+pub const VERSION: u8 = 1;
+
+type Subtags = (
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+);
+
+/// Source of the locale data used by `maximize`, `minimize` and
+/// `character_direction`.
+pub trait DataProvider {
+    /// Returns the version of CLDR the data comes from.
+    fn cldr_version(&self) -> &str;
+
+    /// Returns the likely subtags listed for exactly the given subtags,
+    /// without falling back to any other entry.
+    ///
+    /// An empty `language` stands for `und`.
+    fn likely_subtags(
+        &self,
+        language: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Option<Subtags>;
+
+    /// Returns whether the likely subtags cover all of the given subtags.
+    ///
+    /// Identifiers with subtags which aren't covered, such as ones left out
+    /// of a subset of the data, are left unchanged by `maximize` and
+    /// `minimize`.
+    fn covers(
+        &self,
+        _language: subtags::Language,
+        _script: Option<subtags::Script>,
+        _region: Option<subtags::Region>,
+    ) -> bool {
+        true
+    }
+
+    /// Returns the character direction of a script, if known.
+    fn script_direction(&self, script: subtags::Script) -> Option<CharacterDirection>;
+
+    /// Returns the character direction of a language written in its
+    /// usual script, if known.
+    fn language_direction(&self, language: subtags::Language) -> Option<CharacterDirection>;
+}
+
+/// A [`DataProvider`] serving the tables baked into the crate.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::provider::{BakedDataProvider, DataProvider};
+/// use unic_langid_impl::CharacterDirection;
+///
+/// let script = "Arab".parse().expect("Parsing failed.");
+/// assert_eq!(
+///     BakedDataProvider.script_direction(script),
+///     Some(CharacterDirection::RTL)
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BakedDataProvider;
+
+impl DataProvider for BakedDataProvider {
+    fn cldr_version(&self) -> &str {
+        layout_table::CLDR_VERSION
+    }
+
+    #[cfg(feature = "likelysubtags")]
+    fn likely_subtags(
+        &self,
+        language: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Option<Subtags> {
+        crate::likelysubtags::lookup(language, script, region)
+    }
+
+    #[cfg(not(feature = "likelysubtags"))]
+    fn likely_subtags(
+        &self,
+        _language: subtags::Language,
+        _script: Option<subtags::Script>,
+        _region: Option<subtags::Region>,
+    ) -> Option<Subtags> {
+        None
+    }
+
+    #[cfg(feature = "likelysubtags")]
+    fn covers(
+        &self,
+        language: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> bool {
+        crate::likelysubtags::covers(language, script, region)
+    }
+
+    fn script_direction(&self, script: subtags::Script) -> Option<CharacterDirection> {
+        let script: u32 = script.into();
+        if layout_table::SCRIPTS_CHARACTER_DIRECTION_LTR.contains(&script) {
+            Some(CharacterDirection::LTR)
+        } else if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script) {
+            Some(CharacterDirection::RTL)
+        } else if layout_table::SCRIPTS_CHARACTER_DIRECTION_TTB.contains(&script) {
+            Some(CharacterDirection::TTB)
+        } else {
+            None
+        }
+    }
+
+    fn language_direction(&self, language: subtags::Language) -> Option<CharacterDirection> {
+        let language: u64 = Option::<u64>::from(language)?;
+        if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&language) {
+            Some(CharacterDirection::RTL)
+        } else {
+            None
+        }
+    }
+}
+
+/// Enum with errors that can be returned when loading a [`RuntimeDataProvider`].
+#[derive(Debug, PartialEq)]
+pub enum DataError {
+    /// The data holds an invalid subtag, or isn't a valid binary blob.
+    ParserError(ParserError),
+    /// The JSON doesn't have the structure of CLDR data.
+    InvalidJson(String),
+}
+
+impl From<ParserError> for DataError {
+    fn from(error: ParserError) -> DataError {
+        DataError::ParserError(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DataError {}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DataError::ParserError(p) => write!(f, "Parser error: {}", p),
+            DataError::InvalidJson(message) => write!(f, "Invalid CLDR JSON: {}", message),
+        }
+    }
+}
+
+/// A [`DataProvider`] holding data loaded at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::provider::RuntimeDataProvider;
+/// use unic_langid_impl::LanguageIdentifier;
+///
+/// let mut provider = RuntimeDataProvider::new("test");
+/// provider.insert_likely_subtags(
+///     "und-FR".parse().expect("Parsing failed."),
+///     "fr-Latn-FR".parse().expect("Parsing failed."),
+/// );
+///
+/// let bytes = provider.to_bytes();
+/// let provider = RuntimeDataProvider::from_bytes(&bytes).expect("Loading failed.");
+///
+/// let mut li: LanguageIdentifier = "und-FR".parse().expect("Parsing failed.");
+/// assert_eq!(li.maximize_with(&provider), true);
+/// assert_eq!(li.to_string(), "fr-Latn-FR");
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RuntimeDataProvider {
+    cldr_version: String,
+    likely_subtags: BTreeMap<PackedLanguageIdentifier, PackedLanguageIdentifier>,
+    script_directions: BTreeMap<subtags::Script, CharacterDirection>,
+    language_directions: BTreeMap<subtags::Language, CharacterDirection>,
+}
+
+impl RuntimeDataProvider {
+    /// A constructor which creates an empty provider for the given CLDR version.
+    pub fn new(cldr_version: &str) -> Self {
+        Self {
+            cldr_version: cldr_version.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a likely subtags entry, replacing any previous value for `key`.
+    ///
+    /// A `ZZ` region in `value` is dropped, as the region is unknown.
+    pub fn insert_likely_subtags(
+        &mut self,
+        key: PackedLanguageIdentifier,
+        value: PackedLanguageIdentifier,
+    ) {
+        let region = value.region().filter(|r| r.as_str() != "ZZ");
+        let value = PackedLanguageIdentifier::from_parts(value.language(), value.script(), region);
+        self.likely_subtags.insert(key, value);
+    }
+
+    /// Sets the character direction of a script.
+    pub fn insert_script_direction(
+        &mut self,
+        script: subtags::Script,
+        direction: CharacterDirection,
+    ) {
+        self.script_directions.insert(script, direction);
+    }
+
+    /// Sets the character direction of a language written in its usual script.
+    pub fn insert_language_direction(
+        &mut self,
+        language: subtags::Language,
+        direction: CharacterDirection,
+    ) {
+        self.language_directions.insert(language, direction);
+    }
+
+    /// Loads the CLDR `likelySubtags.json` supplemental data, taking the
+    /// CLDR version from it.
+    #[cfg(feature = "cldr-json")]
+    pub fn load_likely_subtags_json(&mut self, json: &str) -> Result<(), DataError> {
+        let root: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| DataError::InvalidJson(e.to_string()))?;
+        let supplemental = &root["supplemental"];
+        let entries = supplemental["likelySubtags"]
+            .as_object()
+            .ok_or_else(|| DataError::InvalidJson("missing likelySubtags".to_string()))?;
+
+        for (key, value) in entries {
+            let value = value
+                .as_str()
+                .ok_or_else(|| DataError::InvalidJson(format!("invalid value for {}", key)))?;
+            let key = parse_packed(key)?;
+            let value = parse_packed(value)?;
+            self.insert_likely_subtags(key, value);
+        }
+
+        if let Some(version) = supplemental["version"]["_cldrVersion"].as_str() {
+            self.cldr_version = version.to_string();
+        }
+        Ok(())
+    }
+
+    /// Loads the character direction from the CLDR `layout.json` data
+    /// of a single locale.
+    ///
+    /// The direction applies to the script of the locale, if it has one,
+    /// and to its language if it is right-to-left.
+    #[cfg(feature = "cldr-json")]
+    pub fn load_layout_json(&mut self, json: &str) -> Result<(), DataError> {
+        let root: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| DataError::InvalidJson(e.to_string()))?;
+        let (key, locale) = root["main"]
+            .as_object()
+            .and_then(|main| main.iter().next())
+            .ok_or_else(|| DataError::InvalidJson("missing main".to_string()))?;
+        if key == "root" {
+            return Ok(());
+        }
+
+        let direction = match locale["layout"]["orientation"]["characterOrder"].as_str() {
+            Some("left-to-right") => CharacterDirection::LTR,
+            Some("right-to-left") => CharacterDirection::RTL,
+            Some("top-to-bottom") => CharacterDirection::TTB,
+            _ => {
+                return Err(DataError::InvalidJson(format!(
+                    "invalid characterOrder for {}",
+                    key
+                )))
+            }
+        };
+
+        let langid: crate::LanguageIdentifier = key
+            .parse()
+            .map_err(|_| DataError::ParserError(ParserError::InvalidSubtag))?;
+        if let Some(script) = langid.script {
+            self.insert_script_direction(script, direction);
+        }
+        if direction == CharacterDirection::RTL && !langid.language.is_empty() {
+            self.insert_language_direction(langid.language, direction);
+        }
+        Ok(())
+    }
+
+    /// A constructor which loads a provider from the output of
+    /// [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(input: &[u8]) -> Result<Self, DataError> {
+        match input.first() {
+            Some(&VERSION) => {}
+            _ => return Err(ParserError::InvalidEncoding { offset: 0 }.into()),
+        }
+
+        let (length, offset) = read_varint(input, 1)?;
+        let cldr_version = input
+            .get(offset..offset + length)
+            .and_then(|bytes| core::str::from_utf8(bytes).ok())
+            .ok_or(ParserError::InvalidEncoding { offset })?;
+        let mut result = Self::new(cldr_version);
+        let mut offset = offset + length;
+
+        let (count, next) = read_varint(input, offset)?;
+        offset = next;
+        let mut previous = None;
+        for _ in 0..count {
+            let start = offset;
+            let (key, next) = read_packed(input, offset)?;
+            let (value, next) = read_packed(input, next)?;
+            offset = next;
+            if previous >= Some(key) {
+                return Err(ParserError::InvalidEncoding { offset: start }.into());
+            }
+            previous = Some(key);
+            result.likely_subtags.insert(key, value);
+        }
+
+        let (count, next) = read_varint(input, offset)?;
+        offset = next;
+        let mut previous = None;
+        for _ in 0..count {
+            let start = offset;
+            let (key, next) = read_packed(input, offset)?;
+            let (direction, next) = read_direction(input, next)?;
+            offset = next;
+            match key.script() {
+                Some(script)
+                    if key.language().is_empty()
+                        && key.region().is_none()
+                        && previous < Some(script) =>
+                {
+                    previous = Some(script);
+                    result.script_directions.insert(script, direction);
+                }
+                _ => return Err(ParserError::InvalidEncoding { offset: start }.into()),
+            }
+        }
+
+        let (count, next) = read_varint(input, offset)?;
+        offset = next;
+        let mut previous = None;
+        for _ in 0..count {
+            let start = offset;
+            let (key, next) = read_packed(input, offset)?;
+            let (direction, next) = read_direction(input, next)?;
+            offset = next;
+            let language = key.language();
+            if language.is_empty()
+                || key.script().is_some()
+                || key.region().is_some()
+                || previous >= Some(language)
+            {
+                return Err(ParserError::InvalidEncoding { offset: start }.into());
+            }
+            previous = Some(language);
+            result.language_directions.insert(language, direction);
+        }
+
+        if offset != input.len() {
+            return Err(ParserError::InvalidEncoding { offset }.into());
+        }
+        Ok(result)
+    }
+
+    /// Encodes the provider into a new buffer, in the format described in
+    /// the [module documentation](self).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![VERSION];
+        write_varint(self.cldr_version.len(), &mut result);
+        result.extend_from_slice(self.cldr_version.as_bytes());
+
+        write_varint(self.likely_subtags.len(), &mut result);
+        for (key, value) in &self.likely_subtags {
+            write_language_identifier(&(*key).into(), &mut result);
+            write_language_identifier(&(*value).into(), &mut result);
+        }
+
+        write_varint(self.script_directions.len(), &mut result);
+        for (script, direction) in &self.script_directions {
+            let key = PackedLanguageIdentifier::from_parts(Default::default(), Some(*script), None);
+            write_language_identifier(&key.into(), &mut result);
+            result.push(direction_to_byte(*direction));
+        }
+
+        write_varint(self.language_directions.len(), &mut result);
+        for (language, direction) in &self.language_directions {
+            let key = PackedLanguageIdentifier::from_parts(*language, None, None);
+            write_language_identifier(&key.into(), &mut result);
+            result.push(direction_to_byte(*direction));
+        }
+        result
+    }
+}
+
+impl DataProvider for RuntimeDataProvider {
+    fn cldr_version(&self) -> &str {
+        &self.cldr_version
+    }
+
+    fn likely_subtags(
+        &self,
+        language: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Option<Subtags> {
+        let key = PackedLanguageIdentifier::from_parts(language, script, region);
+        self.likely_subtags
+            .get(&key)
+            .map(|value| (value.language(), value.script(), value.region()))
+    }
+
+    fn script_direction(&self, script: subtags::Script) -> Option<CharacterDirection> {
+        self.script_directions.get(&script).copied()
+    }
+
+    fn language_direction(&self, language: subtags::Language) -> Option<CharacterDirection> {
+        self.language_directions.get(&language).copied()
+    }
+}
+
+#[cfg(feature = "cldr-json")]
+fn parse_packed(input: &str) -> Result<PackedLanguageIdentifier, DataError> {
+    input
+        .parse()
+        .map_err(|_| DataError::ParserError(ParserError::InvalidSubtag))
+}
+
+fn read_packed(
+    input: &[u8],
+    offset: usize,
+) -> Result<(PackedLanguageIdentifier, usize), ParserError> {
+    let (langid, next) = read_language_identifier(input, offset)?;
+    let packed = PackedLanguageIdentifier::try_from(langid)
+        .map_err(|_| ParserError::InvalidEncoding { offset })?;
+    Ok((packed, next))
+}
+
+fn read_direction(input: &[u8], offset: usize) -> Result<(CharacterDirection, usize), ParserError> {
+    let direction = match input.get(offset) {
+        Some(0) => CharacterDirection::LTR,
+        Some(1) => CharacterDirection::RTL,
+        Some(2) => CharacterDirection::TTB,
+        _ => return Err(ParserError::InvalidEncoding { offset }),
+    };
+    Ok((direction, offset + 1))
+}
+
+fn direction_to_byte(direction: CharacterDirection) -> u8 {
+    match direction {
+        CharacterDirection::LTR => 0,
+        CharacterDirection::RTL => 1,
+        CharacterDirection::TTB => 2,
+    }
+}
+
+/// Extends the subtags with likely subtags from `provider`, the same way
+/// as [`LanguageIdentifier::maximize`].
+pub fn maximize<P: DataProvider + ?Sized>(
+    provider: &P,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<Subtags> {
+    if !lang.is_empty() && script.is_some() && region.is_some()
+        || !provider.covers(lang, script, region)
+    {
+        return None;
+    }
+
+    if !lang.is_empty() {
+        if region.is_some() {
+            if let Some(result) = provider.likely_subtags(lang, None, region) {
+                return Some(result);
+            }
+        }
+
+        if script.is_some() {
+            if let Some(result) = provider.likely_subtags(lang, script, None) {
+                return Some(result);
+            }
+        }
+
+        return provider
+            .likely_subtags(lang, None, None)
+            .map(|(l, s, r)| (l, script.or(s), region.or(r)));
+    } else if script.is_some() {
+        if region.is_some() {
+            if let Some(result) = provider.likely_subtags(lang, script, region) {
+                return Some(result);
+            }
+        }
+
+        return provider
+            .likely_subtags(lang, script, None)
+            .map(|(l, s, r)| (l, s, region.or(r)));
+    } else if region.is_some() {
+        return provider.likely_subtags(lang, None, region);
+    }
+
+    None
+}
+
+/// Removes likely subtags using `provider`, the same way as
+/// [`LanguageIdentifier::minimize`].
+pub fn minimize<P: DataProvider + ?Sized>(
+    provider: &P,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<Subtags> {
+    minimize_favoring(provider, lang, script, region, false)
+}
+
+/// Like [`minimize`], but keeps the script rather than the region when
+/// either would do, the same way as
+/// [`LanguageIdentifier::minimize_favor_script`].
+pub fn minimize_favor_script<P: DataProvider + ?Sized>(
+    provider: &P,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> Option<Subtags> {
+    minimize_favoring(provider, lang, script, region, true)
+}
+
+fn minimize_favoring<P: DataProvider + ?Sized>(
+    provider: &P,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+    favor_script: bool,
+) -> Option<Subtags> {
+    if !provider.covers(lang, script, region) {
+        return None;
+    }
+
+    // maximize returns None when all 3 components are
+    // already filled so don't call it in that case.
+    let max_langid = if !lang.is_empty() && script.is_some() && region.is_some() {
+        (lang, script, region)
+    } else {
+        maximize(provider, lang, script, region)?
+    };
+
+    if let Some(trial) = maximize(provider, max_langid.0, None, None) {
+        if trial == max_langid {
+            return Some((max_langid.0, None, None));
+        }
+    }
+
+    let with_region = (max_langid.0, None, max_langid.2);
+    let with_script = (max_langid.0, max_langid.1, None);
+    let candidates = if favor_script {
+        [with_script, with_region]
+    } else {
+        [with_region, with_script]
+    };
+    for candidate in candidates {
+        if candidate.1.is_none() && candidate.2.is_none() {
+            continue;
+        }
+        if maximize(provider, candidate.0, candidate.1, candidate.2) == Some(max_langid) {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+/// Returns the character direction of the subtags using `provider`, the
+/// same way as [`LanguageIdentifier::character_direction`].
+pub fn character_direction<P: DataProvider + ?Sized>(
+    provider: &P,
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> CharacterDirection {
+    if let Some(direction) = script.and_then(|s| provider.script_direction(s)) {
+        return direction;
+    }
+    match provider.language_direction(lang) {
+        Some(direction) => {
+            // A language may also be written in a script with a different
+            // direction, which is then likely in some regions.
+            maximize(provider, lang, None, region)
+                .and_then(|(_, script, _)| script)
+                .and_then(|s| provider.script_direction(s))
+                .unwrap_or(direction)
+        }
+        None => CharacterDirection::LTR,
+    }
+}
@@ -1,4 +1,5 @@
 use crate::LanguageIdentifier;
+use alloc::string::ToString;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 impl Serialize for LanguageIdentifier {
@@ -20,7 +21,7 @@ impl<'de> Deserialize<'de> for LanguageIdentifier {
         impl<'de> serde::de::Visitor<'de> for LanguageIdentifierVisitor {
             type Value = LanguageIdentifier;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 write!(formatter, "A valid Unicode Language Identifier")
             }
 
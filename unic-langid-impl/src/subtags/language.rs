@@ -1,7 +1,7 @@
 use crate::parser::errors::ParserError;
-use std::borrow::Borrow;
-use std::convert::TryFrom;
-use std::str::FromStr;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::str::FromStr;
 use tinystr::TinyStr8;
 
 #[derive(Default, Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
@@ -91,8 +91,8 @@ impl FromStr for Language {
     }
 }
 
-impl std::fmt::Display for Language {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Language {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(ref lang) = self.0 {
             f.write_str(lang)
         } else {
@@ -1,5 +1,5 @@
 use crate::parser::errors::ParserError;
-use std::str::FromStr;
+use core::str::FromStr;
 use tinystr::TinyStr4;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
@@ -61,8 +61,8 @@ impl FromStr for Region {
     }
 }
 
-impl std::fmt::Display for Region {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Region {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str(&self.0)
     }
 }
@@ -1,5 +1,5 @@
 use crate::parser::errors::ParserError;
-use std::str::FromStr;
+use core::str::FromStr;
 use tinystr::TinyStr4;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
@@ -49,8 +49,8 @@ impl FromStr for Script {
     }
 }
 
-impl std::fmt::Display for Script {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Script {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str(&self.0)
     }
 }
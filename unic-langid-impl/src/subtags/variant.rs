@@ -1,5 +1,5 @@
 use crate::parser::errors::ParserError;
-use std::str::FromStr;
+use core::str::FromStr;
 use tinystr::TinyStr8;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, Copy)]
@@ -59,8 +59,8 @@ impl FromStr for Variant {
     }
 }
 
-impl std::fmt::Display for Variant {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Variant {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.write_str(&self.0)
     }
 }
@@ -0,0 +1,174 @@
+//! Populations of territories and of the languages spoken in them.
+//!
+//! [`Region::languages`] lists the languages spoken in a territory and
+//! [`Language::regions`] the territories a language is spoken in, using
+//! the CLDR `territoryInfo` and `languageData` data baked into the crate.
+//! Each language is returned as a [`LanguageIdentifier`] with its script
+//! and the territory filled in.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::subtags::{Language, Region};
+//! use unic_langid_impl::territory_info::OfficialStatus;
+//!
+//! let region: Region = "CH".parse().expect("Parsing failed.");
+//! let languages = region.languages();
+//! assert_eq!(languages[0].langid, "de-Latn-CH");
+//! assert_eq!(languages[0].official_status, Some(OfficialStatus::Official));
+//!
+//! let language: Language = "sr".parse().expect("Parsing failed.");
+//! let regions = language.regions();
+//! assert_eq!(regions[0].langid, "sr-Cyrl-RS");
+//! assert_eq!(regions[1].langid, "sr-Latn-RS");
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the `cldr-core` package,
+//! and can be limited to some languages, scripts and territories with its
+//! `--locales`, `--scripts` and `--regions` options. The script of a
+//! language CLDR doesn't give one for is its only primary script, or else
+//! its likely script in the territory.
+#[allow(clippy::unreadable_literal)]
+mod tables;
+
+use crate::subtags::{Language, Region, Script};
+use crate::LanguageIdentifier;
+use alloc::vec::Vec;
+
+pub use tables::CLDR_VERSION;
+
+/// The official status of a language in a territory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OfficialStatus {
+    /// Official in the whole territory.
+    Official,
+    /// Not official by law, but used as such in the whole territory.
+    DeFactoOfficial,
+    /// Official in a part of the territory.
+    OfficialRegional,
+    /// Official for a minority of the population.
+    OfficialMinority,
+}
+
+/// A language spoken in a territory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LanguagePopulation {
+    /// The language, with its script and the territory.
+    pub langid: LanguageIdentifier,
+    /// The percentage of the population of the territory speaking it.
+    pub population_percent: f32,
+    /// The number of people speaking it in the territory.
+    pub population: u64,
+    /// Its official status in the territory, if any.
+    pub official_status: Option<OfficialStatus>,
+}
+
+type Entry = (u32, u64, u32, f32, Option<OfficialStatus>);
+
+fn population(region: u32) -> Option<u64> {
+    tables::POPULATIONS
+        .binary_search_by_key(&region, |(r, _)| *r)
+        .ok()
+        .map(|idx| tables::POPULATIONS[idx].1)
+}
+
+fn language_population(&(region, language, script, percent, status): &Entry) -> LanguagePopulation {
+    let region_population = population(region).unwrap_or(0);
+    // `f64::round` needs `std`.
+    let speakers = region_population as f64 * f64::from(percent) / 100.0 + 0.5;
+    LanguagePopulation {
+        langid: unsafe {
+            LanguageIdentifier::from_parts(
+                Language::from_raw_unchecked(language),
+                Some(Script::from_raw_unchecked(script)),
+                Some(Region::from_raw_unchecked(region)),
+                &[],
+            )
+        },
+        population_percent: percent,
+        population: speakers as u64,
+        official_status: status,
+    }
+}
+
+impl Region {
+    /// Returns the population of the territory, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// let region: Region = "LU".parse().expect("Parsing failed.");
+    /// assert!(region.population().is_some());
+    ///
+    /// let region: Region = "EU".parse().expect("Parsing failed.");
+    /// assert_eq!(region.population(), None);
+    /// ```
+    pub fn population(&self) -> Option<u64> {
+        population(u32::from(*self))
+    }
+
+    /// Returns the languages spoken in the territory, the most spoken first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    /// use unic_langid_impl::territory_info::OfficialStatus;
+    ///
+    /// let region: Region = "CA".parse().expect("Parsing failed.");
+    /// let languages: Vec<_> = region
+    ///     .languages()
+    ///     .into_iter()
+    ///     .filter(|language| language.official_status == Some(OfficialStatus::Official))
+    ///     .map(|language| language.langid.to_string())
+    ///     .collect();
+    /// assert_eq!(languages, vec!["en-Latn-CA", "fr-Latn-CA"]);
+    /// ```
+    pub fn languages(&self) -> Vec<LanguagePopulation> {
+        let region = u32::from(*self);
+        let start = tables::LANGUAGES.partition_point(|(r, ..)| *r < region);
+        tables::LANGUAGES[start..]
+            .iter()
+            .take_while(|(r, ..)| *r == region)
+            .map(language_population)
+            .collect()
+    }
+}
+
+impl Language {
+    /// Returns the territories the language is spoken in, with the most
+    /// speakers first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// let language: Language = "fr".parse().expect("Parsing failed.");
+    /// let regions = language.regions();
+    /// assert_eq!(regions[0].langid, "fr-Latn-FR");
+    /// assert!(regions[0].population > regions[1].population);
+    /// ```
+    pub fn regions(&self) -> Vec<LanguagePopulation> {
+        let language = match Option::<u64>::from(*self) {
+            Some(language) => language,
+            None => return Vec::new(),
+        };
+        let mut result: Vec<_> = tables::LANGUAGES
+            .iter()
+            .filter(|(_, l, ..)| *l == language)
+            .map(language_population)
+            .collect();
+        result.sort_by(|a, b| {
+            b.population
+                .cmp(&a.population)
+                .then_with(|| a.langid.region.cmp(&b.langid.region))
+                .then_with(|| a.langid.script.cmp(&b.langid.script))
+        });
+        result
+    }
+}
@@ -0,0 +1,1740 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+use super::OfficialStatus;
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static POPULATIONS: [(u32, u64); 258] = [
+    (16706, 3835590), // BA
+    (16707, 37694100), // CA
+    (16709, 150000), // EA
+    (16711, 2230910), // GA
+    (16716, 7447400), // LA
+    (16717, 35561700), // MA
+    (16718, 2630070), // NA
+    (16720, 3894080), // PA
+    (16721, 2444170), // QA
+    (16723, 34173500), // SA
+    (16724, 275), // TA
+    (16725, 43922900), // UA
+    (16726, 1000), // VA
+    (16730, 56463600), // ZA
+    (16962, 294560), // BB
+    (16967, 65761100), // GB
+    (16972, 5469610), // LB
+    (16979, 685097), // SB
+    (17217, 940), // AC
+    (17219, 596), // CC
+    (17221, 16904900), // EC
+    (17225, 2098590), // IC
+    (17228, 166487), // LC
+    (17229, 39000), // MC
+    (17230, 290009), // NC
+    (17235, 95981), // SC
+    (17236, 55926), // TC
+    (17238, 101390), // VC
+    (17473, 77000), // AD
+    (17474, 162651000), // BD
+    (17475, 101780000), // CD
+    (17479, 113094), // GD
+    (17481, 267026000), // ID
+    (17485, 3364500), // MD
+    (17491, 45561600), // SD
+    (17492, 16877400), // TD
+    (17729, 9992080), // AE
+    (17730, 11720700), // BE
+    (17732, 80159700), // DE
+    (17733, 1228620), // EE
+    (17735, 3997000), // GE
+    (17737, 5176570), // IE
+    (17738, 101073), // JE
+    (17739, 53527900), // KE
+    (17741, 609859), // ME
+    (17742, 22772400), // NE
+    (17744, 31915000), // PE
+    (17746, 787584), // RE
+    (17747, 10202500), // SE
+    (17750, 28644600), // VE
+    (17753, 29884400), // YE
+    (17985, 36643800), // AF
+    (17986, 20835400), // BF
+    (17987, 5990860), // CF
+    (17991, 199509), // GF
+    (17997, 32556), // MF
+    (17998, 1748), // NF
+    (18000, 295121), // PF
+    (18004, 140), // TF
+    (18007, 15854), // WF
+    (18241, 98179), // AG
+    (18242, 6966900), // BG
+    (18243, 5293070), // CG
+    (18244, 500), // DG
+    (18245, 104124000), // EG
+    (18247, 67052), // GG
+    (18251, 5964900), // KG
+    (18253, 26955700), // MG
+    (18254, 214028000), // NG
+    (18256, 7259460), // PG
+    (18259, 6209660), // SG
+    (18260, 8608440), // TG
+    (18261, 43253000), // UG
+    (18262, 37381), // VG
+    (18498, 1505000), // BH
+    (18499, 8403990), // CH
+    (18501, 652271), // EH
+    (18503, 29340200), // GH
+    (18507, 16927000), // KH
+    (18509, 77917), // MH
+    (18512, 109181000), // PH
+    (18515, 7862), // SH
+    (18516, 68977400), // TH
+    (18753, 18090), // AI
+    (18754, 11865800), // BI
+    (18755, 27481100), // CI
+    (18758, 5571670), // FI
+    (18759, 29581), // GI
+    (18763, 111796), // KI
+    (18764, 39137), // LI
+    (18766, 6203440), // NI
+    (18771, 2102680), // SI
+    (18774, 106235), // VI
+    (19010, 12864600), // BJ
+    (19012, 921804), // DJ
+    (19014, 935974), // FJ
+    (19027, 2926), // SJ
+    (19028, 8873670), // TJ
+    (19267, 8574), // CK
+    (19268, 5869410), // DK
+    (19270, 3198), // FK
+    (19272, 7249910), // HK
+    (19276, 22889200), // LK
+    (19277, 2125970), // MK
+    (19280, 233501000), // PK
+    (19283, 5440600), // SK
+    (19284, 1647), // TK
+    (19288, 1932770), // XK
+    (19521, 3074580), // AL
+    (19522, 7122), // BL
+    (19523, 18186800), // CL
+    (19527, 57616), // GL
+    (19529, 8675480), // IL
+    (19533, 19553400), // ML
+    (19534, 17280400), // NL
+    (19536, 38282300), // PL
+    (19539, 6624930), // SL
+    (19540, 1383720), // TL
+    (19777, 3021320), // AM
+    (19778, 71750), // BM
+    (19779, 27745000), // CM
+    (19780, 74243), // DM
+    (19782, 102436), // FM
+    (19783, 2174000), // GM
+    (19784, 1), // HM
+    (19785, 90499), // IM
+    (19786, 2808570), // JM
+    (19787, 846281), // KM
+    (19789, 56590100), // MM
+    (19791, 4664840), // OM
+    (19792, 5347), // PM
+    (19795, 34232), // SM
+    (19796, 5528630), // TM
+    (19797, 316), // UM
+    (19802, 17426600), // ZM
+    (20034, 464478), // BN
+    (20035, 1394020000), // CN
+    (20039, 12527400), // GN
+    (20040, 9235340), // HN
+    (20041, 1326090000), // IN
+    (20043, 53821), // KN
+    (20045, 3168030), // MN
+    (20048, 50), // PN
+    (20051, 15736400), // SN
+    (20052, 11721200), // TN
+    (20054, 98721300), // VN
+    (20289, 32522300), // AO
+    (20290, 11639900), // BO
+    (20291, 49084800), // CO
+    (20292, 10499700), // DO
+    (20294, 51628), // FO
+    (20297, 3500), // IO
+    (20298, 10820600), // JO
+    (20301, 614458), // MO
+    (20302, 5467440), // NO
+    (20306, 21302900), // RO
+    (20307, 11757100), // SO
+    (20308, 106095), // TO
+    (20547, 1), // CP
+    (20551, 452776), // GP
+    (20554, 125507000), // JP
+    (20555, 25643500), // KP
+    (20557, 51433), // MP
+    (20558, 30327900), // NP
+    (20801, 300), // AQ
+    (20802, 20000), // BQ
+    (20803, 492), // CQ
+    (20807, 836178), // GQ
+    (20809, 38872700), // IQ
+    (20813, 436131), // MQ
+    (21057, 45479100), // AR
+    (21058, 211716000), // BR
+    (21059, 5097990), // CR
+    (21061, 6081200), // ER
+    (21062, 67848200), // FR
+    (21063, 10607100), // GR
+    (21064, 4227750), // HR
+    (21065, 84923300), // IR
+    (21067, 51835100), // KR
+    (21068, 5073300), // LR
+    (21069, 4005480), // MR
+    (21070, 11000), // NR
+    (21072, 3189070), // PR
+    (21075, 609569), // SR
+    (21076, 82017500), // TR
+    (21313, 49437), // AS
+    (21314, 337721), // BS
+    (21317, 50015800), // ES
+    (21319, 20), // GS
+    (21321, 350734), // IS
+    (21324, 1969330), // LS
+    (21325, 5373), // MS
+    (21328, 4818260), // PS
+    (21330, 7012170), // RS
+    (21331, 10561200), // SS
+    (21333, 332639000), // US
+    (21335, 203774), // WS
+    (21569, 8859450), // AT
+    (21570, 782318), // BT
+    (21573, 108113000), // ET
+    (21575, 17153300), // GT
+    (21576, 11067800), // HT
+    (21577, 62402700), // IT
+    (21580, 2731460), // LT
+    (21581, 457267), // MT
+    (21584, 10302700), // PT
+    (21587, 211122), // ST
+    (21588, 1208790), // TT
+    (21593, 194000), // YT
+    (21825, 25466500), // AU
+    (21827, 11059100), // CU
+    (21831, 168485), // GU
+    (21832, 9771830), // HU
+    (21836, 628381), // LU
+    (21837, 1379370), // MU
+    (21838, 2000), // NU
+    (21842, 141722000), // RU
+    (21846, 298333), // VU
+    (22082, 1), // BV
+    (22083, 583255), // CV
+    (22092, 1881230), // LV
+    (22093, 391904), // MV
+    (22099, 6481100), // SV
+    (22100, 11342), // TV
+    (22337, 119428), // AW
+    (22338, 2317230), // BW
+    (22339, 151345), // CW
+    (22343, 1927100), // GW
+    (22347, 2993710), // KW
+    (22349, 21196600), // MW
+    (22352, 21685), // PW
+    (22354, 12712400), // RW
+    (22356, 23603000), // TW
+    (22362, 14546300), // ZW
+    (22593, 26200), // AX
+    (22595, 2205), // CX
+    (22605, 128650000), // MX
+    (22611, 43847), // SX
+    (22850, 9477920), // BY
+    (22851, 1266680), // CY
+    (22855, 750204), // GY
+    (22859, 61944), // KY
+    (22860, 6890540), // LY
+    (22861, 32652100), // MY
+    (22864, 7191690), // PY
+    (22867, 19398400), // SY
+    (22869, 3387610), // UY
+    (23105, 10205800), // AZ
+    (23106, 399598), // BZ
+    (23107, 10702500), // CZ
+    (23108, 42972900), // DZ
+    (23115, 19091900), // KZ
+    (23117, 30098200), // MZ
+    (23118, 4925480), // NZ
+    (23123, 1104480), // SZ
+    (23124, 58552800), // TZ
+    (23125, 30565400), // UZ
+    (23130, 0), // ZZ
+];
+
+#[rustfmt::skip]
+pub static LANGUAGES: [(u32, u64, u32, f32, Option<OfficialStatus>); 1469] = [
+    (16706, 29538, 1819441475, 99.0, Some(OfficialStatus::Official)), // BA: bs-Cyrl
+    (16706, 29538, 1853120844, 99.0, Some(OfficialStatus::Official)), // BA: bs-Latn
+    (16706, 28261, 1853120844, 45.0, None), // BA: en-Latn
+    (16706, 29288, 1853120844, 12.0, Some(OfficialStatus::Official)), // BA: hr-Latn
+    (16706, 29299, 1819441475, 10.0, Some(OfficialStatus::Official)), // BA: sr-Cyrl
+    (16706, 29299, 1853120844, 10.0, Some(OfficialStatus::Official)), // BA: sr-Latn
+    (16707, 28261, 1853120844, 86.0, Some(OfficialStatus::Official)), // CA: en-Latn
+    (16707, 29286, 1853120844, 30.0, Some(OfficialStatus::Official)), // CA: fr-Latn
+    (16707, 26746, 1936613704, 1.8, None), // CA: zh-Hans
+    (16707, 6649209, 1953390920, 1.7, None), // CA: yue-Hant
+    (16707, 29541, 1853120844, 1.6, None), // CA: es-Latn
+    (16707, 24944, 1970435399, 1.6, None), // CA: pa-Guru
+    (16707, 29281, 1650553409, 1.5, None), // CA: ar-Arab
+    (16707, 7104870, 1853120844, 1.5, None), // CA: fil-Latn
+    (16707, 29801, 1853120844, 0.91, None), // CA: it-Latn
+    (16707, 25956, 1853120844, 0.78, None), // CA: de-Latn
+    (16707, 29301, 1650553409, 0.76, None), // CA: ur-Arab
+    (16707, 24934, 1650553409, 0.65, None), // CA: fa-Arab
+    (16707, 29808, 1853120844, 0.61, None), // CA: pt-Latn
+    (16707, 30066, 1819441475, 0.56, None), // CA: ru-Cyrl
+    (16707, 26984, 1635149124, 0.5, None), // CA: hi-Deva
+    (16707, 24948, 1819107668, 0.49, None), // CA: ta-Taml
+    (16707, 26998, 1853120844, 0.49, None), // CA: vi-Latn
+    (16707, 27760, 1853120844, 0.46, None), // CA: pl-Latn
+    (16707, 28523, 1701998411, 0.45, None), // CA: ko-Kore
+    (16707, 30055, 1919579463, 0.36, None), // CA: gu-Gujr
+    (16707, 27749, 1801810503, 0.33, None), // CA: el-Grek
+    (16707, 28530, 1853120844, 0.27, None), // CA: ro-Latn
+    (16707, 28258, 1735288130, 0.24, None), // CA: bn-Beng
+    (16707, 7627888, 1853120844, 0.24, None), // CA: pdt-Latn
+    (16707, 27509, 1819441475, 0.2, None), // CA: uk-Cyrl
+    (16707, 29299, 1819441475, 0.17, None), // CA: sr-Cyrl
+    (16707, 27758, 1853120844, 0.15, None), // CA: nl-Latn
+    (16707, 24938, 1851879498, 0.14, None), // CA: ja-Jpan
+    (16707, 30056, 1853120844, 0.13, None), // CA: hu-Latn
+    (16707, 28531, 1853120844, 0.13, None), // CA: so-Latn
+    (16707, 30057, 1936613699, 0.12, Some(OfficialStatus::OfficialRegional)), // CA: iu-Cans
+    (16707, 30057, 1853120844, 0.12, Some(OfficialStatus::OfficialRegional)), // CA: iu-Latn
+    (16707, 29288, 1853120844, 0.12, None), // CA: hr-Latn
+    (16707, 29300, 1853120844, 0.1, None), // CA: tr-Latn
+    (16707, 27247, 1936613699, 0.063, None), // CA: oj-Cans
+    (16707, 7563887, 1936613699, 0.04, None), // CA: ojs-Cans
+    (16707, 7366755, 1853120844, 0.034, Some(OfficialStatus::OfficialRegional)), // CA: chp-Latn
+    (16707, 6647661, 1853120844, 0.032, None), // CA: moe-Latn
+    (16707, 29283, 1936613699, 0.024, Some(OfficialStatus::OfficialRegional)), // CA: cr-Cans
+    (16707, 6515053, 1853120844, 0.021, None), // CA: mic-Latn
+    (16707, 6976609, 1853120844, 0.017, None), // CA: atj-Latn
+    (16707, 6384738, 1853120844, 0.013, None), // CA: bla-Latn
+    (16707, 7041635, 1936613699, 0.011, None), // CA: crk-Cans
+    (16707, 7234916, 1853120844, 0.0061, Some(OfficialStatus::OfficialRegional)), // CA: den-Latn
+    (16707, 7497572, 1853120844, 0.0056, Some(OfficialStatus::OfficialRegional)), // CA: dgr-Latn
+    (16707, 7828323, 1936613699, 0.0048, None), // CA: csw-Cans
+    (16707, 6844269, 1853120844, 0.0047, None), // CA: moh-Latn
+    (16707, 7041902, 1936613699, 0.0037, None), // CA: nsk-Cans
+    (16707, 7037284, 1853120844, 0.0032, None), // CA: dak-Latn
+    (16707, 6515811, 1853120844, 0.0023, None), // CA: clc-Latn
+    (16707, 7501160, 1853120844, 0.0019, None), // CA: hur-Latn
+    (16707, 6779491, 1853120844, 0.0018, None), // CA: crg-Latn
+    (16707, 7496055, 1853120844, 0.0018, None), // CA: war-Latn
+    (16707, 7104876, 1853120844, 0.0014, None), // CA: lil-Latn
+    (16707, 6384495, 1853120844, 0.0013, None), // CA: oka-Latn
+    (16707, 7172464, 1853120844, 0.0013, None), // CA: pqm-Latn
+    (16707, 7107171, 1936613699, 0.001, None), // CA: crl-Cans
+    (16707, 7042923, 1853120844, 0.001, None), // CA: kwk-Latn
+    (16707, 6911847, 1853120844, 0.0008, Some(OfficialStatus::OfficialRegional)), // CA: gwi-Latn
+    (16709, 29541, 1853120844, 98.0, Some(OfficialStatus::Official)), // EA: es-Latn
+    (16711, 29286, 1853120844, 63.0, Some(OfficialStatus::Official)), // GA: fr-Latn
+    (16711, 7697776, 1853120844, 9.0, None), // GA: puu-Latn
+    (16716, 28524, 1869570380, 69.0, Some(OfficialStatus::Official)), // LA: lo-Laoo
+    (16716, 6777451, 1869570380, 5.8, None), // LA: kjg-Laoo
+    (16716, 6975080, 1869570380, 3.0, None), // LA: hnj-Laoo
+    (16716, 7627883, 1767991380, 0.96, None), // LA: kdt-Thai
+    (16717, 7959137, 1650553409, 87.0, None), // MA: ary-Arab
+    (16717, 29281, 1650553409, 62.0, Some(OfficialStatus::Official)), // MA: ar-Arab
+    (16717, 6842234, 1735288404, 22.0, None), // MA: zgh-Tfng
+    (16717, 29286, 1853120844, 20.0, Some(OfficialStatus::DeFactoOfficial)), // MA: fr-Latn
+    (16717, 28261, 1853120844, 14.0, None), // MA: en-Latn
+    (16717, 7174772, 1853120844, 9.8, Some(OfficialStatus::Official)), // MA: tzm-Latn
+    (16717, 6908019, 1853120844, 8.7, None), // MA: shi-Latn
+    (16717, 6908019, 1735288404, 8.7, None), // MA: shi-Tfng
+    (16717, 6711666, 1853120844, 4.9, None), // MA: rif-Latn
+    (16717, 6711666, 1735288404, 4.9, None), // MA: rif-Tfng
+    (16717, 29541, 1853120844, 0.065, None), // MA: es-Latn
+    (16718, 26209, 1853120844, 75.0, None), // NA: af-Latn
+    (16718, 27243, 1853120844, 35.0, None), // NA: kj-Latn
+    (16718, 26478, 1853120844, 21.0, None), // NA: ng-Latn
+    (16718, 7430510, 1853120844, 11.0, None), // NA: naq-Latn
+    (16718, 31336, 1853120844, 9.1, None), // NA: hz-Latn
+    (16718, 28261, 1853120844, 7.0, Some(OfficialStatus::Official)), // NA: en-Latn
+    (16718, 25956, 1853120844, 0.9, None), // NA: de-Latn
+    (16718, 28276, 1853120844, 0.56, None), // NA: tn-Latn
+    (16720, 29541, 1853120844, 69.0, Some(OfficialStatus::Official)), // PA: es-Latn
+    (16720, 28261, 1853120844, 14.0, None), // PA: en-Latn
+    (16720, 26746, 1953390920, 0.15, None), // PA: zh-Hant
+    (16721, 29281, 1650553409, 89.0, Some(OfficialStatus::Official)), // QA: ar-Arab
+    (16721, 24934, 1650553409, 11.0, None), // QA: fa-Arab
+    (16721, 27757, 1836674125, 0.27, None), // QA: ml-Mlym
+    (16723, 29281, 1650553409, 100.0, Some(OfficialStatus::Official)), // SA: ar-Arab
+    (16723, 7565921, 1650553409, 3.0, None), // SA: ars-Arab
+    (16724, 28261, 1853120844, 99.0, None), // TA: en-Latn
+    (16725, 27509, 1819441475, 65.0, Some(OfficialStatus::Official)), // UA: uk-Cyrl
+    (16725, 30066, 1819441475, 46.0, Some(OfficialStatus::DeFactoOfficial)), // UA: ru-Cyrl
+    (16725, 27760, 1853120844, 2.4, None), // UA: pl-Latn
+    (16725, 27001, 1919051080, 1.3, None), // UA: yi-Hebr
+    (16725, 6649202, 1819441475, 1.2, None), // UA: rue-Cyrl
+    (16725, 25954, 1819441475, 0.83, None), // UA: be-Cyrl
+    (16725, 6845027, 1819441475, 0.56, None), // UA: crh-Cyrl
+    (16725, 28530, 1853120844, 0.52, None), // UA: ro-Latn
+    (16725, 26466, 1819441475, 0.49, None), // UA: bg-Cyrl
+    (16725, 29300, 1853120844, 0.42, None), // UA: tr-Latn
+    (16725, 30056, 1853120844, 0.37, None), // UA: hu-Latn
+    (16725, 27749, 1801810503, 0.016, None), // UA: el-Grek
+    (16726, 29801, 1853120844, 82.0, Some(OfficialStatus::DeFactoOfficial)), // VA: it-Latn
+    (16726, 24940, 1853120844, 82.0, None), // VA: la-Latn
+    (16730, 28261, 1853120844, 31.0, Some(OfficialStatus::Official)), // ZA: en-Latn
+    (16730, 30074, 1853120844, 24.0, Some(OfficialStatus::OfficialRegional)), // ZA: zu-Latn
+    (16730, 26744, 1853120844, 18.0, Some(OfficialStatus::OfficialRegional)), // ZA: xh-Latn
+    (16730, 26209, 1853120844, 13.0, Some(OfficialStatus::OfficialRegional)), // ZA: af-Latn
+    (16730, 7304046, 1853120844, 9.4, Some(OfficialStatus::OfficialRegional)), // ZA: nso-Latn
+    (16730, 28276, 1853120844, 8.2, Some(OfficialStatus::OfficialRegional)), // ZA: tn-Latn
+    (16730, 29811, 1853120844, 7.9, Some(OfficialStatus::OfficialRegional)), // ZA: st-Latn
+    (16730, 29556, 1853120844, 4.4, Some(OfficialStatus::OfficialRegional)), // ZA: ts-Latn
+    (16730, 29555, 1853120844, 2.7, Some(OfficialStatus::OfficialRegional)), // ZA: ss-Latn
+    (16730, 25974, 1853120844, 2.3, Some(OfficialStatus::OfficialRegional)), // ZA: ve-Latn
+    (16730, 26984, 1635149124, 2.0, None), // ZA: hi-Deva
+    (16730, 29294, 1853120844, 1.6, Some(OfficialStatus::OfficialRegional)), // ZA: nr-Latn
+    (16730, 30579, 1853120844, 0.0018, None), // ZA: sw-Latn
+    (16962, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // BB: en-Latn
+    (16967, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // GB: en-Latn
+    (16967, 29286, 1853120844, 23.0, None), // GB: fr-Latn
+    (16967, 25956, 1853120844, 9.0, None), // GB: de-Latn
+    (16967, 29541, 1853120844, 8.0, None), // GB: es-Latn
+    (16967, 27760, 1853120844, 4.0, None), // GB: pl-Latn
+    (16967, 24944, 1970435399, 3.6, None), // GB: pa-Guru
+    (16967, 29301, 1650553409, 3.5, None), // GB: ur-Arab
+    (16967, 24948, 1819107668, 3.2, None), // GB: ta-Taml
+    (16967, 30055, 1919579463, 2.9, None), // GB: gu-Gujr
+    (16967, 7299955, 1853120844, 2.5, None), // GB: sco-Latn
+    (16967, 31075, 1853120844, 1.3, Some(OfficialStatus::OfficialRegional)), // GB: cy-Latn
+    (16967, 28258, 1735288130, 0.4, None), // GB: bn-Beng
+    (16967, 29281, 1650553409, 0.3, None), // GB: ar-Arab
+    (16967, 26746, 1953390920, 0.3, None), // GB: zh-Hant
+    (16967, 29801, 1853120844, 0.2, None), // GB: it-Latn
+    (16967, 29804, 1853120844, 0.2, None), // GB: lt-Latn
+    (16967, 29808, 1853120844, 0.2, None), // GB: pt-Latn
+    (16967, 28531, 1853120844, 0.2, None), // GB: so-Latn
+    (16967, 29300, 1853120844, 0.2, None), // GB: tr-Latn
+    (16967, 24935, 1853120844, 0.15, Some(OfficialStatus::OfficialRegional)), // GB: ga-Latn
+    (16967, 25703, 1853120844, 0.11, Some(OfficialStatus::OfficialRegional)), // GB: gd-Latn
+    (16967, 30571, 1853120844, 0.003, None), // GB: kw-Latn
+    (16967, 28261, 2002872403, 0.0, None), // GB: en-Shaw
+    (16972, 6516833, 1650553409, 100.0, None), // LB: apc-Arab
+    (16972, 29281, 1650553409, 86.0, Some(OfficialStatus::Official)), // LB: ar-Arab
+    (16972, 28261, 1853120844, 40.0, None), // LB: en-Latn
+    (16972, 31080, 1852666433, 5.2, None), // LB: hy-Armn
+    (16972, 30059, 1650553409, 1.7, None), // LB: ku-Arab
+    (16972, 29286, 1853120844, 0.37, None), // LB: fr-Latn
+    (16979, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // SB: en-Latn
+    (16979, 7563632, 1853120844, 82.0, None), // SB: pis-Latn
+    (16979, 6780274, 1853120844, 1.4, None), // SB: rug-Latn
+    (17217, 28261, 1853120844, 99.0, None), // AC: en-Latn
+    (17219, 29549, 1650553409, 83.0, None), // CC: ms-Arab
+    (17219, 28261, 1853120844, 17.0, Some(OfficialStatus::DeFactoOfficial)), // CC: en-Latn
+    (17221, 29541, 1853120844, 96.0, Some(OfficialStatus::Official)), // EC: es-Latn
+    (17221, 30065, 1853120844, 17.0, Some(OfficialStatus::Official)), // EC: qu-Latn
+    (17221, 6780273, 1853120844, 5.7, None), // EC: qug-Latn
+    (17225, 29541, 1853120844, 98.0, Some(OfficialStatus::Official)), // IC: es-Latn
+    (17228, 28261, 1853120844, 90.0, Some(OfficialStatus::Official)), // LC: en-Latn
+    (17229, 29286, 1853120844, 99.0, Some(OfficialStatus::Official)), // MC: fr-Latn
+    (17230, 29286, 1853120844, 96.0, Some(OfficialStatus::Official)), // NC: fr-Latn
+    (17235, 7565923, 1853120844, 98.0, None), // SC: crs-Latn
+    (17235, 29286, 1853120844, 60.0, Some(OfficialStatus::Official)), // SC: fr-Latn
+    (17235, 28261, 1853120844, 38.0, Some(OfficialStatus::Official)), // SC: en-Latn
+    (17236, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // TC: en-Latn
+    (17238, 28261, 1853120844, 96.0, Some(OfficialStatus::Official)), // VC: en-Latn
+    (17473, 24931, 1853120844, 51.0, Some(OfficialStatus::Official)), // AD: ca-Latn
+    (17473, 29541, 1853120844, 43.0, None), // AD: es-Latn
+    (17473, 29286, 1853120844, 7.5, None), // AD: fr-Latn
+    (17474, 28258, 1735288130, 98.0, Some(OfficialStatus::Official)), // BD: bn-Beng
+    (17474, 28261, 1853120844, 18.0, None), // BD: en-Latn
+    (17474, 7629682, 1735288130, 6.5, None), // BD: rkt-Beng
+    (17474, 7108979, 1735288130, 5.0, None), // BD: syl-Beng
+    (17474, 6776946, 1734897490, 0.53, None), // BD: rhg-Rohg
+    (17474, 7365475, 1835753795, 0.22, None), // BD: ccp-Cakm
+    (17474, 31085, 1919777101, 0.21, None), // BD: my-Mymr
+    (17474, 7631463, 1735288130, 0.073, None), // BD: grt-Beng
+    (17474, 7303789, 1853120844, 0.018, None), // BD: mro-Latn
+    (17474, 6909549, 1735288130, 0.011, None), // BD: mni-Beng
+    (17475, 30579, 1853120844, 50.0, Some(OfficialStatus::OfficialRegional)), // CD: sw-Latn
+    (17475, 6387052, 1853120844, 9.6, Some(OfficialStatus::OfficialRegional)), // CD: lua-Latn
+    (17475, 29286, 1853120844, 3.8, Some(OfficialStatus::Official)), // CD: fr-Latn
+    (17475, 28268, 1853120844, 3.1, Some(OfficialStatus::OfficialRegional)), // CD: ln-Latn
+    (17475, 30060, 1853120844, 2.3, None), // CD: lu-Latn
+    (17475, 26475, 1853120844, 1.5, Some(OfficialStatus::OfficialRegional)), // CD: kg-Latn
+    (17475, 7106412, 1853120844, 0.61, None), // CD: lol-Latn
+    (17475, 30578, 1853120844, 0.38, None), // CD: rw-Latn
+    (17479, 28261, 1853120844, 96.0, Some(OfficialStatus::Official)), // GD: en-Latn
+    (17481, 25705, 1853120844, 64.0, Some(OfficialStatus::Official)), // ID: id-Latn
+    (17481, 30314, 1853120844, 34.0, None), // ID: jv-Latn
+    (17481, 30067, 1853120844, 12.0, None), // ID: su-Latn
+    (17481, 6578541, 1853120844, 6.3, None), // ID: mad-Latn
+    (17481, 29549, 1853120844, 3.4, None), // ID: ms-Latn
+    (17481, 7235949, 1853120844, 3.0, None), // ID: min-Latn
+    (17481, 7824738, 1853120844, 2.1, None), // ID: bew-Latn
+    (17481, 7233890, 1853120844, 1.8, None), // ID: ban-Latn
+    (17481, 6780258, 1853120844, 1.6, None), // ID: bug-Latn
+    (17481, 7236194, 1853120844, 1.5, None), // ID: bjn-Latn
+    (17481, 6644577, 1853120844, 1.4, None), // ID: ace-Latn
+    (17481, 29549, 1650553409, 1.2, None), // ID: ms-Arab
+    (17481, 7561587, 1853120844, 0.97, None), // ID: sas-Latn
+    (17481, 6513250, 1853120844, 0.92, None), // ID: bbc-Latn
+    (17481, 26746, 1953390920, 0.92, None), // ID: zh-Hant
+    (17481, 7037293, 1853120844, 0.73, None), // ID: mak-Latn
+    (17481, 7367276, 1853120844, 0.69, None), // ID: ljp-Latn
+    (17481, 6972786, 1853120844, 0.46, None), // ID: rej-Latn
+    (17481, 7499623, 1853120844, 0.41, None), // ID: gor-Latn
+    (17481, 6973806, 1853120844, 0.37, None), // ID: nij-Latn
+    (17481, 6645611, 1853120844, 0.32, None), // ID: kge-Latn
+    (17481, 8023905, 1853120844, 0.27, None), // ID: aoz-Latn
+    (17481, 7501419, 1853120844, 0.14, None), // ID: kvr-Latn
+    (17481, 7823980, 1853120844, 0.13, None), // ID: lbw-Latn
+    (17481, 7954791, 1853120844, 0.12, None), // ID: gay-Latn
+    (17481, 6451058, 1853120844, 0.11, None), // ID: rob-Latn
+    (17481, 7496813, 1853120844, 0.092, None), // ID: mdr-Latn
+    (17481, 7239795, 1853120844, 0.092, None), // ID: sxn-Latn
+    (17481, 7957619, 1853120844, 0.054, None), // ID: sly-Latn
+    (17481, 7763821, 1853120844, 0.024, None), // ID: mwv-Latn
+    (17485, 28530, 1853120844, 63.0, Some(OfficialStatus::Official)), // MD: ro-Latn
+    (17485, 27509, 1819441475, 14.0, None), // MD: uk-Cyrl
+    (17485, 26466, 1819441475, 9.4, None), // MD: bg-Cyrl
+    (17485, 6775143, 1853120844, 3.3, None), // MD: gag-Latn
+    (17485, 30066, 1819441475, 3.0, None), // MD: ru-Cyrl
+    (17491, 29281, 1650553409, 61.0, Some(OfficialStatus::Official)), // SD: ar-Arab
+    (17491, 28261, 1853120844, 61.0, Some(OfficialStatus::Official)), // SD: en-Latn
+    (17491, 6972770, 1650553409, 5.4, None), // SD: bej-Arab
+    (17491, 7501414, 1853120844, 2.7, None), // SD: fvr-Latn
+    (17491, 24936, 1650553409, 1.8, None), // SD: ha-Arab
+    (17491, 7564397, 1853120844, 0.99, None), // SD: mls-Latn
+    (17491, 6383974, 1650553409, 0.83, None), // SD: fia-Arab
+    (17491, 6775162, 1853120844, 0.51, None), // SD: zag-Latn
+    (17492, 29286, 1853120844, 26.0, Some(OfficialStatus::Official)), // TD: fr-Latn
+    (17492, 29281, 1650553409, 17.0, Some(OfficialStatus::Official)), // TD: ar-Arab
+    (17729, 29281, 1650553409, 78.0, Some(OfficialStatus::Official)), // AE: ar-Arab
+    (17729, 28261, 1853120844, 50.0, None), // AE: en-Latn
+    (17729, 27757, 1836674125, 7.0, None), // AE: ml-Mlym
+    (17729, 29552, 1650553409, 2.9, None), // AE: ps-Arab
+    (17729, 7102818, 1650553409, 2.3, None), // AE: bal-Arab
+    (17729, 24934, 1650553409, 1.9, None), // AE: fa-Arab
+    (17730, 28261, 1853120844, 59.0, None), // BE: en-Latn
+    (17730, 27758, 1853120844, 55.0, Some(OfficialStatus::Official)), // BE: nl-Latn
+    (17730, 29286, 1853120844, 38.0, Some(OfficialStatus::Official)), // BE: fr-Latn
+    (17730, 25956, 1853120844, 22.0, Some(OfficialStatus::Official)), // BE: de-Latn
+    (17730, 7564406, 1853120844, 10.0, None), // BE: vls-Latn
+    (17730, 24951, 1853120844, 5.8, None), // BE: wa-Latn
+    (17732, 25956, 1853120844, 91.0, Some(OfficialStatus::Official)), // DE: de-Latn
+    (17732, 28261, 1853120844, 64.0, None), // DE: en-Latn
+    (17732, 29286, 1853120844, 18.0, None), // DE: fr-Latn
+    (17732, 7496034, 1853120844, 17.0, None), // DE: bar-Latn
+    (17732, 7562350, 1853120844, 12.0, None), // DE: nds-Latn
+    (17732, 27758, 1853120844, 9.0, None), // DE: nl-Latn
+    (17732, 29801, 1853120844, 7.0, None), // DE: it-Latn
+    (17732, 29541, 1853120844, 6.0, None), // DE: es-Latn
+    (17732, 30066, 1819441475, 6.0, None), // DE: ru-Cyrl
+    (17732, 6712694, 1853120844, 6.0, None), // DE: vmf-Latn
+    (17732, 29300, 1853120844, 2.5, None), // DE: tr-Latn
+    (17732, 7828327, 1853120844, 2.3, None), // DE: gsw-Latn
+    (17732, 24932, 1853120844, 2.0, None), // DE: da-Latn
+    (17732, 6780787, 1853120844, 1.0, None), // DE: swg-Latn
+    (17732, 29288, 1853120844, 0.79, None), // DE: hr-Latn
+    (17732, 30059, 1853120844, 0.66, None), // DE: ku-Latn
+    (17732, 27749, 1801810503, 0.38, None), // DE: el-Grek
+    (17732, 6845291, 1853120844, 0.3, None), // DE: ksh-Latn
+    (17732, 27760, 1853120844, 0.29, None), // DE: pl-Latn
+    (17732, 6452072, 1853120844, 0.016, None), // DE: hsb-Latn
+    (17732, 7500390, 1853120844, 0.012, Some(OfficialStatus::OfficialRegional)), // DE: frr-Latn
+    (17732, 6452068, 1853120844, 0.0087, None), // DE: dsb-Latn
+    (17732, 7565926, 1853120844, 0.0025, None), // DE: frs-Latn
+    (17732, 7435379, 1853120844, 0.0012, None), // DE: stq-Latn
+    (17732, 7104112, 1853120844, 0.0, None), // DE: pfl-Latn
+    (17733, 29797, 1853120844, 71.0, Some(OfficialStatus::Official)), // EE: et-Latn
+    (17733, 30066, 1819441475, 56.0, None), // EE: ru-Cyrl
+    (17733, 28261, 1853120844, 50.0, None), // EE: en-Latn
+    (17733, 26982, 1853120844, 21.0, None), // EE: fi-Latn
+    (17733, 7303798, 1853120844, 5.7, None), // EE: vro-Latn
+    (17735, 24939, 1919903047, 86.0, Some(OfficialStatus::Official)), // GE: ka-Geor
+    (17735, 6712696, 1919903047, 11.0, None), // GE: xmf-Geor
+    (17735, 30066, 1819441475, 9.0, None), // GE: ru-Cyrl
+    (17735, 31080, 1852666433, 7.0, None), // GE: hy-Armn
+    (17735, 25185, 1819441475, 2.2, Some(OfficialStatus::OfficialRegional)), // GE: ab-Cyrl
+    (17735, 29551, 1819441475, 2.2, Some(OfficialStatus::OfficialRegional)), // GE: os-Cyrl
+    (17735, 30059, 1853120844, 0.89, None), // GE: ku-Latn
+    (17737, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // IE: en-Latn
+    (17737, 24935, 1853120844, 22.0, Some(OfficialStatus::Official)), // IE: ga-Latn
+    (17737, 29286, 1853120844, 17.0, None), // IE: fr-Latn
+    (17738, 28261, 1853120844, 95.0, Some(OfficialStatus::Official)), // JE: en-Latn
+    (17739, 30579, 1853120844, 66.0, Some(OfficialStatus::Official)), // KE: sw-Latn
+    (17739, 28261, 1853120844, 19.0, Some(OfficialStatus::Official)), // KE: en-Latn
+    (17739, 26987, 1853120844, 17.0, None), // KE: ki-Latn
+    (17739, 7959916, 1853120844, 11.0, None), // KE: luy-Latn
+    (17739, 7304556, 1853120844, 9.8, None), // KE: luo-Latn
+    (17739, 7168363, 1853120844, 7.6, None), // KE: kam-Latn
+    (17739, 7236715, 1853120844, 7.6, None), // KE: kln-Latn
+    (17739, 8025447, 1853120844, 4.9, None), // KE: guz-Latn
+    (17739, 7497069, 1853120844, 4.0, None), // KE: mer-Latn
+    (17739, 7561581, 1853120844, 1.6, None), // KE: mas-Latn
+    (17739, 7692901, 1853120844, 1.5, None), // KE: ebu-Latn
+    (17739, 28531, 1853120844, 1.3, None), // KE: so-Latn
+    (17739, 7758180, 1853120844, 0.82, None), // KE: dav-Latn
+    (17739, 7300468, 1853120844, 0.74, None), // KE: teo-Latn
+    (17739, 7302000, 1853120844, 0.69, None), // KE: pko-Latn
+    (17739, 28015, 1853120844, 0.47, None), // KE: om-Latn
+    (17739, 7430515, 1853120844, 0.46, None), // KE: saq-Latn
+    (17739, 29281, 1650553409, 0.046, None), // KE: ar-Arab
+    (17739, 24944, 1970435399, 0.019, None), // KE: pa-Guru
+    (17739, 30055, 1919579463, 0.0093, None), // KE: gu-Gujr
+    (17741, 29299, 1853120844, 100.0, Some(OfficialStatus::Official)), // ME: sr-Latn
+    (17741, 29043, 1853120844, 7.9, None), // ME: sq-Latn
+    (17742, 24936, 1853120844, 41.0, None), // NE: ha-Latn
+    (17742, 29286, 1853120844, 29.0, Some(OfficialStatus::Official)), // NE: fr-Latn
+    (17742, 6646372, 1853120844, 17.0, None), // NE: dje-Latn
+    (17742, 7435622, 1853120844, 7.0, None), // NE: fuq-Latn
+    (17742, 6843764, 1853120844, 6.0, None), // NE: tmh-Latn
+    (17742, 29281, 1650553409, 0.21, None), // NE: ar-Arab
+    (17742, 7436148, 1853120844, 0.035, None), // NE: twq-Latn
+    (17742, 26214, 1835820097, 0.0, None), // NE: ff-Adlm
+    (17742, 26214, 1853120844, 0.0, None), // NE: ff-Latn
+    (17744, 29541, 1853120844, 73.0, Some(OfficialStatus::Official)), // PE: es-Latn
+    (17744, 30065, 1853120844, 15.0, Some(OfficialStatus::Official)), // PE: qu-Latn
+    (17744, 31073, 1853120844, 1.6, None), // PE: ay-Latn
+    (17746, 29286, 1853120844, 89.0, Some(OfficialStatus::Official)), // RE: fr-Latn
+    (17746, 6710130, 1853120844, 71.0, None), // RE: rcf-Latn
+    (17746, 24948, 1819107668, 15.0, None), // RE: ta-Taml
+    (17747, 30323, 1853120844, 95.0, Some(OfficialStatus::Official)), // SE: sv-Latn
+    (17747, 28261, 1853120844, 86.0, None), // SE: en-Latn
+    (17747, 26982, 1853120844, 2.2, Some(OfficialStatus::OfficialRegional)), // SE: fi-Latn
+    (17747, 7629158, 1853120844, 0.55, None), // SE: fit-Latn
+    (17747, 25971, 1853120844, 0.33, None), // SE: se-Latn
+    (17747, 7695730, 1853120844, 0.093, None), // SE: rmu-Latn
+    (17747, 27001, 1919051080, 0.029, None), // SE: yi-Hebr
+    (17747, 6974835, 1853120844, 0.015, None), // SE: smj-Latn
+    (17747, 6385011, 1853120844, 0.0029, None), // SE: sma-Latn
+    (17747, 24937, 1853120844, 0.0, None), // SE: ia-Latn
+    (17750, 29541, 1853120844, 82.0, Some(OfficialStatus::Official)), // VE: es-Latn
+    (17750, 7107193, 1853120844, 0.007, None), // VE: yrl-Latn
+    (17753, 29281, 1650553409, 74.0, Some(OfficialStatus::Official)), // YE: ar-Arab
+    (17753, 28261, 1853120844, 9.0, None), // YE: en-Latn
+    (17985, 24934, 1650553409, 50.0, Some(OfficialStatus::Official)), // AF: fa-Arab
+    (17985, 29552, 1650553409, 43.0, Some(OfficialStatus::Official)), // AF: ps-Arab
+    (17985, 8020328, 1650553409, 5.9, None), // AF: haz-Arab
+    (17985, 31349, 1650553409, 4.7, Some(OfficialStatus::OfficialRegional)), // AF: uz-Arab
+    (17985, 27508, 1853120844, 1.7, Some(OfficialStatus::OfficialRegional)), // AF: tk-Latn
+    (17985, 6582896, 1650553409, 1.2, None), // AF: prd-Arab
+    (17985, 7235426, 1650553409, 0.63, None), // AF: bgn-Arab
+    (17985, 26485, 1650553409, 0.0082, None), // AF: ug-Arab
+    (17985, 27499, 1650553409, 0.0055, None), // AF: kk-Arab
+    (17986, 7565165, 1853120844, 40.0, None), // BF: mos-Latn
+    (17986, 7698788, 1853120844, 32.0, None), // BF: dyu-Latn
+    (17986, 29286, 1853120844, 22.0, Some(OfficialStatus::Official)), // BF: fr-Latn
+    (17986, 26214, 1835820097, 0.0, None), // BF: ff-Adlm
+    (17986, 26214, 1853120844, 0.0, None), // BF: ff-Latn
+    (17987, 29286, 1853120844, 49.0, Some(OfficialStatus::Official)), // CF: fr-Latn
+    (17987, 26483, 1853120844, 49.0, Some(OfficialStatus::Official)), // CF: sg-Latn
+    (17987, 28268, 1853120844, 0.24, None), // CF: ln-Latn
+    (17991, 29286, 1853120844, 77.0, Some(OfficialStatus::Official)), // GF: fr-Latn
+    (17991, 7496551, 1853120844, 26.0, None), // GF: gcr-Latn
+    (17991, 26746, 1953390920, 2.5, None), // GF: zh-Hant
+    (17991, 6975080, 1869570380, 2.4, None), // GF: hnj-Laoo
+    (17997, 29286, 1853120844, 100.0, Some(OfficialStatus::Official)), // MF: fr-Latn
+    (17998, 28261, 1853120844, 96.0, Some(OfficialStatus::Official)), // NF: en-Latn
+    (18000, 29286, 1853120844, 61.0, Some(OfficialStatus::Official)), // PF: fr-Latn
+    (18000, 31092, 1853120844, 31.0, Some(OfficialStatus::Official)), // PF: ty-Latn
+    (18000, 26746, 1953390920, 7.8, None), // PF: zh-Hant
+    (18004, 29286, 1853120844, 100.0, None), // TF: fr-Latn
+    (18007, 7564407, 1853120844, 60.0, None), // WF: wls-Latn
+    (18007, 29286, 1853120844, 48.0, Some(OfficialStatus::Official)), // WF: fr-Latn
+    (18007, 6583654, 1853120844, 30.0, None), // WF: fud-Latn
+    (18241, 28261, 1853120844, 86.0, Some(OfficialStatus::Official)), // AG: en-Latn
+    (18241, 29808, 1853120844, 1.6, None), // AG: pt-Latn
+    (18242, 26466, 1819441475, 100.0, Some(OfficialStatus::Official)), // BG: bg-Cyrl
+    (18242, 28261, 1853120844, 25.0, None), // BG: en-Latn
+    (18242, 30066, 1819441475, 23.0, None), // BG: ru-Cyrl
+    (18242, 29300, 1853120844, 11.0, None), // BG: tr-Latn
+    (18242, 25956, 1853120844, 8.0, None), // BG: de-Latn
+    (18243, 29286, 1853120844, 84.0, Some(OfficialStatus::Official)), // CG: fr-Latn
+    (18243, 28268, 1853120844, 2.4, None), // CG: ln-Latn
+    (18244, 28261, 1853120844, 99.0, Some(OfficialStatus::DeFactoOfficial)), // DG: en-Latn
+    (18245, 29281, 1650553409, 94.0, Some(OfficialStatus::Official)), // EG: ar-Arab
+    (18245, 8024673, 1650553409, 64.0, None), // EG: arz-Arab
+    (18245, 28261, 1853120844, 35.0, None), // EG: en-Latn
+    (18245, 27749, 1801810503, 0.061, None), // EG: el-Grek
+    (18247, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // GG: en-Latn
+    (18251, 31083, 1819441475, 48.0, Some(OfficialStatus::Official)), // KG: ky-Cyrl
+    (18251, 30066, 1819441475, 36.0, Some(OfficialStatus::Official)), // KG: ru-Cyrl
+    (18253, 26477, 1853120844, 90.0, Some(OfficialStatus::Official)), // MG: mg-Latn
+    (18253, 29286, 1853120844, 69.0, Some(OfficialStatus::Official)), // MG: fr-Latn
+    (18253, 28261, 1853120844, 18.0, Some(OfficialStatus::Official)), // MG: en-Latn
+    (18254, 28261, 1853120844, 53.0, Some(OfficialStatus::Official)), // NG: en-Latn
+    (18254, 7168880, 1853120844, 21.0, None), // NG: pcm-Latn
+    (18254, 28537, 1853120844, 13.0, Some(OfficialStatus::Official)), // NG: yo-Latn
+    (18254, 24936, 1853120844, 13.0, None), // NG: ha-Latn
+    (18254, 26473, 1853120844, 13.0, None), // NG: ig-Latn
+    (18254, 7763302, 1853120844, 6.7, None), // NG: fuv-Latn
+    (18254, 7760244, 1853120844, 1.6, None), // NG: tiv-Latn
+    (18254, 6907493, 1853120844, 1.4, None), // NG: efi-Latn
+    (18254, 6447721, 1853120844, 1.4, None), // NG: ibb-Latn
+    (18254, 24936, 1650553409, 1.0, None), // NG: ha-Arab
+    (18254, 7235938, 1853120844, 0.71, None), // NG: bin-Latn
+    (18254, 6971755, 1853120844, 0.21, None), // NG: kaj-Latn
+    (18254, 6775659, 1853120844, 0.093, None), // NG: kcg-Latn
+    (18254, 29281, 1650553409, 0.071, None), // NG: ar-Arab
+    (18254, 6841187, 1853120844, 0.021, None), // NG: cch-Latn
+    (18254, 7302497, 1853120844, 0.0087, None), // NG: amo-Latn
+    (18254, 7237217, 1853120844, 0.0, None), // NG: ann-Latn
+    (18254, 26214, 1835820097, 0.0, None), // NG: ff-Adlm
+    (18254, 26214, 1853120844, 0.0, None), // NG: ff-Latn
+    (18256, 6910068, 1853120844, 71.0, Some(OfficialStatus::Official)), // PG: tpi-Latn
+    (18256, 28261, 1853120844, 50.0, Some(OfficialStatus::Official)), // PG: en-Latn
+    (18256, 28520, 1853120844, 2.1, Some(OfficialStatus::Official)), // PG: ho-Latn
+    (18259, 28261, 1853120844, 93.0, Some(OfficialStatus::Official)), // SG: en-Latn
+    (18259, 26746, 1936613704, 77.0, Some(OfficialStatus::Official)), // SG: zh-Hans
+    (18259, 29549, 1853120844, 14.0, Some(OfficialStatus::Official)), // SG: ms-Latn
+    (18259, 24948, 1819107668, 2.1, Some(OfficialStatus::Official)), // SG: ta-Taml
+    (18259, 27757, 1836674125, 0.16, None), // SG: ml-Mlym
+    (18259, 24944, 1970435399, 0.15, None), // SG: pa-Guru
+    (18260, 29286, 1853120844, 61.0, Some(OfficialStatus::Official)), // TG: fr-Latn
+    (18260, 25957, 1853120844, 17.0, None), // TG: ee-Latn
+    (18260, 6645353, 1853120844, 1.3, None), // TG: ife-Latn
+    (18261, 30579, 1853120844, 75.0, Some(OfficialStatus::Official)), // UG: sw-Latn
+    (18261, 26476, 1853120844, 13.0, None), // UG: lg-Latn
+    (18261, 7240046, 1853120844, 6.3, None), // UG: nyn-Latn
+    (18261, 6776675, 1853120844, 5.4, None), // UG: cgg-Latn
+    (18261, 6778744, 1853120844, 5.3, None), // UG: xog-Latn
+    (18261, 28261, 1853120844, 3.9, Some(OfficialStatus::Official)), // UG: en-Latn
+    (18261, 7300468, 1853120844, 3.9, None), // UG: teo-Latn
+    (18261, 6971756, 1853120844, 3.8, None), // UG: laj-Latn
+    (18261, 6841185, 1853120844, 3.7, None), // UG: ach-Latn
+    (18261, 7895405, 1853120844, 2.9, None), // UG: myx-Latn
+    (18261, 30578, 1853120844, 2.1, None), // UG: rw-Latn
+    (18261, 6976628, 1853120844, 1.9, None), // UG: ttj-Latn
+    (18261, 26984, 1635149124, 0.0051, None), // UG: hi-Deva
+    (18262, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // VG: en-Latn
+    (18498, 29281, 1650553409, 87.0, Some(OfficialStatus::Official)), // BH: ar-Arab
+    (18498, 27757, 1836674125, 3.3, None), // BH: ml-Mlym
+    (18499, 25956, 1853120844, 73.0, Some(OfficialStatus::Official)), // CH: de-Latn
+    (18499, 7828327, 1853120844, 65.0, Some(OfficialStatus::DeFactoOfficial)), // CH: gsw-Latn
+    (18499, 28261, 1853120844, 61.0, None), // CH: en-Latn
+    (18499, 29286, 1853120844, 21.0, Some(OfficialStatus::Official)), // CH: fr-Latn
+    (18499, 29801, 1853120844, 4.3, Some(OfficialStatus::Official)), // CH: it-Latn
+    (18499, 7302508, 1853120844, 4.1, None), // CH: lmo-Latn
+    (18499, 29808, 1853120844, 3.4, None), // CH: pt-Latn
+    (18499, 28018, 1853120844, 0.5, Some(OfficialStatus::OfficialRegional)), // CH: rm-Latn
+    (18499, 7302514, 1853120844, 0.29, None), // CH: rmo-Latn
+    (18499, 6644087, 1853120844, 0.12, None), // CH: wae-Latn
+    (18501, 29281, 1650553409, 100.0, Some(OfficialStatus::Official)), // EH: ar-Arab
+    (18503, 27489, 1853120844, 39.0, Some(OfficialStatus::OfficialRegional)), // GH: ak-Latn
+    (18503, 28261, 1853120844, 21.0, Some(OfficialStatus::Official)), // GH: en-Latn
+    (18503, 25957, 1853120844, 11.0, Some(OfficialStatus::OfficialRegional)), // GH: ee-Latn
+    (18503, 7496289, 1853120844, 5.0, None), // GH: abr-Latn
+    (18503, 7501159, 1853120844, 3.5, None), // GH: gur-Latn
+    (18503, 6382689, 1853120844, 3.0, None), // GH: ada-Latn
+    (18503, 6381927, 1853120844, 2.8, Some(OfficialStatus::OfficialRegional)), // GH: gaa-Latn
+    (18503, 6912622, 1853120844, 1.0, None), // GH: nzi-Latn
+    (18503, 24936, 1853120844, 0.86, None), // GH: ha-Latn
+    (18503, 6709619, 1853120844, 0.014, None), // GH: saf-Latn
+    (18503, 26214, 1835820097, 0.0, None), // GH: ff-Adlm
+    (18503, 26214, 1853120844, 0.0, None), // GH: ff-Latn
+    (18507, 28011, 1919772747, 89.0, Some(OfficialStatus::Official)), // KH: km-Khmr
+    (18507, 6384227, 1650553409, 1.6, None), // KH: cja-Arab
+    (18507, 7627883, 1767991380, 0.11, None), // KH: kdt-Thai
+    (18509, 28261, 1853120844, 93.0, Some(OfficialStatus::Official)), // MH: en-Latn
+    (18509, 26733, 1853120844, 73.0, Some(OfficialStatus::Official)), // MH: mh-Latn
+    (18512, 28261, 1853120844, 64.0, Some(OfficialStatus::Official)), // PH: en-Latn
+    (18512, 7104870, 1853120844, 60.0, Some(OfficialStatus::Official)), // PH: fil-Latn
+    (18512, 29541, 1853120844, 31.0, None), // PH: es-Latn
+    (18512, 6448483, 1853120844, 24.0, Some(OfficialStatus::OfficialRegional)), // PH: ceb-Latn
+    (18512, 7302249, 1853120844, 9.6, Some(OfficialStatus::OfficialRegional)), // PH: ilo-Latn
+    (18512, 7104872, 1853120844, 8.4, Some(OfficialStatus::OfficialRegional)), // PH: hil-Latn
+    (18512, 7039330, 1853120844, 3.0, None), // PH: bik-Latn
+    (18512, 7496055, 1853120844, 2.9, Some(OfficialStatus::OfficialRegional)), // PH: war-Latn
+    (18512, 7103078, 1853120844, 2.3, None), // PH: fbl-Latn
+    (18512, 7168368, 1853120844, 2.3, None), // PH: pam-Latn
+    (18512, 6775152, 1853120844, 1.4, Some(OfficialStatus::OfficialRegional)), // PH: pag-Latn
+    (18512, 6841453, 1853120844, 1.2, Some(OfficialStatus::OfficialRegional)), // PH: mdh-Latn
+    (18512, 6779764, 1853120844, 1.1, Some(OfficialStatus::OfficialRegional)), // PH: tsg-Latn
+    (18512, 26746, 1953390920, 0.73, None), // PH: zh-Hant
+    (18512, 7565411, 1853120844, 0.66, None), // PH: cps-Latn
+    (18512, 6976107, 1853120844, 0.39, None), // PH: krj-Latn
+    (18512, 7304290, 1853120844, 0.28, None), // PH: bto-Latn
+    (18512, 7237224, 1853120844, 0.016, None), // PH: hnn-Latn
+    (18512, 7823988, 1853120844, 0.0092, None), // PH: tbw-Latn
+    (18512, 7695202, 1853120844, 0.0073, None), // PH: bku-Latn
+    (18515, 28261, 1853120844, 69.0, Some(OfficialStatus::Official)), // SH: en-Latn
+    (18516, 26740, 1767991380, 80.0, Some(OfficialStatus::Official)), // TH: th-Thai
+    (18516, 28261, 1853120844, 27.0, None), // TH: en-Latn
+    (18516, 7566452, 1767991380, 24.0, None), // TH: tts-Thai
+    (18516, 6582126, 1634623820, 9.6, None), // TH: nod-Lana
+    (18516, 7696243, 1767991380, 8.0, None), // TH: sou-Thai
+    (18516, 6383213, 1650553409, 5.0, None), // TH: mfa-Arab
+    (18516, 26746, 1953390920, 1.8, None), // TH: zh-Hant
+    (18516, 7174251, 1767991380, 1.7, None), // TH: kxm-Thai
+    (18516, 7627883, 1767991380, 0.48, None), // TH: kdt-Thai
+    (18516, 7827053, 1919777101, 0.17, None), // TH: mnw-Mymr
+    (18516, 6975080, 1869570380, 0.098, None), // TH: hnj-Laoo
+    (18516, 7235699, 1919777101, 0.096, None), // TH: shn-Mymr
+    (18516, 7365484, 1767991380, 0.01, None), // TH: lcp-Thai
+    (18516, 7108460, 1767991380, 0.01, None), // TH: lwl-Thai
+    (18753, 28261, 1853120844, 95.0, Some(OfficialStatus::Official)), // AI: en-Latn
+    (18754, 28274, 1853120844, 63.0, Some(OfficialStatus::Official)), // BI: rn-Latn
+    (18754, 29286, 1853120844, 59.0, Some(OfficialStatus::Official)), // BI: fr-Latn
+    (18754, 30579, 1853120844, 0.054, None), // BI: sw-Latn
+    (18754, 28261, 1853120844, 0.053, Some(OfficialStatus::Official)), // BI: en-Latn
+    (18755, 29286, 1853120844, 49.0, Some(OfficialStatus::Official)), // CI: fr-Latn
+    (18755, 6906722, 1853120844, 11.0, None), // CI: bci-Latn
+    (18755, 6710643, 1853120844, 4.3, None), // CI: sef-Latn
+    (18755, 6975076, 1853120844, 4.0, None), // CI: dnj-Latn
+    (18755, 7300715, 1853120844, 0.23, None), // CI: kfo-Latn
+    (18755, 7762274, 1853120844, 0.17, None), // CI: bqv-Latn
+    (18758, 26982, 1853120844, 94.0, Some(OfficialStatus::Official)), // FI: fi-Latn
+    (18758, 28261, 1853120844, 70.0, None), // FI: en-Latn
+    (18758, 30323, 1853120844, 44.0, Some(OfficialStatus::Official)), // FI: sv-Latn
+    (18758, 25956, 1853120844, 18.0, None), // FI: de-Latn
+    (18758, 30066, 1819441475, 0.81, None), // FI: ru-Cyrl
+    (18758, 29797, 1853120844, 0.11, None), // FI: et-Latn
+    (18758, 6712690, 1853120844, 0.09, None), // FI: rmf-Latn
+    (18758, 25971, 1853120844, 0.036, None), // FI: se-Latn
+    (18758, 7564659, 1853120844, 0.011, Some(OfficialStatus::OfficialRegional)), // FI: sms-Latn
+    (18758, 7236979, 1853120844, 0.011, None), // FI: smn-Latn
+    (18759, 28261, 1853120844, 80.0, Some(OfficialStatus::Official)), // GI: en-Latn
+    (18759, 29541, 1853120844, 50.0, None), // GI: es-Latn
+    (18763, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // KI: en-Latn
+    (18763, 7104871, 1853120844, 60.0, Some(OfficialStatus::Official)), // KI: gil-Latn
+    (18764, 25956, 1853120844, 100.0, Some(OfficialStatus::Official)), // LI: de-Latn
+    (18764, 7828327, 1853120844, 85.0, Some(OfficialStatus::DeFactoOfficial)), // LI: gsw-Latn
+    (18764, 6644087, 1853120844, 3.3, None), // LI: wae-Latn
+    (18766, 29541, 1853120844, 78.0, Some(OfficialStatus::Official)), // NI: es-Latn
+    (18771, 27763, 1853120844, 87.0, Some(OfficialStatus::Official)), // SI: sl-Latn
+    (18771, 29288, 1853120844, 61.0, None), // SI: hr-Latn
+    (18771, 28261, 1853120844, 59.0, None), // SI: en-Latn
+    (18771, 25956, 1853120844, 42.0, None), // SI: de-Latn
+    (18771, 6514038, 1853120844, 1.4, Some(OfficialStatus::OfficialRegional)), // SI: vec-Latn
+    (18771, 30056, 1853120844, 0.44, None), // SI: hu-Latn
+    (18771, 29801, 1853120844, 0.19, None), // SI: it-Latn
+    (18774, 28261, 1853120844, 75.0, Some(OfficialStatus::DeFactoOfficial)), // VI: en-Latn
+    (19010, 29286, 1853120844, 35.0, Some(OfficialStatus::Official)), // BJ: fr-Latn
+    (19010, 7237478, 1853120844, 25.0, None), // BJ: fon-Latn
+    (19010, 28537, 1853120844, 6.7, None), // BJ: yo-Latn
+    (19012, 24929, 1853120844, 42.0, None), // DJ: aa-Latn
+    (19012, 28531, 1853120844, 41.0, None), // DJ: so-Latn
+    (19012, 29281, 1650553409, 7.3, Some(OfficialStatus::Official)), // DJ: ar-Arab
+    (19012, 29286, 1853120844, 2.1, Some(OfficialStatus::Official)), // DJ: fr-Latn
+    (19014, 28261, 1853120844, 94.0, Some(OfficialStatus::Official)), // FJ: en-Latn
+    (19014, 26984, 1635149124, 44.0, None), // FJ: hi-Deva
+    (19014, 6711656, 1853120844, 41.0, Some(OfficialStatus::Official)), // FJ: hif-Latn
+    (19014, 27238, 1853120844, 39.0, Some(OfficialStatus::Official)), // FJ: fj-Latn
+    (19014, 7173234, 1853120844, 0.27, None), // FJ: rtm-Latn
+    (19027, 25198, 1853120844, 51.0, Some(OfficialStatus::Official)), // SJ: nb-Latn
+    (19027, 30066, 1819441475, 41.0, None), // SJ: ru-Cyrl
+    (19028, 26484, 1819441475, 100.0, Some(OfficialStatus::Official)), // TJ: tg-Cyrl
+    (19028, 30066, 1819441475, 12.0, None), // TJ: ru-Cyrl
+    (19028, 24934, 1650553409, 0.78, None), // TJ: fa-Arab
+    (19028, 29281, 1650553409, 0.011, None), // TJ: ar-Arab
+    (19267, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // CK: en-Latn
+    (19268, 24932, 1853120844, 93.0, Some(OfficialStatus::Official)), // DK: da-Latn
+    (19268, 28261, 1853120844, 86.0, None), // DK: en-Latn
+    (19268, 25956, 1853120844, 47.0, Some(OfficialStatus::OfficialRegional)), // DK: de-Latn
+    (19268, 30323, 1853120844, 13.0, None), // DK: sv-Latn
+    (19268, 28518, 1853120844, 0.38, None), // DK: fo-Latn
+    (19268, 27755, 1853120844, 0.12, Some(OfficialStatus::OfficialRegional)), // DK: kl-Latn
+    (19268, 7632234, 1853120844, 0.0, None), // DK: jut-Latn
+    (19270, 28261, 1853120844, 88.0, Some(OfficialStatus::Official)), // FK: en-Latn
+    (19272, 26746, 1953390920, 95.0, Some(OfficialStatus::Official)), // HK: zh-Hant
+    (19272, 6649209, 1953390920, 90.0, None), // HK: yue-Hant
+    (19272, 28261, 1853120844, 51.0, Some(OfficialStatus::Official)), // HK: en-Latn
+    (19276, 26995, 1752066387, 68.0, Some(OfficialStatus::Official)), // LK: si-Sinh
+    (19276, 24948, 1819107668, 15.0, Some(OfficialStatus::Official)), // LK: ta-Taml
+    (19276, 28261, 1853120844, 10.0, None), // LK: en-Latn
+    (19277, 27501, 1819441475, 67.0, Some(OfficialStatus::Official)), // MK: mk-Cyrl
+    (19277, 29043, 1853120844, 25.0, Some(OfficialStatus::OfficialRegional)), // MK: sq-Latn
+    (19277, 29300, 1853120844, 3.5, None), // MK: tr-Latn
+    (19280, 29301, 1650553409, 95.0, Some(OfficialStatus::Official)), // PK: ur-Arab
+    (19280, 24944, 1650553409, 70.0, None), // PK: pa-Arab
+    (19280, 28261, 1853120844, 50.0, Some(OfficialStatus::Official)), // PK: en-Latn
+    (19280, 6840684, 1650553409, 40.0, None), // PK: lah-Arab
+    (19280, 29552, 1650553409, 16.0, None), // PK: ps-Arab
+    (19280, 25715, 1650553409, 15.0, None), // PK: sd-Arab
+    (19280, 7498611, 1650553409, 12.0, None), // PK: skr-Arab
+    (19280, 7102818, 1650553409, 2.6, None), // PK: bal-Arab
+    (19280, 7302760, 1650553409, 1.7, None), // PK: hno-Arab
+    (19280, 6845026, 1650553409, 1.3, None), // PK: brh-Arab
+    (19280, 24934, 1650553409, 0.66, None), // PK: fa-Arab
+    (19280, 7235426, 1650553409, 0.57, None), // PK: bgn-Arab
+    (19280, 6581864, 1650553409, 0.41, None), // PK: hnd-Arab
+    (19280, 26484, 1650553409, 0.33, None), // PK: tg-Arab
+    (19280, 7694951, 1650553409, 0.2, None), // PK: gju-Arab
+    (19280, 7628386, 1650553409, 0.18, None), // PK: bft-Arab
+    (19280, 7894635, 1650553409, 0.16, None), // PK: kvx-Arab
+    (19280, 7825515, 1650553409, 0.15, None), // PK: khw-Arab
+    (19280, 7960173, 1650553409, 0.14, None), // PK: mvy-Arab
+    (19280, 7039591, 1650553409, 0.11, None), // PK: gjk-Arab
+    (19280, 7370859, 1650553409, 0.11, None), // PK: kxp-Arab
+    (19280, 29547, 1650553409, 0.069, None), // PK: ks-Arab
+    (19280, 7828084, 1650553409, 0.053, None), // PK: trw-Arab
+    (19280, 7763042, 1635149124, 0.019, None), // PK: btv-Deva
+    (19283, 27507, 1853120844, 90.0, Some(OfficialStatus::Official)), // SK: sk-Latn
+    (19283, 29539, 1853120844, 47.0, None), // SK: cs-Latn
+    (19283, 28261, 1853120844, 26.0, None), // SK: en-Latn
+    (19283, 25956, 1853120844, 22.0, None), // SK: de-Latn
+    (19283, 30056, 1853120844, 11.0, None), // SK: hu-Latn
+    (19283, 27509, 1819441475, 1.9, None), // SK: uk-Cyrl
+    (19283, 27760, 1853120844, 0.93, None), // SK: pl-Latn
+    (19284, 28261, 1853120844, 78.0, Some(OfficialStatus::Official)), // TK: en-Latn
+    (19284, 7105396, 1853120844, 78.0, Some(OfficialStatus::Official)), // TK: tkl-Latn
+    (19288, 29043, 1853120844, 92.0, Some(OfficialStatus::Official)), // XK: sq-Latn
+    (19288, 7236705, 1853120844, 74.0, None), // XK: aln-Latn
+    (19288, 29299, 1819441475, 5.0, Some(OfficialStatus::Official)), // XK: sr-Cyrl
+    (19288, 29299, 1853120844, 5.0, Some(OfficialStatus::Official)), // XK: sr-Latn
+    (19521, 29043, 1853120844, 100.0, Some(OfficialStatus::Official)), // AL: sq-Latn
+    (19521, 27749, 1801810503, 1.9, None), // AL: el-Grek
+    (19521, 27501, 1819441475, 0.47, None), // AL: mk-Cyrl
+    (19522, 29286, 1853120844, 96.0, Some(OfficialStatus::Official)), // BL: fr-Latn
+    (19523, 29541, 1853120844, 98.0, Some(OfficialStatus::Official)), // CL: es-Latn
+    (19523, 28261, 1853120844, 9.5, None), // CL: en-Latn
+    (19523, 7238241, 1853120844, 1.5, None), // CL: arn-Latn
+    (19527, 27755, 1853120844, 84.0, Some(OfficialStatus::Official)), // GL: kl-Latn
+    (19527, 24932, 1853120844, 14.0, None), // GL: da-Latn
+    (19529, 25960, 1919051080, 100.0, Some(OfficialStatus::Official)), // IL: he-Hebr
+    (19529, 28261, 1853120844, 85.0, None), // IL: en-Latn
+    (19529, 29281, 1650553409, 20.0, Some(OfficialStatus::Official)), // IL: ar-Arab
+    (19529, 6516833, 1650553409, 17.0, None), // IL: apc-Arab
+    (19529, 30066, 1819441475, 11.0, None), // IL: ru-Cyrl
+    (19529, 28530, 1853120844, 3.7, None), // IL: ro-Latn
+    (19529, 27001, 1919051080, 3.0, None), // IL: yi-Hebr
+    (19529, 27760, 1853120844, 1.5, None), // IL: pl-Latn
+    (19529, 6578540, 1919051080, 1.3, None), // IL: lad-Hebr
+    (19529, 30056, 1853120844, 1.0, None), // IL: hu-Latn
+    (19529, 28001, 1768453189, 0.59, None), // IL: am-Ethi
+    (19529, 26996, 1768453189, 0.12, None), // IL: ti-Ethi
+    (19529, 27757, 1836674125, 0.092, None), // IL: ml-Mlym
+    (19533, 29286, 1853120844, 46.0, Some(OfficialStatus::Official)), // ML: fr-Latn
+    (19533, 28002, 1853120844, 46.0, None), // ML: bm-Latn
+    (19533, 7169638, 1853120844, 7.7, None), // ML: ffm-Latn
+    (19533, 7040627, 1853120844, 5.9, None), // ML: snk-Latn
+    (19533, 7042925, 1853120844, 5.0, None), // ML: mwk-Latn
+    (19533, 7562611, 1853120844, 3.4, None), // ML: ses-Latn
+    (19533, 6843764, 1853120844, 2.1, None), // ML: tmh-Latn
+    (19533, 28002, 1869572942, 2.0, None), // ML: bm-Nkoo
+    (19533, 7432299, 1853120844, 1.7, None), // ML: khq-Latn
+    (19533, 7173220, 1853120844, 1.1, None), // ML: dtm-Latn
+    (19533, 7299435, 1853120844, 1.0, None), // ML: kao-Latn
+    (19533, 29281, 1650553409, 0.9, None), // ML: ar-Arab
+    (19533, 7433570, 1853120844, 0.86, None), // ML: bmq-Latn
+    (19533, 6650466, 1853120844, 0.85, None), // ML: bze-Latn
+    (19534, 27758, 1853120844, 100.0, Some(OfficialStatus::Official)), // NL: nl-Latn
+    (19534, 28261, 1853120844, 90.0, None), // NL: en-Latn
+    (19534, 25956, 1853120844, 71.0, None), // NL: de-Latn
+    (19534, 29286, 1853120844, 29.0, None), // NL: fr-Latn
+    (19534, 7562350, 1853120844, 11.0, None), // NL: nds-Latn
+    (19534, 26988, 1853120844, 5.5, None), // NL: li-Latn
+    (19534, 31078, 1853120844, 4.3, Some(OfficialStatus::OfficialRegional)), // NL: fy-Latn
+    (19534, 7565159, 1853120844, 3.6, None), // NL: gos-Latn
+    (19534, 25705, 1853120844, 1.8, None), // NL: id-Latn
+    (19534, 6382970, 1853120844, 1.4, None), // NL: zea-Latn
+    (19534, 6711666, 1853120844, 1.2, None), // NL: rif-Latn
+    (19534, 29300, 1853120844, 1.2, None), // NL: tr-Latn
+    (19536, 27760, 1853120844, 96.0, Some(OfficialStatus::Official)), // PL: pl-Latn
+    (19536, 28261, 1853120844, 33.0, None), // PL: en-Latn
+    (19536, 25956, 1853120844, 19.0, Some(OfficialStatus::OfficialRegional)), // PL: de-Latn
+    (19536, 30066, 1819441475, 18.0, None), // PL: ru-Cyrl
+    (19536, 7109235, 1853120844, 1.3, None), // PL: szl-Latn
+    (19536, 25954, 1819441475, 0.58, None), // PL: be-Cyrl
+    (19536, 27509, 1819441475, 0.39, None), // PL: uk-Cyrl
+    (19536, 6452067, 1853120844, 0.13, Some(OfficialStatus::OfficialRegional)), // PL: csb-Latn
+    (19536, 6909043, 1853120844, 0.031, None), // PL: sli-Latn
+    (19536, 29804, 1853120844, 0.021, Some(OfficialStatus::OfficialRegional)), // PL: lt-Latn
+    (19536, 6779504, 1853120844, 0.0001, None), // PL: prg-Latn
+    (19539, 6910571, 1853120844, 95.0, None), // SL: kri-Latn
+    (19539, 28261, 1853120844, 35.0, Some(OfficialStatus::Official)), // SL: en-Latn
+    (19539, 7234925, 1853120844, 27.0, None), // SL: men-Latn
+    (19539, 7169396, 1853120844, 26.0, None), // SL: tem-Latn
+    (19539, 26214, 1835820097, 0.0, None), // SL: ff-Adlm
+    (19539, 26214, 1853120844, 0.0, None), // SL: ff-Latn
+    (19540, 29808, 1853120844, 59.0, Some(OfficialStatus::Official)), // TL: pt-Latn
+    (19540, 7628148, 1853120844, 59.0, Some(OfficialStatus::Official)), // TL: tet-Latn
+    (19777, 31080, 1852666433, 98.0, Some(OfficialStatus::Official)), // AM: hy-Armn
+    (19777, 30059, 1853120844, 3.3, None), // AM: ku-Latn
+    (19777, 31329, 1853120844, 0.0, None), // AM: az-Latn
+    (19778, 28261, 1853120844, 92.0, Some(OfficialStatus::Official)), // BM: en-Latn
+    (19779, 29286, 1853120844, 68.0, Some(OfficialStatus::Official)), // CM: fr-Latn
+    (19779, 28261, 1853120844, 38.0, Some(OfficialStatus::Official)), // CM: en-Latn
+    (19779, 7173474, 1853120844, 4.6, None), // CM: bum-Latn
+    (19779, 26214, 1853120844, 3.6, None), // CM: ff-Latn
+    (19779, 7305061, 1853120844, 3.1, None), // CM: ewo-Latn
+    (19779, 6447737, 1853120844, 1.6, None), // CM: ybb-Latn
+    (19779, 6972002, 1853120844, 1.4, None), // CM: bbj-Latn
+    (19779, 6844014, 1853120844, 1.4, None), // CM: nnh-Latn
+    (19779, 7170914, 1853120844, 1.3, None), // CM: bkm-Latn
+    (19779, 7561570, 1853120844, 1.2, None), // CM: bas-Latn
+    (19779, 7889250, 1970102594, 1.2, None), // CM: bax-Bamu
+    (19779, 7764322, 1853120844, 1.1, None), // CM: byv-Latn
+    (19779, 6387053, 1853120844, 1.0, None), // CM: mua-Latn
+    (19779, 6709613, 1853120844, 0.74, None), // CM: maf-Latn
+    (19779, 6579810, 1853120844, 0.57, None), // CM: bfd-Latn
+    (19779, 7566178, 1853120844, 0.54, None), // CM: bss-Latn
+    (19779, 6974315, 1853120844, 0.54, None), // CM: kkj-Latn
+    (19779, 6387044, 1853120844, 0.48, None), // CM: dua-Latn
+    (19779, 7300973, 1853120844, 0.47, None), // CM: mgo-Latn
+    (19779, 29281, 1650553409, 0.39, None), // CM: ar-Arab
+    (19779, 7300970, 1853120844, 0.34, None), // CM: jgo-Latn
+    (19779, 6714219, 1853120844, 0.32, None), // CM: ksf-Latn
+    (19779, 7234923, 1853120844, 0.25, None), // CM: ken-Latn
+    (19779, 7432033, 1853120844, 0.14, None), // CM: agq-Latn
+    (19779, 24936, 1650553409, 0.14, None), // CM: ha-Arab
+    (19779, 6778222, 1853120844, 0.032, None), // CM: nmg-Latn
+    (19779, 7758201, 1853120844, 0.0083, None), // CM: yav-Latn
+    (19779, 26214, 1835820097, 0.0, None), // CM: ff-Adlm
+    (19780, 28261, 1853120844, 94.0, Some(OfficialStatus::Official)), // DM: en-Latn
+    (19782, 28261, 1853120844, 57.0, Some(OfficialStatus::Official)), // FM: en-Latn
+    (19782, 7039075, 1853120844, 30.0, None), // FM: chk-Latn
+    (19782, 7237488, 1853120844, 23.0, None), // FM: pon-Latn
+    (19782, 7565163, 1853120844, 7.8, None), // FM: kos-Latn
+    (19782, 7364985, 1853120844, 6.4, None), // FM: yap-Latn
+    (19782, 6909045, 1853120844, 2.9, None), // FM: uli-Latn
+    (19783, 28261, 1853120844, 40.0, Some(OfficialStatus::Official)), // GM: en-Latn
+    (19783, 7233901, 1853120844, 29.0, None), // GM: man-Latn
+    (19783, 26214, 1835820097, 0.0, None), // GM: ff-Adlm
+    (19783, 26214, 1853120844, 0.0, None), // GM: ff-Latn
+    (19785, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // IM: en-Latn
+    (19785, 30311, 1853120844, 1.9, Some(OfficialStatus::Official)), // IM: gv-Latn
+    (19786, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // JM: en-Latn
+    (19786, 7168362, 1853120844, 95.0, None), // JM: jam-Latn
+    (19787, 29281, 1650553409, 66.0, Some(OfficialStatus::Official)), // KM: ar-Arab
+    (19787, 29286, 1853120844, 56.0, Some(OfficialStatus::Official)), // KM: fr-Latn
+    (19787, 6972538, 1650553409, 37.0, Some(OfficialStatus::Official)), // KM: zdj-Arab
+    (19787, 6909559, 1650553409, 34.0, Some(OfficialStatus::Official)), // KM: wni-Arab
+    (19789, 31085, 1919777101, 64.0, Some(OfficialStatus::Official)), // MM: my-Mymr
+    (19789, 7235699, 1919777101, 6.4, None), // MM: shn-Mymr
+    (19789, 6513003, 1853120844, 1.7, None), // MM: kac-Latn
+    (19789, 6776946, 1734897490, 1.7, None), // MM: rhg-Rohg
+    (19789, 7827053, 1919777101, 1.5, None), // MM: mnw-Mymr
+    (19789, 6975080, 1869570380, 0.022, None), // MM: hnj-Laoo
+    (19789, 7628907, 1919777101, 0.0075, None), // MM: kht-Mymr
+    (19791, 29281, 1650553409, 81.0, Some(OfficialStatus::Official)), // OM: ar-Arab
+    (19791, 7102818, 1650553409, 4.9, None), // OM: bal-Arab
+    (19791, 24934, 1650553409, 0.94, None), // OM: fa-Arab
+    (19792, 29286, 1853120844, 96.0, Some(OfficialStatus::Official)), // PM: fr-Latn
+    (19792, 28261, 1853120844, 3.5, None), // PM: en-Latn
+    (19795, 29801, 1853120844, 89.0, Some(OfficialStatus::Official)), // SM: it-Latn
+    (19795, 28517, 1853120844, 0.88, None), // SM: eo-Latn
+    (19796, 27508, 1853120844, 70.0, Some(OfficialStatus::Official)), // TM: tk-Latn
+    (19796, 30066, 1819441475, 12.0, None), // TM: ru-Cyrl
+    (19796, 31349, 1853120844, 9.0, None), // TM: uz-Latn
+    (19796, 30059, 1853120844, 0.4, None), // TM: ku-Latn
+    (19797, 28261, 1853120844, 100.0, Some(OfficialStatus::DeFactoOfficial)), // UM: en-Latn
+    (19802, 7169378, 1853120844, 31.0, None), // ZM: bem-Latn
+    (19802, 28261, 1853120844, 16.0, Some(OfficialStatus::Official)), // ZM: en-Latn
+    (19802, 31086, 1853120844, 15.0, None), // ZM: ny-Latn
+    (19802, 8023916, 1853120844, 6.0, None), // ZM: loz-Latn
+    (20034, 29549, 1853120844, 93.0, Some(OfficialStatus::Official)), // BN: ms-Latn
+    (20034, 26746, 1953390920, 11.0, None), // BN: zh-Hant
+    (20034, 29549, 1650553409, 5.0, Some(OfficialStatus::Official)), // BN: ms-Arab
+    (20034, 28261, 1853120844, 1.7, None), // BN: en-Latn
+    (20035, 26746, 1936613704, 90.0, Some(OfficialStatus::Official)), // CN: zh-Hans
+    (20035, 7697783, 1936613704, 6.0, None), // CN: wuu-Hans
+    (20035, 6649209, 1936613704, 5.2, None), // CN: yue-Hans
+    (20035, 7238504, 1936613704, 2.9, None), // CN: hsn-Hans
+    (20035, 7037288, 1936613704, 2.3, None), // CN: hak-Hans
+    (20035, 7233902, 1936613704, 1.9, None), // CN: nan-Hans
+    (20035, 7233895, 1936613704, 1.7, None), // CN: gan-Hans
+    (20035, 26985, 1768515929, 0.6, None), // CN: ii-Yiii
+    (20035, 26485, 1650553409, 0.55, Some(OfficialStatus::OfficialRegional)), // CN: ug-Arab
+    (20035, 24954, 1853120844, 0.31, Some(OfficialStatus::OfficialRegional)), // CN: za-Latn
+    (20035, 28269, 1735290701, 0.26, Some(OfficialStatus::OfficialRegional)), // CN: mn-Mong
+    (20035, 28514, 1952606548, 0.2, Some(OfficialStatus::OfficialRegional)), // CN: bo-Tibt
+    (20035, 28523, 1701998411, 0.15, Some(OfficialStatus::OfficialRegional)), // CN: ko-Kore
+    (20035, 27499, 1650553409, 0.085, None), // CN: kk-Arab
+    (20035, 7563628, 1970497868, 0.045, None), // CN: lis-Lisu
+    (20035, 31083, 1650553409, 0.034, None), // CN: ky-Arab
+    (20035, 7436398, 1853120844, 0.024, None), // CN: nxq-Latn
+    (20035, 6449259, 1970037076, 0.019, None), // CN: khb-Talu
+    (20035, 6579316, 1701601620, 0.019, None), // CN: tdd-Tale
+    (20035, 7365484, 1767991380, 0.0058, None), // CN: lcp-Thai
+    (20035, 28261, 1853120844, 0.0045, None), // CN: en-Latn
+    (20035, 6975080, 1869570380, 0.004, None), // CN: hnj-Laoo
+    (20035, 30066, 1819441475, 0.001, None), // CN: ru-Cyrl
+    (20035, 26998, 1853120844, 0.0005, None), // CN: vi-Latn
+    (20035, 31349, 1819441475, 0.0004, None), // CN: uz-Cyrl
+    (20035, 6847084, 1936613704, 0.0, None), // CN: lzh-Hans
+    (20039, 29286, 1853120844, 29.0, Some(OfficialStatus::Official)), // GN: fr-Latn
+    (20039, 26214, 1853120844, 26.0, None), // GN: ff-Latn
+    (20039, 7233901, 1869572942, 23.0, None), // GN: man-Nkoo
+    (20039, 7566707, 1853120844, 11.0, None), // GN: sus-Latn
+    (20039, 7303534, 1869572942, 5.0, None), // GN: nqo-Nkoo
+    (20039, 6647915, 1853120844, 3.8, None), // GN: kpe-Latn
+    (20039, 26214, 1835820097, 0.0, None), // GN: ff-Adlm
+    (20040, 29541, 1853120844, 78.0, Some(OfficialStatus::Official)), // HN: es-Latn
+    (20040, 28261, 1853120844, 0.44, None), // HN: en-Latn
+    (20041, 26984, 1635149124, 41.0, Some(OfficialStatus::Official)), // IN: hi-Deva
+    (20041, 28261, 1853120844, 19.0, Some(OfficialStatus::Official)), // IN: en-Latn
+    (20041, 28258, 1735288130, 8.1, Some(OfficialStatus::OfficialRegional)), // IN: bn-Beng
+    (20041, 25972, 1970038100, 7.2, Some(OfficialStatus::OfficialRegional)), // IN: te-Telu
+    (20041, 29293, 1635149124, 7.0, Some(OfficialStatus::OfficialRegional)), // IN: mr-Deva
+    (20041, 24948, 1819107668, 5.9, Some(OfficialStatus::OfficialRegional)), // IN: ta-Taml
+    (20041, 29301, 1650553409, 5.0, Some(OfficialStatus::OfficialRegional)), // IN: ur-Arab
+    (20041, 30055, 1919579463, 4.5, Some(OfficialStatus::OfficialRegional)), // IN: gu-Gujr
+    (20041, 28267, 1633971787, 3.7, Some(OfficialStatus::OfficialRegional)), // IN: kn-Knda
+    (20041, 27757, 1836674125, 3.2, Some(OfficialStatus::OfficialRegional)), // IN: ml-Mlym
+    (20041, 29295, 1635349071, 3.2, Some(OfficialStatus::OfficialRegional)), // IN: or-Orya
+    (20041, 24944, 1970435399, 2.8, Some(OfficialStatus::OfficialRegional)), // IN: pa-Guru
+    (20041, 7301218, 1635149124, 2.3, None), // IN: bho-Deva
+    (20041, 6387553, 1635149124, 1.9, None), // IN: awa-Deva
+    (20041, 29537, 1735288130, 1.3, Some(OfficialStatus::OfficialRegional)), // IN: as-Beng
+    (20041, 6906221, 1635149124, 1.2, Some(OfficialStatus::OfficialRegional)), // IN: mai-Deva
+    (20041, 6514530, 1635149124, 1.2, None), // IN: bgc-Deva
+    (20041, 6775149, 1635149124, 1.2, None), // IN: mag-Deva
+    (20041, 7501677, 1635149124, 1.2, None), // IN: mwr-Deva
+    (20041, 6647400, 1635149124, 1.1, None), // IN: hne-Deva
+    (20041, 6513508, 1650553409, 0.99, None), // IN: dcc-Arab
+    (20041, 25966, 1635149124, 0.56, Some(OfficialStatus::OfficialRegional)), // IN: ne-Deva
+    (20041, 6974050, 1635149124, 0.56, None), // IN: bjj-Deva
+    (20041, 7627123, 1801677903, 0.55, Some(OfficialStatus::OfficialRegional)), // IN: sat-Olck
+    (20041, 7173239, 1635149124, 0.46, None), // IN: wtm-Deva
+    (20041, 7629682, 1735288130, 0.43, None), // IN: rkt-Beng
+    (20041, 29547, 1650553409, 0.41, Some(OfficialStatus::OfficialRegional)), // IN: ks-Arab
+    (20041, 7040875, 1635149124, 0.37, Some(OfficialStatus::OfficialRegional)), // IN: kok-Deva
+    (20041, 7171943, 1635149124, 0.32, None), // IN: gom-Deva
+    (20041, 7763827, 1635149124, 0.28, None), // IN: swv-Deva
+    (20041, 7168615, 1635149124, 0.27, None), // IN: gbm-Deva
+    (20041, 7236972, 1970038100, 0.27, None), // IN: lmn-Telu
+    (20041, 25715, 1635149124, 0.26, Some(OfficialStatus::OfficialRegional)), // IN: sd-Deva
+    (20041, 7237479, 1970038100, 0.24, None), // IN: gon-Telu
+    (20041, 7956075, 1635149124, 0.22, None), // IN: kfy-Deva
+    (20041, 6909796, 1635149124, 0.2, None), // IN: doi-Deva
+    (20041, 7697003, 1635149124, 0.19, None), // IN: kru-Deva
+    (20041, 7037811, 1635149124, 0.18, None), // IN: sck-Deva
+    (20041, 7430775, 1970038100, 0.18, None), // IN: wbq-Telu
+    (20041, 7499384, 1635149124, 0.16, None), // IN: xnr-Deva
+    (20041, 7235691, 1635149124, 0.15, None), // IN: khn-Deva
+    (20041, 7955316, 1633971787, 0.15, None), // IN: tcy-Knda
+    (20041, 7496311, 1635149124, 0.15, None), // IN: wbr-Deva
+    (20041, 7893602, 1635149124, 0.14, None), // IN: brx-Deva
+    (20041, 6647662, 1635149124, 0.13, None), // IN: noe-Deva
+    (20041, 6449250, 1635149124, 0.12, None), // IN: bhb-Deva
+    (20041, 6909549, 1735288130, 0.11, None), // IN: mni-Beng
+    (20041, 26984, 1853120844, 0.1, None), // IN: hi-Latn
+    (20041, 6971762, 1635149124, 0.1, None), // IN: raj-Deva
+    (20041, 6516584, 1635149124, 0.099, None), // IN: hoc-Deva
+    (20041, 7500909, 1635149124, 0.097, None), // IN: mtr-Deva
+    (20041, 7499381, 1735288130, 0.094, None), // IN: unr-Beng
+    (20041, 6908002, 1635149124, 0.092, None), // IN: bhi-Deva
+    (20041, 6975336, 1635149124, 0.082, None), // IN: hoj-Deva
+    (20041, 6383723, 1853120844, 0.08, Some(OfficialStatus::OfficialRegional)), // IN: kha-Latn
+    (20041, 7497323, 1635149124, 0.075, None), // IN: kfr-Deva
+    (20041, 7631463, 1735288130, 0.053, None), // IN: grt-Beng
+    (20041, 7892597, 1735288130, 0.048, None), // IN: unx-Beng
+    (20041, 7956066, 1635149124, 0.037, None), // IN: bfy-Deva
+    (20041, 7893619, 1635149124, 0.035, None), // IN: srx-Deva
+    (20041, 8020339, 1920295251, 0.029, None), // IN: saz-Saur
+    (20041, 7365475, 1835753795, 0.028, None), // IN: ccp-Cakm
+    (20041, 7431778, 1819107668, 0.023, None), // IN: bfq-Taml
+    (20041, 7301742, 1853120844, 0.023, None), // IN: njo-Latn
+    (20041, 6383986, 1853120844, 0.013, None), // IN: ria-Latn
+    (20041, 28514, 1952606548, 0.011, None), // IN: bo-Tibt
+    (20041, 7958626, 1735288130, 0.0068, None), // IN: bpy-Beng
+    (20041, 7628386, 1650553409, 0.0062, None), // IN: bft-Arab
+    (20041, 6386274, 1635149124, 0.0041, None), // IN: bra-Deva
+    (20041, 7365996, 1668310348, 0.0035, None), // IN: lep-Lepc
+    (20041, 7763042, 1635149124, 0.0026, None), // IN: btv-Deva
+    (20041, 6711660, 1635149124, 0.0026, None), // IN: lif-Deva
+    (20041, 6840684, 1650553409, 0.0025, None), // IN: lah-Arab
+    (20041, 24947, 1635149124, 0.0012, Some(OfficialStatus::OfficialRegional)), // IN: sa-Deva
+    (20041, 7628907, 1919777101, 0.0007, None), // IN: kht-Mymr
+    (20041, 30308, 1633773652, 0.0003, None), // IN: dv-Thaa
+    (20041, 31332, 1952606548, 0.0002, None), // IN: dz-Tibt
+    (20043, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // KN: en-Latn
+    (20045, 28269, 1819441475, 93.0, Some(OfficialStatus::Official)), // MN: mn-Cyrl
+    (20045, 27499, 1650553409, 7.2, None), // MN: kk-Arab
+    (20045, 26746, 1936613704, 1.4, None), // MN: zh-Hans
+    (20045, 30066, 1819441475, 0.13, None), // MN: ru-Cyrl
+    (20045, 26485, 1819441475, 0.032, None), // MN: ug-Cyrl
+    (20048, 28261, 1853120844, 92.0, Some(OfficialStatus::Official)), // PN: en-Latn
+    (20051, 28535, 1853120844, 70.0, Some(OfficialStatus::DeFactoOfficial)), // SN: wo-Latn
+    (20051, 29286, 1853120844, 39.0, Some(OfficialStatus::Official)), // SN: fr-Latn
+    (20051, 26214, 1853120844, 21.0, Some(OfficialStatus::OfficialRegional)), // SN: ff-Latn
+    (20051, 7500403, 1853120844, 11.0, Some(OfficialStatus::OfficialRegional)), // SN: srr-Latn
+    (20051, 7305572, 1853120844, 2.6, Some(OfficialStatus::OfficialRegional)), // SN: dyo-Latn
+    (20051, 7758195, 1853120844, 1.5, Some(OfficialStatus::OfficialRegional)), // SN: sav-Latn
+    (20051, 7759469, 1853120844, 0.77, Some(OfficialStatus::OfficialRegional)), // SN: mfv-Latn
+    (20051, 7629410, 1853120844, 0.61, Some(OfficialStatus::OfficialRegional)), // SN: bjt-Latn
+    (20051, 6712947, 1853120844, 0.24, Some(OfficialStatus::OfficialRegional)), // SN: snf-Latn
+    (20051, 6712939, 1853120844, 0.21, Some(OfficialStatus::OfficialRegional)), // SN: knf-Latn
+    (20051, 6517602, 1853120844, 0.097, Some(OfficialStatus::OfficialRegional)), // SN: bsc-Latn
+    (20051, 7955821, 1853120844, 0.046, Some(OfficialStatus::OfficialRegional)), // SN: mey-Latn
+    (20051, 7499380, 1853120844, 0.021, Some(OfficialStatus::OfficialRegional)), // SN: tnr-Latn
+    (20051, 26214, 1835820097, 0.0, None), // SN: ff-Adlm
+    (20052, 29281, 1650553409, 90.0, Some(OfficialStatus::Official)), // TN: ar-Arab
+    (20052, 6448481, 1650553409, 90.0, None), // TN: aeb-Arab
+    (20052, 29286, 1853120844, 74.0, Some(OfficialStatus::Official)), // TN: fr-Latn
+    (20054, 26998, 1853120844, 86.0, Some(OfficialStatus::Official)), // VN: vi-Latn
+    (20054, 26746, 1953390920, 1.1, None), // VN: zh-Hant
+    (20054, 7629922, 1953915220, 0.69, None), // VN: blt-Tavt
+    (20054, 6975080, 1869570380, 0.17, None), // VN: hnj-Laoo
+    (20054, 7170659, 1835100227, 0.089, None), // VN: cjm-Cham
+    (20289, 29808, 1853120844, 67.0, Some(OfficialStatus::Official)), // AO: pt-Latn
+    (20289, 6450549, 1853120844, 29.0, None), // AO: umb-Latn
+    (20289, 6450539, 1853120844, 25.0, None), // AO: kmb-Latn
+    (20289, 28268, 1853120844, 0.67, None), // AO: ln-Latn
+    (20290, 29541, 1853120844, 61.0, Some(OfficialStatus::Official)), // BO: es-Latn
+    (20290, 30065, 1853120844, 32.0, Some(OfficialStatus::Official)), // BO: qu-Latn
+    (20290, 31073, 1853120844, 20.0, Some(OfficialStatus::Official)), // BO: ay-Latn
+    (20290, 28263, 1853120844, 0.45, None), // BO: gn-Latn
+    (20290, 7303777, 1853120844, 0.0009, None), // BO: aro-Latn
+    (20291, 29541, 1853120844, 93.0, Some(OfficialStatus::Official)), // CO: es-Latn
+    (20291, 6518119, 1853120844, 0.27, None), // CO: guc-Latn
+    (20291, 7107193, 1853120844, 0.0061, None), // CO: yrl-Latn
+    (20292, 29541, 1853120844, 78.0, Some(OfficialStatus::Official)), // DO: es-Latn
+    (20292, 28261, 1853120844, 0.076, None), // DO: en-Latn
+    (20294, 28518, 1853120844, 95.0, Some(OfficialStatus::Official)), // FO: fo-Latn
+    (20297, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // IO: en-Latn
+    (20298, 29281, 1650553409, 100.0, Some(OfficialStatus::Official)), // JO: ar-Arab
+    (20298, 6516833, 1650553409, 66.0, None), // JO: apc-Arab
+    (20298, 28261, 1853120844, 45.0, None), // JO: en-Latn
+    (20301, 26746, 1953390920, 98.0, Some(OfficialStatus::Official)), // MO: zh-Hant
+    (20301, 29808, 1853120844, 5.0, Some(OfficialStatus::Official)), // MO: pt-Latn
+    (20301, 28261, 1853120844, 2.3, None), // MO: en-Latn
+    (20302, 25198, 1853120844, 100.0, Some(OfficialStatus::Official)), // NO: nb-Latn
+    (20302, 28526, 1853120844, 100.0, Some(OfficialStatus::Official)), // NO: no-Latn
+    (20302, 28270, 1853120844, 25.0, Some(OfficialStatus::Official)), // NO: nn-Latn
+    (20302, 25971, 1853120844, 0.29, Some(OfficialStatus::OfficialRegional)), // NO: se-Latn
+    (20306, 28530, 1853120844, 90.0, Some(OfficialStatus::Official)), // RO: ro-Latn
+    (20306, 28261, 1853120844, 31.0, None), // RO: en-Latn
+    (20306, 29286, 1853120844, 17.0, None), // RO: fr-Latn
+    (20306, 29541, 1853120844, 10.0, None), // RO: es-Latn
+    (20306, 30056, 1853120844, 6.6, None), // RO: hu-Latn
+    (20306, 25956, 1853120844, 0.21, None), // RO: de-Latn
+    (20306, 29300, 1853120844, 0.13, None), // RO: tr-Latn
+    (20306, 29299, 1853120844, 0.12, None), // RO: sr-Latn
+    (20306, 26466, 1819441475, 0.032, None), // RO: bg-Cyrl
+    (20306, 27749, 1801810503, 0.019, None), // RO: el-Grek
+    (20306, 27760, 1853120844, 0.013, None), // RO: pl-Latn
+    (20307, 28531, 1853120844, 78.0, Some(OfficialStatus::Official)), // SO: so-Latn
+    (20307, 29281, 1650553409, 34.0, Some(OfficialStatus::Official)), // SO: ar-Arab
+    (20307, 30579, 1853120844, 2.0, None), // SO: sw-Latn
+    (20307, 28015, 1853120844, 0.42, None), // SO: om-Latn
+    (20308, 28532, 1853120844, 95.0, Some(OfficialStatus::Official)), // TO: to-Latn
+    (20308, 28261, 1853120844, 28.0, Some(OfficialStatus::Official)), // TO: en-Latn
+    (20551, 29286, 1853120844, 90.0, Some(OfficialStatus::Official)), // GP: fr-Latn
+    (20554, 24938, 1851879498, 95.0, Some(OfficialStatus::Official)), // JP: ja-Jpan
+    (20554, 7698802, 1634623819, 0.77, None), // JP: ryu-Kana
+    (20554, 28523, 1701998411, 0.52, None), // JP: ko-Kore
+    (20555, 28523, 1701998411, 88.0, Some(OfficialStatus::Official)), // KP: ko-Kore
+    (20557, 28261, 1853120844, 97.0, Some(OfficialStatus::DeFactoOfficial)), // MP: en-Latn
+    (20557, 26723, 1853120844, 18.0, None), // MP: ch-Latn
+    (20558, 25966, 1635149124, 44.0, Some(OfficialStatus::Official)), // NP: ne-Deva
+    (20558, 6906221, 1635149124, 11.0, None), // NP: mai-Deva
+    (20558, 7301218, 1635149124, 6.8, None), // NP: bho-Deva
+    (20558, 7824750, 1635149124, 3.3, None), // NP: new-Deva
+    (20558, 7105898, 1635149124, 3.2, None), // NP: jml-Deva
+    (20558, 28261, 1853120844, 3.0, None), // NP: en-Latn
+    (20558, 7959652, 1635149124, 2.5, None), // NP: dty-Deva
+    (20558, 6387553, 1635149124, 2.2, None), // NP: awa-Deva
+    (20558, 7104628, 1635149124, 2.0, None), // NP: thl-Deva
+    (20558, 7364962, 1635149124, 1.5, None), // NP: bap-Deva
+    (20558, 6775924, 1635149124, 1.3, None), // NP: tdg-Deva
+    (20558, 7497844, 1635149124, 1.2, None), // NP: thr-Deva
+    (20558, 6711660, 1635149124, 1.1, None), // NP: lif-Deva
+    (20558, 7366509, 1635149124, 1.1, None), // NP: mgp-Deva
+    (20558, 7432308, 1635149124, 1.0, None), // NP: thq-Deva
+    (20558, 6582893, 1635149124, 0.83, None), // NP: mrd-Deva
+    (20558, 7956066, 1635149124, 0.54, None), // NP: bfy-Deva
+    (20558, 7500664, 1635149124, 0.52, None), // NP: xsr-Deva
+    (20558, 7563890, 1635149124, 0.44, None), // NP: rjs-Deva
+    (20558, 6971764, 1635149124, 0.43, None), // NP: taj-Deva
+    (20558, 26984, 1635149124, 0.42, None), // NP: hi-Deva
+    (20558, 7501415, 1635149124, 0.29, None), // NP: gvr-Deva
+    (20558, 28514, 1952606548, 0.24, None), // NP: bo-Tibt
+    (20558, 7629684, 1635149124, 0.24, None), // NP: tkt-Deva
+    (20558, 6841460, 1635149124, 0.12, None), // NP: tdh-Deva
+    (20558, 28258, 1735288130, 0.094, None), // NP: bn-Beng
+    (20558, 7499381, 1635149124, 0.019, None), // NP: unr-Deva
+    (20558, 7365996, 1668310348, 0.0093, None), // NP: lep-Lepc
+    (20802, 7364976, 1853120844, 81.0, None), // BQ: pap-Latn
+    (20802, 27758, 1853120844, 8.0, Some(OfficialStatus::Official)), // BQ: nl-Latn
+    (20803, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // CQ: en-Latn
+    (20807, 29541, 1853120844, 87.0, Some(OfficialStatus::Official)), // GQ: es-Latn
+    (20807, 7233894, 1853120844, 51.0, None), // GQ: fan-Latn
+    (20807, 29286, 1853120844, 8.8, Some(OfficialStatus::Official)), // GQ: fr-Latn
+    (20807, 6452834, 1853120844, 7.9, None), // GQ: bvb-Latn
+    (20807, 29808, 1853120844, 0.0001, Some(OfficialStatus::Official)), // GQ: pt-Latn
+    (20809, 29281, 1650553409, 68.0, Some(OfficialStatus::Official)), // IQ: ar-Arab
+    (20809, 28261, 1853120844, 35.0, None), // IQ: en-Latn
+    (20809, 6450019, 1650553409, 20.0, Some(OfficialStatus::OfficialRegional)), // IQ: ckb-Arab
+    (20809, 31329, 1650553409, 1.8, Some(OfficialStatus::OfficialRegional)), // IQ: az-Arab
+    (20809, 24934, 1650553409, 0.87, None), // IQ: fa-Arab
+    (20809, 6517356, 1650553409, 0.61, None), // IQ: lrc-Arab
+    (20809, 7502195, 1668446547, 0.5, None), // IQ: syr-Syrc
+    (20813, 29286, 1853120844, 98.0, Some(OfficialStatus::Official)), // MQ: fr-Latn
+    (21057, 29541, 1853120844, 100.0, Some(OfficialStatus::Official)), // AR: es-Latn
+    (21057, 28261, 1853120844, 7.0, None), // AR: en-Latn
+    (21057, 31075, 1853120844, 0.066, None), // AR: cy-Latn
+    (21057, 28263, 1853120844, 0.047, None), // AR: gn-Latn
+    (21058, 29808, 1853120844, 91.0, Some(OfficialStatus::Official)), // BR: pt-Latn
+    (21058, 28261, 1853120844, 8.0, None), // BR: en-Latn
+    (21058, 25956, 1853120844, 0.84, None), // BR: de-Latn
+    (21058, 29801, 1853120844, 0.28, None), // BR: it-Latn
+    (21058, 6514038, 1853120844, 0.24, Some(OfficialStatus::OfficialRegional)), // BR: vec-Latn
+    (21058, 24938, 1851879498, 0.21, None), // BR: ja-Jpan
+    (21058, 29541, 1853120844, 0.036, None), // BR: es-Latn
+    (21058, 7366507, 1853120844, 0.024, None), // BR: kgp-Latn
+    (21058, 28523, 1701998411, 0.021, None), // BR: ko-Kore
+    (21058, 7107193, 1853120844, 0.01, None), // BR: yrl-Latn
+    (21058, 6452583, 1853120844, 0.0084, None), // BR: gub-Latn
+    (21058, 7758200, 1853120844, 0.0047, None), // BR: xav-Latn
+    (21059, 29541, 1853120844, 95.0, Some(OfficialStatus::Official)), // CR: es-Latn
+    (21061, 26996, 1768453189, 60.0, Some(OfficialStatus::DeFactoOfficial)), // ER: ti-Ethi
+    (21061, 28261, 1853120844, 59.0, Some(OfficialStatus::Official)), // ER: en-Latn
+    (21061, 6777204, 1768453189, 18.0, None), // ER: tig-Ethi
+    (21061, 29281, 1650553409, 4.9, Some(OfficialStatus::Official)), // ER: ar-Arab
+    (21061, 24929, 1853120844, 3.6, None), // ER: aa-Latn
+    (21061, 7959411, 1853120844, 3.6, None), // ER: ssy-Latn
+    (21061, 7240034, 1768453189, 1.3, None), // ER: byn-Ethi
+    (21062, 29286, 1853120844, 99.0, Some(OfficialStatus::Official)), // FR: fr-Latn
+    (21062, 28261, 1853120844, 39.0, None), // FR: en-Latn
+    (21062, 29541, 1853120844, 13.0, None), // FR: es-Latn
+    (21062, 25956, 1853120844, 5.0, None), // FR: de-Latn
+    (21062, 25455, 1853120844, 3.0, None), // FR: oc-Latn
+    (21062, 29801, 1853120844, 1.7, None), // FR: it-Latn
+    (21062, 29808, 1853120844, 1.3, None), // FR: pt-Latn
+    (21062, 6579056, 1853120844, 1.1, None), // FR: pcd-Latn
+    (21062, 7828327, 1853120844, 0.91, None), // FR: gsw-Latn
+    (21062, 29282, 1853120844, 0.83, None), // FR: br-Latn
+    (21062, 28515, 1853120844, 0.24, None), // FR: co-Latn
+    (21062, 6975080, 1869570380, 0.19, None), // FR: hnj-Laoo
+    (21062, 24931, 1853120844, 0.17, None), // FR: ca-Latn
+    (21062, 30053, 1853120844, 0.13, None), // FR: eu-Latn
+    (21062, 27758, 1853120844, 0.13, None), // FR: nl-Latn
+    (21062, 7369318, 1853120844, 0.094, None), // FR: frp-Latn
+    (21062, 24937, 1853120844, 0.0002, None), // FR: ia-Latn
+    (21063, 27749, 1801810503, 99.0, Some(OfficialStatus::Official)), // GR: el-Grek
+    (21063, 28261, 1853120844, 51.0, None), // GR: en-Latn
+    (21063, 29286, 1853120844, 9.0, None), // GR: fr-Latn
+    (21063, 25956, 1853120844, 5.0, None), // GR: de-Latn
+    (21063, 7630448, 1801810503, 3.7, None), // GR: pnt-Grek
+    (21063, 27501, 1819441475, 1.6, None), // GR: mk-Cyrl
+    (21063, 29300, 1853120844, 1.2, None), // GR: tr-Latn
+    (21063, 26466, 1819441475, 0.27, None), // GR: bg-Cyrl
+    (21063, 29043, 1853120844, 0.094, None), // GR: sq-Latn
+    (21063, 6583156, 1801810503, 0.0019, None), // GR: tsd-Grek
+    (21064, 29288, 1853120844, 99.0, Some(OfficialStatus::Official)), // HR: hr-Latn
+    (21064, 28261, 1853120844, 49.0, None), // HR: en-Latn
+    (21064, 29801, 1853120844, 1.6, Some(OfficialStatus::OfficialRegional)), // HR: it-Latn
+    (21064, 6514038, 1853120844, 0.7, Some(OfficialStatus::OfficialRegional)), // HR: vec-Latn
+    (21065, 24934, 1650553409, 75.0, Some(OfficialStatus::Official)), // IR: fa-Arab
+    (21065, 31329, 1650553409, 24.0, None), // IR: az-Arab
+    (21065, 7240301, 1650553409, 5.0, None), // IR: mzn-Arab
+    (21065, 7040103, 1650553409, 4.6, None), // IR: glk-Arab
+    (21065, 6450019, 1650553409, 3.9, None), // IR: ckb-Arab
+    (21065, 6841459, 1650553409, 3.7, None), // IR: sdh-Arab
+    (21065, 27508, 1853120844, 2.8, None), // IR: tk-Latn
+    (21065, 6517356, 1650553409, 2.1, None), // IR: lrc-Arab
+    (21065, 29281, 1650553409, 2.0, None), // IR: ar-Arab
+    (21065, 7102818, 1650553409, 2.0, None), // IR: bal-Arab
+    (21065, 7630194, 1650553409, 1.9, None), // IR: rmt-Arab
+    (21065, 6910306, 1650553409, 1.4, None), // IR: bqi-Arab
+    (21065, 8025452, 1650553409, 1.2, None), // IR: luz-Arab
+    (21065, 6908780, 1650553409, 0.76, None), // IR: lki-Arab
+    (21065, 7235426, 1650553409, 0.56, None), // IR: bgn-Arab
+    (21065, 6582896, 1650553409, 0.5, None), // IR: prd-Arab
+    (21065, 31080, 1852666433, 0.24, None), // IR: hy-Armn
+    (21065, 29552, 1650553409, 0.16, None), // IR: ps-Arab
+    (21065, 24939, 1919903047, 0.071, None), // IR: ka-Geor
+    (21065, 8020583, 1650553409, 0.0094, None), // IR: gbz-Arab
+    (21065, 27499, 1650553409, 0.0035, None), // IR: kk-Arab
+    (21067, 28523, 1701998411, 100.0, Some(OfficialStatus::Official)), // KR: ko-Kore
+    (21068, 28261, 1853120844, 83.0, Some(OfficialStatus::Official)), // LR: en-Latn
+    (21068, 6647915, 1853120844, 14.0, None), // LR: kpe-Latn
+    (21068, 6906230, 1768513878, 2.6, None), // LR: vai-Vaii
+    (21068, 7234925, 1853120844, 0.48, None), // LR: men-Latn
+    (21068, 26214, 1835820097, 0.0, None), // LR: ff-Adlm
+    (21068, 26214, 1853120844, 0.0, None), // LR: ff-Latn
+    (21068, 6906230, 1853120844, 0.0, None), // LR: vai-Latn
+    (21069, 29281, 1650553409, 85.0, Some(OfficialStatus::Official)), // MR: ar-Arab
+    (21069, 29286, 1853120844, 17.0, None), // MR: fr-Latn
+    (21069, 26214, 1853120844, 5.7, None), // MR: ff-Latn
+    (21069, 28535, 1853120844, 0.25, None), // MR: wo-Latn
+    (21069, 26214, 1835820097, 0.0, None), // MR: ff-Adlm
+    (21070, 28261, 1853120844, 85.0, Some(OfficialStatus::Official)), // NR: en-Latn
+    (21070, 24942, 1853120844, 63.0, Some(OfficialStatus::Official)), // NR: na-Latn
+    (21072, 29541, 1853120844, 87.0, Some(OfficialStatus::Official)), // PR: es-Latn
+    (21072, 28261, 1853120844, 49.0, Some(OfficialStatus::DeFactoOfficial)), // PR: en-Latn
+    (21075, 27758, 1853120844, 90.0, Some(OfficialStatus::Official)), // SR: nl-Latn
+    (21075, 7238259, 1853120844, 68.0, None), // SR: srn-Latn
+    (21075, 26746, 1953390920, 1.1, None), // SR: zh-Hant
+    (21075, 6975080, 1869570380, 0.38, None), // SR: hnj-Laoo
+    (21076, 29300, 1853120844, 93.0, Some(OfficialStatus::Official)), // TR: tr-Latn
+    (21076, 28261, 1853120844, 17.0, None), // TR: en-Latn
+    (21076, 30059, 1853120844, 5.5, None), // TR: ku-Latn
+    (21076, 6516833, 1650553409, 5.2, None), // TR: apc-Arab
+    (21076, 6388346, 1853120844, 1.4, None), // TR: zza-Latn
+    (21076, 6578795, 1819441475, 0.77, None), // TR: kbd-Cyrl
+    (21076, 31329, 1853120844, 0.74, None), // TR: az-Latn
+    (21076, 31329, 1650553409, 0.65, None), // TR: az-Arab
+    (21076, 29281, 1650553409, 0.56, None), // TR: ar-Arab
+    (21076, 7890786, 1801810503, 0.46, None), // TR: bgx-Grek
+    (21076, 26466, 1819441475, 0.42, None), // TR: bg-Cyrl
+    (21076, 7955553, 1819441475, 0.39, None), // TR: ady-Cyrl
+    (21076, 7694699, 1853120844, 0.19, None), // TR: kiu-Latn
+    (21076, 31080, 1852666433, 0.056, None), // TR: hy-Armn
+    (21076, 24939, 1919903047, 0.056, None), // TR: ka-Geor
+    (21076, 8026732, 1853120844, 0.028, None), // TR: lzz-Latn
+    (21076, 29299, 1853120844, 0.028, None), // TR: sr-Latn
+    (21076, 29043, 1853120844, 0.021, None), // TR: sq-Latn
+    (21076, 25185, 1819441475, 0.0049, None), // TR: ab-Cyrl
+    (21076, 27749, 1801810503, 0.0049, None), // TR: el-Grek
+    (21076, 7697012, 1853120844, 0.0037, None), // TR: tru-Latn
+    (21076, 31349, 1853120844, 0.0024, None), // TR: uz-Latn
+    (21076, 31083, 1853120844, 0.0014, None), // TR: ky-Latn
+    (21076, 27499, 1819441475, 0.0007, None), // TR: kk-Cyrl
+    (21313, 28019, 1853120844, 99.0, Some(OfficialStatus::Official)), // AS: sm-Latn
+    (21313, 28261, 1853120844, 97.0, Some(OfficialStatus::DeFactoOfficial)), // AS: en-Latn
+    (21314, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // BS: en-Latn
+    (21317, 29541, 1853120844, 99.0, Some(OfficialStatus::Official)), // ES: es-Latn
+    (21317, 28261, 1853120844, 24.0, None), // ES: en-Latn
+    (21317, 24931, 1853120844, 17.0, Some(OfficialStatus::OfficialRegional)), // ES: ca-Latn
+    (21317, 27751, 1853120844, 7.0, Some(OfficialStatus::OfficialRegional)), // ES: gl-Latn
+    (21317, 30053, 1853120844, 2.0, Some(OfficialStatus::OfficialRegional)), // ES: eu-Latn
+    (21317, 7631713, 1853120844, 1.3, Some(OfficialStatus::OfficialRegional)), // ES: ast-Latn
+    (21317, 7632997, 1853120844, 0.49, None), // ES: ext-Latn
+    (21317, 28257, 1853120844, 0.052, None), // ES: an-Latn
+    (21317, 25455, 1853120844, 0.0099, Some(OfficialStatus::OfficialRegional)), // ES: oc-Latn
+    (21321, 29545, 1853120844, 100.0, Some(OfficialStatus::Official)), // IS: is-Latn
+    (21321, 24932, 1853120844, 0.64, None), // IS: da-Latn
+    (21324, 29811, 1853120844, 98.0, Some(OfficialStatus::Official)), // LS: st-Latn
+    (21324, 28261, 1853120844, 27.0, Some(OfficialStatus::Official)), // LS: en-Latn
+    (21324, 30074, 1853120844, 14.0, None), // LS: zu-Latn
+    (21324, 29555, 1853120844, 2.4, None), // LS: ss-Latn
+    (21324, 26744, 1853120844, 0.99, None), // LS: xh-Latn
+    (21325, 28261, 1853120844, 65.0, Some(OfficialStatus::Official)), // MS: en-Latn
+    (21328, 29281, 1650553409, 100.0, Some(OfficialStatus::Official)), // PS: ar-Arab
+    (21328, 6516833, 1650553409, 87.0, None), // PS: apc-Arab
+    (21330, 29299, 1819441475, 99.0, Some(OfficialStatus::Official)), // RS: sr-Cyrl
+    (21330, 29299, 1853120844, 99.0, Some(OfficialStatus::Official)), // RS: sr-Latn
+    (21330, 29043, 1853120844, 19.0, None), // RS: sq-Latn
+    (21330, 30056, 1853120844, 4.8, Some(OfficialStatus::OfficialRegional)), // RS: hu-Latn
+    (21330, 28530, 1853120844, 2.1, Some(OfficialStatus::OfficialRegional)), // RS: ro-Latn
+    (21330, 29288, 1853120844, 0.93, Some(OfficialStatus::OfficialRegional)), // RS: hr-Latn
+    (21330, 27507, 1853120844, 0.85, Some(OfficialStatus::OfficialRegional)), // RS: sk-Latn
+    (21330, 27509, 1819441475, 0.0, Some(OfficialStatus::OfficialRegional)), // RS: uk-Cyrl
+    (21331, 28261, 1853120844, 27.0, Some(OfficialStatus::Official)), // SS: en-Latn
+    (21331, 29281, 1650553409, 27.0, None), // SS: ar-Arab
+    (21331, 7566702, 1853120844, 5.6, None), // SS: nus-Latn
+    (21333, 28261, 1853120844, 96.0, Some(OfficialStatus::DeFactoOfficial)), // US: en-Latn
+    (21333, 29541, 1853120844, 9.6, Some(OfficialStatus::OfficialRegional)), // US: es-Latn
+    (21333, 26746, 1953390920, 0.69, None), // US: zh-Hant
+    (21333, 29286, 1853120844, 0.56, None), // US: fr-Latn
+    (21333, 25956, 1853120844, 0.47, None), // US: de-Latn
+    (21333, 7104870, 1853120844, 0.42, None), // US: fil-Latn
+    (21333, 29801, 1853120844, 0.34, None), // US: it-Latn
+    (21333, 26998, 1853120844, 0.34, None), // US: vi-Latn
+    (21333, 28523, 1701998411, 0.3, None), // US: ko-Kore
+    (21333, 30066, 1819441475, 0.24, None), // US: ru-Cyrl
+    (21333, 30318, 1853120844, 0.05, None), // US: nv-Latn
+    (21333, 27001, 1919051080, 0.049, None), // US: yi-Hebr
+    (21333, 6513776, 1853120844, 0.039, None), // US: pdc-Latn
+    (21333, 6975080, 1869570380, 0.035, None), // US: hnj-Laoo
+    (21333, 7823720, 1853120844, 0.0089, Some(OfficialStatus::OfficialRegional)), // US: haw-Latn
+    (21333, 6517350, 1853120844, 0.0084, None), // US: frc-Latn
+    (21333, 7497827, 1919248451, 0.0077, None), // US: chr-Cher
+    (21333, 7697253, 1853120844, 0.0063, None), // US: esu-Latn
+    (21333, 7037284, 1853120844, 0.0059, None), // US: dak-Latn
+    (21333, 7301219, 1853120844, 0.0033, None), // US: cho-Latn
+    (21333, 7629676, 1853120844, 0.0025, None), // US: lkt-Latn
+    (21333, 27497, 1853120844, 0.0024, None), // US: ik-Latn
+    (21333, 7566701, 1853120844, 0.0012, None), // US: mus-Latn
+    (21333, 6578531, 1853120844, 0.0, None), // US: cad-Latn
+    (21333, 6515043, 1853120844, 0.0, None), // US: cic-Latn
+    (21333, 28521, 1853120844, 0.0, None), // US: io-Latn
+    (21333, 7299690, 1853120844, 0.0, None), // US: jbo-Latn
+    (21333, 6386543, 1701278543, 0.0, None), // US: osa-Osge
+    (21335, 28019, 1853120844, 100.0, Some(OfficialStatus::Official)), // WS: sm-Latn
+    (21335, 28261, 1853120844, 2.1, Some(OfficialStatus::Official)), // WS: en-Latn
+    (21569, 25956, 1853120844, 97.0, Some(OfficialStatus::Official)), // AT: de-Latn
+    (21569, 7496034, 1853120844, 95.0, None), // AT: bar-Latn
+    (21569, 28261, 1853120844, 73.0, None), // AT: en-Latn
+    (21569, 29286, 1853120844, 11.0, None), // AT: fr-Latn
+    (21569, 29801, 1853120844, 9.0, None), // AT: it-Latn
+    (21569, 29288, 1853120844, 1.2, Some(OfficialStatus::OfficialRegional)), // AT: hr-Latn
+    (21569, 27763, 1853120844, 0.37, Some(OfficialStatus::OfficialRegional)), // AT: sl-Latn
+    (21569, 30056, 1853120844, 0.26, Some(OfficialStatus::OfficialRegional)), // AT: hu-Latn
+    (21570, 31332, 1952606548, 47.0, Some(OfficialStatus::Official)), // BT: dz-Tibt
+    (21570, 25966, 1635149124, 17.0, None), // BT: ne-Deva
+    (21570, 6976372, 1952606548, 15.0, None), // BT: tsj-Tibt
+    (21570, 28261, 1853120844, 11.0, None), // BT: en-Latn
+    (21570, 7365996, 1668310348, 3.9, None), // BT: lep-Lepc
+    (21573, 28261, 1853120844, 43.0, None), // ET: en-Latn
+    (21573, 28001, 1768453189, 33.0, Some(OfficialStatus::Official)), // ET: am-Ethi
+    (21573, 28015, 1853120844, 32.0, None), // ET: om-Latn
+    (21573, 28531, 1853120844, 6.0, None), // ET: so-Latn
+    (21573, 26996, 1768453189, 6.0, None), // ET: ti-Ethi
+    (21573, 6580595, 1853120844, 3.5, None), // ET: sid-Latn
+    (21573, 7102839, 1768453189, 1.8, None), // ET: wal-Ethi
+    (21573, 24929, 1853120844, 1.4, None), // ET: aa-Latn
+    (21573, 8021351, 1768453189, 0.0, None), // ET: gez-Ethi
+    (21575, 29541, 1853120844, 93.0, Some(OfficialStatus::Official)), // GT: es-Latn
+    (21575, 6518129, 1853120844, 7.0, Some(OfficialStatus::OfficialRegional)), // GT: quc-Latn
+    (21576, 29800, 1853120844, 81.0, Some(OfficialStatus::Official)), // HT: ht-Latn
+    (21576, 29286, 1853120844, 4.7, Some(OfficialStatus::Official)), // HT: fr-Latn
+    (21577, 29801, 1853120844, 95.0, Some(OfficialStatus::Official)), // IT: it-Latn
+    (21577, 28261, 1853120844, 34.0, None), // IT: en-Latn
+    (21577, 29286, 1853120844, 6.3, Some(OfficialStatus::OfficialRegional)), // IT: fr-Latn
+    (21577, 7302508, 1853120844, 5.7, None), // IT: lmo-Latn
+    (21577, 25459, 1853120844, 1.7, None), // IT: sc-Latn
+    (21577, 25956, 1853120844, 1.6, None), // IT: de-Latn
+    (21577, 6514038, 1853120844, 1.3, Some(OfficialStatus::OfficialRegional)), // IT: vec-Latn
+    (21577, 7364974, 1853120844, 0.97, None), // IT: nap-Latn
+    (21577, 6973804, 1853120844, 0.86, None), // IT: lij-Latn
+    (21577, 7234419, 1853120844, 0.82, None), // IT: scn-Latn
+    (21577, 6513779, 1853120844, 0.17, None), // IT: sdc-Latn
+    (21577, 27763, 1853120844, 0.17, None), // IT: sl-Latn
+    (21577, 7501158, 1853120844, 0.06, None), // IT: fur-Latn
+    (21577, 7104357, 1853120844, 0.05, None), // IT: egl-Latn
+    (21577, 24931, 1853120844, 0.035, None), // IT: ca-Latn
+    (21577, 27749, 1801810503, 0.035, None), // IT: el-Grek
+    (21577, 7564656, 1853120844, 0.0099, None), // IT: pms-Latn
+    (21577, 29288, 1853120844, 0.0056, None), // IT: hr-Latn
+    (21577, 7235442, 1853120844, 0.0, None), // IT: rgn-Latn
+    (21580, 29804, 1853120844, 86.0, Some(OfficialStatus::Official)), // LT: lt-Latn
+    (21580, 30066, 1819441475, 80.0, None), // LT: ru-Cyrl
+    (21580, 28261, 1853120844, 38.0, None), // LT: en-Latn
+    (21580, 25956, 1853120844, 14.0, None), // LT: de-Latn
+    (21580, 7563123, 1853120844, 0.0, None), // LT: sgs-Latn
+    (21581, 29805, 1853120844, 100.0, Some(OfficialStatus::Official)), // MT: mt-Latn
+    (21581, 28261, 1853120844, 88.0, Some(OfficialStatus::Official)), // MT: en-Latn
+    (21581, 29801, 1853120844, 56.0, None), // MT: it-Latn
+    (21581, 29286, 1853120844, 11.0, None), // MT: fr-Latn
+    (21584, 29808, 1853120844, 96.0, Some(OfficialStatus::Official)), // PT: pt-Latn
+    (21584, 28261, 1853120844, 27.0, None), // PT: en-Latn
+    (21584, 29286, 1853120844, 15.0, None), // PT: fr-Latn
+    (21584, 29541, 1853120844, 10.0, None), // PT: es-Latn
+    (21584, 27751, 1853120844, 0.14, None), // PT: gl-Latn
+    (21587, 29808, 1853120844, 85.0, Some(OfficialStatus::Official)), // ST: pt-Latn
+    (21588, 28261, 1853120844, 88.0, Some(OfficialStatus::Official)), // TT: en-Latn
+    (21588, 29541, 1853120844, 0.34, None), // TT: es-Latn
+    (21593, 6453107, 1650553409, 88.0, None), // YT: swb-Arab
+    (21593, 29286, 1853120844, 57.0, Some(OfficialStatus::Official)), // YT: fr-Latn
+    (21593, 6518114, 1853120844, 23.0, None), // YT: buc-Latn
+    (21593, 30579, 1853120844, 1.4, None), // YT: sw-Latn
+    (21825, 28261, 1853120844, 96.0, Some(OfficialStatus::DeFactoOfficial)), // AU: en-Latn
+    (21825, 26746, 1953390920, 2.1, None), // AU: zh-Hant
+    (21825, 29801, 1853120844, 1.9, None), // AU: it-Latn
+    (21825, 7365239, 1853120844, 0.0098, None), // AU: wbp-Latn
+    (21825, 6975080, 1869570380, 0.0086, None), // AU: hnj-Laoo
+    (21827, 29541, 1853120844, 100.0, Some(OfficialStatus::Official)), // CU: es-Latn
+    (21831, 28261, 1853120844, 91.0, Some(OfficialStatus::DeFactoOfficial)), // GU: en-Latn
+    (21831, 26723, 1853120844, 22.0, Some(OfficialStatus::Official)), // GU: ch-Latn
+    (21832, 30056, 1853120844, 100.0, Some(OfficialStatus::Official)), // HU: hu-Latn
+    (21832, 28261, 1853120844, 20.0, None), // HU: en-Latn
+    (21832, 25956, 1853120844, 18.0, None), // HU: de-Latn
+    (21832, 29286, 1853120844, 3.0, None), // HU: fr-Latn
+    (21832, 28530, 1853120844, 0.99, None), // HU: ro-Latn
+    (21832, 29288, 1853120844, 0.32, None), // HU: hr-Latn
+    (21832, 27507, 1853120844, 0.12, None), // HU: sk-Latn
+    (21832, 27763, 1853120844, 0.051, None), // HU: sl-Latn
+    (21836, 29286, 1853120844, 87.0, Some(OfficialStatus::Official)), // LU: fr-Latn
+    (21836, 25196, 1853120844, 67.0, Some(OfficialStatus::Official)), // LU: lb-Latn
+    (21836, 25956, 1853120844, 63.0, Some(OfficialStatus::Official)), // LU: de-Latn
+    (21836, 28261, 1853120844, 56.0, None), // LU: en-Latn
+    (21836, 29808, 1853120844, 16.0, None), // LU: pt-Latn
+    (21837, 6645357, 1853120844, 90.0, None), // MU: mfe-Latn
+    (21837, 28261, 1853120844, 72.0, Some(OfficialStatus::Official)), // MU: en-Latn
+    (21837, 7301218, 1635149124, 27.0, None), // MU: bho-Deva
+    (21837, 29301, 1650553409, 5.2, None), // MU: ur-Arab
+    (21837, 29286, 1853120844, 3.0, Some(OfficialStatus::Official)), // MU: fr-Latn
+    (21837, 24948, 1819107668, 2.5, None), // MU: ta-Taml
+    (21838, 28261, 1853120844, 56.0, Some(OfficialStatus::Official)), // NU: en-Latn
+    (21838, 7694702, 1853120844, 56.0, Some(OfficialStatus::Official)), // NU: niu-Latn
+    (21842, 30066, 1819441475, 94.0, Some(OfficialStatus::Official)), // RU: ru-Cyrl
+    (21842, 29812, 1819441475, 1.4, Some(OfficialStatus::OfficialRegional)), // RU: tt-Cyrl
+    (21842, 24930, 1819441475, 1.3, Some(OfficialStatus::OfficialRegional)), // RU: ba-Cyrl
+    (21842, 30307, 1819441475, 1.3, None), // RU: cv-Cyrl
+    (21842, 31080, 1852666433, 0.84, None), // RU: hy-Armn
+    (21842, 25955, 1819441475, 0.66, Some(OfficialStatus::OfficialRegional)), // RU: ce-Cyrl
+    (21842, 30305, 1819441475, 0.39, Some(OfficialStatus::OfficialRegional)), // RU: av-Cyrl
+    (21842, 7169141, 1819441475, 0.38, Some(OfficialStatus::OfficialRegional)), // RU: udm-Cyrl
+    (21842, 7170147, 1819441475, 0.37, None), // RU: chm-Cyrl
+    (21842, 6840691, 1819441475, 0.32, Some(OfficialStatus::OfficialRegional)), // RU: sah-Cyrl
+    (21842, 29551, 1819441475, 0.32, None), // RU: os-Cyrl
+    (21842, 6578795, 1819441475, 0.31, Some(OfficialStatus::OfficialRegional)), // RU: kbd-Cyrl
+    (21842, 7764333, 1819441475, 0.31, Some(OfficialStatus::OfficialRegional)), // RU: myv-Cyrl
+    (21842, 7496036, 1819441475, 0.26, None), // RU: dar-Cyrl
+    (21842, 6387042, 1819441475, 0.22, None), // RU: bua-Cyrl
+    (21842, 6710381, 1819441475, 0.21, Some(OfficialStatus::OfficialRegional)), // RU: mdf-Cyrl
+    (21842, 7173483, 1819441475, 0.2, Some(OfficialStatus::OfficialRegional)), // RU: kum-Cyrl
+    (21842, 30315, 1819441475, 0.18, Some(OfficialStatus::OfficialRegional)), // RU: kv-Cyrl
+    (21842, 8021356, 1819441475, 0.18, Some(OfficialStatus::OfficialRegional)), // RU: lez-Cyrl
+    (21842, 6517355, 1819441475, 0.17, Some(OfficialStatus::OfficialRegional)), // RU: krc-Cyrl
+    (21842, 6844009, 1819441475, 0.16, Some(OfficialStatus::OfficialRegional)), // RU: inh-Cyrl
+    (21842, 7764340, 1819441475, 0.13, Some(OfficialStatus::OfficialRegional)), // RU: tyv-Cyrl
+    (21842, 31329, 1819441475, 0.093, Some(OfficialStatus::OfficialRegional)), // RU: az-Cyrl
+    (21842, 7955553, 1819441475, 0.088, Some(OfficialStatus::OfficialRegional)), // RU: ady-Cyrl
+    (21842, 7107179, 1853120844, 0.082, None), // RU: krl-Latn
+    (21842, 6644332, 1819441475, 0.078, Some(OfficialStatus::OfficialRegional)), // RU: lbe-Cyrl
+    (21842, 6909803, 1819441475, 0.045, Some(OfficialStatus::OfficialRegional)), // RU: koi-Cyrl
+    (21842, 6976109, 1819441475, 0.021, None), // RU: mrj-Cyrl
+    (21842, 7629921, 1819441475, 0.014, None), // RU: alt-Cyrl
+    (21842, 26982, 1853120844, 0.012, None), // RU: fi-Latn
+    (21842, 29299, 1853120844, 0.0035, None), // RU: sr-Latn
+    (21842, 7366006, 1853120844, 0.0025, None), // RU: vep-Latn
+    (21842, 28269, 1819441475, 0.0015, None), // RU: mn-Cyrl
+    (21842, 6847081, 1853120844, 0.0001, None), // RU: izh-Latn
+    (21842, 30051, 1819441475, 0.0, None), // RU: cu-Cyrl
+    (21842, 7630710, 1853120844, 0.0, None), // RU: vot-Latn
+    (21846, 26978, 1853120844, 90.0, Some(OfficialStatus::Official)), // VU: bi-Latn
+    (21846, 28261, 1853120844, 83.0, Some(OfficialStatus::Official)), // VU: en-Latn
+    (21846, 29286, 1853120844, 50.0, Some(OfficialStatus::Official)), // VU: fr-Latn
+    (22083, 6382955, 1853120844, 91.0, None), // CV: kea-Latn
+    (22083, 29808, 1853120844, 76.0, Some(OfficialStatus::Official)), // CV: pt-Latn
+    (22092, 30316, 1853120844, 61.0, Some(OfficialStatus::Official)), // LV: lv-Latn
+    (22092, 28261, 1853120844, 46.0, None), // LV: en-Latn
+    (22092, 30066, 1819441475, 38.0, None), // LV: ru-Cyrl
+    (22092, 6780012, 1853120844, 8.9, None), // LV: ltg-Latn
+    (22093, 30308, 1633773652, 98.0, Some(OfficialStatus::Official)), // MV: dv-Thaa
+    (22093, 28261, 1853120844, 75.0, None), // MV: en-Latn
+    (22099, 29541, 1853120844, 89.0, Some(OfficialStatus::Official)), // SV: es-Latn
+    (22100, 7108212, 1853120844, 87.0, Some(OfficialStatus::Official)), // TV: tvl-Latn
+    (22100, 28261, 1853120844, 9.4, Some(OfficialStatus::Official)), // TV: en-Latn
+    (22337, 27758, 1853120844, 97.0, Some(OfficialStatus::Official)), // AW: nl-Latn
+    (22337, 7364976, 1853120844, 61.0, Some(OfficialStatus::Official)), // AW: pap-Latn
+    (22337, 28261, 1853120844, 2.5, None), // AW: en-Latn
+    (22338, 28261, 1853120844, 81.0, Some(OfficialStatus::Official)), // BW: en-Latn
+    (22338, 28276, 1853120844, 62.0, Some(OfficialStatus::Official)), // BW: tn-Latn
+    (22338, 26209, 1853120844, 0.26, None), // BW: af-Latn
+    (22339, 7364976, 1853120844, 81.0, Some(OfficialStatus::DeFactoOfficial)), // CW: pap-Latn
+    (22339, 27758, 1853120844, 8.0, Some(OfficialStatus::Official)), // CW: nl-Latn
+    (22339, 29541, 1853120844, 3.8, None), // CW: es-Latn
+    (22343, 29808, 1853120844, 100.0, Some(OfficialStatus::Official)), // GW: pt-Latn
+    (22343, 6712939, 1853120844, 2.6, None), // GW: knf-Latn
+    (22343, 26214, 1835820097, 0.0001, None), // GW: ff-Adlm
+    (22343, 26214, 1853120844, 0.0001, None), // GW: ff-Latn
+    (22347, 29281, 1650553409, 100.0, Some(OfficialStatus::Official)), // KW: ar-Arab
+    (22349, 28261, 1853120844, 63.0, Some(OfficialStatus::Official)), // MW: en-Latn
+    (22349, 31086, 1853120844, 63.0, Some(OfficialStatus::Official)), // MW: ny-Latn
+    (22349, 7173492, 1853120844, 8.4, None), // MW: tum-Latn
+    (22349, 6778740, 1853120844, 0.98, None), // MW: tog-Latn
+    (22349, 30074, 1853120844, 0.33, None), // MW: zu-Latn
+    (22352, 7692656, 1853120844, 74.0, Some(OfficialStatus::Official)), // PW: pau-Latn
+    (22352, 28261, 1853120844, 8.7, Some(OfficialStatus::Official)), // PW: en-Latn
+    (22354, 30578, 1853120844, 77.0, Some(OfficialStatus::Official)), // RW: rw-Latn
+    (22354, 28261, 1853120844, 15.0, Some(OfficialStatus::Official)), // RW: en-Latn
+    (22354, 29286, 1853120844, 0.018, Some(OfficialStatus::Official)), // RW: fr-Latn
+    (22356, 26746, 1953390920, 95.0, Some(OfficialStatus::Official)), // TW: zh-Hant
+    (22356, 7762548, 1853120844, 0.02, None), // TW: trv-Latn
+    (22362, 28275, 1853120844, 81.0, Some(OfficialStatus::Official)), // ZW: sn-Latn
+    (22362, 28261, 1853120844, 42.0, Some(OfficialStatus::Official)), // ZW: en-Latn
+    (22362, 25710, 1853120844, 12.0, Some(OfficialStatus::Official)), // ZW: nd-Latn
+    (22362, 6518893, 1853120844, 6.5, None), // ZW: mxc-Latn
+    (22362, 6513774, 1853120844, 6.1, None), // ZW: ndc-Latn
+    (22362, 7037803, 1853120844, 5.3, None), // ZW: kck-Latn
+    (22362, 31086, 1853120844, 1.9, None), // ZW: ny-Latn
+    (22362, 25974, 1853120844, 0.64, None), // ZW: ve-Latn
+    (22362, 28276, 1853120844, 0.22, None), // ZW: tn-Latn
+    (22593, 30323, 1853120844, 99.0, Some(OfficialStatus::Official)), // AX: sv-Latn
+    (22595, 28261, 1853120844, 63.0, Some(OfficialStatus::Official)), // CX: en-Latn
+    (22605, 29541, 1853120844, 83.0, Some(OfficialStatus::DeFactoOfficial)), // MX: es-Latn
+    (22605, 28261, 1853120844, 13.0, None), // MX: en-Latn
+    (22605, 6387065, 1853120844, 0.67, None), // MX: yua-Latn
+    (22605, 6645870, 1853120844, 0.39, None), // MX: nhe-Latn
+    (22605, 7825518, 1853120844, 0.39, None), // MX: nhw-Latn
+    (22605, 8020333, 1853120844, 0.34, None), // MX: maz-Latn
+    (22605, 6841198, 1853120844, 0.19, None), // MX: nch-Latn
+    (22605, 6514038, 1853120844, 0.0019, Some(OfficialStatus::OfficialRegional)), // MX: vec-Latn
+    (22605, 6907251, 1853120844, 0.0007, None), // MX: sei-Latn
+    (22611, 28261, 1853120844, 68.0, Some(OfficialStatus::Official)), // SX: en-Latn
+    (22611, 29541, 1853120844, 11.0, None), // SX: es-Latn
+    (22611, 6515062, 1853120844, 7.1, None), // SX: vic-Latn
+    (22611, 27758, 1853120844, 3.6, Some(OfficialStatus::Official)), // SX: nl-Latn
+    (22850, 25954, 1819441475, 100.0, Some(OfficialStatus::Official)), // BY: be-Cyrl
+    (22850, 30066, 1819441475, 12.0, Some(OfficialStatus::Official)), // BY: ru-Cyrl
+    (22851, 27749, 1801810503, 95.0, Some(OfficialStatus::Official)), // CY: el-Grek
+    (22851, 28261, 1853120844, 73.0, None), // CY: en-Latn
+    (22851, 29300, 1853120844, 23.0, Some(OfficialStatus::Official)), // CY: tr-Latn
+    (22851, 29286, 1853120844, 7.0, None), // CY: fr-Latn
+    (22851, 31080, 1852666433, 0.22, None), // CY: hy-Armn
+    (22851, 29281, 1650553409, 0.1, None), // CY: ar-Arab
+    (22855, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // GY: en-Latn
+    (22859, 28261, 1853120844, 98.0, Some(OfficialStatus::Official)), // KY: en-Latn
+    (22860, 29281, 1650553409, 74.0, Some(OfficialStatus::Official)), // LY: ar-Arab
+    (22861, 29549, 1853120844, 75.0, Some(OfficialStatus::Official)), // MY: ms-Latn
+    (22861, 28261, 1853120844, 21.0, None), // MY: en-Latn
+    (22861, 26746, 1936613704, 17.0, None), // MY: zh-Hans
+    (22861, 24948, 1819107668, 4.2, None), // MY: ta-Taml
+    (22861, 6382185, 1853120844, 2.5, None), // MY: iba-Latn
+    (22861, 30314, 1853120844, 1.2, None), // MY: jv-Latn
+    (22861, 6909306, 1853120844, 1.2, None), // MY: zmi-Latn
+    (22861, 7369828, 1853120844, 0.56, None), // MY: dtp-Latn
+    (22861, 27757, 1836674125, 0.15, None), // MY: ml-Mlym
+    (22861, 6780258, 1853120844, 0.079, None), // MY: bug-Latn
+    (22861, 7236194, 1853120844, 0.015, None), // MY: bjn-Latn
+    (22864, 28263, 1853120844, 80.0, Some(OfficialStatus::Official)), // PY: gn-Latn
+    (22864, 29541, 1853120844, 3.2, Some(OfficialStatus::Official)), // PY: es-Latn
+    (22864, 25956, 1853120844, 2.9, None), // PY: de-Latn
+    (22867, 6516833, 1650553409, 85.0, None), // SY: apc-Arab
+    (22867, 29281, 1650553409, 80.0, Some(OfficialStatus::Official)), // SY: ar-Arab
+    (22867, 30059, 1853120844, 8.0, None), // SY: ku-Latn
+    (22867, 29286, 1853120844, 5.9, Some(OfficialStatus::Official)), // SY: fr-Latn
+    (22867, 31080, 1852666433, 1.8, None), // SY: hy-Armn
+    (22867, 7502195, 1668446547, 0.084, None), // SY: syr-Syrc
+    (22869, 29541, 1853120844, 88.0, Some(OfficialStatus::Official)), // UY: es-Latn
+    (23105, 31329, 1853120844, 89.0, Some(OfficialStatus::Official)), // AZ: az-Latn
+    (23105, 31329, 1819441475, 9.9, Some(OfficialStatus::Official)), // AZ: az-Cyrl
+    (23105, 7957620, 1853120844, 9.8, None), // AZ: tly-Latn
+    (23105, 30059, 1853120844, 0.24, None), // AZ: ku-Latn
+    (23105, 7631988, 1853120844, 0.22, None), // AZ: ttt-Latn
+    (23105, 7498612, 1853120844, 0.16, None), // AZ: tkr-Latn
+    (23106, 28261, 1853120844, 100.0, Some(OfficialStatus::Official)), // BZ: en-Latn
+    (23106, 29541, 1853120844, 28.0, None), // BZ: es-Latn
+    (23107, 29539, 1853120844, 98.0, Some(OfficialStatus::Official)), // CZ: cs-Latn
+    (23107, 28261, 1853120844, 27.0, None), // CZ: en-Latn
+    (23107, 27507, 1853120844, 16.0, None), // CZ: sk-Latn
+    (23107, 25956, 1853120844, 15.0, None), // CZ: de-Latn
+    (23107, 27760, 1853120844, 0.49, None), // CZ: pl-Latn
+    (23108, 7434849, 1650553409, 83.0, None), // DZ: arq-Arab
+    (23108, 29281, 1650553409, 74.0, Some(OfficialStatus::Official)), // DZ: ar-Arab
+    (23108, 29286, 1853120844, 20.0, Some(OfficialStatus::Official)), // DZ: fr-Latn
+    (23108, 6447467, 1853120844, 7.8, None), // DZ: kab-Latn
+    (23108, 28261, 1853120844, 7.0, None), // DZ: en-Latn
+    (23115, 30066, 1819441475, 72.0, Some(OfficialStatus::Official)), // KZ: ru-Cyrl
+    (23115, 27499, 1819441475, 64.0, Some(OfficialStatus::Official)), // KZ: kk-Cyrl
+    (23115, 28261, 1853120844, 15.0, None), // KZ: en-Latn
+    (23115, 25956, 1853120844, 6.4, None), // KZ: de-Latn
+    (23115, 26485, 1819441475, 2.0, None), // KZ: ug-Cyrl
+    (23117, 29808, 1853120844, 27.0, Some(OfficialStatus::Official)), // MZ: pt-Latn
+    (23117, 7826806, 1853120844, 13.0, None), // MZ: vmw-Latn
+    (23117, 6513774, 1853120844, 9.9, None), // MZ: ndc-Latn
+    (23117, 29556, 1853120844, 7.9, None), // MZ: ts-Latn
+    (23117, 7104366, 1853120844, 6.8, None), // MZ: ngl-Latn
+    (23117, 6841715, 1853120844, 4.6, None), // MZ: seh-Latn
+    (23117, 6842221, 1853120844, 4.5, None), // MZ: mgh-Latn
+    (23117, 6778482, 1853120844, 3.4, None), // MZ: rng-Latn
+    (23117, 31086, 1853120844, 2.6, None), // MZ: ny-Latn
+    (23117, 7299449, 1853120844, 2.4, None), // MZ: yao-Latn
+    (23117, 30579, 1853120844, 0.031, None), // MZ: sw-Latn
+    (23117, 30074, 1853120844, 0.006, None), // MZ: zu-Latn
+    (23118, 28261, 1853120844, 98.0, Some(OfficialStatus::DeFactoOfficial)), // NZ: en-Latn
+    (23118, 26989, 1853120844, 2.8, Some(OfficialStatus::Official)), // NZ: mi-Latn
+    (23123, 28261, 1853120844, 80.0, Some(OfficialStatus::Official)), // SZ: en-Latn
+    (23123, 29555, 1853120844, 58.0, Some(OfficialStatus::Official)), // SZ: ss-Latn
+    (23123, 30074, 1853120844, 6.8, None), // SZ: zu-Latn
+    (23123, 29556, 1853120844, 1.7, None), // SZ: ts-Latn
+    (23124, 30579, 1853120844, 90.0, Some(OfficialStatus::Official)), // TZ: sw-Latn
+    (23124, 28261, 1853120844, 69.0, Some(OfficialStatus::Official)), // TZ: en-Latn
+    (23124, 7042419, 1853120844, 8.7, None), // TZ: suk-Latn
+    (23124, 7174510, 1853120844, 3.3, None), // TZ: nym-Latn
+    (23124, 6644843, 1853120844, 2.5, None), // TZ: kde-Latn
+    (23124, 8021346, 1853120844, 1.7, None), // TZ: bez-Latn
+    (23124, 6452075, 1853120844, 1.7, None), // TZ: ksb-Latn
+    (23124, 7561581, 1853120844, 1.5, None), // TZ: mas-Latn
+    (23124, 7956333, 1853120844, 1.4, None), // TZ: mgy-Latn
+    (23124, 6386529, 1853120844, 1.2, None), // TZ: asa-Latn
+    (23124, 6775148, 1853120844, 0.87, None), // TZ: lag-Latn
+    (23124, 6516074, 1853120844, 0.74, None), // TZ: jmc-Latn
+    (23124, 6713202, 1853120844, 0.74, None), // TZ: rof-Latn
+    (23124, 7239030, 1853120844, 0.74, None), // TZ: vun-Latn
+    (23124, 7042930, 1853120844, 0.22, None), // TZ: rwk-Latn
+    (23124, 7365235, 1853120844, 0.2, None), // TZ: sbp-Latn
+    (23125, 31349, 1853120844, 85.0, Some(OfficialStatus::Official)), // UZ: uz-Latn
+    (23125, 31349, 1819441475, 15.0, Some(OfficialStatus::Official)), // UZ: uz-Cyrl
+    (23125, 30066, 1819441475, 14.0, None), // UZ: ru-Cyrl
+    (23125, 6381931, 1819441475, 1.6, None), // UZ: kaa-Cyrl
+    (23125, 29300, 1853120844, 0.76, None), // UZ: tr-Latn
+];
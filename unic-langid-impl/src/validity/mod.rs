@@ -0,0 +1,145 @@
+//! Validity of subtags, from the CLDR validity data.
+//!
+//! A well-formed subtag, like `xzz`, isn't necessarily assigned. The
+//! `id_status` methods of [`Language`], [`Script`], [`Region`] and
+//! [`Variant`] return the status CLDR gives to a subtag, if any, and
+//! [`LanguageIdentifier::is_valid`] tells whether all the subtags of an
+//! identifier are valid.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::subtags::Region;
+//! use unic_langid_impl::validity::IdStatus;
+//! use unic_langid_impl::LanguageIdentifier;
+//!
+//! let region: Region = "SU".parse().expect("Parsing failed.");
+//! assert_eq!(region.id_status(), Some(IdStatus::Deprecated));
+//!
+//! let li: LanguageIdentifier = "de-Latn-CH-1901".parse().expect("Parsing failed.");
+//! assert!(li.is_valid());
+//!
+//! let li: LanguageIdentifier = "xzz-CH".parse().expect("Parsing failed.");
+//! assert!(!li.is_valid());
+//! ```
+//!
+//! # Data
+//!
+//! The tables are generated by `unic-datagen` from the `common/validity`
+//! files of CLDR, which only come with its XML release, and can be limited
+//! to some languages, scripts and regions with its `--locales`, `--scripts`
+//! and `--regions` options. Subtags left out of a subset have no status.
+mod tables;
+
+use crate::subtags::{Language, Region, Script, Variant};
+use crate::LanguageIdentifier;
+
+pub use tables::CLDR_VERSION;
+
+/// The status of a subtag in the CLDR validity data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IdStatus {
+    /// An assigned subtag in regular use.
+    Regular,
+    /// A subtag with a special meaning, like `mul` or `Zyyy`.
+    Special,
+    /// A region grouping other regions, like `419` or `EU`.
+    Macroregion,
+    /// A subtag which has been replaced, like `iw` or `SU`.
+    Deprecated,
+    /// A subtag reserved for private use, like `qtz` or `XX`.
+    PrivateUse,
+    /// A subtag reserved for future use, like `AA`.
+    Reserved,
+    /// The subtag for an unknown value, like `und` or `ZZ`.
+    Unknown,
+}
+
+impl IdStatus {
+    /// Returns whether a subtag with this status is valid, which is the
+    /// case of all of them but [`IdStatus::Reserved`].
+    pub fn is_valid(self) -> bool {
+        self != IdStatus::Reserved
+    }
+}
+
+// The key of `und` in the `LANGUAGES` table.
+const UND: u64 = u64::from_le_bytes(*b"und\0\0\0\0\0");
+
+fn find<T: Ord>(table: &[(T, IdStatus)], key: T) -> Option<IdStatus> {
+    table
+        .binary_search_by(|(k, _)| k.cmp(&key))
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+impl Language {
+    /// Returns the status of the language in the CLDR validity data, if
+    /// it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    /// use unic_langid_impl::validity::IdStatus;
+    ///
+    /// let language: Language = "iw".parse().expect("Parsing failed.");
+    /// assert_eq!(language.id_status(), Some(IdStatus::Deprecated));
+    ///
+    /// assert_eq!(Language::default().id_status(), Some(IdStatus::Unknown));
+    /// ```
+    pub fn id_status(self) -> Option<IdStatus> {
+        find(&tables::LANGUAGES, Option::<u64>::from(self).unwrap_or(UND))
+    }
+}
+
+impl Script {
+    /// Returns the status of the script in the CLDR validity data, if it
+    /// has one.
+    pub fn id_status(self) -> Option<IdStatus> {
+        find(&tables::SCRIPTS, u32::from(self))
+    }
+}
+
+impl Region {
+    /// Returns the status of the region in the CLDR validity data, if it
+    /// has one.
+    pub fn id_status(self) -> Option<IdStatus> {
+        find(&tables::REGIONS, u32::from(self))
+    }
+}
+
+impl Variant {
+    /// Returns the status of the variant in the CLDR validity data, if it
+    /// has one.
+    pub fn id_status(self) -> Option<IdStatus> {
+        find(&tables::VARIANTS, u64::from(self))
+    }
+}
+
+impl LanguageIdentifier {
+    /// Returns whether all the subtags of the `LanguageIdentifier` have a
+    /// valid status in the CLDR validity data.
+    ///
+    /// Deprecated subtags are valid, use `replace_aliases` with the
+    /// `aliases` feature to replace them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sr-Latn-CS".parse().expect("Parsing failed.");
+    /// assert!(li.is_valid());
+    ///
+    /// let li: LanguageIdentifier = "en-AA".parse().expect("Parsing failed.");
+    /// assert!(!li.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        let valid = |status: Option<IdStatus>| status.is_some_and(IdStatus::is_valid);
+        valid(self.language.id_status())
+            && self.script.is_none_or(|s| valid(s.id_status()))
+            && self.region.is_none_or(|r| valid(r.id_status()))
+            && self.variants().all(|v| valid(v.id_status()))
+    }
+}
@@ -0,0 +1,9459 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+use super::IdStatus;
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static LANGUAGES: [(u64, IdStatus); 8759] = [
+    (24929, IdStatus::Regular), // aa
+    (24930, IdStatus::Regular), // ba
+    (24931, IdStatus::Regular), // ca
+    (24932, IdStatus::Regular), // da
+    (24934, IdStatus::Regular), // fa
+    (24935, IdStatus::Regular), // ga
+    (24936, IdStatus::Regular), // ha
+    (24937, IdStatus::Regular), // ia
+    (24938, IdStatus::Regular), // ja
+    (24939, IdStatus::Regular), // ka
+    (24940, IdStatus::Regular), // la
+    (24942, IdStatus::Regular), // na
+    (24944, IdStatus::Regular), // pa
+    (24947, IdStatus::Regular), // sa
+    (24948, IdStatus::Regular), // ta
+    (24951, IdStatus::Regular), // wa
+    (24954, IdStatus::Regular), // za
+    (25185, IdStatus::Regular), // ab
+    (25196, IdStatus::Regular), // lb
+    (25198, IdStatus::Regular), // nb
+    (25455, IdStatus::Regular), // oc
+    (25459, IdStatus::Regular), // sc
+    (25703, IdStatus::Regular), // gd
+    (25705, IdStatus::Regular), // id
+    (25710, IdStatus::Regular), // nd
+    (25715, IdStatus::Regular), // sd
+    (25953, IdStatus::Regular), // ae
+    (25954, IdStatus::Regular), // be
+    (25955, IdStatus::Regular), // ce
+    (25956, IdStatus::Regular), // de
+    (25957, IdStatus::Regular), // ee
+    (25960, IdStatus::Regular), // he
+    (25961, IdStatus::Regular), // ie
+    (25966, IdStatus::Regular), // ne
+    (25971, IdStatus::Regular), // se
+    (25972, IdStatus::Regular), // te
+    (25974, IdStatus::Regular), // ve
+    (26209, IdStatus::Regular), // af
+    (26214, IdStatus::Regular), // ff
+    (26466, IdStatus::Regular), // bg
+    (26473, IdStatus::Regular), // ig
+    (26475, IdStatus::Regular), // kg
+    (26476, IdStatus::Regular), // lg
+    (26477, IdStatus::Regular), // mg
+    (26478, IdStatus::Regular), // ng
+    (26483, IdStatus::Regular), // sg
+    (26484, IdStatus::Regular), // tg
+    (26485, IdStatus::Regular), // ug
+    (26722, IdStatus::Deprecated), // bh
+    (26723, IdStatus::Regular), // ch
+    (26733, IdStatus::Regular), // mh
+    (26739, IdStatus::Deprecated), // sh
+    (26740, IdStatus::Regular), // th
+    (26744, IdStatus::Regular), // xh
+    (26746, IdStatus::Regular), // zh
+    (26978, IdStatus::Regular), // bi
+    (26982, IdStatus::Regular), // fi
+    (26984, IdStatus::Regular), // hi
+    (26985, IdStatus::Regular), // ii
+    (26986, IdStatus::Deprecated), // ji
+    (26987, IdStatus::Regular), // ki
+    (26988, IdStatus::Regular), // li
+    (26989, IdStatus::Regular), // mi
+    (26992, IdStatus::Regular), // pi
+    (26995, IdStatus::Regular), // si
+    (26996, IdStatus::Regular), // ti
+    (26998, IdStatus::Regular), // vi
+    (27001, IdStatus::Regular), // yi
+    (27238, IdStatus::Regular), // fj
+    (27243, IdStatus::Regular), // kj
+    (27247, IdStatus::Regular), // oj
+    (27489, IdStatus::Regular), // ak
+    (27497, IdStatus::Regular), // ik
+    (27499, IdStatus::Regular), // kk
+    (27501, IdStatus::Regular), // mk
+    (27507, IdStatus::Regular), // sk
+    (27508, IdStatus::Regular), // tk
+    (27509, IdStatus::Regular), // uk
+    (27749, IdStatus::Regular), // el
+    (27751, IdStatus::Regular), // gl
+    (27755, IdStatus::Regular), // kl
+    (27757, IdStatus::Regular), // ml
+    (27758, IdStatus::Regular), // nl
+    (27760, IdStatus::Regular), // pl
+    (27763, IdStatus::Regular), // sl
+    (27764, IdStatus::Deprecated), // tl
+    (28001, IdStatus::Regular), // am
+    (28002, IdStatus::Regular), // bm
+    (28011, IdStatus::Regular), // km
+    (28015, IdStatus::Regular), // om
+    (28018, IdStatus::Regular), // rm
+    (28019, IdStatus::Regular), // sm
+    (28257, IdStatus::Regular), // an
+    (28258, IdStatus::Regular), // bn
+    (28261, IdStatus::Regular), // en
+    (28263, IdStatus::Regular), // gn
+    (28265, IdStatus::Deprecated), // in
+    (28267, IdStatus::Regular), // kn
+    (28268, IdStatus::Regular), // ln
+    (28269, IdStatus::Regular), // mn
+    (28270, IdStatus::Regular), // nn
+    (28274, IdStatus::Regular), // rn
+    (28275, IdStatus::Regular), // sn
+    (28276, IdStatus::Regular), // tn
+    (28514, IdStatus::Regular), // bo
+    (28515, IdStatus::Regular), // co
+    (28517, IdStatus::Regular), // eo
+    (28518, IdStatus::Regular), // fo
+    (28520, IdStatus::Regular), // ho
+    (28521, IdStatus::Regular), // io
+    (28523, IdStatus::Regular), // ko
+    (28524, IdStatus::Regular), // lo
+    (28525, IdStatus::Deprecated), // mo
+    (28526, IdStatus::Regular), // no
+    (28530, IdStatus::Regular), // ro
+    (28531, IdStatus::Regular), // so
+    (28532, IdStatus::Regular), // to
+    (28534, IdStatus::Regular), // vo
+    (28535, IdStatus::Regular), // wo
+    (28537, IdStatus::Regular), // yo
+    (29043, IdStatus::Regular), // sq
+    (29281, IdStatus::Regular), // ar
+    (29282, IdStatus::Regular), // br
+    (29283, IdStatus::Regular), // cr
+    (29286, IdStatus::Regular), // fr
+    (29288, IdStatus::Regular), // hr
+    (29291, IdStatus::Regular), // kr
+    (29293, IdStatus::Regular), // mr
+    (29294, IdStatus::Regular), // nr
+    (29295, IdStatus::Regular), // or
+    (29299, IdStatus::Regular), // sr
+    (29300, IdStatus::Regular), // tr
+    (29301, IdStatus::Regular), // ur
+    (29537, IdStatus::Regular), // as
+    (29538, IdStatus::Regular), // bs
+    (29539, IdStatus::Regular), // cs
+    (29541, IdStatus::Regular), // es
+    (29545, IdStatus::Regular), // is
+    (29547, IdStatus::Regular), // ks
+    (29549, IdStatus::Regular), // ms
+    (29551, IdStatus::Regular), // os
+    (29552, IdStatus::Regular), // ps
+    (29555, IdStatus::Regular), // ss
+    (29556, IdStatus::Regular), // ts
+    (29797, IdStatus::Regular), // et
+    (29800, IdStatus::Regular), // ht
+    (29801, IdStatus::Regular), // it
+    (29804, IdStatus::Regular), // lt
+    (29805, IdStatus::Regular), // mt
+    (29808, IdStatus::Regular), // pt
+    (29811, IdStatus::Regular), // st
+    (29812, IdStatus::Regular), // tt
+    (30051, IdStatus::Regular), // cu
+    (30053, IdStatus::Regular), // eu
+    (30055, IdStatus::Regular), // gu
+    (30056, IdStatus::Regular), // hu
+    (30057, IdStatus::Regular), // iu
+    (30059, IdStatus::Regular), // ku
+    (30060, IdStatus::Regular), // lu
+    (30065, IdStatus::Regular), // qu
+    (30066, IdStatus::Regular), // ru
+    (30067, IdStatus::Regular), // su
+    (30074, IdStatus::Regular), // zu
+    (30305, IdStatus::Regular), // av
+    (30307, IdStatus::Regular), // cv
+    (30308, IdStatus::Regular), // dv
+    (30311, IdStatus::Regular), // gv
+    (30314, IdStatus::Regular), // jv
+    (30315, IdStatus::Regular), // kv
+    (30316, IdStatus::Regular), // lv
+    (30318, IdStatus::Regular), // nv
+    (30323, IdStatus::Regular), // sv
+    (30569, IdStatus::Deprecated), // iw
+    (30570, IdStatus::Deprecated), // jw
+    (30571, IdStatus::Regular), // kw
+    (30578, IdStatus::Regular), // rw
+    (30579, IdStatus::Regular), // sw
+    (30580, IdStatus::Deprecated), // tw
+    (31073, IdStatus::Regular), // ay
+    (31075, IdStatus::Regular), // cy
+    (31078, IdStatus::Regular), // fy
+    (31080, IdStatus::Regular), // hy
+    (31083, IdStatus::Regular), // ky
+    (31085, IdStatus::Regular), // my
+    (31086, IdStatus::Regular), // ny
+    (31092, IdStatus::Regular), // ty
+    (31329, IdStatus::Regular), // az
+    (31332, IdStatus::Regular), // dz
+    (31336, IdStatus::Regular), // hz
+    (31349, IdStatus::Regular), // uz
+    (6381921, IdStatus::Regular), // aaa
+    (6381922, IdStatus::Regular), // baa
+    (6381923, IdStatus::Regular), // caa
+    (6381924, IdStatus::Regular), // daa
+    (6381925, IdStatus::Regular), // eaa
+    (6381926, IdStatus::Regular), // faa
+    (6381927, IdStatus::Regular), // gaa
+    (6381928, IdStatus::Regular), // haa
+    (6381930, IdStatus::Regular), // jaa
+    (6381931, IdStatus::Regular), // kaa
+    (6381932, IdStatus::Regular), // laa
+    (6381933, IdStatus::Regular), // maa
+    (6381934, IdStatus::Regular), // naa
+    (6381935, IdStatus::Regular), // oaa
+    (6381936, IdStatus::Regular), // paa
+    (6381937, IdStatus::Reserved), // qaa
+    (6381938, IdStatus::Regular), // raa
+    (6381939, IdStatus::Regular), // saa
+    (6381940, IdStatus::Regular), // taa
+    (6381942, IdStatus::Regular), // vaa
+    (6381943, IdStatus::Regular), // waa
+    (6381944, IdStatus::Regular), // xaa
+    (6381945, IdStatus::Regular), // yaa
+    (6381946, IdStatus::Regular), // zaa
+    (6382177, IdStatus::Regular), // aba
+    (6382178, IdStatus::Regular), // bba
+    (6382179, IdStatus::Regular), // cba
+    (6382180, IdStatus::Regular), // dba
+    (6382183, IdStatus::Regular), // gba
+    (6382184, IdStatus::Regular), // hba
+    (6382185, IdStatus::Regular), // iba
+    (6382187, IdStatus::Regular), // kba
+    (6382188, IdStatus::Deprecated), // lba
+    (6382189, IdStatus::Regular), // mba
+    (6382190, IdStatus::Regular), // nba
+    (6382193, IdStatus::Reserved), // qba
+    (6382195, IdStatus::Regular), // sba
+    (6382196, IdStatus::Regular), // tba
+    (6382197, IdStatus::Regular), // uba
+    (6382199, IdStatus::Regular), // wba
+    (6382200, IdStatus::Deprecated), // xba
+    (6382201, IdStatus::Regular), // yba
+    (6382202, IdStatus::Regular), // zba
+    (6382433, IdStatus::Regular), // aca
+    (6382434, IdStatus::Regular), // bca
+    (6382435, IdStatus::Deprecated), // cca
+    (6382440, IdStatus::Regular), // hca
+    (6382441, IdStatus::Regular), // ica
+    (6382443, IdStatus::Regular), // kca
+    (6382445, IdStatus::Regular), // mca
+    (6382446, IdStatus::Regular), // nca
+    (6382447, IdStatus::Regular), // oca
+    (6382448, IdStatus::Regular), // pca
+    (6382449, IdStatus::Reserved), // qca
+    (6382451, IdStatus::Deprecated), // sca
+    (6382452, IdStatus::Regular), // tca
+    (6382455, IdStatus::Regular), // wca
+    (6382458, IdStatus::Regular), // zca
+    (6382689, IdStatus::Regular), // ada
+    (6382690, IdStatus::Regular), // bda
+    (6382691, IdStatus::Regular), // cda
+    (6382692, IdStatus::Regular), // dda
+    (6382695, IdStatus::Regular), // gda
+    (6382697, IdStatus::Regular), // ida
+    (6382698, IdStatus::Regular), // jda
+    (6382699, IdStatus::Regular), // kda
+    (6382700, IdStatus::Regular), // lda
+    (6382701, IdStatus::Regular), // mda
+    (6382702, IdStatus::Regular), // nda
+    (6382703, IdStatus::Regular), // oda
+    (6382704, IdStatus::Regular), // pda
+    (6382705, IdStatus::Reserved), // qda
+    (6382707, IdStatus::Regular), // sda
+    (6382708, IdStatus::Regular), // tda
+    (6382709, IdStatus::Regular), // uda
+    (6382712, IdStatus::Regular), // xda
+    (6382713, IdStatus::Regular), // yda
+    (6382945, IdStatus::Regular), // aea
+    (6382946, IdStatus::Regular), // bea
+    (6382947, IdStatus::Regular), // cea
+    (6382951, IdStatus::Regular), // gea
+    (6382952, IdStatus::Deprecated), // hea
+    (6382955, IdStatus::Regular), // kea
+    (6382956, IdStatus::Regular), // lea
+    (6382957, IdStatus::Regular), // mea
+    (6382958, IdStatus::Regular), // nea
+    (6382960, IdStatus::Regular), // pea
+    (6382961, IdStatus::Reserved), // qea
+    (6382962, IdStatus::Regular), // rea
+    (6382963, IdStatus::Regular), // sea
+    (6382964, IdStatus::Regular), // tea
+    (6382967, IdStatus::Regular), // wea
+    (6382969, IdStatus::Regular), // yea
+    (6382970, IdStatus::Regular), // zea
+    (6383201, IdStatus::Regular), // afa
+    (6383202, IdStatus::Regular), // bfa
+    (6383203, IdStatus::Regular), // cfa
+    (6383205, IdStatus::Regular), // efa
+    (6383209, IdStatus::Regular), // ifa
+    (6383211, IdStatus::Regular), // kfa
+    (6383212, IdStatus::Regular), // lfa
+    (6383213, IdStatus::Regular), // mfa
+    (6383214, IdStatus::Regular), // nfa
+    (6383216, IdStatus::Regular), // pfa
+    (6383217, IdStatus::Reserved), // qfa
+    (6383224, IdStatus::Regular), // xfa
+    (6383457, IdStatus::Regular), // aga
+    (6383458, IdStatus::Regular), // bga
+    (6383459, IdStatus::Regular), // cga
+    (6383460, IdStatus::Regular), // dga
+    (6383461, IdStatus::Regular), // ega
+    (6383463, IdStatus::Regular), // gga
+    (6383467, IdStatus::Regular), // kga
+    (6383468, IdStatus::Regular), // lga
+    (6383469, IdStatus::Regular), // mga
+    (6383470, IdStatus::Regular), // nga
+    (6383472, IdStatus::Regular), // pga
+    (6383473, IdStatus::PrivateUse), // qga
+    (6383474, IdStatus::Regular), // rga
+    (6383475, IdStatus::Regular), // sga
+    (6383476, IdStatus::Regular), // tga
+    (6383477, IdStatus::Regular), // uga
+    (6383479, IdStatus::Regular), // wga
+    (6383480, IdStatus::Regular), // xga
+    (6383481, IdStatus::Regular), // yga
+    (6383482, IdStatus::Regular), // zga
+    (6383713, IdStatus::Regular), // aha
+    (6383714, IdStatus::Regular), // bha
+    (6383716, IdStatus::Deprecated), // dha
+    (6383719, IdStatus::Regular), // gha
+    (6383723, IdStatus::Regular), // kha
+    (6383724, IdStatus::Regular), // lha
+    (6383725, IdStatus::Regular), // mha
+    (6383726, IdStatus::Regular), // nha
+    (6383728, IdStatus::Regular), // pha
+    (6383729, IdStatus::PrivateUse), // qha
+    (6383731, IdStatus::Regular), // sha
+    (6383733, IdStatus::Regular), // uha
+    (6383735, IdStatus::Regular), // wha
+    (6383736, IdStatus::Regular), // xha
+    (6383737, IdStatus::Regular), // yha
+    (6383969, IdStatus::Regular), // aia
+    (6383970, IdStatus::Regular), // bia
+    (6383971, IdStatus::Regular), // cia
+    (6383972, IdStatus::Regular), // dia
+    (6383974, IdStatus::Regular), // fia
+    (6383975, IdStatus::Regular), // gia
+    (6383976, IdStatus::Regular), // hia
+    (6383978, IdStatus::Regular), // jia
+    (6383979, IdStatus::Regular), // kia
+    (6383980, IdStatus::Regular), // lia
+    (6383981, IdStatus::Regular), // mia
+    (6383982, IdStatus::Regular), // nia
+    (6383983, IdStatus::Regular), // oia
+    (6383984, IdStatus::Regular), // pia
+    (6383985, IdStatus::PrivateUse), // qia
+    (6383986, IdStatus::Regular), // ria
+    (6383987, IdStatus::Regular), // sia
+    (6383988, IdStatus::Regular), // tia
+    (6383992, IdStatus::Deprecated), // xia
+    (6383993, IdStatus::Regular), // yia
+    (6383994, IdStatus::Regular), // zia
+    (6384225, IdStatus::Regular), // aja
+    (6384226, IdStatus::Regular), // bja
+    (6384227, IdStatus::Regular), // cja
+    (6384228, IdStatus::Regular), // dja
+    (6384229, IdStatus::Regular), // eja
+    (6384235, IdStatus::Regular), // kja
+    (6384236, IdStatus::Regular), // lja
+    (6384237, IdStatus::Deprecated), // mja
+    (6384238, IdStatus::Regular), // nja
+    (6384241, IdStatus::PrivateUse), // qja
+    (6384243, IdStatus::Regular), // sja
+    (6384244, IdStatus::Regular), // tja
+    (6384247, IdStatus::Regular), // wja
+    (6384482, IdStatus::Regular), // bka
+    (6384483, IdStatus::Deprecated), // cka
+    (6384484, IdStatus::Regular), // dka
+    (6384485, IdStatus::Regular), // eka
+    (6384487, IdStatus::Regular), // gka
+    (6384488, IdStatus::Regular), // hka
+    (6384490, IdStatus::Regular), // jka
+    (6384491, IdStatus::Regular), // kka
+    (6384492, IdStatus::Regular), // lka
+    (6384493, IdStatus::Regular), // mka
+    (6384494, IdStatus::Regular), // nka
+    (6384495, IdStatus::Regular), // oka
+    (6384496, IdStatus::Regular), // pka
+    (6384497, IdStatus::PrivateUse), // qka
+    (6384498, IdStatus::Regular), // rka
+    (6384499, IdStatus::Regular), // ska
+    (6384500, IdStatus::Regular), // tka
+    (6384501, IdStatus::Regular), // uka
+    (6384502, IdStatus::Regular), // vka
+    (6384503, IdStatus::Regular), // wka
+    (6384504, IdStatus::Regular), // xka
+    (6384505, IdStatus::Regular), // yka
+    (6384506, IdStatus::Regular), // zka
+    (6384737, IdStatus::Regular), // ala
+    (6384738, IdStatus::Regular), // bla
+    (6384739, IdStatus::Regular), // cla
+    (6384742, IdStatus::Regular), // fla
+    (6384744, IdStatus::Regular), // hla
+    (6384745, IdStatus::Regular), // ila
+    (6384747, IdStatus::Regular), // kla
+    (6384748, IdStatus::Regular), // lla
+    (6384749, IdStatus::Regular), // mla
+    (6384750, IdStatus::Regular), // nla
+    (6384751, IdStatus::Regular), // ola
+    (6384752, IdStatus::Regular), // pla
+    (6384753, IdStatus::PrivateUse), // qla
+    (6384755, IdStatus::Regular), // sla
+    (6384756, IdStatus::Regular), // tla
+    (6384757, IdStatus::Regular), // ula
+    (6384759, IdStatus::Regular), // wla
+    (6384760, IdStatus::Regular), // xla
+    (6384761, IdStatus::Regular), // yla
+    (6384762, IdStatus::Regular), // zla
+    (6384993, IdStatus::Regular), // ama
+    (6384994, IdStatus::Regular), // bma
+    (6384995, IdStatus::Regular), // cma
+    (6384996, IdStatus::Regular), // dma
+    (6384997, IdStatus::Regular), // ema
+    (6384999, IdStatus::Regular), // gma
+    (6385000, IdStatus::Regular), // hma
+    (6385001, IdStatus::Regular), // ima
+    (6385002, IdStatus::Regular), // jma
+    (6385003, IdStatus::Regular), // kma
+    (6385004, IdStatus::Regular), // lma
+    (6385005, IdStatus::Regular), // mma
+    (6385006, IdStatus::Regular), // nma
+    (6385007, IdStatus::Regular), // oma
+    (6385008, IdStatus::Regular), // pma
+    (6385009, IdStatus::PrivateUse), // qma
+    (6385010, IdStatus::Regular), // rma
+    (6385011, IdStatus::Regular), // sma
+    (6385012, IdStatus::Regular), // tma
+    (6385013, IdStatus::Regular), // uma
+    (6385014, IdStatus::Regular), // vma
+    (6385015, IdStatus::Regular), // wma
+    (6385016, IdStatus::Regular), // xma
+    (6385017, IdStatus::Deprecated), // yma
+    (6385018, IdStatus::Regular), // zma
+    (6385249, IdStatus::Regular), // ana
+    (6385250, IdStatus::Regular), // bna
+    (6385251, IdStatus::Regular), // cna
+    (6385252, IdStatus::Regular), // dna
+    (6385253, IdStatus::Regular), // ena
+    (6385255, IdStatus::Regular), // gna
+    (6385256, IdStatus::Regular), // hna
+    (6385258, IdStatus::Regular), // jna
+    (6385259, IdStatus::Regular), // kna
+    (6385260, IdStatus::Regular), // lna
+    (6385261, IdStatus::Regular), // mna
+    (6385262, IdStatus::Regular), // nna
+    (6385263, IdStatus::Regular), // ona
+    (6385264, IdStatus::Regular), // pna
+    (6385265, IdStatus::PrivateUse), // qna
+    (6385266, IdStatus::Deprecated), // rna
+    (6385268, IdStatus::Regular), // tna
+    (6385269, IdStatus::Regular), // una
+    (6385272, IdStatus::Regular), // xna
+    (6385273, IdStatus::Regular), // yna
+    (6385274, IdStatus::Regular), // zna
+    (6385505, IdStatus::Regular), // aoa
+    (6385506, IdStatus::Regular), // boa
+    (6385507, IdStatus::Regular), // coa
+    (6385508, IdStatus::Regular), // doa
+    (6385511, IdStatus::Regular), // goa
+    (6385512, IdStatus::Regular), // hoa
+    (6385515, IdStatus::Regular), // koa
+    (6385516, IdStatus::Regular), // loa
+    (6385517, IdStatus::Regular), // moa
+    (6385518, IdStatus::Regular), // noa
+    (6385521, IdStatus::PrivateUse), // qoa
+    (6385522, IdStatus::Regular), // roa
+    (6385523, IdStatus::Regular), // soa
+    (6385527, IdStatus::Regular), // woa
+    (6385761, IdStatus::Regular), // apa
+    (6385762, IdStatus::Regular), // bpa
+    (6385763, IdStatus::Regular), // cpa
+    (6385767, IdStatus::Regular), // gpa
+    (6385770, IdStatus::Regular), // jpa
+    (6385771, IdStatus::Regular), // kpa
+    (6385772, IdStatus::Regular), // lpa
+    (6385773, IdStatus::Regular), // mpa
+    (6385774, IdStatus::Regular), // npa
+    (6385775, IdStatus::Regular), // opa
+    (6385776, IdStatus::Deprecated), // ppa
+    (6385777, IdStatus::PrivateUse), // qpa
+    (6385780, IdStatus::Regular), // tpa
+    (6385784, IdStatus::Regular), // xpa
+    (6385785, IdStatus::Regular), // ypa
+    (6385786, IdStatus::Regular), // zpa
+    (6386017, IdStatus::Regular), // aqa
+    (6386018, IdStatus::Regular), // bqa
+    (6386023, IdStatus::Regular), // gqa
+    (6386027, IdStatus::Regular), // kqa
+    (6386029, IdStatus::Regular), // mqa
+    (6386032, IdStatus::Regular), // pqa
+    (6386033, IdStatus::PrivateUse), // qqa
+    (6386035, IdStatus::Regular), // sqa
+    (6386040, IdStatus::Regular), // xqa
+    (6386274, IdStatus::Regular), // bra
+    (6386275, IdStatus::Regular), // cra
+    (6386276, IdStatus::Regular), // dra
+    (6386277, IdStatus::Regular), // era
+    (6386279, IdStatus::Regular), // gra
+    (6386280, IdStatus::Regular), // hra
+    (6386281, IdStatus::Regular), // ira
+    (6386282, IdStatus::Regular), // jra
+    (6386283, IdStatus::Regular), // kra
+    (6386284, IdStatus::Regular), // lra
+    (6386285, IdStatus::Regular), // mra
+    (6386286, IdStatus::Regular), // nra
+    (6386287, IdStatus::Regular), // ora
+    (6386288, IdStatus::Regular), // pra
+    (6386289, IdStatus::PrivateUse), // qra
+    (6386291, IdStatus::Regular), // sra
+    (6386292, IdStatus::Regular), // tra
+    (6386293, IdStatus::Regular), // ura
+    (6386294, IdStatus::Regular), // vra
+    (6386295, IdStatus::Deprecated), // wra
+    (6386296, IdStatus::Regular), // xra
+    (6386297, IdStatus::Regular), // yra
+    (6386298, IdStatus::Regular), // zra
+    (6386529, IdStatus::Regular), // asa
+    (6386530, IdStatus::Regular), // bsa
+    (6386531, IdStatus::Regular), // csa
+    (6386537, IdStatus::Regular), // isa
+    (6386539, IdStatus::Regular), // ksa
+    (6386540, IdStatus::Regular), // lsa
+    (6386542, IdStatus::Regular), // nsa
+    (6386543, IdStatus::Regular), // osa
+    (6386544, IdStatus::Regular), // psa
+    (6386545, IdStatus::PrivateUse), // qsa
+    (6386547, IdStatus::Regular), // ssa
+    (6386548, IdStatus::Regular), // tsa
+    (6386549, IdStatus::Regular), // usa
+    (6386551, IdStatus::Regular), // wsa
+    (6386552, IdStatus::Regular), // xsa
+    (6386554, IdStatus::Regular), // zsa
+    (6386785, IdStatus::Regular), // ata
+    (6386786, IdStatus::Regular), // bta
+    (6386787, IdStatus::Regular), // cta
+    (6386788, IdStatus::Regular), // dta
+    (6386791, IdStatus::Regular), // gta
+    (6386795, IdStatus::Regular), // kta
+    (6386797, IdStatus::Regular), // mta
+    (6386799, IdStatus::Regular), // ota
+    (6386800, IdStatus::Regular), // pta
+    (6386801, IdStatus::PrivateUse), // qta
+    (6386803, IdStatus::Regular), // sta
+    (6386804, IdStatus::Regular), // tta
+    (6386805, IdStatus::Regular), // uta
+    (6386808, IdStatus::Regular), // xta
+    (6386809, IdStatus::Regular), // yta
+    (6387041, IdStatus::Regular), // aua
+    (6387042, IdStatus::Regular), // bua
+    (6387043, IdStatus::Regular), // cua
+    (6387044, IdStatus::Regular), // dua
+    (6387047, IdStatus::Regular), // gua
+    (6387050, IdStatus::Regular), // jua
+    (6387052, IdStatus::Regular), // lua
+    (6387053, IdStatus::Regular), // mua
+    (6387054, IdStatus::Regular), // nua
+    (6387055, IdStatus::Regular), // oua
+    (6387056, IdStatus::Regular), // pua
+    (6387057, IdStatus::Regular), // qua
+    (6387059, IdStatus::Regular), // sua
+    (6387060, IdStatus::Regular), // tua
+    (6387063, IdStatus::Regular), // wua
+    (6387064, IdStatus::Regular), // xua
+    (6387065, IdStatus::Regular), // yua
+    (6387066, IdStatus::Regular), // zua
+    (6387298, IdStatus::Regular), // bva
+    (6387300, IdStatus::Regular), // dva
+    (6387303, IdStatus::Regular), // gva
+    (6387307, IdStatus::Regular), // kva
+    (6387308, IdStatus::Regular), // lva
+    (6387309, IdStatus::Regular), // mva
+    (6387313, IdStatus::Regular), // qva
+    (6387315, IdStatus::Regular), // sva
+    (6387316, IdStatus::Regular), // tva
+    (6387321, IdStatus::Regular), // yva
+    (6387553, IdStatus::Regular), // awa
+    (6387554, IdStatus::Regular), // bwa
+    (6387555, IdStatus::Regular), // cwa
+    (6387556, IdStatus::Regular), // dwa
+    (6387558, IdStatus::Regular), // fwa
+    (6387559, IdStatus::Regular), // gwa
+    (6387560, IdStatus::Regular), // hwa
+    (6387563, IdStatus::Regular), // kwa
+    (6387564, IdStatus::Regular), // lwa
+    (6387565, IdStatus::Regular), // mwa
+    (6387566, IdStatus::Regular), // nwa
+    (6387568, IdStatus::Regular), // pwa
+    (6387569, IdStatus::Regular), // qwa
+    (6387570, IdStatus::Regular), // rwa
+    (6387572, IdStatus::Regular), // twa
+    (6387573, IdStatus::Regular), // uwa
+    (6387574, IdStatus::Regular), // vwa
+    (6387575, IdStatus::Regular), // wwa
+    (6387576, IdStatus::Regular), // xwa
+    (6387577, IdStatus::Regular), // ywa
+    (6387578, IdStatus::Regular), // zwa
+    (6387810, IdStatus::Regular), // bxa
+    (6387819, IdStatus::Regular), // kxa
+    (6387821, IdStatus::Regular), // mxa
+    (6387822, IdStatus::Regular), // nxa
+    (6387825, IdStatus::Regular), // qxa
+    (6387828, IdStatus::Regular), // txa
+    (6387831, IdStatus::Regular), // wxa
+    (6387833, IdStatus::Regular), // yxa
+    (6388065, IdStatus::Regular), // aya
+    (6388066, IdStatus::Regular), // bya
+    (6388067, IdStatus::Regular), // cya
+    (6388068, IdStatus::Regular), // dya
+    (6388069, IdStatus::Regular), // eya
+    (6388071, IdStatus::Deprecated), // gya
+    (6388072, IdStatus::Regular), // hya
+    (6388073, IdStatus::Regular), // iya
+    (6388074, IdStatus::Regular), // jya
+    (6388075, IdStatus::Regular), // kya
+    (6388076, IdStatus::Regular), // lya
+    (6388081, IdStatus::Regular), // qya
+    (6388083, IdStatus::Regular), // sya
+    (6388084, IdStatus::Regular), // tya
+    (6388085, IdStatus::Regular), // uya
+    (6388087, IdStatus::Deprecated), // wya
+    (6388088, IdStatus::Regular), // xya
+    (6388321, IdStatus::Regular), // aza
+    (6388322, IdStatus::Regular), // bza
+    (6388324, IdStatus::Regular), // dza
+    (6388325, IdStatus::Regular), // eza
+    (6388327, IdStatus::Regular), // gza
+    (6388331, IdStatus::Regular), // kza
+    (6388333, IdStatus::Regular), // mza
+    (6388334, IdStatus::Regular), // nza
+    (6388339, IdStatus::Regular), // sza
+    (6388340, IdStatus::Regular), // tza
+    (6388346, IdStatus::Regular), // zza
+    (6447457, IdStatus::Regular), // aab
+    (6447458, IdStatus::Regular), // bab
+    (6447459, IdStatus::Regular), // cab
+    (6447462, IdStatus::Regular), // fab
+    (6447463, IdStatus::Regular), // gab
+    (6447464, IdStatus::Regular), // hab
+    (6447466, IdStatus::Regular), // jab
+    (6447467, IdStatus::Regular), // kab
+    (6447468, IdStatus::Regular), // lab
+    (6447469, IdStatus::Regular), // mab
+    (6447470, IdStatus::Regular), // nab
+    (6447472, IdStatus::Regular), // pab
+    (6447473, IdStatus::Reserved), // qab
+    (6447474, IdStatus::Regular), // rab
+    (6447475, IdStatus::Regular), // sab
+    (6447476, IdStatus::Regular), // tab
+    (6447479, IdStatus::Regular), // wab
+    (6447480, IdStatus::Regular), // xab
+    (6447481, IdStatus::Regular), // yab
+    (6447482, IdStatus::Regular), // zab
+    (6447713, IdStatus::Regular), // abb
+    (6447714, IdStatus::Regular), // bbb
+    (6447715, IdStatus::Regular), // cbb
+    (6447716, IdStatus::Regular), // dbb
+    (6447719, IdStatus::Regular), // gbb
+    (6447720, IdStatus::Regular), // hbb
+    (6447721, IdStatus::Regular), // ibb
+    (6447723, IdStatus::Regular), // kbb
+    (6447724, IdStatus::Regular), // lbb
+    (6447725, IdStatus::Regular), // mbb
+    (6447726, IdStatus::Regular), // nbb
+    (6447728, IdStatus::Regular), // pbb
+    (6447729, IdStatus::Reserved), // qbb
+    (6447730, IdStatus::Regular), // rbb
+    (6447731, IdStatus::Regular), // sbb
+    (6447732, IdStatus::Deprecated), // tbb
+    (6447734, IdStatus::Regular), // vbb
+    (6447735, IdStatus::Regular), // wbb
+    (6447736, IdStatus::Regular), // xbb
+    (6447737, IdStatus::Regular), // ybb
+    (6447969, IdStatus::Regular), // acb
+    (6447970, IdStatus::Regular), // bcb
+    (6447979, IdStatus::Regular), // kcb
+    (6447981, IdStatus::Regular), // mcb
+    (6447982, IdStatus::Regular), // ncb
+    (6447984, IdStatus::Regular), // pcb
+    (6447985, IdStatus::Reserved), // qcb
+    (6447987, IdStatus::Regular), // scb
+    (6447988, IdStatus::Regular), // tcb
+    (6447992, IdStatus::Regular), // xcb
+    (6448225, IdStatus::Regular), // adb
+    (6448226, IdStatus::Regular), // bdb
+    (6448231, IdStatus::Regular), // gdb
+    (6448233, IdStatus::Regular), // idb
+    (6448236, IdStatus::Regular), // ldb
+    (6448237, IdStatus::Regular), // mdb
+    (6448238, IdStatus::Regular), // ndb
+    (6448241, IdStatus::Reserved), // qdb
+    (6448242, IdStatus::Regular), // rdb
+    (6448243, IdStatus::Regular), // sdb
+    (6448244, IdStatus::Regular), // tdb
+    (6448481, IdStatus::Regular), // aeb
+    (6448482, IdStatus::Regular), // beb
+    (6448483, IdStatus::Regular), // ceb
+    (6448487, IdStatus::Regular), // geb
+    (6448490, IdStatus::Regular), // jeb
+    (6448491, IdStatus::Regular), // keb
+    (6448492, IdStatus::Regular), // leb
+    (6448493, IdStatus::Regular), // meb
+    (6448494, IdStatus::Regular), // neb
+    (6448496, IdStatus::Regular), // peb
+    (6448497, IdStatus::Reserved), // qeb
+    (6448498, IdStatus::Regular), // reb
+    (6448499, IdStatus::Regular), // seb
+    (6448500, IdStatus::Regular), // teb
+    (6448504, IdStatus::Regular), // xeb
+    (6448737, IdStatus::Regular), // afb
+    (6448738, IdStatus::Regular), // bfb
+    (6448745, IdStatus::Regular), // ifb
+    (6448747, IdStatus::Regular), // kfb
+    (6448749, IdStatus::Regular), // mfb
+    (6448753, IdStatus::Reserved), // qfb
+    (6448755, IdStatus::Regular), // sfb
+    (6448993, IdStatus::Regular), // agb
+    (6448994, IdStatus::Regular), // bgb
+    (6448996, IdStatus::Regular), // dgb
+    (6448999, IdStatus::Regular), // ggb
+    (6449001, IdStatus::Regular), // igb
+    (6449002, IdStatus::Regular), // jgb
+    (6449003, IdStatus::Regular), // kgb
+    (6449004, IdStatus::Regular), // lgb
+    (6449005, IdStatus::Regular), // mgb
+    (6449006, IdStatus::Regular), // ngb
+    (6449007, IdStatus::Regular), // ogb
+    (6449009, IdStatus::PrivateUse), // qgb
+    (6449011, IdStatus::Regular), // sgb
+    (6449012, IdStatus::Regular), // tgb
+    (6449013, IdStatus::Regular), // ugb
+    (6449015, IdStatus::Regular), // wgb
+    (6449016, IdStatus::Regular), // xgb
+    (6449018, IdStatus::Regular), // zgb
+    (6449249, IdStatus::Regular), // ahb
+    (6449250, IdStatus::Regular), // bhb
+    (6449251, IdStatus::Regular), // chb
+    (6449257, IdStatus::Regular), // ihb
+    (6449259, IdStatus::Regular), // khb
+    (6449261, IdStatus::Regular), // mhb
+    (6449262, IdStatus::Regular), // nhb
+    (6449265, IdStatus::PrivateUse), // qhb
+    (6449267, IdStatus::Regular), // shb
+    (6449274, IdStatus::Regular), // zhb
+    (6449505, IdStatus::Regular), // aib
+    (6449506, IdStatus::Regular), // bib
+    (6449507, IdStatus::Regular), // cib
+    (6449508, IdStatus::Regular), // dib
+    (6449511, IdStatus::Regular), // gib
+    (6449512, IdStatus::Regular), // hib
+    (6449514, IdStatus::Regular), // jib
+    (6449515, IdStatus::Regular), // kib
+    (6449516, IdStatus::Regular), // lib
+    (6449517, IdStatus::Regular), // mib
+    (6449518, IdStatus::Regular), // nib
+    (6449520, IdStatus::Regular), // pib
+    (6449521, IdStatus::PrivateUse), // qib
+    (6449522, IdStatus::Regular), // rib
+    (6449523, IdStatus::Regular), // sib
+    (6449527, IdStatus::Regular), // wib
+    (6449528, IdStatus::Regular), // xib
+    (6449530, IdStatus::Regular), // zib
+    (6449762, IdStatus::Regular), // bjb
+    (6449764, IdStatus::Regular), // djb
+    (6449771, IdStatus::Regular), // kjb
+    (6449773, IdStatus::Regular), // mjb
+    (6449774, IdStatus::Regular), // njb
+    (6449775, IdStatus::Regular), // ojb
+    (6449777, IdStatus::PrivateUse), // qjb
+    (6449779, IdStatus::Regular), // sjb
+    (6449784, IdStatus::Regular), // xjb
+    (6450017, IdStatus::Regular), // akb
+    (6450018, IdStatus::Deprecated), // bkb
+    (6450019, IdStatus::Regular), // ckb
+    (6450027, IdStatus::Regular), // kkb
+    (6450028, IdStatus::Regular), // lkb
+    (6450029, IdStatus::Regular), // mkb
+    (6450030, IdStatus::Regular), // nkb
+    (6450031, IdStatus::Regular), // okb
+    (6450032, IdStatus::Regular), // pkb
+    (6450033, IdStatus::PrivateUse), // qkb
+    (6450034, IdStatus::Regular), // rkb
+    (6450035, IdStatus::Regular), // skb
+    (6450036, IdStatus::Regular), // tkb
+    (6450039, IdStatus::Regular), // wkb
+    (6450040, IdStatus::Regular), // xkb
+    (6450042, IdStatus::Regular), // zkb
+    (6450274, IdStatus::Regular), // blb
+    (6450279, IdStatus::Regular), // glb
+    (6450280, IdStatus::Regular), // hlb
+    (6450281, IdStatus::Regular), // ilb
+    (6450283, IdStatus::Regular), // klb
+    (6450284, IdStatus::Regular), // llb
+    (6450285, IdStatus::Regular), // mlb
+    (6450288, IdStatus::Regular), // plb
+    (6450289, IdStatus::PrivateUse), // qlb
+    (6450292, IdStatus::Regular), // tlb
+    (6450293, IdStatus::Regular), // ulb
+    (6450296, IdStatus::Regular), // xlb
+    (6450297, IdStatus::Regular), // ylb
+    (6450529, IdStatus::Regular), // amb
+    (6450530, IdStatus::Regular), // bmb
+    (6450532, IdStatus::Regular), // dmb
+    (6450533, IdStatus::Regular), // emb
+    (6450535, IdStatus::Regular), // gmb
+    (6450536, IdStatus::Regular), // hmb
+    (6450538, IdStatus::Regular), // jmb
+    (6450539, IdStatus::Regular), // kmb
+    (6450540, IdStatus::Regular), // lmb
+    (6450541, IdStatus::Regular), // mmb
+    (6450542, IdStatus::Regular), // nmb
+    (6450543, IdStatus::Regular), // omb
+    (6450544, IdStatus::Regular), // pmb
+    (6450545, IdStatus::PrivateUse), // qmb
+    (6450546, IdStatus::Regular), // rmb
+    (6450547, IdStatus::Regular), // smb
+    (6450548, IdStatus::Regular), // tmb
+    (6450549, IdStatus::Regular), // umb
+    (6450550, IdStatus::Regular), // vmb
+    (6450551, IdStatus::Regular), // wmb
+    (6450552, IdStatus::Regular), // xmb
+    (6450553, IdStatus::Regular), // ymb
+    (6450554, IdStatus::Regular), // zmb
+    (6450785, IdStatus::Regular), // anb
+    (6450786, IdStatus::Regular), // bnb
+    (6450787, IdStatus::Regular), // cnb
+    (6450789, IdStatus::Regular), // enb
+    (6450790, IdStatus::Regular), // fnb
+    (6450791, IdStatus::Regular), // gnb
+    (6450793, IdStatus::Regular), // inb
+    (6450795, IdStatus::Regular), // knb
+    (6450796, IdStatus::Regular), // lnb
+    (6450797, IdStatus::Regular), // mnb
+    (6450798, IdStatus::Regular), // nnb
+    (6450799, IdStatus::Regular), // onb
+    (6450800, IdStatus::Deprecated), // pnb
+    (6450801, IdStatus::PrivateUse), // qnb
+    (6450802, IdStatus::Regular), // rnb
+    (6450803, IdStatus::Deprecated), // snb
+    (6450804, IdStatus::Regular), // tnb
+    (6450807, IdStatus::Regular), // wnb
+    (6450808, IdStatus::Regular), // xnb
+    (6451041, IdStatus::Regular), // aob
+    (6451042, IdStatus::Regular), // bob
+    (6451043, IdStatus::Regular), // cob
+    (6451044, IdStatus::Regular), // dob
+    (6451047, IdStatus::Regular), // gob
+    (6451048, IdStatus::Regular), // hob
+    (6451050, IdStatus::Regular), // job
+    (6451052, IdStatus::Regular), // lob
+    (6451057, IdStatus::PrivateUse), // qob
+    (6451058, IdStatus::Regular), // rob
+    (6451059, IdStatus::Regular), // sob
+    (6451060, IdStatus::Regular), // tob
+    (6451063, IdStatus::Regular), // wob
+    (6451065, IdStatus::Regular), // yob
+    (6451297, IdStatus::Regular), // apb
+    (6451298, IdStatus::Deprecated), // bpb
+    (6451299, IdStatus::Regular), // cpb
+    (6451307, IdStatus::Regular), // kpb
+    (6451309, IdStatus::Regular), // mpb
+    (6451310, IdStatus::Regular), // npb
+    (6451313, IdStatus::PrivateUse), // qpb
+    (6451315, IdStatus::Regular), // spb
+    (6451320, IdStatus::Regular), // xpb
+    (6451321, IdStatus::Regular), // ypb
+    (6451322, IdStatus::Regular), // zpb
+    (6451554, IdStatus::Regular), // bqb
+    (6451563, IdStatus::Regular), // kqb
+    (6451565, IdStatus::Regular), // mqb
+    (6451569, IdStatus::PrivateUse), // qqb
+    (6451572, IdStatus::Regular), // tqb
+    (6451809, IdStatus::Deprecated), // arb
+    (6451810, IdStatus::Regular), // brb
+    (6451811, IdStatus::Regular), // crb
+    (6451812, IdStatus::Regular), // drb
+    (6451815, IdStatus::Regular), // grb
+    (6451818, IdStatus::Regular), // jrb
+    (6451819, IdStatus::Regular), // krb
+    (6451821, IdStatus::Regular), // mrb
+    (6451822, IdStatus::Regular), // nrb
+    (6451824, IdStatus::Deprecated), // prb
+    (6451825, IdStatus::PrivateUse), // qrb
+    (6451827, IdStatus::Regular), // srb
+    (6451828, IdStatus::Regular), // trb
+    (6451829, IdStatus::Regular), // urb
+    (6451831, IdStatus::Regular), // wrb
+    (6451832, IdStatus::Regular), // xrb
+    (6451833, IdStatus::Regular), // yrb
+    (6452065, IdStatus::Regular), // asb
+    (6452066, IdStatus::Regular), // bsb
+    (6452067, IdStatus::Regular), // csb
+    (6452068, IdStatus::Regular), // dsb
+    (6452072, IdStatus::Regular), // hsb
+    (6452075, IdStatus::Regular), // ksb
+    (6452076, IdStatus::Regular), // lsb
+    (6452077, IdStatus::Regular), // msb
+    (6452078, IdStatus::Regular), // nsb
+    (6452081, IdStatus::PrivateUse), // qsb
+    (6452082, IdStatus::Regular), // rsb
+    (6452083, IdStatus::Regular), // ssb
+    (6452084, IdStatus::Regular), // tsb
+    (6452088, IdStatus::Regular), // xsb
+    (6452321, IdStatus::Regular), // atb
+    (6452322, IdStatus::Deprecated), // btb
+    (6452324, IdStatus::Regular), // dtb
+    (6452325, IdStatus::Regular), // etb
+    (6452329, IdStatus::Regular), // itb
+    (6452331, IdStatus::Regular), // ktb
+    (6452333, IdStatus::Regular), // mtb
+    (6452335, IdStatus::Regular), // otb
+    (6452337, IdStatus::PrivateUse), // qtb
+    (6452339, IdStatus::Regular), // stb
+    (6452340, IdStatus::Regular), // ttb
+    (6452344, IdStatus::Regular), // xtb
+    (6452577, IdStatus::Regular), // aub
+    (6452578, IdStatus::Regular), // bub
+    (6452579, IdStatus::Regular), // cub
+    (6452580, IdStatus::Regular), // dub
+    (6452582, IdStatus::Regular), // fub
+    (6452583, IdStatus::Regular), // gub
+    (6452584, IdStatus::Regular), // hub
+    (6452586, IdStatus::Regular), // jub
+    (6452587, IdStatus::Regular), // kub
+    (6452589, IdStatus::Regular), // mub
+    (6452590, IdStatus::Regular), // nub
+    (6452591, IdStatus::Regular), // oub
+    (6452592, IdStatus::Regular), // pub
+    (6452593, IdStatus::Regular), // qub
+    (6452594, IdStatus::Regular), // rub
+    (6452595, IdStatus::Regular), // sub
+    (6452596, IdStatus::Regular), // tub
+    (6452599, IdStatus::Regular), // wub
+    (6452600, IdStatus::Regular), // xub
+    (6452601, IdStatus::Regular), // yub
+    (6452833, IdStatus::Regular), // avb
+    (6452834, IdStatus::Regular), // bvb
+    (6452841, IdStatus::Regular), // ivb
+    (6452843, IdStatus::Regular), // kvb
+    (6452845, IdStatus::Regular), // mvb
+    (6452851, IdStatus::Regular), // svb
+    (6453089, IdStatus::Regular), // awb
+    (6453090, IdStatus::Regular), // bwb
+    (6453091, IdStatus::Regular), // cwb
+    (6453095, IdStatus::Regular), // gwb
+    (6453099, IdStatus::Regular), // kwb
+    (6453101, IdStatus::Regular), // mwb
+    (6453102, IdStatus::Regular), // nwb
+    (6453104, IdStatus::Regular), // pwb
+    (6453107, IdStatus::Regular), // swb
+    (6453108, IdStatus::Regular), // twb
+    (6453111, IdStatus::Regular), // wwb
+    (6453345, IdStatus::Regular), // axb
+    (6453346, IdStatus::Regular), // bxb
+    (6453355, IdStatus::Regular), // kxb
+    (6453357, IdStatus::Regular), // mxb
+    (6453363, IdStatus::Regular), // sxb
+    (6453364, IdStatus::Regular), // txb
+    (6453368, IdStatus::Regular), // xxb
+    (6453601, IdStatus::Regular), // ayb
+    (6453602, IdStatus::Regular), // byb
+    (6453603, IdStatus::Regular), // cyb
+    (6453604, IdStatus::Regular), // dyb
+    (6453607, IdStatus::Regular), // gyb
+    (6453611, IdStatus::Regular), // kyb
+    (6453613, IdStatus::Regular), // myb
+    (6453614, IdStatus::Regular), // nyb
+    (6453615, IdStatus::Regular), // oyb
+    (6453619, IdStatus::Regular), // syb
+    (6453623, IdStatus::Regular), // wyb
+    (6453624, IdStatus::Regular), // xyb
+    (6453626, IdStatus::Deprecated), // zyb
+    (6453857, IdStatus::Regular), // azb
+    (6453858, IdStatus::Regular), // bzb
+    (6453867, IdStatus::Regular), // kzb
+    (6453869, IdStatus::Regular), // mzb
+    (6453870, IdStatus::Regular), // nzb
+    (6453875, IdStatus::Regular), // szb
+    (6512993, IdStatus::Regular), // aac
+    (6512994, IdStatus::Regular), // bac
+    (6512995, IdStatus::Regular), // cac
+    (6512996, IdStatus::Regular), // dac
+    (6512999, IdStatus::Regular), // gac
+    (6513000, IdStatus::Regular), // hac
+    (6513002, IdStatus::Regular), // jac
+    (6513003, IdStatus::Regular), // kac
+    (6513004, IdStatus::Regular), // lac
+    (6513006, IdStatus::Regular), // nac
+    (6513007, IdStatus::Regular), // oac
+    (6513008, IdStatus::Regular), // pac
+    (6513009, IdStatus::Reserved), // qac
+    (6513010, IdStatus::Regular), // rac
+    (6513011, IdStatus::Regular), // sac
+    (6513012, IdStatus::Regular), // tac
+    (6513015, IdStatus::Regular), // wac
+    (6513016, IdStatus::Regular), // xac
+    (6513017, IdStatus::Regular), // yac
+    (6513018, IdStatus::Regular), // zac
+    (6513249, IdStatus::Regular), // abc
+    (6513250, IdStatus::Regular), // bbc
+    (6513251, IdStatus::Regular), // cbc
+    (6513253, IdStatus::Regular), // ebc
+    (6513255, IdStatus::Deprecated), // gbc
+    (6513259, IdStatus::Regular), // kbc
+    (6513260, IdStatus::Regular), // lbc
+    (6513261, IdStatus::Regular), // mbc
+    (6513262, IdStatus::Regular), // nbc
+    (6513264, IdStatus::Regular), // pbc
+    (6513265, IdStatus::Reserved), // qbc
+    (6513267, IdStatus::Regular), // sbc
+    (6513268, IdStatus::Regular), // tbc
+    (6513272, IdStatus::Regular), // xbc
+    (6513274, IdStatus::Regular), // zbc
+    (6513506, IdStatus::Deprecated), // bcc
+    (6513507, IdStatus::Regular), // ccc
+    (6513508, IdStatus::Regular), // dcc
+    (6513511, IdStatus::Regular), // gcc
+    (6513515, IdStatus::Regular), // kcc
+    (6513516, IdStatus::Regular), // lcc
+    (6513517, IdStatus::Regular), // mcc
+    (6513518, IdStatus::Regular), // ncc
+    (6513520, IdStatus::Regular), // pcc
+    (6513521, IdStatus::Reserved), // qcc
+    (6513524, IdStatus::Regular), // tcc
+    (6513528, IdStatus::Regular), // xcc
+    (6513762, IdStatus::Regular), // bdc
+    (6513763, IdStatus::Regular), // cdc
+    (6513767, IdStatus::Regular), // gdc
+    (6513769, IdStatus::Regular), // idc
+    (6513771, IdStatus::Regular), // kdc
+    (6513773, IdStatus::Regular), // mdc
+    (6513774, IdStatus::Regular), // ndc
+    (6513776, IdStatus::Regular), // pdc
+    (6513777, IdStatus::Reserved), // qdc
+    (6513779, IdStatus::Regular), // sdc
+    (6513780, IdStatus::Regular), // tdc
+    (6513784, IdStatus::Regular), // xdc
+    (6514017, IdStatus::Regular), // aec
+    (6514018, IdStatus::Regular), // bec
+    (6514020, IdStatus::Regular), // dec
+    (6514023, IdStatus::Regular), // gec
+    (6514027, IdStatus::Regular), // kec
+    (6514028, IdStatus::Regular), // lec
+    (6514029, IdStatus::Regular), // mec
+    (6514030, IdStatus::Regular), // nec
+    (6514033, IdStatus::Reserved), // qec
+    (6514035, IdStatus::Regular), // sec
+    (6514036, IdStatus::Regular), // tec
+    (6514038, IdStatus::Regular), // vec
+    (6514039, IdStatus::Regular), // wec
+    (6514041, IdStatus::Regular), // yec
+    (6514274, IdStatus::Regular), // bfc
+    (6514283, IdStatus::Regular), // kfc
+    (6514285, IdStatus::Regular), // mfc
+    (6514289, IdStatus::Reserved), // qfc
+    (6514529, IdStatus::Regular), // agc
+    (6514530, IdStatus::Regular), // bgc
+    (6514531, IdStatus::Regular), // cgc
+    (6514532, IdStatus::Regular), // dgc
+    (6514539, IdStatus::Deprecated), // kgc
+    (6514541, IdStatus::Regular), // mgc
+    (6514542, IdStatus::Regular), // ngc
+    (6514543, IdStatus::Regular), // ogc
+    (6514545, IdStatus::PrivateUse), // qgc
+    (6514547, IdStatus::Regular), // sgc
+    (6514548, IdStatus::Regular), // tgc
+    (6514786, IdStatus::Regular), // bhc
+    (6514787, IdStatus::Regular), // chc
+    (6514791, IdStatus::Regular), // ghc
+    (6514795, IdStatus::Regular), // khc
+    (6514797, IdStatus::Regular), // mhc
+    (6514798, IdStatus::Regular), // nhc
+    (6514801, IdStatus::PrivateUse), // qhc
+    (6514803, IdStatus::Regular), // shc
+    (6514804, IdStatus::Deprecated), // thc
+    (6514808, IdStatus::Regular), // xhc
+    (6515041, IdStatus::Regular), // aic
+    (6515042, IdStatus::Deprecated), // bic
+    (6515043, IdStatus::Regular), // cic
+    (6515044, IdStatus::Regular), // dic
+    (6515047, IdStatus::Regular), // gic
+    (6515050, IdStatus::Regular), // jic
+    (6515051, IdStatus::Regular), // kic
+    (6515052, IdStatus::Regular), // lic
+    (6515053, IdStatus::Regular), // mic
+    (6515054, IdStatus::Regular), // nic
+    (6515056, IdStatus::Regular), // pic
+    (6515057, IdStatus::PrivateUse), // qic
+    (6515060, IdStatus::Regular), // tic
+    (6515062, IdStatus::Regular), // vic
+    (6515063, IdStatus::Regular), // wic
+    (6515298, IdStatus::Regular), // bjc
+    (6515300, IdStatus::Regular), // djc
+    (6515305, IdStatus::Regular), // ijc
+    (6515307, IdStatus::Regular), // kjc
+    (6515309, IdStatus::Regular), // mjc
+    (6515311, IdStatus::Regular), // ojc
+    (6515313, IdStatus::PrivateUse), // qjc
+    (6515553, IdStatus::Regular), // akc
+    (6515554, IdStatus::Regular), // bkc
+    (6515557, IdStatus::Deprecated), // ekc
+    (6515563, IdStatus::Regular), // kkc
+    (6515564, IdStatus::Regular), // lkc
+    (6515565, IdStatus::Regular), // mkc
+    (6515566, IdStatus::Regular), // nkc
+    (6515567, IdStatus::Regular), // okc
+    (6515568, IdStatus::Regular), // pkc
+    (6515569, IdStatus::PrivateUse), // qkc
+    (6515571, IdStatus::Regular), // skc
+    (6515576, IdStatus::Regular), // xkc
+    (6515809, IdStatus::Regular), // alc
+    (6515810, IdStatus::Regular), // blc
+    (6515811, IdStatus::Regular), // clc
+    (6515815, IdStatus::Regular), // glc
+    (6515819, IdStatus::Regular), // klc
+    (6515820, IdStatus::Regular), // llc
+    (6515821, IdStatus::Regular), // mlc
+    (6515822, IdStatus::Regular), // nlc
+    (6515824, IdStatus::Regular), // plc
+    (6515825, IdStatus::PrivateUse), // qlc
+    (6515827, IdStatus::Regular), // slc
+    (6515828, IdStatus::Regular), // tlc
+    (6515829, IdStatus::Regular), // ulc
+    (6515831, IdStatus::Regular), // wlc
+    (6515832, IdStatus::Regular), // xlc
+    (6516065, IdStatus::Regular), // amc
+    (6516066, IdStatus::Regular), // bmc
+    (6516067, IdStatus::Regular), // cmc
+    (6516068, IdStatus::Regular), // dmc
+    (6516072, IdStatus::Regular), // hmc
+    (6516074, IdStatus::Regular), // jmc
+    (6516075, IdStatus::Regular), // kmc
+    (6516076, IdStatus::Regular), // lmc
+    (6516077, IdStatus::Regular), // mmc
+    (6516078, IdStatus::Regular), // nmc
+    (6516079, IdStatus::Regular), // omc
+    (6516080, IdStatus::Deprecated), // pmc
+    (6516081, IdStatus::PrivateUse), // qmc
+    (6516082, IdStatus::Regular), // rmc
+    (6516083, IdStatus::Regular), // smc
+    (6516084, IdStatus::Regular), // tmc
+    (6516085, IdStatus::Regular), // umc
+    (6516086, IdStatus::Regular), // vmc
+    (6516087, IdStatus::Regular), // wmc
+    (6516088, IdStatus::Regular), // xmc
+    (6516089, IdStatus::Regular), // ymc
+    (6516090, IdStatus::Regular), // zmc
+    (6516321, IdStatus::Regular), // anc
+    (6516322, IdStatus::Regular), // bnc
+    (6516323, IdStatus::Regular), // cnc
+    (6516325, IdStatus::Regular), // enc
+    (6516327, IdStatus::Regular), // gnc
+    (6516329, IdStatus::Regular), // inc
+    (6516331, IdStatus::Deprecated), // knc
+    (6516333, IdStatus::Regular), // mnc
+    (6516334, IdStatus::Regular), // nnc
+    (6516336, IdStatus::Regular), // pnc
+    (6516337, IdStatus::PrivateUse), // qnc
+    (6516339, IdStatus::Regular), // snc
+    (6516340, IdStatus::Regular), // tnc
+    (6516343, IdStatus::Regular), // wnc
+    (6516577, IdStatus::Regular), // aoc
+    (6516579, IdStatus::Regular), // coc
+    (6516580, IdStatus::Regular), // doc
+    (6516583, IdStatus::Regular), // goc
+    (6516584, IdStatus::Regular), // hoc
+    (6516587, IdStatus::Regular), // koc
+    (6516588, IdStatus::Regular), // loc
+    (6516589, IdStatus::Regular), // moc
+    (6516590, IdStatus::Regular), // noc
+    (6516592, IdStatus::Regular), // poc
+    (6516593, IdStatus::PrivateUse), // qoc
+    (6516594, IdStatus::Regular), // roc
+    (6516595, IdStatus::Regular), // soc
+    (6516596, IdStatus::Regular), // toc
+    (6516599, IdStatus::Regular), // woc
+    (6516600, IdStatus::Regular), // xoc
+    (6516602, IdStatus::Regular), // zoc
+    (6516833, IdStatus::Regular), // apc
+    (6516834, IdStatus::Regular), // bpc
+    (6516835, IdStatus::Regular), // cpc
+    (6516843, IdStatus::Regular), // kpc
+    (6516845, IdStatus::Regular), // mpc
+    (6516849, IdStatus::PrivateUse), // qpc
+    (6516851, IdStatus::Regular), // spc
+    (6516852, IdStatus::Regular), // tpc
+    (6516855, IdStatus::Regular), // wpc
+    (6516856, IdStatus::Regular), // xpc
+    (6516858, IdStatus::Regular), // zpc
+    (6517089, IdStatus::Regular), // aqc
+    (6517090, IdStatus::Regular), // bqc
+    (6517099, IdStatus::Regular), // kqc
+    (6517101, IdStatus::Regular), // mqc
+    (6517105, IdStatus::PrivateUse), // qqc
+    (6517345, IdStatus::Regular), // arc
+    (6517346, IdStatus::Regular), // brc
+    (6517347, IdStatus::Regular), // crc
+    (6517348, IdStatus::Regular), // drc
+    (6517350, IdStatus::Regular), // frc
+    (6517351, IdStatus::Regular), // grc
+    (6517352, IdStatus::Regular), // hrc
+    (6517355, IdStatus::Regular), // krc
+    (6517356, IdStatus::Regular), // lrc
+    (6517357, IdStatus::Regular), // mrc
+    (6517358, IdStatus::Regular), // nrc
+    (6517359, IdStatus::Regular), // orc
+    (6517360, IdStatus::Regular), // prc
+    (6517361, IdStatus::PrivateUse), // qrc
+    (6517363, IdStatus::Deprecated), // src
+    (6517364, IdStatus::Regular), // trc
+    (6517365, IdStatus::Regular), // urc
+    (6517601, IdStatus::Regular), // asc
+    (6517602, IdStatus::Regular), // bsc
+    (6517603, IdStatus::Regular), // csc
+    (6517609, IdStatus::Regular), // isc
+    (6517611, IdStatus::Regular), // ksc
+    (6517612, IdStatus::Regular), // lsc
+    (6517613, IdStatus::Regular), // msc
+    (6517614, IdStatus::Regular), // nsc
+    (6517615, IdStatus::Regular), // osc
+    (6517616, IdStatus::Regular), // psc
+    (6517617, IdStatus::PrivateUse), // qsc
+    (6517619, IdStatus::Regular), // ssc
+    (6517620, IdStatus::Regular), // tsc
+    (6517624, IdStatus::Regular), // xsc
+    (6517625, IdStatus::Regular), // ysc
+    (6517857, IdStatus::Regular), // atc
+    (6517858, IdStatus::Regular), // btc
+    (6517859, IdStatus::Regular), // ctc
+    (6517861, IdStatus::Regular), // etc
+    (6517865, IdStatus::Regular), // itc
+    (6517867, IdStatus::Regular), // ktc
+    (6517868, IdStatus::Regular), // ltc
+    (6517869, IdStatus::Regular), // mtc
+    (6517873, IdStatus::PrivateUse), // qtc
+    (6517874, IdStatus::Regular), // rtc
+    (6517876, IdStatus::Regular), // ttc
+    (6517880, IdStatus::Regular), // xtc
+    (6518113, IdStatus::Regular), // auc
+    (6518114, IdStatus::Regular), // buc
+    (6518115, IdStatus::Regular), // cuc
+    (6518116, IdStatus::Regular), // duc
+    (6518118, IdStatus::Deprecated), // fuc
+    (6518119, IdStatus::Regular), // guc
+    (6518120, IdStatus::Regular), // huc
+    (6518122, IdStatus::Regular), // juc
+    (6518123, IdStatus::Regular), // kuc
+    (6518124, IdStatus::Regular), // luc
+    (6518125, IdStatus::Regular), // muc
+    (6518126, IdStatus::Regular), // nuc
+    (6518128, IdStatus::Regular), // puc
+    (6518129, IdStatus::Regular), // quc
+    (6518130, IdStatus::Regular), // ruc
+    (6518131, IdStatus::Regular), // suc
+    (6518132, IdStatus::Regular), // tuc
+    (6518137, IdStatus::Regular), // yuc
+    (6518370, IdStatus::Regular), // bvc
+    (6518375, IdStatus::Regular), // gvc
+    (6518376, IdStatus::Regular), // hvc
+    (6518379, IdStatus::Regular), // kvc
+    (6518385, IdStatus::Regular), // qvc
+    (6518387, IdStatus::Regular), // svc
+    (6518625, IdStatus::Regular), // awc
+    (6518626, IdStatus::Regular), // bwc
+    (6518631, IdStatus::Regular), // gwc
+    (6518632, IdStatus::Regular), // hwc
+    (6518635, IdStatus::Regular), // kwc
+    (6518637, IdStatus::Regular), // mwc
+    (6518638, IdStatus::Regular), // nwc
+    (6518641, IdStatus::Regular), // qwc
+    (6518643, IdStatus::Deprecated), // swc
+    (6518644, IdStatus::Regular), // twc
+    (6518648, IdStatus::Regular), // xwc
+    (6518882, IdStatus::Regular), // bxc
+    (6518889, IdStatus::Regular), // ixc
+    (6518891, IdStatus::Regular), // kxc
+    (6518893, IdStatus::Regular), // mxc
+    (6518897, IdStatus::Regular), // qxc
+    (6518899, IdStatus::Regular), // sxc
+    (6518900, IdStatus::Regular), // txc
+    (6519137, IdStatus::Regular), // ayc
+    (6519138, IdStatus::Regular), // byc
+    (6519147, IdStatus::Regular), // kyc
+    (6519149, IdStatus::Regular), // myc
+    (6519150, IdStatus::Regular), // nyc
+    (6519155, IdStatus::Regular), // syc
+    (6519393, IdStatus::Regular), // azc
+    (6519394, IdStatus::Regular), // bzc
+    (6519403, IdStatus::Regular), // kzc
+    (6519405, IdStatus::Regular), // mzc
+    (6519411, IdStatus::Regular), // szc
+    (6578529, IdStatus::Regular), // aad
+    (6578530, IdStatus::Regular), // bad
+    (6578531, IdStatus::Regular), // cad
+    (6578532, IdStatus::Regular), // dad
+    (6578534, IdStatus::Regular), // fad
+    (6578535, IdStatus::Regular), // gad
+    (6578536, IdStatus::Regular), // had
+    (6578538, IdStatus::Regular), // jad
+    (6578539, IdStatus::Regular), // kad
+    (6578540, IdStatus::Regular), // lad
+    (6578541, IdStatus::Regular), // mad
+    (6578542, IdStatus::Deprecated), // nad
+    (6578544, IdStatus::Regular), // pad
+    (6578545, IdStatus::Reserved), // qad
+    (6578546, IdStatus::Regular), // rad
+    (6578547, IdStatus::Regular), // sad
+    (6578548, IdStatus::Regular), // tad
+    (6578551, IdStatus::Regular), // wad
+    (6578552, IdStatus::Regular), // xad
+    (6578553, IdStatus::Regular), // yad
+    (6578554, IdStatus::Regular), // zad
+    (6578785, IdStatus::Regular), // abd
+    (6578786, IdStatus::Regular), // bbd
+    (6578787, IdStatus::Regular), // cbd
+    (6578788, IdStatus::Regular), // dbd
+    (6578791, IdStatus::Regular), // gbd
+    (6578793, IdStatus::Regular), // ibd
+    (6578795, IdStatus::Regular), // kbd
+    (6578797, IdStatus::Regular), // mbd
+    (6578798, IdStatus::Regular), // nbd
+    (6578801, IdStatus::Reserved), // qbd
+    (6578803, IdStatus::Regular), // sbd
+    (6578804, IdStatus::Regular), // tbd
+    (6578808, IdStatus::Regular), // xbd
+    (6578809, IdStatus::Deprecated), // ybd
+    (6579041, IdStatus::Regular), // acd
+    (6579042, IdStatus::Regular), // bcd
+    (6579043, IdStatus::Regular), // ccd
+    (6579047, IdStatus::Regular), // gcd
+    (6579051, IdStatus::Regular), // kcd
+    (6579052, IdStatus::Regular), // lcd
+    (6579053, IdStatus::Regular), // mcd
+    (6579054, IdStatus::Regular), // ncd
+    (6579056, IdStatus::Regular), // pcd
+    (6579057, IdStatus::Reserved), // qcd
+    (6579060, IdStatus::Regular), // tcd
+    (6579066, IdStatus::Regular), // zcd
+    (6579297, IdStatus::Regular), // add
+    (6579298, IdStatus::Regular), // bdd
+    (6579299, IdStatus::Regular), // cdd
+    (6579300, IdStatus::Regular), // ddd
+    (6579303, IdStatus::Regular), // gdd
+    (6579305, IdStatus::Regular), // idd
+    (6579307, IdStatus::Regular), // kdd
+    (6579308, IdStatus::Regular), // ldd
+    (6579309, IdStatus::Regular), // mdd
+    (6579310, IdStatus::Regular), // ndd
+    (6579313, IdStatus::Reserved), // qdd
+    (6579316, IdStatus::Regular), // tdd
+    (6579319, IdStatus::Regular), // wdd
+    (6579321, IdStatus::Deprecated), // ydd
+    (6579553, IdStatus::Regular), // aed
+    (6579554, IdStatus::Regular), // bed
+    (6579556, IdStatus::Regular), // ded
+    (6579559, IdStatus::Regular), // ged
+    (6579560, IdStatus::Regular), // hed
+    (6579563, IdStatus::Regular), // ked
+    (6579564, IdStatus::Regular), // led
+    (6579565, IdStatus::Regular), // med
+    (6579566, IdStatus::Regular), // ned
+    (6579568, IdStatus::Regular), // ped
+    (6579569, IdStatus::Reserved), // qed
+    (6579571, IdStatus::Regular), // sed
+    (6579572, IdStatus::Regular), // ted
+    (6579574, IdStatus::Regular), // ved
+    (6579575, IdStatus::Regular), // wed
+    (6579576, IdStatus::Regular), // xed
+    (6579809, IdStatus::Regular), // afd
+    (6579810, IdStatus::Regular), // bfd
+    (6579811, IdStatus::Regular), // cfd
+    (6579819, IdStatus::Regular), // kfd
+    (6579821, IdStatus::Regular), // mfd
+    (6579822, IdStatus::Regular), // nfd
+    (6579825, IdStatus::Reserved), // qfd
+    (6580065, IdStatus::Regular), // agd
+    (6580066, IdStatus::Regular), // bgd
+    (6580068, IdStatus::Regular), // dgd
+    (6580071, IdStatus::Regular), // ggd
+    (6580075, IdStatus::Deprecated), // kgd
+    (6580077, IdStatus::Regular), // mgd
+    (6580078, IdStatus::Regular), // ngd
+    (6580080, IdStatus::Regular), // pgd
+    (6580081, IdStatus::PrivateUse), // qgd
+    (6580083, IdStatus::Regular), // sgd
+    (6580084, IdStatus::Regular), // tgd
+    (6580088, IdStatus::Regular), // xgd
+    (6580322, IdStatus::Regular), // bhd
+    (6580323, IdStatus::Regular), // chd
+    (6580324, IdStatus::Deprecated), // dhd
+    (6580331, IdStatus::Regular), // khd
+    (6580333, IdStatus::Regular), // mhd
+    (6580334, IdStatus::Regular), // nhd
+    (6580336, IdStatus::Regular), // phd
+    (6580337, IdStatus::PrivateUse), // qhd
+    (6580339, IdStatus::Regular), // shd
+    (6580340, IdStatus::Regular), // thd
+    (6580344, IdStatus::Regular), // xhd
+    (6580345, IdStatus::Regular), // yhd
+    (6580346, IdStatus::Regular), // zhd
+    (6580577, IdStatus::Regular), // aid
+    (6580578, IdStatus::Regular), // bid
+    (6580579, IdStatus::Regular), // cid
+    (6580580, IdStatus::Regular), // did
+    (6580583, IdStatus::Regular), // gid
+    (6580584, IdStatus::Regular), // hid
+    (6580586, IdStatus::Regular), // jid
+    (6580587, IdStatus::Regular), // kid
+    (6580588, IdStatus::Regular), // lid
+    (6580589, IdStatus::Regular), // mid
+    (6580590, IdStatus::Regular), // nid
+    (6580592, IdStatus::Regular), // pid
+    (6580593, IdStatus::PrivateUse), // qid
+    (6580595, IdStatus::Regular), // sid
+    (6580596, IdStatus::Deprecated), // tid
+    (6580598, IdStatus::Regular), // vid
+    (6580834, IdStatus::Deprecated), // bjd
+    (6580836, IdStatus::Regular), // djd
+    (6580843, IdStatus::Regular), // kjd
+    (6580845, IdStatus::Regular), // mjd
+    (6580846, IdStatus::Regular), // njd
+    (6580849, IdStatus::PrivateUse), // qjd
+    (6580851, IdStatus::Regular), // sjd
+    (6581089, IdStatus::Regular), // akd
+    (6581090, IdStatus::Regular), // bkd
+    (6581095, IdStatus::Regular), // gkd
+    (6581099, IdStatus::Regular), // kkd
+    (6581100, IdStatus::Regular), // lkd
+    (6581102, IdStatus::Regular), // nkd
+    (6581103, IdStatus::Regular), // okd
+    (6581105, IdStatus::PrivateUse), // qkd
+    (6581107, IdStatus::Regular), // skd
+    (6581108, IdStatus::Regular), // tkd
+    (6581111, IdStatus::Regular), // wkd
+    (6581112, IdStatus::Regular), // xkd
+    (6581114, IdStatus::Regular), // zkd
+    (6581345, IdStatus::Regular), // ald
+    (6581346, IdStatus::Regular), // bld
+    (6581347, IdStatus::Deprecated), // cld
+    (6581351, IdStatus::Regular), // gld
+    (6581352, IdStatus::Regular), // hld
+    (6581355, IdStatus::Regular), // kld
+    (6581356, IdStatus::Regular), // lld
+    (6581357, IdStatus::Deprecated), // mld
+    (6581359, IdStatus::Regular), // old
+    (6581360, IdStatus::Regular), // pld
+    (6581361, IdStatus::PrivateUse), // qld
+    (6581363, IdStatus::Regular), // sld
+    (6581364, IdStatus::Regular), // tld
+    (6581368, IdStatus::Regular), // xld
+    (6581602, IdStatus::Regular), // bmd
+    (6581604, IdStatus::Regular), // dmd
+    (6581607, IdStatus::Regular), // gmd
+    (6581608, IdStatus::Regular), // hmd
+    (6581610, IdStatus::Regular), // jmd
+    (6581611, IdStatus::Regular), // kmd
+    (6581612, IdStatus::Regular), // lmd
+    (6581613, IdStatus::Regular), // mmd
+    (6581614, IdStatus::Regular), // nmd
+    (6581616, IdStatus::Regular), // pmd
+    (6581617, IdStatus::PrivateUse), // qmd
+    (6581618, IdStatus::Regular), // rmd
+    (6581619, IdStatus::Deprecated), // smd
+    (6581620, IdStatus::Regular), // tmd
+    (6581621, IdStatus::Regular), // umd
+    (6581622, IdStatus::Regular), // vmd
+    (6581623, IdStatus::Regular), // wmd
+    (6581624, IdStatus::Regular), // xmd
+    (6581625, IdStatus::Regular), // ymd
+    (6581626, IdStatus::Regular), // zmd
+    (6581857, IdStatus::Regular), // and
+    (6581858, IdStatus::Regular), // bnd
+    (6581860, IdStatus::Regular), // dnd
+    (6581861, IdStatus::Regular), // end
+    (6581863, IdStatus::Regular), // gnd
+    (6581864, IdStatus::Regular), // hnd
+    (6581866, IdStatus::Regular), // jnd
+    (6581867, IdStatus::Regular), // knd
+    (6581868, IdStatus::Regular), // lnd
+    (6581869, IdStatus::Regular), // mnd
+    (6581870, IdStatus::Regular), // nnd
+    (6581872, IdStatus::Regular), // pnd
+    (6581873, IdStatus::PrivateUse), // qnd
+    (6581874, IdStatus::Regular), // rnd
+    (6581876, IdStatus::Regular), // tnd
+    (6581877, IdStatus::Unknown), // und
+    (6581879, IdStatus::Regular), // wnd
+    (6581880, IdStatus::Regular), // xnd
+    (6581881, IdStatus::Regular), // ynd
+    (6581882, IdStatus::Regular), // znd
+    (6582113, IdStatus::Regular), // aod
+    (6582115, IdStatus::Regular), // cod
+    (6582118, IdStatus::Regular), // fod
+    (6582119, IdStatus::Regular), // god
+    (6582120, IdStatus::Regular), // hod
+    (6582122, IdStatus::Regular), // jod
+    (6582123, IdStatus::Regular), // kod
+    (6582125, IdStatus::Regular), // mod
+    (6582126, IdStatus::Regular), // nod
+    (6582127, IdStatus::Regular), // ood
+    (6582128, IdStatus::Deprecated), // pod
+    (6582129, IdStatus::PrivateUse), // qod
+    (6582130, IdStatus::Regular), // rod
+    (6582131, IdStatus::Regular), // sod
+    (6582132, IdStatus::Regular), // tod
+    (6582135, IdStatus::Regular), // wod
+    (6582136, IdStatus::Regular), // xod
+    (6582369, IdStatus::Regular), // apd
+    (6582370, IdStatus::Regular), // bpd
+    (6582379, IdStatus::Regular), // kpd
+    (6582381, IdStatus::Regular), // mpd
+    (6582385, IdStatus::PrivateUse), // qpd
+    (6582387, IdStatus::Regular), // spd
+    (6582392, IdStatus::Regular), // xpd
+    (6582394, IdStatus::Regular), // zpd
+    (6582625, IdStatus::Regular), // aqd
+    (6582626, IdStatus::Regular), // bqd
+    (6582627, IdStatus::Regular), // cqd
+    (6582635, IdStatus::Regular), // kqd
+    (6582641, IdStatus::PrivateUse), // qqd
+    (6582881, IdStatus::Regular), // ard
+    (6582882, IdStatus::Regular), // brd
+    (6582883, IdStatus::Regular), // crd
+    (6582884, IdStatus::Regular), // drd
+    (6582886, IdStatus::Regular), // frd
+    (6582887, IdStatus::Regular), // grd
+    (6582891, IdStatus::Regular), // krd
+    (6582893, IdStatus::Regular), // mrd
+    (6582896, IdStatus::Regular), // prd
+    (6582897, IdStatus::PrivateUse), // qrd
+    (6582900, IdStatus::Regular), // trd
+    (6582903, IdStatus::Deprecated), // wrd
+    (6582904, IdStatus::Regular), // xrd
+    (6583137, IdStatus::Deprecated), // asd
+    (6583139, IdStatus::Regular), // csd
+    (6583145, IdStatus::Regular), // isd
+    (6583147, IdStatus::Regular), // ksd
+    (6583148, IdStatus::Regular), // lsd
+    (6583149, IdStatus::Regular), // msd
+    (6583150, IdStatus::Regular), // nsd
+    (6583152, IdStatus::Regular), // psd
+    (6583153, IdStatus::PrivateUse), // qsd
+    (6583155, IdStatus::Regular), // ssd
+    (6583156, IdStatus::Regular), // tsd
+    (6583160, IdStatus::Regular), // xsd
+    (6583161, IdStatus::Regular), // ysd
+    (6583393, IdStatus::Regular), // atd
+    (6583394, IdStatus::Regular), // btd
+    (6583395, IdStatus::Regular), // ctd
+    (6583396, IdStatus::Regular), // dtd
+    (6583401, IdStatus::Regular), // itd
+    (6583403, IdStatus::Regular), // ktd
+    (6583405, IdStatus::Regular), // mtd
+    (6583406, IdStatus::Regular), // ntd
+    (6583407, IdStatus::Regular), // otd
+    (6583409, IdStatus::PrivateUse), // qtd
+    (6583411, IdStatus::Regular), // std
+    (6583412, IdStatus::Regular), // ttd
+    (6583416, IdStatus::Regular), // xtd
+    (6583649, IdStatus::Regular), // aud
+    (6583650, IdStatus::Regular), // bud
+    (6583652, IdStatus::Deprecated), // dud
+    (6583654, IdStatus::Regular), // fud
+    (6583655, IdStatus::Regular), // gud
+    (6583656, IdStatus::Regular), // hud
+    (6583658, IdStatus::Regular), // jud
+    (6583659, IdStatus::Regular), // kud
+    (6583660, IdStatus::Regular), // lud
+    (6583661, IdStatus::Regular), // mud
+    (6583662, IdStatus::Regular), // nud
+    (6583664, IdStatus::Regular), // pud
+    (6583665, IdStatus::Regular), // qud
+    (6583668, IdStatus::Regular), // tud
+    (6583671, IdStatus::Regular), // wud
+    (6583672, IdStatus::Regular), // xud
+    (6583673, IdStatus::Regular), // yud
+    (6583905, IdStatus::Regular), // avd
+    (6583906, IdStatus::Regular), // bvd
+    (6583914, IdStatus::Regular), // jvd
+    (6583915, IdStatus::Regular), // kvd
+    (6583917, IdStatus::Regular), // mvd
+    (6583919, IdStatus::Regular), // ovd
+    (6583924, IdStatus::Regular), // tvd
+    (6584161, IdStatus::Regular), // awd
+    (6584162, IdStatus::Regular), // bwd
+    (6584163, IdStatus::Deprecated), // cwd
+    (6584167, IdStatus::Regular), // gwd
+    (6584171, IdStatus::Regular), // kwd
+    (6584173, IdStatus::Deprecated), // mwd
+    (6584180, IdStatus::Regular), // twd
+    (6584184, IdStatus::Regular), // xwd
+    (6584418, IdStatus::Regular), // bxd
+    (6584427, IdStatus::Regular), // kxd
+    (6584429, IdStatus::Regular), // mxd
+    (6584430, IdStatus::Regular), // nxd
+    (6584434, IdStatus::Regular), // rxd
+    (6584673, IdStatus::Regular), // ayd
+    (6584674, IdStatus::Regular), // byd
+    (6584676, IdStatus::Regular), // dyd
+    (6584679, IdStatus::Regular), // gyd
+    (6584683, IdStatus::Regular), // kyd
+    (6584685, IdStatus::Deprecated), // myd
+    (6584686, IdStatus::Regular), // nyd
+    (6584687, IdStatus::Regular), // oyd
+    (6584691, IdStatus::Regular), // syd
+    (6584929, IdStatus::Regular), // azd
+    (6584930, IdStatus::Regular), // bzd
+    (6584932, IdStatus::Deprecated), // dzd
+    (6584939, IdStatus::Regular), // kzd
+    (6584941, IdStatus::Regular), // mzd
+    (6584942, IdStatus::Regular), // nzd
+    (6584947, IdStatus::Regular), // szd
+    (6644065, IdStatus::Regular), // aae
+    (6644066, IdStatus::Regular), // bae
+    (6644067, IdStatus::Regular), // cae
+    (6644068, IdStatus::Regular), // dae
+    (6644071, IdStatus::Regular), // gae
+    (6644072, IdStatus::Regular), // hae
+    (6644074, IdStatus::Regular), // jae
+    (6644075, IdStatus::Regular), // kae
+    (6644076, IdStatus::Regular), // lae
+    (6644077, IdStatus::Regular), // mae
+    (6644078, IdStatus::Regular), // nae
+    (6644080, IdStatus::Regular), // pae
+    (6644081, IdStatus::Reserved), // qae
+    (6644083, IdStatus::Regular), // sae
+    (6644084, IdStatus::Regular), // tae
+    (6644086, IdStatus::Regular), // vae
+    (6644087, IdStatus::Regular), // wae
+    (6644088, IdStatus::Regular), // xae
+    (6644089, IdStatus::Regular), // yae
+    (6644090, IdStatus::Regular), // zae
+    (6644321, IdStatus::Regular), // abe
+    (6644322, IdStatus::Regular), // bbe
+    (6644323, IdStatus::Deprecated), // cbe
+    (6644324, IdStatus::Regular), // dbe
+    (6644327, IdStatus::Regular), // gbe
+    (6644329, IdStatus::Regular), // ibe
+    (6644330, IdStatus::Regular), // jbe
+    (6644331, IdStatus::Regular), // kbe
+    (6644332, IdStatus::Regular), // lbe
+    (6644333, IdStatus::Regular), // mbe
+    (6644334, IdStatus::Regular), // nbe
+    (6644336, IdStatus::Regular), // pbe
+    (6644337, IdStatus::Reserved), // qbe
+    (6644339, IdStatus::Regular), // sbe
+    (6644340, IdStatus::Regular), // tbe
+    (6644343, IdStatus::Regular), // wbe
+    (6644344, IdStatus::Regular), // xbe
+    (6644345, IdStatus::Regular), // ybe
+    (6644346, IdStatus::Regular), // zbe
+    (6644577, IdStatus::Regular), // ace
+    (6644578, IdStatus::Regular), // bce
+    (6644579, IdStatus::Regular), // cce
+    (6644583, IdStatus::Regular), // gce
+    (6644587, IdStatus::Regular), // kce
+    (6644588, IdStatus::Regular), // lce
+    (6644589, IdStatus::Regular), // mce
+    (6644590, IdStatus::Regular), // nce
+    (6644592, IdStatus::Regular), // pce
+    (6644593, IdStatus::Reserved), // qce
+    (6644595, IdStatus::Regular), // sce
+    (6644596, IdStatus::Regular), // tce
+    (6644600, IdStatus::Regular), // xce
+    (6644833, IdStatus::Regular), // ade
+    (6644834, IdStatus::Regular), // bde
+    (6644835, IdStatus::Regular), // cde
+    (6644836, IdStatus::Regular), // dde
+    (6644839, IdStatus::Regular), // gde
+    (6644841, IdStatus::Regular), // ide
+    (6644843, IdStatus::Regular), // kde
+    (6644845, IdStatus::Regular), // mde
+    (6644849, IdStatus::Reserved), // qde
+    (6644851, IdStatus::Regular), // sde
+    (6644852, IdStatus::Regular), // tde
+    (6644853, IdStatus::Regular), // ude
+    (6644857, IdStatus::Regular), // yde
+    (6645089, IdStatus::Regular), // aee
+    (6645090, IdStatus::Regular), // bee
+    (6645092, IdStatus::Regular), // dee
+    (6645093, IdStatus::Regular), // eee
+    (6645098, IdStatus::Regular), // jee
+    (6645099, IdStatus::Regular), // kee
+    (6645100, IdStatus::Regular), // lee
+    (6645101, IdStatus::Regular), // mee
+    (6645102, IdStatus::Regular), // nee
+    (6645104, IdStatus::Regular), // pee
+    (6645105, IdStatus::Reserved), // qee
+    (6645106, IdStatus::Regular), // ree
+    (6645107, IdStatus::Regular), // see
+    (6645108, IdStatus::Regular), // tee
+    (6645113, IdStatus::Regular), // yee
+    (6645345, IdStatus::Regular), // afe
+    (6645346, IdStatus::Regular), // bfe
+    (6645349, IdStatus::Regular), // efe
+    (6645353, IdStatus::Regular), // ife
+    (6645355, IdStatus::Regular), // kfe
+    (6645357, IdStatus::Regular), // mfe
+    (6645360, IdStatus::Regular), // pfe
+    (6645361, IdStatus::Reserved), // qfe
+    (6645363, IdStatus::Regular), // sfe
+    (6645601, IdStatus::Regular), // age
+    (6645602, IdStatus::Regular), // bge
+    (6645604, IdStatus::Regular), // dge
+    (6645607, IdStatus::Regular), // gge
+    (6645609, IdStatus::Regular), // ige
+    (6645610, IdStatus::Regular), // jge
+    (6645611, IdStatus::Regular), // kge
+    (6645613, IdStatus::Regular), // mge
+    (6645614, IdStatus::Regular), // nge
+    (6645615, IdStatus::Regular), // oge
+    (6645617, IdStatus::PrivateUse), // qge
+    (6645618, IdStatus::Regular), // rge
+    (6645619, IdStatus::Regular), // sge
+    (6645620, IdStatus::Regular), // tge
+    (6645621, IdStatus::Regular), // uge
+    (6645858, IdStatus::Regular), // bhe
+    (6645863, IdStatus::Regular), // ghe
+    (6645867, IdStatus::Regular), // khe
+    (6645869, IdStatus::Regular), // mhe
+    (6645870, IdStatus::Regular), // nhe
+    (6645873, IdStatus::PrivateUse), // qhe
+    (6645875, IdStatus::Regular), // she
+    (6645876, IdStatus::Regular), // the
+    (6645880, IdStatus::Regular), // xhe
+    (6646113, IdStatus::Regular), // aie
+    (6646114, IdStatus::Regular), // bie
+    (6646115, IdStatus::Regular), // cie
+    (6646118, IdStatus::Regular), // fie
+    (6646119, IdStatus::Regular), // gie
+    (6646122, IdStatus::Regular), // jie
+    (6646123, IdStatus::Regular), // kie
+    (6646124, IdStatus::Regular), // lie
+    (6646125, IdStatus::Regular), // mie
+    (6646126, IdStatus::Regular), // nie
+    (6646127, IdStatus::Regular), // oie
+    (6646128, IdStatus::Regular), // pie
+    (6646129, IdStatus::PrivateUse), // qie
+    (6646130, IdStatus::Deprecated), // rie
+    (6646131, IdStatus::Regular), // sie
+    (6646132, IdStatus::Deprecated), // tie
+    (6646135, IdStatus::Regular), // wie
+    (6646370, IdStatus::Regular), // bje
+    (6646371, IdStatus::Regular), // cje
+    (6646372, IdStatus::Regular), // dje
+    (6646377, IdStatus::Regular), // ije
+    (6646378, IdStatus::Regular), // jje
+    (6646379, IdStatus::Regular), // kje
+    (6646380, IdStatus::Regular), // lje
+    (6646381, IdStatus::Regular), // mje
+    (6646385, IdStatus::PrivateUse), // qje
+    (6646387, IdStatus::Regular), // sje
+    (6646625, IdStatus::Regular), // ake
+    (6646629, IdStatus::Regular), // eke
+    (6646631, IdStatus::Regular), // gke
+    (6646632, IdStatus::Regular), // hke
+    (6646633, IdStatus::Deprecated), // ike
+    (6646635, IdStatus::Regular), // kke
+    (6646636, IdStatus::Regular), // lke
+    (6646637, IdStatus::Regular), // mke
+    (6646638, IdStatus::Regular), // nke
+    (6646639, IdStatus::Regular), // oke
+    (6646641, IdStatus::PrivateUse), // qke
+    (6646643, IdStatus::Regular), // ske
+    (6646644, IdStatus::Regular), // tke
+    (6646648, IdStatus::Regular), // xke
+    (6646881, IdStatus::Regular), // ale
+    (6646882, IdStatus::Regular), // ble
+    (6646883, IdStatus::Regular), // cle
+    (6646885, IdStatus::Regular), // ele
+    (6646888, IdStatus::Regular), // hle
+    (6646890, IdStatus::Regular), // jle
+    (6646891, IdStatus::Regular), // kle
+    (6646892, IdStatus::Regular), // lle
+    (6646893, IdStatus::Regular), // mle
+    (6646894, IdStatus::Regular), // nle
+    (6646895, IdStatus::Regular), // ole
+    (6646896, IdStatus::Regular), // ple
+    (6646897, IdStatus::PrivateUse), // qle
+    (6646899, IdStatus::Regular), // sle
+    (6646901, IdStatus::Regular), // ule
+    (6646903, IdStatus::Regular), // wle
+    (6646904, IdStatus::Regular), // xle
+    (6646905, IdStatus::Regular), // yle
+    (6646906, IdStatus::Regular), // zle
+    (6647137, IdStatus::Regular), // ame
+    (6647138, IdStatus::Regular), // bme
+    (6647139, IdStatus::Regular), // cme
+    (6647140, IdStatus::Regular), // dme
+    (6647141, IdStatus::Regular), // eme
+    (6647143, IdStatus::Regular), // gme
+    (6647144, IdStatus::Regular), // hme
+    (6647145, IdStatus::Deprecated), // ime
+    (6647147, IdStatus::Regular), // kme
+    (6647148, IdStatus::Regular), // lme
+    (6647149, IdStatus::Regular), // mme
+    (6647150, IdStatus::Regular), // nme
+    (6647151, IdStatus::Deprecated), // ome
+    (6647152, IdStatus::Regular), // pme
+    (6647153, IdStatus::PrivateUse), // qme
+    (6647154, IdStatus::Regular), // rme
+    (6647156, IdStatus::Regular), // tme
+    (6647158, IdStatus::Regular), // vme
+    (6647159, IdStatus::Regular), // wme
+    (6647160, IdStatus::Regular), // xme
+    (6647161, IdStatus::Regular), // yme
+    (6647162, IdStatus::Regular), // zme
+    (6647393, IdStatus::Regular), // ane
+    (6647394, IdStatus::Regular), // bne
+    (6647396, IdStatus::Regular), // dne
+    (6647399, IdStatus::Regular), // gne
+    (6647400, IdStatus::Regular), // hne
+    (6647401, IdStatus::Regular), // ine
+    (6647403, IdStatus::Regular), // kne
+    (6647405, IdStatus::Regular), // mne
+    (6647406, IdStatus::Regular), // nne
+    (6647407, IdStatus::Regular), // one
+    (6647408, IdStatus::Regular), // pne
+    (6647409, IdStatus::PrivateUse), // qne
+    (6647411, IdStatus::Regular), // sne
+    (6647412, IdStatus::Deprecated), // tne
+    (6647413, IdStatus::Regular), // une
+    (6647415, IdStatus::Regular), // wne
+    (6647417, IdStatus::Regular), // yne
+    (6647418, IdStatus::Regular), // zne
+    (6647649, IdStatus::Regular), // aoe
+    (6647650, IdStatus::Regular), // boe
+    (6647651, IdStatus::Regular), // coe
+    (6647652, IdStatus::Regular), // doe
+    (6647655, IdStatus::Regular), // goe
+    (6647656, IdStatus::Regular), // hoe
+    (6647659, IdStatus::Regular), // koe
+    (6647660, IdStatus::Regular), // loe
+    (6647661, IdStatus::Regular), // moe
+    (6647662, IdStatus::Regular), // noe
+    (6647664, IdStatus::Regular), // poe
+    (6647665, IdStatus::PrivateUse), // qoe
+    (6647666, IdStatus::Regular), // roe
+    (6647667, IdStatus::Regular), // soe
+    (6647668, IdStatus::Deprecated), // toe
+    (6647671, IdStatus::Regular), // woe
+    (6647905, IdStatus::Regular), // ape
+    (6647906, IdStatus::Regular), // bpe
+    (6647907, IdStatus::Regular), // cpe
+    (6647910, IdStatus::Regular), // fpe
+    (6647911, IdStatus::Regular), // gpe
+    (6647915, IdStatus::Regular), // kpe
+    (6647916, IdStatus::Regular), // lpe
+    (6647917, IdStatus::Regular), // mpe
+    (6647920, IdStatus::Regular), // ppe
+    (6647921, IdStatus::PrivateUse), // qpe
+    (6647923, IdStatus::Regular), // spe
+    (6647924, IdStatus::Regular), // tpe
+    (6647928, IdStatus::Deprecated), // xpe
+    (6647930, IdStatus::Regular), // zpe
+    (6648171, IdStatus::Regular), // kqe
+    (6648173, IdStatus::Regular), // mqe
+    (6648176, IdStatus::Regular), // pqe
+    (6648177, IdStatus::PrivateUse), // qqe
+    (6648186, IdStatus::Regular), // zqe
+    (6648417, IdStatus::Regular), // are
+    (6648420, IdStatus::Regular), // dre
+    (6648424, IdStatus::Regular), // hre
+    (6648425, IdStatus::Regular), // ire
+    (6648427, IdStatus::Regular), // kre
+    (6648428, IdStatus::Regular), // lre
+    (6648429, IdStatus::Regular), // mre
+    (6648430, IdStatus::Regular), // nre
+    (6648431, IdStatus::Regular), // ore
+    (6648432, IdStatus::Regular), // pre
+    (6648433, IdStatus::PrivateUse), // qre
+    (6648435, IdStatus::Regular), // sre
+    (6648436, IdStatus::Regular), // tre
+    (6648437, IdStatus::Regular), // ure
+    (6648440, IdStatus::Regular), // xre
+    (6648441, IdStatus::Regular), // yre
+    (6648673, IdStatus::Regular), // ase
+    (6648674, IdStatus::Regular), // bse
+    (6648675, IdStatus::Regular), // cse
+    (6648676, IdStatus::Regular), // dse
+    (6648677, IdStatus::Regular), // ese
+    (6648678, IdStatus::Regular), // fse
+    (6648679, IdStatus::Regular), // gse
+    (6648681, IdStatus::Regular), // ise
+    (6648683, IdStatus::Regular), // kse
+    (6648684, IdStatus::Regular), // lse
+    (6648685, IdStatus::Regular), // mse
+    (6648686, IdStatus::Regular), // nse
+    (6648688, IdStatus::Regular), // pse
+    (6648689, IdStatus::PrivateUse), // qse
+    (6648691, IdStatus::Regular), // sse
+    (6648692, IdStatus::Regular), // tse
+    (6648696, IdStatus::Regular), // xse
+    (6648929, IdStatus::Regular), // ate
+    (6648930, IdStatus::Regular), // bte
+    (6648931, IdStatus::Regular), // cte
+    (6648937, IdStatus::Regular), // ite
+    (6648939, IdStatus::Regular), // kte
+    (6648941, IdStatus::Regular), // mte
+    (6648942, IdStatus::Regular), // nte
+    (6648943, IdStatus::Regular), // ote
+    (6648945, IdStatus::PrivateUse), // qte
+    (6648947, IdStatus::Regular), // ste
+    (6648948, IdStatus::Regular), // tte
+    (6648949, IdStatus::Regular), // ute
+    (6648952, IdStatus::Regular), // xte
+    (6648954, IdStatus::Regular), // zte
+    (6649185, IdStatus::Deprecated), // aue
+    (6649186, IdStatus::Regular), // bue
+    (6649188, IdStatus::Regular), // due
+    (6649190, IdStatus::Regular), // fue
+    (6649191, IdStatus::Regular), // gue
+    (6649192, IdStatus::Regular), // hue
+    (6649195, IdStatus::Regular), // kue
+    (6649196, IdStatus::Regular), // lue
+    (6649197, IdStatus::Regular), // mue
+    (6649198, IdStatus::Regular), // nue
+    (6649199, IdStatus::Regular), // oue
+    (6649200, IdStatus::Regular), // pue
+    (6649202, IdStatus::Regular), // rue
+    (6649203, IdStatus::Regular), // sue
+    (6649204, IdStatus::Regular), // tue
+    (6649209, IdStatus::Regular), // yue
+    (6649442, IdStatus::Regular), // bve
+    (6649445, IdStatus::Regular), // eve
+    (6649447, IdStatus::Regular), // gve
+    (6649448, IdStatus::Regular), // hve
+    (6649451, IdStatus::Regular), // kve
+    (6649453, IdStatus::Regular), // mve
+    (6649457, IdStatus::Regular), // qve
+    (6649459, IdStatus::Regular), // sve
+    (6649460, IdStatus::Regular), // tve
+    (6649461, IdStatus::Regular), // uve
+    (6649464, IdStatus::Regular), // xve
+    (6649697, IdStatus::Regular), // awe
+    (6649698, IdStatus::Regular), // bwe
+    (6649699, IdStatus::Regular), // cwe
+    (6649702, IdStatus::Regular), // fwe
+    (6649703, IdStatus::Regular), // gwe
+    (6649707, IdStatus::Regular), // kwe
+    (6649708, IdStatus::Regular), // lwe
+    (6649709, IdStatus::Regular), // mwe
+    (6649710, IdStatus::Regular), // nwe
+    (6649713, IdStatus::Regular), // qwe
+    (6649716, IdStatus::Regular), // twe
+    (6649720, IdStatus::Regular), // xwe
+    (6649953, IdStatus::Regular), // axe
+    (6649954, IdStatus::Regular), // bxe
+    (6649963, IdStatus::Deprecated), // kxe
+    (6649965, IdStatus::Regular), // mxe
+    (6649966, IdStatus::Regular), // nxe
+    (6649971, IdStatus::Regular), // sxe
+    (6649972, IdStatus::Regular), // txe
+    (6650209, IdStatus::Regular), // aye
+    (6650210, IdStatus::Regular), // bye
+    (6650215, IdStatus::Regular), // gye
+    (6650218, IdStatus::Regular), // jye
+    (6650219, IdStatus::Regular), // kye
+    (6650221, IdStatus::Regular), // mye
+    (6650222, IdStatus::Regular), // nye
+    (6650224, IdStatus::Regular), // pye
+    (6650228, IdStatus::Regular), // tye
+    (6650466, IdStatus::Regular), // bze
+    (6650468, IdStatus::Regular), // dze
+    (6650469, IdStatus::Regular), // eze
+    (6650475, IdStatus::Regular), // kze
+    (6650477, IdStatus::Regular), // mze
+    (6650483, IdStatus::Regular), // sze
+    (6709601, IdStatus::Regular), // aaf
+    (6709602, IdStatus::Regular), // baf
+    (6709603, IdStatus::Regular), // caf
+    (6709604, IdStatus::Deprecated), // daf
+    (6709606, IdStatus::Regular), // faf
+    (6709607, IdStatus::Regular), // gaf
+    (6709608, IdStatus::Regular), // haf
+    (6709610, IdStatus::Regular), // jaf
+    (6709611, IdStatus::Regular), // kaf
+    (6709612, IdStatus::Regular), // laf
+    (6709613, IdStatus::Regular), // maf
+    (6709614, IdStatus::Regular), // naf
+    (6709616, IdStatus::Regular), // paf
+    (6709617, IdStatus::Reserved), // qaf
+    (6709618, IdStatus::Regular), // raf
+    (6709619, IdStatus::Regular), // saf
+    (6709620, IdStatus::Regular), // taf
+    (6709622, IdStatus::Regular), // vaf
+    (6709623, IdStatus::Regular), // waf
+    (6709625, IdStatus::Regular), // yaf
+    (6709626, IdStatus::Regular), // zaf
+    (6709857, IdStatus::Regular), // abf
+    (6709858, IdStatus::Regular), // bbf
+    (6709860, IdStatus::Regular), // dbf
+    (6709863, IdStatus::Regular), // gbf
+    (6709867, IdStatus::Deprecated), // kbf
+    (6709868, IdStatus::Regular), // lbf
+    (6709869, IdStatus::Regular), // mbf
+    (6709870, IdStatus::Deprecated), // nbf
+    (6709872, IdStatus::Regular), // pbf
+    (6709873, IdStatus::Reserved), // qbf
+    (6709875, IdStatus::Regular), // sbf
+    (6709876, IdStatus::Regular), // tbf
+    (6709879, IdStatus::Regular), // wbf
+    (6710113, IdStatus::Regular), // acf
+    (6710114, IdStatus::Regular), // bcf
+    (6710119, IdStatus::Regular), // gcf
+    (6710123, IdStatus::Regular), // kcf
+    (6710124, IdStatus::Regular), // lcf
+    (6710125, IdStatus::Regular), // mcf
+    (6710126, IdStatus::Regular), // ncf
+    (6710128, IdStatus::Regular), // pcf
+    (6710129, IdStatus::Reserved), // qcf
+    (6710130, IdStatus::Regular), // rcf
+    (6710131, IdStatus::Regular), // scf
+    (6710132, IdStatus::Regular), // tcf
+    (6710369, IdStatus::Regular), // adf
+    (6710370, IdStatus::Regular), // bdf
+    (6710371, IdStatus::Regular), // cdf
+    (6710375, IdStatus::Regular), // gdf
+    (6710379, IdStatus::Regular), // kdf
+    (6710381, IdStatus::Regular), // mdf
+    (6710382, IdStatus::Regular), // ndf
+    (6710385, IdStatus::Reserved), // qdf
+    (6710387, IdStatus::Regular), // sdf
+    (6710388, IdStatus::Regular), // tdf
+    (6710626, IdStatus::Regular), // bef
+    (6710628, IdStatus::Regular), // def
+    (6710631, IdStatus::Regular), // gef
+    (6710635, IdStatus::Regular), // kef
+    (6710636, IdStatus::Regular), // lef
+    (6710637, IdStatus::Regular), // mef
+    (6710638, IdStatus::Regular), // nef
+    (6710640, IdStatus::Regular), // pef
+    (6710641, IdStatus::Reserved), // qef
+    (6710643, IdStatus::Regular), // sef
+    (6710644, IdStatus::Regular), // tef
+    (6710882, IdStatus::Regular), // bff
+    (6710889, IdStatus::Regular), // iff
+    (6710891, IdStatus::Regular), // kff
+    (6710893, IdStatus::Regular), // mff
+    (6710897, IdStatus::Reserved), // qff
+    (6711137, IdStatus::Regular), // agf
+    (6711138, IdStatus::Regular), // bgf
+    (6711147, IdStatus::Regular), // kgf
+    (6711149, IdStatus::Regular), // mgf
+    (6711150, IdStatus::Regular), // ngf
+    (6711153, IdStatus::PrivateUse), // qgf
+    (6711156, IdStatus::Regular), // tgf
+    (6711160, IdStatus::Regular), // xgf
+    (6711394, IdStatus::Regular), // bhf
+    (6711395, IdStatus::Regular), // chf
+    (6711403, IdStatus::Regular), // khf
+    (6711405, IdStatus::Regular), // mhf
+    (6711406, IdStatus::Regular), // nhf
+    (6711409, IdStatus::PrivateUse), // qhf
+    (6711412, IdStatus::Regular), // thf
+    (6711649, IdStatus::Regular), // aif
+    (6711650, IdStatus::Regular), // bif
+    (6711652, IdStatus::Regular), // dif
+    (6711654, IdStatus::Regular), // fif
+    (6711656, IdStatus::Regular), // hif
+    (6711659, IdStatus::Regular), // kif
+    (6711660, IdStatus::Regular), // lif
+    (6711661, IdStatus::Regular), // mif
+    (6711662, IdStatus::Regular), // nif
+    (6711664, IdStatus::Regular), // pif
+    (6711665, IdStatus::PrivateUse), // qif
+    (6711666, IdStatus::Regular), // rif
+    (6711667, IdStatus::Regular), // sif
+    (6711668, IdStatus::Regular), // tif
+    (6711670, IdStatus::Regular), // vif
+    (6711671, IdStatus::Regular), // wif
+    (6711673, IdStatus::Regular), // yif
+    (6711906, IdStatus::Regular), // bjf
+    (6711908, IdStatus::Regular), // djf
+    (6711915, IdStatus::Deprecated), // kjf
+    (6711921, IdStatus::PrivateUse), // qjf
+    (6712161, IdStatus::Regular), // akf
+    (6712162, IdStatus::Regular), // bkf
+    (6712171, IdStatus::Regular), // kkf
+    (6712173, IdStatus::Regular), // mkf
+    (6712174, IdStatus::Regular), // nkf
+    (6712177, IdStatus::PrivateUse), // qkf
+    (6712179, IdStatus::Regular), // skf
+    (6712180, IdStatus::Regular), // tkf
+    (6712184, IdStatus::Regular), // xkf
+    (6712417, IdStatus::Regular), // alf
+    (6712418, IdStatus::Regular), // blf
+    (6712427, IdStatus::Regular), // klf
+    (6712428, IdStatus::Regular), // llf
+    (6712429, IdStatus::Regular), // mlf
+    (6712432, IdStatus::Regular), // plf
+    (6712433, IdStatus::PrivateUse), // qlf
+    (6712435, IdStatus::Regular), // slf
+    (6712436, IdStatus::Regular), // tlf
+    (6712437, IdStatus::Regular), // ulf
+    (6712673, IdStatus::Regular), // amf
+    (6712674, IdStatus::Regular), // bmf
+    (6712676, IdStatus::Regular), // dmf
+    (6712680, IdStatus::Regular), // hmf
+    (6712683, IdStatus::Regular), // kmf
+    (6712684, IdStatus::Regular), // lmf
+    (6712685, IdStatus::Regular), // mmf
+    (6712686, IdStatus::Regular), // nmf
+    (6712688, IdStatus::Regular), // pmf
+    (6712689, IdStatus::PrivateUse), // qmf
+    (6712690, IdStatus::Regular), // rmf
+    (6712691, IdStatus::Regular), // smf
+    (6712692, IdStatus::Regular), // tmf
+    (6712694, IdStatus::Regular), // vmf
+    (6712696, IdStatus::Regular), // xmf
+    (6712698, IdStatus::Regular), // zmf
+    (6712929, IdStatus::Regular), // anf
+    (6712930, IdStatus::Regular), // bnf
+    (6712933, IdStatus::Regular), // enf
+    (6712939, IdStatus::Regular), // knf
+    (6712941, IdStatus::Regular), // mnf
+    (6712942, IdStatus::Regular), // nnf
+    (6712945, IdStatus::PrivateUse), // qnf
+    (6712947, IdStatus::Regular), // snf
+    (6712948, IdStatus::Deprecated), // tnf
+    (6713185, IdStatus::Regular), // aof
+    (6713186, IdStatus::Regular), // bof
+    (6713187, IdStatus::Regular), // cof
+    (6713188, IdStatus::Regular), // dof
+    (6713191, IdStatus::Regular), // gof
+    (6713195, IdStatus::Regular), // kof
+    (6713196, IdStatus::Regular), // lof
+    (6713197, IdStatus::Deprecated), // mof
+    (6713198, IdStatus::Regular), // nof
+    (6713200, IdStatus::Regular), // pof
+    (6713201, IdStatus::PrivateUse), // qof
+    (6713202, IdStatus::Regular), // rof
+    (6713204, IdStatus::Regular), // tof
+    (6713207, IdStatus::Regular), // wof
+    (6713441, IdStatus::Regular), // apf
+    (6713443, IdStatus::Regular), // cpf
+    (6713451, IdStatus::Regular), // kpf
+    (6713457, IdStatus::PrivateUse), // qpf
+    (6713460, IdStatus::Regular), // tpf
+    (6713464, IdStatus::Regular), // xpf
+    (6713466, IdStatus::Regular), // zpf
+    (6713698, IdStatus::Regular), // bqf
+    (6713707, IdStatus::Regular), // kqf
+    (6713709, IdStatus::Regular), // mqf
+    (6713713, IdStatus::PrivateUse), // qqf
+    (6713954, IdStatus::Regular), // brf
+    (6713955, IdStatus::Regular), // crf
+    (6713963, IdStatus::Regular), // krf
+    (6713965, IdStatus::Regular), // mrf
+    (6713966, IdStatus::Regular), // nrf
+    (6713968, IdStatus::Regular), // prf
+    (6713969, IdStatus::PrivateUse), // qrf
+    (6713971, IdStatus::Regular), // srf
+    (6713972, IdStatus::Regular), // trf
+    (6713973, IdStatus::Regular), // urf
+    (6714209, IdStatus::Regular), // asf
+    (6714210, IdStatus::Regular), // bsf
+    (6714211, IdStatus::Regular), // csf
+    (6714219, IdStatus::Regular), // ksf
+    (6714221, IdStatus::Regular), // msf
+    (6714222, IdStatus::Regular), // nsf
+    (6714225, IdStatus::PrivateUse), // qsf
+    (6714227, IdStatus::Regular), // ssf
+    (6714228, IdStatus::Deprecated), // tsf
+    (6714466, IdStatus::Regular), // btf
+    (6714475, IdStatus::Regular), // ktf
+    (6714477, IdStatus::Regular), // mtf
+    (6714481, IdStatus::PrivateUse), // qtf
+    (6714483, IdStatus::Regular), // stf
+    (6714484, IdStatus::Regular), // ttf
+    (6714487, IdStatus::Regular), // wtf
+    (6714721, IdStatus::Regular), // auf
+    (6714722, IdStatus::Regular), // buf
+    (6714724, IdStatus::Regular), // duf
+    (6714726, IdStatus::Regular), // fuf
+    (6714727, IdStatus::Regular), // guf
+    (6714728, IdStatus::Regular), // huf
+    (6714731, IdStatus::Regular), // kuf
+    (6714732, IdStatus::Regular), // luf
+    (6714734, IdStatus::Regular), // nuf
+    (6714736, IdStatus::Regular), // puf
+    (6714737, IdStatus::Regular), // quf
+    (6714738, IdStatus::Regular), // ruf
+    (6714740, IdStatus::Regular), // tuf
+    (6714745, IdStatus::Regular), // yuf
+    (6714978, IdStatus::Regular), // bvf
+    (6714983, IdStatus::Regular), // gvf
+    (6714987, IdStatus::Regular), // kvf
+    (6714989, IdStatus::Regular), // mvf
+    (6715234, IdStatus::Regular), // bwf
+    (6715239, IdStatus::Regular), // gwf
+    (6715243, IdStatus::Regular), // kwf
+    (6715245, IdStatus::Regular), // mwf
+    (6715251, IdStatus::Regular), // swf
+    (6715252, IdStatus::Regular), // twf
+    (6715490, IdStatus::Regular), // bxf
+    (6715499, IdStatus::Regular), // kxf
+    (6715501, IdStatus::Regular), // mxf
+    (6715746, IdStatus::Regular), // byf
+    (6715751, IdStatus::Regular), // gyf
+    (6715755, IdStatus::Regular), // kyf
+    (6715757, IdStatus::Regular), // myf
+    (6715758, IdStatus::Regular), // nyf
+    (6716002, IdStatus::Regular), // bzf
+    (6716011, IdStatus::Regular), // kzf
+    (6775137, IdStatus::Regular), // aag
+    (6775138, IdStatus::Regular), // bag
+    (6775139, IdStatus::Regular), // cag
+    (6775140, IdStatus::Regular), // dag
+    (6775142, IdStatus::Regular), // fag
+    (6775143, IdStatus::Regular), // gag
+    (6775144, IdStatus::Regular), // hag
+    (6775147, IdStatus::Regular), // kag
+    (6775148, IdStatus::Regular), // lag
+    (6775149, IdStatus::Regular), // mag
+    (6775150, IdStatus::Regular), // nag
+    (6775152, IdStatus::Regular), // pag
+    (6775153, IdStatus::Reserved), // qag
+    (6775154, IdStatus::Regular), // rag
+    (6775156, IdStatus::Regular), // tag
+    (6775158, IdStatus::Regular), // vag
+    (6775159, IdStatus::Regular), // wag
+    (6775160, IdStatus::Regular), // xag
+    (6775161, IdStatus::Regular), // yag
+    (6775162, IdStatus::Regular), // zag
+    (6775393, IdStatus::Regular), // abg
+    (6775394, IdStatus::Regular), // bbg
+    (6775395, IdStatus::Regular), // cbg
+    (6775396, IdStatus::Regular), // dbg
+    (6775397, IdStatus::Regular), // ebg
+    (6775399, IdStatus::Regular), // gbg
+    (6775401, IdStatus::Regular), // ibg
+    (6775403, IdStatus::Regular), // kbg
+    (6775404, IdStatus::Regular), // lbg
+    (6775406, IdStatus::Regular), // nbg
+    (6775408, IdStatus::Regular), // pbg
+    (6775409, IdStatus::Reserved), // qbg
+    (6775411, IdStatus::Regular), // sbg
+    (6775412, IdStatus::Regular), // tbg
+    (6775416, IdStatus::Regular), // xbg
+    (6775650, IdStatus::Regular), // bcg
+    (6775651, IdStatus::Regular), // ccg
+    (6775659, IdStatus::Regular), // kcg
+    (6775661, IdStatus::Regular), // mcg
+    (6775662, IdStatus::Regular), // ncg
+    (6775664, IdStatus::Regular), // pcg
+    (6775665, IdStatus::Reserved), // qcg
+    (6775667, IdStatus::Regular), // scg
+    (6775668, IdStatus::Regular), // tcg
+    (6775672, IdStatus::Regular), // xcg
+    (6775905, IdStatus::Regular), // adg
+    (6775906, IdStatus::Regular), // bdg
+    (6775907, IdStatus::Deprecated), // cdg
+    (6775908, IdStatus::Regular), // ddg
+    (6775911, IdStatus::Regular), // gdg
+    (6775914, IdStatus::Regular), // jdg
+    (6775915, IdStatus::Regular), // kdg
+    (6775916, IdStatus::Regular), // ldg
+    (6775917, IdStatus::Regular), // mdg
+    (6775918, IdStatus::Regular), // ndg
+    (6775921, IdStatus::Reserved), // qdg
+    (6775923, IdStatus::Regular), // sdg
+    (6775924, IdStatus::Regular), // tdg
+    (6775925, IdStatus::Regular), // udg
+    (6775927, IdStatus::Regular), // wdg
+    (6775929, IdStatus::Regular), // ydg
+    (6776162, IdStatus::Regular), // beg
+    (6776163, IdStatus::Regular), // ceg
+    (6776164, IdStatus::Regular), // deg
+    (6776167, IdStatus::Regular), // geg
+    (6776168, IdStatus::Regular), // heg
+    (6776170, IdStatus::Deprecated), // jeg
+    (6776171, IdStatus::Regular), // keg
+    (6776172, IdStatus::Deprecated), // leg
+    (6776173, IdStatus::Deprecated), // meg
+    (6776174, IdStatus::Regular), // neg
+    (6776176, IdStatus::Regular), // peg
+    (6776177, IdStatus::Reserved), // qeg
+    (6776178, IdStatus::Regular), // reg
+    (6776179, IdStatus::Regular), // seg
+    (6776180, IdStatus::Regular), // teg
+    (6776183, IdStatus::Regular), // weg
+    (6776184, IdStatus::Regular), // xeg
+    (6776186, IdStatus::Regular), // zeg
+    (6776417, IdStatus::Regular), // afg
+    (6776418, IdStatus::Regular), // bfg
+    (6776419, IdStatus::Regular), // cfg
+    (6776427, IdStatus::Regular), // kfg
+    (6776429, IdStatus::Regular), // mfg
+    (6776433, IdStatus::Reserved), // qfg
+    (6776439, IdStatus::Regular), // wfg
+    (6776673, IdStatus::Regular), // agg
+    (6776674, IdStatus::Regular), // bgg
+    (6776675, IdStatus::Regular), // cgg
+    (6776676, IdStatus::Regular), // dgg
+    (6776679, IdStatus::Regular), // ggg
+    (6776681, IdStatus::Regular), // igg
+    (6776683, IdStatus::Regular), // kgg
+    (6776684, IdStatus::Regular), // lgg
+    (6776685, IdStatus::Regular), // mgg
+    (6776686, IdStatus::Regular), // ngg
+    (6776687, IdStatus::Regular), // ogg
+    (6776688, IdStatus::Regular), // pgg
+    (6776689, IdStatus::PrivateUse), // qgg
+    (6776691, IdStatus::Regular), // sgg
+    (6776692, IdStatus::Deprecated), // tgg
+    (6776695, IdStatus::Regular), // wgg
+    (6776696, IdStatus::Regular), // xgg
+    (6776929, IdStatus::Regular), // ahg
+    (6776930, IdStatus::Regular), // bhg
+    (6776931, IdStatus::Regular), // chg
+    (6776932, IdStatus::Regular), // dhg
+    (6776939, IdStatus::Regular), // khg
+    (6776941, IdStatus::Regular), // mhg
+    (6776942, IdStatus::Regular), // nhg
+    (6776944, IdStatus::Regular), // phg
+    (6776945, IdStatus::PrivateUse), // qhg
+    (6776946, IdStatus::Regular), // rhg
+    (6776947, IdStatus::Regular), // shg
+    (6776951, IdStatus::Regular), // whg
+    (6777185, IdStatus::Regular), // aig
+    (6777186, IdStatus::Regular), // big
+    (6777188, IdStatus::Regular), // dig
+    (6777191, IdStatus::Regular), // gig
+    (6777192, IdStatus::Regular), // hig
+    (6777194, IdStatus::Regular), // jig
+    (6777195, IdStatus::Regular), // kig
+    (6777196, IdStatus::Regular), // lig
+    (6777197, IdStatus::Regular), // mig
+    (6777198, IdStatus::Regular), // nig
+    (6777200, IdStatus::Regular), // pig
+    (6777201, IdStatus::PrivateUse), // qig
+    (6777203, IdStatus::Regular), // sig
+    (6777204, IdStatus::Regular), // tig
+    (6777206, IdStatus::Regular), // vig
+    (6777207, IdStatus::Regular), // wig
+    (6777209, IdStatus::Regular), // yig
+    (6777441, IdStatus::Regular), // ajg
+    (6777442, IdStatus::Regular), // bjg
+    (6777451, IdStatus::Regular), // kjg
+    (6777453, IdStatus::Regular), // mjg
+    (6777455, IdStatus::Deprecated), // ojg
+    (6777457, IdStatus::PrivateUse), // qjg
+    (6777458, IdStatus::Regular), // rjg
+    (6777459, IdStatus::Regular), // sjg
+    (6777460, IdStatus::Regular), // tjg
+    (6777697, IdStatus::Regular), // akg
+    (6777698, IdStatus::Regular), // bkg
+    (6777700, IdStatus::Regular), // dkg
+    (6777701, IdStatus::Regular), // ekg
+    (6777707, IdStatus::Regular), // kkg
+    (6777709, IdStatus::Regular), // mkg
+    (6777710, IdStatus::Regular), // nkg
+    (6777711, IdStatus::Regular), // okg
+    (6777712, IdStatus::Regular), // pkg
+    (6777713, IdStatus::PrivateUse), // qkg
+    (6777715, IdStatus::Regular), // skg
+    (6777716, IdStatus::Regular), // tkg
+    (6777717, IdStatus::Regular), // ukg
+    (6777720, IdStatus::Regular), // xkg
+    (6777721, IdStatus::Regular), // ykg
+    (6777722, IdStatus::Regular), // zkg
+    (6777953, IdStatus::Regular), // alg
+    (6777954, IdStatus::Deprecated), // blg
+    (6777956, IdStatus::Regular), // dlg
+    (6777961, IdStatus::Regular), // ilg
+    (6777963, IdStatus::Regular), // klg
+    (6777964, IdStatus::Regular), // llg
+    (6777966, IdStatus::Regular), // nlg
+    (6777968, IdStatus::Regular), // plg
+    (6777969, IdStatus::PrivateUse), // qlg
+    (6777971, IdStatus::Regular), // slg
+    (6777972, IdStatus::Regular), // tlg
+    (6777975, IdStatus::Regular), // wlg
+    (6777976, IdStatus::Regular), // xlg
+    (6777977, IdStatus::Regular), // ylg
+    (6778209, IdStatus::Regular), // amg
+    (6778210, IdStatus::Regular), // bmg
+    (6778211, IdStatus::Regular), // cmg
+    (6778212, IdStatus::Regular), // dmg
+    (6778213, IdStatus::Regular), // emg
+    (6778215, IdStatus::Regular), // gmg
+    (6778216, IdStatus::Regular), // hmg
+    (6778219, IdStatus::Regular), // kmg
+    (6778220, IdStatus::Regular), // lmg
+    (6778221, IdStatus::Regular), // mmg
+    (6778222, IdStatus::Regular), // nmg
+    (6778223, IdStatus::Regular), // omg
+    (6778225, IdStatus::PrivateUse), // qmg
+    (6778226, IdStatus::Regular), // rmg
+    (6778227, IdStatus::Regular), // smg
+    (6778228, IdStatus::Regular), // tmg
+    (6778229, IdStatus::Regular), // umg
+    (6778230, IdStatus::Regular), // vmg
+    (6778231, IdStatus::Regular), // wmg
+    (6778232, IdStatus::Regular), // xmg
+    (6778233, IdStatus::Regular), // ymg
+    (6778234, IdStatus::Regular), // zmg
+    (6778465, IdStatus::Regular), // ang
+    (6778466, IdStatus::Regular), // bng
+    (6778467, IdStatus::Regular), // cng
+    (6778468, IdStatus::Regular), // dng
+    (6778470, IdStatus::Regular), // fng
+    (6778471, IdStatus::Regular), // gng
+    (6778472, IdStatus::Regular), // hng
+    (6778473, IdStatus::Regular), // ing
+    (6778474, IdStatus::Regular), // jng
+    (6778475, IdStatus::Deprecated), // kng
+    (6778476, IdStatus::Regular), // lng
+    (6778477, IdStatus::Regular), // mng
+    (6778478, IdStatus::Regular), // nng
+    (6778479, IdStatus::Regular), // ong
+    (6778480, IdStatus::Regular), // png
+    (6778481, IdStatus::PrivateUse), // qng
+    (6778482, IdStatus::Regular), // rng
+    (6778483, IdStatus::Regular), // sng
+    (6778484, IdStatus::Regular), // tng
+    (6778485, IdStatus::Regular), // ung
+    (6778487, IdStatus::Regular), // wng
+    (6778488, IdStatus::Regular), // xng
+    (6778489, IdStatus::Regular), // yng
+    (6778490, IdStatus::Regular), // zng
+    (6778721, IdStatus::Regular), // aog
+    (6778722, IdStatus::Regular), // bog
+    (6778723, IdStatus::Regular), // cog
+    (6778727, IdStatus::Regular), // gog
+    (6778730, IdStatus::Regular), // jog
+    (6778731, IdStatus::Regular), // kog
+    (6778732, IdStatus::Regular), // log
+    (6778733, IdStatus::Regular), // mog
+    (6778734, IdStatus::Regular), // nog
+    (6778735, IdStatus::Regular), // oog
+    (6778736, IdStatus::Regular), // pog
+    (6778737, IdStatus::PrivateUse), // qog
+    (6778738, IdStatus::Regular), // rog
+    (6778739, IdStatus::Regular), // sog
+    (6778740, IdStatus::Regular), // tog
+    (6778743, IdStatus::Regular), // wog
+    (6778744, IdStatus::Regular), // xog
+    (6778745, IdStatus::Regular), // yog
+    (6778977, IdStatus::Regular), // apg
+    (6778978, IdStatus::Regular), // bpg
+    (6778979, IdStatus::Regular), // cpg
+    (6778987, IdStatus::Regular), // kpg
+    (6778989, IdStatus::Regular), // mpg
+    (6778990, IdStatus::Regular), // npg
+    (6778993, IdStatus::PrivateUse), // qpg
+    (6778995, IdStatus::Regular), // spg
+    (6778996, IdStatus::Regular), // tpg
+    (6779000, IdStatus::Regular), // xpg
+    (6779001, IdStatus::Regular), // ypg
+    (6779002, IdStatus::Regular), // zpg
+    (6779233, IdStatus::Regular), // aqg
+    (6779234, IdStatus::Regular), // bqg
+    (6779243, IdStatus::Regular), // kqg
+    (6779245, IdStatus::Regular), // mqg
+    (6779246, IdStatus::Regular), // nqg
+    (6779249, IdStatus::PrivateUse), // qqg
+    (6779490, IdStatus::Regular), // brg
+    (6779491, IdStatus::Regular), // crg
+    (6779492, IdStatus::Regular), // drg
+    (6779493, IdStatus::Regular), // erg
+    (6779495, IdStatus::Regular), // grg
+    (6779500, IdStatus::Regular), // lrg
+    (6779501, IdStatus::Regular), // mrg
+    (6779502, IdStatus::Regular), // nrg
+    (6779503, IdStatus::Regular), // org
+    (6779504, IdStatus::Regular), // prg
+    (6779505, IdStatus::PrivateUse), // qrg
+    (6779507, IdStatus::Regular), // srg
+    (6779508, IdStatus::Regular), // trg
+    (6779509, IdStatus::Regular), // urg
+    (6779511, IdStatus::Regular), // wrg
+    (6779512, IdStatus::Regular), // xrg
+    (6779514, IdStatus::Regular), // zrg
+    (6779745, IdStatus::Regular), // asg
+    (6779746, IdStatus::Regular), // bsg
+    (6779747, IdStatus::Regular), // csg
+    (6779749, IdStatus::Regular), // esg
+    (6779751, IdStatus::Regular), // gsg
+    (6779753, IdStatus::Regular), // isg
+    (6779755, IdStatus::Regular), // ksg
+    (6779756, IdStatus::Deprecated), // lsg
+    (6779757, IdStatus::Regular), // msg
+    (6779758, IdStatus::Regular), // nsg
+    (6779760, IdStatus::Regular), // psg
+    (6779761, IdStatus::PrivateUse), // qsg
+    (6779763, IdStatus::Regular), // ssg
+    (6779764, IdStatus::Regular), // tsg
+    (6779767, IdStatus::Regular), // wsg
+    (6779769, IdStatus::Regular), // ysg
+    (6780001, IdStatus::Regular), // atg
+    (6780002, IdStatus::Regular), // btg
+    (6780003, IdStatus::Regular), // ctg
+    (6780011, IdStatus::Regular), // ktg
+    (6780012, IdStatus::Regular), // ltg
+    (6780013, IdStatus::Regular), // mtg
+    (6780014, IdStatus::Regular), // ntg
+    (6780017, IdStatus::PrivateUse), // qtg
+    (6780019, IdStatus::Regular), // stg
+    (6780020, IdStatus::Regular), // ttg
+    (6780024, IdStatus::Regular), // xtg
+    (6780026, IdStatus::Regular), // ztg
+    (6780257, IdStatus::Regular), // aug
+    (6780258, IdStatus::Regular), // bug
+    (6780259, IdStatus::Deprecated), // cug
+    (6780260, IdStatus::Regular), // dug
+    (6780263, IdStatus::Deprecated), // gug
+    (6780264, IdStatus::Regular), // hug
+    (6780267, IdStatus::Regular), // kug
+    (6780269, IdStatus::Regular), // mug
+    (6780270, IdStatus::Regular), // nug
+    (6780272, IdStatus::Regular), // pug
+    (6780273, IdStatus::Regular), // qug
+    (6780274, IdStatus::Regular), // rug
+    (6780275, IdStatus::Regular), // sug
+    (6780276, IdStatus::Regular), // tug
+    (6780280, IdStatus::Regular), // xug
+    (6780281, IdStatus::Regular), // yug
+    (6780514, IdStatus::Regular), // bvg
+    (6780515, IdStatus::Regular), // cvg
+    (6780523, IdStatus::Regular), // kvg
+    (6780525, IdStatus::Regular), // mvg
+    (6780769, IdStatus::Regular), // awg
+    (6780770, IdStatus::Regular), // bwg
+    (6780771, IdStatus::Regular), // cwg
+    (6780775, IdStatus::Regular), // gwg
+    (6780779, IdStatus::Regular), // kwg
+    (6780780, IdStatus::Regular), // lwg
+    (6780781, IdStatus::Regular), // mwg
+    (6780782, IdStatus::Regular), // nwg
+    (6780784, IdStatus::Regular), // pwg
+    (6780787, IdStatus::Regular), // swg
+    (6780788, IdStatus::Regular), // twg
+    (6780792, IdStatus::Regular), // xwg
+    (6780793, IdStatus::Regular), // ywg
+    (6781025, IdStatus::Regular), // axg
+    (6781026, IdStatus::Regular), // bxg
+    (6781037, IdStatus::Regular), // mxg
+    (6781038, IdStatus::Regular), // nxg
+    (6781043, IdStatus::Regular), // sxg
+    (6781044, IdStatus::Regular), // txg
+    (6781049, IdStatus::Regular), // yxg
+    (6781281, IdStatus::Regular), // ayg
+    (6781282, IdStatus::Regular), // byg
+    (6781284, IdStatus::Regular), // dyg
+    (6781287, IdStatus::Regular), // gyg
+    (6781291, IdStatus::Regular), // kyg
+    (6781292, IdStatus::Regular), // lyg
+    (6781293, IdStatus::Regular), // myg
+    (6781294, IdStatus::Regular), // nyg
+    (6781306, IdStatus::Regular), // zyg
+    (6781537, IdStatus::Regular), // azg
+    (6781538, IdStatus::Regular), // bzg
+    (6781540, IdStatus::Regular), // dzg
+    (6781547, IdStatus::Regular), // kzg
+    (6781549, IdStatus::Regular), // mzg
+    (6781555, IdStatus::Regular), // szg
+    (6781561, IdStatus::Regular), // yzg
+    (6840673, IdStatus::Regular), // aah
+    (6840674, IdStatus::Regular), // bah
+    (6840675, IdStatus::Regular), // cah
+    (6840676, IdStatus::Regular), // dah
+    (6840678, IdStatus::Regular), // fah
+    (6840679, IdStatus::Regular), // gah
+    (6840680, IdStatus::Regular), // hah
+    (6840682, IdStatus::Regular), // jah
+    (6840683, IdStatus::Regular), // kah
+    (6840684, IdStatus::Regular), // lah
+    (6840686, IdStatus::Regular), // nah
+    (6840688, IdStatus::Regular), // pah
+    (6840689, IdStatus::Reserved), // qah
+    (6840690, IdStatus::Regular), // rah
+    (6840691, IdStatus::Regular), // sah
+    (6840694, IdStatus::Regular), // vah
+    (6840695, IdStatus::Regular), // wah
+    (6840697, IdStatus::Regular), // yah
+    (6840698, IdStatus::Regular), // zah
+    (6840929, IdStatus::Regular), // abh
+    (6840930, IdStatus::Regular), // bbh
+    (6840931, IdStatus::Deprecated), // cbh
+    (6840935, IdStatus::Regular), // gbh
+    (6840937, IdStatus::Regular), // ibh
+    (6840939, IdStatus::Regular), // kbh
+    (6840941, IdStatus::Regular), // mbh
+    (6840942, IdStatus::Regular), // nbh
+    (6840944, IdStatus::Regular), // pbh
+    (6840945, IdStatus::Reserved), // qbh
+    (6840947, IdStatus::Regular), // sbh
+    (6840948, IdStatus::Regular), // tbh
+    (6840951, IdStatus::Regular), // wbh
+    (6840953, IdStatus::Regular), // ybh
+    (6841185, IdStatus::Regular), // ach
+    (6841186, IdStatus::Regular), // bch
+    (6841187, IdStatus::Regular), // cch
+    (6841192, IdStatus::Regular), // hch
+    (6841193, IdStatus::Regular), // ich
+    (6841195, IdStatus::Regular), // kch
+    (6841196, IdStatus::Regular), // lch
+    (6841197, IdStatus::Regular), // mch
+    (6841198, IdStatus::Regular), // nch
+    (6841199, IdStatus::Regular), // och
+    (6841200, IdStatus::Regular), // pch
+    (6841201, IdStatus::Reserved), // qch
+    (6841203, IdStatus::Regular), // sch
+    (6841204, IdStatus::Regular), // tch
+    (6841208, IdStatus::Regular), // xch
+    (6841209, IdStatus::Regular), // ych
+    (6841210, IdStatus::Regular), // zch
+    (6841441, IdStatus::Regular), // adh
+    (6841442, IdStatus::Regular), // bdh
+    (6841443, IdStatus::Regular), // cdh
+    (6841447, IdStatus::Regular), // gdh
+    (6841451, IdStatus::Regular), // kdh
+    (6841452, IdStatus::Regular), // ldh
+    (6841453, IdStatus::Regular), // mdh
+    (6841454, IdStatus::Regular), // ndh
+    (6841457, IdStatus::Reserved), // qdh
+    (6841459, IdStatus::Regular), // sdh
+    (6841460, IdStatus::Regular), // tdh
+    (6841698, IdStatus::Regular), // beh
+    (6841700, IdStatus::Regular), // deh
+    (6841703, IdStatus::Regular), // geh
+    (6841704, IdStatus::Regular), // heh
+    (6841706, IdStatus::Regular), // jeh
+    (6841707, IdStatus::Regular), // keh
+    (6841708, IdStatus::Regular), // leh
+    (6841709, IdStatus::Regular), // meh
+    (6841710, IdStatus::Regular), // neh
+    (6841712, IdStatus::Regular), // peh
+    (6841713, IdStatus::Reserved), // qeh
+    (6841715, IdStatus::Regular), // seh
+    (6841716, IdStatus::Regular), // teh
+    (6841719, IdStatus::Regular), // weh
+    (6841722, IdStatus::Regular), // zeh
+    (6841953, IdStatus::Regular), // afh
+    (6841954, IdStatus::Regular), // bfh
+    (6841963, IdStatus::Regular), // kfh
+    (6841965, IdStatus::Regular), // mfh
+    (6841969, IdStatus::Reserved), // qfh
+    (6842209, IdStatus::Regular), // agh
+    (6842212, IdStatus::Regular), // dgh
+    (6842219, IdStatus::Deprecated), // kgh
+    (6842220, IdStatus::Regular), // lgh
+    (6842221, IdStatus::Regular), // mgh
+    (6842222, IdStatus::Regular), // ngh
+    (6842225, IdStatus::PrivateUse), // qgh
+    (6842227, IdStatus::Regular), // sgh
+    (6842228, IdStatus::Regular), // tgh
+    (6842229, IdStatus::Regular), // ugh
+    (6842234, IdStatus::Regular), // zgh
+    (6842465, IdStatus::Regular), // ahh
+    (6842466, IdStatus::Regular), // bhh
+    (6842467, IdStatus::Regular), // chh
+    (6842471, IdStatus::Regular), // ghh
+    (6842475, IdStatus::Regular), // khh
+    (6842476, IdStatus::Regular), // lhh
+    (6842477, IdStatus::Deprecated), // mhh
+    (6842478, IdStatus::Regular), // nhh
+    (6842480, IdStatus::Regular), // phh
+    (6842481, IdStatus::PrivateUse), // qhh
+    (6842483, IdStatus::Regular), // shh
+    (6842484, IdStatus::Regular), // thh
+    (6842721, IdStatus::Regular), // aih
+    (6842723, IdStatus::Regular), // cih
+    (6842724, IdStatus::Regular), // dih
+    (6842727, IdStatus::Regular), // gih
+    (6842728, IdStatus::Regular), // hih
+    (6842730, IdStatus::Regular), // jih
+    (6842731, IdStatus::Regular), // kih
+    (6842732, IdStatus::Regular), // lih
+    (6842733, IdStatus::Regular), // mih
+    (6842734, IdStatus::Regular), // nih
+    (6842736, IdStatus::Regular), // pih
+    (6842737, IdStatus::PrivateUse), // qih
+    (6842739, IdStatus::Regular), // sih
+    (6842740, IdStatus::Regular), // tih
+    (6842743, IdStatus::Regular), // wih
+    (6842745, IdStatus::Regular), // yih
+    (6842978, IdStatus::Regular), // bjh
+    (6842979, IdStatus::Regular), // cjh
+    (6842987, IdStatus::Regular), // kjh
+    (6842989, IdStatus::Regular), // mjh
+    (6842990, IdStatus::Regular), // njh
+    (6842993, IdStatus::PrivateUse), // qjh
+    (6843233, IdStatus::Regular), // akh
+    (6843234, IdStatus::Regular), // bkh
+    (6843235, IdStatus::Regular), // ckh
+    (6843240, IdStatus::Regular), // hkh
+    (6843243, IdStatus::Regular), // kkh
+    (6843244, IdStatus::Regular), // lkh
+    (6843245, IdStatus::Regular), // mkh
+    (6843246, IdStatus::Regular), // nkh
+    (6843247, IdStatus::Regular), // okh
+    (6843248, IdStatus::Regular), // pkh
+    (6843249, IdStatus::PrivateUse), // qkh
+    (6843250, IdStatus::Regular), // rkh
+    (6843251, IdStatus::Regular), // skh
+    (6843253, IdStatus::Regular), // ukh
+    (6843256, IdStatus::Deprecated), // xkh
+    (6843258, IdStatus::Regular), // zkh
+    (6843489, IdStatus::Regular), // alh
+    (6843490, IdStatus::Regular), // blh
+    (6843491, IdStatus::Regular), // clh
+    (6843493, IdStatus::Regular), // elh
+    (6843494, IdStatus::Regular), // flh
+    (6843495, IdStatus::Regular), // glh
+    (6843499, IdStatus::Regular), // klh
+    (6843500, IdStatus::Regular), // llh
+    (6843501, IdStatus::Regular), // mlh
+    (6843504, IdStatus::Regular), // plh
+    (6843505, IdStatus::PrivateUse), // qlh
+    (6843507, IdStatus::Regular), // slh
+    (6843508, IdStatus::Regular), // tlh
+    (6843511, IdStatus::Regular), // wlh
+    (6843746, IdStatus::Regular), // bmh
+    (6843751, IdStatus::Regular), // gmh
+    (6843752, IdStatus::Regular), // hmh
+    (6843755, IdStatus::Regular), // kmh
+    (6843756, IdStatus::Regular), // lmh
+    (6843757, IdStatus::Regular), // mmh
+    (6843758, IdStatus::Regular), // nmh
+    (6843760, IdStatus::Regular), // pmh
+    (6843761, IdStatus::PrivateUse), // qmh
+    (6843762, IdStatus::Regular), // rmh
+    (6843763, IdStatus::Regular), // smh
+    (6843764, IdStatus::Regular), // tmh
+    (6843766, IdStatus::Regular), // vmh
+    (6843767, IdStatus::Regular), // wmh
+    (6843768, IdStatus::Regular), // xmh
+    (6843769, IdStatus::Regular), // ymh
+    (6843770, IdStatus::Regular), // zmh
+    (6844001, IdStatus::Regular), // anh
+    (6844003, IdStatus::Regular), // cnh
+    (6844005, IdStatus::Regular), // enh
+    (6844007, IdStatus::Regular), // gnh
+    (6844008, IdStatus::Regular), // hnh
+    (6844009, IdStatus::Regular), // inh
+    (6844012, IdStatus::Regular), // lnh
+    (6844013, IdStatus::Regular), // mnh
+    (6844014, IdStatus::Regular), // nnh
+    (6844016, IdStatus::Regular), // pnh
+    (6844017, IdStatus::PrivateUse), // qnh
+    (6844019, IdStatus::Deprecated), // snh
+    (6844020, IdStatus::Regular), // tnh
+    (6844024, IdStatus::Regular), // xnh
+    (6844025, IdStatus::Deprecated), // ynh
+    (6844257, IdStatus::Deprecated), // aoh
+    (6844258, IdStatus::Regular), // boh
+    (6844259, IdStatus::Regular), // coh
+    (6844260, IdStatus::Regular), // doh
+    (6844263, IdStatus::Regular), // goh
+    (6844264, IdStatus::Regular), // hoh
+    (6844267, IdStatus::Regular), // koh
+    (6844268, IdStatus::Regular), // loh
+    (6844269, IdStatus::Regular), // moh
+    (6844270, IdStatus::Regular), // noh
+    (6844272, IdStatus::Regular), // poh
+    (6844273, IdStatus::PrivateUse), // qoh
+    (6844275, IdStatus::Regular), // soh
+    (6844276, IdStatus::Regular), // toh
+    (6844282, IdStatus::Regular), // zoh
+    (6844513, IdStatus::Regular), // aph
+    (6844514, IdStatus::Regular), // bph
+    (6844523, IdStatus::Regular), // kph
+    (6844525, IdStatus::Regular), // mph
+    (6844526, IdStatus::Regular), // nph
+    (6844529, IdStatus::PrivateUse), // qph
+    (6844536, IdStatus::Regular), // xph
+    (6844537, IdStatus::Regular), // yph
+    (6844538, IdStatus::Regular), // zph
+    (6844770, IdStatus::Regular), // bqh
+    (6844779, IdStatus::Regular), // kqh
+    (6844781, IdStatus::Regular), // mqh
+    (6844785, IdStatus::PrivateUse), // qqh
+    (6844787, IdStatus::Regular), // sqh
+    (6845025, IdStatus::Regular), // arh
+    (6845026, IdStatus::Regular), // brh
+    (6845027, IdStatus::Regular), // crh
+    (6845028, IdStatus::Deprecated), // drh
+    (6845029, IdStatus::Regular), // erh
+    (6845031, IdStatus::Regular), // grh
+    (6845033, IdStatus::Regular), // irh
+    (6845035, IdStatus::Regular), // krh
+    (6845037, IdStatus::Regular), // mrh
+    (6845039, IdStatus::Regular), // orh
+    (6845040, IdStatus::Regular), // prh
+    (6845041, IdStatus::PrivateUse), // qrh
+    (6845043, IdStatus::Regular), // srh
+    (6845044, IdStatus::Regular), // trh
+    (6845045, IdStatus::Regular), // urh
+    (6845047, IdStatus::Regular), // wrh
+    (6845281, IdStatus::Regular), // ash
+    (6845282, IdStatus::Regular), // bsh
+    (6845283, IdStatus::Regular), // csh
+    (6845284, IdStatus::Regular), // dsh
+    (6845285, IdStatus::Regular), // esh
+    (6845288, IdStatus::Regular), // hsh
+    (6845289, IdStatus::Regular), // ish
+    (6845291, IdStatus::Regular), // ksh
+    (6845292, IdStatus::Regular), // lsh
+    (6845293, IdStatus::Regular), // msh
+    (6845294, IdStatus::Regular), // nsh
+    (6845296, IdStatus::Regular), // psh
+    (6845297, IdStatus::PrivateUse), // qsh
+    (6845299, IdStatus::Regular), // ssh
+    (6845300, IdStatus::Regular), // tsh
+    (6845301, IdStatus::Regular), // ush
+    (6845304, IdStatus::Regular), // xsh
+    (6845537, IdStatus::Regular), // ath
+    (6845538, IdStatus::Regular), // bth
+    (6845539, IdStatus::Regular), // cth
+    (6845540, IdStatus::Regular), // dth
+    (6845541, IdStatus::Regular), // eth
+    (6845547, IdStatus::Regular), // kth
+    (6845548, IdStatus::Regular), // lth
+    (6845549, IdStatus::Regular), // mth
+    (6845552, IdStatus::Regular), // pth
+    (6845553, IdStatus::PrivateUse), // qth
+    (6845554, IdStatus::Regular), // rth
+    (6845555, IdStatus::Regular), // sth
+    (6845556, IdStatus::Regular), // tth
+    (6845557, IdStatus::Regular), // uth
+    (6845559, IdStatus::Regular), // wth
+    (6845560, IdStatus::Regular), // xth
+    (6845793, IdStatus::Regular), // auh
+    (6845794, IdStatus::Regular), // buh
+    (6845795, IdStatus::Regular), // cuh
+    (6845796, IdStatus::Regular), // duh
+    (6845798, IdStatus::Regular), // fuh
+    (6845799, IdStatus::Regular), // guh
+    (6845800, IdStatus::Regular), // huh
+    (6845802, IdStatus::Regular), // juh
+    (6845803, IdStatus::Regular), // kuh
+    (6845805, IdStatus::Regular), // muh
+    (6845806, IdStatus::Regular), // nuh
+    (6845809, IdStatus::Regular), // quh
+    (6845810, IdStatus::Regular), // ruh
+    (6845812, IdStatus::Regular), // tuh
+    (6845815, IdStatus::Regular), // wuh
+    (6845818, IdStatus::Regular), // zuh
+    (6846050, IdStatus::Regular), // bvh
+    (6846053, IdStatus::Regular), // evh
+    (6846059, IdStatus::Regular), // kvh
+    (6846061, IdStatus::Regular), // mvh
+    (6846062, IdStatus::Regular), // nvh
+    (6846065, IdStatus::Regular), // qvh
+    (6846069, IdStatus::Regular), // uvh
+    (6846305, IdStatus::Regular), // awh
+    (6846306, IdStatus::Regular), // bwh
+    (6846315, IdStatus::Regular), // kwh
+    (6846316, IdStatus::Regular), // lwh
+    (6846317, IdStatus::Regular), // mwh
+    (6846321, IdStatus::Regular), // qwh
+    (6846323, IdStatus::Deprecated), // swh
+    (6846324, IdStatus::Regular), // twh
+    (6846562, IdStatus::Regular), // bxh
+    (6846571, IdStatus::Regular), // kxh
+    (6846573, IdStatus::Regular), // mxh
+    (6846577, IdStatus::Regular), // qxh
+    (6846580, IdStatus::Regular), // txh
+    (6846817, IdStatus::Regular), // ayh
+    (6846818, IdStatus::Regular), // byh
+    (6846827, IdStatus::Regular), // kyh
+    (6846829, IdStatus::Regular), // myh
+    (6846830, IdStatus::Regular), // nyh
+    (6846836, IdStatus::Regular), // tyh
+    (6847074, IdStatus::Regular), // bzh
+    (6847075, IdStatus::Regular), // czh
+    (6847081, IdStatus::Regular), // izh
+    (6847083, IdStatus::Deprecated), // kzh
+    (6847084, IdStatus::Regular), // lzh
+    (6847085, IdStatus::Regular), // mzh
+    (6847088, IdStatus::Regular), // pzh
+    (6847090, IdStatus::Regular), // rzh
+    (6847092, IdStatus::Regular), // tzh
+    (6847096, IdStatus::Regular), // xzh
+    (6906209, IdStatus::Regular), // aai
+    (6906210, IdStatus::Regular), // bai
+    (6906211, IdStatus::Regular), // cai
+    (6906212, IdStatus::Regular), // dai
+    (6906214, IdStatus::Regular), // fai
+    (6906215, IdStatus::Regular), // gai
+    (6906216, IdStatus::Regular), // hai
+    (6906217, IdStatus::Regular), // iai
+    (6906219, IdStatus::Regular), // kai
+    (6906220, IdStatus::Regular), // lai
+    (6906221, IdStatus::Regular), // mai
+    (6906222, IdStatus::Regular), // nai
+    (6906224, IdStatus::Regular), // pai
+    (6906225, IdStatus::Reserved), // qai
+    (6906226, IdStatus::Regular), // rai
+    (6906227, IdStatus::Regular), // sai
+    (6906228, IdStatus::Regular), // tai
+    (6906230, IdStatus::Regular), // vai
+    (6906231, IdStatus::Regular), // wai
+    (6906232, IdStatus::Regular), // xai
+    (6906233, IdStatus::Regular), // yai
+    (6906234, IdStatus::Deprecated), // zai
+    (6906465, IdStatus::Regular), // abi
+    (6906466, IdStatus::Regular), // bbi
+    (6906467, IdStatus::Regular), // cbi
+    (6906468, IdStatus::Regular), // dbi
+    (6906471, IdStatus::Regular), // gbi
+    (6906473, IdStatus::Deprecated), // ibi
+    (6906474, IdStatus::Regular), // jbi
+    (6906475, IdStatus::Regular), // kbi
+    (6906476, IdStatus::Regular), // lbi
+    (6906477, IdStatus::Regular), // mbi
+    (6906478, IdStatus::Regular), // nbi
+    (6906479, IdStatus::Regular), // obi
+    (6906480, IdStatus::Regular), // pbi
+    (6906481, IdStatus::Reserved), // qbi
+    (6906483, IdStatus::Regular), // sbi
+    (6906484, IdStatus::Regular), // tbi
+    (6906485, IdStatus::Regular), // ubi
+    (6906487, IdStatus::Regular), // wbi
+    (6906488, IdStatus::Regular), // xbi
+    (6906489, IdStatus::Regular), // ybi
+    (6906721, IdStatus::Regular), // aci
+    (6906722, IdStatus::Regular), // bci
+    (6906731, IdStatus::Regular), // kci
+    (6906733, IdStatus::Regular), // mci
+    (6906734, IdStatus::Regular), // nci
+    (6906736, IdStatus::Regular), // pci
+    (6906737, IdStatus::Reserved), // qci
+    (6906739, IdStatus::Regular), // sci
+    (6906740, IdStatus::Regular), // tci
+    (6906743, IdStatus::Regular), // wci
+    (6906977, IdStatus::Regular), // adi
+    (6906978, IdStatus::Regular), // bdi
+    (6906979, IdStatus::Regular), // cdi
+    (6906980, IdStatus::Regular), // ddi
+    (6906983, IdStatus::Regular), // gdi
+    (6906985, IdStatus::Regular), // idi
+    (6906987, IdStatus::Regular), // kdi
+    (6906988, IdStatus::Regular), // ldi
+    (6906989, IdStatus::Regular), // mdi
+    (6906990, IdStatus::Regular), // ndi
+    (6906992, IdStatus::Regular), // pdi
+    (6906993, IdStatus::Reserved), // qdi
+    (6906996, IdStatus::Regular), // tdi
+    (6906997, IdStatus::Regular), // udi
+    (6907234, IdStatus::Regular), // bei
+    (6907236, IdStatus::Regular), // dei
+    (6907239, IdStatus::Regular), // gei
+    (6907240, IdStatus::Regular), // hei
+    (6907242, IdStatus::Regular), // jei
+    (6907243, IdStatus::Regular), // kei
+    (6907244, IdStatus::Regular), // lei
+    (6907245, IdStatus::Regular), // mei
+    (6907246, IdStatus::Regular), // nei
+    (6907248, IdStatus::Regular), // pei
+    (6907249, IdStatus::Reserved), // qei
+    (6907250, IdStatus::Regular), // rei
+    (6907251, IdStatus::Regular), // sei
+    (6907252, IdStatus::Regular), // tei
+    (6907255, IdStatus::Regular), // wei
+    (6907257, IdStatus::Regular), // yei
+    (6907489, IdStatus::Regular), // afi
+    (6907490, IdStatus::Regular), // bfi
+    (6907493, IdStatus::Regular), // efi
+    (6907494, IdStatus::Regular), // ffi
+    (6907499, IdStatus::Regular), // kfi
+    (6907501, IdStatus::Regular), // mfi
+    (6907505, IdStatus::Reserved), // qfi
+    (6907508, IdStatus::Regular), // tfi
+    (6907509, IdStatus::Regular), // ufi
+    (6907745, IdStatus::Regular), // agi
+    (6907746, IdStatus::Regular), // bgi
+    (6907748, IdStatus::Regular), // dgi
+    (6907755, IdStatus::Regular), // kgi
+    (6907756, IdStatus::Regular), // lgi
+    (6907757, IdStatus::Regular), // mgi
+    (6907758, IdStatus::Regular), // ngi
+    (6907760, IdStatus::Regular), // pgi
+    (6907761, IdStatus::PrivateUse), // qgi
+    (6907763, IdStatus::Regular), // sgi
+    (6907764, IdStatus::Regular), // tgi
+    (6907767, IdStatus::Regular), // wgi
+    (6907768, IdStatus::Regular), // xgi
+    (6907769, IdStatus::Regular), // ygi
+    (6908001, IdStatus::Regular), // ahi
+    (6908002, IdStatus::Regular), // bhi
+    (6908004, IdStatus::Regular), // dhi
+    (6908008, IdStatus::Regular), // hhi
+    (6908009, IdStatus::Regular), // ihi
+    (6908010, IdStatus::Regular), // jhi
+    (6908011, IdStatus::Regular), // khi
+    (6908012, IdStatus::Regular), // lhi
+    (6908013, IdStatus::Regular), // mhi
+    (6908014, IdStatus::Regular), // nhi
+    (6908016, IdStatus::Regular), // phi
+    (6908017, IdStatus::PrivateUse), // qhi
+    (6908019, IdStatus::Regular), // shi
+    (6908020, IdStatus::Regular), // thi
+    (6908026, IdStatus::Regular), // zhi
+    (6908257, IdStatus::Regular), // aii
+    (6908260, IdStatus::Regular), // dii
+    (6908263, IdStatus::Regular), // gii
+    (6908264, IdStatus::Regular), // hii
+    (6908266, IdStatus::Regular), // jii
+    (6908267, IdStatus::Regular), // kii
+    (6908268, IdStatus::Deprecated), // lii
+    (6908269, IdStatus::Regular), // mii
+    (6908270, IdStatus::Regular), // nii
+    (6908272, IdStatus::Deprecated), // pii
+    (6908273, IdStatus::PrivateUse), // qii
+    (6908275, IdStatus::Regular), // sii
+    (6908276, IdStatus::Regular), // tii
+    (6908279, IdStatus::Regular), // wii
+    (6908280, IdStatus::Regular), // xii
+    (6908281, IdStatus::Regular), // yii
+    (6908513, IdStatus::Regular), // aji
+    (6908514, IdStatus::Regular), // bji
+    (6908515, IdStatus::Regular), // cji
+    (6908516, IdStatus::Regular), // dji
+    (6908519, IdStatus::Deprecated), // gji
+    (6908520, IdStatus::Regular), // hji
+    (6908523, IdStatus::Regular), // kji
+    (6908524, IdStatus::Regular), // lji
+    (6908525, IdStatus::Regular), // mji
+    (6908526, IdStatus::Regular), // nji
+    (6908529, IdStatus::PrivateUse), // qji
+    (6908530, IdStatus::Regular), // rji
+    (6908532, IdStatus::Regular), // tji
+    (6908533, IdStatus::Regular), // uji
+    (6908535, IdStatus::Regular), // wji
+    (6908769, IdStatus::Regular), // aki
+    (6908770, IdStatus::Regular), // bki
+    (6908773, IdStatus::Regular), // eki
+    (6908777, IdStatus::Regular), // iki
+    (6908779, IdStatus::Regular), // kki
+    (6908780, IdStatus::Regular), // lki
+    (6908781, IdStatus::Regular), // mki
+    (6908782, IdStatus::Regular), // nki
+    (6908783, IdStatus::Regular), // oki
+    (6908785, IdStatus::PrivateUse), // qki
+    (6908786, IdStatus::Regular), // rki
+    (6908787, IdStatus::Regular), // ski
+    (6908789, IdStatus::Regular), // uki
+    (6908790, IdStatus::Deprecated), // vki
+    (6908792, IdStatus::Regular), // xki
+    (6908793, IdStatus::Regular), // yki
+    (6909025, IdStatus::Regular), // ali
+    (6909026, IdStatus::Regular), // bli
+    (6909027, IdStatus::Regular), // cli
+    (6909029, IdStatus::Regular), // eli
+    (6909030, IdStatus::Regular), // fli
+    (6909031, IdStatus::Deprecated), // gli
+    (6909033, IdStatus::Regular), // ili
+    (6909035, IdStatus::Regular), // kli
+    (6909036, IdStatus::Regular), // lli
+    (6909037, IdStatus::Regular), // mli
+    (6909038, IdStatus::Regular), // nli
+    (6909041, IdStatus::PrivateUse), // qli
+    (6909043, IdStatus::Regular), // sli
+    (6909044, IdStatus::Regular), // tli
+    (6909045, IdStatus::Regular), // uli
+    (6909047, IdStatus::Regular), // wli
+    (6909048, IdStatus::Regular), // xli
+    (6909049, IdStatus::Regular), // yli
+    (6909281, IdStatus::Regular), // ami
+    (6909282, IdStatus::Regular), // bmi
+    (6909283, IdStatus::Regular), // cmi
+    (6909285, IdStatus::Regular), // emi
+    (6909288, IdStatus::Regular), // hmi
+    (6909289, IdStatus::Regular), // imi
+    (6909290, IdStatus::Regular), // jmi
+    (6909291, IdStatus::Regular), // kmi
+    (6909292, IdStatus::Regular), // lmi
+    (6909293, IdStatus::Regular), // mmi
+    (6909294, IdStatus::Regular), // nmi
+    (6909295, IdStatus::Regular), // omi
+    (6909296, IdStatus::Regular), // pmi
+    (6909297, IdStatus::PrivateUse), // qmi
+    (6909298, IdStatus::Regular), // rmi
+    (6909299, IdStatus::Regular), // smi
+    (6909300, IdStatus::Regular), // tmi
+    (6909301, IdStatus::Regular), // umi
+    (6909302, IdStatus::Regular), // vmi
+    (6909303, IdStatus::Regular), // wmi
+    (6909305, IdStatus::Regular), // ymi
+    (6909306, IdStatus::Regular), // zmi
+    (6909537, IdStatus::Regular), // ani
+    (6909538, IdStatus::Regular), // bni
+    (6909539, IdStatus::Regular), // cni
+    (6909540, IdStatus::Regular), // dni
+    (6909542, IdStatus::Regular), // fni
+    (6909543, IdStatus::Regular), // gni
+    (6909544, IdStatus::Regular), // hni
+    (6909546, IdStatus::Regular), // jni
+    (6909547, IdStatus::Regular), // kni
+    (6909548, IdStatus::Regular), // lni
+    (6909549, IdStatus::Regular), // mni
+    (6909550, IdStatus::Regular), // nni
+    (6909551, IdStatus::Regular), // oni
+    (6909552, IdStatus::Regular), // pni
+    (6909553, IdStatus::PrivateUse), // qni
+    (6909555, IdStatus::Regular), // sni
+    (6909556, IdStatus::Regular), // tni
+    (6909557, IdStatus::Regular), // uni
+    (6909559, IdStatus::Regular), // wni
+    (6909560, IdStatus::Regular), // xni
+    (6909793, IdStatus::Regular), // aoi
+    (6909794, IdStatus::Regular), // boi
+    (6909796, IdStatus::Regular), // doi
+    (6909798, IdStatus::Regular), // foi
+    (6909799, IdStatus::Regular), // goi
+    (6909800, IdStatus::Regular), // hoi
+    (6909803, IdStatus::Regular), // koi
+    (6909804, IdStatus::Regular), // loi
+    (6909805, IdStatus::Regular), // moi
+    (6909806, IdStatus::Regular), // noi
+    (6909808, IdStatus::Regular), // poi
+    (6909809, IdStatus::PrivateUse), // qoi
+    (6909811, IdStatus::Regular), // soi
+    (6909812, IdStatus::Regular), // toi
+    (6909815, IdStatus::Regular), // woi
+    (6909816, IdStatus::Regular), // xoi
+    (6909817, IdStatus::Regular), // yoi
+    (6910049, IdStatus::Regular), // api
+    (6910050, IdStatus::Regular), // bpi
+    (6910051, IdStatus::Regular), // cpi
+    (6910053, IdStatus::Regular), // epi
+    (6910057, IdStatus::Regular), // ipi
+    (6910059, IdStatus::Regular), // kpi
+    (6910061, IdStatus::Regular), // mpi
+    (6910062, IdStatus::Deprecated), // npi
+    (6910064, IdStatus::Regular), // ppi
+    (6910065, IdStatus::PrivateUse), // qpi
+    (6910067, IdStatus::Regular), // spi
+    (6910068, IdStatus::Regular), // tpi
+    (6910069, IdStatus::Regular), // upi
+    (6910072, IdStatus::Regular), // xpi
+    (6910074, IdStatus::Regular), // zpi
+    (6910306, IdStatus::Regular), // bqi
+    (6910311, IdStatus::Regular), // gqi
+    (6910315, IdStatus::Regular), // kqi
+    (6910317, IdStatus::Regular), // mqi
+    (6910321, IdStatus::PrivateUse), // qqi
+    (6910561, IdStatus::Regular), // ari
+    (6910562, IdStatus::Regular), // bri
+    (6910563, IdStatus::Regular), // cri
+    (6910564, IdStatus::Regular), // dri
+    (6910565, IdStatus::Regular), // eri
+    (6910567, IdStatus::Regular), // gri
+    (6910569, IdStatus::Regular), // iri
+    (6910571, IdStatus::Regular), // kri
+    (6910572, IdStatus::Regular), // lri
+    (6910574, IdStatus::Regular), // nri
+    (6910576, IdStatus::Regular), // pri
+    (6910577, IdStatus::PrivateUse), // qri
+    (6910578, IdStatus::Regular), // rri
+    (6910579, IdStatus::Regular), // sri
+    (6910580, IdStatus::Regular), // tri
+    (6910581, IdStatus::Regular), // uri
+    (6910583, IdStatus::Regular), // wri
+    (6910584, IdStatus::Regular), // xri
+    (6910585, IdStatus::Deprecated), // yri
+    (6910817, IdStatus::Regular), // asi
+    (6910818, IdStatus::Regular), // bsi
+    (6910819, IdStatus::Regular), // csi
+    (6910820, IdStatus::Regular), // dsi
+    (6910821, IdStatus::Regular), // esi
+    (6910825, IdStatus::Regular), // isi
+    (6910827, IdStatus::Regular), // ksi
+    (6910828, IdStatus::Regular), // lsi
+    (6910829, IdStatus::Regular), // msi
+    (6910830, IdStatus::Regular), // nsi
+    (6910831, IdStatus::Regular), // osi
+    (6910832, IdStatus::Regular), // psi
+    (6910833, IdStatus::PrivateUse), // qsi
+    (6910834, IdStatus::Deprecated), // rsi
+    (6910835, IdStatus::Regular), // ssi
+    (6910836, IdStatus::Regular), // tsi
+    (6910837, IdStatus::Regular), // usi
+    (6910838, IdStatus::Regular), // vsi
+    (6910839, IdStatus::Regular), // wsi
+    (6910840, IdStatus::Regular), // xsi
+    (6911073, IdStatus::Regular), // ati
+    (6911074, IdStatus::Regular), // bti
+    (6911076, IdStatus::Regular), // dti
+    (6911079, IdStatus::Deprecated), // gti
+    (6911080, IdStatus::Regular), // hti
+    (6911081, IdStatus::Regular), // iti
+    (6911083, IdStatus::Regular), // kti
+    (6911084, IdStatus::Regular), // lti
+    (6911085, IdStatus::Regular), // mti
+    (6911086, IdStatus::Regular), // nti
+    (6911087, IdStatus::Regular), // oti
+    (6911088, IdStatus::Regular), // pti
+    (6911089, IdStatus::PrivateUse), // qti
+    (6911091, IdStatus::Regular), // sti
+    (6911092, IdStatus::Regular), // tti
+    (6911095, IdStatus::Regular), // wti
+    (6911096, IdStatus::Regular), // xti
+    (6911329, IdStatus::Regular), // aui
+    (6911330, IdStatus::Regular), // bui
+    (6911331, IdStatus::Regular), // cui
+    (6911332, IdStatus::Regular), // dui
+    (6911334, IdStatus::Regular), // fui
+    (6911335, IdStatus::Regular), // gui
+    (6911336, IdStatus::Regular), // hui
+    (6911338, IdStatus::Regular), // jui
+    (6911339, IdStatus::Regular), // kui
+    (6911340, IdStatus::Regular), // lui
+    (6911341, IdStatus::Regular), // mui
+    (6911342, IdStatus::Regular), // nui
+    (6911343, IdStatus::Regular), // oui
+    (6911344, IdStatus::Regular), // pui
+    (6911345, IdStatus::Regular), // qui
+    (6911346, IdStatus::Regular), // rui
+    (6911347, IdStatus::Regular), // sui
+    (6911348, IdStatus::Regular), // tui
+    (6911353, IdStatus::Regular), // yui
+    (6911585, IdStatus::Regular), // avi
+    (6911586, IdStatus::Regular), // bvi
+    (6911595, IdStatus::Regular), // kvi
+    (6911596, IdStatus::Regular), // lvi
+    (6911597, IdStatus::Regular), // mvi
+    (6911601, IdStatus::Regular), // qvi
+    (6911608, IdStatus::Regular), // xvi
+    (6911841, IdStatus::Regular), // awi
+    (6911842, IdStatus::Regular), // bwi
+    (6911847, IdStatus::Regular), // gwi
+    (6911850, IdStatus::Regular), // jwi
+    (6911851, IdStatus::Regular), // kwi
+    (6911853, IdStatus::Regular), // mwi
+    (6911854, IdStatus::Regular), // nwi
+    (6911855, IdStatus::Regular), // owi
+    (6911856, IdStatus::Regular), // pwi
+    (6911859, IdStatus::Regular), // swi
+    (6912098, IdStatus::Regular), // bxi
+    (6912107, IdStatus::Regular), // kxi
+    (6912109, IdStatus::Regular), // mxi
+    (6912110, IdStatus::Regular), // nxi
+    (6912116, IdStatus::Regular), // txi
+    (6912353, IdStatus::Regular), // ayi
+    (6912354, IdStatus::Regular), // byi
+    (6912356, IdStatus::Regular), // dyi
+    (6912359, IdStatus::Regular), // gyi
+    (6912363, IdStatus::Regular), // kyi
+    (6912365, IdStatus::Deprecated), // myi
+    (6912366, IdStatus::Regular), // nyi
+    (6912371, IdStatus::Regular), // syi
+    (6912372, IdStatus::Regular), // tyi
+    (6912375, IdStatus::Regular), // wyi
+    (6912610, IdStatus::Regular), // bzi
+    (6912615, IdStatus::Regular), // gzi
+    (6912617, IdStatus::Deprecated), // izi
+    (6912619, IdStatus::Regular), // kzi
+    (6912621, IdStatus::Regular), // mzi
+    (6912622, IdStatus::Regular), // nzi
+    (6971746, IdStatus::Regular), // baj
+    (6971747, IdStatus::Regular), // caj
+    (6971748, IdStatus::Regular), // daj
+    (6971750, IdStatus::Regular), // faj
+    (6971751, IdStatus::Regular), // gaj
+    (6971752, IdStatus::Regular), // haj
+    (6971754, IdStatus::Regular), // jaj
+    (6971755, IdStatus::Regular), // kaj
+    (6971756, IdStatus::Regular), // laj
+    (6971757, IdStatus::Regular), // maj
+    (6971758, IdStatus::Regular), // naj
+    (6971761, IdStatus::Reserved), // qaj
+    (6971762, IdStatus::Regular), // raj
+    (6971763, IdStatus::Regular), // saj
+    (6971764, IdStatus::Regular), // taj
+    (6971766, IdStatus::Regular), // vaj
+    (6971767, IdStatus::Regular), // waj
+    (6971768, IdStatus::Regular), // xaj
+    (6971769, IdStatus::Regular), // yaj
+    (6971770, IdStatus::Regular), // zaj
+    (6972001, IdStatus::Regular), // abj
+    (6972002, IdStatus::Regular), // bbj
+    (6972003, IdStatus::Regular), // cbj
+    (6972004, IdStatus::Regular), // dbj
+    (6972007, IdStatus::Regular), // gbj
+    (6972010, IdStatus::Regular), // jbj
+    (6972011, IdStatus::Regular), // kbj
+    (6972012, IdStatus::Regular), // lbj
+    (6972013, IdStatus::Regular), // mbj
+    (6972014, IdStatus::Regular), // nbj
+    (6972017, IdStatus::Reserved), // qbj
+    (6972019, IdStatus::Regular), // sbj
+    (6972020, IdStatus::Regular), // tbj
+    (6972023, IdStatus::Regular), // wbj
+    (6972024, IdStatus::Regular), // xbj
+    (6972025, IdStatus::Regular), // ybj
+    (6972258, IdStatus::Regular), // bcj
+    (6972259, IdStatus::Regular), // ccj
+    (6972267, IdStatus::Regular), // kcj
+    (6972269, IdStatus::Regular), // mcj
+    (6972270, IdStatus::Regular), // ncj
+    (6972272, IdStatus::Regular), // pcj
+    (6972273, IdStatus::Reserved), // qcj
+    (6972513, IdStatus::Regular), // adj
+    (6972514, IdStatus::Regular), // bdj
+    (6972515, IdStatus::Regular), // cdj
+    (6972516, IdStatus::Regular), // ddj
+    (6972519, IdStatus::Regular), // gdj
+    (6972523, IdStatus::Regular), // kdj
+    (6972524, IdStatus::Regular), // ldj
+    (6972525, IdStatus::Regular), // mdj
+    (6972526, IdStatus::Regular), // ndj
+    (6972529, IdStatus::Reserved), // qdj
+    (6972531, IdStatus::Regular), // sdj
+    (6972532, IdStatus::Regular), // tdj
+    (6972533, IdStatus::Regular), // udj
+    (6972535, IdStatus::Regular), // wdj
+    (6972538, IdStatus::Regular), // zdj
+    (6972770, IdStatus::Regular), // bej
+    (6972775, IdStatus::Regular), // gej
+    (6972779, IdStatus::Regular), // kej
+    (6972780, IdStatus::Regular), // lej
+    (6972781, IdStatus::Regular), // mej
+    (6972782, IdStatus::Regular), // nej
+    (6972784, IdStatus::Regular), // pej
+    (6972785, IdStatus::Reserved), // qej
+    (6972786, IdStatus::Regular), // rej
+    (6972787, IdStatus::Regular), // sej
+    (6972793, IdStatus::Regular), // yej
+    (6973026, IdStatus::Regular), // bfj
+    (6973035, IdStatus::Regular), // kfj
+    (6973037, IdStatus::Regular), // mfj
+    (6973041, IdStatus::Reserved), // qfj
+    (6973281, IdStatus::Regular), // agj
+    (6973282, IdStatus::Regular), // bgj
+    (6973291, IdStatus::Regular), // kgj
+    (6973293, IdStatus::Regular), // mgj
+    (6973294, IdStatus::Regular), // ngj
+    (6973297, IdStatus::PrivateUse), // qgj
+    (6973299, IdStatus::Regular), // sgj
+    (6973300, IdStatus::Regular), // tgj
+    (6973538, IdStatus::Regular), // bhj
+    (6973539, IdStatus::Regular), // chj
+    (6973547, IdStatus::Regular), // khj
+    (6973549, IdStatus::Regular), // mhj
+    (6973552, IdStatus::Regular), // phj
+    (6973553, IdStatus::PrivateUse), // qhj
+    (6973555, IdStatus::Regular), // shj
+    (6973793, IdStatus::Regular), // aij
+    (6973794, IdStatus::Deprecated), // bij
+    (6973796, IdStatus::Regular), // dij
+    (6973800, IdStatus::Regular), // hij
+    (6973803, IdStatus::Regular), // kij
+    (6973804, IdStatus::Regular), // lij
+    (6973805, IdStatus::Regular), // mij
+    (6973806, IdStatus::Regular), // nij
+    (6973808, IdStatus::Regular), // pij
+    (6973809, IdStatus::PrivateUse), // qij
+    (6973811, IdStatus::Regular), // sij
+    (6973812, IdStatus::Regular), // tij
+    (6973815, IdStatus::Regular), // wij
+    (6973817, IdStatus::Regular), // yij
+    (6974050, IdStatus::Regular), // bjj
+    (6974052, IdStatus::Regular), // djj
+    (6974057, IdStatus::Regular), // ijj
+    (6974059, IdStatus::Regular), // kjj
+    (6974061, IdStatus::Regular), // mjj
+    (6974062, IdStatus::Regular), // njj
+    (6974065, IdStatus::PrivateUse), // qjj
+    (6974068, IdStatus::Regular), // tjj
+    (6974305, IdStatus::Regular), // akj
+    (6974306, IdStatus::Regular), // bkj
+    (6974315, IdStatus::Regular), // kkj
+    (6974316, IdStatus::Regular), // lkj
+    (6974317, IdStatus::Regular), // mkj
+    (6974318, IdStatus::Regular), // nkj
+    (6974319, IdStatus::Regular), // okj
+    (6974321, IdStatus::PrivateUse), // qkj
+    (6974323, IdStatus::Regular), // skj
+    (6974326, IdStatus::Regular), // vkj
+    (6974328, IdStatus::Regular), // xkj
+    (6974561, IdStatus::Regular), // alj
+    (6974562, IdStatus::Regular), // blj
+    (6974563, IdStatus::Regular), // clj
+    (6974567, IdStatus::Regular), // glj
+    (6974571, IdStatus::Regular), // klj
+    (6974572, IdStatus::Regular), // llj
+    (6974573, IdStatus::Regular), // mlj
+    (6974574, IdStatus::Regular), // nlj
+    (6974576, IdStatus::Regular), // plj
+    (6974577, IdStatus::PrivateUse), // qlj
+    (6974579, IdStatus::Regular), // slj
+    (6974580, IdStatus::Regular), // tlj
+    (6974586, IdStatus::Regular), // zlj
+    (6974817, IdStatus::Regular), // amj
+    (6974818, IdStatus::Regular), // bmj
+    (6974824, IdStatus::Regular), // hmj
+    (6974827, IdStatus::Regular), // kmj
+    (6974828, IdStatus::Regular), // lmj
+    (6974829, IdStatus::Regular), // mmj
+    (6974830, IdStatus::Regular), // nmj
+    (6974832, IdStatus::Regular), // pmj
+    (6974833, IdStatus::PrivateUse), // qmj
+    (6974835, IdStatus::Regular), // smj
+    (6974836, IdStatus::Regular), // tmj
+    (6974838, IdStatus::Regular), // vmj
+    (6974840, IdStatus::Regular), // xmj
+    (6974842, IdStatus::Regular), // zmj
+    (6975073, IdStatus::Regular), // anj
+    (6975074, IdStatus::Regular), // bnj
+    (6975076, IdStatus::Regular), // dnj
+    (6975079, IdStatus::Regular), // gnj
+    (6975080, IdStatus::Regular), // hnj
+    (6975081, IdStatus::Regular), // inj
+    (6975082, IdStatus::Regular), // jnj
+    (6975083, IdStatus::Regular), // knj
+    (6975084, IdStatus::Regular), // lnj
+    (6975085, IdStatus::Regular), // mnj
+    (6975086, IdStatus::Regular), // nnj
+    (6975087, IdStatus::Regular), // onj
+    (6975088, IdStatus::Regular), // pnj
+    (6975089, IdStatus::PrivateUse), // qnj
+    (6975091, IdStatus::Regular), // snj
+    (6975096, IdStatus::Regular), // xnj
+    (6975329, IdStatus::Regular), // aoj
+    (6975330, IdStatus::Regular), // boj
+    (6975331, IdStatus::Regular), // coj
+    (6975335, IdStatus::Regular), // goj
+    (6975336, IdStatus::Regular), // hoj
+    (6975339, IdStatus::Deprecated), // koj
+    (6975340, IdStatus::Regular), // loj
+    (6975341, IdStatus::Regular), // moj
+    (6975342, IdStatus::Regular), // noj
+    (6975345, IdStatus::PrivateUse), // qoj
+    (6975347, IdStatus::Regular), // soj
+    (6975348, IdStatus::Regular), // toj
+    (6975585, IdStatus::Regular), // apj
+    (6975586, IdStatus::Regular), // bpj
+    (6975595, IdStatus::Regular), // kpj
+    (6975597, IdStatus::Regular), // mpj
+    (6975601, IdStatus::PrivateUse), // qpj
+    (6975604, IdStatus::Regular), // tpj
+    (6975608, IdStatus::Regular), // xpj
+    (6975610, IdStatus::Regular), // zpj
+    (6975842, IdStatus::Regular), // bqj
+    (6975851, IdStatus::Regular), // kqj
+    (6975853, IdStatus::Regular), // mqj
+    (6975857, IdStatus::PrivateUse), // qqj
+    (6975859, IdStatus::Regular), // sqj
+    (6976097, IdStatus::Regular), // arj
+    (6976098, IdStatus::Regular), // brj
+    (6976099, IdStatus::Regular), // crj
+    (6976103, IdStatus::Regular), // grj
+    (6976107, IdStatus::Regular), // krj
+    (6976109, IdStatus::Regular), // mrj
+    (6976113, IdStatus::PrivateUse), // qrj
+    (6976116, IdStatus::Regular), // trj
+    (6976117, IdStatus::Regular), // urj
+    (6976353, IdStatus::Regular), // asj
+    (6976354, IdStatus::Regular), // bsj
+    (6976355, IdStatus::Regular), // csj
+    (6976363, IdStatus::Regular), // ksj
+    (6976365, IdStatus::Regular), // msj
+    (6976369, IdStatus::PrivateUse), // qsj
+    (6976371, IdStatus::Regular), // ssj
+    (6976372, IdStatus::Regular), // tsj
+    (6976376, IdStatus::Deprecated), // xsj
+    (6976609, IdStatus::Regular), // atj
+    (6976610, IdStatus::Regular), // btj
+    (6976619, IdStatus::Regular), // ktj
+    (6976621, IdStatus::Regular), // mtj
+    (6976622, IdStatus::Regular), // ntj
+    (6976625, IdStatus::PrivateUse), // qtj
+    (6976627, IdStatus::Regular), // stj
+    (6976628, IdStatus::Regular), // ttj
+    (6976632, IdStatus::Regular), // xtj
+    (6976865, IdStatus::Regular), // auj
+    (6976866, IdStatus::Regular), // buj
+    (6976867, IdStatus::Regular), // cuj
+    (6976868, IdStatus::Deprecated), // duj
+    (6976870, IdStatus::Regular), // fuj
+    (6976872, IdStatus::Regular), // huj
+    (6976875, IdStatus::Regular), // kuj
+    (6976876, IdStatus::Regular), // luj
+    (6976877, IdStatus::Regular), // muj
+    (6976878, IdStatus::Regular), // nuj
+    (6976880, IdStatus::Regular), // puj
+    (6976883, IdStatus::Regular), // suj
+    (6976884, IdStatus::Regular), // tuj
+    (6976888, IdStatus::Regular), // xuj
+    (6976889, IdStatus::Regular), // yuj
+    (6977122, IdStatus::Regular), // bvj
+    (6977127, IdStatus::Regular), // gvj
+    (6977131, IdStatus::Regular), // kvj
+    (6977137, IdStatus::Regular), // qvj
+    (6977378, IdStatus::Regular), // bwj
+    (6977383, IdStatus::Regular), // gwj
+    (6977387, IdStatus::Regular), // kwj
+    (6977389, IdStatus::Deprecated), // mwj
+    (6977395, IdStatus::Regular), // swj
+    (6977400, IdStatus::Regular), // xwj
+    (6977634, IdStatus::Regular), // bxj
+    (6977643, IdStatus::Regular), // kxj
+    (6977645, IdStatus::Regular), // mxj
+    (6977652, IdStatus::Regular), // txj
+    (6977890, IdStatus::Regular), // byj
+    (6977899, IdStatus::Regular), // kyj
+    (6977901, IdStatus::Regular), // myj
+    (6977902, IdStatus::Regular), // nyj
+    (6977908, IdStatus::Regular), // tyj
+    (6977912, IdStatus::Regular), // xyj
+    (6977914, IdStatus::Regular), // zyj
+    (6978145, IdStatus::Deprecated), // azj
+    (6978146, IdStatus::Regular), // bzj
+    (6978155, IdStatus::Deprecated), // kzj
+    (6978157, IdStatus::Regular), // mzj
+    (6978164, IdStatus::Regular), // tzj
+    (6978170, IdStatus::Regular), // zzj
+    (7037281, IdStatus::Regular), // aak
+    (7037283, IdStatus::Regular), // cak
+    (7037284, IdStatus::Regular), // dak
+    (7037286, IdStatus::Regular), // fak
+    (7037287, IdStatus::Regular), // gak
+    (7037288, IdStatus::Regular), // hak
+    (7037290, IdStatus::Regular), // jak
+    (7037291, IdStatus::Regular), // kak
+    (7037292, IdStatus::Deprecated), // lak
+    (7037293, IdStatus::Regular), // mak
+    (7037294, IdStatus::Regular), // nak
+    (7037296, IdStatus::Regular), // pak
+    (7037297, IdStatus::Reserved), // qak
+    (7037298, IdStatus::Regular), // rak
+    (7037299, IdStatus::Regular), // sak
+    (7037300, IdStatus::Regular), // tak
+    (7037303, IdStatus::Regular), // wak
+    (7037304, IdStatus::Regular), // xak
+    (7037305, IdStatus::Regular), // yak
+    (7037306, IdStatus::Regular), // zak
+    (7037538, IdStatus::Regular), // bbk
+    (7037539, IdStatus::Regular), // cbk
+    (7037541, IdStatus::Regular), // ebk
+    (7037543, IdStatus::Regular), // gbk
+    (7037546, IdStatus::Regular), // jbk
+    (7037547, IdStatus::Regular), // kbk
+    (7037548, IdStatus::Deprecated), // lbk
+    (7037549, IdStatus::Regular), // mbk
+    (7037550, IdStatus::Regular), // nbk
+    (7037551, IdStatus::Regular), // obk
+    (7037553, IdStatus::Reserved), // qbk
+    (7037554, IdStatus::Regular), // rbk
+    (7037555, IdStatus::Regular), // sbk
+    (7037556, IdStatus::Regular), // tbk
+    (7037558, IdStatus::Regular), // vbk
+    (7037559, IdStatus::Regular), // wbk
+    (7037561, IdStatus::Regular), // ybk
+    (7037793, IdStatus::Regular), // ack
+    (7037794, IdStatus::Regular), // bck
+    (7037803, IdStatus::Regular), // kck
+    (7037805, IdStatus::Regular), // mck
+    (7037806, IdStatus::Regular), // nck
+    (7037808, IdStatus::Regular), // pck
+    (7037809, IdStatus::Reserved), // qck
+    (7037811, IdStatus::Regular), // sck
+    (7037812, IdStatus::Regular), // tck
+    (7038050, IdStatus::Regular), // bdk
+    (7038055, IdStatus::Regular), // gdk
+    (7038059, IdStatus::Regular), // kdk
+    (7038060, IdStatus::Regular), // ldk
+    (7038061, IdStatus::Regular), // mdk
+    (7038062, IdStatus::Regular), // ndk
+    (7038063, IdStatus::Regular), // odk
+    (7038065, IdStatus::Reserved), // qdk
+    (7038067, IdStatus::Regular), // sdk
+    (7038068, IdStatus::Regular), // tdk
+    (7038071, IdStatus::Regular), // wdk
+    (7038072, IdStatus::Regular), // xdk
+    (7038073, IdStatus::Regular), // ydk
+    (7038305, IdStatus::Regular), // aek
+    (7038306, IdStatus::Regular), // bek
+    (7038307, IdStatus::Regular), // cek
+    (7038308, IdStatus::Regular), // dek
+    (7038311, IdStatus::Regular), // gek
+    (7038314, IdStatus::Regular), // jek
+    (7038315, IdStatus::Regular), // kek
+    (7038316, IdStatus::Regular), // lek
+    (7038317, IdStatus::Regular), // mek
+    (7038318, IdStatus::Regular), // nek
+    (7038320, IdStatus::Regular), // pek
+    (7038321, IdStatus::Reserved), // qek
+    (7038323, IdStatus::Regular), // sek
+    (7038324, IdStatus::Regular), // tek
+    (7038561, IdStatus::Regular), // afk
+    (7038562, IdStatus::Regular), // bfk
+    (7038567, IdStatus::Regular), // gfk
+    (7038569, IdStatus::Regular), // ifk
+    (7038571, IdStatus::Regular), // kfk
+    (7038573, IdStatus::Regular), // mfk
+    (7038577, IdStatus::Reserved), // qfk
+    (7038817, IdStatus::Regular), // agk
+    (7038818, IdStatus::Regular), // bgk
+    (7038819, IdStatus::Regular), // cgk
+    (7038820, IdStatus::Regular), // dgk
+    (7038823, IdStatus::Regular), // ggk
+    (7038826, IdStatus::Regular), // jgk
+    (7038827, IdStatus::Regular), // kgk
+    (7038828, IdStatus::Regular), // lgk
+    (7038829, IdStatus::Regular), // mgk
+    (7038830, IdStatus::Regular), // ngk
+    (7038832, IdStatus::Regular), // pgk
+    (7038833, IdStatus::PrivateUse), // qgk
+    (7038834, IdStatus::Regular), // rgk
+    (7038835, IdStatus::Regular), // sgk
+    (7039073, IdStatus::Regular), // ahk
+    (7039074, IdStatus::Deprecated), // bhk
+    (7039075, IdStatus::Regular), // chk
+    (7039079, IdStatus::Regular), // ghk
+    (7039083, IdStatus::Deprecated), // khk
+    (7039085, IdStatus::Regular), // mhk
+    (7039086, IdStatus::Regular), // nhk
+    (7039088, IdStatus::Regular), // phk
+    (7039089, IdStatus::PrivateUse), // qhk
+    (7039091, IdStatus::Regular), // shk
+    (7039092, IdStatus::Regular), // thk
+    (7039095, IdStatus::Regular), // whk
+    (7039329, IdStatus::Regular), // aik
+    (7039330, IdStatus::Regular), // bik
+    (7039331, IdStatus::Regular), // cik
+    (7039332, IdStatus::Deprecated), // dik
+    (7039336, IdStatus::Regular), // hik
+    (7039340, IdStatus::Regular), // lik
+    (7039341, IdStatus::Regular), // mik
+    (7039342, IdStatus::Regular), // nik
+    (7039345, IdStatus::PrivateUse), // qik
+    (7039347, IdStatus::Regular), // sik
+    (7039348, IdStatus::Regular), // tik
+    (7039351, IdStatus::Regular), // wik
+    (7039353, IdStatus::Regular), // yik
+    (7039354, IdStatus::Regular), // zik
+    (7039586, IdStatus::Regular), // bjk
+    (7039587, IdStatus::Regular), // cjk
+    (7039588, IdStatus::Regular), // djk
+    (7039591, IdStatus::Regular), // gjk
+    (7039595, IdStatus::Regular), // kjk
+    (7039597, IdStatus::Regular), // mjk
+    (7039601, IdStatus::PrivateUse), // qjk
+    (7039603, IdStatus::Regular), // sjk
+    (7039841, IdStatus::Regular), // akk
+    (7039842, IdStatus::Regular), // bkk
+    (7039844, IdStatus::Regular), // dkk
+    (7039845, IdStatus::Deprecated), // ekk
+    (7039846, IdStatus::Regular), // fkk
+    (7039848, IdStatus::Regular), // hkk
+    (7039849, IdStatus::Regular), // ikk
+    (7039851, IdStatus::Regular), // kkk
+    (7039853, IdStatus::Regular), // mkk
+    (7039854, IdStatus::Regular), // nkk
+    (7039855, IdStatus::Regular), // okk
+    (7039857, IdStatus::PrivateUse), // qkk
+    (7039859, IdStatus::Deprecated), // skk
+    (7039860, IdStatus::Deprecated), // tkk
+    (7039861, IdStatus::Regular), // ukk
+    (7039862, IdStatus::Regular), // vkk
+    (7039864, IdStatus::Regular), // xkk
+    (7039865, IdStatus::Regular), // ykk
+    (7039866, IdStatus::Regular), // zkk
+    (7040097, IdStatus::Regular), // alk
+    (7040098, IdStatus::Regular), // blk
+    (7040099, IdStatus::Regular), // clk
+    (7040100, IdStatus::Regular), // dlk
+    (7040101, IdStatus::Regular), // elk
+    (7040103, IdStatus::Regular), // glk
+    (7040105, IdStatus::Regular), // ilk
+    (7040107, IdStatus::Regular), // klk
+    (7040108, IdStatus::Regular), // llk
+    (7040109, IdStatus::Regular), // mlk
+    (7040110, IdStatus::Regular), // nlk
+    (7040111, IdStatus::Regular), // olk
+    (7040112, IdStatus::Regular), // plk
+    (7040113, IdStatus::PrivateUse), // qlk
+    (7040116, IdStatus::Regular), // tlk
+    (7040117, IdStatus::Regular), // ulk
+    (7040119, IdStatus::Regular), // wlk
+    (7040353, IdStatus::Regular), // amk
+    (7040354, IdStatus::Regular), // bmk
+    (7040355, IdStatus::Deprecated), // cmk
+    (7040356, IdStatus::Regular), // dmk
+    (7040357, IdStatus::Deprecated), // emk
+    (7040360, IdStatus::Regular), // hmk
+    (7040363, IdStatus::Regular), // kmk
+    (7040364, IdStatus::Regular), // lmk
+    (7040365, IdStatus::Regular), // mmk
+    (7040366, IdStatus::Regular), // nmk
+    (7040367, IdStatus::Regular), // omk
+    (7040368, IdStatus::Regular), // pmk
+    (7040369, IdStatus::PrivateUse), // qmk
+    (7040370, IdStatus::Regular), // rmk
+    (7040371, IdStatus::Regular), // smk
+    (7040372, IdStatus::Regular), // tmk
+    (7040374, IdStatus::Regular), // vmk
+    (7040376, IdStatus::Regular), // xmk
+    (7040377, IdStatus::Regular), // ymk
+    (7040378, IdStatus::Regular), // zmk
+    (7040609, IdStatus::Regular), // ank
+    (7040610, IdStatus::Regular), // bnk
+    (7040611, IdStatus::Regular), // cnk
+    (7040612, IdStatus::Regular), // dnk
+    (7040615, IdStatus::Regular), // gnk
+    (7040619, IdStatus::Regular), // knk
+    (7040621, IdStatus::Deprecated), // mnk
+    (7040622, IdStatus::Regular), // nnk
+    (7040623, IdStatus::Regular), // onk
+    (7040624, IdStatus::Regular), // pnk
+    (7040625, IdStatus::PrivateUse), // qnk
+    (7040627, IdStatus::Regular), // snk
+    (7040628, IdStatus::Regular), // tnk
+    (7040629, IdStatus::Regular), // unk
+    (7040630, IdStatus::Regular), // vnk
+    (7040631, IdStatus::Regular), // wnk
+    (7040632, IdStatus::Regular), // xnk
+    (7040633, IdStatus::Regular), // ynk
+    (7040634, IdStatus::Regular), // znk
+    (7040865, IdStatus::Regular), // aok
+    (7040866, IdStatus::Regular), // bok
+    (7040867, IdStatus::Regular), // cok
+    (7040868, IdStatus::Regular), // dok
+    (7040871, IdStatus::Regular), // gok
+    (7040872, IdStatus::Regular), // hok
+    (7040875, IdStatus::Regular), // kok
+    (7040876, IdStatus::Regular), // lok
+    (7040877, IdStatus::Regular), // mok
+    (7040878, IdStatus::Regular), // nok
+    (7040880, IdStatus::Regular), // pok
+    (7040881, IdStatus::PrivateUse), // qok
+    (7040883, IdStatus::Regular), // sok
+    (7040884, IdStatus::Regular), // tok
+    (7040885, IdStatus::Deprecated), // uok
+    (7040887, IdStatus::Regular), // wok
+    (7040888, IdStatus::Regular), // xok
+    (7040889, IdStatus::Regular), // yok
+    (7041121, IdStatus::Regular), // apk
+    (7041122, IdStatus::Regular), // bpk
+    (7041131, IdStatus::Regular), // kpk
+    (7041133, IdStatus::Regular), // mpk
+    (7041135, IdStatus::Regular), // opk
+    (7041136, IdStatus::Regular), // ppk
+    (7041137, IdStatus::PrivateUse), // qpk
+    (7041139, IdStatus::Regular), // spk
+    (7041140, IdStatus::Regular), // tpk
+    (7041144, IdStatus::Regular), // xpk
+    (7041145, IdStatus::Regular), // ypk
+    (7041146, IdStatus::Regular), // zpk
+    (7041377, IdStatus::Regular), // aqk
+    (7041378, IdStatus::Regular), // bqk
+    (7041387, IdStatus::Regular), // kqk
+    (7041389, IdStatus::Regular), // mqk
+    (7041390, IdStatus::Regular), // nqk
+    (7041393, IdStatus::PrivateUse), // qqk
+    (7041395, IdStatus::Regular), // sqk
+    (7041633, IdStatus::Regular), // ark
+    (7041634, IdStatus::Regular), // brk
+    (7041635, IdStatus::Regular), // crk
+    (7041637, IdStatus::Regular), // erk
+    (7041638, IdStatus::Regular), // frk
+    (7041639, IdStatus::Regular), // grk
+    (7041640, IdStatus::Regular), // hrk
+    (7041641, IdStatus::Regular), // irk
+    (7041643, IdStatus::Regular), // krk
+    (7041644, IdStatus::Regular), // lrk
+    (7041645, IdStatus::Regular), // mrk
+    (7041646, IdStatus::Regular), // nrk
+    (7041648, IdStatus::Regular), // prk
+    (7041649, IdStatus::PrivateUse), // qrk
+    (7041651, IdStatus::Regular), // srk
+    (7041652, IdStatus::Regular), // trk
+    (7041653, IdStatus::Regular), // urk
+    (7041655, IdStatus::Regular), // wrk
+    (7041657, IdStatus::Regular), // yrk
+    (7041889, IdStatus::Regular), // ask
+    (7041890, IdStatus::Regular), // bsk
+    (7041891, IdStatus::Regular), // csk
+    (7041893, IdStatus::Deprecated), // esk
+    (7041897, IdStatus::Regular), // isk
+    (7041899, IdStatus::Regular), // ksk
+    (7041901, IdStatus::Regular), // msk
+    (7041902, IdStatus::Regular), // nsk
+    (7041905, IdStatus::PrivateUse), // qsk
+    (7041906, IdStatus::Regular), // rsk
+    (7041907, IdStatus::Regular), // ssk
+    (7041908, IdStatus::Regular), // tsk
+    (7041909, IdStatus::Regular), // usk
+    (7041911, IdStatus::Regular), // wsk
+    (7041914, IdStatus::Regular), // zsk
+    (7042145, IdStatus::Regular), // atk
+    (7042146, IdStatus::Regular), // btk
+    (7042148, IdStatus::Regular), // dtk
+    (7042153, IdStatus::Regular), // itk
+    (7042155, IdStatus::Regular), // ktk
+    (7042157, IdStatus::Regular), // mtk
+    (7042158, IdStatus::Regular), // ntk
+    (7042159, IdStatus::Regular), // otk
+    (7042161, IdStatus::PrivateUse), // qtk
+    (7042163, IdStatus::Regular), // stk
+    (7042164, IdStatus::Regular), // ttk
+    (7042167, IdStatus::Regular), // wtk
+    (7042401, IdStatus::Regular), // auk
+    (7042402, IdStatus::Regular), // buk
+    (7042403, IdStatus::Regular), // cuk
+    (7042404, IdStatus::Regular), // duk
+    (7042407, IdStatus::Regular), // guk
+    (7042408, IdStatus::Regular), // huk
+    (7042410, IdStatus::Regular), // juk
+    (7042411, IdStatus::Regular), // kuk
+    (7042412, IdStatus::Regular), // luk
+    (7042413, IdStatus::Regular), // muk
+    (7042414, IdStatus::Regular), // nuk
+    (7042416, IdStatus::Deprecated), // puk
+    (7042417, IdStatus::Regular), // quk
+    (7042418, IdStatus::Regular), // ruk
+    (7042419, IdStatus::Regular), // suk
+    (7042425, IdStatus::Regular), // yuk
+    (7042657, IdStatus::Regular), // avk
+    (7042658, IdStatus::Regular), // bvk
+    (7042664, IdStatus::Regular), // hvk
+    (7042667, IdStatus::Regular), // kvk
+    (7042668, IdStatus::Regular), // lvk
+    (7042669, IdStatus::Regular), // mvk
+    (7042675, IdStatus::Regular), // svk
+    (7042676, IdStatus::Regular), // tvk
+    (7042913, IdStatus::Regular), // awk
+    (7042914, IdStatus::Regular), // bwk
+    (7042916, IdStatus::Regular), // dwk
+    (7042921, IdStatus::Regular), // iwk
+    (7042923, IdStatus::Regular), // kwk
+    (7042925, IdStatus::Regular), // mwk
+    (7042930, IdStatus::Regular), // rwk
+    (7042931, IdStatus::Regular), // swk
+    (7042936, IdStatus::Regular), // xwk
+    (7043169, IdStatus::Regular), // axk
+    (7043170, IdStatus::Deprecated), // bxk
+    (7043179, IdStatus::Regular), // kxk
+    (7043181, IdStatus::Regular), // mxk
+    (7043182, IdStatus::Regular), // nxk
+    (7043187, IdStatus::Regular), // sxk
+    (7043192, IdStatus::Regular), // xxk
+    (7043425, IdStatus::Regular), // ayk
+    (7043426, IdStatus::Regular), // byk
+    (7043435, IdStatus::Regular), // kyk
+    (7043437, IdStatus::Regular), // myk
+    (7043438, IdStatus::Regular), // nyk
+    (7043443, IdStatus::Regular), // syk
+    (7043448, IdStatus::Regular), // xyk
+    (7043682, IdStatus::Regular), // bzk
+    (7043683, IdStatus::Regular), // czk
+    (7043691, IdStatus::Regular), // kzk
+    (7043693, IdStatus::Regular), // mzk
+    (7043694, IdStatus::Regular), // nzk
+    (7043705, IdStatus::Regular), // yzk
+    (7102817, IdStatus::Regular), // aal
+    (7102818, IdStatus::Regular), // bal
+    (7102819, IdStatus::Regular), // cal
+    (7102820, IdStatus::Regular), // dal
+    (7102822, IdStatus::Regular), // fal
+    (7102823, IdStatus::Regular), // gal
+    (7102824, IdStatus::Regular), // hal
+    (7102826, IdStatus::Regular), // jal
+    (7102828, IdStatus::Regular), // lal
+    (7102830, IdStatus::Regular), // nal
+    (7102832, IdStatus::Regular), // pal
+    (7102833, IdStatus::Reserved), // qal
+    (7102834, IdStatus::Regular), // ral
+    (7102835, IdStatus::Regular), // sal
+    (7102836, IdStatus::Regular), // tal
+    (7102838, IdStatus::Regular), // val
+    (7102839, IdStatus::Regular), // wal
+    (7102840, IdStatus::Regular), // xal
+    (7102841, IdStatus::Regular), // yal
+    (7102842, IdStatus::Regular), // zal
+    (7103073, IdStatus::Regular), // abl
+    (7103074, IdStatus::Regular), // bbl
+    (7103075, IdStatus::Regular), // cbl
+    (7103076, IdStatus::Regular), // dbl
+    (7103078, IdStatus::Regular), // fbl
+    (7103079, IdStatus::Regular), // gbl
+    (7103081, IdStatus::Regular), // ibl
+    (7103083, IdStatus::Regular), // kbl
+    (7103084, IdStatus::Regular), // lbl
+    (7103085, IdStatus::Regular), // mbl
+    (7103087, IdStatus::Regular), // obl
+    (7103088, IdStatus::Regular), // pbl
+    (7103089, IdStatus::Reserved), // qbl
+    (7103090, IdStatus::Regular), // rbl
+    (7103091, IdStatus::Regular), // sbl
+    (7103092, IdStatus::Regular), // tbl
+    (7103093, IdStatus::Regular), // ubl
+    (7103095, IdStatus::Regular), // wbl
+    (7103097, IdStatus::Regular), // ybl
+    (7103098, IdStatus::Regular), // zbl
+    (7103329, IdStatus::Regular), // acl
+    (7103330, IdStatus::Deprecated), // bcl
+    (7103331, IdStatus::Regular), // ccl
+    (7103335, IdStatus::Regular), // gcl
+    (7103337, IdStatus::Regular), // icl
+    (7103339, IdStatus::Regular), // kcl
+    (7103340, IdStatus::Regular), // lcl
+    (7103341, IdStatus::Regular), // mcl
+    (7103342, IdStatus::Regular), // ncl
+    (7103344, IdStatus::Regular), // pcl
+    (7103345, IdStatus::Reserved), // qcl
+    (7103347, IdStatus::Regular), // scl
+    (7103348, IdStatus::Regular), // tcl
+    (7103352, IdStatus::Regular), // xcl
+    (7103353, IdStatus::Regular), // ycl
+    (7103585, IdStatus::Regular), // adl
+    (7103586, IdStatus::Regular), // bdl
+    (7103591, IdStatus::Regular), // gdl
+    (7103595, IdStatus::Regular), // kdl
+    (7103596, IdStatus::Regular), // ldl
+    (7103597, IdStatus::Regular), // mdl
+    (7103598, IdStatus::Regular), // ndl
+    (7103601, IdStatus::Reserved), // qdl
+    (7103603, IdStatus::Regular), // sdl
+    (7103604, IdStatus::Regular), // tdl
+    (7103605, IdStatus::Regular), // udl
+    (7103841, IdStatus::Regular), // ael
+    (7103843, IdStatus::Regular), // cel
+    (7103844, IdStatus::Regular), // del
+    (7103847, IdStatus::Regular), // gel
+    (7103850, IdStatus::Regular), // jel
+    (7103851, IdStatus::Regular), // kel
+    (7103852, IdStatus::Regular), // lel
+    (7103853, IdStatus::Regular), // mel
+    (7103856, IdStatus::Regular), // pel
+    (7103857, IdStatus::Reserved), // qel
+    (7103858, IdStatus::Regular), // rel
+    (7103859, IdStatus::Regular), // sel
+    (7103862, IdStatus::Regular), // vel
+    (7103864, IdStatus::Regular), // xel
+    (7103865, IdStatus::Regular), // yel
+    (7104098, IdStatus::Regular), // bfl
+    (7104107, IdStatus::Regular), // kfl
+    (7104109, IdStatus::Regular), // mfl
+    (7104110, IdStatus::Regular), // nfl
+    (7104112, IdStatus::Regular), // pfl
+    (7104113, IdStatus::Reserved), // qfl
+    (7104353, IdStatus::Regular), // agl
+    (7104354, IdStatus::Regular), // bgl
+    (7104356, IdStatus::Regular), // dgl
+    (7104357, IdStatus::Regular), // egl
+    (7104359, IdStatus::Regular), // ggl
+    (7104361, IdStatus::Regular), // igl
+    (7104363, IdStatus::Regular), // kgl
+    (7104364, IdStatus::Regular), // lgl
+    (7104365, IdStatus::Regular), // mgl
+    (7104366, IdStatus::Regular), // ngl
+    (7104368, IdStatus::Regular), // pgl
+    (7104369, IdStatus::PrivateUse), // qgl
+    (7104371, IdStatus::Deprecated), // sgl
+    (7104376, IdStatus::Regular), // xgl
+    (7104377, IdStatus::Regular), // ygl
+    (7104609, IdStatus::Regular), // ahl
+    (7104610, IdStatus::Regular), // bhl
+    (7104611, IdStatus::Regular), // chl
+    (7104612, IdStatus::Regular), // dhl
+    (7104615, IdStatus::Regular), // ghl
+    (7104619, IdStatus::Regular), // khl
+    (7104620, IdStatus::Regular), // lhl
+    (7104621, IdStatus::Regular), // mhl
+    (7104624, IdStatus::Regular), // phl
+    (7104625, IdStatus::PrivateUse), // qhl
+    (7104627, IdStatus::Regular), // shl
+    (7104628, IdStatus::Regular), // thl
+    (7104633, IdStatus::Regular), // yhl
+    (7104865, IdStatus::Regular), // ail
+    (7104866, IdStatus::Regular), // bil
+    (7104868, IdStatus::Regular), // dil
+    (7104870, IdStatus::Regular), // fil
+    (7104871, IdStatus::Regular), // gil
+    (7104872, IdStatus::Regular), // hil
+    (7104874, IdStatus::Regular), // jil
+    (7104875, IdStatus::Regular), // kil
+    (7104876, IdStatus::Regular), // lil
+    (7104877, IdStatus::Regular), // mil
+    (7104878, IdStatus::Regular), // nil
+    (7104880, IdStatus::Regular), // pil
+    (7104881, IdStatus::PrivateUse), // qil
+    (7104882, IdStatus::Regular), // ril
+    (7104883, IdStatus::Regular), // sil
+    (7104884, IdStatus::Regular), // til
+    (7104886, IdStatus::Regular), // vil
+    (7104887, IdStatus::Regular), // wil
+    (7104888, IdStatus::Regular), // xil
+    (7104889, IdStatus::Regular), // yil
+    (7104890, IdStatus::Regular), // zil
+    (7105122, IdStatus::Regular), // bjl
+    (7105124, IdStatus::Deprecated), // djl
+    (7105131, IdStatus::Regular), // kjl
+    (7105132, IdStatus::Regular), // ljl
+    (7105133, IdStatus::Regular), // mjl
+    (7105134, IdStatus::Regular), // njl
+    (7105137, IdStatus::PrivateUse), // qjl
+    (7105139, IdStatus::Regular), // sjl
+    (7105140, IdStatus::Regular), // tjl
+    (7105377, IdStatus::Regular), // akl
+    (7105378, IdStatus::Regular), // bkl
+    (7105379, IdStatus::Regular), // ckl
+    (7105380, IdStatus::Deprecated), // dkl
+    (7105381, IdStatus::Regular), // ekl
+    (7105385, IdStatus::Regular), // ikl
+    (7105387, IdStatus::Regular), // kkl
+    (7105388, IdStatus::Regular), // lkl
+    (7105389, IdStatus::Regular), // mkl
+    (7105391, IdStatus::Regular), // okl
+    (7105393, IdStatus::PrivateUse), // qkl
+    (7105396, IdStatus::Regular), // tkl
+    (7105397, IdStatus::Regular), // ukl
+    (7105398, IdStatus::Regular), // vkl
+    (7105399, IdStatus::Regular), // wkl
+    (7105400, IdStatus::Regular), // xkl
+    (7105401, IdStatus::Regular), // ykl
+    (7105633, IdStatus::Regular), // all
+    (7105634, IdStatus::Regular), // bll
+    (7105635, IdStatus::Regular), // cll
+    (7105638, IdStatus::Regular), // fll
+    (7105639, IdStatus::Regular), // gll
+    (7105641, IdStatus::Deprecated), // ill
+    (7105643, IdStatus::Regular), // kll
+    (7105644, IdStatus::Regular), // lll
+    (7105645, IdStatus::Regular), // mll
+    (7105646, IdStatus::Regular), // nll
+    (7105648, IdStatus::Regular), // pll
+    (7105649, IdStatus::PrivateUse), // qll
+    (7105651, IdStatus::Regular), // sll
+    (7105652, IdStatus::Regular), // tll
+    (7105653, IdStatus::Regular), // ull
+    (7105655, IdStatus::Regular), // wll
+    (7105657, IdStatus::Regular), // yll
+    (7105889, IdStatus::Regular), // aml
+    (7105890, IdStatus::Regular), // bml
+    (7105891, IdStatus::Regular), // cml
+    (7105892, IdStatus::Regular), // dml
+    (7105895, IdStatus::Regular), // gml
+    (7105896, IdStatus::Regular), // hml
+    (7105897, IdStatus::Regular), // iml
+    (7105898, IdStatus::Regular), // jml
+    (7105899, IdStatus::Regular), // kml
+    (7105900, IdStatus::Regular), // lml
+    (7105901, IdStatus::Regular), // mml
+    (7105902, IdStatus::Regular), // nml
+    (7105903, IdStatus::Regular), // oml
+    (7105904, IdStatus::Regular), // pml
+    (7105905, IdStatus::PrivateUse), // qml
+    (7105906, IdStatus::Regular), // rml
+    (7105907, IdStatus::Regular), // sml
+    (7105908, IdStatus::Regular), // tml
+    (7105910, IdStatus::Regular), // vml
+    (7105912, IdStatus::Regular), // xml
+    (7105913, IdStatus::Regular), // yml
+    (7105914, IdStatus::Regular), // zml
+    (7106145, IdStatus::Regular), // anl
+    (7106146, IdStatus::Regular), // bnl
+    (7106147, IdStatus::Regular), // cnl
+    (7106149, IdStatus::Regular), // enl
+    (7106151, IdStatus::Regular), // gnl
+    (7106153, IdStatus::Regular), // inl
+    (7106154, IdStatus::Regular), // jnl
+    (7106155, IdStatus::Regular), // knl
+    (7106156, IdStatus::Regular), // lnl
+    (7106157, IdStatus::Regular), // mnl
+    (7106158, IdStatus::Regular), // nnl
+    (7106160, IdStatus::Regular), // pnl
+    (7106161, IdStatus::PrivateUse), // qnl
+    (7106162, IdStatus::Regular), // rnl
+    (7106163, IdStatus::Regular), // snl
+    (7106164, IdStatus::Regular), // tnl
+    (7106169, IdStatus::Regular), // ynl
+    (7106401, IdStatus::Regular), // aol
+    (7106402, IdStatus::Regular), // bol
+    (7106403, IdStatus::Regular), // col
+    (7106404, IdStatus::Regular), // dol
+    (7106407, IdStatus::Regular), // gol
+    (7106408, IdStatus::Regular), // hol
+    (7106411, IdStatus::Regular), // kol
+    (7106412, IdStatus::Regular), // lol
+    (7106414, IdStatus::Regular), // nol
+    (7106417, IdStatus::PrivateUse), // qol
+    (7106418, IdStatus::Regular), // rol
+    (7106419, IdStatus::Regular), // sol
+    (7106420, IdStatus::Regular), // tol
+    (7106425, IdStatus::Regular), // yol
+    (7106657, IdStatus::Regular), // apl
+    (7106658, IdStatus::Regular), // bpl
+    (7106667, IdStatus::Regular), // kpl
+    (7106669, IdStatus::Regular), // mpl
+    (7106670, IdStatus::Regular), // npl
+    (7106672, IdStatus::Regular), // ppl
+    (7106673, IdStatus::PrivateUse), // qpl
+    (7106675, IdStatus::Regular), // spl
+    (7106676, IdStatus::Regular), // tpl
+    (7106680, IdStatus::Regular), // xpl
+    (7106682, IdStatus::Regular), // zpl
+    (7106913, IdStatus::Regular), // aql
+    (7106914, IdStatus::Regular), // bql
+    (7106923, IdStatus::Regular), // kql
+    (7106925, IdStatus::Regular), // mql
+    (7106926, IdStatus::Regular), // nql
+    (7106929, IdStatus::PrivateUse), // qql
+    (7106932, IdStatus::Regular), // tql
+    (7107169, IdStatus::Regular), // arl
+    (7107170, IdStatus::Regular), // brl
+    (7107171, IdStatus::Regular), // crl
+    (7107172, IdStatus::Regular), // drl
+    (7107179, IdStatus::Regular), // krl
+    (7107180, IdStatus::Regular), // lrl
+    (7107181, IdStatus::Regular), // mrl
+    (7107182, IdStatus::Regular), // nrl
+    (7107184, IdStatus::Regular), // prl
+    (7107185, IdStatus::PrivateUse), // qrl
+    (7107187, IdStatus::Regular), // srl
+    (7107188, IdStatus::Regular), // trl
+    (7107189, IdStatus::Regular), // url
+    (7107191, IdStatus::Regular), // wrl
+    (7107193, IdStatus::Regular), // yrl
+    (7107425, IdStatus::Regular), // asl
+    (7107426, IdStatus::Regular), // bsl
+    (7107427, IdStatus::Regular), // csl
+    (7107428, IdStatus::Regular), // dsl
+    (7107429, IdStatus::Regular), // esl
+    (7107430, IdStatus::Regular), // fsl
+    (7107431, IdStatus::Regular), // gsl
+    (7107432, IdStatus::Regular), // hsl
+    (7107434, IdStatus::Regular), // jsl
+    (7107435, IdStatus::Regular), // ksl
+    (7107436, IdStatus::Regular), // lsl
+    (7107437, IdStatus::Regular), // msl
+    (7107438, IdStatus::Regular), // nsl
+    (7107440, IdStatus::Regular), // psl
+    (7107441, IdStatus::PrivateUse), // qsl
+    (7107442, IdStatus::Regular), // rsl
+    (7107443, IdStatus::Regular), // ssl
+    (7107444, IdStatus::Regular), // tsl
+    (7107446, IdStatus::Regular), // vsl
+    (7107448, IdStatus::Deprecated), // xsl
+    (7107449, IdStatus::Regular), // ysl
+    (7107450, IdStatus::Regular), // zsl
+    (7107681, IdStatus::Regular), // atl
+    (7107682, IdStatus::Deprecated), // btl
+    (7107683, IdStatus::Regular), // ctl
+    (7107689, IdStatus::Regular), // itl
+    (7107691, IdStatus::Regular), // ktl
+    (7107693, IdStatus::Regular), // mtl
+    (7107695, IdStatus::Regular), // otl
+    (7107697, IdStatus::PrivateUse), // qtl
+    (7107699, IdStatus::Regular), // stl
+    (7107700, IdStatus::Regular), // ttl
+    (7107704, IdStatus::Regular), // xtl
+    (7107705, IdStatus::Regular), // ytl
+    (7107706, IdStatus::Regular), // ztl
+    (7107937, IdStatus::Regular), // aul
+    (7107939, IdStatus::Regular), // cul
+    (7107940, IdStatus::Regular), // dul
+    (7107943, IdStatus::Regular), // gul
+    (7107944, IdStatus::Regular), // hul
+    (7107946, IdStatus::Regular), // jul
+    (7107947, IdStatus::Regular), // kul
+    (7107948, IdStatus::Regular), // lul
+    (7107949, IdStatus::Special), // mul
+    (7107950, IdStatus::Regular), // nul
+    (7107953, IdStatus::Regular), // qul
+    (7107955, IdStatus::Deprecated), // sul
+    (7107956, IdStatus::Regular), // tul
+    (7107959, IdStatus::Regular), // wul
+    (7107960, IdStatus::Regular), // xul
+    (7107961, IdStatus::Regular), // yul
+    (7108193, IdStatus::Regular), // avl
+    (7108194, IdStatus::Regular), // bvl
+    (7108199, IdStatus::Regular), // gvl
+    (7108203, IdStatus::Regular), // kvl
+    (7108205, IdStatus::Regular), // mvl
+    (7108209, IdStatus::Regular), // qvl
+    (7108212, IdStatus::Regular), // tvl
+    (7108213, IdStatus::Regular), // uvl
+    (7108450, IdStatus::Regular), // bwl
+    (7108452, IdStatus::Deprecated), // dwl
+    (7108459, IdStatus::Regular), // kwl
+    (7108460, IdStatus::Regular), // lwl
+    (7108461, IdStatus::Regular), // mwl
+    (7108463, IdStatus::Regular), // owl
+    (7108466, IdStatus::Regular), // rwl
+    (7108467, IdStatus::Regular), // swl
+    (7108468, IdStatus::Regular), // twl
+    (7108472, IdStatus::Regular), // xwl
+    (7108473, IdStatus::Regular), // ywl
+    (7108705, IdStatus::Regular), // axl
+    (7108706, IdStatus::Regular), // bxl
+    (7108713, IdStatus::Regular), // ixl
+    (7108715, IdStatus::Deprecated), // kxl
+    (7108717, IdStatus::Regular), // mxl
+    (7108718, IdStatus::Regular), // nxl
+    (7108721, IdStatus::Regular), // qxl
+    (7108723, IdStatus::Regular), // sxl
+    (7108729, IdStatus::Regular), // yxl
+    (7108961, IdStatus::Regular), // ayl
+    (7108962, IdStatus::Regular), // byl
+    (7108967, IdStatus::Regular), // gyl
+    (7108971, IdStatus::Regular), // kyl
+    (7108973, IdStatus::Regular), // myl
+    (7108974, IdStatus::Regular), // nyl
+    (7108979, IdStatus::Regular), // syl
+    (7108980, IdStatus::Regular), // tyl
+    (7108984, IdStatus::Regular), // xyl
+    (7109218, IdStatus::Regular), // bzl
+    (7109220, IdStatus::Regular), // dzl
+    (7109227, IdStatus::Regular), // kzl
+    (7109228, IdStatus::Regular), // lzl
+    (7109229, IdStatus::Regular), // mzl
+    (7109235, IdStatus::Regular), // szl
+    (7109236, IdStatus::Regular), // tzl
+    (7168353, IdStatus::Deprecated), // aam
+    (7168355, IdStatus::Regular), // cam
+    (7168356, IdStatus::Regular), // dam
+    (7168358, IdStatus::Regular), // fam
+    (7168359, IdStatus::Regular), // gam
+    (7168360, IdStatus::Regular), // ham
+    (7168362, IdStatus::Regular), // jam
+    (7168363, IdStatus::Regular), // kam
+    (7168364, IdStatus::Regular), // lam
+    (7168365, IdStatus::Regular), // mam
+    (7168366, IdStatus::Regular), // nam
+    (7168368, IdStatus::Regular), // pam
+    (7168369, IdStatus::Reserved), // qam
+    (7168370, IdStatus::Regular), // ram
+    (7168371, IdStatus::Regular), // sam
+    (7168373, IdStatus::Regular), // uam
+    (7168374, IdStatus::Regular), // vam
+    (7168375, IdStatus::Regular), // wam
+    (7168376, IdStatus::Regular), // xam
+    (7168377, IdStatus::Regular), // yam
+    (7168378, IdStatus::Regular), // zam
+    (7168609, IdStatus::Regular), // abm
+    (7168610, IdStatus::Regular), // bbm
+    (7168612, IdStatus::Regular), // dbm
+    (7168615, IdStatus::Regular), // gbm
+    (7168617, IdStatus::Regular), // ibm
+    (7168618, IdStatus::Regular), // jbm
+    (7168619, IdStatus::Regular), // kbm
+    (7168620, IdStatus::Regular), // lbm
+    (7168621, IdStatus::Regular), // mbm
+    (7168622, IdStatus::Regular), // nbm
+    (7168623, IdStatus::Regular), // obm
+    (7168624, IdStatus::Regular), // pbm
+    (7168625, IdStatus::Reserved), // qbm
+    (7168627, IdStatus::Regular), // sbm
+    (7168628, IdStatus::Regular), // tbm
+    (7168631, IdStatus::Regular), // wbm
+    (7168632, IdStatus::Regular), // xbm
+    (7168633, IdStatus::Regular), // ybm
+    (7168865, IdStatus::Regular), // acm
+    (7168866, IdStatus::Regular), // bcm
+    (7168867, IdStatus::Regular), // ccm
+    (7168875, IdStatus::Regular), // kcm
+    (7168876, IdStatus::Regular), // lcm
+    (7168877, IdStatus::Regular), // mcm
+    (7168878, IdStatus::Regular), // ncm
+    (7168879, IdStatus::Regular), // ocm
+    (7168880, IdStatus::Regular), // pcm
+    (7168881, IdStatus::Reserved), // qcm
+    (7168884, IdStatus::Regular), // tcm
+    (7168888, IdStatus::Regular), // xcm
+    (7169122, IdStatus::Regular), // bdm
+    (7169123, IdStatus::Regular), // cdm
+    (7169127, IdStatus::Regular), // gdm
+    (7169131, IdStatus::Regular), // kdm
+    (7169132, IdStatus::Regular), // ldm
+    (7169133, IdStatus::Regular), // mdm
+    (7169134, IdStatus::Regular), // ndm
+    (7169137, IdStatus::Reserved), // qdm
+    (7169139, IdStatus::Deprecated), // sdm
+    (7169140, IdStatus::Regular), // tdm
+    (7169141, IdStatus::Regular), // udm
+    (7169144, IdStatus::Regular), // xdm
+    (7169377, IdStatus::Regular), // aem
+    (7169378, IdStatus::Regular), // bem
+    (7169380, IdStatus::Regular), // dem
+    (7169383, IdStatus::Regular), // gem
+    (7169384, IdStatus::Regular), // hem
+    (7169387, IdStatus::Regular), // kem
+    (7169388, IdStatus::Regular), // lem
+    (7169389, IdStatus::Regular), // mem
+    (7169390, IdStatus::Regular), // nem
+    (7169392, IdStatus::Regular), // pem
+    (7169393, IdStatus::Reserved), // qem
+    (7169394, IdStatus::Regular), // rem
+    (7169395, IdStatus::Regular), // sem
+    (7169396, IdStatus::Regular), // tem
+    (7169398, IdStatus::Regular), // vem
+    (7169399, IdStatus::Regular), // wem
+    (7169400, IdStatus::Regular), // xem
+    (7169634, IdStatus::Regular), // bfm
+    (7169635, IdStatus::Regular), // cfm
+    (7169638, IdStatus::Regular), // ffm
+    (7169641, IdStatus::Regular), // ifm
+    (7169643, IdStatus::Regular), // kfm
+    (7169645, IdStatus::Regular), // mfm
+    (7169649, IdStatus::Reserved), // qfm
+    (7169651, IdStatus::Regular), // sfm
+    (7169889, IdStatus::Regular), // agm
+    (7169890, IdStatus::Deprecated), // bgm
+    (7169893, IdStatus::Regular), // egm
+    (7169896, IdStatus::Regular), // hgm
+    (7169897, IdStatus::Regular), // igm
+    (7169899, IdStatus::Regular), // kgm
+    (7169900, IdStatus::Regular), // lgm
+    (7169901, IdStatus::Regular), // mgm
+    (7169902, IdStatus::Regular), // ngm
+    (7169905, IdStatus::PrivateUse), // qgm
+    (7169907, IdStatus::Regular), // sgm
+    (7169912, IdStatus::Regular), // xgm
+    (7169913, IdStatus::Regular), // ygm
+    (7169914, IdStatus::Regular), // zgm
+    (7170145, IdStatus::Regular), // ahm
+    (7170146, IdStatus::Regular), // bhm
+    (7170147, IdStatus::Regular), // chm
+    (7170148, IdStatus::Regular), // dhm
+    (7170156, IdStatus::Regular), // lhm
+    (7170157, IdStatus::Regular), // mhm
+    (7170158, IdStatus::Regular), // nhm
+    (7170160, IdStatus::Regular), // phm
+    (7170161, IdStatus::PrivateUse), // qhm
+    (7170163, IdStatus::Regular), // shm
+    (7170164, IdStatus::Regular), // thm
+    (7170168, IdStatus::Regular), // xhm
+    (7170401, IdStatus::Regular), // aim
+    (7170402, IdStatus::Regular), // bim
+    (7170403, IdStatus::Regular), // cim
+    (7170404, IdStatus::Regular), // dim
+    (7170407, IdStatus::Regular), // gim
+    (7170408, IdStatus::Deprecated), // him
+    (7170410, IdStatus::Regular), // jim
+    (7170411, IdStatus::Regular), // kim
+    (7170413, IdStatus::Regular), // mim
+    (7170414, IdStatus::Regular), // nim
+    (7170416, IdStatus::Regular), // pim
+    (7170417, IdStatus::PrivateUse), // qim
+    (7170418, IdStatus::Regular), // rim
+    (7170419, IdStatus::Regular), // sim
+    (7170420, IdStatus::Regular), // tim
+    (7170423, IdStatus::Regular), // wim
+    (7170425, IdStatus::Regular), // yim
+    (7170426, IdStatus::Regular), // zim
+    (7170658, IdStatus::Regular), // bjm
+    (7170659, IdStatus::Regular), // cjm
+    (7170660, IdStatus::Regular), // djm
+    (7170663, IdStatus::Regular), // gjm
+    (7170667, IdStatus::Regular), // kjm
+    (7170669, IdStatus::Regular), // mjm
+    (7170670, IdStatus::Regular), // njm
+    (7170673, IdStatus::PrivateUse), // qjm
+    (7170675, IdStatus::Regular), // sjm
+    (7170676, IdStatus::Regular), // tjm
+    (7170913, IdStatus::Regular), // akm
+    (7170914, IdStatus::Regular), // bkm
+    (7170915, IdStatus::Regular), // ckm
+    (7170917, IdStatus::Regular), // ekm
+    (7170922, IdStatus::Regular), // jkm
+    (7170923, IdStatus::Regular), // kkm
+    (7170924, IdStatus::Regular), // lkm
+    (7170925, IdStatus::Regular), // mkm
+    (7170926, IdStatus::Regular), // nkm
+    (7170927, IdStatus::Regular), // okm
+    (7170929, IdStatus::PrivateUse), // qkm
+    (7170930, IdStatus::Regular), // rkm
+    (7170931, IdStatus::Regular), // skm
+    (7170932, IdStatus::Regular), // tkm
+    (7170934, IdStatus::Regular), // vkm
+    (7170937, IdStatus::Regular), // ykm
+    (7171169, IdStatus::Regular), // alm
+    (7171170, IdStatus::Regular), // blm
+    (7171171, IdStatus::Regular), // clm
+    (7171172, IdStatus::Regular), // dlm
+    (7171173, IdStatus::Regular), // elm
+    (7171177, IdStatus::Regular), // ilm
+    (7171179, IdStatus::Regular), // klm
+    (7171180, IdStatus::Regular), // llm
+    (7171181, IdStatus::Regular), // mlm
+    (7171182, IdStatus::Regular), // nlm
+    (7171183, IdStatus::Regular), // olm
+    (7171185, IdStatus::PrivateUse), // qlm
+    (7171187, IdStatus::Regular), // slm
+    (7171188, IdStatus::Regular), // tlm
+    (7171189, IdStatus::Regular), // ulm
+    (7171191, IdStatus::Regular), // wlm
+    (7171193, IdStatus::Regular), // ylm
+    (7171194, IdStatus::Regular), // zlm
+    (7171425, IdStatus::Regular), // amm
+    (7171426, IdStatus::Regular), // bmm
+    (7171427, IdStatus::Regular), // cmm
+    (7171428, IdStatus::Regular), // dmm
+    (7171429, IdStatus::Regular), // emm
+    (7171431, IdStatus::Regular), // gmm
+    (7171432, IdStatus::Regular), // hmm
+    (7171435, IdStatus::Regular), // kmm
+    (7171436, IdStatus::Deprecated), // lmm
+    (7171437, IdStatus::Regular), // mmm
+    (7171438, IdStatus::Regular), // nmm
+    (7171440, IdStatus::Regular), // pmm
+    (7171441, IdStatus::PrivateUse), // qmm
+    (7171442, IdStatus::Regular), // rmm
+    (7171443, IdStatus::Regular), // smm
+    (7171444, IdStatus::Regular), // tmm
+    (7171445, IdStatus::Regular), // umm
+    (7171446, IdStatus::Regular), // vmm
+    (7171447, IdStatus::Regular), // wmm
+    (7171448, IdStatus::Regular), // xmm
+    (7171449, IdStatus::Regular), // ymm
+    (7171450, IdStatus::Regular), // zmm
+    (7171681, IdStatus::Regular), // anm
+    (7171682, IdStatus::Regular), // bnm
+    (7171685, IdStatus::Regular), // enm
+    (7171687, IdStatus::Regular), // gnm
+    (7171689, IdStatus::Regular), // inm
+    (7171691, IdStatus::Regular), // knm
+    (7171692, IdStatus::Regular), // lnm
+    (7171693, IdStatus::Regular), // mnm
+    (7171694, IdStatus::Regular), // nnm
+    (7171696, IdStatus::Regular), // pnm
+    (7171697, IdStatus::PrivateUse), // qnm
+    (7171699, IdStatus::Regular), // snm
+    (7171700, IdStatus::Regular), // tnm
+    (7171701, IdStatus::Regular), // unm
+    (7171702, IdStatus::Regular), // vnm
+    (7171703, IdStatus::Regular), // wnm
+    (7171704, IdStatus::Regular), // xnm
+    (7171937, IdStatus::Regular), // aom
+    (7171938, IdStatus::Regular), // bom
+    (7171939, IdStatus::Regular), // com
+    (7171942, IdStatus::Regular), // fom
+    (7171943, IdStatus::Regular), // gom
+    (7171944, IdStatus::Regular), // hom
+    (7171948, IdStatus::Regular), // lom
+    (7171949, IdStatus::Regular), // mom
+    (7171950, IdStatus::Regular), // nom
+    (7171952, IdStatus::Regular), // pom
+    (7171953, IdStatus::PrivateUse), // qom
+    (7171954, IdStatus::Regular), // rom
+    (7171956, IdStatus::Regular), // tom
+    (7171959, IdStatus::Regular), // wom
+    (7171960, IdStatus::Regular), // xom
+    (7171961, IdStatus::Regular), // yom
+    (7171962, IdStatus::Regular), // zom
+    (7172193, IdStatus::Regular), // apm
+    (7172194, IdStatus::Regular), // bpm
+    (7172203, IdStatus::Regular), // kpm
+    (7172205, IdStatus::Regular), // mpm
+    (7172207, IdStatus::Regular), // opm
+    (7172208, IdStatus::Regular), // ppm
+    (7172209, IdStatus::PrivateUse), // qpm
+    (7172211, IdStatus::Regular), // spm
+    (7172212, IdStatus::Regular), // tpm
+    (7172216, IdStatus::Regular), // xpm
+    (7172217, IdStatus::Regular), // ypm
+    (7172218, IdStatus::Regular), // zpm
+    (7172449, IdStatus::Regular), // aqm
+    (7172450, IdStatus::Regular), // bqm
+    (7172459, IdStatus::Regular), // kqm
+    (7172461, IdStatus::Regular), // mqm
+    (7172462, IdStatus::Regular), // nqm
+    (7172464, IdStatus::Regular), // pqm
+    (7172465, IdStatus::PrivateUse), // qqm
+    (7172467, IdStatus::Regular), // sqm
+    (7172468, IdStatus::Regular), // tqm
+    (7172706, IdStatus::Regular), // brm
+    (7172707, IdStatus::Regular), // crm
+    (7172710, IdStatus::Regular), // frm
+    (7172711, IdStatus::Regular), // grm
+    (7172712, IdStatus::Regular), // hrm
+    (7172715, IdStatus::Deprecated), // krm
+    (7172716, IdStatus::Regular), // lrm
+    (7172717, IdStatus::Regular), // mrm
+    (7172718, IdStatus::Regular), // nrm
+    (7172720, IdStatus::Regular), // prm
+    (7172721, IdStatus::PrivateUse), // qrm
+    (7172723, IdStatus::Regular), // srm
+    (7172724, IdStatus::Regular), // trm
+    (7172725, IdStatus::Regular), // urm
+    (7172727, IdStatus::Regular), // wrm
+    (7172728, IdStatus::Regular), // xrm
+    (7172729, IdStatus::Regular), // yrm
+    (7172962, IdStatus::Regular), // bsm
+    (7172963, IdStatus::Regular), // csm
+    (7172965, IdStatus::Regular), // esm
+    (7172967, IdStatus::Regular), // gsm
+    (7172969, IdStatus::Regular), // ism
+    (7172971, IdStatus::Regular), // ksm
+    (7172972, IdStatus::Regular), // lsm
+    (7172973, IdStatus::Regular), // msm
+    (7172974, IdStatus::Regular), // nsm
+    (7172976, IdStatus::Regular), // psm
+    (7172977, IdStatus::PrivateUse), // qsm
+    (7172978, IdStatus::Regular), // rsm
+    (7172979, IdStatus::Regular), // ssm
+    (7172980, IdStatus::Regular), // tsm
+    (7172984, IdStatus::Regular), // xsm
+    (7172985, IdStatus::Regular), // ysm
+    (7172986, IdStatus::Deprecated), // zsm
+    (7173217, IdStatus::Regular), // atm
+    (7173218, IdStatus::Regular), // btm
+    (7173219, IdStatus::Regular), // ctm
+    (7173220, IdStatus::Regular), // dtm
+    (7173225, IdStatus::Regular), // itm
+    (7173227, IdStatus::Regular), // ktm
+    (7173229, IdStatus::Regular), // mtm
+    (7173230, IdStatus::Regular), // ntm
+    (7173231, IdStatus::Regular), // otm
+    (7173233, IdStatus::PrivateUse), // qtm
+    (7173234, IdStatus::Regular), // rtm
+    (7173235, IdStatus::Regular), // stm
+    (7173236, IdStatus::Regular), // ttm
+    (7173239, IdStatus::Regular), // wtm
+    (7173240, IdStatus::Regular), // xtm
+    (7173242, IdStatus::Regular), // ztm
+    (7173473, IdStatus::Regular), // aum
+    (7173474, IdStatus::Regular), // bum
+    (7173475, IdStatus::Deprecated), // cum
+    (7173476, IdStatus::Regular), // dum
+    (7173478, IdStatus::Regular), // fum
+    (7173479, IdStatus::Regular), // gum
+    (7173480, IdStatus::Regular), // hum
+    (7173481, IdStatus::Regular), // ium
+    (7173482, IdStatus::Regular), // jum
+    (7173483, IdStatus::Regular), // kum
+    (7173484, IdStatus::Regular), // lum
+    (7173485, IdStatus::Regular), // mum
+    (7173486, IdStatus::Regular), // num
+    (7173487, IdStatus::Regular), // oum
+    (7173488, IdStatus::Regular), // pum
+    (7173489, IdStatus::Regular), // qum
+    (7173491, IdStatus::Deprecated), // sum
+    (7173492, IdStatus::Regular), // tum
+    (7173493, IdStatus::Regular), // uum
+    (7173494, IdStatus::Regular), // vum
+    (7173495, IdStatus::Regular), // wum
+    (7173496, IdStatus::Regular), // xum
+    (7173497, IdStatus::Regular), // yum
+    (7173498, IdStatus::Regular), // zum
+    (7173729, IdStatus::Regular), // avm
+    (7173730, IdStatus::Regular), // bvm
+    (7173735, IdStatus::Regular), // gvm
+    (7173739, IdStatus::Regular), // kvm
+    (7173741, IdStatus::Deprecated), // mvm
+    (7173742, IdStatus::Regular), // nvm
+    (7173745, IdStatus::Regular), // qvm
+    (7173747, IdStatus::Regular), // svm
+    (7173748, IdStatus::Regular), // tvm
+    (7173985, IdStatus::Regular), // awm
+    (7173986, IdStatus::Regular), // bwm
+    (7173991, IdStatus::Regular), // gwm
+    (7173993, IdStatus::Regular), // iwm
+    (7173995, IdStatus::Regular), // kwm
+    (7173996, IdStatus::Regular), // lwm
+    (7173997, IdStatus::Regular), // mwm
+    (7173998, IdStatus::Regular), // nwm
+    (7174000, IdStatus::Regular), // pwm
+    (7174001, IdStatus::Regular), // qwm
+    (7174002, IdStatus::Regular), // rwm
+    (7174003, IdStatus::Regular), // swm
+    (7174004, IdStatus::Regular), // twm
+    (7174241, IdStatus::Regular), // axm
+    (7174242, IdStatus::Regular), // bxm
+    (7174251, IdStatus::Regular), // kxm
+    (7174252, IdStatus::Regular), // lxm
+    (7174253, IdStatus::Regular), // mxm
+    (7174254, IdStatus::Regular), // nxm
+    (7174256, IdStatus::Regular), // pxm
+    (7174259, IdStatus::Regular), // sxm
+    (7174260, IdStatus::Regular), // txm
+    (7174264, IdStatus::Regular), // xxm
+    (7174265, IdStatus::Regular), // yxm
+    (7174498, IdStatus::Regular), // bym
+    (7174500, IdStatus::Regular), // dym
+    (7174503, IdStatus::Regular), // gym
+    (7174507, IdStatus::Regular), // kym
+    (7174509, IdStatus::Regular), // mym
+    (7174510, IdStatus::Regular), // nym
+    (7174511, IdStatus::Regular), // oym
+    (7174512, IdStatus::Regular), // pym
+    (7174515, IdStatus::Regular), // sym
+    (7174519, IdStatus::Regular), // wym
+    (7174753, IdStatus::Regular), // azm
+    (7174754, IdStatus::Regular), // bzm
+    (7174763, IdStatus::Regular), // kzm
+    (7174765, IdStatus::Regular), // mzm
+    (7174766, IdStatus::Regular), // nzm
+    (7174767, IdStatus::Regular), // ozm
+    (7174772, IdStatus::Regular), // tzm
+    (7174776, IdStatus::Regular), // xzm
+    (7233889, IdStatus::Regular), // aan
+    (7233890, IdStatus::Regular), // ban
+    (7233891, IdStatus::Regular), // can
+    (7233894, IdStatus::Regular), // fan
+    (7233895, IdStatus::Regular), // gan
+    (7233896, IdStatus::Regular), // han
+    (7233897, IdStatus::Regular), // ian
+    (7233898, IdStatus::Regular), // jan
+    (7233900, IdStatus::Regular), // lan
+    (7233901, IdStatus::Regular), // man
+    (7233902, IdStatus::Regular), // nan
+    (7233905, IdStatus::Reserved), // qan
+    (7233906, IdStatus::Regular), // ran
+    (7233908, IdStatus::Regular), // tan
+    (7233909, IdStatus::Regular), // uan
+    (7233910, IdStatus::Regular), // van
+    (7233911, IdStatus::Regular), // wan
+    (7233912, IdStatus::Regular), // xan
+    (7233913, IdStatus::Regular), // yan
+    (7234145, IdStatus::Regular), // abn
+    (7234146, IdStatus::Regular), // bbn
+    (7234147, IdStatus::Regular), // cbn
+    (7234148, IdStatus::Regular), // dbn
+    (7234151, IdStatus::Regular), // gbn
+    (7234152, IdStatus::Regular), // hbn
+    (7234153, IdStatus::Regular), // ibn
+    (7234154, IdStatus::Regular), // jbn
+    (7234155, IdStatus::Regular), // kbn
+    (7234156, IdStatus::Regular), // lbn
+    (7234157, IdStatus::Regular), // mbn
+    (7234158, IdStatus::Regular), // nbn
+    (7234160, IdStatus::Regular), // pbn
+    (7234161, IdStatus::Reserved), // qbn
+    (7234163, IdStatus::Regular), // sbn
+    (7234164, IdStatus::Regular), // tbn
+    (7234168, IdStatus::Regular), // xbn
+    (7234169, IdStatus::Regular), // ybn
+    (7234401, IdStatus::Regular), // acn
+    (7234402, IdStatus::Regular), // bcn
+    (7234403, IdStatus::Regular), // ccn
+    (7234407, IdStatus::Regular), // gcn
+    (7234411, IdStatus::Regular), // kcn
+    (7234413, IdStatus::Regular), // mcn
+    (7234414, IdStatus::Regular), // ncn
+    (7234416, IdStatus::Regular), // pcn
+    (7234417, IdStatus::Reserved), // qcn
+    (7234419, IdStatus::Regular), // scn
+    (7234420, IdStatus::Regular), // tcn
+    (7234424, IdStatus::Regular), // xcn
+    (7234425, IdStatus::Regular), // ycn
+    (7234657, IdStatus::Regular), // adn
+    (7234658, IdStatus::Regular), // bdn
+    (7234659, IdStatus::Regular), // cdn
+    (7234660, IdStatus::Regular), // ddn
+    (7234663, IdStatus::Regular), // gdn
+    (7234664, IdStatus::Deprecated), // hdn
+    (7234667, IdStatus::Regular), // kdn
+    (7234668, IdStatus::Regular), // ldn
+    (7234669, IdStatus::Regular), // mdn
+    (7234670, IdStatus::Regular), // ndn
+    (7234672, IdStatus::Regular), // pdn
+    (7234673, IdStatus::Reserved), // qdn
+    (7234675, IdStatus::Regular), // sdn
+    (7234676, IdStatus::Regular), // tdn
+    (7234913, IdStatus::Regular), // aen
+    (7234915, IdStatus::Regular), // cen
+    (7234916, IdStatus::Regular), // den
+    (7234922, IdStatus::Regular), // jen
+    (7234923, IdStatus::Regular), // ken
+    (7234924, IdStatus::Regular), // len
+    (7234925, IdStatus::Regular), // men
+    (7234926, IdStatus::Regular), // nen
+    (7234929, IdStatus::Reserved), // qen
+    (7234930, IdStatus::Regular), // ren
+    (7234931, IdStatus::Regular), // sen
+    (7234932, IdStatus::Regular), // ten
+    (7234935, IdStatus::Regular), // wen
+    (7234937, IdStatus::Deprecated), // yen
+    (7234938, IdStatus::Regular), // zen
+    (7235169, IdStatus::Regular), // afn
+    (7235170, IdStatus::Regular), // bfn
+    (7235179, IdStatus::Regular), // kfn
+    (7235180, IdStatus::Regular), // lfn
+    (7235181, IdStatus::Regular), // mfn
+    (7235185, IdStatus::Reserved), // qfn
+    (7235188, IdStatus::Regular), // tfn
+    (7235425, IdStatus::Regular), // agn
+    (7235426, IdStatus::Regular), // bgn
+    (7235428, IdStatus::Regular), // dgn
+    (7235431, IdStatus::Deprecated), // ggn
+    (7235433, IdStatus::Regular), // ign
+    (7235435, IdStatus::Regular), // kgn
+    (7235436, IdStatus::Regular), // lgn
+    (7235437, IdStatus::Regular), // mgn
+    (7235438, IdStatus::Regular), // ngn
+    (7235440, IdStatus::Regular), // pgn
+    (7235441, IdStatus::PrivateUse), // qgn
+    (7235442, IdStatus::Regular), // rgn
+    (7235443, IdStatus::Regular), // sgn
+    (7235444, IdStatus::Regular), // tgn
+    (7235445, IdStatus::Regular), // ugn
+    (7235448, IdStatus::Regular), // xgn
+    (7235450, IdStatus::Regular), // zgn
+    (7235681, IdStatus::Regular), // ahn
+    (7235682, IdStatus::Regular), // bhn
+    (7235683, IdStatus::Regular), // chn
+    (7235684, IdStatus::Regular), // dhn
+    (7235687, IdStatus::Regular), // ghn
+    (7235691, IdStatus::Regular), // khn
+    (7235692, IdStatus::Regular), // lhn
+    (7235693, IdStatus::Regular), // mhn
+    (7235694, IdStatus::Regular), // nhn
+    (7235696, IdStatus::Regular), // phn
+    (7235697, IdStatus::PrivateUse), // qhn
+    (7235699, IdStatus::Regular), // shn
+    (7235700, IdStatus::Regular), // thn
+    (7235701, IdStatus::Regular), // uhn
+    (7235706, IdStatus::Regular), // zhn
+    (7235937, IdStatus::Regular), // ain
+    (7235938, IdStatus::Regular), // bin
+    (7235939, IdStatus::Regular), // cin
+    (7235940, IdStatus::Regular), // din
+    (7235943, IdStatus::Regular), // gin
+    (7235945, IdStatus::Regular), // iin
+    (7235949, IdStatus::Regular), // min
+    (7235950, IdStatus::Regular), // nin
+    (7235951, IdStatus::Regular), // oin
+    (7235952, IdStatus::Regular), // pin
+    (7235953, IdStatus::PrivateUse), // qin
+    (7235954, IdStatus::Regular), // rin
+    (7235956, IdStatus::Regular), // tin
+    (7235958, IdStatus::Regular), // vin
+    (7235959, IdStatus::Regular), // win
+    (7235960, IdStatus::Regular), // xin
+    (7235961, IdStatus::Regular), // yin
+    (7235962, IdStatus::Regular), // zin
+    (7236193, IdStatus::Regular), // ajn
+    (7236194, IdStatus::Regular), // bjn
+    (7236195, IdStatus::Regular), // cjn
+    (7236196, IdStatus::Regular), // djn
+    (7236199, IdStatus::Regular), // gjn
+    (7236201, IdStatus::Regular), // ijn
+    (7236203, IdStatus::Regular), // kjn
+    (7236205, IdStatus::Regular), // mjn
+    (7236206, IdStatus::Regular), // njn
+    (7236209, IdStatus::PrivateUse), // qjn
+    (7236211, IdStatus::Regular), // sjn
+    (7236212, IdStatus::Regular), // tjn
+    (7236450, IdStatus::Regular), // bkn
+    (7236451, IdStatus::Regular), // ckn
+    (7236455, IdStatus::Regular), // gkn
+    (7236456, IdStatus::Regular), // hkn
+    (7236459, IdStatus::Regular), // kkn
+    (7236460, IdStatus::Regular), // lkn
+    (7236461, IdStatus::Regular), // mkn
+    (7236462, IdStatus::Regular), // nkn
+    (7236463, IdStatus::Regular), // okn
+    (7236464, IdStatus::Regular), // pkn
+    (7236465, IdStatus::PrivateUse), // qkn
+    (7236467, IdStatus::Regular), // skn
+    (7236468, IdStatus::Regular), // tkn
+    (7236470, IdStatus::Regular), // vkn
+    (7236472, IdStatus::Regular), // xkn
+    (7236473, IdStatus::Regular), // ykn
+    (7236474, IdStatus::Regular), // zkn
+    (7236705, IdStatus::Regular), // aln
+    (7236706, IdStatus::Regular), // bln
+    (7236708, IdStatus::Regular), // dln
+    (7236710, IdStatus::Regular), // fln
+    (7236715, IdStatus::Regular), // kln
+    (7236716, IdStatus::Regular), // lln
+    (7236717, IdStatus::Regular), // mln
+    (7236718, IdStatus::Deprecated), // nln
+    (7236720, IdStatus::Regular), // pln
+    (7236721, IdStatus::PrivateUse), // qln
+    (7236723, IdStatus::Regular), // sln
+    (7236724, IdStatus::Regular), // tln
+    (7236725, IdStatus::Regular), // uln
+    (7236728, IdStatus::Regular), // xln
+    (7236729, IdStatus::Regular), // yln
+    (7236730, IdStatus::Regular), // zln
+    (7236961, IdStatus::Regular), // amn
+    (7236962, IdStatus::Regular), // bmn
+    (7236963, IdStatus::Deprecated), // cmn
+    (7236964, IdStatus::Regular), // dmn
+    (7236965, IdStatus::Regular), // emn
+    (7236967, IdStatus::Regular), // gmn
+    (7236968, IdStatus::Regular), // hmn
+    (7236969, IdStatus::Regular), // imn
+    (7236970, IdStatus::Regular), // jmn
+    (7236971, IdStatus::Regular), // kmn
+    (7236972, IdStatus::Regular), // lmn
+    (7236973, IdStatus::Regular), // mmn
+    (7236974, IdStatus::Regular), // nmn
+    (7236975, IdStatus::Regular), // omn
+    (7236976, IdStatus::Regular), // pmn
+    (7236977, IdStatus::PrivateUse), // qmn
+    (7236978, IdStatus::Regular), // rmn
+    (7236979, IdStatus::Regular), // smn
+    (7236980, IdStatus::Regular), // tmn
+    (7236981, IdStatus::Regular), // umn
+    (7236983, IdStatus::Regular), // wmn
+    (7236984, IdStatus::Regular), // xmn
+    (7236985, IdStatus::Regular), // ymn
+    (7236986, IdStatus::Regular), // zmn
+    (7237217, IdStatus::Regular), // ann
+    (7237218, IdStatus::Regular), // bnn
+    (7237220, IdStatus::Regular), // dnn
+    (7237221, IdStatus::Regular), // enn
+    (7237223, IdStatus::Regular), // gnn
+    (7237224, IdStatus::Regular), // hnn
+    (7237225, IdStatus::Regular), // inn
+    (7237227, IdStatus::Deprecated), // knn
+    (7237228, IdStatus::Regular), // lnn
+    (7237229, IdStatus::Regular), // mnn
+    (7237230, IdStatus::Regular), // nnn
+    (7237231, IdStatus::Regular), // onn
+    (7237232, IdStatus::Regular), // pnn
+    (7237233, IdStatus::PrivateUse), // qnn
+    (7237234, IdStatus::Regular), // rnn
+    (7237235, IdStatus::Regular), // snn
+    (7237236, IdStatus::Regular), // tnn
+    (7237237, IdStatus::Regular), // unn
+    (7237239, IdStatus::Regular), // wnn
+    (7237240, IdStatus::Regular), // xnn
+    (7237241, IdStatus::Regular), // ynn
+    (7237473, IdStatus::Regular), // aon
+    (7237474, IdStatus::Regular), // bon
+    (7237475, IdStatus::Regular), // con
+    (7237476, IdStatus::Regular), // don
+    (7237478, IdStatus::Regular), // fon
+    (7237479, IdStatus::Regular), // gon
+    (7237484, IdStatus::Regular), // lon
+    (7237486, IdStatus::Regular), // non
+    (7237487, IdStatus::Regular), // oon
+    (7237488, IdStatus::Regular), // pon
+    (7237489, IdStatus::PrivateUse), // qon
+    (7237491, IdStatus::Regular), // son
+    (7237493, IdStatus::Regular), // uon
+    (7237495, IdStatus::Regular), // won
+    (7237496, IdStatus::Regular), // xon
+    (7237497, IdStatus::Regular), // yon
+    (7237729, IdStatus::Regular), // apn
+    (7237730, IdStatus::Regular), // bpn
+    (7237731, IdStatus::Regular), // cpn
+    (7237735, IdStatus::Regular), // gpn
+    (7237739, IdStatus::Regular), // kpn
+    (7237740, IdStatus::Regular), // lpn
+    (7237741, IdStatus::Regular), // mpn
+    (7237742, IdStatus::Regular), // npn
+    (7237744, IdStatus::Regular), // ppn
+    (7237745, IdStatus::PrivateUse), // qpn
+    (7237746, IdStatus::Regular), // rpn
+    (7237747, IdStatus::Regular), // spn
+    (7237748, IdStatus::Regular), // tpn
+    (7237752, IdStatus::Regular), // xpn
+    (7237753, IdStatus::Regular), // ypn
+    (7237754, IdStatus::Regular), // zpn
+    (7237985, IdStatus::Regular), // aqn
+    (7237986, IdStatus::Regular), // bqn
+    (7237991, IdStatus::Regular), // gqn
+    (7237995, IdStatus::Regular), // kqn
+    (7237997, IdStatus::Regular), // mqn
+    (7237998, IdStatus::Regular), // nqn
+    (7238001, IdStatus::PrivateUse), // qqn
+    (7238003, IdStatus::Regular), // sqn
+    (7238004, IdStatus::Regular), // tqn
+    (7238241, IdStatus::Regular), // arn
+    (7238242, IdStatus::Regular), // brn
+    (7238243, IdStatus::Regular), // crn
+    (7238244, IdStatus::Regular), // drn
+    (7238249, IdStatus::Regular), // irn
+    (7238251, IdStatus::Regular), // krn
+    (7238252, IdStatus::Regular), // lrn
+    (7238253, IdStatus::Regular), // mrn
+    (7238254, IdStatus::Regular), // nrn
+    (7238255, IdStatus::Regular), // orn
+    (7238256, IdStatus::Regular), // prn
+    (7238257, IdStatus::PrivateUse), // qrn
+    (7238259, IdStatus::Regular), // srn
+    (7238260, IdStatus::Regular), // trn
+    (7238261, IdStatus::Regular), // urn
+    (7238263, IdStatus::Regular), // wrn
+    (7238264, IdStatus::Regular), // xrn
+    (7238265, IdStatus::Regular), // yrn
+    (7238266, IdStatus::Regular), // zrn
+    (7238497, IdStatus::Regular), // asn
+    (7238498, IdStatus::Regular), // bsn
+    (7238499, IdStatus::Regular), // csn
+    (7238500, IdStatus::Regular), // dsn
+    (7238501, IdStatus::Regular), // esn
+    (7238503, IdStatus::Regular), // gsn
+    (7238504, IdStatus::Regular), // hsn
+    (7238505, IdStatus::Regular), // isn
+    (7238507, IdStatus::Regular), // ksn
+    (7238508, IdStatus::Regular), // lsn
+    (7238509, IdStatus::Regular), // msn
+    (7238510, IdStatus::Regular), // nsn
+    (7238511, IdStatus::Regular), // osn
+    (7238512, IdStatus::Regular), // psn
+    (7238513, IdStatus::PrivateUse), // qsn
+    (7238514, IdStatus::Regular), // rsn
+    (7238515, IdStatus::Regular), // ssn
+    (7238520, IdStatus::Regular), // xsn
+    (7238521, IdStatus::Regular), // ysn
+    (7238753, IdStatus::Regular), // atn
+    (7238754, IdStatus::Regular), // btn
+    (7238755, IdStatus::Regular), // ctn
+    (7238756, IdStatus::Regular), // dtn
+    (7238757, IdStatus::Regular), // etn
+    (7238763, IdStatus::Regular), // ktn
+    (7238764, IdStatus::Regular), // ltn
+    (7238765, IdStatus::Regular), // mtn
+    (7238767, IdStatus::Regular), // otn
+    (7238768, IdStatus::Regular), // ptn
+    (7238769, IdStatus::PrivateUse), // qtn
+    (7238771, IdStatus::Regular), // stn
+    (7238772, IdStatus::Regular), // ttn
+    (7238776, IdStatus::Regular), // xtn
+    (7238778, IdStatus::Regular), // ztn
+    (7239009, IdStatus::Regular), // aun
+    (7239010, IdStatus::Regular), // bun
+    (7239012, IdStatus::Regular), // dun
+    (7239014, IdStatus::Regular), // fun
+    (7239015, IdStatus::Regular), // gun
+    (7239018, IdStatus::Regular), // jun
+    (7239019, IdStatus::Regular), // kun
+    (7239020, IdStatus::Regular), // lun
+    (7239021, IdStatus::Regular), // mun
+    (7239022, IdStatus::Regular), // nun
+    (7239023, IdStatus::Deprecated), // oun
+    (7239025, IdStatus::Regular), // qun
+    (7239028, IdStatus::Regular), // tun
+    (7239029, IdStatus::Deprecated), // uun
+    (7239030, IdStatus::Regular), // vun
+    (7239031, IdStatus::Regular), // wun
+    (7239032, IdStatus::Regular), // xun
+    (7239033, IdStatus::Regular), // yun
+    (7239034, IdStatus::Regular), // zun
+    (7239265, IdStatus::Regular), // avn
+    (7239266, IdStatus::Regular), // bvn
+    (7239267, IdStatus::Regular), // cvn
+    (7239269, IdStatus::Regular), // evn
+    (7239271, IdStatus::Regular), // gvn
+    (7239272, IdStatus::Regular), // hvn
+    (7239274, IdStatus::Regular), // jvn
+    (7239275, IdStatus::Regular), // kvn
+    (7239277, IdStatus::Regular), // mvn
+    (7239281, IdStatus::Regular), // qvn
+    (7239284, IdStatus::Regular), // tvn
+    (7239288, IdStatus::Regular), // xvn
+    (7239521, IdStatus::Regular), // awn
+    (7239522, IdStatus::Regular), // bwn
+    (7239527, IdStatus::Regular), // gwn
+    (7239531, IdStatus::Regular), // kwn
+    (7239533, IdStatus::Regular), // mwn
+    (7239536, IdStatus::Regular), // pwn
+    (7239539, IdStatus::Regular), // swn
+    (7239540, IdStatus::Regular), // twn
+    (7239545, IdStatus::Regular), // ywn
+    (7239778, IdStatus::Regular), // bxn
+    (7239787, IdStatus::Regular), // kxn
+    (7239789, IdStatus::Regular), // mxn
+    (7239790, IdStatus::Regular), // nxn
+    (7239793, IdStatus::Regular), // qxn
+    (7239795, IdStatus::Regular), // sxn
+    (7239796, IdStatus::Regular), // txn
+    (7240033, IdStatus::Regular), // ayn
+    (7240034, IdStatus::Regular), // byn
+    (7240036, IdStatus::Regular), // dyn
+    (7240039, IdStatus::Regular), // gyn
+    (7240043, IdStatus::Regular), // kyn
+    (7240044, IdStatus::Regular), // lyn
+    (7240045, IdStatus::Regular), // myn
+    (7240046, IdStatus::Regular), // nyn
+    (7240048, IdStatus::Regular), // pyn
+    (7240050, IdStatus::Regular), // ryn
+    (7240051, IdStatus::Regular), // syn
+    (7240052, IdStatus::Regular), // tyn
+    (7240055, IdStatus::Regular), // wyn
+    (7240058, IdStatus::Regular), // zyn
+    (7240289, IdStatus::Regular), // azn
+    (7240290, IdStatus::Regular), // bzn
+    (7240291, IdStatus::Regular), // czn
+    (7240292, IdStatus::Regular), // dzn
+    (7240295, IdStatus::Regular), // gzn
+    (7240299, IdStatus::Regular), // kzn
+    (7240300, IdStatus::Regular), // lzn
+    (7240301, IdStatus::Regular), // mzn
+    (7240304, IdStatus::Regular), // pzn
+    (7240307, IdStatus::Regular), // szn
+    (7240308, IdStatus::Regular), // tzn
+    (7240309, IdStatus::Deprecated), // uzn
+    (7299425, IdStatus::Regular), // aao
+    (7299426, IdStatus::Regular), // bao
+    (7299427, IdStatus::Regular), // cao
+    (7299428, IdStatus::Regular), // dao
+    (7299431, IdStatus::Regular), // gao
+    (7299432, IdStatus::Regular), // hao
+    (7299434, IdStatus::Regular), // jao
+    (7299435, IdStatus::Regular), // kao
+    (7299438, IdStatus::Regular), // nao
+    (7299440, IdStatus::Regular), // pao
+    (7299441, IdStatus::Reserved), // qao
+    (7299442, IdStatus::Regular), // rao
+    (7299443, IdStatus::Regular), // sao
+    (7299444, IdStatus::Regular), // tao
+    (7299446, IdStatus::Regular), // vao
+    (7299447, IdStatus::Regular), // wao
+    (7299448, IdStatus::Regular), // xao
+    (7299449, IdStatus::Regular), // yao
+    (7299450, IdStatus::Regular), // zao
+    (7299681, IdStatus::Regular), // abo
+    (7299682, IdStatus::Regular), // bbo
+    (7299683, IdStatus::Regular), // cbo
+    (7299684, IdStatus::Regular), // dbo
+    (7299685, IdStatus::Regular), // ebo
+    (7299687, IdStatus::Deprecated), // gbo
+    (7299688, IdStatus::Regular), // hbo
+    (7299690, IdStatus::Regular), // jbo
+    (7299691, IdStatus::Regular), // kbo
+    (7299692, IdStatus::Regular), // lbo
+    (7299693, IdStatus::Regular), // mbo
+    (7299694, IdStatus::Regular), // nbo
+    (7299695, IdStatus::Regular), // obo
+    (7299696, IdStatus::Regular), // pbo
+    (7299697, IdStatus::Reserved), // qbo
+    (7299699, IdStatus::Regular), // sbo
+    (7299700, IdStatus::Regular), // tbo
+    (7299704, IdStatus::Regular), // xbo
+    (7299705, IdStatus::Regular), // ybo
+    (7299938, IdStatus::Regular), // bco
+    (7299939, IdStatus::Regular), // cco
+    (7299947, IdStatus::Regular), // kco
+    (7299949, IdStatus::Regular), // mco
+    (7299950, IdStatus::Regular), // nco
+    (7299951, IdStatus::Regular), // oco
+    (7299953, IdStatus::Reserved), // qco
+    (7299955, IdStatus::Regular), // sco
+    (7299956, IdStatus::Regular), // tco
+    (7299960, IdStatus::Regular), // xco
+    (7300193, IdStatus::Regular), // ado
+    (7300194, IdStatus::Regular), // bdo
+    (7300195, IdStatus::Regular), // cdo
+    (7300196, IdStatus::Regular), // ddo
+    (7300199, IdStatus::Regular), // gdo
+    (7300203, IdStatus::Regular), // kdo
+    (7300204, IdStatus::Regular), // ldo
+    (7300208, IdStatus::Regular), // pdo
+    (7300209, IdStatus::Reserved), // qdo
+    (7300211, IdStatus::Regular), // sdo
+    (7300212, IdStatus::Regular), // tdo
+    (7300216, IdStatus::Regular), // xdo
+    (7300450, IdStatus::Regular), // beo
+    (7300459, IdStatus::Regular), // keo
+    (7300460, IdStatus::Regular), // leo
+    (7300461, IdStatus::Regular), // meo
+    (7300462, IdStatus::Regular), // neo
+    (7300464, IdStatus::Regular), // peo
+    (7300465, IdStatus::Reserved), // qeo
+    (7300467, IdStatus::Regular), // seo
+    (7300468, IdStatus::Regular), // teo
+    (7300470, IdStatus::Regular), // veo
+    (7300471, IdStatus::Regular), // weo
+    (7300705, IdStatus::Regular), // afo
+    (7300706, IdStatus::Regular), // bfo
+    (7300715, IdStatus::Regular), // kfo
+    (7300717, IdStatus::Regular), // mfo
+    (7300719, IdStatus::Regular), // ofo
+    (7300721, IdStatus::Reserved), // qfo
+    (7300724, IdStatus::Regular), // tfo
+    (7300961, IdStatus::Regular), // ago
+    (7300962, IdStatus::Regular), // bgo
+    (7300964, IdStatus::Deprecated), // dgo
+    (7300965, IdStatus::Regular), // ego
+    (7300967, IdStatus::Deprecated), // ggo
+    (7300969, IdStatus::Regular), // igo
+    (7300970, IdStatus::Regular), // jgo
+    (7300971, IdStatus::Regular), // kgo
+    (7300972, IdStatus::Regular), // lgo
+    (7300973, IdStatus::Regular), // mgo
+    (7300974, IdStatus::Deprecated), // ngo
+    (7300975, IdStatus::Regular), // ogo
+    (7300977, IdStatus::PrivateUse), // qgo
+    (7300979, IdStatus::Deprecated), // sgo
+    (7300980, IdStatus::Regular), // tgo
+    (7300981, IdStatus::Regular), // ugo
+    (7300983, IdStatus::Regular), // wgo
+    (7301217, IdStatus::Regular), // aho
+    (7301218, IdStatus::Regular), // bho
+    (7301219, IdStatus::Regular), // cho
+    (7301220, IdStatus::Regular), // dho
+    (7301223, IdStatus::Regular), // gho
+    (7301227, IdStatus::Regular), // kho
+    (7301229, IdStatus::Regular), // mho
+    (7301230, IdStatus::Regular), // nho
+    (7301232, IdStatus::Regular), // pho
+    (7301233, IdStatus::PrivateUse), // qho
+    (7301235, IdStatus::Regular), // sho
+    (7301473, IdStatus::Regular), // aio
+    (7301474, IdStatus::Regular), // bio
+    (7301476, IdStatus::Regular), // dio
+    (7301479, IdStatus::Deprecated), // gio
+    (7301480, IdStatus::Regular), // hio
+    (7301482, IdStatus::Regular), // jio
+    (7301483, IdStatus::Regular), // kio
+    (7301484, IdStatus::Regular), // lio
+    (7301485, IdStatus::Regular), // mio
+    (7301486, IdStatus::Regular), // nio
+    (7301488, IdStatus::Regular), // pio
+    (7301489, IdStatus::PrivateUse), // qio
+    (7301491, IdStatus::Regular), // sio
+    (7301492, IdStatus::Regular), // tio
+    (7301730, IdStatus::Regular), // bjo
+    (7301731, IdStatus::Regular), // cjo
+    (7301732, IdStatus::Regular), // djo
+    (7301737, IdStatus::Regular), // ijo
+    (7301739, IdStatus::Regular), // kjo
+    (7301741, IdStatus::Regular), // mjo
+    (7301742, IdStatus::Regular), // njo
+    (7301745, IdStatus::PrivateUse), // qjo
+    (7301747, IdStatus::Regular), // sjo
+    (7301748, IdStatus::Regular), // tjo
+    (7301985, IdStatus::Regular), // ako
+    (7301986, IdStatus::Regular), // bko
+    (7301987, IdStatus::Regular), // cko
+    (7301989, IdStatus::Regular), // eko
+    (7301991, IdStatus::Regular), // gko
+    (7301993, IdStatus::Regular), // iko
+    (7301994, IdStatus::Regular), // jko
+    (7301995, IdStatus::Regular), // kko
+    (7301996, IdStatus::Regular), // lko
+    (7301997, IdStatus::Regular), // mko
+    (7301998, IdStatus::Regular), // nko
+    (7301999, IdStatus::Regular), // oko
+    (7302000, IdStatus::Regular), // pko
+    (7302001, IdStatus::PrivateUse), // qko
+    (7302003, IdStatus::Regular), // sko
+    (7302006, IdStatus::Regular), // vko
+    (7302008, IdStatus::Regular), // xko
+    (7302009, IdStatus::Regular), // yko
+    (7302010, IdStatus::Regular), // zko
+    (7302241, IdStatus::Regular), // alo
+    (7302242, IdStatus::Regular), // blo
+    (7302243, IdStatus::Regular), // clo
+    (7302245, IdStatus::Regular), // elo
+    (7302247, IdStatus::Regular), // glo
+    (7302249, IdStatus::Regular), // ilo
+    (7302251, IdStatus::Regular), // klo
+    (7302252, IdStatus::Deprecated), // llo
+    (7302253, IdStatus::Regular), // mlo
+    (7302254, IdStatus::Regular), // nlo
+    (7302255, IdStatus::Regular), // olo
+    (7302256, IdStatus::Regular), // plo
+    (7302257, IdStatus::PrivateUse), // qlo
+    (7302260, IdStatus::Regular), // tlo
+    (7302263, IdStatus::Regular), // wlo
+    (7302264, IdStatus::Regular), // xlo
+    (7302265, IdStatus::Regular), // ylo
+    (7302497, IdStatus::Regular), // amo
+    (7302498, IdStatus::Regular), // bmo
+    (7302499, IdStatus::Regular), // cmo
+    (7302500, IdStatus::Regular), // dmo
+    (7302501, IdStatus::Deprecated), // emo
+    (7302505, IdStatus::Regular), // imo
+    (7302507, IdStatus::Regular), // kmo
+    (7302508, IdStatus::Regular), // lmo
+    (7302509, IdStatus::Regular), // mmo
+    (7302510, IdStatus::Regular), // nmo
+    (7302511, IdStatus::Regular), // omo
+    (7302512, IdStatus::Regular), // pmo
+    (7302513, IdStatus::PrivateUse), // qmo
+    (7302514, IdStatus::Regular), // rmo
+    (7302516, IdStatus::Regular), // tmo
+    (7302517, IdStatus::Regular), // umo
+    (7302519, IdStatus::Regular), // wmo
+    (7302520, IdStatus::Regular), // xmo
+    (7302521, IdStatus::Regular), // ymo
+    (7302522, IdStatus::Regular), // zmo
+    (7302753, IdStatus::Regular), // ano
+    (7302754, IdStatus::Regular), // bno
+    (7302755, IdStatus::Regular), // cno
+    (7302756, IdStatus::Regular), // dno
+    (7302757, IdStatus::Regular), // eno
+    (7302759, IdStatus::Deprecated), // gno
+    (7302760, IdStatus::Regular), // hno
+    (7302761, IdStatus::Regular), // ino
+    (7302763, IdStatus::Regular), // kno
+    (7302764, IdStatus::Deprecated), // lno
+    (7302765, IdStatus::Regular), // mno
+    (7302767, IdStatus::Regular), // ono
+    (7302768, IdStatus::Regular), // pno
+    (7302769, IdStatus::PrivateUse), // qno
+    (7302771, IdStatus::Regular), // sno
+    (7302772, IdStatus::Regular), // tno
+    (7302775, IdStatus::Regular), // wno
+    (7302776, IdStatus::Regular), // xno
+    (7302777, IdStatus::Regular), // yno
+    (7303010, IdStatus::Regular), // boo
+    (7303011, IdStatus::Regular), // coo
+    (7303012, IdStatus::Regular), // doo
+    (7303015, IdStatus::Regular), // goo
+    (7303016, IdStatus::Regular), // hoo
+    (7303019, IdStatus::Regular), // koo
+    (7303020, IdStatus::Regular), // loo
+    (7303021, IdStatus::Regular), // moo
+    (7303022, IdStatus::Deprecated), // noo
+    (7303024, IdStatus::Regular), // poo
+    (7303025, IdStatus::PrivateUse), // qoo
+    (7303026, IdStatus::Regular), // roo
+    (7303027, IdStatus::Regular), // soo
+    (7303028, IdStatus::Regular), // too
+    (7303031, IdStatus::Regular), // woo
+    (7303032, IdStatus::Regular), // xoo
+    (7303034, IdStatus::Regular), // zoo
+    (7303265, IdStatus::Regular), // apo
+    (7303266, IdStatus::Regular), // bpo
+    (7303267, IdStatus::Regular), // cpo
+    (7303272, IdStatus::Regular), // hpo
+    (7303273, IdStatus::Regular), // ipo
+    (7303275, IdStatus::Regular), // kpo
+    (7303276, IdStatus::Regular), // lpo
+    (7303277, IdStatus::Regular), // mpo
+    (7303278, IdStatus::Regular), // npo
+    (7303279, IdStatus::Regular), // opo
+    (7303280, IdStatus::Regular), // ppo
+    (7303281, IdStatus::PrivateUse), // qpo
+    (7303283, IdStatus::Regular), // spo
+    (7303284, IdStatus::Regular), // tpo
+    (7303288, IdStatus::Regular), // xpo
+    (7303289, IdStatus::Regular), // ypo
+    (7303290, IdStatus::Regular), // zpo
+    (7303522, IdStatus::Regular), // bqo
+    (7303531, IdStatus::Regular), // kqo
+    (7303533, IdStatus::Regular), // mqo
+    (7303534, IdStatus::Regular), // nqo
+    (7303537, IdStatus::PrivateUse), // qqo
+    (7303539, IdStatus::Regular), // sqo
+    (7303540, IdStatus::Regular), // tqo
+    (7303777, IdStatus::Regular), // aro
+    (7303778, IdStatus::Regular), // bro
+    (7303779, IdStatus::Regular), // cro
+    (7303780, IdStatus::Regular), // dro
+    (7303781, IdStatus::Regular), // ero
+    (7303782, IdStatus::Regular), // fro
+    (7303783, IdStatus::Regular), // gro
+    (7303784, IdStatus::Regular), // hro
+    (7303785, IdStatus::Regular), // iro
+    (7303787, IdStatus::Regular), // kro
+    (7303788, IdStatus::Regular), // lro
+    (7303789, IdStatus::Regular), // mro
+    (7303791, IdStatus::Regular), // oro
+    (7303792, IdStatus::Regular), // pro
+    (7303793, IdStatus::PrivateUse), // qro
+    (7303794, IdStatus::Regular), // rro
+    (7303795, IdStatus::Regular), // sro
+    (7303796, IdStatus::Regular), // tro
+    (7303797, IdStatus::Regular), // uro
+    (7303798, IdStatus::Regular), // vro
+    (7303799, IdStatus::Regular), // wro
+    (7303801, IdStatus::Regular), // yro
+    (7303802, IdStatus::Regular), // zro
+    (7304033, IdStatus::Regular), // aso
+    (7304034, IdStatus::Regular), // bso
+    (7304035, IdStatus::Regular), // cso
+    (7304036, IdStatus::Regular), // dso
+    (7304037, IdStatus::Regular), // eso
+    (7304039, IdStatus::Regular), // gso
+    (7304041, IdStatus::Regular), // iso
+    (7304043, IdStatus::Regular), // kso
+    (7304044, IdStatus::Regular), // lso
+    (7304045, IdStatus::Regular), // mso
+    (7304046, IdStatus::Regular), // nso
+    (7304047, IdStatus::Regular), // oso
+    (7304048, IdStatus::Regular), // pso
+    (7304049, IdStatus::PrivateUse), // qso
+    (7304051, IdStatus::Regular), // sso
+    (7304056, IdStatus::Regular), // xso
+    (7304057, IdStatus::Regular), // yso
+    (7304289, IdStatus::Regular), // ato
+    (7304290, IdStatus::Regular), // bto
+    (7304291, IdStatus::Regular), // cto
+    (7304292, IdStatus::Regular), // dto
+    (7304293, IdStatus::Regular), // eto
+    (7304296, IdStatus::Regular), // hto
+    (7304297, IdStatus::Regular), // ito
+    (7304299, IdStatus::Regular), // kto
+    (7304300, IdStatus::Regular), // lto
+    (7304301, IdStatus::Regular), // mto
+    (7304302, IdStatus::Regular), // nto
+    (7304303, IdStatus::Regular), // oto
+    (7304304, IdStatus::Regular), // pto
+    (7304305, IdStatus::PrivateUse), // qto
+    (7304307, IdStatus::Regular), // sto
+    (7304308, IdStatus::Regular), // tto
+    (7304310, IdStatus::Regular), // vto
+    (7304312, IdStatus::Regular), // xto
+    (7304545, IdStatus::Regular), // auo
+    (7304546, IdStatus::Regular), // buo
+    (7304547, IdStatus::Regular), // cuo
+    (7304548, IdStatus::Regular), // duo
+    (7304551, IdStatus::Regular), // guo
+    (7304552, IdStatus::Regular), // huo
+    (7304554, IdStatus::Regular), // juo
+    (7304555, IdStatus::Regular), // kuo
+    (7304556, IdStatus::Regular), // luo
+    (7304557, IdStatus::Regular), // muo
+    (7304558, IdStatus::Regular), // nuo
+    (7304560, IdStatus::Regular), // puo
+    (7304562, IdStatus::Regular), // ruo
+    (7304563, IdStatus::Regular), // suo
+    (7304564, IdStatus::Regular), // tuo
+    (7304568, IdStatus::Regular), // xuo
+    (7304801, IdStatus::Regular), // avo
+    (7304802, IdStatus::Regular), // bvo
+    (7304807, IdStatus::Regular), // gvo
+    (7304811, IdStatus::Regular), // kvo
+    (7304813, IdStatus::Regular), // mvo
+    (7304814, IdStatus::Regular), // nvo
+    (7304817, IdStatus::Regular), // qvo
+    (7304820, IdStatus::Regular), // tvo
+    (7304824, IdStatus::Regular), // xvo
+    (7305057, IdStatus::Regular), // awo
+    (7305058, IdStatus::Regular), // bwo
+    (7305061, IdStatus::Regular), // ewo
+    (7305064, IdStatus::Regular), // hwo
+    (7305065, IdStatus::Regular), // iwo
+    (7305067, IdStatus::Regular), // kwo
+    (7305068, IdStatus::Regular), // lwo
+    (7305069, IdStatus::Regular), // mwo
+    (7305070, IdStatus::Regular), // nwo
+    (7305072, IdStatus::Regular), // pwo
+    (7305074, IdStatus::Regular), // rwo
+    (7305075, IdStatus::Regular), // swo
+    (7305076, IdStatus::Regular), // two
+    (7305079, IdStatus::Regular), // wwo
+    (7305080, IdStatus::Regular), // xwo
+    (7305314, IdStatus::Regular), // bxo
+    (7305323, IdStatus::Regular), // kxo
+    (7305325, IdStatus::Regular), // mxo
+    (7305326, IdStatus::Regular), // nxo
+    (7305329, IdStatus::Regular), // qxo
+    (7305331, IdStatus::Regular), // sxo
+    (7305332, IdStatus::Regular), // txo
+    (7305569, IdStatus::Regular), // ayo
+    (7305570, IdStatus::Regular), // byo
+    (7305571, IdStatus::Regular), // cyo
+    (7305572, IdStatus::Regular), // dyo
+    (7305573, IdStatus::Regular), // eyo
+    (7305575, IdStatus::Regular), // gyo
+    (7305577, IdStatus::Regular), // iyo
+    (7305579, IdStatus::Regular), // kyo
+    (7305581, IdStatus::Regular), // myo
+    (7305582, IdStatus::Regular), // nyo
+    (7305587, IdStatus::Regular), // syo
+    (7305825, IdStatus::Regular), // azo
+    (7305826, IdStatus::Regular), // bzo
+    (7305827, IdStatus::Regular), // czo
+    (7305835, IdStatus::Regular), // kzo
+    (7305837, IdStatus::Regular), // mzo
+    (7305844, IdStatus::Regular), // tzo
+    (7364961, IdStatus::Regular), // aap
+    (7364962, IdStatus::Regular), // bap
+    (7364963, IdStatus::Regular), // cap
+    (7364964, IdStatus::Deprecated), // dap
+    (7364966, IdStatus::Regular), // fap
+    (7364967, IdStatus::Regular), // gap
+    (7364968, IdStatus::Regular), // hap
+    (7364969, IdStatus::Deprecated), // iap
+    (7364971, IdStatus::Regular), // kap
+    (7364972, IdStatus::Regular), // lap
+    (7364973, IdStatus::Regular), // map
+    (7364974, IdStatus::Regular), // nap
+    (7364976, IdStatus::Regular), // pap
+    (7364977, IdStatus::Reserved), // qap
+    (7364978, IdStatus::Regular), // rap
+    (7364979, IdStatus::Deprecated), // sap
+    (7364980, IdStatus::Regular), // tap
+    (7364982, IdStatus::Regular), // vap
+    (7364983, IdStatus::Regular), // wap
+    (7364984, IdStatus::Regular), // xap
+    (7364985, IdStatus::Regular), // yap
+    (7364986, IdStatus::Regular), // zap
+    (7365217, IdStatus::Regular), // abp
+    (7365218, IdStatus::Regular), // bbp
+    (7365220, IdStatus::Regular), // dbp
+    (7365223, IdStatus::Regular), // gbp
+    (7365227, IdStatus::Regular), // kbp
+    (7365229, IdStatus::Regular), // mbp
+    (7365230, IdStatus::Regular), // nbp
+    (7365232, IdStatus::Regular), // pbp
+    (7365233, IdStatus::Reserved), // qbp
+    (7365234, IdStatus::Regular), // rbp
+    (7365235, IdStatus::Regular), // sbp
+    (7365236, IdStatus::Regular), // tbp
+    (7365239, IdStatus::Regular), // wbp
+    (7365240, IdStatus::Regular), // xbp
+    (7365473, IdStatus::Regular), // acp
+    (7365474, IdStatus::Regular), // bcp
+    (7365475, IdStatus::Regular), // ccp
+    (7365483, IdStatus::Regular), // kcp
+    (7365484, IdStatus::Regular), // lcp
+    (7365485, IdStatus::Regular), // mcp
+    (7365486, IdStatus::Deprecated), // ncp
+    (7365488, IdStatus::Regular), // pcp
+    (7365489, IdStatus::Reserved), // qcp
+    (7365491, IdStatus::Regular), // scp
+    (7365492, IdStatus::Regular), // tcp
+    (7365497, IdStatus::Regular), // ycp
+    (7365729, IdStatus::Deprecated), // adp
+    (7365730, IdStatus::Regular), // bdp
+    (7365739, IdStatus::Regular), // kdp
+    (7365740, IdStatus::Regular), // ldp
+    (7365741, IdStatus::Regular), // mdp
+    (7365742, IdStatus::Regular), // ndp
+    (7365745, IdStatus::Reserved), // qdp
+    (7365747, IdStatus::Regular), // sdp
+    (7365986, IdStatus::Regular), // bep
+    (7365988, IdStatus::Regular), // dep
+    (7365995, IdStatus::Regular), // kep
+    (7365996, IdStatus::Regular), // lep
+    (7365997, IdStatus::Regular), // mep
+    (7366000, IdStatus::Regular), // pep
+    (7366001, IdStatus::Reserved), // qep
+    (7366003, IdStatus::Regular), // sep
+    (7366004, IdStatus::Regular), // tep
+    (7366006, IdStatus::Regular), // vep
+    (7366007, IdStatus::Regular), // wep
+    (7366008, IdStatus::Regular), // xep
+    (7366241, IdStatus::Regular), // afp
+    (7366242, IdStatus::Regular), // bfp
+    (7366251, IdStatus::Regular), // kfp
+    (7366253, IdStatus::Regular), // mfp
+    (7366257, IdStatus::Reserved), // qfp
+    (7366497, IdStatus::Deprecated), // agp
+    (7366498, IdStatus::Regular), // bgp
+    (7366507, IdStatus::Regular), // kgp
+    (7366509, IdStatus::Regular), // mgp
+    (7366510, IdStatus::Regular), // ngp
+    (7366513, IdStatus::PrivateUse), // qgp
+    (7366515, IdStatus::Regular), // sgp
+    (7366516, IdStatus::Regular), // tgp
+    (7366521, IdStatus::Regular), // ygp
+    (7366753, IdStatus::Regular), // ahp
+    (7366754, IdStatus::Regular), // bhp
+    (7366755, IdStatus::Regular), // chp
+    (7366761, IdStatus::Regular), // ihp
+    (7366763, IdStatus::Regular), // khp
+    (7366764, IdStatus::Regular), // lhp
+    (7366765, IdStatus::Regular), // mhp
+    (7366766, IdStatus::Regular), // nhp
+    (7366769, IdStatus::PrivateUse), // qhp
+    (7366770, IdStatus::Regular), // rhp
+    (7366771, IdStatus::Regular), // shp
+    (7366772, IdStatus::Regular), // thp
+    (7367009, IdStatus::Regular), // aip
+    (7367010, IdStatus::Regular), // bip
+    (7367011, IdStatus::Regular), // cip
+    (7367012, IdStatus::Regular), // dip
+    (7367013, IdStatus::Regular), // eip
+    (7367014, IdStatus::Regular), // fip
+    (7367015, IdStatus::Regular), // gip
+    (7367019, IdStatus::Regular), // kip
+    (7367020, IdStatus::Regular), // lip
+    (7367021, IdStatus::Regular), // mip
+    (7367024, IdStatus::Regular), // pip
+    (7367025, IdStatus::PrivateUse), // qip
+    (7367027, IdStatus::Regular), // sip
+    (7367028, IdStatus::Regular), // tip
+    (7367032, IdStatus::Deprecated), // xip
+    (7367033, IdStatus::Regular), // yip
+    (7367265, IdStatus::Regular), // ajp
+    (7367266, IdStatus::Regular), // bjp
+    (7367267, IdStatus::Regular), // cjp
+    (7367275, IdStatus::Regular), // kjp
+    (7367276, IdStatus::Regular), // ljp
+    (7367277, IdStatus::Regular), // mjp
+    (7367279, IdStatus::Regular), // ojp
+    (7367281, IdStatus::PrivateUse), // qjp
+    (7367283, IdStatus::Regular), // sjp
+    (7367284, IdStatus::Regular), // tjp
+    (7367521, IdStatus::Regular), // akp
+    (7367522, IdStatus::Regular), // bkp
+    (7367525, IdStatus::Regular), // ekp
+    (7367527, IdStatus::Regular), // gkp
+    (7367529, IdStatus::Regular), // ikp
+    (7367530, IdStatus::Regular), // jkp
+    (7367531, IdStatus::Regular), // kkp
+    (7367533, IdStatus::Regular), // mkp
+    (7367534, IdStatus::Regular), // nkp
+    (7367536, IdStatus::Regular), // pkp
+    (7367537, IdStatus::PrivateUse), // qkp
+    (7367539, IdStatus::Regular), // skp
+    (7367540, IdStatus::Regular), // tkp
+    (7367541, IdStatus::Regular), // ukp
+    (7367542, IdStatus::Regular), // vkp
+    (7367544, IdStatus::Regular), // xkp
+    (7367546, IdStatus::Regular), // zkp
+    (7367777, IdStatus::Regular), // alp
+    (7367778, IdStatus::Regular), // blp
+    (7367781, IdStatus::Deprecated), // elp
+    (7367785, IdStatus::Regular), // ilp
+    (7367787, IdStatus::Regular), // klp
+    (7367788, IdStatus::Regular), // llp
+    (7367789, IdStatus::Regular), // mlp
+    (7367792, IdStatus::Deprecated), // plp
+    (7367793, IdStatus::PrivateUse), // qlp
+    (7367795, IdStatus::Regular), // slp
+    (7367796, IdStatus::Regular), // tlp
+    (7367798, IdStatus::Regular), // vlp
+    (7367800, IdStatus::Regular), // xlp
+    (7368033, IdStatus::Regular), // amp
+    (7368034, IdStatus::Regular), // bmp
+    (7368037, IdStatus::Regular), // emp
+    (7368038, IdStatus::Regular), // fmp
+    (7368040, IdStatus::Regular), // hmp
+    (7368043, IdStatus::Regular), // kmp
+    (7368044, IdStatus::Regular), // lmp
+    (7368045, IdStatus::Regular), // mmp
+    (7368046, IdStatus::Regular), // nmp
+    (7368047, IdStatus::Regular), // omp
+    (7368049, IdStatus::PrivateUse), // qmp
+    (7368050, IdStatus::Regular), // rmp
+    (7368051, IdStatus::Regular), // smp
+    (7368052, IdStatus::Deprecated), // tmp
+    (7368053, IdStatus::Regular), // ump
+    (7368054, IdStatus::Regular), // vmp
+    (7368056, IdStatus::Regular), // xmp
+    (7368057, IdStatus::Regular), // ymp
+    (7368058, IdStatus::Regular), // zmp
+    (7368289, IdStatus::Regular), // anp
+    (7368290, IdStatus::Regular), // bnp
+    (7368291, IdStatus::Regular), // cnp
+    (7368297, IdStatus::Regular), // inp
+    (7368299, IdStatus::Regular), // knp
+    (7368301, IdStatus::Regular), // mnp
+    (7368302, IdStatus::Regular), // nnp
+    (7368303, IdStatus::Regular), // onp
+    (7368304, IdStatus::Regular), // pnp
+    (7368305, IdStatus::PrivateUse), // qnp
+    (7368306, IdStatus::Regular), // rnp
+    (7368307, IdStatus::Regular), // snp
+    (7368308, IdStatus::Regular), // tnp
+    (7368309, IdStatus::Deprecated), // unp
+    (7368310, IdStatus::Regular), // vnp
+    (7368311, IdStatus::Regular), // wnp
+    (7368546, IdStatus::Regular), // bop
+    (7368547, IdStatus::Regular), // cop
+    (7368548, IdStatus::Regular), // dop
+    (7368551, IdStatus::Regular), // gop
+    (7368552, IdStatus::Regular), // hop
+    (7368555, IdStatus::Regular), // kop
+    (7368556, IdStatus::Regular), // lop
+    (7368557, IdStatus::Regular), // mop
+    (7368558, IdStatus::Regular), // nop
+    (7368560, IdStatus::Regular), // pop
+    (7368561, IdStatus::PrivateUse), // qop
+    (7368562, IdStatus::Regular), // rop
+    (7368563, IdStatus::Regular), // sop
+    (7368564, IdStatus::Regular), // top
+    (7368568, IdStatus::Regular), // xop
+    (7368801, IdStatus::Regular), // app
+    (7368802, IdStatus::Regular), // bpp
+    (7368803, IdStatus::Regular), // cpp
+    (7368804, IdStatus::Regular), // dpp
+    (7368811, IdStatus::Deprecated), // kpp
+    (7368813, IdStatus::Regular), // mpp
+    (7368816, IdStatus::Regular), // ppp
+    (7368817, IdStatus::PrivateUse), // qpp
+    (7368819, IdStatus::Regular), // spp
+    (7368820, IdStatus::Regular), // tpp
+    (7368824, IdStatus::Regular), // xpp
+    (7368825, IdStatus::Regular), // ypp
+    (7368826, IdStatus::Regular), // zpp
+    (7369057, IdStatus::Regular), // aqp
+    (7369058, IdStatus::Regular), // bqp
+    (7369067, IdStatus::Regular), // kqp
+    (7369069, IdStatus::Regular), // mqp
+    (7369073, IdStatus::PrivateUse), // qqp
+    (7369076, IdStatus::Regular), // tqp
+    (7369313, IdStatus::Regular), // arp
+    (7369314, IdStatus::Regular), // brp
+    (7369315, IdStatus::Regular), // crp
+    (7369318, IdStatus::Regular), // frp
+    (7369320, IdStatus::Regular), // hrp
+    (7369323, IdStatus::Regular), // krp
+    (7369325, IdStatus::Regular), // mrp
+    (7369326, IdStatus::Regular), // nrp
+    (7369328, IdStatus::Regular), // prp
+    (7369329, IdStatus::PrivateUse), // qrp
+    (7369332, IdStatus::Regular), // trp
+    (7369333, IdStatus::Regular), // urp
+    (7369335, IdStatus::Regular), // wrp
+    (7369338, IdStatus::Regular), // zrp
+    (7369569, IdStatus::Regular), // asp
+    (7369570, IdStatus::Regular), // bsp
+    (7369571, IdStatus::Regular), // csp
+    (7369575, IdStatus::Regular), // gsp
+    (7369579, IdStatus::Regular), // ksp
+    (7369580, IdStatus::Regular), // lsp
+    (7369581, IdStatus::Regular), // msp
+    (7369582, IdStatus::Regular), // nsp
+    (7369583, IdStatus::Regular), // osp
+    (7369584, IdStatus::Regular), // psp
+    (7369585, IdStatus::PrivateUse), // qsp
+    (7369587, IdStatus::Regular), // ssp
+    (7369588, IdStatus::Regular), // tsp
+    (7369589, IdStatus::Regular), // usp
+    (7369592, IdStatus::Regular), // xsp
+    (7369593, IdStatus::Regular), // ysp
+    (7369825, IdStatus::Regular), // atp
+    (7369826, IdStatus::Regular), // btp
+    (7369827, IdStatus::Regular), // ctp
+    (7369828, IdStatus::Regular), // dtp
+    (7369835, IdStatus::Regular), // ktp
+    (7369837, IdStatus::Regular), // mtp
+    (7369838, IdStatus::Regular), // ntp
+    (7369840, IdStatus::Regular), // ptp
+    (7369841, IdStatus::PrivateUse), // qtp
+    (7369843, IdStatus::Regular), // stp
+    (7369844, IdStatus::Regular), // ttp
+    (7369845, IdStatus::Regular), // utp
+    (7369848, IdStatus::Regular), // xtp
+    (7369849, IdStatus::Regular), // ytp
+    (7369850, IdStatus::Regular), // ztp
+    (7370081, IdStatus::Regular), // aup
+    (7370082, IdStatus::Regular), // bup
+    (7370083, IdStatus::Regular), // cup
+    (7370084, IdStatus::Regular), // dup
+    (7370087, IdStatus::Regular), // gup
+    (7370088, IdStatus::Regular), // hup
+    (7370090, IdStatus::Regular), // jup
+    (7370091, IdStatus::Regular), // kup
+    (7370092, IdStatus::Regular), // lup
+    (7370093, IdStatus::Deprecated), // mup
+    (7370094, IdStatus::Regular), // nup
+    (7370096, IdStatus::Regular), // pup
+    (7370097, IdStatus::Regular), // qup
+    (7370098, IdStatus::Regular), // rup
+    (7370100, IdStatus::Regular), // tup
+    (7370104, IdStatus::Regular), // xup
+    (7370105, IdStatus::Regular), // yup
+    (7370338, IdStatus::Regular), // bvp
+    (7370343, IdStatus::Regular), // gvp
+    (7370347, IdStatus::Regular), // kvp
+    (7370349, IdStatus::Regular), // mvp
+    (7370353, IdStatus::Regular), // qvp
+    (7370594, IdStatus::Regular), // bwp
+    (7370603, IdStatus::Regular), // kwp
+    (7370605, IdStatus::Regular), // mwp
+    (7370611, IdStatus::Regular), // swp
+    (7370612, IdStatus::Regular), // twp
+    (7370850, IdStatus::Regular), // bxp
+    (7370859, IdStatus::Regular), // kxp
+    (7370861, IdStatus::Regular), // mxp
+    (7370865, IdStatus::Regular), // qxp
+    (7371105, IdStatus::Regular), // ayp
+    (7371106, IdStatus::Regular), // byp
+    (7371115, IdStatus::Regular), // kyp
+    (7371117, IdStatus::Regular), // myp
+    (7371118, IdStatus::Regular), // nyp
+    (7371121, IdStatus::Regular), // qyp
+    (7371124, IdStatus::Regular), // typ
+    (7371130, IdStatus::Regular), // zyp
+    (7371362, IdStatus::Regular), // bzp
+    (7371371, IdStatus::Regular), // kzp
+    (7371373, IdStatus::Regular), // mzp
+    (7371379, IdStatus::Regular), // szp
+    (7371384, IdStatus::Regular), // xzp
+    (7430497, IdStatus::Regular), // aaq
+    (7430499, IdStatus::Regular), // caq
+    (7430500, IdStatus::Regular), // daq
+    (7430503, IdStatus::Regular), // gaq
+    (7430504, IdStatus::Regular), // haq
+    (7430506, IdStatus::Regular), // jaq
+    (7430507, IdStatus::Regular), // kaq
+    (7430508, IdStatus::Regular), // laq
+    (7430509, IdStatus::Regular), // maq
+    (7430510, IdStatus::Regular), // naq
+    (7430512, IdStatus::Regular), // paq
+    (7430513, IdStatus::Reserved), // qaq
+    (7430514, IdStatus::Regular), // raq
+    (7430515, IdStatus::Regular), // saq
+    (7430516, IdStatus::Regular), // taq
+    (7430519, IdStatus::Regular), // waq
+    (7430520, IdStatus::Regular), // xaq
+    (7430521, IdStatus::Regular), // yaq
+    (7430522, IdStatus::Regular), // zaq
+    (7430753, IdStatus::Regular), // abq
+    (7430754, IdStatus::Regular), // bbq
+    (7430755, IdStatus::Regular), // cbq
+    (7430756, IdStatus::Regular), // dbq
+    (7430759, IdStatus::Regular), // gbq
+    (7430763, IdStatus::Regular), // kbq
+    (7430764, IdStatus::Regular), // lbq
+    (7430765, IdStatus::Regular), // mbq
+    (7430766, IdStatus::Regular), // nbq
+    (7430769, IdStatus::Reserved), // qbq
+    (7430771, IdStatus::Regular), // sbq
+    (7430772, IdStatus::Regular), // tbq
+    (7430775, IdStatus::Regular), // wbq
+    (7431009, IdStatus::Regular), // acq
+    (7431010, IdStatus::Regular), // bcq
+    (7431011, IdStatus::Deprecated), // ccq
+    (7431019, IdStatus::Regular), // kcq
+    (7431020, IdStatus::Regular), // lcq
+    (7431021, IdStatus::Regular), // mcq
+    (7431022, IdStatus::Regular), // ncq
+    (7431025, IdStatus::Reserved), // qcq
+    (7431027, IdStatus::Regular), // scq
+    (7431028, IdStatus::Regular), // tcq
+    (7431265, IdStatus::Regular), // adq
+    (7431266, IdStatus::Regular), // bdq
+    (7431271, IdStatus::Regular), // gdq
+    (7431275, IdStatus::Regular), // kdq
+    (7431276, IdStatus::Regular), // ldq
+    (7431277, IdStatus::Regular), // mdq
+    (7431278, IdStatus::Regular), // ndq
+    (7431281, IdStatus::Reserved), // qdq
+    (7431283, IdStatus::Regular), // sdq
+    (7431284, IdStatus::Regular), // tdq
+    (7431288, IdStatus::Regular), // xdq
+    (7431521, IdStatus::Regular), // aeq
+    (7431522, IdStatus::Regular), // beq
+    (7431524, IdStatus::Regular), // deq
+    (7431527, IdStatus::Regular), // geq
+    (7431531, IdStatus::Regular), // keq
+    (7431532, IdStatus::Regular), // leq
+    (7431533, IdStatus::Regular), // meq
+    (7431534, IdStatus::Regular), // neq
+    (7431536, IdStatus::Regular), // peq
+    (7431537, IdStatus::Reserved), // qeq
+    (7431539, IdStatus::Regular), // seq
+    (7431540, IdStatus::Regular), // teq
+    (7431778, IdStatus::Regular), // bfq
+    (7431787, IdStatus::Regular), // kfq
+    (7431789, IdStatus::Regular), // mfq
+    (7431793, IdStatus::Reserved), // qfq
+    (7432033, IdStatus::Regular), // agq
+    (7432034, IdStatus::Regular), // bgq
+    (7432043, IdStatus::Regular), // kgq
+    (7432044, IdStatus::Regular), // lgq
+    (7432045, IdStatus::Regular), // mgq
+    (7432046, IdStatus::Regular), // ngq
+    (7432049, IdStatus::PrivateUse), // qgq
+    (7432052, IdStatus::Regular), // tgq
+    (7432290, IdStatus::Regular), // bhq
+    (7432291, IdStatus::Regular), // chq
+    (7432299, IdStatus::Regular), // khq
+    (7432301, IdStatus::Regular), // mhq
+    (7432302, IdStatus::Regular), // nhq
+    (7432304, IdStatus::Regular), // phq
+    (7432305, IdStatus::PrivateUse), // qhq
+    (7432307, IdStatus::Regular), // shq
+    (7432308, IdStatus::Regular), // thq
+    (7432545, IdStatus::Regular), // aiq
+    (7432546, IdStatus::Regular), // biq
+    (7432548, IdStatus::Deprecated), // diq
+    (7432551, IdStatus::Regular), // giq
+    (7432554, IdStatus::Regular), // jiq
+    (7432555, IdStatus::Regular), // kiq
+    (7432556, IdStatus::Regular), // liq
+    (7432557, IdStatus::Regular), // miq
+    (7432558, IdStatus::Regular), // niq
+    (7432561, IdStatus::PrivateUse), // qiq
+    (7432563, IdStatus::Regular), // siq
+    (7432564, IdStatus::Regular), // tiq
+    (7432569, IdStatus::Regular), // yiq
+    (7432802, IdStatus::Deprecated), // bjq
+    (7432811, IdStatus::Regular), // kjq
+    (7432813, IdStatus::Regular), // mjq
+    (7432817, IdStatus::PrivateUse), // qjq
+    (7433057, IdStatus::Regular), // akq
+    (7433058, IdStatus::Regular), // bkq
+    (7433059, IdStatus::Regular), // ckq
+    (7433067, IdStatus::Regular), // kkq
+    (7433069, IdStatus::Regular), // mkq
+    (7433070, IdStatus::Regular), // nkq
+    (7433073, IdStatus::PrivateUse), // qkq
+    (7433075, IdStatus::Regular), // skq
+    (7433076, IdStatus::Regular), // tkq
+    (7433077, IdStatus::Regular), // ukq
+    (7433080, IdStatus::Regular), // xkq
+    (7433313, IdStatus::Regular), // alq
+    (7433314, IdStatus::Regular), // blq
+    (7433323, IdStatus::Regular), // klq
+    (7433324, IdStatus::Regular), // llq
+    (7433325, IdStatus::Regular), // mlq
+    (7433326, IdStatus::Regular), // nlq
+    (7433328, IdStatus::Regular), // plq
+    (7433329, IdStatus::PrivateUse), // qlq
+    (7433331, IdStatus::Regular), // slq
+    (7433332, IdStatus::Regular), // tlq
+    (7433338, IdStatus::Regular), // zlq
+    (7433569, IdStatus::Regular), // amq
+    (7433570, IdStatus::Regular), // bmq
+    (7433573, IdStatus::Regular), // emq
+    (7433575, IdStatus::Regular), // gmq
+    (7433576, IdStatus::Regular), // hmq
+    (7433579, IdStatus::Regular), // kmq
+    (7433580, IdStatus::Regular), // lmq
+    (7433581, IdStatus::Regular), // mmq
+    (7433582, IdStatus::Regular), // nmq
+    (7433583, IdStatus::Regular), // omq
+    (7433584, IdStatus::Regular), // pmq
+    (7433585, IdStatus::PrivateUse), // qmq
+    (7433586, IdStatus::Regular), // rmq
+    (7433587, IdStatus::Regular), // smq
+    (7433588, IdStatus::Regular), // tmq
+    (7433590, IdStatus::Regular), // vmq
+    (7433592, IdStatus::Regular), // xmq
+    (7433593, IdStatus::Regular), // ymq
+    (7433594, IdStatus::Regular), // zmq
+    (7433825, IdStatus::Regular), // anq
+    (7433826, IdStatus::Regular), // bnq
+    (7433827, IdStatus::Regular), // cnq
+    (7433829, IdStatus::Regular), // enq
+    (7433831, IdStatus::Regular), // gnq
+    (7433835, IdStatus::Regular), // knq
+    (7433837, IdStatus::Regular), // mnq
+    (7433838, IdStatus::Regular), // nnq
+    (7433840, IdStatus::Regular), // pnq
+    (7433841, IdStatus::PrivateUse), // qnq
+    (7433843, IdStatus::Regular), // snq
+    (7433844, IdStatus::Regular), // tnq
+    (7433848, IdStatus::Regular), // xnq
+    (7433849, IdStatus::Regular), // ynq
+    (7434082, IdStatus::Regular), // boq
+    (7434083, IdStatus::Regular), // coq
+    (7434084, IdStatus::Regular), // doq
+    (7434087, IdStatus::Regular), // goq
+    (7434091, IdStatus::Regular), // koq
+    (7434092, IdStatus::Regular), // loq
+    (7434093, IdStatus::Regular), // moq
+    (7434094, IdStatus::Regular), // noq
+    (7434096, IdStatus::Regular), // poq
+    (7434097, IdStatus::PrivateUse), // qoq
+    (7434099, IdStatus::Regular), // soq
+    (7434100, IdStatus::Regular), // toq
+    (7434106, IdStatus::Regular), // zoq
+    (7434337, IdStatus::Regular), // apq
+    (7434338, IdStatus::Regular), // bpq
+    (7434347, IdStatus::Regular), // kpq
+    (7434349, IdStatus::Regular), // mpq
+    (7434352, IdStatus::Regular), // ppq
+    (7434353, IdStatus::PrivateUse), // qpq
+    (7434355, IdStatus::Regular), // spq
+    (7434356, IdStatus::Regular), // tpq
+    (7434360, IdStatus::Regular), // xpq
+    (7434362, IdStatus::Regular), // zpq
+    (7434594, IdStatus::Regular), // bqq
+    (7434603, IdStatus::Regular), // kqq
+    (7434605, IdStatus::Regular), // mqq
+    (7434606, IdStatus::Regular), // nqq
+    (7434609, IdStatus::PrivateUse), // qqq
+    (7434611, IdStatus::Regular), // sqq
+    (7434612, IdStatus::Regular), // tqq
+    (7434849, IdStatus::Regular), // arq
+    (7434850, IdStatus::Regular), // brq
+    (7434851, IdStatus::Regular), // crq
+    (7434852, IdStatus::Regular), // drq
+    (7434854, IdStatus::Regular), // frq
+    (7434855, IdStatus::Regular), // grq
+    (7434861, IdStatus::Regular), // mrq
+    (7434864, IdStatus::Regular), // prq
+    (7434865, IdStatus::PrivateUse), // qrq
+    (7434867, IdStatus::Regular), // srq
+    (7434868, IdStatus::Regular), // trq
+    (7434872, IdStatus::Deprecated), // xrq
+    (7435105, IdStatus::Regular), // asq
+    (7435106, IdStatus::Regular), // bsq
+    (7435107, IdStatus::Regular), // csq
+    (7435108, IdStatus::Regular), // dsq
+    (7435109, IdStatus::Regular), // esq
+    (7435115, IdStatus::Regular), // ksq
+    (7435117, IdStatus::Regular), // msq
+    (7435118, IdStatus::Regular), // nsq
+    (7435120, IdStatus::Regular), // psq
+    (7435121, IdStatus::PrivateUse), // qsq
+    (7435123, IdStatus::Regular), // ssq
+    (7435124, IdStatus::Regular), // tsq
+    (7435128, IdStatus::Regular), // xsq
+    (7435361, IdStatus::Regular), // atq
+    (7435362, IdStatus::Regular), // btq
+    (7435371, IdStatus::Regular), // ktq
+    (7435373, IdStatus::Regular), // mtq
+    (7435375, IdStatus::Regular), // otq
+    (7435376, IdStatus::Regular), // ptq
+    (7435377, IdStatus::PrivateUse), // qtq
+    (7435379, IdStatus::Regular), // stq
+    (7435380, IdStatus::Deprecated), // ttq
+    (7435384, IdStatus::Regular), // xtq
+    (7435386, IdStatus::Regular), // ztq
+    (7435617, IdStatus::Regular), // auq
+    (7435618, IdStatus::Regular), // buq
+    (7435619, IdStatus::Regular), // cuq
+    (7435620, IdStatus::Regular), // duq
+    (7435621, IdStatus::Regular), // euq
+    (7435622, IdStatus::Regular), // fuq
+    (7435623, IdStatus::Regular), // guq
+    (7435624, IdStatus::Regular), // huq
+    (7435627, IdStatus::Regular), // kuq
+    (7435628, IdStatus::Regular), // luq
+    (7435629, IdStatus::Regular), // muq
+    (7435630, IdStatus::Regular), // nuq
+    (7435632, IdStatus::Regular), // puq
+    (7435633, IdStatus::Regular), // quq
+    (7435634, IdStatus::Regular), // ruq
+    (7435635, IdStatus::Regular), // suq
+    (7435636, IdStatus::Regular), // tuq
+    (7435641, IdStatus::Regular), // yuq
+    (7435874, IdStatus::Regular), // bvq
+    (7435883, IdStatus::Regular), // kvq
+    (7435885, IdStatus::Regular), // mvq
+    (7436130, IdStatus::Regular), // bwq
+    (7436139, IdStatus::Deprecated), // kwq
+    (7436141, IdStatus::Regular), // mwq
+    (7436147, IdStatus::Regular), // swq
+    (7436148, IdStatus::Regular), // twq
+    (7436153, IdStatus::Regular), // ywq
+    (7436386, IdStatus::Regular), // bxq
+    (7436395, IdStatus::Regular), // kxq
+    (7436397, IdStatus::Regular), // mxq
+    (7436398, IdStatus::Regular), // nxq
+    (7436401, IdStatus::Regular), // qxq
+    (7436404, IdStatus::Regular), // txq
+    (7436641, IdStatus::Regular), // ayq
+    (7436642, IdStatus::Regular), // byq
+    (7436651, IdStatus::Regular), // kyq
+    (7436653, IdStatus::Deprecated), // myq
+    (7436654, IdStatus::Regular), // nyq
+    (7436898, IdStatus::Regular), // bzq
+    (7436907, IdStatus::Regular), // kzq
+    (7436909, IdStatus::Regular), // mzq
+    (7496034, IdStatus::Regular), // bar
+    (7496035, IdStatus::Regular), // car
+    (7496036, IdStatus::Regular), // dar
+    (7496038, IdStatus::Regular), // far
+    (7496039, IdStatus::Regular), // gar
+    (7496040, IdStatus::Regular), // har
+    (7496041, IdStatus::Regular), // iar
+    (7496042, IdStatus::Deprecated), // jar
+    (7496043, IdStatus::Regular), // kar
+    (7496044, IdStatus::Regular), // lar
+    (7496046, IdStatus::Regular), // nar
+    (7496047, IdStatus::Regular), // oar
+    (7496048, IdStatus::Regular), // par
+    (7496049, IdStatus::Reserved), // qar
+    (7496050, IdStatus::Regular), // rar
+    (7496051, IdStatus::Regular), // sar
+    (7496052, IdStatus::Regular), // tar
+    (7496053, IdStatus::Regular), // uar
+    (7496054, IdStatus::Regular), // var
+    (7496055, IdStatus::Regular), // war
+    (7496056, IdStatus::Regular), // xar
+    (7496057, IdStatus::Regular), // yar
+    (7496058, IdStatus::Regular), // zar
+    (7496289, IdStatus::Regular), // abr
+    (7496290, IdStatus::Regular), // bbr
+    (7496291, IdStatus::Regular), // cbr
+    (7496292, IdStatus::Regular), // dbr
+    (7496293, IdStatus::Regular), // ebr
+    (7496295, IdStatus::Regular), // gbr
+    (7496297, IdStatus::Regular), // ibr
+    (7496298, IdStatus::Regular), // jbr
+    (7496299, IdStatus::Regular), // kbr
+    (7496300, IdStatus::Regular), // lbr
+    (7496301, IdStatus::Regular), // mbr
+    (7496302, IdStatus::Regular), // nbr
+    (7496303, IdStatus::Regular), // obr
+    (7496304, IdStatus::Regular), // pbr
+    (7496305, IdStatus::Reserved), // qbr
+    (7496307, IdStatus::Regular), // sbr
+    (7496308, IdStatus::Regular), // tbr
+    (7496309, IdStatus::Regular), // ubr
+    (7496311, IdStatus::Regular), // wbr
+    (7496312, IdStatus::Regular), // xbr
+    (7496545, IdStatus::Regular), // acr
+    (7496546, IdStatus::Regular), // bcr
+    (7496547, IdStatus::Regular), // ccr
+    (7496548, IdStatus::Regular), // dcr
+    (7496549, IdStatus::Regular), // ecr
+    (7496551, IdStatus::Regular), // gcr
+    (7496553, IdStatus::Regular), // icr
+    (7496555, IdStatus::Regular), // kcr
+    (7496557, IdStatus::Regular), // mcr
+    (7496558, IdStatus::Regular), // ncr
+    (7496560, IdStatus::Deprecated), // pcr
+    (7496561, IdStatus::Reserved), // qcr
+    (7496568, IdStatus::Regular), // xcr
+    (7496801, IdStatus::Regular), // adr
+    (7496802, IdStatus::Regular), // bdr
+    (7496803, IdStatus::Regular), // cdr
+    (7496804, IdStatus::Regular), // ddr
+    (7496807, IdStatus::Regular), // gdr
+    (7496809, IdStatus::Regular), // idr
+    (7496811, IdStatus::Regular), // kdr
+    (7496813, IdStatus::Regular), // mdr
+    (7496814, IdStatus::Regular), // ndr
+    (7496817, IdStatus::Reserved), // qdr
+    (7496819, IdStatus::Regular), // sdr
+    (7496820, IdStatus::Regular), // tdr
+    (7497057, IdStatus::Regular), // aer
+    (7497058, IdStatus::Regular), // ber
+    (7497060, IdStatus::Regular), // der
+    (7497062, IdStatus::Regular), // fer
+    (7497066, IdStatus::Regular), // jer
+    (7497067, IdStatus::Regular), // ker
+    (7497068, IdStatus::Regular), // ler
+    (7497069, IdStatus::Regular), // mer
+    (7497070, IdStatus::Regular), // ner
+    (7497073, IdStatus::Reserved), // qer
+    (7497074, IdStatus::Regular), // rer
+    (7497075, IdStatus::Regular), // ser
+    (7497076, IdStatus::Regular), // ter
+    (7497078, IdStatus::Regular), // ver
+    (7497079, IdStatus::Regular), // wer
+    (7497080, IdStatus::Regular), // xer
+    (7497081, IdStatus::Regular), // yer
+    (7497314, IdStatus::Regular), // bfr
+    (7497323, IdStatus::Regular), // kfr
+    (7497325, IdStatus::Regular), // mfr
+    (7497326, IdStatus::Regular), // nfr
+    (7497329, IdStatus::Reserved), // qfr
+    (7497332, IdStatus::Regular), // tfr
+    (7497569, IdStatus::Regular), // agr
+    (7497570, IdStatus::Regular), // bgr
+    (7497572, IdStatus::Regular), // dgr
+    (7497574, IdStatus::Regular), // fgr
+    (7497575, IdStatus::Deprecated), // ggr
+    (7497579, IdStatus::Regular), // kgr
+    (7497580, IdStatus::Regular), // lgr
+    (7497581, IdStatus::Regular), // mgr
+    (7497582, IdStatus::Regular), // ngr
+    (7497585, IdStatus::PrivateUse), // qgr
+    (7497586, IdStatus::Regular), // rgr
+    (7497587, IdStatus::Regular), // sgr
+    (7497588, IdStatus::Regular), // tgr
+    (7497590, IdStatus::Regular), // vgr
+    (7497592, IdStatus::Regular), // xgr
+    (7497593, IdStatus::Regular), // ygr
+    (7497594, IdStatus::Regular), // zgr
+    (7497825, IdStatus::Regular), // ahr
+    (7497826, IdStatus::Regular), // bhr
+    (7497827, IdStatus::Regular), // chr
+    (7497828, IdStatus::Regular), // dhr
+    (7497831, IdStatus::Regular), // ghr
+    (7497832, IdStatus::Regular), // hhr
+    (7497835, IdStatus::Regular), // khr
+    (7497837, IdStatus::Deprecated), // mhr
+    (7497838, IdStatus::Regular), // nhr
+    (7497840, IdStatus::Regular), // phr
+    (7497841, IdStatus::PrivateUse), // qhr
+    (7497843, IdStatus::Regular), // shr
+    (7497844, IdStatus::Regular), // thr
+    (7497848, IdStatus::Regular), // xhr
+    (7498081, IdStatus::Regular), // air
+    (7498082, IdStatus::Regular), // bir
+    (7498083, IdStatus::Regular), // cir
+    (7498084, IdStatus::Regular), // dir
+    (7498086, IdStatus::Regular), // fir
+    (7498087, IdStatus::Regular), // gir
+    (7498088, IdStatus::Regular), // hir
+    (7498089, IdStatus::Regular), // iir
+    (7498092, IdStatus::Regular), // lir
+    (7498093, IdStatus::Regular), // mir
+    (7498094, IdStatus::Regular), // nir
+    (7498096, IdStatus::Regular), // pir
+    (7498097, IdStatus::PrivateUse), // qir
+    (7498098, IdStatus::Regular), // rir
+    (7498099, IdStatus::Regular), // sir
+    (7498103, IdStatus::Regular), // wir
+    (7498104, IdStatus::Regular), // xir
+    (7498105, IdStatus::Regular), // yir
+    (7498106, IdStatus::Deprecated), // zir
+    (7498338, IdStatus::Regular), // bjr
+    (7498339, IdStatus::Deprecated), // cjr
+    (7498340, IdStatus::Regular), // djr
+    (7498343, IdStatus::Regular), // gjr
+    (7498346, IdStatus::Regular), // jjr
+    (7498347, IdStatus::Regular), // kjr
+    (7498349, IdStatus::Regular), // mjr
+    (7498350, IdStatus::Regular), // njr
+    (7498353, IdStatus::PrivateUse), // qjr
+    (7498355, IdStatus::Regular), // sjr
+    (7498593, IdStatus::Regular), // akr
+    (7498594, IdStatus::Regular), // bkr
+    (7498595, IdStatus::Regular), // ckr
+    (7498596, IdStatus::Regular), // dkr
+    (7498597, IdStatus::Regular), // ekr
+    (7498601, IdStatus::Regular), // ikr
+    (7498602, IdStatus::Regular), // jkr
+    (7498603, IdStatus::Regular), // kkr
+    (7498604, IdStatus::Regular), // lkr
+    (7498605, IdStatus::Regular), // mkr
+    (7498606, IdStatus::Regular), // nkr
+    (7498607, IdStatus::Regular), // okr
+    (7498608, IdStatus::Regular), // pkr
+    (7498609, IdStatus::PrivateUse), // qkr
+    (7498611, IdStatus::Regular), // skr
+    (7498612, IdStatus::Regular), // tkr
+    (7498615, IdStatus::Regular), // wkr
+    (7498616, IdStatus::Regular), // xkr
+    (7498617, IdStatus::Regular), // ykr
+    (7498618, IdStatus::Regular), // zkr
+    (7498849, IdStatus::Regular), // alr
+    (7498850, IdStatus::Regular), // blr
+    (7498854, IdStatus::Regular), // flr
+    (7498855, IdStatus::Regular), // glr
+    (7498859, IdStatus::Regular), // klr
+    (7498861, IdStatus::Regular), // mlr
+    (7498862, IdStatus::Deprecated), // nlr
+    (7498863, IdStatus::Regular), // olr
+    (7498864, IdStatus::Regular), // plr
+    (7498865, IdStatus::PrivateUse), // qlr
+    (7498867, IdStatus::Regular), // slr
+    (7498868, IdStatus::Regular), // tlr
+    (7498871, IdStatus::Regular), // wlr
+    (7498873, IdStatus::Regular), // ylr
+    (7499105, IdStatus::Regular), // amr
+    (7499106, IdStatus::Regular), // bmr
+    (7499107, IdStatus::Regular), // cmr
+    (7499108, IdStatus::Regular), // dmr
+    (7499111, IdStatus::Regular), // gmr
+    (7499112, IdStatus::Regular), // hmr
+    (7499113, IdStatus::Regular), // imr
+    (7499114, IdStatus::Regular), // jmr
+    (7499115, IdStatus::Deprecated), // kmr
+    (7499116, IdStatus::Regular), // lmr
+    (7499117, IdStatus::Regular), // mmr
+    (7499118, IdStatus::Regular), // nmr
+    (7499119, IdStatus::Regular), // omr
+    (7499120, IdStatus::Regular), // pmr
+    (7499121, IdStatus::PrivateUse), // qmr
+    (7499122, IdStatus::Deprecated), // rmr
+    (7499123, IdStatus::Regular), // smr
+    (7499124, IdStatus::Regular), // tmr
+    (7499125, IdStatus::Regular), // umr
+    (7499126, IdStatus::Regular), // vmr
+    (7499128, IdStatus::Regular), // xmr
+    (7499129, IdStatus::Regular), // ymr
+    (7499130, IdStatus::Regular), // zmr
+    (7499361, IdStatus::Regular), // anr
+    (7499362, IdStatus::Regular), // bnr
+    (7499363, IdStatus::Deprecated), // cnr
+    (7499364, IdStatus::Regular), // dnr
+    (7499365, IdStatus::Regular), // enr
+    (7499367, IdStatus::Regular), // gnr
+    (7499371, IdStatus::Regular), // knr
+    (7499373, IdStatus::Regular), // mnr
+    (7499374, IdStatus::Regular), // nnr
+    (7499375, IdStatus::Regular), // onr
+    (7499376, IdStatus::Regular), // pnr
+    (7499377, IdStatus::PrivateUse), // qnr
+    (7499378, IdStatus::Regular), // rnr
+    (7499379, IdStatus::Regular), // snr
+    (7499380, IdStatus::Regular), // tnr
+    (7499381, IdStatus::Regular), // unr
+    (7499384, IdStatus::Regular), // xnr
+    (7499617, IdStatus::Regular), // aor
+    (7499618, IdStatus::Regular), // bor
+    (7499620, IdStatus::Regular), // dor
+    (7499622, IdStatus::Regular), // for
+    (7499623, IdStatus::Regular), // gor
+    (7499624, IdStatus::Regular), // hor
+    (7499625, IdStatus::Regular), // ior
+    (7499626, IdStatus::Regular), // jor
+    (7499628, IdStatus::Regular), // lor
+    (7499629, IdStatus::Regular), // mor
+    (7499631, IdStatus::Regular), // oor
+    (7499633, IdStatus::PrivateUse), // qor
+    (7499634, IdStatus::Regular), // ror
+    (7499635, IdStatus::Regular), // sor
+    (7499636, IdStatus::Regular), // tor
+    (7499638, IdStatus::Regular), // vor
+    (7499639, IdStatus::Regular), // wor
+    (7499640, IdStatus::Regular), // xor
+    (7499642, IdStatus::Regular), // zor
+    (7499873, IdStatus::Regular), // apr
+    (7499874, IdStatus::Regular), // bpr
+    (7499882, IdStatus::Regular), // jpr
+    (7499883, IdStatus::Regular), // kpr
+    (7499885, IdStatus::Regular), // mpr
+    (7499888, IdStatus::Deprecated), // ppr
+    (7499889, IdStatus::PrivateUse), // qpr
+    (7499891, IdStatus::Regular), // spr
+    (7499892, IdStatus::Regular), // tpr
+    (7499896, IdStatus::Regular), // xpr
+    (7499898, IdStatus::Regular), // zpr
+    (7500129, IdStatus::Regular), // aqr
+    (7500130, IdStatus::Regular), // bqr
+    (7500135, IdStatus::Regular), // gqr
+    (7500138, IdStatus::Regular), // jqr
+    (7500139, IdStatus::Regular), // kqr
+    (7500140, IdStatus::Regular), // lqr
+    (7500141, IdStatus::Regular), // mqr
+    (7500145, IdStatus::PrivateUse), // qqr
+    (7500147, IdStatus::Regular), // sqr
+    (7500148, IdStatus::Regular), // tqr
+    (7500385, IdStatus::Regular), // arr
+    (7500386, IdStatus::Regular), // brr
+    (7500387, IdStatus::Regular), // crr
+    (7500388, IdStatus::Deprecated), // drr
+    (7500389, IdStatus::Regular), // err
+    (7500390, IdStatus::Regular), // frr
+    (7500391, IdStatus::Regular), // grr
+    (7500392, IdStatus::Deprecated), // hrr
+    (7500393, IdStatus::Regular), // irr
+    (7500394, IdStatus::Regular), // jrr
+    (7500395, IdStatus::Regular), // krr
+    (7500396, IdStatus::Regular), // lrr
+    (7500397, IdStatus::Regular), // mrr
+    (7500398, IdStatus::Regular), // nrr
+    (7500399, IdStatus::Regular), // orr
+    (7500400, IdStatus::Regular), // prr
+    (7500401, IdStatus::PrivateUse), // qrr
+    (7500403, IdStatus::Regular), // srr
+    (7500404, IdStatus::Regular), // trr
+    (7500405, IdStatus::Regular), // urr
+    (7500407, IdStatus::Regular), // wrr
+    (7500408, IdStatus::Regular), // xrr
+    (7500641, IdStatus::Regular), // asr
+    (7500642, IdStatus::Regular), // bsr
+    (7500643, IdStatus::Regular), // csr
+    (7500649, IdStatus::Regular), // isr
+    (7500651, IdStatus::Regular), // ksr
+    (7500652, IdStatus::Regular), // lsr
+    (7500653, IdStatus::Regular), // msr
+    (7500654, IdStatus::Regular), // nsr
+    (7500656, IdStatus::Regular), // psr
+    (7500657, IdStatus::PrivateUse), // qsr
+    (7500659, IdStatus::Regular), // ssr
+    (7500660, IdStatus::Regular), // tsr
+    (7500663, IdStatus::Regular), // wsr
+    (7500664, IdStatus::Regular), // xsr
+    (7500665, IdStatus::Regular), // ysr
+    (7500666, IdStatus::Regular), // zsr
+    (7500897, IdStatus::Regular), // atr
+    (7500898, IdStatus::Regular), // btr
+    (7500900, IdStatus::Regular), // dtr
+    (7500901, IdStatus::Regular), // etr
+    (7500905, IdStatus::Regular), // itr
+    (7500907, IdStatus::Deprecated), // ktr
+    (7500909, IdStatus::Regular), // mtr
+    (7500910, IdStatus::Regular), // ntr
+    (7500911, IdStatus::Regular), // otr
+    (7500912, IdStatus::Regular), // ptr
+    (7500913, IdStatus::PrivateUse), // qtr
+    (7500915, IdStatus::Regular), // str
+    (7500916, IdStatus::Regular), // ttr
+    (7500917, IdStatus::Regular), // utr
+    (7500920, IdStatus::Regular), // xtr
+    (7501153, IdStatus::Regular), // aur
+    (7501155, IdStatus::Regular), // cur
+    (7501156, IdStatus::Regular), // dur
+    (7501158, IdStatus::Regular), // fur
+    (7501159, IdStatus::Regular), // gur
+    (7501160, IdStatus::Regular), // hur
+    (7501162, IdStatus::Regular), // jur
+    (7501164, IdStatus::Regular), // lur
+    (7501165, IdStatus::Regular), // mur
+    (7501166, IdStatus::Regular), // nur
+    (7501168, IdStatus::Regular), // pur
+    (7501169, IdStatus::Regular), // qur
+    (7501171, IdStatus::Regular), // sur
+    (7501173, IdStatus::Regular), // uur
+    (7501175, IdStatus::Regular), // wur
+    (7501176, IdStatus::Regular), // xur
+    (7501177, IdStatus::Regular), // yur
+    (7501410, IdStatus::Regular), // bvr
+    (7501414, IdStatus::Regular), // fvr
+    (7501415, IdStatus::Regular), // gvr
+    (7501419, IdStatus::Regular), // kvr
+    (7501421, IdStatus::Regular), // mvr
+    (7501427, IdStatus::Deprecated), // svr
+    (7501665, IdStatus::Regular), // awr
+    (7501666, IdStatus::Regular), // bwr
+    (7501668, IdStatus::Regular), // dwr
+    (7501671, IdStatus::Regular), // gwr
+    (7501675, IdStatus::Regular), // kwr
+    (7501677, IdStatus::Regular), // mwr
+    (7501678, IdStatus::Regular), // nwr
+    (7501680, IdStatus::Regular), // pwr
+    (7501682, IdStatus::Regular), // rwr
+    (7501683, IdStatus::Regular), // swr
+    (7501684, IdStatus::Regular), // twr
+    (7501687, IdStatus::Regular), // wwr
+    (7501688, IdStatus::Regular), // xwr
+    (7501689, IdStatus::Regular), // ywr
+    (7501922, IdStatus::Deprecated), // bxr
+    (7501931, IdStatus::Regular), // kxr
+    (7501933, IdStatus::Regular), // mxr
+    (7501934, IdStatus::Regular), // nxr
+    (7501937, IdStatus::Regular), // qxr
+    (7501939, IdStatus::Regular), // sxr
+    (7501940, IdStatus::Regular), // txr
+    (7501944, IdStatus::Regular), // xxr
+    (7502177, IdStatus::Deprecated), // ayr
+    (7502178, IdStatus::Regular), // byr
+    (7502183, IdStatus::Regular), // gyr
+    (7502187, IdStatus::Regular), // kyr
+    (7502189, IdStatus::Regular), // myr
+    (7502190, IdStatus::Regular), // nyr
+    (7502195, IdStatus::Regular), // syr
+    (7502196, IdStatus::Regular), // tyr
+    (7502199, IdStatus::Regular), // wyr
+    (7502201, IdStatus::Regular), // yyr
+    (7502434, IdStatus::Regular), // bzr
+    (7502441, IdStatus::Regular), // izr
+    (7502443, IdStatus::Regular), // kzr
+    (7502445, IdStatus::Regular), // mzr
+    (7561569, IdStatus::Regular), // aas
+    (7561570, IdStatus::Regular), // bas
+    (7561571, IdStatus::Regular), // cas
+    (7561572, IdStatus::Regular), // das
+    (7561575, IdStatus::Regular), // gas
+    (7561576, IdStatus::Regular), // has
+    (7561578, IdStatus::Regular), // jas
+    (7561580, IdStatus::Regular), // las
+    (7561581, IdStatus::Regular), // mas
+    (7561582, IdStatus::Regular), // nas
+    (7561584, IdStatus::Regular), // pas
+    (7561585, IdStatus::Reserved), // qas
+    (7561586, IdStatus::Regular), // ras
+    (7561587, IdStatus::Regular), // sas
+    (7561588, IdStatus::Regular), // tas
+    (7561590, IdStatus::Regular), // vas
+    (7561591, IdStatus::Regular), // was
+    (7561592, IdStatus::Regular), // xas
+    (7561593, IdStatus::Regular), // yas
+    (7561594, IdStatus::Regular), // zas
+    (7561825, IdStatus::Regular), // abs
+    (7561826, IdStatus::Regular), // bbs
+    (7561827, IdStatus::Regular), // cbs
+    (7561831, IdStatus::Regular), // gbs
+    (7561835, IdStatus::Regular), // kbs
+    (7561836, IdStatus::Regular), // lbs
+    (7561837, IdStatus::Regular), // mbs
+    (7561838, IdStatus::Regular), // nbs
+    (7561840, IdStatus::Regular), // pbs
+    (7561841, IdStatus::Reserved), // qbs
+    (7561843, IdStatus::Regular), // sbs
+    (7561844, IdStatus::Regular), // tbs
+    (7561847, IdStatus::Regular), // wbs
+    (7562081, IdStatus::Regular), // acs
+    (7562082, IdStatus::Regular), // bcs
+    (7562083, IdStatus::Regular), // ccs
+    (7562085, IdStatus::Regular), // ecs
+    (7562086, IdStatus::Regular), // fcs
+    (7562090, IdStatus::Regular), // jcs
+    (7562091, IdStatus::Regular), // kcs
+    (7562092, IdStatus::Regular), // lcs
+    (7562093, IdStatus::Regular), // mcs
+    (7562094, IdStatus::Regular), // ncs
+    (7562097, IdStatus::Reserved), // qcs
+    (7562099, IdStatus::Regular), // scs
+    (7562100, IdStatus::Regular), // tcs
+    (7562337, IdStatus::Regular), // ads
+    (7562338, IdStatus::Regular), // bds
+    (7562339, IdStatus::Regular), // cds
+    (7562340, IdStatus::Regular), // dds
+    (7562343, IdStatus::Regular), // gds
+    (7562344, IdStatus::Regular), // hds
+    (7562345, IdStatus::Regular), // ids
+    (7562349, IdStatus::Regular), // mds
+    (7562350, IdStatus::Regular), // nds
+    (7562353, IdStatus::Reserved), // qds
+    (7562355, IdStatus::Regular), // sds
+    (7562356, IdStatus::Regular), // tds
+    (7562361, IdStatus::Deprecated), // yds
+    (7562593, IdStatus::Regular), // aes
+    (7562594, IdStatus::Regular), // bes
+    (7562596, IdStatus::Regular), // des
+    (7562599, IdStatus::Regular), // ges
+    (7562603, IdStatus::Regular), // kes
+    (7562604, IdStatus::Regular), // les
+    (7562605, IdStatus::Regular), // mes
+    (7562606, IdStatus::Regular), // nes
+    (7562608, IdStatus::Deprecated), // pes
+    (7562609, IdStatus::Reserved), // qes
+    (7562610, IdStatus::Regular), // res
+    (7562611, IdStatus::Regular), // ses
+    (7562612, IdStatus::Regular), // tes
+    (7562613, IdStatus::Regular), // ues
+    (7562615, IdStatus::Regular), // wes
+    (7562616, IdStatus::Regular), // xes
+    (7562617, IdStatus::Regular), // yes
+    (7562849, IdStatus::Regular), // afs
+    (7562850, IdStatus::Regular), // bfs
+    (7562859, IdStatus::Regular), // kfs
+    (7562861, IdStatus::Regular), // mfs
+    (7562863, IdStatus::Regular), // ofs
+    (7562865, IdStatus::Reserved), // qfs
+    (7562867, IdStatus::Regular), // sfs
+    (7563105, IdStatus::Regular), // ags
+    (7563106, IdStatus::Regular), // bgs
+    (7563108, IdStatus::Regular), // dgs
+    (7563113, IdStatus::Regular), // igs
+    (7563115, IdStatus::Regular), // kgs
+    (7563117, IdStatus::Regular), // mgs
+    (7563118, IdStatus::Regular), // ngs
+    (7563120, IdStatus::Regular), // pgs
+    (7563121, IdStatus::PrivateUse), // qgs
+    (7563122, IdStatus::Regular), // rgs
+    (7563123, IdStatus::Regular), // sgs
+    (7563124, IdStatus::Regular), // tgs
+    (7563129, IdStatus::Regular), // ygs
+    (7563361, IdStatus::Regular), // ahs
+    (7563362, IdStatus::Regular), // bhs
+    (7563364, IdStatus::Regular), // dhs
+    (7563365, IdStatus::Regular), // ehs
+    (7563367, IdStatus::Regular), // ghs
+    (7563370, IdStatus::Regular), // jhs
+    (7563371, IdStatus::Regular), // khs
+    (7563372, IdStatus::Regular), // lhs
+    (7563373, IdStatus::Regular), // mhs
+    (7563377, IdStatus::PrivateUse), // qhs
+    (7563379, IdStatus::Regular), // shs
+    (7563380, IdStatus::Regular), // ths
+    (7563385, IdStatus::Regular), // yhs
+    (7563617, IdStatus::Deprecated), // ais
+    (7563620, IdStatus::Regular), // dis
+    (7563623, IdStatus::Regular), // gis
+    (7563627, IdStatus::Regular), // kis
+    (7563628, IdStatus::Regular), // lis
+    (7563629, IdStatus::Special), // mis
+    (7563630, IdStatus::Regular), // nis
+    (7563632, IdStatus::Regular), // pis
+    (7563633, IdStatus::PrivateUse), // qis
+    (7563635, IdStatus::Regular), // sis
+    (7563636, IdStatus::Regular), // tis
+    (7563637, IdStatus::Regular), // uis
+    (7563638, IdStatus::Regular), // vis
+    (7563640, IdStatus::Regular), // xis
+    (7563641, IdStatus::Regular), // yis
+    (7563873, IdStatus::Regular), // ajs
+    (7563874, IdStatus::Regular), // bjs
+    (7563875, IdStatus::Regular), // cjs
+    (7563881, IdStatus::Regular), // ijs
+    (7563883, IdStatus::Regular), // kjs
+    (7563885, IdStatus::Regular), // mjs
+    (7563886, IdStatus::Regular), // njs
+    (7563887, IdStatus::Regular), // ojs
+    (7563889, IdStatus::PrivateUse), // qjs
+    (7563890, IdStatus::Regular), // rjs
+    (7563891, IdStatus::Regular), // sjs
+    (7563892, IdStatus::Regular), // tjs
+    (7564129, IdStatus::Regular), // aks
+    (7564130, IdStatus::Regular), // bks
+    (7564131, IdStatus::Regular), // cks
+    (7564132, IdStatus::Regular), // dks
+    (7564136, IdStatus::Regular), // hks
+    (7564137, IdStatus::Regular), // iks
+    (7564138, IdStatus::Regular), // jks
+    (7564139, IdStatus::Regular), // kks
+    (7564140, IdStatus::Regular), // lks
+    (7564141, IdStatus::Regular), // mks
+    (7564142, IdStatus::Regular), // nks
+    (7564143, IdStatus::Regular), // oks
+    (7564144, IdStatus::Regular), // pks
+    (7564145, IdStatus::PrivateUse), // qks
+    (7564147, IdStatus::Regular), // sks
+    (7564148, IdStatus::Regular), // tks
+    (7564149, IdStatus::Regular), // uks
+    (7564152, IdStatus::Regular), // xks
+    (7564385, IdStatus::Deprecated), // als
+    (7564386, IdStatus::Regular), // bls
+    (7564393, IdStatus::Regular), // ils
+    (7564394, IdStatus::Regular), // jls
+    (7564395, IdStatus::Regular), // kls
+    (7564396, IdStatus::Regular), // lls
+    (7564397, IdStatus::Regular), // mls
+    (7564400, IdStatus::Regular), // pls
+    (7564401, IdStatus::PrivateUse), // qls
+    (7564403, IdStatus::Regular), // sls
+    (7564404, IdStatus::Regular), // tls
+    (7564406, IdStatus::Regular), // vls
+    (7564407, IdStatus::Regular), // wls
+    (7564408, IdStatus::Regular), // xls
+    (7564410, IdStatus::Regular), // zls
+    (7564641, IdStatus::Regular), // ams
+    (7564642, IdStatus::Regular), // bms
+    (7564643, IdStatus::Regular), // cms
+    (7564644, IdStatus::Regular), // dms
+    (7564645, IdStatus::Regular), // ems
+    (7564648, IdStatus::Regular), // hms
+    (7564649, IdStatus::Regular), // ims
+    (7564650, IdStatus::Regular), // jms
+    (7564651, IdStatus::Regular), // kms
+    (7564654, IdStatus::Regular), // nms
+    (7564656, IdStatus::Regular), // pms
+    (7564657, IdStatus::PrivateUse), // qms
+    (7564658, IdStatus::Regular), // rms
+    (7564659, IdStatus::Regular), // sms
+    (7564660, IdStatus::Regular), // tms
+    (7564661, IdStatus::Regular), // ums
+    (7564662, IdStatus::Regular), // vms
+    (7564663, IdStatus::Regular), // wms
+    (7564664, IdStatus::Regular), // xms
+    (7564665, IdStatus::Regular), // yms
+    (7564666, IdStatus::Regular), // zms
+    (7564897, IdStatus::Regular), // ans
+    (7564898, IdStatus::Regular), // bns
+    (7564899, IdStatus::Regular), // cns
+    (7564904, IdStatus::Regular), // hns
+    (7564905, IdStatus::Regular), // ins
+    (7564906, IdStatus::Regular), // jns
+    (7564907, IdStatus::Regular), // kns
+    (7564908, IdStatus::Regular), // lns
+    (7564909, IdStatus::Regular), // mns
+    (7564910, IdStatus::Deprecated), // nns
+    (7564911, IdStatus::Regular), // ons
+    (7564912, IdStatus::Regular), // pns
+    (7564913, IdStatus::PrivateUse), // qns
+    (7564915, IdStatus::Regular), // sns
+    (7564916, IdStatus::Regular), // tns
+    (7564920, IdStatus::Regular), // xns
+    (7564921, IdStatus::Regular), // yns
+    (7564922, IdStatus::Regular), // zns
+    (7565153, IdStatus::Regular), // aos
+    (7565156, IdStatus::Regular), // dos
+    (7565158, IdStatus::Regular), // fos
+    (7565159, IdStatus::Regular), // gos
+    (7565160, IdStatus::Regular), // hos
+    (7565162, IdStatus::Regular), // jos
+    (7565163, IdStatus::Regular), // kos
+    (7565164, IdStatus::Regular), // los
+    (7565165, IdStatus::Regular), // mos
+    (7565166, IdStatus::Regular), // nos
+    (7565167, IdStatus::Regular), // oos
+    (7565168, IdStatus::Regular), // pos
+    (7565169, IdStatus::PrivateUse), // qos
+    (7565171, IdStatus::Regular), // sos
+    (7565172, IdStatus::Regular), // tos
+    (7565175, IdStatus::Regular), // wos
+    (7565177, IdStatus::Deprecated), // yos
+    (7565178, IdStatus::Regular), // zos
+    (7565409, IdStatus::Regular), // aps
+    (7565410, IdStatus::Regular), // bps
+    (7565411, IdStatus::Regular), // cps
+    (7565416, IdStatus::Regular), // hps
+    (7565419, IdStatus::Regular), // kps
+    (7565421, IdStatus::Regular), // mps
+    (7565422, IdStatus::Regular), // nps
+    (7565424, IdStatus::Regular), // pps
+    (7565425, IdStatus::PrivateUse), // qps
+    (7565427, IdStatus::Regular), // sps
+    (7565432, IdStatus::Regular), // xps
+    (7565434, IdStatus::Regular), // zps
+    (7565666, IdStatus::Regular), // bqs
+    (7565670, IdStatus::Regular), // fqs
+    (7565675, IdStatus::Regular), // kqs
+    (7565677, IdStatus::Regular), // mqs
+    (7565681, IdStatus::PrivateUse), // qqs
+    (7565683, IdStatus::Regular), // sqs
+    (7565921, IdStatus::Regular), // ars
+    (7565922, IdStatus::Regular), // brs
+    (7565923, IdStatus::Regular), // crs
+    (7565924, IdStatus::Regular), // drs
+    (7565925, IdStatus::Regular), // ers
+    (7565926, IdStatus::Regular), // frs
+    (7565927, IdStatus::Regular), // grs
+    (7565931, IdStatus::Regular), // krs
+    (7565933, IdStatus::Regular), // mrs
+    (7565935, IdStatus::Regular), // ors
+    (7565936, IdStatus::Deprecated), // prs
+    (7565937, IdStatus::PrivateUse), // qrs
+    (7565939, IdStatus::Regular), // srs
+    (7565940, IdStatus::Regular), // trs
+    (7565942, IdStatus::Regular), // vrs
+    (7565943, IdStatus::Regular), // wrs
+    (7565945, IdStatus::Regular), // yrs
+    (7565946, IdStatus::Regular), // zrs
+    (7566177, IdStatus::Regular), // ass
+    (7566178, IdStatus::Regular), // bss
+    (7566179, IdStatus::Regular), // css
+    (7566181, IdStatus::Regular), // ess
+    (7566182, IdStatus::Regular), // fss
+    (7566183, IdStatus::Regular), // gss
+    (7566184, IdStatus::Regular), // hss
+    (7566187, IdStatus::Regular), // kss
+    (7566188, IdStatus::Regular), // lss
+    (7566189, IdStatus::Regular), // mss
+    (7566190, IdStatus::Regular), // nss
+    (7566192, IdStatus::Regular), // pss
+    (7566193, IdStatus::PrivateUse), // qss
+    (7566195, IdStatus::Regular), // sss
+    (7566196, IdStatus::Regular), // tss
+    (7566197, IdStatus::Regular), // uss
+    (7566199, IdStatus::Regular), // wss
+    (7566200, IdStatus::Regular), // xss
+    (7566201, IdStatus::Regular), // yss
+    (7566433, IdStatus::Regular), // ats
+    (7566434, IdStatus::Regular), // bts
+    (7566435, IdStatus::Regular), // cts
+    (7566436, IdStatus::Regular), // dts
+    (7566437, IdStatus::Regular), // ets
+    (7566440, IdStatus::Regular), // hts
+    (7566441, IdStatus::Regular), // its
+    (7566443, IdStatus::Regular), // kts
+    (7566444, IdStatus::Regular), // lts
+    (7566445, IdStatus::Regular), // mts
+    (7566446, IdStatus::Deprecated), // nts
+    (7566447, IdStatus::Regular), // ots
+    (7566449, IdStatus::PrivateUse), // qts
+    (7566450, IdStatus::Regular), // rts
+    (7566451, IdStatus::Regular), // sts
+    (7566452, IdStatus::Regular), // tts
+    (7566456, IdStatus::Regular), // xts
+    (7566458, IdStatus::Regular), // zts
+    (7566689, IdStatus::Regular), // aus
+    (7566690, IdStatus::Regular), // bus
+    (7566691, IdStatus::Regular), // cus
+    (7566692, IdStatus::Regular), // dus
+    (7566695, IdStatus::Regular), // gus
+    (7566696, IdStatus::Regular), // hus
+    (7566698, IdStatus::Regular), // jus
+    (7566699, IdStatus::Regular), // kus
+    (7566700, IdStatus::Regular), // lus
+    (7566701, IdStatus::Regular), // mus
+    (7566702, IdStatus::Regular), // nus
+    (7566705, IdStatus::Regular), // qus
+    (7566707, IdStatus::Regular), // sus
+    (7566708, IdStatus::Regular), // tus
+    (7566945, IdStatus::Regular), // avs
+    (7566951, IdStatus::Regular), // gvs
+    (7566955, IdStatus::Deprecated), // kvs
+    (7566956, IdStatus::Deprecated), // lvs
+    (7566957, IdStatus::Regular), // mvs
+    (7566961, IdStatus::Regular), // qvs
+    (7566963, IdStatus::Regular), // svs
+    (7566964, IdStatus::Regular), // tvs
+    (7566968, IdStatus::Regular), // xvs
+    (7567201, IdStatus::Regular), // aws
+    (7567202, IdStatus::Regular), // bws
+    (7567204, IdStatus::Regular), // dws
+    (7567209, IdStatus::Regular), // iws
+    (7567211, IdStatus::Regular), // kws
+    (7567212, IdStatus::Regular), // lws
+    (7567213, IdStatus::Regular), // mws
+    (7567217, IdStatus::Regular), // qws
+    (7567219, IdStatus::Regular), // sws
+    (7567458, IdStatus::Regular), // bxs
+    (7567467, IdStatus::Regular), // kxs
+    (7567469, IdStatus::Regular), // mxs
+    (7567473, IdStatus::Regular), // qxs
+    (7567475, IdStatus::Regular), // sxs
+    (7567476, IdStatus::Regular), // txs
+    (7567713, IdStatus::Regular), // ays
+    (7567714, IdStatus::Regular), // bys
+    (7567723, IdStatus::Regular), // kys
+    (7567725, IdStatus::Regular), // mys
+    (7567726, IdStatus::Regular), // nys
+    (7567728, IdStatus::Regular), // pys
+    (7567730, IdStatus::Regular), // rys
+    (7567731, IdStatus::Regular), // sys
+    (7567732, IdStatus::Regular), // tys
+    (7567970, IdStatus::Regular), // bzs
+    (7567979, IdStatus::Regular), // kzs
+    (7567981, IdStatus::Regular), // mzs
+    (7567982, IdStatus::Regular), // nzs
+    (7567987, IdStatus::Regular), // szs
+    (7567989, IdStatus::Regular), // uzs
+    (7627105, IdStatus::Regular), // aat
+    (7627106, IdStatus::Regular), // bat
+    (7627110, IdStatus::Deprecated), // fat
+    (7627111, IdStatus::Regular), // gat
+    (7627114, IdStatus::Regular), // jat
+    (7627117, IdStatus::Regular), // mat
+    (7627118, IdStatus::Regular), // nat
+    (7627120, IdStatus::Deprecated), // pat
+    (7627121, IdStatus::Reserved), // qat
+    (7627122, IdStatus::Regular), // rat
+    (7627123, IdStatus::Regular), // sat
+    (7627127, IdStatus::Regular), // wat
+    (7627128, IdStatus::Regular), // xat
+    (7627129, IdStatus::Regular), // yat
+    (7627130, IdStatus::Regular), // zat
+    (7627361, IdStatus::Regular), // abt
+    (7627362, IdStatus::Regular), // bbt
+    (7627363, IdStatus::Regular), // cbt
+    (7627364, IdStatus::Regular), // dbt
+    (7627370, IdStatus::Regular), // jbt
+    (7627371, IdStatus::Regular), // kbt
+    (7627372, IdStatus::Regular), // lbt
+    (7627373, IdStatus::Regular), // mbt
+    (7627374, IdStatus::Regular), // nbt
+    (7627375, IdStatus::Regular), // obt
+    (7627376, IdStatus::Regular), // pbt
+    (7627377, IdStatus::Reserved), // qbt
+    (7627379, IdStatus::Regular), // sbt
+    (7627380, IdStatus::Regular), // tbt
+    (7627383, IdStatus::Regular), // wbt
+    (7627386, IdStatus::Regular), // zbt
+    (7627617, IdStatus::Regular), // act
+    (7627618, IdStatus::Regular), // bct
+    (7627623, IdStatus::Regular), // gct
+    (7627626, IdStatus::Regular), // jct
+    (7627627, IdStatus::Regular), // kct
+    (7627629, IdStatus::Regular), // mct
+    (7627630, IdStatus::Regular), // nct
+    (7627633, IdStatus::Reserved), // qct
+    (7627635, IdStatus::Regular), // sct
+    (7627636, IdStatus::Regular), // tct
+    (7627640, IdStatus::Regular), // xct
+    (7627873, IdStatus::Regular), // adt
+    (7627874, IdStatus::Regular), // bdt
+    (7627879, IdStatus::Regular), // gdt
+    (7627881, IdStatus::Regular), // idt
+    (7627882, IdStatus::Regular), // jdt
+    (7627883, IdStatus::Regular), // kdt
+    (7627885, IdStatus::Regular), // mdt
+    (7627886, IdStatus::Regular), // ndt
+    (7627887, IdStatus::Regular), // odt
+    (7627888, IdStatus::Regular), // pdt
+    (7627889, IdStatus::Reserved), // qdt
+    (7627891, IdStatus::Regular), // sdt
+    (7627892, IdStatus::Regular), // tdt
+    (7627895, IdStatus::Regular), // wdt
+    (7628130, IdStatus::Regular), // bet
+    (7628131, IdStatus::Regular), // cet
+    (7628138, IdStatus::Regular), // jet
+    (7628139, IdStatus::Regular), // ket
+    (7628140, IdStatus::Regular), // let
+    (7628141, IdStatus::Regular), // met
+    (7628142, IdStatus::Regular), // net
+    (7628145, IdStatus::Reserved), // qet
+    (7628146, IdStatus::Regular), // ret
+    (7628147, IdStatus::Regular), // set
+    (7628148, IdStatus::Regular), // tet
+    (7628151, IdStatus::Regular), // wet
+    (7628152, IdStatus::Regular), // xet
+    (7628153, IdStatus::Regular), // yet
+    (7628385, IdStatus::Regular), // aft
+    (7628386, IdStatus::Regular), // bft
+    (7628391, IdStatus::Regular), // gft
+    (7628395, IdStatus::Regular), // kft
+    (7628397, IdStatus::Regular), // mft
+    (7628401, IdStatus::Reserved), // qft
+    (7628404, IdStatus::Regular), // tft
+    (7628641, IdStatus::Regular), // agt
+    (7628642, IdStatus::Regular), // bgt
+    (7628644, IdStatus::Regular), // dgt
+    (7628647, IdStatus::Regular), // ggt
+    (7628651, IdStatus::Regular), // kgt
+    (7628652, IdStatus::Regular), // lgt
+    (7628653, IdStatus::Regular), // mgt
+    (7628654, IdStatus::Regular), // ngt
+    (7628657, IdStatus::PrivateUse), // qgt
+    (7628659, IdStatus::Regular), // sgt
+    (7628660, IdStatus::Regular), // tgt
+    (7628662, IdStatus::Regular), // vgt
+    (7628897, IdStatus::Regular), // aht
+    (7628898, IdStatus::Regular), // bht
+    (7628899, IdStatus::Regular), // cht
+    (7628903, IdStatus::Regular), // ght
+    (7628907, IdStatus::Regular), // kht
+    (7628908, IdStatus::Regular), // lht
+    (7628909, IdStatus::Regular), // mht
+    (7628910, IdStatus::Regular), // nht
+    (7628911, IdStatus::Regular), // oht
+    (7628912, IdStatus::Regular), // pht
+    (7628913, IdStatus::PrivateUse), // qht
+    (7628915, IdStatus::Regular), // sht
+    (7628916, IdStatus::Regular), // tht
+    (7628920, IdStatus::Regular), // xht
+    (7629153, IdStatus::Regular), // ait
+    (7629154, IdStatus::Regular), // bit
+    (7629156, IdStatus::Deprecated), // dit
+    (7629157, IdStatus::Regular), // eit
+    (7629158, IdStatus::Regular), // fit
+    (7629159, IdStatus::Regular), // git
+    (7629160, IdStatus::Regular), // hit
+    (7629162, IdStatus::Regular), // jit
+    (7629163, IdStatus::Regular), // kit
+    (7629165, IdStatus::Regular), // mit
+    (7629166, IdStatus::Regular), // nit
+    (7629168, IdStatus::Regular), // pit
+    (7629169, IdStatus::PrivateUse), // qit
+    (7629170, IdStatus::Regular), // rit
+    (7629171, IdStatus::Regular), // sit
+    (7629172, IdStatus::Regular), // tit
+    (7629174, IdStatus::Regular), // vit
+    (7629175, IdStatus::Deprecated), // wit
+    (7629177, IdStatus::Regular), // yit
+    (7629409, IdStatus::Deprecated), // ajt
+    (7629410, IdStatus::Regular), // bjt
+    (7629419, IdStatus::Regular), // kjt
+    (7629421, IdStatus::Regular), // mjt
+    (7629422, IdStatus::Regular), // njt
+    (7629424, IdStatus::Regular), // pjt
+    (7629425, IdStatus::PrivateUse), // qjt
+    (7629427, IdStatus::Regular), // sjt
+    (7629432, IdStatus::Regular), // xjt
+    (7629665, IdStatus::Regular), // akt
+    (7629666, IdStatus::Regular), // bkt
+    (7629667, IdStatus::Regular), // ckt
+    (7629673, IdStatus::Regular), // ikt
+    (7629675, IdStatus::Regular), // kkt
+    (7629676, IdStatus::Regular), // lkt
+    (7629677, IdStatus::Regular), // mkt
+    (7629678, IdStatus::Regular), // nkt
+    (7629680, IdStatus::Regular), // pkt
+    (7629681, IdStatus::PrivateUse), // qkt
+    (7629682, IdStatus::Regular), // rkt
+    (7629683, IdStatus::Regular), // skt
+    (7629684, IdStatus::Regular), // tkt
+    (7629686, IdStatus::Regular), // vkt
+    (7629688, IdStatus::Regular), // xkt
+    (7629689, IdStatus::Regular), // ykt
+    (7629690, IdStatus::Regular), // zkt
+    (7629921, IdStatus::Regular), // alt
+    (7629922, IdStatus::Regular), // blt
+    (7629923, IdStatus::Regular), // clt
+    (7629928, IdStatus::Regular), // hlt
+    (7629931, IdStatus::Regular), // klt
+    (7629935, IdStatus::Regular), // olt
+    (7629936, IdStatus::Deprecated), // plt
+    (7629937, IdStatus::PrivateUse), // qlt
+    (7629939, IdStatus::Regular), // slt
+    (7629940, IdStatus::Regular), // tlt
+    (7630177, IdStatus::Regular), // amt
+    (7630178, IdStatus::Regular), // bmt
+    (7630179, IdStatus::Regular), // cmt
+    (7630184, IdStatus::Regular), // hmt
+    (7630185, IdStatus::Regular), // imt
+    (7630187, IdStatus::Regular), // kmt
+    (7630189, IdStatus::Regular), // mmt
+    (7630190, IdStatus::Regular), // nmt
+    (7630191, IdStatus::Regular), // omt
+    (7630192, IdStatus::Regular), // pmt
+    (7630193, IdStatus::PrivateUse), // qmt
+    (7630194, IdStatus::Regular), // rmt
+    (7630195, IdStatus::Regular), // smt
+    (7630196, IdStatus::Regular), // tmt
+    (7630199, IdStatus::Regular), // wmt
+    (7630200, IdStatus::Regular), // xmt
+    (7630201, IdStatus::Deprecated), // ymt
+    (7630202, IdStatus::Regular), // zmt
+    (7630433, IdStatus::Regular), // ant
+    (7630434, IdStatus::Regular), // bnt
+    (7630435, IdStatus::Regular), // cnt
+    (7630436, IdStatus::Regular), // dnt
+    (7630439, IdStatus::Regular), // gnt
+    (7630441, IdStatus::Regular), // int
+    (7630443, IdStatus::Regular), // knt
+    (7630445, IdStatus::Deprecated), // mnt
+    (7630446, IdStatus::Regular), // nnt
+    (7630447, IdStatus::Regular), // ont
+    (7630448, IdStatus::Regular), // pnt
+    (7630449, IdStatus::PrivateUse), // qnt
+    (7630452, IdStatus::Regular), // tnt
+    (7630456, IdStatus::Regular), // xnt
+    (7630689, IdStatus::Regular), // aot
+    (7630690, IdStatus::Regular), // bot
+    (7630691, IdStatus::Regular), // cot
+    (7630692, IdStatus::Regular), // dot
+    (7630693, IdStatus::Regular), // eot
+    (7630695, IdStatus::Regular), // got
+    (7630696, IdStatus::Regular), // hot
+    (7630699, IdStatus::Regular), // kot
+    (7630700, IdStatus::Regular), // lot
+    (7630701, IdStatus::Regular), // mot
+    (7630702, IdStatus::Regular), // not
+    (7630704, IdStatus::Regular), // pot
+    (7630705, IdStatus::PrivateUse), // qot
+    (7630710, IdStatus::Regular), // vot
+    (7630713, IdStatus::Regular), // yot
+    (7630945, IdStatus::Regular), // apt
+    (7630946, IdStatus::Regular), // bpt
+    (7630955, IdStatus::Regular), // kpt
+    (7630957, IdStatus::Regular), // mpt
+    (7630959, IdStatus::Regular), // opt
+    (7630960, IdStatus::Regular), // ppt
+    (7630961, IdStatus::PrivateUse), // qpt
+    (7630962, IdStatus::Regular), // rpt
+    (7630963, IdStatus::Regular), // spt
+    (7630964, IdStatus::Regular), // tpt
+    (7630968, IdStatus::Regular), // xpt
+    (7630970, IdStatus::Regular), // zpt
+    (7631201, IdStatus::Regular), // aqt
+    (7631202, IdStatus::Regular), // bqt
+    (7631211, IdStatus::Regular), // kqt
+    (7631213, IdStatus::Regular), // mqt
+    (7631214, IdStatus::Regular), // nqt
+    (7631217, IdStatus::PrivateUse), // qqt
+    (7631219, IdStatus::Regular), // sqt
+    (7631220, IdStatus::Regular), // tqt
+    (7631224, IdStatus::Regular), // xqt
+    (7631457, IdStatus::Regular), // art
+    (7631458, IdStatus::Regular), // brt
+    (7631459, IdStatus::Regular), // crt
+    (7631460, IdStatus::Regular), // drt
+    (7631461, IdStatus::Regular), // ert
+    (7631462, IdStatus::Regular), // frt
+    (7631463, IdStatus::Regular), // grt
+    (7631464, IdStatus::Regular), // hrt
+    (7631466, IdStatus::Regular), // jrt
+    (7631467, IdStatus::Regular), // krt
+    (7631468, IdStatus::Regular), // lrt
+    (7631469, IdStatus::Regular), // mrt
+    (7631470, IdStatus::Regular), // nrt
+    (7631471, IdStatus::Regular), // ort
+    (7631472, IdStatus::Regular), // prt
+    (7631473, IdStatus::PrivateUse), // qrt
+    (7631474, IdStatus::Regular), // rrt
+    (7631475, IdStatus::Regular), // srt
+    (7631476, IdStatus::Regular), // trt
+    (7631477, IdStatus::Regular), // urt
+    (7631478, IdStatus::Regular), // vrt
+    (7631480, IdStatus::Regular), // xrt
+    (7631713, IdStatus::Regular), // ast
+    (7631714, IdStatus::Regular), // bst
+    (7631715, IdStatus::Regular), // cst
+    (7631721, IdStatus::Regular), // ist
+    (7631723, IdStatus::Regular), // kst
+    (7631724, IdStatus::Regular), // lst
+    (7631725, IdStatus::Deprecated), // mst
+    (7631726, IdStatus::Regular), // nst
+    (7631727, IdStatus::Regular), // ost
+    (7631728, IdStatus::Regular), // pst
+    (7631729, IdStatus::PrivateUse), // qst
+    (7631731, IdStatus::Regular), // sst
+    (7631732, IdStatus::Regular), // tst
+    (7631969, IdStatus::Regular), // att
+    (7631970, IdStatus::Regular), // btt
+    (7631971, IdStatus::Regular), // ctt
+    (7631972, IdStatus::Regular), // dtt
+    (7631973, IdStatus::Regular), // ett
+    (7631977, IdStatus::Regular), // itt
+    (7631979, IdStatus::Regular), // ktt
+    (7631981, IdStatus::Regular), // mtt
+    (7631983, IdStatus::Regular), // ott
+    (7631984, IdStatus::Regular), // ptt
+    (7631985, IdStatus::PrivateUse), // qtt
+    (7631987, IdStatus::Regular), // stt
+    (7631988, IdStatus::Regular), // ttt
+    (7631992, IdStatus::Regular), // xtt
+    (7631994, IdStatus::Regular), // ztt
+    (7632225, IdStatus::Regular), // aut
+    (7632226, IdStatus::Regular), // but
+    (7632227, IdStatus::Regular), // cut
+    (7632230, IdStatus::Regular), // fut
+    (7632231, IdStatus::Regular), // gut
+    (7632232, IdStatus::Regular), // hut
+    (7632234, IdStatus::Regular), // jut
+    (7632235, IdStatus::Regular), // kut
+    (7632236, IdStatus::Regular), // lut
+    (7632237, IdStatus::Regular), // mut
+    (7632238, IdStatus::Regular), // nut
+    (7632240, IdStatus::Regular), // put
+    (7632242, IdStatus::Regular), // rut
+    (7632243, IdStatus::Regular), // sut
+    (7632244, IdStatus::Regular), // tut
+    (7632246, IdStatus::Regular), // vut
+    (7632247, IdStatus::Regular), // wut
+    (7632248, IdStatus::Regular), // xut
+    (7632249, IdStatus::Regular), // yut
+    (7632481, IdStatus::Regular), // avt
+    (7632482, IdStatus::Regular), // bvt
+    (7632491, IdStatus::Regular), // kvt
+    (7632493, IdStatus::Regular), // mvt
+    (7632500, IdStatus::Regular), // tvt
+    (7632505, IdStatus::Regular), // yvt
+    (7632737, IdStatus::Regular), // awt
+    (7632738, IdStatus::Regular), // bwt
+    (7632739, IdStatus::Regular), // cwt
+    (7632743, IdStatus::Regular), // gwt
+    (7632747, IdStatus::Regular), // kwt
+    (7632748, IdStatus::Regular), // lwt
+    (7632749, IdStatus::Regular), // mwt
+    (7632753, IdStatus::Regular), // qwt
+    (7632755, IdStatus::Regular), // swt
+    (7632756, IdStatus::Regular), // twt
+    (7632760, IdStatus::Regular), // xwt
+    (7632761, IdStatus::Regular), // ywt
+    (7632997, IdStatus::Regular), // ext
+    (7633003, IdStatus::Regular), // kxt
+    (7633005, IdStatus::Regular), // mxt
+    (7633009, IdStatus::Regular), // qxt
+    (7633012, IdStatus::Regular), // txt
+    (7633016, IdStatus::Regular), // xxt
+    (7633249, IdStatus::Regular), // ayt
+    (7633250, IdStatus::Regular), // byt
+    (7633259, IdStatus::Regular), // kyt
+    (7633261, IdStatus::Deprecated), // myt
+    (7633262, IdStatus::Regular), // nyt
+    (7633268, IdStatus::Regular), // tyt
+    (7633272, IdStatus::Regular), // xyt
+    (7633505, IdStatus::Regular), // azt
+    (7633506, IdStatus::Regular), // bzt
+    (7633507, IdStatus::Regular), // czt
+    (7633515, IdStatus::Deprecated), // kzt
+    (7633517, IdStatus::Regular), // mzt
+    (7692641, IdStatus::Regular), // aau
+    (7692642, IdStatus::Regular), // bau
+    (7692643, IdStatus::Regular), // cau
+    (7692644, IdStatus::Regular), // dau
+    (7692646, IdStatus::Regular), // fau
+    (7692647, IdStatus::Regular), // gau
+    (7692650, IdStatus::Regular), // jau
+    (7692652, IdStatus::Regular), // lau
+    (7692653, IdStatus::Regular), // mau
+    (7692656, IdStatus::Regular), // pau
+    (7692657, IdStatus::Reserved), // qau
+    (7692658, IdStatus::Regular), // rau
+    (7692659, IdStatus::Regular), // sau
+    (7692660, IdStatus::Regular), // tau
+    (7692662, IdStatus::Regular), // vau
+    (7692663, IdStatus::Regular), // wau
+    (7692664, IdStatus::Regular), // xau
+    (7692665, IdStatus::Regular), // yau
+    (7692666, IdStatus::Regular), // zau
+    (7692897, IdStatus::Regular), // abu
+    (7692898, IdStatus::Regular), // bbu
+    (7692899, IdStatus::Regular), // cbu
+    (7692900, IdStatus::Regular), // dbu
+    (7692901, IdStatus::Regular), // ebu
+    (7692903, IdStatus::Regular), // gbu
+    (7692904, IdStatus::Regular), // hbu
+    (7692905, IdStatus::Regular), // ibu
+    (7692906, IdStatus::Regular), // jbu
+    (7692907, IdStatus::Regular), // kbu
+    (7692908, IdStatus::Regular), // lbu
+    (7692909, IdStatus::Regular), // mbu
+    (7692910, IdStatus::Regular), // nbu
+    (7692911, IdStatus::Regular), // obu
+    (7692912, IdStatus::Deprecated), // pbu
+    (7692913, IdStatus::Reserved), // qbu
+    (7692915, IdStatus::Regular), // sbu
+    (7692916, IdStatus::Regular), // tbu
+    (7692917, IdStatus::Regular), // ubu
+    (7692922, IdStatus::Regular), // zbu
+    (7693153, IdStatus::Regular), // acu
+    (7693154, IdStatus::Regular), // bcu
+    (7693163, IdStatus::Regular), // kcu
+    (7693165, IdStatus::Regular), // mcu
+    (7693166, IdStatus::Regular), // ncu
+    (7693167, IdStatus::Regular), // ocu
+    (7693169, IdStatus::Reserved), // qcu
+    (7693171, IdStatus::Regular), // scu
+    (7693172, IdStatus::Regular), // tcu
+    (7693176, IdStatus::Regular), // xcu
+    (7693409, IdStatus::Regular), // adu
+    (7693410, IdStatus::Regular), // bdu
+    (7693415, IdStatus::Regular), // gdu
+    (7693417, IdStatus::Regular), // idu
+    (7693419, IdStatus::Regular), // kdu
+    (7693421, IdStatus::Regular), // mdu
+    (7693422, IdStatus::Regular), // ndu
+    (7693423, IdStatus::Regular), // odu
+    (7693424, IdStatus::Regular), // pdu
+    (7693425, IdStatus::Reserved), // qdu
+    (7693427, IdStatus::Regular), // sdu
+    (7693428, IdStatus::Deprecated), // tdu
+    (7693429, IdStatus::Regular), // udu
+    (7693431, IdStatus::Regular), // wdu
+    (7693665, IdStatus::Regular), // aeu
+    (7693666, IdStatus::Regular), // beu
+    (7693674, IdStatus::Regular), // jeu
+    (7693675, IdStatus::Regular), // keu
+    (7693676, IdStatus::Regular), // leu
+    (7693677, IdStatus::Regular), // meu
+    (7693678, IdStatus::Regular), // neu
+    (7693681, IdStatus::Reserved), // qeu
+    (7693683, IdStatus::Regular), // seu
+    (7693684, IdStatus::Regular), // teu
+    (7693687, IdStatus::Regular), // weu
+    (7693688, IdStatus::Regular), // xeu
+    (7693689, IdStatus::Regular), // yeu
+    (7693921, IdStatus::Regular), // afu
+    (7693922, IdStatus::Regular), // bfu
+    (7693929, IdStatus::Regular), // ifu
+    (7693931, IdStatus::Regular), // kfu
+    (7693933, IdStatus::Regular), // mfu
+    (7693934, IdStatus::Regular), // nfu
+    (7693935, IdStatus::Regular), // ofu
+    (7693937, IdStatus::Reserved), // qfu
+    (7694177, IdStatus::Regular), // agu
+    (7694178, IdStatus::Regular), // bgu
+    (7694180, IdStatus::Deprecated), // dgu
+    (7694183, IdStatus::Regular), // ggu
+    (7694187, IdStatus::Regular), // kgu
+    (7694188, IdStatus::Regular), // lgu
+    (7694189, IdStatus::Regular), // mgu
+    (7694190, IdStatus::Regular), // ngu
+    (7694191, IdStatus::Regular), // ogu
+    (7694192, IdStatus::Regular), // pgu
+    (7694193, IdStatus::PrivateUse), // qgu
+    (7694194, IdStatus::Regular), // rgu
+    (7694195, IdStatus::Regular), // sgu
+    (7694196, IdStatus::Regular), // tgu
+    (7694199, IdStatus::Regular), // wgu
+    (7694200, IdStatus::Regular), // xgu
+    (7694201, IdStatus::Regular), // ygu
+    (7694434, IdStatus::Regular), // bhu
+    (7694436, IdStatus::Regular), // dhu
+    (7694437, IdStatus::Regular), // ehu
+    (7694443, IdStatus::Regular), // khu
+    (7694444, IdStatus::Regular), // lhu
+    (7694445, IdStatus::Regular), // mhu
+    (7694446, IdStatus::Regular), // nhu
+    (7694447, IdStatus::Regular), // ohu
+    (7694448, IdStatus::Regular), // phu
+    (7694449, IdStatus::PrivateUse), // qhu
+    (7694451, IdStatus::Regular), // shu
+    (7694452, IdStatus::Regular), // thu
+    (7694455, IdStatus::Regular), // whu
+    (7694456, IdStatus::Regular), // xhu
+    (7694690, IdStatus::Regular), // biu
+    (7694692, IdStatus::Regular), // diu
+    (7694694, IdStatus::Regular), // fiu
+    (7694695, IdStatus::Regular), // giu
+    (7694698, IdStatus::Regular), // jiu
+    (7694699, IdStatus::Regular), // kiu
+    (7694700, IdStatus::Regular), // liu
+    (7694701, IdStatus::Regular), // miu
+    (7694702, IdStatus::Regular), // niu
+    (7694704, IdStatus::Regular), // piu
+    (7694705, IdStatus::PrivateUse), // qiu
+    (7694706, IdStatus::Regular), // riu
+    (7694707, IdStatus::Regular), // siu
+    (7694708, IdStatus::Regular), // tiu
+    (7694711, IdStatus::Regular), // wiu
+    (7694713, IdStatus::Regular), // yiu
+    (7694945, IdStatus::Deprecated), // aju
+    (7694946, IdStatus::Regular), // bju
+    (7694948, IdStatus::Regular), // dju
+    (7694951, IdStatus::Regular), // gju
+    (7694955, IdStatus::Regular), // kju
+    (7694957, IdStatus::Regular), // mju
+    (7694958, IdStatus::Regular), // nju
+    (7694961, IdStatus::PrivateUse), // qju
+    (7694963, IdStatus::Regular), // sju
+    (7694964, IdStatus::Regular), // tju
+    (7695201, IdStatus::Regular), // aku
+    (7695202, IdStatus::Regular), // bku
+    (7695203, IdStatus::Regular), // cku
+    (7695207, IdStatus::Regular), // gku
+    (7695210, IdStatus::Regular), // jku
+    (7695211, IdStatus::Regular), // kku
+    (7695212, IdStatus::Regular), // lku
+    (7695213, IdStatus::Regular), // mku
+    (7695214, IdStatus::Regular), // nku
+    (7695215, IdStatus::Regular), // oku
+    (7695216, IdStatus::Regular), // pku
+    (7695217, IdStatus::PrivateUse), // qku
+    (7695219, IdStatus::Regular), // sku
+    (7695220, IdStatus::Regular), // tku
+    (7695221, IdStatus::Regular), // uku
+    (7695222, IdStatus::Regular), // vku
+    (7695223, IdStatus::Regular), // wku
+    (7695224, IdStatus::Regular), // xku
+    (7695225, IdStatus::Regular), // yku
+    (7695226, IdStatus::Regular), // zku
+    (7695457, IdStatus::Regular), // alu
+    (7695459, IdStatus::Regular), // clu
+    (7695461, IdStatus::Regular), // elu
+    (7695463, IdStatus::Regular), // glu
+    (7695464, IdStatus::Regular), // hlu
+    (7695465, IdStatus::Regular), // ilu
+    (7695467, IdStatus::Regular), // klu
+    (7695468, IdStatus::Regular), // llu
+    (7695469, IdStatus::Regular), // mlu
+    (7695470, IdStatus::Regular), // nlu
+    (7695471, IdStatus::Regular), // olu
+    (7695472, IdStatus::Regular), // plu
+    (7695473, IdStatus::PrivateUse), // qlu
+    (7695475, IdStatus::Regular), // slu
+    (7695476, IdStatus::Regular), // tlu
+    (7695477, IdStatus::Regular), // ulu
+    (7695479, IdStatus::Regular), // wlu
+    (7695480, IdStatus::Regular), // xlu
+    (7695481, IdStatus::Regular), // ylu
+    (7695713, IdStatus::Regular), // amu
+    (7695714, IdStatus::Regular), // bmu
+    (7695716, IdStatus::Regular), // dmu
+    (7695717, IdStatus::Regular), // emu
+    (7695718, IdStatus::Regular), // fmu
+    (7695719, IdStatus::Regular), // gmu
+    (7695720, IdStatus::Regular), // hmu
+    (7695723, IdStatus::Regular), // kmu
+    (7695724, IdStatus::Regular), // lmu
+    (7695725, IdStatus::Regular), // mmu
+    (7695726, IdStatus::Regular), // nmu
+    (7695727, IdStatus::Regular), // omu
+    (7695728, IdStatus::Deprecated), // pmu
+    (7695729, IdStatus::PrivateUse), // qmu
+    (7695730, IdStatus::Regular), // rmu
+    (7695731, IdStatus::Regular), // smu
+    (7695732, IdStatus::Regular), // tmu
+    (7695733, IdStatus::Deprecated), // umu
+    (7695734, IdStatus::Regular), // vmu
+    (7695736, IdStatus::Regular), // xmu
+    (7695738, IdStatus::Regular), // zmu
+    (7695969, IdStatus::Regular), // anu
+    (7695970, IdStatus::Regular), // bnu
+    (7695971, IdStatus::Regular), // cnu
+    (7695972, IdStatus::Regular), // dnu
+    (7695973, IdStatus::Regular), // enu
+    (7695975, IdStatus::Regular), // gnu
+    (7695976, IdStatus::Regular), // hnu
+    (7695979, IdStatus::Regular), // knu
+    (7695980, IdStatus::Regular), // lnu
+    (7695981, IdStatus::Regular), // mnu
+    (7695982, IdStatus::Regular), // nnu
+    (7695983, IdStatus::Regular), // onu
+    (7695984, IdStatus::Regular), // pnu
+    (7695985, IdStatus::PrivateUse), // qnu
+    (7695987, IdStatus::Regular), // snu
+    (7695988, IdStatus::Regular), // tnu
+    (7695989, IdStatus::Regular), // unu
+    (7695991, IdStatus::Regular), // wnu
+    (7695992, IdStatus::Regular), // xnu
+    (7695993, IdStatus::Regular), // ynu
+    (7696225, IdStatus::Regular), // aou
+    (7696226, IdStatus::Regular), // bou
+    (7696227, IdStatus::Regular), // cou
+    (7696231, IdStatus::Regular), // gou
+    (7696233, IdStatus::Regular), // iou
+    (7696235, IdStatus::Regular), // kou
+    (7696236, IdStatus::Regular), // lou
+    (7696237, IdStatus::Regular), // mou
+    (7696238, IdStatus::Regular), // nou
+    (7696241, IdStatus::PrivateUse), // qou
+    (7696242, IdStatus::Regular), // rou
+    (7696243, IdStatus::Regular), // sou
+    (7696244, IdStatus::Regular), // tou
+    (7696481, IdStatus::Regular), // apu
+    (7696482, IdStatus::Regular), // bpu
+    (7696483, IdStatus::Regular), // cpu
+    (7696491, IdStatus::Regular), // kpu
+    (7696493, IdStatus::Regular), // mpu
+    (7696494, IdStatus::Regular), // npu
+    (7696496, IdStatus::Regular), // ppu
+    (7696497, IdStatus::PrivateUse), // qpu
+    (7696499, IdStatus::Regular), // spu
+    (7696500, IdStatus::Regular), // tpu
+    (7696504, IdStatus::Regular), // xpu
+    (7696506, IdStatus::Regular), // zpu
+    (7696738, IdStatus::Regular), // bqu
+    (7696739, IdStatus::Deprecated), // cqu
+    (7696743, IdStatus::Regular), // gqu
+    (7696745, IdStatus::Regular), // iqu
+    (7696747, IdStatus::Regular), // kqu
+    (7696749, IdStatus::Regular), // mqu
+    (7696753, IdStatus::PrivateUse), // qqu
+    (7696755, IdStatus::Regular), // squ
+    (7696756, IdStatus::Regular), // tqu
+    (7696993, IdStatus::Regular), // aru
+    (7696994, IdStatus::Regular), // bru
+    (7696996, IdStatus::Regular), // dru
+    (7696999, IdStatus::Regular), // gru
+    (7697000, IdStatus::Regular), // hru
+    (7697001, IdStatus::Regular), // iru
+    (7697002, IdStatus::Regular), // jru
+    (7697003, IdStatus::Regular), // kru
+    (7697005, IdStatus::Regular), // mru
+    (7697006, IdStatus::Regular), // nru
+    (7697007, IdStatus::Regular), // oru
+    (7697008, IdStatus::Regular), // pru
+    (7697009, IdStatus::PrivateUse), // qru
+    (7697011, IdStatus::Regular), // sru
+    (7697012, IdStatus::Regular), // tru
+    (7697013, IdStatus::Regular), // uru
+    (7697015, IdStatus::Regular), // wru
+    (7697016, IdStatus::Regular), // xru
+    (7697249, IdStatus::Regular), // asu
+    (7697250, IdStatus::Regular), // bsu
+    (7697251, IdStatus::Regular), // csu
+    (7697253, IdStatus::Regular), // esu
+    (7697257, IdStatus::Regular), // isu
+    (7697259, IdStatus::Regular), // ksu
+    (7697261, IdStatus::Regular), // msu
+    (7697262, IdStatus::Regular), // nsu
+    (7697263, IdStatus::Regular), // osu
+    (7697264, IdStatus::Regular), // psu
+    (7697265, IdStatus::PrivateUse), // qsu
+    (7697267, IdStatus::Regular), // ssu
+    (7697268, IdStatus::Regular), // tsu
+    (7697269, IdStatus::Regular), // usu
+    (7697271, IdStatus::Regular), // wsu
+    (7697272, IdStatus::Regular), // xsu
+    (7697274, IdStatus::Regular), // zsu
+    (7697505, IdStatus::Regular), // atu
+    (7697506, IdStatus::Regular), // btu
+    (7697507, IdStatus::Regular), // ctu
+    (7697508, IdStatus::Regular), // dtu
+    (7697509, IdStatus::Regular), // etu
+    (7697511, IdStatus::Regular), // gtu
+    (7697512, IdStatus::Regular), // htu
+    (7697515, IdStatus::Regular), // ktu
+    (7697516, IdStatus::Regular), // ltu
+    (7697517, IdStatus::Regular), // mtu
+    (7697518, IdStatus::Regular), // ntu
+    (7697519, IdStatus::Regular), // otu
+    (7697520, IdStatus::Regular), // ptu
+    (7697521, IdStatus::PrivateUse), // qtu
+    (7697523, IdStatus::Regular), // stu
+    (7697524, IdStatus::Regular), // ttu
+    (7697525, IdStatus::Regular), // utu
+    (7697528, IdStatus::Regular), // xtu
+    (7697530, IdStatus::Regular), // ztu
+    (7697761, IdStatus::Regular), // auu
+    (7697762, IdStatus::Regular), // buu
+    (7697763, IdStatus::Regular), // cuu
+    (7697764, IdStatus::Regular), // duu
+    (7697766, IdStatus::Regular), // fuu
+    (7697767, IdStatus::Regular), // guu
+    (7697768, IdStatus::Regular), // huu
+    (7697770, IdStatus::Regular), // juu
+    (7697771, IdStatus::Regular), // kuu
+    (7697772, IdStatus::Regular), // luu
+    (7697773, IdStatus::Regular), // muu
+    (7697774, IdStatus::Regular), // nuu
+    (7697776, IdStatus::Regular), // puu
+    (7697778, IdStatus::Regular), // ruu
+    (7697780, IdStatus::Regular), // tuu
+    (7697781, IdStatus::Regular), // uuu
+    (7697783, IdStatus::Regular), // wuu
+    (7697784, IdStatus::Regular), // xuu
+    (7697785, IdStatus::Deprecated), // yuu
+    (7698017, IdStatus::Regular), // avu
+    (7698018, IdStatus::Regular), // bvu
+    (7698027, IdStatus::Regular), // kvu
+    (7698028, IdStatus::Regular), // lvu
+    (7698029, IdStatus::Regular), // mvu
+    (7698036, IdStatus::Regular), // tvu
+    (7698273, IdStatus::Regular), // awu
+    (7698274, IdStatus::Regular), // bwu
+    (7698276, IdStatus::Regular), // dwu
+    (7698279, IdStatus::Regular), // gwu
+    (7698283, IdStatus::Regular), // kwu
+    (7698284, IdStatus::Regular), // lwu
+    (7698285, IdStatus::Regular), // mwu
+    (7698291, IdStatus::Regular), // swu
+    (7698292, IdStatus::Regular), // twu
+    (7698297, IdStatus::Regular), // ywu
+    (7698530, IdStatus::Regular), // bxu
+    (7698539, IdStatus::Deprecated), // kxu
+    (7698541, IdStatus::Regular), // mxu
+    (7698542, IdStatus::Deprecated), // nxu
+    (7698545, IdStatus::Regular), // qxu
+    (7698547, IdStatus::Regular), // sxu
+    (7698548, IdStatus::Regular), // txu
+    (7698553, IdStatus::Regular), // yxu
+    (7698785, IdStatus::Regular), // ayu
+    (7698788, IdStatus::Regular), // dyu
+    (7698795, IdStatus::Regular), // kyu
+    (7698797, IdStatus::Regular), // myu
+    (7698798, IdStatus::Regular), // nyu
+    (7698800, IdStatus::Regular), // pyu
+    (7698802, IdStatus::Regular), // ryu
+    (7698804, IdStatus::Regular), // tyu
+    (7698809, IdStatus::Regular), // yyu
+    (7699042, IdStatus::Regular), // bzu
+    (7699051, IdStatus::Regular), // kzu
+    (7699053, IdStatus::Regular), // mzu
+    (7699054, IdStatus::Regular), // nzu
+    (7758177, IdStatus::Regular), // aav
+    (7758178, IdStatus::Regular), // bav
+    (7758179, IdStatus::Regular), // cav
+    (7758180, IdStatus::Regular), // dav
+    (7758183, IdStatus::Deprecated), // gav
+    (7758184, IdStatus::Regular), // hav
+    (7758187, IdStatus::Regular), // kav
+    (7758189, IdStatus::Regular), // mav
+    (7758191, IdStatus::Regular), // oav
+    (7758192, IdStatus::Regular), // pav
+    (7758193, IdStatus::Reserved), // qav
+    (7758194, IdStatus::Regular), // rav
+    (7758195, IdStatus::Regular), // sav
+    (7758196, IdStatus::Regular), // tav
+    (7758198, IdStatus::Regular), // vav
+    (7758199, IdStatus::Regular), // wav
+    (7758200, IdStatus::Regular), // xav
+    (7758201, IdStatus::Regular), // yav
+    (7758202, IdStatus::Regular), // zav
+    (7758433, IdStatus::Regular), // abv
+    (7758434, IdStatus::Regular), // bbv
+    (7758435, IdStatus::Regular), // cbv
+    (7758436, IdStatus::Regular), // dbv
+    (7758439, IdStatus::Regular), // gbv
+    (7758443, IdStatus::Regular), // kbv
+    (7758444, IdStatus::Regular), // lbv
+    (7758445, IdStatus::Regular), // mbv
+    (7758446, IdStatus::Regular), // nbv
+    (7758448, IdStatus::Regular), // pbv
+    (7758449, IdStatus::Reserved), // qbv
+    (7758451, IdStatus::Regular), // sbv
+    (7758452, IdStatus::Regular), // tbv
+    (7758455, IdStatus::Regular), // wbv
+    (7758689, IdStatus::Regular), // acv
+    (7758690, IdStatus::Regular), // bcv
+    (7758699, IdStatus::Regular), // kcv
+    (7758701, IdStatus::Regular), // mcv
+    (7758705, IdStatus::Reserved), // qcv
+    (7758707, IdStatus::Regular), // scv
+    (7758712, IdStatus::Regular), // xcv
+    (7758946, IdStatus::Regular), // bdv
+    (7758955, IdStatus::Deprecated), // kdv
+    (7758957, IdStatus::Regular), // mdv
+    (7758958, IdStatus::Regular), // ndv
+    (7758961, IdStatus::Reserved), // qdv
+    (7758963, IdStatus::Regular), // sdv
+    (7758964, IdStatus::Regular), // tdv
+    (7759202, IdStatus::Regular), // bev
+    (7759204, IdStatus::Regular), // dev
+    (7759207, IdStatus::Regular), // gev
+    (7759211, IdStatus::Regular), // kev
+    (7759212, IdStatus::Regular), // lev
+    (7759213, IdStatus::Regular), // mev
+    (7759214, IdStatus::Regular), // nev
+    (7759216, IdStatus::Regular), // pev
+    (7759217, IdStatus::Reserved), // qev
+    (7759219, IdStatus::Regular), // sev
+    (7759220, IdStatus::Regular), // tev
+    (7759225, IdStatus::Regular), // yev
+    (7759467, IdStatus::Regular), // kfv
+    (7759469, IdStatus::Regular), // mfv
+    (7759473, IdStatus::Reserved), // qfv
+    (7759713, IdStatus::Regular), // agv
+    (7759714, IdStatus::Regular), // bgv
+    (7759723, IdStatus::Regular), // kgv
+    (7759725, IdStatus::Regular), // mgv
+    (7759726, IdStatus::Regular), // ngv
+    (7759729, IdStatus::PrivateUse), // qgv
+    (7759732, IdStatus::Regular), // tgv
+    (7759970, IdStatus::Regular), // bhv
+    (7759972, IdStatus::Regular), // dhv
+    (7759979, IdStatus::Regular), // khv
+    (7759982, IdStatus::Regular), // nhv
+    (7759984, IdStatus::Regular), // phv
+    (7759985, IdStatus::PrivateUse), // qhv
+    (7759987, IdStatus::Regular), // shv
+    (7759988, IdStatus::Regular), // thv
+    (7759992, IdStatus::Regular), // xhv
+    (7760226, IdStatus::Regular), // biv
+    (7760229, IdStatus::Regular), // eiv
+    (7760234, IdStatus::Regular), // jiv
+    (7760235, IdStatus::Regular), // kiv
+    (7760236, IdStatus::Regular), // liv
+    (7760238, IdStatus::Regular), // niv
+    (7760240, IdStatus::Regular), // piv
+    (7760241, IdStatus::PrivateUse), // qiv
+    (7760243, IdStatus::Regular), // siv
+    (7760244, IdStatus::Regular), // tiv
+    (7760245, IdStatus::Regular), // uiv
+    (7760246, IdStatus::Regular), // viv
+    (7760247, IdStatus::Regular), // wiv
+    (7760248, IdStatus::Regular), // xiv
+    (7760249, IdStatus::Regular), // yiv
+    (7760482, IdStatus::Regular), // bjv
+    (7760483, IdStatus::Regular), // cjv
+    (7760491, IdStatus::Regular), // kjv
+    (7760493, IdStatus::Regular), // mjv
+    (7760495, IdStatus::Regular), // ojv
+    (7760497, IdStatus::PrivateUse), // qjv
+    (7760737, IdStatus::Regular), // akv
+    (7760738, IdStatus::Regular), // bkv
+    (7760739, IdStatus::Regular), // ckv
+    (7760742, IdStatus::Regular), // fkv
+    (7760745, IdStatus::Regular), // ikv
+    (7760747, IdStatus::Regular), // kkv
+    (7760749, IdStatus::Regular), // mkv
+    (7760750, IdStatus::Regular), // nkv
+    (7760751, IdStatus::Regular), // okv
+    (7760753, IdStatus::PrivateUse), // qkv
+    (7760755, IdStatus::Regular), // skv
+    (7760756, IdStatus::Regular), // tkv
+    (7760757, IdStatus::Regular), // ukv
+    (7760760, IdStatus::Regular), // xkv
+    (7760762, IdStatus::Regular), // zkv
+    (7760993, IdStatus::Regular), // alv
+    (7760994, IdStatus::Regular), // blv
+    (7761001, IdStatus::Regular), // ilv
+    (7761003, IdStatus::Regular), // klv
+    (7761005, IdStatus::Regular), // mlv
+    (7761006, IdStatus::Regular), // nlv
+    (7761008, IdStatus::Regular), // plv
+    (7761009, IdStatus::PrivateUse), // qlv
+    (7761012, IdStatus::Regular), // tlv
+    (7761015, IdStatus::Regular), // wlv
+    (7761249, IdStatus::Regular), // amv
+    (7761250, IdStatus::Regular), // bmv
+    (7761252, IdStatus::Regular), // dmv
+    (7761255, IdStatus::Regular), // gmv
+    (7761256, IdStatus::Regular), // hmv
+    (7761259, IdStatus::Regular), // kmv
+    (7761260, IdStatus::Regular), // lmv
+    (7761261, IdStatus::Regular), // mmv
+    (7761262, IdStatus::Regular), // nmv
+    (7761263, IdStatus::Regular), // omv
+    (7761265, IdStatus::PrivateUse), // qmv
+    (7761266, IdStatus::Regular), // rmv
+    (7761267, IdStatus::Regular), // smv
+    (7761268, IdStatus::Regular), // tmv
+    (7761270, IdStatus::Regular), // vmv
+    (7761272, IdStatus::Regular), // xmv
+    (7761274, IdStatus::Regular), // zmv
+    (7761505, IdStatus::Regular), // anv
+    (7761506, IdStatus::Regular), // bnv
+    (7761508, IdStatus::Regular), // dnv
+    (7761509, IdStatus::Regular), // env
+    (7761515, IdStatus::Regular), // knv
+    (7761517, IdStatus::Regular), // mnv
+    (7761518, IdStatus::Regular), // nnv
+    (7761520, IdStatus::Regular), // pnv
+    (7761521, IdStatus::PrivateUse), // qnv
+    (7761523, IdStatus::Regular), // snv
+    (7761524, IdStatus::Regular), // tnv
+    (7761762, IdStatus::Regular), // bov
+    (7761763, IdStatus::Regular), // cov
+    (7761764, IdStatus::Regular), // dov
+    (7761767, IdStatus::Regular), // gov
+    (7761768, IdStatus::Regular), // hov
+    (7761771, IdStatus::Regular), // kov
+    (7761772, IdStatus::Regular), // lov
+    (7761773, IdStatus::Regular), // mov
+    (7761774, IdStatus::Regular), // nov
+    (7761776, IdStatus::Regular), // pov
+    (7761777, IdStatus::PrivateUse), // qov
+    (7761779, IdStatus::Regular), // sov
+    (7761780, IdStatus::Regular), // tov
+    (7762017, IdStatus::Regular), // apv
+    (7762018, IdStatus::Regular), // bpv
+    (7762027, IdStatus::Deprecated), // kpv
+    (7762029, IdStatus::Regular), // mpv
+    (7762033, IdStatus::PrivateUse), // qpv
+    (7762035, IdStatus::Regular), // spv
+    (7762036, IdStatus::Regular), // tpv
+    (7762037, IdStatus::Regular), // upv
+    (7762040, IdStatus::Regular), // xpv
+    (7762042, IdStatus::Regular), // zpv
+    (7762274, IdStatus::Regular), // bqv
+    (7762283, IdStatus::Regular), // kqv
+    (7762285, IdStatus::Regular), // mqv
+    (7762289, IdStatus::PrivateUse), // qqv
+    (7762529, IdStatus::Regular), // arv
+    (7762530, IdStatus::Regular), // brv
+    (7762531, IdStatus::Regular), // crv
+    (7762535, IdStatus::Regular), // grv
+    (7762539, IdStatus::Regular), // krv
+    (7762540, IdStatus::Regular), // lrv
+    (7762541, IdStatus::Regular), // mrv
+    (7762543, IdStatus::Regular), // orv
+    (7762545, IdStatus::PrivateUse), // qrv
+    (7762547, IdStatus::Regular), // srv
+    (7762548, IdStatus::Regular), // trv
+    (7762549, IdStatus::Regular), // urv
+    (7762551, IdStatus::Regular), // wrv
+    (7762785, IdStatus::Regular), // asv
+    (7762786, IdStatus::Regular), // bsv
+    (7762787, IdStatus::Regular), // csv
+    (7762795, IdStatus::Regular), // ksv
+    (7762796, IdStatus::Regular), // lsv
+    (7762797, IdStatus::Regular), // msv
+    (7762798, IdStatus::Regular), // nsv
+    (7762801, IdStatus::PrivateUse), // qsv
+    (7762803, IdStatus::Regular), // ssv
+    (7762804, IdStatus::Regular), // tsv
+    (7762806, IdStatus::Regular), // vsv
+    (7762807, IdStatus::Regular), // wsv
+    (7762808, IdStatus::Regular), // xsv
+    (7763041, IdStatus::Regular), // atv
+    (7763042, IdStatus::Regular), // btv
+    (7763049, IdStatus::Regular), // itv
+    (7763051, IdStatus::Regular), // ktv
+    (7763053, IdStatus::Regular), // mtv
+    (7763056, IdStatus::Regular), // ptv
+    (7763057, IdStatus::PrivateUse), // qtv
+    (7763059, IdStatus::Regular), // stv
+    (7763060, IdStatus::Regular), // ttv
+    (7763064, IdStatus::Regular), // xtv
+    (7763298, IdStatus::Regular), // buv
+    (7763299, IdStatus::Regular), // cuv
+    (7763300, IdStatus::Regular), // duv
+    (7763302, IdStatus::Regular), // fuv
+    (7763303, IdStatus::Deprecated), // guv
+    (7763304, IdStatus::Regular), // huv
+    (7763307, IdStatus::Regular), // kuv
+    (7763308, IdStatus::Regular), // luv
+    (7763309, IdStatus::Regular), // muv
+    (7763310, IdStatus::Regular), // nuv
+    (7763313, IdStatus::Regular), // quv
+    (7763315, IdStatus::Regular), // suv
+    (7763316, IdStatus::Regular), // tuv
+    (7763319, IdStatus::Regular), // wuv
+    (7763553, IdStatus::Regular), // avv
+    (7763554, IdStatus::Regular), // bvv
+    (7763560, IdStatus::Regular), // hvv
+    (7763561, IdStatus::Regular), // ivv
+    (7763563, IdStatus::Regular), // kvv
+    (7763565, IdStatus::Regular), // mvv
+    (7763809, IdStatus::Regular), // awv
+    (7763819, IdStatus::Regular), // kwv
+    (7763821, IdStatus::Regular), // mwv
+    (7763827, IdStatus::Regular), // swv
+    (7764066, IdStatus::Regular), // bxv
+    (7764075, IdStatus::Regular), // kxv
+    (7764077, IdStatus::Regular), // mxv
+    (7764322, IdStatus::Regular), // byv
+    (7764331, IdStatus::Regular), // kyv
+    (7764333, IdStatus::Regular), // myv
+    (7764334, IdStatus::Regular), // nyv
+    (7764340, IdStatus::Regular), // tyv
+    (7764578, IdStatus::Regular), // bzv
+    (7764587, IdStatus::Regular), // kzv
+    (7764589, IdStatus::Regular), // mzv
+    (7764595, IdStatus::Regular), // szv
+    (7823713, IdStatus::Regular), // aaw
+    (7823714, IdStatus::Regular), // baw
+    (7823715, IdStatus::Regular), // caw
+    (7823716, IdStatus::Regular), // daw
+    (7823719, IdStatus::Regular), // gaw
+    (7823720, IdStatus::Regular), // haw
+    (7823723, IdStatus::Regular), // kaw
+    (7823724, IdStatus::Regular), // law
+    (7823725, IdStatus::Regular), // maw
+    (7823726, IdStatus::Regular), // naw
+    (7823728, IdStatus::Regular), // paw
+    (7823729, IdStatus::Reserved), // qaw
+    (7823730, IdStatus::Regular), // raw
+    (7823731, IdStatus::Regular), // saw
+    (7823732, IdStatus::Regular), // taw
+    (7823735, IdStatus::Regular), // waw
+    (7823736, IdStatus::Regular), // xaw
+    (7823737, IdStatus::Regular), // yaw
+    (7823738, IdStatus::Regular), // zaw
+    (7823969, IdStatus::Regular), // abw
+    (7823970, IdStatus::Regular), // bbw
+    (7823971, IdStatus::Regular), // cbw
+    (7823972, IdStatus::Regular), // dbw
+    (7823975, IdStatus::Regular), // gbw
+    (7823978, IdStatus::Regular), // jbw
+    (7823979, IdStatus::Regular), // kbw
+    (7823980, IdStatus::Regular), // lbw
+    (7823981, IdStatus::Regular), // mbw
+    (7823982, IdStatus::Regular), // nbw
+    (7823985, IdStatus::Reserved), // qbw
+    (7823987, IdStatus::Regular), // sbw
+    (7823988, IdStatus::Regular), // tbw
+    (7823991, IdStatus::Regular), // wbw
+    (7823992, IdStatus::Regular), // xbw
+    (7823994, IdStatus::Regular), // zbw
+    (7824225, IdStatus::Regular), // acw
+    (7824226, IdStatus::Regular), // bcw
+    (7824235, IdStatus::Regular), // kcw
+    (7824237, IdStatus::Regular), // mcw
+    (7824240, IdStatus::Regular), // pcw
+    (7824241, IdStatus::Reserved), // qcw
+    (7824243, IdStatus::Regular), // scw
+    (7824244, IdStatus::Regular), // tcw
+    (7824248, IdStatus::Regular), // xcw
+    (7824481, IdStatus::Regular), // adw
+    (7824482, IdStatus::Regular), // bdw
+    (7824484, IdStatus::Regular), // ddw
+    (7824491, IdStatus::Regular), // kdw
+    (7824493, IdStatus::Regular), // mdw
+    (7824494, IdStatus::Regular), // ndw
+    (7824497, IdStatus::Reserved), // qdw
+    (7824737, IdStatus::Regular), // aew
+    (7824738, IdStatus::Regular), // bew
+    (7824743, IdStatus::Regular), // gew
+    (7824747, IdStatus::Regular), // kew
+    (7824748, IdStatus::Regular), // lew
+    (7824749, IdStatus::Regular), // mew
+    (7824750, IdStatus::Regular), // new
+    (7824753, IdStatus::Reserved), // qew
+    (7824755, IdStatus::Regular), // sew
+    (7824756, IdStatus::Regular), // tew
+    (7824759, IdStatus::Regular), // wew
+    (7824994, IdStatus::Regular), // bfw
+    (7825003, IdStatus::Regular), // kfw
+    (7825005, IdStatus::Regular), // mfw
+    (7825009, IdStatus::Reserved), // qfw
+    (7825011, IdStatus::Regular), // sfw
+    (7825249, IdStatus::Regular), // agw
+    (7825250, IdStatus::Regular), // bgw
+    (7825252, IdStatus::Regular), // dgw
+    (7825255, IdStatus::Regular), // ggw
+    (7825256, IdStatus::Regular), // hgw
+    (7825257, IdStatus::Regular), // igw
+    (7825259, IdStatus::Regular), // kgw
+    (7825261, IdStatus::Regular), // mgw
+    (7825262, IdStatus::Regular), // ngw
+    (7825265, IdStatus::PrivateUse), // qgw
+    (7825267, IdStatus::Regular), // sgw
+    (7825268, IdStatus::Regular), // tgw
+    (7825271, IdStatus::Deprecated), // wgw
+    (7825272, IdStatus::Regular), // xgw
+    (7825273, IdStatus::Regular), // ygw
+    (7825506, IdStatus::Regular), // bhw
+    (7825507, IdStatus::Regular), // chw
+    (7825508, IdStatus::Regular), // dhw
+    (7825513, IdStatus::Regular), // ihw
+    (7825515, IdStatus::Regular), // khw
+    (7825517, IdStatus::Regular), // mhw
+    (7825518, IdStatus::Regular), // nhw
+    (7825520, IdStatus::Regular), // phw
+    (7825521, IdStatus::PrivateUse), // qhw
+    (7825523, IdStatus::Regular), // shw
+    (7825524, IdStatus::Deprecated), // thw
+    (7825530, IdStatus::Regular), // zhw
+    (7825761, IdStatus::Regular), // aiw
+    (7825762, IdStatus::Regular), // biw
+    (7825763, IdStatus::Regular), // ciw
+    (7825764, IdStatus::Regular), // diw
+    (7825766, IdStatus::Regular), // fiw
+    (7825767, IdStatus::Regular), // giw
+    (7825768, IdStatus::Regular), // hiw
+    (7825771, IdStatus::Regular), // kiw
+    (7825772, IdStatus::Regular), // liw
+    (7825773, IdStatus::Regular), // miw
+    (7825774, IdStatus::Regular), // niw
+    (7825776, IdStatus::Regular), // piw
+    (7825777, IdStatus::PrivateUse), // qiw
+    (7825779, IdStatus::Regular), // siw
+    (7825780, IdStatus::Regular), // tiw
+    (7825783, IdStatus::Deprecated), // wiw
+    (7825786, IdStatus::Regular), // ziw
+    (7826017, IdStatus::Regular), // ajw
+    (7826018, IdStatus::Regular), // bjw
+    (7826020, IdStatus::Regular), // djw
+    (7826028, IdStatus::Regular), // ljw
+    (7826029, IdStatus::Regular), // mjw
+    (7826031, IdStatus::Regular), // ojw
+    (7826033, IdStatus::PrivateUse), // qjw
+    (7826035, IdStatus::Regular), // sjw
+    (7826036, IdStatus::Regular), // tjw
+    (7826273, IdStatus::Regular), // akw
+    (7826274, IdStatus::Regular), // bkw
+    (7826281, IdStatus::Regular), // ikw
+    (7826283, IdStatus::Regular), // kkw
+    (7826285, IdStatus::Regular), // mkw
+    (7826286, IdStatus::Regular), // nkw
+    (7826289, IdStatus::PrivateUse), // qkw
+    (7826290, IdStatus::Regular), // rkw
+    (7826291, IdStatus::Regular), // skw
+    (7826292, IdStatus::Regular), // tkw
+    (7826293, IdStatus::Regular), // ukw
+    (7826295, IdStatus::Regular), // wkw
+    (7826296, IdStatus::Regular), // xkw
+    (7826529, IdStatus::Regular), // alw
+    (7826530, IdStatus::Regular), // blw
+    (7826531, IdStatus::Regular), // clw
+    (7826535, IdStatus::Regular), // glw
+    (7826537, IdStatus::Deprecated), // ilw
+    (7826539, IdStatus::Regular), // klw
+    (7826541, IdStatus::Regular), // mlw
+    (7826542, IdStatus::Regular), // nlw
+    (7826544, IdStatus::Regular), // plw
+    (7826545, IdStatus::PrivateUse), // qlw
+    (7826547, IdStatus::Regular), // slw
+    (7826548, IdStatus::Deprecated), // tlw
+    (7826549, IdStatus::Regular), // ulw
+    (7826551, IdStatus::Regular), // wlw
+    (7826554, IdStatus::Regular), // zlw
+    (7826785, IdStatus::Regular), // amw
+    (7826786, IdStatus::Regular), // bmw
+    (7826788, IdStatus::Regular), // dmw
+    (7826789, IdStatus::Regular), // emw
+    (7826791, IdStatus::Regular), // gmw
+    (7826792, IdStatus::Regular), // hmw
+    (7826794, IdStatus::Regular), // jmw
+    (7826795, IdStatus::Regular), // kmw
+    (7826796, IdStatus::Regular), // lmw
+    (7826797, IdStatus::Regular), // mmw
+    (7826798, IdStatus::Regular), // nmw
+    (7826799, IdStatus::Regular), // omw
+    (7826800, IdStatus::Regular), // pmw
+    (7826801, IdStatus::PrivateUse), // qmw
+    (7826802, IdStatus::Regular), // rmw
+    (7826803, IdStatus::Regular), // smw
+    (7826804, IdStatus::Regular), // tmw
+    (7826806, IdStatus::Regular), // vmw
+    (7826807, IdStatus::Regular), // wmw
+    (7826808, IdStatus::Regular), // xmw
+    (7826810, IdStatus::Regular), // zmw
+    (7827041, IdStatus::Regular), // anw
+    (7827042, IdStatus::Regular), // bnw
+    (7827043, IdStatus::Regular), // cnw
+    (7827044, IdStatus::Regular), // dnw
+    (7827045, IdStatus::Regular), // enw
+    (7827047, IdStatus::Regular), // gnw
+    (7827051, IdStatus::Regular), // knw
+    (7827052, IdStatus::Regular), // lnw
+    (7827053, IdStatus::Regular), // mnw
+    (7827054, IdStatus::Regular), // nnw
+    (7827055, IdStatus::Regular), // onw
+    (7827056, IdStatus::Regular), // pnw
+    (7827057, IdStatus::PrivateUse), // qnw
+    (7827058, IdStatus::Regular), // rnw
+    (7827059, IdStatus::Regular), // snw
+    (7827060, IdStatus::Regular), // tnw
+    (7827063, IdStatus::Regular), // wnw
+    (7827298, IdStatus::Regular), // bow
+    (7827299, IdStatus::Regular), // cow
+    (7827300, IdStatus::Regular), // dow
+    (7827303, IdStatus::Regular), // gow
+    (7827304, IdStatus::Regular), // how
+    (7827305, IdStatus::Regular), // iow
+    (7827306, IdStatus::Regular), // jow
+    (7827307, IdStatus::Regular), // kow
+    (7827308, IdStatus::Regular), // low
+    (7827309, IdStatus::Regular), // mow
+    (7827310, IdStatus::Regular), // now
+    (7827312, IdStatus::Regular), // pow
+    (7827313, IdStatus::PrivateUse), // qow
+    (7827314, IdStatus::Regular), // row
+    (7827315, IdStatus::Regular), // sow
+    (7827316, IdStatus::Regular), // tow
+    (7827319, IdStatus::Regular), // wow
+    (7827320, IdStatus::Regular), // xow
+    (7827553, IdStatus::Regular), // apw
+    (7827554, IdStatus::Regular), // bpw
+    (7827563, IdStatus::Regular), // kpw
+    (7827565, IdStatus::Regular), // mpw
+    (7827569, IdStatus::PrivateUse), // qpw
+    (7827572, IdStatus::Regular), // tpw
+    (7827576, IdStatus::Regular), // xpw
+    (7827578, IdStatus::Regular), // zpw
+    (7827810, IdStatus::Regular), // bqw
+    (7827817, IdStatus::Regular), // iqw
+    (7827819, IdStatus::Regular), // kqw
+    (7827821, IdStatus::Regular), // mqw
+    (7827824, IdStatus::Regular), // pqw
+    (7827825, IdStatus::PrivateUse), // qqw
+    (7827828, IdStatus::Regular), // tqw
+    (7828065, IdStatus::Regular), // arw
+    (7828066, IdStatus::Regular), // brw
+    (7828067, IdStatus::Regular), // crw
+    (7828068, IdStatus::Deprecated), // drw
+    (7828069, IdStatus::Regular), // erw
+    (7828071, IdStatus::Regular), // grw
+    (7828072, IdStatus::Regular), // hrw
+    (7828075, IdStatus::Regular), // krw
+    (7828077, IdStatus::Regular), // mrw
+    (7828079, IdStatus::Regular), // orw
+    (7828080, IdStatus::Regular), // prw
+    (7828081, IdStatus::PrivateUse), // qrw
+    (7828083, IdStatus::Regular), // srw
+    (7828084, IdStatus::Regular), // trw
+    (7828085, IdStatus::Regular), // urw
+    (7828087, IdStatus::Regular), // wrw
+    (7828088, IdStatus::Regular), // xrw
+    (7828089, IdStatus::Regular), // yrw
+    (7828321, IdStatus::Regular), // asw
+    (7828322, IdStatus::Regular), // bsw
+    (7828323, IdStatus::Regular), // csw
+    (7828327, IdStatus::Regular), // gsw
+    (7828331, IdStatus::Regular), // ksw
+    (7828332, IdStatus::Regular), // lsw
+    (7828333, IdStatus::Regular), // msw
+    (7828334, IdStatus::Regular), // nsw
+    (7828336, IdStatus::Regular), // psw
+    (7828337, IdStatus::PrivateUse), // qsw
+    (7828340, IdStatus::Regular), // tsw
+    (7828577, IdStatus::Regular), // atw
+    (7828578, IdStatus::Regular), // btw
+    (7828585, IdStatus::Regular), // itw
+    (7828587, IdStatus::Regular), // ktw
+    (7828589, IdStatus::Regular), // mtw
+    (7828590, IdStatus::Regular), // ntw
+    (7828591, IdStatus::Regular), // otw
+    (7828592, IdStatus::Regular), // ptw
+    (7828593, IdStatus::PrivateUse), // qtw
+    (7828594, IdStatus::Regular), // rtw
+    (7828595, IdStatus::Regular), // stw
+    (7828596, IdStatus::Regular), // ttw
+    (7828599, IdStatus::Regular), // wtw
+    (7828600, IdStatus::Regular), // xtw
+    (7828601, IdStatus::Regular), // ytw
+    (7828833, IdStatus::Regular), // auw
+    (7828834, IdStatus::Regular), // buw
+    (7828835, IdStatus::Regular), // cuw
+    (7828836, IdStatus::Regular), // duw
+    (7828839, IdStatus::Regular), // guw
+    (7828840, IdStatus::Regular), // huw
+    (7828842, IdStatus::Regular), // juw
+    (7828843, IdStatus::Regular), // kuw
+    (7828844, IdStatus::Regular), // luw
+    (7828846, IdStatus::Regular), // nuw
+    (7828848, IdStatus::Regular), // puw
+    (7828849, IdStatus::Regular), // quw
+    (7828851, IdStatus::Regular), // suw
+    (7828852, IdStatus::Regular), // tuw
+    (7828857, IdStatus::Regular), // yuw
+    (7829090, IdStatus::Regular), // bvw
+    (7829099, IdStatus::Regular), // kvw
+    (7829101, IdStatus::Regular), // mvw
+    (7829105, IdStatus::Regular), // qvw
+    (7829108, IdStatus::Regular), // tvw
+    (7829345, IdStatus::Regular), // aww
+    (7829346, IdStatus::Regular), // bww
+    (7829348, IdStatus::Regular), // dww
+    (7829351, IdStatus::Regular), // gww
+    (7829355, IdStatus::Regular), // kww
+    (7829356, IdStatus::Regular), // lww
+    (7829357, IdStatus::Regular), // mww
+    (7829358, IdStatus::Regular), // nww
+    (7829360, IdStatus::Regular), // pww
+    (7829363, IdStatus::Regular), // sww
+    (7829364, IdStatus::Regular), // tww
+    (7829367, IdStatus::Regular), // www
+    (7829368, IdStatus::Regular), // xww
+    (7829369, IdStatus::Regular), // yww
+    (7829602, IdStatus::Regular), // bxw
+    (7829611, IdStatus::Regular), // kxw
+    (7829613, IdStatus::Regular), // mxw
+    (7829617, IdStatus::Regular), // qxw
+    (7829618, IdStatus::Regular), // rxw
+    (7829619, IdStatus::Regular), // sxw
+    (7829623, IdStatus::Regular), // wxw
+    (7829858, IdStatus::Regular), // byw
+    (7829864, IdStatus::Regular), // hyw
+    (7829867, IdStatus::Regular), // kyw
+    (7829869, IdStatus::Regular), // myw
+    (7829870, IdStatus::Regular), // nyw
+    (7829875, IdStatus::Regular), // syw
+    (7830114, IdStatus::Regular), // bzw
+    (7830123, IdStatus::Regular), // kzw
+    (7830125, IdStatus::Regular), // mzw
+    (7830131, IdStatus::Regular), // szw
+    (7889249, IdStatus::Regular), // aax
+    (7889250, IdStatus::Regular), // bax
+    (7889251, IdStatus::Regular), // cax
+    (7889252, IdStatus::Regular), // dax
+    (7889254, IdStatus::Regular), // fax
+    (7889255, IdStatus::Regular), // gax
+    (7889256, IdStatus::Regular), // hax
+    (7889258, IdStatus::Regular), // jax
+    (7889259, IdStatus::Regular), // kax
+    (7889260, IdStatus::Regular), // lax
+    (7889261, IdStatus::Regular), // max
+    (7889262, IdStatus::Regular), // nax
+    (7889264, IdStatus::Regular), // pax
+    (7889265, IdStatus::Reserved), // qax
+    (7889266, IdStatus::Regular), // rax
+    (7889267, IdStatus::Regular), // sax
+    (7889268, IdStatus::Regular), // tax
+    (7889271, IdStatus::Regular), // wax
+    (7889273, IdStatus::Regular), // yax
+    (7889274, IdStatus::Regular), // zax
+    (7889505, IdStatus::Regular), // abx
+    (7889506, IdStatus::Regular), // bbx
+    (7889511, IdStatus::Regular), // gbx
+    (7889515, IdStatus::Regular), // kbx
+    (7889516, IdStatus::Regular), // lbx
+    (7889517, IdStatus::Regular), // mbx
+    (7889518, IdStatus::Deprecated), // nbx
+    (7889521, IdStatus::Reserved), // qbx
+    (7889523, IdStatus::Regular), // sbx
+    (7889524, IdStatus::Regular), // tbx
+    (7889528, IdStatus::Deprecated), // xbx
+    (7889529, IdStatus::Regular), // ybx
+    (7889761, IdStatus::Regular), // acx
+    (7889771, IdStatus::Regular), // kcx
+    (7889773, IdStatus::Regular), // mcx
+    (7889774, IdStatus::Regular), // ncx
+    (7889777, IdStatus::Reserved), // qcx
+    (7889779, IdStatus::Regular), // scx
+    (7889780, IdStatus::Regular), // tcx
+    (7890017, IdStatus::Regular), // adx
+    (7890018, IdStatus::Regular), // bdx
+    (7890023, IdStatus::Regular), // gdx
+    (7890027, IdStatus::Regular), // kdx
+    (7890029, IdStatus::Regular), // mdx
+    (7890030, IdStatus::Regular), // ndx
+    (7890033, IdStatus::Reserved), // qdx
+    (7890035, IdStatus::Regular), // sdx
+    (7890036, IdStatus::Regular), // tdx
+    (7890274, IdStatus::Regular), // bex
+    (7890279, IdStatus::Regular), // gex
+    (7890283, IdStatus::Regular), // kex
+    (7890284, IdStatus::Regular), // lex
+    (7890286, IdStatus::Regular), // nex
+    (7890288, IdStatus::Regular), // pex
+    (7890289, IdStatus::Reserved), // qex
+    (7890292, IdStatus::Regular), // tex
+    (7890530, IdStatus::Regular), // bfx
+    (7890535, IdStatus::Deprecated), // gfx
+    (7890539, IdStatus::Regular), // kfx
+    (7890541, IdStatus::Regular), // mfx
+    (7890545, IdStatus::Reserved), // qfx
+    (7890785, IdStatus::Regular), // agx
+    (7890786, IdStatus::Regular), // bgx
+    (7890788, IdStatus::Regular), // dgx
+    (7890789, IdStatus::Regular), // egx
+    (7890795, IdStatus::Regular), // kgx
+    (7890797, IdStatus::Deprecated), // mgx
+    (7890798, IdStatus::Regular), // ngx
+    (7890801, IdStatus::PrivateUse), // qgx
+    (7890803, IdStatus::Regular), // sgx
+    (7890804, IdStatus::Regular), // tgx
+    (7891042, IdStatus::Regular), // bhx
+    (7891043, IdStatus::Regular), // chx
+    (7891044, IdStatus::Regular), // dhx
+    (7891051, IdStatus::Regular), // khx
+    (7891053, IdStatus::Regular), // mhx
+    (7891054, IdStatus::Regular), // nhx
+    (7891057, IdStatus::PrivateUse), // qhx
+    (7891059, IdStatus::Regular), // shx
+    (7891060, IdStatus::Deprecated), // thx
+    (7891066, IdStatus::Regular), // zhx
+    (7891297, IdStatus::Regular), // aix
+    (7891298, IdStatus::Regular), // bix
+    (7891300, IdStatus::Regular), // dix
+    (7891303, IdStatus::Regular), // gix
+    (7891304, IdStatus::Regular), // hix
+    (7891307, IdStatus::Regular), // kix
+    (7891308, IdStatus::Regular), // lix
+    (7891309, IdStatus::Regular), // mix
+    (7891310, IdStatus::Regular), // nix
+    (7891312, IdStatus::Regular), // pix
+    (7891313, IdStatus::PrivateUse), // qix
+    (7891315, IdStatus::Regular), // six
+    (7891316, IdStatus::Regular), // tix
+    (7891321, IdStatus::Regular), // yix
+    (7891554, IdStatus::Regular), // bjx
+    (7891563, IdStatus::Regular), // kjx
+    (7891564, IdStatus::Regular), // ljx
+    (7891565, IdStatus::Regular), // mjx
+    (7891566, IdStatus::Regular), // njx
+    (7891569, IdStatus::PrivateUse), // qjx
+    (7891809, IdStatus::Regular), // akx
+    (7891810, IdStatus::Regular), // bkx
+    (7891811, IdStatus::Regular), // ckx
+    (7891812, IdStatus::Regular), // dkx
+    (7891817, IdStatus::Regular), // ikx
+    (7891819, IdStatus::Regular), // kkx
+    (7891821, IdStatus::Regular), // mkx
+    (7891822, IdStatus::Regular), // nkx
+    (7891823, IdStatus::Regular), // okx
+    (7891825, IdStatus::PrivateUse), // qkx
+    (7891827, IdStatus::Regular), // skx
+    (7891828, IdStatus::Regular), // tkx
+    (7891832, IdStatus::Regular), // xkx
+    (7892065, IdStatus::Regular), // alx
+    (7892066, IdStatus::Regular), // blx
+    (7892069, IdStatus::Regular), // elx
+    (7892075, IdStatus::Regular), // klx
+    (7892076, IdStatus::Regular), // llx
+    (7892077, IdStatus::Regular), // mlx
+    (7892078, IdStatus::Regular), // nlx
+    (7892081, IdStatus::PrivateUse), // qlx
+    (7892083, IdStatus::Regular), // slx
+    (7892084, IdStatus::Regular), // tlx
+    (7892087, IdStatus::Regular), // wlx
+    (7892321, IdStatus::Regular), // amx
+    (7892322, IdStatus::Regular), // bmx
+    (7892324, IdStatus::Regular), // dmx
+    (7892325, IdStatus::Regular), // emx
+    (7892327, IdStatus::Regular), // gmx
+    (7892328, IdStatus::Regular), // hmx
+    (7892330, IdStatus::Regular), // jmx
+    (7892331, IdStatus::Regular), // kmx
+    (7892332, IdStatus::Regular), // lmx
+    (7892333, IdStatus::Regular), // mmx
+    (7892334, IdStatus::Regular), // nmx
+    (7892335, IdStatus::Regular), // omx
+    (7892336, IdStatus::Regular), // pmx
+    (7892337, IdStatus::PrivateUse), // qmx
+    (7892338, IdStatus::Regular), // rmx
+    (7892339, IdStatus::Regular), // smx
+    (7892342, IdStatus::Regular), // vmx
+    (7892343, IdStatus::Regular), // wmx
+    (7892344, IdStatus::Regular), // xmx
+    (7892345, IdStatus::Regular), // ymx
+    (7892346, IdStatus::Regular), // zmx
+    (7892577, IdStatus::Regular), // anx
+    (7892578, IdStatus::Regular), // bnx
+    (7892579, IdStatus::Regular), // cnx
+    (7892581, IdStatus::Regular), // enx
+    (7892587, IdStatus::Regular), // knx
+    (7892589, IdStatus::Regular), // mnx
+    (7892590, IdStatus::Deprecated), // nnx
+    (7892591, IdStatus::Regular), // onx
+    (7892592, IdStatus::Regular), // pnx
+    (7892593, IdStatus::PrivateUse), // qnx
+    (7892595, IdStatus::Regular), // snx
+    (7892596, IdStatus::Regular), // tnx
+    (7892597, IdStatus::Regular), // unx
+    (7892833, IdStatus::Regular), // aox
+    (7892834, IdStatus::Regular), // box
+    (7892835, IdStatus::Regular), // cox
+    (7892836, IdStatus::Regular), // dox
+    (7892838, IdStatus::Regular), // fox
+    (7892839, IdStatus::Regular), // gox
+    (7892843, IdStatus::Deprecated), // kox
+    (7892844, IdStatus::Regular), // lox
+    (7892845, IdStatus::Regular), // mox
+    (7892848, IdStatus::Regular), // pox
+    (7892849, IdStatus::PrivateUse), // qox
+    (7892851, IdStatus::Regular), // sox
+    (7892852, IdStatus::Regular), // tox
+    (7892857, IdStatus::Regular), // yox
+    (7893089, IdStatus::Regular), // apx
+    (7893090, IdStatus::Regular), // bpx
+    (7893091, IdStatus::Regular), // cpx
+    (7893098, IdStatus::Regular), // jpx
+    (7893099, IdStatus::Regular), // kpx
+    (7893100, IdStatus::Regular), // lpx
+    (7893101, IdStatus::Regular), // mpx
+    (7893102, IdStatus::Regular), // npx
+    (7893105, IdStatus::PrivateUse), // qpx
+    (7893107, IdStatus::Regular), // spx
+    (7893108, IdStatus::Regular), // tpx
+    (7893112, IdStatus::Regular), // xpx
+    (7893114, IdStatus::Regular), // zpx
+    (7893346, IdStatus::Regular), // bqx
+    (7893355, IdStatus::Regular), // kqx
+    (7893357, IdStatus::Regular), // mqx
+    (7893361, IdStatus::PrivateUse), // qqx
+    (7893363, IdStatus::Regular), // sqx
+    (7893601, IdStatus::Regular), // arx
+    (7893602, IdStatus::Regular), // brx
+    (7893603, IdStatus::Regular), // crx
+    (7893607, IdStatus::Regular), // grx
+    (7893608, IdStatus::Regular), // hrx
+    (7893609, IdStatus::Regular), // irx
+    (7893611, IdStatus::Regular), // krx
+    (7893613, IdStatus::Regular), // mrx
+    (7893614, IdStatus::Regular), // nrx
+    (7893615, IdStatus::Regular), // orx
+    (7893616, IdStatus::Regular), // prx
+    (7893617, IdStatus::PrivateUse), // qrx
+    (7893619, IdStatus::Regular), // srx
+    (7893620, IdStatus::Regular), // trx
+    (7893621, IdStatus::Regular), // urx
+    (7893623, IdStatus::Regular), // wrx
+    (7893857, IdStatus::Regular), // asx
+    (7893858, IdStatus::Regular), // bsx
+    (7893859, IdStatus::Regular), // csx
+    (7893861, IdStatus::Regular), // esx
+    (7893867, IdStatus::Regular), // ksx
+    (7893869, IdStatus::Regular), // msx
+    (7893870, IdStatus::Regular), // nsx
+    (7893871, IdStatus::Regular), // osx
+    (7893873, IdStatus::PrivateUse), // qsx
+    (7893875, IdStatus::Regular), // ssx
+    (7893876, IdStatus::Regular), // tsx
+    (7894113, IdStatus::Regular), // atx
+    (7894114, IdStatus::Regular), // btx
+    (7894117, IdStatus::Regular), // etx
+    (7894120, IdStatus::Regular), // htx
+    (7894121, IdStatus::Regular), // itx
+    (7894123, IdStatus::Regular), // ktx
+    (7894125, IdStatus::Regular), // mtx
+    (7894126, IdStatus::Regular), // ntx
+    (7894127, IdStatus::Regular), // otx
+    (7894129, IdStatus::PrivateUse), // qtx
+    (7894138, IdStatus::Regular), // ztx
+    (7894369, IdStatus::Regular), // aux
+    (7894370, IdStatus::Regular), // bux
+    (7894371, IdStatus::Regular), // cux
+    (7894372, IdStatus::Regular), // dux
+    (7894375, IdStatus::Regular), // gux
+    (7894376, IdStatus::Regular), // hux
+    (7894379, IdStatus::Regular), // kux
+    (7894381, IdStatus::Regular), // mux
+    (7894382, IdStatus::Regular), // nux
+    (7894384, IdStatus::Regular), // pux
+    (7894385, IdStatus::Regular), // qux
+    (7894387, IdStatus::Regular), // sux
+    (7894388, IdStatus::Regular), // tux
+    (7894391, IdStatus::Regular), // wux
+    (7894393, IdStatus::Regular), // yux
+    (7894626, IdStatus::Regular), // bvx
+    (7894635, IdStatus::Regular), // kvx
+    (7894637, IdStatus::Regular), // mvx
+    (7894643, IdStatus::Regular), // svx
+    (7894644, IdStatus::Regular), // tvx
+    (7894881, IdStatus::Regular), // awx
+    (7894882, IdStatus::Regular), // bwx
+    (7894887, IdStatus::Regular), // gwx
+    (7894891, IdStatus::Regular), // kwx
+    (7894893, IdStatus::Deprecated), // mwx
+    (7894894, IdStatus::Regular), // nwx
+    (7894899, IdStatus::Regular), // swx
+    (7894900, IdStatus::Regular), // twx
+    (7895137, IdStatus::Regular), // axx
+    (7895138, IdStatus::Deprecated), // bxx
+    (7895143, IdStatus::Regular), // gxx
+    (7895147, IdStatus::Regular), // kxx
+    (7895149, IdStatus::Regular), // mxx
+    (7895150, IdStatus::Regular), // nxx
+    (7895156, IdStatus::Regular), // txx
+    (7895162, IdStatus::Special), // zxx
+    (7895393, IdStatus::Deprecated), // ayx
+    (7895394, IdStatus::Regular), // byx
+    (7895400, IdStatus::Regular), // hyx
+    (7895401, IdStatus::Regular), // iyx
+    (7895403, IdStatus::Regular), // kyx
+    (7895405, IdStatus::Regular), // myx
+    (7895406, IdStatus::Regular), // nyx
+    (7895408, IdStatus::Regular), // pyx
+    (7895411, IdStatus::Regular), // syx
+    (7895412, IdStatus::Regular), // tyx
+    (7895650, IdStatus::Regular), // bzx
+    (7895659, IdStatus::Regular), // kzx
+    (7895661, IdStatus::Regular), // mzx
+    (7895668, IdStatus::Regular), // tzx
+    (7954786, IdStatus::Regular), // bay
+    (7954787, IdStatus::Regular), // cay
+    (7954788, IdStatus::Regular), // day
+    (7954790, IdStatus::Regular), // fay
+    (7954791, IdStatus::Regular), // gay
+    (7954792, IdStatus::Regular), // hay
+    (7954794, IdStatus::Regular), // jay
+    (7954795, IdStatus::Regular), // kay
+    (7954796, IdStatus::Regular), // lay
+    (7954798, IdStatus::Regular), // nay
+    (7954800, IdStatus::Regular), // pay
+    (7954801, IdStatus::Reserved), // qay
+    (7954802, IdStatus::Regular), // ray
+    (7954803, IdStatus::Regular), // say
+    (7954804, IdStatus::Regular), // tay
+    (7954806, IdStatus::Regular), // vay
+    (7954807, IdStatus::Regular), // way
+    (7954808, IdStatus::Regular), // xay
+    (7954809, IdStatus::Regular), // yay
+    (7954810, IdStatus::Regular), // zay
+    (7955041, IdStatus::Regular), // aby
+    (7955042, IdStatus::Regular), // bby
+    (7955043, IdStatus::Regular), // cby
+    (7955044, IdStatus::Regular), // dby
+    (7955047, IdStatus::Regular), // gby
+    (7955049, IdStatus::Regular), // iby
+    (7955051, IdStatus::Regular), // kby
+    (7955052, IdStatus::Regular), // lby
+    (7955053, IdStatus::Regular), // mby
+    (7955054, IdStatus::Regular), // nby
+    (7955056, IdStatus::Regular), // pby
+    (7955057, IdStatus::Reserved), // qby
+    (7955059, IdStatus::Regular), // sby
+    (7955060, IdStatus::Regular), // tby
+    (7955061, IdStatus::Regular), // uby
+    (7955064, IdStatus::Regular), // xby
+    (7955065, IdStatus::Regular), // yby
+    (7955297, IdStatus::Regular), // acy
+    (7955298, IdStatus::Regular), // bcy
+    (7955301, IdStatus::Regular), // ecy
+    (7955307, IdStatus::Regular), // kcy
+    (7955309, IdStatus::Regular), // mcy
+    (7955313, IdStatus::Reserved), // qcy
+    (7955316, IdStatus::Regular), // tcy
+    (7955320, IdStatus::Regular), // xcy
+    (7955553, IdStatus::Regular), // ady
+    (7955554, IdStatus::Regular), // bdy
+    (7955555, IdStatus::Regular), // cdy
+    (7955560, IdStatus::Regular), // hdy
+    (7955563, IdStatus::Regular), // kdy
+    (7955565, IdStatus::Regular), // mdy
+    (7955566, IdStatus::Regular), // ndy
+    (7955569, IdStatus::Reserved), // qdy
+    (7955572, IdStatus::Regular), // tdy
+    (7955575, IdStatus::Regular), // wdy
+    (7955576, IdStatus::Regular), // xdy
+    (7955809, IdStatus::Regular), // aey
+    (7955810, IdStatus::Regular), // bey
+    (7955811, IdStatus::Regular), // cey
+    (7955815, IdStatus::Regular), // gey
+    (7955819, IdStatus::Regular), // key
+    (7955820, IdStatus::Regular), // ley
+    (7955821, IdStatus::Regular), // mey
+    (7955822, IdStatus::Regular), // ney
+    (7955824, IdStatus::Regular), // pey
+    (7955825, IdStatus::Reserved), // qey
+    (7955826, IdStatus::Regular), // rey
+    (7955827, IdStatus::Regular), // sey
+    (7955828, IdStatus::Regular), // tey
+    (7955833, IdStatus::Regular), // yey
+    (7956066, IdStatus::Regular), // bfy
+    (7956073, IdStatus::Regular), // ify
+    (7956075, IdStatus::Regular), // kfy
+    (7956077, IdStatus::Regular), // mfy
+    (7956081, IdStatus::Reserved), // qfy
+    (7956321, IdStatus::Regular), // agy
+    (7956322, IdStatus::Regular), // bgy
+    (7956325, IdStatus::Regular), // egy
+    (7956331, IdStatus::Regular), // kgy
+    (7956333, IdStatus::Regular), // mgy
+    (7956334, IdStatus::Regular), // ngy
+    (7956336, IdStatus::Deprecated), // pgy
+    (7956337, IdStatus::PrivateUse), // qgy
+    (7956339, IdStatus::Regular), // sgy
+    (7956340, IdStatus::Regular), // tgy
+    (7956341, IdStatus::Regular), // ugy
+    (7956343, IdStatus::Regular), // wgy
+    (7956578, IdStatus::Regular), // bhy
+    (7956579, IdStatus::Regular), // chy
+    (7956584, IdStatus::Regular), // hhy
+    (7956587, IdStatus::Regular), // khy
+    (7956589, IdStatus::Regular), // mhy
+    (7956590, IdStatus::Regular), // nhy
+    (7956593, IdStatus::PrivateUse), // qhy
+    (7956595, IdStatus::Regular), // shy
+    (7956596, IdStatus::Regular), // thy
+    (7956833, IdStatus::Regular), // aiy
+    (7956834, IdStatus::Regular), // biy
+    (7956835, IdStatus::Regular), // ciy
+    (7956836, IdStatus::Regular), // diy
+    (7956839, IdStatus::Regular), // giy
+    (7956842, IdStatus::Regular), // jiy
+    (7956843, IdStatus::Regular), // kiy
+    (7956844, IdStatus::Regular), // liy
+    (7956845, IdStatus::Regular), // miy
+    (7956846, IdStatus::Regular), // niy
+    (7956848, IdStatus::Regular), // piy
+    (7956849, IdStatus::PrivateUse), // qiy
+    (7956851, IdStatus::Regular), // siy
+    (7956852, IdStatus::Regular), // tiy
+    (7956855, IdStatus::Regular), // wiy
+    (7956856, IdStatus::Regular), // xiy
+    (7956857, IdStatus::Deprecated), // yiy
+    (7957090, IdStatus::Regular), // bjy
+    (7957091, IdStatus::Regular), // cjy
+    (7957099, IdStatus::Regular), // kjy
+    (7957101, IdStatus::Regular), // mjy
+    (7957102, IdStatus::Regular), // njy
+    (7957105, IdStatus::PrivateUse), // qjy
+    (7957345, IdStatus::Regular), // aky
+    (7957346, IdStatus::Regular), // bky
+    (7957347, IdStatus::Regular), // cky
+    (7957349, IdStatus::Regular), // eky
+    (7957355, IdStatus::Regular), // kky
+    (7957356, IdStatus::Regular), // lky
+    (7957357, IdStatus::Regular), // mky
+    (7957361, IdStatus::PrivateUse), // qky
+    (7957363, IdStatus::Regular), // sky
+    (7957365, IdStatus::Regular), // uky
+    (7957367, IdStatus::Regular), // wky
+    (7957368, IdStatus::Regular), // xky
+    (7957369, IdStatus::Regular), // yky
+    (7957601, IdStatus::Regular), // aly
+    (7957602, IdStatus::Regular), // bly
+    (7957603, IdStatus::Regular), // cly
+    (7957606, IdStatus::Regular), // fly
+    (7957607, IdStatus::Regular), // gly
+    (7957611, IdStatus::Regular), // kly
+    (7957614, IdStatus::Regular), // nly
+    (7957616, IdStatus::Regular), // ply
+    (7957617, IdStatus::PrivateUse), // qly
+    (7957619, IdStatus::Regular), // sly
+    (7957620, IdStatus::Regular), // tly
+    (7957623, IdStatus::Regular), // wly
+    (7957624, IdStatus::Regular), // xly
+    (7957625, IdStatus::Regular), // yly
+    (7957857, IdStatus::Regular), // amy
+    (7957858, IdStatus::Deprecated), // bmy
+    (7957860, IdStatus::Regular), // dmy
+    (7957861, IdStatus::Regular), // emy
+    (7957863, IdStatus::Regular), // gmy
+    (7957864, IdStatus::Regular), // hmy
+    (7957865, IdStatus::Regular), // imy
+    (7957867, IdStatus::Regular), // kmy
+    (7957868, IdStatus::Regular), // lmy
+    (7957869, IdStatus::Regular), // mmy
+    (7957870, IdStatus::Regular), // nmy
+    (7957871, IdStatus::Regular), // omy
+    (7957872, IdStatus::Regular), // pmy
+    (7957873, IdStatus::PrivateUse), // qmy
+    (7957874, IdStatus::Deprecated), // rmy
+    (7957875, IdStatus::Regular), // smy
+    (7957876, IdStatus::Regular), // tmy
+    (7957878, IdStatus::Regular), // vmy
+    (7957880, IdStatus::Regular), // xmy
+    (7957882, IdStatus::Regular), // zmy
+    (7958113, IdStatus::Regular), // any
+    (7958114, IdStatus::Regular), // bny
+    (7958116, IdStatus::Regular), // dny
+    (7958123, IdStatus::Regular), // kny
+    (7958125, IdStatus::Regular), // mny
+    (7958126, IdStatus::Regular), // nny
+    (7958128, IdStatus::Regular), // pny
+    (7958129, IdStatus::PrivateUse), // qny
+    (7958131, IdStatus::Regular), // sny
+    (7958132, IdStatus::Regular), // tny
+    (7958135, IdStatus::Regular), // wny
+    (7958136, IdStatus::Regular), // xny
+    (7958370, IdStatus::Regular), // boy
+    (7958371, IdStatus::Deprecated), // coy
+    (7958372, IdStatus::Regular), // doy
+    (7958375, IdStatus::Regular), // goy
+    (7958376, IdStatus::Regular), // hoy
+    (7958379, IdStatus::Regular), // koy
+    (7958380, IdStatus::Regular), // loy
+    (7958381, IdStatus::Regular), // moy
+    (7958382, IdStatus::Regular), // noy
+    (7958384, IdStatus::Regular), // poy
+    (7958385, IdStatus::PrivateUse), // qoy
+    (7958387, IdStatus::Regular), // soy
+    (7958388, IdStatus::Regular), // toy
+    (7958391, IdStatus::Regular), // woy
+    (7958393, IdStatus::Regular), // yoy
+    (7958625, IdStatus::Regular), // apy
+    (7958626, IdStatus::Regular), // bpy
+    (7958627, IdStatus::Regular), // cpy
+    (7958635, IdStatus::Regular), // kpy
+    (7958637, IdStatus::Regular), // mpy
+    (7958638, IdStatus::Regular), // npy
+    (7958639, IdStatus::Regular), // opy
+    (7958641, IdStatus::PrivateUse), // qpy
+    (7958643, IdStatus::Deprecated), // spy
+    (7958644, IdStatus::Regular), // tpy
+    (7958648, IdStatus::Regular), // xpy
+    (7958650, IdStatus::Regular), // zpy
+    (7958882, IdStatus::Regular), // bqy
+    (7958891, IdStatus::Regular), // kqy
+    (7958893, IdStatus::Regular), // mqy
+    (7958894, IdStatus::Regular), // nqy
+    (7958897, IdStatus::PrivateUse), // qqy
+    (7959137, IdStatus::Regular), // ary
+    (7959138, IdStatus::Regular), // bry
+    (7959139, IdStatus::Regular), // cry
+    (7959140, IdStatus::Regular), // dry
+    (7959143, IdStatus::Regular), // gry
+    (7959145, IdStatus::Regular), // iry
+    (7959147, IdStatus::Regular), // kry
+    (7959149, IdStatus::Regular), // mry
+    (7959151, IdStatus::Deprecated), // ory
+    (7959152, IdStatus::Deprecated), // pry
+    (7959153, IdStatus::PrivateUse), // qry
+    (7959155, IdStatus::Regular), // sry
+    (7959156, IdStatus::Regular), // try
+    (7959157, IdStatus::Regular), // ury
+    (7959159, IdStatus::Regular), // wry
+    (7959161, IdStatus::Regular), // yry
+    (7959393, IdStatus::Regular), // asy
+    (7959394, IdStatus::Regular), // bsy
+    (7959395, IdStatus::Regular), // csy
+    (7959397, IdStatus::Regular), // esy
+    (7959403, IdStatus::Regular), // ksy
+    (7959404, IdStatus::Regular), // lsy
+    (7959405, IdStatus::Regular), // msy
+    (7959406, IdStatus::Regular), // nsy
+    (7959408, IdStatus::Regular), // psy
+    (7959409, IdStatus::PrivateUse), // qsy
+    (7959411, IdStatus::Regular), // ssy
+    (7959412, IdStatus::Regular), // tsy
+    (7959416, IdStatus::Regular), // xsy
+    (7959417, IdStatus::Regular), // ysy
+    (7959649, IdStatus::Regular), // aty
+    (7959650, IdStatus::Regular), // bty
+    (7959651, IdStatus::Regular), // cty
+    (7959652, IdStatus::Regular), // dty
+    (7959657, IdStatus::Regular), // ity
+    (7959659, IdStatus::Regular), // kty
+    (7959661, IdStatus::Regular), // mty
+    (7959662, IdStatus::Regular), // nty
+    (7959663, IdStatus::Regular), // oty
+    (7959664, IdStatus::Regular), // pty
+    (7959665, IdStatus::PrivateUse), // qty
+    (7959667, IdStatus::Regular), // sty
+    (7959668, IdStatus::Regular), // tty
+    (7959672, IdStatus::Regular), // xty
+    (7959673, IdStatus::Regular), // yty
+    (7959674, IdStatus::Regular), // zty
+    (7959905, IdStatus::Regular), // auy
+    (7959906, IdStatus::Regular), // buy
+    (7959907, IdStatus::Regular), // cuy
+    (7959908, IdStatus::Regular), // duy
+    (7959910, IdStatus::Regular), // fuy
+    (7959912, IdStatus::Regular), // huy
+    (7959914, IdStatus::Regular), // juy
+    (7959915, IdStatus::Regular), // kuy
+    (7959916, IdStatus::Regular), // luy
+    (7959917, IdStatus::Regular), // muy
+    (7959918, IdStatus::Regular), // nuy
+    (7959920, IdStatus::Regular), // puy
+    (7959921, IdStatus::Regular), // quy
+    (7959922, IdStatus::Regular), // ruy
+    (7959923, IdStatus::Regular), // suy
+    (7959924, IdStatus::Regular), // tuy
+    (7959927, IdStatus::Regular), // wuy
+    (7959929, IdStatus::Regular), // yuy
+    (7959930, IdStatus::Regular), // zuy
+    (7960162, IdStatus::Regular), // bvy
+    (7960167, IdStatus::Regular), // gvy
+    (7960171, IdStatus::Regular), // kvy
+    (7960173, IdStatus::Regular), // mvy
+    (7960177, IdStatus::Regular), // qvy
+    (7960180, IdStatus::Regular), // tvy
+    (7960417, IdStatus::Regular), // awy
+    (7960418, IdStatus::Regular), // bwy
+    (7960420, IdStatus::Regular), // dwy
+    (7960427, IdStatus::Regular), // kwy
+    (7960429, IdStatus::Deprecated), // mwy
+    (7960430, IdStatus::Regular), // nwy
+    (7960435, IdStatus::Regular), // swy
+    (7960436, IdStatus::Regular), // twy
+    (7960683, IdStatus::Regular), // kxy
+    (7960685, IdStatus::Regular), // mxy
+    (7960692, IdStatus::Regular), // txy
+    (7960697, IdStatus::Regular), // yxy
+    (7960929, IdStatus::Deprecated), // ayy
+    (7960930, IdStatus::Deprecated), // byy
+    (7960932, IdStatus::Regular), // dyy
+    (7960935, IdStatus::Regular), // gyy
+    (7960938, IdStatus::Regular), // jyy
+    (7960939, IdStatus::Regular), // kyy
+    (7960941, IdStatus::Regular), // myy
+    (7960942, IdStatus::Regular), // nyy
+    (7960943, IdStatus::Regular), // oyy
+    (7960944, IdStatus::Regular), // pyy
+    (7960947, IdStatus::Regular), // syy
+    (7960948, IdStatus::Regular), // tyy
+    (7960951, IdStatus::Regular), // wyy
+    (7960952, IdStatus::Regular), // xyy
+    (7961186, IdStatus::Regular), // bzy
+    (7961195, IdStatus::Regular), // kzy
+    (7961197, IdStatus::Regular), // mzy
+    (7961198, IdStatus::Regular), // nzy
+    (7961203, IdStatus::Regular), // szy
+    (8020321, IdStatus::Regular), // aaz
+    (8020322, IdStatus::Deprecated), // baz
+    (8020323, IdStatus::Regular), // caz
+    (8020324, IdStatus::Regular), // daz
+    (8020326, IdStatus::Regular), // faz
+    (8020327, IdStatus::Deprecated), // gaz
+    (8020328, IdStatus::Regular), // haz
+    (8020330, IdStatus::Regular), // jaz
+    (8020332, IdStatus::Regular), // laz
+    (8020333, IdStatus::Regular), // maz
+    (8020334, IdStatus::Regular), // naz
+    (8020336, IdStatus::Regular), // paz
+    (8020337, IdStatus::Reserved), // qaz
+    (8020338, IdStatus::Regular), // raz
+    (8020339, IdStatus::Regular), // saz
+    (8020340, IdStatus::Regular), // taz
+    (8020343, IdStatus::Regular), // waz
+    (8020345, IdStatus::Regular), // yaz
+    (8020346, IdStatus::Regular), // zaz
+    (8020577, IdStatus::Regular), // abz
+    (8020578, IdStatus::Deprecated), // bbz
+    (8020583, IdStatus::Regular), // gbz
+    (8020587, IdStatus::Regular), // kbz
+    (8020588, IdStatus::Regular), // lbz
+    (8020589, IdStatus::Regular), // mbz
+    (8020592, IdStatus::Deprecated), // pbz
+    (8020593, IdStatus::Reserved), // qbz
+    (8020595, IdStatus::Regular), // sbz
+    (8020596, IdStatus::Regular), // tbz
+    (8020833, IdStatus::Regular), // acz
+    (8020834, IdStatus::Regular), // bcz
+    (8020843, IdStatus::Regular), // kcz
+    (8020845, IdStatus::Regular), // mcz
+    (8020846, IdStatus::Regular), // ncz
+    (8020849, IdStatus::Reserved), // qcz
+    (8020852, IdStatus::Regular), // tcz
+    (8021089, IdStatus::Regular), // adz
+    (8021090, IdStatus::Regular), // bdz
+    (8021091, IdStatus::Regular), // cdz
+    (8021099, IdStatus::Regular), // kdz
+    (8021101, IdStatus::Regular), // mdz
+    (8021102, IdStatus::Regular), // ndz
+    (8021105, IdStatus::Reserved), // qdz
+    (8021107, IdStatus::Regular), // sdz
+    (8021345, IdStatus::Regular), // aez
+    (8021346, IdStatus::Regular), // bez
+    (8021348, IdStatus::Regular), // dez
+    (8021351, IdStatus::Regular), // gez
+    (8021355, IdStatus::Regular), // kez
+    (8021356, IdStatus::Regular), // lez
+    (8021357, IdStatus::Regular), // mez
+    (8021358, IdStatus::Regular), // nez
+    (8021360, IdStatus::Regular), // pez
+    (8021361, IdStatus::Reserved), // qez
+    (8021363, IdStatus::Regular), // sez
+    (8021364, IdStatus::Regular), // tez
+    (8021601, IdStatus::Regular), // afz
+    (8021602, IdStatus::Regular), // bfz
+    (8021611, IdStatus::Regular), // kfz
+    (8021613, IdStatus::Regular), // mfz
+    (8021617, IdStatus::PrivateUse), // qfz
+    (8021857, IdStatus::Regular), // agz
+    (8021858, IdStatus::Regular), // bgz
+    (8021860, IdStatus::Regular), // dgz
+    (8021868, IdStatus::Regular), // lgz
+    (8021869, IdStatus::Regular), // mgz
+    (8021870, IdStatus::Regular), // ngz
+    (8021872, IdStatus::Regular), // pgz
+    (8021873, IdStatus::PrivateUse), // qgz
+    (8021875, IdStatus::Regular), // sgz
+    (8021876, IdStatus::Regular), // tgz
+    (8022114, IdStatus::Regular), // bhz
+    (8022115, IdStatus::Regular), // chz
+    (8022123, IdStatus::Regular), // khz
+    (8022125, IdStatus::Regular), // mhz
+    (8022126, IdStatus::Regular), // nhz
+    (8022129, IdStatus::PrivateUse), // qhz
+    (8022131, IdStatus::Regular), // shz
+    (8022132, IdStatus::Regular), // thz
+    (8022370, IdStatus::Regular), // biz
+    (8022372, IdStatus::Regular), // diz
+    (8022375, IdStatus::Regular), // giz
+    (8022379, IdStatus::Regular), // kiz
+    (8022380, IdStatus::Regular), // liz
+    (8022381, IdStatus::Regular), // miz
+    (8022382, IdStatus::Regular), // niz
+    (8022384, IdStatus::Regular), // piz
+    (8022385, IdStatus::PrivateUse), // qiz
+    (8022387, IdStatus::Regular), // siz
+    (8022388, IdStatus::Regular), // tiz
+    (8022393, IdStatus::Regular), // yiz
+    (8022394, IdStatus::Regular), // ziz
+    (8022625, IdStatus::Regular), // ajz
+    (8022626, IdStatus::Regular), // bjz
+    (8022635, IdStatus::Regular), // kjz
+    (8022637, IdStatus::Regular), // mjz
+    (8022638, IdStatus::Regular), // njz
+    (8022641, IdStatus::PrivateUse), // qjz
+    (8022881, IdStatus::Regular), // akz
+    (8022882, IdStatus::Regular), // bkz
+    (8022883, IdStatus::Regular), // ckz
+    (8022889, IdStatus::Regular), // ikz
+    (8022891, IdStatus::Regular), // kkz
+    (8022893, IdStatus::Regular), // mkz
+    (8022894, IdStatus::Regular), // nkz
+    (8022895, IdStatus::Regular), // okz
+    (8022897, IdStatus::PrivateUse), // qkz
+    (8022899, IdStatus::Regular), // skz
+    (8022900, IdStatus::Regular), // tkz
+    (8022902, IdStatus::Regular), // vkz
+    (8022904, IdStatus::Regular), // xkz
+    (8022906, IdStatus::Regular), // zkz
+    (8023137, IdStatus::Regular), // alz
+    (8023138, IdStatus::Regular), // blz
+    (8023147, IdStatus::Regular), // klz
+    (8023149, IdStatus::Regular), // mlz
+    (8023150, IdStatus::Regular), // nlz
+    (8023152, IdStatus::Regular), // plz
+    (8023153, IdStatus::PrivateUse), // qlz
+    (8023155, IdStatus::Regular), // slz
+    (8023393, IdStatus::Regular), // amz
+    (8023394, IdStatus::Regular), // bmz
+    (8023397, IdStatus::Regular), // emz
+    (8023399, IdStatus::Regular), // gmz
+    (8023400, IdStatus::Regular), // hmz
+    (8023403, IdStatus::Regular), // kmz
+    (8023404, IdStatus::Deprecated), // lmz
+    (8023405, IdStatus::Regular), // mmz
+    (8023406, IdStatus::Regular), // nmz
+    (8023408, IdStatus::Regular), // pmz
+    (8023409, IdStatus::PrivateUse), // qmz
+    (8023410, IdStatus::Regular), // rmz
+    (8023411, IdStatus::Regular), // smz
+    (8023412, IdStatus::Regular), // tmz
+    (8023414, IdStatus::Regular), // vmz
+    (8023416, IdStatus::Regular), // xmz
+    (8023417, IdStatus::Regular), // ymz
+    (8023418, IdStatus::Regular), // zmz
+    (8023649, IdStatus::Regular), // anz
+    (8023650, IdStatus::Regular), // bnz
+    (8023655, IdStatus::Regular), // gnz
+    (8023657, IdStatus::Regular), // inz
+    (8023659, IdStatus::Regular), // knz
+    (8023660, IdStatus::Regular), // lnz
+    (8023661, IdStatus::Regular), // mnz
+    (8023662, IdStatus::Regular), // nnz
+    (8023664, IdStatus::Regular), // pnz
+    (8023665, IdStatus::PrivateUse), // qnz
+    (8023667, IdStatus::Regular), // snz
+    (8023668, IdStatus::Regular), // tnz
+    (8023669, IdStatus::Regular), // unz
+    (8023672, IdStatus::Regular), // xnz
+    (8023905, IdStatus::Regular), // aoz
+    (8023906, IdStatus::Regular), // boz
+    (8023907, IdStatus::Regular), // coz
+    (8023908, IdStatus::Regular), // doz
+    (8023911, IdStatus::Regular), // goz
+    (8023912, IdStatus::Regular), // hoz
+    (8023915, IdStatus::Regular), // koz
+    (8023916, IdStatus::Regular), // loz
+    (8023917, IdStatus::Regular), // moz
+    (8023918, IdStatus::Regular), // noz
+    (8023920, IdStatus::Regular), // poz
+    (8023921, IdStatus::PrivateUse), // qoz
+    (8023923, IdStatus::Regular), // soz
+    (8023924, IdStatus::Regular), // toz
+    (8024161, IdStatus::Regular), // apz
+    (8024162, IdStatus::Regular), // bpz
+    (8024171, IdStatus::Regular), // kpz
+    (8024173, IdStatus::Regular), // mpz
+    (8024177, IdStatus::PrivateUse), // qpz
+    (8024180, IdStatus::Regular), // tpz
+    (8024184, IdStatus::Regular), // xpz
+    (8024185, IdStatus::Regular), // ypz
+    (8024186, IdStatus::Regular), // zpz
+    (8024417, IdStatus::Regular), // aqz
+    (8024418, IdStatus::Regular), // bqz
+    (8024427, IdStatus::Regular), // kqz
+    (8024429, IdStatus::Regular), // mqz
+    (8024433, IdStatus::PrivateUse), // qqz
+    (8024673, IdStatus::Regular), // arz
+    (8024674, IdStatus::Regular), // brz
+    (8024675, IdStatus::Regular), // crz
+    (8024679, IdStatus::Regular), // grz
+    (8024680, IdStatus::Regular), // hrz
+    (8024683, IdStatus::Regular), // krz
+    (8024684, IdStatus::Regular), // lrz
+    (8024685, IdStatus::Regular), // mrz
+    (8024686, IdStatus::Regular), // nrz
+    (8024687, IdStatus::Regular), // orz
+    (8024688, IdStatus::Regular), // prz
+    (8024689, IdStatus::PrivateUse), // qrz
+    (8024691, IdStatus::Regular), // srz
+    (8024692, IdStatus::Regular), // trz
+    (8024693, IdStatus::Regular), // urz
+    (8024695, IdStatus::Regular), // wrz
+    (8024929, IdStatus::Regular), // asz
+    (8024931, IdStatus::Regular), // csz
+    (8024932, IdStatus::Regular), // dsz
+    (8024939, IdStatus::Regular), // ksz
+    (8024941, IdStatus::Regular), // msz
+    (8024942, IdStatus::Regular), // nsz
+    (8024945, IdStatus::PrivateUse), // qsz
+    (8024947, IdStatus::Regular), // ssz
+    (8024948, IdStatus::Regular), // tsz
+    (8025185, IdStatus::Regular), // atz
+    (8025186, IdStatus::Regular), // btz
+    (8025187, IdStatus::Regular), // ctz
+    (8025189, IdStatus::Regular), // etz
+    (8025193, IdStatus::Regular), // itz
+    (8025195, IdStatus::Regular), // ktz
+    (8025198, IdStatus::Regular), // ntz
+    (8025199, IdStatus::Regular), // otz
+    (8025201, IdStatus::PrivateUse), // qtz
+    (8025204, IdStatus::Regular), // ttz
+    (8025208, IdStatus::Deprecated), // xtz
+    (8025441, IdStatus::Regular), // auz
+    (8025442, IdStatus::Regular), // buz
+    (8025444, IdStatus::Regular), // duz
+    (8025447, IdStatus::Regular), // guz
+    (8025448, IdStatus::Regular), // huz
+    (8025451, IdStatus::Regular), // kuz
+    (8025452, IdStatus::Regular), // luz
+    (8025453, IdStatus::Regular), // muz
+    (8025454, IdStatus::Regular), // nuz
+    (8025456, IdStatus::Deprecated), // puz
+    (8025457, IdStatus::Deprecated), // quz
+    (8025458, IdStatus::Regular), // ruz
+    (8025459, IdStatus::Regular), // suz
+    (8025460, IdStatus::Regular), // tuz
+    (8025465, IdStatus::Regular), // yuz
+    (8025698, IdStatus::Regular), // bvz
+    (8025707, IdStatus::Regular), // kvz
+    (8025709, IdStatus::Regular), // mvz
+    (8025713, IdStatus::Regular), // qvz
+    (8025954, IdStatus::Regular), // bwz
+    (8025956, IdStatus::Regular), // dwz
+    (8025963, IdStatus::Regular), // kwz
+    (8025965, IdStatus::Regular), // mwz
+    (8026210, IdStatus::Regular), // bxz
+    (8026219, IdStatus::Regular), // kxz
+    (8026221, IdStatus::Regular), // mxz
+    (8026465, IdStatus::Regular), // ayz
+    (8026466, IdStatus::Regular), // byz
+    (8026471, IdStatus::Regular), // gyz
+    (8026475, IdStatus::Regular), // kyz
+    (8026477, IdStatus::Regular), // myz
+    (8026484, IdStatus::Regular), // tyz
+    (8026489, IdStatus::Regular), // yyz
+    (8026721, IdStatus::Regular), // azz
+    (8026722, IdStatus::Regular), // bzz
+    (8026729, IdStatus::Regular), // izz
+    (8026731, IdStatus::Regular), // kzz
+    (8026732, IdStatus::Regular), // lzz
+    (8026733, IdStatus::Regular), // mzz
+    (8026734, IdStatus::Regular), // nzz
+];
+
+#[rustfmt::skip]
+pub static SCRIPTS: [(u32, IdStatus); 226] = [
+    (1633771857, IdStatus::Reserved), // Qaaa
+    (1633773652, IdStatus::Regular), // Thaa
+    (1633837393, IdStatus::PrivateUse), // Qaba
+    (1633840197, IdStatus::Regular), // Elba
+    (1633971787, IdStatus::Regular), // Knda
+    (1634427213, IdStatus::Regular), // Maka
+    (1634562895, IdStatus::Regular), // Osma
+    (1634623819, IdStatus::Regular), // Kana
+    (1634623820, IdStatus::Regular), // Lana
+    (1634625868, IdStatus::Regular), // Lina
+    (1634885975, IdStatus::Regular), // Wara
+    (1634888008, IdStatus::Regular), // Hira
+    (1634889555, IdStatus::Regular), // Sora
+    (1634954836, IdStatus::Regular), // Tnsa
+    (1635148106, IdStatus::Regular), // Java
+    (1635149124, IdStatus::Regular), // Deva
+    (1635214670, IdStatus::Regular), // Newa
+    (1635349071, IdStatus::Regular), // Orya
+    (1650549073, IdStatus::Reserved), // Qaab
+    (1650553409, IdStatus::Regular), // Arab
+    (1650614609, IdStatus::PrivateUse), // Qabb
+    (1650942292, IdStatus::Regular), // Tagb
+    (1651009345, IdStatus::Regular), // Aghb
+    (1651337548, IdStatus::Regular), // Limb
+    (1651401032, IdStatus::Regular), // Hanb
+    (1651401050, IdStatus::Special), // Zanb
+    (1651403084, IdStatus::Regular), // Linb
+    (1651663182, IdStatus::Regular), // Narb
+    (1651663187, IdStatus::Regular), // Sarb
+    (1667326289, IdStatus::Reserved), // Qaac
+    (1667391825, IdStatus::PrivateUse), // Qabc
+    (1668310348, IdStatus::Regular), // Lepc
+    (1668440397, IdStatus::Regular), // Marc
+    (1668441421, IdStatus::Regular), // Merc
+    (1668446547, IdStatus::Regular), // Syrc
+    (1668637008, IdStatus::Regular), // Pauc
+    (1684103505, IdStatus::Reserved), // Qaad
+    (1684169041, IdStatus::PrivateUse), // Qabd
+    (1684302163, IdStatus::Regular), // Sidd
+    (1684500307, IdStatus::Regular), // Sogd
+    (1684567362, IdStatus::Regular), // Buhd
+    (1684955469, IdStatus::Regular), // Mand
+    (1684955470, IdStatus::Regular), // Nand
+    (1684956493, IdStatus::Regular), // Mend
+    (1684957523, IdStatus::Regular), // Sind
+    (1684960595, IdStatus::Regular), // Sund
+    (1685219411, IdStatus::Regular), // Shrd
+    (1685220432, IdStatus::Regular), // Plrd
+    (1700880721, IdStatus::Reserved), // Qaae
+    (1700946257, IdStatus::PrivateUse), // Qabe
+    (1701278543, IdStatus::Regular), // Osge
+    (1701601620, IdStatus::Regular), // Tale
+    (1701998411, IdStatus::Regular), // Kore
+    (1702458202, IdStatus::Special), // Zsye
+    (1717657937, IdStatus::Reserved), // Qaaf
+    (1717723473, IdStatus::PrivateUse), // Qabf
+    (1717855565, IdStatus::Regular), // Medf
+    (1734435153, IdStatus::Special), // Qaag
+    (1734436944, IdStatus::Regular), // Phag
+    (1734437959, IdStatus::Regular), // Glag
+    (1734500689, IdStatus::PrivateUse), // Qabg
+    (1734897490, IdStatus::Regular), // Rohg
+    (1735157588, IdStatus::Regular), // Tglg
+    (1735287112, IdStatus::Regular), // Hang
+    (1735287124, IdStatus::Regular), // Tang
+    (1735288130, IdStatus::Regular), // Beng
+    (1735288404, IdStatus::Regular), // Tfng
+    (1735289426, IdStatus::Regular), // Rjng
+    (1735290184, IdStatus::Regular), // Hmng
+    (1735290695, IdStatus::Regular), // Gong
+    (1735290701, IdStatus::Regular), // Mong
+    (1735292232, IdStatus::Regular), // Hung
+    (1751212369, IdStatus::Reserved), // Qaah
+    (1751216706, IdStatus::Regular), // Brah
+    (1751277905, IdStatus::PrivateUse), // Qabh
+    (1751872079, IdStatus::Regular), // Orkh
+    (1752066387, IdStatus::Regular), // Sinh
+    (1752066394, IdStatus::Special), // Zinh
+    (1752328532, IdStatus::Regular), // Tirh
+    (1752459606, IdStatus::Regular), // Vith
+    (1752460634, IdStatus::Special), // Zmth
+    (1752461127, IdStatus::Regular), // Goth
+    (1767989585, IdStatus::Deprecated), // Qaai
+    (1767991380, IdStatus::Regular), // Thai
+    (1767993922, IdStatus::Regular), // Brai
+    (1768055121, IdStatus::PrivateUse), // Qabi
+    (1768126796, IdStatus::Regular), // Lyci
+    (1768189773, IdStatus::Regular), // Modi
+    (1768192332, IdStatus::Regular), // Lydi
+    (1768256589, IdStatus::Regular), // Mtei
+    (1768387906, IdStatus::Regular), // Bugi
+    (1768453189, IdStatus::Regular), // Ethi
+    (1768453195, IdStatus::Regular), // Kthi
+    (1768513878, IdStatus::Regular), // Vaii
+    (1768515929, IdStatus::Regular), // Yiii
+    (1768710466, IdStatus::Regular), // Bali
+    (1768710475, IdStatus::Regular), // Kali
+    (1768712272, IdStatus::Regular), // Phli
+    (1768780353, IdStatus::Regular), // Armi
+    (1768841544, IdStatus::Regular), // Hani
+    (1768841549, IdStatus::Regular), // Mani
+    (1769103683, IdStatus::Regular), // Cari
+    (1769239120, IdStatus::Regular), // Prti
+    (1769431371, IdStatus::Regular), // Kawi
+    (1769629017, IdStatus::Regular), // Yezi
+    (1784766801, IdStatus::Reserved), // Qaaj
+    (1784832337, IdStatus::PrivateUse), // Qabj
+    (1785225549, IdStatus::Regular), // Mahj
+    (1785686091, IdStatus::Regular), // Khoj
+    (1801544017, IdStatus::Reserved), // Qaak
+    (1801546052, IdStatus::Regular), // Diak
+    (1801609553, IdStatus::PrivateUse), // Qabk
+    (1801677903, IdStatus::Regular), // Olck
+    (1801810503, IdStatus::Regular), // Grek
+    (1802789186, IdStatus::Regular), // Batk
+    (1818321233, IdStatus::Reserved), // Qaal
+    (1818326089, IdStatus::Regular), // Ital
+    (1818386769, IdStatus::PrivateUse), // Qabl
+    (1819107668, IdStatus::Regular), // Taml
+    (1819309380, IdStatus::Regular), // Dupl
+    (1819441475, IdStatus::Regular), // Cyrl
+    (1835098449, IdStatus::Reserved), // Qaam
+    (1835099983, IdStatus::Regular), // Ogam
+    (1835100227, IdStatus::Regular), // Cham
+    (1835163985, IdStatus::PrivateUse), // Qabm
+    (1835491662, IdStatus::Regular), // Nagm
+    (1835753795, IdStatus::Regular), // Cakm
+    (1835819344, IdStatus::Regular), // Palm
+    (1835820097, IdStatus::Regular), // Adlm
+    (1835953991, IdStatus::Regular), // Gonm
+    (1836017729, IdStatus::Regular), // Ahom
+    (1836213584, IdStatus::Regular), // Perm
+    (1836674117, IdStatus::Regular), // Elym
+    (1836674125, IdStatus::Regular), // Mlym
+    (1836675930, IdStatus::Special), // Zsym
+    (1851875665, IdStatus::Reserved), // Qaan
+    (1851879498, IdStatus::Regular), // Jpan
+    (1851880001, IdStatus::Special), // Aran
+    (1851880007, IdStatus::Regular), // Gran
+    (1851941201, IdStatus::PrivateUse), // Qabn
+    (1852665923, IdStatus::Regular), // Cpmn
+    (1852666433, IdStatus::Regular), // Armn
+    (1853120844, IdStatus::Regular), // Latn
+    (1868652881, IdStatus::Reserved), // Qaao
+    (1868718417, IdStatus::PrivateUse), // Qabo
+    (1868918872, IdStatus::Regular), // Xpeo
+    (1869049683, IdStatus::Regular), // Sogo
+    (1869112151, IdStatus::Regular), // Wcho
+    (1869379923, IdStatus::Regular), // Sylo
+    (1869439306, IdStatus::Regular), // Jamo
+    (1869504840, IdStatus::Regular), // Hano
+    (1869570380, IdStatus::Regular), // Laoo
+    (1869572942, IdStatus::Regular), // Nkoo
+    (1869574733, IdStatus::Regular), // Mroo
+    (1869639490, IdStatus::Regular), // Bopo
+    (1869768013, IdStatus::Regular), // Mero
+    (1869901652, IdStatus::Regular), // Toto
+    (1870229331, IdStatus::Regular), // Soyo
+    (1885430097, IdStatus::Reserved), // Qaap
+    (1885495633, IdStatus::PrivateUse), // Qabp
+    (1886152784, IdStatus::Regular), // Phlp
+    (1886285128, IdStatus::Regular), // Hmnp
+    (1887004485, IdStatus::Regular), // Egyp
+    (1902207313, IdStatus::PrivateUse), // Qaaq
+    (1902272849, IdStatus::PrivateUse), // Qabq
+    (1918984529, IdStatus::PrivateUse), // Qaar
+    (1918986069, IdStatus::Regular), // Ugar
+    (1918986315, IdStatus::Regular), // Khar
+    (1919050065, IdStatus::PrivateUse), // Qabr
+    (1919051080, IdStatus::Regular), // Hebr
+    (1919248451, IdStatus::Regular), // Cher
+    (1919381316, IdStatus::Regular), // Dogr
+    (1919382863, IdStatus::Regular), // Ougr
+    (1919579463, IdStatus::Regular), // Gujr
+    (1919639892, IdStatus::Regular), // Takr
+    (1919770963, IdStatus::Regular), // Samr
+    (1919772747, IdStatus::Regular), // Khmr
+    (1919777101, IdStatus::Regular), // Mymr
+    (1919841618, IdStatus::Regular), // Runr
+    (1919903047, IdStatus::Regular), // Geor
+    (1920229704, IdStatus::Regular), // Hatr
+    (1920295251, IdStatus::Regular), // Saur
+    (1935761745, IdStatus::PrivateUse), // Qaas
+    (1935827281, IdStatus::PrivateUse), // Qabs
+    (1936418882, IdStatus::Regular), // Bhks
+    (1936613699, IdStatus::Regular), // Cans
+    (1936613704, IdStatus::Regular), // Hans
+    (1936877635, IdStatus::Regular), // Chrs
+    (1936941378, IdStatus::Regular), // Bass
+    (1937008971, IdStatus::Regular), // Kits
+    (1952538961, IdStatus::PrivateUse), // Qaat
+    (1952539214, IdStatus::Regular), // Nbat
+    (1952604497, IdStatus::PrivateUse), // Qabt
+    (1952606548, IdStatus::Regular), // Tibt
+    (1953198664, IdStatus::Regular), // Hrkt
+    (1953264973, IdStatus::Regular), // Mult
+    (1953390920, IdStatus::Regular), // Hant
+    (1953525571, IdStatus::Regular), // Copt
+    (1953656899, IdStatus::Regular), // Cprt
+    (1953657668, IdStatus::Regular), // Dsrt
+    (1953723969, IdStatus::Regular), // Avst
+    (1953915220, IdStatus::Regular), // Tavt
+    (1969316177, IdStatus::PrivateUse), // Qaau
+    (1969381713, IdStatus::PrivateUse), // Qabu
+    (1969779534, IdStatus::Regular), // Nshu
+    (1970037076, IdStatus::Regular), // Talu
+    (1970038100, IdStatus::Regular), // Telu
+    (1970102594, IdStatus::Regular), // Bamu
+    (1970435399, IdStatus::Regular), // Guru
+    (1970497868, IdStatus::Regular), // Lisu
+    (1986093393, IdStatus::PrivateUse), // Qaav
+    (1986158929, IdStatus::PrivateUse), // Qabv
+    (2002870609, IdStatus::PrivateUse), // Qaaw
+    (2002872403, IdStatus::Regular), // Shaw
+    (2002936145, IdStatus::PrivateUse), // Qabw
+    (2003724115, IdStatus::Regular), // Sgnw
+    (2004184136, IdStatus::Regular), // Hluw
+    (2019647825, IdStatus::PrivateUse), // Qaax
+    (2019713361, IdStatus::PrivateUse), // Qabx
+    (2020501584, IdStatus::Regular), // Phnx
+    (2020963160, IdStatus::Regular), // Xsux
+    (2021161050, IdStatus::Special), // Zxxx
+    (2036425041, IdStatus::PrivateUse), // Qaay
+    (2038004058, IdStatus::Special), // Zyyy
+    (2053202257, IdStatus::PrivateUse), // Qaaz
+    (2054847066, IdStatus::Unknown), // Zzzz
+];
+
+#[rustfmt::skip]
+pub static REGIONS: [(u32, IdStatus); 343] = [
+    (16705, IdStatus::Reserved), // AA
+    (16706, IdStatus::Regular), // BA
+    (16707, IdStatus::Regular), // CA
+    (16709, IdStatus::Regular), // EA
+    (16711, IdStatus::Regular), // GA
+    (16716, IdStatus::Regular), // LA
+    (16717, IdStatus::Regular), // MA
+    (16718, IdStatus::Regular), // NA
+    (16720, IdStatus::Regular), // PA
+    (16721, IdStatus::Regular), // QA
+    (16723, IdStatus::Regular), // SA
+    (16724, IdStatus::Regular), // TA
+    (16725, IdStatus::Regular), // UA
+    (16726, IdStatus::Regular), // VA
+    (16728, IdStatus::Special), // XA
+    (16730, IdStatus::Regular), // ZA
+    (16962, IdStatus::Regular), // BB
+    (16967, IdStatus::Regular), // GB
+    (16972, IdStatus::Regular), // LB
+    (16979, IdStatus::Regular), // SB
+    (16984, IdStatus::Special), // XB
+    (17217, IdStatus::Regular), // AC
+    (17219, IdStatus::Regular), // CC
+    (17221, IdStatus::Regular), // EC
+    (17225, IdStatus::Regular), // IC
+    (17228, IdStatus::Regular), // LC
+    (17229, IdStatus::Regular), // MC
+    (17230, IdStatus::Regular), // NC
+    (17235, IdStatus::Regular), // SC
+    (17236, IdStatus::Regular), // TC
+    (17238, IdStatus::Regular), // VC
+    (17240, IdStatus::PrivateUse), // XC
+    (17473, IdStatus::Regular), // AD
+    (17474, IdStatus::Regular), // BD
+    (17475, IdStatus::Regular), // CD
+    (17476, IdStatus::Deprecated), // DD
+    (17479, IdStatus::Regular), // GD
+    (17481, IdStatus::Regular), // ID
+    (17485, IdStatus::Regular), // MD
+    (17491, IdStatus::Regular), // SD
+    (17492, IdStatus::Regular), // TD
+    (17496, IdStatus::PrivateUse), // XD
+    (17497, IdStatus::Deprecated), // YD
+    (17729, IdStatus::Regular), // AE
+    (17730, IdStatus::Regular), // BE
+    (17732, IdStatus::Regular), // DE
+    (17733, IdStatus::Regular), // EE
+    (17735, IdStatus::Regular), // GE
+    (17737, IdStatus::Regular), // IE
+    (17738, IdStatus::Regular), // JE
+    (17739, IdStatus::Regular), // KE
+    (17741, IdStatus::Regular), // ME
+    (17742, IdStatus::Regular), // NE
+    (17744, IdStatus::Regular), // PE
+    (17746, IdStatus::Regular), // RE
+    (17747, IdStatus::Regular), // SE
+    (17750, IdStatus::Regular), // VE
+    (17752, IdStatus::PrivateUse), // XE
+    (17753, IdStatus::Regular), // YE
+    (17985, IdStatus::Regular), // AF
+    (17986, IdStatus::Regular), // BF
+    (17987, IdStatus::Regular), // CF
+    (17991, IdStatus::Regular), // GF
+    (17997, IdStatus::Regular), // MF
+    (17998, IdStatus::Regular), // NF
+    (18000, IdStatus::Regular), // PF
+    (18004, IdStatus::Regular), // TF
+    (18007, IdStatus::Regular), // WF
+    (18008, IdStatus::PrivateUse), // XF
+    (18241, IdStatus::Regular), // AG
+    (18242, IdStatus::Regular), // BG
+    (18243, IdStatus::Regular), // CG
+    (18244, IdStatus::Regular), // DG
+    (18245, IdStatus::Regular), // EG
+    (18247, IdStatus::Regular), // GG
+    (18251, IdStatus::Regular), // KG
+    (18253, IdStatus::Regular), // MG
+    (18254, IdStatus::Regular), // NG
+    (18256, IdStatus::Regular), // PG
+    (18259, IdStatus::Regular), // SG
+    (18260, IdStatus::Regular), // TG
+    (18261, IdStatus::Regular), // UG
+    (18262, IdStatus::Regular), // VG
+    (18264, IdStatus::PrivateUse), // XG
+    (18498, IdStatus::Regular), // BH
+    (18499, IdStatus::Regular), // CH
+    (18501, IdStatus::Regular), // EH
+    (18503, IdStatus::Regular), // GH
+    (18507, IdStatus::Regular), // KH
+    (18509, IdStatus::Regular), // MH
+    (18512, IdStatus::Regular), // PH
+    (18515, IdStatus::Regular), // SH
+    (18516, IdStatus::Regular), // TH
+    (18520, IdStatus::PrivateUse), // XH
+    (18753, IdStatus::Regular), // AI
+    (18754, IdStatus::Regular), // BI
+    (18755, IdStatus::Regular), // CI
+    (18758, IdStatus::Regular), // FI
+    (18759, IdStatus::Regular), // GI
+    (18763, IdStatus::Regular), // KI
+    (18764, IdStatus::Regular), // LI
+    (18766, IdStatus::Regular), // NI
+    (18771, IdStatus::Regular), // SI
+    (18774, IdStatus::Regular), // VI
+    (18776, IdStatus::PrivateUse), // XI
+    (19010, IdStatus::Regular), // BJ
+    (19012, IdStatus::Regular), // DJ
+    (19014, IdStatus::Regular), // FJ
+    (19027, IdStatus::Regular), // SJ
+    (19028, IdStatus::Regular), // TJ
+    (19032, IdStatus::PrivateUse), // XJ
+    (19267, IdStatus::Regular), // CK
+    (19268, IdStatus::Regular), // DK
+    (19270, IdStatus::Regular), // FK
+    (19272, IdStatus::Regular), // HK
+    (19276, IdStatus::Regular), // LK
+    (19277, IdStatus::Regular), // MK
+    (19280, IdStatus::Regular), // PK
+    (19283, IdStatus::Regular), // SK
+    (19284, IdStatus::Regular), // TK
+    (19288, IdStatus::Regular), // XK
+    (19521, IdStatus::Regular), // AL
+    (19522, IdStatus::Regular), // BL
+    (19523, IdStatus::Regular), // CL
+    (19527, IdStatus::Regular), // GL
+    (19529, IdStatus::Regular), // IL
+    (19533, IdStatus::Regular), // ML
+    (19534, IdStatus::Regular), // NL
+    (19536, IdStatus::Regular), // PL
+    (19539, IdStatus::Regular), // SL
+    (19540, IdStatus::Regular), // TL
+    (19544, IdStatus::PrivateUse), // XL
+    (19777, IdStatus::Regular), // AM
+    (19778, IdStatus::Regular), // BM
+    (19779, IdStatus::Regular), // CM
+    (19780, IdStatus::Regular), // DM
+    (19782, IdStatus::Regular), // FM
+    (19783, IdStatus::Regular), // GM
+    (19784, IdStatus::Regular), // HM
+    (19785, IdStatus::Regular), // IM
+    (19786, IdStatus::Regular), // JM
+    (19787, IdStatus::Regular), // KM
+    (19789, IdStatus::Regular), // MM
+    (19791, IdStatus::Regular), // OM
+    (19792, IdStatus::Regular), // PM
+    (19793, IdStatus::Reserved), // QM
+    (19795, IdStatus::Regular), // SM
+    (19796, IdStatus::Regular), // TM
+    (19797, IdStatus::Regular), // UM
+    (19800, IdStatus::PrivateUse), // XM
+    (19802, IdStatus::Regular), // ZM
+    (20033, IdStatus::Deprecated), // AN
+    (20034, IdStatus::Regular), // BN
+    (20035, IdStatus::Regular), // CN
+    (20039, IdStatus::Regular), // GN
+    (20040, IdStatus::Regular), // HN
+    (20041, IdStatus::Regular), // IN
+    (20043, IdStatus::Regular), // KN
+    (20045, IdStatus::Regular), // MN
+    (20048, IdStatus::Regular), // PN
+    (20049, IdStatus::Reserved), // QN
+    (20051, IdStatus::Regular), // SN
+    (20052, IdStatus::Regular), // TN
+    (20053, IdStatus::Macroregion), // UN
+    (20054, IdStatus::Regular), // VN
+    (20056, IdStatus::PrivateUse), // XN
+    (20289, IdStatus::Regular), // AO
+    (20290, IdStatus::Regular), // BO
+    (20291, IdStatus::Regular), // CO
+    (20292, IdStatus::Regular), // DO
+    (20294, IdStatus::Regular), // FO
+    (20297, IdStatus::Regular), // IO
+    (20298, IdStatus::Regular), // JO
+    (20301, IdStatus::Regular), // MO
+    (20302, IdStatus::Regular), // NO
+    (20305, IdStatus::Macroregion), // QO
+    (20306, IdStatus::Regular), // RO
+    (20307, IdStatus::Regular), // SO
+    (20308, IdStatus::Regular), // TO
+    (20312, IdStatus::PrivateUse), // XO
+    (20547, IdStatus::Regular), // CP
+    (20551, IdStatus::Regular), // GP
+    (20554, IdStatus::Regular), // JP
+    (20555, IdStatus::Regular), // KP
+    (20557, IdStatus::Regular), // MP
+    (20558, IdStatus::Regular), // NP
+    (20561, IdStatus::Reserved), // QP
+    (20564, IdStatus::Deprecated), // TP
+    (20568, IdStatus::PrivateUse), // XP
+    (20801, IdStatus::Regular), // AQ
+    (20802, IdStatus::Regular), // BQ
+    (20803, IdStatus::Regular), // CQ
+    (20807, IdStatus::Regular), // GQ
+    (20809, IdStatus::Regular), // IQ
+    (20813, IdStatus::Regular), // MQ
+    (20817, IdStatus::Reserved), // QQ
+    (20824, IdStatus::PrivateUse), // XQ
+    (21057, IdStatus::Regular), // AR
+    (21058, IdStatus::Regular), // BR
+    (21059, IdStatus::Regular), // CR
+    (21061, IdStatus::Regular), // ER
+    (21062, IdStatus::Regular), // FR
+    (21063, IdStatus::Regular), // GR
+    (21064, IdStatus::Regular), // HR
+    (21065, IdStatus::Regular), // IR
+    (21067, IdStatus::Regular), // KR
+    (21068, IdStatus::Regular), // LR
+    (21069, IdStatus::Regular), // MR
+    (21070, IdStatus::Regular), // NR
+    (21072, IdStatus::Regular), // PR
+    (21073, IdStatus::Reserved), // QR
+    (21075, IdStatus::Regular), // SR
+    (21076, IdStatus::Regular), // TR
+    (21080, IdStatus::PrivateUse), // XR
+    (21082, IdStatus::Deprecated), // ZR
+    (21313, IdStatus::Regular), // AS
+    (21314, IdStatus::Regular), // BS
+    (21315, IdStatus::Deprecated), // CS
+    (21317, IdStatus::Regular), // ES
+    (21319, IdStatus::Regular), // GS
+    (21321, IdStatus::Regular), // IS
+    (21324, IdStatus::Regular), // LS
+    (21325, IdStatus::Regular), // MS
+    (21328, IdStatus::Regular), // PS
+    (21329, IdStatus::Reserved), // QS
+    (21330, IdStatus::Regular), // RS
+    (21331, IdStatus::Regular), // SS
+    (21333, IdStatus::Regular), // US
+    (21335, IdStatus::Regular), // WS
+    (21336, IdStatus::PrivateUse), // XS
+    (21569, IdStatus::Regular), // AT
+    (21570, IdStatus::Regular), // BT
+    (21573, IdStatus::Regular), // ET
+    (21575, IdStatus::Regular), // GT
+    (21576, IdStatus::Regular), // HT
+    (21577, IdStatus::Regular), // IT
+    (21580, IdStatus::Regular), // LT
+    (21581, IdStatus::Regular), // MT
+    (21582, IdStatus::Deprecated), // NT
+    (21584, IdStatus::Regular), // PT
+    (21585, IdStatus::Reserved), // QT
+    (21587, IdStatus::Regular), // ST
+    (21588, IdStatus::Regular), // TT
+    (21592, IdStatus::PrivateUse), // XT
+    (21593, IdStatus::Regular), // YT
+    (21825, IdStatus::Regular), // AU
+    (21826, IdStatus::Deprecated), // BU
+    (21827, IdStatus::Regular), // CU
+    (21829, IdStatus::Macroregion), // EU
+    (21831, IdStatus::Regular), // GU
+    (21832, IdStatus::Regular), // HU
+    (21836, IdStatus::Regular), // LU
+    (21837, IdStatus::Regular), // MU
+    (21838, IdStatus::Regular), // NU
+    (21841, IdStatus::Deprecated), // QU
+    (21842, IdStatus::Regular), // RU
+    (21843, IdStatus::Deprecated), // SU
+    (21846, IdStatus::Regular), // VU
+    (21848, IdStatus::PrivateUse), // XU
+    (21849, IdStatus::Deprecated), // YU
+    (22082, IdStatus::Regular), // BV
+    (22083, IdStatus::Regular), // CV
+    (22092, IdStatus::Regular), // LV
+    (22093, IdStatus::Regular), // MV
+    (22097, IdStatus::Reserved), // QV
+    (22099, IdStatus::Regular), // SV
+    (22100, IdStatus::Regular), // TV
+    (22104, IdStatus::PrivateUse), // XV
+    (22337, IdStatus::Regular), // AW
+    (22338, IdStatus::Regular), // BW
+    (22339, IdStatus::Regular), // CW
+    (22343, IdStatus::Regular), // GW
+    (22347, IdStatus::Regular), // KW
+    (22349, IdStatus::Regular), // MW
+    (22352, IdStatus::Regular), // PW
+    (22353, IdStatus::Reserved), // QW
+    (22354, IdStatus::Regular), // RW
+    (22356, IdStatus::Regular), // TW
+    (22360, IdStatus::PrivateUse), // XW
+    (22362, IdStatus::Regular), // ZW
+    (22593, IdStatus::Regular), // AX
+    (22595, IdStatus::Regular), // CX
+    (22598, IdStatus::Deprecated), // FX
+    (22605, IdStatus::Regular), // MX
+    (22609, IdStatus::Reserved), // QX
+    (22611, IdStatus::Regular), // SX
+    (22616, IdStatus::PrivateUse), // XX
+    (22850, IdStatus::Regular), // BY
+    (22851, IdStatus::Regular), // CY
+    (22855, IdStatus::Regular), // GY
+    (22859, IdStatus::Regular), // KY
+    (22860, IdStatus::Regular), // LY
+    (22861, IdStatus::Regular), // MY
+    (22864, IdStatus::Regular), // PY
+    (22865, IdStatus::Reserved), // QY
+    (22867, IdStatus::Regular), // SY
+    (22869, IdStatus::Regular), // UY
+    (22872, IdStatus::PrivateUse), // XY
+    (23105, IdStatus::Regular), // AZ
+    (23106, IdStatus::Regular), // BZ
+    (23107, IdStatus::Regular), // CZ
+    (23108, IdStatus::Regular), // DZ
+    (23109, IdStatus::Macroregion), // EZ
+    (23115, IdStatus::Regular), // KZ
+    (23117, IdStatus::Regular), // MZ
+    (23118, IdStatus::Regular), // NZ
+    (23121, IdStatus::Reserved), // QZ
+    (23123, IdStatus::Regular), // SZ
+    (23124, IdStatus::Regular), // TZ
+    (23125, IdStatus::Regular), // UZ
+    (23128, IdStatus::PrivateUse), // XZ
+    (23130, IdStatus::Unknown), // ZZ
+    (3158832, IdStatus::Macroregion), // 030
+    (3159345, IdStatus::Macroregion), // 150
+    (3223600, IdStatus::Macroregion), // 001
+    (3223856, IdStatus::Macroregion), // 011
+    (3224112, IdStatus::Macroregion), // 021
+    (3224881, IdStatus::Macroregion), // 151
+    (3225136, IdStatus::Macroregion), // 061
+    (3289136, IdStatus::Macroregion), // 002
+    (3289138, IdStatus::Macroregion), // 202
+    (3290161, IdStatus::Macroregion), // 142
+    (3354672, IdStatus::Macroregion), // 003
+    (3354928, IdStatus::Macroregion), // 013
+    (3355697, IdStatus::Macroregion), // 143
+    (3355952, IdStatus::Macroregion), // 053
+    (3420464, IdStatus::Macroregion), // 014
+    (3420976, IdStatus::Macroregion), // 034
+    (3421488, IdStatus::Macroregion), // 054
+    (3421489, IdStatus::Macroregion), // 154
+    (3485744, IdStatus::Macroregion), // 005
+    (3486000, IdStatus::Macroregion), // 015
+    (3486512, IdStatus::Macroregion), // 035
+    (3486769, IdStatus::Macroregion), // 145
+    (3487025, IdStatus::Macroregion), // 155
+    (3617072, IdStatus::Macroregion), // 017
+    (3618096, IdStatus::Macroregion), // 057
+    (3682608, IdStatus::Macroregion), // 018
+    (3747888, IdStatus::Macroregion), // 009
+    (3748144, IdStatus::Macroregion), // 019
+    (3748148, IdStatus::Macroregion), // 419
+    (3748400, IdStatus::Macroregion), // 029
+    (3748656, IdStatus::Macroregion), // 039
+];
+
+#[rustfmt::skip]
+pub static VARIANTS: [(u64, IdStatus); 110] = [
+    (825243953, IdStatus::Regular), // 1901
+    (876165425, IdStatus::Regular), // 1994
+    (909719857, IdStatus::Regular), // 1996
+    (418263101299, IdStatus::Regular), // solba
+    (418431263074, IdStatus::Regular), // barla
+    (418598447726, IdStatus::Regular), // njiva
+    (435594422626, IdStatus::Regular), // biske
+    (456901947250, IdStatus::Regular), // rozaj
+    (461329885554, IdStatus::Regular), // rigik
+    (461330216302, IdStatus::Regular), // nulik
+    (478560412007, IdStatus::Regular), // gallo
+    (478593246576, IdStatus::Regular), // peano
+    (491328402800, IdStatus::Regular), // puter
+    (491328463210, IdStatus::Regular), // jauer
+    (491361498482, IdStatus::Regular), // rumgr
+    (491495056245, IdStatus::Regular), // uccor
+    (491495058283, IdStatus::Regular), // kkcor
+    (491495060331, IdStatus::Regular), // kscor
+    (495689426286, IdStatus::Regular), // nedis
+    (495706927983, IdStatus::Regular), // osojs
+    (500069003106, IdStatus::Regular), // boont
+    (504314031201, IdStatus::Regular), // aluku
+    (504364167011, IdStatus::Regular), // cornu
+    (508441161587, IdStatus::Regular), // sotav
+    (512735865196, IdStatus::Regular), // lipaw
+    (53022330810209, IdStatus::Regular), // ao1990
+    (107113823953264, IdStatus::Regular), // pamaka
+    (107114160284782, IdStatus::Regular), // ndyuka
+    (107135433076582, IdStatus::Regular), // fonipa
+    (107135634403174, IdStatus::Regular), // fonupa
+    (109330211825000, IdStatus::Deprecated), // heploc
+    (110442422954350, IdStatus::Regular), // nicard
+    (111516417091955, IdStatus::Regular), // simple
+    (111546565878643, IdStatus::Regular), // scouse
+    (111550742492001, IdStatus::Regular), // asante
+    (118143300297076, IdStatus::Regular), // tarask
+    (118143651507045, IdStatus::Regular), // ekavsk
+    (119165820695143, IdStatus::Regular), // grital
+    (121399287900528, IdStatus::Regular), // pinyin
+    (121424688931175, IdStatus::Regular), // gascon
+    (121424738610805, IdStatus::Regular), // unifon
+    (121437607589217, IdStatus::Regular), // auvern
+    (122507204649316, IdStatus::Regular), // dajnko
+    (123649448438115, IdStatus::Regular), // cisaup
+    (125780070984821, IdStatus::Regular), // ulster
+    (125822735377269, IdStatus::Regular), // ucrcor
+    (126879480771169, IdStatus::Regular), // aranes
+    (126939526689379, IdStatus::Regular), // creiss
+    (14188610780160368, IdStatus::Regular), // pahawh2
+    (14412644059210337, IdStatus::Regular), // abl1943
+    (14470085756871024, IdStatus::Regular), // pahawh3
+    (14751560733581680, IdStatus::Regular), // pahawh4
+    (15534107281290348, IdStatus::Regular), // ltg2007
+    (15544222901955681, IdStatus::Regular), // alalc97
+    (16099294895895660, IdStatus::Regular), // ltg1929
+    (27413494041506401, IdStatus::Deprecated), // arevmda
+    (27417853132366178, IdStatus::Regular), // bauddha
+    (27421173091103329, IdStatus::Regular), // arkaika
+    (27421173141299574, IdStatus::Regular), // vaidika
+    (27421173259526508, IdStatus::Regular), // laukika
+    (27421194566000994, IdStatus::Regular), // balanka
+    (27422255774790241, IdStatus::Deprecated), // arevela
+    (27426636507803494, IdStatus::Regular), // fonnapa
+    (27429934941697129, IdStatus::Regular), // itihasa
+    (27981962910658402, IdStatus::Regular), // bohoric
+    (27987404752122480, IdStatus::Regular), // provenc
+    (29113304356973925, IdStatus::Regular), // emodeng
+    (30792305807289185, IdStatus::Regular), // akuapem
+    (31078191949768044, IdStatus::Regular), // lemosin
+    (31084793314570093, IdStatus::Regular), // monoton
+    (31084793482211184, IdStatus::Regular), // polyton
+    (31088095926445416, IdStatus::Regular), // hepburn
+    (31361835717649773, IdStatus::Regular), // metelko
+    (32216186383856231, IdStatus::Regular), // grmistr
+    (32496484589204071, IdStatus::Regular), // grclass
+    (33333247416104307, IdStatus::Regular), // sursilv
+    (33333247416235379, IdStatus::Regular), // sutsilv
+    (3833752067066589027, IdStatus::Regular), // colb1945
+    (3905246711469793634, IdStatus::Regular), // baku1926
+    (4048796750519231856, IdStatus::Regular), // petr1708
+    (4049080424233596268, IdStatus::Regular), // luna1918
+    (7019250820032782710, IdStatus::Regular), // valencia
+    (7019833578358007158, IdStatus::Regular), // vecdruka
+    (7165055901679641964, IdStatus::Regular), // lengadoc
+    (7233171745672869425, IdStatus::Regular), // 1694acad
+    (7233171745756494129, IdStatus::Regular), // 1959acad
+    (7236828769668785011, IdStatus::Regular), // scotland
+    (7236850772551886190, IdStatus::Regular), // newfound
+    (7307503147828866923, IdStatus::Regular), // kociewie
+    (7308332188119097719, IdStatus::Regular), // wadegile
+    (7453005910990086498, IdStatus::Regular), // basiceng
+    (7453010365055859050, IdStatus::Regular), // jyutping
+    (7453017000561766260, IdStatus::Regular), // tongyong
+    (7526485199365762918, IdStatus::Regular), // fonkirsh
+    (7742658007299223400, IdStatus::Regular), // hognorsk
+    (7742662345165728361, IdStatus::Regular), // ijekavsk
+    (7816395612155181427, IdStatus::Regular), // synnejyl
+    (7884799541939171170, IdStatus::Regular), // bornholm
+    (7953764214046356851, IdStatus::Regular), // surmiran
+    (7953771876100303202, IdStatus::Regular), // biscayan
+    (8029185261498364776, IdStatus::Regular), // hsistemo
+    (8029185261498364792, IdStatus::Regular), // xsistemo
+    (8101238453279027046, IdStatus::Regular), // fonxsamp
+    (8103490248412391798, IdStatus::Regular), // vivaraup
+    (8243105062565208438, IdStatus::Regular), // vallader
+    (8316297378405642355, IdStatus::Regular), // spanglis
+    (8386662811163785327, IdStatus::Regular), // oxendict
+    (8386662853170443825, IdStatus::Regular), // 1606nict
+    (8388351699796784500, IdStatus::Regular), // tunumiit
+    (8534154595153311337, IdStatus::Regular), // ivanchov
+];
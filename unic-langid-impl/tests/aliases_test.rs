@@ -0,0 +1,55 @@
+use unic_langid_impl::aliases::keyword_type_alias;
+use unic_langid_impl::LanguageIdentifier;
+
+fn replace_aliases(input: &str) -> (String, bool) {
+    let mut langid: LanguageIdentifier = input.parse().unwrap();
+    let changed = langid.replace_aliases();
+    (langid.to_string(), changed)
+}
+
+#[test]
+fn replace_aliases_test() {
+    let tests = [
+        ("iw", "he"),
+        ("iw-IL", "he-IL"),
+        ("aar", "aa"),
+        ("sh", "sr-Latn"),
+        ("sh-Cyrl", "sr-Cyrl"),
+        ("sh-YU", "sr-Latn-RS"),
+        ("no-bokmal", "nb"),
+        ("hy-arevela", "hy"),
+        ("sgn-BR", "bzs"),
+        ("de-DD", "de-DE"),
+        ("ru-SU", "ru-RU"),
+        ("hy-SU", "hy-AM"),
+        ("uz-Qaai", "uz-Zinh"),
+        ("und-heploc", "und-alalc97"),
+        ("en-US", "en-US"),
+        ("und", "und"),
+    ];
+    for (input, expected) in tests {
+        assert_eq!(
+            replace_aliases(input),
+            (expected.to_string(), input != expected),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn replace_aliases_idempotent_test() {
+    for input in ["sh-YU", "hy-SU", "iw-IL", "no-bokmal"] {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        assert!(langid.replace_aliases());
+        assert!(!langid.replace_aliases(), "{}", input);
+    }
+}
+
+#[test]
+fn keyword_type_alias_test() {
+    assert_eq!(keyword_type_alias("ca", "islamicc"), Some("islamic-civil"));
+    assert_eq!(keyword_type_alias("tz", "usnavajo"), Some("usden"));
+    assert_eq!(keyword_type_alias("ca", "gregorian"), None);
+    assert_eq!(keyword_type_alias("nu", "islamicc"), None);
+}
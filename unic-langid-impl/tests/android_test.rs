@@ -0,0 +1,88 @@
+use unic_langid_impl::android::{
+    from_android_dir, from_android_qualifier, to_android_dir, to_android_qualifier,
+};
+use unic_langid_impl::parser::{ParserError, SubtagKind};
+use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
+
+fn assert_from_qualifier(input: &str, expected: &str) {
+    let langid = from_android_qualifier(input).expect("Conversion failed.");
+    assert_eq!(langid.to_string(), expected);
+}
+
+fn assert_to_qualifier(input: &str, expected: &str) {
+    let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+    assert_eq!(to_android_qualifier(&langid), expected);
+    assert_eq!(from_android_qualifier(expected), Ok(langid));
+}
+
+#[test]
+fn test_from_android_qualifier() {
+    assert_from_qualifier("en", "en");
+    assert_from_qualifier("zh-rTW", "zh-TW");
+    assert_from_qualifier("fil-rPH", "fil-PH");
+    assert_from_qualifier("in", "id");
+    assert_from_qualifier("iw-rIL", "he-IL");
+    assert_from_qualifier("ji", "yi");
+    assert_from_qualifier("b+sr+Latn+RS", "sr-Latn-RS");
+    assert_from_qualifier("b+es+419", "es-419");
+    assert_from_qualifier("b+iw", "he");
+    assert_from_qualifier("b+ca+ES+valencia", "ca-ES-valencia");
+    assert_from_qualifier("", "und");
+
+    assert_eq!(
+        from_android_qualifier("zh-TW"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::MalformedSubtag {
+                expected: SubtagKind::Region,
+                offset: 3,
+                length: 2,
+            }
+        ))
+    );
+    assert!(from_android_qualifier("en-rUS-foo").is_err());
+    assert!(from_android_qualifier("english").is_err());
+    assert!(from_android_qualifier("b+en+USA").is_err());
+}
+
+#[test]
+fn test_to_android_qualifier() {
+    assert_to_qualifier("en", "en");
+    assert_to_qualifier("zh-TW", "zh-rTW");
+    assert_to_qualifier("id-ID", "in-rID");
+    assert_to_qualifier("he", "iw");
+    assert_to_qualifier("yi", "ji");
+    assert_to_qualifier("sr-Latn-RS", "b+sr+Latn+RS");
+    assert_to_qualifier("es-419", "b+es+419");
+    assert_to_qualifier("ca-ES-valencia", "b+ca+ES+valencia");
+    assert_to_qualifier("und-US", "b+und+US");
+    assert_to_qualifier("und", "");
+}
+
+#[test]
+fn test_android_dir() {
+    let convert = |dir| from_android_dir(dir).map(|langid| langid.to_string());
+
+    assert_eq!(convert("values"), Ok("und".to_string()));
+    assert_eq!(convert("values-zh-rTW"), Ok("zh-TW".to_string()));
+    assert_eq!(convert("values-in"), Ok("id".to_string()));
+    assert_eq!(convert("values-b+sr+Latn+RS"), Ok("sr-Latn-RS".to_string()));
+    assert_eq!(
+        convert("values-mcc310-mnc004-en-rUS"),
+        Ok("en-US".to_string())
+    );
+    assert_eq!(convert("values-fr-land-v21"), Ok("fr".to_string()));
+    assert_eq!(convert("drawable-night-v8"), Ok("und".to_string()));
+    assert_eq!(convert("layout-sw600dp"), Ok("und".to_string()));
+    assert_eq!(convert("values-car"), Ok("und".to_string()));
+    assert_eq!(convert("values-port"), Ok("und".to_string()));
+    assert_eq!(convert("values-mcc310-land"), Ok("und".to_string()));
+    assert_eq!(convert("values-ldrtl-night"), Ok("und".to_string()));
+    assert_eq!(convert("values-car-night"), Ok("und".to_string()));
+    assert_eq!(convert("values-fr-car"), Ok("fr".to_string()));
+    assert_eq!(convert("values-de-rAT-car"), Ok("de-AT".to_string()));
+
+    let dir = |tag: &str| to_android_dir("values", &tag.parse().unwrap());
+    assert_eq!(dir("zh-TW"), "values-zh-rTW");
+    assert_eq!(dir("sr-Latn"), "values-b+sr+Latn");
+    assert_eq!(dir("und"), "values");
+}
@@ -0,0 +1,38 @@
+use unic_langid_impl::apple::{from_lproj, to_lproj};
+use unic_langid_impl::LanguageIdentifier;
+
+fn assert_from_lproj(input: &str, expected: &str) {
+    let langid = from_lproj(input).expect("Conversion failed.");
+    assert_eq!(langid.to_string(), expected);
+}
+
+#[test]
+fn test_from_lproj() {
+    assert_from_lproj("en.lproj", "en");
+    assert_from_lproj("en_GB.lproj", "en-GB");
+    assert_from_lproj("pt-BR.lproj", "pt-BR");
+    assert_from_lproj("zh-Hans.lproj", "zh-Hans");
+    assert_from_lproj("zh_Hant_HK.lproj", "zh-Hant-HK");
+    assert_from_lproj("Base.lproj", "und");
+    assert_from_lproj("English.lproj", "en");
+    assert_from_lproj("Japanese.lproj", "ja");
+    assert_from_lproj("sr-Latn", "sr-Latn");
+
+    assert!(from_lproj("Klingonese.lproj").is_err());
+    assert!(from_lproj("en-USA.lproj").is_err());
+}
+
+#[test]
+fn test_to_lproj() {
+    for (input, expected) in &[
+        ("en", "en.lproj"),
+        ("en-GB", "en-GB.lproj"),
+        ("zh-Hans", "zh-Hans.lproj"),
+        ("es-419", "es-419.lproj"),
+        ("und", "Base.lproj"),
+    ] {
+        let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+        assert_eq!(to_lproj(&langid), *expected);
+        assert_eq!(from_lproj(expected), Ok(langid));
+    }
+}
@@ -0,0 +1,40 @@
+//! Proptest strategies shared by the tests of `unic-langid-impl` and
+//! `unic-locale-impl`.
+#![allow(dead_code)]
+
+use proptest::prelude::*;
+
+fn strategy(language: &'static str, max_variants: usize) -> impl Strategy<Value = String> {
+    (
+        language,
+        proptest::option::of("[a-zA-Z]{4}"),
+        proptest::option::of("([a-zA-Z]{2}|[0-9]{3})"),
+        proptest::collection::vec("([a-zA-Z0-9]{5,8}|[0-9][a-zA-Z0-9]{3})", 0..=max_variants)
+            .prop_filter("duplicate variants", |variants| {
+                variants.iter().enumerate().all(|(idx, variant)| {
+                    !variants[..idx]
+                        .iter()
+                        .any(|v| v.eq_ignore_ascii_case(variant))
+                })
+            }),
+    )
+        .prop_map(|(language, script, region, variants)| {
+            let mut parts = vec![language];
+            parts.extend(script);
+            parts.extend(region);
+            parts.extend(variants);
+            parts.join("-")
+        })
+}
+
+/// Returns a strategy of well-formed language identifiers in mixed case,
+/// with up to `max_variants` variants which are distinct ignoring case.
+pub fn langid_strategy(max_variants: usize) -> impl Strategy<Value = String> {
+    strategy("([a-zA-Z]{2,3}|[a-zA-Z]{5,8}|und)", max_variants)
+}
+
+/// Like [`langid_strategy`], without the 5 to 8 letter languages which
+/// ICU4X doesn't support.
+pub fn icu4x_langid_strategy(max_variants: usize) -> impl Strategy<Value = String> {
+    strategy("([a-zA-Z]{2,3}|und)", max_variants)
+}
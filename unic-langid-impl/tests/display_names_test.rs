@@ -0,0 +1,124 @@
+use unic_langid_impl::display_names::{
+    Alternate, DisplayNames, DisplayNamesOptions, LanguageDisplay,
+};
+use unic_langid_impl::LanguageIdentifier;
+
+fn names(locale: &str, language_display: LanguageDisplay, alternate: Alternate) -> DisplayNames {
+    let options = DisplayNamesOptions {
+        language_display,
+        alternate,
+    };
+    DisplayNames::new(&locale.parse().unwrap(), options).unwrap()
+}
+
+fn name(names: &DisplayNames, input: &str) -> String {
+    let langid: LanguageIdentifier = input.parse().unwrap();
+    names.language_identifier(&langid)
+}
+
+#[test]
+fn dialect_and_standard() {
+    let tests = [
+        ("de", "Schweizer Hochdeutsch", "Deutsch (Schweiz)"),
+        ("en", "Swiss High German", "German (Switzerland)"),
+        ("fr", "allemand suisse", "allemand (Suisse)"),
+    ];
+    for (locale, dialect, standard) in tests {
+        let dialect_names = names(locale, LanguageDisplay::Dialect, Alternate::Default);
+        assert_eq!(name(&dialect_names, "de-CH"), dialect, "{}", locale);
+        let standard_names = names(locale, LanguageDisplay::Standard, Alternate::Default);
+        assert_eq!(name(&standard_names, "de-CH"), standard, "{}", locale);
+    }
+}
+
+#[test]
+fn qualifiers() {
+    let en = names("en", LanguageDisplay::Dialect, Alternate::Default);
+    assert_eq!(name(&en, "en"), "English");
+    assert_eq!(name(&en, "en-GB"), "British English");
+    assert_eq!(name(&en, "zh-Hant"), "Traditional Chinese");
+    assert_eq!(name(&en, "zh-Hant-TW"), "Traditional Chinese (Taiwan)");
+    assert_eq!(name(&en, "zh-TW"), "Chinese (Taiwan)");
+    assert_eq!(name(&en, "sr-Cyrl-RU"), "Serbian (Cyrillic, Russia)");
+    assert_eq!(
+        name(&en, "de-CH-1996"),
+        "Swiss High German (German orthography of 1996)"
+    );
+    assert_eq!(name(&en, "el-polyton"), "Greek (Polytonic)");
+    assert_eq!(name(&en, "und"), "Unknown language");
+    assert_eq!(name(&en, "und-Latn"), "Unknown language (Latin)");
+
+    let standard = names("en", LanguageDisplay::Standard, Alternate::Default);
+    assert_eq!(
+        name(&standard, "zh-Hant-TW"),
+        "Chinese (Traditional, Taiwan)"
+    );
+}
+
+#[test]
+fn missing_names() {
+    let en = names("en", LanguageDisplay::Dialect, Alternate::Default);
+    assert_eq!(name(&en, "xyz"), "xyz");
+    assert_eq!(name(&en, "xyz-Qaaa-AA"), "xyz (Qaaa, AA)");
+    assert_eq!(name(&en, "de-abcde"), "German (abcde)");
+    assert_eq!(en.language("xyz".parse().unwrap()), None);
+    assert_eq!(en.script("Qaaa".parse().unwrap()), None);
+    assert_eq!(en.region("AA".parse().unwrap()), None);
+    assert_eq!(en.variant("abcde".parse().unwrap()), None);
+}
+
+#[test]
+fn alternates() {
+    let short = names("en", LanguageDisplay::Dialect, Alternate::Short);
+    assert_eq!(name(&short, "en-GB"), "UK English");
+    assert_eq!(name(&short, "fr-GB"), "French (UK)");
+    assert_eq!(name(&short, "zh-HK"), "Chinese (Hong Kong)");
+    assert_eq!(name(&short, "fr-CI"), "French (Côte d’Ivoire)");
+
+    let variant = names("en", LanguageDisplay::Dialect, Alternate::Variant);
+    assert_eq!(name(&variant, "fr-CI"), "French (Ivory Coast)");
+    assert_eq!(name(&variant, "en-GB"), "British English");
+
+    let fr = names("fr", LanguageDisplay::Standard, Alternate::Short);
+    assert_eq!(name(&fr, "en-US"), "anglais (É.-U.)");
+}
+
+#[test]
+fn keywords() {
+    let en = names("en", LanguageDisplay::Dialect, Alternate::Default);
+    assert_eq!(en.key("ca"), Some("Calendar"));
+    assert_eq!(en.key_type("ca", "buddhist"), Some("Buddhist Calendar"));
+    assert_eq!(en.key_type("ca", "gregory"), Some("Gregorian Calendar"));
+    assert_eq!(en.key_type("co", "phonebk"), Some("Phonebook Sort Order"));
+    assert_eq!(en.key_type("kn", "true"), Some("Sort Digits Numerically"));
+    assert_eq!(en.keyword("ca", "coptic"), "Coptic Calendar");
+    assert_eq!(en.keyword("ca", "abcde"), "Calendar: abcde");
+    assert_eq!(en.keyword("xx", "abc"), "xx: abc");
+
+    let langid: LanguageIdentifier = "ja-JP".parse().unwrap();
+    assert_eq!(
+        en.locale_with_keywords(&langid, [("ca", "buddhist"), ("nu", "thai")]),
+        "Japanese (Japan, Buddhist Calendar, Thai Digits)"
+    );
+
+    let fr = names("fr", LanguageDisplay::Dialect, Alternate::Default);
+    assert_eq!(fr.keyword("ca", "abcde"), "calendrier\u{202f}: abcde");
+}
+
+#[test]
+fn display_locale_fallback() {
+    let options = DisplayNamesOptions::default();
+    let de_at = DisplayNames::new(&"de-AT".parse().unwrap(), options).unwrap();
+    assert_eq!(de_at.locale().to_string(), "de-AT");
+    assert_eq!(name(&de_at, "fur"), "Friulanisch");
+    assert_eq!(name(&de_at, "fr-CA"), "Französisch (Kanada)");
+
+    let de_li = DisplayNames::new(&"de-LI".parse().unwrap(), options).unwrap();
+    assert_eq!(de_li.locale().to_string(), "de");
+
+    let de_latn_ch = DisplayNames::new(&"de-Latn-CH-1996".parse().unwrap(), options).unwrap();
+    assert_eq!(de_latn_ch.locale().to_string(), "de");
+
+    assert!(DisplayNames::new(&"pl".parse().unwrap(), options).is_none());
+    assert!(DisplayNames::new(&"und".parse().unwrap(), options).is_none());
+}
@@ -0,0 +1,162 @@
+mod common;
+
+use common::langid_strategy;
+use proptest::prelude::*;
+use unic_langid_impl::parser::ParserError;
+use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
+
+fn invalid(offset: usize) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+    Err(LanguageIdentifierError::ParserError(
+        ParserError::InvalidEncoding { offset },
+    ))
+}
+
+fn assert_bytes(input: &str, expected: &[u8]) {
+    let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+    assert_eq!(langid.to_bytes(), expected);
+    assert_eq!(LanguageIdentifier::from_bytes_encoded(expected), Ok(langid));
+}
+
+fn assert_u64(input: &str) {
+    let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+    let packed = langid.to_u64().expect("Packing failed.");
+    assert_eq!(LanguageIdentifier::from_u64(packed), Ok(langid));
+}
+
+#[test]
+fn test_to_bytes() {
+    assert_bytes("und", &[1, 0x00, 0]);
+    assert_bytes("en", &[1, 0x02, b'e', b'n', 0]);
+    assert_bytes("en-US", &[1, 0x42, b'e', b'n', b'U', b'S', 0]);
+    assert_bytes("es-419", &[1, 0x62, b'e', b's', b'4', b'1', b'9', 0]);
+    assert_bytes("und-Latn", &[1, 0x10, b'L', b'a', b't', b'n', 0]);
+    assert_bytes(
+        "sl-rozaj-biske",
+        &[
+            1, 0x02, b's', b'l', 2, 5, b'b', b'i', b's', b'k', b'e', 5, b'r', b'o', b'z', b'a',
+            b'j',
+        ],
+    );
+}
+
+#[test]
+fn test_from_bytes_encoded_invalid() {
+    assert_eq!(LanguageIdentifier::from_bytes_encoded(&[]), invalid(0));
+    // Unknown version.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[2, 0x00, 0]),
+        invalid(0)
+    );
+    // Reserved header bit.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x80, 0]),
+        invalid(1)
+    );
+    // Truncated language.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x03, b'e', b'n']),
+        invalid(2)
+    );
+    // Malformed language.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x02, b'e', b'1', 0]),
+        invalid(2)
+    );
+    // Missing variant count.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x02, b'e', b'n']),
+        invalid(4)
+    );
+    // Trailing bytes.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x02, b'e', b'n', 0, 0]),
+        invalid(5)
+    );
+    // Non-canonical case.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x02, b'E', b'n', 0]),
+        invalid(1)
+    );
+    // `und` spelled out.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x03, b'u', b'n', b'd', 0]),
+        invalid(1)
+    );
+    // Overlong variant count.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[1, 0x02, b'e', b'n', 0x80, 0x00]),
+        invalid(4)
+    );
+    // Unordered variants.
+    assert_eq!(
+        LanguageIdentifier::from_bytes_encoded(&[
+            1, 0x02, b's', b'l', 2, 5, b'r', b'o', b'z', b'a', b'j', 5, b'b', b'i', b's', b'k',
+            b'e',
+        ]),
+        invalid(1)
+    );
+}
+
+#[test]
+fn test_u64() {
+    assert_u64("und");
+    assert_u64("en");
+    assert_u64("en-US");
+    assert_u64("zh-Hant-TW");
+    assert_u64("es-419");
+    assert_u64("und-Latn");
+    assert_u64("und-001");
+    assert_u64("zzzzzzz-Zzzz-ZZ");
+
+    let langid: LanguageIdentifier = "und".parse().expect("Parsing failed.");
+    assert_eq!(langid.to_u64(), Some(0));
+
+    let langid: LanguageIdentifier = "abcdefgh".parse().expect("Parsing failed.");
+    assert_eq!(langid.to_u64(), None);
+
+    let langid: LanguageIdentifier = "ca-ES-valencia".parse().expect("Parsing failed.");
+    assert_eq!(langid.to_u64(), None);
+}
+
+#[test]
+fn test_from_u64_invalid() {
+    // Region out of range.
+    assert_eq!(LanguageIdentifier::from_u64(2047), invalid(0));
+    // Single letter language.
+    assert_eq!(LanguageIdentifier::from_u64(1 << 30), invalid(0));
+    // Letter `a` followed by a padding digit.
+    assert_eq!(LanguageIdentifier::from_u64(27 << 30), invalid(0));
+}
+
+proptest! {
+    #[test]
+    fn test_bytes_roundtrip(input in langid_strategy(2)) {
+        let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+        let bytes = langid.to_bytes();
+        prop_assert_eq!(LanguageIdentifier::from_bytes_encoded(&bytes), Ok(langid));
+    }
+
+    #[test]
+    fn test_u64_roundtrip(input in langid_strategy(2)) {
+        let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+        if let Some(packed) = langid.to_u64() {
+            prop_assert_eq!(LanguageIdentifier::from_u64(packed), Ok(langid));
+        } else {
+            prop_assert!(langid.variants().len() > 0 || langid.language.as_str().len() == 8);
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_encoded_arbitrary(input in proptest::collection::vec(any::<u8>(), 0..24)) {
+        if let Ok(langid) = LanguageIdentifier::from_bytes_encoded(&input) {
+            prop_assert_eq!(langid.to_bytes(), input);
+        }
+    }
+
+    #[test]
+    fn test_from_u64_arbitrary(input in any::<u64>()) {
+        if let Ok(langid) = LanguageIdentifier::from_u64(input) {
+            prop_assert_eq!(langid.to_u64(), Some(input));
+        }
+    }
+}
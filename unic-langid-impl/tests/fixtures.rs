@@ -25,6 +25,7 @@ struct LangIdTestOutputObject {
 #[serde(untagged)]
 enum LangIdTestOutput {
     String(String),
+    Error { error: String },
     Object(LangIdTestOutputObject),
 }
 
@@ -46,6 +47,12 @@ fn test_langid_fixtures(path: &str) {
     for test in tests {
         let s = test.input.string;
 
+        if let LangIdTestOutput::Error { error } = test.output {
+            let err = s.parse::<LanguageIdentifier>().unwrap_err();
+            assert_eq!(err.to_string(), error);
+            continue;
+        }
+
         let langid: LanguageIdentifier = s.parse().expect("Parsing failed.");
 
         match test.output {
@@ -65,6 +72,7 @@ fn test_langid_fixtures(path: &str) {
             LangIdTestOutput::String(s) => {
                 assert_eq!(langid.to_string(), s);
             }
+            LangIdTestOutput::Error { .. } => unreachable!(),
         }
     }
 }
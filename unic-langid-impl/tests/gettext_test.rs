@@ -0,0 +1,109 @@
+use unic_langid_impl::gettext::{candidates, parse_language_header};
+use unic_langid_impl::parser::{ParserError, SubtagKind};
+use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
+
+fn assert_candidates(input: &str, codeset: Option<&str>, expected: &[&str]) {
+    let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+    assert_eq!(candidates(&langid, codeset), expected);
+}
+
+fn assert_header(input: &str, expected: &str) {
+    let langid = parse_language_header(input).expect("Parsing failed.");
+    assert_eq!(langid.to_string(), expected);
+}
+
+#[test]
+fn test_candidates() {
+    assert_candidates("de", None, &["de"]);
+    assert_candidates("pt-BR", None, &["pt_BR", "pt"]);
+    assert_candidates(
+        "sr-Latn-RS",
+        None,
+        &["sr_RS@latin", "sr@latin", "sr_RS", "sr"],
+    );
+    assert_candidates("sr-Cyrl", None, &["sr@cyrillic", "sr"]);
+    assert_candidates(
+        "ca-ES-valencia",
+        None,
+        &["ca_ES@valencia", "ca@valencia", "ca_ES", "ca"],
+    );
+    assert_candidates(
+        "sr-Latn-ME-ijekavsk",
+        None,
+        &["sr_ME@ijekavianlatin", "sr@ijekavianlatin", "sr_ME", "sr"],
+    );
+    // Scripts without a modifier are dropped.
+    assert_candidates("zh-Hant-TW", None, &["zh_TW", "zh"]);
+    // Variants without a modifier are dropped.
+    assert_candidates("de-DE-1901", None, &["de_DE", "de"]);
+}
+
+#[test]
+fn test_candidates_codeset() {
+    assert_candidates(
+        "sr-Latn-RS",
+        Some("UTF-8"),
+        &[
+            "sr_RS.UTF-8@latin",
+            "sr_RS.utf8@latin",
+            "sr_RS@latin",
+            "sr.UTF-8@latin",
+            "sr.utf8@latin",
+            "sr@latin",
+            "sr_RS.UTF-8",
+            "sr_RS.utf8",
+            "sr_RS",
+            "sr.UTF-8",
+            "sr.utf8",
+            "sr",
+        ],
+    );
+    assert_candidates("de", Some("8859-1"), &["de.8859-1", "de.iso88591", "de"]);
+    // A codeset which is already normalized is tried once.
+    assert_candidates("de", Some("utf8"), &["de.utf8", "de"]);
+}
+
+#[test]
+fn test_parse_language_header() {
+    assert_header("de", "de");
+    assert_header("pt_BR", "pt-BR");
+    assert_header("sr@latin", "sr-Latn");
+    assert_header("sr_RS@ijekavianlatin", "sr-Latn-RS-ijekavsk");
+    assert_header("ca@valencia", "ca-valencia");
+    assert_header("de_DE.UTF-8", "de-DE");
+    assert_header("Language: uz_UZ@cyrillic", "uz-Cyrl-UZ");
+    assert_header("language:zh_TW\n", "zh-TW");
+    assert_header("Language: ", "und");
+    assert_header("", "und");
+
+    assert_eq!(
+        parse_language_header("Language: de@x"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::MalformedSubtag {
+                expected: SubtagKind::Variant,
+                offset: 13,
+                length: 1,
+            }
+        ))
+    );
+    assert_eq!(
+        parse_language_header("  language:\tsr_RS.UTF-8@x\n"),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::MalformedSubtag {
+                expected: SubtagKind::Variant,
+                offset: 24,
+                length: 1,
+            }
+        ))
+    );
+    assert!(parse_language_header("Language: en_USA").is_err());
+}
+
+#[test]
+fn test_candidates_roundtrip() {
+    for tag in &["sr-Latn-RS", "ca-ES-valencia", "uz-Cyrl", "pt-BR"] {
+        let langid: LanguageIdentifier = tag.parse().unwrap();
+        let candidates = candidates(&langid, None);
+        assert_eq!(parse_language_header(&candidates[0]), Ok(langid));
+    }
+}
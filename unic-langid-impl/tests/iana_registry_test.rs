@@ -0,0 +1,102 @@
+use unic_langid_impl::iana_registry::{Record, FILE_DATE};
+use unic_langid_impl::subtags::{Language, Region, Script, Variant};
+use unic_langid_impl::LanguageIdentifier;
+
+fn langid(input: &str) -> LanguageIdentifier {
+    input.parse().unwrap()
+}
+
+#[test]
+fn records_test() {
+    assert_eq!(FILE_DATE, "2024-03-07");
+
+    let language: Language = "mo".parse().unwrap();
+    assert_eq!(
+        language.registry_record(),
+        Some(&Record {
+            descriptions: &["Moldavian", "Moldovan"],
+            added: "2005-10-16",
+            deprecated: Some("2008-11-22"),
+            preferred_value: Some("ro"),
+        })
+    );
+
+    let script: Script = "Jpan".parse().unwrap();
+    assert_eq!(
+        script.registry_record().unwrap().descriptions,
+        &["Japanese (alias for Han + Hiragana + Katakana)"]
+    );
+
+    let region: Region = "419".parse().unwrap();
+    assert_eq!(
+        region.registry_record().unwrap().descriptions,
+        &["Latin America and the Caribbean"]
+    );
+
+    let variant: Variant = "heploc".parse().unwrap();
+    let record = variant.registry_record().unwrap();
+    assert_eq!(record.deprecated, Some("2010-02-07"));
+    assert_eq!(record.preferred_value, Some("alalc97"));
+
+    let language: Language = "xyz".parse().unwrap();
+    assert_eq!(language.registry_record(), None);
+    // Private use ranges are left out.
+    let language: Language = "qaa".parse().unwrap();
+    assert_eq!(language.registry_record(), None);
+}
+
+#[test]
+fn suppress_script_test() {
+    let tests = [
+        ("en-Latn", true, "en"),
+        ("en-Latn-US", true, "en-US"),
+        ("en-Cyrl", false, "en-Cyrl"),
+        ("en", false, "en"),
+        ("ru-Cyrl-RU", true, "ru-RU"),
+        ("sr-Latn", false, "sr-Latn"),
+        ("ja-Jpan-JP", true, "ja-JP"),
+        ("xyz-Latn", false, "xyz-Latn"),
+    ];
+    for (input, suppressed, output) in tests {
+        let mut li = langid(input);
+        assert_eq!(li.has_suppressed_script(), suppressed, "{}", input);
+        assert_eq!(li.remove_suppressed_script(), suppressed, "{}", input);
+        assert_eq!(li.to_string(), output);
+        assert!(!li.has_suppressed_script());
+    }
+}
+
+#[test]
+fn variant_prefixes_test() {
+    let variant: Variant = "rozaj".parse().unwrap();
+    assert_eq!(variant.prefixes(), &["sl"]);
+    let variant: Variant = "fonipa".parse().unwrap();
+    assert!(variant.prefixes().is_empty());
+
+    let tests = [
+        ("de-1901", true),
+        ("de-CH-1901", true),
+        ("de-Latn-AT-1996", true),
+        ("fr-1901", false),
+        ("sl-rozaj", true),
+        ("sl-IT-rozaj", true),
+        ("sl-rozaj-biske", true),
+        ("sl-biske", false),
+        ("ja-Latn-hepburn", true),
+        ("ja-hepburn", false),
+        ("ja-Latn-hepburn-heploc", true),
+        ("ja-Latn-heploc", false),
+        ("en-fonipa", true),
+        ("en-xxxxx", true),
+        ("ca-valencia", true),
+        ("es-valencia", false),
+    ];
+    for (input, valid) in tests {
+        assert_eq!(
+            langid(input).has_valid_variant_prefixes(),
+            valid,
+            "{}",
+            input
+        );
+    }
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::icu4x_langid_strategy;
+use proptest::prelude::*;
+use unic_langid_impl::parser::ParserError;
+use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
+
+fn assert_roundtrip(input: &str) {
+    let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+    let icu_langid =
+        icu_locale_core::LanguageIdentifier::try_from(&langid).expect("Conversion failed.");
+    assert_eq!(icu_langid.to_string(), langid.to_string());
+    assert_eq!(LanguageIdentifier::from(&icu_langid), langid);
+}
+
+#[test]
+fn test_icu4x_roundtrip() {
+    assert_roundtrip("und");
+    assert_roundtrip("en-US");
+    assert_roundtrip("sr-Latn-RS");
+    assert_roundtrip("es-419");
+    assert_roundtrip("und-Cyrl");
+    assert_roundtrip("sl-rozaj-biske-1994");
+    assert_roundtrip("de-CH-1996");
+}
+
+#[test]
+fn test_icu4x_long_language() {
+    let langid: LanguageIdentifier = "german-DE".parse().expect("Parsing failed.");
+    assert_eq!(
+        icu_locale_core::LanguageIdentifier::try_from(&langid),
+        Err(LanguageIdentifierError::ParserError(
+            ParserError::InvalidLanguage
+        ))
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_icu4x_from_langid(input in icu4x_langid_strategy(2)) {
+        let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+        let icu_langid = icu_locale_core::LanguageIdentifier::try_from(&langid)
+            .expect("Conversion failed.");
+        prop_assert_eq!(icu_langid.to_string(), langid.to_string());
+        prop_assert_eq!(LanguageIdentifier::from(icu_langid), langid);
+    }
+
+    #[test]
+    fn test_icu4x_to_langid(input in icu4x_langid_strategy(2)) {
+        let icu_langid: icu_locale_core::LanguageIdentifier =
+            input.parse().expect("Parsing failed.");
+        let langid = LanguageIdentifier::from(&icu_langid);
+        prop_assert_eq!(langid.to_string(), icu_langid.to_string());
+        prop_assert_eq!(
+            icu_locale_core::LanguageIdentifier::try_from(langid).expect("Conversion failed."),
+            icu_langid
+        );
+    }
+}
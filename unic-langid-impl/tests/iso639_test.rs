@@ -0,0 +1,135 @@
+use unic_langid_impl::subtags::Language;
+use unic_langid_impl::{LanguageIdentifier, MatchOptions};
+
+fn codes(
+    language: &str,
+) -> (
+    Option<&'static str>,
+    Option<&'static str>,
+    Option<&'static str>,
+    Option<&'static str>,
+) {
+    let language: Language = language.parse().unwrap();
+    (
+        language.to_iso639_1(),
+        language.to_iso639_2t(),
+        language.to_iso639_2b(),
+        language.to_iso639_3(),
+    )
+}
+
+#[test]
+fn conversions_test() {
+    assert_eq!(
+        codes("de"),
+        (Some("de"), Some("deu"), Some("ger"), Some("deu"))
+    );
+    assert_eq!(
+        codes("en"),
+        (Some("en"), Some("eng"), Some("eng"), Some("eng"))
+    );
+    assert_eq!(
+        codes("zh"),
+        (Some("zh"), Some("zho"), Some("chi"), Some("zho"))
+    );
+    assert_eq!(codes("gsw"), (None, Some("gsw"), Some("gsw"), Some("gsw")));
+    assert_eq!(codes("cmn"), (None, None, None, Some("cmn")));
+    assert_eq!(codes("und"), (None, Some("und"), Some("und"), Some("und")));
+    assert_eq!(codes("xyz"), (None, None, None, None));
+}
+
+#[test]
+fn non_canonical_test() {
+    assert_eq!(codes("fre"), codes("fr"));
+    assert_eq!(codes("fra"), codes("fr"));
+}
+
+#[test]
+fn from_iso639_test() {
+    let tests = [
+        ("de", "de"),
+        ("deu", "de"),
+        ("ger", "de"),
+        ("GER", "de"),
+        ("fre", "fr"),
+        ("chi", "zh"),
+        ("zho", "zh"),
+        ("cmn", "cmn"),
+        ("gsw", "gsw"),
+        ("nob", "nb"),
+        ("und", "und"),
+        ("xyz", "xyz"),
+    ];
+    for (input, output) in tests {
+        assert_eq!(
+            Language::from_iso639(input),
+            Ok(output.parse().unwrap()),
+            "{}",
+            input
+        );
+    }
+
+    assert!(Language::from_iso639("").is_err());
+    assert!(Language::from_iso639("d").is_err());
+    assert!(Language::from_iso639("de1").is_err());
+}
+
+#[test]
+fn macrolanguages_test() {
+    let language = |code: &str| -> Language { code.parse().unwrap() };
+
+    assert_eq!(language("cmn").macrolanguage(), Some(language("zh")));
+    assert_eq!(language("arb").macrolanguage(), Some(language("ar")));
+    assert_eq!(language("zsm").macrolanguage(), Some(language("ms")));
+    assert_eq!(language("id").macrolanguage(), Some(language("ms")));
+    assert_eq!(language("nob").macrolanguage(), Some(language("no")));
+    assert_eq!(language("swh").macrolanguage(), Some(language("sw")));
+    assert_eq!(language("ekk").macrolanguage(), Some(language("et")));
+    assert_eq!(language("zh").macrolanguage(), None);
+    assert_eq!(language("de").macrolanguage(), None);
+
+    assert!(language("zh").is_macrolanguage());
+    assert!(language("zho").is_macrolanguage());
+    assert!(!language("cmn").is_macrolanguage());
+    assert!(language("sw").is_macrolanguage());
+    assert!(!language("de").is_macrolanguage());
+
+    assert_eq!(
+        language("ar").encompassed_languages(),
+        vec![
+            language("acm"),
+            language("arb"),
+            language("ary"),
+            language("arz")
+        ]
+    );
+    assert!(language("zh")
+        .encompassed_languages()
+        .contains(&language("yue")));
+    assert!(language("de").encompassed_languages().is_empty());
+}
+
+#[test]
+fn matches_macrolanguages_test() {
+    let langid = |input: &str| -> LanguageIdentifier { input.parse().unwrap() };
+    let options = MatchOptions {
+        self_as_range: true,
+        other_as_range: false,
+        macrolanguages: true,
+    };
+
+    let available = langid("zh-Hans");
+    assert!(!available.matches(&langid("cmn-Hans-CN"), true, false));
+    assert!(available.matches_with_options(&langid("cmn-Hans-CN"), options));
+    assert!(langid("cmn-Hans-CN").matches_with_options(&langid("zh-Hans-CN"), options));
+    assert!(langid("ms").matches_with_options(&langid("zsm-MY"), options));
+    assert!(!available.matches_with_options(&langid("cmn-Hant-TW"), options));
+    // Individual languages of the same macrolanguage don't match.
+    assert!(!langid("cmn").matches_with_options(&langid("yue"), options));
+
+    let options = MatchOptions {
+        macrolanguages: false,
+        ..options
+    };
+    assert!(!available.matches_with_options(&langid("cmn-Hans-CN"), options));
+}
@@ -1,7 +1,10 @@
-use unic_langid_impl::parser::parse_language_identifier;
+#![allow(clippy::bool_assert_comparison)]
+
+use unic_langid_impl::parser::{parse_language_identifier, ParserError, SubtagKind};
 use unic_langid_impl::subtags;
 use unic_langid_impl::CharacterDirection;
 use unic_langid_impl::LanguageIdentifier;
+use unic_langid_impl::LanguageIdentifierError;
 
 fn assert_language_identifier(
     loc: &LanguageIdentifier,
@@ -57,6 +60,110 @@ fn test_language_casing() {
     assert_parsed_language_identifier("ZH_cyrl_hN", Some("zh"), Some("Cyrl"), Some("HN"), None);
 }
 
+#[test]
+fn test_parser_errors() {
+    assert_eq!(
+        parse_language_identifier(b"e1-US"),
+        Err(ParserError::MalformedSubtag {
+            expected: SubtagKind::Language,
+            offset: 0,
+            length: 2,
+        })
+    );
+    assert_eq!(
+        parse_language_identifier(b"en-Latn-USA"),
+        Err(ParserError::MalformedSubtag {
+            expected: SubtagKind::Region,
+            offset: 8,
+            length: 3,
+        })
+    );
+    assert_eq!(
+        parse_language_identifier(b"en_US-x"),
+        Err(ParserError::MalformedSubtag {
+            expected: SubtagKind::Variant,
+            offset: 6,
+            length: 1,
+        })
+    );
+    assert_eq!(
+        parse_language_identifier(b"de-DE-"),
+        Err(ParserError::MalformedSubtag {
+            expected: SubtagKind::Variant,
+            offset: 6,
+            length: 0,
+        })
+    );
+
+    let err = parse_language_identifier(b"sl-rozaj-biske-rozaj").unwrap_err();
+    assert_eq!(
+        err,
+        ParserError::DuplicateVariant {
+            offset: 15,
+            length: 5,
+        }
+    );
+    assert_eq!(err.to_string(), "Duplicate variant subtag at 15..20");
+    assert_eq!(
+        ParserError::MalformedSubtag {
+            expected: SubtagKind::Script,
+            offset: 3,
+            length: 4,
+        }
+        .to_string(),
+        "Invalid subtag at 3..7, expected script"
+    );
+}
+
+#[test]
+fn test_lenient_parser() {
+    fn assert_lenient(input: &str, expected: &str, dropped: &[&str]) {
+        let (langid, errors) = LanguageIdentifier::from_bytes_lenient(input.as_bytes());
+        assert_eq!(langid.to_string(), expected);
+        let spans = errors
+            .iter()
+            .map(|e| &input[e.span().unwrap()])
+            .collect::<Vec<_>>();
+        assert_eq!(spans, dropped);
+    }
+
+    assert_lenient("en-US", "en-US", &[]);
+    assert_lenient("en-US-posix-xx_YY", "en-US-posix", &["xx", "YY"]);
+    assert_lenient("en_US.UTF-8", "en-US", &[".UTF-8"]);
+    assert_lenient("de-DE-", "de-DE", &[""]);
+    assert_lenient("sr-Latn-RS-@latin", "sr-Latn-RS", &["@latin"]);
+    assert_lenient("sl-rozaj-rozaj-biske", "sl-biske-rozaj", &["rozaj"]);
+    assert_lenient("en-Latn-USA-GB", "en-Latn-GB", &["USA"]);
+    assert_lenient("en-US-u-ca-buddhist", "en-US", &["u-ca-buddhist"]);
+    assert_lenient("e1-US", "und", &["e1"]);
+}
+
+#[test]
+fn test_duplicate_variants() {
+    let err = "pl-macos-Windows-nedis-macos-nedis-aRabic"
+        .parse::<LanguageIdentifier>()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        LanguageIdentifierError::ParserError(ParserError::DuplicateVariant {
+            offset: 23,
+            length: 5,
+        })
+    );
+
+    // Variants differing only in case are duplicates too.
+    assert_eq!(
+        parse_language_identifier(b"sl-rozaj-ROZAJ"),
+        Err(ParserError::DuplicateVariant {
+            offset: 9,
+            length: 5,
+        })
+    );
+    assert!("pl-macos-Windows-nedis-aRabic"
+        .parse::<LanguageIdentifier>()
+        .is_ok());
+}
+
 #[test]
 fn test_serialize_langid() {
     let langid: LanguageIdentifier = "en-Latn-US".parse().unwrap();
@@ -1,5 +1,8 @@
-use unic_langid_impl::likelysubtags::{maximize, minimize, CLDR_VERSION};
+use unic_langid_impl::likelysubtags::{
+    covers, maximize, minimize, minimize_favor_script, CLDR_VERSION,
+};
 use unic_langid_impl::subtags;
+use unic_langid_impl::{LanguageIdentifier, PackedLanguageIdentifier};
 
 static STRINGS: &[(&str, Option<&str>)] = &[
     ("en-US", Some("en-Latn-US")),
@@ -98,6 +101,14 @@ fn version_works() {
     assert_eq!(CLDR_VERSION, "44");
 }
 
+#[test]
+fn covers_test() {
+    for i in STRINGS {
+        let chunks = extract_input(i.0);
+        assert!(covers(chunks.0, chunks.1, chunks.2));
+    }
+}
+
 #[test]
 fn minimize_test() {
     let lang = "zh".parse().unwrap();
@@ -111,3 +122,45 @@ fn minimize_test() {
     let result = minimize(lang, Some(script), Some(region));
     assert_eq!(result, Some(extract_input("en")));
 }
+
+#[test]
+fn minimize_favor_script_test() {
+    let lang = "zh".parse().unwrap();
+    let script = "Hant".parse().unwrap();
+    let region = "TW".parse().unwrap();
+    let result = minimize_favor_script(lang, Some(script), Some(region));
+    assert_eq!(result, Some(extract_input("zh-Hant")));
+    let result = minimize(lang, Some(script), Some(region));
+    assert_eq!(result, Some(extract_input("zh-TW")));
+
+    // Without a choice to make, both give the same result.
+    let lang = "sr".parse().unwrap();
+    let script = "Cyrl".parse().unwrap();
+    let region = "RS".parse().unwrap();
+    let result = minimize_favor_script(lang, Some(script), Some(region));
+    assert_eq!(result, Some(extract_input("sr")));
+
+    let lang = "sr".parse().unwrap();
+    let script = "Latn".parse().unwrap();
+    let region = "RS".parse().unwrap();
+    let result = minimize_favor_script(lang, Some(script), Some(region));
+    assert_eq!(result, Some(extract_input("sr-Latn")));
+
+    let lang = "pt".parse().unwrap();
+    let region = "PT".parse().unwrap();
+    let result = minimize_favor_script(lang, None, Some(region));
+    assert_eq!(result, Some(extract_input("pt-PT")));
+}
+
+#[test]
+fn packed_test() {
+    for i in STRINGS {
+        let mut langid: LanguageIdentifier = i.0.parse().unwrap();
+        let mut packed = PackedLanguageIdentifier::try_from(&langid).unwrap();
+        assert_eq!(packed.maximize(), langid.maximize());
+        assert_eq!(LanguageIdentifier::from(packed), langid);
+
+        assert_eq!(packed.minimize(), langid.minimize());
+        assert_eq!(LanguageIdentifier::from(packed), langid);
+    }
+}
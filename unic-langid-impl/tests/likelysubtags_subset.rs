@@ -0,0 +1,106 @@
+//! Checks the likely subtags tables against the subset they were built
+//! with, if any. CI builds them with:
+//!
+//! ```text
+//! UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES=en,sr \
+//! UNIC_LANGID_LIKELYSUBTAGS_SCRIPTS=Latn,Cyrl \
+//! UNIC_LANGID_LIKELYSUBTAGS_REGIONS=US,RS \
+//!     cargo test --features likelysubtags --test likelysubtags_subset
+//! ```
+//!
+//! Without these variables the tables are complete, and every identifier
+//! must maximize and minimize as with the full data.
+use unic_langid_impl::likelysubtags::{covers, IS_SUBSET};
+use unic_langid_impl::LanguageIdentifier;
+
+/// Identifiers, and how they maximize and minimize with the full data.
+static STRINGS: &[(&str, &str, &str)] = &[
+    ("en", "en-Latn-US", "en"),
+    ("en-US", "en-Latn-US", "en"),
+    ("en-Latn-US", "en-Latn-US", "en"),
+    ("en-Cyrl", "en-Cyrl-US", "en-Cyrl"),
+    ("sr", "sr-Cyrl-RS", "sr"),
+    ("sr-RS", "sr-Cyrl-RS", "sr"),
+    ("sr-Latn", "sr-Latn-RS", "sr-Latn"),
+    ("sr-Latn-RS", "sr-Latn-RS", "sr-Latn"),
+    ("und-RS", "sr-Cyrl-RS", "sr"),
+    ("und-US", "en-Latn-US", "en"),
+    ("und-Latn-RS", "sr-Latn-RS", "sr-Latn"),
+    ("und-Cyrl", "ru-Cyrl-RU", "ru"),
+    ("en-FR", "en-Latn-FR", "en-FR"),
+    ("fr", "fr-Latn-FR", "fr"),
+    ("fr-Latn-FR", "fr-Latn-FR", "fr"),
+    ("und-FR", "fr-Latn-FR", "fr"),
+    ("und-Arab", "ar-Arab-EG", "ar"),
+    ("zh-TW", "zh-Hant-TW", "zh-TW"),
+    ("sr-ME", "sr-Latn-ME", "sr-ME"),
+];
+
+fn listed(list: Option<&str>, subtag: Option<&str>) -> bool {
+    match (list, subtag) {
+        (Some(list), Some(subtag)) => list
+            .split(',')
+            .any(|s| s.trim().eq_ignore_ascii_case(subtag)),
+        _ => true,
+    }
+}
+
+/// Returns whether the subtags of `langid` are listed in the subset the
+/// tables were built with. `und` always is.
+fn kept(langid: &LanguageIdentifier) -> bool {
+    let language = Some(langid.language.as_str()).filter(|l| *l != "und");
+    listed(option_env!("UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES"), language)
+        && listed(
+            option_env!("UNIC_LANGID_LIKELYSUBTAGS_SCRIPTS"),
+            langid.script.as_ref().map(|s| s.as_str()),
+        )
+        && listed(
+            option_env!("UNIC_LANGID_LIKELYSUBTAGS_REGIONS"),
+            langid.region.as_ref().map(|r| r.as_str()),
+        )
+}
+
+#[test]
+fn is_subset_test() {
+    let configured = option_env!("UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES").is_some()
+        || option_env!("UNIC_LANGID_LIKELYSUBTAGS_SCRIPTS").is_some()
+        || option_env!("UNIC_LANGID_LIKELYSUBTAGS_REGIONS").is_some();
+    assert_eq!(IS_SUBSET, configured);
+}
+
+#[test]
+fn subset_test() {
+    for (input, maximized, minimized) in STRINGS {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        let max_langid: LanguageIdentifier = maximized.parse().unwrap();
+        let is_kept = kept(&langid);
+        assert_eq!(
+            covers(langid.language, langid.script, langid.region),
+            is_kept,
+            "{}",
+            input
+        );
+
+        let mut result = langid.clone();
+        result.maximize();
+        let expected = if is_kept { maximized } else { input };
+        assert_eq!(result.to_string(), *expected, "maximize {}", input);
+
+        // Minimizing goes through the maximized form, so one made of
+        // subtags which aren't listed may also leave it unchanged.
+        let mut result = langid.clone();
+        result.minimize();
+        if !is_kept {
+            assert_eq!(result.to_string(), *input, "minimize {}", input);
+        } else if kept(&max_langid) {
+            assert_eq!(result.to_string(), *minimized, "minimize {}", input);
+        } else {
+            let result = result.to_string();
+            assert!(
+                result == *minimized || result == *input,
+                "minimize {}",
+                input
+            );
+        }
+    }
+}
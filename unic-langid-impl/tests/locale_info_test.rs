@@ -0,0 +1,70 @@
+use unic_langid_impl::locale_info::{self, HourCycle, WeekInfo};
+use unic_langid_impl::LanguageIdentifier;
+
+fn langid(input: &str) -> LanguageIdentifier {
+    input.parse().unwrap()
+}
+
+#[test]
+fn calendars_test() {
+    assert_eq!(locale_info::calendars(&langid("en-US")), &["gregory"]);
+    assert_eq!(
+        locale_info::calendars(&langid("fa")),
+        &[
+            "persian",
+            "gregory",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla"
+        ]
+    );
+    assert_eq!(
+        locale_info::calendars(&langid("ja-JP")),
+        &["gregory", "japanese"]
+    );
+    assert_eq!(locale_info::calendars(&langid("ko")), &["gregory", "dangi"]);
+    // Regions without data use the world's calendars.
+    assert_eq!(locale_info::calendars(&langid("de-CH")), &["gregory"]);
+}
+
+#[test]
+fn hour_cycle_test() {
+    assert_eq!(locale_info::hour_cycle(&langid("en")), HourCycle::H12);
+    assert_eq!(locale_info::hour_cycle(&langid("en-GB")), HourCycle::H23);
+    assert_eq!(locale_info::hour_cycle(&langid("ca-ES")), HourCycle::H23);
+    assert_eq!(locale_info::hour_cycle(&langid("zh-TW")), HourCycle::H12);
+    assert_eq!(locale_info::hour_cycle(&langid("ko")), HourCycle::H12);
+    assert_eq!(locale_info::hour_cycle(&langid("pt-BR")), HourCycle::H23);
+    assert_eq!(locale_info::hour_cycle(&langid("und-AQ")), HourCycle::H23);
+
+    assert_eq!(HourCycle::H11.as_str(), "h11");
+    assert_eq!("h24".parse(), Ok(HourCycle::H24));
+    assert!("h13".parse::<HourCycle>().is_err());
+}
+
+#[test]
+fn week_info_test() {
+    assert_eq!(
+        locale_info::week_info(&langid("de")),
+        WeekInfo {
+            first_day: 1,
+            weekend: vec![6, 7],
+            minimal_days: 4,
+        }
+    );
+    assert_eq!(locale_info::week_info(&langid("he")).weekend, vec![5, 6]);
+    assert_eq!(locale_info::week_info(&langid("hi-IN")).weekend, vec![7]);
+    assert_eq!(locale_info::week_info(&langid("fa-IR")).first_day, 6);
+    assert_eq!(locale_info::week_info(&langid("ps-AF")).weekend, vec![4, 5]);
+    assert_eq!(locale_info::week_info(&langid("dv")).first_day, 5);
+    assert_eq!(locale_info::week_info(&langid("pt-BR")).first_day, 7);
+    assert_eq!(locale_info::week_info(&langid("sv")).minimal_days, 4);
+    assert_eq!(
+        locale_info::week_info(&langid("und-AQ")),
+        WeekInfo {
+            first_day: 1,
+            weekend: vec![6, 7],
+            minimal_days: 1,
+        }
+    );
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use common::langid_strategy;
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+use unic_langid_impl::parser::ParserError;
+use unic_langid_impl::{
+    LanguageIdentifier, LanguageIdentifierError, PackError, PackedLanguageIdentifier,
+};
+
+fn assert_roundtrip(input: &str) {
+    let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+    let packed = PackedLanguageIdentifier::try_from(&langid).expect("Packing failed.");
+    assert_eq!(packed.to_string(), input);
+    assert_eq!(packed.language(), langid.language);
+    assert_eq!(packed.script(), langid.script);
+    assert_eq!(packed.region(), langid.region);
+    assert_eq!(LanguageIdentifier::from(packed), langid);
+    assert_eq!(
+        PackedLanguageIdentifier::try_from_raw(packed.into_raw()),
+        Ok(packed)
+    );
+}
+
+#[test]
+fn test_packed_roundtrip() {
+    assert_roundtrip("und");
+    assert_roundtrip("en");
+    assert_roundtrip("en-US");
+    assert_roundtrip("zh-Hant-TW");
+    assert_roundtrip("es-419");
+    assert_roundtrip("und-Latn");
+    assert_roundtrip("abcdefgh-Zzzz-999");
+}
+
+#[test]
+fn test_packed_variants() {
+    let langid: LanguageIdentifier = "ca-ES-valencia".parse().expect("Parsing failed.");
+    assert_eq!(
+        PackedLanguageIdentifier::try_from(langid.clone()),
+        Err(PackError::Variant("valencia".parse().unwrap()))
+    );
+    assert_eq!(
+        PackedLanguageIdentifier::try_from(&langid),
+        Err(PackError::Variant("valencia".parse().unwrap()))
+    );
+    assert_eq!(
+        "ca-ES-valencia".parse::<PackedLanguageIdentifier>(),
+        Err(LanguageIdentifierError::PackError(PackError::Variant(
+            "valencia".parse().unwrap()
+        )))
+    );
+    assert_eq!(
+        PackError::Variant("valencia".parse().unwrap()).to_string(),
+        "The variant subtag valencia can't be packed"
+    );
+}
+
+#[test]
+fn test_packed_try_from_raw_invalid() {
+    let packed: PackedLanguageIdentifier = "en-US".parse().expect("Parsing failed.");
+    let raw = packed.into_raw();
+
+    // Uppercase language.
+    let upper = raw & !(0xFF << 120) | u128::from(b'E') << 120;
+    assert_eq!(
+        PackedLanguageIdentifier::try_from_raw(upper),
+        Err(ParserError::InvalidSubtag)
+    );
+    // Garbage after the padding of the region.
+    assert_eq!(
+        PackedLanguageIdentifier::try_from_raw(raw | 1),
+        Err(ParserError::InvalidSubtag)
+    );
+    // Single letter language.
+    assert_eq!(
+        PackedLanguageIdentifier::try_from_raw(u128::from(b'e') << 120),
+        Err(ParserError::InvalidLanguage)
+    );
+}
+
+#[test]
+fn test_packed_ord() {
+    let input = [
+        "und", "und-Latn", "en", "en-US", "en-Latn", "de-AT", "de-001",
+    ];
+    let langids = input
+        .iter()
+        .map(|s| s.parse::<LanguageIdentifier>().expect("Parsing failed."))
+        .collect::<BTreeSet<_>>();
+    let packed = input
+        .iter()
+        .map(|s| {
+            s.parse::<PackedLanguageIdentifier>()
+                .expect("Parsing failed.")
+        })
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        packed
+            .into_iter()
+            .map(LanguageIdentifier::from)
+            .collect::<Vec<_>>(),
+        langids.into_iter().collect::<Vec<_>>()
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_packed_roundtrip_arbitrary(input in langid_strategy(0)) {
+        let langid: LanguageIdentifier = input.parse().expect("Parsing failed.");
+        let packed = PackedLanguageIdentifier::try_from(&langid).expect("Packing failed.");
+        prop_assert_eq!(packed.to_string(), langid.to_string());
+        prop_assert_eq!(LanguageIdentifier::from(packed), langid);
+    }
+
+    #[test]
+    fn test_packed_ord_arbitrary(a in langid_strategy(0), b in langid_strategy(0)) {
+        let a: LanguageIdentifier = a.parse().expect("Parsing failed.");
+        let b: LanguageIdentifier = b.parse().expect("Parsing failed.");
+        let packed_a = PackedLanguageIdentifier::try_from(&a).expect("Packing failed.");
+        let packed_b = PackedLanguageIdentifier::try_from(&b).expect("Packing failed.");
+        prop_assert_eq!(packed_a.cmp(&packed_b), a.cmp(&b));
+    }
+
+    #[test]
+    fn test_packed_try_from_raw_arbitrary(input in any::<u128>()) {
+        if let Ok(packed) = PackedLanguageIdentifier::try_from_raw(input) {
+            prop_assert_eq!(packed.into_raw(), input);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use unic_langid_impl::LanguageIdentifier;
+
+fn parents(input: &str) -> Vec<String> {
+    let mut langid: LanguageIdentifier = input.parse().unwrap();
+    let mut result = vec![];
+    while let Some(parent) = langid.parent() {
+        result.push(parent.to_string());
+        langid = parent;
+    }
+    result
+}
+
+#[test]
+fn parent_test() {
+    assert_eq!(parents("en-AU"), vec!["en-001", "en", "und"]);
+    assert_eq!(parents("en-US"), vec!["en", "und"]);
+    assert_eq!(parents("es-AR"), vec!["es-419", "es", "und"]);
+    assert_eq!(parents("pt-AO"), vec!["pt-PT", "pt", "und"]);
+    assert_eq!(parents("zh-Hant-HK"), vec!["zh-Hant", "und"]);
+    assert_eq!(parents("zh-Hant-MO"), vec!["zh-Hant-HK", "zh-Hant", "und"]);
+    assert_eq!(parents("sr-Latn-ME"), vec!["sr-Latn", "und"]);
+    assert_eq!(parents("nb"), vec!["no", "und"]);
+    assert_eq!(parents("ca-ES-valencia"), vec!["ca-ES", "ca", "und"]);
+    assert_eq!(parents("und"), Vec::<String>::new());
+}
@@ -0,0 +1,246 @@
+use std::fs;
+use unic_langid_impl::likelysubtags;
+use unic_langid_impl::parser::ParserError;
+use unic_langid_impl::provider::{
+    self, BakedDataProvider, DataError, DataProvider, RuntimeDataProvider,
+};
+use unic_langid_impl::{CharacterDirection, LanguageIdentifier};
+
+fn cldr_provider() -> RuntimeDataProvider {
+    let mut provider = RuntimeDataProvider::default();
+    let json = fs::read_to_string("./data/cldr-core/supplemental/likelySubtags.json").unwrap();
+    provider.load_likely_subtags_json(&json).unwrap();
+    for entry in fs::read_dir("./data/cldr-misc-full/main/").unwrap() {
+        let mut path = entry.unwrap().path();
+        path.push("layout.json");
+        let json = fs::read_to_string(path).unwrap();
+        provider.load_layout_json(&json).unwrap();
+    }
+    provider
+}
+
+fn fixture_provider() -> RuntimeDataProvider {
+    let mut provider = RuntimeDataProvider::new("fixture");
+    for (key, value) in [
+        ("aa", "aa-Latn-ZZ"),
+        ("aa-BB", "aa-Latn-BB"),
+        ("aa-Cyrl", "aa-Cyrl-CC"),
+        ("bb", "bb-Arab-DD"),
+        ("bb-EE", "bb-Latn-EE"),
+        ("und-Arab", "bb-Arab-DD"),
+        ("und-FF", "aa-Latn-FF"),
+    ] {
+        provider.insert_likely_subtags(key.parse().unwrap(), value.parse().unwrap());
+    }
+    provider.insert_script_direction("Latn".parse().unwrap(), CharacterDirection::LTR);
+    provider.insert_script_direction("Arab".parse().unwrap(), CharacterDirection::RTL);
+    provider.insert_language_direction("bb".parse().unwrap(), CharacterDirection::RTL);
+    provider
+}
+
+fn maximize(provider: &dyn DataProvider, input: &str) -> String {
+    let mut langid: LanguageIdentifier = input.parse().unwrap();
+    langid.maximize_with(provider);
+    langid.to_string()
+}
+
+fn minimize(provider: &dyn DataProvider, input: &str) -> String {
+    let mut langid: LanguageIdentifier = input.parse().unwrap();
+    langid.minimize_with(provider);
+    langid.to_string()
+}
+
+fn direction(provider: &dyn DataProvider, input: &str) -> CharacterDirection {
+    let langid: LanguageIdentifier = input.parse().unwrap();
+    langid.character_direction_with(provider)
+}
+
+fn cldr_keys() -> Vec<LanguageIdentifier> {
+    let json = fs::read_to_string("./data/cldr-core/supplemental/likelySubtags.json").unwrap();
+    let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let mut keys: Vec<LanguageIdentifier> = v["supplemental"]["likelySubtags"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(|k| k.parse().unwrap())
+        .collect();
+    keys.extend(
+        [
+            "en-US",
+            "en-Latn-DE",
+            "und-Latn-AM",
+            "und-Cyrl-UK",
+            "zz",
+            "und",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap()),
+    );
+    keys
+}
+
+#[test]
+fn baked_matches_likelysubtags() {
+    for langid in cldr_keys() {
+        let (lang, script, region) = (langid.language, langid.script, langid.region);
+        assert_eq!(
+            provider::maximize(&BakedDataProvider, lang, script, region),
+            likelysubtags::maximize(lang, script, region),
+            "{}",
+            langid
+        );
+        assert_eq!(
+            provider::minimize(&BakedDataProvider, lang, script, region),
+            likelysubtags::minimize(lang, script, region),
+            "{}",
+            langid
+        );
+    }
+}
+
+#[test]
+fn cldr_json_matches_baked() {
+    let provider = cldr_provider();
+    assert_eq!(provider.cldr_version(), BakedDataProvider.cldr_version());
+
+    for langid in cldr_keys() {
+        let mut max = langid.clone();
+        let mut expected = langid.clone();
+        assert_eq!(max.maximize_with(&provider), expected.maximize());
+        assert_eq!(max, expected);
+
+        let mut min = langid.clone();
+        let mut expected = langid.clone();
+        assert_eq!(min.minimize_with(&provider), expected.minimize());
+        assert_eq!(min, expected);
+
+        let mut min = langid.clone();
+        let mut expected = langid.clone();
+        assert_eq!(
+            min.minimize_favor_script_with(&provider),
+            expected.minimize_favor_script()
+        );
+        assert_eq!(min, expected);
+    }
+
+    for entry in fs::read_dir("./data/cldr-misc-full/main/").unwrap() {
+        let name = entry.unwrap().file_name();
+        let langid: LanguageIdentifier = match name.to_str().unwrap().parse() {
+            Ok(langid) => langid,
+            Err(_) => continue,
+        };
+        assert_eq!(
+            langid.character_direction_with(&provider),
+            langid.character_direction(),
+            "{}",
+            langid
+        );
+    }
+}
+
+#[test]
+fn fixture_works() {
+    let provider = fixture_provider();
+    assert_eq!(provider.cldr_version(), "fixture");
+
+    assert_eq!(maximize(&provider, "aa"), "aa-Latn");
+    assert_eq!(maximize(&provider, "aa-BB"), "aa-Latn-BB");
+    assert_eq!(maximize(&provider, "aa-Cyrl"), "aa-Cyrl-CC");
+    assert_eq!(maximize(&provider, "aa-GG"), "aa-Latn-GG");
+    assert_eq!(maximize(&provider, "und-Arab-GG"), "bb-Arab-GG");
+    assert_eq!(maximize(&provider, "und-FF"), "aa-Latn-FF");
+    assert_eq!(maximize(&provider, "cc"), "cc");
+    assert_eq!(maximize(&BakedDataProvider, "cc"), "cc");
+
+    assert_eq!(minimize(&provider, "aa-Latn-BB"), "aa-BB");
+    assert_eq!(minimize(&provider, "aa-Cyrl-CC"), "aa-Cyrl");
+    assert_eq!(minimize(&provider, "bb-Arab-DD"), "bb");
+
+    assert_eq!(direction(&provider, "aa"), CharacterDirection::LTR);
+    assert_eq!(direction(&provider, "bb"), CharacterDirection::RTL);
+    assert_eq!(direction(&provider, "bb-EE"), CharacterDirection::LTR);
+    assert_eq!(direction(&provider, "aa-Arab"), CharacterDirection::RTL);
+    assert_eq!(direction(&provider, "ar"), CharacterDirection::LTR);
+    assert_eq!(direction(&BakedDataProvider, "ar"), CharacterDirection::RTL);
+}
+
+#[test]
+fn bytes_roundtrip() {
+    let provider = fixture_provider();
+    let bytes = provider.to_bytes();
+    assert_eq!(RuntimeDataProvider::from_bytes(&bytes), Ok(provider));
+
+    let provider = cldr_provider();
+    let bytes = provider.to_bytes();
+    assert_eq!(RuntimeDataProvider::from_bytes(&bytes), Ok(provider));
+
+    let provider = RuntimeDataProvider::new("");
+    assert_eq!(provider.to_bytes(), &[1, 0, 0, 0, 0]);
+}
+
+#[test]
+fn bytes_invalid() {
+    let invalid = |offset| {
+        Err(DataError::ParserError(ParserError::InvalidEncoding {
+            offset,
+        }))
+    };
+
+    assert_eq!(RuntimeDataProvider::from_bytes(&[]), invalid(0));
+    // Unknown version.
+    assert_eq!(
+        RuntimeDataProvider::from_bytes(&[2, 0, 0, 0, 0]),
+        invalid(0)
+    );
+    // Truncated CLDR version.
+    assert_eq!(RuntimeDataProvider::from_bytes(&[1, 2, b'4']), invalid(2));
+    // Truncated tables.
+    assert_eq!(RuntimeDataProvider::from_bytes(&[1, 0, 0, 0]), invalid(4));
+    // Trailing bytes.
+    assert_eq!(
+        RuntimeDataProvider::from_bytes(&[1, 0, 0, 0, 0, 0]),
+        invalid(5)
+    );
+    // Unknown direction.
+    assert_eq!(
+        RuntimeDataProvider::from_bytes(&[1, 0, 0, 0, 1, 0x02, b'a', b'r', 0, 3]),
+        invalid(9)
+    );
+    // Language direction keyed by a script.
+    assert_eq!(
+        RuntimeDataProvider::from_bytes(&[1, 0, 0, 0, 1, 0x10, b'A', b'r', b'a', b'b', 0, 1]),
+        invalid(5)
+    );
+    // Unordered entries.
+    assert_eq!(
+        RuntimeDataProvider::from_bytes(&[
+            1, 0, 0, 0, 2, 0x02, b'h', b'e', 0, 1, 0x02, b'a', b'r', 0, 1
+        ]),
+        invalid(10)
+    );
+}
+
+#[test]
+fn json_invalid() {
+    let mut provider = RuntimeDataProvider::default();
+    assert!(matches!(
+        provider.load_likely_subtags_json("{"),
+        Err(DataError::InvalidJson(_))
+    ));
+    assert!(matches!(
+        provider.load_likely_subtags_json("{}"),
+        Err(DataError::InvalidJson(_))
+    ));
+    assert_eq!(
+        provider.load_likely_subtags_json(
+            r#"{"supplemental": {"likelySubtags": {"en": "en-Latn-USA"}}}"#
+        ),
+        Err(DataError::ParserError(ParserError::InvalidSubtag))
+    );
+    assert!(matches!(
+        provider.load_layout_json(
+            r#"{"main": {"en": {"layout": {"orientation": {"characterOrder": "sideways"}}}}}"#
+        ),
+        Err(DataError::InvalidJson(_))
+    ));
+}
@@ -0,0 +1,102 @@
+use unic_langid_impl::subtags::{Language, Region};
+use unic_langid_impl::territory_info::OfficialStatus;
+
+fn region_languages(region: &str) -> Vec<(String, Option<OfficialStatus>)> {
+    let region: Region = region.parse().unwrap();
+    region
+        .languages()
+        .into_iter()
+        .map(|language| (language.langid.to_string(), language.official_status))
+        .collect()
+}
+
+fn language_regions(language: &str) -> Vec<String> {
+    let language: Language = language.parse().unwrap();
+    language
+        .regions()
+        .into_iter()
+        .map(|language| language.langid.to_string())
+        .collect()
+}
+
+#[test]
+fn region_languages_test() {
+    use OfficialStatus::*;
+
+    assert_eq!(
+        region_languages("CH"),
+        vec![
+            ("de-Latn-CH".to_string(), Some(Official)),
+            ("gsw-Latn-CH".to_string(), Some(DeFactoOfficial)),
+            ("en-Latn-CH".to_string(), None),
+            ("fr-Latn-CH".to_string(), Some(Official)),
+            ("it-Latn-CH".to_string(), Some(Official)),
+            ("lmo-Latn-CH".to_string(), None),
+            ("pt-Latn-CH".to_string(), None),
+            ("rm-Latn-CH".to_string(), Some(OfficialRegional)),
+            ("rmo-Latn-CH".to_string(), None),
+            ("wae-Latn-CH".to_string(), None),
+        ]
+    );
+    assert_eq!(
+        region_languages("US")[0],
+        ("en-Latn-US".to_string(), Some(DeFactoOfficial))
+    );
+    assert_eq!(
+        region_languages("BE")[3],
+        ("de-Latn-BE".to_string(), Some(Official))
+    );
+    assert!(region_languages("AQ").is_empty());
+}
+
+#[test]
+fn scripts_test() {
+    // Given in `territoryInfo`.
+    assert_eq!(region_languages("TW")[0].0, "zh-Hant-TW");
+    assert_eq!(region_languages("RS")[0].0, "sr-Cyrl-RS");
+    assert_eq!(region_languages("RS")[1].0, "sr-Latn-RS");
+    // The only primary script in `languageData`.
+    assert_eq!(region_languages("IN")[0].0, "hi-Deva-IN");
+    // The likely script in the territory.
+    assert!(region_languages("RS").contains(&(
+        "ro-Latn-RS".to_string(),
+        Some(OfficialStatus::OfficialRegional)
+    )));
+}
+
+#[test]
+fn language_regions_test() {
+    assert_eq!(
+        language_regions("de")[..5],
+        [
+            "de-Latn-DE",
+            "de-Latn-NL",
+            "de-Latn-AT",
+            "de-Latn-PL",
+            "de-Latn-CH",
+        ]
+    );
+    assert_eq!(language_regions("de").len(), 27);
+    // `zh` in HK is listed both as `zh` and `zh_Hant`, and only counted once.
+    assert_eq!(
+        language_regions("zh")[..3],
+        ["zh-Hans-CN", "zh-Hant-TW", "zh-Hant-HK"]
+    );
+    assert_eq!(language_regions("zh").len(), 20);
+    assert!(language_regions("xyz").is_empty());
+    assert!(language_regions("und").is_empty());
+}
+
+#[test]
+fn population_test() {
+    let region: Region = "LU".parse().unwrap();
+    assert_eq!(region.population(), Some(628381));
+
+    let languages = region.languages();
+    assert_eq!(languages[0].langid, "fr-Latn-LU");
+    assert_eq!(languages[0].population_percent, 87.0);
+    assert_eq!(languages[0].population, 546691);
+
+    let region: Region = "EU".parse().unwrap();
+    assert_eq!(region.population(), None);
+}
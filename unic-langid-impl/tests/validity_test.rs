@@ -0,0 +1,55 @@
+use unic_langid_impl::subtags::{Language, Region, Script, Variant};
+use unic_langid_impl::validity::IdStatus;
+use unic_langid_impl::LanguageIdentifier;
+
+#[test]
+fn id_status_test() {
+    let language = |s: &str| s.parse::<Language>().unwrap().id_status();
+    assert_eq!(language("en"), Some(IdStatus::Regular));
+    assert_eq!(language("mul"), Some(IdStatus::Special));
+    assert_eq!(language("mo"), Some(IdStatus::Deprecated));
+    assert_eq!(language("qtz"), Some(IdStatus::PrivateUse));
+    assert_eq!(language("qaa"), Some(IdStatus::Reserved));
+    assert_eq!(language("und"), Some(IdStatus::Unknown));
+    assert_eq!(language("xzz"), None);
+
+    let script = |s: &str| s.parse::<Script>().unwrap().id_status();
+    assert_eq!(script("Latn"), Some(IdStatus::Regular));
+    assert_eq!(script("Zyyy"), Some(IdStatus::Special));
+    assert_eq!(script("Qabx"), Some(IdStatus::PrivateUse));
+    assert_eq!(script("Qaaa"), Some(IdStatus::Reserved));
+    assert_eq!(script("Zzzz"), Some(IdStatus::Unknown));
+    assert_eq!(script("Abcd"), None);
+
+    let region = |s: &str| s.parse::<Region>().unwrap().id_status();
+    assert_eq!(region("CH"), Some(IdStatus::Regular));
+    assert_eq!(region("419"), Some(IdStatus::Macroregion));
+    assert_eq!(region("EU"), Some(IdStatus::Macroregion));
+    assert_eq!(region("DD"), Some(IdStatus::Deprecated));
+    assert_eq!(region("XX"), Some(IdStatus::PrivateUse));
+    assert_eq!(region("AA"), Some(IdStatus::Reserved));
+    assert_eq!(region("ZZ"), Some(IdStatus::Unknown));
+    assert_eq!(region("999"), None);
+
+    let variant = |s: &str| s.parse::<Variant>().unwrap().id_status();
+    assert_eq!(variant("valencia"), Some(IdStatus::Regular));
+    assert_eq!(variant("heploc"), Some(IdStatus::Deprecated));
+    assert_eq!(variant("abcde"), None);
+}
+
+#[test]
+fn is_valid_test() {
+    for (input, expected) in [
+        ("en", true),
+        ("de-Latn-CH-1901", true),
+        ("und-ZZ", true),
+        ("iw-SU", true),
+        ("en-AA", false),
+        ("xzz", false),
+        ("en-Abcd", false),
+        ("ca-ES-abcde", false),
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(langid.is_valid(), expected, "{}", input);
+    }
+}
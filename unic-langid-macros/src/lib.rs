@@ -1,3 +1,5 @@
+#![no_std]
+
 use proc_macro_hack::proc_macro_hack;
 pub use unic_langid_impl::{subtags, LanguageIdentifier};
 
@@ -99,14 +99,99 @@
 //! assert_eq!(li, "fr");
 //! ```
 //!
+//! `minimize_favor_script` removes subtags like `minimize`, but keeps the script rather than
+//! the region when either would do, turning `zh-Hant-TW` into `zh-Hant` rather than `zh-TW`.
+//!
 //! The feature is optional because it increases the binary size of the library by including
 //! a data table for CLDR likelySubtags.
 //!
+//! ## Display names
+//!
+//! If `feature = "display-names"` is selected, `LanguageIdentifier` gains a `display_name` method
+//! which returns its name in a display locale, like "Schweizer Hochdeutsch" or
+//! "Deutsch (Schweiz)" for `de-CH` in German, using CLDR data generated by `unic-datagen`.
+//! The bundled display locales are German, English and French and their regional variants.
+//! See the `display_names` module for the available options.
+//!
+//! ## Territory info
+//!
+//! If `feature = "territory-info"` is selected, `Region` gains a `languages` method listing
+//! the languages spoken in the territory, the most spoken first, with their official status,
+//! and `Language` gains a `regions` method listing the territories it is spoken in. Both
+//! return `LanguageIdentifier`s with the script filled in, using CLDR `territoryInfo` and
+//! `languageData` data generated by `unic-datagen`.
+//! See the `territory_info` module for details.
+//!
+//! ## ISO 639
+//!
+//! If `feature = "iso639"` is selected, `Language` gains `to_iso639_1`, `to_iso639_2t`,
+//! `to_iso639_2b` and `to_iso639_3` methods, and a `from_iso639` constructor which accepts
+//! any of these codes, like `ger`, `deu` or `de`, and normalizes them to the Unicode canonical
+//! code, using the ISO 639-3 code tables from SIL generated by `unic-datagen`. `Language` also
+//! gains `macrolanguage`, `encompassed_languages` and `is_macrolanguage` methods, and
+//! `MatchOptions::macrolanguages` lets `matches_with_options` match an individual language,
+//! like `cmn`, with its macrolanguage, like `zh`. The bundled macrolanguage table fully covers
+//! Arabic, Persian, Malay, Norwegian, Albanian and Chinese, and relates the other macrolanguages
+//! to the individual languages CLDR aliases to them, like Swahili to `swh`.
+//!
+//! ## IANA registry
+//!
+//! If `feature = "iana-registry"` is selected, subtags gain a `registry_record` method returning
+//! their description, `Added` and `Deprecated` dates and preferred value from the IANA Language
+//! Subtag Registry. `Language` gains `suppress_script`, `Variant` gains `prefixes` and
+//! `has_valid_prefix`, and `LanguageIdentifier` gains `has_valid_variant_prefixes`,
+//! `has_suppressed_script` and `remove_suppressed_script`, which turns `en-Latn-US` into `en-US`.
+//! The bundled registry is a partial excerpt which only covers a few dozen common subtags, so
+//! other subtags have no record. See the `iana_registry` module for details.
+//!
+//! ## Locale info
+//!
+//! If `feature = "locale-info"` is selected, the `locale_info` module returns the calendars,
+//! preferred hour cycle and week of a `LanguageIdentifier`, by its region or likely region,
+//! using CLDR `calendarPreferenceData`, `timeData` and `weekData` generated by `unic-datagen`.
+//!
+//! ## Aliases, parent locales and validity
+//!
+//! Three features bring more CLDR data generated by `unic-datagen`:
+//!
+//!  * `aliases`: `LanguageIdentifier::replace_aliases` replaces deprecated and legacy subtags,
+//!    turning `iw` into `he` or `sh-YU` into `sr-Latn-RS`.
+//!  * `parent-locales`: `LanguageIdentifier::parent` returns the locale to inherit locale data
+//!    from, like `en-001` for `en-AU`.
+//!  * `validity`: subtags gain an `id_status` method, and `LanguageIdentifier` an `is_valid`
+//!    method telling whether all of its subtags are assigned.
+//!
+//! See the `aliases`, `parent_locales` and `validity` modules for details.
+//!
+//! ## Platform conversions
+//!
+//! Conversions to and from platform specific locale names are each behind their own
+//! feature: `android` and `apple` for Android resource qualifiers like `values-b+sr+Latn`
+//! and `.lproj` names, and `gettext` for gettext catalog lookup and `Language` headers.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is enabled by default. Without it the crate is `no_std` and only
+//! requires `alloc`, keeping parsing, serialization, matching, likely subtags and character
+//! direction. The `std` feature only adds `std::error::Error` implementations, and is required
+//! by the `cldr-json` feature.
+//!
+//! ```toml
+//! unic-langid = { version = "0.9", default-features = false }
+//! ```
+//!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Language Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_language_identifier
 //! [`LanguageIdentifier`]: ./struct.LanguageIdentifier.html
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub use unic_langid_impl::*;
 
+#[doc(hidden)]
+pub mod __private {
+    pub extern crate alloc;
+}
+
 #[cfg(feature = "unic-langid-macros")]
 pub use unic_langid_macros::{lang, langid, region, script, variant};
 
@@ -114,7 +199,7 @@ pub use unic_langid_macros::{lang, langid, region, script, variant};
 #[macro_export]
 macro_rules! langids {
     ( $($langid:expr),* ) => {
-        vec![$(
+        $crate::__private::alloc::vec![$(
             $crate::langid!($langid),
         )*]
     };
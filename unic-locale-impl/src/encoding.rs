@@ -0,0 +1,90 @@
+//! Compact binary encoding of `Locale`.
+//!
+//! A `Locale` is encoded as its `LanguageIdentifier`, in the format described
+//! in [`unic_langid_impl::encoding`], followed by its extensions:
+//!
+//! | Bytes | Content                                                         |
+//! |-------|-----------------------------------------------------------------|
+//! | 1     | Version, shared with `LanguageIdentifier`, currently `1`.       |
+//! | 2+    | Language identifier, without the version byte.                  |
+//! | 1+    | Length of the extensions, as a LEB128 varint.                   |
+//! | 0+    | Extensions in their canonical string form, without the leading `-`. |
+//!
+//! A locale without extensions therefore takes a single byte more than its
+//! language identifier. Decoding validates the input and rejects anything
+//! which isn't the canonical encoding.
+//!
+//! Like their string form, the encoding doesn't include other extensions.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::Locale;
+//!
+//! let loc: Locale = "en-US-u-hc-h12".parse().expect("Parsing failed.");
+//!
+//! let bytes = loc.to_bytes();
+//! assert_eq!(bytes.len(), 16);
+//! assert_eq!(Locale::from_bytes_encoded(&bytes), Ok(loc));
+//! ```
+use crate::parser::ParserError;
+use crate::{ExtensionsMap, Locale};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use unic_langid_impl::encoding::{
+    read_language_identifier, read_varint, write_language_identifier, write_varint, VERSION,
+};
+use unic_langid_impl::parser::ParserError as LangIdParserError;
+
+fn from_langid_error(error: LangIdParserError) -> ParserError {
+    match error {
+        LangIdParserError::InvalidEncoding { offset } => ParserError::InvalidEncoding { offset },
+        error => error.into(),
+    }
+}
+
+/// Encodes `loc` into a new buffer, starting with the version byte.
+pub fn encode(loc: &Locale) -> Vec<u8> {
+    let mut result = vec![VERSION];
+    write_language_identifier(&loc.id, &mut result);
+
+    let extensions = loc.extensions.to_string();
+    let extensions = extensions.strip_prefix('-').unwrap_or_default();
+    write_varint(extensions.len(), &mut result);
+    result.extend_from_slice(extensions.as_bytes());
+    result
+}
+
+/// Decodes a locale from `input`, which must hold exactly one encoded
+/// locale starting with the version byte.
+pub fn decode(input: &[u8]) -> Result<Locale, ParserError> {
+    match input.first() {
+        Some(&VERSION) => {}
+        _ => return Err(ParserError::InvalidEncoding { offset: 0 }),
+    }
+    let (id, offset) = read_language_identifier(input, 1).map_err(from_langid_error)?;
+    let (length, offset) = read_varint(input, offset).map_err(from_langid_error)?;
+
+    let bytes = input
+        .get(offset..offset + length)
+        .ok_or(ParserError::InvalidEncoding { offset })?;
+    if offset + length != input.len() {
+        return Err(ParserError::InvalidEncoding {
+            offset: offset + length,
+        });
+    }
+
+    let extensions = if bytes.is_empty() {
+        ExtensionsMap::default()
+    } else {
+        let extensions = ExtensionsMap::from_bytes(bytes)
+            .map_err(|_| ParserError::InvalidEncoding { offset })?;
+        if extensions.to_string().as_bytes().get(1..) != Some(bytes) {
+            return Err(ParserError::InvalidEncoding { offset });
+        }
+        extensions
+    };
+
+    Ok(Locale { id, extensions })
+}
@@ -1,6 +1,5 @@
 use crate::parser::ParserError;
-use std::error::Error;
-use std::fmt::{self, Display};
+use core::fmt::{self, Display};
 use unic_langid_impl::LanguageIdentifierError;
 
 /// Enum with errors that can be returned by Locale.
@@ -26,7 +25,8 @@ impl From<LanguageIdentifierError> for LocaleError {
     }
 }
 
-impl Error for LocaleError {}
+#[cfg(feature = "std")]
+impl std::error::Error for LocaleError {}
 
 impl Display for LocaleError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
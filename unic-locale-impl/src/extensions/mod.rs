@@ -11,18 +11,20 @@ mod private;
 mod transform;
 mod unicode;
 
+use alloc::vec;
+use alloc::vec::Vec;
 pub use private::PrivateExtensionList;
 pub use transform::TransformExtensionList;
+pub(crate) use unicode::parse_key;
 pub use unicode::UnicodeExtensionList;
 
-use std::collections::BTreeMap;
-use std::fmt::Write;
-use std::iter::Peekable;
-use std::str::FromStr;
+use alloc::collections::BTreeMap;
+use core::fmt::Write;
+use core::str::FromStr;
 
 use tinystr::TinyStr8;
 
-use crate::parser::ParserError;
+use crate::parser::{ParserError, SubtagIterator, SubtagKind};
 
 /// Defines the type of extension.
 #[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, PartialOrd, Ord)]
@@ -50,8 +52,8 @@ impl ExtensionType {
     }
 }
 
-impl std::fmt::Display for ExtensionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for ExtensionType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         let ch = match self {
             ExtensionType::Unicode => 'u',
             ExtensionType::Transform => 't',
@@ -73,31 +75,61 @@ pub struct ExtensionsMap {
 
 impl ExtensionsMap {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParserError> {
-        let mut iterator = bytes.split(|c| *c == b'-' || *c == b'_').peekable();
+        let mut iterator = SubtagIterator::new(bytes);
+        // Serialized extensions start with a separator and an empty
+        // map serializes to an empty string.
+        if iterator.peek() == Some(b"") {
+            iterator.next();
+        }
         Self::try_from_iter(&mut iterator)
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
-    ) -> Result<Self, ParserError> {
+    pub(crate) fn try_from_iter(iter: &mut SubtagIterator) -> Result<Self, ParserError> {
         let mut result = ExtensionsMap::default();
+        let mut seen = vec![];
 
+        let mut offset = iter.offset();
         let mut st = iter.next();
         while let Some(subtag) = st {
-            match subtag.first().map(|b| ExtensionType::from_byte(*b)) {
-                Some(Ok(ExtensionType::Unicode)) => {
+            if subtag.len() != 1 {
+                return Err(ParserError::MalformedSubtag {
+                    expected: SubtagKind::ExtensionSingleton,
+                    offset,
+                    length: subtag.len(),
+                });
+            }
+            let singleton =
+                ExtensionType::from_byte(subtag[0]).map_err(|_| ParserError::MalformedSubtag {
+                    expected: SubtagKind::ExtensionSingleton,
+                    offset,
+                    length: 1,
+                })?;
+            if seen.contains(&singleton) {
+                return Err(ParserError::DuplicateSingleton { offset, length: 1 });
+            }
+            seen.push(singleton);
+
+            match singleton {
+                ExtensionType::Unicode => {
                     result.unicode = UnicodeExtensionList::try_from_iter(iter)?;
                 }
-                Some(Ok(ExtensionType::Transform)) => {
+                ExtensionType::Transform => {
                     result.transform = TransformExtensionList::try_from_iter(iter)?;
                 }
-                Some(Ok(ExtensionType::Private)) => {
+                ExtensionType::Private => {
                     result.private = PrivateExtensionList::try_from_iter(iter)?;
                 }
-                None => {}
-                _ => unimplemented!(),
+                ExtensionType::Other(_) => {
+                    // Other extensions are not supported yet.
+                    return Err(ParserError::MalformedSubtag {
+                        expected: SubtagKind::ExtensionSingleton,
+                        offset,
+                        length: 1,
+                    });
+                }
             }
 
+            offset = iter.offset();
             st = iter.next();
         }
 
@@ -117,8 +149,8 @@ impl FromStr for ExtensionsMap {
     }
 }
 
-impl std::fmt::Display for ExtensionsMap {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for ExtensionsMap {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         // Alphabetic by singleton (t, u, x)
         write!(f, "{}{}{}", self.transform, self.unicode, self.private)?;
 
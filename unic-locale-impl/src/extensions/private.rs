@@ -1,5 +1,6 @@
 use crate::errors::LocaleError;
-use crate::parser::ParserError;
+use crate::parser::{ParserError, SubtagIterator, SubtagKind};
+use alloc::vec::Vec;
 
 use tinystr::TinyStr8;
 
@@ -25,7 +26,7 @@ use tinystr::TinyStr8;
 /// [`Unicode Private Extensions`]: https://unicode.org/reports/tr35/#pu_extensions
 /// [`Unicode Locale Identifier`]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
 #[derive(Clone, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
-pub struct PrivateExtensionList(Vec<TinyStr8>);
+pub struct PrivateExtensionList(pub(crate) Vec<TinyStr8>);
 
 fn parse_value(t: &[u8]) -> Result<TinyStr8, ParserError> {
     let s = TinyStr8::try_from_utf8(t).map_err(|_| ParserError::InvalidSubtag)?;
@@ -156,13 +157,19 @@ impl PrivateExtensionList {
         self.0.clear();
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut impl Iterator<Item = &'a [u8]>,
-    ) -> Result<Self, ParserError> {
+    pub(crate) fn try_from_iter(iter: &mut SubtagIterator) -> Result<Self, ParserError> {
         let mut pext = Self::default();
 
-        for subtag in iter {
-            pext.0.push(parse_value(subtag)?);
+        let mut offset = iter.offset();
+        while let Some(subtag) = iter.next() {
+            pext.0.push(
+                parse_value(subtag).map_err(|_| ParserError::MalformedSubtag {
+                    expected: SubtagKind::Private,
+                    offset,
+                    length: subtag.len(),
+                })?,
+            );
+            offset = iter.offset();
         }
         pext.0.sort_unstable();
 
@@ -170,8 +177,8 @@ impl PrivateExtensionList {
     }
 }
 
-impl std::fmt::Display for PrivateExtensionList {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for PrivateExtensionList {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if self.is_empty() {
             return Ok(());
         }
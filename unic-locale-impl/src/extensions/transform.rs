@@ -1,10 +1,11 @@
 use crate::errors::LocaleError;
-use crate::parser::ParserError;
+use crate::parser::{ParserError, SubtagIterator, SubtagKind};
+use alloc::vec;
+use alloc::vec::Vec;
 
-use unic_langid_impl::LanguageIdentifier;
+use unic_langid_impl::{LanguageIdentifier, LanguageIdentifierError};
 
-use std::collections::BTreeMap;
-use std::iter::Peekable;
+use alloc::collections::BTreeMap;
 
 use tinystr::{TinyStr4, TinyStr8};
 
@@ -39,10 +40,10 @@ use tinystr::{TinyStr4, TinyStr8};
 /// [`Unicode Locale Identifier`]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
 #[derive(Clone, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
 pub struct TransformExtensionList {
-    tlang: Option<LanguageIdentifier>,
+    pub(crate) tlang: Option<LanguageIdentifier>,
 
     // Canonical: sort by key (BTreeMap is already) / remove value 'true'
-    tfields: BTreeMap<TinyStr4, Vec<TinyStr8>>,
+    pub(crate) tfields: BTreeMap<TinyStr4, Vec<TinyStr8>>,
 }
 
 fn parse_tkey(key: &[u8]) -> Result<TinyStr4, ParserError> {
@@ -280,9 +281,7 @@ impl TransformExtensionList {
         self.tfields.clear();
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
-    ) -> Result<Self, ParserError> {
+    pub(crate) fn try_from_iter(iter: &mut SubtagIterator) -> Result<Self, ParserError> {
         let mut text = Self::default();
 
         let mut st_peek = iter.peek();
@@ -297,20 +296,45 @@ impl TransformExtensionList {
                     text.tfields.insert(current_tkey, current_tvalue);
                     current_tvalue = vec![];
                 }
-                current_tkey = Some(parse_tkey(subtag)?);
+                current_tkey =
+                    Some(
+                        parse_tkey(subtag).map_err(|_| ParserError::MalformedSubtag {
+                            expected: SubtagKind::TransformKey,
+                            offset: iter.offset(),
+                            length: slen,
+                        })?,
+                    );
                 iter.next();
+            } else if slen == 1 {
+                break;
             } else if current_tkey.is_some() {
-                if let Some(tval) = parse_tvalue(subtag)? {
+                let tval = parse_tvalue(subtag).map_err(|_| ParserError::MalformedSubtag {
+                    expected: SubtagKind::TransformValue,
+                    offset: iter.offset(),
+                    length: slen,
+                })?;
+                if let Some(tval) = tval {
                     current_tvalue.push(tval);
                 }
                 iter.next();
-            } else if is_language_subtag(subtag) {
-                text.tlang = Some(
-                    LanguageIdentifier::try_from_iter(iter, true)
-                        .map_err(|_| ParserError::InvalidLanguage)?,
-                );
+            } else if text.tlang.is_none() && is_language_subtag(subtag) {
+                let offset = iter.offset();
+                text.tlang = Some(LanguageIdentifier::try_from_iter(iter, true).map_err(
+                    |err| match err {
+                        LanguageIdentifierError::ParserError(err) => ParserError::from(err),
+                        _ => ParserError::MalformedSubtag {
+                            expected: SubtagKind::Language,
+                            offset,
+                            length: slen,
+                        },
+                    },
+                )?);
             } else {
-                break;
+                return Err(ParserError::MalformedSubtag {
+                    expected: SubtagKind::TransformKey,
+                    offset: iter.offset(),
+                    length: slen,
+                });
             }
             st_peek = iter.peek();
         }
@@ -323,8 +347,8 @@ impl TransformExtensionList {
     }
 }
 
-impl std::fmt::Display for TransformExtensionList {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for TransformExtensionList {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if self.is_empty() {
             return Ok(());
         }
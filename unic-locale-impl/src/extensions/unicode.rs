@@ -1,9 +1,10 @@
 use crate::errors::LocaleError;
-use crate::parser::ParserError;
+use crate::parser::{ParserError, SubtagIterator, SubtagKind};
+use alloc::vec;
+use alloc::vec::Vec;
 
-use std::collections::BTreeMap;
-use std::iter::Peekable;
-use std::ops::RangeInclusive;
+use alloc::collections::BTreeMap;
+use core::ops::RangeInclusive;
 
 use tinystr::{TinyStr4, TinyStr8};
 
@@ -38,13 +39,13 @@ const ATTR_LENGTH: RangeInclusive<usize> = 3..=8;
 #[derive(Clone, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
 pub struct UnicodeExtensionList {
     // Canonical: sort by key (BTreeMap is already sorted) / remove value 'true'
-    keywords: BTreeMap<TinyStr4, Vec<TinyStr8>>,
+    pub(crate) keywords: BTreeMap<TinyStr4, Vec<TinyStr8>>,
 
     // Canonical: sort / de-dup
-    attributes: Vec<TinyStr8>,
+    pub(crate) attributes: Vec<TinyStr8>,
 }
 
-fn parse_key(key: &[u8]) -> Result<TinyStr4, ParserError> {
+pub(crate) fn parse_key(key: &[u8]) -> Result<TinyStr4, ParserError> {
     if key.len() != KEY_LENGTH || !key[0].is_ascii_alphanumeric() || !key[1].is_ascii_alphabetic() {
         return Err(ParserError::InvalidSubtag);
     }
@@ -336,9 +337,7 @@ impl UnicodeExtensionList {
         self.attributes.clear();
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
-    ) -> Result<Self, ParserError> {
+    pub(crate) fn try_from_iter(iter: &mut SubtagIterator) -> Result<Self, ParserError> {
         let mut uext = Self::default();
 
         let mut st_peek = iter.peek();
@@ -353,18 +352,45 @@ impl UnicodeExtensionList {
                     uext.keywords.insert(current_keyword, current_types);
                     current_types = vec![];
                 }
-                current_keyword = Some(parse_key(subtag)?);
+                current_keyword =
+                    Some(parse_key(subtag).map_err(|_| ParserError::MalformedSubtag {
+                        expected: SubtagKind::UnicodeKey,
+                        offset: iter.offset(),
+                        length: slen,
+                    })?);
                 iter.next();
             } else if current_keyword.is_some() && is_type(subtag) {
-                if let Some(ty) = parse_type(subtag)? {
+                let ty = parse_type(subtag).map_err(|_| ParserError::MalformedSubtag {
+                    expected: SubtagKind::UnicodeType,
+                    offset: iter.offset(),
+                    length: slen,
+                })?;
+                if let Some(ty) = ty {
                     current_types.push(ty);
                 }
                 iter.next();
             } else if is_attribute(subtag) {
-                uext.attributes.push(parse_attribute(subtag)?);
+                let attribute =
+                    parse_attribute(subtag).map_err(|_| ParserError::MalformedSubtag {
+                        expected: SubtagKind::UnicodeAttribute,
+                        offset: iter.offset(),
+                        length: slen,
+                    })?;
+                uext.attributes.push(attribute);
                 iter.next();
-            } else {
+            } else if slen == 1 {
                 break;
+            } else {
+                let expected = if current_keyword.is_some() {
+                    SubtagKind::UnicodeType
+                } else {
+                    SubtagKind::UnicodeKey
+                };
+                return Err(ParserError::MalformedSubtag {
+                    expected,
+                    offset: iter.offset(),
+                    length: slen,
+                });
             }
             st_peek = iter.peek();
         }
@@ -380,8 +406,8 @@ impl UnicodeExtensionList {
     }
 }
 
-impl std::fmt::Display for UnicodeExtensionList {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for UnicodeExtensionList {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if self.is_empty() {
             return Ok(());
         }
@@ -0,0 +1,384 @@
+//! Conversion between `Locale` and ICU legacy locale IDs.
+//!
+//! ICU legacy IDs, as used by ICU4C, ICU4J and older Java data, have the form
+//! `language[_Script][_REGION][_VARIANT...][@key=value;key=value]`,
+//! for example `ja_JP@calendar=japanese` or `de__PHONEBOOK`.
+//!
+//! Keyword names and values are mapped between their long legacy form and
+//! their BCP 47 form based on the aliases of the CLDR `bcp47` data generated
+//! by `unic-datagen`, such as `collation=phonebook` and `-u-co-phonebk`, or
+//! `timezone=America/Los_Angeles` and `-u-tz-uslax`. Keywords and types
+//! without an alias are passed through. Special legacy variants, such as `POSIX` or
+//! `EURO`, are mapped to the unicode extension keywords which replaced them.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::icu::{from_icu, to_icu};
+//!
+//! let (loc, loss) = from_icu("de@collation=phonebook;calendar=gregorian")
+//!     .expect("Conversion failed.");
+//! assert_eq!(loc.to_string(), "de-u-ca-gregory-co-phonebk");
+//! assert!(loss.is_empty());
+//!
+//! assert_eq!(to_icu(&loc), "de@calendar=gregorian;collation=phonebook");
+//! ```
+mod tables;
+
+use self::tables::{KEY_ALIASES, REGION_ALPHA3, TYPE_ALIASES};
+use crate::errors::LocaleError;
+use crate::extensions::ExtensionsMap;
+use crate::Locale;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use unic_langid_impl::{subtags, LanguageIdentifier};
+
+pub use self::tables::CLDR_VERSION;
+
+/// Legacy variants and the keyword which replaced them, optionally
+/// limited to a single language.
+static VARIANT_KEYWORDS: &[(Option<&str>, &str, &str, &str)] = &[
+    (None, "direct", "co", "direct"),
+    (None, "euro", "cu", "eur"),
+    (None, "phonebook", "co", "phonebk"),
+    (None, "pinyin", "co", "pinyin"),
+    (None, "posix", "va", "posix"),
+    (None, "stroke", "co", "stroke"),
+    (Some("es"), "traditional", "co", "trad"),
+    (Some("ja"), "traditional", "ca", "japanese"),
+    (Some("th"), "traditional", "ca", "buddhist"),
+];
+
+/// Regions whose pre-Euro currency is selected by the `PREEURO` variant.
+static PREEURO_CURRENCIES: &[(&str, &str)] = &[
+    ("AT", "ats"),
+    ("BE", "bef"),
+    ("DE", "dem"),
+    ("ES", "esp"),
+    ("FI", "fim"),
+    ("FR", "frf"),
+    ("GR", "grd"),
+    ("IE", "iep"),
+    ("IT", "itl"),
+    ("LU", "luf"),
+    ("NL", "nlg"),
+    ("PT", "pte"),
+];
+
+/// Legacy IDs which name a language through a variant.
+static LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("art__lojban", "jbo"),
+    ("zh_gan", "gan"),
+    ("zh_guoyu", "zh"),
+    ("zh_hakka", "hak"),
+    ("zh_min_nan", "nan"),
+    ("zh_wuu", "wuu"),
+    ("zh_xiang", "hsn"),
+    ("zh_yue", "yue"),
+];
+
+/// A part of an ICU legacy ID which has no equivalent in a `Locale`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IcuLoss {
+    /// A three letter region which isn't an ISO 3166 alpha-3 code,
+    /// such as `XYZ`.
+    Region(String),
+    /// A variant which is neither a special legacy variant
+    /// nor a well-formed variant subtag, such as `TRADITIONAL` for `fr`.
+    Variant(String),
+    /// A keyword with an unknown name or a malformed value,
+    /// such as `timezone=Mars/Olympus_Mons`.
+    Keyword(String),
+}
+
+fn alpha3_region(code: &str) -> Option<&'static str> {
+    REGION_ALPHA3
+        .binary_search_by_key(&code, |(alpha3, _)| alpha3)
+        .ok()
+        .map(|idx| REGION_ALPHA3[idx].1)
+}
+
+/// Converts an ICU legacy locale ID into a `Locale`.
+///
+/// Both `_` and `-` are accepted as subtag separators, and an empty ID
+/// or `root` is mapped to `und`. Like in ICU, ISO 3166 alpha-3 regions,
+/// such as `USA`, are replaced with their alpha-2 code.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::icu::from_icu;
+///
+/// let (loc, _) = from_icu("en_US_POSIX").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "en-US-u-va-posix");
+///
+/// let (loc, _) = from_icu("ja_JP_TRADITIONAL").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "ja-JP-u-ca-japanese");
+///
+/// let (loc, _) = from_icu("sr_Latn_RS@colNumeric=yes").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "sr-Latn-RS-u-kn");
+///
+/// let (loc, _) = from_icu("en_USA").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "en-US");
+/// ```
+pub fn from_icu(id: &str) -> Result<(Locale, Vec<IcuLoss>), LocaleError> {
+    let mut loss = vec![];
+
+    let (base, keywords) = match id.split_once('@') {
+        Some((base, keywords)) => (base, Some(keywords)),
+        None => (id, None),
+    };
+
+    let normalized = base.replace('-', "_").to_ascii_lowercase();
+    let base = LANGUAGE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == normalized)
+        .map_or(base, |(_, language)| language);
+
+    let mut parts = base.split(['_', '-']);
+    let language = match parts.next() {
+        Some(language) if !language.is_empty() && !language.eq_ignore_ascii_case("root") => {
+            language
+        }
+        _ => "und",
+    };
+
+    let mut tag = language.to_string();
+    let mut variants: Vec<subtags::Variant> = vec![];
+    let mut variant_keywords = vec![];
+    let mut region = None;
+
+    // The script and region have fixed positions, an empty part
+    // stands for a missing region.
+    for (idx, part) in parts.enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+        let is_script =
+            idx == 0 && part.len() == 4 && part.bytes().all(|b| b.is_ascii_alphabetic());
+        let is_region = idx <= 1 && region.is_none() && (part.len() == 2 || part.len() == 3);
+        if is_script || is_region {
+            let mut part = part.to_string();
+            if is_region {
+                part.make_ascii_uppercase();
+                if part.len() == 3 && part.bytes().all(|b| b.is_ascii_alphabetic()) {
+                    match alpha3_region(&part) {
+                        Some(alpha2) => part = alpha2.to_string(),
+                        None => {
+                            loss.push(IcuLoss::Region(part.clone()));
+                            region = Some(part);
+                            continue;
+                        }
+                    }
+                }
+                region = Some(part.clone());
+            }
+            tag.push('-');
+            tag.push_str(&part);
+            continue;
+        }
+
+        let variant = part.to_ascii_lowercase();
+        if let Some((_, _, key, value)) = VARIANT_KEYWORDS.iter().find(|(lang, v, ..)| {
+            *v == variant && lang.is_none_or(|l| l.eq_ignore_ascii_case(language))
+        }) {
+            variant_keywords.push((*key, value.to_string()));
+        } else if variant == "preeuro" {
+            match PREEURO_CURRENCIES
+                .iter()
+                .find(|(r, _)| Some(*r) == region.as_deref())
+            {
+                Some((_, currency)) => variant_keywords.push(("cu", currency.to_string())),
+                None => loss.push(IcuLoss::Variant(part.to_string())),
+            }
+        } else if let Ok(variant) = variant.parse::<subtags::Variant>() {
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
+        } else {
+            loss.push(IcuLoss::Variant(part.to_string()));
+        }
+    }
+
+    let mut id = LanguageIdentifier::from_bytes(tag.as_bytes())?;
+    id.set_variants(&variants);
+    let mut locale: Locale = id.into();
+
+    for (key, value) in variant_keywords {
+        locale
+            .extensions
+            .unicode
+            .set_keyword(key, &[value.as_str()])?;
+    }
+
+    if let Some(keywords) = keywords {
+        for keyword in keywords.split(';').filter(|k| !k.trim().is_empty()) {
+            if !apply_keyword(&mut locale, keyword) {
+                loss.push(IcuLoss::Keyword(keyword.trim().to_string()));
+            }
+        }
+    }
+
+    Ok((locale, loss))
+}
+
+fn apply_keyword(locale: &mut Locale, keyword: &str) -> bool {
+    let (key, value) = match keyword.split_once('=') {
+        Some((key, value)) => (key.trim().to_ascii_lowercase(), value.trim()),
+        None => return false,
+    };
+
+    if key == "attribute" {
+        let mut unicode = locale.extensions.unicode.clone();
+        if value
+            .split('-')
+            .all(|attr| unicode.set_attribute(attr).is_ok())
+        {
+            locale.extensions.unicode = unicode;
+            return true;
+        }
+        return false;
+    }
+
+    if key.len() == 1 {
+        let ext = match ExtensionsMap::from_bytes(format!("{}-{}", key, value).as_bytes()) {
+            Ok(ext) => ext,
+            Err(_) => return false,
+        };
+        match key.as_str() {
+            "t" => locale.extensions.transform = ext.transform,
+            "x" => locale.extensions.private = ext.private,
+            _ => return false,
+        }
+        return true;
+    }
+
+    let key = match KEY_ALIASES.iter().find(|(legacy, _)| *legacy == key) {
+        Some((_, key)) => key.to_string(),
+        None => key,
+    };
+
+    let value = value.to_ascii_lowercase();
+    let value = TYPE_ALIASES
+        .iter()
+        .find(|(k, legacy, _)| *k == key && legacy.eq_ignore_ascii_case(&value))
+        .map_or(value.as_str(), |(_, _, t)| t);
+
+    let types = value.split('-').collect::<Vec<_>>();
+    locale
+        .extensions
+        .unicode
+        .set_keyword(key.as_str(), &types)
+        .is_ok()
+}
+
+/// Converts a `Locale` into an ICU legacy locale ID.
+///
+/// Keywords are written with their legacy names and values and sorted
+/// by name. The `-u-va-posix` keyword is written as the `POSIX` variant.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::icu::to_icu;
+///
+/// let loc: Locale = "en-US-u-va-posix".parse().expect("Parsing failed.");
+/// assert_eq!(to_icu(&loc), "en_US_POSIX");
+///
+/// let loc: Locale = "ca-valencia-u-ks-level2".parse().expect("Parsing failed.");
+/// assert_eq!(to_icu(&loc), "ca__VALENCIA@colstrength=secondary");
+/// ```
+pub fn to_icu(locale: &Locale) -> String {
+    let mut extensions = locale.extensions.clone();
+    let mut variants = locale
+        .id
+        .variants()
+        .map(|v| v.as_str().to_ascii_uppercase())
+        .collect::<Vec<_>>();
+
+    let posix = extensions
+        .unicode
+        .keyword("va")
+        .map(|v| v.eq(["posix"]))
+        .unwrap_or(false);
+    if posix {
+        let _ = extensions.unicode.remove_keyword("va");
+        variants.push("POSIX".to_string());
+    }
+
+    let mut result = String::new();
+    if !locale.id.language.is_empty() {
+        result.push_str(locale.id.language.as_str());
+    }
+    if let Some(script) = locale.id.script {
+        result.push('_');
+        result.push_str(script.as_str());
+    }
+    if locale.id.region.is_some() || !variants.is_empty() {
+        result.push('_');
+    }
+    if let Some(region) = locale.id.region {
+        result.push_str(region.as_str());
+    }
+    for variant in variants {
+        result.push('_');
+        result.push_str(&variant);
+    }
+
+    let mut keywords: Vec<(String, String)> = vec![];
+
+    let attributes = extensions.unicode.attributes().collect::<Vec<_>>();
+    if !attributes.is_empty() {
+        keywords.push(("attribute".to_string(), attributes.join("-")));
+    }
+
+    for key in extensions.unicode.keyword_keys() {
+        let types = match extensions.unicode.keyword(key) {
+            Ok(types) => types.collect::<Vec<_>>().join("-"),
+            Err(_) => continue,
+        };
+        let name = KEY_ALIASES
+            .iter()
+            .find(|(_, k)| *k == key)
+            .map_or(key, |(legacy, _)| legacy);
+        let types = if types.is_empty() {
+            "true".to_string()
+        } else {
+            types
+        };
+        // The preferred legacy name of a type is listed first.
+        let value = TYPE_ALIASES
+            .iter()
+            .find(|(k, _, t)| *k == key && *t == types)
+            .map_or(types.as_str(), |(_, legacy, _)| legacy);
+        keywords.push((name.to_string(), value.to_string()));
+    }
+
+    if !extensions.transform.is_empty() {
+        // Skip the leading `-t-`.
+        let value = extensions.transform.to_string()[3..].to_string();
+        keywords.push(("t".to_string(), value));
+    }
+    for (singleton, subtags) in &extensions.other {
+        let value = subtags.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        keywords.push((singleton.to_string(), value.join("-")));
+    }
+    if !extensions.private.is_empty() {
+        // Skip the leading `-x-`.
+        let value = extensions.private.to_string()[3..].to_string();
+        keywords.push(("x".to_string(), value));
+    }
+
+    keywords.sort();
+    for (idx, (key, value)) in keywords.iter().enumerate() {
+        result.push(if idx == 0 { '@' } else { ';' });
+        result.push_str(key);
+        result.push('=');
+        result.push_str(value);
+    }
+
+    result
+}
@@ -0,0 +1,955 @@
+// This file is generated by `unic-datagen`. Do not edit it by hand.
+
+pub static CLDR_VERSION: &str = "43";
+
+#[rustfmt::skip]
+pub static KEY_ALIASES: [(&str, &str); 17] = [
+    ("calendar", "ca"),
+    ("colalternate", "ka"),
+    ("colbackwards", "kb"),
+    ("colcasefirst", "kf"),
+    ("colcaselevel", "kc"),
+    ("colhiraganaquaternary", "kh"),
+    ("collation", "co"),
+    ("colnormalization", "kk"),
+    ("colnumeric", "kn"),
+    ("colreorder", "kr"),
+    ("colstrength", "ks"),
+    ("currency", "cu"),
+    ("hours", "hc"),
+    ("measure", "ms"),
+    ("numbers", "nu"),
+    ("timezone", "tz"),
+    ("variabletop", "vt"),
+];
+
+#[rustfmt::skip]
+pub static TYPE_ALIASES: [(&str, &str, &str); 621] = [
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "gregorian", "gregory"),
+    ("co", "dictionary", "dict"),
+    ("co", "gb2312han", "gb2312"),
+    ("co", "phonebook", "phonebk"),
+    ("co", "traditional", "trad"),
+    ("ka", "non-ignorable", "noignore"),
+    ("kb", "no", "false"),
+    ("kb", "yes", "true"),
+    ("kc", "no", "false"),
+    ("kc", "yes", "true"),
+    ("kf", "no", "false"),
+    ("kh", "no", "false"),
+    ("kh", "yes", "true"),
+    ("kk", "no", "false"),
+    ("kk", "yes", "true"),
+    ("kn", "no", "false"),
+    ("kn", "yes", "true"),
+    ("ks", "identical", "identic"),
+    ("ks", "primary", "level1"),
+    ("ks", "secondary", "level2"),
+    ("ks", "tertiary", "level3"),
+    ("ks", "quaternary", "level4"),
+    ("ks", "quarternary", "level4"),
+    ("ms", "imperial", "uksystem"),
+    ("nu", "traditional", "traditio"),
+    ("tz", "Europe/Andorra", "adalv"),
+    ("tz", "Asia/Dubai", "aedxb"),
+    ("tz", "Asia/Kabul", "afkbl"),
+    ("tz", "America/Antigua", "aganu"),
+    ("tz", "America/Anguilla", "aiaxa"),
+    ("tz", "Europe/Tirane", "altia"),
+    ("tz", "Asia/Yerevan", "amevn"),
+    ("tz", "America/Curacao", "ancur"),
+    ("tz", "Africa/Luanda", "aolad"),
+    ("tz", "Antarctica/Casey", "aqcas"),
+    ("tz", "Antarctica/Davis", "aqdav"),
+    ("tz", "Antarctica/DumontDUrville", "aqddu"),
+    ("tz", "Antarctica/Mawson", "aqmaw"),
+    ("tz", "Antarctica/McMurdo", "aqmcm"),
+    ("tz", "Antarctica/Palmer", "aqplm"),
+    ("tz", "Antarctica/Rothera", "aqrot"),
+    ("tz", "Antarctica/Syowa", "aqsyw"),
+    ("tz", "Antarctica/Troll", "aqtrl"),
+    ("tz", "Antarctica/Vostok", "aqvos"),
+    ("tz", "America/Buenos_Aires", "arbue"),
+    ("tz", "America/Argentina/Buenos_Aires", "arbue"),
+    ("tz", "America/Cordoba", "arcor"),
+    ("tz", "America/Argentina/Cordoba", "arcor"),
+    ("tz", "America/Rosario", "arcor"),
+    ("tz", "America/Catamarca", "arctc"),
+    ("tz", "America/Argentina/Catamarca", "arctc"),
+    ("tz", "America/Argentina/ComodRivadavia", "arctc"),
+    ("tz", "America/Argentina/La_Rioja", "arirj"),
+    ("tz", "America/Jujuy", "arjuj"),
+    ("tz", "America/Argentina/Jujuy", "arjuj"),
+    ("tz", "America/Argentina/San_Luis", "arluq"),
+    ("tz", "America/Mendoza", "armdz"),
+    ("tz", "America/Argentina/Mendoza", "armdz"),
+    ("tz", "America/Argentina/Rio_Gallegos", "arrgl"),
+    ("tz", "America/Argentina/Salta", "arsla"),
+    ("tz", "America/Argentina/Tucuman", "artuc"),
+    ("tz", "America/Argentina/San_Juan", "aruaq"),
+    ("tz", "America/Argentina/Ushuaia", "arush"),
+    ("tz", "Pacific/Pago_Pago", "asppg"),
+    ("tz", "Pacific/Samoa", "asppg"),
+    ("tz", "US/Samoa", "asppg"),
+    ("tz", "Europe/Vienna", "atvie"),
+    ("tz", "Australia/Adelaide", "auadl"),
+    ("tz", "Australia/South", "auadl"),
+    ("tz", "Australia/Broken_Hill", "aubhq"),
+    ("tz", "Australia/Yancowinna", "aubhq"),
+    ("tz", "Australia/Brisbane", "aubne"),
+    ("tz", "Australia/Queensland", "aubne"),
+    ("tz", "Australia/Darwin", "audrw"),
+    ("tz", "Australia/North", "audrw"),
+    ("tz", "Australia/Eucla", "aueuc"),
+    ("tz", "Australia/Hobart", "auhba"),
+    ("tz", "Australia/Tasmania", "auhba"),
+    ("tz", "Australia/Currie", "aukns"),
+    ("tz", "Australia/Lindeman", "auldc"),
+    ("tz", "Australia/Lord_Howe", "auldh"),
+    ("tz", "Australia/LHI", "auldh"),
+    ("tz", "Australia/Melbourne", "aumel"),
+    ("tz", "Australia/Victoria", "aumel"),
+    ("tz", "Antarctica/Macquarie", "aumqi"),
+    ("tz", "Australia/Perth", "auper"),
+    ("tz", "Australia/West", "auper"),
+    ("tz", "Australia/Sydney", "ausyd"),
+    ("tz", "Australia/ACT", "ausyd"),
+    ("tz", "Australia/Canberra", "ausyd"),
+    ("tz", "Australia/NSW", "ausyd"),
+    ("tz", "America/Aruba", "awaua"),
+    ("tz", "Asia/Baku", "azbak"),
+    ("tz", "Europe/Sarajevo", "basjj"),
+    ("tz", "America/Barbados", "bbbgi"),
+    ("tz", "Asia/Dhaka", "bddac"),
+    ("tz", "Asia/Dacca", "bddac"),
+    ("tz", "Europe/Brussels", "bebru"),
+    ("tz", "Africa/Ouagadougou", "bfoua"),
+    ("tz", "Europe/Sofia", "bgsof"),
+    ("tz", "Asia/Bahrain", "bhbah"),
+    ("tz", "Africa/Bujumbura", "bibjm"),
+    ("tz", "Africa/Porto-Novo", "bjptn"),
+    ("tz", "Atlantic/Bermuda", "bmbda"),
+    ("tz", "Asia/Brunei", "bnbwn"),
+    ("tz", "America/La_Paz", "bolpb"),
+    ("tz", "America/Kralendijk", "bqkra"),
+    ("tz", "America/Araguaina", "braux"),
+    ("tz", "America/Belem", "brbel"),
+    ("tz", "America/Boa_Vista", "brbvb"),
+    ("tz", "America/Cuiaba", "brcgb"),
+    ("tz", "America/Campo_Grande", "brcgr"),
+    ("tz", "America/Eirunepe", "brern"),
+    ("tz", "America/Noronha", "brfen"),
+    ("tz", "Brazil/DeNoronha", "brfen"),
+    ("tz", "America/Fortaleza", "brfor"),
+    ("tz", "America/Manaus", "brmao"),
+    ("tz", "Brazil/West", "brmao"),
+    ("tz", "America/Maceio", "brmcz"),
+    ("tz", "America/Porto_Velho", "brpvh"),
+    ("tz", "America/Rio_Branco", "brrbr"),
+    ("tz", "America/Porto_Acre", "brrbr"),
+    ("tz", "Brazil/Acre", "brrbr"),
+    ("tz", "America/Recife", "brrec"),
+    ("tz", "America/Sao_Paulo", "brsao"),
+    ("tz", "Brazil/East", "brsao"),
+    ("tz", "America/Bahia", "brssa"),
+    ("tz", "America/Santarem", "brstm"),
+    ("tz", "America/Nassau", "bsnas"),
+    ("tz", "Asia/Thimphu", "btthi"),
+    ("tz", "Asia/Thimbu", "btthi"),
+    ("tz", "Africa/Gaborone", "bwgbe"),
+    ("tz", "Europe/Minsk", "bymsq"),
+    ("tz", "America/Belize", "bzbze"),
+    ("tz", "America/Creston", "cacfq"),
+    ("tz", "America/Edmonton", "caedm"),
+    ("tz", "Canada/Mountain", "caedm"),
+    ("tz", "America/Rainy_River", "caffs"),
+    ("tz", "America/Fort_Nelson", "cafne"),
+    ("tz", "America/Glace_Bay", "caglb"),
+    ("tz", "America/Goose_Bay", "cagoo"),
+    ("tz", "America/Halifax", "cahal"),
+    ("tz", "Canada/Atlantic", "cahal"),
+    ("tz", "America/Iqaluit", "caiql"),
+    ("tz", "America/Moncton", "camon"),
+    ("tz", "America/Nipigon", "canpg"),
+    ("tz", "America/Pangnirtung", "capnt"),
+    ("tz", "America/Resolute", "careb"),
+    ("tz", "America/Regina", "careg"),
+    ("tz", "Canada/East-Saskatchewan", "careg"),
+    ("tz", "Canada/Saskatchewan", "careg"),
+    ("tz", "America/St_Johns", "casjf"),
+    ("tz", "Canada/Newfoundland", "casjf"),
+    ("tz", "America/Thunder_Bay", "cathu"),
+    ("tz", "America/Toronto", "cator"),
+    ("tz", "America/Montreal", "cator"),
+    ("tz", "Canada/Eastern", "cator"),
+    ("tz", "America/Vancouver", "cavan"),
+    ("tz", "Canada/Pacific", "cavan"),
+    ("tz", "America/Winnipeg", "cawnp"),
+    ("tz", "Canada/Central", "cawnp"),
+    ("tz", "America/Blanc-Sablon", "caybx"),
+    ("tz", "America/Cambridge_Bay", "caycb"),
+    ("tz", "America/Dawson", "cayda"),
+    ("tz", "America/Dawson_Creek", "caydq"),
+    ("tz", "America/Rankin_Inlet", "cayek"),
+    ("tz", "America/Inuvik", "cayev"),
+    ("tz", "America/Whitehorse", "cayxy"),
+    ("tz", "Canada/Yukon", "cayxy"),
+    ("tz", "America/Swift_Current", "cayyn"),
+    ("tz", "America/Yellowknife", "cayzf"),
+    ("tz", "America/Coral_Harbour", "cayzs"),
+    ("tz", "America/Atikokan", "cayzs"),
+    ("tz", "Indian/Cocos", "cccck"),
+    ("tz", "Africa/Lubumbashi", "cdfbm"),
+    ("tz", "Africa/Kinshasa", "cdfih"),
+    ("tz", "Africa/Bangui", "cfbgf"),
+    ("tz", "Africa/Brazzaville", "cgbzv"),
+    ("tz", "Europe/Zurich", "chzrh"),
+    ("tz", "Africa/Abidjan", "ciabj"),
+    ("tz", "Pacific/Rarotonga", "ckrar"),
+    ("tz", "Pacific/Easter", "clipc"),
+    ("tz", "Chile/EasterIsland", "clipc"),
+    ("tz", "America/Punta_Arenas", "clpuq"),
+    ("tz", "America/Santiago", "clscl"),
+    ("tz", "Chile/Continental", "clscl"),
+    ("tz", "Africa/Douala", "cmdla"),
+    ("tz", "Asia/Shanghai", "cnsha"),
+    ("tz", "Asia/Chongqing", "cnsha"),
+    ("tz", "Asia/Chungking", "cnsha"),
+    ("tz", "Asia/Harbin", "cnsha"),
+    ("tz", "PRC", "cnsha"),
+    ("tz", "Asia/Urumqi", "cnurc"),
+    ("tz", "Asia/Kashgar", "cnurc"),
+    ("tz", "America/Bogota", "cobog"),
+    ("tz", "America/Costa_Rica", "crsjo"),
+    ("tz", "CST6CDT", "cst6cdt"),
+    ("tz", "America/Havana", "cuhav"),
+    ("tz", "Cuba", "cuhav"),
+    ("tz", "Atlantic/Cape_Verde", "cvrai"),
+    ("tz", "Indian/Christmas", "cxxch"),
+    ("tz", "Asia/Famagusta", "cyfmg"),
+    ("tz", "Asia/Nicosia", "cynic"),
+    ("tz", "Europe/Nicosia", "cynic"),
+    ("tz", "Europe/Prague", "czprg"),
+    ("tz", "Europe/Berlin", "deber"),
+    ("tz", "Europe/Busingen", "debsngn"),
+    ("tz", "Africa/Djibouti", "djjib"),
+    ("tz", "Europe/Copenhagen", "dkcph"),
+    ("tz", "America/Dominica", "dmdom"),
+    ("tz", "America/Santo_Domingo", "dosdq"),
+    ("tz", "Africa/Algiers", "dzalg"),
+    ("tz", "Pacific/Galapagos", "ecgps"),
+    ("tz", "America/Guayaquil", "ecgye"),
+    ("tz", "Europe/Tallinn", "eetll"),
+    ("tz", "Africa/Cairo", "egcai"),
+    ("tz", "Egypt", "egcai"),
+    ("tz", "Africa/El_Aaiun", "eheai"),
+    ("tz", "Africa/Asmera", "erasm"),
+    ("tz", "Africa/Asmara", "erasm"),
+    ("tz", "Africa/Ceuta", "esceu"),
+    ("tz", "Atlantic/Canary", "eslpa"),
+    ("tz", "Europe/Madrid", "esmad"),
+    ("tz", "EST5EDT", "est5edt"),
+    ("tz", "Africa/Addis_Ababa", "etadd"),
+    ("tz", "Europe/Helsinki", "fihel"),
+    ("tz", "Europe/Mariehamn", "fimhq"),
+    ("tz", "Pacific/Fiji", "fjsuv"),
+    ("tz", "Atlantic/Stanley", "fkpsy"),
+    ("tz", "Pacific/Kosrae", "fmksa"),
+    ("tz", "Pacific/Ponape", "fmpni"),
+    ("tz", "Pacific/Pohnpei", "fmpni"),
+    ("tz", "Pacific/Truk", "fmtkk"),
+    ("tz", "Pacific/Chuuk", "fmtkk"),
+    ("tz", "Pacific/Yap", "fmtkk"),
+    ("tz", "Atlantic/Faeroe", "fotho"),
+    ("tz", "Atlantic/Faroe", "fotho"),
+    ("tz", "Europe/Paris", "frpar"),
+    ("tz", "Africa/Libreville", "galbv"),
+    ("tz", "Asia/Gaza", "gazastrp"),
+    ("tz", "Europe/London", "gblon"),
+    ("tz", "Europe/Belfast", "gblon"),
+    ("tz", "GB", "gblon"),
+    ("tz", "GB-Eire", "gblon"),
+    ("tz", "America/Grenada", "gdgnd"),
+    ("tz", "Asia/Tbilisi", "getbs"),
+    ("tz", "America/Cayenne", "gfcay"),
+    ("tz", "Europe/Guernsey", "gggci"),
+    ("tz", "Africa/Accra", "ghacc"),
+    ("tz", "Europe/Gibraltar", "gigib"),
+    ("tz", "America/Danmarkshavn", "gldkshvn"),
+    ("tz", "America/Godthab", "glgoh"),
+    ("tz", "America/Nuuk", "glgoh"),
+    ("tz", "America/Scoresbysund", "globy"),
+    ("tz", "America/Thule", "glthu"),
+    ("tz", "Africa/Banjul", "gmbjl"),
+    ("tz", "Etc/GMT", "gmt"),
+    ("tz", "Etc/GMT+0", "gmt"),
+    ("tz", "Etc/GMT-0", "gmt"),
+    ("tz", "Etc/GMT0", "gmt"),
+    ("tz", "Etc/Greenwich", "gmt"),
+    ("tz", "GMT", "gmt"),
+    ("tz", "GMT+0", "gmt"),
+    ("tz", "GMT-0", "gmt"),
+    ("tz", "GMT0", "gmt"),
+    ("tz", "Greenwich", "gmt"),
+    ("tz", "Africa/Conakry", "gncky"),
+    ("tz", "America/Guadeloupe", "gpbbr"),
+    ("tz", "America/Marigot", "gpmsb"),
+    ("tz", "America/St_Barthelemy", "gpsbh"),
+    ("tz", "Africa/Malabo", "gqssg"),
+    ("tz", "Europe/Athens", "grath"),
+    ("tz", "Atlantic/South_Georgia", "gsgrv"),
+    ("tz", "America/Guatemala", "gtgua"),
+    ("tz", "Pacific/Guam", "gugum"),
+    ("tz", "Africa/Bissau", "gwoxb"),
+    ("tz", "America/Guyana", "gygeo"),
+    ("tz", "Asia/Hebron", "hebron"),
+    ("tz", "Asia/Hong_Kong", "hkhkg"),
+    ("tz", "Hongkong", "hkhkg"),
+    ("tz", "America/Tegucigalpa", "hntgu"),
+    ("tz", "Europe/Zagreb", "hrzag"),
+    ("tz", "America/Port-au-Prince", "htpap"),
+    ("tz", "Europe/Budapest", "hubud"),
+    ("tz", "Asia/Jayapura", "iddjj"),
+    ("tz", "Asia/Jakarta", "idjkt"),
+    ("tz", "Asia/Makassar", "idmak"),
+    ("tz", "Asia/Ujung_Pandang", "idmak"),
+    ("tz", "Asia/Pontianak", "idpnk"),
+    ("tz", "Europe/Dublin", "iedub"),
+    ("tz", "Eire", "iedub"),
+    ("tz", "Europe/Isle_of_Man", "imdgs"),
+    ("tz", "Asia/Calcutta", "inccu"),
+    ("tz", "Asia/Kolkata", "inccu"),
+    ("tz", "Indian/Chagos", "iodga"),
+    ("tz", "Asia/Baghdad", "iqbgw"),
+    ("tz", "Asia/Tehran", "irthr"),
+    ("tz", "Iran", "irthr"),
+    ("tz", "Atlantic/Reykjavik", "isrey"),
+    ("tz", "Iceland", "isrey"),
+    ("tz", "Europe/Rome", "itrom"),
+    ("tz", "Asia/Jerusalem", "jeruslm"),
+    ("tz", "Asia/Tel_Aviv", "jeruslm"),
+    ("tz", "Israel", "jeruslm"),
+    ("tz", "Europe/Jersey", "jesth"),
+    ("tz", "America/Jamaica", "jmkin"),
+    ("tz", "Jamaica", "jmkin"),
+    ("tz", "Asia/Amman", "joamm"),
+    ("tz", "Asia/Tokyo", "jptyo"),
+    ("tz", "Japan", "jptyo"),
+    ("tz", "Africa/Nairobi", "kenbo"),
+    ("tz", "Asia/Bishkek", "kgfru"),
+    ("tz", "Asia/Phnom_Penh", "khpnh"),
+    ("tz", "Pacific/Kiritimati", "kicxi"),
+    ("tz", "Pacific/Enderbury", "kipho"),
+    ("tz", "Pacific/Kanton", "kipho"),
+    ("tz", "Pacific/Tarawa", "kitrw"),
+    ("tz", "Indian/Comoro", "kmyva"),
+    ("tz", "America/St_Kitts", "knbas"),
+    ("tz", "Asia/Pyongyang", "kpfnj"),
+    ("tz", "Asia/Seoul", "krsel"),
+    ("tz", "ROK", "krsel"),
+    ("tz", "Asia/Kuwait", "kwkwi"),
+    ("tz", "America/Cayman", "kygec"),
+    ("tz", "Asia/Aqtau", "kzaau"),
+    ("tz", "Asia/Aqtobe", "kzakx"),
+    ("tz", "Asia/Almaty", "kzala"),
+    ("tz", "Asia/Atyrau", "kzguw"),
+    ("tz", "Asia/Qostanay", "kzksn"),
+    ("tz", "Asia/Qyzylorda", "kzkzo"),
+    ("tz", "Asia/Oral", "kzura"),
+    ("tz", "Asia/Vientiane", "lavte"),
+    ("tz", "Asia/Beirut", "lbbey"),
+    ("tz", "America/St_Lucia", "lccas"),
+    ("tz", "Europe/Vaduz", "livdz"),
+    ("tz", "Asia/Colombo", "lkcmb"),
+    ("tz", "Africa/Monrovia", "lrmlw"),
+    ("tz", "Africa/Maseru", "lsmsu"),
+    ("tz", "Europe/Vilnius", "ltvno"),
+    ("tz", "Europe/Luxembourg", "lulux"),
+    ("tz", "Europe/Riga", "lvrix"),
+    ("tz", "Africa/Tripoli", "lytip"),
+    ("tz", "Libya", "lytip"),
+    ("tz", "Africa/Casablanca", "macas"),
+    ("tz", "Europe/Monaco", "mcmon"),
+    ("tz", "Europe/Chisinau", "mdkiv"),
+    ("tz", "Europe/Tiraspol", "mdkiv"),
+    ("tz", "Europe/Podgorica", "metgd"),
+    ("tz", "Indian/Antananarivo", "mgtnr"),
+    ("tz", "Pacific/Kwajalein", "mhkwa"),
+    ("tz", "Kwajalein", "mhkwa"),
+    ("tz", "Pacific/Majuro", "mhmaj"),
+    ("tz", "Europe/Skopje", "mkskp"),
+    ("tz", "Africa/Bamako", "mlbko"),
+    ("tz", "Africa/Timbuktu", "mlbko"),
+    ("tz", "Asia/Rangoon", "mmrgn"),
+    ("tz", "Asia/Yangon", "mmrgn"),
+    ("tz", "Asia/Choibalsan", "mncoq"),
+    ("tz", "Asia/Hovd", "mnhvd"),
+    ("tz", "Asia/Ulaanbaatar", "mnuln"),
+    ("tz", "Asia/Ulan_Bator", "mnuln"),
+    ("tz", "Asia/Macau", "momfm"),
+    ("tz", "Asia/Macao", "momfm"),
+    ("tz", "Pacific/Saipan", "mpspn"),
+    ("tz", "America/Martinique", "mqfdf"),
+    ("tz", "Africa/Nouakchott", "mrnkc"),
+    ("tz", "America/Montserrat", "msmni"),
+    ("tz", "MST7MDT", "mst7mdt"),
+    ("tz", "Europe/Malta", "mtmla"),
+    ("tz", "Indian/Mauritius", "muplu"),
+    ("tz", "Indian/Maldives", "mvmle"),
+    ("tz", "Africa/Blantyre", "mwblz"),
+    ("tz", "America/Chihuahua", "mxchi"),
+    ("tz", "America/Ciudad_Juarez", "mxcjs"),
+    ("tz", "America/Cancun", "mxcun"),
+    ("tz", "America/Hermosillo", "mxhmo"),
+    ("tz", "America/Matamoros", "mxmam"),
+    ("tz", "America/Mexico_City", "mxmex"),
+    ("tz", "Mexico/General", "mxmex"),
+    ("tz", "America/Merida", "mxmid"),
+    ("tz", "America/Monterrey", "mxmty"),
+    ("tz", "America/Mazatlan", "mxmzt"),
+    ("tz", "Mexico/BajaSur", "mxmzt"),
+    ("tz", "America/Ojinaga", "mxoji"),
+    ("tz", "America/Bahia_Banderas", "mxpvr"),
+    ("tz", "America/Santa_Isabel", "mxstis"),
+    ("tz", "America/Tijuana", "mxtij"),
+    ("tz", "America/Ensenada", "mxtij"),
+    ("tz", "Mexico/BajaNorte", "mxtij"),
+    ("tz", "Asia/Kuching", "mykch"),
+    ("tz", "Asia/Kuala_Lumpur", "mykul"),
+    ("tz", "Africa/Maputo", "mzmpm"),
+    ("tz", "Africa/Windhoek", "nawdh"),
+    ("tz", "Pacific/Noumea", "ncnou"),
+    ("tz", "Africa/Niamey", "nenim"),
+    ("tz", "Pacific/Norfolk", "nfnlk"),
+    ("tz", "Africa/Lagos", "nglos"),
+    ("tz", "America/Managua", "nimga"),
+    ("tz", "Europe/Amsterdam", "nlams"),
+    ("tz", "Europe/Oslo", "noosl"),
+    ("tz", "Asia/Katmandu", "npktm"),
+    ("tz", "Asia/Kathmandu", "npktm"),
+    ("tz", "Pacific/Nauru", "nrinu"),
+    ("tz", "Pacific/Niue", "nuiue"),
+    ("tz", "Pacific/Auckland", "nzakl"),
+    ("tz", "Antarctica/South_Pole", "nzakl"),
+    ("tz", "NZ", "nzakl"),
+    ("tz", "Pacific/Chatham", "nzcht"),
+    ("tz", "NZ-CHAT", "nzcht"),
+    ("tz", "Asia/Muscat", "ommct"),
+    ("tz", "America/Panama", "papty"),
+    ("tz", "America/Lima", "pelim"),
+    ("tz", "Pacific/Gambier", "pfgmr"),
+    ("tz", "Pacific/Marquesas", "pfnhv"),
+    ("tz", "Pacific/Tahiti", "pfppt"),
+    ("tz", "Pacific/Port_Moresby", "pgpom"),
+    ("tz", "Pacific/Bougainville", "pgraw"),
+    ("tz", "Asia/Manila", "phmnl"),
+    ("tz", "Asia/Karachi", "pkkhi"),
+    ("tz", "Europe/Warsaw", "plwaw"),
+    ("tz", "Poland", "plwaw"),
+    ("tz", "America/Miquelon", "pmmqc"),
+    ("tz", "Pacific/Pitcairn", "pnpcn"),
+    ("tz", "America/Puerto_Rico", "prsju"),
+    ("tz", "PST8PDT", "pst8pdt"),
+    ("tz", "Atlantic/Madeira", "ptfnc"),
+    ("tz", "Europe/Lisbon", "ptlis"),
+    ("tz", "Portugal", "ptlis"),
+    ("tz", "Atlantic/Azores", "ptpdl"),
+    ("tz", "Pacific/Palau", "pwror"),
+    ("tz", "America/Asuncion", "pyasu"),
+    ("tz", "Asia/Qatar", "qadoh"),
+    ("tz", "Indian/Reunion", "rereu"),
+    ("tz", "Europe/Bucharest", "robuh"),
+    ("tz", "Europe/Belgrade", "rsbeg"),
+    ("tz", "Europe/Astrakhan", "ruasf"),
+    ("tz", "Asia/Barnaul", "rubax"),
+    ("tz", "Asia/Chita", "ruchita"),
+    ("tz", "Asia/Anadyr", "rudyr"),
+    ("tz", "Asia/Magadan", "rugdx"),
+    ("tz", "Asia/Irkutsk", "ruikt"),
+    ("tz", "Europe/Kaliningrad", "rukgd"),
+    ("tz", "Asia/Khandyga", "rukhndg"),
+    ("tz", "Asia/Krasnoyarsk", "rukra"),
+    ("tz", "Europe/Samara", "rukuf"),
+    ("tz", "Europe/Kirov", "rukvx"),
+    ("tz", "Europe/Moscow", "rumow"),
+    ("tz", "W-SU", "rumow"),
+    ("tz", "Asia/Novokuznetsk", "runoz"),
+    ("tz", "Asia/Omsk", "ruoms"),
+    ("tz", "Asia/Novosibirsk", "ruovb"),
+    ("tz", "Asia/Kamchatka", "rupkc"),
+    ("tz", "Europe/Saratov", "rurtw"),
+    ("tz", "Asia/Srednekolymsk", "rusred"),
+    ("tz", "Asia/Tomsk", "rutof"),
+    ("tz", "Europe/Ulyanovsk", "ruuly"),
+    ("tz", "Asia/Ust-Nera", "ruunera"),
+    ("tz", "Asia/Sakhalin", "ruuus"),
+    ("tz", "Europe/Volgograd", "ruvog"),
+    ("tz", "Asia/Vladivostok", "ruvvo"),
+    ("tz", "Asia/Yekaterinburg", "ruyek"),
+    ("tz", "Asia/Yakutsk", "ruyks"),
+    ("tz", "Africa/Kigali", "rwkgl"),
+    ("tz", "Asia/Riyadh", "saruh"),
+    ("tz", "Pacific/Guadalcanal", "sbhir"),
+    ("tz", "Indian/Mahe", "scmaw"),
+    ("tz", "Africa/Khartoum", "sdkrt"),
+    ("tz", "Europe/Stockholm", "sesto"),
+    ("tz", "Asia/Singapore", "sgsin"),
+    ("tz", "Singapore", "sgsin"),
+    ("tz", "Atlantic/St_Helena", "shshn"),
+    ("tz", "Europe/Ljubljana", "silju"),
+    ("tz", "Arctic/Longyearbyen", "sjlyr"),
+    ("tz", "Atlantic/Jan_Mayen", "sjlyr"),
+    ("tz", "Europe/Bratislava", "skbts"),
+    ("tz", "Africa/Freetown", "slfna"),
+    ("tz", "Europe/San_Marino", "smsai"),
+    ("tz", "Africa/Dakar", "sndkr"),
+    ("tz", "Africa/Mogadishu", "somgq"),
+    ("tz", "America/Paramaribo", "srpbm"),
+    ("tz", "Africa/Juba", "ssjub"),
+    ("tz", "Africa/Sao_Tome", "sttms"),
+    ("tz", "America/El_Salvador", "svsal"),
+    ("tz", "America/Lower_Princes", "sxphi"),
+    ("tz", "Asia/Damascus", "sydam"),
+    ("tz", "Africa/Mbabane", "szqmn"),
+    ("tz", "America/Grand_Turk", "tcgdt"),
+    ("tz", "Africa/Ndjamena", "tdndj"),
+    ("tz", "Indian/Kerguelen", "tfpfr"),
+    ("tz", "Africa/Lome", "tglfw"),
+    ("tz", "Asia/Bangkok", "thbkk"),
+    ("tz", "Asia/Dushanbe", "tjdyu"),
+    ("tz", "Pacific/Fakaofo", "tkfko"),
+    ("tz", "Asia/Dili", "tldil"),
+    ("tz", "Asia/Ashgabat", "tmasb"),
+    ("tz", "Asia/Ashkhabad", "tmasb"),
+    ("tz", "Africa/Tunis", "tntun"),
+    ("tz", "Pacific/Tongatapu", "totbu"),
+    ("tz", "Europe/Istanbul", "trist"),
+    ("tz", "Asia/Istanbul", "trist"),
+    ("tz", "Turkey", "trist"),
+    ("tz", "America/Port_of_Spain", "ttpos"),
+    ("tz", "Pacific/Funafuti", "tvfun"),
+    ("tz", "Asia/Taipei", "twtpe"),
+    ("tz", "ROC", "twtpe"),
+    ("tz", "Africa/Dar_es_Salaam", "tzdar"),
+    ("tz", "Europe/Kiev", "uaiev"),
+    ("tz", "Europe/Kyiv", "uaiev"),
+    ("tz", "Europe/Zaporozhye", "uaozh"),
+    ("tz", "Europe/Simferopol", "uasip"),
+    ("tz", "Europe/Uzhgorod", "uauzh"),
+    ("tz", "Africa/Kampala", "ugkla"),
+    ("tz", "Pacific/Wake", "umawk"),
+    ("tz", "Pacific/Johnston", "umjon"),
+    ("tz", "Pacific/Midway", "ummdy"),
+    ("tz", "Etc/Unknown", "unk"),
+    ("tz", "America/Adak", "usadk"),
+    ("tz", "America/Atka", "usadk"),
+    ("tz", "US/Aleutian", "usadk"),
+    ("tz", "America/Indiana/Marengo", "usaeg"),
+    ("tz", "America/Anchorage", "usanc"),
+    ("tz", "US/Alaska", "usanc"),
+    ("tz", "America/Boise", "usboi"),
+    ("tz", "America/Chicago", "uschi"),
+    ("tz", "US/Central", "uschi"),
+    ("tz", "America/Denver", "usden"),
+    ("tz", "America/Shiprock", "usden"),
+    ("tz", "Navajo", "usden"),
+    ("tz", "US/Mountain", "usden"),
+    ("tz", "America/Detroit", "usdet"),
+    ("tz", "US/Michigan", "usdet"),
+    ("tz", "Pacific/Honolulu", "ushnl"),
+    ("tz", "US/Hawaii", "ushnl"),
+    ("tz", "America/Indianapolis", "usind"),
+    ("tz", "America/Fort_Wayne", "usind"),
+    ("tz", "America/Indiana/Indianapolis", "usind"),
+    ("tz", "US/East-Indiana", "usind"),
+    ("tz", "America/Indiana/Vevay", "usinvev"),
+    ("tz", "America/Juneau", "usjnu"),
+    ("tz", "America/Indiana/Knox", "usknx"),
+    ("tz", "America/Knox_IN", "usknx"),
+    ("tz", "US/Indiana-Starke", "usknx"),
+    ("tz", "America/Los_Angeles", "uslax"),
+    ("tz", "US/Pacific", "uslax"),
+    ("tz", "US/Pacific-New", "uslax"),
+    ("tz", "America/Louisville", "uslui"),
+    ("tz", "America/Kentucky/Louisville", "uslui"),
+    ("tz", "America/Menominee", "usmnm"),
+    ("tz", "America/Kentucky/Monticello", "usmoc"),
+    ("tz", "America/Metlakatla", "usmtm"),
+    ("tz", "America/North_Dakota/Center", "usndcnt"),
+    ("tz", "America/North_Dakota/New_Salem", "usndnsl"),
+    ("tz", "America/New_York", "usnyc"),
+    ("tz", "US/Eastern", "usnyc"),
+    ("tz", "America/Indiana/Vincennes", "usoea"),
+    ("tz", "America/Nome", "usome"),
+    ("tz", "America/Phoenix", "usphx"),
+    ("tz", "US/Arizona", "usphx"),
+    ("tz", "America/Sitka", "ussit"),
+    ("tz", "America/Indiana/Tell_City", "ustel"),
+    ("tz", "America/Indiana/Winamac", "uswlz"),
+    ("tz", "America/Indiana/Petersburg", "uswsq"),
+    ("tz", "America/North_Dakota/Beulah", "usxul"),
+    ("tz", "America/Yakutat", "usyak"),
+    ("tz", "Etc/UTC", "utc"),
+    ("tz", "Etc/UCT", "utc"),
+    ("tz", "Etc/Universal", "utc"),
+    ("tz", "Etc/Zulu", "utc"),
+    ("tz", "UCT", "utc"),
+    ("tz", "UTC", "utc"),
+    ("tz", "Universal", "utc"),
+    ("tz", "Zulu", "utc"),
+    ("tz", "Etc/GMT-1", "utce01"),
+    ("tz", "Etc/GMT-2", "utce02"),
+    ("tz", "Etc/GMT-3", "utce03"),
+    ("tz", "Etc/GMT-4", "utce04"),
+    ("tz", "Etc/GMT-5", "utce05"),
+    ("tz", "Etc/GMT-6", "utce06"),
+    ("tz", "Etc/GMT-7", "utce07"),
+    ("tz", "Etc/GMT-8", "utce08"),
+    ("tz", "Etc/GMT-9", "utce09"),
+    ("tz", "Etc/GMT-10", "utce10"),
+    ("tz", "Etc/GMT-11", "utce11"),
+    ("tz", "Etc/GMT-12", "utce12"),
+    ("tz", "Etc/GMT-13", "utce13"),
+    ("tz", "Etc/GMT-14", "utce14"),
+    ("tz", "Etc/GMT+1", "utcw01"),
+    ("tz", "Etc/GMT+2", "utcw02"),
+    ("tz", "Etc/GMT+3", "utcw03"),
+    ("tz", "Etc/GMT+4", "utcw04"),
+    ("tz", "Etc/GMT+5", "utcw05"),
+    ("tz", "EST", "utcw05"),
+    ("tz", "Etc/GMT+6", "utcw06"),
+    ("tz", "Etc/GMT+7", "utcw07"),
+    ("tz", "MST", "utcw07"),
+    ("tz", "Etc/GMT+8", "utcw08"),
+    ("tz", "Etc/GMT+9", "utcw09"),
+    ("tz", "Etc/GMT+10", "utcw10"),
+    ("tz", "HST", "utcw10"),
+    ("tz", "Etc/GMT+11", "utcw11"),
+    ("tz", "Etc/GMT+12", "utcw12"),
+    ("tz", "America/Montevideo", "uymvd"),
+    ("tz", "Asia/Samarkand", "uzskd"),
+    ("tz", "Asia/Tashkent", "uztas"),
+    ("tz", "Europe/Vatican", "vavat"),
+    ("tz", "America/St_Vincent", "vcsvd"),
+    ("tz", "America/Caracas", "veccs"),
+    ("tz", "America/Tortola", "vgtov"),
+    ("tz", "America/St_Thomas", "vistt"),
+    ("tz", "America/Virgin", "vistt"),
+    ("tz", "Asia/Saigon", "vnsgn"),
+    ("tz", "Asia/Ho_Chi_Minh", "vnsgn"),
+    ("tz", "Pacific/Efate", "vuvli"),
+    ("tz", "Pacific/Wallis", "wfmau"),
+    ("tz", "Pacific/Apia", "wsapw"),
+    ("tz", "Asia/Aden", "yeade"),
+    ("tz", "Indian/Mayotte", "ytmam"),
+    ("tz", "Africa/Johannesburg", "zajnb"),
+    ("tz", "Africa/Lusaka", "zmlun"),
+    ("tz", "Africa/Harare", "zwhre"),
+];
+
+#[rustfmt::skip]
+pub static REGION_ALPHA3: [(&str, &str); 302] = [
+    ("AAA", "AA"),
+    ("ABW", "AW"),
+    ("AFG", "AF"),
+    ("AGO", "AO"),
+    ("AIA", "AI"),
+    ("ALA", "AX"),
+    ("ALB", "AL"),
+    ("AND", "AD"),
+    ("ANT", "AN"),
+    ("ARE", "AE"),
+    ("ARG", "AR"),
+    ("ARM", "AM"),
+    ("ASM", "AS"),
+    ("ATA", "AQ"),
+    ("ATF", "TF"),
+    ("ATG", "AG"),
+    ("AUS", "AU"),
+    ("AUT", "AT"),
+    ("AZE", "AZ"),
+    ("BDI", "BI"),
+    ("BEL", "BE"),
+    ("BEN", "BJ"),
+    ("BES", "BQ"),
+    ("BFA", "BF"),
+    ("BGD", "BD"),
+    ("BGR", "BG"),
+    ("BHR", "BH"),
+    ("BHS", "BS"),
+    ("BIH", "BA"),
+    ("BLM", "BL"),
+    ("BLR", "BY"),
+    ("BLZ", "BZ"),
+    ("BMU", "BM"),
+    ("BOL", "BO"),
+    ("BRA", "BR"),
+    ("BRB", "BB"),
+    ("BRN", "BN"),
+    ("BTN", "BT"),
+    ("BUR", "BU"),
+    ("BVT", "BV"),
+    ("BWA", "BW"),
+    ("CAF", "CF"),
+    ("CAN", "CA"),
+    ("CCK", "CC"),
+    ("CHE", "CH"),
+    ("CHL", "CL"),
+    ("CHN", "CN"),
+    ("CIV", "CI"),
+    ("CMR", "CM"),
+    ("COD", "CD"),
+    ("COG", "CG"),
+    ("COK", "CK"),
+    ("COL", "CO"),
+    ("COM", "KM"),
+    ("CPV", "CV"),
+    ("CRI", "CR"),
+    ("CUB", "CU"),
+    ("CUW", "CW"),
+    ("CXR", "CX"),
+    ("CYM", "KY"),
+    ("CYP", "CY"),
+    ("CZE", "CZ"),
+    ("DDR", "DD"),
+    ("DEU", "DE"),
+    ("DJI", "DJ"),
+    ("DMA", "DM"),
+    ("DNK", "DK"),
+    ("DOM", "DO"),
+    ("DZA", "DZ"),
+    ("ECU", "EC"),
+    ("EGY", "EG"),
+    ("ERI", "ER"),
+    ("ESH", "EH"),
+    ("ESP", "ES"),
+    ("EST", "EE"),
+    ("ETH", "ET"),
+    ("FIN", "FI"),
+    ("FJI", "FJ"),
+    ("FLK", "FK"),
+    ("FRA", "FR"),
+    ("FRO", "FO"),
+    ("FSM", "FM"),
+    ("FXX", "FX"),
+    ("GAB", "GA"),
+    ("GBR", "GB"),
+    ("GEO", "GE"),
+    ("GGY", "GG"),
+    ("GHA", "GH"),
+    ("GIB", "GI"),
+    ("GIN", "GN"),
+    ("GLP", "GP"),
+    ("GMB", "GM"),
+    ("GNB", "GW"),
+    ("GNQ", "GQ"),
+    ("GRC", "GR"),
+    ("GRD", "GD"),
+    ("GRL", "GL"),
+    ("GTM", "GT"),
+    ("GUF", "GF"),
+    ("GUM", "GU"),
+    ("GUY", "GY"),
+    ("HKG", "HK"),
+    ("HMD", "HM"),
+    ("HND", "HN"),
+    ("HRV", "HR"),
+    ("HTI", "HT"),
+    ("HUN", "HU"),
+    ("IDN", "ID"),
+    ("IMN", "IM"),
+    ("IND", "IN"),
+    ("IOT", "IO"),
+    ("IRL", "IE"),
+    ("IRN", "IR"),
+    ("IRQ", "IQ"),
+    ("ISL", "IS"),
+    ("ISR", "IL"),
+    ("ITA", "IT"),
+    ("JAM", "JM"),
+    ("JEY", "JE"),
+    ("JOR", "JO"),
+    ("JPN", "JP"),
+    ("KAZ", "KZ"),
+    ("KEN", "KE"),
+    ("KGZ", "KG"),
+    ("KHM", "KH"),
+    ("KIR", "KI"),
+    ("KNA", "KN"),
+    ("KOR", "KR"),
+    ("KWT", "KW"),
+    ("LAO", "LA"),
+    ("LBN", "LB"),
+    ("LBR", "LR"),
+    ("LBY", "LY"),
+    ("LCA", "LC"),
+    ("LIE", "LI"),
+    ("LKA", "LK"),
+    ("LSO", "LS"),
+    ("LTU", "LT"),
+    ("LUX", "LU"),
+    ("LVA", "LV"),
+    ("MAC", "MO"),
+    ("MAF", "MF"),
+    ("MAR", "MA"),
+    ("MCO", "MC"),
+    ("MDA", "MD"),
+    ("MDG", "MG"),
+    ("MDV", "MV"),
+    ("MEX", "MX"),
+    ("MHL", "MH"),
+    ("MKD", "MK"),
+    ("MLI", "ML"),
+    ("MLT", "MT"),
+    ("MMR", "MM"),
+    ("MNE", "ME"),
+    ("MNG", "MN"),
+    ("MNP", "MP"),
+    ("MOZ", "MZ"),
+    ("MRT", "MR"),
+    ("MSR", "MS"),
+    ("MTQ", "MQ"),
+    ("MUS", "MU"),
+    ("MWI", "MW"),
+    ("MYS", "MY"),
+    ("MYT", "YT"),
+    ("NAM", "NA"),
+    ("NCL", "NC"),
+    ("NER", "NE"),
+    ("NFK", "NF"),
+    ("NGA", "NG"),
+    ("NIC", "NI"),
+    ("NIU", "NU"),
+    ("NLD", "NL"),
+    ("NOR", "NO"),
+    ("NPL", "NP"),
+    ("NRU", "NR"),
+    ("NTZ", "NT"),
+    ("NZL", "NZ"),
+    ("OMN", "OM"),
+    ("PAK", "PK"),
+    ("PAN", "PA"),
+    ("PCN", "PN"),
+    ("PER", "PE"),
+    ("PHL", "PH"),
+    ("PLW", "PW"),
+    ("PNG", "PG"),
+    ("POL", "PL"),
+    ("PRI", "PR"),
+    ("PRK", "KP"),
+    ("PRT", "PT"),
+    ("PRY", "PY"),
+    ("PSE", "PS"),
+    ("PYF", "PF"),
+    ("QAT", "QA"),
+    ("QMM", "QM"),
+    ("QNN", "QN"),
+    ("QOO", "QO"),
+    ("QPP", "QP"),
+    ("QQQ", "QQ"),
+    ("QRR", "QR"),
+    ("QSS", "QS"),
+    ("QTT", "QT"),
+    ("QUU", "EU"),
+    ("QVV", "QV"),
+    ("QWW", "QW"),
+    ("QXX", "QX"),
+    ("QYY", "QY"),
+    ("QZZ", "QZ"),
+    ("REU", "RE"),
+    ("ROU", "RO"),
+    ("RUS", "RU"),
+    ("RWA", "RW"),
+    ("SAU", "SA"),
+    ("SCG", "CS"),
+    ("SDN", "SD"),
+    ("SEN", "SN"),
+    ("SGP", "SG"),
+    ("SGS", "GS"),
+    ("SHN", "SH"),
+    ("SJM", "SJ"),
+    ("SLB", "SB"),
+    ("SLE", "SL"),
+    ("SLV", "SV"),
+    ("SMR", "SM"),
+    ("SOM", "SO"),
+    ("SPM", "PM"),
+    ("SRB", "RS"),
+    ("SSD", "SS"),
+    ("STP", "ST"),
+    ("SUN", "SU"),
+    ("SUR", "SR"),
+    ("SVK", "SK"),
+    ("SVN", "SI"),
+    ("SWE", "SE"),
+    ("SWZ", "SZ"),
+    ("SXM", "SX"),
+    ("SYC", "SC"),
+    ("SYR", "SY"),
+    ("TCA", "TC"),
+    ("TCD", "TD"),
+    ("TGO", "TG"),
+    ("THA", "TH"),
+    ("TJK", "TJ"),
+    ("TKL", "TK"),
+    ("TKM", "TM"),
+    ("TLS", "TL"),
+    ("TMP", "TP"),
+    ("TON", "TO"),
+    ("TTO", "TT"),
+    ("TUN", "TN"),
+    ("TUR", "TR"),
+    ("TUV", "TV"),
+    ("TWN", "TW"),
+    ("TZA", "TZ"),
+    ("UGA", "UG"),
+    ("UKR", "UA"),
+    ("UMI", "UM"),
+    ("URY", "UY"),
+    ("USA", "US"),
+    ("UZB", "UZ"),
+    ("VAT", "VA"),
+    ("VCT", "VC"),
+    ("VEN", "VE"),
+    ("VGB", "VG"),
+    ("VIR", "VI"),
+    ("VNM", "VN"),
+    ("VUT", "VU"),
+    ("WLF", "WF"),
+    ("WSM", "WS"),
+    ("XAA", "XA"),
+    ("XBB", "XB"),
+    ("XCC", "XC"),
+    ("XDD", "XD"),
+    ("XEE", "XE"),
+    ("XFF", "XF"),
+    ("XGG", "XG"),
+    ("XHH", "XH"),
+    ("XII", "XI"),
+    ("XJJ", "XJ"),
+    ("XKK", "XK"),
+    ("XLL", "XL"),
+    ("XMM", "XM"),
+    ("XNN", "XN"),
+    ("XOO", "XO"),
+    ("XPP", "XP"),
+    ("XQQ", "XQ"),
+    ("XRR", "XR"),
+    ("XSS", "XS"),
+    ("XTT", "XT"),
+    ("XUU", "XU"),
+    ("XVV", "XV"),
+    ("XWW", "XW"),
+    ("XXX", "XX"),
+    ("XYY", "XY"),
+    ("XZZ", "XZ"),
+    ("YEM", "YE"),
+    ("YMD", "YD"),
+    ("YUG", "YU"),
+    ("ZAF", "ZA"),
+    ("ZAR", "ZR"),
+    ("ZMB", "ZM"),
+    ("ZWE", "ZW"),
+    ("ZZZ", "ZZ"),
+];
@@ -0,0 +1,239 @@
+//! Conversions between `Locale` and the ICU4X `icu_locale_core::Locale`.
+//!
+//! The language identifier, unicode extension keywords and attributes, and
+//! private use subtags are copied in their raw `tinystr` form. ICU4X doesn't
+//! expose the subtags of transform extension fields and other extensions,
+//! so those are converted through their string form.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::Locale;
+//!
+//! let loc: Locale = "de-DE-u-ca-buddhist-x-foo".parse().expect("Parsing failed.");
+//!
+//! let icu_loc = icu_locale_core::Locale::try_from(&loc).expect("Conversion failed.");
+//! assert_eq!(icu_loc.to_string(), "de-DE-u-ca-buddhist-x-foo");
+//!
+//! assert_eq!(Locale::try_from(&icu_loc), Ok(loc));
+//! ```
+use crate::errors::LocaleError;
+use crate::extensions::{
+    ExtensionsMap, PrivateExtensionList, TransformExtensionList, UnicodeExtensionList,
+};
+use crate::parser::ParserError;
+use crate::Locale;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::vec::Vec;
+use icu_locale_core as icu;
+use icu_locale_core::extensions::{other, private, transform, unicode};
+use icu_locale_core::subtags::Subtag;
+use tinystr::{TinyStr4, TinyStr8};
+use unic_langid_impl::LanguageIdentifier;
+
+fn key_raw(key: TinyStr4) -> [u8; 2] {
+    // Extension keys are always two characters long.
+    key.resize::<2>().all_bytes().to_owned()
+}
+
+fn subtags(input: &[TinyStr8]) -> Vec<Subtag> {
+    input
+        .iter()
+        .map(|s| Subtag::from_tinystr_unvalidated(*s))
+        .collect()
+}
+
+impl TryFrom<&UnicodeExtensionList> for unicode::Unicode {
+    type Error = LocaleError;
+
+    fn try_from(input: &UnicodeExtensionList) -> Result<Self, Self::Error> {
+        let keywords = input
+            .keywords
+            .iter()
+            .map(|(key, value)| {
+                let key = unicode::Key::try_from_raw(key_raw(*key))?;
+                Ok((key, unicode::Value::from_vec_unchecked(subtags(value))))
+            })
+            .collect::<Result<_, icu::ParseError>>()
+            .map_err(|_| ParserError::InvalidExtension)?;
+        let attributes = input
+            .attributes
+            .iter()
+            .map(|a| unicode::Attribute::try_from_raw(*a.all_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParserError::InvalidExtension)?;
+
+        let mut result = unicode::Unicode::new();
+        result.keywords = keywords;
+        result.attributes = unicode::Attributes::from_vec_unchecked(attributes);
+        Ok(result)
+    }
+}
+
+impl From<&unicode::Unicode> for UnicodeExtensionList {
+    fn from(input: &unicode::Unicode) -> Self {
+        let keywords = input
+            .keywords
+            .iter()
+            .map(|(key, value)| {
+                let value = value
+                    .as_subtags_slice()
+                    .iter()
+                    .map(|s| s.as_tinystr())
+                    .collect();
+                (key.to_tinystr().resize(), value)
+            })
+            .collect();
+        let attributes = input.attributes.iter().map(|a| a.to_tinystr()).collect();
+        Self {
+            keywords,
+            attributes,
+        }
+    }
+}
+
+impl TryFrom<&TransformExtensionList> for transform::Transform {
+    type Error = LocaleError;
+
+    fn try_from(input: &TransformExtensionList) -> Result<Self, Self::Error> {
+        let lang = input
+            .tlang
+            .as_ref()
+            .map(icu::LanguageIdentifier::try_from)
+            .transpose()?;
+        let fields = input
+            .tfields
+            .iter()
+            .map(|(key, value)| {
+                let key = transform::Key::try_from_raw(key_raw(*key))?;
+                let value = if value.is_empty() {
+                    transform::Value::try_from_str("true")?
+                } else {
+                    let value = value.iter().map(|v| v.as_str()).collect::<Vec<_>>();
+                    transform::Value::try_from_str(&value.join("-"))?
+                };
+                Ok((key, value))
+            })
+            .collect::<Result<_, icu::ParseError>>()
+            .map_err(|_| ParserError::InvalidExtension)?;
+
+        let mut result = transform::Transform::new();
+        result.lang = lang;
+        result.fields = fields;
+        Ok(result)
+    }
+}
+
+impl TryFrom<&transform::Transform> for TransformExtensionList {
+    type Error = LocaleError;
+
+    fn try_from(input: &transform::Transform) -> Result<Self, Self::Error> {
+        let mut result = if input.fields.is_empty() {
+            Self::default()
+        } else {
+            ExtensionsMap::from_bytes(format!("t-{}", input.fields).as_bytes())?.transform
+        };
+        result.tlang = input.lang.as_ref().map(LanguageIdentifier::from);
+        Ok(result)
+    }
+}
+
+impl TryFrom<&PrivateExtensionList> for private::Private {
+    type Error = LocaleError;
+
+    fn try_from(input: &PrivateExtensionList) -> Result<Self, Self::Error> {
+        let subtags = input
+            .0
+            .iter()
+            .map(|s| private::Subtag::try_from_raw(*s.all_bytes()))
+            .collect::<Result<_, _>>()
+            .map_err(|_| ParserError::InvalidExtension)?;
+        Ok(private::Private::from_vec_unchecked(subtags))
+    }
+}
+
+impl From<&private::Private> for PrivateExtensionList {
+    fn from(input: &private::Private) -> Self {
+        Self(input.iter().map(|s| s.to_tinystr()).collect())
+    }
+}
+
+impl TryFrom<&ExtensionsMap> for icu::extensions::Extensions {
+    type Error = LocaleError;
+
+    fn try_from(input: &ExtensionsMap) -> Result<Self, Self::Error> {
+        let mut result = icu::extensions::Extensions::new();
+        result.unicode = (&input.unicode).try_into()?;
+        result.transform = (&input.transform).try_into()?;
+        result.private = (&input.private).try_into()?;
+        result.other = input
+            .other
+            .iter()
+            .map(|(ext, value)| other::Other::from_vec_unchecked(*ext as u8, subtags(value)))
+            .collect();
+        Ok(result)
+    }
+}
+
+impl TryFrom<&icu::extensions::Extensions> for ExtensionsMap {
+    type Error = LocaleError;
+
+    fn try_from(input: &icu::extensions::Extensions) -> Result<Self, Self::Error> {
+        let mut result = ExtensionsMap {
+            unicode: (&input.unicode).into(),
+            transform: (&input.transform).try_into()?,
+            private: (&input.private).into(),
+            ..Default::default()
+        };
+        for other in &input.other {
+            // The string form of an other extension starts with its singleton.
+            let subtags = other
+                .to_string()
+                .split('-')
+                .skip(1)
+                .map(|s| TinyStr8::try_from_str(s).map_err(|_| ParserError::InvalidSubtag))
+                .collect::<Result<_, _>>()?;
+            result.other.insert(other.get_ext(), subtags);
+        }
+        Ok(result)
+    }
+}
+
+impl TryFrom<&Locale> for icu::Locale {
+    type Error = LocaleError;
+
+    fn try_from(input: &Locale) -> Result<Self, Self::Error> {
+        Ok(icu::Locale {
+            id: (&input.id).try_into()?,
+            extensions: (&input.extensions).try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Locale> for icu::Locale {
+    type Error = LocaleError;
+
+    fn try_from(input: Locale) -> Result<Self, Self::Error> {
+        Self::try_from(&input)
+    }
+}
+
+impl TryFrom<&icu::Locale> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(input: &icu::Locale) -> Result<Self, Self::Error> {
+        Ok(Locale {
+            id: (&input.id).into(),
+            extensions: (&input.extensions).try_into()?,
+        })
+    }
+}
+
+impl TryFrom<icu::Locale> for Locale {
+    type Error = LocaleError;
+
+    fn try_from(input: icu::Locale) -> Result<Self, Self::Error> {
+        Self::try_from(&input)
+    }
+}
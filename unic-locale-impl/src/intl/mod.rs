@@ -0,0 +1,350 @@
+//! The ECMA-402 `Intl.Locale` API.
+//!
+//! [`Locale::with_options`] builds a `Locale` from a tag and
+//! [`LocaleOptions`] the way the `Intl.Locale` constructor does, and the
+//! getters of `Intl.Locale`, like `calendar` or `hourCycle`, are methods of
+//! `Locale` with their snake case names. Getters of unicode extension
+//! keywords return `None` when the keyword is not set.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::intl::LocaleOptions;
+//! use unic_locale_impl::Locale;
+//!
+//! let options = LocaleOptions {
+//!     region: Some("GB"),
+//!     hour_cycle: Some("h12"),
+//!     numeric: Some(true),
+//!     ..Default::default()
+//! };
+//! let loc = Locale::with_options("en-US-u-ca-buddhist", options)
+//!     .expect("Invalid options.");
+//!
+//! assert_eq!(loc.to_string(), "en-GB-u-ca-buddhist-hc-h12-kn");
+//! assert_eq!(*loc.base_name(), "en-GB");
+//! assert_eq!(loc.calendar().as_deref(), Some("buddhist"));
+//! assert_eq!(loc.hour_cycle().as_deref(), Some("h12"));
+//! assert_eq!(loc.collation(), None);
+//! assert!(loc.numeric());
+//! ```
+//!
+//! The ECMA-402 operations resolving the locale of `Intl` objects are the
+//! functions [`canonicalize_locale_list`], [`best_available_locale`],
+//! [`lookup_matcher`], [`best_fit_matcher`] and [`resolve_locale`].
+mod resolve;
+
+use crate::errors::LocaleError;
+use crate::extensions::UnicodeExtensionList;
+use crate::parser::ParserError;
+use crate::{CharacterDirection, LanguageIdentifier, Locale};
+use alloc::string::String;
+#[cfg(feature = "locale-info")]
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "locale-info")]
+use unic_langid_impl::locale_info::{self, WeekInfo};
+use unic_langid_impl::{subtags, LanguageIdentifierError};
+
+pub use resolve::{
+    best_available_locale, best_fit_matcher, canonicalize_locale_list, lookup_matcher,
+    resolve_locale, LocaleMatcher, MatcherResult, ResolveOptions, ResolvedKeyword, ResolvedLocale,
+};
+
+/// Overrides applied by [`Locale::with_options`], named after the options
+/// of the `Intl.Locale` constructor.
+///
+/// Options left as `None` keep the subtag or keyword of the tag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LocaleOptions<'a> {
+    /// The language subtag, like `en`.
+    pub language: Option<&'a str>,
+    /// The script subtag, like `Latn`.
+    pub script: Option<&'a str>,
+    /// The region subtag, like `US`.
+    pub region: Option<&'a str>,
+    /// The `ca` keyword, like `buddhist`.
+    pub calendar: Option<&'a str>,
+    /// The `co` keyword, like `phonebk`.
+    pub collation: Option<&'a str>,
+    /// The `hc` keyword, one of `h11`, `h12`, `h23` and `h24`.
+    pub hour_cycle: Option<&'a str>,
+    /// The `kf` keyword, one of `upper`, `lower` and `false`.
+    pub case_first: Option<&'a str>,
+    /// The `nu` keyword, like `arab`.
+    pub numbering_system: Option<&'a str>,
+    /// The `kn` keyword.
+    pub numeric: Option<bool>,
+}
+
+/// The direction of text in a line, as the `direction` of `getTextInfo`
+/// names it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    /// Left to right, like `ltr`.
+    Ltr,
+    /// Right to left, like `rtl`.
+    Rtl,
+}
+
+impl TextDirection {
+    /// Returns the `direction` of the text direction, like `ltr`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+        }
+    }
+}
+
+/// Information about the text of a locale, as returned by
+/// [`Locale::get_text_info`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextInfo {
+    /// The direction of characters in a line.
+    pub direction: TextDirection,
+}
+
+/// Checks that `value` is a sequence of `-` separated keyword types, which
+/// the `Intl.Locale` constructor requires of its keyword options.
+fn keyword_types(value: &str) -> Result<Vec<&str>, ParserError> {
+    value
+        .split('-')
+        .map(|t| {
+            if (3..=8).contains(&t.len()) && t.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                Ok(t)
+            } else {
+                Err(ParserError::InvalidSubtag)
+            }
+        })
+        .collect()
+}
+
+/// Returns the value of the keyword `key` of `unicode`, its types joined
+/// with `-`, or `None` if it is not set.
+fn keyword_value(unicode: &UnicodeExtensionList, key: &str) -> Option<String> {
+    let key = tinystr::TinyStr4::try_from_str(key).ok()?;
+    unicode.keywords.get(&key).map(|types| {
+        types
+            .iter()
+            .map(|t| t.as_str())
+            .collect::<Vec<_>>()
+            .join("-")
+    })
+}
+
+fn one_of<'a>(value: &'a str, allowed: &[&str]) -> Result<&'a str, ParserError> {
+    if allowed.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ParserError::InvalidSubtag)
+    }
+}
+
+impl Locale {
+    /// Parses `tag` and applies the overrides of `options`, like the
+    /// `Intl.Locale` constructor.
+    ///
+    /// Subtag options replace the subtags of `tag`, and keyword options
+    /// replace its unicode extension keywords. Invalid options, like an
+    /// `hour_cycle` other than `h11`, `h12`, `h23` and `h24`, are errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::intl::LocaleOptions;
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let options = LocaleOptions {
+    ///     script: Some("Cyrl"),
+    ///     numbering_system: Some("latn"),
+    ///     ..Default::default()
+    /// };
+    /// let loc = Locale::with_options("sr-RS", options).expect("Invalid options.");
+    /// assert_eq!(loc.to_string(), "sr-Cyrl-RS-u-nu-latn");
+    ///
+    /// let options = LocaleOptions {
+    ///     hour_cycle: Some("h13"),
+    ///     ..Default::default()
+    /// };
+    /// assert!(Locale::with_options("en", options).is_err());
+    /// ```
+    pub fn with_options(tag: &str, options: LocaleOptions) -> Result<Self, LocaleError> {
+        let mut loc: Self = tag.parse()?;
+
+        if let Some(language) = options.language {
+            loc.id.language = subtags::Language::from_bytes(language.as_bytes())
+                .map_err(LanguageIdentifierError::from)?;
+        }
+        if let Some(script) = options.script {
+            loc.id.script = Some(
+                subtags::Script::from_bytes(script.as_bytes())
+                    .map_err(LanguageIdentifierError::from)?,
+            );
+        }
+        if let Some(region) = options.region {
+            loc.id.region = Some(
+                subtags::Region::from_bytes(region.as_bytes())
+                    .map_err(LanguageIdentifierError::from)?,
+            );
+        }
+
+        let keywords = [
+            ("ca", options.calendar.map(keyword_types).transpose()?),
+            ("co", options.collation.map(keyword_types).transpose()?),
+            (
+                "hc",
+                options
+                    .hour_cycle
+                    .map(|hc| one_of(hc, &["h11", "h12", "h23", "h24"]).map(|hc| vec![hc]))
+                    .transpose()?,
+            ),
+            (
+                "kf",
+                options
+                    .case_first
+                    .map(|kf| one_of(kf, &["upper", "lower", "false"]).map(|kf| vec![kf]))
+                    .transpose()?,
+            ),
+            (
+                "kn",
+                options
+                    .numeric
+                    .map(|kn| vec![if kn { "true" } else { "false" }]),
+            ),
+            (
+                "nu",
+                options.numbering_system.map(keyword_types).transpose()?,
+            ),
+        ];
+        for (key, value) in keywords {
+            if let Some(value) = value {
+                loc.extensions.unicode.set_keyword(key, &value)?;
+            }
+        }
+        Ok(loc)
+    }
+
+    /// Returns the language identifier of the `Locale`, without its
+    /// extensions, like `baseName` of `Intl.Locale`.
+    pub fn base_name(&self) -> &LanguageIdentifier {
+        &self.id
+    }
+
+    /// Returns the calendar of the `Locale`, its `ca` keyword.
+    pub fn calendar(&self) -> Option<String> {
+        keyword_value(&self.extensions.unicode, "ca")
+    }
+
+    /// Returns the case first setting of the `Locale`, its `kf` keyword.
+    pub fn case_first(&self) -> Option<String> {
+        keyword_value(&self.extensions.unicode, "kf")
+    }
+
+    /// Returns the collation of the `Locale`, its `co` keyword.
+    pub fn collation(&self) -> Option<String> {
+        keyword_value(&self.extensions.unicode, "co")
+    }
+
+    /// Returns the hour cycle of the `Locale`, its `hc` keyword.
+    pub fn hour_cycle(&self) -> Option<String> {
+        keyword_value(&self.extensions.unicode, "hc")
+    }
+
+    /// Returns the numbering system of the `Locale`, its `nu` keyword.
+    pub fn numbering_system(&self) -> Option<String> {
+        keyword_value(&self.extensions.unicode, "nu")
+    }
+
+    /// Returns whether the `Locale` has numeric collation, which is
+    /// whether its `kn` keyword is set to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "de-u-kn".parse().expect("Parsing failed.");
+    /// assert!(loc.numeric());
+    ///
+    /// let loc: Locale = "de-u-kn-false".parse().expect("Parsing failed.");
+    /// assert!(!loc.numeric());
+    /// ```
+    pub fn numeric(&self) -> bool {
+        keyword_value(&self.extensions.unicode, "kn").as_deref() == Some("")
+    }
+
+    /// Returns the calendars of the `Locale`, the preferred one first: its
+    /// `ca` keyword if set, and the calendars of its region otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "ja-JP".parse().expect("Parsing failed.");
+    /// assert_eq!(loc.get_calendars(), &["gregory", "japanese"]);
+    ///
+    /// let loc: Locale = "ja-JP-u-ca-japanese".parse().expect("Parsing failed.");
+    /// assert_eq!(loc.get_calendars(), &["japanese"]);
+    /// ```
+    #[cfg(feature = "locale-info")]
+    pub fn get_calendars(&self) -> Vec<String> {
+        match self.calendar() {
+            Some(calendar) => vec![calendar],
+            None => locale_info::calendars(&self.id)
+                .iter()
+                .map(|calendar| calendar.to_string())
+                .collect(),
+        }
+    }
+
+    /// Returns the hour cycles of the `Locale`: its `hc` keyword if set,
+    /// and the preferred hour cycle of its language and region otherwise.
+    #[cfg(feature = "locale-info")]
+    pub fn get_hour_cycles(&self) -> Vec<String> {
+        match self.hour_cycle() {
+            Some(hour_cycle) => vec![hour_cycle],
+            None => vec![locale_info::hour_cycle(&self.id).as_str().to_string()],
+        }
+    }
+
+    /// Returns the week of the `Locale`, its first day being its `fw`
+    /// keyword if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US".parse().expect("Parsing failed.");
+    /// assert_eq!(loc.get_week_info().first_day, 7);
+    ///
+    /// let loc: Locale = "en-US-u-fw-mon".parse().expect("Parsing failed.");
+    /// assert_eq!(loc.get_week_info().first_day, 1);
+    /// ```
+    #[cfg(feature = "locale-info")]
+    pub fn get_week_info(&self) -> WeekInfo {
+        let mut week = locale_info::week_info(&self.id);
+        let days = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+        if let Some(idx) = keyword_value(&self.extensions.unicode, "fw")
+            .and_then(|fw| days.iter().position(|day| *day == fw))
+        {
+            week.first_day = idx as u8 + 1;
+        }
+        week
+    }
+
+    /// Returns information about the text of the `Locale`.
+    ///
+    /// Like `getTextInfo`, the direction is `rtl` for right to left scripts
+    /// and `ltr` for all others, including top to bottom ones.
+    pub fn get_text_info(&self) -> TextInfo {
+        let direction = match self.id.character_direction() {
+            CharacterDirection::RTL => TextDirection::Rtl,
+            CharacterDirection::LTR | CharacterDirection::TTB => TextDirection::Ltr,
+        };
+        TextInfo { direction }
+    }
+}
@@ -0,0 +1,374 @@
+use super::keyword_value;
+use crate::errors::LocaleError;
+use crate::extensions::UnicodeExtensionList;
+use crate::parser::ParserError;
+use crate::{LanguageIdentifier, Locale};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "aliases")]
+use tinystr::TinyStr8;
+#[cfg(feature = "aliases")]
+use unic_langid_impl::aliases;
+
+/// The result of [`lookup_matcher`] and [`best_fit_matcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatcherResult {
+    /// The matching available locale, or the default locale.
+    pub locale: LanguageIdentifier,
+    /// The unicode extension of the requested locale which matched, if it
+    /// had one.
+    pub extension: Option<UnicodeExtensionList>,
+}
+
+/// The matching algorithm of [`resolve_locale`], like the `localeMatcher`
+/// option of `Intl` constructors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LocaleMatcher {
+    /// [`lookup_matcher`].
+    Lookup,
+    /// [`best_fit_matcher`].
+    #[default]
+    BestFit,
+}
+
+/// Options of [`resolve_locale`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ResolveOptions<'a> {
+    /// The matching algorithm.
+    pub locale_matcher: LocaleMatcher,
+    /// Keyword values set by options rather than by the requested locale,
+    /// like `("hc", "h23")` for the `hourCycle` option of
+    /// `Intl.DateTimeFormat`.
+    pub keywords: &'a [(&'a str, &'a str)],
+}
+
+/// A relevant extension key resolved by [`resolve_locale`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResolvedKeyword {
+    /// The key, like `nu`.
+    pub key: String,
+    /// The resolved value, or `None` if the locale data has no values for
+    /// the key.
+    pub value: Option<String>,
+    /// Whether the value is the one of the requested locale, and is kept
+    /// in the unicode extension of the resolved locale.
+    pub honoured: bool,
+}
+
+/// The result of [`resolve_locale`], like the `locale` and extension
+/// options returned by `resolvedOptions` of `Intl` objects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedLocale {
+    /// The resolved locale, with the honoured keywords.
+    pub locale: Locale,
+    /// The available locale whose data is used.
+    pub data_locale: LanguageIdentifier,
+    /// The relevant extension keys, in order.
+    pub keywords: Vec<ResolvedKeyword>,
+}
+
+/// Parses and canonicalizes a list of locales, removing duplicates, like
+/// the ECMA-402 `CanonicalizeLocaleList` operation.
+///
+/// Canonicalization normalizes the casing and order of subtags, as parsing
+/// does. With `feature = "aliases"`, it also replaces deprecated subtags
+/// and unicode extension types with the CLDR alias data, like `iw` with
+/// `he` and `-u-ca-islamicc` with `-u-ca-islamic-civil`. An invalid
+/// locale, including one with `_` separators, is an error.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::intl::canonicalize_locale_list;
+///
+/// let locales = canonicalize_locale_list(&["EN-us", "de-u-nu-latn-ca-gregory", "en-US"])
+///     .expect("Invalid locale.");
+/// assert_eq!(locales.len(), 2);
+/// assert_eq!(locales[0].to_string(), "en-US");
+/// assert_eq!(locales[1].to_string(), "de-u-ca-gregory-nu-latn");
+///
+/// assert!(canonicalize_locale_list(&["en", "e"]).is_err());
+/// assert!(canonicalize_locale_list(&["en_US"]).is_err());
+/// ```
+pub fn canonicalize_locale_list<S: AsRef<str>>(locales: &[S]) -> Result<Vec<Locale>, LocaleError> {
+    let mut result: Vec<Locale> = Vec::with_capacity(locales.len());
+    for locale in locales {
+        let locale = locale.as_ref();
+        // The parser accepts `_` separators, which language tags don't.
+        if locale.contains('_') {
+            return Err(ParserError::InvalidSubtag.into());
+        }
+        let locale: Locale = locale.parse()?;
+        #[cfg(feature = "aliases")]
+        let locale = replace_aliases(locale);
+        if !result.contains(&locale) {
+            result.push(locale);
+        }
+    }
+    Ok(result)
+}
+
+/// Replaces the deprecated subtags and unicode extension types of `locale`.
+#[cfg(feature = "aliases")]
+fn replace_aliases(mut locale: Locale) -> Locale {
+    locale.id.replace_aliases();
+    for (key, types) in locale.extensions.unicode.keywords.iter_mut() {
+        let value: Vec<&str> = types.iter().map(|t| t.as_str()).collect();
+        if let Some(preferred) = aliases::keyword_type_alias(key.as_str(), &value.join("-")) {
+            // safe because all table entries are well formed.
+            *types = preferred
+                .split('-')
+                .map(|t| TinyStr8::try_from_str(t).unwrap())
+                .collect();
+        }
+    }
+    locale
+}
+
+/// Returns the available locale which is the longest prefix of `locale`,
+/// removing subtags from the end, like the ECMA-402 `BestAvailableLocale`
+/// operation.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::intl::best_available_locale;
+/// use unic_locale_impl::LanguageIdentifier;
+///
+/// let available: Vec<LanguageIdentifier> = vec!["de".parse().unwrap(), "en-US".parse().unwrap()];
+///
+/// assert_eq!(best_available_locale(&available, "de-CH-1996"), Some(&available[0]));
+/// assert_eq!(best_available_locale(&available, "en-GB"), None);
+/// ```
+pub fn best_available_locale<'a>(
+    available: &'a [LanguageIdentifier],
+    locale: &str,
+) -> Option<&'a LanguageIdentifier> {
+    let mut candidate = locale;
+    loop {
+        if let Some(found) = available.iter().find(|langid| **langid == candidate) {
+            return Some(found);
+        }
+        let mut pos = candidate.rfind('-')?;
+        // Remove singletons, like `x` in `de-x-foo`, with the subtag after.
+        if pos >= 2 && candidate.as_bytes()[pos - 2] == b'-' {
+            pos -= 2;
+        }
+        candidate = &candidate[..pos];
+    }
+}
+
+fn matcher_result(locale: &LanguageIdentifier, requested: &Locale) -> MatcherResult {
+    let unicode = &requested.extensions.unicode;
+    MatcherResult {
+        locale: locale.clone(),
+        extension: (!unicode.is_empty()).then(|| unicode.clone()),
+    }
+}
+
+/// Returns the first requested locale with an available locale from
+/// [`best_available_locale`], ignoring its unicode extension, like the
+/// ECMA-402 `LookupMatcher` operation.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::intl::{canonicalize_locale_list, lookup_matcher};
+/// use unic_locale_impl::LanguageIdentifier;
+///
+/// let available: Vec<LanguageIdentifier> = vec!["de".parse().unwrap(), "fr".parse().unwrap()];
+/// let requested = canonicalize_locale_list(&["it", "fr-CA-u-nu-latn"]).expect("Invalid locale.");
+///
+/// let result = lookup_matcher(&available, &requested, &"en".parse().unwrap());
+/// assert_eq!(result.locale, "fr");
+/// assert_eq!(result.extension.map(|e| e.to_string()), Some("-u-nu-latn".to_string()));
+/// ```
+pub fn lookup_matcher(
+    available: &[LanguageIdentifier],
+    requested: &[Locale],
+    default_locale: &LanguageIdentifier,
+) -> MatcherResult {
+    for locale in requested {
+        let mut no_extensions_locale = locale.clone();
+        no_extensions_locale.extensions.unicode = UnicodeExtensionList::default();
+        if let Some(found) = best_available_locale(available, &no_extensions_locale.to_string()) {
+            return matcher_result(found, locale);
+        }
+    }
+    MatcherResult {
+        locale: default_locale.clone(),
+        extension: None,
+    }
+}
+
+fn maximized(langid: &LanguageIdentifier) -> LanguageIdentifier {
+    #[allow(unused_mut)]
+    let mut langid = langid.clone();
+    #[cfg(feature = "likelysubtags")]
+    langid.maximize();
+    langid
+}
+
+/// Returns the first requested locale with a close available locale, like
+/// an ECMA-402 best fit matcher.
+///
+/// An available locale equal to the requested one is preferred, then one
+/// with the same likely language, script and region, then one with the
+/// same likely language and script, and then the first available locale
+/// found by [`best_available_locale`]. Without the `likelysubtags`
+/// feature, likely subtags are the subtags of the locales.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::intl::{best_fit_matcher, canonicalize_locale_list};
+/// use unic_locale_impl::LanguageIdentifier;
+///
+/// let available: Vec<LanguageIdentifier> = vec!["zh".parse().unwrap(), "zh-Hant".parse().unwrap()];
+/// let requested = canonicalize_locale_list(&["zh-TW"]).expect("Invalid locale.");
+///
+/// let result = best_fit_matcher(&available, &requested, &"en".parse().unwrap());
+/// # #[cfg(feature = "likelysubtags")]
+/// assert_eq!(result.locale, "zh-Hant");
+/// ```
+pub fn best_fit_matcher(
+    available: &[LanguageIdentifier],
+    requested: &[Locale],
+    default_locale: &LanguageIdentifier,
+) -> MatcherResult {
+    let available_maximized: Vec<LanguageIdentifier> = available.iter().map(maximized).collect();
+    for locale in requested {
+        if let Some(found) = available.iter().find(|langid| **langid == locale.id) {
+            return matcher_result(found, locale);
+        }
+        let max = maximized(&locale.id);
+        let best = available_maximized
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.language == max.language && other.script == max.script)
+            .max_by_key(|(idx, other)| (other.region == max.region, core::cmp::Reverse(*idx)));
+        if let Some((idx, _)) = best {
+            return matcher_result(&available[idx], locale);
+        }
+        let mut no_extensions_locale = locale.clone();
+        no_extensions_locale.extensions.unicode = UnicodeExtensionList::default();
+        if let Some(found) = best_available_locale(available, &no_extensions_locale.to_string()) {
+            return matcher_result(found, locale);
+        }
+    }
+    MatcherResult {
+        locale: default_locale.clone(),
+        extension: None,
+    }
+}
+
+/// Resolves the locale of an `Intl` object, like the ECMA-402
+/// `ResolveLocale` operation.
+///
+/// The requested locales, from [`canonicalize_locale_list`], are matched
+/// with the available ones by the matcher of `options`. Then for each of
+/// `relevant_extension_keys`, `locale_data` returns the values the
+/// matched locale supports, the default one first, and the value of the
+/// keyword in the requested locale is honoured if it is supported. A
+/// supported value of the keyword in `options` replaces it, but is not
+/// added to the resolved locale.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::intl::{canonicalize_locale_list, resolve_locale, ResolveOptions};
+/// use unic_locale_impl::LanguageIdentifier;
+///
+/// let available: Vec<LanguageIdentifier> = vec!["ar".parse().unwrap(), "en".parse().unwrap()];
+/// let requested = canonicalize_locale_list(&["ar-EG-u-nu-latn-ca-islamic"])
+///     .expect("Invalid locale.");
+/// let locale_data = |_: &LanguageIdentifier, key: &str| -> &'static [&'static str] {
+///     match key {
+///         "nu" => &["arab", "latn"],
+///         _ => &[],
+///     }
+/// };
+///
+/// let resolved = resolve_locale(
+///     &available,
+///     &requested,
+///     &"en".parse().unwrap(),
+///     ResolveOptions::default(),
+///     &["nu"],
+///     locale_data,
+/// );
+/// assert_eq!(resolved.locale.to_string(), "ar-u-nu-latn");
+/// assert_eq!(resolved.data_locale, "ar");
+/// assert_eq!(resolved.keywords[0].value.as_deref(), Some("latn"));
+/// assert!(resolved.keywords[0].honoured);
+/// ```
+pub fn resolve_locale<'a, F>(
+    available: &[LanguageIdentifier],
+    requested: &[Locale],
+    default_locale: &LanguageIdentifier,
+    options: ResolveOptions,
+    relevant_extension_keys: &[&str],
+    locale_data: F,
+) -> ResolvedLocale
+where
+    F: Fn(&LanguageIdentifier, &str) -> &'a [&'a str],
+{
+    let matched = match options.locale_matcher {
+        LocaleMatcher::Lookup => lookup_matcher(available, requested, default_locale),
+        LocaleMatcher::BestFit => best_fit_matcher(available, requested, default_locale),
+    };
+    let mut locale = Locale::from(matched.locale.clone());
+    let mut keywords = Vec::with_capacity(relevant_extension_keys.len());
+
+    for key in relevant_extension_keys {
+        let key_data = locale_data(&matched.locale, key);
+        let mut value = key_data.first().map(|value| value.to_string());
+        let mut honoured = false;
+
+        let requested = matched
+            .extension
+            .as_ref()
+            .and_then(|extension| keyword_value(extension, key));
+        if let Some(requested) = requested {
+            if !requested.is_empty() {
+                if key_data.contains(&requested.as_str()) {
+                    value = Some(requested);
+                    honoured = true;
+                }
+            } else if key_data.contains(&"true") {
+                value = Some("true".to_string());
+                honoured = true;
+            }
+        }
+
+        if let Some((_, option)) = options.keywords.iter().find(|(k, _)| k == key) {
+            let mut option = option.to_ascii_lowercase();
+            if option.is_empty() {
+                option = "true".to_string();
+            }
+            if value.as_deref() != Some(option.as_str()) && key_data.contains(&option.as_str()) {
+                value = Some(option);
+                honoured = false;
+            }
+        }
+
+        if let (true, Some(value)) = (honoured, &value) {
+            honoured = locale
+                .extensions
+                .unicode
+                .set_keyword(*key, &[value.as_str()])
+                .is_ok();
+        }
+        keywords.push(ResolvedKeyword {
+            key: key.to_string(),
+            value,
+            honoured,
+        });
+    }
+
+    ResolvedLocale {
+        locale,
+        data_locale: matched.locale,
+        keywords,
+    }
+}
@@ -0,0 +1,246 @@
+//! Conversion between `Locale`, Windows LCIDs and Win32 locale names.
+//!
+//! An LCID stores a language identifier in its lower 16 bits and a sort
+//! identifier in bits 16 to 19. LCIDs with a non-default sort order are
+//! mapped to a locale with the matching `-u-co-` keyword, for example
+//! `0x00010407` (`de-DE_phoneb`) is `de-DE-u-co-phonebk`.
+//!
+//! Neutral cultures, such as `0x0009` (`en`) or `0x7C04` (`zh-Hant`),
+//! have no region, while specific cultures, such as `0x0409` (`en-US`), do.
+//!
+//! The mapping is table driven and doesn't depend on any Windows API.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::lcid::{from_lcid, to_lcid};
+//!
+//! let loc = from_lcid(0x0804).expect("Unknown LCID.");
+//! assert_eq!(loc.to_string(), "zh-CN");
+//!
+//! let loc = from_lcid(0x00020804).expect("Unknown LCID.");
+//! assert_eq!(loc.to_string(), "zh-CN-u-co-stroke");
+//! assert_eq!(to_lcid(&loc), Some(0x00020804));
+//! ```
+mod tables;
+
+use crate::errors::LocaleError;
+use crate::parser::{ParserError, SubtagKind};
+use crate::Locale;
+use alloc::string::{String, ToString};
+use unic_langid_impl::LanguageIdentifier;
+
+/// Win32 names from earlier Windows and .NET releases,
+/// and the names which replaced them.
+static LEGACY_NAMES: &[(&str, &str)] = &[
+    ("az-AZ-Cyrl", "az-Cyrl-AZ"),
+    ("az-AZ-Latn", "az-Latn-AZ"),
+    ("bs-BA-Latn", "bs-Latn-BA"),
+    ("div", "dv"),
+    ("div-MV", "dv-MV"),
+    ("en-CB", "en-029"),
+    ("sr-SP-Cyrl", "sr-Cyrl-CS"),
+    ("sr-SP-Latn", "sr-Latn-CS"),
+    ("uz-UZ-Cyrl", "uz-Cyrl-UZ"),
+    ("uz-UZ-Latn", "uz-Latn-UZ"),
+    ("zh-CHS", "zh-Hans"),
+    ("zh-CHT", "zh-Hant"),
+];
+
+/// Sort suffixes of Win32 names and their `-u-co-` keyword values,
+/// limited to a language for the sorts CLDR has no dedicated type for.
+///
+/// The Hungarian technical sort drops the Hungarian digraphs, like the
+/// root collation, and the Georgian modern sort is the CLDR default.
+/// MS-LCID spells the Hungarian suffix `tchncl` and .NET `technl`.
+static SORT_NAMES: &[(Option<&str>, &str, &str)] = &[
+    (None, "phoneb", "phonebk"),
+    (None, "pronun", "zhuyin"),
+    (None, "radstr", "unihan"),
+    (None, "stroke", "stroke"),
+    (None, "tradnl", "trad"),
+    (Some("hu"), "technl", "ducet"),
+    (Some("hu"), "tchncl", "ducet"),
+    (Some("ka"), "modern", "standard"),
+];
+
+fn sort_applies(language: Option<&str>, locale: &Locale) -> bool {
+    language.is_none_or(|language| locale.id.language == language)
+}
+
+fn lookup(lcid: u32) -> Option<&'static str> {
+    tables::LCIDS
+        .binary_search_by_key(&lcid, |(l, _)| *l)
+        .ok()
+        .map(|idx| tables::LCIDS[idx].1)
+}
+
+/// Converts an LCID into a `Locale`.
+///
+/// `0x007F`, the invariant locale, is mapped to `und`.
+/// Returns `None` for LCIDs without a predefined culture, including
+/// special values such as `LOCALE_USER_DEFAULT` or `LOCALE_CUSTOM_UNSPECIFIED`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::lcid::from_lcid;
+///
+/// assert_eq!(from_lcid(0x0409).map(|l| l.to_string()), Some("en-US".to_string()));
+/// assert_eq!(from_lcid(0x040A).map(|l| l.to_string()), Some("es-ES-u-co-trad".to_string()));
+/// assert_eq!(from_lcid(0x7C04).map(|l| l.to_string()), Some("zh-Hant".to_string()));
+/// assert_eq!(from_lcid(0x1000), None);
+/// ```
+pub fn from_lcid(lcid: u32) -> Option<Locale> {
+    // Table values are well-formed, so parsing them can't fail.
+    lookup(lcid).map(|tag| tag.parse().unwrap())
+}
+
+/// Converts a `Locale` into an LCID.
+///
+/// The locale has to match a predefined culture exactly, so for example
+/// `en-Latn-US` has no LCID even though `en-US` does.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::lcid::to_lcid;
+///
+/// let loc: Locale = "sr-Latn-RS".parse().expect("Parsing failed.");
+/// assert_eq!(to_lcid(&loc), Some(0x241A));
+///
+/// let loc: Locale = "de-DE-u-co-phonebk".parse().expect("Parsing failed.");
+/// assert_eq!(to_lcid(&loc), Some(0x00010407));
+/// ```
+pub fn to_lcid(locale: &Locale) -> Option<u32> {
+    let tag = locale.to_string();
+    tables::LCIDS
+        .iter()
+        .find(|(_, t)| *t == tag)
+        .map(|(lcid, _)| *lcid)
+}
+
+/// Returns whether an LCID identifies a neutral culture,
+/// or `None` if the LCID has no predefined culture.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::lcid::is_neutral;
+///
+/// assert_eq!(is_neutral(0x0009), Some(true));
+/// assert_eq!(is_neutral(0x0409), Some(false));
+/// assert_eq!(is_neutral(0x0400), None);
+/// ```
+pub fn is_neutral(lcid: u32) -> Option<bool> {
+    from_lcid(lcid).map(|loc| loc.id.region.is_none())
+}
+
+/// Converts a Win32 locale name into a `Locale`.
+///
+/// The empty name of the invariant locale is mapped to `und`.
+/// Legacy names such as `zh-CHS` are replaced with their current
+/// equivalent and a sort suffix such as `_phoneb` is mapped to
+/// a `-u-co-` keyword. Names of custom cultures, which have no LCID,
+/// are accepted as long as they are well-formed.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::lcid::from_windows_name;
+///
+/// let loc = from_windows_name("zh-CHT").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "zh-Hant");
+///
+/// let loc = from_windows_name("es-ES_tradnl").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "es-ES-u-co-trad");
+///
+/// let loc = from_windows_name("hu-HU_technl").expect("Conversion failed.");
+/// assert_eq!(loc.to_string(), "hu-HU-u-co-ducet");
+/// ```
+pub fn from_windows_name(name: &str) -> Result<Locale, LocaleError> {
+    if name.is_empty() {
+        return Ok(Locale::default());
+    }
+
+    // The sort suffix is separated with an underscore, which the
+    // parser would otherwise accept as a subtag separator.
+    let (name, sort) = match name.split_once('_') {
+        Some((name, sort)) => (name, Some(sort)),
+        None => (name, None),
+    };
+    let sort_offset = name.len() + 1;
+    let name = LEGACY_NAMES
+        .iter()
+        .find(|(legacy, _)| legacy.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, current)| current);
+
+    let mut locale: Locale = name.parse()?;
+
+    if let Some(sort) = sort {
+        let collation = SORT_NAMES
+            .iter()
+            .find(|(language, s, _)| {
+                s.eq_ignore_ascii_case(sort) && sort_applies(*language, &locale)
+            })
+            .map(|(.., co)| *co)
+            .ok_or(ParserError::MalformedSubtag {
+                expected: SubtagKind::UnicodeType,
+                offset: sort_offset,
+                length: sort.len(),
+            })?;
+        locale.extensions.unicode.set_keyword("co", &[collation])?;
+    }
+
+    Ok(locale)
+}
+
+/// Converts a `Locale` into a Win32 locale name.
+///
+/// `und` is mapped to the empty name of the invariant locale.
+/// Returns `None` if the locale has extensions other than a `-u-co-`
+/// keyword with a Win32 sort suffix.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::lcid::to_windows_name;
+///
+/// let loc: Locale = "de-DE-u-co-phonebk".parse().expect("Parsing failed.");
+/// assert_eq!(to_windows_name(&loc), Some("de-DE_phoneb".to_string()));
+///
+/// let loc: Locale = "th-TH-u-nu-thai".parse().expect("Parsing failed.");
+/// assert_eq!(to_windows_name(&loc), None);
+/// ```
+pub fn to_windows_name(locale: &Locale) -> Option<String> {
+    let mut extensions = locale.extensions.clone();
+    let sort = match extensions.unicode.keyword("co") {
+        Ok(mut co) if co.len() == 1 => {
+            let co = co.next()?;
+            SORT_NAMES
+                .iter()
+                .find(|(language, _, c)| *c == co && sort_applies(*language, locale))
+                .map(|(_, s, _)| *s)
+        }
+        _ => None,
+    };
+    if sort.is_some() {
+        let _ = extensions.unicode.remove_keyword("co");
+    }
+    if !extensions.is_empty() {
+        return None;
+    }
+
+    if locale.id == LanguageIdentifier::default() && sort.is_none() {
+        return Some(String::new());
+    }
+
+    let mut result = locale.id.to_string();
+    if let Some(sort) = sort {
+        result.push('_');
+        result.push_str(sort);
+    }
+    Some(result)
+}
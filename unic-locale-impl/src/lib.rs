@@ -1,12 +1,48 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod encoding;
 pub(crate) mod errors;
 pub mod extensions;
+#[cfg(feature = "icu")]
+pub mod icu;
+#[cfg(feature = "icu4x")]
+mod icu4x;
+pub mod intl;
+#[cfg(feature = "lcid")]
+pub mod lcid;
 pub mod parser;
+#[cfg(feature = "posix")]
+pub mod posix;
+#[cfg(feature = "system")]
+pub mod system;
 
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
 use errors::LocaleError;
 pub use extensions::{ExtensionType, ExtensionsMap};
-use std::str::FromStr;
+use parser::ParserError;
+#[cfg(feature = "aliases")]
+pub use unic_langid_impl::aliases;
+#[cfg(feature = "display-names")]
+pub use unic_langid_impl::display_names;
+#[cfg(feature = "iana-registry")]
+pub use unic_langid_impl::iana_registry;
+#[cfg(feature = "iso639")]
+pub use unic_langid_impl::iso639;
+#[cfg(feature = "locale-info")]
+pub use unic_langid_impl::locale_info;
+#[cfg(feature = "parent-locales")]
+pub use unic_langid_impl::parent_locales;
+#[cfg(feature = "territory-info")]
+pub use unic_langid_impl::territory_info;
+#[cfg(feature = "validity")]
+pub use unic_langid_impl::validity;
 pub use unic_langid_impl::CharacterDirection;
-pub use unic_langid_impl::{subtags, LanguageIdentifier};
+pub use unic_langid_impl::{subtags, LanguageIdentifier, MatchOptions};
 
 /// `Locale` is a core struct representing a Unicode Locale Identifier.
 ///
@@ -77,6 +113,59 @@ type PartsTuple = (
     String,
 );
 
+/// How [`Locale::matches_with_policy`] compares private use extensions.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PrivateUsePolicy {
+    /// Private use extensions must be equal.
+    Compare,
+    /// Private use extensions are ignored.
+    Ignore,
+    /// Locales with private use extensions never match, as in
+    /// [`Locale::matches`].
+    #[default]
+    RequireEmpty,
+}
+
+/// Which extensions [`Locale::matches_with_policy`] compares.
+///
+/// The default policy is the one of [`Locale::matches`]. Transform
+/// extensions, like `-t-en`, and other extensions are always ignored,
+/// whatever the policy, so `de-t-en` matches `de-t-fr`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ExtensionPolicy<'a> {
+    /// The unicode extension keys whose values must be equal, like `co`
+    /// and `nu`. Other keywords are ignored. Locales never match for a key
+    /// which isn't a valid unicode extension key, like `c` or `1a`.
+    pub unicode_keys: &'a [&'a str],
+    /// Whether a keyword of `unicode_keys` missing from either locale
+    /// matches any value of the other.
+    pub missing_keywords_match: bool,
+    /// How private use extensions are compared.
+    pub private_use: PrivateUsePolicy,
+}
+
+impl ExtensionPolicy<'_> {
+    /// A policy ignoring all extensions.
+    pub const IGNORE_ALL: Self = Self {
+        unicode_keys: &[],
+        missing_keywords_match: false,
+        private_use: PrivateUsePolicy::Ignore,
+    };
+}
+
+/// Options of [`Locale::maximize_with_options`] and
+/// [`Locale::minimize_with_options`].
+#[cfg(feature = "likelysubtags")]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct LikelySubtagsOptions {
+    /// Also add or remove the likely subtags of the language of the
+    /// transform extension, its `tlang`.
+    pub transform_lang: bool,
+    /// When minimizing, keep the script rather than the region when either
+    /// would do, like `zh-Hant` rather than `zh-TW` for `zh-Hant-TW`.
+    pub favor_script: bool,
+}
+
 impl Locale {
     /// A constructor which takes a utf8 slice, parses it and
     /// produces a well-formed `Locale`.
@@ -95,6 +184,29 @@ impl Locale {
         Ok(parser::parse_locale(v)?)
     }
 
+    /// A constructor which takes a utf8 slice, parses it leniently and
+    /// produces a well-formed `Locale` together with a list of subtags
+    /// which were dropped.
+    ///
+    /// The longest well-formed prefix of the input is kept, and any later
+    /// subtag which doesn't fit into the locale is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let input = "de-DE-u-co-phonebk-x-";
+    /// let (loc, errors) = Locale::from_bytes_lenient(input.as_bytes());
+    ///
+    /// assert_eq!(loc.to_string(), "de-DE-u-co-phonebk");
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].span(), Some(21..21));
+    /// ```
+    pub fn from_bytes_lenient(v: &[u8]) -> (Self, Vec<ParserError>) {
+        parser::parse_locale_lenient(v)
+    }
+
     /// A constructor which takes optional subtags as `AsRef<[u8]>`, parses them and
     /// produces a well-formed `Locale`.
     ///
@@ -168,6 +280,89 @@ impl Locale {
         (lang, region, script, variants, self.extensions.to_string())
     }
 
+    /// Encodes the `Locale` into the compact binary form
+    /// described in [`encoding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "de-DE-u-co-phonebk".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_bytes().len(), 20);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encoding::encode(self)
+    }
+
+    /// A constructor which takes the compact binary form produced by
+    /// [`to_bytes`](Self::to_bytes) and decodes it into a `Locale`.
+    ///
+    /// Malformed and non-canonical input is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "ja-JP-u-ca-japanese-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let bytes = loc.to_bytes();
+    /// assert_eq!(Locale::from_bytes_encoded(&bytes), Ok(loc));
+    ///
+    /// assert!(Locale::from_bytes_encoded(&bytes[..10]).is_err());
+    /// ```
+    pub fn from_bytes_encoded(v: &[u8]) -> Result<Self, LocaleError> {
+        Ok(encoding::decode(v)?)
+    }
+
+    /// Packs the `Locale` into a single `u64` as described in
+    /// [`unic_langid_impl::encoding`].
+    ///
+    /// Returns `None` if there are extensions or variants, or the language
+    /// subtag is longer than 7 letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "pt-BR".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(loc.to_u64().is_some());
+    ///
+    /// let loc: Locale = "pt-BR-u-nu-latn".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_u64(), None);
+    /// ```
+    pub fn to_u64(&self) -> Option<u64> {
+        if !self.extensions.is_empty() {
+            return None;
+        }
+        self.id.to_u64()
+    }
+
+    /// A constructor which takes a `u64` produced by [`to_u64`](Self::to_u64)
+    /// and unpacks it into a `Locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "sr-Latn-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let packed = loc.to_u64().expect("Packing failed.");
+    /// assert_eq!(Locale::from_u64(packed), Ok(loc));
+    /// ```
+    pub fn from_u64(v: u64) -> Result<Self, LocaleError> {
+        Ok(LanguageIdentifier::from_u64(v)?.into())
+    }
+
     /// Compares a `Locale` to another `AsRef<Locale`
     /// allowing for either side to use the missing fields as wildcards.
     ///
@@ -197,12 +392,222 @@ impl Locale {
         other: &O,
         self_as_range: bool,
         other_as_range: bool,
+    ) -> bool {
+        // The remaining options only exist with some features.
+        #[allow(clippy::needless_update)]
+        let options = MatchOptions {
+            self_as_range,
+            other_as_range,
+            ..Default::default()
+        };
+        self.matches_with_options(other, options)
+    }
+
+    /// Compares a `Locale` to another `AsRef<Locale>` like
+    /// [`matches`](Self::matches), with more options.
+    ///
+    /// See [`LanguageIdentifier::matches_with_options`] for details.
+    pub fn matches_with_options<O: AsRef<Self>>(&self, other: &O, options: MatchOptions) -> bool {
+        self.matches_with_policy(other, options, ExtensionPolicy::default())
+    }
+
+    /// Compares a `Locale` to another `AsRef<Locale>` like
+    /// [`matches_with_options`](Self::matches_with_options), comparing
+    /// extensions as `policy` says.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{ExtensionPolicy, Locale};
+    ///
+    /// let phonebook: Locale = "de-u-co-phonebk".parse().expect("Parsing failed.");
+    /// let standard: Locale = "de".parse().expect("Parsing failed.");
+    /// let requested: Locale = "de-u-co-phonebk-nu-latn".parse().expect("Parsing failed.");
+    ///
+    /// // By default, unicode extensions are ignored.
+    /// assert!(requested.matches(&standard, false, false));
+    ///
+    /// let policy = ExtensionPolicy {
+    ///     unicode_keys: &["co"],
+    ///     ..Default::default()
+    /// };
+    /// let options = Default::default();
+    /// assert!(requested.matches_with_policy(&phonebook, options, policy));
+    /// assert!(!requested.matches_with_policy(&standard, options, policy));
+    ///
+    /// let policy = ExtensionPolicy {
+    ///     missing_keywords_match: true,
+    ///     ..policy
+    /// };
+    /// assert!(requested.matches_with_policy(&standard, options, policy));
+    /// ```
+    pub fn matches_with_policy<O: AsRef<Self>>(
+        &self,
+        other: &O,
+        options: MatchOptions,
+        policy: ExtensionPolicy,
     ) -> bool {
         let other = other.as_ref();
-        if !self.extensions.private.is_empty() || !other.extensions.private.is_empty() {
-            return false;
+        let private_matches = match policy.private_use {
+            PrivateUsePolicy::Compare => self.extensions.private == other.extensions.private,
+            PrivateUsePolicy::Ignore => true,
+            PrivateUsePolicy::RequireEmpty => {
+                self.extensions.private.is_empty() && other.extensions.private.is_empty()
+            }
+        };
+        let keywords_match = policy.unicode_keys.iter().all(|key| {
+            let key = match extensions::parse_key(key.as_bytes()) {
+                Ok(key) => key,
+                Err(_) => return false,
+            };
+            let value = self.extensions.unicode.keywords.get(&key);
+            let other_value = other.extensions.unicode.keywords.get(&key);
+            value == other_value
+                || policy.missing_keywords_match && (value.is_none() || other_value.is_none())
+        });
+        private_matches && keywords_match && self.id.matches_with_options(&other.id, options)
+    }
+
+    /// Returns a copy of the `Locale` with likely subtags added to its
+    /// language identifier, keeping the extensions.
+    ///
+    /// See [`LanguageIdentifier::maximize`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "zh-TW-u-nu-hanidec".parse().expect("Parsing failed.");
+    /// assert_eq!(loc.maximize().to_string(), "zh-Hant-TW-u-nu-hanidec");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn maximize(&self) -> Self {
+        self.maximize_with_options(LikelySubtagsOptions::default())
+    }
+
+    /// Returns a copy of the `Locale` with likely subtags removed from its
+    /// language identifier, keeping the extensions.
+    ///
+    /// See [`LanguageIdentifier::minimize`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "zh-Hant-TW-u-nu-hanidec".parse().expect("Parsing failed.");
+    /// assert_eq!(loc.minimize().to_string(), "zh-TW-u-nu-hanidec");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize(&self) -> Self {
+        self.minimize_with_options(LikelySubtagsOptions::default())
+    }
+
+    /// Returns a copy of the `Locale` with likely subtags added like
+    /// [`maximize`](Self::maximize), with more options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{LikelySubtagsOptions, Locale};
+    ///
+    /// let loc: Locale = "sr-t-ru".parse().expect("Parsing failed.");
+    /// let options = LikelySubtagsOptions {
+    ///     transform_lang: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(loc.maximize_with_options(options).to_string(), "sr-Cyrl-RS-t-ru-Cyrl-RU");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn maximize_with_options(&self, options: LikelySubtagsOptions) -> Self {
+        let mut loc = self.clone();
+        loc.id.maximize();
+        if options.transform_lang {
+            if let Some(tlang) = &mut loc.extensions.transform.tlang {
+                tlang.maximize();
+            }
+        }
+        loc
+    }
+
+    /// Returns a copy of the `Locale` with likely subtags removed like
+    /// [`minimize`](Self::minimize), with more options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{LikelySubtagsOptions, Locale};
+    ///
+    /// let loc: Locale = "zh-Hant-TW-u-nu-hanidec".parse().expect("Parsing failed.");
+    /// let options = LikelySubtagsOptions {
+    ///     favor_script: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(loc.minimize_with_options(options).to_string(), "zh-Hant-u-nu-hanidec");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize_with_options(&self, options: LikelySubtagsOptions) -> Self {
+        let minimize = |langid: &mut LanguageIdentifier| {
+            if options.favor_script {
+                langid.minimize_favor_script();
+            } else {
+                langid.minimize();
+            }
+        };
+        let mut loc = self.clone();
+        minimize(&mut loc.id);
+        if options.transform_lang {
+            if let Some(tlang) = &mut loc.extensions.transform.tlang {
+                minimize(tlang);
+            }
         }
-        self.id.matches(&other.id, self_as_range, other_as_range)
+        loc
+    }
+
+    /// Returns the name of the `Locale` in the display locale of `names`.
+    ///
+    /// Unicode extension keywords are named after the subtags of the
+    /// language identifier. Other extensions are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::display_names::DisplayNames;
+    ///
+    /// let names = DisplayNames::new(&"en".parse().expect("Parsing failed."), Default::default())
+    ///     .expect("No data for en.");
+    ///
+    /// let loc: Locale = "de-CH-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.display_name(&names), "Swiss High German (Buddhist Calendar)");
+    /// ```
+    #[cfg(feature = "display-names")]
+    pub fn display_name(&self, names: &display_names::DisplayNames) -> String {
+        let keywords: Vec<(&str, String)> = self
+            .extensions
+            .unicode
+            .keywords
+            .iter()
+            .map(|(key, values)| {
+                let value = if values.is_empty() {
+                    String::from("true")
+                } else {
+                    values
+                        .iter()
+                        .map(|value| value.as_str())
+                        .collect::<Vec<_>>()
+                        .join("-")
+                };
+                (key.as_str(), value)
+            })
+            .collect();
+        names.locale_with_keywords(
+            &self.id,
+            keywords.iter().map(|(key, value)| (*key, value.as_str())),
+        )
     }
 }
 
@@ -242,8 +647,8 @@ impl AsRef<Locale> for Locale {
     }
 }
 
-impl std::fmt::Display for Locale {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Locale {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}{}", self.id, self.extensions)
     }
 }
@@ -1,13 +1,60 @@
-use std::error::Error;
-use std::fmt::{self, Display};
+use core::fmt::{self, Display};
+use core::ops::Range;
 use unic_langid_impl::parser::ParserError as LangIdParserError;
+use unic_langid_impl::parser::SubtagKind;
 
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParserError {
     InvalidLanguage,
     InvalidSubtag,
     InvalidExtension,
     LangIdError(LangIdParserError),
+    /// The subtag at `offset..offset + length` is not a well-formed
+    /// subtag of the `expected` kind.
+    MalformedSubtag {
+        expected: SubtagKind,
+        offset: usize,
+        length: usize,
+    },
+    /// The extension singleton at `offset..offset + length` has already
+    /// been used earlier in the locale.
+    DuplicateSingleton {
+        offset: usize,
+        length: usize,
+    },
+    /// The binary encoded input is malformed at `offset`.
+    InvalidEncoding {
+        offset: usize,
+    },
+}
+
+impl ParserError {
+    /// Returns the byte range of the input the error points at, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::parser::parse_locale;
+    ///
+    /// let input = "en-US-u-ca-buddhist-u-hc-h12";
+    /// let err = parse_locale(input).expect_err("Parsing should fail.");
+    ///
+    /// assert_eq!(err.span(), Some(20..21));
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            ParserError::InvalidLanguage
+            | ParserError::InvalidSubtag
+            | ParserError::InvalidExtension
+            | ParserError::InvalidEncoding { .. } => None,
+            ParserError::LangIdError(err) => err.span(),
+            ParserError::MalformedSubtag { offset, length, .. }
+            | ParserError::DuplicateSingleton { offset, length } => {
+                Some(*offset..*offset + *length)
+            }
+        }
+    }
 }
 
 impl From<LangIdParserError> for ParserError {
@@ -16,16 +63,36 @@ impl From<LangIdParserError> for ParserError {
     }
 }
 
-impl Error for ParserError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let value = match &self {
-            ParserError::InvalidLanguage => "The given language subtag is invalid",
-            ParserError::InvalidSubtag => "Invalid subtag",
-            ParserError::InvalidExtension => "Invalid extension",
-            ParserError::LangIdError(_) => "Language Identifier Parser Error",
-        };
-        f.write_str(value)
+        match &self {
+            ParserError::InvalidLanguage => f.write_str("The given language subtag is invalid"),
+            ParserError::InvalidSubtag => f.write_str("Invalid subtag"),
+            ParserError::InvalidExtension => f.write_str("Invalid extension"),
+            ParserError::LangIdError(err) => err.fmt(f),
+            ParserError::MalformedSubtag {
+                expected,
+                offset,
+                length,
+            } => write!(
+                f,
+                "Invalid subtag at {}..{}, expected {}",
+                offset,
+                offset + length,
+                expected
+            ),
+            ParserError::DuplicateSingleton { offset, length } => write!(
+                f,
+                "Duplicate extension singleton at {}..{}",
+                offset,
+                offset + length
+            ),
+            ParserError::InvalidEncoding { offset } => {
+                write!(f, "Invalid binary encoding at byte {}", offset)
+            }
+        }
     }
 }
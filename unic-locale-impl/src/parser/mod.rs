@@ -3,14 +3,95 @@ pub mod errors;
 pub use self::errors::ParserError;
 use super::extensions::ExtensionsMap;
 use super::Locale;
+use alloc::vec;
+use alloc::vec::Vec;
+use unic_langid_impl::parser::{expected_subtag_kind, split_trailing_junk};
+pub use unic_langid_impl::parser::{SubtagIterator, SubtagKind};
 use unic_langid_impl::LanguageIdentifier;
 
 pub fn parse_locale<S: AsRef<[u8]>>(t: S) -> Result<Locale, ParserError> {
-    let mut iter = t.as_ref().split(|c| *c == b'-' || *c == b'_').peekable();
+    let mut iter = SubtagIterator::new(t.as_ref());
+    parse_locale_from_iter(&mut iter)
+}
+
+fn parse_locale_from_iter(iter: &mut SubtagIterator) -> Result<Locale, ParserError> {
+    let id = LanguageIdentifier::try_from_iter(iter, true).map_err(|err| match err {
+        unic_langid_impl::LanguageIdentifierError::ParserError(err) => ParserError::from(err),
+        _ => ParserError::InvalidLanguage,
+    })?;
 
-    let id = LanguageIdentifier::try_from_iter(&mut iter, true)
-        .map_err(|_| ParserError::InvalidLanguage)?;
+    // Anything left after the language identifier has to start an extension.
+    // If it doesn't, report it against the subtag the identifier expected next.
+    if let Some(subtag) = iter.peek() {
+        if subtag.len() != 1 {
+            return Err(ParserError::MalformedSubtag {
+                expected: expected_subtag_kind(&id),
+                offset: iter.offset(),
+                length: subtag.len(),
+            });
+        }
+    }
 
-    let extensions = ExtensionsMap::try_from_iter(&mut iter)?;
+    let extensions = ExtensionsMap::try_from_iter(iter)?;
     Ok(Locale { id, extensions })
 }
+
+/// Parses a `Locale` recovering from malformed subtags.
+///
+/// The longest well-formed prefix of the input is kept and any subtag
+/// which doesn't fit into the locale is dropped and reported. Errors which
+/// can't be recovered from that way truncate the input before the subtag
+/// the parser failed at. Trailing junk such as a POSIX codeset is dropped
+/// as a whole.
+pub fn parse_locale_lenient<S: AsRef<[u8]>>(t: S) -> (Locale, Vec<ParserError>) {
+    let t = t.as_ref();
+    let (mut input, junk) = split_trailing_junk(t);
+    let mut errors = vec![];
+    let mut ignored = vec![];
+
+    let locale = loop {
+        let mut iter = SubtagIterator::with_ignored(input, &ignored);
+        let err = match parse_locale_from_iter(&mut iter) {
+            Ok(locale) => break locale,
+            Err(err) => err,
+        };
+        let end = match err.span() {
+            // The language subtag always starts at 0 and without
+            // it there is no prefix to keep.
+            Some(span) if span.start == 0 => {
+                errors.push(err);
+                break Locale::default();
+            }
+            Some(span) if !ignored.contains(&span.start) => {
+                ignored.push(span.start);
+                errors.push(err);
+                continue;
+            }
+            Some(span) => span.start,
+            None => iter.offset(),
+        };
+        // The error can't be recovered from by dropping a single subtag.
+        // Keep everything before the last subtag the parser got to.
+        errors.push(err);
+        let end = end.min(input.len());
+        match input[..end].iter().rposition(|&b| b == b'-' || b == b'_') {
+            Some(sep) if sep > 0 => input = &input[..sep],
+            _ => break Locale::default(),
+        }
+    };
+
+    if junk < t.len() {
+        let expected = if locale.extensions.is_empty() {
+            expected_subtag_kind(&locale.id)
+        } else {
+            SubtagKind::ExtensionSingleton
+        };
+        errors.push(ParserError::MalformedSubtag {
+            expected,
+            offset: junk,
+            length: t.len() - junk,
+        });
+    }
+
+    (locale, errors)
+}
@@ -0,0 +1,262 @@
+//! Conversion between `Locale` and POSIX locale names.
+//!
+//! POSIX locale names, as used by `LANG`, `LC_ALL` and `LC_*` environment
+//! variables, have the form `language[_territory][.codeset][@modifier]`,
+//! for example `sr_RS.UTF-8@latin` or `ca_ES@valencia`.
+//!
+//! Some modifiers map onto a script, a variant or a unicode extension keyword,
+//! while parts which have no equivalent on the other side are reported
+//! as `PosixLoss`.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::posix::{from_posix, to_posix, CLocaleMapping, PosixLoss};
+//!
+//! let (loc, loss) = from_posix("sr_RS.UTF-8@latin", CLocaleMapping::Undetermined)
+//!     .expect("Parsing failed.");
+//!
+//! assert_eq!(loc.to_string(), "sr-Latn-RS");
+//! assert_eq!(loss, &[PosixLoss::Codeset("UTF-8".to_string())]);
+//!
+//! let (name, loss) = to_posix(&loc, Some("UTF-8"));
+//! assert_eq!(name, "sr_RS.UTF-8@latin");
+//! assert!(loss.is_empty());
+//! ```
+use crate::errors::LocaleError;
+use crate::Locale;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use unic_langid_impl::gettext::{
+    modifier_script, modifier_variant, script_modifier, variant_modifier, IJEKAVIAN_LATIN_MODIFIER,
+};
+use unic_langid_impl::{subtags, LanguageIdentifier};
+
+/// Modifier which selects the Euro currency.
+const EURO_MODIFIER: &str = "euro";
+
+/// The `Locale` which the `C` and `POSIX` locale names are mapped to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CLocaleMapping {
+    /// `und`
+    #[default]
+    Undetermined,
+    /// `en-US-u-va-posix`
+    EnUsPosix,
+}
+
+/// A part of the input of a conversion which has no equivalent in the output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PosixLoss {
+    /// The codeset of a POSIX name, such as `UTF-8`.
+    Codeset(String),
+    /// A POSIX modifier with no `Locale` equivalent, such as `@phonebook`.
+    Modifier(String),
+    /// Subtags of a `Locale` with no POSIX equivalent, such as `fonipa`
+    /// or `u-ca-buddhist`.
+    Subtags(String),
+}
+
+/// Converts a POSIX locale name into a `Locale`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::posix::{from_posix, CLocaleMapping};
+///
+/// let (loc, _) = from_posix("ca_ES@valencia", CLocaleMapping::Undetermined)
+///     .expect("Parsing failed.");
+/// assert_eq!(loc.to_string(), "ca-ES-valencia");
+///
+/// let (loc, _) = from_posix("de_DE@euro", CLocaleMapping::Undetermined)
+///     .expect("Parsing failed.");
+/// assert_eq!(loc.to_string(), "de-DE-u-cu-eur");
+///
+/// let (loc, _) = from_posix("C.UTF-8", CLocaleMapping::EnUsPosix)
+///     .expect("Parsing failed.");
+/// assert_eq!(loc.to_string(), "en-US-u-va-posix");
+/// ```
+pub fn from_posix(name: &str, c: CLocaleMapping) -> Result<(Locale, Vec<PosixLoss>), LocaleError> {
+    let mut loss = vec![];
+
+    let (name, modifier) = match name.split_once('@') {
+        Some((name, modifier)) => (name, Some(modifier)),
+        None => (name, None),
+    };
+    let (base, codeset) = match name.split_once('.') {
+        Some((base, codeset)) => (base, Some(codeset)),
+        None => (name, None),
+    };
+
+    if let Some(codeset) = codeset {
+        loss.push(PosixLoss::Codeset(codeset.to_string()));
+    }
+
+    if base == "C" || base == "POSIX" {
+        if let Some(modifier) = modifier {
+            loss.push(PosixLoss::Modifier(modifier.to_string()));
+        }
+        let locale = match c {
+            CLocaleMapping::Undetermined => Locale::default(),
+            CLocaleMapping::EnUsPosix => Locale::from_bytes(b"en-US-u-va-posix")?,
+        };
+        return Ok((locale, loss));
+    }
+
+    let mut locale: Locale = LanguageIdentifier::from_bytes(base.as_bytes())?.into();
+
+    if let Some(modifier) = modifier {
+        if !apply_modifier(&mut locale, modifier)? {
+            loss.push(PosixLoss::Modifier(modifier.to_string()));
+        }
+    }
+
+    Ok((locale, loss))
+}
+
+fn apply_modifier(locale: &mut Locale, modifier: &str) -> Result<bool, LocaleError> {
+    let modifier = modifier.to_ascii_lowercase();
+
+    if let Some(script) = modifier_script(&modifier) {
+        locale.id.script = Some(script);
+    } else if let Some(variant) = modifier_variant(&modifier) {
+        add_variant(&mut locale.id, variant);
+    } else if modifier == IJEKAVIAN_LATIN_MODIFIER {
+        locale.id.script = modifier_script("latin");
+        add_variant(&mut locale.id, modifier_variant("ijekavian").unwrap());
+    } else if modifier == EURO_MODIFIER {
+        locale.extensions.unicode.set_keyword("cu", &["eur"])?;
+    } else {
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+fn add_variant(langid: &mut LanguageIdentifier, variant: subtags::Variant) {
+    let mut variants = langid.variants().copied().collect::<Vec<_>>();
+    variants.push(variant);
+    langid.set_variants(&variants);
+}
+
+/// Converts a `Locale` into a POSIX locale name with an optional codeset.
+///
+/// A script is expressed as a modifier. When the `likelysubtags` feature is
+/// enabled, a script which is the likely script for the language and region
+/// is omitted instead.
+///
+/// Only one modifier can be expressed, so any other script, variant or
+/// extension is reported as lost.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::posix::{to_posix, PosixLoss};
+///
+/// let loc: Locale = "uz-Cyrl-UZ".parse().expect("Parsing failed.");
+/// assert_eq!(to_posix(&loc, None).0, "uz_UZ@cyrillic");
+///
+/// let loc: Locale = "de-DE-u-cu-eur-ca-buddhist".parse().expect("Parsing failed.");
+/// let (name, loss) = to_posix(&loc, Some("ISO-8859-15"));
+/// assert_eq!(name, "de_DE.ISO-8859-15@euro");
+/// assert_eq!(loss, &[PosixLoss::Subtags("u-ca-buddhist".to_string())]);
+/// ```
+pub fn to_posix(locale: &Locale, codeset: Option<&str>) -> (String, Vec<PosixLoss>) {
+    let mut loss = vec![];
+    let mut result = String::new();
+    // Each modifier is stored together with the subtags it represents,
+    // so that all but the first one can be reported as lost.
+    let mut modifiers: Vec<(&str, String)> = vec![];
+
+    if is_en_us_posix(locale) {
+        result.push('C');
+    } else if locale.id.language.is_empty() {
+        result.push('C');
+        if *locale != Locale::default() {
+            loss.push(PosixLoss::Subtags(locale.to_string()));
+        }
+    } else {
+        result.push_str(locale.id.language.as_str());
+        if let Some(region) = locale.id.region {
+            result.push('_');
+            result.push_str(region.as_str());
+        }
+
+        let mut variants = locale.id.variants().copied().collect::<Vec<_>>();
+
+        if let Some(script) = locale
+            .id
+            .script
+            .filter(|s| !is_likely_script(&locale.id, *s))
+        {
+            let ijekavsk = variants.iter().position(|v| *v == "ijekavsk");
+            if let (true, Some(idx)) = (script == "Latn", ijekavsk) {
+                variants.remove(idx);
+                modifiers.push((IJEKAVIAN_LATIN_MODIFIER, "Latn-ijekavsk".to_string()));
+            } else if let Some(m) = script_modifier(script) {
+                modifiers.push((m, script.to_string()));
+            } else {
+                loss.push(PosixLoss::Subtags(script.to_string()));
+            }
+        }
+
+        for variant in variants {
+            if let Some(m) = variant_modifier(variant) {
+                modifiers.push((m, variant.to_string()));
+            } else {
+                loss.push(PosixLoss::Subtags(variant.to_string()));
+            }
+        }
+
+        let mut extensions = locale.extensions.clone();
+        let euro = extensions
+            .unicode
+            .keyword("cu")
+            .map(|v| v.eq(["eur"]))
+            .unwrap_or(false);
+        if euro {
+            let _ = extensions.unicode.remove_keyword("cu");
+            modifiers.push((EURO_MODIFIER, "u-cu-eur".to_string()));
+        }
+        if !extensions.is_empty() {
+            // Skip the leading separator.
+            loss.push(PosixLoss::Subtags(extensions.to_string()[1..].to_string()));
+        }
+    }
+
+    if let Some(codeset) = codeset {
+        result.push('.');
+        result.push_str(codeset);
+    }
+
+    let mut modifiers = modifiers.into_iter();
+    if let Some((modifier, _)) = modifiers.next() {
+        result.push('@');
+        result.push_str(modifier);
+    }
+    for (_, subtags) in modifiers {
+        loss.push(PosixLoss::Subtags(subtags));
+    }
+
+    (result, loss)
+}
+
+fn is_en_us_posix(locale: &Locale) -> bool {
+    locale.id.language == "en"
+        && locale.id.script.is_none()
+        && locale.id.region.is_some_and(|r| r == "US")
+        && locale.id.variants().len() == 0
+        && locale.extensions.to_string() == "-u-va-posix"
+}
+
+#[cfg(feature = "likelysubtags")]
+fn is_likely_script(langid: &LanguageIdentifier, script: subtags::Script) -> bool {
+    unic_langid_impl::likelysubtags::maximize(langid.language, None, langid.region)
+        .is_some_and(|(_, s, _)| s == Some(script))
+}
+
+#[cfg(not(feature = "likelysubtags"))]
+fn is_likely_script(_langid: &LanguageIdentifier, _script: subtags::Script) -> bool {
+    false
+}
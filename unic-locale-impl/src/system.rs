@@ -0,0 +1,94 @@
+//! Detection of the user's preferred locales from the process environment.
+//!
+//! The locale for messages is selected following the POSIX precedence of
+//! `LC_ALL`, `LC_MESSAGES` and `LANG`, where the first non-empty variable wins.
+//! Unless that locale is `C` or `POSIX`, the GNU `LANGUAGE` variable, a `:`
+//! separated priority list of locale names, takes precedence over it.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use unic_locale_impl::posix::CLocaleMapping;
+//! use unic_locale_impl::system::preferred_locales_from;
+//!
+//! let env: HashMap<&str, &str> = [
+//!     ("LANG", "de_DE.UTF-8"),
+//!     ("LANGUAGE", "sr@latin:de_AT:de"),
+//! ].into_iter().collect();
+//!
+//! let locales = preferred_locales_from(
+//!     |key| env.get(key).map(|v| v.to_string()),
+//!     CLocaleMapping::Undetermined,
+//! );
+//!
+//! assert_eq!(
+//!     locales.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+//!     &["sr-Latn", "de-AT", "de"]
+//! );
+//! ```
+use crate::posix::{from_posix, CLocaleMapping};
+use crate::Locale;
+
+/// Variables which select the locale for messages, in order of precedence.
+static LOCALE_VARIABLES: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// GNU extension listing locales for messages in order of preference.
+const LANGUAGE_VARIABLE: &str = "LANGUAGE";
+
+/// Returns the user's preferred locales based on the environment
+/// of the current process.
+///
+/// `C` and `POSIX` locales are mapped to `und`.
+pub fn preferred_locales() -> Vec<Locale> {
+    preferred_locales_from(|key| std::env::var(key).ok(), CLocaleMapping::default())
+}
+
+/// Returns the user's preferred locales based on an environment
+/// provided as a variable lookup function.
+///
+/// Variables which are empty are treated as unset, and names which
+/// can't be converted to a `Locale` are skipped.
+/// If no variable is set, the returned list is empty.
+pub fn preferred_locales_from<F>(env: F, c: CLocaleMapping) -> Vec<Locale>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let var = |key: &str| env(key).filter(|v| !v.is_empty());
+
+    let name = match LOCALE_VARIABLES.iter().find_map(|key| var(key)) {
+        Some(name) => name,
+        None => return vec![],
+    };
+
+    if is_c_locale(&name) {
+        return from_posix(&name, c)
+            .map(|(locale, _)| vec![locale])
+            .unwrap_or_default();
+    }
+
+    let mut result: Vec<Locale> = vec![];
+    if let Some(language) = var(LANGUAGE_VARIABLE) {
+        for (locale, _) in language
+            .split(':')
+            .filter(|name| !name.is_empty() && !is_c_locale(name))
+            .filter_map(|name| from_posix(name, c).ok())
+        {
+            if !result.contains(&locale) {
+                result.push(locale);
+            }
+        }
+    }
+
+    if result.is_empty() {
+        if let Ok((locale, _)) = from_posix(&name, c) {
+            result.push(locale);
+        }
+    }
+    result
+}
+
+fn is_c_locale(name: &str) -> bool {
+    let base = name.split(['.', '@']).next().unwrap_or(name);
+    base == "C" || base == "POSIX"
+}
@@ -0,0 +1,42 @@
+use unic_locale_impl::display_names::{DisplayNames, DisplayNamesOptions, LanguageDisplay};
+use unic_locale_impl::Locale;
+
+fn name(display: &str, language_display: LanguageDisplay, input: &str) -> String {
+    let options = DisplayNamesOptions {
+        language_display,
+        ..Default::default()
+    };
+    let names = DisplayNames::new(&display.parse().unwrap(), options).unwrap();
+    let loc: Locale = input.parse().unwrap();
+    loc.display_name(&names)
+}
+
+#[test]
+fn display_name() {
+    let dialect = LanguageDisplay::Dialect;
+    let standard = LanguageDisplay::Standard;
+
+    assert_eq!(name("en", dialect, "de-CH"), "Swiss High German");
+    assert_eq!(name("de", standard, "de-CH"), "Deutsch (Schweiz)");
+    assert_eq!(
+        name("en", standard, "de-CH-u-ca-buddhist"),
+        "German (Switzerland, Buddhist Calendar)"
+    );
+    assert_eq!(
+        name("de", dialect, "de-CH-u-hc-h23-nu-latn"),
+        "Schweizer Hochdeutsch (24-Stunden-Format (0-23), Westliche Ziffern)"
+    );
+    assert_eq!(
+        name("en", dialect, "fr-u-ca-islamic-umalqura"),
+        "French (Islamic Calendar (Umm al-Qura))"
+    );
+    assert_eq!(
+        name("en", dialect, "fr-u-kn"),
+        "French (Sort Digits Numerically)"
+    );
+    assert_eq!(
+        name("fr", dialect, "ja-u-ca-abcde"),
+        "japonais (calendrier\u{202f}: abcde)"
+    );
+    assert_eq!(name("en", dialect, "fr-t-en-x-foo"), "French");
+}
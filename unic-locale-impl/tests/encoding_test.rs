@@ -0,0 +1,108 @@
+use proptest::prelude::*;
+use unic_locale_impl::encoding::decode;
+use unic_locale_impl::parser::ParserError;
+use unic_locale_impl::Locale;
+
+fn assert_roundtrip(input: &str) {
+    let loc: Locale = input.parse().expect("Parsing failed.");
+    let bytes = loc.to_bytes();
+    assert_eq!(Locale::from_bytes_encoded(&bytes), Ok(loc));
+}
+
+fn assert_invalid(input: &[u8], offset: usize) {
+    assert_eq!(decode(input), Err(ParserError::InvalidEncoding { offset }));
+    assert!(Locale::from_bytes_encoded(input).is_err());
+}
+
+fn locale_strategy() -> impl Strategy<Value = String> {
+    (
+        "([a-z]{2,3}|und)",
+        proptest::option::of("[A-Z][a-z]{3}"),
+        proptest::option::of("([A-Z]{2}|[0-9]{3})"),
+        proptest::option::of(proptest::collection::btree_map(
+            "[a-z0-9][a-z]",
+            "[a-z0-9]{3,8}",
+            1..3,
+        )),
+        proptest::option::of(proptest::collection::btree_set("[a-z0-9]{1,8}", 1..3)),
+    )
+        .prop_map(|(language, script, region, keywords, private)| {
+            let mut parts = vec![language];
+            parts.extend(script);
+            parts.extend(region);
+            if let Some(keywords) = keywords {
+                parts.push("u".to_string());
+                for (key, value) in keywords {
+                    parts.push(key);
+                    parts.push(value);
+                }
+            }
+            if let Some(private) = private {
+                parts.push("x".to_string());
+                parts.extend(private);
+            }
+            parts.join("-")
+        })
+}
+
+#[test]
+fn test_to_bytes() {
+    let loc: Locale = "en-US".parse().expect("Parsing failed.");
+    assert_eq!(loc.to_bytes(), &[1, 0x42, b'e', b'n', b'U', b'S', 0, 0]);
+
+    let loc: Locale = "en-u-hc-h12".parse().expect("Parsing failed.");
+    let mut expected = vec![1, 0x02, b'e', b'n', 0, 8];
+    expected.extend_from_slice(b"u-hc-h12");
+    assert_eq!(loc.to_bytes(), expected);
+
+    assert_roundtrip("und");
+    assert_roundtrip("sl-rozaj-biske-u-nu-latn");
+    assert_roundtrip("en-t-it-h0-hybrid-u-ca-buddhist-x-foo");
+    assert_roundtrip("de-u-co-phonebk-ka-shifted");
+}
+
+#[test]
+fn test_from_bytes_encoded_invalid() {
+    assert_invalid(&[], 0);
+    assert_invalid(&[2, 0x00, 0, 0], 0);
+    // Missing extensions length.
+    assert_invalid(&[1, 0x02, b'e', b'n', 0], 5);
+    // Truncated extensions.
+    assert_invalid(&[1, 0x02, b'e', b'n', 0, 4, b'u', b'-'], 6);
+    // Trailing bytes.
+    assert_invalid(&[1, 0x02, b'e', b'n', 0, 0, 0], 6);
+
+    let mut input = vec![1, 0x02, b'e', b'n', 0, 8];
+    input.extend_from_slice(b"u-HC-h12");
+    assert_invalid(&input, 6);
+
+    let mut input = vec![1, 0x02, b'e', b'n', 0, 8];
+    input.extend_from_slice(b"u-hc-h1!");
+    assert_invalid(&input, 6);
+}
+
+#[test]
+fn test_u64() {
+    let loc: Locale = "zh-Hant-TW".parse().expect("Parsing failed.");
+    let packed = loc.to_u64().expect("Packing failed.");
+    assert_eq!(Locale::from_u64(packed), Ok(loc));
+
+    let loc: Locale = "zh-Hant-TW-u-nu-hanidec".parse().expect("Parsing failed.");
+    assert_eq!(loc.to_u64(), None);
+}
+
+proptest! {
+    #[test]
+    fn test_bytes_roundtrip(input in locale_strategy()) {
+        let loc: Locale = input.parse().expect("Parsing failed.");
+        let bytes = loc.to_bytes();
+        prop_assert_eq!(Locale::from_bytes_encoded(&bytes), Ok(loc));
+    }
+
+    #[test]
+    fn test_from_bytes_encoded_arbitrary(input in proptest::collection::vec(any::<u8>(), 0..32)) {
+        if let Ok(loc) = Locale::from_bytes_encoded(&input) {
+            prop_assert_eq!(loc.to_bytes(), input);
+        }
+    }
+}
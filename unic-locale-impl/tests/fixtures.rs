@@ -1,3 +1,5 @@
+#![allow(clippy::iter_nth_zero)]
+
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::error::Error;
@@ -0,0 +1,141 @@
+#[path = "../../unic-langid-impl/tests/common/mod.rs"]
+mod common;
+
+use proptest::prelude::*;
+use unic_locale_impl::{LanguageIdentifier, Locale};
+
+/// Returns language identifiers in canonical case, which the conversions
+/// keep.
+fn langid_strategy() -> impl Strategy<Value = String> {
+    common::icu4x_langid_strategy(2).prop_map(|langid| {
+        let langid: LanguageIdentifier = langid.parse().expect("Parsing failed.");
+        langid.to_string()
+    })
+}
+
+fn unicode_strategy() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of(
+        (
+            proptest::collection::btree_set("[a-z]{3,8}", 0..2),
+            proptest::collection::btree_map(
+                "[a-z0-9][a-z]",
+                proptest::collection::vec("[a-z0-9]{3,8}", 1..3),
+                1..3,
+            ),
+        )
+            .prop_map(|(attributes, keywords)| {
+                let mut parts = vec!["u".to_string()];
+                parts.extend(attributes);
+                for (key, value) in keywords {
+                    parts.push(key);
+                    parts.extend(value.into_iter().filter(|v| v != "true"));
+                }
+                parts.join("-")
+            }),
+    )
+}
+
+fn transform_strategy() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of(
+        (
+            proptest::option::of(langid_strategy()),
+            proptest::collection::btree_map(
+                "[a-z][0-9]",
+                proptest::collection::vec("[a-z0-9]{3,8}", 1..3),
+                0..3,
+            ),
+        )
+            .prop_filter("empty transform extension", |(tlang, tfields)| {
+                tlang.is_some() || !tfields.is_empty()
+            })
+            .prop_map(|(tlang, tfields)| {
+                let mut parts = vec!["t".to_string()];
+                // Transform language subtags are displayed in lowercase.
+                parts.extend(tlang.map(|t| t.to_lowercase()));
+                for (key, value) in tfields {
+                    parts.push(key);
+                    parts.extend(value);
+                }
+                parts.join("-")
+            }),
+    )
+}
+
+fn private_strategy() -> impl Strategy<Value = Option<String>> {
+    proptest::option::of(
+        proptest::collection::btree_set("[a-z0-9]{1,8}", 1..3).prop_map(|subtags| {
+            let mut parts = vec!["x".to_string()];
+            parts.extend(subtags);
+            parts.join("-")
+        }),
+    )
+}
+
+fn locale_strategy() -> impl Strategy<Value = String> {
+    (
+        langid_strategy(),
+        transform_strategy(),
+        unicode_strategy(),
+        private_strategy(),
+    )
+        .prop_map(|(langid, transform, unicode, private)| {
+            let mut parts = vec![langid];
+            parts.extend(transform);
+            parts.extend(unicode);
+            parts.extend(private);
+            parts.join("-")
+        })
+}
+
+fn assert_roundtrip(input: &str) {
+    let loc: Locale = input.parse().expect("Parsing failed.");
+    let icu_loc = icu_locale_core::Locale::try_from(&loc).expect("Conversion failed.");
+    assert_eq!(icu_loc.to_string(), input);
+    assert_eq!(Locale::try_from(&icu_loc), Ok(loc));
+}
+
+#[test]
+fn test_icu4x_roundtrip() {
+    assert_roundtrip("und");
+    assert_roundtrip("en-US");
+    assert_roundtrip("sr-Latn-RS-u-nu-latn");
+    assert_roundtrip("en-u-bar-foo-ca-buddhist-hc-h12");
+    assert_roundtrip("de-u-co-phonebk-ka-shifted");
+    assert_roundtrip("en-t-it-h0-hybrid-x-private");
+    assert_roundtrip("ja-t-it-m0-xxx-v0-yyy");
+    assert_roundtrip("pl-x-bar-foo");
+}
+
+#[test]
+fn test_icu4x_other_extension() {
+    let icu_loc: icu_locale_core::Locale = "en-a-foo-b-bar-u-ca-gregory"
+        .parse()
+        .expect("Parsing failed.");
+    let loc = Locale::try_from(&icu_loc).expect("Conversion failed.");
+    assert_eq!(loc.extensions.other.len(), 2);
+    assert_eq!(loc.extensions.unicode.to_string(), "-u-ca-gregory");
+    assert_eq!(
+        icu_locale_core::Locale::try_from(loc).expect("Conversion failed."),
+        icu_loc
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_icu4x_from_locale(input in locale_strategy()) {
+        let loc: Locale = input.parse().expect("Parsing failed.");
+        let icu_loc = icu_locale_core::Locale::try_from(&loc).expect("Conversion failed.");
+        prop_assert_eq!(icu_loc.to_string(), input);
+        prop_assert_eq!(Locale::try_from(icu_loc), Ok(loc));
+    }
+
+    #[test]
+    fn test_icu4x_to_locale(input in locale_strategy()) {
+        let icu_loc: icu_locale_core::Locale = input.parse().expect("Parsing failed.");
+        let loc = Locale::try_from(&icu_loc).expect("Conversion failed.");
+        prop_assert_eq!(
+            icu_locale_core::Locale::try_from(loc).expect("Conversion failed."),
+            icu_loc
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use unic_locale_impl::icu::{from_icu, to_icu, IcuLoss};
+use unic_locale_impl::Locale;
+
+fn assert_from_icu(input: &str, expected: &str, loss: &[IcuLoss]) {
+    let (loc, l) = from_icu(input).expect("Conversion failed.");
+    assert_eq!(loc.to_string(), expected);
+    assert_eq!(l, loss);
+}
+
+fn assert_to_icu(input: &str, expected: &str) {
+    let loc: Locale = input.parse().expect("Parsing failed.");
+    assert_eq!(to_icu(&loc), expected);
+}
+
+#[test]
+fn test_from_icu() {
+    assert_from_icu("en_US", "en-US", &[]);
+    assert_from_icu("sr_Latn_RS", "sr-Latn-RS", &[]);
+    assert_from_icu("es_419", "es-419", &[]);
+    assert_from_icu("ca_ES_VALENCIA", "ca-ES-valencia", &[]);
+    assert_from_icu("", "und", &[]);
+    assert_from_icu("root", "und", &[]);
+    assert_from_icu("_US", "und-US", &[]);
+
+    assert_from_icu("ja_JP@calendar=japanese", "ja-JP-u-ca-japanese", &[]);
+    assert_from_icu("de@collation=phonebook", "de-u-co-phonebk", &[]);
+    assert_from_icu(
+        "th@calendar=gregorian;numbers=thai",
+        "th-u-ca-gregory-nu-thai",
+        &[],
+    );
+    assert_from_icu(
+        "en@colStrength=primary;colNumeric=yes",
+        "en-u-kn-ks-level1",
+        &[],
+    );
+    assert_from_icu("en@colCaseLevel=no", "en-u-kc-false", &[]);
+    assert_from_icu("en@calendar=islamic-civil", "en-u-ca-islamic-civil", &[]);
+    assert_from_icu("de@currency=EUR", "de-u-cu-eur", &[]);
+    assert_from_icu("en@attribute=foo-bar;hc=h23", "en-u-bar-foo-hc-h23", &[]);
+    assert_from_icu("en@x=private;t=und-latn", "en-t-und-Latn-x-private", &[]);
+
+    assert_from_icu(
+        "en@timezone=America/Los_Angeles;calendar=buddhist",
+        "en-u-ca-buddhist-tz-uslax",
+        &[],
+    );
+    assert_from_icu("en@timezone=US/Pacific", "en-u-tz-uslax", &[]);
+    assert_from_icu(
+        "en@timezone=Mars/Olympus_Mons",
+        "en",
+        &[IcuLoss::Keyword("timezone=Mars/Olympus_Mons".to_string())],
+    );
+    assert_from_icu("en@a=foo", "en", &[IcuLoss::Keyword("a=foo".to_string())]);
+    assert_from_icu(
+        "en@unknownkey=value",
+        "en",
+        &[IcuLoss::Keyword("unknownkey=value".to_string())],
+    );
+
+    // Alpha-3 regions are replaced with their alpha-2 code.
+    assert_from_icu("en_USA", "en-US", &[]);
+    assert_from_icu("pt_bra", "pt-BR", &[]);
+    assert_from_icu("sr_Latn_SRB", "sr-Latn-RS", &[]);
+    assert_from_icu("de_DEU_PREEURO", "de-DE-u-cu-dem", &[]);
+    assert_from_icu("en_XYZ", "en", &[IcuLoss::Region("XYZ".to_string())]);
+    assert_from_icu("en_USA_X", "en-US", &[IcuLoss::Variant("X".to_string())]);
+}
+
+#[test]
+fn test_from_icu_legacy_variants() {
+    assert_from_icu("en_US_POSIX", "en-US-u-va-posix", &[]);
+    assert_from_icu("de__PHONEBOOK", "de-u-co-phonebk", &[]);
+    assert_from_icu("es__TRADITIONAL", "es-u-co-trad", &[]);
+    assert_from_icu("ja_JP_TRADITIONAL", "ja-JP-u-ca-japanese", &[]);
+    assert_from_icu("th_TH_TRADITIONAL", "th-TH-u-ca-buddhist", &[]);
+    assert_from_icu("zh_TW_STROKE", "zh-TW-u-co-stroke", &[]);
+    assert_from_icu("zh__PINYIN", "zh-u-co-pinyin", &[]);
+    assert_from_icu("de_DE_EURO", "de-DE-u-cu-eur", &[]);
+    assert_from_icu("de_DE_PREEURO", "de-DE-u-cu-dem", &[]);
+    assert_from_icu("art__LOJBAN", "jbo", &[]);
+    assert_from_icu("zh_MIN_NAN", "nan", &[]);
+    assert_from_icu("zh_GUOYU", "zh", &[]);
+
+    assert_from_icu(
+        "fr_FR_TRADITIONAL",
+        "fr-FR",
+        &[IcuLoss::Variant("TRADITIONAL".to_string())],
+    );
+    assert_from_icu(
+        "en_US_PREEURO",
+        "en-US",
+        &[IcuLoss::Variant("PREEURO".to_string())],
+    );
+}
+
+#[test]
+fn test_to_icu() {
+    assert_to_icu("en-US", "en_US");
+    assert_to_icu("sr-Latn-RS", "sr_Latn_RS");
+    assert_to_icu("und", "");
+    assert_to_icu("und-US", "_US");
+    assert_to_icu("ca-ES-valencia", "ca_ES_VALENCIA");
+    assert_to_icu("de-1901", "de__1901");
+    assert_to_icu("en-US-u-va-posix", "en_US_POSIX");
+    assert_to_icu("de-u-co-phonebk", "de@collation=phonebook");
+    assert_to_icu(
+        "ja-JP-u-ca-japanese-nu-finance",
+        "ja_JP@calendar=japanese;numbers=finance",
+    );
+    assert_to_icu("en-u-kn", "en@colnumeric=yes");
+    assert_to_icu("en-u-kn-false", "en@colnumeric=no");
+    assert_to_icu("en-u-foo-hc-h12", "en@attribute=foo;hours=h12");
+    assert_to_icu("en-t-und-latn-x-private", "en@t=und-Latn;x=private");
+    assert_to_icu("en-US-u-tz-usnyc", "en_US@timezone=America/New_York");
+}
+
+#[test]
+fn test_icu_roundtrip() {
+    for tag in &[
+        "en-US-u-va-posix",
+        "de-u-co-phonebk",
+        "zh-Hant-TW-u-co-stroke-nu-hanidec",
+        "en-u-ka-noignore-kn-ks-identic",
+        "ca-ES-valencia-u-ms-uksystem",
+        "sr-Latn-t-sr-cyrl-x-foo",
+        "pt-BR-u-ca-gregory-tz-brsao",
+    ] {
+        let loc: Locale = tag.parse().expect("Parsing failed.");
+        let (result, loss) = from_icu(&to_icu(&loc)).expect("Conversion failed.");
+        assert_eq!(result, loc);
+        assert!(loss.is_empty());
+    }
+}
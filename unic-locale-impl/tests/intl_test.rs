@@ -0,0 +1,294 @@
+use unic_locale_impl::intl::{
+    best_available_locale, best_fit_matcher, canonicalize_locale_list, lookup_matcher,
+    resolve_locale, LocaleMatcher, LocaleOptions, ResolveOptions, ResolvedKeyword, TextDirection,
+    TextInfo,
+};
+use unic_locale_impl::{LanguageIdentifier, Locale};
+
+fn locale(input: &str) -> Locale {
+    input.parse().unwrap()
+}
+
+#[test]
+fn with_options_test() {
+    let options = LocaleOptions {
+        language: Some("fr"),
+        script: Some("Latn"),
+        region: Some("CA"),
+        ..Default::default()
+    };
+    let loc = Locale::with_options("en-US-u-ca-gregory-x-foo", options).unwrap();
+    assert_eq!(loc.to_string(), "fr-Latn-CA-u-ca-gregory-x-foo");
+
+    let options = LocaleOptions {
+        calendar: Some("islamic-civil"),
+        collation: Some("phonebk"),
+        case_first: Some("upper"),
+        numbering_system: Some("arab"),
+        numeric: Some(false),
+        ..Default::default()
+    };
+    let loc = Locale::with_options("ar-u-ca-gregory-hc-h12", options).unwrap();
+    assert_eq!(
+        loc.to_string(),
+        "ar-u-ca-islamic-civil-co-phonebk-hc-h12-kf-upper-kn-false-nu-arab"
+    );
+    assert_eq!(loc.calendar().as_deref(), Some("islamic-civil"));
+    assert_eq!(loc.case_first().as_deref(), Some("upper"));
+    assert_eq!(loc.collation().as_deref(), Some("phonebk"));
+    assert_eq!(loc.hour_cycle().as_deref(), Some("h12"));
+    assert_eq!(loc.numbering_system().as_deref(), Some("arab"));
+    assert!(!loc.numeric());
+
+    let invalid = [
+        LocaleOptions {
+            language: Some("e"),
+            ..Default::default()
+        },
+        LocaleOptions {
+            region: Some("USA"),
+            ..Default::default()
+        },
+        LocaleOptions {
+            calendar: Some("ab"),
+            ..Default::default()
+        },
+        LocaleOptions {
+            collation: Some("phonebk-"),
+            ..Default::default()
+        },
+        LocaleOptions {
+            case_first: Some("true"),
+            ..Default::default()
+        },
+    ];
+    for options in invalid {
+        assert!(
+            Locale::with_options("en", options).is_err(),
+            "{:?}",
+            options
+        );
+    }
+    assert!(Locale::with_options("en-", Default::default()).is_err());
+}
+
+#[test]
+fn getters_test() {
+    let loc = locale("de-DE-u-co-phonebk-kn");
+    assert_eq!(*loc.base_name(), "de-DE");
+    assert_eq!(loc.calendar(), None);
+    assert_eq!(loc.case_first(), None);
+    assert_eq!(loc.collation().as_deref(), Some("phonebk"));
+    assert_eq!(loc.hour_cycle(), None);
+    assert_eq!(loc.numbering_system(), None);
+    assert!(loc.numeric());
+}
+
+#[test]
+fn locale_info_test() {
+    assert_eq!(locale("th-TH").get_calendars(), &["buddhist", "gregory"]);
+    assert_eq!(locale("th-TH-u-ca-gregory").get_calendars(), &["gregory"]);
+
+    assert_eq!(locale("en-US").get_hour_cycles(), &["h12"]);
+    assert_eq!(locale("en-GB").get_hour_cycles(), &["h23"]);
+    assert_eq!(locale("en-US-u-hc-h23").get_hour_cycles(), &["h23"]);
+
+    let week = locale("ar-SA").get_week_info();
+    assert_eq!(week.first_day, 7);
+    assert_eq!(week.weekend, vec![5, 6]);
+    assert_eq!(week.minimal_days, 1);
+    assert_eq!(locale("ar-SA-u-fw-sat").get_week_info().first_day, 6);
+    assert_eq!(locale("ar-SA-u-fw-foo").get_week_info().first_day, 7);
+}
+
+#[test]
+fn text_info_test() {
+    assert_eq!(
+        locale("he").get_text_info(),
+        TextInfo {
+            direction: TextDirection::Rtl
+        }
+    );
+    assert_eq!(
+        locale("en-u-ca-buddhist").get_text_info().direction,
+        TextDirection::Ltr
+    );
+    assert_eq!(
+        locale("mn-Mong").get_text_info().direction,
+        TextDirection::Ltr
+    );
+    assert_eq!(TextDirection::Rtl.as_str(), "rtl");
+}
+
+fn langids(input: &[&str]) -> Vec<LanguageIdentifier> {
+    input.iter().map(|langid| langid.parse().unwrap()).collect()
+}
+
+#[test]
+fn canonicalize_locale_list_test() {
+    let locales = canonicalize_locale_list(&["fr-ca", "EN", "fr-CA", "en-u-nu-latn"]).unwrap();
+    let locales: Vec<String> = locales.iter().map(|loc| loc.to_string()).collect();
+    assert_eq!(locales, &["fr-CA", "en", "en-u-nu-latn"]);
+
+    assert!(canonicalize_locale_list::<&str>(&[]).unwrap().is_empty());
+    assert!(canonicalize_locale_list(&["en-US-"]).is_err());
+    assert!(canonicalize_locale_list(&["fr_ca"]).is_err());
+    assert!(canonicalize_locale_list(&["en", "de-u-ca-buddhist_nu-thai"]).is_err());
+}
+
+#[cfg(feature = "aliases")]
+#[test]
+fn canonicalize_locale_list_aliases_test() {
+    let locales =
+        canonicalize_locale_list(&["iw", "he", "en-u-ca-islamicc", "sh-YU-u-tz-usnavajo"]).unwrap();
+    let locales: Vec<String> = locales.iter().map(|loc| loc.to_string()).collect();
+    assert_eq!(
+        locales,
+        &["he", "en-u-ca-islamic-civil", "sr-Latn-RS-u-tz-usden"]
+    );
+}
+
+#[test]
+fn best_available_locale_test() {
+    let available = langids(&["de", "de-CH", "zh-Hant"]);
+
+    assert_eq!(
+        best_available_locale(&available, "de-CH"),
+        Some(&available[1])
+    );
+    assert_eq!(
+        best_available_locale(&available, "de-AT-1996"),
+        Some(&available[0])
+    );
+    assert_eq!(
+        best_available_locale(&available, "zh-Hant-TW-x-foo"),
+        Some(&available[2])
+    );
+    assert_eq!(best_available_locale(&available, "zh-TW"), None);
+    assert_eq!(best_available_locale(&available, "en"), None);
+}
+
+#[test]
+fn matchers_test() {
+    let available = langids(&["en", "en-GB", "pt-PT", "pt-BR", "zh-Hant"]);
+    let default = "en".parse().unwrap();
+
+    let requested = canonicalize_locale_list(&["en-GB-u-ca-gregory"]).unwrap();
+    let result = lookup_matcher(&available, &requested, &default);
+    assert_eq!(result.locale, "en-GB");
+    assert_eq!(result.extension.unwrap().to_string(), "-u-ca-gregory");
+
+    let requested = canonicalize_locale_list(&["pt", "zh-TW"]).unwrap();
+    let result = lookup_matcher(&available, &requested, &default);
+    assert_eq!(result.locale, "en");
+    assert_eq!(result.extension, None);
+
+    // The best fit matcher uses likely subtags, `pt-Latn-BR` and
+    // `zh-Hant-TW`.
+    let result = best_fit_matcher(&available, &requested, &default);
+    assert_eq!(result.locale, "pt-BR");
+    let requested = canonicalize_locale_list(&["zh-TW"]).unwrap();
+    let result = best_fit_matcher(&available, &requested, &default);
+    assert_eq!(result.locale, "zh-Hant");
+
+    let requested = canonicalize_locale_list(&["ja", "ko"]).unwrap();
+    assert_eq!(
+        best_fit_matcher(&available, &requested, &default).locale,
+        "en"
+    );
+}
+
+#[test]
+fn resolve_locale_test() {
+    let available = langids(&["de", "th"]);
+    let default = "en".parse().unwrap();
+    let locale_data = |langid: &LanguageIdentifier, key: &str| -> &'static [&'static str] {
+        match (langid.language.as_str(), key) {
+            ("de", "co") => &["standard", "phonebk"],
+            ("th", "ca") => &["buddhist", "gregory"],
+            (_, "ca") => &["gregory"],
+            (_, "kn") => &["false", "true"],
+            _ => &[],
+        }
+    };
+    let keys = ["ca", "co", "kn"];
+
+    let requested = canonicalize_locale_list(&["de-AT-u-co-phonebk-ca-buddhist-kn"]).unwrap();
+    let resolved = resolve_locale(
+        &available,
+        &requested,
+        &default,
+        ResolveOptions::default(),
+        &keys,
+        locale_data,
+    );
+    assert_eq!(resolved.locale.to_string(), "de-u-co-phonebk-kn");
+    assert_eq!(resolved.data_locale, "de");
+    assert_eq!(
+        resolved.keywords,
+        vec![
+            ResolvedKeyword {
+                key: "ca".to_string(),
+                value: Some("gregory".to_string()),
+                honoured: false,
+            },
+            ResolvedKeyword {
+                key: "co".to_string(),
+                value: Some("phonebk".to_string()),
+                honoured: true,
+            },
+            ResolvedKeyword {
+                key: "kn".to_string(),
+                value: Some("true".to_string()),
+                honoured: true,
+            },
+        ]
+    );
+
+    // Options replace the requested keywords, and are not added to the
+    // locale.
+    let requested = canonicalize_locale_list(&["th-u-ca-buddhist"]).unwrap();
+    let options = ResolveOptions {
+        locale_matcher: LocaleMatcher::Lookup,
+        keywords: &[("ca", "GREGORY"), ("kn", "maybe")],
+    };
+    let resolved = resolve_locale(
+        &available,
+        &requested,
+        &default,
+        options,
+        &keys,
+        locale_data,
+    );
+    assert_eq!(resolved.locale.to_string(), "th");
+    assert_eq!(resolved.keywords[0].value.as_deref(), Some("gregory"));
+    assert!(!resolved.keywords[0].honoured);
+    assert_eq!(resolved.keywords[1].value, None);
+    assert_eq!(resolved.keywords[2].value.as_deref(), Some("false"));
+
+    // An option equal to the requested keyword keeps it.
+    let options = ResolveOptions {
+        keywords: &[("ca", "buddhist")],
+        ..Default::default()
+    };
+    let resolved = resolve_locale(
+        &available,
+        &requested,
+        &default,
+        options,
+        &keys,
+        locale_data,
+    );
+    assert_eq!(resolved.locale.to_string(), "th-u-ca-buddhist");
+
+    let requested = canonicalize_locale_list(&["fr-u-ca-buddhist"]).unwrap();
+    let resolved = resolve_locale(
+        &available,
+        &requested,
+        &default,
+        ResolveOptions::default(),
+        &keys,
+        locale_data,
+    );
+    assert_eq!(resolved.locale.to_string(), "en");
+}
@@ -0,0 +1,103 @@
+use unic_locale_impl::lcid::{from_lcid, from_windows_name, is_neutral, to_lcid, to_windows_name};
+use unic_locale_impl::Locale;
+
+fn assert_lcid(lcid: u32, expected: &str) {
+    let loc = from_lcid(lcid).expect("Unknown LCID.");
+    assert_eq!(loc.to_string(), expected);
+    assert_eq!(to_lcid(&loc), Some(lcid));
+}
+
+#[test]
+fn test_lcid() {
+    assert_lcid(0x0409, "en-US");
+    assert_lcid(0x0804, "zh-CN");
+    assert_lcid(0x0C0A, "es-ES");
+    assert_lcid(0x040A, "es-ES-u-co-trad");
+    assert_lcid(0x2409, "en-029");
+    assert_lcid(0x580A, "es-419");
+    assert_lcid(0x0803, "ca-ES-valencia");
+    assert_lcid(0x081A, "sr-Latn-CS");
+    assert_lcid(0x241A, "sr-Latn-RS");
+    assert_lcid(0x007F, "und");
+
+    assert_eq!(from_lcid(0x0400), None);
+    assert_eq!(from_lcid(0x1000), None);
+    assert_eq!(from_lcid(0xFFFF), None);
+
+    let loc: Locale = "en-Latn-US".parse().unwrap();
+    assert_eq!(to_lcid(&loc), None);
+}
+
+#[test]
+fn test_sort_lcid() {
+    assert_lcid(0x00010407, "de-DE-u-co-phonebk");
+    assert_lcid(0x00020804, "zh-CN-u-co-stroke");
+    assert_lcid(0x00030404, "zh-TW-u-co-zhuyin");
+    assert_lcid(0x00040411, "ja-JP-u-co-unihan");
+    assert_lcid(0x0001040E, "hu-HU-u-co-ducet");
+    assert_lcid(0x00010437, "ka-GE-u-co-standard");
+
+    // A sort identifier which isn't defined for the language.
+    assert_eq!(from_lcid(0x00010409), None);
+}
+
+#[test]
+fn test_neutral_lcid() {
+    assert_lcid(0x0009, "en");
+    assert_lcid(0x0004, "zh-Hans");
+    assert_lcid(0x7C04, "zh-Hant");
+    assert_lcid(0x701A, "sr-Latn");
+
+    assert_eq!(is_neutral(0x0009), Some(true));
+    assert_eq!(is_neutral(0x7C04), Some(true));
+    assert_eq!(is_neutral(0x0409), Some(false));
+    assert_eq!(is_neutral(0x00010407), Some(false));
+    assert_eq!(is_neutral(0x1000), None);
+}
+
+#[test]
+fn test_windows_name() {
+    let convert = |name| from_windows_name(name).map(|loc| loc.to_string());
+
+    assert_eq!(convert("en-US"), Ok("en-US".to_string()));
+    assert_eq!(convert("zh-CHS"), Ok("zh-Hans".to_string()));
+    assert_eq!(convert("zh-CHT"), Ok("zh-Hant".to_string()));
+    assert_eq!(convert("sr-SP-Latn"), Ok("sr-Latn-CS".to_string()));
+    assert_eq!(convert("sr-Latn-CS"), Ok("sr-Latn-CS".to_string()));
+    assert_eq!(convert("en-CB"), Ok("en-029".to_string()));
+    assert_eq!(
+        convert("de-DE_phoneb"),
+        Ok("de-DE-u-co-phonebk".to_string())
+    );
+    assert_eq!(convert("zh-TW_radstr"), Ok("zh-TW-u-co-unihan".to_string()));
+    assert_eq!(convert(""), Ok("und".to_string()));
+
+    // Custom cultures have no LCID.
+    assert_eq!(convert("en-DE"), Ok("en-DE".to_string()));
+
+    assert_eq!(convert("hu-HU_technl"), Ok("hu-HU-u-co-ducet".to_string()));
+    assert_eq!(convert("hu-HU_tchncl"), Ok("hu-HU-u-co-ducet".to_string()));
+    assert_eq!(
+        convert("ka-GE_modern"),
+        Ok("ka-GE-u-co-standard".to_string())
+    );
+    // Language specific sorts don't apply to other languages.
+    assert!(from_windows_name("de-DE_modern").is_err());
+    assert!(from_windows_name("en-US_bogus").is_err());
+    assert!(from_windows_name("en-USA").is_err());
+
+    let name = |tag: &str| to_windows_name(&tag.parse().unwrap());
+
+    assert_eq!(name("en-US"), Some("en-US".to_string()));
+    assert_eq!(name("es-ES-u-co-trad"), Some("es-ES_tradnl".to_string()));
+    assert_eq!(name("zh-TW-u-co-zhuyin"), Some("zh-TW_pronun".to_string()));
+    assert_eq!(name("hu-HU-u-co-ducet"), Some("hu-HU_technl".to_string()));
+    assert_eq!(
+        name("ka-GE-u-co-standard"),
+        Some("ka-GE_modern".to_string())
+    );
+    assert_eq!(name("de-DE-u-co-standard"), None);
+    assert_eq!(name("und"), Some("".to_string()));
+    assert_eq!(name("de-DE-u-co-emoji"), None);
+    assert_eq!(name("en-US-x-private"), None);
+}
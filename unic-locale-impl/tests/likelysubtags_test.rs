@@ -0,0 +1,60 @@
+use unic_locale_impl::{LikelySubtagsOptions, Locale};
+
+fn locale(input: &str) -> Locale {
+    input.parse().unwrap()
+}
+
+#[test]
+fn maximize_test() {
+    let loc = locale("sr-u-nu-latn-x-priv");
+    let max = loc.maximize();
+    assert_eq!(max.to_string(), "sr-Cyrl-RS-u-nu-latn-x-priv");
+    assert_eq!(max.minimize(), loc);
+    assert_eq!(
+        locale("en-Latn-US-u-hc-h23").minimize().to_string(),
+        "en-u-hc-h23"
+    );
+
+    // The transform extension is left alone by default.
+    let loc = locale("ja-t-zh-m0-names");
+    assert_eq!(loc.maximize().to_string(), "ja-Jpan-JP-t-zh-m0-names");
+
+    let options = LikelySubtagsOptions {
+        transform_lang: true,
+        ..Default::default()
+    };
+    let max = loc.maximize_with_options(options);
+    assert_eq!(max.to_string(), "ja-Jpan-JP-t-zh-Hans-CN-m0-names");
+    assert_eq!(max.minimize_with_options(options), loc);
+}
+
+#[test]
+fn minimize_test() {
+    let favor_script = LikelySubtagsOptions {
+        favor_script: true,
+        ..Default::default()
+    };
+    let loc = locale("zh-Hant-TW-u-ca-roc");
+    assert_eq!(loc.minimize().to_string(), "zh-TW-u-ca-roc");
+    assert_eq!(
+        loc.minimize_with_options(favor_script).to_string(),
+        "zh-Hant-u-ca-roc"
+    );
+
+    let loc = locale("de-Latn-DE-t-zh-Hant-TW");
+    assert_eq!(loc.minimize().to_string(), "de-t-zh-Hant-TW");
+    let options = LikelySubtagsOptions {
+        transform_lang: true,
+        favor_script: true,
+    };
+    assert_eq!(
+        loc.minimize_with_options(options).to_string(),
+        "de-t-zh-Hant"
+    );
+
+    // Unknown languages are kept.
+    assert_eq!(
+        locale("qaa-Latn-u-nu-latn").minimize().to_string(),
+        "qaa-Latn-u-nu-latn"
+    );
+}
@@ -1,6 +1,10 @@
+#![allow(clippy::bool_assert_comparison)]
+
 use unic_langid_impl::LanguageIdentifier;
-use unic_locale_impl::parser::parse_locale;
-use unic_locale_impl::{CharacterDirection, ExtensionsMap, Locale};
+use unic_locale_impl::parser::{parse_locale, ParserError, SubtagKind};
+use unic_locale_impl::{
+    CharacterDirection, ExtensionPolicy, ExtensionsMap, Locale, MatchOptions, PrivateUsePolicy,
+};
 
 fn assert_locale_extensions(loc: &Locale, extensions: &ExtensionsMap) {
     assert_eq!(&loc.extensions, extensions);
@@ -100,6 +104,125 @@ fn test_locale_identifier() {
     assert_parsed_locale_identifier("und-x-testing", &extensions);
 }
 
+#[test]
+fn test_parser_errors() {
+    fn malformed(expected: SubtagKind, offset: usize, length: usize) -> ParserError {
+        ParserError::MalformedSubtag {
+            expected,
+            offset,
+            length,
+        }
+    }
+
+    assert_eq!(
+        parse_locale("en-Latn-USA-u-ca-buddhist"),
+        Err(malformed(SubtagKind::Region, 8, 3))
+    );
+    assert_eq!(
+        parse_locale("en-US-u-ca-buddhist-ÁÁ"),
+        Err(malformed(SubtagKind::UnicodeType, 20, 4))
+    );
+    assert_eq!(
+        parse_locale("en-US-u-c1-gregory"),
+        Err(malformed(SubtagKind::UnicodeKey, 8, 2))
+    );
+    assert_eq!(
+        parse_locale("en-US-x-foo-"),
+        Err(malformed(SubtagKind::Private, 12, 0))
+    );
+    assert_eq!(
+        parse_locale("en-US-t-es-AR-foo"),
+        Err(malformed(SubtagKind::TransformKey, 14, 3))
+    );
+    assert_eq!(
+        parse_locale("en-US-t-h0-hybridtoolong"),
+        Err(malformed(SubtagKind::TransformValue, 11, 13))
+    );
+    assert_eq!(
+        parse_locale("en-US-x-private-tooolong1"),
+        Err(malformed(SubtagKind::Private, 16, 9))
+    );
+    assert_eq!(
+        parse_locale("en-US-u-ca-buddhist-!"),
+        Err(malformed(SubtagKind::ExtensionSingleton, 20, 1))
+    );
+
+    let err = parse_locale("en-US-u-ca-buddhist-u-hc-h12").unwrap_err();
+    assert_eq!(
+        err,
+        ParserError::DuplicateSingleton {
+            offset: 20,
+            length: 1
+        }
+    );
+    assert_eq!(err.to_string(), "Duplicate extension singleton at 20..21");
+
+    let err = parse_locale("en-US-macos-macos").unwrap_err();
+    assert_eq!(err.span(), Some(12..17));
+
+    for input in &[
+        "de-DE-u-ca-gregory-Á",
+        "de-DE-u-attr1-ca-toolongtype",
+        "de-DE-u-Áttr",
+        "de-DE-t-m0-Á",
+        "de-DE-t-1a-foo",
+        "de-DE-t-en-Latn-USA",
+        "de-DE-t-en-h0-Á",
+        "de-DE-x-",
+    ] {
+        let err = parse_locale(input).unwrap_err();
+        assert!(err.span().is_some(), "{} has no span: {:?}", input, err);
+    }
+}
+
+#[test]
+fn test_lenient_parser() {
+    fn assert_lenient(input: &str, expected: &str, dropped: &[&str]) {
+        let (loc, errors) = Locale::from_bytes_lenient(input.as_bytes());
+        assert_eq!(loc.to_string(), expected);
+        let spans = errors
+            .iter()
+            .map(|e| &input[e.span().unwrap()])
+            .collect::<Vec<_>>();
+        assert_eq!(spans, dropped);
+    }
+
+    assert_lenient("en-US-u-hc-h12", "en-US-u-hc-h12", &[]);
+    assert_lenient("en-US-posix-xx_YY", "en-US-posix", &["xx", "YY"]);
+    assert_lenient("en_US.UTF-8", "en-US", &[".UTF-8"]);
+    assert_lenient("de-DE-", "de-DE", &[""]);
+    assert_lenient("sr-Latn-RS-@latin", "sr-Latn-RS", &["@latin"]);
+    assert_lenient(
+        "de-u-co-phonebk-c1-u-nu-latn",
+        "de-u-co-phonebk-nu-latn",
+        &["c1", "u"],
+    );
+    assert_lenient(
+        "en-a-foo-t-es-AR-x-private",
+        "en-t-es-AR-x-private",
+        &["a", "foo"],
+    );
+    assert_lenient("1-u-ca-buddhist", "und", &["1"]);
+    assert_lenient("de-DE-u-ca-buddhist-Á", "de-DE-u-ca-buddhist", &["Á"]);
+    assert_lenient(
+        "de-DE-u-ca-toolongtype-nu-latn",
+        "de-DE-u-ca-nu-latn",
+        &["toolongtype"],
+    );
+    assert_lenient(
+        "de-DE-t-h0-toolongvalue-m0-ungegn",
+        "de-DE-t-h0-m0-ungegn",
+        &["toolongvalue"],
+    );
+    assert_lenient("de-DE-t-en-1a-foo", "de-DE-t-en", &["1a", "foo"]);
+}
+
+#[test]
+fn test_transform_followed_by_unicode() {
+    let loc: Locale = "en-t-es-AR-h0-hybrid-u-ca-buddhist".parse().unwrap();
+    assert_eq!(loc.to_string(), "en-t-es-AR-h0-hybrid-u-ca-buddhist");
+}
+
 #[test]
 fn test_serialize_locale() {
     let loc: Locale = "en-u-hc-h12".parse().unwrap();
@@ -157,6 +280,78 @@ fn test_matches() {
     );
 }
 
+#[test]
+fn test_matches_with_policy() {
+    let options = MatchOptions::default();
+    let de: Locale = "de".parse().unwrap();
+    let de_phonebk: Locale = "de-u-co-phonebk".parse().unwrap();
+    let de_phonebk_arab: Locale = "de-u-co-phonebk-nu-arab-x-foo".parse().unwrap();
+    let de_x_foo: Locale = "de-x-foo".parse().unwrap();
+    let de_x_bar: Locale = "de-x-bar".parse().unwrap();
+
+    // The default policy is the one of `matches`.
+    let policy = ExtensionPolicy::default();
+    assert!(de.matches_with_policy(&de_phonebk, options, policy));
+    assert!(!de.matches_with_policy(&de_x_foo, options, policy));
+
+    let policy = ExtensionPolicy::IGNORE_ALL;
+    assert!(de_x_foo.matches_with_policy(&de_x_bar, options, policy));
+    assert!(de_phonebk_arab.matches_with_policy(&de, options, policy));
+
+    let policy = ExtensionPolicy {
+        unicode_keys: &["co", "NU"],
+        private_use: PrivateUsePolicy::Ignore,
+        ..Default::default()
+    };
+    assert!(de_phonebk.matches_with_policy(&de_phonebk, options, policy));
+    assert!(!de_phonebk.matches_with_policy(&de, options, policy));
+    assert!(!de_phonebk_arab.matches_with_policy(&de_phonebk, options, policy));
+
+    let policy = ExtensionPolicy {
+        missing_keywords_match: true,
+        ..policy
+    };
+    assert!(de_phonebk.matches_with_policy(&de, options, policy));
+    assert!(de_phonebk_arab.matches_with_policy(&de_phonebk, options, policy));
+    let de_eor: Locale = "de-u-co-eor".parse().unwrap();
+    assert!(!de_phonebk_arab.matches_with_policy(&de_eor, options, policy));
+
+    // Invalid keys never match.
+    let policy = ExtensionPolicy {
+        unicode_keys: &["c"],
+        missing_keywords_match: true,
+        ..Default::default()
+    };
+    assert!(!de.matches_with_policy(&de, options, policy));
+
+    // Transform extensions are always ignored.
+    let de_t_en: Locale = "de-t-en".parse().unwrap();
+    let de_t_fr: Locale = "de-t-fr".parse().unwrap();
+    assert!(de_t_en.matches_with_policy(&de_t_fr, options, ExtensionPolicy::default()));
+
+    let policy = ExtensionPolicy {
+        private_use: PrivateUsePolicy::Compare,
+        ..Default::default()
+    };
+    assert!(de_x_foo.matches_with_policy(&de_x_foo, options, policy));
+    assert!(!de_x_foo.matches_with_policy(&de_x_bar, options, policy));
+    assert!(!de_x_foo.matches_with_policy(&de, options, policy));
+    assert!(de.matches_with_policy(&de_phonebk, options, policy));
+
+    // The language identifiers are still compared with `options`.
+    let de_at: Locale = "de-AT-u-co-phonebk".parse().unwrap();
+    let policy = ExtensionPolicy {
+        unicode_keys: &["co"],
+        ..Default::default()
+    };
+    assert!(!de_phonebk.matches_with_policy(&de_at, options, policy));
+    let options = MatchOptions {
+        self_as_range: true,
+        ..Default::default()
+    };
+    assert!(de_phonebk.matches_with_policy(&de_at, options, policy));
+}
+
 #[test]
 fn test_set_fields() {
     let mut loc = Locale::default();
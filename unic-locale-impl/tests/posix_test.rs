@@ -0,0 +1,88 @@
+use unic_locale_impl::posix::{from_posix, to_posix, CLocaleMapping, PosixLoss};
+use unic_locale_impl::Locale;
+
+fn assert_from_posix(input: &str, expected: &str, loss: &[PosixLoss]) {
+    let (loc, l) = from_posix(input, CLocaleMapping::Undetermined).expect("Conversion failed.");
+    assert_eq!(loc.to_string(), expected);
+    assert_eq!(l, loss);
+}
+
+fn assert_to_posix(input: &str, codeset: Option<&str>, expected: &str, loss: &[PosixLoss]) {
+    let loc: Locale = input.parse().expect("Parsing failed.");
+    let (name, l) = to_posix(&loc, codeset);
+    assert_eq!(name, expected);
+    assert_eq!(l, loss);
+}
+
+#[test]
+fn test_from_posix() {
+    assert_from_posix("en_US", "en-US", &[]);
+    assert_from_posix("pl", "pl", &[]);
+    assert_from_posix(
+        "sr_RS.UTF-8@latin",
+        "sr-Latn-RS",
+        &[PosixLoss::Codeset("UTF-8".to_string())],
+    );
+    assert_from_posix("uz_UZ@cyrillic", "uz-Cyrl-UZ", &[]);
+    assert_from_posix("ca_ES@valencia", "ca-ES-valencia", &[]);
+    assert_from_posix("sr_RS@ijekavianlatin", "sr-Latn-RS-ijekavsk", &[]);
+    assert_from_posix("de_DE@euro", "de-DE-u-cu-eur", &[]);
+    assert_from_posix(
+        "de_DE@phonebook",
+        "de-DE",
+        &[PosixLoss::Modifier("phonebook".to_string())],
+    );
+    assert_from_posix("C", "und", &[]);
+    assert_from_posix("POSIX", "und", &[]);
+    assert_from_posix("C.UTF-8", "und", &[PosixLoss::Codeset("UTF-8".to_string())]);
+
+    let (loc, _) = from_posix("POSIX", CLocaleMapping::EnUsPosix).unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-va-posix");
+
+    assert!(from_posix("", CLocaleMapping::Undetermined).is_err());
+    assert!(from_posix("e1_US.UTF-8", CLocaleMapping::Undetermined).is_err());
+}
+
+#[test]
+fn test_to_posix() {
+    assert_to_posix("en-US", None, "en_US", &[]);
+    assert_to_posix("en-US", Some("UTF-8"), "en_US.UTF-8", &[]);
+    assert_to_posix("sr-Latn-RS", Some("UTF-8"), "sr_RS.UTF-8@latin", &[]);
+    assert_to_posix("ca-ES-valencia", None, "ca_ES@valencia", &[]);
+    assert_to_posix("sr-Latn-ijekavsk", None, "sr@ijekavianlatin", &[]);
+    assert_to_posix("de-AT-u-cu-eur", None, "de_AT@euro", &[]);
+    assert_to_posix("und", None, "C", &[]);
+    assert_to_posix("en-US-u-va-posix", Some("UTF-8"), "C.UTF-8", &[]);
+    assert_to_posix(
+        "und-DE",
+        None,
+        "C",
+        &[PosixLoss::Subtags("und-DE".to_string())],
+    );
+    assert_to_posix(
+        "ca-Cyrl-ES-fonipa-valencia",
+        None,
+        "ca_ES@cyrillic",
+        &[
+            PosixLoss::Subtags("fonipa".to_string()),
+            PosixLoss::Subtags("valencia".to_string()),
+        ],
+    );
+    assert_to_posix(
+        "ja-Hrkt-JP-u-ca-japanese",
+        None,
+        "ja_JP",
+        &[
+            PosixLoss::Subtags("Hrkt".to_string()),
+            PosixLoss::Subtags("u-ca-japanese".to_string()),
+        ],
+    );
+}
+
+#[cfg(feature = "likelysubtags")]
+#[test]
+fn test_to_posix_likely_script() {
+    assert_to_posix("en-Latn-US", None, "en_US", &[]);
+    assert_to_posix("sr-Cyrl-RS", None, "sr_RS", &[]);
+    assert_to_posix("sr-Latn-RS", None, "sr_RS@latin", &[]);
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use unic_locale_impl::posix::CLocaleMapping;
+use unic_locale_impl::system::preferred_locales_from;
+
+fn assert_preferred_locales(env: &[(&str, &str)], c: CLocaleMapping, expected: &[&str]) {
+    let env: HashMap<&str, &str> = env.iter().copied().collect();
+    let locales = preferred_locales_from(|key| env.get(key).map(|v| v.to_string()), c);
+    assert_eq!(
+        locales.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn test_precedence() {
+    let c = CLocaleMapping::Undetermined;
+
+    assert_preferred_locales(&[], c, &[]);
+    assert_preferred_locales(&[("LANG", "de_DE.UTF-8")], c, &["de-DE"]);
+    assert_preferred_locales(
+        &[("LANG", "de_DE.UTF-8"), ("LC_MESSAGES", "fr_FR.UTF-8")],
+        c,
+        &["fr-FR"],
+    );
+    assert_preferred_locales(
+        &[
+            ("LANG", "de_DE.UTF-8"),
+            ("LC_MESSAGES", "fr_FR.UTF-8"),
+            ("LC_ALL", "pl_PL.UTF-8"),
+        ],
+        c,
+        &["pl-PL"],
+    );
+    assert_preferred_locales(&[("LANG", "de_DE.UTF-8"), ("LC_ALL", "")], c, &["de-DE"]);
+}
+
+#[test]
+fn test_language_list() {
+    let c = CLocaleMapping::Undetermined;
+
+    assert_preferred_locales(
+        &[
+            ("LANG", "sr_RS.UTF-8@latin"),
+            ("LANGUAGE", "sr@latin:en_GB::en"),
+        ],
+        c,
+        &["sr-Latn", "en-GB", "en"],
+    );
+    assert_preferred_locales(
+        &[("LANG", "ca_ES.UTF-8@valencia"), ("LANGUAGE", "e1:C")],
+        c,
+        &["ca-ES-valencia"],
+    );
+    assert_preferred_locales(
+        &[("LANG", "pt_BR"), ("LANGUAGE", "pt_BR:pt_BR:pt")],
+        c,
+        &["pt-BR", "pt"],
+    );
+    // `LANGUAGE` is ignored for the `C` locale.
+    assert_preferred_locales(&[("LC_ALL", "C"), ("LANGUAGE", "de")], c, &["und"]);
+}
+
+#[test]
+fn test_c_locale() {
+    assert_preferred_locales(
+        &[("LANG", "C.UTF-8")],
+        CLocaleMapping::Undetermined,
+        &["und"],
+    );
+    assert_preferred_locales(
+        &[("LANG", "POSIX")],
+        CLocaleMapping::EnUsPosix,
+        &["en-US-u-va-posix"],
+    );
+}
@@ -1,3 +1,5 @@
+#![no_std]
+
 use proc_macro_hack::proc_macro_hack;
 pub use tinystr::{TinyStr4, TinyStr8};
 pub use unic_locale_impl::subtags;
@@ -71,36 +71,124 @@
 //!
 //! ## Likely Subtags
 //!
-//! If `feature = "likelysubtags"` is selected, the `Locale` gains two more methods:
+//! If `feature = "likelysubtags"` is selected, the `Locale` gains `maximize` and `minimize`
+//! methods returning a copy of the `Locale` with its subtags extended to their most likely
+//! values, or with the subtags that are not needed removed. Extensions are kept.
 //!
-//!  * add_likely_subtags
-//!  * remove_likely_subtags
+//! `maximize_with_options` and `minimize_with_options` can also update the language of the
+//! transform extension, and minimize keeping the script rather than the region, like ICU's
+//! "favor script" mode.
 //!
-//! Both of them operate in place updating the existing `Locale` by either extending
-//! subtags to most likely values, or removing the subtags that are not needed.
-//!
-//! Both methods return a `bool` that indicates if the identifier has been modified.
-//!
-//! ``` ignore
-//! use unic_locale::Locale;
+//! ```
+//! # #[cfg(feature = "likelysubtags")]
+//! # {
+//! use unic_locale::{LikelySubtagsOptions, Locale};
 //!
-//! let mut loc: Locale = "fr-FR".parse()
+//! let loc: Locale = "zh-TW-u-nu-hanidec".parse()
 //!     .expect("Parsing failed.");
 //!
-//! assert_eq!(loc.add_likely_subtags(), true);
-//! assert_eq!(loc, "fr-Latn-FR");
+//! let max = loc.maximize();
+//! assert_eq!(max.to_string(), "zh-Hant-TW-u-nu-hanidec");
+//! assert_eq!(max.minimize(), loc);
 //!
-//! assert_eq!(loc.remove_likely_subtags(), true);
-//! assert_eq!(loc, "fr");
+//! let options = LikelySubtagsOptions {
+//!     favor_script: true,
+//!     ..Default::default()
+//! };
+//! assert_eq!(max.minimize_with_options(options).to_string(), "zh-Hant-u-nu-hanidec");
+//! # }
 //! ```
 //!
 //! The feature is optional because it increases the binary size of the library by including
 //! a data table for CLDR likelySubtags.
 //!
+//! ## Display names
+//!
+//! If `feature = "display-names"` is selected, `Locale` gains a `display_name` method
+//! which returns its name in a display locale, like "Schweizer Hochdeutsch" or
+//! "Deutsch (Schweiz)" for `de-CH` in German, using CLDR data generated by `unic-datagen`.
+//! The bundled display locales are German, English and French and their regional variants.
+//! See the `display_names` module for the available options.
+//!
+//! ## Territory info
+//!
+//! If `feature = "territory-info"` is selected, `Region` gains a `languages` method listing
+//! the languages spoken in the territory, the most spoken first, with their official status,
+//! and `Language` gains a `regions` method listing the territories it is spoken in. Both
+//! return `LanguageIdentifier`s with the script filled in, using CLDR `territoryInfo` and
+//! `languageData` data generated by `unic-datagen`.
+//! See the `territory_info` module for details.
+//!
+//! ## ISO 639
+//!
+//! If `feature = "iso639"` is selected, `Language` gains `to_iso639_1`, `to_iso639_2t`,
+//! `to_iso639_2b` and `to_iso639_3` methods, and a `from_iso639` constructor which accepts
+//! any of these codes, like `ger`, `deu` or `de`, and normalizes them to the Unicode canonical
+//! code, using the ISO 639-3 code tables from SIL generated by `unic-datagen`. `Language` also
+//! gains `macrolanguage`, `encompassed_languages` and `is_macrolanguage` methods, and
+//! `MatchOptions::macrolanguages` lets `matches_with_options` match an individual language,
+//! like `cmn`, with its macrolanguage, like `zh`. The bundled macrolanguage table fully covers
+//! Arabic, Persian, Malay, Norwegian, Albanian and Chinese, and relates the other macrolanguages
+//! to the individual languages CLDR aliases to them, like Swahili to `swh`.
+//!
+//! ## IANA registry
+//!
+//! If `feature = "iana-registry"` is selected, subtags gain a `registry_record` method returning
+//! their description, `Added` and `Deprecated` dates and preferred value from the IANA Language
+//! Subtag Registry. `Language` gains `suppress_script`, `Variant` gains `prefixes` and
+//! `has_valid_prefix`, and `LanguageIdentifier` gains `has_valid_variant_prefixes`,
+//! `has_suppressed_script` and `remove_suppressed_script`, which turns `en-Latn-US` into `en-US`.
+//! The bundled registry is a partial excerpt which only covers a few dozen common subtags, so
+//! other subtags have no record. See the `iana_registry` module for details.
+//!
+//! ## Locale info
+//!
+//! If `feature = "locale-info"` is selected, `Locale` gains the `get_calendars`,
+//! `get_hour_cycles` and `get_week_info` methods of `Intl.Locale`, which honour the `ca`, `hc`
+//! and `fw` keywords, and otherwise use the region or likely region of the `Locale` with CLDR
+//! `calendarPreferenceData`, `timeData` and `weekData` generated by `unic-datagen`.
+//! The rest of the `Intl.Locale` API, like `Locale::with_options`, is always available, as are
+//! the ECMA-402 locale resolution operations, like `lookup_matcher` and `resolve_locale`.
+//! See the `intl` and `locale_info` modules for details.
+//!
+//! ## Aliases, parent locales and validity
+//!
+//! The `aliases`, `parent-locales` and `validity` features add the `replace_aliases`, `parent`
+//! and `is_valid` methods to the `id` of a `Locale`, which replace deprecated subtags, return
+//! the locale to inherit locale data from, and tell whether all subtags are assigned, using
+//! CLDR data generated by `unic-datagen`.
+//! See the `aliases`, `parent_locales` and `validity` modules for details.
+//!
+//! ## Platform conversions
+//!
+//! Conversions to and from platform specific locale names are each behind their own
+//! feature: `posix` for POSIX locale names like `sr_RS.UTF-8@latin`, `icu` for ICU legacy
+//! IDs like `de@collation=phonebook`, `lcid` for Windows LCIDs and Win32 names, `android`
+//! and `apple` for Android resource qualifiers and `.lproj` names, and `gettext` for gettext
+//! catalog lookup. The `system` feature enables `posix`.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is enabled by default. Without it the crate is `no_std` and only
+//! requires `alloc`, keeping parsing, serialization, matching, likely subtags and character
+//! direction. The `std` feature only adds `std::error::Error` implementations, and is required
+//! by the `cldr-json` and `system` features.
+//!
+//! ```toml
+//! unic-locale = { version = "0.9", default-features = false }
+//! ```
+//!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Locale Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_locale_identifier
 //! [`Locale`]: ./struct.Locale.html
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub use unic_locale_impl::*;
 
+#[doc(hidden)]
+pub mod __private {
+    pub extern crate alloc;
+}
+
 #[cfg(feature = "unic-locale-macros")]
 pub use unic_locale_macros::locale;
 
@@ -108,7 +196,7 @@ pub use unic_locale_macros::locale;
 #[macro_export]
 macro_rules! locales {
     ( $($locale:expr),* ) => {
-        vec![$(
+        $crate::__private::alloc::vec![$(
             $crate::locale!($locale),
         )*]
     };